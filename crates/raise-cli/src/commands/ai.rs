@@ -1,16 +1,36 @@
 // FICHIER : src-tauri/tools/raise-cli/src/commands/ai.rs
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 use raise_core::{user_error, user_info, user_success, utils::prelude::*};
 
 // --- IMPORTS MÉTIER RAISE ---
 use raise_core::ai::agents::intent_classifier::{EngineeringIntent, IntentClassifier};
+use raise_core::ai::agents::intent_examples::{ExampleSource, IntentExampleStore};
+use raise_core::ai::agents::sandbox::{ArtifactOverlay, ChangeKind};
 use raise_core::ai::agents::tools::query_knowledge_graph;
 use raise_core::ai::agents::{dynamic_agent::DynamicAgent, Agent, AgentContext};
 use raise_core::json_db::collections::manager::CollectionsManager;
+use raise_core::json_db::storage::StorageEngine;
 
+use raise_core::ai::context::ingestion::ingest_path;
 use raise_core::ai::context::rag::RagRetriever;
-use raise_core::ai::llm::client::LlmClient;
+use raise_core::ai::graph_store::duplicates::{
+    find_duplicates, DuplicateOptions, DEFAULT_DUPLICATE_THRESHOLD,
+};
+use raise_core::ai::graph_store::GraphStore;
+use raise_core::ai::llm::client::{LlmClient, LlmEngine};
+use raise_core::ai::llm::models::{LocalModelManager, ModelCheck};
+use raise_core::ai::llm::usage::UsageTracker;
+use raise_core::ai::maintenance::{MaintenanceScheduler, MaintenanceTask, RunStatus, RunTrigger};
+use raise_core::ai::memory::benchmark::{
+    load_corpus, normalize, run_backend, sample_queries, save_report, BackendBenchResult,
+    BenchBackend, VectorBenchReport, BENCH_COLLECTION,
+};
+use raise_core::ai::memory::migration::EmbeddingMigrator;
+use raise_core::ai::memory::{
+    native_store::NativeLocalStore, qdrant_store::QdrantMemory, VectorStore,
+};
+use raise_core::ai::nlp::embeddings::EmbeddingEngine;
 use raise_core::ai::nlp::parser::CommandType;
 use raise_core::ai::orchestrator::AiOrchestrator;
 use raise_core::ai::training::ai_train_domain_native;
@@ -21,6 +41,7 @@ use raise_core::utils::data::json::Clearance;
 use raise_core::utils::io::audio::AudioListener;
 
 use raise_core::ai::agents::prompt_engine::PromptEngine;
+use raise_core::ai::agents::prompt_registry::PromptRegistry;
 use raise_core::ai::agents::tools::extract_json_from_llm;
 use raise_core::ai::assurance::health::RaiseHealthEngine;
 use raise_core::services::ai_service::validate_arcadia_gnn;
@@ -49,6 +70,9 @@ pub enum AiCommands {
         input: String,
         #[arg(long, short = 'x')]
         execute: bool,
+        /// Simule l'exécution : affiche le diff des artefacts et demande approbation avant écriture
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// 🔍 Inspecter un agent et son prompt lié
@@ -87,6 +111,47 @@ pub enum AiCommands {
         uri_b: String,
     },
 
+    /// 📥 Ingeste des documents (md, txt, pdf, docx, xlsx, pptx, odt) dans le RAG
+    #[command(visible_alias = "in")]
+    Ingest {
+        /// Fichier ou dossier (parcouru récursivement)
+        path: String,
+    },
+
+    /// 🧬 Cohérence de l'index RAG avec le modèle d'embedding (statut, migration, historique)
+    #[command(visible_alias = "emb")]
+    Embeddings {
+        #[command(subcommand)]
+        action: EmbeddingAction,
+    },
+
+    /// 👯 Détecte les éléments quasi dupliqués d'une même couche et propose leur fusion
+    #[command(visible_alias = "dup")]
+    Duplicates {
+        /// Similarité cosinus minimale (0.0 - 1.0)
+        #[arg(long, default_value_t = DEFAULT_DUPLICATE_THRESHOLD)]
+        threshold: f32,
+        /// Couche analysée (oa, sa, la, pa...) ; toutes par défaut
+        #[arg(long)]
+        layer: Option<String>,
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+
+    /// 📦 Modèles GGUF locaux (catalogue, téléchargement vérifié, bascule du modèle actif)
+    #[command(visible_alias = "mdl")]
+    Models {
+        #[command(subcommand)]
+        action: ModelAction,
+    },
+
+    /// 🧹 Tâches de maintenance planifiées (résumé des sessions, index vectoriel, dérive)
+    #[command(visible_alias = "mnt")]
+    Maintenance {
+        #[command(subcommand)]
+        action: MaintenanceAction,
+    },
+
     #[command(visible_alias = "r")]
     Rag {
         #[command(subcommand)]
@@ -150,14 +215,197 @@ pub enum AiCommands {
         #[arg(short, long)]
         domain: Option<String>,
     },
+
+    /// 📝 Registre versionné des gabarits de prompts
+    #[command(visible_alias = "p")]
+    Prompts {
+        #[command(subcommand)]
+        action: PromptAction,
+    },
+
+    /// 📚 Exemples annotés (few-shot) du classifieur d'intentions
+    #[command(visible_alias = "ex")]
+    Examples {
+        #[command(subcommand)]
+        action: ExampleAction,
+    },
+
+    /// 💶 Tableau de bord des coûts LLM (par projet, session et backend)
+    #[command(visible_alias = "u")]
+    Usage {
+        /// Mois comptable (AAAA-MM), courant par défaut
+        #[arg(long)]
+        month: Option<String>,
+    },
+
+    /// ⏱️ Compare les backends vectoriels (débit d'indexation, latence, recall@k)
+    #[command(visible_alias = "bv")]
+    BenchVectors {
+        /// Corpus JSON ou JSONL (`id`, `content`, `metadata`, `vector` optionnel)
+        corpus: String,
+
+        /// Backends à comparer (candle, qdrant, leann)
+        #[arg(long, value_delimiter = ',', default_value = "candle,qdrant,leann")]
+        backends: Vec<String>,
+
+        /// Nombre de voisins évalués (recall@k)
+        #[arg(short, long, default_value_t = 10)]
+        k: usize,
+
+        /// Nombre de requêtes échantillonnées dans le corpus
+        #[arg(short, long, default_value_t = 50)]
+        queries: usize,
+
+        /// URL Qdrant (écrase la configuration du composant ai_graph_store)
+        #[arg(long)]
+        qdrant_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum PromptAction {
+    /// 📋 Liste la dernière version de chaque gabarit
+    #[command(visible_alias = "ls")]
+    List,
+
+    /// 🔍 Affiche un gabarit (dernière version par défaut) et son historique
+    Show {
+        name: String,
+        #[arg(long)]
+        version: Option<u32>,
+    },
+
+    /// ✏️ Publie une nouvelle version (texte ou fichier)
+    Set {
+        name: String,
+        /// Texte du gabarit (variables en `{{nom}}`)
+        #[arg(long, conflicts_with = "file", required_unless_present = "file")]
+        text: Option<String>,
+        /// Fichier contenant le gabarit
+        #[arg(long)]
+        file: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+    },
+
+    /// 🧪 Ajoute une note d'évaluation à une version
+    Note {
+        name: String,
+        version: u32,
+        comment: String,
+        #[arg(long)]
+        score: Option<f64>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ExampleAction {
+    /// 📋 Liste les exemples annotés
+    #[command(visible_alias = "ls")]
+    List,
+
+    /// ➕ Ajoute un exemple curé
+    Add {
+        utterance: String,
+        /// Intention attendue en JSON (ex: '{"intent":"chat"}')
+        intent: String,
+    },
+
+    /// 🚩 Signale une erreur de classification constatée en usage réel
+    Flag {
+        utterance: String,
+        /// Intention attendue en JSON
+        expected: String,
+        /// Intention produite à tort (JSON)
+        #[arg(long)]
+        predicted: Option<String>,
+    },
+
+    /// 🗑️ Supprime un exemple
+    #[command(visible_alias = "rm")]
+    Remove { id: String },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum EmbeddingAction {
+    /// 🔎 Compare l'index au modèle actif (dimension et identité du modèle)
+    #[command(visible_alias = "s")]
+    Status,
+
+    /// 🔁 Ré-vectorise la base de connaissances puis bascule atomiquement l'index
+    #[command(visible_alias = "m")]
+    Migrate,
+
+    /// 📜 Historique des migrations et leur progression
+    #[command(visible_alias = "h")]
+    History,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ModelAction {
+    /// 📋 Modèles déclarés dans la configuration ai_llm et leur état local
+    #[command(visible_alias = "l")]
+    List,
+
+    /// ⬇️ Télécharge un modèle depuis HuggingFace (empreinte SHA-256 contrôlée)
+    #[command(visible_alias = "d")]
+    Download { id: String },
+
+    /// 🔎 Recalcule l'empreinte des fichiers installés
+    #[command(visible_alias = "v")]
+    Verify { id: String },
+
+    /// 🔁 Fait du modèle le modèle d'inférence actif
+    #[command(visible_alias = "u")]
+    Use { id: String },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum MaintenanceAction {
+    /// 🗓️ Planification et prochaine exécution de chaque tâche
+    #[command(visible_alias = "s")]
+    Schedule,
+
+    /// ▶️ Exécute immédiatement une tâche
+    #[command(visible_alias = "r")]
+    Run {
+        #[arg(value_enum)]
+        task: CliMaintenanceTask,
+    },
+
+    /// 📜 Historique des exécutions
+    #[command(visible_alias = "h")]
+    History {
+        #[arg(long, value_enum)]
+        task: Option<CliMaintenanceTask>,
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum CliMaintenanceTask {
+    Sessions,
+    VectorIndex,
+    Drift,
+}
+
+impl From<CliMaintenanceTask> for MaintenanceTask {
+    fn from(task: CliMaintenanceTask) -> Self {
+        match task {
+            CliMaintenanceTask::Sessions => MaintenanceTask::SummarizeSessions,
+            CliMaintenanceTask::VectorIndex => MaintenanceTask::OptimizeVectorIndex,
+            CliMaintenanceTask::Drift => MaintenanceTask::DriftSnapshot,
+        }
+    }
 }
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum RagAction {
-    /// 📥 Ingeste un document (texte) dans la base vectorielle locale
+    /// 📥 Ingeste un document ou un dossier dans la base vectorielle locale
     #[command(visible_alias = "i")]
     Ingest {
-        /// Chemin vers le fichier à ingérer
+        /// Fichier ou dossier à ingérer
         path: String,
     },
 
@@ -186,6 +434,98 @@ pub async fn handle(args: AiArgs, ctx: CliContext) -> RaiseResult<()> {
         ),
     };
 
+    // Le benchmark vectoriel ne dépend ni du LLM ni de l'orchestrateur
+    if let Some(AiCommands::BenchVectors {
+        corpus,
+        backends,
+        k,
+        queries,
+        qdrant_url,
+    }) = &args.command
+    {
+        let manager = CollectionsManager::new(&ctx.storage, &ctx.active_domain, &ctx.active_db);
+        return run_vector_bench(
+            &manager,
+            corpus,
+            backends,
+            *k,
+            *queries,
+            qdrant_url.as_deref(),
+        )
+        .await;
+    }
+
+    // Le registre de prompts ne dépend ni du LLM ni de l'orchestrateur
+    if let Some(AiCommands::Prompts { action }) = &args.command {
+        return handle_prompt_action(action, &ctx).await;
+    }
+
+    if let Some(AiCommands::Examples { action }) = &args.command {
+        return handle_example_action(action, &ctx).await;
+    }
+
+    // Le tableau de bord des coûts ne lit que la collection système `ai_usage`
+    if let Some(AiCommands::Usage { month }) = &args.command {
+        let dashboard = UsageTracker::system(&ctx.storage)
+            .dashboard(month.as_deref())
+            .await?;
+        println!("\n💶 CONSOMMATION LLM — {}", dashboard.month);
+        println!(
+            "Total : {} appels, {} tokens, {:.2}",
+            dashboard.total.calls,
+            dashboard.total.input_tokens + dashboard.total.output_tokens,
+            dashboard.total.cost
+        );
+        for project in &dashboard.by_project {
+            let budget = match (project.budget, project.budget_ratio) {
+                (Some(b), Some(r)) => format!(" / {:.2} ({:.0} %)", b, r * 100.0),
+                _ => String::new(),
+            };
+            println!("  📁 {} : {:.2}{}", project.key, project.cost, budget);
+        }
+        for backend in &dashboard.by_backend {
+            println!(
+                "  🤖 {} : {:.2} ({} appels)",
+                backend.key, backend.cost, backend.calls
+            );
+        }
+        user_info!("AI_USAGE_DASHBOARD", json_value!(dashboard));
+        return Ok(());
+    }
+
+    // L'analyse des doublons n'utilise que le modèle et les vecteurs du GraphStore
+    if let Some(AiCommands::Duplicates {
+        threshold,
+        layer,
+        limit,
+    }) = &args.command
+    {
+        let options = DuplicateOptions {
+            threshold: *threshold,
+            layer: layer.clone(),
+            limit: *limit,
+        };
+        return run_duplicates(&ctx.storage, &ctx.active_domain, &ctx.active_db, &options).await;
+    }
+
+    // La gestion des modèles ne dépend que de la configuration ai_llm (et du moteur natif pour la bascule)
+    if let Some(AiCommands::Models { action }) = &args.command {
+        let models = LocalModelManager::system(&ctx.storage);
+        return run_model_action(&models, action, ctx.kernel.native_llm.as_ref()).await;
+    }
+
+    // La maintenance s'appuie sur l'orchestrateur s'il est chargé (LLM, index du RAG)
+    if let Some(AiCommands::Maintenance { action }) = &args.command {
+        let mut scheduler = MaintenanceScheduler::system(ctx.storage.clone());
+        if let Some(orch) = &ctx.kernel.orchestrator {
+            let orch = orch.lock().await;
+            scheduler = scheduler
+                .with_llm(orch.llm_remote.clone())
+                .with_vector_store(orch.rag.vector_store(), orch.rag.collection_name());
+        }
+        return run_maintenance_action(&scheduler, action).await;
+    }
+
     let orch_ref = match &ctx.kernel.orchestrator {
         Some(o) => o,
         None => raise_error!(
@@ -256,6 +596,15 @@ pub async fn handle(args: AiArgs, ctx: CliContext) -> RaiseResult<()> {
         run_rag_action(domain_path.clone(), &manager, action.clone()).await?;
         return Ok(());
     }
+    if let AiCommands::Embeddings { action } = &command {
+        run_embedding_action(domain_path.clone(), &manager, &storage, action).await?;
+        return Ok(());
+    }
+    if let AiCommands::Ingest { path } = &command {
+        let action = RagAction::Ingest { path: path.clone() };
+        run_rag_action(domain_path.clone(), &manager, action).await?;
+        return Ok(());
+    }
 
     let client = LlmClient::new(&manager, storage.clone(), ctx.kernel.native_llm.clone()).await?;
 
@@ -264,6 +613,7 @@ pub async fn handle(args: AiArgs, ctx: CliContext) -> RaiseResult<()> {
         .as_ref()
         .map(|s| s.id.clone())
         .unwrap_or_else(|| "cli_session".to_string());
+    let client = client.with_session(&session_id);
 
     // 1. On crée un manager pointant sur la partition système
     let sys_manager = raise_core::json_db::collections::manager::CollectionsManager::new(
@@ -316,8 +666,19 @@ pub async fn handle(args: AiArgs, ctx: CliContext) -> RaiseResult<()> {
             }
             run_voice_mode(&agent_ctx, client, &manager).await?
         }
-        AiCommands::Classify { input, execute } => {
-            process_input(&agent_ctx, &input, client, execute).await
+        AiCommands::Classify {
+            input,
+            execute,
+            dry_run,
+        } => {
+            if dry_run {
+                let overlay = SharedRef::new(ArtifactOverlay::new());
+                let sim_ctx = agent_ctx.clone().with_dry_run(overlay.clone());
+                process_input(&sim_ctx, &input, client, true).await;
+                review_pending_changes(&overlay, &storage).await?;
+            } else {
+                process_input(&agent_ctx, &input, client, execute).await
+            }
         }
         AiCommands::Inspect { reference } => {
             inspect_agent_logic(&agent_ctx, &reference, &ctx.active_domain, &ctx.active_db).await?;
@@ -518,40 +879,30 @@ async fn run_rag_action(
 
     match action {
         RagAction::Ingest { path } => {
-            let target_path = PathBuf::from(&path);
             user_info!("RAG_INGESTION_START", json_value!({"path": path}));
 
-            if !target_path.exists() {
-                raise_error!(
-                    "RAG_FILE_NOT_FOUND",
-                    error = "Le fichier ou dossier spécifié n'existe pas.",
-                    context = json_value!({"path": path})
-                );
+            let report = ingest_path(&mut rag_engine, manager, Path::new(&path)).await?;
+            for file in &report.files {
+                if file.already_indexed {
+                    println!("   ⏭️  {} (déjà indexé)", file.path);
+                } else {
+                    println!("   ✅ {} — {} chunks", file.path, file.chunks);
+                }
+            }
+            for skipped in &report.skipped {
+                println!("   ⚠️  {} : {}", skipped.path, skipped.reason);
             }
 
-            let content = fs::read_to_string_async(&target_path).await?;
-            let source_name = target_path
-                .file_name()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_string();
-
-            match rag_engine
-                .index_document(manager, &content, &source_name)
-                .await
-            {
-                Ok(chunks_count) => {
-                    user_success!(
-                        "RAG_INGESTION_SUCCESS",
-                        json_value!({ "chunks_indexed": chunks_count, "source": source_name })
-                    );
-                }
-                Err(e) => {
-                    user_error!(
-                        "RAG_INGESTION_FAILED",
-                        json_value!({ "error": e.to_string(), "path": path })
-                    );
-                }
+            if report.files.is_empty() {
+                user_error!(
+                    "RAG_INGESTION_FAILED",
+                    json_value!({ "path": path, "skipped": report.skipped.len() })
+                );
+            } else {
+                user_success!(
+                    "RAG_INGESTION_SUCCESS",
+                    json_value!({ "chunks_indexed": report.total_chunks, "files": report.files.len(), "skipped": report.skipped.len() })
+                );
             }
         }
 
@@ -583,6 +934,240 @@ async fn run_rag_action(
     Ok(())
 }
 
+async fn run_embedding_action(
+    domain_path: PathBuf,
+    manager: &CollectionsManager<'_>,
+    storage: &StorageEngine,
+    action: &EmbeddingAction,
+) -> RaiseResult<()> {
+    let migrator = EmbeddingMigrator::system(storage);
+
+    if let EmbeddingAction::History = action {
+        let jobs = migrator.list().await?;
+        if jobs.is_empty() {
+            println!("Aucune migration d'embeddings enregistrée.");
+        }
+        for job in jobs {
+            println!(
+                "{} [{:?}] {}/{} : {} ({}d) -> {} ({}d) — {:.0}% {}",
+                job.started_at.format("%Y-%m-%d %H:%M"),
+                job.state,
+                job.space,
+                job.collection,
+                job.from_model.as_deref().unwrap_or("-"),
+                job.from_dimension.unwrap_or(0),
+                job.to_model,
+                job.to_dimension,
+                job.progress() * 100.0,
+                job.error.as_deref().unwrap_or("")
+            );
+        }
+        return Ok(());
+    }
+
+    let mut rag = RagRetriever::new_internal(domain_path, manager).await?;
+    match action {
+        EmbeddingAction::Status => {
+            let status = rag.embedding_status(manager).await?;
+            let current = status
+                .current
+                .as_ref()
+                .map(|m| format!("{} ({} dimensions)", m.model, m.dimension))
+                .unwrap_or_else(|| "index vide".to_string());
+            println!("\n🧬 Collection : {}", status.collection);
+            println!("   Index  : {}", current);
+            println!(
+                "   Modèle : {} ({} dimensions)",
+                status.target_model, status.target_dimension
+            );
+            if status.migration_required {
+                user_warn!(
+                    "WRN_EMBEDDING_MIGRATION_REQUIRED",
+                    json_value!({ "hint": "raise ai embeddings migrate" })
+                );
+            } else {
+                user_success!("INF_EMBEDDING_UP_TO_DATE", json_value!({}));
+            }
+        }
+        EmbeddingAction::Migrate => match rag.migrate_embeddings(manager, &migrator).await? {
+            Some(job) => println!(
+                "✅ {} vecteurs ré-calculés ({} -> {}).",
+                job.processed,
+                job.from_dimension.unwrap_or(0),
+                job.to_dimension
+            ),
+            None => user_info!(
+                "INF_EMBEDDING_UP_TO_DATE",
+                json_value!({ "collection": rag.collection_name() })
+            ),
+        },
+        EmbeddingAction::History => {}
+    }
+    Ok(())
+}
+
+async fn run_duplicates(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    options: &DuplicateOptions,
+) -> RaiseResult<()> {
+    let model = raise_core::services::model_service::load_project_model(storage, space, db).await?;
+    let manager = CollectionsManager::new(storage, space, db);
+    let graph_path = storage.config.db_root(space, db).join("graph_store");
+    let store = GraphStore::new(graph_path, &manager).await?;
+
+    let report = find_duplicates(&store, &manager, &model, options).await?;
+    println!(
+        "\n👯 {} éléments comparés (seuil {:.2}), {} sans vecteur",
+        report.compared,
+        report.threshold,
+        report.missing_vectors.len()
+    );
+    if report.candidates.is_empty() {
+        println!("Aucun doublon détecté.");
+    }
+    for pair in &report.candidates {
+        println!(
+            "[{}] {:.3} : {} ({}) ≈ {} ({})",
+            pair.layer,
+            pair.similarity,
+            pair.left.name,
+            pair.left.id,
+            pair.right.name,
+            pair.right.id
+        );
+        let conflicts = if pair.proposal.conflicts.is_empty() {
+            String::new()
+        } else {
+            format!(" — à arbitrer : {}", pair.proposal.conflicts.join(", "))
+        };
+        println!(
+            "    ↳ fusion proposée : garder {}, absorber {}{}",
+            pair.proposal.keep, pair.proposal.absorb, conflicts
+        );
+    }
+    Ok(())
+}
+
+async fn run_model_action(
+    models: &LocalModelManager<'_>,
+    action: &ModelAction,
+    engine: Option<&SharedRef<AsyncMutex<dyn LlmEngine>>>,
+) -> RaiseResult<()> {
+    let print_check = |check: &ModelCheck| {
+        for file in &check.files {
+            let state = match file.verified {
+                Some(true) => "✅",
+                Some(false) => "❌",
+                None => "➖",
+            };
+            println!(
+                "{} {} ({} octets) sha256={}",
+                state, file.path, file.bytes, file.sha256
+            );
+        }
+    };
+
+    match action {
+        ModelAction::List => {
+            let statuses = models.list().await?;
+            if statuses.is_empty() {
+                println!("Aucun modèle déclaré (service_settings.models du composant ai_llm).");
+            }
+            for status in statuses {
+                let marker = if status.active { "⭐" } else { "  " };
+                let state = match status.size_bytes {
+                    Some(bytes) if status.installed => format!("{} Mo", bytes / (1024 * 1024)),
+                    _ => "non installé".to_string(),
+                };
+                println!(
+                    "{} {} — {}/{} [{}] {}",
+                    marker,
+                    status.spec.id,
+                    status.spec.weights.repo,
+                    status.spec.weights.file,
+                    state,
+                    status.spec.description
+                );
+            }
+        }
+        ModelAction::Download { id } => print_check(&models.download(id).await?),
+        ModelAction::Verify { id } => {
+            let check = models.verify(id).await?;
+            print_check(&check);
+            if !check.is_valid() {
+                raise_error!(
+                    "ERR_AI_MODEL_CHECKSUM_MISMATCH",
+                    error = "Fichier(s) altéré(s) : relancez le téléchargement.",
+                    context = json_value!({ "model_id": id })
+                );
+            }
+        }
+        ModelAction::Use { id } => {
+            let status = models.switch(id, engine).await?;
+            if engine.is_none() {
+                user_info!(
+                    "INF_AI_MODEL_ACTIVE_ON_RESTART",
+                    json_value!({ "model_id": status.spec.id, "hint": "Le moteur natif n'est pas chargé : le modèle sera utilisé au prochain démarrage." })
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn run_maintenance_action(
+    scheduler: &MaintenanceScheduler,
+    action: &MaintenanceAction,
+) -> RaiseResult<()> {
+    match action {
+        MaintenanceAction::Schedule => {
+            for entry in scheduler.schedule(&UtcClock::now()).await? {
+                let next = entry
+                    .next_run
+                    .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                let state = if entry.enabled { "✅" } else { "⏸️" };
+                println!(
+                    "{} {:?} [{}] prochaine exécution : {}",
+                    state, entry.task, entry.cron, next
+                );
+            }
+        }
+        MaintenanceAction::Run { task } => {
+            let run = scheduler
+                .run_task((*task).into(), RunTrigger::Manual)
+                .await?;
+            match run.status {
+                RunStatus::Succeeded => println!("✅ {:?} : {}", run.task, run.details),
+                RunStatus::Failed => raise_error!(
+                    "ERR_AI_MAINTENANCE_RUN_FAILED",
+                    error = run.error.clone().unwrap_or_default(),
+                    context = json_value!({ "task": run.task, "run_id": run.id })
+                ),
+            }
+        }
+        MaintenanceAction::History { task, limit } => {
+            let runs = scheduler.history(task.map(Into::into), *limit).await?;
+            if runs.is_empty() {
+                println!("Aucune exécution de maintenance enregistrée.");
+            }
+            for run in runs {
+                println!(
+                    "{} [{:?}/{:?}] {:?} {}",
+                    run.started_at.format("%Y-%m-%d %H:%M"),
+                    run.trigger,
+                    run.status,
+                    run.task,
+                    run.error.as_deref().unwrap_or("")
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
 async fn run_voice_mode(
     ctx: &AgentContext,
     client: LlmClient,
@@ -674,6 +1259,66 @@ async fn run_agent<A: Agent>(
     }
 }
 
+/// Affiche les changements simulés puis les applique si l'utilisateur approuve.
+async fn review_pending_changes(
+    overlay: &ArtifactOverlay,
+    storage: &StorageEngine,
+) -> RaiseResult<()> {
+    let pending = overlay.preview();
+    if pending.is_empty() {
+        user_info!("AI_DRY_RUN_NO_CHANGES");
+        return Ok(());
+    }
+
+    println!("\n📝 --- APERÇU DES CHANGEMENTS (DRY-RUN) ---");
+    for change in &pending {
+        let marker = match change.kind {
+            ChangeKind::Create => "+",
+            ChangeKind::Update => "~",
+        };
+        println!(
+            "{} {}/{}/{} :: {} ({})",
+            marker,
+            change.space,
+            change.db,
+            change.collection,
+            change.artifact.id,
+            change.artifact.name
+        );
+        for field in &change.diff {
+            let show =
+                |v: &Option<JsonValue>| v.as_ref().map(|x| x.to_string()).unwrap_or("∅".into());
+            println!(
+                "    {} : {} → {}",
+                field.path,
+                show(&field.before),
+                show(&field.after)
+            );
+        }
+    }
+
+    print!("\nAppliquer ces {} changement(s) ? [o/N] ", pending.len());
+    let _ = std::io::Write::flush(&mut std::io::stdout());
+    let mut answer = String::new();
+    if let Err(e) = std::io::stdin().read_line(&mut answer) {
+        raise_error!("ERR_CLI_DRY_RUN_STDIN", error = e);
+    }
+
+    if matches!(
+        answer.trim().to_lowercase().as_str(),
+        "o" | "oui" | "y" | "yes"
+    ) {
+        overlay.apply(storage, None).await?;
+    } else {
+        overlay.discard();
+        user_info!(
+            "AI_DRY_RUN_DISCARDED",
+            json_value!({ "changes": pending.len() })
+        );
+    }
+    Ok(())
+}
+
 async fn run_gnn_validation(domain_path: &Path, uri_a: &str, uri_b: &str) -> RaiseResult<()> {
     let root_path_str = domain_path.to_string_lossy().to_string();
 
@@ -711,6 +1356,154 @@ async fn run_gnn_validation(domain_path: &Path, uri_a: &str, uri_b: &str) -> Rai
     Ok(())
 }
 
+async fn run_vector_bench(
+    manager: &CollectionsManager<'_>,
+    corpus: &str,
+    backends: &[String],
+    k: usize,
+    query_count: usize,
+    qdrant_url: Option<&str>,
+) -> RaiseResult<()> {
+    let backends: Vec<BenchBackend> = backends
+        .iter()
+        .map(|b| BenchBackend::parse(b))
+        .collect::<RaiseResult<_>>()?;
+
+    // 1. Corpus : vectorisation des documents qui n'embarquent pas leur vecteur
+    let mut docs = load_corpus(Path::new(corpus)).await?;
+    let missing: Vec<usize> = (0..docs.len())
+        .filter(|&i| docs[i].vector.is_none())
+        .collect();
+    if !missing.is_empty() {
+        user_info!(
+            "AI_BENCH_EMBEDDING",
+            json_value!({ "documents": missing.len() })
+        );
+        let mut engine = EmbeddingEngine::new(manager).await?;
+        let texts = missing.iter().map(|&i| docs[i].content.clone()).collect();
+        for (i, vector) in missing.into_iter().zip(engine.embed_batch(texts)?) {
+            docs[i].vector = Some(vector);
+        }
+    }
+    docs.iter_mut()
+        .filter_map(|d| d.vector.as_mut())
+        .for_each(|v| normalize(v));
+    let queries = sample_queries(&docs, query_count);
+    let dimension = queries.first().map(|q| q.len()).unwrap_or_default();
+
+    // 2. Mesures backend par backend (un backend indisponible n'interrompt pas la campagne)
+    let collection = format!("bench_vectors_{}", UtcClock::now().timestamp());
+    let mut results = Vec::new();
+    for backend in backends {
+        user_info!(
+            "AI_BENCH_BACKEND_START",
+            json_value!({ "backend": backend.as_str() })
+        );
+        let store: Box<dyn VectorStore> = match backend {
+            BenchBackend::Candle => {
+                match NativeLocalStore::new(manager, AppConfig::device()).await {
+                    Ok(s) => Box::new(s),
+                    Err(e) => {
+                        results.push(BackendBenchResult::failed(backend, &e));
+                        continue;
+                    }
+                }
+            }
+            BenchBackend::Qdrant => match build_bench_qdrant(manager, qdrant_url).await {
+                Ok(s) => Box::new(s),
+                Err(e) => {
+                    results.push(BackendBenchResult::failed(backend, &e));
+                    continue;
+                }
+            },
+            BenchBackend::Leann => {
+                results.push(BackendBenchResult::skipped(
+                    backend,
+                    "Aucun client LEANN n'est compilé dans ce build",
+                ));
+                continue;
+            }
+        };
+
+        let result = run_backend(
+            backend,
+            store.as_ref(),
+            manager,
+            &collection,
+            &docs,
+            &queries,
+            k,
+        )
+        .await
+        .unwrap_or_else(|e| BackendBenchResult::failed(backend, &e));
+
+        // Nettoyage : la collection de bench ne doit pas survivre à la campagne
+        if backend == BenchBackend::Qdrant {
+            if let Ok(qdrant) = build_bench_qdrant(manager, qdrant_url).await {
+                let _ = qdrant.delete_collection(&collection).await;
+            }
+        }
+        results.push(result);
+    }
+    if manager.list_collections().await?.contains(&collection) {
+        let _ = manager.drop_collection(&collection).await;
+    }
+
+    // 3. Rapport comparatif
+    println!(
+        "\n{:<8} {:<8} {:>12} {:>10} {:>10} {:>10} {:>10}",
+        "BACKEND", "STATUT", "DOCS/S", "P50 (ms)", "P95 (ms)", "P99 (ms)", "RECALL@K"
+    );
+    for r in &results {
+        println!(
+            "{:<8} {:<8} {:>12.1} {:>10.2} {:>10.2} {:>10.2} {:>10.3}",
+            r.backend.as_str(),
+            format!("{:?}", r.status).to_lowercase(),
+            r.docs_per_sec,
+            r.latency_p50_ms,
+            r.latency_p95_ms,
+            r.latency_p99_ms,
+            r.recall_at_k
+        );
+        if let Some(err) = &r.error {
+            user_warn!(
+                "AI_BENCH_BACKEND_UNAVAILABLE",
+                json_value!({ "backend": r.backend.as_str(), "reason": err })
+            );
+        }
+    }
+
+    let report = VectorBenchReport {
+        id: UniqueId::new_v4().to_string(),
+        created_at: UtcClock::now().to_rfc3339(),
+        corpus: corpus.to_string(),
+        documents: docs.len(),
+        queries: queries.len(),
+        dimension,
+        k,
+        results,
+    };
+    save_report(manager, &report).await?;
+    user_success!(
+        "AI_BENCH_DONE",
+        json_value!({ "report_id": report.id, "collection": BENCH_COLLECTION })
+    );
+    Ok(())
+}
+
+async fn build_bench_qdrant(
+    manager: &CollectionsManager<'_>,
+    qdrant_url: Option<&str>,
+) -> RaiseResult<QdrantMemory> {
+    if let Some(url) = qdrant_url {
+        return QdrantMemory::new(url);
+    }
+    let settings = AppConfig::get_runtime_settings(manager, "ref:components:handle:ai_graph_store")
+        .await
+        .unwrap_or_else(|_| json_value!({}));
+    QdrantMemory::from_settings(&settings)
+}
+
 async fn inspect_agent_logic(
     ctx: &AgentContext,
     reference: &str,
@@ -890,6 +1683,149 @@ async fn run_execute_action(
 }
 
 // --- TESTS UNITAIRES ---
+fn parse_intent(raw: &str) -> RaiseResult<EngineeringIntent> {
+    match json::deserialize_from_str::<EngineeringIntent>(raw) {
+        Ok(intent) => Ok(intent),
+        Err(e) => raise_error!(
+            "ERR_INTENT_EXAMPLE_INVALID",
+            error = e,
+            context =
+                json_value!({ "intent": raw, "hint": "Format attendu : '{\"intent\":\"chat\"}'" })
+        ),
+    }
+}
+
+async fn handle_example_action(action: &ExampleAction, ctx: &CliContext) -> RaiseResult<()> {
+    let store = IntentExampleStore::system(&ctx.storage);
+    match action {
+        ExampleAction::List => {
+            let examples = store.list().await?;
+            println!("\n📚 EXEMPLES D'INTENTIONS ({})", examples.len());
+            for e in &examples {
+                let flag = match e.source {
+                    ExampleSource::Correction => "🚩",
+                    ExampleSource::Curated => "  ",
+                };
+                println!(
+                    "{} {} \"{}\" => {}",
+                    flag,
+                    e.id,
+                    e.utterance,
+                    json::serialize_to_string(&e.intent)?
+                );
+            }
+        }
+        ExampleAction::Add { utterance, intent } => {
+            let example = store
+                .add(utterance, parse_intent(intent)?, &ctx.active_user)
+                .await?;
+            user_success!(
+                "SUC_INTENT_EXAMPLE_ADDED",
+                json_value!({ "id": example.id })
+            );
+        }
+        ExampleAction::Flag {
+            utterance,
+            expected,
+            predicted,
+        } => {
+            let predicted = predicted.as_deref().map(parse_intent).transpose()?;
+            let example = store
+                .flag_misclassification(
+                    utterance,
+                    parse_intent(expected)?,
+                    predicted,
+                    &ctx.active_user,
+                )
+                .await?;
+            user_success!(
+                "SUC_INTENT_EXAMPLE_FLAGGED",
+                json_value!({ "id": example.id })
+            );
+        }
+        ExampleAction::Remove { id } => {
+            if !store.remove(id).await? {
+                user_warn!("WRN_INTENT_EXAMPLE_NOT_FOUND", json_value!({ "id": id }));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn handle_prompt_action(action: &PromptAction, ctx: &CliContext) -> RaiseResult<()> {
+    let registry = PromptRegistry::system(&ctx.storage);
+    match action {
+        PromptAction::List => {
+            let templates = registry.list().await?;
+            println!("\n📝 GABARITS DE PROMPTS ({})", templates.len());
+            for t in &templates {
+                let score = t
+                    .average_score()
+                    .map(|s| format!(" — score {:.2}", s))
+                    .unwrap_or_default();
+                println!("  {} [{}]{}", t.id, t.variables.join(", "), score);
+            }
+        }
+        PromptAction::Show { name, version } => {
+            let Some(template) = registry.get(name, *version).await? else {
+                raise_error!(
+                    "ERR_PROMPT_TEMPLATE_NOT_FOUND",
+                    error = format!("Gabarit '{}' introuvable.", name),
+                    context = json_value!({ "name": name, "version": version })
+                );
+            };
+            println!(
+                "\n📝 {} (par {}, {})",
+                template.id, template.author, template.created_at
+            );
+            println!("{}\n", template.template);
+            for note in &template.notes {
+                println!("  🧪 {} {:?} : {}", note.author, note.score, note.comment);
+            }
+            let history = registry.versions(name).await?;
+            let versions: Vec<String> = history.iter().map(|t| format!("v{}", t.version)).collect();
+            println!("Historique : {}", versions.join(" → "));
+        }
+        PromptAction::Set {
+            name,
+            text,
+            file,
+            description,
+        } => {
+            let template = match (text, file) {
+                (Some(text), _) => text.clone(),
+                (None, Some(file)) => fs::read_to_string_async(&PathBuf::from(file)).await?,
+                (None, None) => raise_error!(
+                    "ERR_CLI_MISSING_ARGUMENT",
+                    error = "--text ou --file est requis."
+                ),
+            };
+            let published = registry
+                .publish(name, &template, description.as_deref(), &ctx.active_user)
+                .await?;
+            user_success!(
+                "SUC_PROMPT_TEMPLATE_PUBLISHED",
+                json_value!({ "id": published.id, "variables": published.variables })
+            );
+        }
+        PromptAction::Note {
+            name,
+            version,
+            comment,
+            score,
+        } => {
+            let noted = registry
+                .annotate(name, *version, &ctx.active_user, *score, comment)
+                .await?;
+            user_success!(
+                "SUC_PROMPT_TEMPLATE_NOTED",
+                json_value!({ "id": noted.id, "notes": noted.notes.len(), "average_score": noted.average_score() })
+            );
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -924,9 +1860,15 @@ mod tests {
             Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
         };
 
-        if let Some(AiCommands::Classify { input, execute }) = cli.args.command {
+        if let Some(AiCommands::Classify {
+            input,
+            execute,
+            dry_run,
+        }) = cli.args.command
+        {
             assert_eq!(input, "créer un composant SA");
             assert!(execute);
+            assert!(!dry_run);
             Ok(())
         } else {
             raise_error!(
@@ -1068,6 +2010,248 @@ mod tests {
         }
     }
 
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_ai_usage_parsing() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+
+        let cli = match TestCli::try_parse_from(vec!["test", "u", "--month", "2026-10"]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+
+        if let Some(AiCommands::Usage { month }) = cli.args.command {
+            assert_eq!(month.as_deref(), Some("2026-10"));
+            Ok(())
+        } else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de l'alias 'u'"
+            )
+        }
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_embedding_history_command() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+        let cli = match TestCli::try_parse_from(vec!["test", "emb", "h"]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+        let Some(AiCommands::Embeddings { action }) = cli.args.command else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de l'alias 'emb'"
+            );
+        };
+        assert!(matches!(action, EmbeddingAction::History));
+
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "emb");
+        run_embedding_action(PathBuf::from("."), &manager, &sandbox.storage, &action).await
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_duplicates_parsing() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+        let cli = match TestCli::try_parse_from(vec![
+            "test",
+            "dup",
+            "--threshold",
+            "0.95",
+            "--layer",
+            "sa",
+        ]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+        let Some(AiCommands::Duplicates {
+            threshold,
+            layer,
+            limit,
+        }) = cli.args.command
+        else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de l'alias 'dup'"
+            );
+        };
+        assert_eq!(threshold, 0.95);
+        assert_eq!(layer.as_deref(), Some("sa"));
+        assert_eq!(limit, 50);
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_models_use_parsing() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+        let cli = match TestCli::try_parse_from(vec!["test", "mdl", "u", "qwen-7b"]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+        let Some(AiCommands::Models { action }) = cli.args.command else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de l'alias 'mdl'"
+            );
+        };
+        assert!(matches!(action, ModelAction::Use { id } if id == "qwen-7b"));
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_maintenance_history_command() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+        let cli = match TestCli::try_parse_from(vec![
+            "test",
+            "mnt",
+            "h",
+            "--task",
+            "vector-index",
+            "--limit",
+            "5",
+        ]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+        let Some(AiCommands::Maintenance { action }) = cli.args.command else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de l'alias 'mnt'"
+            );
+        };
+        assert!(matches!(
+            action,
+            MaintenanceAction::History {
+                task: Some(CliMaintenanceTask::VectorIndex),
+                limit: 5
+            }
+        ));
+
+        let sandbox = DbSandbox::new().await?;
+        let scheduler = MaintenanceScheduler::new(
+            SharedRef::new(sandbox.storage.clone()),
+            "test",
+            "maintenance",
+        );
+        run_maintenance_action(&scheduler, &action).await
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_ai_ingest_parsing() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+
+        let cli = match TestCli::try_parse_from(vec!["test", "in", "docs/specs"]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+
+        if let Some(AiCommands::Ingest { path }) = cli.args.command {
+            assert_eq!(path, "docs/specs");
+            Ok(())
+        } else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de l'alias 'in'"
+            )
+        }
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_intent_example_commands() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+        let cli = match TestCli::try_parse_from(vec![
+            "test",
+            "ex",
+            "flag",
+            "salut raise",
+            r#"{"intent":"chat"}"#,
+            "--predicted",
+            r#"{"intent":"unknown"}"#,
+        ]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+        let Some(AiCommands::Examples { action }) = cli.args.command else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de 'ex flag'"
+            );
+        };
+
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let ctx = CliContext::mock(
+            AppConfig::get(),
+            raise_core::utils::context::SessionManager::new(storage.clone()),
+            storage.clone(),
+        );
+        handle_example_action(&action, &ctx).await?;
+        assert!(parse_intent("pas du json").is_err());
+
+        let examples = IntentExampleStore::system(&storage).list().await?;
+        assert_eq!(examples.len(), 1);
+        assert_eq!(
+            examples[0].misclassified_as,
+            Some(EngineeringIntent::Unknown)
+        );
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_prompt_registry_commands() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+        let cli = match TestCli::try_parse_from(vec![
+            "test",
+            "p",
+            "set",
+            "greeter",
+            "--text",
+            "Bonjour {{who}}",
+        ]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+        let Some(AiCommands::Prompts { action }) = cli.args.command else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de 'p set'"
+            );
+        };
+
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let ctx = CliContext::mock(
+            AppConfig::get(),
+            raise_core::utils::context::SessionManager::new(storage.clone()),
+            storage.clone(),
+        );
+        handle_prompt_action(&action, &ctx).await?;
+        handle_prompt_action(
+            &PromptAction::Note {
+                name: "greeter".into(),
+                version: 1,
+                comment: "clair".into(),
+                score: Some(4.0),
+            },
+            &ctx,
+        )
+        .await?;
+
+        let template = PromptRegistry::system(&storage)
+            .get("greeter", None)
+            .await?;
+        assert_eq!(template.map(|t| (t.version, t.notes.len())), Some((1, 1)));
+        Ok(())
+    }
+
     #[async_test]
     #[serial_test::serial]
     #[cfg_attr(not(feature = "cuda"), ignore)]
@@ -0,0 +1,203 @@
+// FICHIER : crates/raise-cli/src/commands/audit.rs
+
+use clap::{Args, CommandFactory, Subcommand};
+use raise_core::utils::context::audit::{
+    is_state_changing, parse_day, AuditChannel, AuditLog, AuditOutcome, AuditQuery,
+};
+use raise_core::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+// 🎯 Import du contexte global CLI
+use crate::CliContext;
+
+/// Journal d'audit des actions utilisateur (CLI et interface graphique).
+#[derive(Args, Clone, Debug)]
+pub struct AuditArgs {
+    #[command(subcommand)]
+    pub command: AuditCommands,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum AuditCommands {
+    /// Liste les actions tracées (les plus récentes d'abord)
+    List {
+        #[arg(long)]
+        actor: Option<String>,
+        /// Préfixe d'action (ex: `jsondb`, `jsondb.insert`)
+        #[arg(long)]
+        action: Option<String>,
+        /// Date de début incluse (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+        /// Date de fin incluse (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
+        /// Uniquement les actions en échec
+        #[arg(long)]
+        failed: bool,
+        #[arg(short, long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Scelle le digest d'une journée (racine de Merkle signée)
+    Anchor {
+        #[arg(default_value = "today")]
+        day: String,
+    },
+    /// Vérifie l'intégrité d'une journée et de son ancrage
+    Verify {
+        #[arg(default_value = "today")]
+        day: String,
+    },
+}
+
+pub async fn handle(args: AuditArgs, ctx: CliContext) -> RaiseResult<()> {
+    let audit = AuditLog::new(ctx.storage.clone());
+
+    match args.command {
+        AuditCommands::List {
+            actor,
+            action,
+            since,
+            until,
+            failed,
+            limit,
+        } => {
+            let filter = AuditQuery {
+                actor,
+                action,
+                since: since.as_deref().map(parse_day).transpose()?,
+                until: until.as_deref().map(parse_day).transpose()?,
+                outcome: failed.then_some(AuditOutcome::Failure),
+                limit: Some(limit),
+            };
+            let entries = audit.query(&filter).await?;
+            for entry in &entries {
+                user_info!(
+                    "AUDIT_ENTRY",
+                    json_value!({
+                        "timestamp": entry.timestamp,
+                        "actor": entry.actor,
+                        "channel": entry.channel,
+                        "action": entry.action,
+                        "outcome": entry.outcome,
+                        "error_code": entry.error_code,
+                        "params_hash": entry.params_hash
+                    })
+                );
+            }
+            user_success!("AUDIT_LIST_DONE", json_value!({ "count": entries.len() }));
+        }
+
+        AuditCommands::Anchor { day } => {
+            // Le Ledger Mentis n'est pas monté en CLI : le commit signé est conservé dans `audit_anchors`
+            audit.anchor_day(parse_day(&day)?, None).await?;
+        }
+
+        AuditCommands::Verify { day } => {
+            let report = audit.verify_day(parse_day(&day)?).await?;
+            let payload = json::serialize_to_value(&report)?;
+            if report.is_valid {
+                user_success!("AUDIT_DAY_VALID", payload);
+            } else {
+                raise_error!(
+                    "ERR_AUDIT_INTEGRITY",
+                    error = "Journal d'audit altéré ou digest ancré divergent",
+                    context = payload
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Trace une invocation CLI (`argv` complet) si elle modifie l'état.
+/// Un échec d'écriture du journal n'interrompt jamais la commande.
+pub async fn record_invocation(ctx: &CliContext, argv: &[String], result: &RaiseResult<()>) {
+    if ctx.is_test_mode {
+        return;
+    }
+    let Some((action, params)) = describe_invocation(argv) else {
+        return;
+    };
+    if !is_state_changing(&action) {
+        return;
+    }
+    let error_code = match result {
+        Ok(_) => None,
+        Err(AppError::Structured(data)) => Some(data.code.clone()),
+    };
+
+    let audit = AuditLog::new(ctx.storage.clone());
+    if let Err(e) = audit
+        .record(
+            &ctx.active_user,
+            AuditChannel::Cli,
+            &action,
+            &params,
+            error_code.as_deref(),
+        )
+        .await
+    {
+        user_warn!(
+            "AUDIT_RECORD_FAILED",
+            json_value!({ "action": action, "error": e.to_string() })
+        );
+    }
+}
+
+/// Chemin de sous-commandes (`jsondb.insert`) et arguments de la commande feuille.
+fn describe_invocation(argv: &[String]) -> Option<(String, JsonValue)> {
+    let mut command = crate::Cli::command();
+    let matches = command.clone().try_get_matches_from(argv).ok()?;
+
+    let mut path = Vec::new();
+    let mut current = &matches;
+    while let Some((name, sub_matches)) = current.subcommand() {
+        path.push(name.to_string());
+        command = command.find_subcommand(name)?.clone();
+        current = sub_matches;
+    }
+    if path.is_empty() {
+        return None;
+    }
+
+    let mut params = JsonObject::new();
+    for arg in command.get_arguments() {
+        let id = arg.get_id().as_str();
+        if let Ok(Some(raw)) = current.try_get_raw(id) {
+            let values: Vec<String> = raw.map(|v| v.to_string_lossy().to_string()).collect();
+            params.insert(id.to_string(), json_value!(values));
+        }
+    }
+    Some((path.join("."), JsonValue::Object(params)))
+}
+
+// =========================================================================
+// TESTS UNITAIRES (Conformité "Zéro Dette")
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(line: &str) -> Vec<String> {
+        std::iter::once("raise-cli")
+            .chain(line.split_whitespace())
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_describe_invocation_extracts_action_and_params() {
+        let (action, params) =
+            describe_invocation(&argv("secrets set llm_key sk-123 --backend vault")).unwrap();
+        assert_eq!(action, "secrets.set");
+        assert!(is_state_changing(&action));
+        assert_eq!(params["key"], json_value!(["llm_key"]));
+        assert_eq!(params["backend"], json_value!(["vault"]));
+
+        let (action, _) = describe_invocation(&argv("audit list --failed")).unwrap();
+        assert!(!is_state_changing(&action));
+
+        assert!(describe_invocation(&argv("")).is_none());
+    }
+}
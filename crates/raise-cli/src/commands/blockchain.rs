@@ -2,6 +2,10 @@
 
 use clap::{Args, Subcommand};
 
+use raise_core::blockchain::consensus::reputation::ReputationStore;
+use raise_core::blockchain::crypto::signing::KeyPair;
+use raise_core::blockchain::explorer::{ChainExplorer, ExploreKind, ExplorerQuery};
+use raise_core::blockchain::notary::Notary;
 use raise_core::{user_error, user_info, user_success, utils::prelude::*}; // 🎯 Façade Unique RAISE
 
 // 🎯 Import du contexte global CLI
@@ -24,6 +28,29 @@ pub enum BlockchainCommands {
         #[arg(short, long)]
         verbose: bool,
     },
+    /// Parcourt l'historique indexé de la chaîne (blocks, mutations, authors, schemas).
+    Explore {
+        #[arg(default_value = "blocks")]
+        kind: String,
+        #[arg(long)]
+        author: Option<String>,
+        #[arg(long)]
+        commit: Option<String>,
+        #[arg(long)]
+        element: Option<String>,
+        #[arg(long)]
+        schema: Option<String>,
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+    },
+    /// Rapport de réputation des validateurs (infractions, exclusions du quorum).
+    Reputation,
+    /// Horodate un fichier externe et ancre son empreinte dans la chaîne.
+    Notarize { path: PathBuf },
+    /// Vérifie un fichier contre son ancrage dans la chaîne.
+    VerifyFile { path: PathBuf },
 }
 
 /// Handler principal pour les commandes Blockchain
@@ -67,6 +94,93 @@ pub async fn handle(args: BlockchainArgs, ctx: CliContext) -> RaiseResult<()> {
             );
         }
 
+        BlockchainCommands::Explore {
+            kind,
+            author,
+            commit,
+            element,
+            schema,
+            limit,
+            offset,
+        } => {
+            let query = ExplorerQuery {
+                kind: ExploreKind::parse(&kind)?,
+                author,
+                commit_id: commit,
+                element_id: element,
+                schema,
+                limit: Some(limit),
+                offset: Some(offset),
+            };
+            let page = ChainExplorer::system(&ctx.storage).explore(&query).await?;
+            for item in &page.items {
+                println!("  {}", item);
+            }
+            user_info!(
+                "BLOCKCHAIN_EXPLORE_PAGE",
+                json_value!({
+                    "kind": page.kind,
+                    "total": page.total,
+                    "offset": page.offset,
+                    "count": page.items.len()
+                })
+            );
+        }
+
+        BlockchainCommands::Reputation => {
+            let ledger = ReputationStore::system(&ctx.storage)
+                .load(ctx.config.reputation.clone())
+                .await?;
+            let report = ledger.report();
+            for record in &report.validators {
+                println!(
+                    "  {} : {} votes, {} manqués, {} invalides, {} doubles, {} pts{}",
+                    record.validator,
+                    record.votes_cast,
+                    record.missed_votes,
+                    record.invalid_proposals,
+                    record.double_votes,
+                    record.penalty_points,
+                    if report.excluded.contains(&record.validator) {
+                        " [EXCLU]"
+                    } else {
+                        ""
+                    }
+                );
+            }
+            user_info!(
+                "BLOCKCHAIN_REPUTATION_REPORT",
+                json_value!({
+                    "validators": report.validators.len(),
+                    "excluded": report.excluded
+                })
+            );
+        }
+
+        BlockchainCommands::Notarize { path } => {
+            let record = Notary::system(&ctx.storage)
+                .notarize(&path, &KeyPair::generate())
+                .await?;
+            user_success!(
+                "BLOCKCHAIN_FILE_NOTARIZED",
+                json_value!({
+                    "file": record.file_name,
+                    "sha256": record.sha256,
+                    "commit_id": record.commit.id,
+                    "gen_time": record.token.gen_time
+                })
+            );
+        }
+
+        BlockchainCommands::VerifyFile { path } => {
+            let verification = Notary::system(&ctx.storage).verify(&path).await?;
+            if verification.verified {
+                user_success!("BLOCKCHAIN_FILE_VERIFIED", json_value!(verification));
+            } else {
+                user_error!("BLOCKCHAIN_FILE_NOT_VERIFIED", json_value!(verification));
+            }
+        }
+
         BlockchainCommands::SyncCheck { verbose } => {
             user_info!("SYNC_DIAGNOSTIC_INIT", json_value!({ "verbose": verbose }));
 
@@ -138,4 +252,66 @@ mod tests {
 
         handle(args, ctx).await
     }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_explore_empty_chain() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let session_mgr = SessionManager::new(storage.clone());
+
+        let ctx = CliContext::mock(AppConfig::get(), session_mgr, storage);
+        let args = BlockchainArgs {
+            command: BlockchainCommands::Explore {
+                kind: "mutations".into(),
+                author: None,
+                commit: None,
+                element: Some("urn:sa:radar-01".into()),
+                schema: None,
+                limit: 10,
+                offset: 0,
+            },
+        };
+
+        handle(args, ctx).await
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_reputation_report_empty() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let session_mgr = SessionManager::new(storage.clone());
+
+        let ctx = CliContext::mock(AppConfig::get(), session_mgr, storage);
+        let args = BlockchainArgs {
+            command: BlockchainCommands::Reputation,
+        };
+
+        handle(args, ctx).await
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_notarize_and_verify_file() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let dir = tempdir()?;
+        let path = dir.path().join("delivery.txt");
+        fs::write_async(&path, b"v1").await?;
+
+        let session_mgr = SessionManager::new(storage.clone());
+        let ctx = CliContext::mock(AppConfig::get(), session_mgr, storage.clone());
+        let args = BlockchainArgs {
+            command: BlockchainCommands::Notarize { path: path.clone() },
+        };
+        handle(args, ctx).await?;
+
+        let session_mgr = SessionManager::new(storage.clone());
+        let ctx = CliContext::mock(AppConfig::get(), session_mgr, storage);
+        let args = BlockchainArgs {
+            command: BlockchainCommands::VerifyFile { path },
+        };
+        handle(args, ctx).await
+    }
 }
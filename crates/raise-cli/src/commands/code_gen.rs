@@ -481,6 +481,7 @@ mod tests {
                 code_generator: None,
             },
             active_user: "tester".to_string(),
+            identity: None,
             active_domain: config.mount_points.system.domain.clone(),
             active_db: config.mount_points.system.db.clone(),
             is_test_mode: true,
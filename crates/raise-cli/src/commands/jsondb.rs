@@ -4,11 +4,28 @@ use clap::{Args, Subcommand};
 
 // --- IMPORTS RAISE ---
 use raise_core::json_db::{
-    collections::manager::CollectionsManager,
+    backup,
+    bundle::{ConflictPolicy, ImportOptions, ProjectBundle},
+    collections::{
+        acl::CollectionGrant,
+        audit_fields::AuditPolicy,
+        id_strategy::IdStrategy,
+        manager::{CollectionsManager, INSERT_MANY_BATCH_SIZE},
+        ttl::{self, TtlPolicy},
+        vacuum::{self, VacuumOptions},
+    },
     indexes::manager::IndexManager,
+    migrations::{Migration, Migrator},
     query::{Condition, FilterOperator, Projection, Query, QueryEngine, QueryFilter},
-    transactions::{manager::TransactionManager, TransactionRequest},
+    schema::{
+        introspection, references,
+        strictness::{self, ValidationMode},
+    },
+    transactions::{manager::TransactionManager, wal, TransactionRequest},
+    views::{MaterializedViews, ViewDefinition},
 };
+use raise_core::services::access_service;
+use raise_core::utils::context::AccessRole;
 use raise_core::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 // 🎯 Import du contexte global CLI
@@ -105,15 +122,113 @@ pub enum JsondbCommands {
         name: String,
     },
     ListCollections,
+    /// Champs d'une collection dérivés de son schéma (autocomplétion)
+    Describe {
+        #[arg(long)]
+        collection: String,
+        /// Sortie JSON brute (consommée par les outils d'autocomplétion)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Change le mode de validation (strict|warn|off) et liste les documents non conformes
+    Tighten {
+        #[arg(long)]
+        collection: String,
+        #[arg(long, default_value = "strict")]
+        mode: String,
+        /// Rapport seul, sans changer le mode
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Affiche ou change la stratégie d'identifiant (uuid_v4|uuid_v7|ulid|content_hash|prefixed:PRÉFIXE[:largeur])
+    IdStrategy {
+        #[arg(long)]
+        collection: String,
+        /// Nouvelle stratégie (absente : affiche la stratégie courante)
+        strategy: Option<String>,
+    },
+    /// Affiche ou change les champs d'audit posés à l'écriture (dates et auteur)
+    AuditFields {
+        #[arg(long)]
+        collection: String,
+        /// Désactive les champs d'audit de la collection
+        #[arg(long, conflicts_with = "enable")]
+        disable: bool,
+        #[arg(long)]
+        enable: bool,
+        /// Fuseau des horodatages : UTC | local | +HH:MM
+        #[arg(long)]
+        timezone: Option<String>,
+        /// N'enregistre pas l'auteur (`_created_by` / `_updated_by`)
+        #[arg(long)]
+        no_actor: bool,
+    },
+    /// Affiche ou change les rôles autorisés sur des collections (nom, `préfixe*` ou `*`)
+    Acl {
+        /// Motif de collections (absent : affiche toutes les règles)
+        pattern: Option<String>,
+        /// Rôles autorisés en lecture (viewer,editor,validator,admin)
+        #[arg(long, value_delimiter = ',', requires = "pattern")]
+        read: Vec<String>,
+        /// Rôles autorisés en écriture
+        #[arg(long, value_delimiter = ',', requires = "pattern")]
+        write: Vec<String>,
+        /// Retire la règle du motif
+        #[arg(long, requires = "pattern", conflicts_with_all = ["read", "write"])]
+        clear: bool,
+    },
+    /// Émet un jeton d'API signé (à passer via `--token` ou `RAISE_API_TOKEN`)
+    IssueToken {
+        #[arg(long)]
+        user: String,
+        #[arg(long, value_delimiter = ',', required = true)]
+        roles: Vec<String>,
+        /// Durée de validité en heures (absente : sans expiration)
+        #[arg(long)]
+        ttl_hours: Option<i64>,
+    },
+    /// Affiche ou change l'expiration des documents d'une collection (champ date + délai)
+    Ttl {
+        #[arg(long)]
+        collection: String,
+        /// Champ date des documents (RFC 3339 ou epoch)
+        #[arg(long, requires = "expire_after", conflicts_with = "clear")]
+        field: Option<String>,
+        /// Délai d'expiration en secondes (`expireAfterSeconds`)
+        #[arg(long, requires = "field")]
+        expire_after: Option<u64>,
+        /// Retire l'expiration de la collection
+        #[arg(long)]
+        clear: bool,
+    },
+    /// Supprime immédiatement les documents expirés de la base
+    SweepTtl,
+    /// Purge fichiers temporaires orphelins, segments et entrées d'index périmés
+    Vacuum {
+        /// Rapport seul, sans suppression
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Checkpoint du journal (WAL) : replie les transactions validées et le tronque
+    Compact,
+    /// Liste (ou retire avec --fix) les références `x_ref` orphelines de la base
+    CheckRefs {
+        #[arg(long)]
+        fix: bool,
+    },
 
     // --- INDEXES ---
     CreateIndex {
         #[arg(long)]
         collection: String,
+        /// Champ indexé ; plusieurs champs séparés par des virgules pour un index composé
         #[arg(long)]
         field: String,
         #[arg(long, default_value = "hash")]
         kind: String,
+        /// Refuse les écritures créant un doublon de clé
+        #[arg(long)]
+        unique: bool,
     },
     DropIndex {
         #[arg(long)]
@@ -203,6 +318,93 @@ pub enum JsondbCommands {
         #[arg(long)]
         file: PathBuf,
     },
+    /// Exporte la base active dans un paquet de projet (.raisepkg)
+    ExportBundle {
+        #[arg(long)]
+        path: PathBuf,
+    },
+    /// Importe un paquet de projet dans la base active (créée si absente)
+    ImportBundle {
+        #[arg(long)]
+        path: PathBuf,
+        /// Fichiers déjà présents et différents : fail | skip | overwrite
+        #[arg(long, default_value = "fail")]
+        on_conflict: String,
+        /// Rapport seul (conflits compris), sans écriture
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Sauvegarde cohérente de la base active (archive .tar.zst)
+    Backup {
+        #[arg(long)]
+        path: PathBuf,
+    },
+    /// Restaure la base active depuis une sauvegarde (remplace son contenu)
+    Restore {
+        #[arg(long)]
+        path: PathBuf,
+    },
+    /// Duplique la base active (fork pour une étude « what-if »)
+    CloneDb {
+        /// Espace cible (défaut : espace actif)
+        #[arg(long)]
+        space: Option<String>,
+        #[arg(long)]
+        db: String,
+    },
+    /// Copie une collection de la base active vers une autre base existante
+    CopyCollection {
+        #[arg(long)]
+        collection: String,
+        /// Espace cible (défaut : espace actif)
+        #[arg(long)]
+        to_space: Option<String>,
+        #[arg(long)]
+        to_db: String,
+        /// Nom de la collection copiée (défaut : même nom)
+        #[arg(long = "as")]
+        as_name: Option<String>,
+    },
+    /// Applique les migrations en attente déclarées dans un fichier JSON (`[Migration]`)
+    Migrate {
+        #[arg(long)]
+        file: PathBuf,
+        /// Affiche le plan sans rien écrire
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Annule les migrations de version supérieure à `--to` (étapes `down`)
+    Rollback {
+        #[arg(long)]
+        to: String,
+        /// Déclarations complétant les étapes `down` absentes de l'historique
+        #[arg(long)]
+        file: Option<PathBuf>,
+        /// Affiche le plan sans rien écrire
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Historique des migrations appliquées
+    MigrationHistory,
+    /// Crée une vue matérialisée à partir d'une requête SELECT
+    CreateView {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        sql: String,
+    },
+    /// Recalcule entièrement une vue matérialisée
+    RefreshView {
+        #[arg(long)]
+        name: String,
+    },
+    /// Supprime une vue matérialisée et sa collection
+    DropView {
+        #[arg(long)]
+        name: String,
+    },
+    /// Vues matérialisées de la base active
+    ListViews,
 }
 
 pub async fn handle(args: JsondbArgs, ctx: CliContext) -> RaiseResult<()> {
@@ -221,8 +423,12 @@ pub async fn handle(args: JsondbArgs, ctx: CliContext) -> RaiseResult<()> {
     let tx_mgr = TransactionManager::new(storage, active_domain, active_db);
 
     // Vérification de l'existence de la base (sauf pour création)
-    if !matches!(args.command, JsondbCommands::CreateDb { .. })
-        && !storage.config.db_root(active_domain, active_db).exists()
+    if !matches!(
+        args.command,
+        JsondbCommands::CreateDb { .. }
+            | JsondbCommands::ImportBundle { .. }
+            | JsondbCommands::Restore { .. }
+    ) && !storage.config.db_root(active_domain, active_db).exists()
     {
         raise_error!(
             "ERR_DB_NOT_FOUND",
@@ -339,13 +545,244 @@ pub async fn handle(args: JsondbArgs, ctx: CliContext) -> RaiseResult<()> {
             let cols = col_mgr.list_collections().await?;
             println!("{}", json::serialize_to_string_pretty(&cols)?);
         }
+        JsondbCommands::Describe { collection, json } => {
+            let info = introspection::describe_collection(&col_mgr, &collection).await?;
+            if json {
+                println!("{}", json::serialize_to_string_pretty(&info)?);
+            } else {
+                println!(
+                    "📐 {} ({})",
+                    info.collection,
+                    info.schema_uri.as_deref().unwrap_or("sans schéma")
+                );
+                for field in &info.fields {
+                    let mut flags = Vec::new();
+                    if field.required {
+                        flags.push("requis".to_string());
+                    }
+                    if let Some(source) = &field.computed_by {
+                        flags.push(format!("calculé:{}", source));
+                    }
+                    if let Some(values) = &field.enum_values {
+                        let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                        flags.push(format!("enum[{}]", values.join("|")));
+                    }
+                    println!(
+                        "  {:<32} {:<16} {}",
+                        field.path,
+                        field.types.join("|"),
+                        flags.join(" ")
+                    );
+                }
+            }
+        }
+        JsondbCommands::Tighten {
+            collection,
+            mode,
+            dry_run,
+        } => {
+            let mode = ValidationMode::parse(&mode)?;
+            let report =
+                strictness::tighten_collection(&col_mgr, &collection, mode, dry_run).await?;
+            for violation in &report.violations {
+                println!("  ❌ {:<40} {}", violation.id, violation.error);
+            }
+            user_info!(
+                "JSONDB_COLLECTION_TIGHTENED",
+                json_value!({
+                    "collection": report.collection,
+                    "previous_mode": report.previous_mode,
+                    "mode": report.mode,
+                    "applied": report.applied,
+                    "checked": report.checked,
+                    "violations": report.violations.len()
+                })
+            );
+        }
+        JsondbCommands::IdStrategy {
+            collection,
+            strategy,
+        } => {
+            if let Some(strategy) = strategy {
+                let strategy = IdStrategy::parse(&strategy)?;
+                col_mgr
+                    .set_collection_id_strategy(&collection, strategy)
+                    .await?;
+            }
+            let current = col_mgr.collection_id_strategy(&collection).await;
+            user_info!(
+                "JSONDB_ID_STRATEGY",
+                json_value!({ "collection": collection, "id_strategy": current })
+            );
+        }
+        JsondbCommands::AuditFields {
+            collection,
+            disable,
+            enable,
+            timezone,
+            no_actor,
+        } => {
+            if disable || enable || timezone.is_some() || no_actor {
+                let mut policy = col_mgr.collection_audit_policy(&collection).await;
+                if disable || enable {
+                    policy.enabled = enable;
+                }
+                if let Some(timezone) = timezone {
+                    policy.timezone = timezone;
+                }
+                if no_actor {
+                    policy.created_by = None;
+                    policy.updated_by = None;
+                }
+                col_mgr
+                    .set_collection_audit_policy(&collection, policy)
+                    .await?;
+            }
+            let current: AuditPolicy = col_mgr.collection_audit_policy(&collection).await;
+            user_info!(
+                "JSONDB_AUDIT_FIELDS",
+                json_value!({ "collection": collection, "audit": current })
+            );
+        }
+        JsondbCommands::Acl {
+            pattern,
+            read,
+            write,
+            clear,
+        } => {
+            if let Some(pattern) = pattern {
+                if clear {
+                    col_mgr.set_collection_acl(&pattern, None).await?;
+                } else if !read.is_empty() || !write.is_empty() {
+                    let grant = CollectionGrant {
+                        read: parse_roles(&read)?,
+                        write: parse_roles(&write)?,
+                    };
+                    col_mgr.set_collection_acl(&pattern, Some(grant)).await?;
+                }
+            }
+            let acl = col_mgr.collection_acl().await;
+            println!("{}", json::serialize_to_string_pretty(&acl.rules)?);
+        }
+        JsondbCommands::IssueToken {
+            user,
+            roles,
+            ttl_hours,
+        } => {
+            if let Some(identity) = &ctx.identity {
+                if !identity.roles.contains(&AccessRole::Admin) {
+                    raise_error!(
+                        "ERR_ACCESS_DENIED",
+                        error = "Seul un administrateur peut émettre des jetons d'API.",
+                        context = json_value!({ "user": identity.user })
+                    );
+                }
+            }
+            let (token, claims) =
+                access_service::issue_api_token(&user, parse_roles(&roles)?, ttl_hours).await?;
+            user_success!(
+                "JSONDB_TOKEN_ISSUED",
+                json_value!({ "user": claims.sub, "roles": claims.roles, "expires_at": claims.exp })
+            );
+            println!("{}", token);
+        }
+        JsondbCommands::Compact => {
+            let report = wal::checkpoint(storage, active_domain, active_db).await?;
+            user_success!(
+                "JSONDB_WAL_COMPACTED",
+                json_value!({
+                    "folded": report.folded,
+                    "reclaimed_bytes": report.reclaimed_bytes,
+                    "pending": report.pending
+                })
+            );
+        }
+        JsondbCommands::Ttl {
+            collection,
+            field,
+            expire_after,
+            clear,
+        } => {
+            if clear {
+                col_mgr.set_collection_ttl(&collection, None).await?;
+            } else if let (Some(field), Some(expire_after)) = (field, expire_after) {
+                col_mgr
+                    .set_collection_ttl(&collection, Some(TtlPolicy::new(&field, expire_after)))
+                    .await?;
+            }
+            let current = col_mgr.collection_ttl(&collection).await;
+            user_info!(
+                "JSONDB_TTL",
+                json_value!({ "collection": collection, "ttl": current })
+            );
+        }
+        JsondbCommands::SweepTtl => {
+            let report = ttl::sweep_expired(&col_mgr, &AppConfig::get().ttl.collections).await?;
+            user_success!(
+                "JSONDB_TTL_SWEEP_DONE",
+                json_value!({ "removed": report.removed, "total": report.total_removed })
+            );
+        }
+        JsondbCommands::Vacuum { dry_run } => {
+            let options = VacuumOptions {
+                dry_run,
+                ..Default::default()
+            };
+            let report = vacuum::vacuum(&col_mgr, &options).await?;
+            user_success!(
+                "JSONDB_VACUUM_DONE",
+                json_value!({
+                    "dry_run": report.dry_run,
+                    "collections": report.collections_scanned,
+                    "dead_entries": report.dead_entries_removed,
+                    "temp_files": report.temp_files_removed,
+                    "stale_index_files": report.stale_index_files_removed,
+                    "index_entries": report.index_entries_pruned,
+                    "reclaimed_bytes": report.reclaimed_bytes
+                })
+            );
+        }
+        JsondbCommands::CheckRefs { fix } => {
+            let report = references::check_refs(&col_mgr, fix).await?;
+            for d in &report.dangling {
+                let status = if d.fixed { "🔧" } else { "❌" };
+                println!(
+                    "  {} {}/{}.{} -> {}/{}/{}:{}",
+                    status,
+                    d.collection,
+                    d.document_id,
+                    d.field,
+                    d.target.space,
+                    d.target.db,
+                    d.target.collection,
+                    d.value
+                );
+            }
+            user_info!(
+                "JSONDB_REFS_CHECKED",
+                json_value!({
+                    "collections": report.collections_scanned,
+                    "documents": report.documents_scanned,
+                    "references": report.references_checked,
+                    "dangling": report.dangling.len(),
+                    "fixed": report.dangling.iter().filter(|d| d.fixed).count()
+                })
+            );
+        }
         JsondbCommands::CreateIndex {
             collection,
             field,
             kind,
+            unique,
         } => {
-            idx_mgr.create_index(&collection, &field, &kind).await?;
-            user_success!("JSONDB_INDEX_CREATED", json_value!({ "field": field }));
+            let fields: Vec<&str> = field.split(',').map(str::trim).collect();
+            idx_mgr
+                .create_index_with(&collection, &fields, &kind, unique)
+                .await?;
+            user_success!(
+                "JSONDB_INDEX_CREATED",
+                json_value!({ "field": field, "unique": unique })
+            );
         }
         JsondbCommands::DropIndex { collection, field } => {
             idx_mgr.drop_index(&collection, &field).await?;
@@ -450,6 +887,10 @@ pub async fn handle(args: JsondbArgs, ctx: CliContext) -> RaiseResult<()> {
                     let result = QueryEngine::new(&col_mgr).execute_query(q).await?;
                     println!("{}", json::serialize_to_string_pretty(&result.documents)?);
                 }
+                SqlRequest::Explain(q) => {
+                    let plan = QueryEngine::new(&col_mgr).explain_query(q).await?;
+                    println!("{}", json::serialize_to_string_pretty(&plan)?);
+                }
                 SqlRequest::Write(ops) => {
                     // Les Insert, Update et notre nouveau DeleteMany passent tous par cette porte unifiée !
                     tx_mgr.execute_smart(ops).await?;
@@ -468,21 +909,16 @@ pub async fn handle(args: JsondbArgs, ctx: CliContext) -> RaiseResult<()> {
             } else {
                 vec![json]
             };
-            let count = docs.len();
-            for doc in docs {
-                match col_mgr.upsert_document(&collection, doc).await {
-                    Ok(_) => {}
-                    Err(e) => {
-                        user_warn!(
-                            "JSONDB_IMPORT_PARTIAL_FAIL",
-                            json_value!({
-                                "error": e.to_string(),
-                                "hint": "L'import de ce document a échoué. Le script continue."
-                            })
-                        );
-                    }
-                }
-            }
+            let ids = col_mgr
+                .insert_many_with(
+                    &collection,
+                    docs,
+                    INSERT_MANY_BATCH_SIZE,
+                    render_import_progress,
+                )
+                .await?;
+            eprintln!();
+            let count = ids.len();
             user_success!("JSONDB_IMPORT_SUCCESS", json_value!({ "count": count }));
         }
 
@@ -510,11 +946,172 @@ pub async fn handle(args: JsondbArgs, ctx: CliContext) -> RaiseResult<()> {
 
             user_success!("JSONDB_TX_SUCCESS", json_value!({}));
         }
+        JsondbCommands::ExportBundle { path } => {
+            let bundle = ProjectBundle::collect(&col_mgr).await?;
+            bundle.save(&path).await?;
+            println!(
+                "{}",
+                json::serialize_to_string_pretty(&bundle.manifest.summary)?
+            );
+            user_success!(
+                "JSONDB_BUNDLE_EXPORTED",
+                json_value!({ "path": path, "entries": bundle.manifest.entries.len() })
+            );
+        }
+        JsondbCommands::ImportBundle {
+            path,
+            on_conflict,
+            dry_run,
+        } => {
+            let options = ImportOptions {
+                space: Some(active_domain.clone()),
+                db: Some(active_db.clone()),
+                on_conflict: ConflictPolicy::parse(&on_conflict)?,
+                dry_run,
+            };
+            let report = ProjectBundle::load(&path)
+                .await?
+                .import(storage, &options)
+                .await?;
+            for conflict in &report.conflicts {
+                println!("  ⚠️ {}", conflict);
+            }
+            user_info!(
+                "JSONDB_BUNDLE_IMPORTED",
+                json_value!({
+                    "dry_run": report.dry_run,
+                    "created_db": report.created_db,
+                    "written": report.written,
+                    "unchanged": report.unchanged,
+                    "skipped": report.skipped,
+                    "overwritten": report.overwritten,
+                    "conflicts": report.conflicts.len()
+                })
+            );
+        }
+        JsondbCommands::Backup { path } => {
+            let manifest =
+                backup::create_snapshot(storage, active_domain, active_db, &path).await?;
+            user_success!(
+                "JSONDB_SNAPSHOT_CREATED",
+                json_value!({
+                    "path": path,
+                    "files": manifest.files.len(),
+                    "bytes": manifest.total_bytes,
+                    "pending_transactions": manifest.wal.pending.len(),
+                    "encrypted": manifest.encrypted
+                })
+            );
+        }
+        JsondbCommands::Restore { path } => {
+            let report = backup::restore_snapshot(storage, active_domain, active_db, &path).await?;
+            user_success!(
+                "JSONDB_SNAPSHOT_RESTORED",
+                json_value!({
+                    "snapshot": report.snapshot_created_at,
+                    "files": report.files,
+                    "replaced": report.replaced,
+                    "rolled_back": report.rolled_back_transactions
+                })
+            );
+        }
+        JsondbCommands::CloneDb { space, db } => {
+            let space = space.as_deref().unwrap_or(active_domain);
+            col_mgr.clone_db(space, &db).await?;
+        }
+        JsondbCommands::CopyCollection {
+            collection,
+            to_space,
+            to_db,
+            as_name,
+        } => {
+            let to_space = to_space.as_deref().unwrap_or(active_domain);
+            col_mgr
+                .copy_collection(&collection, to_space, &to_db, as_name.as_deref())
+                .await?;
+        }
+        JsondbCommands::Migrate { file, dry_run } => {
+            let declared: Vec<Migration> = fs::read_json_async(&file).await?;
+            let migrator = Migrator::new(storage, active_domain, active_db);
+            let plan = migrator.migrate(declared, dry_run).await?;
+            println!("{}", json::serialize_to_string_pretty(&plan)?);
+            user_success!(
+                "JSONDB_MIGRATIONS_APPLIED",
+                json_value!({ "count": plan.len(), "dry_run": dry_run })
+            );
+        }
+        JsondbCommands::Rollback { to, file, dry_run } => {
+            let declared: Vec<Migration> = match file {
+                Some(file) => fs::read_json_async(&file).await?,
+                None => Vec::new(),
+            };
+            let migrator = Migrator::new(storage, active_domain, active_db);
+            let plan = migrator.rollback_to(&declared, &to, dry_run).await?;
+            println!("{}", json::serialize_to_string_pretty(&plan)?);
+            user_success!(
+                "JSONDB_MIGRATIONS_ROLLED_BACK",
+                json_value!({ "target": to, "count": plan.len(), "dry_run": dry_run })
+            );
+        }
+        JsondbCommands::MigrationHistory => {
+            let history = Migrator::new(storage, active_domain, active_db)
+                .history()
+                .await?;
+            println!("{}", json::serialize_to_string_pretty(&history)?);
+        }
+        JsondbCommands::CreateView { name, sql } => {
+            let def = ViewDefinition::from_sql(&name, &sql)?;
+            let report = MaterializedViews::new(&col_mgr).create(def).await?;
+            user_success!(
+                "JSONDB_VIEW_CREATED",
+                json_value!({ "view": report.view, "rows": report.upserted })
+            );
+        }
+        JsondbCommands::RefreshView { name } => {
+            let report = MaterializedViews::new(&col_mgr).refresh(&name).await?;
+            user_success!(
+                "JSONDB_VIEW_REFRESHED",
+                json_value!({
+                    "view": report.view,
+                    "upserted": report.upserted,
+                    "deleted": report.deleted
+                })
+            );
+        }
+        JsondbCommands::DropView { name } => {
+            MaterializedViews::new(&col_mgr).drop(&name).await?;
+            user_success!("JSONDB_VIEW_DROPPED", json_value!({ "view": name }));
+        }
+        JsondbCommands::ListViews => {
+            for view in MaterializedViews::new(&col_mgr).list().await? {
+                let mode = format!("{:?}", view.mode);
+                println!(
+                    "  {:<32} {:<12} {}",
+                    view.name,
+                    mode,
+                    view.sql.unwrap_or_else(|| view.sources.join(", "))
+                );
+            }
+        }
         _ => {}
     }
     Ok(())
 }
 
+fn parse_roles(names: &[String]) -> RaiseResult<Vec<AccessRole>> {
+    names
+        .iter()
+        .map(|name| match AccessRole::parse(name) {
+            Some(role) => Ok(role),
+            None => raise_error!(
+                "ERR_ACCESS_UNKNOWN_ROLE",
+                error = format!("Rôle inconnu : '{}'.", name),
+                context = json_value!({ "expected": ["viewer", "editor", "validator", "admin"] })
+            ),
+        })
+        .collect()
+}
+
 async fn parse_data(input: &str) -> RaiseResult<JsonValue> {
     if let Some(path_str) = input.strip_prefix('@') {
         fs::read_json_async(Path::new(path_str)).await
@@ -526,6 +1123,19 @@ async fn parse_data(input: &str) -> RaiseResult<JsonValue> {
     }
 }
 
+/// Barre de progression de `import`, réécrite sur place (stderr).
+fn render_import_progress(done: usize, total: usize) {
+    const WIDTH: usize = 30;
+    let filled = (done * WIDTH).checked_div(total).unwrap_or(WIDTH);
+    eprint!(
+        "\r  [{}{}] {}/{} documents validés",
+        "#".repeat(filled),
+        ".".repeat(WIDTH - filled),
+        done,
+        total
+    );
+}
+
 // =========================================================================
 // TESTS UNITAIRES (Conformité "Zéro Dette")
 // =========================================================================
@@ -552,18 +1162,256 @@ mod tests {
     fn test_parse_create_index_defaults() -> RaiseResult<()> {
         let args = vec!["test", "create-index", "--collection", "u", "--field", "e"];
         let cli = TestCli::try_parse_from(args).map_err(|e| build_error!("ERR_TEST", error = e))?;
-        if let JsondbCommands::CreateIndex { kind, .. } = cli.args.command {
+        if let JsondbCommands::CreateIndex { kind, unique, .. } = cli.args.command {
             assert_eq!(kind, "hash");
+            assert!(!unique);
             Ok(())
         } else {
             raise_error!("ERR_TEST_FAIL", error = "Parsing failed");
         }
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_describe_json_flag() {
+        let cli = TestCli::try_parse_from(["test", "describe", "--collection", "users", "--json"])
+            .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::Describe { ref collection, json: true } if collection == "users"
+        ));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_tighten_defaults_to_strict() {
+        let cli =
+            TestCli::try_parse_from(["test", "tighten", "--collection", "users", "--dry-run"])
+                .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::Tighten { ref mode, dry_run: true, .. } if mode == "strict"
+        ));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_id_strategy() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "id-strategy",
+            "--collection",
+            "functions",
+            "prefixed:SA-FUNC",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::IdStrategy { strategy: Some(ref s), .. } if s == "prefixed:SA-FUNC"
+        ));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_audit_fields() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "audit-fields",
+            "--collection",
+            "actors",
+            "--timezone",
+            "+02:00",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::AuditFields { timezone: Some(ref tz), disable: false, .. } if tz == "+02:00"
+        ));
+        assert!(TestCli::try_parse_from([
+            "test",
+            "audit-fields",
+            "--collection",
+            "actors",
+            "--enable",
+            "--disable"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_acl() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "acl",
+            "_*",
+            "--read",
+            "editor,admin",
+            "--write",
+            "admin",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::Acl { pattern: Some(ref p), ref read, clear: false, .. }
+                if p == "_*" && read.len() == 2
+        ));
+        assert!(TestCli::try_parse_from(["test", "acl", "--read", "viewer"]).is_err());
+        assert!(parse_roles(&["validator".to_string()]).is_ok());
+        assert!(parse_roles(&["root".to_string()]).is_err());
+    }
+
     #[async_test]
     #[serial_test::serial]
     async fn test_parse_data_helper_robustness() -> RaiseResult<()> {
         assert!(parse_data(r#"{"test":true}"#).await.is_ok());
         Ok(())
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_vacuum_dry_run() {
+        let cli = TestCli::try_parse_from(["test", "vacuum", "--dry-run"]).unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::Vacuum { dry_run: true }
+        ));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_ttl() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "ttl",
+            "--collection",
+            "agent_sessions",
+            "--field",
+            "_updated_at",
+            "--expire-after",
+            "86400",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::Ttl { field: Some(ref f), expire_after: Some(86400), clear: false, .. }
+                if f == "_updated_at"
+        ));
+        // Un délai sans champ est refusé
+        assert!(TestCli::try_parse_from([
+            "test",
+            "ttl",
+            "--collection",
+            "agent_sessions",
+            "--expire-after",
+            "60"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_compact() {
+        let cli = TestCli::try_parse_from(["test", "compact"]).unwrap();
+        assert!(matches!(cli.args.command, JsondbCommands::Compact));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_import_bundle_defaults_to_fail() {
+        let cli =
+            TestCli::try_parse_from(["test", "import-bundle", "--path", "demo.raisepkg"]).unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::ImportBundle { ref on_conflict, dry_run: false, .. } if on_conflict == "fail"
+        ));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_backup_and_restore() {
+        let cli = TestCli::try_parse_from(["test", "backup", "--path", "db.tar.zst"]).unwrap();
+        assert!(matches!(cli.args.command, JsondbCommands::Backup { .. }));
+        let cli = TestCli::try_parse_from(["test", "restore", "--path", "db.tar.zst"]).unwrap();
+        assert!(matches!(cli.args.command, JsondbCommands::Restore { .. }));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_clone_and_copy_collection() {
+        let cli = TestCli::try_parse_from(["test", "clone-db", "--db", "what_if"]).unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::CloneDb { space: None, ref db } if db == "what_if"
+        ));
+        let cli = TestCli::try_parse_from([
+            "test",
+            "copy-collection",
+            "--collection",
+            "actors",
+            "--to-db",
+            "what_if",
+            "--as",
+            "actors_v2",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::CopyCollection { to_space: None, as_name: Some(ref name), .. } if name == "actors_v2"
+        ));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_migration_commands() {
+        let cli =
+            TestCli::try_parse_from(["test", "rollback", "--to", "1.0.0", "--dry-run"]).unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::Rollback { ref to, file: None, dry_run: true } if to == "1.0.0"
+        ));
+        let cli =
+            TestCli::try_parse_from(["test", "migrate", "--file", "m.json", "--dry-run"]).unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::Migrate { dry_run: true, .. }
+        ));
+        let cli = TestCli::try_parse_from(["test", "migration-history"]).unwrap();
+        assert!(matches!(cli.args.command, JsondbCommands::MigrationHistory));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_view_commands() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "create-view",
+            "--name",
+            "drafts",
+            "--sql",
+            "SELECT * FROM requirements WHERE status = 'draft'",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::CreateView { ref name, .. } if name == "drafts"
+        ));
+        let cli = TestCli::try_parse_from(["test", "refresh-view", "--name", "drafts"]).unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::RefreshView { .. }
+        ));
+        let cli = TestCli::try_parse_from(["test", "list-views"]).unwrap();
+        assert!(matches!(cli.args.command, JsondbCommands::ListViews));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_parse_check_refs_fix() {
+        let cli = TestCli::try_parse_from(["test", "check-refs", "--fix"]).unwrap();
+        assert!(matches!(
+            cli.args.command,
+            JsondbCommands::CheckRefs { fix: true }
+        ));
+    }
 }
@@ -2,6 +2,7 @@
 // Chaque module ici correspondra à un fichier .rs dans le même dossier
 
 pub mod ai;
+pub mod audit;
 pub mod blockchain;
 pub mod code_gen;
 pub mod dl;
@@ -10,6 +11,7 @@ pub mod jsondb;
 pub mod model_engine;
 pub mod plugins;
 pub mod rules;
+pub mod secrets;
 pub mod spatial;
 pub mod traceability;
 pub mod utils;
@@ -1,7 +1,13 @@
 // FICHIER : src-tauri/tools/raise-cli/src/commands/model_engine.rs
 
 use clap::{Args, Subcommand};
-use raise_core::model_engine::{ConsistencyChecker, Severity, TransformationDomain};
+use raise_core::model_engine::validators::{
+    RequirementQualityAnalyzer, SarifExporter, SarifSource,
+};
+use raise_core::model_engine::{
+    ArxmlExporter, ArxmlMappingProfile, ComplianceValidator, ConsistencyChecker, ProjectModel,
+    Severity, TransformationDomain,
+};
 use raise_core::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 // 🎯 Import du contexte global CLI
@@ -19,9 +25,33 @@ pub enum ModelCommands {
     /// Charge un modèle de projet depuis un fichier (.aird, .json)
     Load { path: String },
     /// Valide la cohérence sémantique du modèle (Règles métier Arcadia)
-    Validate,
+    Validate {
+        /// Modèle RAISE au format JSON (fichier du dépôt)
+        model: Option<String>,
+        /// Journal SARIF produit pour l'annotation en revue de code (GitHub, GitLab)
+        #[arg(long)]
+        sarif: Option<String>,
+    },
     /// Transforme le modèle vers un domaine spécifique (Projection)
     Transform { domain: String },
+    /// Exporte l'architecture physique vers AUTOSAR (.arxml)
+    ExportArxml {
+        /// Modèle RAISE au format JSON
+        model: String,
+        /// Fichier .arxml produit
+        output: String,
+        /// Profil de correspondance PA -> AUTOSAR (JSON)
+        #[arg(long)]
+        profile: Option<String>,
+    },
+    /// Évalue la qualité des exigences (ambiguïté, atomicité, testabilité)
+    RequirementsQuality {
+        /// Modèle RAISE au format JSON
+        model: String,
+        /// Score global en dessous duquel une exigence est signalée
+        #[arg(long, default_value_t = 0.7)]
+        threshold: f64,
+    },
 }
 
 pub async fn handle(args: ModelArgs, ctx: CliContext) -> RaiseResult<()> {
@@ -50,19 +80,55 @@ pub async fn handle(args: ModelArgs, ctx: CliContext) -> RaiseResult<()> {
             user_success!("MODEL_LOAD_SUCCESS", json_value!({"status": "analyzed"}));
         }
 
-        ModelCommands::Validate => {
+        ModelCommands::Validate { model, sarif } => {
             user_info!(
                 "MODEL_VALIDATION_START",
                 json_value!({ "user": ctx.active_user })
             );
 
-            // Utilisation du validateur sémantique du Core
-            let _checker = ConsistencyChecker;
+            let Some(model) = model else {
+                // Utilisation du validateur sémantique du Core
+                let _checker = ConsistencyChecker;
+                user_success!(
+                    "MODEL_VALIDATION_OK",
+                    json_value!({ "severity": format!("{:?}", Severity::Info) })
+                );
+                return Ok(());
+            };
 
-            user_success!(
-                "MODEL_VALIDATION_OK",
-                json_value!({ "severity": format!("{:?}", Severity::Info) })
-            );
+            // Le texte brut sert aussi à situer chaque élément dans le fichier (SARIF)
+            let content = fs::read_to_string_async(Path::new(&model)).await?;
+            let project: ProjectModel = json::deserialize_from_str(&content)?;
+            let mut issues = ConsistencyChecker::new()
+                .check_model(&project, None)?
+                .issues;
+            issues.extend(ComplianceValidator::new().check_model(&project));
+
+            let errors = issues
+                .iter()
+                .filter(|i| i.severity == Severity::Error)
+                .count();
+            if let Some(output) = sarif {
+                SarifExporter::new()
+                    .with_source(SarifSource::new(&model, &content))
+                    .export_to_file(&issues, Path::new(&output))
+                    .await?;
+                user_info!(
+                    "MODEL_VALIDATION_SARIF_WRITTEN",
+                    json_value!({ "path": output, "results": issues.len() })
+                );
+            }
+            if errors > 0 {
+                user_warn!(
+                    "WRN_MODEL_VALIDATION_ERRORS",
+                    json_value!({ "errors": errors, "issues": issues.len() })
+                );
+            } else {
+                user_success!(
+                    "MODEL_VALIDATION_OK",
+                    json_value!({ "issues": issues.len() })
+                );
+            }
         }
 
         ModelCommands::Transform { domain } => {
@@ -93,6 +159,43 @@ pub async fn handle(args: ModelArgs, ctx: CliContext) -> RaiseResult<()> {
                 );
             }
         }
+
+        ModelCommands::ExportArxml {
+            model,
+            output,
+            profile,
+        } => {
+            let profile = match profile {
+                Some(path) => ArxmlMappingProfile::load(Path::new(&path)).await?,
+                None => ArxmlMappingProfile::default(),
+            };
+            let project: ProjectModel = fs::read_json_async(Path::new(&model)).await?;
+            ArxmlExporter::new(profile)
+                .export_to_file(&project, Path::new(&output))
+                .await?;
+        }
+
+        ModelCommands::RequirementsQuality { model, threshold } => {
+            let project: ProjectModel = fs::read_json_async(Path::new(&model)).await?;
+            let results = RequirementQualityAnalyzer::new()
+                .analyze_model(&project)
+                .await;
+            let weak: Vec<_> = results.iter().filter(|q| q.score < threshold).collect();
+            for quality in &weak {
+                user_warn!(
+                    "WRN_REQ_QUALITY_LOW",
+                    json_value!({
+                        "requirement_id": quality.requirement_id,
+                        "score": quality.score,
+                        "issues": quality.issues,
+                    })
+                );
+            }
+            user_success!(
+                "SUC_REQ_QUALITY_DONE",
+                json_value!({ "requirements": results.len(), "below_threshold": weak.len() })
+            );
+        }
     }
     Ok(())
 }
@@ -104,8 +207,15 @@ pub async fn handle(args: ModelArgs, ctx: CliContext) -> RaiseResult<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clap::Parser;
     use raise_core::utils::testing::DbSandbox;
 
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        args: ModelArgs,
+    }
+
     #[async_test]
     #[serial_test::serial]
     #[cfg_attr(not(feature = "cuda"), ignore)]
@@ -116,9 +226,32 @@ mod tests {
 
         let ctx = crate::CliContext::mock(AppConfig::get(), session_mgr, storage);
         let args = ModelArgs {
-            command: ModelCommands::Validate,
+            command: ModelCommands::Validate {
+                model: None,
+                sarif: None,
+            },
         };
 
         handle(args, ctx).await
     }
+
+    #[test]
+    fn test_validate_sarif_args() -> RaiseResult<()> {
+        let cli = match TestCli::try_parse_from(vec![
+            "test",
+            "validate",
+            "models/drone.json",
+            "--sarif",
+            "raise.sarif",
+        ]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+        let ModelCommands::Validate { model, sarif } = cli.args.command else {
+            panic!("Sous-commande validate attendue");
+        };
+        assert_eq!(model.as_deref(), Some("models/drone.json"));
+        assert_eq!(sarif.as_deref(), Some("raise.sarif"));
+        Ok(())
+    }
 }
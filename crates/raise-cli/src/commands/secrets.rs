@@ -0,0 +1,165 @@
+// FICHIER : crates/raise-cli/src/commands/secrets.rs
+
+use clap::{Args, Subcommand};
+use raise_core::utils::data::secrets::{SecretBackendKind, SecretsProvider};
+use raise_core::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+// 🎯 Import du contexte global CLI
+use crate::CliContext;
+
+/// Gestion des secrets (clés API, jetons) : trousseau OS, coffre chiffré, environnement.
+#[derive(Args, Clone, Debug)]
+pub struct SecretsArgs {
+    #[command(subcommand)]
+    pub command: SecretsCommands,
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum SecretsCommands {
+    /// Enregistre un secret (valeur lue sur l'entrée standard si omise)
+    Set {
+        key: String,
+        value: Option<String>,
+        /// Backend cible : keyring | vault
+        #[arg(short, long)]
+        backend: Option<String>,
+    },
+    /// Lit un secret (masqué sauf avec --reveal)
+    Get {
+        key: String,
+        #[arg(long)]
+        reveal: bool,
+    },
+    /// Supprime un secret de tous les backends inscriptibles
+    Delete { key: String },
+    /// Liste les backends et leur disponibilité
+    Backends,
+}
+
+pub async fn handle(args: SecretsArgs, _ctx: CliContext) -> RaiseResult<()> {
+    execute(args, SecretsProvider::global()).await
+}
+
+async fn execute(args: SecretsArgs, provider: &SecretsProvider) -> RaiseResult<()> {
+    match args.command {
+        SecretsCommands::Set {
+            key,
+            value,
+            backend,
+        } => {
+            let target = backend
+                .as_deref()
+                .map(SecretBackendKind::parse)
+                .transpose()?;
+
+            let value = match value {
+                Some(v) => v,
+                None => {
+                    let mut line = String::new();
+                    if let Err(e) = std::io::stdin().read_line(&mut line) {
+                        raise_error!("ERR_CLI_SECRET_STDIN", error = e);
+                    }
+                    line.trim_end_matches(['\r', '\n']).to_string()
+                }
+            };
+
+            let stored_in = provider.set(&key, &value, target).await?;
+            user_success!(
+                "SECRET_STORED",
+                json_value!({ "key": key, "backend": stored_in.as_str() })
+            );
+        }
+
+        SecretsCommands::Get { key, reveal } => {
+            let value = provider.require(&key).await?;
+            if reveal {
+                // Sortie brute volontaire (hors journaux, donc non masquée)
+                println!("{}", value);
+            } else {
+                user_info!(
+                    "SECRET_FOUND",
+                    json_value!({ "key": key, "length": value.len(), "value": mask(&value) })
+                );
+            }
+        }
+
+        SecretsCommands::Delete { key } => {
+            if provider.delete(&key).await? {
+                user_success!("SECRET_DELETED", json_value!({ "key": key }));
+            } else {
+                user_warn!("SECRET_NOT_FOUND", json_value!({ "key": key }));
+            }
+        }
+
+        SecretsCommands::Backends => {
+            for (kind, available) in provider.backends() {
+                user_info!(
+                    "SECRET_BACKEND_STATUS",
+                    json_value!({ "backend": kind.as_str(), "available": available })
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Aperçu non sensible : 2 premiers caractères puis masque.
+fn mask(value: &str) -> String {
+    let head: String = value.chars().take(2).collect();
+    format!("{}****", head)
+}
+
+// =========================================================================
+// TESTS UNITAIRES (Conformité "Zéro Dette")
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raise_core::utils::data::secrets::VaultBackend;
+    use raise_core::utils::io::fs::tempdir;
+
+    #[async_test]
+    async fn test_secrets_set_get_delete_via_vault() -> RaiseResult<()> {
+        let dir = tempdir().unwrap();
+        let provider = SecretsProvider::new(vec![Box::new(VaultBackend::new(
+            dir.path().join("vault.json"),
+            Some("cli-test-passphrase".into()),
+        ))]);
+
+        let set = SecretsArgs {
+            command: SecretsCommands::Set {
+                key: "cli_token".into(),
+                value: Some("tok-0123456789".into()),
+                backend: Some("vault".into()),
+            },
+        };
+        execute(set, &provider).await?;
+        assert_eq!(
+            provider.get("cli_token").await?.as_deref(),
+            Some("tok-0123456789")
+        );
+
+        let get = SecretsArgs {
+            command: SecretsCommands::Get {
+                key: "cli_token".into(),
+                reveal: false,
+            },
+        };
+        execute(get, &provider).await?;
+
+        let delete = SecretsArgs {
+            command: SecretsCommands::Delete {
+                key: "cli_token".into(),
+            },
+        };
+        execute(delete, &provider).await?;
+        assert!(provider.get("cli_token").await?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mask_hides_value() {
+        assert_eq!(mask("abcdef"), "ab****");
+    }
+}
@@ -1,12 +1,14 @@
 // FICHIER : src-tauri/tools/raise-cli/src/commands/traceability.rs
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 use raise_core::{user_error, user_info, user_success, utils::prelude::*}; // 🎯 Façade Unique RAISE
 
 // Imports métiers depuis le cœur
 use raise_core::model_engine::types::ProjectModel;
+use raise_core::services::{model_service, traceability_service};
 use raise_core::traceability::{
-    reporting::audit_report::AuditGenerator, ChangeTracker, ImpactAnalyzer, Tracer,
+    reporting::audit_report::AuditGenerator, ChangeTracker, ImpactAnalyzer, NeighborhoodQuery,
+    Tracer, TraversalDirection,
 };
 
 // 🎯 Import du contexte global CLI
@@ -29,6 +31,39 @@ pub enum TraceabilityCommands {
     },
     /// Affiche les derniers changements détectés dans le Knowledge Graph
     History,
+    /// Explore le voisinage d'un élément (profondeur, types de relation, sens)
+    #[command(visible_alias = "nb")]
+    Neighbors {
+        /// Identifiant de l'élément central
+        element_id: String,
+        #[arg(long, default_value_t = 1)]
+        depth: usize,
+        /// Type de relation à suivre (répétable, toutes par défaut)
+        #[arg(long = "relation")]
+        relations: Vec<String>,
+        #[arg(long, value_enum, default_value_t = CliDirection::Both)]
+        direction: CliDirection,
+        /// Sortie projetée pour le Spatial Engine (positions 3D)
+        #[arg(long)]
+        spatial: bool,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum CliDirection {
+    Downstream,
+    Upstream,
+    Both,
+}
+
+impl From<CliDirection> for TraversalDirection {
+    fn from(direction: CliDirection) -> Self {
+        match direction {
+            CliDirection::Downstream => TraversalDirection::Downstream,
+            CliDirection::Upstream => TraversalDirection::Upstream,
+            CliDirection::Both => TraversalDirection::Both,
+        }
+    }
 }
 
 /// Helper pour extraire les documents sémantiques du graphe
@@ -102,6 +137,43 @@ pub async fn handle(args: TraceabilityArgs, ctx: CliContext) -> RaiseResult<()>
             );
         }
 
+        TraceabilityCommands::Neighbors {
+            element_id,
+            depth,
+            relations,
+            direction,
+            spatial,
+        } => {
+            let model =
+                model_service::load_project_model(&ctx.storage, &ctx.active_domain, &ctx.active_db)
+                    .await?;
+            let query = NeighborhoodQuery {
+                depth,
+                relations,
+                direction: direction.into(),
+                ..Default::default()
+            };
+            let neighborhood =
+                traceability_service::get_element_neighbors(&model, &element_id, &query).await?;
+
+            if spatial {
+                let graph = neighborhood.to_spatial_graph();
+                println!("{}", json::serialize_to_string_pretty(&graph)?);
+            } else {
+                println!("{}", json::serialize_to_string_pretty(&neighborhood)?);
+            }
+
+            user_success!(
+                "TRACE_NEIGHBORS_OK",
+                json_value!({
+                    "center": element_id,
+                    "nodes": neighborhood.nodes.len(),
+                    "links": neighborhood.links.len(),
+                    "truncated": neighborhood.truncated
+                })
+            );
+        }
+
         TraceabilityCommands::History => {
             user_info!(
                 "TRACE_HISTORY_FETCH",
@@ -143,4 +215,51 @@ mod tests {
         // Propagation directe du résultat pour un test pur
         handle(args, ctx).await
     }
+
+    #[derive(clap::Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        args: TraceabilityArgs,
+    }
+
+    #[test]
+    fn test_neighbors_parsing() -> RaiseResult<()> {
+        use clap::Parser;
+        let cli = match TestCli::try_parse_from([
+            "test",
+            "nb",
+            "f1",
+            "--depth",
+            "2",
+            "--relation",
+            "allocatedTo",
+            "--relation",
+            "realizedBy",
+            "--direction",
+            "upstream",
+        ]) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_TEST_PARSE", error = e.to_string()),
+        };
+
+        let TraceabilityCommands::Neighbors {
+            element_id,
+            depth,
+            relations,
+            direction,
+            spatial,
+        } = cli.args.command
+        else {
+            raise_error!(
+                "ERR_TEST_ASSERTION_FAILED",
+                error = "Échec du parsing de l'alias 'nb'"
+            );
+        };
+        assert_eq!(element_id, "f1");
+        assert_eq!(depth, 2);
+        assert_eq!(relations, vec!["allocatedTo", "realizedBy"]);
+        assert_eq!(direction, CliDirection::Upstream);
+        assert!(!spatial);
+        Ok(())
+    }
 }
@@ -3,6 +3,8 @@
 use clap::{Args, Subcommand};
 use raise_core::json_db::collections::manager::CollectionsManager;
 use raise_core::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
+use raise_core::utils::context::i18n::lint_locale_dir;
+use raise_core::utils::core::error_catalog::localized_catalog;
 use raise_core::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 // 🎯 Import du contexte global CLI
@@ -38,6 +40,33 @@ pub enum UtilsCommands {
     UseDomain { domain: String },
     /// Bascule sur une autre base de données
     UseDb { db: String },
+    /// Vérifie la cohérence des fichiers de locales (clés manquantes, arguments, syntaxe ICU)
+    I18nLint {
+        /// Dossier des locales (défaut : <PATH_RAISE_ASSET>/locales)
+        #[arg(long)]
+        dir: Option<String>,
+        /// Locale de référence pour les arguments
+        #[arg(long, default_value = "en")]
+        reference: String,
+    },
+    /// Catalogue des codes d'erreur (description localisée, conseil de remédiation)
+    Errors {
+        #[command(subcommand)]
+        action: ErrorsAction,
+    },
+}
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum ErrorsAction {
+    /// Liste les codes connus, optionnellement filtrés par code ou module
+    List {
+        /// Sous-chaîne recherchée dans le code ou le module (sans casse)
+        #[arg(long)]
+        filter: Option<String>,
+        /// Export JSON du catalogue localisé
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 pub async fn handle(args: UtilsArgs, ctx: CliContext) -> RaiseResult<()> {
@@ -173,6 +202,57 @@ pub async fn handle(args: UtilsArgs, ctx: CliContext) -> RaiseResult<()> {
             let res = ctx.session_mgr.switch_db(&db).await?;
             user_success!("DB_SWITCHED", json_value!(res));
         }
+
+        UtilsCommands::I18nLint { dir, reference } => {
+            let locales_dir = match dir {
+                Some(d) => PathBuf::from(d),
+                None => match ctx.config.get_path("PATH_RAISE_ASSET") {
+                    Some(p) => p.join("locales"),
+                    None => raise_error!(
+                        "ERR_CLI_I18N_NO_DIR",
+                        error = "PATH_RAISE_ASSET manquant : précisez --dir."
+                    ),
+                },
+            };
+
+            let report = lint_locale_dir(&locales_dir, &reference).await?;
+            for issue in &report.issues {
+                user_warn!("I18N_LINT_ISSUE", json_value!(issue));
+            }
+
+            if report.is_clean() {
+                user_success!(
+                    "I18N_LINT_CLEAN",
+                    json_value!({ "locales": report.locales, "keys": report.key_count })
+                );
+            } else {
+                raise_error!(
+                    "ERR_I18N_LINT_FAILED",
+                    error = format!("{} problème(s) détecté(s).", report.issues.len()),
+                    context = json_value!({ "locales": report.locales, "keys": report.key_count })
+                );
+            }
+        }
+
+        UtilsCommands::Errors {
+            action: ErrorsAction::List { filter, json },
+        } => {
+            let entries = localized_catalog(filter.as_deref());
+            if json {
+                println!("{}", json::serialize_to_string_pretty(&entries)?);
+            } else {
+                for entry in &entries {
+                    println!("  {} [{}] {}", entry.code, entry.module, entry.message);
+                    if let Some(hint) = &entry.hint {
+                        println!("      ↳ {}", hint);
+                    }
+                }
+                user_info!(
+                    "CLI_ERRORS_LISTED",
+                    json_value!({ "count": entries.len(), "filter": filter })
+                );
+            }
+        }
     }
     Ok(())
 }
@@ -237,6 +317,72 @@ mod tests {
         Ok(())
     }
 
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_i18n_lint_reports_missing_key() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let ctx = crate::CliContext::mock(
+            AppConfig::get(),
+            crate::context::SessionManager::new(storage.clone()),
+            storage,
+        );
+
+        let dir = raise_core::utils::io::fs::tempdir().unwrap();
+        let locale = |handle: &str, keys: &[&str]| {
+            json_value!({
+                "handle": handle,
+                "translations": keys.iter().map(|k| json_value!({ "key": k, "value": k })).collect::<Vec<_>>()
+            })
+        };
+        fs::write_json_atomic_async(&dir.path().join("en.json"), &locale("en", &["A", "B"]))
+            .await?;
+        fs::write_json_atomic_async(&dir.path().join("fr.json"), &locale("fr", &["A"])).await?;
+
+        let result = handle(
+            UtilsArgs {
+                command: UtilsCommands::I18nLint {
+                    dir: Some(dir.path().to_string_lossy().to_string()),
+                    reference: "en".into(),
+                },
+            },
+            ctx,
+        )
+        .await;
+
+        let Err(AppError::Structured(data)) = result else {
+            panic!("Le lint aurait dû échouer sur la clé manquante");
+        };
+        assert_eq!(data.code, "ERR_I18N_LINT_FAILED");
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_errors_list_execution() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let ctx = crate::CliContext::mock(
+            AppConfig::get(),
+            crate::context::SessionManager::new(storage.clone()),
+            storage,
+        );
+        assert!(!localized_catalog(Some("ERR_DB_SQL")).is_empty());
+
+        handle(
+            UtilsArgs {
+                command: UtilsCommands::Errors {
+                    action: ErrorsAction::List {
+                        filter: Some("ERR_DB_SQL".into()),
+                        json: true,
+                    },
+                },
+            },
+            ctx,
+        )
+        .await
+    }
+
     #[async_test]
     #[serial_test::serial]
     async fn test_info_execution_integrity() -> RaiseResult<()> {
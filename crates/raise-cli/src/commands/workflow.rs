@@ -11,7 +11,7 @@ use raise_core::plugins::manager::PluginManager;
 
 use raise_core::workflow_engine::{
     compiler::WorkflowCompiler, executor::WorkflowExecutor, mandate::Mandate,
-    scheduler::WorkflowScheduler, ExecutionStatus, WorkflowInstance,
+    scheduler::WorkflowScheduler, tools::InstantiatePatternTool, ExecutionStatus, WorkflowInstance,
 };
 
 // 🎯 Import du contexte global CLI
@@ -36,6 +36,9 @@ pub enum WorkflowCommands {
     Start {
         mission_id: String,
         workflow_id: String,
+        /// Contexte d'entrée (objet JSON), validé contre le `context_schema` du workflow
+        #[arg(long)]
+        inputs: Option<String>,
     },
     /// Reprend un workflow en attente de validation (HITL)
     Resume {
@@ -69,9 +72,12 @@ async fn init_cli_engine(ctx: &CliContext) -> RaiseResult<WorkflowScheduler> {
     };
 
     let pm = SharedRef::new(PluginManager::new(&ctx.storage, None));
-    let executor = WorkflowExecutor::new(SharedRef::new(AsyncMutex::new(orch)), pm);
+    let mut executor = WorkflowExecutor::new(SharedRef::new(AsyncMutex::new(orch)), pm);
+    executor.register_tool(Box::new(InstantiatePatternTool));
 
-    Ok(WorkflowScheduler::new(executor))
+    Ok(WorkflowScheduler::new(executor)
+        .with_scheduling(AppConfig::get().workflow_scheduling.clone())
+        .with_plan_review(AppConfig::get().plan_review.clone()))
 }
 
 pub async fn handle(args: WorkflowArgs, ctx: CliContext) -> RaiseResult<()> {
@@ -125,13 +131,20 @@ pub async fn handle(args: WorkflowArgs, ctx: CliContext) -> RaiseResult<()> {
         WorkflowCommands::Start {
             mission_id,
             workflow_id,
+            inputs,
         } => {
+            let inputs: JsonObject<String, JsonValue> = match inputs {
+                Some(raw) => json::deserialize_from_str(&raw)
+                    .map_err(|e| build_error!("ERR_JSON_PARSE", error = e))?,
+                None => JsonObject::new(),
+            };
+
             let mut scheduler = init_cli_engine(&ctx).await?;
             let manager = CollectionsManager::new(&ctx.storage, &ctx.active_domain, &ctx.active_db);
 
             scheduler.load_mission(&mission_id, &manager).await?;
             let instance = scheduler
-                .create_instance(&mission_id, &workflow_id, &manager)
+                .create_instance_with_context(&mission_id, &workflow_id, inputs, &manager)
                 .await?;
 
             user_success!(
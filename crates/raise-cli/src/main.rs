@@ -7,9 +7,13 @@ mod commands;
 use raise_core::ai::agents::AgentContext;
 use raise_core::ai::assurance::health::RaiseHealthEngine;
 use raise_core::kernel::state::RaiseKernelState;
+use raise_core::services::access_service;
 use raise_core::utils::io::os::run_cli_app;
 use raise_core::{
-    json_db::{collections::manager::CollectionsManager, storage::StorageEngine},
+    json_db::{
+        collections::{acl, audit_fields, manager::CollectionsManager},
+        storage::StorageEngine,
+    },
     raise_error, user_debug, user_error, user_info, user_warn,
     utils::{context, prelude::*},
 };
@@ -24,6 +28,8 @@ pub struct CliContext {
     pub storage: SharedRef<StorageEngine>,
     pub kernel: RaiseKernelState,
     pub active_user: String,
+    /// Identité soumise aux règles d'accès des collections (jeton d'API ou contrôle activé).
+    pub identity: Option<context::AccessIdentity>,
     pub active_domain: String,
     pub active_db: String,
     pub is_test_mode: bool,
@@ -47,6 +53,14 @@ struct Cli {
     )]
     user: Option<String>,
 
+    #[arg(
+        long,
+        global = true,
+        env = "RAISE_API_TOKEN",
+        help = "Jeton d'API signé (identité et rôles du relecteur)"
+    )]
+    token: Option<String>,
+
     #[arg(
         long,
         global = true,
@@ -105,6 +119,8 @@ enum Commands {
     CodeGen(commands::code_gen::CodeGenArgs),
     Validator(commands::validator::ValidatorArgs),
     Utils(commands::utils::UtilsArgs),
+    Secrets(commands::secrets::SecretsArgs),
+    Audit(commands::audit::AuditArgs),
 }
 
 fn main() -> RaiseResult<()> {
@@ -128,7 +144,8 @@ fn main() -> RaiseResult<()> {
         }
 
         // 3. PARSING DU CLI
-        let cli = Cli::parse();
+        let argv: Vec<String> = std::env::args().collect();
+        let cli = Cli::parse_from(&argv);
 
         // 4. RÉSOLUTION SÉMANTIQUE DES PRIORITÉS (CLI > Config > Mount Points)
         let active_user = match cli.user.clone() {
@@ -195,6 +212,20 @@ fn main() -> RaiseResult<()> {
 
         let session_mgr = context::SessionManager::new(storage.clone());
 
+        // RÉSOLUTION DE L'IDENTITÉ D'ACCÈS (jeton d'API prioritaire sur l'utilisateur local)
+        let access = context::AccessGuard::new();
+        let identity = match cli.token.as_deref() {
+            Some(token) => Some(access_service::login_api_token(&access, token).await?),
+            None if config.access_control.enabled => {
+                Some(access.set_local_user(&config.access_control, &active_user))
+            }
+            None => None,
+        };
+        let active_user = identity
+            .as_ref()
+            .map(|i| i.user.clone())
+            .unwrap_or(active_user);
+
         // RÉSOLUTION DU CONTEXTE DE SIMULATION
         let sim_domain = cli.sim_domain.clone().unwrap_or_else(|| "".to_string());
         let sim_db = cli.sim_db.clone().unwrap_or_else(|| "".to_string());
@@ -262,6 +293,7 @@ fn main() -> RaiseResult<()> {
             storage,
             kernel: kernel_state,
             active_user,
+            identity,
             active_domain,
             active_db,
             is_test_mode: false,
@@ -314,7 +346,7 @@ fn main() -> RaiseResult<()> {
 
         // 8. DISPATCH DES COMMANDES
         match cli.command {
-            Some(cmd) => match execute_command(cmd.clone(), ctx.clone()).await {
+            Some(cmd) => match execute_audited(cmd.clone(), ctx.clone(), &argv).await {
                 Ok(_) => (),
                 Err(e) => raise_error!(
                     "CLI_COMMAND_EXECUTION_FAILED",
@@ -398,8 +430,11 @@ async fn run_global_shell(mut ctx: CliContext) -> RaiseResult<()> {
                     continue;
                 }
 
-                let _ = rl.add_history_entry(input.as_str());
-                let _ = rl.save_history(&history_path);
+                // Une valeur de secret ne doit jamais atterrir dans l'historique
+                if !input.starts_with("secrets set") {
+                    let _ = rl.add_history_entry(input.as_str());
+                    let _ = rl.save_history(&history_path);
+                }
 
                 if input == "exit" || input == "quit" {
                     break;
@@ -419,10 +454,11 @@ async fn run_global_shell(mut ctx: CliContext) -> RaiseResult<()> {
                         let mut full_args = vec!["repl".to_string()];
                         full_args.extend(args);
 
-                        match Cli::try_parse_from(full_args) {
+                        match Cli::try_parse_from(&full_args) {
                             Ok(cli_repl) => {
                                 if let Some(cmd) = cli_repl.command {
-                                    if let Err(e) = execute_command(cmd.clone(), ctx.clone()).await
+                                    if let Err(e) =
+                                        execute_audited(cmd.clone(), ctx.clone(), &full_args).await
                                     {
                                         user_error!(
                                             "CLI_COMMAND_FAILED",
@@ -472,9 +508,26 @@ async fn execute_command(cmd: Commands, ctx: CliContext) -> RaiseResult<()> {
         Commands::CodeGen(args) => commands::code_gen::handle(args, ctx).await,
         Commands::Validator(args) => commands::validator::handle(args, ctx).await,
         Commands::Utils(args) => commands::utils::handle(args, ctx).await,
+        Commands::Secrets(args) => commands::secrets::handle(args, ctx).await,
+        Commands::Audit(args) => commands::audit::handle(args, ctx).await,
     }
 }
 
+/// Exécute la commande puis la trace dans le journal d'audit si elle modifie l'état.
+/// Les documents écrits portent l'utilisateur actif (`_created_by` / `_updated_by`) et, en
+/// présence d'une identité, chaque collection touchée est soumise à ses règles d'accès.
+async fn execute_audited(cmd: Commands, ctx: CliContext, argv: &[String]) -> RaiseResult<()> {
+    let actor = ctx.active_user.clone();
+    let identity = ctx.identity.clone();
+    let result = acl::with_optional_access(
+        identity,
+        audit_fields::with_actor(actor, execute_command(cmd, ctx.clone())),
+    )
+    .await;
+    commands::audit::record_invocation(&ctx, argv, &result).await;
+    result
+}
+
 // ============================================================================
 // 🎯 LOGIQUE MÉTIER DU CONTEXTE CLI
 // ============================================================================
@@ -536,6 +589,7 @@ impl CliContext {
                 code_generator: None,
             },
             active_user: "mock_user".to_string(),
+            identity: None,
             active_domain: "mock_domain".to_string(),
             active_db: "mock_db".to_string(),
             is_test_mode: true,
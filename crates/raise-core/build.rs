@@ -0,0 +1,202 @@
+// FICHIER : crates/raise-core/build.rs
+
+//! Collecte, à la compilation, des codes d'erreur levés par `raise_error!` / `build_error!`
+//! dans `src/` : module d'origine, description (premier `error = "..."` littéral), conseil
+//! (premier `"hint": "..."` littéral) et nombre d'occurrences. Le catalogue généré
+//! (`$OUT_DIR/error_catalog.rs`) est inclus par `utils::core::error_catalog`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ERROR_MACROS: &[&str] = &["raise_error!(", "build_error!("];
+
+/// Les modules de test, en fin de fichier, ne lèvent pas de codes de production.
+const TEST_MODULE_MARKER: &str = "#[cfg(test)]\nmod tests";
+
+struct Entry {
+    module: String,
+    description: Option<String>,
+    hint: Option<String>,
+    occurrences: usize,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let root = Path::new("src");
+    let mut files = Vec::new();
+    collect_sources(root, &mut files);
+    files.sort();
+
+    let mut entries = BTreeMap::new();
+    for file in &files {
+        let Ok(source) = fs::read_to_string(file) else {
+            continue;
+        };
+        let source = match source.find(TEST_MODULE_MARKER) {
+            Some(end) => &source[..end],
+            None => &source[..],
+        };
+        scan(source, &module_path(root, file), &mut entries);
+    }
+
+    let mut out = String::from("pub static ERROR_CATALOG: &[ErrorSpec] = &[\n");
+    for (code, entry) in &entries {
+        out.push_str(&format!(
+            "    ErrorSpec {{ code: \"{}\", module: \"{}\", description: {}, hint: {}, occurrences: {} }},\n",
+            code,
+            entry.module,
+            optional_literal(&entry.description),
+            optional_literal(&entry.hint),
+            entry.occurrences
+        ));
+    }
+    out.push_str("];\n");
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR non défini");
+    fs::write(PathBuf::from(out_dir).join("error_catalog.rs"), out)
+        .expect("Écriture du catalogue d'erreurs impossible");
+}
+
+fn collect_sources(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_sources(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// `src/json_db/query/sql.rs` -> `json_db::query::sql` ; `mod.rs` désigne son dossier.
+fn module_path(root: &Path, file: &Path) -> String {
+    let relative = file.strip_prefix(root).unwrap_or(file).with_extension("");
+    let mut parts: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if parts.last().is_some_and(|p| p == "mod" || p == "lib") {
+        parts.pop();
+    }
+    match parts.is_empty() {
+        true => "crate".to_string(),
+        false => parts.join("::"),
+    }
+}
+
+fn scan(source: &str, module: &str, entries: &mut BTreeMap<String, Entry>) {
+    for pattern in ERROR_MACROS {
+        let mut from = 0;
+        while let Some(pos) = source[from..].find(pattern) {
+            let start = from + pos + pattern.len();
+            from = start;
+
+            let body = invocation_body(&source[start..]);
+            let Some((code, rest)) = leading_literal(body) else {
+                continue; // Code calculé (ex: définition de la macro elle-même)
+            };
+            if !is_error_code(code) {
+                continue;
+            }
+
+            let entry = entries.entry(code.to_string()).or_insert_with(|| Entry {
+                module: module.to_string(),
+                description: None,
+                hint: None,
+                occurrences: 0,
+            });
+            entry.occurrences += 1;
+            if entry.description.is_none() {
+                entry.description = literal_after(rest, "error", '=');
+            }
+            if entry.hint.is_none() {
+                entry.hint = literal_after(rest, "\"hint\"", ':');
+            }
+        }
+    }
+}
+
+fn is_error_code(code: &str) -> bool {
+    code.starts_with(|c: char| c.is_ascii_uppercase())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Arguments de l'invocation, jusqu'à la parenthèse fermante (chaînes comprises).
+fn invocation_body(s: &str) -> &str {
+    let mut depth = 1usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &s[..i];
+                }
+            }
+            _ => {}
+        }
+    }
+    s
+}
+
+/// Contenu brut (échappements conservés) du littéral en tête de `s`, et la suite.
+fn leading_literal(s: &str) -> Option<(&str, &str)> {
+    let s = s.trim_start().strip_prefix('"')?;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some((&s[..i], &s[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Littéral suivant la première occurrence de `key <separator>` (ex: `error = "..."`).
+fn literal_after(body: &str, key: &str, separator: char) -> Option<String> {
+    let mut from = 0;
+    while let Some(pos) = body[from..].find(key) {
+        let start = from + pos;
+        from = start + key.len();
+        let standalone = body[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !(c.is_ascii_alphanumeric() || c == '_'));
+        let Some(rest) = body[from..].trim_start().strip_prefix(separator) else {
+            continue;
+        };
+        if !standalone || rest.starts_with('=') {
+            continue;
+        }
+        return leading_literal(rest).map(|(literal, _)| literal.to_string());
+    }
+    None
+}
+
+fn optional_literal(value: &Option<String>) -> String {
+    match value {
+        Some(text) => format!("Some(\"{}\")", text),
+        None => "None".to_string(),
+    }
+}
@@ -2,6 +2,9 @@
 
 use crate::utils::prelude::*;
 
+use super::coordination::ElementLockRegistry;
+use super::sandbox::ArtifactOverlay;
+use crate::ai::assurance::xai::{ToolCallKind, XaiFrame};
 use crate::ai::llm::client::LlmClient;
 use crate::ai::world_model::NeuroSymbolicEngine;
 use crate::code_generator::CodeGeneratorService;
@@ -25,6 +28,12 @@ pub struct AgentContext {
     pub codegen: SharedRef<CodeGeneratorService>,
     pub world_engine: SharedRef<NeuroSymbolicEngine>,
     pub paths: AgentPaths,
+    /// Mode simulation : les écritures d'artefacts sont capturées ici au lieu d'être persistées
+    pub dry_run: Option<SharedRef<ArtifactOverlay>>,
+    /// Trame XAI active : chaque appel d'outil de l'agent y est consigné (provenance).
+    pub xai_frame: Option<SharedRef<SyncMutex<XaiFrame>>>,
+    /// Registre des verrous d'éléments : coordonne les écritures concurrentes entre agents
+    pub locks: SharedRef<ElementLockRegistry>,
 }
 
 impl AgentContext {
@@ -58,9 +67,54 @@ impl AgentContext {
                 domain_root,
                 dataset_root,
             },
+            dry_run: None,
+            xai_frame: None,
+            locks: ElementLockRegistry::global(),
         })
     }
 
+    /// Bascule le contexte en mode simulation (aperçu des artefacts avant écriture).
+    pub fn with_dry_run(mut self, overlay: SharedRef<ArtifactOverlay>) -> Self {
+        self.dry_run = Some(overlay);
+        self
+    }
+
+    /// Active une trame XAI qui recevra l'audit des appels d'outils.
+    pub fn with_xai_frame(mut self, frame: SharedRef<SyncMutex<XaiFrame>>) -> Self {
+        self.xai_frame = Some(frame);
+        self
+    }
+
+    /// Remplace le registre de verrous (par défaut, le registre global du processus).
+    pub fn with_locks(mut self, locks: SharedRef<ElementLockRegistry>) -> Self {
+        self.locks = locks;
+        self
+    }
+
+    /// Consigne un appel d'outil dans la trame active (sans effet hors trame).
+    pub fn record_tool_call(
+        &self,
+        tool: &str,
+        kind: ToolCallKind,
+        input: &JsonValue,
+        output: &JsonValue,
+        started: TimeInstant,
+        is_error: bool,
+    ) {
+        let Some(frame) = &self.xai_frame else {
+            return;
+        };
+        match frame.lock() {
+            Ok(mut frame) => {
+                frame.record_tool_call(tool, kind, input, output, started.elapsed(), is_error)
+            }
+            Err(_) => user_warn!(
+                "WRN_XAI_FRAME_LOCK",
+                json_value!({ "agent": self.agent_id, "tool": tool })
+            ),
+        }
+    }
+
     pub fn generate_default_session_id(agent_name: &str, workflow_id: &str) -> RaiseResult<String> {
         if agent_name.is_empty() || workflow_id.is_empty() {
             raise_error!(
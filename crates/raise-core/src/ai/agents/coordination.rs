@@ -0,0 +1,552 @@
+// FICHIER : src-tauri/src/ai/agents/coordination.rs
+
+//! Contrôle de concurrence des écritures d'agents sur les éléments partagés du modèle.
+//! Chaque élément peut porter un verrou consultatif (bail à durée limitée) ; un agent qui
+//! réécrit un élément déjà verrouillé, ou modifié depuis sa lecture sans fusion possible,
+//! ne l'écrase pas : le conflit est mis en attente d'un arbitrage humain (porte HITL).
+
+use super::CreatedArtifact;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::transactions::TransactionRequest;
+use crate::kernel::events::{publish_event, RaiseEvent};
+use crate::model_engine::validators::incremental::{revalidate_after_commit, ElementChange};
+use crate::utils::prelude::*;
+
+/// Durée de vie par défaut d'un verrou (un agent bloqué ne fige pas l'élément indéfiniment).
+pub const DEFAULT_LOCK_TTL_SECONDS: i64 = 300;
+
+/// Verrou consultatif posé sur un élément.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ElementLock {
+    pub holder: String,
+    pub acquired_at: UtcTimestamp,
+    pub expires_at: UtcTimestamp,
+    /// État de l'élément lors de la prise du verrou (base de la fusion à trois voies).
+    pub base: Option<JsonValue>,
+}
+
+/// Origine d'un conflit d'écriture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictReason {
+    /// L'élément est verrouillé par un autre acteur.
+    Locked,
+    /// L'élément a changé depuis sa lecture et les mêmes champs ont été modifiés.
+    ConcurrentEdit,
+}
+
+/// Écriture suspendue en attente d'arbitrage humain.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct WriteConflict {
+    pub id: String,
+    pub artifact: CreatedArtifact,
+    pub space: String,
+    pub db: String,
+    pub collection: String,
+    /// Agent auteur de l'écriture suspendue.
+    pub agent: String,
+    /// Détenteur du verrou au moment du conflit.
+    pub holder: Option<String>,
+    pub reason: ConflictReason,
+    pub base: Option<JsonValue>,
+    pub current: Option<JsonValue>,
+    pub proposed: JsonValue,
+    /// Champs (pointeurs JSON) modifiés des deux côtés.
+    pub fields: Vec<String>,
+    pub detected_at: UtcTimestamp,
+}
+
+/// Décision prise avant l'écriture d'un artefact.
+#[derive(Debug, Clone)]
+pub enum WriteDecision {
+    /// Écriture autorisée (document éventuellement fusionné).
+    Proceed(JsonValue),
+    /// Écriture suspendue : le conflit est enregistré dans le registre.
+    Conflict(Box<WriteConflict>),
+}
+
+/// Fusion à trois voies champ à champ : la proposition est un correctif sur `base`.
+/// Un champ absent de la proposition n'est pas modifié ; les tableaux sont comparés en bloc.
+/// Retourne le document fusionné ou la liste des champs modifiés des deux côtés.
+pub fn merge_three_way(
+    base: &JsonValue,
+    current: &JsonValue,
+    proposed: &JsonValue,
+) -> Result<JsonValue, Vec<String>> {
+    let mut conflicts = Vec::new();
+    let merged = merge_node("", base, current, proposed, &mut conflicts);
+    if conflicts.is_empty() {
+        Ok(merged)
+    } else {
+        Err(conflicts)
+    }
+}
+
+fn merge_node(
+    path: &str,
+    base: &JsonValue,
+    current: &JsonValue,
+    proposed: &JsonValue,
+    conflicts: &mut Vec<String>,
+) -> JsonValue {
+    if let (JsonValue::Object(c), JsonValue::Object(p)) = (current, proposed) {
+        let mut merged = c.clone();
+        for (key, p_val) in p {
+            let child = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+            let b_val = base.get(key).unwrap_or(&JsonValue::Null);
+            let c_val = c.get(key).unwrap_or(&JsonValue::Null);
+            merged.insert(
+                key.clone(),
+                merge_node(&child, b_val, c_val, p_val, conflicts),
+            );
+        }
+        return JsonValue::Object(merged);
+    }
+    if proposed == base || proposed == current {
+        current.clone()
+    } else if current == base {
+        proposed.clone()
+    } else {
+        conflicts.push(path.to_string());
+        current.clone()
+    }
+}
+
+/// Registre des verrous et des conflits en attente, partagé par tous les agents du processus.
+/// L'orchestrateur le transmet aux `AgentContext` et libère les verrous en fin de tour.
+#[derive(Debug)]
+pub struct ElementLockRegistry {
+    ttl: CalendarDuration,
+    locks: SyncMutex<UnorderedMap<String, ElementLock>>,
+    conflicts: SyncMutex<OrderedMap<String, WriteConflict>>,
+}
+
+impl Default for ElementLockRegistry {
+    fn default() -> Self {
+        Self::new(CalendarDuration::seconds(DEFAULT_LOCK_TTL_SECONDS))
+    }
+}
+
+impl ElementLockRegistry {
+    pub fn new(ttl: CalendarDuration) -> Self {
+        Self {
+            ttl,
+            locks: SyncMutex::new(UnorderedMap::new()),
+            conflicts: SyncMutex::new(OrderedMap::new()),
+        }
+    }
+
+    /// Registre global du processus.
+    pub fn global() -> SharedRef<Self> {
+        static REGISTRY: StaticCell<SharedRef<ElementLockRegistry>> = StaticCell::new();
+        REGISTRY
+            .get_or_init(|| SharedRef::new(Self::default()))
+            .clone()
+    }
+
+    fn key(space: &str, db: &str, collection: &str, id: &str) -> String {
+        format!("{}/{}/{}/{}", space, db, collection, id)
+    }
+
+    fn live_lock(&self, key: &str) -> Option<ElementLock> {
+        let mut locks = lock_or_recover(&self.locks);
+        match locks.get(key) {
+            Some(lock) if lock.expires_at <= UtcClock::now() => {
+                locks.remove(key);
+                None
+            }
+            other => other.cloned(),
+        }
+    }
+
+    /// Verrouille un élément pour `holder` et mémorise son état courant.
+    /// Retourne le verrou concurrent si l'élément est déjà tenu par un autre acteur.
+    pub async fn acquire(
+        &self,
+        manager: &CollectionsManager<'_>,
+        collection: &str,
+        id: &str,
+        holder: &str,
+    ) -> RaiseResult<Result<ElementLock, ElementLock>> {
+        let key = Self::key(&manager.space, &manager.db, collection, id);
+        let base = match self.live_lock(&key) {
+            Some(lock) if lock.holder != holder => return Ok(Err(lock)),
+            // Renouvellement : la base de fusion reste celle de la première lecture
+            Some(lock) => lock.base,
+            None => manager.get_document(collection, id).await?,
+        };
+        let now = UtcClock::now();
+        let mut locks = lock_or_recover(&self.locks);
+        // Un autre acteur a pu verrouiller pendant la lecture
+        if let Some(lock) = locks.get(&key) {
+            if lock.holder != holder && lock.expires_at > now {
+                return Ok(Err(lock.clone()));
+            }
+        }
+        let lock = ElementLock {
+            holder: holder.to_string(),
+            acquired_at: now,
+            expires_at: now + self.ttl,
+            base,
+        };
+        locks.insert(key, lock.clone());
+        Ok(Ok(lock))
+    }
+
+    pub fn release(&self, space: &str, db: &str, collection: &str, id: &str, holder: &str) {
+        let key = Self::key(space, db, collection, id);
+        let mut locks = lock_or_recover(&self.locks);
+        if locks.get(&key).is_some_and(|l| l.holder == holder) {
+            locks.remove(&key);
+        }
+    }
+
+    /// Libère tous les verrous d'un acteur (fin de tour d'agent).
+    pub fn release_all(&self, holder: &str) -> usize {
+        let mut locks = lock_or_recover(&self.locks);
+        let before = locks.len();
+        locks.retain(|_, l| l.holder != holder);
+        before - locks.len()
+    }
+
+    /// Contrôle une écriture d'agent : verrou d'un autre acteur ou édition concurrente
+    /// non fusionnable ⇒ conflit en attente ; sinon le verrou est pris pour la durée du commit.
+    pub async fn prepare_write(
+        &self,
+        manager: &CollectionsManager<'_>,
+        collection: &str,
+        agent: &str,
+        artifact: CreatedArtifact,
+        proposed: JsonValue,
+    ) -> RaiseResult<WriteDecision> {
+        let key = Self::key(&manager.space, &manager.db, collection, &artifact.id);
+        let current = manager.get_document(collection, &artifact.id).await?;
+        let own_lock = match self.live_lock(&key) {
+            Some(lock) if lock.holder != agent => {
+                let conflict = self.record_conflict(
+                    manager,
+                    collection,
+                    agent,
+                    artifact,
+                    Some(lock.holder),
+                    ConflictReason::Locked,
+                    (lock.base, current, proposed),
+                    vec![],
+                );
+                return Ok(WriteDecision::Conflict(Box::new(conflict)));
+            }
+            other => other,
+        };
+
+        let document = match (own_lock.and_then(|l| l.base), &current) {
+            // Élément modifié par un tiers depuis la prise du verrou : fusion à trois voies
+            (Some(base), Some(cur)) if &base != cur => {
+                match merge_three_way(&base, cur, &proposed) {
+                    Ok(merged) => merged,
+                    Err(fields) => {
+                        let conflict = self.record_conflict(
+                            manager,
+                            collection,
+                            agent,
+                            artifact,
+                            None,
+                            ConflictReason::ConcurrentEdit,
+                            (Some(base), current, proposed),
+                            fields,
+                        );
+                        return Ok(WriteDecision::Conflict(Box::new(conflict)));
+                    }
+                }
+            }
+            _ => proposed,
+        };
+
+        if let Err(lock) = self
+            .acquire(manager, collection, &artifact.id, agent)
+            .await?
+        {
+            let conflict = self.record_conflict(
+                manager,
+                collection,
+                agent,
+                artifact,
+                Some(lock.holder),
+                ConflictReason::Locked,
+                (lock.base, current, document),
+                vec![],
+            );
+            return Ok(WriteDecision::Conflict(Box::new(conflict)));
+        }
+        Ok(WriteDecision::Proceed(document))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn record_conflict(
+        &self,
+        manager: &CollectionsManager<'_>,
+        collection: &str,
+        agent: &str,
+        artifact: CreatedArtifact,
+        holder: Option<String>,
+        reason: ConflictReason,
+        (base, current, proposed): (Option<JsonValue>, Option<JsonValue>, JsonValue),
+        fields: Vec<String>,
+    ) -> WriteConflict {
+        let conflict = WriteConflict {
+            id: format!("conflict_{}", UniqueId::new_v4()),
+            artifact,
+            space: manager.space.clone(),
+            db: manager.db.clone(),
+            collection: collection.to_string(),
+            agent: agent.to_string(),
+            holder,
+            reason,
+            base,
+            current,
+            proposed,
+            fields,
+            detected_at: UtcClock::now(),
+        };
+        lock_or_recover(&self.conflicts).insert(conflict.id.clone(), conflict.clone());
+        user_warn!(
+            "WRN_AGENT_WRITE_CONFLICT",
+            json_value!({
+                "conflict": conflict.id,
+                "element": conflict.artifact.id,
+                "agent": conflict.agent,
+                "holder": conflict.holder,
+                "reason": conflict.reason,
+                "hint": "Écriture suspendue : arbitrage humain requis."
+            })
+        );
+        publish_event(RaiseEvent::AgentWriteConflict {
+            conflict_id: conflict.id.clone(),
+            element_id: conflict.artifact.id.clone(),
+            agent: conflict.agent.clone(),
+        });
+        conflict
+    }
+
+    /// Conflits en attente d'arbitrage (tous agents confondus si `agent` vaut `None`).
+    pub fn pending_conflicts(&self, agent: Option<&str>) -> Vec<WriteConflict> {
+        lock_or_recover(&self.conflicts)
+            .values()
+            .filter(|c| agent.is_none_or(|a| c.agent == a))
+            .cloned()
+            .collect()
+    }
+
+    /// Arbitre un conflit : `approved` applique la proposition de l'agent sur l'état courant
+    /// (champ à champ), sinon elle est abandonnée. Le conflit est retiré dans les deux cas.
+    pub async fn resolve(
+        &self,
+        storage: &StorageEngine,
+        conflict_id: &str,
+        approved: bool,
+    ) -> RaiseResult<WriteConflict> {
+        let Some(conflict) = lock_or_recover(&self.conflicts).get(conflict_id).cloned() else {
+            raise_error!(
+                "ERR_AGENT_CONFLICT_NOT_FOUND",
+                error = "Aucun conflit d'écriture en attente avec cet identifiant.",
+                context = json_value!({ "conflict": conflict_id })
+            );
+        };
+
+        if approved {
+            let manager = CollectionsManager::new(storage, &conflict.space, &conflict.db);
+            let current = manager
+                .get_document(&conflict.collection, &conflict.artifact.id)
+                .await?;
+            let request = match &current {
+                Some(cur) => {
+                    // Les champs proposés priment ; les autres restent ceux de la version courante
+                    let document = merge_three_way(cur, cur, &conflict.proposed)
+                        .unwrap_or_else(|_| conflict.proposed.clone());
+                    TransactionRequest::Update {
+                        collection: conflict.collection.clone(),
+                        id: Some(conflict.artifact.id.clone()),
+                        handle: None,
+                        document,
+                    }
+                }
+                None => TransactionRequest::Insert {
+                    collection: conflict.collection.clone(),
+                    id: Some(conflict.artifact.id.clone()),
+                    document: conflict.proposed.clone(),
+                },
+            };
+            TransactionManager::new(storage, &conflict.space, &conflict.db)
+                .execute_smart(vec![request])
+                .await?;
+
+            let change = match manager
+                .get_document(&conflict.collection, &conflict.artifact.id)
+                .await?
+            {
+                Some(after) if current.is_some() => {
+                    ElementChange::updated(&conflict.collection, &conflict.artifact.id, &after)
+                }
+                _ => ElementChange::created(&conflict.collection, &conflict.artifact.id),
+            };
+            revalidate_after_commit(storage, &conflict.space, &conflict.db, vec![change]).await;
+        }
+
+        lock_or_recover(&self.conflicts).remove(conflict_id);
+        user_info!(
+            "INF_AGENT_CONFLICT_RESOLVED",
+            json_value!({ "conflict": conflict_id, "approved": approved })
+        );
+        Ok(conflict)
+    }
+}
+
+fn lock_or_recover<T>(mutex: &SyncMutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|p| p.into_inner())
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    fn artifact(id: &str) -> CreatedArtifact {
+        CreatedArtifact {
+            id: id.into(),
+            name: id.into(),
+            layer: "SA".into(),
+            element_type: "SystemFunction".into(),
+            path: format!("ref:elements:id:{}", id),
+        }
+    }
+
+    #[test]
+    fn test_three_way_merge() {
+        let base = json_value!({ "_id": "f1", "name": "Brake", "mass": 10, "dims": { "w": 1 } });
+        let current = json_value!({ "_id": "f1", "name": "Brake", "mass": 12, "dims": { "w": 1 } });
+
+        // Champs disjoints : les deux modifications sont conservées
+        let proposed = json_value!({ "_id": "f1", "name": "Brake v2", "dims": { "w": 1, "h": 2 } });
+        let merged = merge_three_way(&base, &current, &proposed).unwrap();
+        assert_eq!(merged["name"], "Brake v2");
+        assert_eq!(merged["mass"], 12);
+        assert_eq!(merged["dims"]["h"], 2);
+
+        // Même champ modifié des deux côtés
+        let clash = json_value!({ "mass": 15 });
+        assert_eq!(
+            merge_three_way(&base, &current, &clash),
+            Err(vec!["/mass".to_string()])
+        );
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_locked_and_concurrent_writes_become_hitl_conflicts() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "coordination");
+        DbSandbox::mock_db(&manager).await?;
+        let schema = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager.create_collection("elements", &schema).await?;
+        manager
+            .insert_raw(
+                "elements",
+                &json_value!({ "_id": "f1", "name": "Brake", "mass": 10 }),
+            )
+            .await?;
+
+        let registry = ElementLockRegistry::default();
+
+        // 1. Élément verrouillé par un utilisateur : l'écriture de l'agent est suspendue
+        assert!(registry
+            .acquire(&manager, "elements", "f1", "user:alice")
+            .await?
+            .is_ok());
+        let decision = registry
+            .prepare_write(
+                &manager,
+                "elements",
+                "agent_system",
+                artifact("f1"),
+                json_value!({ "_id": "f1", "name": "Brake (IA)" }),
+            )
+            .await?;
+        let WriteDecision::Conflict(conflict) = decision else {
+            panic!("Un conflit de verrou était attendu");
+        };
+        assert_eq!(conflict.reason, ConflictReason::Locked);
+        assert_eq!(conflict.holder.as_deref(), Some("user:alice"));
+        assert_eq!(registry.pending_conflicts(Some("agent_system")).len(), 1);
+        registry.release_all("user:alice");
+
+        // 2. Lecture par l'agent puis modification concurrente du même champ
+        assert!(registry
+            .acquire(&manager, "elements", "f1", "agent_system")
+            .await?
+            .is_ok());
+        manager
+            .upsert_document(
+                "elements",
+                json_value!({ "_id": "f1", "name": "Brake (user)", "mass": 10 }),
+            )
+            .await?;
+        let merged = registry
+            .prepare_write(
+                &manager,
+                "elements",
+                "agent_system",
+                artifact("f1"),
+                json_value!({ "_id": "f1", "mass": 11 }),
+            )
+            .await?;
+        let WriteDecision::Proceed(merged) = merged else {
+            panic!("Les champs disjoints devaient fusionner");
+        };
+        assert_eq!(
+            (&merged["name"], &merged["mass"]),
+            (&json_value!("Brake (user)"), &json_value!(11))
+        );
+        let clash = registry
+            .prepare_write(
+                &manager,
+                "elements",
+                "agent_system",
+                artifact("f1"),
+                json_value!({ "_id": "f1", "name": "Brake (agent)" }),
+            )
+            .await?;
+        assert!(matches!(
+            clash,
+            WriteDecision::Conflict(c) if c.reason == ConflictReason::ConcurrentEdit
+        ));
+        assert_eq!(registry.release_all("agent_system"), 1);
+
+        // 3. Arbitrage : la première proposition est appliquée, la seconde abandonnée
+        registry
+            .resolve(&sandbox.storage, &conflict.id, true)
+            .await?;
+        let doc = manager.get_document("elements", "f1").await?.unwrap();
+        assert_eq!(doc["name"], "Brake (IA)");
+        assert_eq!(doc["mass"], 10);
+
+        let remaining = registry.pending_conflicts(None);
+        assert_eq!(remaining.len(), 1);
+        registry
+            .resolve(&sandbox.storage, &remaining[0].id, false)
+            .await?;
+        assert!(registry.pending_conflicts(None).is_empty());
+        assert!(registry
+            .resolve(&sandbox.storage, &remaining[0].id, false)
+            .await
+            .is_err());
+        Ok(())
+    }
+}
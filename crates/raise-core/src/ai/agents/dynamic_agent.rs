@@ -192,11 +192,34 @@ impl Agent for DynamicAgent {
             ),
         };
 
+        let (message, pending_changes) = match &ctx.dry_run {
+            Some(overlay) => (
+                format!(
+                    "Simulation terminée. {} artefacts en attente d'approbation.",
+                    artifacts.len()
+                ),
+                overlay.preview(),
+            ),
+            None => (
+                format!("Cycle terminé. {} artefacts persistés.", artifacts.len()),
+                vec![],
+            ),
+        };
+
+        // 🎯 HITL : les écritures en conflit attendent l'arbitrage humain
+        let conflicts = ctx.locks.pending_conflicts(Some(&ctx.agent_id));
+        let message = match conflicts.len() {
+            0 => message,
+            n => format!("{} {} écriture(s) en conflit à arbitrer.", message, n),
+        };
+
         Ok(Some(AgentResult {
-            message: format!("Cycle terminé. {} artefacts persistés.", artifacts.len()),
+            message,
             artifacts,
             outgoing_message: None,
             xai_frame: None,
+            pending_changes,
+            conflicts,
         }))
     }
 }
@@ -4,9 +4,21 @@ use crate::ai::llm::client::{LlmBackend, LlmClient};
 use crate::utils::data::json::Clearance;
 use crate::utils::prelude::*;
 
+use super::intent_examples::{IntentExampleStore, FEW_SHOT_EXAMPLES};
+use super::prompt_registry::PromptRegistry;
 // Import de la Toolbox pour le parsing JSON robuste
 use super::tools::extract_json_from_llm;
 
+/// Gabarit du registre surchargeant le prompt du Dispatcher.
+pub const DISPATCHER_TEMPLATE: &str = "intent_dispatcher";
+
+/// Prompt intégré, utilisé tant qu'aucune version de `intent_dispatcher` n'est publiée.
+const DEFAULT_DISPATCHER_PROMPT: &str = "Tu es le Dispatcher IA de RAISE. Tu convertis les demandes utilisateur en JSON STRICT.\n\
+                             SCHÉMAS :\n\
+                             - Création : { \"intent\": \"create_element\", \"layer\": \"SA|LA|PA|DATA|OA|TRANSVERSE\", \"element_type\": \"str\", \"name\": \"str\" }\n\
+                             - Code : { \"intent\": \"generate_code\", \"language\": \"str\", \"filename\": \"str\" }\n\
+                             - Chat : { \"intent\": \"chat\" }";
+
 #[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
 #[serde(tag = "intent")]
 pub enum EngineeringIntent {
@@ -118,12 +130,28 @@ impl IntentClassifier {
             };
         }
 
-        // 2. 🔄 BOUCLE DE RÉFLEXION POUR LA CLASSIFICATION LLM
-        let system_prompt = "Tu es le Dispatcher IA de RAISE. Tu convertis les demandes utilisateur en JSON STRICT.\n\
-                             SCHÉMAS :\n\
-                             - Création : { \"intent\": \"create_element\", \"layer\": \"SA|LA|PA|DATA|OA|TRANSVERSE\", \"element_type\": \"str\", \"name\": \"str\" }\n\
-                             - Code : { \"intent\": \"generate_code\", \"language\": \"str\", \"filename\": \"str\" }\n\
-                             - Chat : { \"intent\": \"chat\" }";
+        // 2. 📚 EXEMPLES ANNOTÉS : un énoncé déjà corrigé est résolu sans LLM
+        let examples = IntentExampleStore::system(self.llm.storage());
+        match examples.exact_match(user_input).await {
+            Ok(Some(example)) => return example.intent,
+            Ok(None) => {}
+            Err(e) => user_warn!(
+                "WARN_INTENT_EXAMPLES_UNAVAILABLE",
+                json_value!({ "component": "IntentClassifier", "error": e.to_string() })
+            ),
+        }
+
+        // 3. 🔄 BOUCLE DE RÉFLEXION POUR LA CLASSIFICATION LLM (few-shot)
+        let mut system_prompt = PromptRegistry::system(self.llm.storage())
+            .render_or(DISPATCHER_TEMPLATE, DEFAULT_DISPATCHER_PROMPT, None)
+            .await;
+        let few_shot = examples
+            .few_shot_block(user_input, FEW_SHOT_EXAMPLES)
+            .await
+            .unwrap_or_default();
+        if !few_shot.is_empty() {
+            system_prompt = format!("{}\n\n{}", system_prompt, few_shot);
+        }
 
         let mut current_feedback = String::new();
         let max_retries = 2;
@@ -143,7 +171,7 @@ impl IntentClassifier {
                 .llm
                 .ask(
                     LlmBackend::LocalLlama,
-                    system_prompt,
+                    &system_prompt,
                     &user_prompt,
                     Clearance::Internal,
                 )
@@ -206,7 +234,7 @@ impl IntentClassifier {
             }
         }
 
-        // 3. FALLBACK ULTIME (Si le LLM échoue)
+        // 4. FALLBACK ULTIME (Si le LLM échoue)
         let fallback_val = heuristic_fallback(user_input);
         json::deserialize_from_value::<EngineeringIntent>(fallback_val)
             .unwrap_or(EngineeringIntent::Unknown)
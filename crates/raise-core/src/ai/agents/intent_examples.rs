@@ -0,0 +1,339 @@
+// FICHIER : src-tauri/src/ai/agents/intent_examples.rs
+
+//! Exemples annotés (énoncé → intention) pour l'`IntentClassifier` (collection `intent_examples`).
+//! Les exemples les plus proches de la demande sont injectés en few-shot dans le prompt de
+//! classification ; les erreurs constatées en usage réel sont enregistrées comme corrections,
+//! ce qui améliore la précision au fil du temps sans réentraînement.
+
+use super::intent_classifier::EngineeringIntent;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+/// Collection des exemples (un document par énoncé normalisé).
+pub const INTENT_EXAMPLES_COLLECTION: &str = "intent_examples";
+/// Nombre d'exemples injectés dans le prompt de classification.
+pub const FEW_SHOT_EXAMPLES: usize = 5;
+
+/// Origine d'un exemple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum ExampleSource {
+    /// Ajouté volontairement par un curateur.
+    Curated,
+    /// Issu d'une erreur de classification signalée en usage réel.
+    Correction,
+}
+
+/// Exemple annoté.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct IntentExample {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub utterance: String,
+    /// Intention attendue.
+    pub intent: EngineeringIntent,
+    pub source: ExampleSource,
+    /// Intention produite à tort par le classifieur (corrections uniquement).
+    #[serde(default)]
+    pub misclassified_as: Option<EngineeringIntent>,
+    pub author: String,
+    pub created_at: UtcTimestamp,
+}
+
+fn normalize(utterance: &str) -> String {
+    utterance
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn tokens(text: &str) -> UniqueSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| t.chars().count() > 2)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Similarité de Jaccard entre les mots (3+ caractères) de deux énoncés.
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (tokens(a), tokens(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+/// Identifiant stable d'un énoncé : ré-annoter un énoncé remplace l'exemple existant.
+pub fn example_id(utterance: &str) -> String {
+    let mut hasher = CryptoSha256::new();
+    hasher.update(normalize(utterance).as_bytes());
+    let digest: String = hasher
+        .finalize()
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("ex_{}", digest)
+}
+
+/// Magasin d'exemples adossé à une base JSON-DB (partition système par défaut).
+pub struct IntentExampleStore<'a> {
+    manager: CollectionsManager<'a>,
+}
+
+impl<'a> IntentExampleStore<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            manager: CollectionsManager::new(storage, space, db),
+        }
+    }
+
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, INTENT_EXAMPLES_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(INTENT_EXAMPLES_COLLECTION, &schema_uri)
+            .await
+    }
+
+    async fn save(&self, example: IntentExample) -> RaiseResult<IntentExample> {
+        self.ensure_collection().await?;
+        self.manager
+            .upsert_document(
+                INTENT_EXAMPLES_COLLECTION,
+                json::serialize_to_value(&example)?,
+            )
+            .await?;
+        Ok(example)
+    }
+
+    /// Ajoute (ou remplace) un exemple curé.
+    pub async fn add(
+        &self,
+        utterance: &str,
+        intent: EngineeringIntent,
+        author: &str,
+    ) -> RaiseResult<IntentExample> {
+        self.save(IntentExample {
+            id: example_id(utterance),
+            utterance: utterance.trim().to_string(),
+            intent,
+            source: ExampleSource::Curated,
+            misclassified_as: None,
+            author: author.to_string(),
+            created_at: UtcClock::now(),
+        })
+        .await
+    }
+
+    /// Signale une erreur de classification : l'énoncé devient un exemple correctif.
+    pub async fn flag_misclassification(
+        &self,
+        utterance: &str,
+        expected: EngineeringIntent,
+        predicted: Option<EngineeringIntent>,
+        author: &str,
+    ) -> RaiseResult<IntentExample> {
+        let example = self
+            .save(IntentExample {
+                id: example_id(utterance),
+                utterance: utterance.trim().to_string(),
+                intent: expected,
+                source: ExampleSource::Correction,
+                misclassified_as: predicted,
+                author: author.to_string(),
+                created_at: UtcClock::now(),
+            })
+            .await?;
+        user_info!(
+            "INF_INTENT_MISCLASSIFICATION_FLAGGED",
+            json_value!({ "id": example.id, "expected": example.intent, "predicted": example.misclassified_as })
+        );
+        Ok(example)
+    }
+
+    pub async fn list(&self) -> RaiseResult<Vec<IntentExample>> {
+        if self
+            .manager
+            .collection_schema_uri(INTENT_EXAMPLES_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(vec![]);
+        }
+        let mut examples: Vec<IntentExample> = self
+            .manager
+            .list_all(INTENT_EXAMPLES_COLLECTION)
+            .await?
+            .into_iter()
+            .map(json::deserialize_from_value)
+            .collect::<RaiseResult<_>>()?;
+        examples.sort_by_key(|e| e.created_at);
+        Ok(examples)
+    }
+
+    pub async fn remove(&self, id: &str) -> RaiseResult<bool> {
+        if self
+            .manager
+            .get_document(INTENT_EXAMPLES_COLLECTION, id)
+            .await?
+            .is_none()
+        {
+            return Ok(false);
+        }
+        self.manager
+            .delete_document(INTENT_EXAMPLES_COLLECTION, id)
+            .await?;
+        Ok(true)
+    }
+
+    /// Exemples les plus proches de `input` (à similarité égale, les corrections d'abord).
+    pub async fn select(&self, input: &str, k: usize) -> RaiseResult<Vec<IntentExample>> {
+        let mut scored: Vec<(f64, IntentExample)> = self
+            .list()
+            .await?
+            .into_iter()
+            .map(|e| (similarity(input, &e.utterance), e))
+            .filter(|(score, _)| *score > 0.0)
+            .collect();
+        scored.sort_by(|(sa, a), (sb, b)| {
+            sb.total_cmp(sa).then_with(|| {
+                (b.source == ExampleSource::Correction)
+                    .cmp(&(a.source == ExampleSource::Correction))
+            })
+        });
+        Ok(scored.into_iter().take(k).map(|(_, e)| e).collect())
+    }
+
+    /// Exemple dont l'énoncé normalisé est identique à `input`.
+    pub async fn exact_match(&self, input: &str) -> RaiseResult<Option<IntentExample>> {
+        if self
+            .manager
+            .collection_schema_uri(INTENT_EXAMPLES_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(None);
+        }
+        let Some(doc) = self
+            .manager
+            .get_document(INTENT_EXAMPLES_COLLECTION, &example_id(input))
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(json::deserialize_from_value(doc)?))
+    }
+
+    /// Bloc few-shot à ajouter au prompt système (vide si aucun exemple pertinent).
+    pub async fn few_shot_block(&self, input: &str, k: usize) -> RaiseResult<String> {
+        let examples = self.select(input, k).await?;
+        if examples.is_empty() {
+            return Ok(String::new());
+        }
+        let mut block = String::from("EXEMPLES :");
+        for example in examples {
+            block.push_str(&format!(
+                "\n- \"{}\" => {}",
+                example.utterance,
+                json::serialize_to_string(&example.intent)?
+            ));
+        }
+        Ok(block)
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    fn create(layer: &str, element_type: &str, name: &str) -> EngineeringIntent {
+        EngineeringIntent::CreateElement {
+            layer: layer.into(),
+            element_type: element_type.into(),
+            name: name.into(),
+        }
+    }
+
+    #[test]
+    fn test_similarity_and_ids() {
+        assert_eq!(
+            example_id("Crée  un Système"),
+            example_id("crée un système")
+        );
+        assert!(similarity("crée le système radar", "crée un système de freinage") > 0.0);
+        assert_eq!(similarity("bonjour", "système radar"), 0.0);
+    }
+
+    #[async_test]
+    async fn test_examples_feed_few_shot_context() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "examples");
+        DbSandbox::mock_db(&manager).await?;
+        let store = IntentExampleStore::new(&sandbox.storage, "test", "examples");
+
+        assert!(store.few_shot_block("crée le radar", 3).await?.is_empty());
+
+        store
+            .add(
+                "crée le composant radar",
+                create("LA", "Component", "radar"),
+                "alice",
+            )
+            .await?;
+        store
+            .add("bonjour", EngineeringIntent::Chat, "alice")
+            .await?;
+        store
+            .flag_misclassification(
+                "ajoute l'exigence radar",
+                create("TRANSVERSE", "Requirement", "radar"),
+                Some(create("SA", "Function", "radar")),
+                "bob",
+            )
+            .await?;
+
+        let selected = store.select("crée l'exigence du radar", 5).await?;
+        assert_eq!(selected.len(), 2);
+        assert_eq!(selected[0].source, ExampleSource::Correction);
+
+        let block = store.few_shot_block("crée l'exigence du radar", 5).await?;
+        assert!(block.starts_with("EXEMPLES :"));
+        assert!(block.contains("\"intent\":\"create_element\""));
+
+        let exact = store.exact_match("Bonjour").await?;
+        assert_eq!(exact.map(|e| e.intent), Some(EngineeringIntent::Chat));
+
+        assert!(store.remove(&example_id("bonjour")).await?);
+        assert_eq!(store.list().await?.len(), 2);
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
 // FICHIER : src-tauri/src/ai/agents/mod.rs
 
 pub mod context;
+pub mod coordination;
 pub mod dynamic_agent;
 pub mod intent_classifier;
+pub mod intent_examples;
 pub mod prompt_engine;
+pub mod prompt_registry;
+pub mod sandbox;
 pub mod tools;
 
 pub use self::context::AgentContext;
@@ -30,6 +34,12 @@ pub struct AgentResult {
     pub outgoing_message: Option<AclMessage>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xai_frame: Option<crate::ai::assurance::XaiFrame>,
+    /// Changements simulés en attente d'approbation (mode dry-run)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending_changes: Vec<sandbox::PendingChange>,
+    /// Écritures suspendues en attente d'arbitrage humain (conflits de concurrence)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<coordination::WriteConflict>,
 }
 
 impl AgentResult {
@@ -39,6 +49,8 @@ impl AgentResult {
             artifacts: vec![],
             outgoing_message: None,
             xai_frame: None,
+            pending_changes: vec![],
+            conflicts: vec![],
         }
     }
 
@@ -48,6 +60,8 @@ impl AgentResult {
             artifacts: vec![],
             outgoing_message: Some(msg),
             xai_frame: None,
+            pending_changes: vec![],
+            conflicts: vec![],
         }
     }
 }
@@ -1,5 +1,6 @@
 // FICHIER : src-tauri/src/ai/agents/prompt_engine.rs
 
+use super::prompt_registry::{PromptRegistry, TEMPLATE_REF_PREFIX};
 use crate::json_db::collections::manager::{parse_smart_link, CollectionsManager, SmartLink};
 use crate::json_db::storage::StorageEngine;
 use crate::utils::prelude::*;
@@ -24,6 +25,13 @@ impl PromptEngine {
 
     /// Compile un prompt complet à partir de son ID ou de sa référence (URN) en DB.
     pub async fn compile(&self, prompt_id: &str, vars: Option<&JsonValue>) -> RaiseResult<String> {
+        // 🎯 0. GABARIT VERSIONNÉ (`tpl:nom` ou `tpl:nom@vN`)
+        if prompt_id.starts_with(TEMPLATE_REF_PREFIX) {
+            return PromptRegistry::new(&self.db, &self.space, &self.db_name)
+                .render(prompt_id, vars)
+                .await;
+        }
+
         // 🎯 1. RÉSOLUTION DE L'URI (Smart Link)
         let (target_space, target_db, target_col, target_id) = match parse_smart_link(prompt_id) {
             Some(SmartLink::Absolute {
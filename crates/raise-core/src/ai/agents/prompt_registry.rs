@@ -0,0 +1,423 @@
+// FICHIER : src-tauri/src/ai/agents/prompt_registry.rs
+
+//! Registre versionné des gabarits de prompts (collection `prompt_templates`).
+//! Chaque publication crée une nouvelle version immuable (`nom@vN`) portant ses variables
+//! `{{...}}` et ses notes d'évaluation ; les agents résolvent la dernière version à l'exécution,
+//! ce qui permet d'itérer sur un prompt sans recompiler.
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+/// Collection des gabarits (un document par version).
+pub const PROMPT_TEMPLATES_COLLECTION: &str = "prompt_templates";
+/// Préfixe permettant au `PromptEngine` de référencer un gabarit (`tpl:nom` ou `tpl:nom@v2`).
+pub const TEMPLATE_REF_PREFIX: &str = "tpl:";
+
+/// Note d'évaluation attachée à une version.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct EvaluationNote {
+    pub author: String,
+    /// Score libre (ex: 0.0 – 1.0 ou 1 – 5 selon la grille d'évaluation).
+    pub score: Option<f64>,
+    pub comment: String,
+    pub created_at: UtcTimestamp,
+}
+
+/// Version publiée d'un gabarit.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct PromptTemplate {
+    /// `nom@vN`
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub name: String,
+    pub version: u32,
+    pub template: String,
+    /// Variables `{{...}}` requises au rendu.
+    pub variables: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub author: String,
+    pub created_at: UtcTimestamp,
+    #[serde(default)]
+    pub notes: Vec<EvaluationNote>,
+}
+
+impl PromptTemplate {
+    /// Remplace les `{{variables}}` ; échoue si une variable requise n'est pas fournie.
+    pub fn render(&self, vars: Option<&JsonValue>) -> RaiseResult<String> {
+        let provided = vars.and_then(|v| v.as_object());
+        let mut rendered = self.template.clone();
+        for name in &self.variables {
+            let Some(value) = provided.and_then(|obj| obj.get(name)) else {
+                raise_error!(
+                    "ERR_PROMPT_MISSING_VARIABLE",
+                    error = format!(
+                        "Variable '{}' manquante pour le prompt '{}'.",
+                        name, self.id
+                    ),
+                    context = json_value!({ "template": self.id, "variables": self.variables })
+                );
+            };
+            let text = match value.as_str() {
+                Some(s) => s.to_string(),
+                None => value.to_string(),
+            };
+            rendered = rendered.replace(&format!("{{{{{}}}}}", name), &text);
+        }
+        Ok(rendered)
+    }
+
+    /// Moyenne des scores d'évaluation renseignés.
+    pub fn average_score(&self) -> Option<f64> {
+        let scores: Vec<f64> = self.notes.iter().filter_map(|n| n.score).collect();
+        (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64)
+    }
+}
+
+pub fn template_id(name: &str, version: u32) -> String {
+    format!("{}@v{}", name, version)
+}
+
+/// Découpe une référence `nom` ou `nom@vN` (version absente = dernière).
+pub fn parse_template_ref(reference: &str) -> (&str, Option<u32>) {
+    let reference = reference
+        .strip_prefix(TEMPLATE_REF_PREFIX)
+        .unwrap_or(reference);
+    match reference.rsplit_once("@v") {
+        Some((name, version)) => match version.parse() {
+            Ok(v) => (name, Some(v)),
+            Err(_) => (reference, None),
+        },
+        None => (reference, None),
+    }
+}
+
+/// Variables `{{nom}}` d'un gabarit, dans l'ordre d'apparition et sans doublon.
+pub fn extract_variables(template: &str) -> Vec<String> {
+    let mut variables: Vec<String> = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if !name.is_empty()
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+            && !variables.iter().any(|v| v == name)
+        {
+            variables.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    variables
+}
+
+/// Registre adossé à une base JSON-DB (partition système par défaut).
+pub struct PromptRegistry<'a> {
+    manager: CollectionsManager<'a>,
+}
+
+impl<'a> PromptRegistry<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            manager: CollectionsManager::new(storage, space, db),
+        }
+    }
+
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, PROMPT_TEMPLATES_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(PROMPT_TEMPLATES_COLLECTION, &schema_uri)
+            .await
+    }
+
+    async fn has_collection(&self) -> bool {
+        self.manager
+            .collection_schema_uri(PROMPT_TEMPLATES_COLLECTION)
+            .await
+            .is_some()
+    }
+
+    async fn query(&self, condition: Option<Condition>) -> RaiseResult<Vec<PromptTemplate>> {
+        if !self.has_collection().await {
+            return Ok(vec![]);
+        }
+        let mut query = Query::new(PROMPT_TEMPLATES_COLLECTION);
+        query.filter = condition.map(|c| QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![c],
+        });
+        let result = QueryEngine::new(&self.manager).execute_query(query).await?;
+        result
+            .documents
+            .into_iter()
+            .map(json::deserialize_from_value)
+            .collect()
+    }
+
+    /// Historique d'un gabarit, de la plus ancienne à la plus récente version.
+    pub async fn versions(&self, name: &str) -> RaiseResult<Vec<PromptTemplate>> {
+        let mut versions = self
+            .query(Some(Condition::eq("name", json_value!(name))))
+            .await?;
+        versions.sort_by_key(|t| t.version);
+        Ok(versions)
+    }
+
+    /// Version demandée, ou la dernière si `version` est `None`.
+    pub async fn get(
+        &self,
+        name: &str,
+        version: Option<u32>,
+    ) -> RaiseResult<Option<PromptTemplate>> {
+        let versions = self.versions(name).await?;
+        Ok(match version {
+            Some(v) => versions.into_iter().find(|t| t.version == v),
+            None => versions.into_iter().last(),
+        })
+    }
+
+    /// Dernière version de chaque gabarit, triée par nom.
+    pub async fn list(&self) -> RaiseResult<Vec<PromptTemplate>> {
+        let mut latest: OrderedMap<String, PromptTemplate> = OrderedMap::new();
+        for template in self.query(None).await? {
+            match latest.get(&template.name) {
+                Some(current) if current.version >= template.version => {}
+                _ => {
+                    latest.insert(template.name.clone(), template);
+                }
+            }
+        }
+        Ok(latest.into_values().collect())
+    }
+
+    /// Publie une nouvelle version. Un texte identique à la dernière version n'en crée pas de nouvelle.
+    pub async fn publish(
+        &self,
+        name: &str,
+        template: &str,
+        description: Option<&str>,
+        author: &str,
+    ) -> RaiseResult<PromptTemplate> {
+        if name.is_empty() || name.contains('@') {
+            raise_error!(
+                "ERR_PROMPT_TEMPLATE_INVALID_NAME",
+                error = format!("Nom de gabarit invalide : '{}'.", name),
+                context = json_value!({ "name": name })
+            );
+        }
+        self.ensure_collection().await?;
+
+        let latest = self.get(name, None).await?;
+        if let Some(current) = latest.as_ref().filter(|t| t.template == template) {
+            return Ok(current.clone());
+        }
+
+        let version = latest.map_or(1, |t| t.version + 1);
+        let published = PromptTemplate {
+            id: template_id(name, version),
+            name: name.to_string(),
+            version,
+            template: template.to_string(),
+            variables: extract_variables(template),
+            description: description.map(str::to_string),
+            author: author.to_string(),
+            created_at: UtcClock::now(),
+            notes: vec![],
+        };
+        self.manager
+            .insert_raw(
+                PROMPT_TEMPLATES_COLLECTION,
+                &json::serialize_to_value(&published)?,
+            )
+            .await?;
+        user_info!(
+            "INF_PROMPT_TEMPLATE_PUBLISHED",
+            json_value!({ "id": published.id, "variables": published.variables })
+        );
+        Ok(published)
+    }
+
+    /// Ajoute une note d'évaluation à une version existante.
+    pub async fn annotate(
+        &self,
+        name: &str,
+        version: u32,
+        author: &str,
+        score: Option<f64>,
+        comment: &str,
+    ) -> RaiseResult<PromptTemplate> {
+        let Some(mut template) = self.get(name, Some(version)).await? else {
+            raise_error!(
+                "ERR_PROMPT_TEMPLATE_NOT_FOUND",
+                error = format!("Gabarit '{}' introuvable.", template_id(name, version)),
+                context = json_value!({ "name": name, "version": version })
+            );
+        };
+        template.notes.push(EvaluationNote {
+            author: author.to_string(),
+            score,
+            comment: comment.to_string(),
+            created_at: UtcClock::now(),
+        });
+        self.manager
+            .upsert_document(
+                PROMPT_TEMPLATES_COLLECTION,
+                json::serialize_to_value(&template)?,
+            )
+            .await?;
+        Ok(template)
+    }
+
+    /// Rend un gabarit référencé par `nom`, `nom@vN` ou `tpl:nom[@vN]`.
+    pub async fn render(&self, reference: &str, vars: Option<&JsonValue>) -> RaiseResult<String> {
+        let (name, version) = parse_template_ref(reference);
+        match self.get(name, version).await? {
+            Some(template) => template.render(vars),
+            None => raise_error!(
+                "ERR_PROMPT_TEMPLATE_NOT_FOUND",
+                error = format!("Gabarit de prompt '{}' introuvable.", reference),
+                context = json_value!({ "name": name, "version": version })
+            ),
+        }
+    }
+
+    /// Rend la dernière version publiée, ou le prompt intégré `default` si aucune ne l'est
+    /// encore (ou si le registre est inaccessible).
+    pub async fn render_or(&self, name: &str, default: &str, vars: Option<&JsonValue>) -> String {
+        match self.get(name, None).await {
+            Ok(Some(template)) => match template.render(vars) {
+                Ok(prompt) => return prompt,
+                Err(e) => user_warn!(
+                    "WRN_PROMPT_TEMPLATE_FALLBACK",
+                    json_value!({ "template": template.id, "error": e.to_string() })
+                ),
+            },
+            Ok(None) => {}
+            Err(e) => user_warn!(
+                "WRN_PROMPT_TEMPLATE_FALLBACK",
+                json_value!({ "template": name, "error": e.to_string() })
+            ),
+        }
+        let builtin = PromptTemplate {
+            id: template_id(name, 0),
+            name: name.to_string(),
+            version: 0,
+            template: default.to_string(),
+            variables: extract_variables(default),
+            description: None,
+            author: "builtin".to_string(),
+            created_at: UtcClock::now(),
+            notes: vec![],
+        };
+        builtin.render(vars).unwrap_or_else(|_| default.to_string())
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_template_refs_and_variables() {
+        assert_eq!(
+            parse_template_ref("tpl:dispatcher@v3"),
+            ("dispatcher", Some(3))
+        );
+        assert_eq!(parse_template_ref("dispatcher"), ("dispatcher", None));
+        assert_eq!(
+            extract_variables("{{ layer }} puis {{name}}, encore {{name}} et {{ pas valide }}"),
+            vec!["layer".to_string(), "name".to_string()]
+        );
+    }
+
+    #[async_test]
+    async fn test_publish_version_annotate_and_render() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "prompts");
+        DbSandbox::mock_db(&manager).await?;
+        let registry = PromptRegistry::new(&sandbox.storage, "test", "prompts");
+
+        assert_eq!(
+            registry
+                .render_or(
+                    "greeter",
+                    "Bonjour {{who}}",
+                    Some(&json_value!({"who": "RAISE"}))
+                )
+                .await,
+            "Bonjour RAISE"
+        );
+
+        let v1 = registry
+            .publish("greeter", "Salut {{who}}", None, "alice")
+            .await?;
+        assert_eq!(
+            (v1.version, v1.variables.clone()),
+            (1, vec!["who".to_string()])
+        );
+        let same = registry
+            .publish("greeter", "Salut {{who}}", None, "alice")
+            .await?;
+        assert_eq!(same.version, 1);
+        let v2 = registry
+            .publish(
+                "greeter",
+                "Bienvenue {{who}} ({{role}})",
+                Some("plus formel"),
+                "bob",
+            )
+            .await?;
+        assert_eq!(v2.id, "greeter@v2");
+
+        let vars = json_value!({ "who": "Ada", "role": "architecte" });
+        assert_eq!(
+            registry.render("tpl:greeter", Some(&vars)).await?,
+            "Bienvenue Ada (architecte)"
+        );
+        assert_eq!(
+            registry.render("greeter@v1", Some(&vars)).await?,
+            "Salut Ada"
+        );
+        assert!(registry.render("greeter", None).await.is_err());
+
+        registry
+            .annotate("greeter", 2, "eval", Some(0.5), "trop long")
+            .await?;
+        let noted = registry
+            .annotate("greeter", 2, "eval", Some(1.0), "ok")
+            .await?;
+        assert_eq!(noted.average_score(), Some(0.75));
+        assert_eq!(registry.versions("greeter").await?.len(), 2);
+        assert_eq!(registry.list().await?[0].version, 2);
+        Ok(())
+    }
+}
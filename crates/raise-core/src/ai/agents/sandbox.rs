@@ -0,0 +1,342 @@
+// FICHIER : src-tauri/src/ai/agents/sandbox.rs
+
+use super::CreatedArtifact;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::transactions::TransactionRequest;
+use crate::model_engine::validators::incremental::{revalidate_after_commit, ElementChange};
+use crate::utils::prelude::*;
+
+/// Nature d'un changement capturé en mode simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Create,
+    Update,
+}
+
+/// Différence sur un champ (pointeur JSON), `None` = absent.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct FieldDiff {
+    pub path: String,
+    pub before: Option<JsonValue>,
+    pub after: Option<JsonValue>,
+}
+
+/// Écriture d'artefact interceptée par le bac à sable (non encore appliquée en base).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct PendingChange {
+    pub artifact: CreatedArtifact,
+    pub space: String,
+    pub db: String,
+    pub collection: String,
+    pub kind: ChangeKind,
+    pub before: Option<JsonValue>,
+    pub after: JsonValue,
+    pub diff: Vec<FieldDiff>,
+}
+
+/// Surcouche mémoire du mode "dry-run" : les agents y écrivent leurs artefacts
+/// (validés par le schéma mais non persistés) ; l'utilisateur approuve ensuite
+/// tout ou partie des changements avant application via `apply`.
+#[derive(Debug, Default)]
+pub struct ArtifactOverlay {
+    pending: SyncMutex<OrderedMap<String, PendingChange>>,
+}
+
+impl ArtifactOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(space: &str, db: &str, collection: &str, id: &str) -> String {
+        format!("{}/{}/{}/{}", space, db, collection, id)
+    }
+
+    /// Lecture "à travers" la surcouche : l'état simulé prime sur la base.
+    pub async fn read(
+        &self,
+        manager: &CollectionsManager<'_>,
+        collection: &str,
+        id: &str,
+    ) -> RaiseResult<Option<JsonValue>> {
+        let key = Self::key(&manager.space, &manager.db, collection, id);
+        if let Some(change) = self.lock().get(&key) {
+            return Ok(Some(change.after.clone()));
+        }
+        manager.get_document(collection, id).await
+    }
+
+    /// Capture une écriture : résolution des références et validation de schéma
+    /// comme `insert_with_schema`, sans toucher au stockage.
+    pub async fn stage(
+        &self,
+        manager: &CollectionsManager<'_>,
+        collection: &str,
+        artifact: CreatedArtifact,
+        doc: JsonValue,
+    ) -> RaiseResult<PendingChange> {
+        let mut doc = manager.resolve_document_references(collection, doc).await?;
+        manager.prepare_document(collection, &mut doc).await?;
+
+        let key = Self::key(&manager.space, &manager.db, collection, &artifact.id);
+        // L'état d'origine reste celui de la base, même après plusieurs écritures simulées
+        let staged_before = self.lock().get(&key).map(|c| c.before.clone());
+        let before = match staged_before {
+            Some(b) => b,
+            None => manager.get_document(collection, &artifact.id).await?,
+        };
+
+        let change = PendingChange {
+            kind: if before.is_some() {
+                ChangeKind::Update
+            } else {
+                ChangeKind::Create
+            },
+            diff: json_diff(before.as_ref().unwrap_or(&JsonValue::Null), &doc),
+            artifact,
+            space: manager.space.clone(),
+            db: manager.db.clone(),
+            collection: collection.to_string(),
+            before,
+            after: doc,
+        };
+        self.lock().insert(key, change.clone());
+        Ok(change)
+    }
+
+    /// Changements en attente (triés par espace, base, collection puis ID).
+    pub fn preview(&self) -> Vec<PendingChange> {
+        self.lock().values().cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub fn discard(&self) {
+        self.lock().clear();
+    }
+
+    /// Applique les changements approuvés (`None` = tous) puis les retire de la surcouche.
+    /// Chaque base cible est écrite dans une transaction unique : tout ou rien.
+    pub async fn apply(
+        &self,
+        storage: &StorageEngine,
+        approved_ids: Option<&[String]>,
+    ) -> RaiseResult<Vec<CreatedArtifact>> {
+        let mut by_db: OrderedMap<(String, String), Vec<(String, PendingChange)>> =
+            OrderedMap::new();
+        for (key, change) in self.lock().iter() {
+            if approved_ids.is_none_or(|ids| ids.contains(&change.artifact.id)) {
+                by_db
+                    .entry((change.space.clone(), change.db.clone()))
+                    .or_default()
+                    .push((key.clone(), change.clone()));
+            }
+        }
+
+        let mut applied = Vec::new();
+        for ((space, db), changes) in by_db {
+            let requests = changes
+                .iter()
+                .map(|(_, c)| match c.kind {
+                    ChangeKind::Create => TransactionRequest::Insert {
+                        collection: c.collection.clone(),
+                        id: Some(c.artifact.id.clone()),
+                        document: c.after.clone(),
+                    },
+                    ChangeKind::Update => TransactionRequest::Update {
+                        collection: c.collection.clone(),
+                        id: Some(c.artifact.id.clone()),
+                        handle: None,
+                        document: c.after.clone(),
+                    },
+                })
+                .collect();
+
+            TransactionManager::new(storage, &space, &db)
+                .execute_smart(requests)
+                .await?;
+
+            let touched = changes
+                .iter()
+                .map(|(_, c)| match c.kind {
+                    ChangeKind::Create => ElementChange::created(&c.collection, &c.artifact.id),
+                    ChangeKind::Update => {
+                        ElementChange::updated(&c.collection, &c.artifact.id, &c.after)
+                    }
+                })
+                .collect();
+            revalidate_after_commit(storage, &space, &db, touched).await;
+
+            let mut pending = self.lock();
+            for (key, change) in changes {
+                pending.remove(&key);
+                applied.push(change.artifact);
+            }
+        }
+
+        user_success!(
+            "AI_DRY_RUN_APPLIED",
+            json_value!({ "applied": applied.len(), "remaining": self.lock().len() })
+        );
+        Ok(applied)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, OrderedMap<String, PendingChange>> {
+        self.pending.lock().unwrap_or_else(|p| p.into_inner())
+    }
+}
+
+/// Diff structurel champ à champ (pointeurs JSON). Les tableaux sont comparés en bloc.
+pub fn json_diff(before: &JsonValue, after: &JsonValue) -> Vec<FieldDiff> {
+    let empty = JsonValue::Object(JsonObject::new());
+    // Création : chaque champ du document apparaît comme ajouté
+    let before = if before.is_null() && after.is_object() {
+        &empty
+    } else {
+        before
+    };
+    let mut diffs = Vec::new();
+    diff_node("", before, after, &mut diffs);
+    diffs
+}
+
+fn diff_node(path: &str, before: &JsonValue, after: &JsonValue, out: &mut Vec<FieldDiff>) {
+    match (before, after) {
+        (JsonValue::Object(b), JsonValue::Object(a)) => {
+            for (key, a_val) in a {
+                let child = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                match b.get(key) {
+                    Some(b_val) => diff_node(&child, b_val, a_val, out),
+                    None => out.push(FieldDiff {
+                        path: child,
+                        before: None,
+                        after: Some(a_val.clone()),
+                    }),
+                }
+            }
+            for (key, b_val) in b {
+                if !a.contains_key(key) {
+                    out.push(FieldDiff {
+                        path: format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1")),
+                        before: Some(b_val.clone()),
+                        after: None,
+                    });
+                }
+            }
+        }
+        _ if before != after => out.push(FieldDiff {
+            path: path.to_string(),
+            before: (!before.is_null()).then(|| before.clone()),
+            after: (!after.is_null()).then(|| after.clone()),
+        }),
+        _ => {}
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    fn artifact(id: &str) -> CreatedArtifact {
+        CreatedArtifact {
+            id: id.into(),
+            name: id.into(),
+            layer: "SA".into(),
+            element_type: "SystemFunction".into(),
+            path: format!("ref:elements:id:{}", id),
+        }
+    }
+
+    #[test]
+    fn test_json_diff_reports_added_changed_removed() {
+        let before =
+            json_value!({ "name": "Brake", "mass": 10, "legacy": true, "dims": { "w": 1 } });
+        let after = json_value!({ "name": "Brake", "mass": 12, "dims": { "w": 1, "h": 2 } });
+        let diff = json_diff(&before, &after);
+
+        let paths: Vec<&str> = diff.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(diff.len(), 3);
+        assert!(paths.contains(&"/mass"));
+        assert!(paths.contains(&"/dims/h"));
+        assert!(paths.contains(&"/legacy"));
+
+        let created = json_diff(&JsonValue::Null, &json_value!({ "a": 1 }));
+        assert_eq!(created[0].path, "/a");
+        assert_eq!(created[0].before, None);
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_overlay_captures_then_applies_only_approved() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        let schema = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager.create_collection("elements", &schema).await?;
+        manager
+            .insert_raw("elements", &json_value!({ "_id": "f1", "name": "Old" }))
+            .await?;
+
+        let overlay = ArtifactOverlay::new();
+        let update = overlay
+            .stage(
+                &manager,
+                "elements",
+                artifact("f1"),
+                json_value!({ "_id": "f1", "name": "New" }),
+            )
+            .await?;
+        assert_eq!(update.kind, ChangeKind::Update);
+        assert!(update.diff.iter().any(|d| d.path == "/name"));
+
+        overlay
+            .stage(
+                &manager,
+                "elements",
+                artifact("f2"),
+                json_value!({ "_id": "f2", "name": "Fresh" }),
+            )
+            .await?;
+
+        // Rien n'a touché la base ; la lecture à travers la surcouche voit l'état simulé
+        assert_eq!(
+            manager.get_document("elements", "f1").await?.unwrap()["name"],
+            "Old"
+        );
+        assert!(manager.get_document("elements", "f2").await?.is_none());
+        assert_eq!(
+            overlay.read(&manager, "elements", "f1").await?.unwrap()["name"],
+            "New"
+        );
+
+        let applied = overlay
+            .apply(&sandbox.storage, Some(&["f2".to_string()]))
+            .await?;
+        assert_eq!(applied.len(), 1);
+        assert!(manager.get_document("elements", "f2").await?.is_some());
+        assert_eq!(
+            manager.get_document("elements", "f1").await?.unwrap()["name"],
+            "Old"
+        );
+        assert_eq!(overlay.preview().len(), 1);
+
+        overlay.discard();
+        assert!(overlay.is_empty());
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
 // FICHIER : src-tauri/src/ai/agents/tools.rs
 
+use super::coordination::WriteDecision;
 use super::{AgentContext, AgentSession, CreatedArtifact};
-use crate::ai::protocols::mcp::{McpTool, McpToolCall};
+use crate::ai::assurance::xai::ToolCallKind;
+use crate::ai::protocols::mcp::{McpTool, McpToolCall, McpToolResult};
 use crate::ai::tools::QueryDbTool;
 use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::transactions::TransactionRequest;
+use crate::model_engine::validators::incremental::{revalidate_after_commit, ElementChange};
 use crate::utils::data::config::AppConfig;
 use crate::utils::prelude::*;
 
@@ -26,7 +31,31 @@ pub fn extract_json_from_llm(response: &str) -> String {
     }
 }
 
-///  Sauvegarde en lot des artefacts via `insert_with_schema` pour garantir la validation
+/// Exécute un outil MCP pour le compte de l'agent et consigne l'appel dans la trame XAI active.
+pub async fn invoke_tool(
+    ctx: &AgentContext,
+    tool: &dyn McpTool,
+    call: McpToolCall,
+    kind: ToolCallKind,
+) -> McpToolResult {
+    let started = TimeInstant::now();
+    let (name, arguments) = (call.name.clone(), call.arguments.clone());
+    let result = tool.execute(call).await;
+    ctx.record_tool_call(
+        &name,
+        kind,
+        &arguments,
+        &result.content,
+        started,
+        result.is_error,
+    );
+    result
+}
+
+///  Sauvegarde en lot des artefacts dans une transaction unique (validation de schéma incluse).
+///  En mode dry-run (`ctx.dry_run`), les documents validés sont capturés dans la surcouche.
+///  Hors dry-run, un élément verrouillé par un autre acteur ou modifié en parallèle n'est pas
+///  écrit : le conflit est suspendu dans `ctx.locks` pour arbitrage humain.
 pub async fn save_artifacts_batch(
     ctx: &AgentContext,
     docs: Vec<JsonValue>,
@@ -73,6 +102,7 @@ pub async fn save_artifacts_batch(
     let target_manager = CollectionsManager::new(&ctx.db, active_domain, active_db);
 
     // 3. Traitement itératif des documents (Validation DDL + JSON-LD via la Forteresse)
+    let mut staged: Vec<(String, JsonValue)> = Vec::new();
     for mut doc in docs {
         let doc_id = match doc
             .get("_id")
@@ -117,33 +147,130 @@ pub async fn save_artifacts_batch(
             }
         }
 
-        // 🎯 STRICT : Utilisation de insert_with_schema pour forcer le passage par la "Forteresse"
-        match target_manager.insert_with_schema(&collection, doc).await {
-            Ok(_) => {
-                artifacts.push(CreatedArtifact {
-                    id: doc_id.clone(),
-                    name,
-                    layer: layer.to_uppercase(),
-                    element_type,
-                    path: format!("ref:{}:id:{}", collection, doc_id),
-                });
+        let artifact = CreatedArtifact {
+            id: doc_id.clone(),
+            name,
+            layer: layer.to_uppercase(),
+            element_type,
+            path: format!("ref:{}:id:{}", collection, doc_id),
+        };
+
+        // 🎯 DRY-RUN : même validation, mais l'écriture reste dans la surcouche mémoire
+        match &ctx.dry_run {
+            Some(overlay) => {
+                match overlay
+                    .stage(&target_manager, &collection, artifact.clone(), doc)
+                    .await
+                {
+                    Ok(_) => artifacts.push(artifact),
+                    Err(e) => user_warn!(
+                        "WARN_ARTIFACT_SAVE_FAILED",
+                        json_value!({
+                            "id": doc_id,
+                            "collection": collection,
+                            "error": e.to_string()
+                        })
+                    ),
+                }
             }
-            Err(e) => {
-                user_warn!(
-                    "WARN_ARTIFACT_SAVE_FAILED",
-                    json_value!({
-                        "id": doc_id,
-                        "collection": collection,
-                        "error": e.to_string()
-                    })
-                );
+            None => {
+                match ctx
+                    .locks
+                    .prepare_write(
+                        &target_manager,
+                        &collection,
+                        &ctx.agent_id,
+                        artifact.clone(),
+                        doc,
+                    )
+                    .await?
+                {
+                    WriteDecision::Proceed(doc) => {
+                        staged.push((collection, doc));
+                        artifacts.push(artifact);
+                    }
+                    WriteDecision::Conflict(_) => continue,
+                }
             }
         }
     }
 
+    // 4. Écriture atomique : composant, fonctions et échanges passent ou échouent ensemble
+    if !staged.is_empty() {
+        let changes = staged
+            .iter()
+            .filter_map(|(col, doc)| {
+                doc["_id"]
+                    .as_str()
+                    .map(|id| ElementChange::created(col, id))
+            })
+            .collect();
+        let written = json_value!(staged
+            .iter()
+            .map(|(col, doc)| json_value!({ "collection": col, "_id": doc["_id"] }))
+            .collect::<Vec<_>>());
+        let started = TimeInstant::now();
+        let locked: Vec<(String, String)> = staged
+            .iter()
+            .filter_map(|(col, doc)| doc["_id"].as_str().map(|id| (col.clone(), id.to_string())))
+            .collect();
+        let outcome = commit_artifacts_atomically(&target_manager, staged).await;
+        for (col, id) in &locked {
+            ctx.locks
+                .release(active_domain, active_db, col, id, &ctx.agent_id);
+        }
+        ctx.record_tool_call(
+            "commit_artifacts",
+            ToolCallKind::DbWrite,
+            &written,
+            &json_value!({ "committed": outcome.is_ok() }),
+            started,
+            outcome.is_err(),
+        );
+        outcome?;
+        revalidate_after_commit(&ctx.db, active_domain, active_db, changes).await;
+    }
+
     Ok(artifacts)
 }
 
+/// Persiste un lot d'artefacts dans une seule transaction json_db (WAL + undo log).
+/// Les références `ref:` entre artefacts du même lot sont résolues en mémoire ;
+/// au moindre échec, rien n'est écrit.
+pub async fn commit_artifacts_atomically(
+    manager: &CollectionsManager<'_>,
+    staged: Vec<(String, JsonValue)>,
+) -> RaiseResult<()> {
+    let ids: Vec<String> = staged
+        .iter()
+        .filter_map(|(_, doc)| doc["_id"].as_str().map(str::to_string))
+        .collect();
+
+    let requests = staged
+        .into_iter()
+        .map(|(collection, document)| TransactionRequest::Insert {
+            id: document["_id"].as_str().map(str::to_string),
+            collection,
+            document,
+        })
+        .collect();
+
+    let tx_manager = TransactionManager::new(manager.storage, &manager.space, &manager.db);
+    if let Err(e) = tx_manager.execute_smart(requests).await {
+        raise_error!(
+            "ERR_AGENT_ARTIFACTS_TX_ROLLBACK",
+            error = e,
+            context = json_value!({
+                "space": manager.space,
+                "db": manager.db,
+                "artifacts": ids,
+                "hint": "Aucun artefact du lot n'a été persisté."
+            })
+        );
+    }
+    Ok(())
+}
+
 /// Interroge le Knowledge Graph système
 pub async fn query_knowledge_graph(
     ctx: &AgentContext,
@@ -161,7 +288,7 @@ pub async fn query_knowledge_graph(
         json_value!({ "reference": reference, "as_rdf": as_rdf }),
     );
 
-    let result = tool.execute(call).await;
+    let result = invoke_tool(ctx, &tool, call, ToolCallKind::DbQuery).await;
 
     if result.is_error {
         // 🎯 FIX : Utilisation directe de la macro divergente
@@ -205,7 +332,7 @@ pub async fn load_session(ctx: &AgentContext) -> RaiseResult<AgentSession> {
         json_value!({ "reference": format!("ref:session_agents:handle:{}", handle_slug), "as_rdf": false }),
     );
 
-    let result = tool.execute(call).await;
+    let result = invoke_tool(ctx, &tool, call, ToolCallKind::DbQuery).await;
     let mut session = AgentSession::new(&ctx.session_id, &ctx.agent_id);
 
     if !result.is_error {
@@ -253,7 +380,19 @@ pub async fn save_session(ctx: &AgentContext, session: &AgentSession) -> RaiseRe
         "updated_at": UtcClock::now().to_rfc3339()
     });
 
-    if let Err(e) = manager.upsert_document("session_agents", session_doc).await {
+    let started = TimeInstant::now();
+    let outcome = manager
+        .upsert_document("session_agents", session_doc.clone())
+        .await;
+    ctx.record_tool_call(
+        "save_session",
+        ToolCallKind::DbWrite,
+        &session_doc,
+        &json_value!({ "saved": outcome.is_ok() }),
+        started,
+        outcome.is_err(),
+    );
+    if let Err(e) = outcome {
         // 🎯 FIX : Divergence pure sans enveloppe
         raise_error!(
             "ERR_SESSION_DB_SAVE_FAIL",
@@ -285,4 +424,60 @@ Voici l'analyse demandée :
 "#;
         assert_eq!(extract_json_from_llm(input), "{\"status\": \"ok\"}");
     }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_commit_artifacts_atomically_all_or_nothing() -> RaiseResult<()> {
+        let sandbox = crate::utils::testing::DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        let schema = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager.create_collection("elements", &schema).await?;
+
+        // Composant + fonction allouée : la référence est résolue dans la transaction
+        commit_artifacts_atomically(
+            &manager,
+            vec![
+                (
+                    "elements".into(),
+                    json_value!({ "_id": "comp-1", "handle": "brake_ctrl" }),
+                ),
+                (
+                    "elements".into(),
+                    json_value!({ "_id": "fn-1", "allocated_to": "ref:elements:handle:brake_ctrl" }),
+                ),
+            ],
+        )
+        .await?;
+        let function = manager.get_document("elements", "fn-1").await?.unwrap();
+        assert_eq!(function["allocated_to"], "comp-1");
+
+        // Un artefact invalide fait échouer tout le lot
+        let result = commit_artifacts_atomically(
+            &manager,
+            vec![
+                ("elements".into(), json_value!({ "_id": "fn-2" })),
+                (
+                    "ghosts".into(),
+                    json_value!({
+                        "_id": "ghost-1",
+                        "$schema": format!("db://{}/{}/schemas/v1/missing.schema.json", manager.space, manager.db)
+                    }),
+                ),
+            ],
+        )
+        .await;
+        let Err(AppError::Structured(data)) = result else {
+            panic!("Le lot aurait dû être annulé");
+        };
+        assert_eq!(data.code, "ERR_AGENT_ARTIFACTS_TX_ROLLBACK");
+        assert!(manager.get_document("elements", "fn-2").await?.is_none());
+        Ok(())
+    }
 }
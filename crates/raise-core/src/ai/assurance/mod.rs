@@ -1,11 +1,13 @@
 // FICHIER : src-tauri/src/ai/assurance/mod.rs
 
 pub mod health;
+pub mod model_card;
 pub mod quality;
 pub mod xai;
 
+pub use model_card::{ModelCard, ModelCardFormat, ModelCardGenerator};
 pub use quality::{QualityReport, QualityStatus};
-pub use xai::{XaiFrame, XaiMethod};
+pub use xai::{ToolCallKind, ToolInvocation, XaiFrame, XaiMethod};
 
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::utils::prelude::*; // 🎯 Façade Unique
@@ -115,7 +117,40 @@ pub async fn execute_certified<A: crate::ai::agents::Agent>(
     ctx: &crate::ai::agents::AgentContext,
     intent: &crate::ai::agents::intent_classifier::EngineeringIntent,
 ) -> RaiseResult<Option<crate::ai::agents::AgentResult>> {
-    let result = agent.process(ctx, intent).await?;
+    // Trame active : reçoit l'audit des appels d'outils effectués pendant le traitement
+    let active = match &ctx.xai_frame {
+        Some(frame) => frame.clone(),
+        None => {
+            let mut frame = XaiFrame::new(
+                agent.id(),
+                XaiMethod::ChainOfThought,
+                xai::ExplanationScope::Local,
+            );
+            frame.input_snapshot = format!("{:?}", intent);
+            SharedRef::new(SyncMutex::new(frame))
+        }
+    };
+    let audited_ctx = ctx.clone().with_xai_frame(active.clone());
+    let started = TimeInstant::now();
+
+    let mut result = agent.process(&audited_ctx, intent).await?;
+
+    if let Some(res) = result.as_mut() {
+        let audited = match active.lock() {
+            Ok(frame) => Some(frame.clone()),
+            Err(_) => None,
+        };
+        match (res.xai_frame.as_mut(), audited) {
+            // L'agent a produit sa propre trame : on y rattache la provenance des outils
+            (Some(frame), Some(audited)) => frame.tool_calls.extend(audited.tool_calls),
+            (None, Some(mut audited)) if !audited.tool_calls.is_empty() => {
+                audited.predicted_output = res.message.clone();
+                audited.computation_time_ms = started.elapsed().as_millis() as u64;
+                res.xai_frame = Some(audited);
+            }
+            _ => {}
+        }
+    }
 
     if let Some(res) = &result {
         let config = AppConfig::get();
@@ -129,7 +164,8 @@ pub async fn execute_certified<A: crate::ai::agents::Agent>(
             let _ = self::persistence::save_xai_frame(&sys_manager, frame).await;
         }
 
-        if !res.artifacts.is_empty() {
+        // En simulation, aucun artefact n'est encore en base : pas de rapport qualité
+        if !res.artifacts.is_empty() && ctx.dry_run.is_none() {
             let report =
                 self::QualityReport::new(&ctx.paths.domain_root.to_string_lossy(), "active_db");
             let _ = self::persistence::save_quality_report(&sys_manager, &report).await;
@@ -243,6 +279,8 @@ mod tests {
                 artifacts: vec![artifact],
                 outgoing_message: None,
                 xai_frame: Some(frame),
+                pending_changes: vec![],
+                conflicts: vec![],
             }))
         }
     }
@@ -0,0 +1,702 @@
+// FICHIER : src-tauri/src/ai/assurance/model_card.rs
+
+//! Fiche modèle (model card) d'un adaptateur LoRA : provenance des données d'entraînement,
+//! paramètres du run, évaluations issues des `QualityReport`, usage prévu et limites.
+//! La fiche est produite à chaque entraînement et régénérée quand de nouvelles évaluations
+//! sont rattachées à l'adaptateur.
+
+use super::quality::{QualityMetric, QualityReport, QualityStatus};
+use crate::ai::training::dataset::DatasetProvenance;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
+use crate::utils::prelude::*;
+
+/// Collection des fiches modèle (une par adaptateur).
+pub const MODEL_CARD_COLLECTION: &str = "model_cards";
+/// Fichiers écrits à côté des poids de l'adaptateur.
+pub const MODEL_CARD_JSON: &str = "model_card.json";
+pub const MODEL_CARD_MARKDOWN: &str = "MODEL_CARD.md";
+pub const MODEL_CARD_PDF: &str = "model_card.pdf";
+/// En dessous de ce volume, la généralisation de l'adaptateur est signalée comme non démontrée.
+pub const MIN_REPRESENTATIVE_SAMPLES: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelCardFormat {
+    Json,
+    Markdown,
+    Pdf,
+}
+
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct TrainingRun {
+    pub epochs: usize,
+    pub learning_rate: f64,
+    /// Perte moyenne de la dernière époque.
+    pub final_loss: Option<f64>,
+    pub device: String,
+}
+
+/// Évaluation rattachée à l'adaptateur (résumé d'un `QualityReport`).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct EvaluationSummary {
+    pub report_id: String,
+    pub dataset_version: String,
+    pub timestamp: i64,
+    pub status: QualityStatus,
+    pub global_score: f64,
+    pub metrics: Vec<QualityMetric>,
+}
+
+impl From<&QualityReport> for EvaluationSummary {
+    fn from(report: &QualityReport) -> Self {
+        Self {
+            report_id: report.id.clone(),
+            dataset_version: report.dataset_version.clone(),
+            timestamp: report.timestamp,
+            status: report.overall_status.clone(),
+            global_score: report.global_score,
+            metrics: report.metrics.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ModelCard {
+    /// `model_card:<adaptateur>`
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub adapter: String,
+    pub base_model: Option<String>,
+    /// Chemin des poids (`adapter_model.safetensors`).
+    pub artifact_path: Option<String>,
+    pub generated_at: i64,
+    pub training: Option<TrainingRun>,
+    pub dataset: Option<DatasetProvenance>,
+    /// Évaluations, de la plus ancienne à la plus récente.
+    pub evaluations: Vec<EvaluationSummary>,
+    pub intended_use: Vec<String>,
+    /// Limites déclarées puis limites déduites de la provenance et des évaluations.
+    pub limitations: Vec<String>,
+}
+
+pub struct ModelCardGenerator {
+    adapter: String,
+    base_model: Option<String>,
+    artifact_path: Option<String>,
+    training: Option<TrainingRun>,
+    dataset: Option<DatasetProvenance>,
+    reports: Vec<QualityReport>,
+    intended_use: Vec<String>,
+    limitations: Vec<String>,
+}
+
+impl ModelCardGenerator {
+    pub fn new(adapter: &str) -> Self {
+        Self {
+            adapter: adapter.to_string(),
+            base_model: None,
+            artifact_path: None,
+            training: None,
+            dataset: None,
+            reports: Vec::new(),
+            intended_use: Vec::new(),
+            limitations: Vec::new(),
+        }
+    }
+
+    /// Reprend les éléments d'une fiche existante (régénération après évaluation) ; seules
+    /// les limites déclarées sont conservées, les limites déduites sont recalculées.
+    pub fn from_card(card: &ModelCard) -> Self {
+        let derived = derived_limitations(card.dataset.as_ref(), &card.evaluations);
+        Self {
+            adapter: card.adapter.clone(),
+            base_model: card.base_model.clone(),
+            artifact_path: card.artifact_path.clone(),
+            training: card.training.clone(),
+            dataset: card.dataset.clone(),
+            reports: Vec::new(),
+            intended_use: card.intended_use.clone(),
+            limitations: card
+                .limitations
+                .iter()
+                .filter(|l| !derived.contains(l))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn with_base_model(mut self, base_model: &str) -> Self {
+        self.base_model = Some(base_model.to_string());
+        self
+    }
+
+    pub fn with_artifact_path(mut self, path: &Path) -> Self {
+        self.artifact_path = Some(path.to_string_lossy().replace('\\', "/"));
+        self
+    }
+
+    pub fn with_training(mut self, training: TrainingRun) -> Self {
+        self.training = Some(training);
+        self
+    }
+
+    pub fn with_dataset(mut self, dataset: DatasetProvenance) -> Self {
+        self.dataset = Some(dataset);
+        self
+    }
+
+    pub fn with_quality_reports(mut self, reports: Vec<QualityReport>) -> Self {
+        self.reports.extend(reports);
+        self
+    }
+
+    pub fn with_intended_use(mut self, usage: &str) -> Self {
+        self.intended_use.push(usage.to_string());
+        self
+    }
+
+    pub fn with_limitation(mut self, limitation: &str) -> Self {
+        self.limitations.push(limitation.to_string());
+        self
+    }
+
+    pub fn generate(self) -> ModelCard {
+        let mut evaluations: Vec<EvaluationSummary> =
+            self.reports.iter().map(EvaluationSummary::from).collect();
+        evaluations.sort_by_key(|e| e.timestamp);
+
+        let mut intended_use = self.intended_use;
+        if intended_use.is_empty() {
+            let domain = self
+                .dataset
+                .as_ref()
+                .map(|d| d.domain.as_str())
+                .unwrap_or("général");
+            intended_use.push(format!(
+                "Assistance à l'ingénierie système sur le domaine « {} » : les réponses sont des propositions soumises à revue humaine.",
+                domain
+            ));
+        }
+
+        let mut limitations = self.limitations;
+        limitations.extend(derived_limitations(self.dataset.as_ref(), &evaluations));
+
+        ModelCard {
+            id: format!("model_card:{}", self.adapter),
+            adapter: self.adapter,
+            base_model: self.base_model,
+            artifact_path: self.artifact_path,
+            generated_at: UtcClock::now().timestamp(),
+            training: self.training,
+            dataset: self.dataset,
+            evaluations,
+            intended_use,
+            limitations,
+        }
+    }
+}
+
+/// Limites déduites : provenance absente ou réduite, évaluations manquantes ou hors seuil.
+fn derived_limitations(
+    dataset: Option<&DatasetProvenance>,
+    evaluations: &[EvaluationSummary],
+) -> Vec<String> {
+    let mut limitations = Vec::new();
+    match dataset {
+        None => limitations.push("Provenance des données d'entraînement non documentée.".into()),
+        Some(d) if d.samples < MIN_REPRESENTATIVE_SAMPLES => limitations.push(format!(
+            "Jeu d'entraînement réduit ({} exemples) : généralisation non démontrée.",
+            d.samples
+        )),
+        Some(_) => {}
+    }
+
+    let Some(latest) = evaluations.last() else {
+        limitations.push("Aucune évaluation qualité rattachée : performances non mesurées.".into());
+        return limitations;
+    };
+    if latest.status == QualityStatus::Fail {
+        limitations.push(
+            "Dernière évaluation en échec critique : usage hors expérimentation déconseillé."
+                .into(),
+        );
+    }
+    for metric in latest.metrics.iter().filter(|m| !m.passed) {
+        limitations.push(format!(
+            "Métrique « {} » hors seuil ({}){}.",
+            metric.name,
+            metric.value,
+            if metric.is_critical { ", critique" } else { "" }
+        ));
+    }
+    limitations
+}
+
+impl ModelCard {
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# Fiche modèle : {}\n\n", self.adapter);
+        md.push_str(&format!(
+            "- Modèle de base : {}\n",
+            self.base_model.as_deref().unwrap_or("non renseigné")
+        ));
+        if let Some(path) = &self.artifact_path {
+            md.push_str(&format!("- Poids : `{}`\n", path));
+        }
+        md.push_str(&format!(
+            "- Générée le : {}\n\n",
+            format_timestamp(self.generated_at)
+        ));
+
+        md.push_str("## Usage prévu\n\n");
+        for usage in &self.intended_use {
+            md.push_str(&format!("- {}\n", usage));
+        }
+
+        md.push_str("\n## Entraînement\n\n");
+        match &self.training {
+            Some(t) => {
+                md.push_str(&format!(
+                    "- Époques : {}\n- Taux d'apprentissage : {}\n- Matériel : {}\n",
+                    t.epochs, t.learning_rate, t.device
+                ));
+                if let Some(loss) = t.final_loss {
+                    md.push_str(&format!("- Perte finale : {:.4}\n", loss));
+                }
+            }
+            None => md.push_str("Paramètres d'entraînement non renseignés.\n"),
+        }
+
+        md.push_str("\n## Données d'entraînement\n\n");
+        match &self.dataset {
+            Some(d) => {
+                md.push_str(&format!(
+                    "- Domaine : {}\n- Source : `{}/{}`\n- Exemples : {}\n- Empreinte : `{}`\n- Extraites le : {}\n",
+                    d.domain,
+                    d.space,
+                    d.db,
+                    d.samples,
+                    d.fingerprint,
+                    format_timestamp(d.extracted_at)
+                ));
+                if !d.sources.is_empty() {
+                    md.push_str("\n| Collection | Exemples |\n| --- | --- |\n");
+                    for (collection, count) in &d.sources {
+                        md.push_str(&format!("| {} | {} |\n", collection, count));
+                    }
+                }
+            }
+            None => md.push_str("Provenance non documentée.\n"),
+        }
+
+        md.push_str("\n## Évaluations\n\n");
+        if self.evaluations.is_empty() {
+            md.push_str("Aucune évaluation rattachée.\n");
+        }
+        for eval in &self.evaluations {
+            md.push_str(&format!(
+                "### {} ({:?}, score {:.1})\n\nJeu d'évaluation : {}\n\n| Métrique | Catégorie | Valeur | Seuils | Résultat |\n| --- | --- | --- | --- | --- |\n",
+                format_timestamp(eval.timestamp),
+                eval.status,
+                eval.global_score,
+                eval.dataset_version
+            ));
+            for m in &eval.metrics {
+                let bounds = match (m.threshold_min, m.threshold_max) {
+                    (Some(min), Some(max)) => format!("[{}, {}]", min, max),
+                    (Some(min), None) => format!(">= {}", min),
+                    (None, Some(max)) => format!("<= {}", max),
+                    (None, None) => "-".to_string(),
+                };
+                md.push_str(&format!(
+                    "| {}{} | {:?} | {} | {} | {} |\n",
+                    m.name,
+                    if m.is_critical { " (critique)" } else { "" },
+                    m.category,
+                    m.value,
+                    bounds,
+                    if m.passed { "OK" } else { "KO" }
+                ));
+            }
+            md.push('\n');
+        }
+
+        md.push_str("\n## Limites\n\n");
+        if self.limitations.is_empty() {
+            md.push_str("Aucune limite identifiée.\n");
+        }
+        for limitation in &self.limitations {
+            md.push_str(&format!("- {}\n", limitation));
+        }
+        md
+    }
+
+    /// Rendu PDF du texte de la fiche (Helvetica, pages A4 paginées).
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let lines: Vec<String> = self
+            .to_markdown()
+            .lines()
+            .filter(|l| !l.starts_with("| ---"))
+            .flat_map(|l| wrap(&plain_text(l), PDF_LINE_CHARS))
+            .collect();
+        render_pdf(&lines)
+    }
+
+    /// Écrit la fiche dans les formats demandés, à côté des poids de l'adaptateur.
+    pub async fn export(
+        &self,
+        dir: &Path,
+        formats: &[ModelCardFormat],
+    ) -> RaiseResult<Vec<PathBuf>> {
+        fs::ensure_dir_async(dir).await?;
+        let mut written = Vec::new();
+        for format in formats {
+            let path = match format {
+                ModelCardFormat::Json => {
+                    let path = dir.join(MODEL_CARD_JSON);
+                    fs::write_json_atomic_async(&path, self).await?;
+                    path
+                }
+                ModelCardFormat::Markdown => {
+                    let path = dir.join(MODEL_CARD_MARKDOWN);
+                    fs::write_async(&path, self.to_markdown()).await?;
+                    path
+                }
+                ModelCardFormat::Pdf => {
+                    let path = dir.join(MODEL_CARD_PDF);
+                    fs::write_async(&path, self.to_pdf()).await?;
+                    path
+                }
+            };
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+// --- PERSISTANCE ---
+
+/// Enregistre (ou remplace) la fiche de l'adaptateur dans la collection `model_cards`.
+pub async fn save_model_card(
+    manager: &CollectionsManager<'_>,
+    card: &ModelCard,
+) -> RaiseResult<()> {
+    if manager
+        .collection_schema_uri(MODEL_CARD_COLLECTION)
+        .await
+        .is_none()
+    {
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager
+            .create_collection(MODEL_CARD_COLLECTION, &schema_uri)
+            .await?;
+    }
+    manager
+        .upsert_document(MODEL_CARD_COLLECTION, json::serialize_to_value(card)?)
+        .await?;
+    Ok(())
+}
+
+/// Rapports qualité dont `model_id` désigne l'adaptateur.
+pub async fn quality_reports_for(
+    manager: &CollectionsManager<'_>,
+    adapter: &str,
+) -> RaiseResult<Vec<QualityReport>> {
+    let settings =
+        AppConfig::get_runtime_settings(manager, "ref:components:handle:ai_assurance").await?;
+    let Some(collection) = settings["quality_collection"].as_str() else {
+        raise_error!(
+            "ERR_ASSURANCE_CONFIG",
+            error = "Paramètre 'quality_collection' manquant dans service_settings."
+        );
+    };
+    if manager.collection_schema_uri(collection).await.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let mut query = Query::new(collection);
+    query.filter = Some(QueryFilter {
+        operator: FilterOperator::And,
+        conditions: vec![Condition::eq("model_id", json_value!(adapter))],
+    });
+    let result = QueryEngine::new(manager).execute_query(query).await?;
+    result
+        .documents
+        .into_iter()
+        .map(json::deserialize_from_value)
+        .collect()
+}
+
+/// Régénère la fiche d'un adaptateur avec ses évaluations actuelles et la réécrit
+/// (base et fichiers du dossier de l'adaptateur).
+pub async fn refresh_model_card(
+    manager: &CollectionsManager<'_>,
+    adapter_dir: &Path,
+    formats: &[ModelCardFormat],
+) -> RaiseResult<ModelCard> {
+    let path = adapter_dir.join(MODEL_CARD_JSON);
+    if !fs::exists_async(&path).await {
+        raise_error!(
+            "ERR_ASSURANCE_MODEL_CARD_NOT_FOUND",
+            error = "Aucune fiche modèle dans le dossier de l'adaptateur.",
+            context = json_value!({ "path": path.to_string_lossy() })
+        );
+    }
+    let previous: ModelCard = fs::read_json_async(&path).await?;
+    let reports = quality_reports_for(manager, &previous.adapter).await?;
+    let card = ModelCardGenerator::from_card(&previous)
+        .with_quality_reports(reports)
+        .generate();
+
+    save_model_card(manager, &card).await?;
+    card.export(adapter_dir, formats).await?;
+    Ok(card)
+}
+
+// --- RENDU PDF ---
+
+const PDF_LINE_CHARS: usize = 95;
+const PDF_LINES_PER_PAGE: usize = 60;
+
+fn format_timestamp(ts: i64) -> String {
+    UtcTimestamp::from_timestamp(ts, 0)
+        .map(|d| d.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| ts.to_string())
+}
+
+/// Ligne Markdown réduite à son texte (titres, puces, code et tableaux).
+fn plain_text(line: &str) -> String {
+    let trimmed = line.trim_start_matches('#').trim_start();
+    let text = if line.starts_with('|') {
+        trimmed
+            .trim_matches('|')
+            .split('|')
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("   ")
+    } else {
+        trimmed.to_string()
+    };
+    text.replace('`', "")
+}
+
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in line.split(' ') {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    lines.push(current);
+    lines
+}
+
+/// Chaîne littérale PDF en WinAnsiEncoding (caractères hors Latin-1 remplacés par `?`).
+fn pdf_string(text: &str) -> Vec<u8> {
+    let mut out = vec![b'('];
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => out.extend([b'\\', c as u8]),
+            '«' => out.push(0xAB),
+            '»' => out.push(0xBB),
+            c if (c as u32) < 0x100 => out.push(c as u32 as u8),
+            _ => out.push(b'?'),
+        }
+    }
+    out.push(b')');
+    out
+}
+
+fn render_pdf(lines: &[String]) -> Vec<u8> {
+    let pages: Vec<&[String]> = if lines.is_empty() {
+        vec![lines]
+    } else {
+        lines.chunks(PDF_LINES_PER_PAGE).collect()
+    };
+    // 1 catalogue, 2 arbre des pages, 3 police, puis (page, contenu) par page
+    let page_ids: Vec<usize> = (0..pages.len()).map(|i| 4 + 2 * i).collect();
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{} 0 R", id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        )
+        .into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+    ];
+    for (page, id) in pages.iter().zip(&page_ids) {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                id + 1
+            )
+            .into_bytes(),
+        );
+        let mut stream = b"BT /F1 10 Tf 12 TL 50 800 Td\n".to_vec();
+        for line in page.iter() {
+            stream.extend(pdf_string(line));
+            stream.extend(b" '\n");
+        }
+        stream.extend(b"ET");
+        let mut content = format!("<< /Length {} >>\nstream\n", stream.len()).into_bytes();
+        content.extend(stream);
+        content.extend(b"\nendstream");
+        objects.push(content);
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend(format!("{} 0 obj\n", i + 1).into_bytes());
+        pdf.extend(object);
+        pdf.extend(b"\nendobj\n");
+    }
+    let xref = pdf.len();
+    pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).into_bytes());
+    for offset in offsets {
+        pdf.extend(format!("{:010} 00000 n \n", offset).into_bytes());
+    }
+    pdf.extend(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        )
+        .into_bytes(),
+    );
+    pdf
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::assurance::quality::MetricCategory;
+    use crate::utils::testing::DbSandbox;
+
+    fn provenance(samples: usize) -> DatasetProvenance {
+        let mut sources = OrderedMap::new();
+        sources.insert("safety_rules".to_string(), samples);
+        DatasetProvenance {
+            domain: "safety".to_string(),
+            space: "un2".to_string(),
+            db: "_system".to_string(),
+            sources,
+            samples,
+            fingerprint: "abc123".to_string(),
+            extracted_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_model_card_derives_limitations() {
+        let bare = ModelCardGenerator::new("raise-safety-adapter").generate();
+        assert_eq!(bare.id, "model_card:raise-safety-adapter");
+        assert_eq!(bare.limitations.len(), 2);
+        assert_eq!(bare.intended_use.len(), 1);
+
+        let mut report = QualityReport::new("raise-safety-adapter", "eval_v1");
+        report.add_metric(
+            "Accuracy",
+            MetricCategory::Performance,
+            0.7,
+            Some(0.9),
+            None,
+            true,
+        );
+        let card = ModelCardGenerator::new("raise-safety-adapter")
+            .with_dataset(provenance(12))
+            .with_quality_reports(vec![report])
+            .with_limitation("Non qualifié DO-178C.")
+            .generate();
+
+        assert_eq!(card.limitations[0], "Non qualifié DO-178C.");
+        assert!(card.limitations.iter().any(|l| l.contains("12 exemples")));
+        assert!(card
+            .limitations
+            .iter()
+            .any(|l| l.contains("échec critique")));
+        assert!(card.limitations.iter().any(|l| l.contains("Accuracy")));
+        assert!(card.intended_use[0].contains("safety"));
+        assert_eq!(card.evaluations[0].status, QualityStatus::Fail);
+
+        // Régénération : la limite déclarée survit, les limites déduites sont recalculées
+        let refreshed = ModelCardGenerator::from_card(&card).generate();
+        assert_eq!(refreshed.limitations[0], "Non qualifié DO-178C.");
+        assert!(refreshed
+            .limitations
+            .iter()
+            .any(|l| l.contains("Aucune évaluation")));
+        assert!(!refreshed.limitations.iter().any(|l| l.contains("Accuracy")));
+    }
+
+    #[async_test]
+    async fn test_model_card_export_and_persist() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "cards");
+        DbSandbox::mock_db(&manager).await?;
+
+        let card = ModelCardGenerator::new("raise-safety-adapter")
+            .with_base_model("qwen2.5-1.5b")
+            .with_training(TrainingRun {
+                epochs: 3,
+                learning_rate: 1e-4,
+                final_loss: Some(1.25),
+                device: "cpu".to_string(),
+            })
+            .with_dataset(provenance(250))
+            .generate();
+
+        let dir = sandbox
+            .storage
+            .config
+            .data_root
+            .join("lora/raise-safety-adapter");
+        let written = card
+            .export(
+                &dir,
+                &[
+                    ModelCardFormat::Json,
+                    ModelCardFormat::Markdown,
+                    ModelCardFormat::Pdf,
+                ],
+            )
+            .await?;
+        assert_eq!(written.len(), 3);
+
+        let md = fs::read_to_string_async(&dir.join(MODEL_CARD_MARKDOWN)).await?;
+        assert!(md.contains("# Fiche modèle : raise-safety-adapter"));
+        assert!(md.contains("| safety_rules | 250 |"));
+        let pdf = card.to_pdf();
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+
+        save_model_card(&manager, &card).await?;
+        let stored = manager
+            .get_document(MODEL_CARD_COLLECTION, "model_card:raise-safety-adapter")
+            .await?;
+        assert_eq!(
+            stored.map(|d| d["dataset"]["samples"].clone()),
+            Some(json_value!(250))
+        );
+        Ok(())
+    }
+}
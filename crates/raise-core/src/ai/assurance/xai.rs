@@ -38,6 +38,41 @@ pub struct VisualArtifact {
     pub description: String,
 }
 
+/// Nature d'un appel d'outil effectué par un agent.
+#[derive(Debug, Serializable, Deserializable, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCallKind {
+    DbQuery,
+    DbWrite,
+    FileWrite,
+    Mcp,
+}
+
+/// Appel d'outil tracé pendant une décision : seules les empreintes des entrées/sorties
+/// sont conservées (provenance vérifiable sans dupliquer les données).
+#[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
+pub struct ToolInvocation {
+    pub call_id: String,
+    pub tool: String,
+    pub kind: ToolCallKind,
+    pub input_hash: String,
+    pub output_hash: String,
+    pub duration_ms: u64,
+    pub is_error: bool,
+    pub timestamp: i64,
+}
+
+/// Empreinte SHA-256 (hex) d'une charge JSON.
+pub fn payload_hash(payload: &JsonValue) -> String {
+    let mut hasher = CryptoSha256::new();
+    hasher.update(payload.to_string().as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
 /// 🔮 XAI FRAME (Preuve d'Explicabilité)
 /// Documente pourquoi une décision a été prise par un agent.
 #[derive(Debug, Serializable, Deserializable, Clone)]
@@ -55,6 +90,9 @@ pub struct XaiFrame {
     pub fidelity_score: Option<f32>,
     pub computation_time_ms: u64,
     pub meta: UnorderedMap<String, String>,
+    /// Outils invoqués pendant la décision, dans l'ordre d'exécution.
+    #[serde(default)]
+    pub tool_calls: Vec<ToolInvocation>,
 }
 
 impl XaiFrame {
@@ -72,6 +110,7 @@ impl XaiFrame {
             fidelity_score: None,
             computation_time_ms: 0,
             meta: UnorderedMap::new(),
+            tool_calls: Vec::new(),
         }
     }
 
@@ -85,6 +124,27 @@ impl XaiFrame {
         });
     }
 
+    pub fn record_tool_call(
+        &mut self,
+        tool: &str,
+        kind: ToolCallKind,
+        input: &JsonValue,
+        output: &JsonValue,
+        duration: TimeDuration,
+        is_error: bool,
+    ) {
+        self.tool_calls.push(ToolInvocation {
+            call_id: UniqueId::new_v4().to_string(),
+            tool: tool.to_string(),
+            kind,
+            input_hash: payload_hash(input),
+            output_hash: payload_hash(output),
+            duration_ms: duration.as_millis() as u64,
+            is_error,
+            timestamp: UtcClock::now().timestamp(),
+        });
+    }
+
     /// Résumé structuré pour injection dans le contexte d'un Agent (Prompt Engineering)
     pub fn summarize_for_llm(&self) -> String {
         let mut summary = format!(
@@ -102,6 +162,19 @@ impl XaiFrame {
                 f.feature_id, f.raw_value, f.attribution_score
             ));
         }
+
+        if !self.tool_calls.is_empty() {
+            summary.push_str("Outils invoqués :\n");
+            for call in &self.tool_calls {
+                summary.push_str(&format!(
+                    "- {} ({:?}, {} ms){}\n",
+                    call.tool,
+                    call.kind,
+                    call.duration_ms,
+                    if call.is_error { " ❌" } else { "" }
+                ));
+            }
+        }
         summary
     }
 }
@@ -126,4 +199,40 @@ mod tests {
         assert!(summary.contains("Temperature"));
         assert!(summary.contains("0.85"));
     }
+
+    #[test]
+    fn test_tool_calls_are_hashed_and_summarized() -> RaiseResult<()> {
+        let mut frame = XaiFrame::new(
+            "agent_x",
+            XaiMethod::ChainOfThought,
+            ExplanationScope::Local,
+        );
+        let input = json_value!({ "reference": "ref:configs:handle:x" });
+        frame.record_tool_call(
+            "query_db",
+            ToolCallKind::DbQuery,
+            &input,
+            &json_value!({ "data": 1 }),
+            TimeDuration::from_millis(12),
+            false,
+        );
+
+        let call = &frame.tool_calls[0];
+        assert_eq!(call.input_hash, payload_hash(&input));
+        assert_eq!(call.input_hash.len(), 64);
+        assert_eq!(call.duration_ms, 12);
+
+        // Les trames persistées avant l'audit des outils restent lisibles
+        let mut legacy = json::serialize_to_value(&frame)?;
+        if let Some(obj) = legacy.as_object_mut() {
+            obj.remove("tool_calls");
+        }
+        let restored: XaiFrame = json::deserialize_from_value(legacy)?;
+        assert!(restored.tool_calls.is_empty());
+
+        assert!(frame
+            .summarize_for_llm()
+            .contains("query_db (DbQuery, 12 ms)"));
+        Ok(())
+    }
 }
@@ -0,0 +1,713 @@
+// FICHIER : src-tauri/src/ai/context/ingestion.rs
+
+//! Ingestion documentaire pour le RAG : extraction du texte (Markdown, texte brut, PDF,
+//! Office OOXML et OpenDocument), découpage avec chevauchement puis indexation dans le
+//! magasin vectoriel avec les métadonnées de provenance (fichier, format, empreinte).
+//! Un document déjà indexé (même empreinte SHA-256) n'est pas ré-ingéré.
+
+use super::rag::RagRetriever;
+use crate::ai::nlp::splitting;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::utils::prelude::*;
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+/// Taille cible d'un chunk (tokens estimés).
+pub const CHUNK_TOKENS: usize = 512;
+/// Recouvrement entre deux chunks consécutifs (tokens estimés).
+pub const CHUNK_OVERLAP_TOKENS: usize = 64;
+
+/// Formats pris en charge par l'ingestion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentFormat {
+    Text,
+    Markdown,
+    Pdf,
+    Docx,
+    Xlsx,
+    Pptx,
+    /// ODT / ODS / ODP
+    OpenDocument,
+}
+
+impl DocumentFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_string_lossy().to_lowercase();
+        match ext.as_str() {
+            "txt" | "text" | "log" | "csv" | "json" | "yaml" | "yml" | "toml" | "xml" => {
+                Some(Self::Text)
+            }
+            "md" | "markdown" => Some(Self::Markdown),
+            "pdf" => Some(Self::Pdf),
+            "docx" => Some(Self::Docx),
+            "xlsx" => Some(Self::Xlsx),
+            "pptx" => Some(Self::Pptx),
+            "odt" | "ods" | "odp" => Some(Self::OpenDocument),
+            _ => None,
+        }
+    }
+}
+
+/// Fichier indexé (ou reconnu comme déjà présent).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct IngestedFile {
+    pub path: String,
+    pub format: DocumentFormat,
+    pub document_id: String,
+    pub chunks: usize,
+    pub already_indexed: bool,
+}
+
+/// Fichier ignoré, avec la raison.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct SkippedFile {
+    pub path: String,
+    pub reason: String,
+}
+
+/// Bilan d'une ingestion.
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct IngestionReport {
+    pub files: Vec<IngestedFile>,
+    pub skipped: Vec<SkippedFile>,
+    pub total_chunks: usize,
+}
+
+/// Identifiant stable d'un document, dérivé de l'empreinte de son contenu.
+pub fn document_id(bytes: &[u8]) -> (String, String) {
+    let mut hasher = CryptoSha256::new();
+    hasher.update(bytes);
+    let sha256: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    (format!("doc_{}", &sha256[..16]), sha256)
+}
+
+// -------------------------------------------------------------------------
+// 📄 EXTRACTION DU TEXTE
+// -------------------------------------------------------------------------
+
+/// Extrait le texte brut d'un document selon son format.
+pub fn extract_text(bytes: &[u8], format: DocumentFormat) -> RaiseResult<String> {
+    let text = match format {
+        DocumentFormat::Text | DocumentFormat::Markdown => {
+            String::from_utf8_lossy(bytes).into_owned()
+        }
+        DocumentFormat::Pdf => extract_pdf(bytes)?,
+        DocumentFormat::Docx => {
+            let xml = read_zip_entries(bytes, |name| name == "word/document.xml")?;
+            xml_text(&xml.join("\n"), &[b"t"], &[b"p"])?
+        }
+        DocumentFormat::Pptx => {
+            let slides = read_zip_entries(bytes, |name| {
+                name.starts_with("ppt/slides/slide") && name.ends_with(".xml")
+            })?;
+            let mut parts = Vec::new();
+            for slide in slides {
+                parts.push(xml_text(&slide, &[b"t"], &[b"p"])?);
+            }
+            parts.join("\n\n")
+        }
+        DocumentFormat::Xlsx => {
+            let strings = read_zip_entries(bytes, |name| {
+                name == "xl/sharedStrings.xml"
+                    || (name.starts_with("xl/worksheets/sheet") && name.ends_with(".xml"))
+            })?;
+            let mut parts = Vec::new();
+            for xml in strings {
+                parts.push(xml_text(&xml, &[b"t"], &[b"si", b"row"])?);
+            }
+            parts.join("\n")
+        }
+        DocumentFormat::OpenDocument => {
+            let content = read_zip_entries(bytes, |name| name == "content.xml")?;
+            xml_text(&content.join("\n"), &[], &[b"p", b"h"])?
+        }
+    };
+    Ok(normalize_whitespace(&text))
+}
+
+/// Lit les entrées XML d'une archive (OOXML / ODF), dans l'ordre naturel de leurs noms.
+fn read_zip_entries(bytes: &[u8], select: impl Fn(&str) -> bool) -> RaiseResult<Vec<String>> {
+    let mut archive = match zip::ZipArchive::new(std::io::Cursor::new(bytes)) {
+        Ok(a) => a,
+        Err(e) => raise_error!(
+            "ERR_INGEST_ARCHIVE_INVALID",
+            error = e.to_string(),
+            context =
+                json_value!({ "hint": "Document Office corrompu ou protégé par mot de passe ?" })
+        ),
+    };
+    let mut names: Vec<String> = archive
+        .file_names()
+        .filter(|n| select(n))
+        .map(str::to_string)
+        .collect();
+    // slide2.xml avant slide10.xml
+    names.sort_by_key(|n| (n.len(), n.clone()));
+
+    let mut entries = Vec::new();
+    for name in names {
+        let mut entry = match archive.by_name(&name) {
+            Ok(e) => e,
+            Err(e) => raise_error!(
+                "ERR_INGEST_ARCHIVE_ENTRY",
+                error = e.to_string(),
+                context = json_value!({ "entry": name })
+            ),
+        };
+        let mut xml = String::new();
+        if let Err(e) = entry.read_to_string(&mut xml) {
+            raise_error!(
+                "ERR_INGEST_ARCHIVE_ENTRY",
+                error = e.to_string(),
+                context = json_value!({ "entry": name })
+            );
+        }
+        entries.push(xml);
+    }
+    if entries.is_empty() {
+        raise_error!(
+            "ERR_INGEST_ARCHIVE_INVALID",
+            error = "Aucune partie textuelle trouvée dans l'archive."
+        );
+    }
+    Ok(entries)
+}
+
+/// Texte contenu dans les éléments `text_tags` (tous si vide), avec un saut de ligne
+/// à la fermeture des éléments `break_tags`.
+fn xml_text(xml: &str, text_tags: &[&[u8]], break_tags: &[&[u8]]) -> RaiseResult<String> {
+    let mut reader = Reader::from_str(xml);
+    let mut out = String::new();
+    let mut depth = 0usize;
+
+    loop {
+        let inside = text_tags.is_empty() || depth > 0;
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                if text_tags.contains(&e.local_name().as_ref()) {
+                    depth += 1;
+                }
+            }
+            Ok(Event::End(e)) => {
+                let name = e.local_name();
+                if text_tags.contains(&name.as_ref()) {
+                    depth = depth.saturating_sub(1);
+                }
+                if break_tags.contains(&name.as_ref()) {
+                    out.push('\n');
+                }
+            }
+            Ok(Event::Empty(e)) => match e.local_name().as_ref() {
+                b"tab" => out.push('\t'),
+                b"br" | b"line-break" => out.push('\n'),
+                b"s" => out.push(' '),
+                _ => {}
+            },
+            Ok(Event::Text(t)) if inside => match t.decode() {
+                Ok(text) => out.push_str(&text),
+                Err(e) => raise_error!("ERR_INGEST_XML_ENCODING", error = e.to_string()),
+            },
+            Ok(Event::CData(t)) if inside => match t.decode() {
+                Ok(text) => out.push_str(&text),
+                Err(e) => raise_error!("ERR_INGEST_XML_ENCODING", error = e.to_string()),
+            },
+            Ok(Event::GeneralRef(r)) if inside => {
+                if let Ok(Some(c)) = r.resolve_char_ref() {
+                    out.push(c);
+                } else if let Ok(name) = r.decode() {
+                    out.push_str(match name.as_ref() {
+                        "amp" => "&",
+                        "lt" => "<",
+                        "gt" => ">",
+                        "quot" => "\"",
+                        "apos" => "'",
+                        _ => "",
+                    });
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => raise_error!(
+                "ERR_INGEST_XML_PARSE",
+                error = e.to_string(),
+                context = json_value!({ "position": reader.buffer_position() })
+            ),
+            _ => {}
+        }
+    }
+    Ok(out)
+}
+
+/// Extraction du texte d'un PDF : flux décompressés (FlateDecode) puis opérateurs de
+/// texte (`Tj`, `TJ`, `'`, `"`). Les PDF scannés (images seules) ne produisent aucun texte.
+fn extract_pdf(bytes: &[u8]) -> RaiseResult<String> {
+    if !bytes.starts_with(b"%PDF") {
+        raise_error!(
+            "ERR_INGEST_PDF_INVALID",
+            error = "En-tête %PDF absent.",
+            context = json_value!({ "size": bytes.len() })
+        );
+    }
+
+    let mut out = String::new();
+    let mut cursor = 0;
+    while let Some(pos) = find(bytes, b"stream", cursor) {
+        // "endstream" contient aussi "stream"
+        if pos >= 3 && &bytes[pos - 3..pos] == b"end" {
+            cursor = pos + 6;
+            continue;
+        }
+        let mut start = pos + 6;
+        if bytes.get(start) == Some(&b'\r') {
+            start += 1;
+        }
+        if bytes.get(start) == Some(&b'\n') {
+            start += 1;
+        }
+        let Some(end) = find(bytes, b"endstream", start) else {
+            break;
+        };
+        cursor = end + 9;
+
+        let dict_start = bytes[..pos]
+            .windows(2)
+            .rposition(|w| w == b"<<")
+            .unwrap_or(pos);
+        let dict = &bytes[dict_start..pos];
+        let raw = &bytes[start..end];
+
+        let content = if find(dict, b"/FlateDecode", 0).is_some() {
+            match inflate(raw) {
+                Some(data) => data,
+                None => continue,
+            }
+        } else if find(dict, b"/Filter", 0).is_some() {
+            // Images (DCT, JBIG2...) ou filtres non pris en charge
+            continue;
+        } else {
+            raw.to_vec()
+        };
+        let text = pdf_content_text(&content);
+        if !text.trim().is_empty() {
+            out.push_str(&text);
+            out.push('\n');
+        }
+    }
+
+    if out.trim().is_empty() {
+        raise_error!(
+            "ERR_INGEST_PDF_NO_TEXT",
+            error = "Aucun texte extractible (PDF scanné ou polices non standard).",
+            context = json_value!({ "size": bytes.len() })
+        );
+    }
+    Ok(out)
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|p| p + from)
+}
+
+fn inflate(raw: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    if flate2::read::ZlibDecoder::new(raw)
+        .read_to_end(&mut out)
+        .is_ok()
+    {
+        return Some(out);
+    }
+    out.clear();
+    flate2::read::DeflateDecoder::new(raw)
+        .read_to_end(&mut out)
+        .ok()
+        .map(|_| out)
+}
+
+/// Interprète les opérateurs de texte d'un flux de contenu PDF.
+fn pdf_content_text(content: &[u8]) -> String {
+    let mut out = String::new();
+    let mut operands: Vec<u8> = Vec::new();
+    let mut in_array = false;
+    let mut i = 0;
+
+    while i < content.len() {
+        match content[i] {
+            b'(' => {
+                let (string, next) = pdf_literal(content, i);
+                operands.extend(string);
+                i = next;
+            }
+            b'<' if content.get(i + 1) == Some(&b'<') => i += 2,
+            b'<' => {
+                let end = find(content, b">", i).unwrap_or(content.len());
+                let hex: Vec<u8> = content[i + 1..end]
+                    .iter()
+                    .filter(|c| c.is_ascii_hexdigit())
+                    .copied()
+                    .collect();
+                operands.extend(hex.chunks(2).filter_map(|pair| {
+                    u8::from_str_radix(&String::from_utf8_lossy(pair), 16).ok()
+                }));
+                i = end + 1;
+            }
+            b'[' => {
+                in_array = true;
+                i += 1;
+            }
+            b']' => {
+                in_array = false;
+                i += 1;
+            }
+            b'%' => {
+                while i < content.len() && content[i] != b'\n' && content[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            c if c == b'-' || c == b'.' || c.is_ascii_digit() => {
+                let start = i;
+                i += 1;
+                while i < content.len() && (content[i] == b'.' || content[i].is_ascii_digit()) {
+                    i += 1;
+                }
+                // Dans un tableau TJ, un fort crénage négatif sépare deux mots
+                if in_array {
+                    let value: f32 = String::from_utf8_lossy(&content[start..i])
+                        .parse()
+                        .unwrap_or(0.0);
+                    if value < -200.0 {
+                        operands.push(b' ');
+                    }
+                }
+            }
+            c if c.is_ascii_alphabetic() || c == b'\'' || c == b'"' || c == b'*' => {
+                let start = i;
+                i += 1;
+                while i < content.len() && (content[i].is_ascii_alphabetic() || content[i] == b'*')
+                {
+                    i += 1;
+                }
+                match &content[start..i] {
+                    b"Tj" | b"TJ" => out.push_str(&pdf_decode_string(&operands)),
+                    b"'" | b"\"" => {
+                        out.push('\n');
+                        out.push_str(&pdf_decode_string(&operands));
+                    }
+                    b"T*" | b"ET" => out.push('\n'),
+                    b"Td" | b"TD" | b"Tm" => out.push(' '),
+                    _ => {}
+                }
+                operands.clear();
+            }
+            _ => i += 1,
+        }
+    }
+    out
+}
+
+/// Chaîne littérale `( ... )` avec parenthèses imbriquées et séquences d'échappement.
+fn pdf_literal(content: &[u8], open: usize) -> (Vec<u8>, usize) {
+    let mut out = Vec::new();
+    let mut depth = 0;
+    let mut i = open;
+    while i < content.len() {
+        let c = content[i];
+        match c {
+            b'(' => {
+                if depth > 0 {
+                    out.push(c);
+                }
+                depth += 1;
+            }
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return (out, i + 1);
+                }
+                out.push(c);
+            }
+            b'\\' => {
+                i += 1;
+                match content.get(i) {
+                    Some(b'n') => out.push(b'\n'),
+                    Some(b'r') => out.push(b'\r'),
+                    Some(b't') => out.push(b'\t'),
+                    Some(d) if d.is_ascii_digit() => {
+                        let start = i;
+                        while i < content.len() && i < start + 3 && content[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                        let octal = String::from_utf8_lossy(&content[start..i]);
+                        out.push(u8::from_str_radix(&octal, 8).unwrap_or(b'?'));
+                        continue;
+                    }
+                    Some(b'\r') | Some(b'\n') => {}
+                    Some(&other) => out.push(other),
+                    None => {}
+                }
+            }
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+    (out, content.len())
+}
+
+/// UTF-16BE si marqué par un BOM, sinon Latin-1 (approximation de PDFDocEncoding).
+fn pdf_decode_string(bytes: &[u8]) -> String {
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = bytes[2..]
+            .chunks(2)
+            .map(|p| u16::from_be_bytes([p[0], *p.get(1).unwrap_or(&0)]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+    bytes
+        .iter()
+        .map(|&b| b as char)
+        .filter(|c| !c.is_control() || c.is_whitespace())
+        .collect()
+}
+
+/// Espaces multiples réduits, lignes vides consécutives fusionnées.
+fn normalize_whitespace(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank = 0;
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if line.is_empty() {
+            blank += 1;
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank > 0 { "\n\n" } else { "\n" });
+        }
+        out.push_str(&line);
+        blank = 0;
+    }
+    out
+}
+
+// -------------------------------------------------------------------------
+// 📥 INGESTION
+// -------------------------------------------------------------------------
+
+/// Fichiers à ingérer : le fichier lui-même, ou les documents pris en charge d'un dossier.
+pub fn collect_documents(path: &Path) -> RaiseResult<Vec<PathBuf>> {
+    if !path.exists() {
+        raise_error!(
+            "ERR_INGEST_PATH_NOT_FOUND",
+            error = "Le fichier ou dossier spécifié n'existe pas.",
+            context = json_value!({ "path": path })
+        );
+    }
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files: Vec<PathBuf> = fs::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| DocumentFormat::from_path(p).is_some())
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Extrait et découpe un fichier : (format, identifiant, empreinte, chunks).
+pub async fn prepare_document(
+    path: &Path,
+) -> RaiseResult<(DocumentFormat, String, String, Vec<String>)> {
+    let Some(format) = DocumentFormat::from_path(path) else {
+        raise_error!(
+            "ERR_INGEST_FORMAT_UNSUPPORTED",
+            error = "Format non pris en charge (md, txt, pdf, docx, xlsx, pptx, odt...).",
+            context = json_value!({ "path": path })
+        );
+    };
+    let bytes = fs::read_async(path).await?;
+    let (doc_id, sha256) = document_id(&bytes);
+    let text = extract_text(&bytes, format)?;
+    let chunks = splitting::split_text_with_overlap(&text, CHUNK_TOKENS, CHUNK_OVERLAP_TOKENS);
+    Ok((format, doc_id, sha256, chunks))
+}
+
+/// Ingère un fichier ou un dossier dans le RAG. Les erreurs d'un fichier n'interrompent
+/// pas le lot : il est simplement reporté dans `skipped`.
+pub async fn ingest_path(
+    rag: &mut RagRetriever,
+    manager: &CollectionsManager<'_>,
+    path: &Path,
+) -> RaiseResult<IngestionReport> {
+    let mut report = IngestionReport::default();
+
+    for file in collect_documents(path)? {
+        let file_path = file.to_string_lossy().to_string();
+        let (format, doc_id, sha256, chunks) = match prepare_document(&file).await {
+            Ok(prepared) => prepared,
+            Err(e) => {
+                user_warn!(
+                    "WRN_INGEST_FILE_SKIPPED",
+                    json_value!({ "path": file_path, "error": e.to_string() })
+                );
+                report.skipped.push(SkippedFile {
+                    path: file_path,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+        if chunks.is_empty() {
+            report.skipped.push(SkippedFile {
+                path: file_path,
+                reason: "Document vide".into(),
+            });
+            continue;
+        }
+
+        let already_indexed = rag.contains_document(manager, &doc_id).await?;
+        let indexed = if already_indexed {
+            0
+        } else {
+            let source = file
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| file_path.clone());
+            rag.index_chunks(
+                manager,
+                &chunks,
+                &doc_id,
+                json_value!({
+                    "source": source,
+                    "path": file_path,
+                    "format": format,
+                    "sha256": sha256
+                }),
+            )
+            .await?
+        };
+
+        report.total_chunks += indexed;
+        report.files.push(IngestedFile {
+            path: file_path,
+            format,
+            document_id: doc_id,
+            chunks: if already_indexed {
+                chunks.len()
+            } else {
+                indexed
+            },
+            already_indexed,
+        });
+    }
+
+    user_info!(
+        "INF_INGEST_DONE",
+        json_value!({ "files": report.files.len(), "skipped": report.skipped.len(), "chunks": report.total_chunks })
+    );
+    Ok(report)
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn office_archive(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        {
+            let mut writer = zip::ZipWriter::new(&mut buffer);
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated);
+            for (name, content) in entries {
+                writer.start_file(*name, options).expect("zip entry");
+                writer.write_all(content.as_bytes()).expect("zip write");
+            }
+            writer.finish().expect("zip finish");
+        }
+        buffer.into_inner()
+    }
+
+    fn deflated_pdf(content: &str) -> Vec<u8> {
+        let mut encoder =
+            flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(content.as_bytes()).expect("deflate");
+        let stream = encoder.finish().expect("deflate");
+        let mut pdf = b"%PDF-1.4\n4 0 obj\n<< /Length 0 /Filter /FlateDecode >>\nstream\n".to_vec();
+        pdf.extend(stream);
+        pdf.extend(b"\nendstream\nendobj\n%%EOF");
+        pdf
+    }
+
+    #[test]
+    fn test_extract_text_per_format() -> RaiseResult<()> {
+        assert_eq!(
+            DocumentFormat::from_path(Path::new("spec/Radar.DOCX")),
+            Some(DocumentFormat::Docx)
+        );
+        assert_eq!(DocumentFormat::from_path(Path::new("logo.png")), None);
+
+        let docx = office_archive(&[(
+            "word/document.xml",
+            r#"<w:document xmlns:w="w"><w:body><w:p><w:r><w:t>Exigence</w:t></w:r><w:r><w:t xml:space="preserve"> R&amp;D</w:t></w:r></w:p><w:p><w:r><w:t>Radar</w:t></w:r></w:p></w:body></w:document>"#,
+        )]);
+        assert_eq!(
+            extract_text(&docx, DocumentFormat::Docx)?,
+            "Exigence R&D\nRadar"
+        );
+
+        let pptx = office_archive(&[
+            (
+                "ppt/slides/slide10.xml",
+                r#"<p:sld xmlns:a="a" xmlns:p="p"><a:p><a:r><a:t>Fin</a:t></a:r></a:p></p:sld>"#,
+            ),
+            (
+                "ppt/slides/slide2.xml",
+                r#"<p:sld xmlns:a="a" xmlns:p="p"><a:p><a:r><a:t>Début</a:t></a:r></a:p></p:sld>"#,
+            ),
+        ]);
+        assert_eq!(extract_text(&pptx, DocumentFormat::Pptx)?, "Début\n\nFin");
+
+        let pdf =
+            deflated_pdf("BT /F1 12 Tf 72 712 Td (Vitesse max) Tj T* [(120)-300(km/h)] TJ ET");
+        assert_eq!(
+            extract_text(&pdf, DocumentFormat::Pdf)?,
+            "Vitesse max\n120 km/h"
+        );
+
+        assert!(extract_text(b"%PDF-1.4\n%%EOF", DocumentFormat::Pdf).is_err());
+        assert!(extract_text(b"pas une archive", DocumentFormat::Docx).is_err());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_prepare_document_chunks_with_overlap() -> RaiseResult<()> {
+        let dir = tempdir()?;
+        let doc = dir.path().join("notes.md");
+        let body = "Le radar détecte les obstacles. ".repeat(400);
+        fs::write_async(&doc, body.as_bytes()).await?;
+        fs::write_async(dir.path().join("image.png"), b"\x89PNG").await?;
+
+        let files = collect_documents(dir.path())?;
+        assert_eq!(files, vec![doc.clone()]);
+
+        let (format, doc_id, sha256, chunks) = prepare_document(&doc).await?;
+        assert_eq!(format, DocumentFormat::Markdown);
+        assert!(doc_id.starts_with("doc_") && sha256.starts_with(&doc_id[4..]));
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.chars().count() <= CHUNK_TOKENS * 4));
+        Ok(())
+    }
+}
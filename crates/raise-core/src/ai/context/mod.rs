@@ -1,4 +1,5 @@
 pub mod conversation_manager;
+pub mod ingestion;
 pub mod memory_store;
 pub mod rag;
 pub mod retriever;
@@ -1,4 +1,7 @@
 // FICHIER : crates/raise-core/src/ai/context/rag.rs
+use crate::ai::memory::migration::{
+    self, EmbeddingMigrator, EmbeddingStatus, MigrationJob, UNKNOWN_MODEL,
+};
 use crate::ai::memory::{native_store::NativeLocalStore, MemoryRecord, VectorStore};
 use crate::ai::nlp::{embeddings::EmbeddingEngine, splitting};
 use crate::json_db::collections::manager::CollectionsManager;
@@ -6,7 +9,7 @@ use crate::json_db::collections::manager::CollectionsManager;
 use crate::utils::prelude::*;
 
 pub struct RagRetriever {
-    backend: NativeLocalStore, // 🎯 Connexion directe et exclusive au moteur natif
+    backend: SharedRef<NativeLocalStore>, // 🎯 Connexion directe au moteur natif (partagée avec la maintenance)
     embedder: EmbeddingEngine,
     collection_name: String,
 }
@@ -51,7 +54,8 @@ impl RagRetriever {
             .to_string();
 
         // 🎯 Initialisation du moteur d'embeddings via le point de montage système
-        let embedder = EmbeddingEngine::new(manager).await?;
+        let mut embedder = EmbeddingEngine::new(manager).await?;
+        let dimension = embedder.dimension()?;
 
         user_info!(
             "INF_RAG_NATIVEENGINE_INIT",
@@ -68,7 +72,7 @@ impl RagRetriever {
 
         // 🎯 Rigueur : Passage du manager à l'infrastructure vectorielle
         memory
-            .init_collection(manager, &collection_name, 384)
+            .init_collection(manager, &collection_name, dimension as u64)
             .await?;
 
         // Chargement sécurisé de l'index
@@ -77,11 +81,91 @@ impl RagRetriever {
             Err(e) => user_warn!("WRN_RAG_EMPTY", json_value!({"error": e.to_string()})),
         }
 
-        Ok(Self {
-            backend: memory,
+        let mut rag = Self {
+            backend: SharedRef::new(memory),
             embedder,
             collection_name,
-        })
+        };
+
+        // 🎯 Détection d'un changement de modèle d'embedding (dimension ou identité)
+        let status = rag.embedding_status(manager).await?;
+        if status.migration_required {
+            user_warn!(
+                "WRN_EMBEDDING_MIGRATION_REQUIRED",
+                json_value!({
+                    "collection": status.collection,
+                    "current": status.current,
+                    "target_model": status.target_model,
+                    "target_dimension": status.target_dimension,
+                    "hint": "Lancez `raise ai embeddings migrate` pour ré-vectoriser la base de connaissances."
+                })
+            );
+        } else if status
+            .current
+            .as_ref()
+            .is_none_or(|m| m.model == UNKNOWN_MODEL)
+        {
+            migration::write_manifest(
+                manager,
+                &rag.collection_name,
+                &status.target_model,
+                status.target_dimension,
+            )
+            .await?;
+        }
+
+        Ok(rag)
+    }
+
+    /// Index vectoriel sous-jacent (les tâches de maintenance opèrent sur la même instance).
+    pub fn vector_store(&self) -> SharedRef<NativeLocalStore> {
+        self.backend.clone()
+    }
+
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Compare l'index de la base de connaissances au modèle d'embedding actif.
+    pub async fn embedding_status(
+        &mut self,
+        manager: &CollectionsManager<'_>,
+    ) -> RaiseResult<EmbeddingStatus> {
+        migration::check_collection(
+            &self.backend,
+            manager,
+            &self.collection_name,
+            &mut self.embedder,
+        )
+        .await
+    }
+
+    /// Exécute une migration préparée par `EmbeddingMigrator::prepare`.
+    pub async fn run_embedding_migration(
+        &mut self,
+        manager: &CollectionsManager<'_>,
+        migrator: &EmbeddingMigrator<'_>,
+        job: MigrationJob,
+    ) -> RaiseResult<MigrationJob> {
+        migrator
+            .run(job, &self.backend, manager, &mut self.embedder)
+            .await
+    }
+
+    /// Ré-vectorise la base de connaissances si le modèle a changé.
+    pub async fn migrate_embeddings(
+        &mut self,
+        manager: &CollectionsManager<'_>,
+        migrator: &EmbeddingMigrator<'_>,
+    ) -> RaiseResult<Option<MigrationJob>> {
+        migrator
+            .migrate_if_needed(
+                &self.backend,
+                manager,
+                &self.collection_name,
+                &mut self.embedder,
+            )
+            .await
     }
 
     pub async fn index_document(
@@ -91,12 +175,35 @@ impl RagRetriever {
         source: &str,
     ) -> RaiseResult<usize> {
         let chunks = splitting::split_text_into_chunks(content, 512);
+        self.index_chunks(
+            manager,
+            &chunks,
+            &UniqueId::new_v4().to_string(),
+            json_value!({ "source": source }),
+        )
+        .await
+    }
+
+    /// Indexe des chunks déjà découpés. Les identifiants `{document_id}_{index}` sont
+    /// déterministes : un document déjà présent est détecté par `contains_document`.
+    pub async fn index_chunks(
+        &mut self,
+        manager: &CollectionsManager<'_>,
+        chunks: &[String],
+        document_id: &str,
+        metadata: JsonValue,
+    ) -> RaiseResult<usize> {
         if chunks.is_empty() {
             return Ok(0);
         }
+        let source = metadata
+            .get("source")
+            .and_then(|v| v.as_str())
+            .unwrap_or("?")
+            .to_string();
 
         // 🎯 Match strict sur le batch d'embeddings
-        let vectors = match self.embedder.embed_batch(chunks.clone()) {
+        let vectors = match self.embedder.embed_batch(chunks.to_vec()) {
             Ok(v) => v,
             Err(e) => raise_error!(
                 "ERR_RAG_EMBEDDING_BATCH",
@@ -109,15 +216,17 @@ impl RagRetriever {
 
         let mut records = Vec::new();
         for (i, chunk) in chunks.iter().enumerate() {
+            let mut chunk_metadata = metadata.clone();
+            if let Some(obj) = chunk_metadata.as_object_mut() {
+                obj.insert("document_id".into(), json_value!(document_id));
+                obj.insert("chunk_index".into(), json_value!(i));
+                obj.insert("total_chunks".into(), json_value!(chunks.len()));
+                obj.insert("ingested_at".into(), json_value!(ingest_time));
+            }
             records.push(MemoryRecord {
-                id: UniqueId::new_v4().to_string(),
+                id: format!("{}_{}", document_id, i),
                 content: chunk.clone(),
-                metadata: json_value!({
-                    "source": source,
-                    "chunk_index": i,
-                    "total_chunks": chunks.len(),
-                    "ingested_at": ingest_time
-                }),
+                metadata: chunk_metadata,
                 vectors: Some(vectors[i].clone()),
             });
         }
@@ -144,6 +253,18 @@ impl RagRetriever {
         Ok(chunks.len())
     }
 
+    /// Vrai si le premier chunk du document est déjà présent dans la collection.
+    pub async fn contains_document(
+        &self,
+        manager: &CollectionsManager<'_>,
+        document_id: &str,
+    ) -> RaiseResult<bool> {
+        Ok(manager
+            .get_document(&self.collection_name, &format!("{}_0", document_id))
+            .await?
+            .is_some())
+    }
+
     pub async fn retrieve(
         &mut self,
         manager: &CollectionsManager<'_>,
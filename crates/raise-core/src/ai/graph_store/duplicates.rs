@@ -0,0 +1,449 @@
+// FICHIER : src-tauri/src/ai/graph_store/duplicates.rs
+
+//! Détection des doublons sémantiques : deux éléments d'une même couche Arcadia dont les
+//! embeddings sont quasi colinéaires (similarité cosinus au-delà d'un seuil) sont signalés
+//! avec une proposition de fusion. Les modèles importés en regorgent (copier-coller, imports
+//! successifs de Capella, renommages partiels).
+
+use super::store::{extract_rich_semantic_content, GraphStore};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
+use crate::utils::prelude::*;
+
+pub const DEFAULT_DUPLICATE_THRESHOLD: f32 = 0.92;
+
+/// Paramètres de l'analyse.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct DuplicateOptions {
+    #[serde(default = "default_threshold")]
+    pub threshold: f32,
+    /// Restreint l'analyse à une couche (`oa`, `sa`, `la`, `pa`...).
+    #[serde(default)]
+    pub layer: Option<String>,
+    /// Nombre maximal de paires retournées (les plus similaires d'abord).
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_threshold() -> f32 {
+    DEFAULT_DUPLICATE_THRESHOLD
+}
+
+fn default_limit() -> usize {
+    100
+}
+
+impl Default for DuplicateOptions {
+    fn default() -> Self {
+        Self {
+            threshold: default_threshold(),
+            layer: None,
+            limit: default_limit(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct DuplicateElement {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub collection: String,
+}
+
+/// Fusion proposée : `absorb` disparaît au profit de `keep`, enrichi des propriétés qui lui
+/// manquent. Les propriétés renseignées différemment des deux côtés sont à arbitrer.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct MergeProposal {
+    pub keep: String,
+    pub absorb: String,
+    pub merged: JsonValue,
+    pub conflicts: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct DuplicateCandidate {
+    pub layer: String,
+    pub left: DuplicateElement,
+    pub right: DuplicateElement,
+    pub similarity: f32,
+    pub proposal: MergeProposal,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct DuplicateReport {
+    pub threshold: f32,
+    /// Éléments disposant d'un vecteur (donc comparés).
+    pub compared: usize,
+    /// Éléments ignorés faute de vecteur (ni indexés, ni vectorisables).
+    pub missing_vectors: Vec<String>,
+    pub candidates: Vec<DuplicateCandidate>,
+}
+
+/// Propriétés techniques exclues de la fusion.
+fn is_technical(key: &str) -> bool {
+    key == "embedding" || key.starts_with('_') || key.starts_with('@')
+}
+
+fn is_empty_value(value: &JsonValue) -> bool {
+    match value {
+        JsonValue::Null => true,
+        JsonValue::String(s) => s.trim().is_empty(),
+        JsonValue::Array(a) => a.is_empty(),
+        JsonValue::Object(o) => o.is_empty(),
+        _ => false,
+    }
+}
+
+fn richness(el: &ArcadiaElement) -> usize {
+    el.properties
+        .iter()
+        .filter(|(k, v)| !is_technical(k) && !is_empty_value(v))
+        .count()
+}
+
+/// Conserve l'élément le mieux renseigné (à égalité, le plus petit identifiant).
+pub fn propose_merge(a: &ArcadiaElement, b: &ArcadiaElement) -> MergeProposal {
+    let (keep, absorb) = match richness(a).cmp(&richness(b)) {
+        std::cmp::Ordering::Greater => (a, b),
+        std::cmp::Ordering::Less => (b, a),
+        std::cmp::Ordering::Equal if a.id <= b.id => (a, b),
+        std::cmp::Ordering::Equal => (b, a),
+    };
+
+    let mut merged = json::serialize_to_value(keep).unwrap_or_else(|_| json_value!({}));
+    let mut conflicts = Vec::new();
+    if keep.name.as_str() != absorb.name.as_str() {
+        conflicts.push("name".to_string());
+    }
+
+    let mut keys: Vec<&String> = absorb.properties.keys().collect();
+    keys.sort();
+    for key in keys {
+        let value = &absorb.properties[key];
+        if is_technical(key) || is_empty_value(value) {
+            continue;
+        }
+        match keep.properties.get(key) {
+            Some(existing) if !is_empty_value(existing) => {
+                if existing != value {
+                    conflicts.push(key.clone());
+                }
+            }
+            _ => merged[key.as_str()] = value.clone(),
+        }
+    }
+    if let Some(obj) = merged.as_object_mut() {
+        obj.remove("embedding");
+    }
+
+    MergeProposal {
+        keep: keep.id.clone(),
+        absorb: absorb.id.clone(),
+        merged,
+        conflicts,
+    }
+}
+
+fn normalize(vector: &[f32]) -> Option<Vec<f32>> {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if vector.is_empty() || norm == 0.0 || !norm.is_finite() {
+        return None;
+    }
+    Some(vector.iter().map(|x| x / norm).collect())
+}
+
+fn describe(el: &ArcadiaElement, collection: &str) -> DuplicateElement {
+    DuplicateElement {
+        id: el.id.clone(),
+        name: el.name.as_str().to_string(),
+        kind: el.kind.clone(),
+        collection: collection.to_string(),
+    }
+}
+
+/// Vecteur d'un élément : champ `embedding` du document, sinon index vectoriel du
+/// GraphStore, sinon vectorisation à la volée si le moteur NLP est chargé.
+async fn element_vector(
+    store: &GraphStore,
+    el: &ArcadiaElement,
+    indexed: &UnorderedMap<String, Vec<f32>>,
+) -> Option<Vec<f32>> {
+    if let Some(values) = el.properties.get("embedding").and_then(|v| v.as_array()) {
+        let vector: Vec<f32> = values
+            .iter()
+            .filter_map(|v| v.as_f64().map(|f| f as f32))
+            .collect();
+        if vector.len() == values.len() {
+            return Some(vector);
+        }
+    }
+    if let Some(vector) = indexed.get(&el.id) {
+        return Some(vector.clone());
+    }
+    let embedder = store.embedder.as_ref()?;
+    let text = extract_rich_semantic_content(&json::serialize_to_value(el).ok()?);
+    if text.is_empty() {
+        return None;
+    }
+    embedder.lock().await.embed_query(&text).ok()
+}
+
+/// Recherche les paires d'éléments quasi identiques, couche par couche.
+pub async fn find_duplicates(
+    store: &GraphStore,
+    manager: &CollectionsManager<'_>,
+    model: &ProjectModel,
+    options: &DuplicateOptions,
+) -> RaiseResult<DuplicateReport> {
+    let mut layers: Vec<&String> = model.layers.keys().collect();
+    layers.sort();
+
+    let mut report = DuplicateReport {
+        threshold: options.threshold,
+        compared: 0,
+        missing_vectors: vec![],
+        candidates: vec![],
+    };
+
+    for layer in layers {
+        if options.layer.as_ref().is_some_and(|l| l != layer) {
+            continue;
+        }
+        let mut collections: Vec<(&String, &Vec<ArcadiaElement>)> =
+            model.layers[layer].iter().collect();
+        collections.sort_by(|a, b| a.0.cmp(b.0));
+
+        // (élément, collection, vecteur normalisé)
+        let mut entries: Vec<(&ArcadiaElement, &str, Vec<f32>)> = Vec::new();
+        for (collection, elements) in collections {
+            let indexed = indexed_vectors(store, manager, collection).await;
+            for el in elements {
+                match element_vector(store, el, &indexed)
+                    .await
+                    .and_then(|v| normalize(&v))
+                {
+                    Some(vector) => entries.push((el, collection.as_str(), vector)),
+                    None => report.missing_vectors.push(el.id.clone()),
+                }
+            }
+        }
+        report.compared += entries.len();
+
+        for i in 0..entries.len() {
+            for j in (i + 1)..entries.len() {
+                let (a, col_a, va) = &entries[i];
+                let (b, col_b, vb) = &entries[j];
+                if a.id == b.id || va.len() != vb.len() {
+                    continue;
+                }
+                let similarity: f32 = va.iter().zip(vb).map(|(x, y)| x * y).sum();
+                if similarity < options.threshold {
+                    continue;
+                }
+                report.candidates.push(DuplicateCandidate {
+                    layer: layer.clone(),
+                    left: describe(a, col_a),
+                    right: describe(b, col_b),
+                    similarity,
+                    proposal: propose_merge(a, b),
+                });
+            }
+        }
+    }
+
+    report
+        .candidates
+        .sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    report.candidates.truncate(options.limit);
+
+    user_info!(
+        "INF_GRAPH_DUPLICATES_FOUND",
+        json_value!({
+            "compared": report.compared,
+            "candidates": report.candidates.len(),
+            "missing_vectors": report.missing_vectors.len()
+        })
+    );
+    Ok(report)
+}
+
+async fn indexed_vectors(
+    store: &GraphStore,
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+) -> UnorderedMap<String, Vec<f32>> {
+    let Some(vector_store) = &store.vector_store else {
+        return UnorderedMap::new();
+    };
+    match vector_store.export_index(manager, collection).await {
+        // Une ligne par écriture : la dernière version d'un document l'emporte
+        Ok((ids, vectors)) => ids.into_iter().zip(vectors).collect(),
+        Err(e) => {
+            user_trace!(
+                "INF_GRAPH_DUPLICATES_NO_INDEX",
+                json_value!({ "collection": collection, "reason": e.to_string() })
+            );
+            UnorderedMap::new()
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::memory::native_store::NativeLocalStore;
+    use crate::ai::memory::{MemoryRecord, VectorStore};
+    use crate::json_db::jsonld::JsonLdProcessor;
+    use crate::model_engine::types::NameType;
+    use crate::utils::testing::DbSandbox;
+
+    fn element(id: &str, name: &str, props: JsonValue) -> ArcadiaElement {
+        let mut properties = UnorderedMap::new();
+        if let Some(obj) = props.as_object() {
+            for (k, v) in obj {
+                properties.insert(k.clone(), v.clone());
+            }
+        }
+        ArcadiaElement {
+            id: id.to_string(),
+            name: NameType::String(name.to_string()),
+            kind: "SystemFunction".to_string(),
+            properties,
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_richest_element() {
+        let a = element(
+            "f1",
+            "Acquérir cible",
+            json_value!({ "description": "Radar" }),
+        );
+        let b = element(
+            "f2",
+            "Acquerir cible",
+            json_value!({ "description": "Radar bande X", "owner": "sys", "embedding": [1.0] }),
+        );
+        let proposal = propose_merge(&a, &b);
+        assert_eq!(proposal.keep, "f2");
+        assert_eq!(proposal.absorb, "f1");
+        assert_eq!(proposal.conflicts, vec!["name", "description"]);
+        assert_eq!(proposal.merged["owner"], "sys");
+        assert!(proposal.merged.get("embedding").is_none());
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_duplicates_same_layer_above_threshold() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        let generic_schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        let _ = manager
+            .create_collection("components", &generic_schema_uri)
+            .await;
+        let _ = manager
+            .create_collection("service_configs", &generic_schema_uri)
+            .await;
+        manager
+            .upsert_document(
+                "components",
+                json_value!({ "_id": "comp_ai_graph_store", "handle": "ai_graph_store" }),
+            )
+            .await?;
+        manager
+            .upsert_document(
+                "service_configs",
+                json_value!({
+                    "_id": "cfg_ai_graph_store",
+                    "component_id": "ref:components:handle:ai_graph_store",
+                    "service_settings": { "embedding_dim": 3 }
+                }),
+            )
+            .await?;
+
+        // f3 n'a pas de champ `embedding` : son vecteur vient de l'index du GraphStore
+        let vector_store = NativeLocalStore::new(&manager, &ComputeHardware::Cpu).await?;
+        vector_store
+            .init_collection(&manager, "functions", 3)
+            .await?;
+        vector_store
+            .add_documents(
+                &manager,
+                "functions",
+                vec![MemoryRecord {
+                    id: "f3".into(),
+                    content: "Acquérir la cible".into(),
+                    metadata: json_value!({}),
+                    vectors: Some(vec![0.98, 0.2, 0.0]),
+                }],
+            )
+            .await?;
+        let store = GraphStore {
+            storage_path: sandbox.storage.config.data_root.join("graph_store"),
+            vector_store: Some(SharedRef::new(vector_store)),
+            embedder: None,
+            embedding_dim: 3,
+            processor: JsonLdProcessor::new()?,
+        };
+
+        let mut model = ProjectModel::default();
+        let f = |id, name, v: [f32; 3]| element(id, name, json_value!({ "embedding": v }));
+        model.add_element(
+            "sa",
+            "functions",
+            f("f1", "Acquérir cible", [1.0, 0.1, 0.0]),
+        );
+        model.add_element("sa", "functions", f("f2", "Suivre piste", [0.0, 1.0, 0.0]));
+        model.add_element(
+            "sa",
+            "functions",
+            element("f3", "Acquérir la cible", json_value!({})),
+        );
+        model.add_element(
+            "sa",
+            "functions",
+            element("f4", "Sans vecteur", json_value!({})),
+        );
+        // Même vecteur, mais couche différente : jamais comparé à f1
+        model.add_element(
+            "la",
+            "functions",
+            f("l1", "Acquérir cible", [1.0, 0.1, 0.0]),
+        );
+
+        let report =
+            find_duplicates(&store, &manager, &model, &DuplicateOptions::default()).await?;
+        assert_eq!(report.compared, 4);
+        assert_eq!(report.missing_vectors, vec!["f4"]);
+        assert_eq!(report.candidates.len(), 1);
+        let pair = &report.candidates[0];
+        assert_eq!(pair.layer, "sa");
+        assert_eq!(
+            (pair.left.id.as_str(), pair.right.id.as_str()),
+            ("f1", "f3")
+        );
+        assert!(pair.similarity > 0.98);
+
+        let only_la = DuplicateOptions {
+            layer: Some("la".into()),
+            ..Default::default()
+        };
+        let report = find_duplicates(&store, &manager, &model, &only_la).await?;
+        assert!(report.candidates.is_empty());
+        Ok(())
+    }
+}
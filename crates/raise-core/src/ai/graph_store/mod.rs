@@ -1,6 +1,7 @@
 // FICHIER : src-tauri/src/ai/graph_store/mod.rs
 
 pub mod adjacency;
+pub mod duplicates;
 pub mod engine;
 pub mod features;
 pub mod logic;
@@ -8,6 +8,7 @@ use async_trait::async_trait;
 
 // 🎯 Import des fournisseurs Cloud
 use crate::ai::llm::providers::{claude, gemini, mistral};
+use crate::ai::llm::usage::UsageTracker;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum LlmBackend {
@@ -29,14 +30,51 @@ pub trait LlmEngine: Send + Sync {
         user: &str,
         max_tokens: usize,
     ) -> RaiseResult<String>;
+
+    /// Génération en flux : `on_token` reçoit les fragments au fil de l'eau et retourne
+    /// `false` pour interrompre. Par défaut, la réponse complète est émise en un seul fragment.
+    async fn generate_stream(
+        &mut self,
+        system: &str,
+        user: &str,
+        max_tokens: usize,
+        on_token: &mut (dyn FnMut(&str) -> bool + Send),
+    ) -> RaiseResult<String> {
+        let response = self.generate(system, user, max_tokens).await?;
+        on_token(&response);
+        Ok(response)
+    }
+
+    /// Recharge les poids depuis les réglages `ai_llm` courants (bascule de modèle à chaud).
+    /// Les moteurs sans poids locaux ne le supportent pas.
+    async fn reload(&mut self, _manager: &CollectionsManager<'_>) -> RaiseResult<()> {
+        raise_error!(
+            "ERR_AI_ENGINE_RELOAD_UNSUPPORTED",
+            error = "Ce moteur ne supporte pas le changement de modèle à chaud."
+        )
+    }
 }
 
+/// Récepteur des fragments d'une réponse en flux ; retourne `false` pour interrompre.
+pub type TokenSink = SharedRef<dyn Fn(&str) -> bool + Send + Sync>;
+
 #[derive(Clone)]
 pub struct LlmClient {
     storage: SharedRef<StorageEngine>,
     pub space: String,
     pub db_name: String,
     native_engine: Option<SharedRef<AsyncMutex<dyn LlmEngine>>>,
+    /// Session imputée dans le suivi des coûts (`ai_usage`).
+    pub session_id: String,
+    /// Destination des fragments quand la réponse est diffusée en flux (`ai_chat_stream`).
+    stream: Option<TokenSink>,
+}
+
+impl LlmBackend {
+    /// Clé du backend dans les tarifs `AppConfig.ai_budget.prices`.
+    pub fn usage_key(&self) -> String {
+        format!("{:?}", self).to_lowercase()
+    }
 }
 
 impl LlmClient {
@@ -50,10 +88,43 @@ impl LlmClient {
             space: manager.space.to_string(),
             db_name: manager.db.to_string(),
             native_engine,
+            session_id: "default".to_string(),
+            stream: None,
         })
     }
 
+    /// Moteur de stockage partagé (registres système : coûts, gabarits de prompts).
+    pub fn storage(&self) -> &StorageEngine {
+        self.storage.as_ref()
+    }
+
+    /// Impute les appels suivants à une session (suivi des coûts).
+    pub fn with_session(mut self, session_id: &str) -> Self {
+        self.session_id = session_id.to_string();
+        self
+    }
+
+    /// Diffuse les réponses suivantes fragment par fragment vers `sink`.
+    pub fn with_stream(mut self, sink: TokenSink) -> Self {
+        self.stream = Some(sink);
+        self
+    }
+
+    /// Transmet un fragment au flux éventuel ; `false` si le destinataire a demandé l'arrêt.
+    fn emit(&self, chunk: &str) -> bool {
+        match &self.stream {
+            Some(sink) => sink(chunk),
+            None => true,
+        }
+    }
+
+    /// Projet facturé : la base de travail du client.
+    fn usage_project(&self) -> String {
+        format!("{}/{}", self.space, self.db_name)
+    }
+
     /// Le "Gatekeeper" hybride : Route la requête en fonction de l'habilitation (Clearance).
+    #[instrument(name = "ai.llm.ask", skip_all, fields(backend = ?backend, clearance = ?clearance))]
     pub async fn ask(
         &self,
         backend: LlmBackend,
@@ -61,17 +132,61 @@ impl LlmClient {
         user_prompt: &str,
         clearance: Clearance,
     ) -> RaiseResult<String> {
+        // 0. BUDGET : blocage si le budget mensuel du projet est épuisé
+        let tracker = UsageTracker::system(self.storage.as_ref());
+        let project = self.usage_project();
+        tracker.enforce(&project).await?;
+
+        let (response, engine_key) = self
+            .route(backend, system_prompt, user_prompt, clearance)
+            .await?;
+
+        let prompt = format!("{}\n{}", system_prompt, user_prompt);
+        if let Err(e) = tracker
+            .record(&self.session_id, &project, &engine_key, &prompt, &response)
+            .await
+        {
+            user_warn!(
+                "WRN_AI_USAGE_RECORD_FAILED",
+                json_value!({ "project": project, "technical_error": e.to_string() })
+            );
+        }
+        Ok(response)
+    }
+
+    /// Routage selon l'habilitation ; retourne la réponse et la clé tarifaire du moteur utilisé.
+    async fn route(
+        &self,
+        backend: LlmBackend,
+        system_prompt: &str,
+        user_prompt: &str,
+        clearance: Clearance,
+    ) -> RaiseResult<(String, String)> {
         // 1. DÉLÉGATION DIRECTE CLOUD (Données Publiques)
         if clearance == Clearance::Public {
-            return self.call_cloud(backend, system_prompt, user_prompt).await;
+            let response = self
+                .call_cloud(backend.clone(), system_prompt, user_prompt)
+                .await?;
+            // Les fournisseurs distants répondent d'un bloc : un seul fragment
+            self.emit(&response);
+            return Ok((response, backend.usage_key()));
         }
 
         // 2. EXÉCUTION LOCALE SOUVERAINE (Priorité pour tous les autres niveaux)
         if let Some(engine_ref) = &self.native_engine {
             let mut engine = engine_ref.lock().await;
 
-            match engine.generate(system_prompt, user_prompt, 1024).await {
-                Ok(response) => return Ok(response),
+            let generation = match &self.stream {
+                Some(sink) => {
+                    let mut on_token = |chunk: &str| sink(chunk);
+                    engine
+                        .generate_stream(system_prompt, user_prompt, 1024, &mut on_token)
+                        .await
+                }
+                None => engine.generate(system_prompt, user_prompt, 1024).await,
+            };
+            match generation {
+                Ok(response) => return Ok((response, "local".to_string())),
                 Err(e) => {
                     // Si l'exécution locale échoue, on vérifie si la loi/stratégie autorise la fuite Cloud
                     if !clearance.is_cloud_authorized() {
@@ -99,7 +214,12 @@ impl LlmClient {
                 "AI_LOCAL_UNAVAILABLE",
                 json_value!({"hint": format!("Bascule sur le réseau distant autorisée (Niveau: {:?}).", clearance)})
             );
-            return self.call_cloud(backend, system_prompt, user_prompt).await;
+            let response = self
+                .call_cloud(backend.clone(), system_prompt, user_prompt)
+                .await?;
+            // Les fournisseurs distants répondent d'un bloc : un seul fragment
+            self.emit(&response);
+            return Ok((response, backend.usage_key()));
         }
 
         unreachable!()
@@ -368,4 +488,34 @@ mod tests {
         );
         Ok(())
     }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_llm_client_streams_chunks_to_sink() -> RaiseResult<()> {
+        let sandbox = AgentDbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.db, "test", "db");
+
+        let expected_msg = "Réponse diffusée";
+        let mock_engine = SharedRef::new(AsyncMutex::new(MockLlmEngine {
+            response: expected_msg.to_string(),
+        }));
+
+        let (tx, mut rx) = AsyncChannel::unbounded_channel::<String>();
+        let sink: TokenSink = SharedRef::new(move |chunk: &str| tx.send(chunk.to_string()).is_ok());
+        let client = LlmClient::new(&manager, sandbox.db.clone(), Some(mock_engine))
+            .await?
+            .with_stream(sink);
+
+        let result = client
+            .ask(LlmBackend::Mock, "System", "Prompt", Clearance::Secret)
+            .await?;
+        assert_eq!(result, expected_msg);
+
+        let mut streamed = String::new();
+        while let Ok(chunk) = rx.try_recv() {
+            streamed.push_str(&chunk);
+        }
+        assert_eq!(streamed, expected_msg);
+        Ok(())
+    }
 }
@@ -2,9 +2,11 @@ use crate::utils::prelude::*;
 
 use self::native_engine::NativeTensorEngine;
 pub mod client;
+pub mod models;
 pub mod native_engine;
 pub mod providers;
 pub mod response_parser;
+pub mod usage;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,681 @@
+// FICHIER : src-tauri/src/ai/llm/models.rs
+
+//! Gestion des modèles GGUF locaux : catalogue déclaré dans les réglages du composant
+//! `ai_llm` (`service_settings.models`), téléchargement depuis HuggingFace avec contrôle
+//! SHA-256 vers `ai-assets/models`, et bascule à chaud du modèle d'inférence actif.
+
+use crate::ai::llm::client::LlmEngine;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::collections::merge::MergeStrategy;
+use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
+use crate::json_db::storage::StorageEngine;
+use crate::utils::core::RuntimeEnv;
+use crate::utils::prelude::*;
+
+/// Composant portant la configuration du LLM natif.
+pub const LLM_COMPONENT_REF: &str = "ref:components:handle:ai_llm";
+pub const DEFAULT_HUB_URL: &str = "https://huggingface.co";
+
+/// Fichier distant d'un dépôt HuggingFace.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ModelFile {
+    pub repo: String,
+    pub file: String,
+    /// Empreinte attendue (hexadécimal) ; sans elle, le fichier est accepté tel quel.
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+/// Modèle déclaré dans `service_settings.models`.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct LocalModelSpec {
+    pub id: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default = "default_revision")]
+    pub revision: String,
+    pub weights: ModelFile,
+    /// Tokenizer associé (souvent publié dans le dépôt du modèle non quantifié).
+    #[serde(default)]
+    pub tokenizer: Option<ModelFile>,
+}
+
+fn default_revision() -> String {
+    "main".to_string()
+}
+
+impl LocalModelSpec {
+    /// Chemin relatif au dossier des modèles : un sous-dossier par modèle évite les
+    /// collisions entre tokenizers homonymes.
+    pub fn weights_path(&self) -> String {
+        self.local_path(&self.weights)
+    }
+
+    pub fn tokenizer_path(&self) -> Option<String> {
+        self.tokenizer.as_ref().map(|t| self.local_path(t))
+    }
+
+    fn local_path(&self, file: &ModelFile) -> String {
+        let name = Path::new(&file.file)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| file.file.clone());
+        format!("{}/{}", self.id, name)
+    }
+}
+
+/// État d'un modèle du catalogue sur ce poste.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct LocalModelStatus {
+    #[serde(flatten)]
+    pub spec: LocalModelSpec,
+    pub installed: bool,
+    pub size_bytes: Option<u64>,
+    pub active: bool,
+}
+
+/// Résultat du contrôle d'un fichier.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct FileCheck {
+    pub path: String,
+    pub bytes: u64,
+    pub sha256: String,
+    /// `None` si aucune empreinte n'est déclarée.
+    pub verified: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ModelCheck {
+    pub model_id: String,
+    pub files: Vec<FileCheck>,
+}
+
+impl ModelCheck {
+    pub fn is_valid(&self) -> bool {
+        self.files.iter().all(|f| f.verified != Some(false))
+    }
+}
+
+pub struct LocalModelManager<'a> {
+    manager: CollectionsManager<'a>,
+    models_dir: Option<PathBuf>,
+}
+
+impl<'a> LocalModelManager<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            manager: CollectionsManager::new(storage, space, db),
+            models_dir: None,
+        }
+    }
+
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    /// Remplace le dossier des modèles (par défaut, celui du moteur natif).
+    pub fn with_models_dir(mut self, dir: PathBuf) -> Self {
+        self.models_dir = Some(dir);
+        self
+    }
+
+    /// Dossier des modèles, résolu comme dans `NativeTensorEngine::new`.
+    pub fn models_dir(&self) -> RaiseResult<PathBuf> {
+        if let Some(dir) = &self.models_dir {
+            return Ok(dir.clone());
+        }
+        let config = AppConfig::get();
+        config.resolve_asset_path(
+            config
+                .system_assets
+                .ai_assets_paths
+                .as_ref()
+                .and_then(|p| p.models.as_ref()),
+            "ai-assets/models",
+        )
+    }
+
+    async fn settings(&self) -> RaiseResult<JsonValue> {
+        AppConfig::get_runtime_settings(&self.manager, LLM_COMPONENT_REF).await
+    }
+
+    /// Catalogue déclaré dans les réglages `ai_llm`.
+    pub async fn catalog(&self) -> RaiseResult<Vec<LocalModelSpec>> {
+        let settings = self.settings().await?;
+        match settings.get("models") {
+            Some(models) => json::deserialize_from_value(models.clone()),
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn spec(&self, id: &str) -> RaiseResult<LocalModelSpec> {
+        match self.catalog().await?.into_iter().find(|m| m.id == id) {
+            Some(spec) => Ok(spec),
+            None => raise_error!(
+                "ERR_AI_MODEL_UNKNOWN",
+                error = format!("Modèle '{}' absent du catalogue ai_llm.", id),
+                context = json_value!({ "model_id": id, "hint": "raise ai models list" })
+            ),
+        }
+    }
+
+    /// Catalogue enrichi de l'état local (présence, taille, modèle actif).
+    pub async fn list(&self) -> RaiseResult<Vec<LocalModelStatus>> {
+        let settings = self.settings().await?;
+        let active = settings
+            .get("rust_model_file")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let dir = self.models_dir()?;
+
+        let mut statuses = Vec::new();
+        for spec in self.catalog().await? {
+            let weights = dir.join(spec.weights_path());
+            let size_bytes = match fs::metadata_async(&weights).await {
+                Ok(meta) => Some(meta.len()),
+                Err(_) => None,
+            };
+            let tokenizer_ok = match spec.tokenizer_path() {
+                Some(path) => fs::exists_async(&dir.join(path)).await,
+                None => true,
+            };
+            statuses.push(LocalModelStatus {
+                installed: size_bytes.is_some() && tokenizer_ok,
+                active: spec.weights_path() == active,
+                size_bytes,
+                spec,
+            });
+        }
+        Ok(statuses)
+    }
+
+    /// Télécharge les fichiers manquants (ou corrompus) du modèle et contrôle leur empreinte.
+    pub async fn download(&self, id: &str) -> RaiseResult<ModelCheck> {
+        let spec = self.spec(id).await?;
+        let settings = self.settings().await?;
+        let hub = settings
+            .get("hub_url")
+            .and_then(|v| v.as_str())
+            .unwrap_or(DEFAULT_HUB_URL)
+            .trim_end_matches('/')
+            .to_string();
+        // `hf_token` accepte une référence `secret:` (résolue par get_runtime_settings)
+        let token = settings
+            .get("hf_token")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or_else(|| RuntimeEnv::var("HF_TOKEN").ok());
+        let dir = self.models_dir()?;
+
+        let mut files = vec![(spec.weights.clone(), spec.weights_path())];
+        if let (Some(tokenizer), Some(path)) = (&spec.tokenizer, spec.tokenizer_path()) {
+            files.push((tokenizer.clone(), path));
+        }
+
+        let mut checks = Vec::new();
+        for (remote, relative) in files {
+            let target = dir.join(&relative);
+            if fs::exists_async(&target).await {
+                let check = check_file(&target, remote.sha256.as_deref()).await?;
+                if check.verified != Some(false) {
+                    checks.push(check);
+                    continue;
+                }
+                user_warn!(
+                    "WRN_AI_MODEL_CORRUPTED",
+                    json_value!({ "path": target.to_string_lossy(), "action": "re-download" })
+                );
+            }
+            let url = format!(
+                "{}/{}/resolve/{}/{}",
+                hub, remote.repo, spec.revision, remote.file
+            );
+            checks.push(
+                download_file(&url, &target, remote.sha256.as_deref(), token.as_deref()).await?,
+            );
+        }
+
+        user_success!(
+            "SUC_AI_MODEL_DOWNLOADED",
+            json_value!({ "model_id": spec.id, "files": checks.len() })
+        );
+        Ok(ModelCheck {
+            model_id: spec.id,
+            files: checks,
+        })
+    }
+
+    /// Recalcule l'empreinte des fichiers installés.
+    pub async fn verify(&self, id: &str) -> RaiseResult<ModelCheck> {
+        let spec = self.spec(id).await?;
+        let dir = self.models_dir()?;
+
+        let mut files = vec![(spec.weights.sha256.clone(), spec.weights_path())];
+        if let (Some(tokenizer), Some(path)) = (&spec.tokenizer, spec.tokenizer_path()) {
+            files.push((tokenizer.sha256.clone(), path));
+        }
+
+        let mut checks = Vec::new();
+        for (sha256, relative) in files {
+            let path = dir.join(&relative);
+            if !fs::exists_async(&path).await {
+                raise_error!(
+                    "ERR_AI_MODEL_NOT_INSTALLED",
+                    error = format!("Fichier absent : {}", relative),
+                    context = json_value!({ "model_id": id, "hint": format!("raise ai models download {}", id) })
+                );
+            }
+            checks.push(check_file(&path, sha256.as_deref()).await?);
+        }
+        Ok(ModelCheck {
+            model_id: spec.id,
+            files: checks,
+        })
+    }
+
+    /// Fait du modèle le modèle d'inférence actif : les réglages `ai_llm` sont mis à jour
+    /// puis, si un moteur est chargé, ses poids sont remplacés à chaud. En cas d'échec du
+    /// rechargement, les réglages précédents sont restaurés.
+    pub async fn switch(
+        &self,
+        id: &str,
+        engine: Option<&SharedRef<AsyncMutex<dyn LlmEngine>>>,
+    ) -> RaiseResult<LocalModelStatus> {
+        let Some(status) = self.list().await?.into_iter().find(|s| s.spec.id == id) else {
+            raise_error!(
+                "ERR_AI_MODEL_UNKNOWN",
+                error = format!("Modèle '{}' absent du catalogue ai_llm.", id),
+                context = json_value!({ "model_id": id })
+            );
+        };
+        if !status.installed {
+            raise_error!(
+                "ERR_AI_MODEL_NOT_INSTALLED",
+                error = format!("Le modèle '{}' n'est pas téléchargé.", id),
+                context = json_value!({ "model_id": id, "hint": format!("raise ai models download {}", id) })
+            );
+        }
+
+        let (collection_manager, config_id, previous) = self.llm_service_config().await?;
+        let mut patch = json_value!({ "rust_model_file": status.spec.weights_path() });
+        if let Some(tokenizer) = status.spec.tokenizer_path() {
+            patch["rust_tokenizer_file"] = json_value!(tokenizer);
+        }
+        collection_manager
+            .update_document_with(
+                "service_configs",
+                &config_id,
+                json_value!({ "service_settings": patch }),
+                Some(MergeStrategy::DeepMerge),
+            )
+            .await?;
+
+        if let Some(engine) = engine {
+            let reloaded = engine.lock().await.reload(&self.manager).await;
+            if let Err(e) = reloaded {
+                collection_manager
+                    .update_document_with(
+                        "service_configs",
+                        &config_id,
+                        json_value!({ "service_settings": previous }),
+                        Some(MergeStrategy::Replace),
+                    )
+                    .await?;
+                return Err(e);
+            }
+        }
+
+        user_success!(
+            "SUC_AI_MODEL_SWITCHED",
+            json_value!({ "model_id": id, "hot_reload": engine.is_some() })
+        );
+        Ok(LocalModelStatus {
+            active: true,
+            ..status
+        })
+    }
+
+    /// Document `service_configs` brut du composant `ai_llm` (références `secret:` non
+    /// résolues, pour ne jamais réécrire un secret en clair).
+    async fn llm_service_config(&self) -> RaiseResult<(CollectionsManager<'a>, String, JsonValue)> {
+        let config = AppConfig::get();
+        let (domain, db, _) =
+            config.resolve_system_uri(Some(&LLM_COMPONENT_REF.to_string()), "service_configs");
+        let target = CollectionsManager::new(self.manager.storage, &domain, &db);
+        let component_id = match target.resolve_single_reference(LLM_COMPONENT_REF).await {
+            Ok(uuid) => uuid,
+            Err(_) => LLM_COMPONENT_REF.to_string(),
+        };
+
+        let mut query = Query::new("service_configs");
+        query.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::eq("component_id", json_value!(component_id))],
+        });
+        query.limit = Some(1);
+        let result = QueryEngine::new(&target).execute_query(query).await?;
+
+        let Some(doc) = result.documents.into_iter().next() else {
+            raise_error!(
+                "ERR_CONFIG_NOT_FOUND",
+                error = "Configuration du composant ai_llm introuvable.",
+                context = json_value!({ "target": LLM_COMPONENT_REF })
+            );
+        };
+        let Some(id) = doc.get("_id").and_then(|v| v.as_str()).map(str::to_string) else {
+            raise_error!(
+                "ERR_CONFIG_INVALID_SETTINGS",
+                error = "service_configs sans _id."
+            );
+        };
+        let settings = doc.get("service_settings").cloned().unwrap_or_default();
+        Ok((target, id, settings))
+    }
+}
+
+/// Empreinte SHA-256 d'un fichier, lue par blocs (les GGUF pèsent plusieurs Go).
+async fn check_file(path: &Path, expected: Option<&str>) -> RaiseResult<FileCheck> {
+    let mut file = fs::open_async(path).await?;
+    let mut hasher = CryptoSha256::new();
+    let mut buffer = vec![0u8; 1 << 20];
+    let mut bytes = 0u64;
+    loop {
+        let read = match file.read(&mut buffer).await {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) => raise_error!(
+                "ERR_FS_READ_ASYNC",
+                error = e,
+                context = json_value!({ "path": path.to_string_lossy() })
+            ),
+        };
+        hasher.update(&buffer[..read]);
+        bytes += read as u64;
+    }
+    let sha256 = hex::encode(hasher.finalize());
+    Ok(FileCheck {
+        path: path.to_string_lossy().to_string(),
+        bytes,
+        verified: expected.map(|e| e.eq_ignore_ascii_case(&sha256)),
+        sha256,
+    })
+}
+
+/// Télécharge `url` dans `target` via un fichier `.part`, renommé seulement si l'empreinte
+/// correspond : un transfert interrompu ou altéré ne remplace jamais un modèle valide.
+async fn download_file(
+    url: &str,
+    target: &Path,
+    expected: Option<&str>,
+    token: Option<&str>,
+) -> RaiseResult<FileCheck> {
+    if let Some(parent) = target.parent() {
+        fs::ensure_dir_async(parent).await?;
+    }
+    let part = target.with_extension("part");
+
+    let mut request = get_download_client().get(url);
+    if let Some(token) = token {
+        request = request.header("Authorization", format!("Bearer {}", token));
+    }
+    let mut response = match request.send().await {
+        Ok(r) => r,
+        Err(e) => raise_error!(
+            "ERR_AI_MODEL_DOWNLOAD_FAILED",
+            error = e,
+            context = json_value!({ "url": url })
+        ),
+    };
+    if !response.status().is_success() {
+        raise_error!(
+            "ERR_AI_MODEL_DOWNLOAD_FAILED",
+            error = format!("HTTP {}", response.status().as_u16()),
+            context = json_value!({ "url": url })
+        );
+    }
+
+    let total = response.content_length();
+    user_info!(
+        "MSG_AI_MODEL_DOWNLOAD_START",
+        json_value!({ "url": url, "bytes": total })
+    );
+
+    let mut file = fs::create_async(&part).await?;
+    let mut hasher = CryptoSha256::new();
+    let mut bytes = 0u64;
+    let mut next_report = 10u64;
+    loop {
+        let chunk = match response.chunk().await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(e) => {
+                drop(file);
+                let _ = fs::remove_file_async(&part).await;
+                raise_error!(
+                    "ERR_AI_MODEL_DOWNLOAD_FAILED",
+                    error = e,
+                    context = json_value!({ "url": url, "received": bytes })
+                );
+            }
+        };
+        hasher.update(&chunk);
+        if let Err(e) = file.write_all(&chunk).await {
+            drop(file);
+            let _ = fs::remove_file_async(&part).await;
+            raise_error!(
+                "ERR_FS_WRITE_ASYNC",
+                error = e,
+                context = json_value!({ "path": part.to_string_lossy() })
+            );
+        }
+        bytes += chunk.len() as u64;
+
+        if let Some(total) = total.filter(|t| *t > 0) {
+            let percent = bytes * 100 / total;
+            if percent >= next_report {
+                user_debug!(
+                    "MSG_AI_MODEL_DOWNLOAD_PROGRESS",
+                    json_value!({ "url": url, "percent": percent })
+                );
+                next_report = percent - percent % 10 + 10;
+            }
+        }
+    }
+    file.flush().await.ok();
+    file.sync_all().await.ok();
+    drop(file);
+
+    let sha256 = hex::encode(hasher.finalize());
+    let verified = expected.map(|e| e.eq_ignore_ascii_case(&sha256));
+    if verified == Some(false) {
+        let _ = fs::remove_file_async(&part).await;
+        raise_error!(
+            "ERR_AI_MODEL_CHECKSUM_MISMATCH",
+            error = "L'empreinte SHA-256 du fichier téléchargé ne correspond pas au catalogue.",
+            context = json_value!({ "url": url, "expected": expected, "actual": sha256 })
+        );
+    }
+    fs::rename_async(&part, &target.to_path_buf()).await?;
+
+    Ok(FileCheck {
+        path: target.to_string_lossy().to_string(),
+        bytes,
+        sha256,
+        verified,
+    })
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::network::server::get;
+    use crate::utils::testing::DbSandbox;
+
+    const GGUF_BYTES: &[u8] = b"GGUF fake weights";
+    const TOKENIZER_BYTES: &[u8] = b"{\"model\": {}}";
+
+    fn sha(bytes: &[u8]) -> String {
+        let mut hasher = CryptoSha256::new();
+        hasher.update(bytes);
+        hex::encode(hasher.finalize())
+    }
+
+    /// Mini dépôt HuggingFace local (`/{repo}/resolve/{revision}/{file}`).
+    async fn serve_hub() -> RaiseResult<String> {
+        let router = HttpRouter::new()
+            .route(
+                "/org/tiny-GGUF/resolve/main/tiny-q4.gguf",
+                get(|| async { GGUF_BYTES }),
+            )
+            .route(
+                "/org/tiny/resolve/main/tokenizer.json",
+                get(|| async { TOKENIZER_BYTES }),
+            );
+        let listener = match HttpTcpListener::bind("127.0.0.1:0").await {
+            Ok(l) => l,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        let addr = match listener.local_addr() {
+            Ok(a) => a,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        spawn_async_task(async move {
+            let _ = run_http_server(listener, router).await;
+        });
+        Ok(format!("http://{}", addr))
+    }
+
+    async fn inject_llm_config(manager: &CollectionsManager<'_>, hub: &str) -> RaiseResult<()> {
+        let generic_schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        let _ = manager
+            .create_collection("components", &generic_schema_uri)
+            .await;
+        let _ = manager
+            .create_collection("service_configs", &generic_schema_uri)
+            .await;
+        manager
+            .upsert_document(
+                "components",
+                json_value!({ "_id": "comp_ai_llm", "handle": "ai_llm" }),
+            )
+            .await?;
+        manager
+            .upsert_document(
+                "service_configs",
+                json_value!({
+                    "_id": "cfg_ai_llm_models",
+                    "component_id": LLM_COMPONENT_REF,
+                    "service_settings": {
+                        "rust_model_file": "legacy/legacy.gguf",
+                        "rust_tokenizer_file": "legacy/tokenizer.json",
+                        "hub_url": hub,
+                        "models": [
+                            {
+                                "id": "tiny",
+                                "weights": { "repo": "org/tiny-GGUF", "file": "tiny-q4.gguf", "sha256": sha(GGUF_BYTES) },
+                                "tokenizer": { "repo": "org/tiny", "file": "tokenizer.json" }
+                            },
+                            {
+                                "id": "tampered",
+                                "weights": { "repo": "org/tiny-GGUF", "file": "tiny-q4.gguf", "sha256": sha(b"other") }
+                            }
+                        ]
+                    }
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_download_verifies_checksum() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        inject_llm_config(&manager, &serve_hub().await?).await?;
+
+        let models_dir = sandbox.storage.config.data_root.join("models");
+        let models =
+            LocalModelManager::system(&sandbox.storage).with_models_dir(models_dir.clone());
+
+        let listed = models.list().await?;
+        assert_eq!(listed.len(), 2);
+        assert!(listed.iter().all(|m| !m.installed && !m.active));
+
+        let check = models.download("tiny").await?;
+        assert!(check.is_valid());
+        assert_eq!(check.files[0].verified, Some(true));
+        assert_eq!(check.files[1].verified, None);
+        assert_eq!(
+            fs::read_async(models_dir.join("tiny/tiny-q4.gguf")).await?,
+            GGUF_BYTES
+        );
+        assert!(models.verify("tiny").await?.is_valid());
+
+        // Empreinte divergente : rien n'est installé
+        let Err(AppError::Structured(err)) = models.download("tampered").await else {
+            panic!("Le téléchargement aurait dû être rejeté");
+        };
+        assert_eq!(err.code, "ERR_AI_MODEL_CHECKSUM_MISMATCH");
+        assert!(!fs::exists_async(&models_dir.join("tampered/tiny-q4.gguf")).await);
+        assert!(!fs::exists_async(&models_dir.join("tampered/tiny-q4.part")).await);
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_switch_updates_active_model() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        inject_llm_config(&manager, "http://127.0.0.1:9").await?;
+
+        let models_dir = sandbox.storage.config.data_root.join("models");
+        fs::ensure_dir_async(&models_dir.join("tiny")).await?;
+        fs::write_async(models_dir.join("tiny/tiny-q4.gguf"), GGUF_BYTES).await?;
+        fs::write_async(models_dir.join("tiny/tokenizer.json"), TOKENIZER_BYTES).await?;
+        let models = LocalModelManager::system(&sandbox.storage).with_models_dir(models_dir);
+
+        let Err(AppError::Structured(err)) = models.switch("tampered", None).await else {
+            panic!("Un modèle absent ne peut pas devenir actif");
+        };
+        assert_eq!(err.code, "ERR_AI_MODEL_NOT_INSTALLED");
+
+        let status = models.switch("tiny", None).await?;
+        assert!(status.active);
+        let settings = AppConfig::get_runtime_settings(&manager, LLM_COMPONENT_REF).await?;
+        assert_eq!(settings["rust_model_file"], "tiny/tiny-q4.gguf");
+        assert_eq!(settings["rust_tokenizer_file"], "tiny/tokenizer.json");
+        assert_eq!(settings["models"].as_array().map(|m| m.len()), Some(2));
+
+        let listed = models.list().await?;
+        assert!(listed
+            .iter()
+            .any(|m| m.spec.id == "tiny" && m.active && m.installed));
+        Ok(())
+    }
+}
@@ -26,6 +26,26 @@ impl LlmEngine for NativeTensorEngine {
         // mais la signature du trait reste compatible async.
         self.generate(system, user, max_tokens)
     }
+
+    async fn generate_stream(
+        &mut self,
+        system: &str,
+        user: &str,
+        max_tokens: usize,
+        on_token: &mut (dyn FnMut(&str) -> bool + Send),
+    ) -> RaiseResult<String> {
+        self.generate_streaming(system, user, max_tokens, on_token)
+    }
+
+    /// Le nouvel engine n'est substitué qu'une fois entièrement chargé : en cas d'échec,
+    /// l'ancien modèle reste actif.
+    async fn reload(
+        &mut self,
+        manager: &crate::json_db::collections::manager::CollectionsManager<'_>,
+    ) -> RaiseResult<()> {
+        *self = NativeTensorEngine::new(manager).await?;
+        Ok(())
+    }
 }
 
 impl NativeTensorEngine {
@@ -194,6 +214,18 @@ impl NativeTensorEngine {
         system_prompt: &str,
         user_prompt: &str,
         max_tokens: usize,
+    ) -> RaiseResult<String> {
+        self.generate_streaming(system_prompt, user_prompt, max_tokens, &mut |_| true)
+    }
+
+    /// Génération token par token : `on_token` reçoit chaque fragment de texte décodé
+    /// et peut interrompre la génération en retournant `false` (annulation).
+    pub fn generate_streaming(
+        &mut self,
+        system_prompt: &str,
+        user_prompt: &str,
+        max_tokens: usize,
+        on_token: &mut (dyn FnMut(&str) -> bool + Send),
     ) -> RaiseResult<String> {
         let formatted_prompt = Self::format_prompt(system_prompt, user_prompt);
 
@@ -234,6 +266,7 @@ impl NativeTensorEngine {
         };
 
         let stop_token_id = self.tokenizer.token_to_id("<|endoftext|>");
+        let mut emitted_len = 0;
 
         for _i in 0..max_tokens {
             let context_size = if index_pos == 0 { tokens.len() } else { 1 };
@@ -276,6 +309,21 @@ impl NativeTensorEngine {
             tokens.push(next_token);
             generated_tokens.push(next_token);
             index_pos += context_size;
+
+            // Décodage incrémental : un token peut ne porter qu'une partie d'un caractère UTF-8,
+            // on n'émet que le texte stabilisé depuis le dernier fragment.
+            if let Ok(text) = self.tokenizer.decode(&generated_tokens, true) {
+                if text.len() > emitted_len
+                    && text.is_char_boundary(emitted_len)
+                    && !text.ends_with('\u{FFFD}')
+                {
+                    let keep_going = on_token(&text[emitted_len..]);
+                    emitted_len = text.len();
+                    if !keep_going {
+                        break;
+                    }
+                }
+            }
         }
 
         match self.tokenizer.decode(&generated_tokens, true) {
@@ -160,12 +160,14 @@ pub async fn ask(
 
     // 6. Appel HTTP Sécurisé avec Retry & Custom Headers (Zéro Dette Locale)
     let client = get_client();
+    let breaker = CircuitBreaker::global();
     let mut attempt = 0;
     let max_retries = 3;
     let mut delay = TimeDuration::from_secs(1);
 
     let response_data: ClaudeResponse = loop {
         attempt += 1;
+        breaker.acquire(url)?;
 
         let request_builder = client
             .post(url)
@@ -173,10 +175,17 @@ pub async fn ask(
             .header("anthropic-version", "2023-06-01") // Version standard de l'API Messages
             .json(&request_body);
 
+        let started = TimeInstant::now();
         match request_builder.send().await {
             Ok(response) => {
                 let status = response.status();
 
+                if status.is_server_error() || status == HttpStatusCode::TOO_MANY_REQUESTS {
+                    breaker.record_failure(url, &format!("HTTP {}", status.as_u16()));
+                } else {
+                    breaker.record_success(url, started.elapsed());
+                }
+
                 if status.is_success() {
                     match response.json::<ClaudeResponse>().await {
                         Ok(data) => break data, // Sortie de boucle victorieuse
@@ -205,6 +214,7 @@ pub async fn ask(
                 }
             }
             Err(e) => {
+                breaker.record_failure(url, &e.to_string());
                 crate::user_warn!(
                     "NET_CONN_FAILED",
                     json_value!({ "provider": "AnthropicClaude", "error": e.to_string(), "attempt": attempt })
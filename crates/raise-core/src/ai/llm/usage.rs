@@ -0,0 +1,354 @@
+// FICHIER : src-tauri/src/ai/llm/usage.rs
+
+//! Suivi des coûts LLM : chaque appel est consigné dans la collection système `ai_usage`
+//! (tokens × tarif du backend) et les budgets mensuels d'`AppConfig.ai_budget` sont
+//! appliqués avant l'appel (avertissement au-delà du seuil, blocage au-delà du budget).
+//! Les fournisseurs ne renvoyant que du texte, les tokens sont estimés (~4 caractères/token).
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
+use crate::json_db::storage::StorageEngine;
+use crate::utils::data::config::AiBudgetConfig;
+use crate::utils::prelude::*;
+
+/// Collection système des consommations LLM (un document par appel).
+pub const AI_USAGE_COLLECTION: &str = "ai_usage";
+
+/// Estimation grossière du nombre de tokens d'un texte.
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(4)
+}
+
+/// Mois comptable courant (`AAAA-MM`).
+pub fn current_month() -> String {
+    UtcClock::now().format("%Y-%m").to_string()
+}
+
+/// Consommation d'un appel LLM.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct UsageRecord {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub session_id: String,
+    /// Projet facturé (`domaine/db`).
+    pub project: String,
+    pub backend: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost: f64,
+    pub month: String,
+    pub timestamp: String,
+}
+
+/// Agrégat d'une dimension du tableau de bord.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct UsageTotals {
+    pub key: String,
+    pub calls: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost: f64,
+    /// Budget applicable et part consommée (projets uniquement).
+    pub budget: Option<f64>,
+    pub budget_ratio: Option<f64>,
+}
+
+/// Tableau de bord mensuel des consommations.
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct UsageDashboard {
+    pub month: String,
+    pub total: UsageTotals,
+    pub by_project: Vec<UsageTotals>,
+    pub by_session: Vec<UsageTotals>,
+    pub by_backend: Vec<UsageTotals>,
+}
+
+/// État d'un projet au regard de son budget.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BudgetStatus {
+    Unlimited,
+    Ok { spent: f64, budget: f64 },
+    Warning { spent: f64, budget: f64 },
+    Exceeded { spent: f64, budget: f64 },
+}
+
+/// Registre des consommations, stocké dans la partition système par défaut.
+pub struct UsageTracker<'a> {
+    manager: CollectionsManager<'a>,
+    config: AiBudgetConfig,
+}
+
+impl<'a> UsageTracker<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str, config: AiBudgetConfig) -> Self {
+        Self {
+            manager: CollectionsManager::new(storage, space, db),
+            config,
+        }
+    }
+
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+            config.ai_budget.clone(),
+        )
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, AI_USAGE_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(AI_USAGE_COLLECTION, &schema_uri)
+            .await
+    }
+
+    pub fn cost_of(&self, backend: &str, input_tokens: u64, output_tokens: u64) -> f64 {
+        let Some(price) = self.config.prices.get(backend) else {
+            return 0.0;
+        };
+        (input_tokens as f64 * price.input_per_mtok + output_tokens as f64 * price.output_per_mtok)
+            / 1_000_000.0
+    }
+
+    fn budget_for(&self, project: &str) -> Option<f64> {
+        self.config
+            .project_budgets
+            .get(project)
+            .copied()
+            .or(self.config.monthly_budget)
+    }
+
+    /// Consigne un appel et retourne l'enregistrement créé.
+    pub async fn record(
+        &self,
+        session_id: &str,
+        project: &str,
+        backend: &str,
+        prompt: &str,
+        response: &str,
+    ) -> RaiseResult<UsageRecord> {
+        self.ensure_collection().await?;
+        let input_tokens = estimate_tokens(prompt);
+        let output_tokens = estimate_tokens(response);
+        let record = UsageRecord {
+            id: UniqueId::new_v4().to_string(),
+            session_id: session_id.to_string(),
+            project: project.to_string(),
+            backend: backend.to_string(),
+            input_tokens,
+            output_tokens,
+            cost: self.cost_of(backend, input_tokens, output_tokens),
+            month: current_month(),
+            timestamp: UtcClock::now().to_rfc3339(),
+        };
+        self.manager
+            .insert_raw(AI_USAGE_COLLECTION, &json::serialize_to_value(&record)?)
+            .await?;
+        Ok(record)
+    }
+
+    async fn month_records(&self, month: &str) -> RaiseResult<Vec<UsageRecord>> {
+        if self
+            .manager
+            .collection_schema_uri(AI_USAGE_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(vec![]);
+        }
+        let mut query = Query::new(AI_USAGE_COLLECTION);
+        query.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::eq("month", json_value!(month))],
+        });
+        let result = QueryEngine::new(&self.manager).execute_query(query).await?;
+        result
+            .documents
+            .into_iter()
+            .map(json::deserialize_from_value)
+            .collect()
+    }
+
+    /// Dépense du mois courant pour un projet, comparée à son budget.
+    pub async fn budget_status(&self, project: &str) -> RaiseResult<BudgetStatus> {
+        let Some(budget) = self.budget_for(project) else {
+            return Ok(BudgetStatus::Unlimited);
+        };
+        let spent: f64 = self
+            .month_records(&current_month())
+            .await?
+            .iter()
+            .filter(|r| !self.config.project_budgets.contains_key(project) || r.project == project)
+            .map(|r| r.cost)
+            .sum();
+        Ok(if spent >= budget {
+            BudgetStatus::Exceeded { spent, budget }
+        } else if spent >= budget * self.config.soft_warning_ratio {
+            BudgetStatus::Warning { spent, budget }
+        } else {
+            BudgetStatus::Ok { spent, budget }
+        })
+    }
+
+    /// Garde-fou avant un appel : avertit au-delà du seuil, refuse au-delà du budget.
+    pub async fn enforce(&self, project: &str) -> RaiseResult<BudgetStatus> {
+        let status = self.budget_status(project).await?;
+        match &status {
+            BudgetStatus::Exceeded { spent, budget } => raise_error!(
+                "ERR_AI_BUDGET_EXCEEDED",
+                error = format!(
+                    "Budget LLM mensuel épuisé pour '{}' ({:.2} / {:.2}).",
+                    project, spent, budget
+                ),
+                context = json_value!({ "project": project, "spent": spent, "budget": budget, "month": current_month() })
+            ),
+            BudgetStatus::Warning { spent, budget } => user_warn!(
+                "WRN_AI_BUDGET_THRESHOLD",
+                json_value!({ "project": project, "spent": spent, "budget": budget })
+            ),
+            _ => {}
+        }
+        Ok(status)
+    }
+
+    /// Tableau de bord d'un mois (courant par défaut).
+    pub async fn dashboard(&self, month: Option<&str>) -> RaiseResult<UsageDashboard> {
+        let month = month.map(str::to_string).unwrap_or_else(current_month);
+        let records = self.month_records(&month).await?;
+
+        let mut total = UsageTotals {
+            key: month.clone(),
+            ..Default::default()
+        };
+        let mut by_project: OrderedMap<String, UsageTotals> = OrderedMap::new();
+        let mut by_session: OrderedMap<String, UsageTotals> = OrderedMap::new();
+        let mut by_backend: OrderedMap<String, UsageTotals> = OrderedMap::new();
+        for record in &records {
+            accumulate(&mut total, record);
+            for (map, key) in [
+                (&mut by_project, &record.project),
+                (&mut by_session, &record.session_id),
+                (&mut by_backend, &record.backend),
+            ] {
+                let entry = map.entry(key.clone()).or_insert_with(|| UsageTotals {
+                    key: key.clone(),
+                    ..Default::default()
+                });
+                accumulate(entry, record);
+            }
+        }
+
+        let by_project = by_project
+            .into_values()
+            .map(|mut t| {
+                t.budget = self.budget_for(&t.key);
+                t.budget_ratio = t.budget.filter(|b| *b > 0.0).map(|b| t.cost / b);
+                t
+            })
+            .collect();
+        Ok(UsageDashboard {
+            month,
+            total,
+            by_project,
+            by_session: sorted_by_cost(by_session),
+            by_backend: sorted_by_cost(by_backend),
+        })
+    }
+}
+
+fn accumulate(totals: &mut UsageTotals, record: &UsageRecord) {
+    totals.calls += 1;
+    totals.input_tokens += record.input_tokens;
+    totals.output_tokens += record.output_tokens;
+    totals.cost += record.cost;
+}
+
+fn sorted_by_cost(map: OrderedMap<String, UsageTotals>) -> Vec<UsageTotals> {
+    let mut totals: Vec<UsageTotals> = map.into_values().collect();
+    totals.sort_by(|a, b| b.cost.total_cmp(&a.cost));
+    totals
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::data::config::LlmPrice;
+    use crate::utils::testing::DbSandbox;
+
+    fn priced_config() -> AiBudgetConfig {
+        let mut config = AiBudgetConfig::default();
+        config.prices.insert(
+            "claude".into(),
+            LlmPrice {
+                input_per_mtok: 1_000_000.0,
+                output_per_mtok: 2_000_000.0,
+            },
+        );
+        config.project_budgets.insert("acme/model".into(), 20.0);
+        config
+    }
+
+    #[test]
+    fn test_cost_estimation() {
+        assert_eq!(estimate_tokens("abcdefgh"), 2);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        assert_eq!(estimate_tokens(""), 0);
+    }
+
+    #[async_test]
+    async fn test_budget_warning_then_hard_stop() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "usage");
+        DbSandbox::mock_db(&manager).await?;
+        let tracker = UsageTracker::new(&sandbox.storage, "test", "usage", priced_config());
+
+        assert!(matches!(
+            tracker.enforce("acme/model").await?,
+            BudgetStatus::Ok { .. }
+        ));
+        assert_eq!(tracker.enforce("other/db").await?, BudgetStatus::Unlimited);
+
+        // 8 caractères -> 2 tokens d'entrée (2.0), 4 caractères -> 1 token de sortie (2.0)
+        let record = tracker
+            .record("s1", "acme/model", "claude", "abcdefgh", "abcd")
+            .await?;
+        assert_eq!(record.cost, 4.0);
+        for _ in 0..3 {
+            tracker
+                .record("s2", "acme/model", "claude", "abcdefgh", "abcd")
+                .await?;
+        }
+        assert!(matches!(
+            tracker.enforce("acme/model").await?,
+            BudgetStatus::Warning { .. }
+        ));
+        tracker
+            .record("s2", "acme/model", "claude", "abcdefgh", "abcd")
+            .await?;
+        assert!(tracker.enforce("acme/model").await.is_err());
+
+        let dashboard = tracker.dashboard(None).await?;
+        assert_eq!(dashboard.total.calls, 5);
+        assert_eq!(dashboard.total.cost, 20.0);
+        assert_eq!(dashboard.by_session[0].key, "s2");
+        assert_eq!(dashboard.by_project[0].budget_ratio, Some(1.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,924 @@
+// FICHIER : src-tauri/src/ai/maintenance.rs
+
+//! Tâches de maintenance IA planifiées (syntaxe cron), exécutées en arrière-plan plutôt
+//! qu'au détour d'une requête utilisateur :
+//! - résumé des sessions d'agents trop longues (historique compacté + résumé cumulatif) ;
+//! - optimisation de l'index vectoriel (lignes orphelines et doublons) ;
+//! - instantané de dérive des embeddings (centroïde comparé à l'instantané précédent).
+//!
+//! Chaque exécution est historisée dans la collection système `ai_maintenance_runs`.
+
+use crate::ai::llm::client::LlmClient;
+use crate::ai::memory::migration::read_manifest;
+use crate::ai::memory::native_store::NativeLocalStore;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+/// Historique des exécutions (un document par exécution).
+pub const MAINTENANCE_RUNS_COLLECTION: &str = "ai_maintenance_runs";
+/// Instantanés de dérive des embeddings.
+pub const DRIFT_SNAPSHOTS_COLLECTION: &str = "ai_drift_snapshots";
+/// Sessions d'agents (cf. `agents::tools::save_session`).
+pub const AGENT_SESSIONS_COLLECTION: &str = "session_agents";
+/// Longueur maximale conservée pour un résumé cumulatif.
+const MAX_SUMMARY_CHARS: usize = 4_000;
+
+// =========================================================================
+// EXPRESSIONS CRON
+// =========================================================================
+
+/// Expression cron à 5 champs (minute heure jour-du-mois mois jour-de-semaine), en UTC.
+/// Chaque champ accepte `*`, une valeur, une plage `a-b`, un pas `*/n` ou `a-b/n`, et des listes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: Vec<bool>,
+    hours: Vec<bool>,
+    days: Vec<bool>,
+    months: Vec<bool>,
+    weekdays: Vec<bool>,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Option<Vec<bool>> {
+    let mut allowed = vec![false; max as usize + 1];
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((r, s)) => (r, s.parse::<u32>().ok().filter(|s| *s > 0)?),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (a.parse().ok()?, b.parse().ok()?),
+                None => {
+                    let v = range.parse().ok()?;
+                    // `5/15` : de 5 jusqu'au maximum
+                    (v, if part.contains('/') { max } else { v })
+                }
+            },
+        };
+        if start < min || end > max || start > end {
+            return None;
+        }
+        for v in (start..=end).step_by(step as usize) {
+            allowed[v as usize] = true;
+        }
+    }
+    Some(allowed)
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> RaiseResult<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let parsed = match fields.as_slice() {
+            [m, h, dom, mon, dow] => (|| {
+                let mut weekdays = parse_cron_field(dow, 0, 7)?;
+                // 0 et 7 désignent tous deux le dimanche
+                weekdays[0] |= weekdays[7];
+                Some(Self {
+                    expression: expression.trim().to_string(),
+                    minutes: parse_cron_field(m, 0, 59)?,
+                    hours: parse_cron_field(h, 0, 23)?,
+                    days: parse_cron_field(dom, 1, 31)?,
+                    months: parse_cron_field(mon, 1, 12)?,
+                    weekdays,
+                    days_restricted: *dom != "*",
+                    weekdays_restricted: *dow != "*",
+                })
+            })(),
+            _ => None,
+        };
+        match parsed {
+            Some(schedule) => Ok(schedule),
+            None => raise_error!(
+                "ERR_CRON_EXPRESSION_INVALID",
+                error = format!("Expression cron invalide : '{}'.", expression),
+                context = json_value!({
+                    "expression": expression,
+                    "hint": "5 champs attendus : minute heure jour mois jour-de-semaine (ex: '30 2 * * *')."
+                })
+            ),
+        }
+    }
+
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    fn matches_day(&self, at: &UtcTimestamp) -> bool {
+        if !self.months[at.month() as usize] {
+            return false;
+        }
+        let dom = self.days[at.day() as usize];
+        let dow = self.weekdays[at.weekday().num_days_from_sunday() as usize];
+        // Sémantique cron : si les deux champs sont restreints, l'un OU l'autre suffit
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => dom || dow,
+            (true, false) => dom,
+            (false, true) => dow,
+            (false, false) => true,
+        }
+    }
+
+    /// La minute contenant `at` est-elle planifiée ?
+    pub fn matches(&self, at: &UtcTimestamp) -> bool {
+        self.matches_day(at) && self.hours[at.hour() as usize] && self.minutes[at.minute() as usize]
+    }
+
+    /// Prochaine minute planifiée strictement après `after` (recherche bornée à 4 ans).
+    pub fn next_after(&self, after: &UtcTimestamp) -> Option<UtcTimestamp> {
+        let start = after.timestamp() - after.timestamp().rem_euclid(60) + 60;
+        let mut at = UtcTimestamp::from_timestamp(start, 0)?;
+        let limit = *after + CalendarDuration::days(4 * 366);
+        while at <= limit {
+            if !self.matches_day(&at) {
+                // Saut direct au lendemain minuit
+                let midnight = at.timestamp() - at.timestamp().rem_euclid(86_400) + 86_400;
+                at = UtcTimestamp::from_timestamp(midnight, 0)?;
+                continue;
+            }
+            if self.hours[at.hour() as usize] && self.minutes[at.minute() as usize] {
+                return Some(at);
+            }
+            at += CalendarDuration::minutes(1);
+        }
+        None
+    }
+}
+
+// =========================================================================
+// CONFIGURATION & HISTORIQUE
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceTask {
+    SummarizeSessions,
+    OptimizeVectorIndex,
+    DriftSnapshot,
+}
+
+impl MaintenanceTask {
+    pub const ALL: [MaintenanceTask; 3] = [
+        Self::SummarizeSessions,
+        Self::OptimizeVectorIndex,
+        Self::DriftSnapshot,
+    ];
+}
+
+/// Planification d'une tâche.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct MaintenanceJob {
+    pub task: MaintenanceTask,
+    pub cron: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Réglages du composant `ai_maintenance` (`service_settings`).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct MaintenanceConfig {
+    #[serde(default = "default_jobs")]
+    pub jobs: Vec<MaintenanceJob>,
+    /// Au-delà de ce nombre de messages, une session est résumée.
+    #[serde(default = "default_session_max_messages")]
+    pub session_max_messages: usize,
+    /// Messages récents conservés tels quels après résumé.
+    #[serde(default = "default_session_keep_recent")]
+    pub session_keep_recent: usize,
+}
+
+fn default_jobs() -> Vec<MaintenanceJob> {
+    let job = |task, cron: &str| MaintenanceJob {
+        task,
+        cron: cron.to_string(),
+        enabled: true,
+    };
+    vec![
+        job(MaintenanceTask::SummarizeSessions, "0 2 * * *"),
+        job(MaintenanceTask::OptimizeVectorIndex, "30 2 * * *"),
+        job(MaintenanceTask::DriftSnapshot, "0 3 * * 1"),
+    ]
+}
+
+fn default_session_max_messages() -> usize {
+    40
+}
+
+fn default_session_keep_recent() -> usize {
+    10
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            jobs: default_jobs(),
+            session_max_messages: default_session_max_messages(),
+            session_keep_recent: default_session_keep_recent(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum RunTrigger {
+    Scheduled,
+    Manual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    Succeeded,
+    Failed,
+}
+
+/// Exécution historisée d'une tâche.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct MaintenanceRun {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub task: MaintenanceTask,
+    pub trigger: RunTrigger,
+    pub status: RunStatus,
+    pub started_at: UtcTimestamp,
+    pub finished_at: UtcTimestamp,
+    /// Bilan propre à la tâche (sessions résumées, lignes retirées, dérive...).
+    #[serde(default)]
+    pub details: JsonValue,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Prochaine échéance d'une tâche planifiée.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ScheduleEntry {
+    pub task: MaintenanceTask,
+    pub cron: String,
+    pub enabled: bool,
+    pub next_run: Option<UtcTimestamp>,
+}
+
+/// Statistiques d'un index vectoriel à un instant donné.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct DriftSnapshot {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub collection: String,
+    pub model: Option<String>,
+    pub vectors: usize,
+    pub dimension: usize,
+    pub centroid: Vec<f32>,
+    pub mean_norm: f32,
+    /// Distance cosinus au centroïde de l'instantané précédent (None si incomparable).
+    pub drift: Option<f32>,
+    pub taken_at: UtcTimestamp,
+}
+
+fn cosine_distance(a: &[f32], b: &[f32]) -> Option<f32> {
+    if a.len() != b.len() || a.is_empty() {
+        return None;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let na = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let nb = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if na == 0.0 || nb == 0.0 {
+        return None;
+    }
+    Some(1.0 - dot / (na * nb))
+}
+
+/// Résumé extractif (sans LLM) : première phrase de chaque message, après le résumé existant.
+pub fn extractive_summary(previous: Option<&str>, messages: &[JsonValue]) -> String {
+    let mut lines: Vec<String> = previous
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| vec![p.trim().to_string()])
+        .unwrap_or_default();
+    for msg in messages {
+        let content = msg["content"].as_str().unwrap_or("").trim();
+        let sentence = content
+            .split_inclusive(['.', '!', '?', '\n'])
+            .next()
+            .unwrap_or(content)
+            .trim();
+        if sentence.is_empty() {
+            continue;
+        }
+        let sentence: String = sentence.chars().take(200).collect();
+        lines.push(format!(
+            "- {}: {}",
+            msg["role"].as_str().unwrap_or("?"),
+            sentence
+        ));
+    }
+    let summary = lines.join("\n");
+    // Résumé cumulatif borné : on conserve la partie la plus récente
+    let overflow = summary.chars().count().saturating_sub(MAX_SUMMARY_CHARS);
+    summary.chars().skip(overflow).collect()
+}
+
+// =========================================================================
+// PLANIFICATEUR
+// =========================================================================
+
+/// Planificateur des tâches de maintenance (base système par défaut).
+pub struct MaintenanceScheduler {
+    storage: SharedRef<StorageEngine>,
+    space: String,
+    db: String,
+    llm: Option<LlmClient>,
+    vector_store: Option<(SharedRef<NativeLocalStore>, String)>,
+}
+
+impl MaintenanceScheduler {
+    pub fn new(storage: SharedRef<StorageEngine>, space: &str, db: &str) -> Self {
+        Self {
+            storage,
+            space: space.to_string(),
+            db: db.to_string(),
+            llm: None,
+            vector_store: None,
+        }
+    }
+
+    pub fn system(storage: SharedRef<StorageEngine>) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    /// Résumés rédigés par le LLM (à défaut, résumé extractif).
+    pub fn with_llm(mut self, llm: LlmClient) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    /// Index vectoriel partagé (ex: celui du `RagRetriever`) : évite qu'un cache mémoire
+    /// concurrent ne réécrive un index obsolète après optimisation.
+    pub fn with_vector_store(
+        mut self,
+        store: SharedRef<NativeLocalStore>,
+        collection: &str,
+    ) -> Self {
+        self.vector_store = Some((store, collection.to_string()));
+        self
+    }
+
+    fn manager(&self) -> CollectionsManager<'_> {
+        CollectionsManager::new(&self.storage, &self.space, &self.db)
+    }
+
+    async fn ensure_collection(&self, collection: &str) -> RaiseResult<()> {
+        let mgr = self.manager();
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, collection)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(collection, &schema_uri).await
+    }
+
+    /// Réglages du composant `ai_maintenance` (valeurs par défaut s'il n'est pas configuré).
+    pub async fn config(&self) -> RaiseResult<MaintenanceConfig> {
+        match AppConfig::get_runtime_settings(
+            &self.manager(),
+            "ref:components:handle:ai_maintenance",
+        )
+        .await
+        {
+            Ok(settings) => Ok(json::deserialize_from_value(settings)?),
+            Err(_) => Ok(MaintenanceConfig::default()),
+        }
+    }
+
+    /// Planification effective et prochaine échéance de chaque tâche.
+    pub async fn schedule(&self, now: &UtcTimestamp) -> RaiseResult<Vec<ScheduleEntry>> {
+        let config = self.config().await?;
+        config
+            .jobs
+            .into_iter()
+            .map(|job| {
+                let next_run = match job.enabled {
+                    true => CronSchedule::parse(&job.cron)?.next_after(now),
+                    false => None,
+                };
+                Ok(ScheduleEntry {
+                    task: job.task,
+                    cron: job.cron,
+                    enabled: job.enabled,
+                    next_run,
+                })
+            })
+            .collect()
+    }
+
+    /// Exécute les tâches planifiées sur la minute de `now`.
+    pub async fn run_due(&self, now: &UtcTimestamp) -> RaiseResult<Vec<MaintenanceRun>> {
+        let config = self.config().await?;
+        let mut runs = Vec::new();
+        for job in config.jobs.iter().filter(|j| j.enabled) {
+            let schedule = match CronSchedule::parse(&job.cron) {
+                Ok(s) => s,
+                Err(e) => {
+                    user_warn!(
+                        "WRN_AI_MAINTENANCE_CRON_INVALID",
+                        json_value!({ "task": job.task, "cron": job.cron, "error": e.to_string() })
+                    );
+                    continue;
+                }
+            };
+            if schedule.matches(now) {
+                runs.push(self.run_task(job.task, RunTrigger::Scheduled).await?);
+            }
+        }
+        Ok(runs)
+    }
+
+    /// Exécute une tâche et historise son résultat (un échec est consigné, pas propagé).
+    pub async fn run_task(
+        &self,
+        task: MaintenanceTask,
+        trigger: RunTrigger,
+    ) -> RaiseResult<MaintenanceRun> {
+        let started_at = UtcClock::now();
+        let outcome = match task {
+            MaintenanceTask::SummarizeSessions => self.summarize_sessions().await,
+            MaintenanceTask::OptimizeVectorIndex => self.optimize_vector_index().await,
+            MaintenanceTask::DriftSnapshot => self.drift_snapshot().await,
+        };
+        let (status, details, error) = match outcome {
+            Ok(details) => (RunStatus::Succeeded, details, None),
+            Err(e) => (RunStatus::Failed, JsonValue::Null, Some(e.to_string())),
+        };
+        let run = MaintenanceRun {
+            id: format!("run_{}", UniqueId::new_v4()),
+            task,
+            trigger,
+            status,
+            started_at,
+            finished_at: UtcClock::now(),
+            details,
+            error,
+        };
+
+        self.ensure_collection(MAINTENANCE_RUNS_COLLECTION).await?;
+        self.manager()
+            .upsert_document(MAINTENANCE_RUNS_COLLECTION, json::serialize_to_value(&run)?)
+            .await?;
+        match run.status {
+            RunStatus::Succeeded => user_info!(
+                "INF_AI_MAINTENANCE_RUN",
+                json_value!({ "task": run.task, "details": run.details })
+            ),
+            RunStatus::Failed => user_warn!(
+                "WRN_AI_MAINTENANCE_RUN_FAILED",
+                json_value!({ "task": run.task, "error": run.error })
+            ),
+        }
+        Ok(run)
+    }
+
+    /// Historique des exécutions, du plus récent au plus ancien.
+    pub async fn history(
+        &self,
+        task: Option<MaintenanceTask>,
+        limit: usize,
+    ) -> RaiseResult<Vec<MaintenanceRun>> {
+        let manager = self.manager();
+        if manager
+            .collection_schema_uri(MAINTENANCE_RUNS_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(vec![]);
+        }
+        let mut runs: Vec<MaintenanceRun> = manager
+            .list_all(MAINTENANCE_RUNS_COLLECTION)
+            .await?
+            .into_iter()
+            .map(json::deserialize_from_value)
+            .collect::<RaiseResult<_>>()?;
+        runs.retain(|r| task.is_none_or(|t| r.task == t));
+        runs.sort_by_key(|r| std::cmp::Reverse(r.started_at));
+        runs.truncate(limit);
+        Ok(runs)
+    }
+
+    /// Boucle d'arrière-plan : réveil à chaque début de minute, exécution des tâches échues.
+    pub fn spawn(self: SharedRef<Self>) -> tokio::task::JoinHandle<()> {
+        spawn_async_task(async move {
+            loop {
+                let now = UtcClock::now();
+                let wait = 60 - now.timestamp().rem_euclid(60) as u64;
+                sleep_async(TimeDuration::from_secs(wait)).await;
+                if let Err(e) = self.run_due(&UtcClock::now()).await {
+                    user_warn!(
+                        "WRN_AI_MAINTENANCE_TICK_FAILED",
+                        json_value!({ "error": e.to_string() })
+                    );
+                }
+            }
+        })
+    }
+
+    // --- Tâches ---
+
+    async fn summarize_sessions(&self) -> RaiseResult<JsonValue> {
+        let config = self.config().await?;
+        let manager = self.manager();
+        if manager
+            .collection_schema_uri(AGENT_SESSIONS_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(json_value!({ "sessions_scanned": 0, "sessions_summarized": 0 }));
+        }
+
+        let keep = config.session_keep_recent.min(config.session_max_messages);
+        let sessions = manager.list_all(AGENT_SESSIONS_COLLECTION).await?;
+        let (mut summarized, mut compacted) = (0, 0);
+        for mut doc in sessions.iter().cloned() {
+            let messages = doc["messages"].as_array().cloned().unwrap_or_default();
+            if messages.len() <= config.session_max_messages {
+                continue;
+            }
+            let (older, recent) = messages.split_at(messages.len() - keep);
+            let summary = self.summarize(doc["summary"].as_str(), older).await;
+
+            if let Some(obj) = doc.as_object_mut() {
+                obj.insert("summary".into(), json_value!(summary));
+                obj.insert("messages".into(), json_value!(recent));
+                obj.insert(
+                    "summary_updated_at".into(),
+                    json_value!(UtcClock::now().to_rfc3339()),
+                );
+            }
+            manager
+                .upsert_document(AGENT_SESSIONS_COLLECTION, doc)
+                .await?;
+            summarized += 1;
+            compacted += older.len();
+        }
+        Ok(json_value!({
+            "sessions_scanned": sessions.len(),
+            "sessions_summarized": summarized,
+            "messages_compacted": compacted
+        }))
+    }
+
+    async fn summarize(&self, previous: Option<&str>, messages: &[JsonValue]) -> String {
+        if let Some(llm) = &self.llm {
+            let transcript: Vec<String> = messages
+                .iter()
+                .map(|m| {
+                    format!(
+                        "{}: {}",
+                        m["role"].as_str().unwrap_or("?"),
+                        m["content"].as_str().unwrap_or("")
+                    )
+                })
+                .collect();
+            let prompt = format!(
+                "Résumé actuel :\n{}\n\nNouveaux échanges :\n{}\n\nMets à jour le résumé en conservant les décisions, éléments du modèle et points ouverts.",
+                previous.unwrap_or("(aucun)"),
+                transcript.join("\n")
+            );
+            match llm.generate(&prompt).await {
+                Ok(summary) if !summary.trim().is_empty() => return summary.trim().to_string(),
+                Ok(_) => {}
+                Err(e) => user_warn!(
+                    "WRN_AI_MAINTENANCE_SUMMARY_LLM",
+                    json_value!({ "error": e.to_string(), "fallback": "extractive" })
+                ),
+            }
+        }
+        extractive_summary(previous, messages)
+    }
+
+    /// Index vectoriel ciblé : celui fourni, sinon la base de connaissances RAG.
+    async fn target_store(&self) -> RaiseResult<(SharedRef<NativeLocalStore>, String)> {
+        if let Some((store, collection)) = &self.vector_store {
+            return Ok((store.clone(), collection.clone()));
+        }
+        let manager = self.manager();
+        let collection =
+            AppConfig::get_runtime_settings(&manager, "ref:components:handle:context_rag")
+                .await
+                .ok()
+                .and_then(|s| s["collection_name"].as_str().map(str::to_string))
+                .unwrap_or_else(|| "raise_knowledge_base".to_string());
+        let store = NativeLocalStore::new(&manager, &ComputeHardware::Cpu).await?;
+        Ok((SharedRef::new(store), collection))
+    }
+
+    async fn optimize_vector_index(&self) -> RaiseResult<JsonValue> {
+        let (store, collection) = self.target_store().await?;
+        let report = store.compact(&self.manager(), &collection).await?;
+        json::serialize_to_value(&report)
+    }
+
+    async fn drift_snapshot(&self) -> RaiseResult<JsonValue> {
+        let (store, collection) = self.target_store().await?;
+        let manager = self.manager();
+        let (_, vectors) = store.export_index(&manager, &collection).await?;
+
+        let dimension = vectors.first().map(Vec::len).unwrap_or(0);
+        let mut centroid = vec![0.0f32; dimension];
+        for v in &vectors {
+            for (c, x) in centroid.iter_mut().zip(v) {
+                *c += x;
+            }
+        }
+        if !vectors.is_empty() {
+            centroid.iter_mut().for_each(|c| *c /= vectors.len() as f32);
+        }
+        let mean_norm = match vectors.len() {
+            0 => 0.0,
+            n => {
+                vectors
+                    .iter()
+                    .map(|v| v.iter().map(|x| x * x).sum::<f32>().sqrt())
+                    .sum::<f32>()
+                    / n as f32
+            }
+        };
+
+        let previous = self.last_snapshot(&collection).await?;
+        let model = read_manifest(&manager, &collection).await?.map(|m| m.model);
+        let drift = previous
+            .filter(|p| p.model == model)
+            .and_then(|p| cosine_distance(&p.centroid, &centroid));
+
+        let snapshot = DriftSnapshot {
+            id: format!("drift_{}", UniqueId::new_v4()),
+            collection,
+            model,
+            vectors: vectors.len(),
+            dimension,
+            centroid,
+            mean_norm,
+            drift,
+            taken_at: UtcClock::now(),
+        };
+        self.ensure_collection(DRIFT_SNAPSHOTS_COLLECTION).await?;
+        manager
+            .upsert_document(
+                DRIFT_SNAPSHOTS_COLLECTION,
+                json::serialize_to_value(&snapshot)?,
+            )
+            .await?;
+        Ok(json_value!({
+            "snapshot": snapshot.id,
+            "collection": snapshot.collection,
+            "vectors": snapshot.vectors,
+            "mean_norm": snapshot.mean_norm,
+            "drift": snapshot.drift
+        }))
+    }
+
+    /// Dernier instantané de dérive d'une collection.
+    pub async fn last_snapshot(&self, collection: &str) -> RaiseResult<Option<DriftSnapshot>> {
+        let manager = self.manager();
+        if manager
+            .collection_schema_uri(DRIFT_SNAPSHOTS_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(None);
+        }
+        let snapshots: Vec<DriftSnapshot> = manager
+            .list_all(DRIFT_SNAPSHOTS_COLLECTION)
+            .await?
+            .into_iter()
+            .map(json::deserialize_from_value)
+            .collect::<RaiseResult<_>>()?;
+        Ok(snapshots
+            .into_iter()
+            .filter(|s| s.collection == collection)
+            .max_by_key(|s| s.taken_at))
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::memory::{MemoryRecord, VectorStore};
+    use crate::utils::testing::DbSandbox;
+
+    fn at(rfc3339: &str) -> UtcTimestamp {
+        parse_system_time(rfc3339).unwrap()
+    }
+
+    #[test]
+    fn test_cron_parsing_and_next_run() {
+        let nightly = CronSchedule::parse("30 2 * * *").unwrap();
+        assert!(nightly.matches(&at("2026-10-15T02:30:45Z")));
+        assert!(!nightly.matches(&at("2026-10-15T02:31:00Z")));
+        assert_eq!(
+            nightly.next_after(&at("2026-10-15T02:30:00Z")),
+            Some(at("2026-10-16T02:30:00Z"))
+        );
+
+        // Lundi 3h (et 0/7 = dimanche)
+        let weekly = CronSchedule::parse("0 3 * * 1").unwrap();
+        assert_eq!(
+            weekly.next_after(&at("2026-10-15T12:00:00Z")),
+            Some(at("2026-10-19T03:00:00Z"))
+        );
+        let sunday = CronSchedule::parse("0 0 * * 7").unwrap();
+        assert!(sunday.matches(&at("2026-10-18T00:00:00Z")));
+
+        let every_quarter = CronSchedule::parse("*/15 8-18 * * 1-5").unwrap();
+        assert!(every_quarter.matches(&at("2026-10-15T08:45:00Z")));
+        assert!(!every_quarter.matches(&at("2026-10-15T08:50:00Z")));
+
+        for invalid in ["* * * *", "60 * * * *", "*/0 * * * *", "a b c d e"] {
+            assert!(CronSchedule::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_extractive_summary_is_cumulative() {
+        let messages = vec![
+            json_value!({ "role": "user", "content": "Crée le radar. Puis le reste." }),
+            json_value!({ "role": "assistant", "content": "" }),
+        ];
+        let summary = extractive_summary(Some("- user: Bonjour."), &messages);
+        assert_eq!(summary, "- user: Bonjour.\n- user: Crée le radar.");
+    }
+
+    async fn inject_configs(manager: &CollectionsManager<'_>) -> RaiseResult<()> {
+        let generic_schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        let _ = manager
+            .create_collection("components", &generic_schema_uri)
+            .await;
+        let _ = manager
+            .create_collection("service_configs", &generic_schema_uri)
+            .await;
+        for (handle, settings) in [
+            ("ai_graph_store", json_value!({ "embedding_dim": 2 })),
+            (
+                "ai_maintenance",
+                json_value!({
+                    "jobs": [
+                        { "task": "summarize_sessions", "cron": "0 2 * * *" },
+                        { "task": "drift_snapshot", "cron": "0 2 * * *", "enabled": false }
+                    ],
+                    "session_max_messages": 4,
+                    "session_keep_recent": 2
+                }),
+            ),
+        ] {
+            manager
+                .upsert_document(
+                    "components",
+                    json_value!({ "_id": format!("comp_{}", handle), "handle": handle }),
+                )
+                .await?;
+            manager
+                .upsert_document(
+                    "service_configs",
+                    json_value!({
+                        "_id": format!("cfg_{}", handle),
+                        "component_id": format!("ref:components:handle:{}", handle),
+                        "service_settings": settings
+                    }),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_scheduled_jobs_run_and_are_recorded() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        inject_configs(&manager).await?;
+
+        // Session de 6 messages (seuil : 4, on en garde 2)
+        let generic_schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager
+            .create_collection(AGENT_SESSIONS_COLLECTION, &generic_schema_uri)
+            .await?;
+        let messages: Vec<JsonValue> = (0..6)
+            .map(|i| json_value!({ "role": "user", "content": format!("Message {}.", i), "timestamp": UtcClock::now() }))
+            .collect();
+        manager
+            .upsert_document(
+                AGENT_SESSIONS_COLLECTION,
+                json_value!({ "_id": "s1", "handle": "s1", "messages": messages }),
+            )
+            .await?;
+
+        // Index avec un doublon et un orphelin
+        let store = SharedRef::new(NativeLocalStore::new(&manager, &ComputeHardware::Cpu).await?);
+        store.init_collection(&manager, "kb", 2).await?;
+        let record = |id: &str, v: Vec<f32>| MemoryRecord {
+            id: id.into(),
+            content: id.into(),
+            metadata: json_value!({}),
+            vectors: Some(v),
+        };
+        store
+            .add_documents(
+                &manager,
+                "kb",
+                vec![record("a", vec![1.0, 0.0]), record("b", vec![0.0, 1.0])],
+            )
+            .await?;
+        store
+            .add_documents(&manager, "kb", vec![record("a", vec![0.6, 0.8])])
+            .await?;
+        manager.delete_document("kb", "b").await?;
+
+        let scheduler = MaintenanceScheduler::system(SharedRef::new(sandbox.storage.clone()))
+            .with_vector_store(store.clone(), "kb");
+
+        // Planification : seule la tâche active et planifiée à 2h tourne
+        let runs = scheduler.run_due(&at("2026-10-15T02:00:10Z")).await?;
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].status, RunStatus::Succeeded);
+        assert_eq!(runs[0].details["sessions_summarized"], 1);
+        assert!(scheduler
+            .run_due(&at("2026-10-15T02:01:00Z"))
+            .await?
+            .is_empty());
+
+        let session = manager
+            .get_document(AGENT_SESSIONS_COLLECTION, "s1")
+            .await?
+            .unwrap();
+        assert_eq!(session["messages"].as_array().map(Vec::len), Some(2));
+        assert!(session["summary"].as_str().unwrap().contains("Message 3."));
+
+        let optimized = scheduler
+            .run_task(MaintenanceTask::OptimizeVectorIndex, RunTrigger::Manual)
+            .await?;
+        assert_eq!(optimized.details["rows_before"], 3);
+        assert_eq!(optimized.details["rows_after"], 1);
+        assert_eq!(optimized.details["duplicates_removed"], 1);
+        assert_eq!(optimized.details["orphans_removed"], 1);
+        assert_eq!(
+            store.export_index(&manager, "kb").await?.1,
+            vec![vec![0.6, 0.8]]
+        );
+
+        // Deux instantanés : le second mesure la dérive par rapport au premier
+        scheduler
+            .run_task(MaintenanceTask::DriftSnapshot, RunTrigger::Manual)
+            .await?;
+        let second = scheduler
+            .run_task(MaintenanceTask::DriftSnapshot, RunTrigger::Manual)
+            .await?;
+        assert_eq!(second.details["drift"], 0.0);
+
+        let history = scheduler.history(None, 10).await?;
+        assert_eq!(history.len(), 4);
+        assert_eq!(
+            scheduler
+                .history(Some(MaintenanceTask::DriftSnapshot), 10)
+                .await?
+                .len(),
+            2
+        );
+
+        let schedule = scheduler.schedule(&at("2026-10-15T12:00:00Z")).await?;
+        assert_eq!(schedule[0].next_run, Some(at("2026-10-16T02:00:00Z")));
+        assert_eq!(schedule[1].next_run, None);
+        Ok(())
+    }
+}
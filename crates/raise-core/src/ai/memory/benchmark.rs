@@ -0,0 +1,358 @@
+// FICHIER : src-tauri/src/ai/memory/benchmark.rs
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::utils::prelude::*; // 🎯 Façade Unique
+
+use super::{MemoryRecord, VectorStore};
+
+/// Collection recevant les rapports comparatifs (un document par campagne).
+pub const BENCH_COLLECTION: &str = "vector_benchmarks";
+
+/// Backends vectoriels comparables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "lowercase")]
+pub enum BenchBackend {
+    /// `NativeLocalStore` (tenseurs Candle + JSON-DB)
+    Candle,
+    /// `QdrantMemory` (serveur REST)
+    Qdrant,
+    /// Wrapper Python LEANN
+    Leann,
+}
+
+impl BenchBackend {
+    pub fn parse(value: &str) -> RaiseResult<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "candle" | "native" => Ok(Self::Candle),
+            "qdrant" => Ok(Self::Qdrant),
+            "leann" => Ok(Self::Leann),
+            _ => raise_error!(
+                "ERR_BENCH_UNKNOWN_BACKEND",
+                error = format!("Backend vectoriel inconnu : '{}'", value),
+                context = json_value!({ "supported": ["candle", "qdrant", "leann"] })
+            ),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Candle => "candle",
+            Self::Qdrant => "qdrant",
+            Self::Leann => "leann",
+        }
+    }
+}
+
+/// Document du corpus de test (`vector` optionnel : calculé par le moteur NLP sinon).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct BenchDocument {
+    pub id: String,
+    pub content: String,
+    #[serde(default)]
+    pub metadata: JsonValue,
+    #[serde(default)]
+    pub vector: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "lowercase")]
+pub enum BenchStatus {
+    Ok,
+    Failed,
+    Skipped,
+}
+
+/// Mesures d'un backend sur le corpus.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct BackendBenchResult {
+    pub backend: BenchBackend,
+    pub status: BenchStatus,
+    pub error: Option<String>,
+    pub index_ms: f64,
+    pub docs_per_sec: f64,
+    pub latency_mean_ms: f64,
+    pub latency_p50_ms: f64,
+    pub latency_p95_ms: f64,
+    pub latency_p99_ms: f64,
+    pub recall_at_k: f64,
+}
+
+/// Rapport comparatif persisté dans `vector_benchmarks`.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct VectorBenchReport {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub created_at: String,
+    pub corpus: String,
+    pub documents: usize,
+    pub queries: usize,
+    pub dimension: usize,
+    pub k: usize,
+    pub results: Vec<BackendBenchResult>,
+}
+
+impl BackendBenchResult {
+    fn empty(backend: BenchBackend, status: BenchStatus, error: Option<String>) -> Self {
+        Self {
+            backend,
+            status,
+            error,
+            index_ms: 0.0,
+            docs_per_sec: 0.0,
+            latency_mean_ms: 0.0,
+            latency_p50_ms: 0.0,
+            latency_p95_ms: 0.0,
+            latency_p99_ms: 0.0,
+            recall_at_k: 0.0,
+        }
+    }
+
+    pub fn skipped(backend: BenchBackend, reason: &str) -> Self {
+        Self::empty(backend, BenchStatus::Skipped, Some(reason.to_string()))
+    }
+
+    pub fn failed(backend: BenchBackend, error: &AppError) -> Self {
+        Self::empty(backend, BenchStatus::Failed, Some(error.to_string()))
+    }
+}
+
+/// Charge un corpus JSON (tableau) ou JSONL (un document par ligne).
+pub async fn load_corpus(path: &Path) -> RaiseResult<Vec<BenchDocument>> {
+    let raw = fs::read_to_string_async(path).await?;
+    let docs: Vec<BenchDocument> = if raw.trim_start().starts_with('[') {
+        json::deserialize_from_str(&raw)?
+    } else {
+        raw.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(json::deserialize_from_str)
+            .collect::<RaiseResult<_>>()?
+    };
+    if docs.is_empty() {
+        raise_error!(
+            "ERR_BENCH_EMPTY_CORPUS",
+            error = "Le corpus de benchmark est vide",
+            context = json_value!({ "path": path.to_string_lossy() })
+        );
+    }
+    Ok(docs)
+}
+
+/// Normalisation L2 (contrat du `NativeLocalStore` : produit scalaire = cosinus).
+pub fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > f32::EPSILON {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// Vérité terrain par balayage exhaustif (vecteurs normalisés).
+pub fn exact_top_k(docs: &[BenchDocument], query: &[f32], k: usize) -> Vec<String> {
+    let mut scored: Vec<(f32, &str)> = docs
+        .iter()
+        .filter_map(|d| {
+            d.vector
+                .as_ref()
+                .map(|v| (v.iter().zip(query).map(|(a, b)| a * b).sum(), d.id.as_str()))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(FmtOrdering::Equal));
+    scored
+        .into_iter()
+        .take(k)
+        .map(|(_, id)| id.to_string())
+        .collect()
+}
+
+pub fn recall_at_k(truth: &[String], found: &[String]) -> f64 {
+    if truth.is_empty() {
+        return 1.0;
+    }
+    let hits = truth.iter().filter(|id| found.contains(id)).count();
+    hits as f64 / truth.len() as f64
+}
+
+/// Percentile par rang le plus proche sur un échantillon trié.
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Requêtes : vecteurs de documents répartis uniformément dans le corpus.
+pub fn sample_queries(docs: &[BenchDocument], count: usize) -> Vec<Vec<f32>> {
+    let with_vectors: Vec<&Vec<f32>> = docs.iter().filter_map(|d| d.vector.as_ref()).collect();
+    if with_vectors.is_empty() || count == 0 {
+        return vec![];
+    }
+    let step = (with_vectors.len() / count.min(with_vectors.len())).max(1);
+    with_vectors
+        .into_iter()
+        .step_by(step)
+        .take(count)
+        .cloned()
+        .collect()
+}
+
+/// Indexe le corpus puis rejoue les requêtes sur un backend.
+pub async fn run_backend(
+    backend: BenchBackend,
+    store: &dyn VectorStore,
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+    docs: &[BenchDocument],
+    queries: &[Vec<f32>],
+    k: usize,
+) -> RaiseResult<BackendBenchResult> {
+    let dimension = queries.first().map(|q| q.len()).unwrap_or_default();
+    store
+        .init_collection(manager, collection, dimension as u64)
+        .await?;
+
+    let records: Vec<MemoryRecord> = docs
+        .iter()
+        .map(|d| MemoryRecord {
+            id: d.id.clone(),
+            content: d.content.clone(),
+            metadata: d.metadata.clone(),
+            vectors: d.vector.clone(),
+        })
+        .collect();
+
+    let started = TimeInstant::now();
+    store.add_documents(manager, collection, records).await?;
+    let index_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    let mut latencies = Vec::with_capacity(queries.len());
+    let mut recall_sum = 0.0;
+    for query in queries {
+        let started = TimeInstant::now();
+        let hits = store
+            .search_similarity(manager, collection, query, k as u64, -1.0, None)
+            .await?;
+        latencies.push(started.elapsed().as_secs_f64() * 1000.0);
+
+        let found: Vec<String> = hits.into_iter().map(|h| h.id).collect();
+        recall_sum += recall_at_k(&exact_top_k(docs, query, k), &found);
+    }
+    store.unload_collection(collection).await?;
+
+    latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(FmtOrdering::Equal));
+    let n = latencies.len().max(1) as f64;
+    Ok(BackendBenchResult {
+        backend,
+        status: BenchStatus::Ok,
+        error: None,
+        index_ms,
+        docs_per_sec: docs.len() as f64 / (index_ms / 1000.0).max(f64::EPSILON),
+        latency_mean_ms: latencies.iter().sum::<f64>() / n,
+        latency_p50_ms: percentile(&latencies, 50.0),
+        latency_p95_ms: percentile(&latencies, 95.0),
+        latency_p99_ms: percentile(&latencies, 99.0),
+        recall_at_k: recall_sum / n,
+    })
+}
+
+/// Persiste le rapport dans la collection `vector_benchmarks` de la base courante.
+pub async fn save_report(
+    manager: &CollectionsManager<'_>,
+    report: &VectorBenchReport,
+) -> RaiseResult<()> {
+    let meta = manager
+        .storage
+        .config
+        .db_collection_path(&manager.space, &manager.db, BENCH_COLLECTION)
+        .join("_meta.json");
+    if !meta.exists() {
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager
+            .create_collection(BENCH_COLLECTION, &schema_uri)
+            .await?;
+    }
+    manager
+        .insert_raw(BENCH_COLLECTION, &json::serialize_to_value(report)?)
+        .await
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(id: &str, v: [f32; 2]) -> BenchDocument {
+        let mut vector = v.to_vec();
+        normalize(&mut vector);
+        BenchDocument {
+            id: id.into(),
+            content: id.into(),
+            metadata: json_value!({}),
+            vector: Some(vector),
+        }
+    }
+
+    #[test]
+    fn test_percentiles_and_recall() {
+        let sorted: Vec<f64> = (1..=100).map(|x| x as f64).collect();
+        assert_eq!(percentile(&sorted, 50.0), 50.0);
+        assert_eq!(percentile(&sorted, 99.0), 99.0);
+        assert_eq!(percentile(&[], 95.0), 0.0);
+
+        let truth = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            recall_at_k(&truth, &["b".to_string(), "z".to_string()]),
+            0.5
+        );
+        assert!(BenchBackend::parse("faiss").is_err());
+    }
+
+    #[test]
+    fn test_exact_top_k_ground_truth() {
+        let docs = vec![
+            doc("x", [1.0, 0.0]),
+            doc("y", [0.0, 1.0]),
+            doc("xy", [1.0, 1.0]),
+        ];
+        let top = exact_top_k(&docs, &[1.0, 0.0], 2);
+        assert_eq!(top, vec!["x".to_string(), "xy".to_string()]);
+        assert_eq!(sample_queries(&docs, 2).len(), 2);
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_save_report_creates_collection() -> RaiseResult<()> {
+        let sandbox = crate::utils::testing::DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        let report = VectorBenchReport {
+            id: UniqueId::new_v4().to_string(),
+            created_at: UtcClock::now().to_rfc3339(),
+            corpus: "corpus.jsonl".into(),
+            documents: 3,
+            queries: 1,
+            dimension: 2,
+            k: 2,
+            results: vec![BackendBenchResult::skipped(BenchBackend::Leann, "offline")],
+        };
+        save_report(&manager, &report).await?;
+        save_report(
+            &manager,
+            &VectorBenchReport {
+                id: UniqueId::new_v4().to_string(),
+                ..report
+            },
+        )
+        .await?;
+        assert_eq!(manager.list_all(BENCH_COLLECTION).await?.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,575 @@
+// FICHIER : src-tauri/src/ai/memory/migration.rs
+
+//! Migration des embeddings lors d'un changement de modèle : chaque index vectoriel porte
+//! un manifeste (`tensors/embedding.json` : modèle + dimension). Un écart avec le modèle
+//! actif déclenche une ré-vectorisation complète dans un index de transition, suivie
+//! (progression dans la collection système `embedding_migrations`), puis substituée
+//! atomiquement à l'index courant. L'ancien index reste interrogeable pendant l'opération.
+
+use super::native_store::NativeLocalStore;
+use crate::ai::nlp::embeddings::EmbeddingEngine;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+/// Collection système des migrations (un document par exécution).
+pub const EMBEDDING_MIGRATIONS_COLLECTION: &str = "embedding_migrations";
+/// Manifeste du modèle, rangé à côté des tenseurs de la collection.
+pub const EMBEDDING_MANIFEST_FILE: &str = "embedding.json";
+/// Taille des lots ré-vectorisés entre deux mises à jour de la progression.
+pub const REEMBED_BATCH_SIZE: usize = 32;
+/// Modèle attribué aux index antérieurs aux manifestes.
+pub const UNKNOWN_MODEL: &str = "unknown";
+
+/// Modèle ayant produit les vecteurs d'un index.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct EmbeddingManifest {
+    pub model: String,
+    pub dimension: usize,
+    pub updated_at: UtcTimestamp,
+}
+
+/// Source de vecteurs : le moteur NLP en production, un substitut déterministe en test.
+pub trait Embedder: Send {
+    fn model_id(&self) -> String;
+    fn dimension(&mut self) -> RaiseResult<usize>;
+    fn embed_batch(&mut self, texts: Vec<String>) -> RaiseResult<Vec<Vec<f32>>>;
+}
+
+impl Embedder for EmbeddingEngine {
+    fn model_id(&self) -> String {
+        EmbeddingEngine::model_id(self)
+    }
+
+    fn dimension(&mut self) -> RaiseResult<usize> {
+        EmbeddingEngine::dimension(self)
+    }
+
+    fn embed_batch(&mut self, texts: Vec<String>) -> RaiseResult<Vec<Vec<f32>>> {
+        EmbeddingEngine::embed_batch(self, texts)
+    }
+}
+
+/// Écart entre un index et le modèle actif.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct EmbeddingStatus {
+    pub collection: String,
+    /// Modèle de l'index (None si la collection n'a encore aucun vecteur).
+    pub current: Option<EmbeddingManifest>,
+    pub target_model: String,
+    pub target_dimension: usize,
+    pub migration_required: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationState {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Exécution d'une migration et sa progression.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct MigrationJob {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub space: String,
+    pub db: String,
+    pub collection: String,
+    pub from_model: Option<String>,
+    pub from_dimension: Option<usize>,
+    pub to_model: String,
+    pub to_dimension: usize,
+    pub state: MigrationState,
+    pub processed: usize,
+    pub total: usize,
+    pub started_at: UtcTimestamp,
+    #[serde(default)]
+    pub finished_at: Option<UtcTimestamp>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl MigrationJob {
+    /// Avancement dans [0, 1].
+    pub fn progress(&self) -> f64 {
+        if self.total == 0 {
+            return if self.state == MigrationState::Completed {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        self.processed as f64 / self.total as f64
+    }
+}
+
+async fn manifest_path(manager: &CollectionsManager<'_>, collection: &str) -> PathBuf {
+    NativeLocalStore::get_tensor_dir(manager, collection)
+        .await
+        .join(EMBEDDING_MANIFEST_FILE)
+}
+
+/// Manifeste d'un index (None pour un index antérieur aux manifestes).
+pub async fn read_manifest(
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+) -> RaiseResult<Option<EmbeddingManifest>> {
+    let path = manifest_path(manager, collection).await;
+    if !fs::exists_async(&path).await {
+        return Ok(None);
+    }
+    Ok(Some(fs::read_json_async(&path).await?))
+}
+
+pub async fn write_manifest(
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+    model: &str,
+    dimension: usize,
+) -> RaiseResult<()> {
+    let manifest = EmbeddingManifest {
+        model: model.to_string(),
+        dimension,
+        updated_at: UtcClock::now(),
+    };
+    fs::write_json_atomic_async(&manifest_path(manager, collection).await, &manifest).await
+}
+
+/// Compare l'index d'une collection au modèle actif.
+pub async fn check_collection(
+    store: &NativeLocalStore,
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+    embedder: &mut dyn Embedder,
+) -> RaiseResult<EmbeddingStatus> {
+    let target_model = embedder.model_id();
+    let target_dimension = embedder.dimension()?;
+
+    let stored_dimension = store.collection_dimension(manager, collection).await?;
+    let current = match (read_manifest(manager, collection).await?, stored_dimension) {
+        (Some(manifest), _) => Some(manifest),
+        (None, Some(dimension)) => Some(EmbeddingManifest {
+            model: UNKNOWN_MODEL.to_string(),
+            dimension,
+            updated_at: UtcClock::now(),
+        }),
+        (None, None) => None,
+    };
+
+    // Un index sans manifeste mais de même dimension est présumé produit par le modèle actif
+    let migration_required = current.as_ref().is_some_and(|m| {
+        m.dimension != target_dimension || (m.model != UNKNOWN_MODEL && m.model != target_model)
+    });
+
+    Ok(EmbeddingStatus {
+        collection: collection.to_string(),
+        current,
+        target_model,
+        target_dimension,
+        migration_required,
+    })
+}
+
+/// Registre des migrations, stocké dans la partition système par défaut.
+pub struct EmbeddingMigrator<'a> {
+    manager: CollectionsManager<'a>,
+}
+
+impl<'a> EmbeddingMigrator<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            manager: CollectionsManager::new(storage, space, db),
+        }
+    }
+
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, EMBEDDING_MIGRATIONS_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(EMBEDDING_MIGRATIONS_COLLECTION, &schema_uri)
+            .await
+    }
+
+    async fn save(&self, job: &MigrationJob) -> RaiseResult<()> {
+        self.manager
+            .upsert_document(
+                EMBEDDING_MIGRATIONS_COLLECTION,
+                json::serialize_to_value(job)?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get(&self, id: &str) -> RaiseResult<Option<MigrationJob>> {
+        if self
+            .manager
+            .collection_schema_uri(EMBEDDING_MIGRATIONS_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(None);
+        }
+        match self
+            .manager
+            .get_document(EMBEDDING_MIGRATIONS_COLLECTION, id)
+            .await?
+        {
+            Some(doc) => Ok(Some(json::deserialize_from_value(doc)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Migrations enregistrées, les plus récentes d'abord.
+    pub async fn list(&self) -> RaiseResult<Vec<MigrationJob>> {
+        if self
+            .manager
+            .collection_schema_uri(EMBEDDING_MIGRATIONS_COLLECTION)
+            .await
+            .is_none()
+        {
+            return Ok(vec![]);
+        }
+        let mut jobs: Vec<MigrationJob> = self
+            .manager
+            .list_all(EMBEDDING_MIGRATIONS_COLLECTION)
+            .await?
+            .into_iter()
+            .map(json::deserialize_from_value)
+            .collect::<RaiseResult<_>>()?;
+        jobs.sort_by_key(|j| std::cmp::Reverse(j.started_at));
+        Ok(jobs)
+    }
+
+    /// Enregistre une migration à venir (état `Running`, progression nulle).
+    pub async fn prepare(
+        &self,
+        target: &CollectionsManager<'_>,
+        status: &EmbeddingStatus,
+    ) -> RaiseResult<MigrationJob> {
+        self.ensure_collection().await?;
+        let job = MigrationJob {
+            id: UniqueId::new_v4().to_string(),
+            space: target.space.clone(),
+            db: target.db.clone(),
+            collection: status.collection.clone(),
+            from_model: status.current.as_ref().map(|m| m.model.clone()),
+            from_dimension: status.current.as_ref().map(|m| m.dimension),
+            to_model: status.target_model.clone(),
+            to_dimension: status.target_dimension,
+            state: MigrationState::Running,
+            processed: 0,
+            total: 0,
+            started_at: UtcClock::now(),
+            finished_at: None,
+            error: None,
+        };
+        self.save(&job).await?;
+        Ok(job)
+    }
+
+    /// Ré-vectorise la collection puis substitue le nouvel index. L'échec est consigné
+    /// dans le job ; l'index d'origine reste alors intact.
+    pub async fn run(
+        &self,
+        mut job: MigrationJob,
+        store: &NativeLocalStore,
+        target: &CollectionsManager<'_>,
+        embedder: &mut dyn Embedder,
+    ) -> RaiseResult<MigrationJob> {
+        user_info!(
+            "INF_EMBEDDING_MIGRATION_START",
+            json_value!({ "job": job.id, "collection": job.collection, "from": job.from_model, "to": job.to_model })
+        );
+        match self.reembed(&mut job, store, target, embedder).await {
+            Ok(()) => {
+                job.state = MigrationState::Completed;
+                job.finished_at = Some(UtcClock::now());
+                self.save(&job).await?;
+                user_success!(
+                    "INF_EMBEDDING_MIGRATION_DONE",
+                    json_value!({ "job": job.id, "collection": job.collection, "vectors": job.processed })
+                );
+                Ok(job)
+            }
+            Err(e) => {
+                job.state = MigrationState::Failed;
+                job.finished_at = Some(UtcClock::now());
+                job.error = Some(e.to_string());
+                self.save(&job).await?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn reembed(
+        &self,
+        job: &mut MigrationJob,
+        store: &NativeLocalStore,
+        target: &CollectionsManager<'_>,
+        embedder: &mut dyn Embedder,
+    ) -> RaiseResult<()> {
+        let mut ids: Vec<String> = Vec::new();
+        let mut vectors: Vec<Vec<f32>> = Vec::new();
+
+        // Deux passes : la seconde rattrape les documents ajoutés pendant la première
+        for _ in 0..2 {
+            let pending: Vec<(String, String)> = target
+                .list_all(&job.collection)
+                .await?
+                .into_iter()
+                .filter_map(|doc| {
+                    let id = doc.get("_id")?.as_str()?.to_string();
+                    let content = doc.get("content")?.as_str()?.to_string();
+                    Some((id, content))
+                })
+                .filter(|(id, _)| !ids.contains(id))
+                .collect();
+            if pending.is_empty() {
+                break;
+            }
+            job.total += pending.len();
+
+            for batch in pending.chunks(REEMBED_BATCH_SIZE) {
+                let texts = batch.iter().map(|(_, c)| c.clone()).collect();
+                let batch_vectors = embedder.embed_batch(texts)?;
+                if let Some(bad) = batch_vectors.iter().find(|v| v.len() != job.to_dimension) {
+                    raise_error!(
+                        "ERR_VECTOR_DIMENSION_MISMATCH",
+                        error = "Le modèle cible a produit un vecteur de dimension inattendue.",
+                        context =
+                            json_value!({ "expected": job.to_dimension, "received": bad.len() })
+                    );
+                }
+                ids.extend(batch.iter().map(|(id, _)| id.clone()));
+                vectors.extend(batch_vectors);
+                job.processed = ids.len();
+                self.save(job).await?;
+            }
+        }
+
+        let manifest = EmbeddingManifest {
+            model: job.to_model.clone(),
+            dimension: job.to_dimension,
+            updated_at: UtcClock::now(),
+        };
+        store
+            .swap_index(
+                target,
+                &job.collection,
+                ids,
+                vectors,
+                &[(
+                    EMBEDDING_MANIFEST_FILE,
+                    json::serialize_to_value(&manifest)?,
+                )],
+            )
+            .await
+    }
+
+    /// Vérifie la collection et migre si nécessaire (None si l'index est à jour).
+    pub async fn migrate_if_needed(
+        &self,
+        store: &NativeLocalStore,
+        target: &CollectionsManager<'_>,
+        collection: &str,
+        embedder: &mut dyn Embedder,
+    ) -> RaiseResult<Option<MigrationJob>> {
+        let status = check_collection(store, target, collection, embedder).await?;
+        if !status.migration_required {
+            return Ok(None);
+        }
+        let job = self.prepare(target, &status).await?;
+        Ok(Some(self.run(job, store, target, embedder).await?))
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::memory::{MemoryRecord, VectorStore};
+    use crate::utils::testing::DbSandbox;
+
+    /// Vecteur unitaire déterministe : la position du 1 dépend de la longueur du texte.
+    struct FakeEmbedder {
+        model: &'static str,
+        dimension: usize,
+    }
+
+    impl Embedder for FakeEmbedder {
+        fn model_id(&self) -> String {
+            self.model.to_string()
+        }
+
+        fn dimension(&mut self) -> RaiseResult<usize> {
+            Ok(self.dimension)
+        }
+
+        fn embed_batch(&mut self, texts: Vec<String>) -> RaiseResult<Vec<Vec<f32>>> {
+            Ok(texts
+                .iter()
+                .map(|t| {
+                    let mut v = vec![0.0; self.dimension];
+                    v[t.len() % self.dimension] = 1.0;
+                    v
+                })
+                .collect())
+        }
+    }
+
+    async fn component_configs(manager: &CollectionsManager<'_>) -> RaiseResult<()> {
+        let config = AppConfig::get();
+        let generic_schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            config.mount_points.system.domain, config.mount_points.system.db
+        );
+        let _ = manager
+            .create_collection("components", &generic_schema_uri)
+            .await;
+        manager
+            .upsert_document(
+                "components",
+                json_value!({ "_id": "comp_store_id", "handle": "ai_graph_store", "name": "Vector Store" }),
+            )
+            .await?;
+        let _ = manager
+            .create_collection("service_configs", &generic_schema_uri)
+            .await;
+        manager
+            .upsert_document(
+                "service_configs",
+                json_value!({
+                    "_id": "mock_store_cfg",
+                    "component_id": "ref:components:handle:ai_graph_store",
+                    "service_settings": { "embedding_dim": 4 }
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_job_progress() {
+        let mut job = MigrationJob {
+            id: "j".into(),
+            space: "s".into(),
+            db: "d".into(),
+            collection: "kb".into(),
+            from_model: None,
+            from_dimension: None,
+            to_model: "m".into(),
+            to_dimension: 4,
+            state: MigrationState::Running,
+            processed: 0,
+            total: 0,
+            started_at: UtcClock::now(),
+            finished_at: None,
+            error: None,
+        };
+        assert_eq!(job.progress(), 0.0);
+        job.total = 4;
+        job.processed = 1;
+        assert_eq!(job.progress(), 0.25);
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_dimension_change_triggers_reembedding_and_swap() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        component_configs(&manager).await?;
+
+        let store = NativeLocalStore::new(&manager, &ComputeHardware::Cpu).await?;
+        store.init_collection(&manager, "kb", 2).await?;
+        let records = ["radar", "freinage", "capteur"]
+            .iter()
+            .map(|text| MemoryRecord {
+                id: format!("doc_{}", text),
+                content: text.to_string(),
+                metadata: json_value!({}),
+                vectors: Some(vec![1.0, 0.0]),
+            })
+            .collect();
+        store.add_documents(&manager, "kb", records).await?;
+
+        let mut next = FakeEmbedder {
+            model: "native:next",
+            dimension: 4,
+        };
+        let status = check_collection(&store, &manager, "kb", &mut next).await?;
+        assert!(status.migration_required);
+        assert_eq!(status.current.map(|m| m.dimension), Some(2));
+
+        // Le mélange silencieux de dimensions est refusé
+        let mixed = MemoryRecord {
+            id: "doc_mixed".into(),
+            content: "mixte".into(),
+            metadata: json_value!({}),
+            vectors: Some(vec![0.0; 4]),
+        };
+        assert!(store
+            .add_documents(&manager, "kb", vec![mixed])
+            .await
+            .is_err());
+
+        let migrator = EmbeddingMigrator::new(&sandbox.storage, &manager.space, &manager.db);
+        let job = migrator
+            .migrate_if_needed(&store, &manager, "kb", &mut next)
+            .await?
+            .expect("migration attendue");
+        assert_eq!(job.state, MigrationState::Completed);
+        assert_eq!((job.processed, job.total), (3, 3));
+        assert_eq!(job.from_dimension, Some(2));
+
+        assert_eq!(store.collection_dimension(&manager, "kb").await?, Some(4));
+        assert_eq!(
+            read_manifest(&manager, "kb").await?.map(|m| m.model),
+            Some("native:next".to_string())
+        );
+        let hits = store
+            .search_similarity(&manager, "kb", &[0.0, 0.0, 0.0, 1.0], 3, 0.5, None)
+            .await?;
+        // Seul "capteur" (7 % 4 = 3) est aligné sur l'axe interrogé
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].content, "capteur");
+        assert!(migrator
+            .migrate_if_needed(&store, &manager, "kb", &mut next)
+            .await?
+            .is_none());
+        assert_eq!(
+            migrator.get(&job.id).await?.map(|j| j.state),
+            Some(MigrationState::Completed)
+        );
+        Ok(())
+    }
+}
@@ -3,7 +3,11 @@
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::utils::prelude::*; // 🎯 Façade Unique
 
+pub mod benchmark;
+pub mod migration;
 pub mod native_store;
+pub mod qdrant_store;
+pub mod query_provider;
 
 #[derive(Debug, Clone, Serializable, Deserializable)]
 pub struct MemoryRecord {
@@ -40,7 +44,30 @@ pub trait VectorStore: Send + Sync {
         limit: u64,
         score_threshold: f32,
         filter: Option<UnorderedMap<String, String>>,
-    ) -> RaiseResult<Vec<MemoryRecord>>;
+    ) -> RaiseResult<Vec<MemoryRecord>> {
+        let scored = self
+            .search_scored(
+                manager,
+                collection_name,
+                vector,
+                limit,
+                score_threshold,
+                filter,
+            )
+            .await?;
+        Ok(scored.into_iter().map(|(record, _)| record).collect())
+    }
+
+    /// `search_similarity` avec le score de chaque résultat (par score décroissant).
+    async fn search_scored(
+        &self,
+        manager: &CollectionsManager<'_>,
+        collection_name: &str,
+        vector: &[f32],
+        limit: u64,
+        score_threshold: f32,
+        filter: Option<UnorderedMap<String, String>>,
+    ) -> RaiseResult<Vec<(MemoryRecord, f32)>>;
 
     async fn unload_collection(&self, collection_name: &str) -> RaiseResult<()>;
 }
@@ -20,6 +20,16 @@ struct CollectionState {
     vector_matrix: Option<NeuralTensor>,
 }
 
+/// Bilan d'une optimisation d'index (`compact`).
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct IndexCompaction {
+    pub collection: String,
+    pub rows_before: usize,
+    pub rows_after: usize,
+    pub orphans_removed: usize,
+    pub duplicates_removed: usize,
+}
+
 impl NativeLocalStore {
     /// Initialise le store vectoriel avec le périphérique spécifié.
     pub async fn new(
@@ -46,7 +56,7 @@ impl NativeLocalStore {
     }
 
     /// 🎯 RÉSOLUTION DÉTERMINISTE : Les tenseurs mémoires sont rangés dans la partition "tensors" de la DB.
-    async fn get_tensor_dir(manager: &CollectionsManager<'_>, col: &str) -> PathBuf {
+    pub(crate) async fn get_tensor_dir(manager: &CollectionsManager<'_>, col: &str) -> PathBuf {
         manager
             .storage
             .config
@@ -130,7 +140,16 @@ impl NativeLocalStore {
         col_state: &CollectionState,
     ) -> RaiseResult<()> {
         let col_dir = Self::get_tensor_dir(manager, col).await;
-        fs::ensure_dir_async(&col_dir).await?;
+        self.write_index_files(&col_dir, col_state).await
+    }
+
+    /// Écrit `index.json` et `vectors.safetensors` dans le dossier donné.
+    async fn write_index_files(
+        &self,
+        col_dir: &Path,
+        col_state: &CollectionState,
+    ) -> RaiseResult<()> {
+        fs::ensure_dir_async(col_dir).await?;
 
         let index_path = col_dir.join("index.json");
         fs::write_json_atomic_async(&index_path, &col_state.index_to_id).await?;
@@ -179,6 +198,156 @@ impl NativeLocalStore {
         Ok(())
     }
 
+    /// Dimension des vecteurs indexés (None si la collection est vide).
+    pub async fn collection_dimension(
+        &self,
+        manager: &CollectionsManager<'_>,
+        col: &str,
+    ) -> RaiseResult<Option<usize>> {
+        self.ensure_loaded(manager, col).await?;
+        let state = self.state.read().await;
+        Ok(state
+            .get(col)
+            .and_then(|cs| cs.vector_matrix.as_ref())
+            .and_then(|m| m.dims().get(1).copied()))
+    }
+
+    /// Copie de l'index d'une collection : identifiants et vecteurs, ligne à ligne.
+    pub async fn export_index(
+        &self,
+        manager: &CollectionsManager<'_>,
+        col: &str,
+    ) -> RaiseResult<(Vec<String>, Vec<Vec<f32>>)> {
+        self.ensure_loaded(manager, col).await?;
+        let state = self.state.read().await;
+        let Some(cs) = state.get(col) else {
+            return Ok((vec![], vec![]));
+        };
+        let vectors = match &cs.vector_matrix {
+            Some(m) => match m.to_vec2::<f32>() {
+                Ok(v) => v,
+                Err(e) => raise_error!("ERR_VECTOR_EXPORT_FAILED", error = e.to_string()),
+            },
+            None => vec![],
+        };
+        Ok((cs.index_to_id.clone(), vectors))
+    }
+
+    /// Optimise l'index d'une collection : retire les lignes orphelines (document supprimé
+    /// de JSON-DB) et les doublons d'un même document (seule la dernière version est gardée).
+    /// Le manifeste d'embedding est conservé ; rien n'est réécrit si l'index est déjà compact.
+    pub async fn compact(
+        &self,
+        manager: &CollectionsManager<'_>,
+        col: &str,
+    ) -> RaiseResult<IndexCompaction> {
+        let (ids, vectors) = self.export_index(manager, col).await?;
+        let mut report = IndexCompaction {
+            collection: col.to_string(),
+            rows_before: ids.len(),
+            ..Default::default()
+        };
+
+        let mut seen = UniqueSet::new();
+        let mut kept: Vec<(String, Vec<f32>)> = Vec::new();
+        // Parcours à rebours : la dernière ligne d'un document est la plus récente
+        for (id, vector) in ids.into_iter().zip(vectors).rev() {
+            if !seen.insert(id.clone()) {
+                report.duplicates_removed += 1;
+            } else if manager.get_document(col, &id).await?.is_none() {
+                report.orphans_removed += 1;
+            } else {
+                kept.push((id, vector));
+            }
+        }
+        kept.reverse();
+        report.rows_after = kept.len();
+
+        if report.rows_after != report.rows_before {
+            let manifest_file = super::migration::EMBEDDING_MANIFEST_FILE;
+            let manifest_path = Self::get_tensor_dir(manager, col).await.join(manifest_file);
+            let extra: Vec<(&str, JsonValue)> = if fs::exists_async(&manifest_path).await {
+                vec![(manifest_file, fs::read_json_async(&manifest_path).await?)]
+            } else {
+                vec![]
+            };
+            let (index_to_id, vectors) = kept.into_iter().unzip();
+            self.swap_index(manager, col, index_to_id, vectors, &extra)
+                .await?;
+        }
+        Ok(report)
+    }
+
+    /// Remplace atomiquement l'index d'une collection (migration d'embeddings).
+    /// Le nouvel index est écrit dans `tensors.next` puis substitué à `tensors` par
+    /// renommage, sous verrou d'écriture : aucune recherche ne voit d'état intermédiaire.
+    pub async fn swap_index(
+        &self,
+        manager: &CollectionsManager<'_>,
+        col: &str,
+        index_to_id: Vec<String>,
+        vectors: Vec<Vec<f32>>,
+        extra_files: &[(&str, JsonValue)],
+    ) -> RaiseResult<()> {
+        let matrix = match vectors.first().map(|v| v.len()) {
+            Some(d) => {
+                let n = vectors.len();
+                let flat: Vec<f32> = vectors.into_iter().flatten().collect();
+                if flat.len() != n * d {
+                    raise_error!(
+                        "ERR_VECTOR_DIMENSION_MISMATCH",
+                        error = "Vecteurs de dimensions hétérogènes dans le nouvel index.",
+                        context = json_value!({ "collection": col, "expected_dimension": d })
+                    );
+                }
+                match NeuralTensor::from_vec(flat, (n, d), &self.device) {
+                    Ok(t) => Some(t),
+                    Err(e) => raise_error!("ERR_VECTOR_CREATION_FAILED", error = e.to_string()),
+                }
+            }
+            None => None,
+        };
+        let next_state = CollectionState {
+            index_to_id,
+            vector_matrix: matrix,
+        };
+
+        let live_dir = Self::get_tensor_dir(manager, col).await;
+        let next_dir = live_dir.with_file_name("tensors.next");
+        let prev_dir = live_dir.with_file_name("tensors.prev");
+        for stale in [&next_dir, &prev_dir] {
+            if fs::exists_async(stale).await {
+                fs::remove_dir_all_async(stale).await?;
+            }
+        }
+
+        let mut state = self.state.write().await;
+        self.write_index_files(&next_dir, &next_state).await?;
+        for (name, content) in extra_files {
+            fs::write_json_atomic_async(&next_dir.join(name), content).await?;
+        }
+
+        if fs::exists_async(&live_dir).await {
+            fs::rename_async(&live_dir, &prev_dir).await?;
+        }
+        if let Err(e) = fs::rename_async(&next_dir, &live_dir).await {
+            // Restauration de l'index d'origine
+            let _ = fs::rename_async(&prev_dir, &live_dir).await;
+            return Err(e);
+        }
+        state.insert(col.to_string(), next_state);
+        drop(state);
+
+        if fs::exists_async(&prev_dir).await {
+            fs::remove_dir_all_async(&prev_dir).await?;
+        }
+        user_info!(
+            "INF_VECTOR_INDEX_SWAPPED",
+            json_value!({ "collection": col, "space": manager.space, "db": manager.db })
+        );
+        Ok(())
+    }
+
     /// Découverte à froid (Warm-up).
     /// Scanne le répertoire des tenseurs pour identifier les collections existantes
     /// et préparer le Lazy Loading sans saturer la VRAM immédiatement.
@@ -265,6 +434,30 @@ impl VectorStore for NativeLocalStore {
     ) -> RaiseResult<()> {
         self.ensure_loaded(manager, collection_name).await?;
 
+        // 🎯 Garde-fou : un changement de modèle d'embedding ne doit pas corrompre l'index
+        if let Some(expected) = self.collection_dimension(manager, collection_name).await? {
+            if let Some(received) = records
+                .iter()
+                .filter_map(|r| r.vectors.as_ref())
+                .map(|v| v.len())
+                .find(|len| *len != expected)
+            {
+                raise_error!(
+                    "ERR_VECTOR_DIMENSION_MISMATCH",
+                    error = format!(
+                        "Vecteurs de dimension {} refusés : la collection '{}' est indexée en dimension {}.",
+                        received, collection_name, expected
+                    ),
+                    context = json_value!({
+                        "collection": collection_name,
+                        "expected": expected,
+                        "received": received,
+                        "hint": "Le modèle d'embedding a changé : lancez `raise ai embeddings migrate`."
+                    })
+                );
+            }
+        }
+
         let mut valid_vectors = Vec::new();
         let mut new_ids = Vec::new();
 
@@ -321,7 +514,7 @@ impl VectorStore for NativeLocalStore {
         Ok(())
     }
 
-    async fn search_similarity(
+    async fn search_scored(
         &self,
         manager: &CollectionsManager<'_>,
         collection_name: &str,
@@ -329,7 +522,7 @@ impl VectorStore for NativeLocalStore {
         limit: u64,
         threshold: f32,
         filter: Option<UnorderedMap<String, String>>,
-    ) -> RaiseResult<Vec<MemoryRecord>> {
+    ) -> RaiseResult<Vec<(MemoryRecord, f32)>> {
         self.ensure_loaded(manager, collection_name).await?;
 
         let state = self.state.read().await;
@@ -343,6 +536,24 @@ impl VectorStore for NativeLocalStore {
             None => return Ok(vec![]),
         };
 
+        let expected = matrix.dims().get(1).copied().unwrap_or(0);
+        if query_vec.len() != expected {
+            raise_error!(
+                "ERR_VECTOR_DIMENSION_MISMATCH",
+                error = format!(
+                    "Requête de dimension {} sur un index de dimension {}.",
+                    query_vec.len(),
+                    expected
+                ),
+                context = json_value!({
+                    "collection": collection_name,
+                    "expected": expected,
+                    "received": query_vec.len(),
+                    "hint": "Le modèle d'embedding a changé : lancez `raise ai embeddings migrate`."
+                })
+            );
+        }
+
         let q = match NeuralTensor::from_slice(query_vec, (1, query_vec.len()), &self.device) {
             Ok(t) => t,
             Err(e) => raise_error!("ERR_VECTOR_QUERY_INIT", error = e.to_string()),
@@ -374,7 +585,7 @@ impl VectorStore for NativeLocalStore {
 
         let mut results = Vec::new();
 
-        for (_, idx) in ranked {
+        for (score, idx) in ranked {
             if results.len() >= limit as usize {
                 break;
             }
@@ -406,7 +617,7 @@ impl VectorStore for NativeLocalStore {
                 }
 
                 if meta_match {
-                    results.push(MemoryRecord {
+                    let record = MemoryRecord {
                         id: id.clone(),
                         content: doc
                             .get("content")
@@ -415,7 +626,8 @@ impl VectorStore for NativeLocalStore {
                             .to_string(),
                         metadata: doc.get("metadata").cloned().unwrap_or(json_value!({})),
                         vectors: None,
-                    });
+                    };
+                    results.push((record, score));
                 }
             }
         }
@@ -0,0 +1,596 @@
+// FICHIER : src-tauri/src/ai/memory/qdrant_store.rs
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::utils::prelude::*; // 🎯 Façade Unique
+
+use super::{MemoryRecord, VectorStore};
+
+/// Métrique de distance d'une collection Qdrant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+pub enum QdrantDistance {
+    Cosine,
+    Dot,
+    Euclid,
+    Manhattan,
+}
+
+impl QdrantDistance {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cosine => "Cosine",
+            Self::Dot => "Dot",
+            Self::Euclid => "Euclid",
+            Self::Manhattan => "Manhattan",
+        }
+    }
+
+    pub fn parse(value: &str) -> RaiseResult<Self> {
+        match value.to_lowercase().as_str() {
+            "cosine" => Ok(Self::Cosine),
+            "dot" => Ok(Self::Dot),
+            "euclid" | "euclidean" => Ok(Self::Euclid),
+            "manhattan" => Ok(Self::Manhattan),
+            _ => raise_error!(
+                "ERR_QDRANT_UNKNOWN_DISTANCE",
+                error = format!("Métrique de distance inconnue : '{}'", value),
+                context = json_value!({ "supported": ["cosine", "dot", "euclid", "manhattan"] })
+            ),
+        }
+    }
+}
+
+/// Type d'un index de payload (champs filtrables).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadFieldType {
+    Keyword,
+    Integer,
+    Float,
+    Bool,
+    Text,
+    Datetime,
+}
+
+impl PayloadFieldType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Keyword => "keyword",
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Bool => "bool",
+            Self::Text => "text",
+            Self::Datetime => "datetime",
+        }
+    }
+}
+
+/// Résumé d'une collection côté serveur.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct QdrantCollectionInfo {
+    pub name: String,
+    pub status: String,
+    pub points_count: Option<u64>,
+    pub vector_size: Option<u64>,
+    pub distance: Option<String>,
+    /// Champs de payload indexés (nom → type).
+    pub payload_indexes: OrderedMap<String, String>,
+}
+
+/// Instantané d'une collection (sauvegarde côté serveur).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct QdrantSnapshot {
+    pub name: String,
+    #[serde(default)]
+    pub creation_time: Option<String>,
+    #[serde(default)]
+    pub size: u64,
+}
+
+/// Backend vectoriel Qdrant (API REST, port 6333 par défaut).
+/// Le contenu et les métadonnées voyagent dans le payload des points :
+/// aucune copie n'est faite dans JSON-DB.
+pub struct QdrantMemory {
+    base_url: String,
+    api_key: Option<String>,
+    distance: QdrantDistance,
+}
+
+impl QdrantMemory {
+    pub fn new(url: &str) -> RaiseResult<Self> {
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            raise_error!(
+                "ERR_QDRANT_INVALID_URL",
+                error = format!("URL Qdrant invalide : '{}'", url),
+                context = json_value!({ "hint": "Format attendu : http://127.0.0.1:6333" })
+            );
+        }
+        Ok(Self {
+            base_url: url.trim_end_matches('/').to_string(),
+            api_key: None,
+            distance: QdrantDistance::Cosine,
+        })
+    }
+
+    /// Construit le backend depuis les `service_settings` du composant mémoire
+    /// (`qdrant_url`, `qdrant_distance`, `qdrant_api_key` — ce dernier accepte `secret:<clé>`).
+    pub fn from_settings(settings: &JsonValue) -> RaiseResult<Self> {
+        let url = settings["qdrant_url"]
+            .as_str()
+            .unwrap_or("http://127.0.0.1:6333");
+        let mut store = Self::new(url)?;
+        if let Some(distance) = settings["qdrant_distance"].as_str() {
+            store.distance = QdrantDistance::parse(distance)?;
+        }
+        store.api_key = settings["qdrant_api_key"].as_str().map(str::to_string);
+        Ok(store)
+    }
+
+    pub fn with_distance(mut self, distance: QdrantDistance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    pub fn distance(&self) -> QdrantDistance {
+        self.distance
+    }
+
+    /// Appel REST : renvoie `Ok(None)` sur 404, le champ `result` sinon.
+    async fn call(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<JsonValue>,
+    ) -> RaiseResult<Option<JsonValue>> {
+        let url = format!("{}{}", self.base_url, path);
+        let breaker = CircuitBreaker::global();
+        breaker.acquire(&url)?;
+
+        let mut request = get_client().request(method.clone(), &url);
+        if let Some(key) = &self.api_key {
+            request = request.header("api-key", key);
+        }
+        if let Some(b) = &body {
+            request = request.json(b);
+        }
+
+        let started = TimeInstant::now();
+        let response = match request.send().await {
+            Ok(r) => r,
+            Err(e) => {
+                breaker.record_failure(&url, &e.to_string());
+                raise_error!(
+                    "ERR_QDRANT_UNREACHABLE",
+                    error = e,
+                    context = json_value!({ "url": url, "method": method.as_str() })
+                );
+            }
+        };
+
+        let status = response.status();
+        if status.is_server_error() {
+            breaker.record_failure(&url, &format!("HTTP {}", status.as_u16()));
+        } else {
+            breaker.record_success(&url, started.elapsed());
+        }
+        if status == HttpStatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let payload: JsonValue = match response.json().await {
+            Ok(p) => p,
+            Err(e) => raise_error!(
+                "ERR_QDRANT_DECODE",
+                error = e,
+                context = json_value!({ "url": url, "status": status.as_u16() })
+            ),
+        };
+
+        if !status.is_success() {
+            raise_error!(
+                "ERR_QDRANT_REQUEST",
+                error = payload["status"]["error"]
+                    .as_str()
+                    .unwrap_or("Réponse Qdrant en erreur")
+                    .to_string(),
+                context = json_value!({
+                    "url": url,
+                    "method": method.as_str(),
+                    "status": status.as_u16()
+                })
+            );
+        }
+        Ok(Some(payload["result"].clone()))
+    }
+
+    // --- CYCLE DE VIE DES COLLECTIONS ---
+
+    pub async fn list_collections(&self) -> RaiseResult<Vec<String>> {
+        let result = self
+            .call(HttpMethod::GET, "/collections", None)
+            .await?
+            .unwrap_or_default();
+        Ok(result["collections"]
+            .as_array()
+            .map(|cols| {
+                cols.iter()
+                    .filter_map(|c| c["name"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    pub async fn collection_info(&self, name: &str) -> RaiseResult<Option<QdrantCollectionInfo>> {
+        let path = format!("/collections/{}", name);
+        Ok(self
+            .call(HttpMethod::GET, &path, None)
+            .await?
+            .map(|result| parse_collection_info(name, &result)))
+    }
+
+    /// Crée la collection avec la métrique configurée. `false` si elle existait déjà.
+    pub async fn create_collection(&self, name: &str, vector_size: u64) -> RaiseResult<bool> {
+        if self.collection_info(name).await?.is_some() {
+            return Ok(false);
+        }
+        let body = json_value!({
+            "vectors": { "size": vector_size, "distance": self.distance.as_str() }
+        });
+        self.call(
+            HttpMethod::PUT,
+            &format!("/collections/{}", name),
+            Some(body),
+        )
+        .await?;
+        user_info!(
+            "INF_QDRANT_COLLECTION_CREATED",
+            json_value!({ "collection": name, "size": vector_size, "distance": self.distance.as_str() })
+        );
+        Ok(true)
+    }
+
+    /// Supprime la collection et tous ses points. `false` si elle n'existait pas.
+    pub async fn delete_collection(&self, name: &str) -> RaiseResult<bool> {
+        let deleted = self
+            .call(HttpMethod::DELETE, &format!("/collections/{}", name), None)
+            .await?
+            .and_then(|r| r.as_bool())
+            .unwrap_or(false);
+        if deleted {
+            user_info!(
+                "INF_QDRANT_COLLECTION_DELETED",
+                json_value!({ "collection": name })
+            );
+        }
+        Ok(deleted)
+    }
+
+    /// Indexe un champ du payload pour accélérer les filtres (`metadata.<clé>` pour les métadonnées).
+    pub async fn create_payload_index(
+        &self,
+        collection: &str,
+        field: &str,
+        field_type: PayloadFieldType,
+    ) -> RaiseResult<()> {
+        let body = json_value!({ "field_name": field, "field_schema": field_type.as_str() });
+        let path = format!("/collections/{}/index?wait=true", collection);
+        if self
+            .call(HttpMethod::PUT, &path, Some(body))
+            .await?
+            .is_none()
+        {
+            raise_error!(
+                "ERR_QDRANT_COLLECTION_NOT_FOUND",
+                error = "Collection Qdrant introuvable",
+                context = json_value!({ "collection": collection, "field": field })
+            );
+        }
+        Ok(())
+    }
+
+    pub async fn delete_payload_index(&self, collection: &str, field: &str) -> RaiseResult<()> {
+        let path = format!("/collections/{}/index/{}?wait=true", collection, field);
+        self.call(HttpMethod::DELETE, &path, None).await?;
+        Ok(())
+    }
+
+    /// Déclenche un instantané serveur de la collection.
+    pub async fn create_snapshot(&self, collection: &str) -> RaiseResult<QdrantSnapshot> {
+        let path = format!("/collections/{}/snapshots?wait=true", collection);
+        let result = match self.call(HttpMethod::POST, &path, None).await? {
+            Some(r) => r,
+            None => raise_error!(
+                "ERR_QDRANT_COLLECTION_NOT_FOUND",
+                error = "Collection Qdrant introuvable",
+                context = json_value!({ "collection": collection })
+            ),
+        };
+        let snapshot: QdrantSnapshot = json::deserialize_from_value(result)?;
+        user_info!(
+            "INF_QDRANT_SNAPSHOT_CREATED",
+            json_value!({ "collection": collection, "snapshot": snapshot.name })
+        );
+        Ok(snapshot)
+    }
+
+    pub async fn list_snapshots(&self, collection: &str) -> RaiseResult<Vec<QdrantSnapshot>> {
+        let path = format!("/collections/{}/snapshots", collection);
+        match self.call(HttpMethod::GET, &path, None).await? {
+            Some(result) => json::deserialize_from_value(result),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// Identifiant de point Qdrant (UUID) : repris tel quel ou dérivé de l'ID RAISE par SHA-256.
+fn point_id(record_id: &str) -> String {
+    if let Ok(uuid) = UniqueId::parse_str(record_id) {
+        return uuid.to_string();
+    }
+    let digest = CryptoSha256::digest(record_id.as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    UniqueId::from_bytes(bytes).to_string()
+}
+
+/// Filtre `must` sur les métadonnées, avec typage identique au store natif (entier, booléen, texte).
+fn build_filter(filter: &UnorderedMap<String, String>) -> JsonValue {
+    let must: Vec<JsonValue> = filter
+        .iter()
+        .map(|(k, v)| {
+            let value = if let Ok(n) = v.parse::<i64>() {
+                json_value!(n)
+            } else if let Ok(b) = v.parse::<bool>() {
+                json_value!(b)
+            } else {
+                json_value!(v)
+            };
+            json_value!({ "key": format!("metadata.{}", k), "match": { "value": value } })
+        })
+        .collect();
+    json_value!({ "must": must })
+}
+
+fn parse_collection_info(name: &str, result: &JsonValue) -> QdrantCollectionInfo {
+    let vectors = &result["config"]["params"]["vectors"];
+    let payload_indexes = result["payload_schema"]
+        .as_object()
+        .map(|schema| {
+            schema
+                .iter()
+                .map(|(field, def)| {
+                    let kind = def["data_type"].as_str().unwrap_or("unknown").to_string();
+                    (field.clone(), kind)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    QdrantCollectionInfo {
+        name: name.to_string(),
+        status: result["status"].as_str().unwrap_or("unknown").to_string(),
+        points_count: result["points_count"].as_u64(),
+        vector_size: vectors["size"].as_u64(),
+        distance: vectors["distance"].as_str().map(str::to_string),
+        payload_indexes,
+    }
+}
+
+#[async_interface]
+impl VectorStore for QdrantMemory {
+    async fn init_collection(
+        &self,
+        _manager: &CollectionsManager<'_>,
+        collection_name: &str,
+        vector_size: u64,
+    ) -> RaiseResult<()> {
+        self.create_collection(collection_name, vector_size).await?;
+        Ok(())
+    }
+
+    async fn add_documents(
+        &self,
+        _manager: &CollectionsManager<'_>,
+        collection_name: &str,
+        records: Vec<MemoryRecord>,
+    ) -> RaiseResult<()> {
+        let points: Vec<JsonValue> = records
+            .into_iter()
+            .filter_map(|rec| {
+                let id = if rec.id.is_empty() {
+                    UniqueId::new_v4().to_string()
+                } else {
+                    rec.id
+                };
+                rec.vectors.map(|vector| {
+                    json_value!({
+                        "id": point_id(&id),
+                        "vector": vector,
+                        "payload": {
+                            "record_id": id,
+                            "content": rec.content,
+                            "metadata": rec.metadata
+                        }
+                    })
+                })
+            })
+            .collect();
+
+        if points.is_empty() {
+            return Ok(());
+        }
+
+        let path = format!("/collections/{}/points?wait=true", collection_name);
+        if self
+            .call(
+                HttpMethod::PUT,
+                &path,
+                Some(json_value!({ "points": points })),
+            )
+            .await?
+            .is_none()
+        {
+            raise_error!(
+                "ERR_QDRANT_COLLECTION_NOT_FOUND",
+                error = "Collection Qdrant introuvable (init_collection manquant ?)",
+                context = json_value!({ "collection": collection_name })
+            );
+        }
+        Ok(())
+    }
+
+    async fn search_scored(
+        &self,
+        _manager: &CollectionsManager<'_>,
+        collection_name: &str,
+        vector: &[f32],
+        limit: u64,
+        score_threshold: f32,
+        filter: Option<UnorderedMap<String, String>>,
+    ) -> RaiseResult<Vec<(MemoryRecord, f32)>> {
+        let mut body = json_value!({
+            "vector": vector,
+            "limit": limit,
+            "score_threshold": score_threshold,
+            "with_payload": true
+        });
+        if let Some(f) = filter.as_ref().filter(|f| !f.is_empty()) {
+            body["filter"] = build_filter(f);
+        }
+
+        let path = format!("/collections/{}/points/search", collection_name);
+        let hits = match self.call(HttpMethod::POST, &path, Some(body)).await? {
+            Some(JsonValue::Array(hits)) => hits,
+            _ => return Ok(vec![]),
+        };
+
+        Ok(hits
+            .into_iter()
+            .map(|hit| {
+                let payload = &hit["payload"];
+                let record = MemoryRecord {
+                    id: payload["record_id"]
+                        .as_str()
+                        .map(str::to_string)
+                        .unwrap_or_else(|| hit["id"].to_string()),
+                    content: payload["content"].as_str().unwrap_or("").to_string(),
+                    metadata: payload.get("metadata").cloned().unwrap_or(json_value!({})),
+                    vectors: None,
+                };
+                (record, hit["score"].as_f64().unwrap_or(0.0) as f32)
+            })
+            .collect())
+    }
+
+    /// Les collections vivent côté serveur : rien à libérer localement.
+    async fn unload_collection(&self, _collection_name: &str) -> RaiseResult<()> {
+        Ok(())
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::AgentDbSandbox;
+
+    #[test]
+    fn test_distance_parsing_and_config() -> RaiseResult<()> {
+        assert_eq!(QdrantDistance::parse("euclidean")?, QdrantDistance::Euclid);
+        assert!(QdrantDistance::parse("hamming").is_err());
+
+        let store = QdrantMemory::from_settings(&json_value!({
+            "qdrant_url": "http://qdrant.local:6333/",
+            "qdrant_distance": "dot"
+        }))?;
+        assert_eq!(store.distance(), QdrantDistance::Dot);
+        assert_eq!(store.base_url, "http://qdrant.local:6333");
+        assert!(QdrantMemory::new("qdrant.local:6333").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_point_id_is_stable_uuid() {
+        let uuid = UniqueId::new_v4().to_string();
+        assert_eq!(point_id(&uuid), uuid);
+        assert_eq!(point_id("doc-42"), point_id("doc-42"));
+        assert!(UniqueId::parse_str(&point_id("doc-42")).is_ok());
+        assert_ne!(point_id("doc-42"), point_id("doc-43"));
+    }
+
+    #[test]
+    fn test_filter_and_collection_info_mapping() {
+        let filter = UnorderedMap::from([("layer".to_string(), "sa".to_string())]);
+        let f = build_filter(&filter);
+        assert_eq!(f["must"][0]["key"], "metadata.layer");
+        assert_eq!(f["must"][0]["match"]["value"], "sa");
+
+        let info = parse_collection_info(
+            "kb",
+            &json_value!({
+                "status": "green",
+                "points_count": 12,
+                "config": { "params": { "vectors": { "size": 384, "distance": "Cosine" } } },
+                "payload_schema": { "metadata.layer": { "data_type": "keyword", "points": 12 } }
+            }),
+        );
+        assert_eq!(info.vector_size, Some(384));
+        assert_eq!(info.payload_indexes["metadata.layer"], "keyword");
+    }
+
+    /// Nécessite un serveur Qdrant (`docker run -p 6333:6333 qdrant/qdrant`).
+    #[async_test]
+    #[ignore]
+    async fn test_qdrant_collection_lifecycle() -> RaiseResult<()> {
+        let sandbox = AgentDbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.db,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        let store = QdrantMemory::new("http://127.0.0.1:6333")?.with_distance(QdrantDistance::Dot);
+        let col = "raise_lifecycle_test";
+
+        let _ = store.delete_collection(col).await?;
+        store.init_collection(&manager, col, 2).await?;
+        assert!(store.list_collections().await?.contains(&col.to_string()));
+
+        store
+            .create_payload_index(col, "metadata.layer", PayloadFieldType::Keyword)
+            .await?;
+        store
+            .add_documents(
+                &manager,
+                col,
+                vec![MemoryRecord {
+                    id: "doc-1".into(),
+                    content: "Qdrant".into(),
+                    metadata: json_value!({ "layer": "sa" }),
+                    vectors: Some(vec![1.0, 0.0]),
+                }],
+            )
+            .await?;
+
+        let filter = UnorderedMap::from([("layer".to_string(), "sa".to_string())]);
+        let hits = store
+            .search_similarity(&manager, col, &[1.0, 0.0], 5, 0.0, Some(filter))
+            .await?;
+        assert_eq!(hits[0].id, "doc-1");
+
+        let info = store.collection_info(col).await?.unwrap();
+        assert_eq!(info.distance.as_deref(), Some("Dot"));
+        assert!(info.payload_indexes.contains_key("metadata.layer"));
+
+        assert!(!store.create_snapshot(col).await?.name.is_empty());
+        assert!(store.delete_collection(col).await?);
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+// FICHIER : src-tauri/src/ai/memory/query_provider.rs
+
+//! Pont entre le `QueryEngine` de JSON-DB et un `VectorStore` : sert les conditions
+//! `NearText` (texte vectorisé par l'`EmbeddingEngine`) et `NearVector` d'une requête.
+
+use crate::ai::nlp::embeddings::EmbeddingEngine;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::executor::BoxFuture;
+use crate::json_db::query::vector::{VectorSearch, VectorSearchProvider, VectorTarget};
+use crate::utils::prelude::*; // 🎯 Façade Unique
+
+use super::VectorStore;
+
+pub struct StoreVectorSearch {
+    store: SharedRef<dyn VectorStore>,
+    embedder: Option<SharedRef<AsyncMutex<EmbeddingEngine>>>,
+}
+
+impl StoreVectorSearch {
+    /// Sans moteur d'embeddings, seules les conditions `NearVector` sont servies.
+    pub fn new(store: SharedRef<dyn VectorStore>) -> Self {
+        Self {
+            store,
+            embedder: None,
+        }
+    }
+
+    pub fn with_embedder(mut self, embedder: SharedRef<AsyncMutex<EmbeddingEngine>>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    async fn query_vector(&self, target: &VectorTarget) -> RaiseResult<Vec<f32>> {
+        match (target, &self.embedder) {
+            (VectorTarget::Vector(vector), _) => Ok(vector.clone()),
+            (VectorTarget::Text(text), Some(embedder)) => embedder.lock().await.embed_query(text),
+            (VectorTarget::Text(_), None) => raise_error!(
+                "ERR_DB_QUERY_VECTOR_EMBEDDER_MISSING",
+                error = "NearText nécessite un moteur d'embeddings : utilisez NearVector ou branchez-en un.",
+                context = json_value!({ "hint": "StoreVectorSearch::with_embedder" })
+            ),
+        }
+    }
+}
+
+impl VectorSearchProvider for StoreVectorSearch {
+    fn search<'a>(
+        &'a self,
+        manager: &'a CollectionsManager<'a>,
+        request: &'a VectorSearch,
+    ) -> BoxFuture<'a, RaiseResult<Vec<(String, f64)>>> {
+        Box::pin(async move {
+            let vector = self.query_vector(&request.target).await?;
+            let hits = self
+                .store
+                .search_scored(
+                    manager,
+                    &request.collection,
+                    &vector,
+                    request.k as u64,
+                    request.min_score,
+                    None,
+                )
+                .await?;
+            Ok(hits
+                .into_iter()
+                .map(|(record, score)| (record.id, score as f64))
+                .collect())
+        })
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ai::memory::MemoryRecord;
+    use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
+    use crate::utils::testing::DbSandbox;
+
+    /// Store en mémoire : produit scalaire sur des vecteurs fixés par `_id`.
+    struct FixedStore(Vec<(&'static str, Vec<f32>)>);
+
+    #[async_interface]
+    impl VectorStore for FixedStore {
+        async fn init_collection(
+            &self,
+            _manager: &CollectionsManager<'_>,
+            _collection_name: &str,
+            _vector_size: u64,
+        ) -> RaiseResult<()> {
+            Ok(())
+        }
+
+        async fn add_documents(
+            &self,
+            _manager: &CollectionsManager<'_>,
+            _collection_name: &str,
+            _records: Vec<MemoryRecord>,
+        ) -> RaiseResult<()> {
+            Ok(())
+        }
+
+        async fn search_scored(
+            &self,
+            _manager: &CollectionsManager<'_>,
+            _collection_name: &str,
+            vector: &[f32],
+            limit: u64,
+            score_threshold: f32,
+            _filter: Option<UnorderedMap<String, String>>,
+        ) -> RaiseResult<Vec<(MemoryRecord, f32)>> {
+            let mut hits: Vec<(MemoryRecord, f32)> = self
+                .0
+                .iter()
+                .map(|(id, v)| {
+                    let score = v.iter().zip(vector).map(|(a, b)| a * b).sum::<f32>();
+                    let record = MemoryRecord {
+                        id: id.to_string(),
+                        content: String::new(),
+                        metadata: json_value!({}),
+                        vectors: None,
+                    };
+                    (record, score)
+                })
+                .filter(|(_, score)| *score >= score_threshold)
+                .collect();
+            hits.sort_by(|a, b| b.1.total_cmp(&a.1));
+            hits.truncate(limit as usize);
+            Ok(hits)
+        }
+
+        async fn unload_collection(&self, _collection_name: &str) -> RaiseResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_test]
+    async fn test_near_vector_combines_with_structured_filter() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "semantic");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "requirements",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        for doc in [
+            json_value!({ "_id": "r1", "title": "Freinage d'urgence", "status": "draft" }),
+            json_value!({ "_id": "r2", "title": "Distance de freinage", "status": "draft" }),
+            json_value!({ "_id": "r3", "title": "ABS", "status": "approved" }),
+            json_value!({ "_id": "r4", "title": "Éclairage cabine", "status": "draft" }),
+        ] {
+            manager.insert_raw("requirements", &doc).await?;
+        }
+        let store = FixedStore(vec![
+            ("r1", vec![0.8, 0.2]),
+            ("r2", vec![0.9, 0.1]),
+            ("r3", vec![1.0, 0.0]),
+            ("r4", vec![0.0, 1.0]),
+        ]);
+
+        // "Exigences proches de [1, 0] au statut draft"
+        let near = json_value!({ "vector": [1.0, 0.0], "min_score": 0.5 });
+        let mut query = Query::new("requirements");
+        query.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![
+                Condition::eq("status", json_value!("draft")),
+                Condition::near_vector("", near),
+            ],
+        });
+
+        let engine = QueryEngine::new(&manager)
+            .with_vector_provider(Box::new(StoreVectorSearch::new(SharedRef::new(store))));
+        let result = engine.execute_query(query.clone()).await?;
+        let ids: Vec<&str> = result
+            .documents
+            .iter()
+            .filter_map(|d| d["_id"].as_str())
+            .collect();
+        assert_eq!(ids, vec!["r2", "r1"]);
+        assert!((result.documents[0]["_score"].as_f64().unwrap() - 0.9).abs() < 1e-6);
+
+        // Sans store branché ou sans moteur d'embeddings : erreur explicite
+        assert!(QueryEngine::new(&manager)
+            .execute_query(query)
+            .await
+            .is_err());
+        let mut near_text = Query::new("requirements");
+        near_text.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::near_text("", json_value!("freinage"))],
+        });
+        let engine = QueryEngine::new(&manager).with_vector_provider(Box::new(
+            StoreVectorSearch::new(SharedRef::new(FixedStore(Vec::new()))),
+        ));
+        assert!(engine.execute_query(near_text).await.is_err());
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ pub mod context;
 pub mod deep_learning;
 pub mod graph_store;
 pub mod llm;
+pub mod maintenance;
 pub mod memory;
 pub mod nlp;
 pub mod orchestrator;
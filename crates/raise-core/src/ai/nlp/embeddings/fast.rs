@@ -8,6 +8,7 @@ pub struct FastEmbedEngine {
     // 🎯 ZÉRO DETTE : On ne manipule plus la librairie tierce ici.
     // On s'appuie exclusivement sur la forteresse que vous avez bâtie.
     embedder: TextEmbedder,
+    model_name: String,
 }
 
 impl FastEmbedEngine {
@@ -38,7 +39,14 @@ impl FastEmbedEngine {
             json_value!({ "model": model_name_str, "status": "initialized_via_core_facade" })
         );
 
-        Ok(Self { embedder })
+        Ok(Self {
+            embedder,
+            model_name: model_name_str.to_string(),
+        })
+    }
+
+    pub fn model_name(&self) -> &str {
+        &self.model_name
     }
 
     /// Vectorise un lot de textes (Batch Inference) pour optimiser le débit.
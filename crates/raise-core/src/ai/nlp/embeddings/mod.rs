@@ -14,6 +14,8 @@ pub enum EngineType {
 
 pub struct EmbeddingEngine {
     inner: EngineImplementation,
+    /// Dimension des vecteurs produits, mesurée au premier besoin.
+    dimension: Option<usize>,
 }
 
 enum EngineImplementation {
@@ -73,7 +75,28 @@ impl EmbeddingEngine {
                 EngineImplementation::Native(Box::new(native_engine))
             }
         };
-        Ok(Self { inner })
+        Ok(Self {
+            inner,
+            dimension: None,
+        })
+    }
+
+    /// Identifiant du modèle actif (`backend:modèle`), consigné dans les index vectoriels.
+    pub fn model_id(&self) -> String {
+        match &self.inner {
+            EngineImplementation::Lightweight(e) => format!("fastembed:{}", e.model_name()),
+            EngineImplementation::Native(e) => format!("native:{}", e.model_name()),
+        }
+    }
+
+    /// Dimension des vecteurs produits par le modèle actif.
+    pub fn dimension(&mut self) -> RaiseResult<usize> {
+        if let Some(dimension) = self.dimension {
+            return Ok(dimension);
+        }
+        let dimension = self.embed_query("dimension")?.len();
+        self.dimension = Some(dimension);
+        Ok(dimension)
     }
 
     /// Vectorise un lot de textes (Batch Inference) avec dispatching sémantique.
@@ -7,6 +7,7 @@ pub struct NativeNlpEngine {
     model: NeuralBertModel,
     tokenizer: TextTokenizer,
     device: ComputeHardware,
+    model_name: String,
 }
 
 impl NativeNlpEngine {
@@ -149,9 +150,14 @@ impl NativeNlpEngine {
             model,
             tokenizer,
             device,
+            model_name: model_dir.to_string(),
         })
     }
 
+    pub fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
     /// Vectorise un lot de textes (Batch Inference)
     pub fn embed_batch(&mut self, texts: Vec<String>) -> RaiseResult<Vec<Vec<f32>>> {
         let batch_size = texts.len();
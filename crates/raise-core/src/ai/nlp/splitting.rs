@@ -1,4 +1,4 @@
-use text_splitter::TextSplitter;
+use text_splitter::{ChunkConfig, TextSplitter};
 
 /// Découpe un texte long en morceaux (chunks) respectant une limite approximative de caractères.
 pub fn split_text_into_chunks(text: &str, max_tokens_per_chunk: usize) -> Vec<String> {
@@ -14,6 +14,25 @@ pub fn split_text_into_chunks(text: &str, max_tokens_per_chunk: usize) -> Vec<St
     splitter.chunks(text).map(|s| s.to_string()).collect()
 }
 
+/// Découpe avec chevauchement : la fin de chaque chunk est répétée au début du suivant,
+/// afin qu'une phrase coupée reste retrouvable dans son contexte.
+pub fn split_text_with_overlap(
+    text: &str,
+    max_tokens_per_chunk: usize,
+    overlap_tokens: usize,
+) -> Vec<String> {
+    let max_chars = max_tokens_per_chunk * 4;
+    // Un chevauchement supérieur ou égal à la taille du chunk est refusé par le splitter
+    let config = match ChunkConfig::new(max_chars).with_overlap(overlap_tokens * 4) {
+        Ok(config) => config,
+        Err(_) => return split_text_into_chunks(text, max_tokens_per_chunk),
+    };
+    TextSplitter::new(config)
+        .chunks(text)
+        .map(|s| s.to_string())
+        .collect()
+}
+
 pub fn split_markdown(text: &str, max_tokens: usize) -> Vec<String> {
     // TextSplitter gère intelligemment la sémantique (paragraphes, etc.) par défaut
     split_text_into_chunks(text, max_tokens)
@@ -32,4 +51,20 @@ mod tests {
         assert!(!chunks.is_empty());
         println!("Chunks: {:?}", chunks);
     }
+
+    #[test]
+    fn test_splitting_with_overlap() {
+        let text = "alpha bravo charlie delta echo foxtrot golf hotel india juliet";
+        let chunks = split_text_with_overlap(text, 5, 2);
+        assert!(chunks.len() > 1);
+        // Le début de chaque chunk reprend la fin du précédent
+        let tail = chunks[0].split_whitespace().last().unwrap_or_default();
+        assert!(chunks[1].contains(tail));
+
+        // Chevauchement invalide : repli sur le découpage simple
+        assert_eq!(
+            split_text_with_overlap(text, 5, 5),
+            split_text_into_chunks(text, 5)
+        );
+    }
 }
@@ -4,7 +4,7 @@ use crate::ai::context::{
     conversation_manager::ConversationSession, memory_store::MemoryStore, rag::RagRetriever,
     retriever::SimpleRetriever,
 };
-use crate::ai::llm::client::{LlmBackend, LlmClient, LlmEngine};
+use crate::ai::llm::client::{LlmBackend, LlmClient, LlmEngine, TokenSink};
 use crate::ai::nlp::parser::CommandType;
 use crate::ai::world_model::engine::WorldModelConfig;
 use crate::ai::world_model::{NeuroSymbolicEngine, WorldAction, WorldTrainer};
@@ -13,11 +13,61 @@ use crate::json_db::storage::StorageEngine;
 use crate::model_engine::types::{ArcadiaElement, ProjectModel};
 use crate::utils::data::json::Clearance;
 use crate::utils::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 // --- IMPORTS AGENTS ---
+use crate::ai::agents::coordination::{ElementLockRegistry, WriteConflict};
 use crate::ai::agents::intent_classifier::IntentClassifier;
 use crate::ai::agents::{dynamic_agent::DynamicAgent, Agent, AgentContext, AgentResult};
 
+/// Événement d'une réponse diffusée en flux (`execute_workflow_stream`).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AiStreamEvent {
+    /// Un agent prend la main (premier tour ou redirection).
+    Agent { agent_id: String },
+    /// Fragment de texte généré par le LLM.
+    Delta { text: String },
+    /// Fin normale : résultat consolidé, identique à `execute_workflow`.
+    Completed { result: AgentResult },
+    /// Génération interrompue à la demande de l'utilisateur.
+    Cancelled,
+}
+
+/// Jeton d'annulation partagé entre le flux en cours et la commande d'arrêt.
+#[derive(Clone, Default)]
+pub struct StreamCancellation {
+    flag: SharedRef<AtomicBool>,
+    signal: SharedRef<AsyncSignal>,
+}
+
+impl StreamCancellation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+        self.signal.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+
+    /// Se résout dès que l'annulation est demandée.
+    pub async fn cancelled(&self) {
+        loop {
+            // Le futur est créé avant le test : un `cancel` concurrent ne peut pas être perdu
+            let notified = self.signal.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
 /// Chef d'orchestre du système IA RAISE.
 /// Gère le cycle de vie hybride : RAG sémantique, Inférence LLM et World Model Neuro-Symbolique.
 pub struct AiOrchestrator {
@@ -28,6 +78,8 @@ pub struct AiOrchestrator {
     pub session: ConversationSession,
     pub memory_store: MemoryStore,
     pub world_engine: SharedRef<NeuroSymbolicEngine>,
+    /// Verrous d'éléments partagés par les agents (conflits d'écriture → arbitrage humain).
+    pub locks: SharedRef<ElementLockRegistry>,
 
     pub space: String,
     pub db_name: String,
@@ -82,10 +134,11 @@ impl AiOrchestrator {
             rag,
             symbolic,
             llm_native: native_llm,
-            llm_remote,
+            llm_remote: llm_remote.with_session(&session.id),
             session,
             memory_store,
             world_engine: SharedRef::new(world_engine),
+            locks: ElementLockRegistry::global(),
             space: manager.space.to_string(),
             db_name: manager.db.to_string(),
             storage,
@@ -94,6 +147,45 @@ impl AiOrchestrator {
 
     /// Exécute un workflow multi-agents complet avec routage d'intention.
     pub async fn execute_workflow(&mut self, user_query: &str) -> RaiseResult<AgentResult> {
+        self.run_workflow(user_query, None).await
+    }
+
+    /// Variante en flux de `execute_workflow` : les fragments générés par les agents sont
+    /// émis sur `events` au fil de l'eau. `cancel` interrompt la génération en cours
+    /// (`ERR_AI_STREAM_CANCELLED`) ; les verrous de l'agent actif sont alors libérés.
+    pub async fn execute_workflow_stream(
+        &mut self,
+        user_query: &str,
+        events: AsyncChannel::UnboundedSender<AiStreamEvent>,
+        cancel: StreamCancellation,
+    ) -> RaiseResult<AgentResult> {
+        let outcome = self
+            .run_workflow(user_query, Some((&events, &cancel)))
+            .await;
+        match outcome {
+            Ok(result) => {
+                let _ = events.send(AiStreamEvent::Completed {
+                    result: result.clone(),
+                });
+                Ok(result)
+            }
+            Err(e) => {
+                if cancel.is_cancelled() {
+                    let _ = events.send(AiStreamEvent::Cancelled);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn run_workflow(
+        &mut self,
+        user_query: &str,
+        stream: Option<(
+            &AsyncChannel::UnboundedSender<AiStreamEvent>,
+            &StreamCancellation,
+        )>,
+    ) -> RaiseResult<AgentResult> {
         let app_config = AppConfig::get();
         let storage_arc = self.storage.clone();
 
@@ -129,8 +221,35 @@ impl AiOrchestrator {
         const MAX_HOPS: i32 = 5;
         let mut accumulated_artifacts = Vec::new();
         let mut accumulated_messages = Vec::new();
+        let mut accumulated_conflicts = Vec::new();
+
+        // En flux, les agents parlent via un client qui relaie chaque fragment ;
+        // le classifieur d'intention reste silencieux.
+        let agent_llm = match stream {
+            Some((events, cancel)) => {
+                let (events, cancel) = (events.clone(), cancel.clone());
+                let sink: TokenSink = SharedRef::new(move |chunk: &str| {
+                    !cancel.is_cancelled()
+                        && events
+                            .send(AiStreamEvent::Delta {
+                                text: chunk.to_string(),
+                            })
+                            .is_ok()
+                });
+                self.llm_remote.clone().with_stream(sink)
+            }
+            None => self.llm_remote.clone(),
+        };
 
         loop {
+            if let Some((_, cancel)) = stream {
+                if cancel.is_cancelled() {
+                    raise_error!(
+                        "ERR_AI_STREAM_CANCELLED",
+                        context = json_value!({ "agent": current_agent_urn })
+                    );
+                }
+            }
             if hop_count >= MAX_HOPS {
                 accumulated_messages
                     .push("⚠️ Limite de redirections entre agents atteinte.".to_string());
@@ -141,18 +260,37 @@ impl AiOrchestrator {
                 &current_agent_urn,
                 &global_session_id,
                 storage_arc.clone(),
-                self.llm_remote.clone(),
+                agent_llm.clone(),
                 self.world_engine.clone(),
                 domain_path.clone(),
                 dataset_path.clone(),
             )
-            .await?;
+            .await?
+            .with_locks(self.locks.clone());
 
             let agent = DynamicAgent::new(&current_agent_urn);
-            match agent.process(&ctx, &current_intent).await? {
+            let outcome = match stream {
+                Some((events, cancel)) => {
+                    let _ = events.send(AiStreamEvent::Agent {
+                        agent_id: current_agent_urn.clone(),
+                    });
+                    AgentAttention! {
+                        res = agent.process(&ctx, &current_intent) => res,
+                        _ = cancel.cancelled() => Err(build_error!(
+                            "ERR_AI_STREAM_CANCELLED",
+                            context = json_value!({ "agent": current_agent_urn })
+                        )),
+                    }
+                }
+                None => agent.process(&ctx, &current_intent).await,
+            };
+            // Fin de tour : l'agent rend la main, ses verrous sont libérés
+            self.locks.release_all(&current_agent_urn);
+            match outcome? {
                 Some(res) => {
                     accumulated_artifacts.extend(res.artifacts);
                     accumulated_messages.push(res.message);
+                    accumulated_conflicts.extend(res.conflicts);
 
                     if let Some(acl_msg) = res.outgoing_message {
                         current_agent_urn = acl_msg.receiver.clone();
@@ -172,9 +310,27 @@ impl AiOrchestrator {
             artifacts: accumulated_artifacts,
             outgoing_message: None,
             xai_frame: None,
+            pending_changes: vec![],
+            conflicts: accumulated_conflicts,
         })
     }
 
+    /// Écritures d'agents suspendues en attente d'arbitrage humain.
+    pub fn pending_conflicts(&self) -> Vec<WriteConflict> {
+        self.locks.pending_conflicts(None)
+    }
+
+    /// Arbitre un conflit d'écriture (`approved` applique la proposition de l'agent).
+    pub async fn resolve_conflict(
+        &self,
+        conflict_id: &str,
+        approved: bool,
+    ) -> RaiseResult<WriteConflict> {
+        self.locks
+            .resolve(&self.storage, conflict_id, approved)
+            .await
+    }
+
     /// Interface "Ask" optimisée : Priorité au Local (VRAM partagée) -> Fallback Cloud.
     pub async fn ask(&mut self, query: &str) -> RaiseResult<String> {
         self.session.add_user_message(query);
@@ -300,6 +456,29 @@ mod tests {
         }
     }
 
+    #[async_test]
+    async fn test_stream_cancellation_wakes_waiters() {
+        let cancel = StreamCancellation::new();
+        assert!(!cancel.is_cancelled());
+
+        let waiter = cancel.clone();
+        let handle = spawn_async_task(async move { waiter.cancelled().await });
+        cancel.cancel();
+        handle.await.unwrap();
+        assert!(cancel.is_cancelled());
+
+        // Déjà annulé : résolution immédiate
+        cancel.cancelled().await;
+    }
+
+    #[test]
+    fn test_stream_event_wire_format() {
+        let delta = json::serialize_to_value(&AiStreamEvent::Delta { text: "Bon".into() }).unwrap();
+        assert_eq!(delta, json_value!({ "type": "delta", "text": "Bon" }));
+        let cancelled = json::serialize_to_value(&AiStreamEvent::Cancelled).unwrap();
+        assert_eq!(cancelled, json_value!({ "type": "cancelled" }));
+    }
+
     #[async_test]
     #[serial_test::serial]
     #[cfg_attr(not(feature = "cuda"), ignore)]
@@ -1,5 +1,6 @@
 // FICHIER : src-tauri/src/ai/training/dataset.rs
 
+use crate::json_db::collections::id_strategy::content_hash;
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::utils::prelude::*; // 🎯 Façade Unique
 
@@ -10,13 +11,36 @@ pub struct TrainingExample {
     pub output: String,
 }
 
+/// Provenance d'un jeu d'entraînement, reprise dans la fiche modèle de l'adaptateur.
+#[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
+pub struct DatasetProvenance {
+    pub domain: String,
+    pub space: String,
+    pub db: String,
+    /// Collections retenues et nombre d'exemples tirés de chacune.
+    pub sources: OrderedMap<String, usize>,
+    pub samples: usize,
+    /// Empreinte canonique des exemples : deux extractions identiques ont la même empreinte.
+    pub fingerprint: String,
+    pub extracted_at: i64,
+}
+
 /// Extrait les données spécifiquement pour un domaine métier à partir du Graphe de Connaissance.
 /// Cette fonction alimente le moteur d'entraînement natif en respectant les points de montage.
 pub async fn extract_domain_data(
     manager: &CollectionsManager<'_>,
     domain: &str,
 ) -> RaiseResult<Vec<TrainingExample>> {
+    Ok(extract_domain_dataset(manager, domain).await?.0)
+}
+
+/// Comme `extract_domain_data`, avec la provenance du jeu extrait.
+pub async fn extract_domain_dataset(
+    manager: &CollectionsManager<'_>,
+    domain: &str,
+) -> RaiseResult<(Vec<TrainingExample>, DatasetProvenance)> {
     let mut dataset = Vec::new();
+    let mut sources = OrderedMap::new();
 
     // 1. Récupération de la liste des collections via le manager
     // 🎯 Rigueur : Utilisation de Match...raise_error au lieu de expect/unwrap
@@ -53,6 +77,7 @@ pub async fn extract_domain_data(
         };
 
         // 3. Transformation en exemples d'entraînement (Synthetic Augmentation)
+        let before = dataset.len();
         for doc in docs {
             dataset.push(TrainingExample {
                 instruction: format!("Analyser cet élément technique du domaine {}.", domain),
@@ -66,6 +91,9 @@ pub async fn extract_domain_data(
                 ),
             });
         }
+        if dataset.len() > before {
+            sources.insert(col, dataset.len() - before);
+        }
     }
 
     user_info!(
@@ -73,7 +101,16 @@ pub async fn extract_domain_data(
         json_value!({ "domain": domain, "samples": dataset.len() })
     );
 
-    Ok(dataset)
+    let provenance = DatasetProvenance {
+        domain: domain.to_string(),
+        space: manager.space.clone(),
+        db: manager.db.clone(),
+        sources,
+        samples: dataset.len(),
+        fingerprint: content_hash(&json_value!({ "examples": dataset })),
+        extracted_at: UtcClock::now().timestamp(),
+    };
+    Ok((dataset, provenance))
 }
 
 // =========================================================================
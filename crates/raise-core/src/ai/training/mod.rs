@@ -1,5 +1,6 @@
 // FICHIER : src-tauri/src/ai/training/mod.rs
 
+use crate::ai::assurance::model_card::{self, ModelCardFormat, ModelCardGenerator, TrainingRun};
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::utils::prelude::*; // 🎯 Façade Unique
 
@@ -63,7 +64,7 @@ pub async fn ai_train_domain_native(
     // ---------------------------------------------------------
     // 2. EXTRACTION DES DONNÉES D'ENTRAÎNEMENT
     // ---------------------------------------------------------
-    let examples = dataset::extract_domain_data(manager, domain).await?;
+    let (examples, provenance) = dataset::extract_domain_dataset(manager, domain).await?;
 
     if examples.is_empty() {
         raise_error!(
@@ -94,6 +95,7 @@ pub async fn ai_train_domain_native(
     // ---------------------------------------------------------
     // 4. BOUCLE D'APPRENTISSAGE RÉSILIENTE
     // ---------------------------------------------------------
+    let mut final_loss = None;
     for epoch in 1..=epochs {
         let mut epoch_loss = 0.0;
 
@@ -136,9 +138,11 @@ pub async fn ai_train_domain_native(
             epoch_loss += loss.to_vec0::<f32>().unwrap_or(0.0);
         }
 
+        let mean_loss = epoch_loss / examples.len() as f32;
+        final_loss = Some(mean_loss as f64);
         user_info!(
             "MSG_TRAINING_EPOCH_COMPLETE",
-            json_value!({ "epoch": epoch, "loss": epoch_loss / examples.len() as f32 })
+            json_value!({ "epoch": epoch, "loss": mean_loss })
         );
     }
 
@@ -155,25 +159,52 @@ pub async fn ai_train_domain_native(
         "ai-assets/lora",
     )?;
 
-    let lora_dir = lora_base_path.join(format!("raise-{}-adapter", domain));
+    let adapter = format!("raise-{}-adapter", domain);
+    let lora_dir = lora_base_path.join(&adapter);
 
     fs::ensure_dir_async(&lora_dir).await?;
 
     let save_path = lora_dir.join("adapter_model.safetensors");
-    match varmap.save(&save_path) {
-        Ok(_) => {
-            user_success!(
-                "MSG_TRAINING_SUCCESS",
-                json_value!({ "path": save_path.to_string_lossy(), "domain": domain })
-            );
-            Ok(format!("Adaptateur sauvegardé : {:?}", save_path))
-        }
-        Err(e) => raise_error!(
+    if let Err(e) = varmap.save(&save_path) {
+        raise_error!(
             "ERR_MODEL_SAVE_WEIGHTS",
             error = e.to_string(),
             context = json_value!({"path": save_path.to_string_lossy()})
-        ),
+        );
+    }
+
+    // ---------------------------------------------------------
+    // 6. FICHE MODÈLE (ASSURANCE IA)
+    // ---------------------------------------------------------
+    let mut generator = ModelCardGenerator::new(&adapter)
+        .with_artifact_path(&save_path)
+        .with_training(TrainingRun {
+            epochs,
+            learning_rate: lr,
+            final_loss,
+            device: format!("{:?}", device),
+        })
+        .with_dataset(provenance);
+    if let Some(base_model) = settings.get("rust_model_file").and_then(|v| v.as_str()) {
+        generator = generator.with_base_model(base_model);
     }
+    let card = generator.generate();
+    model_card::save_model_card(manager, &card).await?;
+    card.export(
+        &lora_dir,
+        &[
+            ModelCardFormat::Json,
+            ModelCardFormat::Markdown,
+            ModelCardFormat::Pdf,
+        ],
+    )
+    .await?;
+
+    user_success!(
+        "MSG_TRAINING_SUCCESS",
+        json_value!({ "path": save_path.to_string_lossy(), "domain": domain, "model_card": card.id })
+    );
+    Ok(format!("Adaptateur sauvegardé : {:?}", save_path))
 }
 
 // =========================================================================
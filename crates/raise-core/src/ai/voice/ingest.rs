@@ -0,0 +1,311 @@
+// FICHIER : src-tauri/src/ai/voice/ingest.rs
+
+//! Ingestion audio pour la transcription : décode un enregistrement (WAV ou PCM brut issu de
+//! la WebAudio API), le ramène au format attendu par Whisper (mono, 16 kHz, f32) puis le
+//! transcrit par fenêtres de 30 s. Le texte obtenu alimente ensuite `ai_chat`.
+
+use super::stt::WhisperEngine;
+use crate::utils::prelude::*;
+
+/// Fréquence d'échantillonnage attendue par Whisper.
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+/// Fenêtre maximale traitée en une passe par Whisper.
+pub const WHISPER_WINDOW_SECONDS: usize = 30;
+/// Durée maximale acceptée pour un enregistrement.
+pub const MAX_AUDIO_SECONDS: f32 = 300.0;
+
+/// Enregistrement transmis par l'interface.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+#[serde(tag = "format", rename_all = "snake_case")]
+pub enum AudioPayload {
+    /// Fichier WAV encodé en base64.
+    Wav { data_base64: String },
+    /// Échantillons f32 entrelacés (ex: `AudioBuffer.getChannelData`).
+    Pcm {
+        samples: Vec<f32>,
+        sample_rate: u32,
+        #[serde(default = "default_channels")]
+        channels: u16,
+    },
+}
+
+fn default_channels() -> u16 {
+    1
+}
+
+/// Signal décodé (échantillons entrelacés, normalisés dans [-1, 1]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioClip {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+/// Résultat d'une transcription.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct Transcription {
+    pub text: String,
+    pub duration_seconds: f32,
+    pub segments: usize,
+}
+
+impl AudioClip {
+    pub fn duration_seconds(&self) -> f32 {
+        if self.sample_rate == 0 || self.channels == 0 {
+            return 0.0;
+        }
+        self.samples.len() as f32 / (self.sample_rate as f32 * self.channels as f32)
+    }
+
+    /// Signal mono 16 kHz prêt pour Whisper (moyenne des canaux + interpolation linéaire).
+    pub fn to_whisper_pcm(&self) -> Vec<f32> {
+        let channels = self.channels.max(1) as usize;
+        let mono: Vec<f32> = self
+            .samples
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect();
+        resample_linear(&mono, self.sample_rate, WHISPER_SAMPLE_RATE)
+    }
+}
+
+/// Rééchantillonnage par interpolation linéaire (suffisant pour la voix).
+pub fn resample_linear(input: &[f32], from: u32, to: u32) -> Vec<f32> {
+    if from == to || input.is_empty() || from == 0 {
+        return input.to_vec();
+    }
+    let ratio = from as f64 / to as f64;
+    let out_len = (input.len() as f64 / ratio).round() as usize;
+    (0..out_len)
+        .map(|i| {
+            let pos = i as f64 * ratio;
+            let idx = pos.floor() as usize;
+            let frac = (pos - idx as f64) as f32;
+            let a = input[idx.min(input.len() - 1)];
+            let b = input[(idx + 1).min(input.len() - 1)];
+            a + (b - a) * frac
+        })
+        .collect()
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> Option<u16> {
+    bytes
+        .get(at..at + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    bytes
+        .get(at..at + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// Décode un fichier WAV (PCM entier 8/16/24/32 bits ou flottant 32 bits).
+pub fn decode_wav(bytes: &[u8]) -> RaiseResult<AudioClip> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        raise_error!(
+            "ERR_AUDIO_WAV_INVALID",
+            error = "En-tête RIFF/WAVE absent : seul le format WAV est accepté.",
+            context = json_value!({ "size": bytes.len() })
+        );
+    }
+
+    let mut format: Option<(u16, u16, u32, u16)> = None;
+    let mut data: Option<&[u8]> = None;
+    let mut cursor = 12;
+    while cursor + 8 <= bytes.len() {
+        let id = &bytes[cursor..cursor + 4];
+        let size = read_u32(bytes, cursor + 4).unwrap_or(0) as usize;
+        let body_start = cursor + 8;
+        let body_end = (body_start + size).min(bytes.len());
+        let body = &bytes[body_start..body_end];
+        match id {
+            b"fmt " => {
+                let mut tag = read_u16(body, 0).unwrap_or(0);
+                // WAVE_FORMAT_EXTENSIBLE : le vrai format est en tête du sous-format (GUID)
+                if tag == 0xFFFE {
+                    tag = read_u16(body, 24).unwrap_or(0);
+                }
+                format = Some((
+                    tag,
+                    read_u16(body, 2).unwrap_or(0),
+                    read_u32(body, 4).unwrap_or(0),
+                    read_u16(body, 14).unwrap_or(0),
+                ));
+            }
+            b"data" => data = Some(body),
+            _ => {}
+        }
+        // Les blocs sont alignés sur 2 octets
+        cursor = body_start + size + (size % 2);
+    }
+
+    let (Some((tag, channels, sample_rate, bits)), Some(data)) = (format, data) else {
+        raise_error!(
+            "ERR_AUDIO_WAV_INVALID",
+            error = "Blocs 'fmt ' ou 'data' manquants dans le fichier WAV."
+        );
+    };
+    if channels == 0 || sample_rate == 0 {
+        raise_error!(
+            "ERR_AUDIO_WAV_INVALID",
+            error = "Nombre de canaux ou fréquence d'échantillonnage nuls.",
+            context = json_value!({ "channels": channels, "sample_rate": sample_rate })
+        );
+    }
+
+    let samples: Vec<f32> = match (tag, bits) {
+        (1, 8) => data.iter().map(|&b| (b as f32 - 128.0) / 128.0).collect(),
+        (1, 16) => data
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32_768.0)
+            .collect(),
+        (1, 24) => data
+            .as_chunks::<3>()
+            .0
+            .iter()
+            .map(|b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8_388_608.0)
+            .collect(),
+        (1, 32) => data
+            .as_chunks::<4>()
+            .0
+            .iter()
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0)
+            .collect(),
+        (3, 32) => data
+            .as_chunks::<4>()
+            .0
+            .iter()
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        _ => raise_error!(
+            "ERR_AUDIO_WAV_UNSUPPORTED",
+            error = "Encodage WAV non supporté (PCM 8/16/24/32 bits ou float 32 bits attendus).",
+            context = json_value!({ "format_tag": tag, "bits_per_sample": bits })
+        ),
+    };
+
+    Ok(AudioClip {
+        samples,
+        sample_rate,
+        channels,
+    })
+}
+
+/// Décode la charge transmise par l'interface et vérifie sa durée.
+pub fn decode_payload(payload: &AudioPayload) -> RaiseResult<AudioClip> {
+    let clip = match payload {
+        AudioPayload::Wav { data_base64 } => decode_wav(&decode_base64(data_base64)?)?,
+        AudioPayload::Pcm {
+            samples,
+            sample_rate,
+            channels,
+        } => AudioClip {
+            samples: samples.clone(),
+            sample_rate: *sample_rate,
+            channels: (*channels).max(1),
+        },
+    };
+    if clip.duration_seconds() > MAX_AUDIO_SECONDS {
+        raise_error!(
+            "ERR_AUDIO_TOO_LONG",
+            error = format!(
+                "Enregistrement de {:.0} s : la limite est de {:.0} s.",
+                clip.duration_seconds(),
+                MAX_AUDIO_SECONDS
+            ),
+            context = json_value!({ "duration_seconds": clip.duration_seconds() })
+        );
+    }
+    Ok(clip)
+}
+
+/// Transcrit un enregistrement complet, fenêtre par fenêtre.
+pub fn transcribe_clip(engine: &mut WhisperEngine, clip: &AudioClip) -> RaiseResult<Transcription> {
+    let pcm = clip.to_whisper_pcm();
+    let window = WHISPER_SAMPLE_RATE as usize * WHISPER_WINDOW_SECONDS;
+    let mut parts = Vec::new();
+    for chunk in pcm.chunks(window) {
+        let text = engine.transcribe(chunk)?;
+        let text = text.trim();
+        if !text.is_empty() {
+            parts.push(text.to_string());
+        }
+    }
+    Ok(Transcription {
+        text: parts.join(" "),
+        duration_seconds: clip.duration_seconds(),
+        segments: pcm.len().div_ceil(window),
+    })
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav_16bit(samples: &[i16], sample_rate: u32, channels: u16) -> Vec<u8> {
+        let data: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&channels.to_le_bytes());
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * channels as u32 * 2).to_le_bytes());
+        wav.extend_from_slice(&(channels * 2).to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        wav.extend_from_slice(&data);
+        wav
+    }
+
+    #[test]
+    fn test_decode_stereo_wav_to_whisper_pcm() -> RaiseResult<()> {
+        // 4 trames stéréo à 32 kHz : canal gauche plein, canal droit nul
+        let wav = wav_16bit(&[16_384, 0, 16_384, 0, -16_384, 0, -16_384, 0], 32_000, 2);
+        let clip = decode_wav(&wav)?;
+        assert_eq!(
+            (clip.channels, clip.sample_rate, clip.samples.len()),
+            (2, 32_000, 8)
+        );
+        assert_eq!(clip.samples[0], 0.5);
+
+        let pcm = clip.to_whisper_pcm();
+        assert_eq!(pcm.len(), 2);
+        assert_eq!(pcm[0], 0.25);
+        assert_eq!(pcm[1], -0.25);
+        Ok(())
+    }
+
+    #[test]
+    fn test_payload_validation() -> RaiseResult<()> {
+        assert!(decode_wav(b"OggS....").is_err());
+
+        let encoded = AudioPayload::Wav {
+            data_base64: encode_base64(&wav_16bit(&[0; 160], 16_000, 1)),
+        };
+        assert_eq!(decode_payload(&encoded)?.duration_seconds(), 0.01);
+
+        let too_long = AudioPayload::Pcm {
+            samples: vec![0.0; (MAX_AUDIO_SECONDS as usize + 1) * 1_000],
+            sample_rate: 1_000,
+            channels: 1,
+        };
+        assert!(decode_payload(&too_long).is_err());
+
+        assert_eq!(
+            resample_linear(&[0.0, 1.0], 8_000, 16_000),
+            vec![0.0, 0.5, 1.0, 1.0]
+        );
+        Ok(())
+    }
+}
@@ -1 +1,2 @@
+pub mod ingest;
 pub mod stt;
@@ -4,6 +4,7 @@ use crate::blockchain::storage::commit::{MentisCommit, MutationOp};
 use crate::json_db::storage::StorageEngine;
 use crate::json_db::transactions::manager::TransactionManager;
 use crate::json_db::transactions::TransactionRequest;
+use crate::model_engine::validators::incremental::{revalidate_after_commit, ElementChange};
 use crate::utils::prelude::*;
 
 /// Adaptateur responsable de l'application des commits blockchain dans la JSON-DB.
@@ -28,9 +29,17 @@ impl<'a> DbAdapter<'a> {
     pub async fn apply_commit(&self, commit: &MentisCommit) -> RaiseResult<()> {
         let tm = TransactionManager::new(self.storage, &self.space, &self.db);
         let mut requests = Vec::new();
+        let mut changes = Vec::new();
 
         for mutation in &commit.mutations {
             let collection = self.resolve_collection(&mutation.element_id, &mutation.payload)?;
+            changes.push(match mutation.operation {
+                MutationOp::Create => ElementChange::created(&collection, &mutation.element_id),
+                MutationOp::Update => {
+                    ElementChange::updated(&collection, &mutation.element_id, &mutation.payload)
+                }
+                MutationOp::Delete => ElementChange::deleted(&collection, &mutation.element_id),
+            });
 
             match mutation.operation {
                 MutationOp::Create | MutationOp::Update => {
@@ -72,7 +81,11 @@ impl<'a> DbAdapter<'a> {
 
         // Exécution atomique via le moteur JSON_DB
         match tm.execute_smart(requests).await {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                // Revalidation incrémentale des seuls éléments touchés par le commit
+                revalidate_after_commit(self.storage, &self.space, &self.db, changes).await;
+                Ok(())
+            }
             Err(e) => raise_error!(
                 "ERR_BLOCKCHAIN_COMMIT_APPLY_FAILED",
                 error = format!("Échec transactionnel du commit : {}", e),
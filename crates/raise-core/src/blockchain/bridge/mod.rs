@@ -1,5 +1,6 @@
 // FICHIER : src-tauri/src/blockchain/bridge/mod.rs
 
+use crate::blockchain::explorer::ChainExplorer;
 use crate::blockchain::storage::commit::MentisCommit;
 use crate::json_db::storage::StorageEngine;
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
@@ -16,6 +17,7 @@ pub use model_sync::ModelSync;
 pub struct ArcadiaBridge<'a> {
     db_adapter: DbAdapter<'a>,
     model_sync: ModelSync<'a>,
+    explorer: ChainExplorer<'a>,
 }
 
 impl<'a> ArcadiaBridge<'a> {
@@ -30,10 +32,12 @@ impl<'a> ArcadiaBridge<'a> {
                 &config.mount_points.system.db,
             ),
             model_sync: ModelSync::new(app_state),
+            explorer: ChainExplorer::system(storage),
         }
     }
 
     /// Traite un nouveau commit blockchain : Persistance physique (DB) puis synchronisation logique (Modèle).
+    #[instrument(name = "blockchain.process_commit", skip_all, fields(commit_id = %commit.id))]
     pub async fn process_new_commit(&self, commit: &MentisCommit) -> RaiseResult<()> {
         // 1. Persistance physique garantie (ACID via TransactionManager)
         self.db_adapter.apply_commit(commit).await?;
@@ -51,6 +55,14 @@ impl<'a> ArcadiaBridge<'a> {
             );
         }
 
+        // 3. Index de l'explorateur (consultation uniquement, non bloquant)
+        if let Err(e) = self.explorer.index_commit(commit).await {
+            user_warn!(
+                "WRN_BRIDGE_EXPLORER_INDEX_FAILED",
+                json_value!({ "commit_id": commit.id, "technical_error": e.to_string() })
+            );
+        }
+
         Ok(())
     }
 }
@@ -3,16 +3,22 @@
 
 pub mod leader;
 pub mod pending;
+pub mod reputation;
 pub mod vote;
 
+use crate::blockchain::consensus::reputation::{Offense, ReputationLedger};
 use crate::blockchain::consensus::vote::{Vote, VoteCollector};
 use crate::blockchain::storage::commit::MentisCommit;
+use crate::kernel::events::{publish_event, RaiseEvent};
 use crate::utils::prelude::*;
 
 /// Moteur de consensus gérant les cycles de validation des blocs.
 pub struct ConsensusEngine {
     pub pending_validations: UnorderedMap<String, VoteCollector>,
     pub default_quorum: usize,
+    /// Jeu d'autorités attendu à chaque scrutin (vide : pas de suivi des votes manqués).
+    pub authorities: UniqueSet<String>,
+    pub reputation: ReputationLedger,
 }
 
 impl ConsensusEngine {
@@ -21,16 +27,23 @@ impl ConsensusEngine {
         Self {
             pending_validations: UnorderedMap::new(),
             default_quorum,
+            authorities: UniqueSet::new(),
+            reputation: ReputationLedger::from_config(),
         }
     }
 
+    /// Déclare les validateurs attendus (clés publiques) pour le suivi des votes manqués.
+    pub fn set_authorities(&mut self, authorities: impl IntoIterator<Item = String>) {
+        self.authorities = authorities.into_iter().collect();
+    }
+
     /// Enregistre un nouveau commit en attente de validation.
     pub fn register_commit(&mut self, commit: &MentisCommit) {
         if !self.pending_validations.contains_key(&commit.id) {
-            self.pending_validations.insert(
-                commit.id.clone(),
-                VoteCollector::new(commit.id.clone(), self.default_quorum),
-            );
+            let mut collector = VoteCollector::new(commit.id.clone(), self.default_quorum);
+            collector.parent_hash = commit.parent_hash.clone();
+            self.pending_validations
+                .insert(commit.id.clone(), collector);
             user_trace!(
                 "TRC_CONSENSUS_REGISTER",
                 json_value!({ "commit_id": commit.id, "quorum_required": self.default_quorum })
@@ -40,11 +53,26 @@ impl ConsensusEngine {
 
     /// Traite un vote entrant et vérifie si le quorum est atteint.
     /// Retourne `true` si le bloc vient d'atteindre le quorum de validation.
+    /// Les votes d'un validateur exclu ne comptent pas ; un vote pour un commit concurrent
+    /// d'un commit déjà voté (même parent) est rejeté et sanctionné comme double vote.
     pub fn process_incoming_vote(&mut self, vote: Vote) -> bool {
+        if self.reputation.is_excluded(&vote.voter) {
+            user_trace!(
+                "TRC_CONSENSUS_VOTE_EXCLUDED",
+                json_value!({ "commit_id": vote.commit_id, "voter": vote.voter })
+            );
+            return false;
+        }
+        if self.is_double_vote(&vote) {
+            self.reputation
+                .record_offense(&vote.voter, Offense::DoubleVote);
+            return false;
+        }
         if let Some(collector) = self.pending_validations.get_mut(&vote.commit_id) {
             // On ajoute le vote (add_vote gère la vérification cryptographique et l'Anti-Sybil)
             if collector.add_vote(&vote) {
                 let is_validated = collector.is_validated();
+                self.reputation.record_vote(&vote.voter);
 
                 if is_validated {
                     user_success!(
@@ -58,13 +86,57 @@ impl ConsensusEngine {
         false
     }
 
+    fn is_double_vote(&self, vote: &Vote) -> bool {
+        let Some(target) = self.pending_validations.get(&vote.commit_id) else {
+            return false;
+        };
+        self.pending_validations.values().any(|other| {
+            other.target_commit_id != vote.commit_id
+                && other.parent_hash == target.parent_hash
+                && other.voters.contains(&vote.voter)
+        })
+    }
+
+    /// Signale un commit annoncé invalide (signature ou racine de Merkle) à la charge de son auteur.
+    pub fn report_invalid_proposal(&mut self, commit: &MentisCommit) {
+        user_warn!(
+            "WRN_CONSENSUS_INVALID_PROPOSAL",
+            json_value!({ "commit_id": commit.id, "author": commit.author })
+        );
+        self.reputation
+            .record_offense(&commit.author, Offense::InvalidProposal);
+    }
+
+    /// Impute un vote manqué à chaque autorité absente d'un scrutin clos.
+    fn record_missed_votes(&mut self, collector: &VoteCollector) {
+        let missing: Vec<String> = self
+            .authorities
+            .iter()
+            .filter(|a| !collector.voters.contains(*a))
+            .cloned()
+            .collect();
+        for validator in missing {
+            self.reputation
+                .record_offense(&validator, Offense::MissedVote);
+        }
+    }
+
     /// Nettoie les validations en attente trop anciennes pour éviter les fuites de mémoire.
     pub fn garbage_collect(&mut self, max_age_minutes: i64) {
         let now = UtcClock::now();
         let initial_count = self.pending_validations.len();
 
-        self.pending_validations
-            .retain(|_, collector| (now - collector.created_at).num_minutes() < max_age_minutes);
+        let expired: Vec<String> = self
+            .pending_validations
+            .iter()
+            .filter(|(_, c)| (now - c.created_at).num_minutes() >= max_age_minutes)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in expired {
+            if let Some(collector) = self.pending_validations.remove(&id) {
+                self.record_missed_votes(&collector);
+            }
+        }
 
         let removed = initial_count - self.pending_validations.len();
         if removed > 0 {
@@ -77,12 +149,17 @@ impl ConsensusEngine {
 
     /// Finalise un cycle de validation en retirant le collecteur de la mémoire.
     /// Typiquement appelé après que le bloc ait été persisté sur le disque.
+    #[instrument(name = "blockchain.finalize_validation", skip_all, fields(commit_id = commit_id))]
     pub fn finalize_validation(&mut self, commit_id: &str) {
-        if self.pending_validations.remove(commit_id).is_some() {
+        if let Some(collector) = self.pending_validations.remove(commit_id) {
+            self.record_missed_votes(&collector);
             user_trace!(
                 "TRC_CONSENSUS_FINALIZED",
                 json_value!({ "commit_id": commit_id })
             );
+            publish_event(RaiseEvent::CommitFinalized {
+                commit_id: commit_id.to_string(),
+            });
         }
     }
 }
@@ -171,4 +248,38 @@ mod tests {
             "Le vieux collecteur aurait dû être purgé par le GC"
         );
     }
+
+    #[test]
+    fn test_consensus_reputation_tracking() {
+        let author = KeyPair::generate();
+        let voter = KeyPair::generate();
+        let mut engine = ConsensusEngine::new(2);
+        engine.set_authorities([voter.public_key_hex(), "absent".to_string()]);
+
+        // Deux propositions concurrentes (même parent)
+        let a = MentisCommit::new(vec![], None, &author);
+        let mut b = MentisCommit::new(vec![], None, &author);
+        b.id = format!("{}-b", a.id);
+        engine.register_commit(&a);
+        engine.register_commit(&b);
+
+        assert!(!engine.process_incoming_vote(Vote::new(a.id.clone(), &voter)));
+        assert!(!engine.process_incoming_vote(Vote::new(b.id.clone(), &voter)));
+        let record = &engine.reputation.records[&voter.public_key_hex()];
+        assert_eq!((record.votes_cast, record.double_votes), (1, 1));
+        // Exclu après le double vote : ses votes ne comptent plus
+        assert!(engine.reputation.is_excluded(&voter.public_key_hex()));
+
+        engine.finalize_validation(&a.id);
+        assert_eq!(engine.reputation.records["absent"].missed_votes, 1);
+
+        let mut forged = MentisCommit::new(vec![], None, &author);
+        forged.merkle_root = "tampered".into();
+        assert!(!forged.verify());
+        engine.report_invalid_proposal(&forged);
+        assert_eq!(
+            engine.reputation.records[&author.public_key_hex()].invalid_proposals,
+            1
+        );
+    }
 }
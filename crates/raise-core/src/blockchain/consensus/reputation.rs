@@ -0,0 +1,300 @@
+// src-tauri/src/blockchain/consensus/reputation.rs
+//! Réputation des validateurs Mentis : comptabilise les infractions (votes manqués,
+//! propositions invalides, doubles votes) et exclut temporairement du quorum les
+//! validateurs dont les pénalités dépassent le seuil configuré.
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::data::config::ReputationConfig;
+use crate::utils::prelude::*;
+
+/// Collection système persistant les fiches de réputation (une par validateur).
+pub const REPUTATION_COLLECTION: &str = "validator_reputation";
+
+/// Infraction imputable à un validateur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum Offense {
+    /// Membre du jeu d'autorités absent d'un scrutin clos.
+    MissedVote,
+    /// Commit annoncé dont la signature ou la racine de Merkle est invalide.
+    InvalidProposal,
+    /// Votes pour deux commits concurrents (même parent).
+    DoubleVote,
+}
+
+/// Fiche de réputation d'un validateur.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ValidatorRecord {
+    #[serde(rename = "_id")]
+    pub validator: String,
+    pub votes_cast: u64,
+    pub missed_votes: u64,
+    pub invalid_proposals: u64,
+    pub double_votes: u64,
+    /// Points accumulés depuis la dernière exclusion.
+    pub penalty_points: u32,
+    pub exclusions: u32,
+    pub excluded_until: Option<UtcTimestamp>,
+    pub last_offense: Option<UtcTimestamp>,
+}
+
+impl ValidatorRecord {
+    fn new(validator: &str) -> Self {
+        Self {
+            validator: validator.to_string(),
+            votes_cast: 0,
+            missed_votes: 0,
+            invalid_proposals: 0,
+            double_votes: 0,
+            penalty_points: 0,
+            exclusions: 0,
+            excluded_until: None,
+            last_offense: None,
+        }
+    }
+
+    pub fn is_excluded_at(&self, now: UtcTimestamp) -> bool {
+        self.excluded_until.is_some_and(|until| now < until)
+    }
+}
+
+/// Rapport de santé du jeu d'autorités.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ReputationReport {
+    pub generated_at: UtcTimestamp,
+    pub policy: ReputationConfig,
+    /// Validateurs triés par pénalités décroissantes.
+    pub validators: Vec<ValidatorRecord>,
+    pub excluded: Vec<String>,
+}
+
+/// Registre de réputation tenu par le moteur de consensus.
+#[derive(Debug, Clone, Default)]
+pub struct ReputationLedger {
+    pub policy: ReputationConfig,
+    pub records: OrderedMap<String, ValidatorRecord>,
+}
+
+impl ReputationLedger {
+    pub fn new(policy: ReputationConfig) -> Self {
+        Self {
+            policy,
+            records: OrderedMap::new(),
+        }
+    }
+
+    /// Registre configuré depuis `AppConfig.reputation`.
+    pub fn from_config() -> Self {
+        Self::new(AppConfig::get().reputation.clone())
+    }
+
+    fn record_mut(&mut self, validator: &str) -> &mut ValidatorRecord {
+        self.records
+            .entry(validator.to_string())
+            .or_insert_with(|| ValidatorRecord::new(validator))
+    }
+
+    pub fn record_vote(&mut self, validator: &str) {
+        self.record_mut(validator).votes_cast += 1;
+    }
+
+    /// Impute une infraction. Retourne `true` si le validateur vient d'être exclu du quorum.
+    pub fn record_offense(&mut self, validator: &str, offense: Offense) -> bool {
+        let policy = self.policy.clone();
+        let now = UtcClock::now();
+        let record = self.record_mut(validator);
+
+        let penalty = match offense {
+            Offense::MissedVote => {
+                record.missed_votes += 1;
+                policy.missed_vote_penalty
+            }
+            Offense::InvalidProposal => {
+                record.invalid_proposals += 1;
+                policy.invalid_proposal_penalty
+            }
+            Offense::DoubleVote => {
+                record.double_votes += 1;
+                policy.double_vote_penalty
+            }
+        };
+        record.penalty_points = record.penalty_points.saturating_add(penalty);
+        record.last_offense = Some(now);
+
+        user_trace!(
+            "TRC_CONSENSUS_OFFENSE",
+            json_value!({ "validator": validator, "offense": offense, "points": record.penalty_points })
+        );
+
+        if record.penalty_points < policy.exclusion_threshold || record.is_excluded_at(now) {
+            return false;
+        }
+        record.penalty_points = 0;
+        record.exclusions += 1;
+        record.excluded_until =
+            Some(now + TimeDuration::from_secs(policy.exclusion_minutes.max(0) as u64 * 60));
+        user_warn!(
+            "WRN_CONSENSUS_VALIDATOR_EXCLUDED",
+            json_value!({
+                "validator": validator,
+                "offense": offense,
+                "exclusion_minutes": policy.exclusion_minutes
+            })
+        );
+        true
+    }
+
+    pub fn is_excluded(&self, validator: &str) -> bool {
+        self.records
+            .get(validator)
+            .is_some_and(|r| r.is_excluded_at(UtcClock::now()))
+    }
+
+    pub fn report(&self) -> ReputationReport {
+        let now = UtcClock::now();
+        let mut validators: Vec<ValidatorRecord> = self.records.values().cloned().collect();
+        validators.sort_by(|a, b| {
+            let weight =
+                |r: &ValidatorRecord| r.exclusions as u64 * 1_000 + r.penalty_points as u64;
+            weight(b).cmp(&weight(a))
+        });
+        let excluded = validators
+            .iter()
+            .filter(|r| r.is_excluded_at(now))
+            .map(|r| r.validator.clone())
+            .collect();
+        ReputationReport {
+            generated_at: now,
+            policy: self.policy.clone(),
+            validators,
+            excluded,
+        }
+    }
+}
+
+/// Persistance du registre dans une base JSON-DB (partition système par défaut).
+pub struct ReputationStore<'a> {
+    manager: CollectionsManager<'a>,
+}
+
+impl<'a> ReputationStore<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            manager: CollectionsManager::new(storage, space, db),
+        }
+    }
+
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, REPUTATION_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(REPUTATION_COLLECTION, &schema_uri)
+            .await
+    }
+
+    pub async fn save(&self, ledger: &ReputationLedger) -> RaiseResult<()> {
+        self.ensure_collection().await?;
+        for record in ledger.records.values() {
+            self.manager
+                .upsert_document(REPUTATION_COLLECTION, json::serialize_to_value(record)?)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Recharge les fiches persistées avec la politique courante.
+    pub async fn load(&self, policy: ReputationConfig) -> RaiseResult<ReputationLedger> {
+        let mut ledger = ReputationLedger::new(policy);
+        if !self
+            .manager
+            .list_collections()
+            .await?
+            .iter()
+            .any(|c| c == REPUTATION_COLLECTION)
+        {
+            return Ok(ledger);
+        }
+        for doc in self.manager.list_all(REPUTATION_COLLECTION).await? {
+            let record: ValidatorRecord = json::deserialize_from_value(doc)?;
+            ledger.records.insert(record.validator.clone(), record);
+        }
+        Ok(ledger)
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_offenses_accumulate_until_exclusion() {
+        let mut ledger = ReputationLedger::new(ReputationConfig::default());
+        ledger.record_vote("v1");
+
+        for _ in 0..9 {
+            assert!(!ledger.record_offense("v1", Offense::MissedVote));
+        }
+        assert!(!ledger.is_excluded("v1"));
+        assert!(ledger.record_offense("v1", Offense::MissedVote));
+        assert!(ledger.is_excluded("v1"));
+
+        // Une double signature suffit à elle seule à exclure
+        assert!(ledger.record_offense("v2", Offense::DoubleVote));
+
+        let report = ledger.report();
+        assert_eq!(report.excluded.len(), 2);
+        let v1 = &ledger.records["v1"];
+        assert_eq!((v1.votes_cast, v1.missed_votes, v1.exclusions), (1, 10, 1));
+        assert_eq!(v1.penalty_points, 0);
+    }
+
+    #[async_test]
+    async fn test_reputation_store_roundtrip() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "rep");
+        DbSandbox::mock_db(&manager).await?;
+        let store = ReputationStore::new(&sandbox.storage, "test", "rep");
+
+        assert!(store
+            .load(ReputationConfig::default())
+            .await?
+            .records
+            .is_empty());
+
+        let mut ledger = ReputationLedger::new(ReputationConfig::default());
+        ledger.record_offense("v1", Offense::InvalidProposal);
+        store.save(&ledger).await?;
+        ledger.record_offense("v1", Offense::InvalidProposal);
+        store.save(&ledger).await?;
+
+        let loaded = store.load(ReputationConfig::default()).await?;
+        assert_eq!(loaded.records["v1"].invalid_proposals, 2);
+        assert!(loaded.is_excluded("v1"));
+        Ok(())
+    }
+}
@@ -36,6 +36,8 @@ pub struct VoteCollector {
     pub target_commit_id: String,
     pub voters: UniqueSet<String>,
     pub quorum_threshold: usize,
+    /// Parent du commit ciblé : deux commits de même parent sont concurrents (détection du double vote).
+    pub parent_hash: Option<String>,
     /// Horodatage de création pour la purge des votes orphelins (Garbage Collection)
     pub created_at: UtcTimestamp,
 }
@@ -47,6 +49,7 @@ impl VoteCollector {
             target_commit_id,
             voters: UniqueSet::new(),
             quorum_threshold: threshold,
+            parent_hash: None,
             created_at: UtcClock::now(), // 🎯 FIX : Initialisation du timestamp
         }
     }
@@ -1,8 +1,104 @@
 // src-tauri/src/blockchain/crypto/hashing.rs
 //! Moteur de hachage Mentis : Canonisation stricte, déterminisme SHA-256 et Arbres de Merkle.
+//! Les empreintes de contenu portent leur algorithme (`sha256:<hex>`, `blake3:<hex>`) et sont
+//! calculées sur la forme canonique RFC 8785 (`json::to_canonical_string`). Une empreinte sans
+//! préfixe est une empreinte historique (`calculate_hash`, SHA-256).
 
 use crate::utils::prelude::*;
 
+/// Algorithmes d'empreinte de contenu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serializable, Deserializable)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    pub fn parse(tag: &str) -> Option<Self> {
+        match tag.to_ascii_lowercase().as_str() {
+            "sha256" | "sha-256" => Some(Self::Sha256),
+            "blake3" => Some(Self::Blake3),
+            _ => None,
+        }
+    }
+
+    /// Condensat hexadécimal brut (sans préfixe).
+    pub fn digest_hex(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Sha256 => {
+                let mut hasher = CryptoSha256::new();
+                hasher.update(bytes);
+                hex::encode(hasher.finalize().as_slice())
+            }
+            Self::Blake3 => {
+                let mut hasher = CryptoBlake3::new();
+                hasher.update(bytes);
+                hasher.finalize().to_hex().to_string()
+            }
+        }
+    }
+}
+
+/// Empreinte étiquetée `<algo>:<hex>` d'un condensat déjà calculé.
+pub fn tag_hash(algorithm: HashAlgorithm, hex_digest: &str) -> String {
+    format!("{}:{}", algorithm.tag(), hex_digest)
+}
+
+/// Algorithme et condensat d'une empreinte ; sans préfixe, SHA-256 historique.
+/// `None` si le préfixe n'est pas un algorithme connu.
+pub fn split_hash(hash: &str) -> Option<(HashAlgorithm, &str)> {
+    match hash.split_once(':') {
+        Some((tag, digest)) => HashAlgorithm::parse(tag).map(|alg| (alg, digest)),
+        None => Some((HashAlgorithm::Sha256, hash)),
+    }
+}
+
+pub fn is_tagged(hash: &str) -> bool {
+    hash.split_once(':')
+        .is_some_and(|(tag, _)| HashAlgorithm::parse(tag).is_some())
+}
+
+/// Empreinte étiquetée d'octets bruts (fichiers, artefacts).
+pub fn hash_bytes(algorithm: HashAlgorithm, bytes: &[u8]) -> String {
+    tag_hash(algorithm, &algorithm.digest_hex(bytes))
+}
+
+/// Empreinte étiquetée de la forme canonique d'une valeur JSON.
+pub fn hash_canonical(algorithm: HashAlgorithm, value: &JsonValue) -> String {
+    hash_bytes(algorithm, json::to_canonical_string(value).as_bytes())
+}
+
+/// Empreinte de `value` dans le même format que `reference` (algorithme étiqueté ou
+/// format historique), pour comparer une valeur à une empreinte existante.
+pub fn hash_like(reference: &str, value: &JsonValue) -> String {
+    match split_hash(reference) {
+        Some((algorithm, _)) if is_tagged(reference) => hash_canonical(algorithm, value),
+        _ => calculate_hash(value),
+    }
+}
+
+/// Revérifie une empreinte de contenu JSON avec l'algorithme qu'elle désigne.
+pub fn verify_canonical(expected: &str, value: &JsonValue) -> bool {
+    hash_like(expected, value) == expected
+}
+
+/// Revérifie une empreinte d'octets bruts (sans préfixe : SHA-256 hex).
+pub fn verify_bytes(expected: &str, bytes: &[u8]) -> bool {
+    match split_hash(expected) {
+        Some((algorithm, digest)) => algorithm.digest_hex(bytes) == digest.to_ascii_lowercase(),
+        None => false,
+    }
+}
+
 /// Calcule un hash SHA-256 déterministe pour n'importe quelle donnée JSON Mentis.
 /// 🤖 IA NOTE: On utilise BTreeMap pour forcer le tri alphabétique récursif des clés.
 pub fn calculate_hash(value: &JsonValue) -> String {
@@ -47,6 +143,21 @@ fn sort_json_recursive(v: &JsonValue) -> JsonValue {
 /// Calcule la véritable racine de Merkle pour un ensemble de hashes Mentis.
 /// Contrairement à une simple concaténation, cette fonction opère par paires (Tree).
 pub fn calculate_merkle_root(hashes: &[String]) -> String {
+    merkle_root_by(hashes, |bytes| HashAlgorithm::Sha256.digest_hex(bytes))
+}
+
+/// Racine de Merkle étiquetée : les paires sont hachées avec `algorithm`.
+pub fn merkle_root_with(algorithm: HashAlgorithm, hashes: &[String]) -> String {
+    if hashes.is_empty() {
+        return String::new();
+    }
+    tag_hash(
+        algorithm,
+        &merkle_root_by(hashes, |bytes| algorithm.digest_hex(bytes)),
+    )
+}
+
+fn merkle_root_by(hashes: &[String], digest: impl Fn(&[u8]) -> String) -> String {
     if hashes.is_empty() {
         return String::new();
     }
@@ -67,16 +178,7 @@ pub fn calculate_merkle_root(hashes: &[String]) -> String {
             };
 
             // On hache la paire combinée
-            let mut hasher = CryptoSha256::new();
-            hasher.update(combined.as_bytes());
-            let result = hasher.finalize();
-
-            let hex_result = result
-                .iter()
-                .map(|byte| format!("{:02x}", byte))
-                .collect::<String>();
-
-            next_level.push(hex_result);
+            next_level.push(digest(combined.as_bytes()));
         }
 
         // On remonte d'un niveau dans l'arbre
@@ -156,4 +258,44 @@ mod tests {
             "Les racines de 3 et 4 feuilles doivent différer"
         );
     }
+
+    #[test]
+    fn test_tagged_hashes() {
+        let value = json_value!({ "b": 1.0, "a": "x" });
+        let sha = hash_canonical(HashAlgorithm::Sha256, &value);
+        let blake = hash_canonical(HashAlgorithm::Blake3, &value);
+        assert!(sha.starts_with("sha256:") && sha.len() == 7 + 64);
+        assert!(blake.starts_with("blake3:") && blake.len() == 7 + 64);
+        // Forme canonique : `1.0` et `1` sont le même contenu
+        assert_eq!(
+            sha,
+            hash_canonical(HashAlgorithm::Sha256, &json_value!({ "a": "x", "b": 1 }))
+        );
+
+        assert!(verify_canonical(&sha, &value));
+        assert!(verify_canonical(&blake, &value));
+        assert!(!verify_canonical(&blake, &json_value!({ "a": "y" })));
+        // Empreintes historiques sans préfixe
+        assert!(verify_canonical(&calculate_hash(&value), &value));
+        assert!(!verify_canonical("md5:00", &value));
+
+        assert_eq!(
+            hash_bytes(HashAlgorithm::Blake3, b""),
+            "blake3:af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        assert!(verify_bytes(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            b""
+        ));
+
+        let leaves = vec![sha.clone(), blake.clone(), sha];
+        let root = merkle_root_with(HashAlgorithm::Blake3, &leaves);
+        assert!(root.starts_with("blake3:"));
+        assert_eq!(
+            calculate_merkle_root(&leaves),
+            split_hash(&merkle_root_with(HashAlgorithm::Sha256, &leaves))
+                .unwrap()
+                .1
+        );
+    }
 }
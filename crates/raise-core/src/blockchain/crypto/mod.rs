@@ -11,7 +11,10 @@ pub mod signing;
 // On expose les primitives essentielles au niveau du module `crypto`
 // pour garantir un couplage faible et simplifier les imports dans `consensus` et `storage`.
 
-pub use hashing::{calculate_hash, calculate_merkle_root};
+pub use hashing::{
+    calculate_hash, calculate_merkle_root, hash_bytes, hash_canonical, merkle_root_with,
+    verify_canonical, HashAlgorithm,
+};
 pub use signing::{verify_signature, KeyPair};
 
 // =========================================================================
@@ -0,0 +1,506 @@
+// src-tauri/src/blockchain/explorer.rs
+//! Explorateur Mentis : index consultable de l'historique de la chaîne (blocs, mutations,
+//! auteurs, ancres de schéma), alimenté par le bridge à chaque commit appliqué.
+
+use crate::blockchain::storage::commit::{MentisCommit, MutationOp};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::{
+    Condition, FilterOperator, Query, QueryEngine, QueryFilter, SortField, SortOrder,
+};
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+/// Collection d'index des blocs (un document par commit).
+pub const CHAIN_BLOCKS_COLLECTION: &str = "chain_blocks";
+/// Collection d'index des mutations (un document par mutation).
+pub const CHAIN_MUTATIONS_COLLECTION: &str = "chain_mutations";
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Entrée d'index d'un bloc.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct BlockEntry {
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Hauteur dans la chaîne (0 pour le genesis).
+    pub height: u64,
+    pub parent_hash: Option<String>,
+    pub author: String,
+    pub timestamp: String,
+    pub merkle_root: String,
+    pub mutation_count: usize,
+}
+
+/// Entrée d'index d'une mutation.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct MutationEntry {
+    /// `<commit_id>:<index>`
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub commit_id: String,
+    pub height: u64,
+    pub index: usize,
+    pub element_id: String,
+    pub operation: MutationOp,
+    pub author: String,
+    pub timestamp: String,
+    /// Ancre de schéma du payload (`$schema`, à défaut `@type`).
+    pub schema_anchor: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "lowercase")]
+pub enum ExploreKind {
+    #[default]
+    Blocks,
+    Mutations,
+    Authors,
+    Schemas,
+}
+
+impl ExploreKind {
+    pub fn parse(value: &str) -> RaiseResult<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "blocks" | "commits" => Ok(Self::Blocks),
+            "mutations" => Ok(Self::Mutations),
+            "authors" => Ok(Self::Authors),
+            "schemas" => Ok(Self::Schemas),
+            _ => raise_error!(
+                "ERR_MENTIS_EXPLORE_KIND_UNKNOWN",
+                error = format!("Vue d'exploration inconnue : '{}'", value),
+                context =
+                    json_value!({ "supported": ["blocks", "mutations", "authors", "schemas"] })
+            ),
+        }
+    }
+}
+
+/// Requête paginée de l'explorateur ; les filtres s'appliquent aux vues qui les portent.
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+#[serde(default)]
+pub struct ExplorerQuery {
+    pub kind: ExploreKind,
+    pub author: Option<String>,
+    pub commit_id: Option<String>,
+    pub element_id: Option<String>,
+    pub schema: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// Page de résultats (les plus récents d'abord).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ExplorerPage {
+    pub kind: ExploreKind,
+    pub items: Vec<JsonValue>,
+    pub total: u64,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+/// Index de l'explorateur, stocké dans une base JSON-DB (partition système par défaut).
+pub struct ChainExplorer<'a> {
+    manager: CollectionsManager<'a>,
+}
+
+impl<'a> ChainExplorer<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            manager: CollectionsManager::new(storage, space, db),
+        }
+    }
+
+    /// Explorateur de la partition système (Mount Points).
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    async fn ensure_collection(&self, name: &str) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, name)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(name, &schema_uri).await
+    }
+
+    /// Dernier bloc indexé (parent naturel d'un nouveau commit d'ancrage).
+    pub async fn head(&self) -> RaiseResult<Option<String>> {
+        let page = self
+            .explore(&ExplorerQuery {
+                kind: ExploreKind::Blocks,
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await?;
+        Ok(page
+            .items
+            .first()
+            .and_then(|b| b.get("_id"))
+            .and_then(|id| id.as_str())
+            .map(str::to_string))
+    }
+
+    /// Indexe un commit (idempotent : un bloc déjà indexé est ignoré).
+    pub async fn index_commit(&self, commit: &MentisCommit) -> RaiseResult<()> {
+        self.ensure_collection(CHAIN_BLOCKS_COLLECTION).await?;
+        self.ensure_collection(CHAIN_MUTATIONS_COLLECTION).await?;
+
+        if self
+            .manager
+            .get_document(CHAIN_BLOCKS_COLLECTION, &commit.id)
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        let height = match &commit.parent_hash {
+            Some(parent) => self
+                .manager
+                .get_document(CHAIN_BLOCKS_COLLECTION, parent)
+                .await?
+                .and_then(|b| b.get("height").and_then(|h| h.as_u64()))
+                .map_or(0, |h| h + 1),
+            None => 0,
+        };
+        let timestamp = commit.timestamp.to_rfc3339();
+
+        for (index, mutation) in commit.mutations.iter().enumerate() {
+            let schema_anchor = mutation
+                .payload
+                .get("$schema")
+                .or_else(|| mutation.payload.get("@type"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string);
+            let entry = MutationEntry {
+                id: format!("{}:{}", commit.id, index),
+                commit_id: commit.id.clone(),
+                height,
+                index,
+                element_id: mutation.element_id.clone(),
+                operation: mutation.operation.clone(),
+                author: commit.author.clone(),
+                timestamp: timestamp.clone(),
+                schema_anchor,
+            };
+            self.manager
+                .insert_raw(
+                    CHAIN_MUTATIONS_COLLECTION,
+                    &json::serialize_to_value(&entry)?,
+                )
+                .await?;
+        }
+
+        let block = BlockEntry {
+            id: commit.id.clone(),
+            height,
+            parent_hash: commit.parent_hash.clone(),
+            author: commit.author.clone(),
+            timestamp,
+            merkle_root: commit.merkle_root.clone(),
+            mutation_count: commit.mutations.len(),
+        };
+        self.manager
+            .insert_raw(CHAIN_BLOCKS_COLLECTION, &json::serialize_to_value(&block)?)
+            .await
+    }
+
+    pub async fn explore(&self, query: &ExplorerQuery) -> RaiseResult<ExplorerPage> {
+        let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let offset = query.offset.unwrap_or(0);
+
+        let (items, total) = match query.kind {
+            ExploreKind::Blocks => {
+                let mut conditions = vec![];
+                if let Some(author) = &query.author {
+                    conditions.push(Condition::eq("author", json_value!(author)));
+                }
+                if let Some(id) = &query.commit_id {
+                    conditions.push(Condition::eq("_id", json_value!(id)));
+                }
+                self.page(CHAIN_BLOCKS_COLLECTION, conditions, "height", limit, offset)
+                    .await?
+            }
+            ExploreKind::Mutations => {
+                let conditions = self.mutation_conditions(query);
+                self.page(
+                    CHAIN_MUTATIONS_COLLECTION,
+                    conditions,
+                    "height",
+                    limit,
+                    offset,
+                )
+                .await?
+            }
+            ExploreKind::Authors => paginate(self.authors(query).await?, limit, offset),
+            ExploreKind::Schemas => paginate(self.schemas(query).await?, limit, offset),
+        };
+
+        Ok(ExplorerPage {
+            kind: query.kind,
+            items,
+            total,
+            limit,
+            offset,
+        })
+    }
+
+    fn mutation_conditions(&self, query: &ExplorerQuery) -> Vec<Condition> {
+        let mut conditions = vec![];
+        if let Some(author) = &query.author {
+            conditions.push(Condition::eq("author", json_value!(author)));
+        }
+        if let Some(id) = &query.commit_id {
+            conditions.push(Condition::eq("commit_id", json_value!(id)));
+        }
+        if let Some(id) = &query.element_id {
+            conditions.push(Condition::eq("element_id", json_value!(id)));
+        }
+        if let Some(schema) = &query.schema {
+            conditions.push(Condition::eq("schema_anchor", json_value!(schema)));
+        }
+        conditions
+    }
+
+    async fn page(
+        &self,
+        collection: &str,
+        conditions: Vec<Condition>,
+        order_by: &str,
+        limit: usize,
+        offset: usize,
+    ) -> RaiseResult<(Vec<JsonValue>, u64)> {
+        let Some(docs) = self
+            .query_all(collection, conditions, Some(order_by))
+            .await?
+        else {
+            return Ok((vec![], 0));
+        };
+        Ok(paginate(docs, limit, offset))
+    }
+
+    /// Documents d'une collection d'index (`None` si rien n'a encore été indexé).
+    async fn query_all(
+        &self,
+        collection: &str,
+        conditions: Vec<Condition>,
+        order_by: Option<&str>,
+    ) -> RaiseResult<Option<Vec<JsonValue>>> {
+        if self
+            .manager
+            .collection_schema_uri(collection)
+            .await
+            .is_none()
+        {
+            return Ok(None);
+        }
+        let mut query = Query::new(collection);
+        if !conditions.is_empty() {
+            query.filter = Some(QueryFilter {
+                operator: FilterOperator::And,
+                conditions,
+            });
+        }
+        query.sort = order_by.map(|field| vec![SortField::new(field, SortOrder::Desc)]);
+        let result = QueryEngine::new(&self.manager).execute_query(query).await?;
+        Ok(Some(result.documents))
+    }
+
+    async fn authors(&self, query: &ExplorerQuery) -> RaiseResult<Vec<JsonValue>> {
+        let mut conditions = vec![];
+        if let Some(author) = &query.author {
+            conditions.push(Condition::eq("author", json_value!(author)));
+        }
+        let blocks = self
+            .query_all(CHAIN_BLOCKS_COLLECTION, conditions, Some("height"))
+            .await?
+            .unwrap_or_default();
+
+        // Blocs triés du plus récent au plus ancien : le premier vu est le dernier actif
+        let mut stats: OrderedMap<String, JsonValue> = OrderedMap::new();
+        for block in blocks {
+            let Ok(block) = json::deserialize_from_value::<BlockEntry>(block) else {
+                continue;
+            };
+            let entry = stats.entry(block.author.clone()).or_insert_with(|| {
+                json_value!({
+                    "author": block.author,
+                    "commits": 0,
+                    "mutations": 0,
+                    "last_commit": block.id,
+                    "last_seen": block.timestamp
+                })
+            });
+            entry["commits"] = json_value!(entry["commits"].as_u64().unwrap_or(0) + 1);
+            entry["mutations"] =
+                json_value!(entry["mutations"].as_u64().unwrap_or(0) + block.mutation_count as u64);
+            entry["first_seen"] = json_value!(block.timestamp);
+        }
+        let mut authors: Vec<JsonValue> = stats.into_values().collect();
+        authors.sort_by_key(|a| std::cmp::Reverse(a["commits"].as_u64().unwrap_or(0)));
+        Ok(authors)
+    }
+
+    async fn schemas(&self, query: &ExplorerQuery) -> RaiseResult<Vec<JsonValue>> {
+        let conditions = self.mutation_conditions(query);
+        let mutations = self
+            .query_all(CHAIN_MUTATIONS_COLLECTION, conditions, Some("height"))
+            .await?
+            .unwrap_or_default();
+
+        let mut anchors: OrderedMap<String, (u64, UniqueSet<String>, String)> = OrderedMap::new();
+        for mutation in mutations {
+            let Ok(m) = json::deserialize_from_value::<MutationEntry>(mutation) else {
+                continue;
+            };
+            let Some(anchor) = m.schema_anchor else {
+                continue;
+            };
+            let entry = anchors
+                .entry(anchor)
+                .or_insert_with(|| (0, UniqueSet::new(), m.commit_id.clone()));
+            entry.0 += 1;
+            entry.1.insert(m.element_id);
+        }
+        Ok(anchors
+            .into_iter()
+            .map(|(schema, (count, elements, last_commit))| {
+                json_value!({
+                    "schema": schema,
+                    "mutations": count,
+                    "elements": elements.len(),
+                    "last_commit": last_commit
+                })
+            })
+            .collect())
+    }
+}
+
+fn paginate(items: Vec<JsonValue>, limit: usize, offset: usize) -> (Vec<JsonValue>, u64) {
+    let total = items.len() as u64;
+    (items.into_iter().skip(offset).take(limit).collect(), total)
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::crypto::signing::KeyPair;
+    use crate::blockchain::storage::commit::Mutation;
+    use crate::utils::testing::DbSandbox;
+
+    fn mutation(id: &str, kind: &str) -> Mutation {
+        Mutation {
+            element_id: id.into(),
+            operation: MutationOp::Create,
+            payload: json_value!({ "@type": kind }),
+        }
+    }
+
+    #[async_test]
+    async fn test_explorer_indexes_and_paginates_chain_history() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let explorer = ChainExplorer::new(&sandbox.storage, "test", "explorer");
+        DbSandbox::mock_db(&explorer.manager).await?;
+
+        let alice = KeyPair::generate();
+        let bob = KeyPair::generate();
+        let c0 = MentisCommit::new(
+            vec![
+                mutation("urn:sa:1", "SystemComponent"),
+                mutation("urn:sa:2", "SystemComponent"),
+            ],
+            None,
+            &alice,
+        );
+        let c1 = MentisCommit::new(
+            vec![mutation("urn:sf:1", "SystemFunction")],
+            Some(c0.id.clone()),
+            &bob,
+        );
+        let c2 = MentisCommit::new(
+            vec![mutation("urn:sa:1", "SystemComponent")],
+            Some(c1.id.clone()),
+            &alice,
+        );
+        for commit in [&c0, &c1, &c2] {
+            explorer.index_commit(commit).await?;
+        }
+        explorer.index_commit(&c2).await?; // idempotent
+
+        let blocks = explorer
+            .explore(&ExplorerQuery {
+                limit: Some(2),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(blocks.total, 3);
+        assert_eq!(blocks.items.len(), 2);
+        assert_eq!(blocks.items[0]["_id"], json_value!(c2.id));
+        assert_eq!(blocks.items[0]["height"], 2);
+
+        let history = explorer
+            .explore(&ExplorerQuery {
+                kind: ExploreKind::Mutations,
+                element_id: Some("urn:sa:1".into()),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(history.total, 2);
+
+        let authors = explorer
+            .explore(&ExplorerQuery {
+                kind: ExploreKind::Authors,
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(
+            authors.items[0]["author"],
+            json_value!(alice.public_key_hex())
+        );
+        assert_eq!(authors.items[0]["commits"], 2);
+        assert_eq!(authors.items[0]["mutations"], 3);
+
+        let schemas = explorer
+            .explore(&ExplorerQuery {
+                kind: ExploreKind::Schemas,
+                ..Default::default()
+            })
+            .await?;
+        let component = schemas
+            .items
+            .iter()
+            .find(|s| s["schema"] == "SystemComponent")
+            .unwrap();
+        assert_eq!(component["mutations"], 3);
+        assert_eq!(component["elements"], 2);
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_explorer_empty_index() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let explorer = ChainExplorer::new(&sandbox.storage, "test", "explorer_empty");
+        let page = explorer.explore(&ExplorerQuery::default()).await?;
+        assert_eq!(page.total, 0);
+        assert!(ExploreKind::parse("ledgers").is_err());
+        Ok(())
+    }
+}
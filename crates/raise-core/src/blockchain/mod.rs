@@ -8,6 +8,8 @@ pub mod bridge; // Adaptateur JsonDB
 pub mod client; // Client P2P Principal
 pub mod consensus; // Quorum & Votes
 pub mod crypto; // Hashing & Signatures
+pub mod explorer; // Index & navigation de l'historique
+pub mod notary; // Horodatage & ancrage de fichiers externes
 pub mod p2p; // Transport (p2p)
 pub mod storage; // Ledger & Commits
 pub mod sync; // Synchronisation Delta
@@ -0,0 +1,307 @@
+// src-tauri/src/blockchain/notary.rs
+//! Notarisation Mentis de fichiers externes : le hash SHA-256 d'un document livré est
+//! ancré dans la chaîne comme `SemanticEvidence`, accompagné d'un jeton d'horodatage
+//! inspiré de RFC 3161 (TSTInfo signé), puis peut être revérifié à tout moment.
+
+use crate::blockchain::crypto::hashing::{tag_hash, HashAlgorithm};
+use crate::blockchain::crypto::signing::{verify_signature, KeyPair};
+use crate::blockchain::explorer::{ChainExplorer, ExploreKind, ExplorerQuery};
+use crate::blockchain::storage::commit::{MentisCommit, Mutation, MutationOp};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+/// Collection d'enregistrements de notarisation (un document par empreinte).
+pub const NOTARY_COLLECTION: &str = "notarized_files";
+/// Type sémantique porté par la mutation ancrée.
+pub const EVIDENCE_TYPE: &str = "SemanticEvidence";
+/// Politique d'horodatage annoncée dans les jetons émis par ce nœud.
+pub const NOTARY_POLICY: &str = "raise:mentis:notary:v1";
+
+/// Jeton d'horodatage (équivalent simplifié du TSTInfo RFC 3161).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct TimestampToken {
+    pub version: u8,
+    pub policy: String,
+    pub hash_algorithm: String,
+    /// Empreinte horodatée (`messageImprint`).
+    pub hashed_message: String,
+    pub serial_number: String,
+    pub gen_time: UtcTimestamp,
+    /// Clé publique de l'autorité d'horodatage (le nœud notaire).
+    pub tsa: String,
+    pub signature: Vec<u8>,
+}
+
+impl TimestampToken {
+    /// Données signées : tous les champs sauf la signature.
+    fn signed_content(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}",
+            self.version,
+            self.policy,
+            self.hash_algorithm,
+            self.hashed_message,
+            self.serial_number,
+            self.gen_time.to_rfc3339(),
+            self.tsa
+        )
+    }
+
+    pub fn issue(hashed_message: &str, tsa: &KeyPair) -> Self {
+        let mut token = Self {
+            version: 1,
+            policy: NOTARY_POLICY.to_string(),
+            hash_algorithm: HashAlgorithm::Sha256.tag().to_string(),
+            hashed_message: hashed_message.to_string(),
+            serial_number: UniqueId::new_v4().to_string(),
+            gen_time: UtcClock::now(),
+            tsa: tsa.public_key_hex(),
+            signature: vec![],
+        };
+        token.signature = tsa.sign(&token.signed_content());
+        token
+    }
+
+    pub fn verify(&self) -> bool {
+        verify_signature(&self.tsa, &self.signed_content(), &self.signature)
+    }
+}
+
+/// Enregistrement d'un fichier notarisé.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct NotarizedFile {
+    /// `urn:raise:notary:<sha256>`
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub file_name: String,
+    pub size: u64,
+    pub sha256: String,
+    pub token: TimestampToken,
+    /// Commit d'ancrage, conservé pour revérification hors réseau.
+    pub commit: MentisCommit,
+}
+
+/// Résultat de la vérification d'un fichier contre la chaîne.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct NotaryVerification {
+    pub file_name: String,
+    pub sha256: String,
+    /// Vrai si l'empreinte est ancrée et que jeton, commit et index concordent.
+    pub verified: bool,
+    pub anchored_at: Option<UtcTimestamp>,
+    pub commit_id: Option<String>,
+    pub token_valid: bool,
+    pub commit_valid: bool,
+    pub commit_indexed: bool,
+}
+
+pub fn evidence_id(sha256: &str) -> String {
+    format!("urn:raise:notary:{}", sha256)
+}
+
+/// Empreinte SHA-256 (hex) et taille d'un fichier.
+pub async fn hash_file(path: &Path) -> RaiseResult<(String, u64)> {
+    let bytes = fs::read_async(path).await?;
+    let digest = HashAlgorithm::Sha256.digest_hex(&bytes);
+    Ok((digest, bytes.len() as u64))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Service de notarisation adossé à une base JSON-DB (partition système par défaut).
+pub struct Notary<'a> {
+    storage: &'a StorageEngine,
+    manager: CollectionsManager<'a>,
+}
+
+impl<'a> Notary<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            storage,
+            manager: CollectionsManager::new(storage, space, db),
+        }
+    }
+
+    pub fn system(storage: &'a StorageEngine) -> Self {
+        let config = AppConfig::get();
+        Self::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    fn explorer(&self) -> ChainExplorer<'a> {
+        ChainExplorer::new(self.storage, &self.manager.space, &self.manager.db)
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, NOTARY_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(NOTARY_COLLECTION, &schema_uri).await
+    }
+
+    /// Horodate et ancre un fichier. Une empreinte déjà notarisée renvoie l'enregistrement
+    /// existant (la première date d'ancrage fait foi).
+    pub async fn notarize(&self, path: &Path, keys: &KeyPair) -> RaiseResult<NotarizedFile> {
+        let (sha256, size) = hash_file(path).await?;
+        let id = evidence_id(&sha256);
+        self.ensure_collection().await?;
+
+        if let Some(existing) = self.manager.get_document(NOTARY_COLLECTION, &id).await? {
+            user_info!(
+                "INF_NOTARY_ALREADY_ANCHORED",
+                json_value!({ "sha256": sha256, "id": id })
+            );
+            return json::deserialize_from_value(existing);
+        }
+
+        let token = TimestampToken::issue(&sha256, keys);
+        let mutation = Mutation {
+            element_id: id.clone(),
+            operation: MutationOp::Create,
+            payload: json_value!({
+                "@type": EVIDENCE_TYPE,
+                "file_name": file_name(path),
+                "size": size,
+                "sha256": sha256,
+                "content_hash": tag_hash(HashAlgorithm::Sha256, &sha256),
+                "timestamp_token": token
+            }),
+        };
+        let commit = MentisCommit::new(vec![mutation], self.explorer().head().await?, keys);
+        self.explorer().index_commit(&commit).await?;
+
+        let record = NotarizedFile {
+            id,
+            file_name: file_name(path),
+            size,
+            sha256,
+            token,
+            commit,
+        };
+        self.manager
+            .insert_raw(NOTARY_COLLECTION, &json::serialize_to_value(&record)?)
+            .await?;
+
+        user_success!(
+            "INF_NOTARY_ANCHORED",
+            json_value!({ "sha256": record.sha256, "commit_id": record.commit.id })
+        );
+        Ok(record)
+    }
+
+    /// Revérifie un fichier : empreinte recalculée, jeton d'horodatage, intégrité du commit
+    /// d'ancrage et présence du bloc dans l'index de la chaîne.
+    pub async fn verify(&self, path: &Path) -> RaiseResult<NotaryVerification> {
+        let (sha256, _) = hash_file(path).await?;
+        let mut verification = NotaryVerification {
+            file_name: file_name(path),
+            sha256: sha256.clone(),
+            verified: false,
+            anchored_at: None,
+            commit_id: None,
+            token_valid: false,
+            commit_valid: false,
+            commit_indexed: false,
+        };
+
+        self.ensure_collection().await?;
+        let Some(doc) = self
+            .manager
+            .get_document(NOTARY_COLLECTION, &evidence_id(&sha256))
+            .await?
+        else {
+            return Ok(verification);
+        };
+        let record: NotarizedFile = json::deserialize_from_value(doc)?;
+
+        verification.anchored_at = Some(record.token.gen_time);
+        verification.commit_id = Some(record.commit.id.clone());
+        verification.token_valid = record.token.verify() && record.token.hashed_message == sha256;
+        verification.commit_valid = record.commit.verify()
+            && record.commit.mutations.iter().any(|m| {
+                m.element_id == record.id
+                    && m.payload.get("sha256").and_then(|h| h.as_str()) == Some(sha256.as_str())
+            });
+        verification.commit_indexed = self
+            .explorer()
+            .explore(&ExplorerQuery {
+                kind: ExploreKind::Blocks,
+                commit_id: Some(record.commit.id.clone()),
+                ..Default::default()
+            })
+            .await?
+            .total
+            > 0;
+        verification.verified =
+            verification.token_valid && verification.commit_valid && verification.commit_indexed;
+        Ok(verification)
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_timestamp_token_signature() {
+        let keys = KeyPair::generate();
+        let token = TimestampToken::issue("abc", &keys);
+        assert!(token.verify());
+
+        let mut forged = token.clone();
+        forged.hashed_message = "abcd".into();
+        assert!(!forged.verify());
+    }
+
+    #[async_test]
+    async fn test_notarize_then_verify_file() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "notary");
+        DbSandbox::mock_db(&manager).await?;
+        let notary = Notary::new(&sandbox.storage, "test", "notary");
+        let keys = KeyPair::generate();
+
+        let dir = tempdir()?;
+        let delivered = dir.path().join("livrable.pdf");
+        fs::write_async(&delivered, b"release 1.0").await?;
+
+        let first = notary.notarize(&delivered, &keys).await?;
+        assert_eq!(first.size, 11);
+        let again = notary.notarize(&delivered, &keys).await?;
+        assert_eq!(again.commit.id, first.commit.id);
+
+        let ok = notary.verify(&delivered).await?;
+        assert!(ok.verified);
+        assert_eq!(ok.commit_id.as_deref(), Some(first.commit.id.as_str()));
+
+        // Un document modifié n'est plus reconnu
+        fs::write_async(&delivered, b"release 1.0 (modifiee)").await?;
+        let ko = notary.verify(&delivered).await?;
+        assert!(!ko.verified);
+        assert!(ko.anchored_at.is_none());
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ pub mod behavior;
 pub mod protocol;
 pub mod service;
 pub mod swarm;
+pub mod transfer;
 pub mod vpn;
 
 // Réexportations stratégiques pour simplifier l'usage par les commandes Tauri
@@ -13,6 +14,7 @@ pub use behavior::MentisBehavior;
 pub use protocol::{MentisNetMessage, MentisResponse};
 pub use service::{init_mentis_network, spawn_p2p_service};
 pub use swarm::create_swarm;
+pub use transfer::{ArtifactKind, ArtifactManifest, ArtifactStore, TransferProgress};
 pub use vpn::P2PVpnResolver; // 🎯 Rendu accessible pour la configuration réseau
 
 // ============================================================================
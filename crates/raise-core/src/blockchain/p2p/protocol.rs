@@ -2,13 +2,122 @@
 //! Protocole réseau Mentis : Définition unifiée des messages du réseau P2P.
 
 use crate::blockchain::consensus::vote::Vote;
+use crate::blockchain::p2p::transfer::ArtifactManifest;
 use crate::blockchain::storage::commit::MentisCommit;
+use crate::blockchain::sync::summary::ChainSummary;
 use crate::utils::prelude::*;
 
+/// Version courante du protocole Mentis annoncée lors de la négociation.
+pub const PROTOCOL_VERSION: u16 = 3;
+/// Plus ancienne version encore servie par ce nœud.
+pub const MIN_PROTOCOL_VERSION: u16 = 1;
+
+/// Capacité : diffusion des commits et des votes (Gossipsub), depuis la v1.
+pub const CAP_GOSSIP: &str = "gossip";
+/// Capacité : récupération ciblée (`RequestCommit`, `RequestLatestHash`), depuis la v1.
+pub const CAP_SYNC: &str = "sync";
+/// Capacité : anti-entropie par résumés Merkle (`RequestSummary`, `RequestCommitsFrom`), depuis la v2.
+pub const CAP_ANTI_ENTROPY: &str = "anti_entropy";
+/// Capacité : transfert d'artefacts par morceaux (`RequestArtifactManifest`, `RequestArtifactChunk`), depuis la v3.
+pub const CAP_ARTIFACTS: &str = "artifacts";
+
+/// Ouverture de session : versions et capacités supportées par un pair.
+#[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
+pub struct Handshake {
+    pub version: u16,
+    pub min_version: u16,
+    pub capabilities: Vec<String>,
+}
+
+impl Handshake {
+    /// Handshake du nœud local.
+    pub fn local() -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            min_version: MIN_PROTOCOL_VERSION,
+            capabilities: vec![
+                CAP_GOSSIP.to_string(),
+                CAP_SYNC.to_string(),
+                CAP_ANTI_ENTROPY.to_string(),
+                CAP_ARTIFACTS.to_string(),
+            ],
+        }
+    }
+}
+
+/// Session négociée : plus haute version commune et capacités partagées.
+#[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
+pub struct NegotiatedSession {
+    pub version: u16,
+    pub capabilities: Vec<String>,
+}
+
+impl NegotiatedSession {
+    /// Session implicite d'un pair antérieur à la négociation (v1, sans `Hello`).
+    pub fn legacy() -> Self {
+        Self {
+            version: 1,
+            capabilities: vec![CAP_GOSSIP.to_string(), CAP_SYNC.to_string()],
+        }
+    }
+
+    pub fn supports(&self, capability: &str) -> bool {
+        self.capabilities.iter().any(|c| c == capability)
+    }
+
+    /// Capacité requise par un message, `None` s'il est toujours autorisé.
+    pub fn required_capability(msg: &MentisNetMessage) -> Option<&'static str> {
+        match msg {
+            MentisNetMessage::Hello(_) => None,
+            MentisNetMessage::AnnounceCommit(_) | MentisNetMessage::SubmitVote(_) => {
+                Some(CAP_GOSSIP)
+            }
+            MentisNetMessage::RequestCommit { .. } | MentisNetMessage::RequestLatestHash => {
+                Some(CAP_SYNC)
+            }
+            MentisNetMessage::RequestSummary | MentisNetMessage::RequestCommitsFrom { .. } => {
+                Some(CAP_ANTI_ENTROPY)
+            }
+            MentisNetMessage::RequestArtifactManifest { .. }
+            | MentisNetMessage::RequestArtifactChunk { .. } => Some(CAP_ARTIFACTS),
+        }
+    }
+
+    pub fn allows(&self, msg: &MentisNetMessage) -> bool {
+        Self::required_capability(msg).is_none_or(|cap| self.supports(cap))
+    }
+}
+
+/// Négocie une session entre le handshake local et celui d'un pair : la version retenue est
+/// la plus haute commune, les capacités sont l'intersection des deux listes.
+pub fn negotiate(local: &Handshake, remote: &Handshake) -> RaiseResult<NegotiatedSession> {
+    let version = local.version.min(remote.version);
+    if version < local.min_version || version < remote.min_version {
+        raise_error!(
+            "ERR_MENTIS_PROTOCOL_INCOMPATIBLE",
+            error = "Aucune version de protocole commune avec le pair.",
+            context = json_value!({ "local": local, "remote": remote })
+        );
+    }
+    let capabilities = local
+        .capabilities
+        .iter()
+        .filter(|c| remote.capabilities.contains(c))
+        .cloned()
+        .collect();
+    Ok(NegotiatedSession {
+        version,
+        capabilities,
+    })
+}
+
 /// 🛰️ Messages du réseau Mentis.
 /// Regroupe la diffusion (Gossipsub) et les requêtes de synchronisation (Request-Response).
 #[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
 pub enum MentisNetMessage {
+    /// Ouverture de session : annonce de version et de capacités (à envoyer en premier).
+    Hello(Handshake),
+
     /// Annonce d'un nouveau bloc de connaissance (Diffusion globale).
     AnnounceCommit(MentisCommit),
 
@@ -20,12 +129,33 @@ pub enum MentisNetMessage {
 
     /// Requête pour obtenir le hash de tête (Head) d'un pair afin de vérifier la synchro.
     RequestLatestHash,
+
+    /// Anti-entropie : demande le résumé Merkle (segments + état) du Ledger d'un pair.
+    RequestSummary,
+
+    /// Anti-entropie : demande les commits de la chaîne à partir d'une hauteur (ordre croissant).
+    RequestCommitsFrom { from_height: usize, limit: usize },
+
+    /// Artefacts : demande le manifeste (taille, empreintes des morceaux) d'un artefact.
+    RequestArtifactManifest { artifact_id: String },
+
+    /// Artefacts : demande un morceau d'un artefact (reprise possible à n'importe quel index).
+    RequestArtifactChunk { artifact_id: String, index: usize },
 }
 
 /// 📦 Réponses directes du protocole Mentis.
 /// Utilisé exclusivement dans les échanges ciblés (Request-Response) pour le transfert de données.
 #[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
 pub enum MentisResponse {
+    /// Session acceptée (réponse à Hello).
+    Welcome(NegotiatedSession),
+
+    /// Aucune version commune : le pair doit être mis à jour.
+    VersionMismatch { min_version: u16, max_version: u16 },
+
+    /// Message non couvert par les capacités de la session négociée.
+    Unsupported { capability: String },
+
     /// Retourne le commit complet demandé par RequestCommit.
     CommitFound(MentisCommit),
 
@@ -35,6 +165,25 @@ pub enum MentisResponse {
     /// Retourne le hash de tête du Ledger local.
     LatestHash(Option<String>),
 
+    /// Résumé Merkle du Ledger local (réponse à RequestSummary).
+    Summary(ChainSummary),
+
+    /// Lot ordonné de commits (réponse à RequestCommitsFrom).
+    Commits(Vec<MentisCommit>),
+
+    /// Manifeste d'un artefact (réponse à RequestArtifactManifest).
+    ArtifactManifest(ArtifactManifest),
+
+    /// Morceau d'un artefact (réponse à RequestArtifactChunk).
+    ArtifactChunk {
+        artifact_id: String,
+        index: usize,
+        data: Vec<u8>,
+    },
+
+    /// Artefact (ou morceau) inconnu sur le nœud local.
+    ArtifactNotFound,
+
     /// Acquittement simple (pour des requêtes ne nécessitant pas de payload de retour).
     Ack,
 }
@@ -119,4 +268,30 @@ mod tests {
             panic!("Mauvais type de message après décodage");
         }
     }
+
+    /// Test 5 : Négociation de version et de capacités.
+    #[test]
+    fn test_protocol_negotiation() {
+        let local = Handshake::local();
+        let old_peer = Handshake {
+            version: 1,
+            min_version: 1,
+            capabilities: vec![CAP_GOSSIP.into(), CAP_SYNC.into()],
+        };
+        let session = negotiate(&local, &old_peer).unwrap();
+        assert_eq!(session, NegotiatedSession::legacy());
+        assert!(session.allows(&MentisNetMessage::RequestLatestHash));
+        assert!(!session.allows(&MentisNetMessage::RequestSummary));
+        assert!(!session.allows(&MentisNetMessage::RequestArtifactManifest {
+            artifact_id: "a".into()
+        }));
+
+        let future_peer = Handshake {
+            version: 9,
+            min_version: 5,
+            capabilities: vec![],
+        };
+        assert!(negotiate(&local, &future_peer).is_err());
+        assert_eq!(negotiate(&local, &local).unwrap().version, PROTOCOL_VERSION);
+    }
 }
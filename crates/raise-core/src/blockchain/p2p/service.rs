@@ -2,6 +2,7 @@
 
 use crate::blockchain::bridge::ArcadiaBridge;
 use crate::blockchain::consensus::pending::PendingCommits;
+use crate::blockchain::consensus::reputation::ReputationStore;
 use crate::blockchain::consensus::{vote::Vote, ConsensusEngine};
 use crate::blockchain::p2p::behavior::MentisBehavior;
 use crate::blockchain::p2p::behavior::MentisBehaviorEvent;
@@ -103,6 +104,11 @@ pub fn spawn_p2p_service(
                                         let _ = swarm.behaviour_mut().gossipsub.publish(topic, vote_data);
                                     }
                                 },
+                                MentisNetMessage::AnnounceCommit(commit) => {
+                                    let mut engine = consensus_state.lock().await;
+                                    engine.report_invalid_proposal(&commit);
+                                    persist_reputation(&storage_state, &engine).await;
+                                },
                                 MentisNetMessage::SubmitVote(vote) => {
                                     let mut engine = consensus_state.lock().await;
 
@@ -113,6 +119,7 @@ pub fn spawn_p2p_service(
                                             let bridge = ArcadiaBridge::new(&storage_state, &app_state);
                                             let _ = bridge.process_new_commit(&final_commit).await;
                                             engine.finalize_validation(&final_commit.id);
+                                            persist_reputation(&storage_state, &engine).await;
                                         }
                                     }
                                 },
@@ -132,6 +139,20 @@ pub fn spawn_p2p_service(
     });
 }
 
+/// Sauvegarde le registre de réputation (rapport `blockchain reputation`) ; un échec
+/// n'interrompt pas la boucle réseau.
+async fn persist_reputation(storage: &StorageEngine, engine: &ConsensusEngine) {
+    if let Err(e) = ReputationStore::system(storage)
+        .save(&engine.reputation)
+        .await
+    {
+        user_warn!(
+            "WRN_CONSENSUS_REPUTATION_PERSIST_FAILED",
+            json_value!({ "technical_error": e.to_string() })
+        );
+    }
+}
+
 // ============================================================================
 // TESTS UNITAIRES
 // ============================================================================
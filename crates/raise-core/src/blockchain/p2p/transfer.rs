@@ -0,0 +1,453 @@
+// src-tauri/src/blockchain/p2p/transfer.rs
+//! Transfert d'artefacts volumineux (bundles de code généré, baselines de modèle) de pair à
+//! pair, par morceaux vérifiés et reprenables. Les octets passent par le canal
+//! Request-Response, jamais par le consensus ni par les commits.
+
+use crate::blockchain::p2p::protocol::{MentisNetMessage, MentisResponse};
+use crate::blockchain::sync::engine::SyncPeer;
+use crate::kernel::events::{publish_event, RaiseEvent};
+use crate::utils::prelude::*;
+use std::io::SeekFrom;
+
+/// Taille d'un morceau (bien en deçà de la limite d'une réponse Request-Response).
+pub const ARTIFACT_CHUNK_SIZE: usize = 256 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    CodeBundle,
+    ModelBaseline,
+    Other,
+}
+
+/// Description d'un artefact : l'identifiant est l'empreinte SHA-256 du contenu complet,
+/// chaque morceau a la sienne pour être vérifié dès réception.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ArtifactManifest {
+    pub id: String,
+    pub name: String,
+    pub kind: ArtifactKind,
+    pub size: u64,
+    pub chunk_size: usize,
+    pub chunk_hashes: Vec<String>,
+}
+
+impl ArtifactManifest {
+    fn of(name: &str, kind: ArtifactKind, bytes: &[u8]) -> Self {
+        Self {
+            id: sha256_hex(bytes),
+            name: name.to_string(),
+            kind,
+            size: bytes.len() as u64,
+            chunk_size: ARTIFACT_CHUNK_SIZE,
+            chunk_hashes: bytes.chunks(ARTIFACT_CHUNK_SIZE).map(sha256_hex).collect(),
+        }
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.chunk_hashes.len()
+    }
+
+    /// Cohérence taille / morceaux d'un manifeste reçu d'un pair.
+    fn check(&self, artifact_id: &str) -> RaiseResult<()> {
+        let expected = (self.size as usize).div_ceil(self.chunk_size.max(1));
+        if self.id != artifact_id
+            || !is_artifact_id(artifact_id)
+            || self.chunk_size == 0
+            || self.chunk_count() != expected
+        {
+            raise_error!(
+                "ERR_P2P_ARTIFACT_MANIFEST_INVALID",
+                error = "Manifeste incohérent avec l'artefact demandé.",
+                context = json_value!({ "requested": artifact_id, "manifest": self })
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Avancement d'un téléchargement (aussi publié sur le bus : `raise:artifact_transfer`).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct TransferProgress {
+    pub artifact_id: String,
+    pub chunks_done: usize,
+    pub chunks_total: usize,
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    /// Morceaux repris d'un téléchargement interrompu (non retransférés).
+    pub resumed_chunks: usize,
+}
+
+/// Dépôt local des artefacts : `<root>/<id>/{manifest.json,data}` pour les artefacts
+/// complets, `<root>/partial/<id>/` pour les téléchargements en cours.
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    root: PathBuf,
+}
+
+impl ArtifactStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn complete_dir(&self, id: &str) -> PathBuf {
+        self.root.join(id)
+    }
+
+    fn partial_dir(&self, id: &str) -> PathBuf {
+        self.root.join("partial").join(id)
+    }
+
+    /// Enregistre un artefact produit localement et le rend disponible aux pairs.
+    pub async fn publish(
+        &self,
+        name: &str,
+        kind: ArtifactKind,
+        bytes: &[u8],
+    ) -> RaiseResult<ArtifactManifest> {
+        let manifest = ArtifactManifest::of(name, kind, bytes);
+        let dir = self.complete_dir(&manifest.id);
+        fs::ensure_dir_async(&dir).await?;
+        fs::write_atomic_async(&dir.join("data"), bytes).await?;
+        fs::write_json_atomic_async(&dir.join("manifest.json"), &manifest).await?;
+        Ok(manifest)
+    }
+
+    pub async fn publish_file(
+        &self,
+        path: &Path,
+        kind: ArtifactKind,
+    ) -> RaiseResult<ArtifactManifest> {
+        let bytes = fs::read_async(path).await?;
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        self.publish(&name, kind, &bytes).await
+    }
+
+    /// Manifeste d'un artefact complet (`None` s'il est absent ou incomplet).
+    pub fn manifest(&self, id: &str) -> RaiseResult<Option<ArtifactManifest>> {
+        // L'identifiant vient d'un pair : jamais de chemin arbitraire
+        if !is_artifact_id(id) {
+            return Ok(None);
+        }
+        let path = self.complete_dir(id).join("manifest.json");
+        if !fs::exists_sync(&path) {
+            return Ok(None);
+        }
+        fs::read_json_sync(&path).map(Some)
+    }
+
+    pub fn data_path(&self, id: &str) -> PathBuf {
+        self.complete_dir(id).join("data")
+    }
+
+    pub async fn list(&self) -> RaiseResult<Vec<ArtifactManifest>> {
+        let mut manifests = Vec::new();
+        if !fs::exists_async(&self.root).await {
+            return Ok(manifests);
+        }
+        let mut entries = fs::read_dir_async(&self.root).await?;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let id = entry.file_name().to_string_lossy().to_string();
+            if let Some(manifest) = self.manifest(&id)? {
+                manifests.push(manifest);
+            }
+        }
+        manifests.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(manifests)
+    }
+
+    /// Lit le morceau `index` directement dans le fichier (sans charger l'artefact entier).
+    fn read_chunk(&self, manifest: &ArtifactManifest, index: usize) -> RaiseResult<Vec<u8>> {
+        let offset = (index * manifest.chunk_size) as u64;
+        let len = (manifest.size - offset).min(manifest.chunk_size as u64) as usize;
+        let mut file = fs::open_sync(self.data_path(&manifest.id))?;
+        let mut data = vec![0u8; len];
+        if let Err(e) = file
+            .seek(SeekFrom::Start(offset))
+            .and_then(|_| file.read_exact(&mut data))
+        {
+            raise_error!(
+                "ERR_P2P_ARTIFACT_READ",
+                error = e,
+                context = json_value!({ "artifact_id": manifest.id, "index": index })
+            );
+        }
+        Ok(data)
+    }
+
+    /// Côté serveur : répond aux requêtes d'artefact d'un pair (`SyncEngine`).
+    pub fn serve(&self, req: &MentisNetMessage) -> RaiseResult<Option<MentisResponse>> {
+        match req {
+            MentisNetMessage::RequestArtifactManifest { artifact_id } => {
+                Ok(Some(match self.manifest(artifact_id)? {
+                    Some(manifest) => MentisResponse::ArtifactManifest(manifest),
+                    None => MentisResponse::ArtifactNotFound,
+                }))
+            }
+            MentisNetMessage::RequestArtifactChunk { artifact_id, index } => {
+                let response = match self.manifest(artifact_id)? {
+                    Some(manifest) if *index < manifest.chunk_count() => {
+                        MentisResponse::ArtifactChunk {
+                            artifact_id: artifact_id.clone(),
+                            index: *index,
+                            data: self.read_chunk(&manifest, *index)?,
+                        }
+                    }
+                    _ => MentisResponse::ArtifactNotFound,
+                };
+                Ok(Some(response))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Télécharge un artefact depuis un pair. Les morceaux déjà reçus lors d'une tentative
+    /// précédente sont revérifiés puis conservés ; chaque morceau reçu est contrôlé contre
+    /// son empreinte, et l'artefact assemblé contre son identifiant.
+    pub async fn fetch(
+        &self,
+        peer: &dyn SyncPeer,
+        artifact_id: &str,
+        mut on_progress: impl FnMut(&TransferProgress) + Send,
+    ) -> RaiseResult<ArtifactManifest> {
+        if let Some(manifest) = self.manifest(artifact_id)? {
+            return Ok(manifest);
+        }
+
+        let manifest = match peer
+            .request(MentisNetMessage::RequestArtifactManifest {
+                artifact_id: artifact_id.to_string(),
+            })
+            .await?
+        {
+            MentisResponse::ArtifactManifest(manifest) => manifest,
+            MentisResponse::ArtifactNotFound => raise_error!(
+                "ERR_P2P_ARTIFACT_NOT_FOUND",
+                error = "Le pair ne possède pas cet artefact.",
+                context = json_value!({ "artifact_id": artifact_id })
+            ),
+            other => raise_error!(
+                "ERR_P2P_UNEXPECTED_RESPONSE",
+                error = "Réponse inattendue à RequestArtifactManifest",
+                context = json_value!({ "response": other })
+            ),
+        };
+        manifest.check(artifact_id)?;
+
+        let partial = self.partial_dir(artifact_id);
+        fs::ensure_dir_async(&partial).await?;
+        let mut progress = TransferProgress {
+            artifact_id: artifact_id.to_string(),
+            chunks_done: 0,
+            chunks_total: manifest.chunk_count(),
+            bytes_done: 0,
+            bytes_total: manifest.size,
+            resumed_chunks: 0,
+        };
+
+        for (index, expected) in manifest.chunk_hashes.iter().enumerate() {
+            let chunk_path = partial.join(format!("{}.chunk", index));
+            let kept = if fs::exists_async(&chunk_path).await {
+                fs::read_async(&chunk_path)
+                    .await
+                    .ok()
+                    .filter(|data| &sha256_hex(data) == expected)
+            } else {
+                None
+            };
+            let len = match kept {
+                Some(data) => {
+                    progress.resumed_chunks += 1;
+                    data.len()
+                }
+                None => {
+                    let data = self.request_chunk(peer, &manifest, index).await?;
+                    fs::write_atomic_async(&chunk_path, &data).await?;
+                    data.len()
+                }
+            };
+            progress.chunks_done += 1;
+            progress.bytes_done += len as u64;
+            on_progress(&progress);
+            publish_event(RaiseEvent::ArtifactTransfer {
+                artifact_id: artifact_id.to_string(),
+                chunks_done: progress.chunks_done,
+                chunks_total: progress.chunks_total,
+                bytes_done: progress.bytes_done,
+            });
+        }
+
+        let mut bytes = Vec::with_capacity(manifest.size as usize);
+        for index in 0..manifest.chunk_count() {
+            bytes.extend(fs::read_async(partial.join(format!("{}.chunk", index))).await?);
+        }
+        if sha256_hex(&bytes) != manifest.id {
+            fs::remove_dir_all_async(&partial).await?;
+            raise_error!(
+                "ERR_P2P_ARTIFACT_HASH_MISMATCH",
+                error = "L'artefact assemblé ne correspond pas à son empreinte.",
+                context = json_value!({ "artifact_id": artifact_id })
+            );
+        }
+
+        let dir = self.complete_dir(artifact_id);
+        fs::ensure_dir_async(&dir).await?;
+        fs::write_atomic_async(&dir.join("data"), &bytes).await?;
+        fs::write_json_atomic_async(&dir.join("manifest.json"), &manifest).await?;
+        fs::remove_dir_all_async(&partial).await?;
+        Ok(manifest)
+    }
+
+    async fn request_chunk(
+        &self,
+        peer: &dyn SyncPeer,
+        manifest: &ArtifactManifest,
+        index: usize,
+    ) -> RaiseResult<Vec<u8>> {
+        let data = match peer
+            .request(MentisNetMessage::RequestArtifactChunk {
+                artifact_id: manifest.id.clone(),
+                index,
+            })
+            .await?
+        {
+            MentisResponse::ArtifactChunk {
+                index: received,
+                data,
+                ..
+            } if received == index => data,
+            other => raise_error!(
+                "ERR_P2P_UNEXPECTED_RESPONSE",
+                error = "Réponse inattendue à RequestArtifactChunk",
+                context = json_value!({ "index": index, "received": format!("{:?}", VariantMarker(&other)) })
+            ),
+        };
+        if sha256_hex(&data) != manifest.chunk_hashes[index] {
+            raise_error!(
+                "ERR_P2P_ARTIFACT_CHUNK_CORRUPT",
+                error = "Morceau reçu altéré (empreinte invalide).",
+                context = json_value!({ "artifact_id": manifest.id, "index": index })
+            );
+        }
+        Ok(data)
+    }
+}
+
+/// Empreinte SHA-256 hexadécimale (seule forme d'identifiant acceptée).
+fn is_artifact_id(id: &str) -> bool {
+    id.len() == 64 && id.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = CryptoSha256::new();
+    hasher.update(bytes);
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::storage::chain::Ledger;
+    use crate::blockchain::sync::engine::SyncEngine;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Pair servi par un `SyncEngine` ; coupe la connexion après `budget` morceaux.
+    struct ArtifactPeer {
+        engine: SyncEngine,
+        budget: AtomicUsize,
+    }
+
+    impl ArtifactPeer {
+        fn new(store: ArtifactStore, budget: usize) -> Self {
+            let ledger = SharedRef::new(SyncMutex::new(Ledger::new()));
+            Self {
+                engine: SyncEngine::new(ledger).with_artifacts(store),
+                budget: AtomicUsize::new(budget),
+            }
+        }
+    }
+
+    #[async_interface]
+    impl SyncPeer for ArtifactPeer {
+        async fn request(&self, msg: MentisNetMessage) -> RaiseResult<MentisResponse> {
+            if let MentisNetMessage::RequestArtifactChunk { .. } = msg {
+                if self.budget.fetch_sub(1, Ordering::SeqCst) == 0 {
+                    raise_error!("ERR_TEST_PEER_DISCONNECTED");
+                }
+            }
+            match self.engine.process_sync_request(&msg)? {
+                Some(response) => Ok(response),
+                None => raise_error!("ERR_TEST_PEER_NO_RESPONSE"),
+            }
+        }
+    }
+
+    fn payload() -> Vec<u8> {
+        (0..ARTIFACT_CHUNK_SIZE * 2 + 1234)
+            .map(|i| (i % 251) as u8)
+            .collect()
+    }
+
+    #[async_test]
+    async fn test_fetch_resumes_after_interruption() -> RaiseResult<()> {
+        let dir = tempdir()?;
+        let remote = ArtifactStore::new(dir.path().join("remote"));
+        let local = ArtifactStore::new(dir.path().join("local"));
+        let manifest = remote
+            .publish("baseline.json", ArtifactKind::ModelBaseline, &payload())
+            .await?;
+        assert_eq!(manifest.chunk_count(), 3);
+
+        // Coupure après le premier morceau
+        let flaky = ArtifactPeer::new(remote.clone(), 1);
+        assert!(local.fetch(&flaky, &manifest.id, |_| {}).await.is_err());
+        assert!(local.manifest(&manifest.id)?.is_none());
+
+        let peer = ArtifactPeer::new(remote, usize::MAX);
+        let mut last = None;
+        let fetched = local
+            .fetch(&peer, &manifest.id, |p| last = Some(p.clone()))
+            .await?;
+        let last = last.expect("progression");
+        assert_eq!(fetched, manifest);
+        assert_eq!(last.resumed_chunks, 1);
+        assert_eq!(last.bytes_done, manifest.size);
+        assert_eq!(
+            fs::read_async(local.data_path(&manifest.id)).await?,
+            payload()
+        );
+        assert_eq!(local.list().await?.len(), 1);
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_fetch_rejects_corrupted_chunk() -> RaiseResult<()> {
+        let dir = tempdir()?;
+        let remote = ArtifactStore::new(dir.path().join("remote"));
+        let local = ArtifactStore::new(dir.path().join("local"));
+        let manifest = remote
+            .publish("bundle.zip", ArtifactKind::CodeBundle, b"generated code")
+            .await?;
+
+        // Contenu altéré côté serveur après publication
+        fs::write_async(remote.data_path(&manifest.id), b"tampered code!").await?;
+        let peer = ArtifactPeer::new(remote, usize::MAX);
+        assert!(local.fetch(&peer, &manifest.id, |_| {}).await.is_err());
+        assert!(local.manifest(&manifest.id)?.is_none());
+
+        assert!(local.fetch(&peer, "unknown", |_| {}).await.is_err());
+        assert!(local.manifest("../remote")?.is_none());
+        Ok(())
+    }
+}
@@ -1,15 +1,27 @@
 // src-tauri/src/blockchain/storage/chain.rs
 //! Registre local (Ledger) Mentis : Assure le stockage et le chaînage cryptographique des commits.
 
-use crate::blockchain::storage::commit::MentisCommit;
+use crate::blockchain::storage::commit::{MentisCommit, MutationOp};
+use crate::blockchain::storage::endorsement::ReadWriteSet;
 use crate::utils::prelude::*;
 
+/// Valeur courante d'un élément et version (ID du dernier commit l'ayant écrit).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct StateEntry {
+    /// `Null` après une suppression (la version reste suivie pour le contrôle MVCC).
+    pub value: JsonValue,
+    pub version: String,
+}
+
 #[derive(Debug, Serializable, Deserializable, Default)]
 pub struct Ledger {
     /// Stockage brut des commits indexés par leur ID.
     pub commits: UnorderedMap<String, MentisCommit>,
     /// Pointeur vers la tête de la chaîne (Head).
     pub last_commit_hash: Option<String>,
+    /// État courant (world state) dérivé des mutations appliquées.
+    #[serde(default)]
+    pub state: UnorderedMap<String, StateEntry>,
 }
 
 impl Ledger {
@@ -55,11 +67,128 @@ impl Ledger {
 
         // 3. Intégration
         let id = commit.id.clone();
+        for mutation in &commit.mutations {
+            let value = match mutation.operation {
+                MutationOp::Delete => JsonValue::Null,
+                _ => mutation.payload.clone(),
+            };
+            self.state.insert(
+                mutation.element_id.clone(),
+                StateEntry {
+                    value,
+                    version: id.clone(),
+                },
+            );
+        }
         self.last_commit_hash = Some(id.clone());
         self.commits.insert(id, commit);
 
         Ok(())
     }
+
+    pub fn get_commit(&self, id: &str) -> Option<&MentisCommit> {
+        self.commits.get(id)
+    }
+
+    /// Commits de la chaîne principale, du genesis à la tête (suivi des `parent_hash`).
+    pub fn ordered_commits(&self) -> Vec<&MentisCommit> {
+        let mut chain = Vec::with_capacity(self.commits.len());
+        let mut cursor = self.last_commit_hash.as_deref();
+        while let Some(commit) = cursor.and_then(|id| self.commits.get(id)) {
+            chain.push(commit);
+            cursor = commit.parent_hash.as_deref();
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Hauteur de la chaîne principale : nombre de blocs du genesis à la tête.
+    pub fn height(&self) -> usize {
+        self.ordered_commits().len()
+    }
+
+    /// Horodatage du bloc de hauteur `height` (le genesis est à la hauteur 1).
+    pub fn block_timestamp(&self, height: usize) -> Option<UtcTimestamp> {
+        let index = height.checked_sub(1)?;
+        self.ordered_commits().get(index).map(|c| c.timestamp)
+    }
+
+    /// Hauteur atteinte à l'instant `at` : nombre de blocs horodatés au plus tard à `at`.
+    pub fn height_at(&self, at: UtcTimestamp) -> usize {
+        self.ordered_commits()
+            .iter()
+            .take_while(|c| c.timestamp <= at)
+            .count()
+    }
+
+    /// Valeur d'un élément telle qu'elle était après le bloc de hauteur `height`
+    /// (`None` s'il n'avait pas encore été écrit). Au-delà de la tête : état courant.
+    pub fn get_at_block(&self, element_id: &str, height: usize) -> Option<StateEntry> {
+        self.ordered_commits()
+            .into_iter()
+            .take(height)
+            .rev()
+            .find_map(|commit| {
+                let mutation = commit
+                    .mutations
+                    .iter()
+                    .rev()
+                    .find(|m| m.element_id == element_id)?;
+                Some(StateEntry {
+                    value: match mutation.operation {
+                        MutationOp::Delete => JsonValue::Null,
+                        _ => mutation.payload.clone(),
+                    },
+                    version: commit.id.clone(),
+                })
+            })
+    }
+
+    /// Valeur d'un élément à l'instant `at` (cf. `height_at`).
+    pub fn get_at_time(&self, element_id: &str, at: UtcTimestamp) -> Option<StateEntry> {
+        self.get_at_block(element_id, self.height_at(at))
+    }
+
+    /// Version courante d'un élément (`None` s'il n'a jamais été écrit).
+    pub fn version_of(&self, element_id: &str) -> Option<&str> {
+        self.state.get(element_id).map(|e| e.version.as_str())
+    }
+
+    /// Contrôle MVCC : chaque lecture de la simulation doit porter sur la version courante.
+    pub fn validate_read_set(&self, rwset: &ReadWriteSet) -> RaiseResult<()> {
+        let stale: Vec<&String> = rwset
+            .reads
+            .iter()
+            .filter(|(id, seen)| self.version_of(id) != seen.as_deref())
+            .map(|(id, _)| id)
+            .collect();
+        if !stale.is_empty() {
+            raise_error!(
+                "ERR_MENTIS_MVCC_READ_CONFLICT",
+                error = "Read set obsolète : un élément lu a été modifié depuis la simulation.",
+                context = json_value!({ "stale_keys": stale })
+            );
+        }
+        Ok(())
+    }
+
+    /// Ajoute un commit issu d'une simulation (endorsement) : ses mutations doivent être
+    /// exactement le write set, et son read set doit être encore valide.
+    pub fn append_endorsed(
+        &mut self,
+        commit: MentisCommit,
+        rwset: &ReadWriteSet,
+    ) -> RaiseResult<()> {
+        if commit.mutations != rwset.writes {
+            raise_error!(
+                "ERR_MENTIS_RWSET_MISMATCH",
+                error = "Les mutations du commit ne correspondent pas au write set endossé.",
+                context = json_value!({ "commit_id": commit.id })
+            );
+        }
+        self.validate_read_set(rwset)?;
+        self.append_commit(commit)
+    }
 }
 
 // =========================================================================
@@ -100,4 +229,62 @@ mod tests {
             "Le Ledger doit rejeter un bloc qui brise la chaîne."
         );
     }
+
+    #[test]
+    fn test_ledger_reads_at_past_heights() -> RaiseResult<()> {
+        use crate::blockchain::storage::commit::Mutation;
+
+        let mut ledger = Ledger::new();
+        let keys = KeyPair::generate();
+        let t0 = UtcTimestamp::from_timestamp(1_700_000_000, 0).unwrap();
+        let write = |op: MutationOp, value: JsonValue| Mutation {
+            element_id: "urn:offer:1".into(),
+            operation: op,
+            payload: value,
+        };
+
+        for (i, mutation) in [
+            write(MutationOp::Create, json_value!({ "price": 10 })),
+            write(MutationOp::Update, json_value!({ "price": 12 })),
+            write(MutationOp::Delete, JsonValue::Null),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let at = t0 + CalendarDuration::hours(i as i64);
+            let commit =
+                MentisCommit::new_at(vec![mutation], ledger.last_commit_hash.clone(), &keys, at);
+            ledger.append_commit(commit)?;
+        }
+
+        assert_eq!(ledger.height(), 3);
+        assert!(ledger.get_at_block("urn:offer:1", 0).is_none());
+        assert_eq!(
+            ledger.get_at_block("urn:offer:1", 1).unwrap().value["price"],
+            10
+        );
+        assert_eq!(
+            ledger.get_at_block("urn:offer:1", 2).unwrap().value["price"],
+            12
+        );
+        assert_eq!(
+            ledger.get_at_block("urn:offer:1", 3),
+            ledger.state.get("urn:offer:1").cloned()
+        );
+        assert_eq!(
+            ledger.get_at_block("urn:offer:1", 99).unwrap().value,
+            JsonValue::Null
+        );
+
+        // Lecture par horodatage : entre le 2e et le 3e bloc
+        let at = t0 + CalendarDuration::minutes(90);
+        assert_eq!(ledger.height_at(at), 2);
+        assert_eq!(
+            ledger.get_at_time("urn:offer:1", at).unwrap().value["price"],
+            12
+        );
+        assert_eq!(ledger.block_timestamp(1), Some(t0));
+        assert!(ledger.block_timestamp(0).is_none());
+        Ok(())
+    }
 }
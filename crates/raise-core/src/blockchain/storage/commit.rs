@@ -1,7 +1,10 @@
 // src-tauri/src/blockchain/storage/commit.rs
 //! Unité de valeur Mentis : Mutations de connaissance souveraines, immuables et auditables.
 
-use crate::blockchain::crypto::hashing::{calculate_hash, calculate_merkle_root};
+use crate::blockchain::crypto::hashing::{
+    calculate_hash, calculate_merkle_root, hash_canonical, is_tagged, merkle_root_with, split_hash,
+    tag_hash, HashAlgorithm,
+};
 use crate::blockchain::crypto::signing::{verify_signature, KeyPair};
 use crate::utils::prelude::*;
 
@@ -29,7 +32,8 @@ pub struct Mutation {
 /// Un bloc est une preuve cryptographique de l'existence et de l'intégrité d'un savoir.
 #[derive(Serializable, Deserializable, Debug, Clone, PartialEq)]
 pub struct MentisCommit {
-    /// Identifiant unique du bloc (Hash SHA-256 déterministe du contenu).
+    /// Identifiant unique du bloc : empreinte étiquetée du contenu canonique (`sha256:<hex>`),
+    /// ou hash SHA-256 sans préfixe pour les blocs historiques.
     pub id: String,
     /// Référence au bloc précédent (Garantit la continuité du Ledger).
     pub parent_hash: Option<String>,
@@ -49,18 +53,42 @@ impl MentisCommit {
     /// Crée, scelle et signe un nouveau bloc de connaissance Mentis.
     /// 🎯 ÉVOLUTION : La Merkle Root est calculée automatiquement pour éviter les erreurs.
     pub fn new(mutations: Vec<Mutation>, parent_hash: Option<String>, keys: &KeyPair) -> Self {
+        Self::new_at(mutations, parent_hash, keys, UtcClock::now())
+    }
+
+    /// `new` avec un horodatage imposé (horloge injectée par les tests ou un rejeu).
+    pub fn new_at(
+        mutations: Vec<Mutation>,
+        parent_hash: Option<String>,
+        keys: &KeyPair,
+        timestamp: UtcTimestamp,
+    ) -> Self {
+        Self::new_with(
+            mutations,
+            parent_hash,
+            keys,
+            timestamp,
+            HashAlgorithm::default(),
+        )
+    }
+
+    /// `new_at` avec un algorithme d'empreinte explicite, inscrit dans l'ID et la Merkle Root.
+    pub fn new_with(
+        mutations: Vec<Mutation>,
+        parent_hash: Option<String>,
+        keys: &KeyPair,
+        timestamp: UtcTimestamp,
+        algorithm: HashAlgorithm,
+    ) -> Self {
         // 1. On calcule d'abord la Merkle Root des mutations pour sceller la liste
-        let mutation_hashes: Vec<String> = mutations
-            .iter()
-            .map(|m| calculate_hash(&json::json_value!(m)))
-            .collect();
-        let merkle_root = calculate_merkle_root(&mutation_hashes);
+        let merkle_root = Self::mutations_root(&mutations, Some(algorithm));
 
         let mut commit = Self {
-            id: String::new(),
+            // L'ID provisoire porte l'algorithme retenu par `compute_content_hash`
+            id: tag_hash(algorithm, ""),
             parent_hash,
             author: keys.public_key_hex(),
-            timestamp: UtcClock::now(),
+            timestamp,
             mutations,
             merkle_root,
             signature: vec![],
@@ -75,7 +103,36 @@ impl MentisCommit {
         commit
     }
 
-    /// Recalcule dynamiquement le hash à partir des données réelles présentes dans le struct.
+    /// Algorithme d'empreinte du bloc (`None` : bloc historique sans préfixe).
+    pub fn hash_algorithm(&self) -> Option<HashAlgorithm> {
+        if !is_tagged(&self.id) {
+            return None;
+        }
+        split_hash(&self.id).map(|(algorithm, _)| algorithm)
+    }
+
+    /// Merkle Root des mutations, au format étiqueté ou historique.
+    fn mutations_root(mutations: &[Mutation], algorithm: Option<HashAlgorithm>) -> String {
+        match algorithm {
+            Some(algorithm) => {
+                let hashes: Vec<String> = mutations
+                    .iter()
+                    .map(|m| hash_canonical(algorithm, &json::json_value!(m)))
+                    .collect();
+                merkle_root_with(algorithm, &hashes)
+            }
+            None => {
+                let hashes: Vec<String> = mutations
+                    .iter()
+                    .map(|m| calculate_hash(&json::json_value!(m)))
+                    .collect();
+                calculate_merkle_root(&hashes)
+            }
+        }
+    }
+
+    /// Recalcule dynamiquement le hash à partir des données réelles présentes dans le struct,
+    /// avec l'algorithme désigné par l'ID (format historique si l'ID n'a pas de préfixe).
     /// Vital pour détecter toute altération de champ après création.
     pub fn compute_content_hash(&self) -> String {
         let content = json::json_value!({
@@ -85,19 +142,17 @@ impl MentisCommit {
             "mutations": self.mutations,
             "merkle_root": self.merkle_root
         });
-        calculate_hash(&content)
+        match self.hash_algorithm() {
+            Some(algorithm) => hash_canonical(algorithm, &content),
+            None => calculate_hash(&content),
+        }
     }
 
     /// Vérifie l'intégrité et l'authenticité absolue du bloc Mentis.
     /// Retourne true si et seulement si les données, l'ID et la Signature concordent.
     pub fn verify(&self) -> bool {
         // Étape 1 : Vérification de la structure Merkle interne (Anti-Mutation Injection)
-        let mutation_hashes: Vec<String> = self
-            .mutations
-            .iter()
-            .map(|m| calculate_hash(&json::json_value!(m)))
-            .collect();
-        let expected_merkle = calculate_merkle_root(&mutation_hashes);
+        let expected_merkle = Self::mutations_root(&self.mutations, self.hash_algorithm());
         if self.merkle_root != expected_merkle {
             return false;
         }
@@ -299,4 +354,39 @@ mod tests {
             "Un bloc sans mutation mais signé par l'auteur est valide."
         );
     }
+
+    #[test]
+    fn test_mentis_commit_hash_algorithms() {
+        let keys = KeyPair::generate();
+        let mutations = vec![Mutation {
+            element_id: "urn:mentis:02".into(),
+            operation: MutationOp::Update,
+            payload: json::json_value!({ "score": 1.5 }),
+        }];
+
+        let sha = MentisCommit::new(mutations.clone(), None, &keys);
+        assert!(sha.id.starts_with("sha256:"));
+        assert!(sha.merkle_root.starts_with("sha256:"));
+        assert_eq!(sha.hash_algorithm(), Some(HashAlgorithm::Sha256));
+
+        let blake = MentisCommit::new_with(
+            mutations.clone(),
+            Some(sha.id.clone()),
+            &keys,
+            UtcClock::now(),
+            HashAlgorithm::Blake3,
+        );
+        assert!(blake.id.starts_with("blake3:"));
+        assert!(blake.verify());
+
+        // Bloc historique (ID sans préfixe) : toujours vérifiable
+        let mut legacy = sha.clone();
+        legacy.merkle_root = MentisCommit::mutations_root(&legacy.mutations, None);
+        legacy.id = String::new();
+        legacy.id = legacy.compute_content_hash();
+        legacy.signature = keys.sign(&legacy.id);
+        assert_eq!(legacy.hash_algorithm(), None);
+        assert_eq!(legacy.id.len(), 64);
+        assert!(legacy.verify());
+    }
 }
@@ -0,0 +1,164 @@
+// src-tauri/src/blockchain/storage/endorsement.rs
+//! Simulation (endorsement) Mentis : exécute une transaction contre l'état du Ledger sans
+//! l'écrire, en traçant son read/write set pour le contrôle MVCC au moment du commit.
+
+use crate::blockchain::crypto::signing::KeyPair;
+use crate::blockchain::storage::chain::Ledger;
+use crate::blockchain::storage::commit::{MentisCommit, Mutation, MutationOp};
+use crate::utils::prelude::*;
+
+/// Read/write set d'une transaction simulée.
+#[derive(Serializable, Deserializable, Debug, Clone, Default, PartialEq)]
+pub struct ReadWriteSet {
+    /// Élément lu -> version observée (`None` : élément inexistant au moment de la lecture).
+    pub reads: OrderedMap<String, Option<String>>,
+    /// Mutations produites, dans l'ordre d'écriture.
+    pub writes: Vec<Mutation>,
+}
+
+impl ReadWriteSet {
+    /// Scelle le write set dans un commit chaîné sur la tête actuelle du Ledger.
+    pub fn to_commit(&self, ledger: &Ledger, keys: &KeyPair) -> MentisCommit {
+        MentisCommit::new(self.writes.clone(), ledger.last_commit_hash.clone(), keys)
+    }
+}
+
+/// Transaction en cours de simulation : lit l'état du Ledger, n'écrit que dans son write set.
+pub struct Simulation<'a> {
+    ledger: &'a Ledger,
+    rwset: ReadWriteSet,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(ledger: &'a Ledger) -> Self {
+        Self {
+            ledger,
+            rwset: ReadWriteSet::default(),
+        }
+    }
+
+    /// Lit un élément. Les écritures de la simulation sont visibles (read-your-writes)
+    /// et ne sont pas tracées comme lectures.
+    pub fn read(&mut self, element_id: &str) -> Option<JsonValue> {
+        if let Some(own) = self
+            .rwset
+            .writes
+            .iter()
+            .rev()
+            .find(|m| m.element_id == element_id)
+        {
+            return match own.operation {
+                MutationOp::Delete => None,
+                _ => Some(own.payload.clone()),
+            };
+        }
+
+        let entry = self.ledger.state.get(element_id);
+        self.rwset
+            .reads
+            .insert(element_id.to_string(), entry.map(|e| e.version.clone()));
+        entry.map(|e| e.value.clone()).filter(|v| !v.is_null())
+    }
+
+    pub fn write(&mut self, element_id: &str, operation: MutationOp, payload: JsonValue) {
+        self.rwset.writes.push(Mutation {
+            element_id: element_id.to_string(),
+            operation,
+            payload,
+        });
+    }
+
+    pub fn into_rwset(self) -> ReadWriteSet {
+        self.rwset
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded_ledger(keys: &KeyPair) -> Ledger {
+        let mut ledger = Ledger::new();
+        let genesis = MentisCommit::new(
+            vec![Mutation {
+                element_id: "urn:stock".into(),
+                operation: MutationOp::Create,
+                payload: json_value!({ "qty": 10 }),
+            }],
+            None,
+            keys,
+        );
+        ledger.append_commit(genesis).unwrap();
+        ledger
+    }
+
+    fn decrement(ledger: &Ledger) -> ReadWriteSet {
+        let mut sim = Simulation::new(ledger);
+        let qty = sim.read("urn:stock").unwrap()["qty"].as_i64().unwrap();
+        sim.write(
+            "urn:stock",
+            MutationOp::Update,
+            json_value!({ "qty": qty - 1 }),
+        );
+        sim.into_rwset()
+    }
+
+    /// Test 1 : Deux simulations concurrentes sur la même clé, la seconde est rejetée.
+    #[test]
+    fn test_mvcc_rejects_stale_read_set() {
+        let keys = KeyPair::generate();
+        let mut ledger = seeded_ledger(&keys);
+
+        let tx_a = decrement(&ledger);
+        let tx_b = decrement(&ledger);
+        assert_eq!(
+            tx_a.reads["urn:stock"].as_deref(),
+            ledger.last_commit_hash.as_deref()
+        );
+
+        let commit_a = tx_a.to_commit(&ledger, &keys);
+        ledger.append_endorsed(commit_a, &tx_a).unwrap();
+        assert_eq!(ledger.state["urn:stock"].value, json_value!({ "qty": 9 }));
+
+        let commit_b = tx_b.to_commit(&ledger, &keys);
+        assert!(ledger.append_endorsed(commit_b, &tx_b).is_err());
+
+        // Re-simulation sur l'état à jour : acceptée
+        let retry = decrement(&ledger);
+        let commit = retry.to_commit(&ledger, &keys);
+        ledger.append_endorsed(commit, &retry).unwrap();
+        assert_eq!(ledger.state["urn:stock"].value, json_value!({ "qty": 8 }));
+    }
+
+    /// Test 2 : Lecture d'une clé absente puis création concurrente (phantom).
+    #[test]
+    fn test_mvcc_detects_phantom_create_and_rwset_mismatch() {
+        let keys = KeyPair::generate();
+        let mut ledger = seeded_ledger(&keys);
+
+        let mut sim = Simulation::new(&ledger);
+        assert!(sim.read("urn:new").is_none());
+        sim.write("urn:new", MutationOp::Create, json_value!({ "v": 1 }));
+        assert_eq!(sim.read("urn:new"), Some(json_value!({ "v": 1 })));
+        let tx = sim.into_rwset();
+        assert_eq!(tx.reads.len(), 1);
+
+        let mut other = Simulation::new(&ledger);
+        other.write("urn:new", MutationOp::Create, json_value!({ "v": 2 }));
+        let other = other.into_rwset();
+        let c = other.to_commit(&ledger, &keys);
+        ledger.append_endorsed(c, &other).unwrap();
+
+        let c = tx.to_commit(&ledger, &keys);
+        assert!(ledger.validate_read_set(&tx).is_err());
+
+        // Un commit dont les mutations diffèrent du write set est refusé
+        let forged = MentisCommit::new(vec![], ledger.last_commit_hash.clone(), &keys);
+        assert!(ledger.append_endorsed(forged, &tx).is_err());
+        assert!(ledger.append_endorsed(c, &tx).is_err());
+    }
+}
@@ -6,6 +6,14 @@ pub mod commit;
 /// Gestion du registre local (Ledger) et du chaînage des blocs.
 pub mod chain;
 
+/// Simulation des transactions et read/write sets (contrôle MVCC).
+pub mod endorsement;
+
+/// Données privées : hash on-chain, contenu dans un side-DB réservé aux membres.
+pub mod private;
+
 // Réexportation des structures clés pour un usage simplifié dans le reste de Raise
-pub use chain::Ledger;
+pub use chain::{Ledger, StateEntry};
 pub use commit::{MentisCommit, Mutation, MutationOp};
+pub use endorsement::{ReadWriteSet, Simulation};
+pub use private::PrivateDataStore;
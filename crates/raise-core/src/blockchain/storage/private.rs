@@ -0,0 +1,271 @@
+// src-tauri/src/blockchain/storage/private.rs
+//! Données privées Mentis : seul le hash d'une preuve est inscrit au Ledger, le contenu
+//! complet reste dans un side-DB partagé entre les pairs membres de la collection privée.
+
+use crate::blockchain::crypto::hashing::calculate_hash;
+use crate::blockchain::crypto::signing::KeyPair;
+use crate::blockchain::storage::commit::{MentisCommit, Mutation, MutationOp};
+use crate::utils::prelude::*;
+
+/// Clé du payload on-chain désignant une preuve privée.
+pub const PRIVATE_PAYLOAD_KEY: &str = "@private";
+
+/// Collection privée : définit quels pairs (clés publiques) détiennent le contenu.
+#[derive(Serializable, Deserializable, Debug, Clone, PartialEq)]
+pub struct PrivateCollection {
+    pub name: String,
+    pub members: UniqueSet<String>,
+}
+
+/// Contenu complet d'une preuve, hors chaîne.
+#[derive(Serializable, Deserializable, Debug, Clone, PartialEq)]
+pub struct PrivateEvidence {
+    pub collection: String,
+    #[serde(rename = "@id")]
+    pub element_id: String,
+    pub payload: JsonValue,
+    /// Hash inscrit au Ledger (`calculate_hash` de `{@id, payload}`).
+    pub hash: String,
+}
+
+impl PrivateEvidence {
+    fn compute_hash(element_id: &str, payload: &JsonValue) -> String {
+        calculate_hash(&json_value!({ "@id": element_id, "payload": payload }))
+    }
+
+    /// Vrai si le contenu correspond toujours au hash on-chain.
+    pub fn verify(&self) -> bool {
+        Self::compute_hash(&self.element_id, &self.payload) == self.hash
+    }
+}
+
+/// Référence on-chain d'une preuve privée, lue depuis le payload d'une mutation.
+pub fn private_reference(mutation: &Mutation) -> Option<(&str, &str)> {
+    let reference = mutation.payload.get(PRIVATE_PAYLOAD_KEY)?;
+    Some((
+        reference.get("collection")?.as_str()?,
+        reference.get("hash")?.as_str()?,
+    ))
+}
+
+/// Side-DB local des preuves privées, indexé par hash.
+#[derive(Debug, Serializable, Deserializable, Default)]
+pub struct PrivateDataStore {
+    pub collections: UnorderedMap<String, PrivateCollection>,
+    pub evidences: UnorderedMap<String, PrivateEvidence>,
+}
+
+impl PrivateDataStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Déclare (ou redéfinit) une collection privée et ses membres.
+    pub fn define_collection(&mut self, name: &str, members: &[String]) {
+        self.collections.insert(
+            name.to_string(),
+            PrivateCollection {
+                name: name.to_string(),
+                members: members.iter().cloned().collect(),
+            },
+        );
+    }
+
+    /// Range le contenu dans le side-DB et renvoie la mutation à inscrire au Ledger,
+    /// dont le payload ne porte que la collection et le hash.
+    pub fn seal_mutation(
+        &mut self,
+        collection: &str,
+        element_id: &str,
+        operation: MutationOp,
+        payload: JsonValue,
+    ) -> RaiseResult<Mutation> {
+        if !self.collections.contains_key(collection) {
+            raise_error!(
+                "ERR_MENTIS_PRIVATE_COLLECTION_UNKNOWN",
+                error = format!("Collection privée inconnue : '{}'", collection),
+                context = json_value!({ "collection": collection, "element_id": element_id })
+            );
+        }
+        let hash = PrivateEvidence::compute_hash(element_id, &payload);
+        self.evidences.insert(
+            hash.clone(),
+            PrivateEvidence {
+                collection: collection.to_string(),
+                element_id: element_id.to_string(),
+                payload,
+                hash: hash.clone(),
+            },
+        );
+        Ok(Mutation {
+            element_id: element_id.to_string(),
+            operation,
+            payload: json_value!({
+                PRIVATE_PAYLOAD_KEY: { "collection": collection, "hash": hash }
+            }),
+        })
+    }
+
+    /// Restitue le contenu d'une preuve privée inscrite au Ledger, réservé aux membres
+    /// de sa collection. Le contenu est revérifié contre le hash on-chain.
+    pub fn get_private_evidence(
+        &self,
+        mutation: &Mutation,
+        requester: &KeyPair,
+    ) -> RaiseResult<JsonValue> {
+        let Some((collection, hash)) = private_reference(mutation) else {
+            raise_error!(
+                "ERR_MENTIS_NOT_PRIVATE",
+                error = "La mutation ne référence pas de donnée privée.",
+                context = json_value!({ "element_id": mutation.element_id })
+            );
+        };
+
+        let requester_pk = requester.public_key_hex();
+        let authorized = self
+            .collections
+            .get(collection)
+            .is_some_and(|c| c.members.contains(&requester_pk));
+        if !authorized {
+            raise_error!(
+                "ERR_MENTIS_PRIVATE_ACCESS_DENIED",
+                error = format!("Accès refusé à la collection privée '{}'", collection),
+                context = json_value!({ "collection": collection, "requester": requester_pk })
+            );
+        }
+
+        match self.evidences.get(hash) {
+            Some(evidence) if evidence.verify() => Ok(evidence.payload.clone()),
+            Some(_) => raise_error!(
+                "ERR_MENTIS_PRIVATE_TAMPERED",
+                error = "Le contenu privé ne correspond plus au hash du Ledger.",
+                context = json_value!({ "collection": collection, "hash": hash })
+            ),
+            None => raise_error!(
+                "ERR_MENTIS_PRIVATE_MISSING",
+                error = "Contenu privé absent du side-DB local.",
+                context = json_value!({ "collection": collection, "hash": hash })
+            ),
+        }
+    }
+
+    /// Preuves qu'un pair est autorisé à recevoir (dissémination entre membres).
+    pub fn evidences_for_peer(&self, peer_public_key: &str) -> Vec<PrivateEvidence> {
+        self.evidences
+            .values()
+            .filter(|e| {
+                self.collections
+                    .get(&e.collection)
+                    .is_some_and(|c| c.members.contains(peer_public_key))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Intègre les preuves reçues d'un pair pour un commit donné ; un contenu dont le hash
+    /// ne concorde pas, ou absent des mutations du commit, est ignoré. Retourne le nombre accepté.
+    pub fn ingest(&mut self, commit: &MentisCommit, received: Vec<PrivateEvidence>) -> usize {
+        let expected: UniqueSet<&str> = commit
+            .mutations
+            .iter()
+            .filter_map(|m| private_reference(m).map(|(_, hash)| hash))
+            .collect();
+        let mut accepted = 0;
+        for evidence in received {
+            if evidence.verify() && expected.contains(evidence.hash.as_str()) {
+                self.evidences.insert(evidence.hash.clone(), evidence);
+                accepted += 1;
+            }
+        }
+        accepted
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (PrivateDataStore, KeyPair, KeyPair) {
+        let member = KeyPair::generate();
+        let outsider = KeyPair::generate();
+        let mut store = PrivateDataStore::new();
+        store.define_collection("audit_evidence", &[member.public_key_hex()]);
+        (store, member, outsider)
+    }
+
+    /// Test 1 : Seul le hash est inscrit au Ledger, le contenu est réservé aux membres.
+    #[test]
+    fn test_private_evidence_hash_on_chain_and_gated_access() {
+        let (mut store, member, outsider) = setup();
+        let payload = json_value!({ "report": "pentest-2026.pdf", "score": 7 });
+        let mutation = store
+            .seal_mutation(
+                "audit_evidence",
+                "urn:ev:1",
+                MutationOp::Create,
+                payload.clone(),
+            )
+            .unwrap();
+
+        let commit = MentisCommit::new(vec![mutation.clone()], None, &member);
+        assert!(commit.verify());
+        let on_chain = json::serialize_to_string(&commit).unwrap();
+        assert!(!on_chain.contains("pentest-2026"));
+
+        assert_eq!(
+            store.get_private_evidence(&mutation, &member).unwrap(),
+            payload
+        );
+        assert!(store.get_private_evidence(&mutation, &outsider).is_err());
+    }
+
+    /// Test 2 : Altération du side-DB détectée à la lecture.
+    #[test]
+    fn test_private_evidence_tamper_detected() {
+        let (mut store, member, _) = setup();
+        let mutation = store
+            .seal_mutation(
+                "audit_evidence",
+                "urn:ev:2",
+                MutationOp::Create,
+                json_value!({ "v": 1 }),
+            )
+            .unwrap();
+        for evidence in store.evidences.values_mut() {
+            evidence.payload = json_value!({ "v": 2 });
+        }
+        assert!(store.get_private_evidence(&mutation, &member).is_err());
+    }
+
+    /// Test 3 : Dissémination vers un pair membre, contrôlée par le hash du commit.
+    #[test]
+    fn test_private_evidence_dissemination() {
+        let (mut store, member, outsider) = setup();
+        let mutation = store
+            .seal_mutation(
+                "audit_evidence",
+                "urn:ev:3",
+                MutationOp::Create,
+                json_value!({ "v": 3 }),
+            )
+            .unwrap();
+        let commit = MentisCommit::new(vec![mutation.clone()], None, &member);
+
+        assert!(store
+            .evidences_for_peer(&outsider.public_key_hex())
+            .is_empty());
+        let shared = store.evidences_for_peer(&member.public_key_hex());
+
+        let mut peer_store = PrivateDataStore::new();
+        peer_store.define_collection("audit_evidence", &[member.public_key_hex()]);
+        let mut forged = shared[0].clone();
+        forged.payload = json_value!({ "v": 99 });
+        assert_eq!(peer_store.ingest(&commit, vec![forged]), 0);
+        assert_eq!(peer_store.ingest(&commit, shared), 1);
+        assert!(peer_store.get_private_evidence(&mutation, &member).is_ok());
+    }
+}
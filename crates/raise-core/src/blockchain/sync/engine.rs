@@ -1,20 +1,94 @@
 // src-tauri/src/blockchain/sync/engine.rs
 //! Moteur de synchronisation Mentis : Répond aux requêtes de synchronisation des autres nœuds.
 
-use crate::blockchain::p2p::protocol::{MentisNetMessage, MentisResponse};
+use crate::blockchain::p2p::protocol::{
+    negotiate, Handshake, MentisNetMessage, MentisResponse, NegotiatedSession, CAP_ANTI_ENTROPY,
+    CAP_ARTIFACTS, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION,
+};
+use crate::blockchain::p2p::transfer::ArtifactStore;
 use crate::blockchain::storage::chain::Ledger;
+use crate::blockchain::sync::state::SyncStatus;
+use crate::blockchain::sync::summary::{ChainSummary, SUMMARY_SEGMENT_SIZE};
 use crate::utils::prelude::*;
 
+/// Nombre maximal de commits par lot transféré pendant l'anti-entropie.
+pub const SYNC_BATCH_SIZE: usize = 128;
+
+/// Canal de requête vers un pair (Request-Response libp2p, ou pair local en test).
+#[async_interface]
+pub trait SyncPeer: Send + Sync {
+    async fn request(&self, msg: MentisNetMessage) -> RaiseResult<MentisResponse>;
+}
+
+/// Bilan d'une session d'anti-entropie.
+#[derive(Debug, Serializable, Deserializable, Clone, PartialEq)]
+pub struct SyncReport {
+    /// Hauteur de la première divergence détectée (`None` : déjà à jour).
+    pub divergence_height: Option<usize>,
+    pub fetched: usize,
+    pub applied: usize,
+    pub status: SyncStatus,
+}
+
 /// Le moteur de synchronisation Mentis.
 pub struct SyncEngine {
     /// Référence partagée vers le registre local (Ledger).
     ledger: SharedRef<SyncMutex<Ledger>>,
+    /// Sessions négociées par pair (un pair sans `Hello` reçoit la session v1 implicite).
+    sessions: SyncMutex<UnorderedMap<String, NegotiatedSession>>,
+    /// Dépôt servi aux pairs (`None` : le nœud ne partage aucun artefact).
+    artifacts: Option<ArtifactStore>,
 }
 
 impl SyncEngine {
     /// Crée une nouvelle instance du moteur de synchronisation.
     pub fn new(ledger: SharedRef<SyncMutex<Ledger>>) -> Self {
-        Self { ledger }
+        Self {
+            ledger,
+            sessions: SyncMutex::new(UnorderedMap::new()),
+            artifacts: None,
+        }
+    }
+
+    /// Sert les artefacts de `store` aux pairs (capacité `artifacts`).
+    pub fn with_artifacts(mut self, store: ArtifactStore) -> Self {
+        self.artifacts = Some(store);
+        self
+    }
+
+    /// Point d'entrée Request-Response : mémorise la session négociée par `Hello` et refuse
+    /// les messages hors des capacités de la session du pair.
+    pub fn process_peer_request(
+        &self,
+        peer_id: &str,
+        req: &MentisNetMessage,
+    ) -> RaiseResult<Option<MentisResponse>> {
+        let mut sessions = match self.sessions.lock() {
+            Ok(g) => g,
+            Err(_) => raise_error!("ERR_SYNC_SESSION_LOCK", error = "Session lock poisoned"),
+        };
+
+        if let MentisNetMessage::Hello(_) = req {
+            let response = self.process_sync_request(req)?;
+            if let Some(MentisResponse::Welcome(session)) = &response {
+                sessions.insert(peer_id.to_string(), session.clone());
+            }
+            return Ok(response);
+        }
+
+        let session = sessions
+            .get(peer_id)
+            .cloned()
+            .unwrap_or_else(NegotiatedSession::legacy);
+        drop(sessions);
+        if let Some(capability) =
+            NegotiatedSession::required_capability(req).filter(|cap| !session.supports(cap))
+        {
+            return Ok(Some(MentisResponse::Unsupported {
+                capability: capability.to_string(),
+            }));
+        }
+        self.process_sync_request(req)
     }
 
     /// Traite une requête de synchronisation ciblée et génère la réponse MentisResponse.
@@ -23,6 +97,27 @@ impl SyncEngine {
         req: &MentisNetMessage,
     ) -> RaiseResult<Option<MentisResponse>> {
         match req {
+            // 🤝 Ouverture de session : version commune et capacités partagées
+            MentisNetMessage::Hello(remote) => match negotiate(&Handshake::local(), remote) {
+                Ok(session) => {
+                    user_trace!(
+                        "TRC_SYNC_SESSION_NEGOTIATED",
+                        json_value!({ "version": session.version, "capabilities": session.capabilities })
+                    );
+                    Ok(Some(MentisResponse::Welcome(session)))
+                }
+                Err(_) => {
+                    user_warn!(
+                        "WRN_SYNC_PROTOCOL_MISMATCH",
+                        json_value!({ "remote": remote, "local_version": PROTOCOL_VERSION })
+                    );
+                    Ok(Some(MentisResponse::VersionMismatch {
+                        min_version: MIN_PROTOCOL_VERSION,
+                        max_version: PROTOCOL_VERSION,
+                    }))
+                }
+            },
+
             // 🔍 Un pair demande quel est notre dernier hash
             MentisNetMessage::RequestLatestHash => {
                 let guard = match self.ledger.lock() {
@@ -41,23 +136,290 @@ impl SyncEngine {
 
             // 📥 Un pair demande un commit spécifique qu'il lui manque
             MentisNetMessage::RequestCommit { commit_hash } => {
-                let _guard = match self.ledger.lock() {
-                    Ok(g) => g,
-                    Err(_) => raise_error!("ERR_SYNC_LEDGER_LOCK", error = "Ledger lock poisoned"),
-                };
+                let guard = self.lock_ledger()?;
 
                 user_trace!("TRC_SYNC_GET_COMMIT", json_value!({ "hash": commit_hash }));
 
-                // TODO: Appeler guard.get_commit(commit_hash) quand l'API Ledger le permettra.
-                // Pour l'instant, on simule que le commit n'est pas trouvé.
-                Ok(Some(MentisResponse::CommitNotFound))
+                Ok(Some(match guard.get_commit(commit_hash) {
+                    Some(commit) => MentisResponse::CommitFound(commit.clone()),
+                    None => MentisResponse::CommitNotFound,
+                }))
+            }
+
+            MentisNetMessage::RequestSummary => Ok(Some(MentisResponse::Summary(
+                ChainSummary::of(&*self.lock_ledger()?),
+            ))),
+
+            MentisNetMessage::RequestCommitsFrom { from_height, limit } => {
+                let guard = self.lock_ledger()?;
+                let batch = guard
+                    .ordered_commits()
+                    .into_iter()
+                    .skip(*from_height)
+                    .take((*limit).min(SYNC_BATCH_SIZE))
+                    .cloned()
+                    .collect();
+                Ok(Some(MentisResponse::Commits(batch)))
             }
 
+            MentisNetMessage::RequestArtifactManifest { .. }
+            | MentisNetMessage::RequestArtifactChunk { .. } => match &self.artifacts {
+                Some(store) => store.serve(req),
+                None => Ok(Some(MentisResponse::Unsupported {
+                    capability: CAP_ARTIFACTS.to_string(),
+                })),
+            },
+
             // Les messages de diffusion (AnnounceCommit, SubmitVote) sont ignorés ici,
             // ils sont traités en amont par le ConsensusEngine dans service.rs.
             _ => Ok(None),
         }
     }
+
+    fn lock_ledger(&self) -> RaiseResult<std::sync::MutexGuard<'_, Ledger>> {
+        match self.ledger.lock() {
+            Ok(g) => Ok(g),
+            Err(_) => raise_error!("ERR_SYNC_LEDGER_LOCK", error = "Ledger lock poisoned"),
+        }
+    }
+
+    /// Ouvre une session avec un pair. Un pair antérieur à la négociation (v1) ne comprend
+    /// pas `Hello` : on lui attribue la session implicite v1 plutôt que d'échouer.
+    pub async fn negotiate_with(&self, peer: &dyn SyncPeer) -> RaiseResult<NegotiatedSession> {
+        match peer
+            .request(MentisNetMessage::Hello(Handshake::local()))
+            .await
+        {
+            Ok(MentisResponse::Welcome(session)) => Ok(session),
+            Ok(MentisResponse::VersionMismatch {
+                min_version,
+                max_version,
+            }) => raise_error!(
+                "ERR_SYNC_PROTOCOL_INCOMPATIBLE",
+                error = "Le pair ne supporte aucune version de protocole commune.",
+                context = json_value!({
+                    "local": [MIN_PROTOCOL_VERSION, PROTOCOL_VERSION],
+                    "remote": [min_version, max_version]
+                })
+            ),
+            _ => {
+                user_trace!(
+                    "TRC_SYNC_LEGACY_PEER",
+                    json_value!({ "assumed_version": 1 })
+                );
+                Ok(NegotiatedSession::legacy())
+            }
+        }
+    }
+
+    /// Synchronise le Ledger local avec un pair : anti-entropie si la session négociée le
+    /// permet, sinon remontée de la chaîne commit par commit (pairs v1).
+    /// Un historique local incompatible (fork) n'est jamais réécrit : la session s'arrête en erreur.
+    pub async fn synchronize_with(
+        &self,
+        peer: &dyn SyncPeer,
+        on_progress: impl FnMut(&SyncStatus) + Send,
+    ) -> RaiseResult<SyncReport> {
+        let session = self.negotiate_with(peer).await?;
+        if session.supports(CAP_ANTI_ENTROPY) {
+            self.anti_entropy(peer, on_progress).await
+        } else {
+            self.legacy_catch_up(peer, on_progress).await
+        }
+    }
+
+    /// Rattrapage v1 : part de la tête du pair et remonte les `parent_hash` jusqu'à un
+    /// commit connu, puis applique les commits manquants dans l'ordre.
+    async fn legacy_catch_up(
+        &self,
+        peer: &dyn SyncPeer,
+        mut on_progress: impl FnMut(&SyncStatus) + Send,
+    ) -> RaiseResult<SyncReport> {
+        let head = match peer.request(MentisNetMessage::RequestLatestHash).await? {
+            MentisResponse::LatestHash(head) => head,
+            other => raise_error!(
+                "ERR_SYNC_UNEXPECTED_RESPONSE",
+                error = "Réponse inattendue à RequestLatestHash",
+                context = json_value!({ "response": other })
+            ),
+        };
+        let target_hash = head.clone().unwrap_or_default();
+
+        let mut missing = Vec::new();
+        let mut cursor = head;
+        while let Some(hash) = cursor {
+            if self.lock_ledger()?.get_commit(&hash).is_some() {
+                break;
+            }
+            match peer
+                .request(MentisNetMessage::RequestCommit {
+                    commit_hash: hash.clone(),
+                })
+                .await?
+            {
+                MentisResponse::CommitFound(commit) => {
+                    cursor = commit.parent_hash.clone();
+                    missing.push(commit);
+                }
+                other => raise_error!(
+                    "ERR_SYNC_UNEXPECTED_RESPONSE",
+                    error = "Commit de la chaîne distante introuvable",
+                    context = json_value!({ "hash": hash, "response": other })
+                ),
+            }
+        }
+
+        if missing.is_empty() {
+            on_progress(&SyncStatus::UpToDate);
+            return Ok(SyncReport {
+                divergence_height: None,
+                fetched: 0,
+                applied: 0,
+                status: SyncStatus::UpToDate,
+            });
+        }
+
+        let fetched = missing.len();
+        let mut ledger = self.lock_ledger()?;
+        let divergence = ledger.len();
+        let anchor = missing.last().and_then(|c| c.parent_hash.clone());
+        if anchor != ledger.last_commit_hash {
+            let status = SyncStatus::Error(format!(
+                "Fork : la chaîne du pair se raccroche à {:?}, tête locale {:?}",
+                anchor, ledger.last_commit_hash
+            ));
+            on_progress(&status);
+            raise_error!(
+                "ERR_SYNC_FORK_DETECTED",
+                error = "Historique local incompatible avec celui du pair.",
+                context = json_value!({ "anchor": anchor, "local_head": ledger.last_commit_hash })
+            );
+        }
+        for (applied, commit) in missing.into_iter().rev().enumerate() {
+            ledger.append_commit(commit)?;
+            on_progress(&SyncStatus::Syncing {
+                progress: (applied + 1) as f32 / fetched as f32,
+                target_hash: target_hash.clone(),
+            });
+        }
+
+        on_progress(&SyncStatus::UpToDate);
+        Ok(SyncReport {
+            divergence_height: Some(divergence),
+            fetched,
+            applied: fetched,
+            status: SyncStatus::UpToDate,
+        })
+    }
+
+    /// Anti-entropie avec un pair : compare les résumés Merkle, localise la divergence
+    /// puis rapatrie les commits manquants dans l'ordre, par lots.
+    async fn anti_entropy(
+        &self,
+        peer: &dyn SyncPeer,
+        mut on_progress: impl FnMut(&SyncStatus) + Send,
+    ) -> RaiseResult<SyncReport> {
+        let remote = match peer.request(MentisNetMessage::RequestSummary).await? {
+            MentisResponse::Summary(summary) => summary,
+            other => raise_error!(
+                "ERR_SYNC_UNEXPECTED_RESPONSE",
+                error = "Réponse inattendue à RequestSummary",
+                context = json_value!({ "response": other })
+            ),
+        };
+        let local = ChainSummary::of(&*self.lock_ledger()?);
+
+        let Some(divergence) = local.divergence_from(&remote) else {
+            on_progress(&SyncStatus::UpToDate);
+            return Ok(SyncReport {
+                divergence_height: None,
+                fetched: 0,
+                applied: 0,
+                status: SyncStatus::UpToDate,
+            });
+        };
+
+        // On repart du début du segment : la divergence peut se situer à l'intérieur
+        let mut cursor = (divergence / SUMMARY_SEGMENT_SIZE) * SUMMARY_SEGMENT_SIZE;
+        let target_hash = remote.head.clone().unwrap_or_default();
+        let missing = remote.height.saturating_sub(local.height).max(1);
+        let (mut fetched, mut applied) = (0, 0);
+
+        while cursor < remote.height {
+            let batch = match peer
+                .request(MentisNetMessage::RequestCommitsFrom {
+                    from_height: cursor,
+                    limit: SYNC_BATCH_SIZE,
+                })
+                .await?
+            {
+                MentisResponse::Commits(batch) => batch,
+                other => raise_error!(
+                    "ERR_SYNC_UNEXPECTED_RESPONSE",
+                    error = "Réponse inattendue à RequestCommitsFrom",
+                    context = json_value!({ "response": other })
+                ),
+            };
+            if batch.is_empty() {
+                break;
+            }
+            let batch_len = batch.len();
+            fetched += batch_len;
+
+            {
+                let mut ledger = self.lock_ledger()?;
+                let local_ids: Vec<String> = ledger
+                    .ordered_commits()
+                    .iter()
+                    .map(|c| c.id.clone())
+                    .collect();
+                for (offset, commit) in batch.into_iter().enumerate() {
+                    let height = cursor + offset;
+                    match local_ids.get(height) {
+                        Some(id) if *id == commit.id => continue,
+                        Some(id) => {
+                            let status = SyncStatus::Error(format!(
+                                "Fork à la hauteur {} : local {} / distant {}",
+                                height, id, commit.id
+                            ));
+                            on_progress(&status);
+                            raise_error!(
+                                "ERR_SYNC_FORK_DETECTED",
+                                error = "Historique local incompatible avec celui du pair.",
+                                context = json_value!({
+                                    "height": height,
+                                    "local": id,
+                                    "remote": commit.id
+                                })
+                            );
+                        }
+                        None => {
+                            ledger.append_commit(commit)?;
+                            applied += 1;
+                        }
+                    }
+                }
+            }
+            cursor += batch_len;
+
+            on_progress(&SyncStatus::Syncing {
+                progress: (applied as f32 / missing as f32).min(1.0),
+                target_hash: target_hash.clone(),
+            });
+        }
+
+        user_info!(
+            "INF_SYNC_ANTI_ENTROPY_DONE",
+            json_value!({ "divergence": divergence, "fetched": fetched, "applied": applied })
+        );
+        on_progress(&SyncStatus::UpToDate);
+        Ok(SyncReport {
+            divergence_height: Some(divergence),
+            fetched,
+            applied,
+            status: SyncStatus::UpToDate,
+        })
+    }
 }
 
 // =========================================================================
@@ -90,7 +452,7 @@ mod tests {
         };
         let response = engine.process_sync_request(&req).unwrap();
 
-        // Par défaut, le mock renvoie CommitNotFound
+        // Hash inconnu du ledger local
         assert_eq!(response, Some(MentisResponse::CommitNotFound));
     }
 
@@ -113,4 +475,151 @@ mod tests {
             "Les messages Gossip doivent être ignorés par le SyncEngine"
         );
     }
+
+    use crate::blockchain::crypto::signing::KeyPair;
+    use crate::blockchain::storage::commit::MentisCommit;
+
+    /// Pair en mémoire : répond via le SyncEngine d'un autre ledger.
+    struct LocalPeer(SyncEngine);
+
+    #[async_interface]
+    impl SyncPeer for LocalPeer {
+        async fn request(&self, msg: MentisNetMessage) -> RaiseResult<MentisResponse> {
+            Ok(self.0.process_sync_request(&msg)?.unwrap())
+        }
+    }
+
+    fn extend(ledger: &mut Ledger, n: usize, keys: &KeyPair) {
+        for _ in 0..n {
+            let c = MentisCommit::new(vec![], ledger.last_commit_hash.clone(), keys);
+            ledger.append_commit(c).unwrap();
+        }
+    }
+
+    #[async_test]
+    async fn test_anti_entropy_catches_up_in_order() {
+        let keys = KeyPair::generate();
+        let mut remote = Ledger::new();
+        extend(&mut remote, 10, &keys);
+        // Le nœud local a vu les 10 premiers commits puis a été hors-ligne
+        let local = SharedRef::new(SyncMutex::new(Ledger::new()));
+        for commit in remote.ordered_commits() {
+            local.lock().unwrap().append_commit(commit.clone()).unwrap();
+        }
+        extend(&mut remote, 3 * SUMMARY_SEGMENT_SIZE, &keys);
+        let head = remote.last_commit_hash.clone();
+        let peer = LocalPeer(SyncEngine::new(SharedRef::new(SyncMutex::new(remote))));
+
+        let engine = SyncEngine::new(local.clone());
+        let mut updates = Vec::new();
+        let report = engine
+            .synchronize_with(&peer, |s| updates.push(s.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(report.divergence_height, Some(10));
+        assert_eq!(report.applied, 3 * SUMMARY_SEGMENT_SIZE);
+        assert_eq!(local.lock().unwrap().last_commit_hash, head);
+        assert!(updates
+            .iter()
+            .any(|s| matches!(s, SyncStatus::Syncing { .. })));
+        assert_eq!(updates.last(), Some(&SyncStatus::UpToDate));
+
+        // Seconde session : rien à rapatrier
+        let again = engine.synchronize_with(&peer, |_| {}).await.unwrap();
+        assert_eq!(again.divergence_height, None);
+    }
+
+    #[async_test]
+    async fn test_anti_entropy_refuses_fork() {
+        let keys = KeyPair::generate();
+        let mut remote = Ledger::new();
+        extend(&mut remote, 5, &keys);
+        let mut forked = Ledger::new();
+        extend(&mut forked, 3, &KeyPair::generate());
+        let peer = LocalPeer(SyncEngine::new(SharedRef::new(SyncMutex::new(remote))));
+
+        let local = SharedRef::new(SyncMutex::new(forked));
+        let engine = SyncEngine::new(local.clone());
+        let mut last = None;
+        let result = engine
+            .synchronize_with(&peer, |s| last = Some(s.clone()))
+            .await;
+
+        assert!(result.is_err());
+        assert!(matches!(last, Some(SyncStatus::Error(_))));
+        assert_eq!(local.lock().unwrap().ordered_commits().len(), 3);
+    }
+
+    /// Pair v1 : ignore `Hello` et les requêtes d'anti-entropie.
+    struct LegacyPeer(SyncEngine);
+
+    #[async_interface]
+    impl SyncPeer for LegacyPeer {
+        async fn request(&self, msg: MentisNetMessage) -> RaiseResult<MentisResponse> {
+            match msg {
+                MentisNetMessage::Hello(_)
+                | MentisNetMessage::RequestSummary
+                | MentisNetMessage::RequestCommitsFrom { .. } => {
+                    raise_error!("ERR_TEST_UNKNOWN_VARIANT", error = "unknown variant")
+                }
+                other => Ok(self.0.process_sync_request(&other)?.unwrap()),
+            }
+        }
+    }
+
+    #[async_test]
+    async fn test_legacy_peer_catch_up() {
+        let keys = KeyPair::generate();
+        let mut remote = Ledger::new();
+        extend(&mut remote, 3, &keys);
+        let local = SharedRef::new(SyncMutex::new(Ledger::new()));
+        local
+            .lock()
+            .unwrap()
+            .append_commit(remote.ordered_commits()[0].clone())
+            .unwrap();
+        extend(&mut remote, 4, &keys);
+        let head = remote.last_commit_hash.clone();
+        let peer = LegacyPeer(SyncEngine::new(SharedRef::new(SyncMutex::new(remote))));
+
+        let engine = SyncEngine::new(local.clone());
+        assert_eq!(
+            engine.negotiate_with(&peer).await.unwrap(),
+            NegotiatedSession::legacy()
+        );
+        let report = engine.synchronize_with(&peer, |_| {}).await.unwrap();
+        assert_eq!((report.divergence_height, report.applied), (Some(1), 6));
+        assert_eq!(local.lock().unwrap().last_commit_hash, head);
+    }
+
+    #[test]
+    fn test_peer_sessions_gate_capabilities() {
+        let engine = SyncEngine::new(SharedRef::new(SyncMutex::new(Ledger::new())));
+
+        // Sans Hello : session v1, l'anti-entropie est refusée
+        let response = engine
+            .process_peer_request("peer-a", &MentisNetMessage::RequestSummary)
+            .unwrap();
+        assert!(matches!(response, Some(MentisResponse::Unsupported { .. })));
+
+        let hello = MentisNetMessage::Hello(Handshake::local());
+        let response = engine.process_peer_request("peer-a", &hello).unwrap();
+        assert!(matches!(response, Some(MentisResponse::Welcome(_))));
+        let response = engine
+            .process_peer_request("peer-a", &MentisNetMessage::RequestSummary)
+            .unwrap();
+        assert!(matches!(response, Some(MentisResponse::Summary(_))));
+
+        let future = MentisNetMessage::Hello(Handshake {
+            version: 7,
+            min_version: 7,
+            capabilities: vec![],
+        });
+        let response = engine.process_peer_request("peer-b", &future).unwrap();
+        assert!(matches!(
+            response,
+            Some(MentisResponse::VersionMismatch { .. })
+        ));
+    }
 }
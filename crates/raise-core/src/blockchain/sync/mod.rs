@@ -7,6 +7,9 @@ pub mod engine;
 /// Calcul des écarts de données (diff) pour la synchronisation optimisée.
 pub mod delta;
 
+/// Résumés Merkle segmentés du Ledger pour l'anti-entropie.
+pub mod summary;
+
 /// Gestionnaire d'états de synchronisation (Initializing, Syncing, UpToDate).
 pub mod state;
 
@@ -18,3 +21,4 @@ pub mod state;
 pub use delta::MentisDelta;
 pub use engine::SyncEngine;
 pub use state::SyncStatus;
+pub use summary::ChainSummary;
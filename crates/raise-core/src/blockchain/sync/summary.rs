@@ -0,0 +1,107 @@
+// src-tauri/src/blockchain/sync/summary.rs
+//! Résumé Merkle d'un Ledger pour l'anti-entropie : la chaîne est découpée en segments
+//! de taille fixe dont on compare les racines pour localiser la première divergence.
+
+use crate::blockchain::crypto::hashing::{calculate_hash, calculate_merkle_root};
+use crate::blockchain::storage::chain::Ledger;
+use crate::utils::prelude::*;
+
+/// Nombre de commits par segment de résumé.
+pub const SUMMARY_SEGMENT_SIZE: usize = 64;
+
+#[derive(Debug, Serializable, Deserializable, Clone, PartialEq, Default)]
+pub struct ChainSummary {
+    /// Nombre de commits de la chaîne principale.
+    pub height: usize,
+    pub head: Option<String>,
+    /// Racine de Merkle des IDs de chaque segment (le dernier peut être incomplet).
+    pub segment_roots: Vec<String>,
+    /// Racine de Merkle de l'état courant (`element_id` + version).
+    pub state_root: String,
+}
+
+impl ChainSummary {
+    pub fn of(ledger: &Ledger) -> Self {
+        let ids: Vec<String> = ledger
+            .ordered_commits()
+            .iter()
+            .map(|c| c.id.clone())
+            .collect();
+        let segment_roots = ids
+            .chunks(SUMMARY_SEGMENT_SIZE)
+            .map(calculate_merkle_root)
+            .collect();
+
+        let mut state: Vec<(&String, &String)> = ledger
+            .state
+            .iter()
+            .map(|(id, entry)| (id, &entry.version))
+            .collect();
+        state.sort();
+        let leaves: Vec<String> = state
+            .into_iter()
+            .map(|(id, version)| calculate_hash(&json_value!([id, version])))
+            .collect();
+
+        Self {
+            height: ids.len(),
+            head: ledger.last_commit_hash.clone(),
+            segment_roots,
+            state_root: calculate_merkle_root(&leaves),
+        }
+    }
+
+    /// Hauteur à partir de laquelle `self` (local) et `remote` divergent :
+    /// début du premier segment différent, ou fin de la chaîne commune la plus courte.
+    /// `None` si les deux résumés sont identiques.
+    pub fn divergence_from(&self, remote: &ChainSummary) -> Option<usize> {
+        if self.head == remote.head && self.state_root == remote.state_root {
+            return None;
+        }
+        let common = self.height.min(remote.height);
+        let full_segments = common / SUMMARY_SEGMENT_SIZE;
+        for segment in 0..full_segments {
+            if self.segment_roots.get(segment) != remote.segment_roots.get(segment) {
+                return Some(segment * SUMMARY_SEGMENT_SIZE);
+            }
+        }
+        Some(common)
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::crypto::signing::KeyPair;
+    use crate::blockchain::storage::commit::MentisCommit;
+
+    fn ledger_with(n: usize, keys: &KeyPair) -> Ledger {
+        let mut ledger = Ledger::new();
+        for _ in 0..n {
+            let c = MentisCommit::new(vec![], ledger.last_commit_hash.clone(), keys);
+            ledger.append_commit(c).unwrap();
+        }
+        ledger
+    }
+
+    #[test]
+    fn test_summary_segments_and_divergence() {
+        let keys = KeyPair::generate();
+        let long = ledger_with(SUMMARY_SEGMENT_SIZE + 10, &keys);
+        let summary = ChainSummary::of(&long);
+        assert_eq!(summary.height, SUMMARY_SEGMENT_SIZE + 10);
+        assert_eq!(summary.segment_roots.len(), 2);
+
+        assert_eq!(summary.divergence_from(&summary.clone()), None);
+        let empty = ChainSummary::of(&Ledger::new());
+        assert_eq!(empty.divergence_from(&summary), Some(0));
+
+        // Chaînes indépendantes : divergence dès le premier segment
+        let other = ChainSummary::of(&ledger_with(SUMMARY_SEGMENT_SIZE + 2, &keys));
+        assert_eq!(summary.divergence_from(&other), Some(0));
+    }
+}
@@ -1,6 +1,7 @@
 // src-tauri/src/blockchain/vpn/innernet_client.rs
 //! Client Innernet RAISE : Orchestration du maillage VPN WireGuard sécurisé.
 
+use super::metrics::{self, PeerMetricsStore, PeerOverview, PeerSeries};
 use crate::utils::prelude::*;
 
 /// Configuration du segment réseau mesh.
@@ -50,6 +51,8 @@ pub struct NetworkStatus {
 pub struct InnernetClient {
     config: NetworkConfig,
     status: SharedRef<AsyncRwLock<NetworkStatus>>,
+    /// Historique RTT / débits par pair, alimenté par `list_peers`.
+    metrics: SharedRef<AsyncRwLock<PeerMetricsStore>>,
 }
 
 impl InnernetClient {
@@ -66,6 +69,7 @@ impl InnernetClient {
         Self {
             config,
             status: SharedRef::new(AsyncRwLock::new(status)),
+            metrics: SharedRef::new(AsyncRwLock::new(PeerMetricsStore::default())),
         }
     }
 
@@ -146,6 +150,67 @@ impl InnernetClient {
         }
     }
 
+    /// Relève la topologie, mesure le RTT de chaque pair et enrichit l'historique.
+    pub async fn list_peers(&self) -> RaiseResult<Vec<PeerOverview>> {
+        let output = self.run_command(["show", &self.config.name]).await?;
+        let peers = self.parse_wg_topology(&String::from_utf8_lossy(&output.stdout));
+
+        let mut rtts = Vec::with_capacity(peers.len());
+        for peer in &peers {
+            rtts.push(Self::probe_rtt(&peer.ip).await);
+        }
+
+        let now = UtcClock::now().timestamp();
+        let mut store = self.metrics.write().await;
+        store.retain(&peers);
+        let overview = peers
+            .iter()
+            .zip(rtts)
+            .map(|(peer, rtt)| store.record(peer, rtt, now))
+            .collect();
+        drop(store);
+
+        self.status.write().await.peers = peers;
+        Ok(overview)
+    }
+
+    /// Séries temporelles RTT / débits d'un pair, ou de tous (plus lent en tête).
+    pub async fn peer_stats(&self, public_key: Option<&str>) -> Vec<PeerSeries> {
+        self.metrics.read().await.series(public_key)
+    }
+
+    /// Échantillonnage périodique des pairs tant que l'interface est active.
+    pub fn spawn_peer_sampler(&self, interval: TimeDuration) -> tokio::task::JoinHandle<()> {
+        let client = self.clone();
+        spawn_async_task(async move {
+            loop {
+                sleep_async(interval).await;
+                if !client.status.read().await.connected {
+                    continue;
+                }
+                if let Err(e) = client.list_peers().await {
+                    user_warn!(
+                        "WRN_VPN_PEER_SAMPLING_FAILED",
+                        json_value!({ "network": client.config.name, "error": e.to_string() })
+                    );
+                }
+            }
+        })
+    }
+
+    /// RTT d'un ping unique (1 s max) ; `None` si le pair ne répond pas.
+    async fn probe_rtt(ip: &str) -> Option<f64> {
+        let output = AsyncCommand::new("ping")
+            .args(["-c", "1", "-W", "1", ip])
+            .output()
+            .await
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        metrics::parse_ping_rtt(&String::from_utf8_lossy(&output.stdout))
+    }
+
     /// Exécuteur générique de commandes système avec gestion d'erreurs RAISE[cite: 12, 14].
     async fn run_command<I, S>(&self, args: I) -> RaiseResult<ProcessOutput>
     where
@@ -222,6 +287,17 @@ impl InnernetClient {
                         .into();
                 } else if l.starts_with("latest handshake:") {
                     p.last_handshake = Some(UtcClock::now().timestamp());
+                } else if let Some(transfer) = l.strip_prefix("transfer:") {
+                    // `transfer: 1.45 MiB received, 3.21 MiB sent`
+                    let words: Vec<&str> = transfer.split_whitespace().collect();
+                    for pair in words.windows(3) {
+                        let bytes = metrics::parse_transfer_bytes(pair[0], pair[1]);
+                        match (bytes, pair[2].trim_end_matches(',')) {
+                            (Some(b), "received") => p.transfer_rx = b,
+                            (Some(b), "sent") => p.transfer_tx = b,
+                            _ => {}
+                        }
+                    }
                 }
             }
         }
@@ -279,6 +355,7 @@ peer: pubkey_alpha
 
 peer: pubkey_beta
   allowed ips: 10.42.0.3/32, 10.42.1.0/24
+  transfer: 1.50 KiB received, 2 MiB sent
         "#;
 
         let peers = client.parse_wg_topology(dump);
@@ -286,6 +363,8 @@ peer: pubkey_beta
         assert_eq!(peers[0].public_key, "pubkey_alpha");
         assert_eq!(peers[0].ip, "10.42.0.2");
         assert_eq!(peers[1].ip, "10.42.0.3"); // Doit extraire la première IP du range
+        assert_eq!(peers[1].transfer_rx, 1536);
+        assert_eq!(peers[1].transfer_tx, 2 * 1024 * 1024);
     }
 
     /// Test 4 : Vérification de l'intégrité de l'état initial[cite: 14].
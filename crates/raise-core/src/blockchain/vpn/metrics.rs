@@ -0,0 +1,288 @@
+// src-tauri/src/blockchain/vpn/metrics.rs
+//! Historique des métriques VPN par pair : RTT et débits échantillonnés, conservés dans un
+//! tampon circulaire pour diagnostiquer un consensus lent (pair lointain, lien saturé).
+
+use super::innernet_client::Peer;
+use crate::utils::prelude::*;
+use std::collections::VecDeque;
+
+/// Période d'échantillonnage par défaut (`InnernetClient::spawn_peer_sampler`).
+pub const PEER_SAMPLE_INTERVAL_SECS: u64 = 10;
+/// Échantillons conservés par pair (1 h à la période par défaut).
+pub const PEER_HISTORY_CAPACITY: usize = 360;
+
+/// Mesure ponctuelle d'un pair.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct PeerSample {
+    /// Epoch secondes.
+    pub timestamp: i64,
+    /// `None` si le pair n'a pas répondu au ping.
+    pub rtt_ms: Option<f64>,
+    /// Débits moyens depuis l'échantillon précédent (octets/s).
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+}
+
+/// Tampon circulaire des échantillons d'un pair.
+#[derive(Debug, Clone)]
+pub struct PeerHistory {
+    capacity: usize,
+    samples: VecDeque<PeerSample>,
+    /// Compteurs cumulés du dernier relevé : `(timestamp, rx, tx)`.
+    last_transfer: Option<(i64, u64, u64)>,
+}
+
+impl PeerHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            samples: VecDeque::with_capacity(capacity.max(1)),
+            last_transfer: None,
+        }
+    }
+
+    /// Enregistre un relevé : le débit est dérivé de l'écart des compteurs WireGuard
+    /// (nul au premier relevé ou après une remise à zéro de l'interface).
+    pub fn record(&mut self, peer: &Peer, rtt_ms: Option<f64>, now: i64) -> &PeerSample {
+        let (rx_rate, tx_rate) = match self.last_transfer {
+            Some((at, rx, tx)) if now > at => {
+                let elapsed = (now - at) as f64;
+                (
+                    peer.transfer_rx.saturating_sub(rx) as f64 / elapsed,
+                    peer.transfer_tx.saturating_sub(tx) as f64 / elapsed,
+                )
+            }
+            _ => (0.0, 0.0),
+        };
+        self.last_transfer = Some((now, peer.transfer_rx, peer.transfer_tx));
+
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(PeerSample {
+            timestamp: now,
+            rtt_ms,
+            rx_bytes_per_sec: rx_rate,
+            tx_bytes_per_sec: tx_rate,
+        });
+        &self.samples[self.samples.len() - 1]
+    }
+
+    pub fn latest(&self) -> Option<&PeerSample> {
+        self.samples.back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Séries temporelles en colonnes, directement exploitables par un graphique.
+    pub fn series(&self, peer: &Peer) -> PeerSeries {
+        let rtts: Vec<f64> = self.samples.iter().filter_map(|s| s.rtt_ms).collect();
+        let mut sorted = rtts.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        PeerSeries {
+            public_key: peer.public_key.clone(),
+            name: peer.name.clone(),
+            ip: peer.ip.clone(),
+            timestamps: self.samples.iter().map(|s| s.timestamp).collect(),
+            rtt_ms: self.samples.iter().map(|s| s.rtt_ms).collect(),
+            rx_bytes_per_sec: self.samples.iter().map(|s| s.rx_bytes_per_sec).collect(),
+            tx_bytes_per_sec: self.samples.iter().map(|s| s.tx_bytes_per_sec).collect(),
+            avg_rtt_ms: (!rtts.is_empty()).then(|| rtts.iter().sum::<f64>() / rtts.len() as f64),
+            p95_rtt_ms: percentile(&sorted, 0.95),
+            loss_ratio: if self.samples.is_empty() {
+                0.0
+            } else {
+                1.0 - rtts.len() as f64 / self.samples.len() as f64
+            },
+        }
+    }
+}
+
+/// Historique d'un pair sous forme de colonnes alignées sur `timestamps`.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct PeerSeries {
+    pub public_key: String,
+    pub name: String,
+    pub ip: String,
+    pub timestamps: Vec<i64>,
+    pub rtt_ms: Vec<Option<f64>>,
+    pub rx_bytes_per_sec: Vec<f64>,
+    pub tx_bytes_per_sec: Vec<f64>,
+    pub avg_rtt_ms: Option<f64>,
+    pub p95_rtt_ms: Option<f64>,
+    /// Part des échantillons sans réponse au ping.
+    pub loss_ratio: f64,
+}
+
+/// Pair courant enrichi de sa dernière mesure (`vpn_list_peers`).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct PeerOverview {
+    #[serde(flatten)]
+    pub peer: Peer,
+    pub latest: Option<PeerSample>,
+    pub samples: usize,
+}
+
+/// Historiques de tous les pairs, indexés par clé publique.
+#[derive(Debug, Clone)]
+pub struct PeerMetricsStore {
+    capacity: usize,
+    peers: UnorderedMap<String, (Peer, PeerHistory)>,
+}
+
+impl Default for PeerMetricsStore {
+    fn default() -> Self {
+        Self::new(PEER_HISTORY_CAPACITY)
+    }
+}
+
+impl PeerMetricsStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            peers: UnorderedMap::new(),
+        }
+    }
+
+    pub fn record(&mut self, peer: &Peer, rtt_ms: Option<f64>, now: i64) -> PeerOverview {
+        let capacity = self.capacity;
+        let (known, history) = self
+            .peers
+            .entry(peer.public_key.clone())
+            .or_insert_with(|| (peer.clone(), PeerHistory::new(capacity)));
+        *known = peer.clone();
+        let latest = history.record(peer, rtt_ms, now).clone();
+        PeerOverview {
+            peer: peer.clone(),
+            latest: Some(latest),
+            samples: history.len(),
+        }
+    }
+
+    /// Séries d'un pair (`Some(clé)`) ou de tous, triées par RTT moyen décroissant :
+    /// le pair qui ralentit le consensus apparaît en tête.
+    pub fn series(&self, public_key: Option<&str>) -> Vec<PeerSeries> {
+        let mut series: Vec<PeerSeries> = self
+            .peers
+            .iter()
+            .filter(|(key, _)| public_key.is_none_or(|k| k == key.as_str()))
+            .map(|(_, (peer, history))| history.series(peer))
+            .collect();
+        series.sort_by(|a, b| {
+            b.avg_rtt_ms
+                .unwrap_or(f64::MAX)
+                .total_cmp(&a.avg_rtt_ms.unwrap_or(f64::MAX))
+        });
+        series
+    }
+
+    /// Oublie les pairs absents de la topologie courante.
+    pub fn retain(&mut self, peers: &[Peer]) {
+        self.peers
+            .retain(|key, _| peers.iter().any(|p| &p.public_key == key));
+    }
+}
+
+fn percentile(sorted: &[f64], q: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((sorted.len() as f64 * q).ceil() as usize).clamp(1, sorted.len());
+    Some(sorted[rank - 1])
+}
+
+/// RTT extrait de la sortie de `ping` (`time=12.3 ms`).
+pub fn parse_ping_rtt(output: &str) -> Option<f64> {
+    let raw = output.split("time=").nth(1)?;
+    raw.split_whitespace().next()?.parse().ok()
+}
+
+/// Volume WireGuard (`1.45 MiB`) converti en octets.
+pub fn parse_transfer_bytes(value: &str, unit: &str) -> Option<u64> {
+    let factor: f64 = match unit.trim_end_matches(',') {
+        "B" => 1.0,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some((value.parse::<f64>().ok()? * factor) as u64)
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer(key: &str, rx: u64, tx: u64) -> Peer {
+        Peer {
+            name: key.to_string(),
+            ip: "10.42.0.2".into(),
+            public_key: key.to_string(),
+            endpoint: None,
+            last_handshake: None,
+            transfer_rx: rx,
+            transfer_tx: tx,
+        }
+    }
+
+    #[test]
+    fn test_history_rates_and_ring_buffer() {
+        let mut history = PeerHistory::new(3);
+        assert_eq!(
+            history
+                .record(&peer("a", 0, 0), Some(10.0), 100)
+                .rx_bytes_per_sec,
+            0.0
+        );
+        let sample = history
+            .record(&peer("a", 1_000, 500), Some(30.0), 110)
+            .clone();
+        assert_eq!(sample.rx_bytes_per_sec, 100.0);
+        assert_eq!(sample.tx_bytes_per_sec, 50.0);
+        history.record(&peer("a", 1_000, 500), None, 120);
+        history.record(&peer("a", 2_000, 500), Some(20.0), 130);
+
+        // Capacité 3 : le premier échantillon est évincé
+        let series = history.series(&peer("a", 0, 0));
+        assert_eq!(series.timestamps, vec![110, 120, 130]);
+        assert_eq!(series.rtt_ms, vec![Some(30.0), None, Some(20.0)]);
+        assert_eq!(series.avg_rtt_ms, Some(25.0));
+        assert_eq!(series.p95_rtt_ms, Some(30.0));
+        assert!((series.loss_ratio - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_store_orders_slowest_peer_first() {
+        let mut store = PeerMetricsStore::new(10);
+        store.record(&peer("fast", 0, 0), Some(5.0), 1);
+        store.record(&peer("slow", 0, 0), Some(250.0), 1);
+        let series = store.series(None);
+        assert_eq!(series[0].public_key, "slow");
+        assert_eq!(store.series(Some("fast")).len(), 1);
+
+        store.retain(&[peer("fast", 0, 0)]);
+        assert!(store.series(Some("slow")).is_empty());
+    }
+
+    #[test]
+    fn test_parsers() {
+        let ping = "64 bytes from 10.42.0.2: icmp_seq=1 ttl=64 time=12.4 ms";
+        assert_eq!(parse_ping_rtt(ping), Some(12.4));
+        assert_eq!(parse_ping_rtt("100% packet loss"), None);
+        assert_eq!(parse_transfer_bytes("1.5", "KiB"), Some(1536));
+        assert_eq!(parse_transfer_bytes("2", "MiB,"), Some(2 * 1024 * 1024));
+        assert_eq!(parse_transfer_bytes("2", "parsecs"), None);
+    }
+}
@@ -3,6 +3,8 @@
 
 /// Client d'orchestration pour la CLI Innernet.
 pub mod innernet_client;
+/// Historique RTT / débits par pair.
+pub mod metrics;
 
 // =========================================================================
 // FAÇADE VPN (Standard RAISE)
@@ -11,6 +13,7 @@ pub mod innernet_client;
 // d'implémentation de la CLI.
 
 pub use innernet_client::{InnernetClient, NetworkConfig, NetworkStatus, Peer};
+pub use metrics::{PeerOverview, PeerSample, PeerSeries, PEER_SAMPLE_INTERVAL_SECS};
 
 // =========================================================================
 // TESTS DE CONFORMITÉ DE LA FAÇADE
@@ -0,0 +1,397 @@
+// FICHIER : crates/raise-core/src/json_db/backup.rs
+
+//! Sauvegarde et restauration à un instant donné d'une base : archive tar compressée zstd
+//! (`.tar.zst`) contenant les fichiers bruts de la base (documents, index, schémas,
+//! `_system.json`, journal WAL) et un manifeste `snapshot.json` (empreintes, position du WAL).
+//! La capture est faite sous le verrou d'index de la base : aucune transaction ne s'applique
+//! pendant la copie. Les fichiers sont archivés tels quels (chiffrés si le stockage l'est).
+
+use crate::json_db::bundle::{is_safe_entry, relative_key, sha256_hex, walk_files};
+use crate::json_db::storage::{encryption, StorageEngine};
+use crate::json_db::transactions::{wal, TransactionLog};
+use crate::utils::prelude::*;
+
+pub const SNAPSHOT_EXTENSION: &str = "tar.zst";
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+const SNAPSHOT_MANIFEST: &str = "snapshot.json";
+const SYSTEM_INDEX: &str = "_system.json";
+const WAL_DIR: &str = "wal";
+
+/// Position du journal au moment de la capture.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct WalPosition {
+    /// Transactions journalisées mais non validées (annulées à la restauration).
+    pub pending: Vec<String>,
+    /// Horodatage de la plus récente entrée du journal.
+    pub last_timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct SnapshotManifest {
+    pub format_version: u32,
+    pub raise_version: String,
+    pub created_at: String,
+    pub space: String,
+    pub db: String,
+    /// Fichiers scellés par le chiffrement au repos : la clé est requise pour restaurer.
+    pub encrypted: bool,
+    pub wal: WalPosition,
+    /// Empreinte SHA-256 de chaque fichier, chemins relatifs à la racine de la base.
+    pub files: OrderedMap<String, String>,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct RestoreReport {
+    pub space: String,
+    pub db: String,
+    pub snapshot_created_at: String,
+    pub files: usize,
+    /// Une base existante a été remplacée.
+    pub replaced: bool,
+    /// Transactions du journal annulées après restauration.
+    pub rolled_back_transactions: usize,
+}
+
+/// Capture la base `space/db` dans l'archive `dest`.
+pub async fn create_snapshot(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    dest: &Path,
+) -> RaiseResult<SnapshotManifest> {
+    let db_root = storage.config.db_root(space, db);
+    if !db_root.join(SYSTEM_INDEX).exists() {
+        raise_error!(
+            "ERR_DB_NOT_FOUND",
+            error = format!("La base '{}/{}' n'existe pas.", space, db),
+            context = json_value!({ "action": "create_snapshot" })
+        );
+    }
+
+    // 1. COPIE COHÉRENTE : les commits prennent ce verrou avant toute écriture
+    let lock = storage.get_index_lock(space, db)?;
+    let (files, wal) = {
+        let _guard = lock.lock().await;
+        let mut files: OrderedMap<String, Vec<u8>> = OrderedMap::new();
+        for path in walk_files(&db_root) {
+            let key = relative_key(&db_root, &path);
+            // Écritures atomiques en cours d'un autre processus
+            if key.contains(".tmp.") {
+                continue;
+            }
+            files.insert(key, fs::read_async(&path).await?);
+        }
+        (files, wal_position(storage, space, db).await?)
+    };
+
+    // 2. ARCHIVE : manifeste en tête, puis les fichiers dans l'ordre du manifeste
+    let manifest = SnapshotManifest {
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        raise_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: UtcClock::now().to_rfc3339(),
+        space: space.to_string(),
+        db: db.to_string(),
+        encrypted: files.values().any(|bytes| encryption::is_sealed(bytes)),
+        wal,
+        total_bytes: files.values().map(|b| b.len() as u64).sum(),
+        files: files
+            .iter()
+            .map(|(path, bytes)| (path.clone(), sha256_hex(bytes)))
+            .collect(),
+    };
+
+    let mut builder = tar::Builder::new(Vec::new());
+    let manifest_bytes = json::serialize_to_bytes(&manifest)?;
+    let entries = std::iter::once((SNAPSHOT_MANIFEST, manifest_bytes.as_slice()))
+        .chain(files.iter().map(|(k, v)| (k.as_str(), v.as_slice())));
+    for (name, bytes) in entries {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(UtcClock::now().timestamp().max(0) as u64);
+        header.set_cksum();
+        if let Err(e) = builder.append_data(&mut header, name, bytes) {
+            raise_error!(
+                "ERR_BACKUP_WRITE",
+                error = e.to_string(),
+                context = json_value!({ "entry": name })
+            );
+        }
+    }
+    let archive = match builder.into_inner() {
+        Ok(a) => a,
+        Err(e) => raise_error!("ERR_BACKUP_WRITE", error = e.to_string()),
+    };
+    fs::write_compressed_atomic_async(dest, &archive).await?;
+
+    Ok(manifest)
+}
+
+/// Lit une archive et vérifie son intégrité (manifeste, empreintes, chemins).
+pub async fn read_snapshot(
+    src: &Path,
+) -> RaiseResult<(SnapshotManifest, OrderedMap<String, Vec<u8>>)> {
+    let archive = fs::read_compressed_async(src).await?;
+    let mut reader = tar::Archive::new(std::io::Cursor::new(archive));
+    let entries = match reader.entries() {
+        Ok(e) => e,
+        Err(e) => raise_error!("ERR_BACKUP_INVALID", error = e.to_string()),
+    };
+
+    let mut raw: OrderedMap<String, Vec<u8>> = OrderedMap::new();
+    for entry in entries {
+        let mut entry = match entry {
+            Ok(e) => e,
+            Err(e) => raise_error!("ERR_BACKUP_INVALID", error = e.to_string()),
+        };
+        let name = match entry.path() {
+            Ok(p) => p.to_string_lossy().to_string(),
+            Err(e) => raise_error!("ERR_BACKUP_INVALID", error = e.to_string()),
+        };
+        if !is_safe_entry(&name) {
+            raise_error!(
+                "ERR_BACKUP_UNSAFE_PATH",
+                context = json_value!({ "entry": name })
+            );
+        }
+        let mut content = Vec::new();
+        if let Err(e) = entry.read_to_end(&mut content) {
+            raise_error!(
+                "ERR_BACKUP_INVALID",
+                error = e.to_string(),
+                context = json_value!({ "entry": name })
+            );
+        }
+        raw.insert(name, content);
+    }
+
+    let Some(manifest_bytes) = raw.remove(SNAPSHOT_MANIFEST) else {
+        raise_error!(
+            "ERR_BACKUP_INVALID",
+            error = "Manifeste absent de la sauvegarde."
+        );
+    };
+    let manifest: SnapshotManifest = json::deserialize_from_bytes(&manifest_bytes)?;
+    if manifest.format_version > SNAPSHOT_FORMAT_VERSION {
+        raise_error!(
+            "ERR_BACKUP_VERSION_UNSUPPORTED",
+            context = json_value!({
+                "format_version": manifest.format_version,
+                "supported": SNAPSHOT_FORMAT_VERSION
+            })
+        );
+    }
+
+    let mut damaged: Vec<String> = raw
+        .keys()
+        .filter(|k| !manifest.files.contains_key(*k))
+        .cloned()
+        .collect();
+    for (path, sha256) in &manifest.files {
+        match raw.get(path) {
+            Some(bytes) if &sha256_hex(bytes) == sha256 => {}
+            _ => damaged.push(path.clone()),
+        }
+    }
+    if !damaged.is_empty() || !manifest.files.contains_key(SYSTEM_INDEX) {
+        raise_error!(
+            "ERR_BACKUP_INTEGRITY",
+            error = "Le contenu de la sauvegarde ne correspond pas à son manifeste.",
+            context = json_value!({ "entries": damaged })
+        );
+    }
+    Ok((manifest, raw))
+}
+
+/// Remplace la base `space/db` par le contenu de l'archive `src`.
+/// La base restaurée doit être celle de la sauvegarde : les références `db://` internes
+/// ne sont pas réécrites. Les transactions en attente dans le journal sont annulées.
+pub async fn restore_snapshot(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    src: &Path,
+) -> RaiseResult<RestoreReport> {
+    let (manifest, files) = read_snapshot(src).await?;
+    if manifest.space != space || manifest.db != db {
+        raise_error!(
+            "ERR_BACKUP_TARGET_MISMATCH",
+            error = format!(
+                "La sauvegarde concerne '{}/{}', pas '{}/{}'.",
+                manifest.space, manifest.db, space, db
+            ),
+            context = json_value!({ "path": src.to_string_lossy() })
+        );
+    }
+    if manifest.encrypted && storage.config.encryption.is_none() {
+        raise_error!(
+            "ERR_DB_ENCRYPTION_KEY_MISSING",
+            error = "Sauvegarde chiffrée mais aucune clé n'est configurée pour ce stockage.",
+            context = json_value!({ "path": src.to_string_lossy() })
+        );
+    }
+
+    // 1. PRÉPARATION hors de la base : une restauration interrompue ne l'altère pas
+    let db_root = storage.config.db_root(space, db);
+    let space_root = storage.config.data_root.join(space);
+    let staging = space_root.join(format!(".{}.restore-{}", db, UniqueId::new_v4()));
+    for (path, bytes) in &files {
+        fs::write_atomic_async(&staging.join(path), bytes).await?;
+    }
+    // La clé courante doit ouvrir la sauvegarde avant tout remplacement
+    if let Err(e) =
+        encryption::read_json::<JsonValue>(&storage.config, &staging.join(SYSTEM_INDEX)).await
+    {
+        let _ = fs::remove_dir_all_async(&staging).await;
+        return Err(e);
+    }
+
+    // 2. BASCULE sous le verrou d'index
+    let lock = storage.get_index_lock(space, db)?;
+    let replaced = {
+        let _guard = lock.lock().await;
+        let replaced = db_root.exists();
+        let previous = space_root.join(format!(".{}.previous-{}", db, UniqueId::new_v4()));
+        if replaced {
+            fs::rename_async(&db_root, &previous).await?;
+        }
+        if let Err(e) = fs::rename_async(&staging, &db_root).await {
+            if replaced {
+                let _ = fs::rename_async(&previous, &db_root).await;
+            }
+            let _ = fs::remove_dir_all_async(&staging).await;
+            return Err(e);
+        }
+        if replaced {
+            fs::remove_dir_all_async(&previous).await?;
+        }
+        storage.cache.clear()?;
+        replaced
+    };
+
+    // 3. JOURNAL : annulation des transactions non validées au moment de la capture
+    let rolled_back =
+        wal::recover_pending_transactions(&storage.config, space, db, storage).await?;
+
+    Ok(RestoreReport {
+        space: space.to_string(),
+        db: db.to_string(),
+        snapshot_created_at: manifest.created_at,
+        files: files.len(),
+        replaced,
+        rolled_back_transactions: rolled_back,
+    })
+}
+
+async fn wal_position(storage: &StorageEngine, space: &str, db: &str) -> RaiseResult<WalPosition> {
+    let mut pending = wal::list_pending(&storage.config, space, db).await?;
+    pending.sort();
+    let wal_dir = storage.config.db_root(space, db).join(WAL_DIR);
+    let mut last_timestamp = None;
+    for id in &pending {
+        let path = wal_dir.join(format!("{}.json", id));
+        if let Ok(log) = encryption::read_json::<TransactionLog>(&storage.config, &path).await {
+            last_timestamp = last_timestamp.max(Some(log.timestamp));
+        }
+    }
+    Ok(WalPosition {
+        pending,
+        last_timestamp,
+    })
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_db::collections::manager::CollectionsManager;
+    use crate::utils::data::config::{BOOTSTRAP_DB, BOOTSTRAP_DOMAIN};
+    use crate::utils::testing::DbSandbox;
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_snapshot_restore_roundtrip() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let (space, db) = ("backup_space", "backup_db");
+        let manager = CollectionsManager::new(&sandbox.storage, space, db);
+        DbSandbox::mock_db(&manager).await?;
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            BOOTSTRAP_DOMAIN, BOOTSTRAP_DB
+        );
+        manager.create_collection("actors", &schema_uri).await?;
+        manager
+            .insert_raw("actors", &json_value!({ "_id": "a1", "name": "Alice" }))
+            .await?;
+
+        let archive = sandbox.storage.config.data_root.join("actors.tar.zst");
+        let manifest = create_snapshot(&sandbox.storage, space, db, &archive).await?;
+        assert!(manifest.files.contains_key(SYSTEM_INDEX));
+        assert!(manifest.files.contains_key("collections/actors/a1.json"));
+        assert!(!manifest.encrypted);
+
+        // Évolution postérieure à la capture
+        manager
+            .insert_raw("actors", &json_value!({ "_id": "a2", "name": "Bob" }))
+            .await?;
+        manager.delete_document("actors", "a1").await?;
+
+        let report = restore_snapshot(&sandbox.storage, space, db, &archive).await?;
+        assert!(report.replaced);
+        assert_eq!(report.files, manifest.files.len());
+        assert!(manager.get_document("actors", "a1").await?.is_some());
+        assert!(manager.get_document("actors", "a2").await?.is_none());
+
+        let Err(AppError::Structured(err)) =
+            restore_snapshot(&sandbox.storage, space, "other_db", &archive).await
+        else {
+            panic!("Une sauvegarde ne se restaure que dans sa base d'origine");
+        };
+        assert_eq!(err.code, "ERR_BACKUP_TARGET_MISMATCH");
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_tampered_snapshot_is_rejected() -> RaiseResult<()> {
+        let dir = tempdir()?;
+        let manifest = SnapshotManifest {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            raise_version: "test".into(),
+            created_at: UtcClock::now().to_rfc3339(),
+            space: "s".into(),
+            db: "d".into(),
+            encrypted: false,
+            wal: WalPosition::default(),
+            files: [(SYSTEM_INDEX.to_string(), sha256_hex(b"{}"))]
+                .into_iter()
+                .collect(),
+            total_bytes: 2,
+        };
+        let mut builder = tar::Builder::new(Vec::new());
+        for (name, bytes) in [
+            (SNAPSHOT_MANIFEST, json::serialize_to_bytes(&manifest)?),
+            (SYSTEM_INDEX, b"{\"altered\":true}".to_vec()),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(bytes.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, name, bytes.as_slice())
+                .unwrap();
+        }
+        let path = dir.path().join("bad.tar.zst");
+        fs::write_compressed_atomic_async(&path, &builder.into_inner().unwrap()).await?;
+
+        let Err(AppError::Structured(err)) = read_snapshot(&path).await else {
+            panic!("La sauvegarde altérée aurait dû être rejetée");
+        };
+        assert_eq!(err.code, "ERR_BACKUP_INTEGRITY");
+        Ok(())
+    }
+}
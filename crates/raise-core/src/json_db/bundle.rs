@@ -0,0 +1,760 @@
+// FICHIER : crates/raise-core/src/json_db/bundle.rs
+
+//! Paquet de projet (`.raisepkg`) : une archive unique contenant la base d'un projet
+//! (index système, schémas, collections — dont définitions de workflows et métriques de
+//! référence — et plugins WASM), avec un manifeste et l'empreinte SHA-256 de chaque entrée.
+//! L'import vérifie l'intégrité avant toute écriture et applique une politique de conflit.
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::indexes::{IndexDefinition, IndexManager};
+use crate::json_db::storage::{encryption, StorageEngine};
+use crate::model_engine::metrics::METRICS_COLLECTION;
+use crate::utils::prelude::*;
+
+pub const BUNDLE_EXTENSION: &str = "raisepkg";
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+/// Dossier des plugins WASM d'un projet, à la racine de sa base.
+pub const PLUGINS_DIR: &str = "plugins";
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const SYSTEM_INDEX: &str = "_system.json";
+const COLLECTION_META: &str = "_meta.json";
+const WORKFLOW_DEFINITIONS: &str = "workflow_definitions";
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct BundleEntry {
+    pub sha256: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct BundleSummary {
+    pub collections: usize,
+    pub documents: usize,
+    pub schemas: usize,
+    pub plugins: usize,
+    pub workflow_definitions: usize,
+    /// Mesures historisées du modèle (références du calcul de churn).
+    pub baselines: usize,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct BundleManifest {
+    pub format_version: u32,
+    pub raise_version: String,
+    pub created_at: String,
+    pub space: String,
+    pub db: String,
+    pub summary: BundleSummary,
+    /// Entrées de l'archive, chemins relatifs à la racine de la base.
+    pub entries: OrderedMap<String, BundleEntry>,
+    /// Empreinte de l'ensemble (chemins et empreintes des entrées, triés).
+    pub digest: String,
+}
+
+/// Traitement d'un fichier du paquet déjà présent, avec un contenu différent, dans la cible.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Aucune écriture si un conflit est détecté.
+    #[default]
+    Fail,
+    /// La version locale est conservée.
+    Skip,
+    /// La version du paquet remplace la version locale.
+    Overwrite,
+}
+
+impl ConflictPolicy {
+    pub fn parse(value: &str) -> RaiseResult<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "fail" => Ok(Self::Fail),
+            "skip" => Ok(Self::Skip),
+            "overwrite" => Ok(Self::Overwrite),
+            _ => raise_error!(
+                "ERR_BUNDLE_CONFLICT_POLICY_UNKNOWN",
+                error = format!("Politique de conflit inconnue : '{}'", value),
+                context = json_value!({ "supported": ["fail", "skip", "overwrite"] })
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+#[serde(default)]
+pub struct ImportOptions {
+    /// Espace cible (celui du paquet par défaut).
+    pub space: Option<String>,
+    /// Base cible (celle du paquet par défaut).
+    pub db: Option<String>,
+    pub on_conflict: ConflictPolicy,
+    /// Rapport seul (conflits compris), sans écriture.
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct ImportReport {
+    pub space: String,
+    pub db: String,
+    pub dry_run: bool,
+    pub created_db: bool,
+    pub created_collections: Vec<String>,
+    pub written: usize,
+    pub unchanged: usize,
+    pub skipped: usize,
+    pub overwritten: usize,
+    /// Chemins (relatifs à la base) en conflit avec la cible.
+    pub conflicts: Vec<String>,
+}
+
+/// Paquet chargé en mémoire : manifeste et contenu des entrées.
+#[derive(Debug, Clone)]
+pub struct ProjectBundle {
+    pub manifest: BundleManifest,
+    files: OrderedMap<String, Vec<u8>>,
+}
+
+enum Action {
+    Write,
+    Unchanged,
+    Conflict,
+}
+
+impl ProjectBundle {
+    /// Rassemble la base du manager.
+    pub async fn collect(manager: &CollectionsManager<'_>) -> RaiseResult<Self> {
+        let db_root = manager.storage.config.db_root(&manager.space, &manager.db);
+        if !db_root.join(SYSTEM_INDEX).exists() {
+            raise_error!(
+                "ERR_DB_NOT_FOUND",
+                error = format!("La base '{}/{}' n'existe pas.", manager.space, manager.db),
+                context = json_value!({ "action": "collect_bundle" })
+            );
+        }
+
+        let mut files = OrderedMap::new();
+        let mut summary = BundleSummary::default();
+        // Contenu en clair : le paquet reste importable dans un stockage chiffré ou non
+        let config = &manager.storage.config;
+        files.insert(
+            SYSTEM_INDEX.to_string(),
+            encryption::read_plain(config, &db_root.join(SYSTEM_INDEX)).await?,
+        );
+
+        for path in walk_files(&db_root.join("schemas")) {
+            files.insert(relative_key(&db_root, &path), fs::read_async(&path).await?);
+            summary.schemas += 1;
+        }
+
+        let collections_root = db_root.join("collections");
+        let mut collections: Vec<PathBuf> = match fs::read_dir_sync(&collections_root) {
+            Ok(entries) => entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        collections.sort();
+        for col_path in collections {
+            let name = col_path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            summary.collections += 1;
+            for path in fs::read_dir_sync(&col_path)?.flatten().map(|e| e.path()) {
+                let file_name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let is_document = !file_name.starts_with('_')
+                    && file_name.ends_with(".json")
+                    && !file_name.contains(".tmp");
+                if !path.is_file() || !(is_document || file_name == COLLECTION_META) {
+                    continue;
+                }
+                files.insert(
+                    relative_key(&db_root, &path),
+                    encryption::read_plain(config, &path).await?,
+                );
+                if is_document {
+                    summary.documents += 1;
+                    match name.as_str() {
+                        WORKFLOW_DEFINITIONS => summary.workflow_definitions += 1,
+                        METRICS_COLLECTION => summary.baselines += 1,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for path in walk_files(&db_root.join(PLUGINS_DIR)) {
+            if path.extension().is_some_and(|ext| ext == "wasm") {
+                files.insert(relative_key(&db_root, &path), fs::read_async(&path).await?);
+                summary.plugins += 1;
+            }
+        }
+
+        let entries: OrderedMap<String, BundleEntry> = files
+            .iter()
+            .map(|(path, bytes)| {
+                (
+                    path.clone(),
+                    BundleEntry {
+                        sha256: sha256_hex(bytes),
+                        size: bytes.len() as u64,
+                    },
+                )
+            })
+            .collect();
+        let manifest = BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            raise_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: UtcClock::now().to_rfc3339(),
+            space: manager.space.clone(),
+            db: manager.db.clone(),
+            summary,
+            digest: entries_digest(&entries),
+            entries,
+        };
+        Ok(Self { manifest, files })
+    }
+
+    /// Archive zip : `manifest.json` puis les entrées, dans l'ordre du manifeste.
+    pub fn to_bytes(&self) -> RaiseResult<Vec<u8>> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        let mut writer = zip::ZipWriter::new(&mut buffer);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        let manifest = json::serialize_to_string_pretty(&self.manifest)?;
+        let entries = std::iter::once((MANIFEST_ENTRY, manifest.as_bytes()))
+            .chain(self.files.iter().map(|(k, v)| (k.as_str(), v.as_slice())));
+        for (name, bytes) in entries {
+            if let Err(e) = writer.start_file(name, options) {
+                raise_error!(
+                    "ERR_BUNDLE_WRITE",
+                    error = e.to_string(),
+                    context = json_value!({ "entry": name })
+                );
+            }
+            if let Err(e) = writer.write_all(bytes) {
+                raise_error!(
+                    "ERR_BUNDLE_WRITE",
+                    error = e.to_string(),
+                    context = json_value!({ "entry": name })
+                );
+            }
+        }
+        if let Err(e) = writer.finish() {
+            raise_error!("ERR_BUNDLE_WRITE", error = e.to_string());
+        }
+        Ok(buffer.into_inner())
+    }
+
+    /// Relit une archive et vérifie son intégrité (empreintes, entrées manquantes ou en trop).
+    pub fn from_bytes(bytes: &[u8]) -> RaiseResult<Self> {
+        let mut archive = match zip::ZipArchive::new(std::io::Cursor::new(bytes)) {
+            Ok(a) => a,
+            Err(e) => raise_error!("ERR_BUNDLE_INVALID", error = e.to_string()),
+        };
+
+        let mut raw: OrderedMap<String, Vec<u8>> = OrderedMap::new();
+        for i in 0..archive.len() {
+            let mut entry = match archive.by_index(i) {
+                Ok(e) => e,
+                Err(e) => raise_error!("ERR_BUNDLE_INVALID", error = e.to_string()),
+            };
+            let name = entry.name().to_string();
+            if !is_safe_entry(&name) {
+                raise_error!(
+                    "ERR_BUNDLE_UNSAFE_PATH",
+                    context = json_value!({ "entry": name })
+                );
+            }
+            let mut content = Vec::new();
+            if let Err(e) = entry.read_to_end(&mut content) {
+                raise_error!(
+                    "ERR_BUNDLE_INVALID",
+                    error = e.to_string(),
+                    context = json_value!({ "entry": name })
+                );
+            }
+            raw.insert(name, content);
+        }
+
+        let Some(manifest_bytes) = raw.remove(MANIFEST_ENTRY) else {
+            raise_error!("ERR_BUNDLE_INVALID", error = "Manifeste absent du paquet.");
+        };
+        let manifest: BundleManifest = json::deserialize_from_bytes(&manifest_bytes)?;
+        if manifest.format_version > BUNDLE_FORMAT_VERSION {
+            raise_error!(
+                "ERR_BUNDLE_VERSION_UNSUPPORTED",
+                context = json_value!({
+                    "format_version": manifest.format_version,
+                    "supported": BUNDLE_FORMAT_VERSION
+                })
+            );
+        }
+
+        let mut damaged: Vec<String> = raw
+            .keys()
+            .filter(|k| !manifest.entries.contains_key(*k))
+            .cloned()
+            .collect();
+        for (path, entry) in &manifest.entries {
+            match raw.get(path) {
+                Some(bytes) if sha256_hex(bytes) == entry.sha256 => {}
+                _ => damaged.push(path.clone()),
+            }
+        }
+        if !damaged.is_empty() || entries_digest(&manifest.entries) != manifest.digest {
+            raise_error!(
+                "ERR_BUNDLE_INTEGRITY",
+                error = "Le contenu du paquet ne correspond pas à son manifeste.",
+                context = json_value!({ "entries": damaged })
+            );
+        }
+        Ok(Self {
+            manifest,
+            files: raw,
+        })
+    }
+
+    pub async fn save(&self, path: &Path) -> RaiseResult<()> {
+        fs::write_atomic_async(path, &self.to_bytes()?).await
+    }
+
+    pub async fn load(path: &Path) -> RaiseResult<Self> {
+        Self::from_bytes(&fs::read_async(path).await?)
+    }
+
+    /// Importe le paquet dans la base cible (créée si absente). Les conflits sont tous
+    /// détectés avant la première écriture.
+    pub async fn import(
+        &self,
+        storage: &StorageEngine,
+        options: &ImportOptions,
+    ) -> RaiseResult<ImportReport> {
+        let space = options
+            .space
+            .clone()
+            .unwrap_or_else(|| self.manifest.space.clone());
+        let db = options
+            .db
+            .clone()
+            .unwrap_or_else(|| self.manifest.db.clone());
+        let manager = CollectionsManager::new(storage, &space, &db);
+        let db_root = storage.config.db_root(&space, &db);
+
+        let mut report = ImportReport {
+            space: space.clone(),
+            db: db.clone(),
+            dry_run: options.dry_run,
+            created_db: !db_root.join(SYSTEM_INDEX).exists(),
+            ..Default::default()
+        };
+
+        // 1. PLAN : comparaison de chaque entrée avec la cible
+        let mut plan: Vec<(&str, Action)> = Vec::new();
+        for path in self.files.keys() {
+            if path == SYSTEM_INDEX
+                || collection_entry(path).is_some_and(|(_, file)| file == COLLECTION_META)
+            {
+                continue;
+            }
+            let incoming = self.relocated(path, &space, &db)?;
+            let target = db_root.join(path);
+            let action = if !target.exists() {
+                Action::Write
+            } else {
+                let local = encryption::read_plain(&storage.config, &target).await?;
+                let same = match (
+                    json::deserialize_from_bytes::<JsonValue>(&local),
+                    json::deserialize_from_bytes::<JsonValue>(&incoming),
+                ) {
+                    (Ok(a), Ok(b)) if path.ends_with(".json") => a == b,
+                    _ => local == incoming,
+                };
+                if same {
+                    Action::Unchanged
+                } else {
+                    Action::Conflict
+                }
+            };
+            if matches!(action, Action::Conflict) {
+                report.conflicts.push(path.clone());
+            }
+            plan.push((path.as_str(), action));
+        }
+
+        if !report.conflicts.is_empty()
+            && options.on_conflict == ConflictPolicy::Fail
+            && !options.dry_run
+        {
+            raise_error!(
+                "ERR_BUNDLE_IMPORT_CONFLICT",
+                error = format!(
+                    "{} fichier(s) du paquet diffèrent de la base cible.",
+                    report.conflicts.len()
+                ),
+                context = json_value!({
+                    "space": space,
+                    "db": db,
+                    "conflicts": report.conflicts,
+                    "hint": "Relancez avec la politique 'skip' ou 'overwrite'."
+                })
+            );
+        }
+
+        for (_, action) in &plan {
+            match action {
+                Action::Write => report.written += 1,
+                Action::Unchanged => report.unchanged += 1,
+                Action::Conflict => match options.on_conflict {
+                    ConflictPolicy::Overwrite => report.overwritten += 1,
+                    _ => report.skipped += 1,
+                },
+            }
+        }
+        let new_collections: Vec<String> = self
+            .files
+            .keys()
+            .filter_map(|p| collection_entry(p))
+            .filter(|(name, file)| {
+                *file == COLLECTION_META
+                    && !db_root
+                        .join("collections")
+                        .join(name)
+                        .join(COLLECTION_META)
+                        .exists()
+            })
+            .map(|(name, _)| name.to_string())
+            .collect();
+        report.created_collections = new_collections.clone();
+        if options.dry_run {
+            return Ok(report);
+        }
+
+        // 2. BASE ET COLLECTIONS : index système vidé de ses documents (réindexés à l'insertion)
+        if report.created_db {
+            let mut system: JsonValue =
+                json::deserialize_from_bytes(&self.relocated(SYSTEM_INDEX, &space, &db)?)?;
+            for key in ["space", "domain"] {
+                if system.get(key).is_some() {
+                    system[key] = json_value!(space);
+                }
+            }
+            system["database"] = json_value!(db);
+            system["name"] = json_value!(format!("{}_{}", space, db));
+            if system.get("handle").is_some() {
+                system["handle"] = json_value!(format!("{}_{}", space, db));
+            }
+            if let Some(cols) = system
+                .get_mut("collections")
+                .and_then(|c| c.as_object_mut())
+            {
+                for entry in cols.values_mut() {
+                    entry["items"] = json_value!([]);
+                }
+            }
+            fs::ensure_dir_async(&db_root).await?;
+            encryption::write_json_atomic(&storage.config, &db_root.join(SYSTEM_INDEX), &system)
+                .await?;
+        }
+
+        let mut pending_indexes: Vec<(String, IndexDefinition)> = Vec::new();
+        for name in &new_collections {
+            let meta_key = format!("collections/{}/{}", name, COLLECTION_META);
+            let meta: JsonValue =
+                json::deserialize_from_bytes(&self.relocated(&meta_key, &space, &db)?)?;
+            let schema = meta
+                .get("schema")
+                .and_then(|s| s.as_str())
+                .unwrap_or_default();
+            manager.create_collection(name, schema).await?;
+
+            // Réglages de la collection (fusion, validation…) ; index recréés après les données
+            let meta_path = db_root.join(&meta_key);
+            let mut local: JsonValue = fs::read_json_async(&meta_path).await?;
+            if let (Some(local_obj), Some(obj)) = (local.as_object_mut(), meta.as_object()) {
+                for (key, value) in obj {
+                    if key != "schema" && key != "indexes" {
+                        local_obj.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+            fs::write_json_atomic_async(&meta_path, &local).await?;
+            if let Some(indexes) = meta.get("indexes") {
+                let defs: Vec<IndexDefinition> =
+                    json::deserialize_from_value(indexes.clone()).unwrap_or_default();
+                pending_indexes.extend(defs.into_iter().map(|d| (name.clone(), d)));
+            }
+        }
+
+        // 3. FICHIERS ET DOCUMENTS
+        let mut idx_mgr = IndexManager::new(storage, &space, &db);
+        for (path, action) in plan {
+            let overwrite = match action {
+                Action::Write => false,
+                Action::Unchanged => continue,
+                Action::Conflict if options.on_conflict == ConflictPolicy::Overwrite => true,
+                Action::Conflict => continue,
+            };
+            let incoming = self.relocated(path, &space, &db)?;
+            match collection_entry(path) {
+                Some((collection, _)) => {
+                    let doc: JsonValue = json::deserialize_from_bytes(&incoming)?;
+                    if overwrite {
+                        let old: JsonValue =
+                            encryption::read_json(&storage.config, &db_root.join(path)).await?;
+                        idx_mgr.remove_document(collection, &old).await?;
+                    }
+                    manager.insert_raw(collection, &doc).await?;
+                }
+                None => {
+                    let target = db_root.join(path);
+                    if let Some(parent) = target.parent() {
+                        fs::ensure_dir_async(parent).await?;
+                    }
+                    fs::write_atomic_async(&target, &incoming).await?;
+                }
+            }
+        }
+
+        for (collection, def) in pending_indexes {
+            idx_mgr
+                .create_index(&collection, &def.name, &def.kind())
+                .await?;
+        }
+
+        user_success!(
+            "INF_BUNDLE_IMPORTED",
+            json_value!({
+                "space": report.space,
+                "db": report.db,
+                "written": report.written,
+                "overwritten": report.overwritten,
+                "skipped": report.skipped
+            })
+        );
+        Ok(report)
+    }
+
+    /// Contenu d'une entrée, les URI `db://espace/base/` du projet d'origine pointant sur la cible.
+    fn relocated(&self, path: &str, space: &str, db: &str) -> RaiseResult<Vec<u8>> {
+        let Some(bytes) = self.files.get(path) else {
+            raise_error!(
+                "ERR_BUNDLE_INTEGRITY",
+                error = "Entrée attendue absente du paquet.",
+                context = json_value!({ "entries": [path] })
+            );
+        };
+        let moved = space != self.manifest.space || db != self.manifest.db;
+        if !moved || !path.ends_with(".json") {
+            return Ok(bytes.clone());
+        }
+        let from = format!("db://{}/{}/", self.manifest.space, self.manifest.db);
+        let to = format!("db://{}/{}/", space, db);
+        Ok(String::from_utf8_lossy(bytes)
+            .replace(&from, &to)
+            .into_bytes())
+    }
+}
+
+/// Collection et nom de fichier d'une entrée `collections/<nom>/<fichier>`.
+fn collection_entry(path: &str) -> Option<(&str, &str)> {
+    let mut parts = path.split('/');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some("collections"), Some(name), Some(file), None) => Some((name, file)),
+        _ => None,
+    }
+}
+
+pub(crate) fn walk_files(root: &Path) -> Vec<PathBuf> {
+    if !root.exists() {
+        return Vec::new();
+    }
+    let mut files: Vec<PathBuf> = fs::WalkDir::new(root)
+        .into_iter()
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+    files
+}
+
+pub(crate) fn relative_key(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Refuse les chemins absolus ou remontant hors de la base (zip slip).
+pub(crate) fn is_safe_entry(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('/')
+        && !name.contains('\\')
+        && name.split('/').all(|part| !part.is_empty() && part != "..")
+}
+
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = CryptoSha256::new();
+    hasher.update(bytes);
+    hex::encode(hasher.finalize())
+}
+
+fn entries_digest(entries: &OrderedMap<String, BundleEntry>) -> String {
+    let mut hasher = CryptoSha256::new();
+    for (path, entry) in entries {
+        hasher.update(path.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(entry.sha256.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::data::config::{BOOTSTRAP_DB, BOOTSTRAP_DOMAIN};
+    use crate::utils::testing::mock::insert_mock_db;
+    use crate::utils::testing::DbSandbox;
+
+    async fn source_project(storage: &StorageEngine) -> RaiseResult<CollectionsManager<'_>> {
+        let manager = CollectionsManager::new(storage, "bundle_space", "src_db");
+        DbSandbox::mock_db(&manager).await?;
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            BOOTSTRAP_DOMAIN, BOOTSTRAP_DB
+        );
+        manager
+            .create_collection(WORKFLOW_DEFINITIONS, &schema_uri)
+            .await?;
+        insert_mock_db(
+            &manager,
+            WORKFLOW_DEFINITIONS,
+            &json_value!({ "_id": "wf-1", "handle": "wf_review", "name": "Revue" }),
+        )
+        .await?;
+
+        let plugins = storage
+            .config
+            .db_root("bundle_space", "src_db")
+            .join(PLUGINS_DIR);
+        fs::ensure_dir_async(&plugins).await?;
+        fs::write_async(plugins.join("spy.wasm"), b"\0asm\x01\0\0\0").await?;
+        Ok(manager)
+    }
+
+    #[test]
+    fn test_entry_paths_are_confined() {
+        assert!(is_safe_entry("collections/dapps/a.json"));
+        assert!(!is_safe_entry("../_system.json"));
+        assert!(!is_safe_entry("/etc/passwd"));
+        assert!(!is_safe_entry("schemas//x.json"));
+        assert_eq!(
+            collection_entry("collections/dapps/a.json"),
+            Some(("dapps", "a.json"))
+        );
+        assert_eq!(collection_entry("plugins/spy.wasm"), None);
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_bundle_roundtrip_into_new_db() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let source = source_project(&sandbox.storage).await?;
+
+        let bundle = ProjectBundle::collect(&source).await?;
+        assert_eq!(bundle.manifest.summary.workflow_definitions, 1);
+        assert_eq!(bundle.manifest.summary.plugins, 1);
+
+        let loaded = ProjectBundle::from_bytes(&bundle.to_bytes()?)?;
+        let options = ImportOptions {
+            db: Some("dst_db".into()),
+            ..Default::default()
+        };
+        let report = loaded.import(&sandbox.storage, &options).await?;
+        assert!(report.created_db);
+        assert!(report
+            .created_collections
+            .contains(&WORKFLOW_DEFINITIONS.to_string()));
+
+        let target = CollectionsManager::new(&sandbox.storage, "bundle_space", "dst_db");
+        let wf = target.get_document(WORKFLOW_DEFINITIONS, "wf-1").await?;
+        assert_eq!(wf.unwrap()["name"], "Revue");
+        assert!(sandbox
+            .storage
+            .config
+            .db_root("bundle_space", "dst_db")
+            .join("plugins/spy.wasm")
+            .exists());
+
+        // Réimport à l'identique : rien à écrire
+        let again = loaded.import(&sandbox.storage, &options).await?;
+        assert_eq!(again.written, 0);
+        assert!(again.conflicts.is_empty());
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_import_conflict_policies() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let source = source_project(&sandbox.storage).await?;
+        let bundle = ProjectBundle::collect(&source).await?;
+
+        source
+            .insert_raw(
+                WORKFLOW_DEFINITIONS,
+                &json_value!({ "_id": "wf-1", "handle": "wf_review", "name": "Revue locale" }),
+            )
+            .await?;
+
+        let mut options = ImportOptions::default();
+        let Err(AppError::Structured(err)) = bundle.import(&sandbox.storage, &options).await else {
+            panic!("Un conflit aurait dû bloquer l'import");
+        };
+        assert_eq!(err.code, "ERR_BUNDLE_IMPORT_CONFLICT");
+
+        options.on_conflict = ConflictPolicy::Skip;
+        let report = bundle.import(&sandbox.storage, &options).await?;
+        assert_eq!(report.skipped, 1);
+        let local = source.get_document(WORKFLOW_DEFINITIONS, "wf-1").await?;
+        assert_eq!(local.unwrap()["name"], "Revue locale");
+
+        options.on_conflict = ConflictPolicy::Overwrite;
+        let report = bundle.import(&sandbox.storage, &options).await?;
+        assert_eq!(report.overwritten, 1);
+        let restored = source.get_document(WORKFLOW_DEFINITIONS, "wf-1").await?;
+        assert_eq!(restored.unwrap()["name"], "Revue");
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_tampered_bundle_is_rejected() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let source = source_project(&sandbox.storage).await?;
+        let mut bundle = ProjectBundle::collect(&source).await?;
+        bundle
+            .files
+            .insert("plugins/spy.wasm".into(), b"tampered".to_vec());
+
+        let Err(AppError::Structured(err)) = ProjectBundle::from_bytes(&bundle.to_bytes()?) else {
+            panic!("Le paquet altéré aurait dû être rejeté");
+        };
+        assert_eq!(err.code, "ERR_BUNDLE_INTEGRITY");
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+// FICHIER : src-tauri/src/json_db/collections/acl.rs
+
+//! Contrôle d'accès par collection : rôles autorisés en lecture et en écriture, déclarés
+//! dans `_system.json` (`acl.<motif>`) et appliqués par `CollectionsManager`.
+//! Les contrôles ne portent que sur les tâches exécutées au nom d'une identité
+//! (`with_access`) ; les traitements internes (WAL, TTL, vues…) restent de confiance.
+
+use super::audit_fields::with_actor;
+use crate::utils::context::{AccessIdentity, AccessRole};
+use crate::utils::prelude::*;
+
+/// Clé racine de `_system.json` portant les règles d'accès.
+pub const SYSTEM_ACL_KEY: &str = "acl";
+
+tokio::task_local! {
+    static ACCESS_IDENTITY: AccessIdentity;
+}
+
+/// Exécute `fut` au nom de `identity` : chaque accès à une collection est contrôlé, et les
+/// documents écrits portent son nom (`with_actor`). Le contexte ne traverse pas les `spawn`.
+pub async fn with_access<F: std::future::Future>(identity: AccessIdentity, fut: F) -> F::Output {
+    let actor = identity.user.clone();
+    ACCESS_IDENTITY
+        .scope(identity, with_actor(actor, fut))
+        .await
+}
+
+/// Variante pour un appelant dont l'identité est facultative (contrôle d'accès désactivé).
+pub async fn with_optional_access<F: std::future::Future>(
+    identity: Option<AccessIdentity>,
+    fut: F,
+) -> F::Output {
+    match identity {
+        Some(identity) => with_access(identity, fut).await,
+        None => fut.await,
+    }
+}
+
+/// Identité contrôlée pour la tâche courante (`None` : appel interne de confiance).
+pub fn current_access() -> Option<AccessIdentity> {
+    ACCESS_IDENTITY.try_with(|identity| identity.clone()).ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessMode {
+    Read,
+    Write,
+}
+
+/// Rôles autorisés sur les collections couvertes par un motif.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+#[serde(default)]
+pub struct CollectionGrant {
+    pub read: Vec<AccessRole>,
+    pub write: Vec<AccessRole>,
+}
+
+/// Règles d'une base, par motif : nom exact, préfixe (`_sys*`) ou `*`.
+/// Une collection couverte par aucun motif est ouverte à tous.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct CollectionAcl {
+    pub rules: OrderedMap<String, CollectionGrant>,
+}
+
+impl CollectionAcl {
+    pub fn from_index(index: &JsonValue) -> Self {
+        let rules = index
+            .get(SYSTEM_ACL_KEY)
+            .cloned()
+            .and_then(|v| json::deserialize_from_value(v).ok())
+            .unwrap_or_default();
+        Self { rules }
+    }
+
+    /// Règle applicable : nom exact, sinon préfixe le plus long, sinon `*`.
+    pub fn grant_for(&self, collection: &str) -> Option<&CollectionGrant> {
+        if let Some(grant) = self.rules.get(collection) {
+            return Some(grant);
+        }
+        self.rules
+            .iter()
+            .filter_map(|(pattern, grant)| {
+                let prefix = pattern.strip_suffix('*')?;
+                collection
+                    .starts_with(prefix)
+                    .then_some((prefix.len(), grant))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, grant)| grant)
+    }
+
+    /// Les administrateurs passent toujours ; sinon un des rôles doit figurer dans la règle.
+    pub fn permits(&self, identity: &AccessIdentity, collection: &str, mode: AccessMode) -> bool {
+        if identity.roles.contains(&AccessRole::Admin) {
+            return true;
+        }
+        let Some(grant) = self.grant_for(collection) else {
+            return true;
+        };
+        let allowed = match mode {
+            AccessMode::Read => &grant.read,
+            AccessMode::Write => &grant.write,
+        };
+        identity.roles.iter().any(|role| allowed.contains(role))
+    }
+
+    pub fn check(
+        &self,
+        identity: &AccessIdentity,
+        collection: &str,
+        mode: AccessMode,
+    ) -> RaiseResult<()> {
+        if self.permits(identity, collection, mode) {
+            return Ok(());
+        }
+        raise_error!(
+            "ERR_DB_ACCESS_DENIED",
+            error = format!(
+                "Accès {} refusé sur la collection '{}'.",
+                match mode {
+                    AccessMode::Read => "en lecture",
+                    AccessMode::Write => "en écriture",
+                },
+                collection
+            ),
+            context = json_value!({
+                "collection": collection,
+                "mode": mode,
+                "user": identity.user,
+                "roles": identity.roles
+            })
+        );
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::context::IdentitySource;
+
+    fn identity(role: AccessRole) -> AccessIdentity {
+        AccessIdentity {
+            user: "u".into(),
+            roles: vec![role],
+            source: IdentitySource::Local,
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn test_acl_pattern_resolution() {
+        let acl = CollectionAcl::from_index(&json_value!({
+            "acl": {
+                "*": { "read": ["viewer", "editor"], "write": ["editor"] },
+                "_*": { "read": ["editor"], "write": [] },
+                "requirements": { "read": ["viewer", "editor", "validator"], "write": ["editor", "validator"] }
+            }
+        }));
+        let viewer = identity(AccessRole::Viewer);
+        let editor = identity(AccessRole::Editor);
+
+        assert!(acl.permits(&viewer, "actors", AccessMode::Read));
+        assert!(!acl.permits(&viewer, "actors", AccessMode::Write));
+        assert!(acl.permits(&editor, "actors", AccessMode::Write));
+        // Le préfixe le plus long l'emporte sur `*`
+        assert!(!acl.permits(&viewer, "_settings", AccessMode::Read));
+        assert!(!acl.permits(&editor, "_settings", AccessMode::Write));
+        assert!(acl.permits(&identity(AccessRole::Admin), "_settings", AccessMode::Write));
+        // Le nom exact l'emporte sur les motifs
+        assert!(acl.permits(
+            &identity(AccessRole::Validator),
+            "requirements",
+            AccessMode::Write
+        ));
+
+        // Sans règle : tout est ouvert
+        assert!(CollectionAcl::default().permits(&viewer, "actors", AccessMode::Write));
+    }
+}
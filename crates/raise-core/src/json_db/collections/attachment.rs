@@ -0,0 +1,281 @@
+// FICHIER : src-tauri/src/json_db/collections/attachment.rs
+
+//! Pièces jointes binaires (PDF, images de preuve…) rattachées à un document.
+//! Le contenu est rangé par empreinte sous `{collection}/_attachments/{_id}/{sha256}` (scellé
+//! si le stockage est chiffré) ; le document porte la table `attachments` (nom → empreinte,
+//! taille, type), champ métier inclus dans les commits ancrés sur la blockchain.
+
+use crate::json_db::storage::encryption;
+use crate::json_db::storage::JsonDbConfig;
+use crate::utils::prelude::*;
+
+/// Champ du document listant ses pièces jointes.
+pub const ATTACHMENTS_FIELD: &str = "attachments";
+/// Sous-dossier de la collection hébergeant les contenus.
+pub const ATTACHMENTS_DIR: &str = "_attachments";
+/// Taille des blocs rendus par `AttachmentReader::next_chunk`.
+pub const ATTACHMENT_CHUNK_SIZE: usize = 64 * 1024;
+
+const MAX_NAME_LEN: usize = 255;
+const HASH_TAG: &str = "sha256";
+
+/// Entrée de la table `attachments` d'un document.
+#[derive(Debug, Clone, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentMeta {
+    /// Empreinte du contenu en clair (`sha256:<hex>`).
+    pub hash: String,
+    pub size: u64,
+    pub content_type: String,
+}
+
+impl AttachmentMeta {
+    pub fn describe(name: &str, bytes: &[u8]) -> Self {
+        let mut hasher = CryptoSha256::new();
+        hasher.update(bytes);
+        Self {
+            hash: format!("{}:{}", HASH_TAG, hex::encode(hasher.finalize())),
+            size: bytes.len() as u64,
+            content_type: content_type_for(name).to_string(),
+        }
+    }
+
+    /// Condensat hexadécimal, qui nomme le fichier de contenu.
+    pub fn digest(&self) -> &str {
+        self.hash
+            .strip_prefix(HASH_TAG)
+            .and_then(|d| d.strip_prefix(':'))
+            .unwrap_or(&self.hash)
+    }
+}
+
+/// Type MIME déduit de l'extension ; `application/octet-stream` par défaut.
+pub fn content_type_for(name: &str) -> &'static str {
+    let ext = name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "txt" | "log" => "text/plain",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "zip" => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Un nom de pièce jointe est une clé de la table `attachments`, jamais un chemin.
+pub fn validate_name(name: &str) -> RaiseResult<()> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_NAME_LEN
+        && !name.starts_with('.')
+        && !name
+            .chars()
+            .any(|c| c == '/' || c == '\\' || c.is_control());
+    if !valid {
+        raise_error!(
+            "ERR_DB_ATTACHMENT_NAME_INVALID",
+            error = "Nom de pièce jointe invalide : ni séparateur de chemin, ni point initial, 255 octets au plus.",
+            context = json_value!({ "name": name })
+        );
+    }
+    Ok(())
+}
+
+/// Dossier des contenus d'un document : `{collection}/_attachments/{_id}`.
+pub fn attachments_dir(
+    cfg: &JsonDbConfig,
+    space: &str,
+    db: &str,
+    collection: &str,
+    id: &str,
+) -> PathBuf {
+    cfg.db_collection_path(space, db, collection)
+        .join(ATTACHMENTS_DIR)
+        .join(id)
+}
+
+/// Table `attachments` d'un document (vide si absente ou illisible).
+pub fn attachments_of(doc: &JsonValue) -> OrderedMap<String, AttachmentMeta> {
+    doc.get(ATTACHMENTS_FIELD)
+        .and_then(|v| json::deserialize_from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// Écrit un contenu (idempotent : un contenu identique n'est pas réécrit).
+pub async fn store_blob(
+    cfg: &JsonDbConfig,
+    dir: &Path,
+    meta: &AttachmentMeta,
+    bytes: &[u8],
+) -> RaiseResult<()> {
+    let path = dir.join(meta.digest());
+    if fs::exists_async(&path).await {
+        return Ok(());
+    }
+    fs::ensure_dir_async(dir).await?;
+    match &cfg.encryption {
+        Some(cipher) => fs::write_atomic_async(&path, &cipher.seal(bytes)?).await,
+        None => fs::write_atomic_async(&path, bytes).await,
+    }
+}
+
+/// Supprime un contenu s'il n'est plus référencé par la table du document.
+pub async fn release_blob(
+    dir: &Path,
+    meta: &AttachmentMeta,
+    remaining: &OrderedMap<String, AttachmentMeta>,
+) -> RaiseResult<()> {
+    let path = dir.join(meta.digest());
+    if remaining.values().any(|m| m.hash == meta.hash) || !fs::exists_async(&path).await {
+        return Ok(());
+    }
+    fs::remove_file_async(&path).await
+}
+
+enum ReaderSource {
+    File(tokio::fs::File),
+    /// Contenu scellé : déchiffré en bloc (AES-GCM n'authentifie que le message entier).
+    Memory(Vec<u8>, usize),
+}
+
+/// Lecture par blocs d'une pièce jointe ; l'empreinte est recalculée au fil de l'eau et
+/// vérifiée sur le dernier bloc (`ERR_DB_ATTACHMENT_CORRUPTED` en cas d'écart).
+pub struct AttachmentReader {
+    meta: AttachmentMeta,
+    source: ReaderSource,
+    hasher: CryptoSha256,
+    read: u64,
+    done: bool,
+}
+
+impl AttachmentReader {
+    pub async fn open(cfg: &JsonDbConfig, dir: &Path, meta: AttachmentMeta) -> RaiseResult<Self> {
+        let path = dir.join(meta.digest());
+        if !fs::exists_async(&path).await {
+            raise_error!(
+                "ERR_DB_ATTACHMENT_CONTENT_MISSING",
+                error = "Contenu de la pièce jointe absent du stockage.",
+                context = json_value!({ "path": path, "hash": meta.hash })
+            );
+        }
+        let source = match &cfg.encryption {
+            Some(_) => ReaderSource::Memory(encryption::read_plain(cfg, &path).await?, 0),
+            None => ReaderSource::File(fs::open_async(&path).await?),
+        };
+        Ok(Self {
+            meta,
+            source,
+            hasher: CryptoSha256::new(),
+            read: 0,
+            done: false,
+        })
+    }
+
+    pub fn meta(&self) -> &AttachmentMeta {
+        &self.meta
+    }
+
+    /// Bloc suivant (au plus `ATTACHMENT_CHUNK_SIZE` octets), `None` en fin de contenu.
+    pub async fn next_chunk(&mut self) -> RaiseResult<Option<Vec<u8>>> {
+        if self.done {
+            return Ok(None);
+        }
+        let chunk = match &mut self.source {
+            ReaderSource::File(file) => {
+                let mut buf = vec![0u8; ATTACHMENT_CHUNK_SIZE];
+                let n = match file.read(&mut buf).await {
+                    Ok(n) => n,
+                    Err(e) => raise_error!(
+                        "ERR_DB_ATTACHMENT_READ_FAILED",
+                        error = e,
+                        context = json_value!({ "hash": self.meta.hash })
+                    ),
+                };
+                buf.truncate(n);
+                buf
+            }
+            ReaderSource::Memory(bytes, pos) => {
+                let end = (*pos + ATTACHMENT_CHUNK_SIZE).min(bytes.len());
+                let chunk = bytes[*pos..end].to_vec();
+                *pos = end;
+                chunk
+            }
+        };
+
+        if chunk.is_empty() {
+            self.done = true;
+            self.verify()?;
+            return Ok(None);
+        }
+        self.hasher.update(&chunk);
+        self.read += chunk.len() as u64;
+        Ok(Some(chunk))
+    }
+
+    /// Contenu complet, vérifié.
+    pub async fn read_to_end(mut self) -> RaiseResult<Vec<u8>> {
+        let mut out = Vec::with_capacity(self.meta.size as usize);
+        while let Some(chunk) = self.next_chunk().await? {
+            out.extend_from_slice(&chunk);
+        }
+        Ok(out)
+    }
+
+    fn verify(&self) -> RaiseResult<()> {
+        let actual = format!(
+            "{}:{}",
+            HASH_TAG,
+            hex::encode(self.hasher.clone().finalize())
+        );
+        if actual != self.meta.hash || self.read != self.meta.size {
+            raise_error!(
+                "ERR_DB_ATTACHMENT_CORRUPTED",
+                error =
+                    "Le contenu lu ne correspond pas à l'empreinte enregistrée dans le document.",
+                context = json_value!({
+                    "expected": self.meta.hash,
+                    "actual": actual,
+                    "expected_size": self.meta.size,
+                    "actual_size": self.read
+                })
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_name_and_meta() -> RaiseResult<()> {
+        validate_name("rapport final.PDF")?;
+        for bad in ["", "../x.pdf", "a/b.png", ".hidden", "a\\b"] {
+            assert!(validate_name(bad).is_err(), "{bad:?} devrait être refusé");
+        }
+
+        let meta = AttachmentMeta::describe("scan.JPG", b"abc");
+        assert_eq!(
+            meta.hash,
+            "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(meta.digest().len(), 64);
+        assert_eq!(meta.size, 3);
+        assert_eq!(meta.content_type, "image/jpeg");
+        assert_eq!(content_type_for("blob"), "application/octet-stream");
+
+        let doc = json_value!({ ATTACHMENTS_FIELD: { "scan.JPG": meta.clone() } });
+        assert_eq!(attachments_of(&doc).get("scan.JPG"), Some(&meta));
+        assert!(attachments_of(&json_value!({})).is_empty());
+        Ok(())
+    }
+}
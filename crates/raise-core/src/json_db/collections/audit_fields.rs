@@ -0,0 +1,216 @@
+// FICHIER : src-tauri/src/json_db/collections/audit_fields.rs
+
+//! Champs d'audit posés par le stockage sur chaque écriture préparée (`prepare_document`) :
+//! dates de création / modification dans le fuseau de la collection, et auteur lu dans le
+//! contexte d'identité de la tâche (`with_actor`). Politique par collection dans `_system.json`
+//! (`collections.<nom>.audit`) ; les champs calculés par les schémas (`x_compute`) sont écrasés.
+
+use crate::utils::prelude::*;
+
+/// Clé de l'entrée collection de `_system.json` portant la politique d'audit.
+pub const SYSTEM_AUDIT_KEY: &str = "audit";
+
+tokio::task_local! {
+    static AUDIT_ACTOR: String;
+}
+
+/// Exécute `fut` au nom de `actor` : les documents écrits pendant son exécution en portent
+/// l'identité. Le contexte ne traverse pas les `spawn`.
+pub async fn with_actor<F: std::future::Future>(actor: impl Into<String>, fut: F) -> F::Output {
+    AUDIT_ACTOR.scope(actor.into(), fut).await
+}
+
+/// Identité injectée pour la tâche courante, s'il y en a une.
+pub fn current_actor() -> Option<String> {
+    AUDIT_ACTOR.try_with(|actor| actor.clone()).ok()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(default)]
+pub struct AuditPolicy {
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+    /// `None` : auteur non enregistré.
+    pub created_by: Option<String>,
+    pub updated_by: Option<String>,
+    /// `UTC`, `local` ou décalage fixe (`+02:00`).
+    pub timezone: String,
+}
+
+impl Default for AuditPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            created_at: "_created_at".to_string(),
+            updated_at: "_updated_at".to_string(),
+            created_by: Some("_created_by".to_string()),
+            updated_by: Some("_updated_by".to_string()),
+            timezone: "UTC".to_string(),
+        }
+    }
+}
+
+enum AuditZone {
+    Utc,
+    Local,
+    Fixed(UtcOffset),
+}
+
+impl AuditPolicy {
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// Vérifie le fuseau et les noms de champs avant enregistrement.
+    pub fn validate(&self) -> RaiseResult<()> {
+        self.zone()?;
+        let names = [
+            Some(&self.created_at),
+            Some(&self.updated_at),
+            self.created_by.as_ref(),
+            self.updated_by.as_ref(),
+        ];
+        if names.into_iter().flatten().any(|n| n.trim().is_empty()) {
+            raise_error!(
+                "ERR_DB_AUDIT_POLICY_INVALID",
+                error = "Les noms des champs d'audit ne peuvent pas être vides.",
+                context = json_value!({ "policy": self })
+            );
+        }
+        Ok(())
+    }
+
+    fn zone(&self) -> RaiseResult<AuditZone> {
+        match self.timezone.trim() {
+            "" | "Z" | "UTC" | "utc" => Ok(AuditZone::Utc),
+            "local" => Ok(AuditZone::Local),
+            offset => match offset.parse::<UtcOffset>() {
+                Ok(fixed) => Ok(AuditZone::Fixed(fixed)),
+                Err(_) => raise_error!(
+                    "ERR_DB_AUDIT_TIMEZONE_INVALID",
+                    error = format!("Fuseau d'audit invalide : '{}'", offset),
+                    context = json_value!({ "supported": ["UTC", "local", "+HH:MM"] })
+                ),
+            },
+        }
+    }
+
+    /// Horodatage RFC3339 de l'instant présent, avec le décalage du fuseau configuré.
+    pub fn now(&self) -> String {
+        let now = UtcClock::now();
+        match self.zone() {
+            Ok(AuditZone::Local) => now.with_timezone(&LocalClock).to_rfc3339(),
+            Ok(AuditZone::Fixed(offset)) => now.with_timezone(&offset).to_rfc3339(),
+            _ => now.to_rfc3339(),
+        }
+    }
+
+    /// Pose les champs de création (s'ils manquent) et de modification (toujours).
+    pub fn stamp(&self, doc: &mut JsonValue) {
+        if !self.enabled {
+            return;
+        }
+        let Some(obj) = doc.as_object_mut() else {
+            return;
+        };
+        let now = JsonValue::String(self.now());
+        let actor = current_actor().map(JsonValue::String);
+
+        obj.entry(self.created_at.clone()).or_insert(now.clone());
+        obj.insert(self.updated_at.clone(), now);
+        if let Some(actor) = actor {
+            if let Some(field) = &self.created_by {
+                obj.entry(field.clone()).or_insert(actor.clone());
+            }
+            if let Some(field) = &self.updated_by {
+                obj.insert(field.clone(), actor);
+            }
+        }
+    }
+
+    /// Reprend les champs de création de `previous` (une mise à jour ne les réécrit pas).
+    pub fn keep_creation(&self, previous: &JsonValue, doc: &mut JsonValue) {
+        if !self.enabled {
+            return;
+        }
+        let Some(obj) = doc.as_object_mut() else {
+            return;
+        };
+        for field in std::iter::once(&self.created_at).chain(self.created_by.as_ref()) {
+            if let Some(value) = previous.get(field) {
+                obj.insert(field.clone(), value.clone());
+            }
+        }
+    }
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_test]
+    async fn test_stamp_with_injected_actor() {
+        let policy = AuditPolicy::default();
+        let mut doc = json_value!({ "name": "Pump" });
+        with_actor("alice", async { policy.stamp(&mut doc) }).await;
+        assert_eq!(doc["_created_by"], "alice");
+        assert_eq!(doc["_updated_by"], "alice");
+        assert_eq!(doc["_created_at"], doc["_updated_at"]);
+
+        let created_at = doc["_created_at"].clone();
+        with_actor("bob", async { policy.stamp(&mut doc) }).await;
+        assert_eq!(doc["_created_by"], "alice");
+        assert_eq!(doc["_updated_by"], "bob");
+        assert_eq!(doc["_created_at"], created_at);
+
+        // Sans identité injectée : horodatage seul
+        let mut anonymous = json_value!({});
+        policy.stamp(&mut anonymous);
+        assert!(anonymous.get("_created_at").is_some());
+        assert!(anonymous.get("_created_by").is_none());
+        assert!(current_actor().is_none());
+    }
+
+    #[test]
+    fn test_timezone_and_policy_validation() -> RaiseResult<()> {
+        let paris = AuditPolicy {
+            timezone: "+02:00".into(),
+            ..AuditPolicy::default()
+        };
+        paris.validate()?;
+        assert!(paris.now().ends_with("+02:00"));
+        assert!(AuditPolicy::default().now().ends_with("+00:00"));
+
+        let bad_zone = AuditPolicy {
+            timezone: "Mars/Olympus".into(),
+            ..AuditPolicy::default()
+        };
+        assert!(bad_zone.validate().is_err());
+        let bad_field = AuditPolicy {
+            created_by: Some(" ".into()),
+            ..AuditPolicy::default()
+        };
+        assert!(bad_field.validate().is_err());
+
+        let mut doc = json_value!({ "_updated_at": "old" });
+        AuditPolicy::disabled().stamp(&mut doc);
+        assert_eq!(doc, json_value!({ "_updated_at": "old" }));
+
+        let mut replaced = json_value!({ "name": "new" });
+        AuditPolicy::default().keep_creation(
+            &json_value!({ "_created_at": "t0", "_created_by": "alice" }),
+            &mut replaced,
+        );
+        assert_eq!(replaced["_created_at"], "t0");
+        assert_eq!(replaced["_created_by"], "alice");
+        Ok(())
+    }
+}
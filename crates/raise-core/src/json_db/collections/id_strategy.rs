@@ -0,0 +1,225 @@
+// FICHIER : src-tauri/src/json_db/collections/id_strategy.rs
+
+//! Stratégies de génération des `_id` par collection, stockées dans `_system.json`
+//! (`collections.<nom>.id_strategy`) et appliquées par `prepare_document` aux documents sans `_id`.
+//! Identifiants triables (`uuid_v7`, `ulid`), stables (`content_hash`) ou lisibles (`SA-FUNC-0042`).
+
+use crate::utils::prelude::*;
+
+/// Clé de l'entrée collection de `_system.json` portant la stratégie.
+pub const SYSTEM_ID_STRATEGY_KEY: &str = "id_strategy";
+/// Clé de l'entrée collection de `_system.json` portant le dernier numéro attribué (`prefixed`).
+pub const SYSTEM_ID_SEQUENCE_KEY: &str = "id_sequence";
+
+/// Alphabet Crockford Base32 (ULID).
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const DEFAULT_PREFIX_WIDTH: usize = 4;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IdStrategy {
+    /// UUID v4 aléatoire, via le `x_compute` du schéma (comportement historique).
+    #[default]
+    UuidV4,
+    /// UUID v7 : horodatage milliseconde en tête, triable chronologiquement.
+    UuidV7,
+    /// ULID : 26 caractères Crockford Base32, triable chronologiquement.
+    Ulid,
+    /// SHA-256 du contenu métier (hors champs `_*` et `$*`) : même contenu, même identifiant.
+    ContentHash,
+    /// Préfixe + numéro séquentiel complété de zéros (`SA-FUNC-0042`).
+    Prefixed {
+        prefix: String,
+        #[serde(default = "default_prefix_width")]
+        width: usize,
+    },
+}
+
+fn default_prefix_width() -> usize {
+    DEFAULT_PREFIX_WIDTH
+}
+
+impl IdStrategy {
+    /// Forme textuelle : `uuid_v4`, `uuid_v7`, `ulid`, `content_hash`, `prefixed:<PRÉFIXE>[:<largeur>]`.
+    pub fn parse(value: &str) -> RaiseResult<Self> {
+        let value = value.trim();
+        if let Some(spec) = value.strip_prefix("prefixed:") {
+            let (prefix, width) = match spec.rsplit_once(':') {
+                Some((prefix, width)) => match width.parse::<usize>() {
+                    Ok(width) => (prefix, width),
+                    Err(_) => (spec, DEFAULT_PREFIX_WIDTH),
+                },
+                None => (spec, DEFAULT_PREFIX_WIDTH),
+            };
+            if prefix.is_empty() {
+                raise_error!(
+                    "ERR_DB_ID_STRATEGY_UNKNOWN",
+                    error = "La stratégie 'prefixed' requiert un préfixe (ex: prefixed:SA-FUNC).",
+                    context = json_value!({ "value": value })
+                );
+            }
+            return Ok(Self::Prefixed {
+                prefix: prefix.to_string(),
+                width,
+            });
+        }
+        match value.to_lowercase().replace('-', "_").as_str() {
+            "uuid_v4" | "uuidv4" => Ok(Self::UuidV4),
+            "uuid_v7" | "uuidv7" => Ok(Self::UuidV7),
+            "ulid" => Ok(Self::Ulid),
+            "content_hash" => Ok(Self::ContentHash),
+            _ => raise_error!(
+                "ERR_DB_ID_STRATEGY_UNKNOWN",
+                error = format!("Stratégie d'identifiant inconnue : '{}'", value),
+                context = json_value!({
+                    "supported": ["uuid_v4", "uuid_v7", "ulid", "content_hash", "prefixed:<PRÉFIXE>[:<largeur>]"]
+                })
+            ),
+        }
+    }
+
+    /// Identifiant d'un document pour les stratégies sans état (`None` pour `prefixed`,
+    /// qui dépend du compteur de la collection, et pour `uuid_v4`, laissé au schéma).
+    pub fn generate(&self, doc: &JsonValue) -> Option<String> {
+        match self {
+            Self::UuidV4 | Self::Prefixed { .. } => None,
+            Self::UuidV7 => Some(uuid_v7()),
+            Self::Ulid => Some(ulid()),
+            Self::ContentHash => Some(content_hash(doc)),
+        }
+    }
+
+    /// Identifiant `prefixed` du numéro `sequence`.
+    pub fn format_sequence(prefix: &str, width: usize, sequence: u64) -> String {
+        format!("{}-{:0width$}", prefix, sequence, width = width)
+    }
+}
+
+/// UUID v7 (RFC 9562) : 48 bits de millisecondes Unix puis 74 bits aléatoires.
+pub fn uuid_v7() -> String {
+    let millis = UtcClock::now().timestamp_millis().max(0) as u64;
+    let mut bytes = rand::random::<u128>().to_be_bytes();
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    bytes[6] = (bytes[6] & 0x0F) | 0x70;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    UniqueId::from_bytes(bytes).to_string()
+}
+
+/// ULID : 48 bits de millisecondes Unix puis 80 bits aléatoires, en Crockford Base32.
+pub fn ulid() -> String {
+    let millis = UtcClock::now().timestamp_millis().max(0) as u128 & ((1 << 48) - 1);
+    let random = rand::random::<u128>() & ((1 << 80) - 1);
+    encode_crockford((millis << 80) | random)
+}
+
+fn encode_crockford(value: u128) -> String {
+    (0..26)
+        .map(|i| {
+            let shift = 125 - 5 * i;
+            CROCKFORD[((value >> shift) & 0x1F) as usize] as char
+        })
+        .collect()
+}
+
+/// Empreinte du contenu métier, indépendante de l'ordre des clés.
+pub fn content_hash(doc: &JsonValue) -> String {
+    let business = match doc {
+        JsonValue::Object(obj) => JsonValue::Object(
+            obj.iter()
+                .filter(|(k, _)| !k.starts_with('_') && !k.starts_with('$'))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        ),
+        other => other.clone(),
+    };
+    let mut hasher = CryptoSha256::new();
+    hasher.update(canonical_json(&business));
+    hex::encode(hasher.finalize())
+}
+
+fn canonical_json(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Object(obj) => {
+            let mut keys: Vec<&String> = obj.keys().collect();
+            keys.sort();
+            let fields: Vec<String> = keys
+                .into_iter()
+                .map(|k| {
+                    format!(
+                        "{}:{}",
+                        JsonValue::String(k.clone()),
+                        canonical_json(&obj[k])
+                    )
+                })
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        JsonValue::Array(items) => {
+            let items: Vec<String> = items.iter().map(canonical_json).collect();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_serialize() -> RaiseResult<()> {
+        assert_eq!(IdStrategy::parse("ULID")?, IdStrategy::Ulid);
+        assert_eq!(IdStrategy::parse("uuid-v7")?, IdStrategy::UuidV7);
+        assert_eq!(
+            IdStrategy::parse("prefixed:SA-FUNC")?,
+            IdStrategy::Prefixed {
+                prefix: "SA-FUNC".into(),
+                width: 4
+            }
+        );
+        assert_eq!(
+            IdStrategy::parse("prefixed:REQ:6")?,
+            IdStrategy::Prefixed {
+                prefix: "REQ".into(),
+                width: 6
+            }
+        );
+        assert!(IdStrategy::parse("serial").is_err());
+
+        let stored = json::serialize_to_value(IdStrategy::parse("prefixed:SA-FUNC")?)?;
+        assert_eq!(
+            stored,
+            json_value!({ "kind": "prefixed", "prefix": "SA-FUNC", "width": 4 })
+        );
+        let back: IdStrategy = json::deserialize_from_value(json_value!({ "kind": "ulid" }))?;
+        assert_eq!(back, IdStrategy::Ulid);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_ids_are_sortable_or_stable() {
+        let u7 = uuid_v7();
+        assert_eq!(
+            UniqueId::parse_str(&u7).map(|u| u.get_version_num()).ok(),
+            Some(7)
+        );
+
+        let (a, b) = (ulid(), ulid());
+        assert_eq!(a.len(), 26);
+        assert!(a[..10] <= b[..10], "horodatage en tête");
+
+        let h1 = content_hash(&json_value!({ "name": "Pump", "tags": ["a"], "_id": "x" }));
+        let h2 = content_hash(&json_value!({ "tags": ["a"], "name": "Pump", "_created_at": "t" }));
+        assert_eq!(h1, h2);
+        assert_ne!(h1, content_hash(&json_value!({ "name": "Valve" })));
+
+        assert_eq!(
+            IdStrategy::format_sequence("SA-FUNC", 4, 42),
+            "SA-FUNC-0042"
+        );
+    }
+}
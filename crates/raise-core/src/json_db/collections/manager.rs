@@ -5,10 +5,24 @@ use crate::json_db::indexes::IndexManager;
 use crate::json_db::jsonld::{JsonLdProcessor, VocabularyRegistry};
 use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
 use crate::json_db::schema::ddl::DdlHandler;
+use crate::json_db::schema::strictness::{ValidationMode, SYSTEM_VALIDATION_MODE_KEY};
+use crate::json_db::schema::{introspection, references};
 use crate::json_db::schema::{SchemaRegistry, SchemaValidator};
-use crate::json_db::storage::{file_storage, StorageEngine};
-
+use crate::json_db::storage::{encryption, file_storage, StorageEngine};
+use crate::json_db::transactions::changes::{self, ChangeEvent, ChangeKind, ChangeStream};
+use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::transactions::{Operation, TransactionRequest};
+use crate::utils::context::AccessRole;
+
+use super::acl::{current_access, AccessMode, CollectionAcl, CollectionGrant, SYSTEM_ACL_KEY};
+use super::attachment::{self, AttachmentMeta, AttachmentReader, ATTACHMENTS_FIELD};
+use super::audit_fields::{AuditPolicy, SYSTEM_AUDIT_KEY};
 use super::collection;
+use super::id_strategy::{IdStrategy, SYSTEM_ID_SEQUENCE_KEY, SYSTEM_ID_STRATEGY_KEY};
+use super::merge::{MergeStrategy, META_MERGE_STRATEGY_KEY};
+use super::patch::DocumentPatch;
+use super::revision;
+use super::ttl::{TtlPolicy, META_TTL_KEY};
 
 pub enum EntityIdentity {
     Id(String),
@@ -22,6 +36,24 @@ pub struct CollectionsManager<'a> {
     pub db: String,
 }
 
+/// Taille des lots validés par `insert_many` entre deux notifications de progression.
+pub const INSERT_MANY_BATCH_SIZE: usize = 500;
+
+/// Registre et validateur compilés pour un schéma.
+struct CompiledSchema {
+    uri: String,
+    registry: SchemaRegistry,
+    validator: SchemaValidator,
+}
+
+/// Réglages d'une collection partagés par les documents d'une même préparation.
+struct PreparationContext {
+    collection_uri: Option<String>,
+    validation_mode: ValidationMode,
+    audit: AuditPolicy,
+    compiled: Option<CompiledSchema>,
+}
+
 pub struct SystemIndexTx<'a> {
     pub manager: &'a CollectionsManager<'a>,
     pub document: JsonValue,
@@ -106,6 +138,131 @@ impl<'a> CollectionsManager<'a> {
             .await
     }
 
+    /// Clone la base vers `dst_space/dst_db` (fork d'une étude « what-if ») : copie cohérente
+    /// prise sous le verrou d'index, URIs internes remappées vers la cible.
+    pub async fn clone_db(&self, dst_space: &str, dst_db: &str) -> RaiseResult<usize> {
+        let index = self.load_index().await?;
+        if let Some(collections) = index.get("collections").and_then(|c| c.as_object()) {
+            for name in collections.keys() {
+                self.authorize(name, AccessMode::Read).await?;
+            }
+        }
+
+        let lock = self.storage.get_index_lock(&self.space, &self.db)?;
+        let files = {
+            let _guard = lock.lock().await;
+            file_storage::clone_db(
+                &self.storage.config,
+                &self.space,
+                &self.db,
+                dst_space,
+                dst_db,
+            )
+            .await?
+        };
+        user_success!(
+            "INF_DB_CLONED",
+            json_value!({
+                "source": format!("{}/{}", self.space, self.db),
+                "target": format!("{}/{}", dst_space, dst_db),
+                "files": files
+            })
+        );
+        Ok(files)
+    }
+
+    /// Copie une collection vers une base existante (éventuellement sous un autre nom) :
+    /// documents, réglages et index. Un schéma propre à la base source est remappé vers la
+    /// cible, et y est recopié s'il manque.
+    pub async fn copy_collection(
+        &self,
+        collection: &str,
+        dst_space: &str,
+        dst_db: &str,
+        dst_collection: Option<&str>,
+    ) -> RaiseResult<usize> {
+        let dst_name = dst_collection.unwrap_or(collection);
+        let target = CollectionsManager::new(self.storage, dst_space, dst_db);
+        self.authorize(collection, AccessMode::Read).await?;
+        target.authorize(dst_name, AccessMode::Write).await?;
+        let target_index = target.load_index().await?;
+        if target_index["collections"].get(dst_name).is_some() {
+            raise_error!(
+                "ERR_DB_COLLECTION_ALREADY_EXISTS",
+                error = format!(
+                    "La collection '{}' existe déjà dans '{}/{}'.",
+                    dst_name, dst_space, dst_db
+                ),
+                context = json_value!({ "action": "copy_collection" })
+            );
+        }
+
+        // 1. Remappage du schéma (avant les verrous : `create_schema_def` prend celui de la cible)
+        let source_prefix = file_storage::db_uri_prefix(&self.space, &self.db);
+        let target_prefix = file_storage::db_uri_prefix(dst_space, dst_db);
+        let schema_uri = self.collection_schema_uri(collection).await;
+        let target_schema = schema_uri
+            .as_deref()
+            .map(|uri| uri.replacen(&source_prefix, &target_prefix, 1));
+        if let (Some(uri), Some(remapped)) = (&schema_uri, &target_schema) {
+            if uri != remapped && target.get_schema_def(remapped).await.is_err() {
+                let mut schema = self.get_schema_def(uri).await?;
+                json::replace_uri_in_json(&mut schema, &source_prefix, &target_prefix);
+                target.create_schema_def(remapped, schema).await?;
+            }
+        }
+
+        // 2. Verrous pris dans un ordre fixe : deux copies croisées ne s'interbloquent pas
+        let same_db = self.space == dst_space && self.db == dst_db;
+        let source_first = (self.space.as_str(), self.db.as_str()) <= (dst_space, dst_db);
+        let source_lock = self.storage.get_index_lock(&self.space, &self.db)?;
+        let target_lock = self.storage.get_index_lock(dst_space, dst_db)?;
+        let mut _source_guard = None;
+        if !same_db && source_first {
+            _source_guard = Some(source_lock.lock().await);
+        }
+        let target_guard = target_lock.lock().await;
+        if !same_db && !source_first {
+            _source_guard = Some(source_lock.lock().await);
+        }
+
+        // 3. Copie physique puis inscription dans l'index système de la cible
+        let files = file_storage::copy_collection(
+            &self.storage.config,
+            (&self.space, &self.db, collection),
+            (dst_space, dst_db, dst_name),
+        )
+        .await?;
+
+        let mut entry = self.load_index().await?["collections"][collection].clone();
+        if !entry.is_object() {
+            entry = json_value!({ "items": [], "x_indexes": [] });
+        }
+        if let Some(remapped) = &target_schema {
+            entry["schema"] = json_value!(remapped);
+        }
+        let mut tx = target.begin_system_tx(&target_guard).await?;
+        if !tx.document["collections"].is_object() {
+            tx.document["collections"] = json_value!({});
+        }
+        tx.document["collections"][dst_name] = entry;
+        if let Err(e) = tx.commit().await {
+            let _ = collection::drop_collection(&self.storage.config, dst_space, dst_db, dst_name)
+                .await;
+            return Err(e);
+        }
+
+        user_success!(
+            "INF_DB_COLLECTION_COPIED",
+            json_value!({
+                "source": format!("{}/{}/{}", self.space, self.db, collection),
+                "target": format!("{}/{}/{}", dst_space, dst_db, dst_name),
+                "files": files
+            })
+        );
+        Ok(files)
+    }
+
     pub async fn load_index(&self) -> RaiseResult<JsonValue> {
         let sys_path = self
             .storage
@@ -125,10 +282,7 @@ impl<'a> CollectionsManager<'a> {
             );
         }
 
-        match fs::read_json_async(&sys_path).await {
-            Ok(v) => Ok(v),
-            Err(e) => Err(e),
-        }
+        encryption::read_json(&self.storage.config, &sys_path).await
     }
 
     // ============================================================================
@@ -258,6 +412,7 @@ impl<'a> CollectionsManager<'a> {
         collection: &str,
         id_or_handle: &str,
     ) -> RaiseResult<Option<JsonValue>> {
+        self.authorize(collection, AccessMode::Read).await?;
         // 1. Protection "Fail-Fast"
         let col_path = self
             .storage
@@ -302,7 +457,9 @@ impl<'a> CollectionsManager<'a> {
                     if path.file_name().and_then(|s| s.to_str()) == Some("_meta.json") {
                         continue;
                     }
-                    if let Ok(content) = fs::read_json_async::<JsonValue>(&path).await {
+                    if let Ok(content) =
+                        encryption::read_json::<JsonValue>(&self.storage.config, &path).await
+                    {
                         if content.get("handle").and_then(|v| v.as_str()) == Some(id_or_handle) {
                             return Ok(Some(content));
                         }
@@ -350,6 +507,7 @@ impl<'a> CollectionsManager<'a> {
     }
 
     pub async fn list_all(&self, collection: &str) -> RaiseResult<Vec<JsonValue>> {
+        self.authorize(collection, AccessMode::Read).await?;
         collection::list_documents(self.storage, &self.space, &self.db, collection, None, None)
             .await
     }
@@ -364,6 +522,7 @@ impl<'a> CollectionsManager<'a> {
         limit: usize,
         offset: usize,
     ) -> RaiseResult<Vec<JsonValue>> {
+        self.authorize(collection, AccessMode::Read).await?;
         collection::list_documents(
             self.storage,
             &self.space,
@@ -477,6 +636,7 @@ impl<'a> CollectionsManager<'a> {
 
     // --- GESTION DES COLLECTIONS ---
     pub async fn create_collection(&self, name: &str, uri: &str) -> RaiseResult<()> {
+        self.authorize(name, AccessMode::Write).await?;
         let sys_path = self
             .storage
             .config
@@ -512,6 +672,7 @@ impl<'a> CollectionsManager<'a> {
     }
 
     pub async fn drop_collection(&self, name: &str) -> RaiseResult<()> {
+        self.authorize(name, AccessMode::Write).await?;
         collection::drop_collection(&self.storage.config, &self.space, &self.db, name).await?;
         self.remove_collection_from_system_index(name).await?;
         Ok(())
@@ -519,17 +680,447 @@ impl<'a> CollectionsManager<'a> {
 
     // --- INDEXES SECONDAIRES ---
     pub async fn create_index(&self, collection: &str, field: &str, kind: &str) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
         let mut idx_mgr = IndexManager::new(self.storage, &self.space, &self.db);
         idx_mgr.create_index(collection, field, kind).await
     }
 
+    /// Index composé et/ou unique, cf. `IndexManager::create_index_with`.
+    pub async fn create_index_with(
+        &self,
+        collection: &str,
+        fields: &[&str],
+        kind: &str,
+        unique: bool,
+    ) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let mut idx_mgr = IndexManager::new(self.storage, &self.space, &self.db);
+        idx_mgr
+            .create_index_with(collection, fields, kind, unique)
+            .await
+    }
+
     pub async fn drop_index(&self, collection: &str, field: &str) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
         let mut idx_mgr = IndexManager::new(self.storage, &self.space, &self.db);
         idx_mgr.drop_index(collection, field).await
     }
 
     // --- HELPER INDEX SYSTÈME & RÉSOLUTION SCHÉMA ---
 
+    /// URI du schéma d'une collection : `_meta.json` d'abord, puis index système.
+    pub async fn collection_schema_uri(&self, collection: &str) -> Option<String> {
+        let meta_path = self
+            .storage
+            .config
+            .db_collection_path(&self.space, &self.db, collection)
+            .join("_meta.json");
+
+        if meta_path.exists() {
+            if let Ok(content) = fs::read_to_string_async(&meta_path).await {
+                if let Ok(meta) = json::deserialize_from_str::<JsonValue>(&content) {
+                    if let Some(s) = meta.get("schema").and_then(|v| v.as_str()) {
+                        if !s.is_empty() {
+                            return Some(self.build_schema_uri(s).await);
+                        }
+                    }
+                }
+            }
+        }
+
+        match self.resolve_schema_from_index(collection).await {
+            Ok(sys_uri) if !sys_uri.is_empty() => Some(sys_uri),
+            _ => None,
+        }
+    }
+
+    /// Stratégie de fusion par défaut d'une collection (`merge_strategy` du `_meta.json`).
+    pub async fn collection_merge_strategy(&self, collection: &str) -> MergeStrategy {
+        self.read_collection_meta(collection)
+            .await
+            .and_then(|meta| meta.get(META_MERGE_STRATEGY_KEY).cloned())
+            .and_then(|v| json::deserialize_from_value(v).ok())
+            .unwrap_or_default()
+    }
+
+    pub async fn set_collection_merge_strategy(
+        &self,
+        collection: &str,
+        strategy: MergeStrategy,
+    ) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let col_path = self
+            .storage
+            .config
+            .db_collection_path(&self.space, &self.db, collection);
+        if !col_path.exists() {
+            raise_error!(
+                "ERR_DB_COLLECTION_NOT_FOUND",
+                error = format!("Collection '{}' introuvable", collection),
+                context = json_value!({ "action": "set_collection_merge_strategy", "space": self.space, "db": self.db })
+            );
+        }
+        let mut meta = self
+            .read_collection_meta(collection)
+            .await
+            .unwrap_or_else(|| json_value!({}));
+        if let Some(obj) = meta.as_object_mut() {
+            obj.insert(
+                META_MERGE_STRATEGY_KEY.to_string(),
+                json::serialize_to_value(strategy)?,
+            );
+        }
+        fs::write_json_atomic_async(&col_path.join("_meta.json"), &meta).await
+    }
+
+    /// Politique d'expiration des documents d'une collection (`ttl` du `_meta.json`).
+    pub async fn collection_ttl(&self, collection: &str) -> Option<TtlPolicy> {
+        self.read_collection_meta(collection)
+            .await
+            .and_then(|meta| meta.get(META_TTL_KEY).cloned())
+            .and_then(|v| json::deserialize_from_value(v).ok())
+    }
+
+    /// Déclare (ou retire avec `None`) l'expiration des documents d'une collection.
+    pub async fn set_collection_ttl(
+        &self,
+        collection: &str,
+        policy: Option<TtlPolicy>,
+    ) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let col_path = self
+            .storage
+            .config
+            .db_collection_path(&self.space, &self.db, collection);
+        if !col_path.exists() {
+            raise_error!(
+                "ERR_DB_COLLECTION_NOT_FOUND",
+                error = format!("Collection '{}' introuvable", collection),
+                context = json_value!({ "action": "set_collection_ttl", "space": self.space, "db": self.db })
+            );
+        }
+        let mut meta = self
+            .read_collection_meta(collection)
+            .await
+            .unwrap_or_else(|| json_value!({}));
+        if let Some(obj) = meta.as_object_mut() {
+            match policy {
+                Some(policy) => {
+                    obj.insert(META_TTL_KEY.to_string(), json::serialize_to_value(policy)?);
+                }
+                None => {
+                    obj.remove(META_TTL_KEY);
+                }
+            }
+        }
+        fs::write_json_atomic_async(&col_path.join("_meta.json"), &meta).await
+    }
+
+    /// Mode de validation d'une collection (`validation_mode` dans `_system.json`, `strict` par défaut).
+    pub async fn collection_validation_mode(&self, collection: &str) -> ValidationMode {
+        let Ok(index) = self.load_index().await else {
+            return ValidationMode::default();
+        };
+        index
+            .get("collections")
+            .and_then(|cols| cols.get(collection))
+            .and_then(|col| col.get(SYSTEM_VALIDATION_MODE_KEY))
+            .cloned()
+            .and_then(|v| json::deserialize_from_value(v).ok())
+            .unwrap_or_default()
+    }
+
+    pub async fn set_collection_validation_mode(
+        &self,
+        collection: &str,
+        mode: ValidationMode,
+    ) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let lock = self.storage.get_index_lock(&self.space, &self.db)?;
+        let guard = lock.lock().await;
+        let mut tx = self.begin_system_tx(&guard).await?;
+
+        let Some(col) = tx
+            .document
+            .get_mut("collections")
+            .and_then(|cols| cols.get_mut(collection))
+            .and_then(|col| col.as_object_mut())
+        else {
+            raise_error!(
+                "ERR_DB_COLLECTION_NOT_FOUND",
+                error = format!("Collection '{}' absente de l'index système", collection),
+                context = json_value!({ "action": "set_collection_validation_mode", "space": self.space, "db": self.db })
+            );
+        };
+        col.insert(
+            SYSTEM_VALIDATION_MODE_KEY.to_string(),
+            json::serialize_to_value(mode)?,
+        );
+        tx.commit().await
+    }
+
+    /// Stratégie d'identifiant d'une collection (`id_strategy` dans `_system.json`, `uuid_v4` par défaut).
+    pub async fn collection_id_strategy(&self, collection: &str) -> IdStrategy {
+        let Ok(index) = self.load_index().await else {
+            return IdStrategy::default();
+        };
+        index
+            .get("collections")
+            .and_then(|cols| cols.get(collection))
+            .and_then(|col| col.get(SYSTEM_ID_STRATEGY_KEY))
+            .cloned()
+            .and_then(|v| json::deserialize_from_value(v).ok())
+            .unwrap_or_default()
+    }
+
+    pub async fn set_collection_id_strategy(
+        &self,
+        collection: &str,
+        strategy: IdStrategy,
+    ) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let lock = self.storage.get_index_lock(&self.space, &self.db)?;
+        let guard = lock.lock().await;
+        let mut tx = self.begin_system_tx(&guard).await?;
+
+        let Some(col) = tx
+            .document
+            .get_mut("collections")
+            .and_then(|cols| cols.get_mut(collection))
+            .and_then(|col| col.as_object_mut())
+        else {
+            raise_error!(
+                "ERR_DB_COLLECTION_NOT_FOUND",
+                error = format!("Collection '{}' absente de l'index système", collection),
+                context = json_value!({ "action": "set_collection_id_strategy", "space": self.space, "db": self.db })
+            );
+        };
+        col.insert(
+            SYSTEM_ID_STRATEGY_KEY.to_string(),
+            json::serialize_to_value(strategy)?,
+        );
+        tx.commit().await
+    }
+
+    pub async fn collection_audit_policy(&self, collection: &str) -> AuditPolicy {
+        let Ok(index) = self.load_index().await else {
+            return AuditPolicy::default();
+        };
+        index
+            .get("collections")
+            .and_then(|cols| cols.get(collection))
+            .and_then(|col| col.get(SYSTEM_AUDIT_KEY))
+            .cloned()
+            .and_then(|v| json::deserialize_from_value(v).ok())
+            .unwrap_or_default()
+    }
+
+    pub async fn set_collection_audit_policy(
+        &self,
+        collection: &str,
+        policy: AuditPolicy,
+    ) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
+        policy.validate()?;
+        let lock = self.storage.get_index_lock(&self.space, &self.db)?;
+        let guard = lock.lock().await;
+        let mut tx = self.begin_system_tx(&guard).await?;
+
+        let Some(col) = tx
+            .document
+            .get_mut("collections")
+            .and_then(|cols| cols.get_mut(collection))
+            .and_then(|col| col.as_object_mut())
+        else {
+            raise_error!(
+                "ERR_DB_COLLECTION_NOT_FOUND",
+                error = format!("Collection '{}' absente de l'index système", collection),
+                context = json_value!({ "action": "set_collection_audit_policy", "space": self.space, "db": self.db })
+            );
+        };
+        col.insert(
+            SYSTEM_AUDIT_KEY.to_string(),
+            json::serialize_to_value(policy)?,
+        );
+        tx.commit().await
+    }
+
+    /// Règles d'accès par collection de la base (`acl` dans `_system.json`).
+    pub async fn collection_acl(&self) -> CollectionAcl {
+        match self.load_index().await {
+            Ok(index) => CollectionAcl::from_index(&index),
+            Err(_) => CollectionAcl::default(),
+        }
+    }
+
+    /// Déclare (ou retire avec `None`) la règle d'accès d'un motif de collections.
+    /// Réservé aux administrateurs lorsqu'une identité est active.
+    pub async fn set_collection_acl(
+        &self,
+        pattern: &str,
+        grant: Option<CollectionGrant>,
+    ) -> RaiseResult<()> {
+        if let Some(identity) = current_access() {
+            if !identity.roles.contains(&AccessRole::Admin) {
+                raise_error!(
+                    "ERR_DB_ACCESS_DENIED",
+                    error = "Seul un administrateur peut modifier les règles d'accès.",
+                    context = json_value!({ "pattern": pattern, "user": identity.user })
+                );
+            }
+        }
+        let lock = self.storage.get_index_lock(&self.space, &self.db)?;
+        let guard = lock.lock().await;
+        let mut tx = self.begin_system_tx(&guard).await?;
+
+        if !tx.document[SYSTEM_ACL_KEY].is_object() {
+            tx.document[SYSTEM_ACL_KEY] = json_value!({});
+        }
+        match grant {
+            Some(grant) => {
+                tx.document[SYSTEM_ACL_KEY][pattern] = json::serialize_to_value(grant)?;
+            }
+            None => {
+                if let Some(rules) = tx.document[SYSTEM_ACL_KEY].as_object_mut() {
+                    rules.remove(pattern);
+                }
+            }
+        }
+        tx.commit().await
+    }
+
+    /// Contrôle l'accès de l'identité de la tâche courante (aucun contrôle sans identité).
+    async fn authorize(&self, collection: &str, mode: AccessMode) -> RaiseResult<()> {
+        let Some(identity) = current_access() else {
+            return Ok(());
+        };
+        self.collection_acl()
+            .await
+            .check(&identity, collection, mode)
+    }
+
+    /// Attribue un `_id` selon la stratégie de la collection si le document n'en a pas.
+    /// `uuid_v4` laisse la main au `x_compute` du schéma.
+    async fn assign_document_id(&self, collection: &str, doc: &mut JsonValue) -> RaiseResult<()> {
+        let has_id = doc
+            .get("_id")
+            .and_then(|v| v.as_str())
+            .is_some_and(|id| !id.is_empty());
+        if has_id || !doc.is_object() {
+            return Ok(());
+        }
+
+        let id = match self.collection_id_strategy(collection).await {
+            IdStrategy::UuidV4 => return Ok(()),
+            IdStrategy::Prefixed { prefix, width } => {
+                self.next_prefixed_id(collection, &prefix, width).await?
+            }
+            strategy => match strategy.generate(doc) {
+                Some(id) => id,
+                None => return Ok(()),
+            },
+        };
+        if let Some(obj) = doc.as_object_mut() {
+            obj.insert("_id".to_string(), JsonValue::String(id));
+        }
+        Ok(())
+    }
+
+    /// Réserve le prochain numéro (`id_sequence` dans `_system.json`), en sautant ceux déjà
+    /// pris par des documents insérés avec un `_id` explicite.
+    async fn next_prefixed_id(
+        &self,
+        collection: &str,
+        prefix: &str,
+        width: usize,
+    ) -> RaiseResult<String> {
+        let lock = self.storage.get_index_lock(&self.space, &self.db)?;
+        let guard = lock.lock().await;
+        let mut tx = self.begin_system_tx(&guard).await?;
+
+        let Some(col) = tx
+            .document
+            .get_mut("collections")
+            .and_then(|cols| cols.get_mut(collection))
+            .and_then(|col| col.as_object_mut())
+        else {
+            raise_error!(
+                "ERR_DB_COLLECTION_NOT_FOUND",
+                error = format!("Collection '{}' absente de l'index système", collection),
+                context = json_value!({ "action": "next_prefixed_id", "space": self.space, "db": self.db })
+            );
+        };
+        let mut sequence = col
+            .get(SYSTEM_ID_SEQUENCE_KEY)
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+        let id = loop {
+            sequence += 1;
+            let candidate = IdStrategy::format_sequence(prefix, width, sequence);
+            if self
+                .storage
+                .read_document(&self.space, &self.db, collection, &candidate)
+                .await?
+                .is_none()
+            {
+                break candidate;
+            }
+        };
+        col.insert(SYSTEM_ID_SEQUENCE_KEY.to_string(), json_value!(sequence));
+        tx.commit().await?;
+        Ok(id)
+    }
+
+    /// Vérifie les champs `x_ref` du document : erreur en mode `strict`, avertissement en `warn`.
+    async fn check_document_refs(
+        &self,
+        collection: &str,
+        doc: &JsonValue,
+        reg: &SchemaRegistry,
+        uri: &str,
+        mode: ValidationMode,
+    ) -> RaiseResult<()> {
+        let Some(schema) = reg.get_by_uri(uri) else {
+            return Ok(());
+        };
+        let fields = introspection::describe_schema(schema, reg, uri);
+        let refs = references::ref_fields(&fields, &self.space, &self.db);
+        if refs.is_empty() {
+            return Ok(());
+        }
+        let dangling = references::find_dangling(self, collection, doc, &refs).await?;
+        if dangling.is_empty() {
+            return Ok(());
+        }
+        let context = json_value!({
+            "collection": collection,
+            "document_id": doc.get("_id"),
+            "dangling": dangling
+        });
+        if mode == ValidationMode::Strict {
+            raise_error!(
+                "ERR_DB_REF_DANGLING",
+                error = format!(
+                    "Référence(s) vers des documents inexistants dans '{}'",
+                    collection
+                ),
+                context = context
+            );
+        }
+        user_warn!("WRN_DB_REF_DANGLING", context);
+        Ok(())
+    }
+
+    async fn read_collection_meta(&self, collection: &str) -> Option<JsonValue> {
+        let meta_path = self
+            .storage
+            .config
+            .db_collection_path(&self.space, &self.db, collection)
+            .join("_meta.json");
+        let content = fs::read_to_string_async(&meta_path).await.ok()?;
+        json::deserialize_from_str::<JsonValue>(&content).ok()
+    }
+
     async fn resolve_schema_from_index(&self, col_name: &str) -> RaiseResult<String> {
         DdlHandler::new(self)
             .resolve_schema_from_index(col_name)
@@ -667,6 +1258,7 @@ impl<'a> CollectionsManager<'a> {
 
     // --- ÉCRITURE ET MISE À JOUR ---
     pub async fn insert_raw(&self, collection: &str, doc: &JsonValue) -> RaiseResult<()> {
+        self.authorize(collection, AccessMode::Write).await?;
         let internal_id = doc
             .get("_id")
             .and_then(|v| v.as_str())
@@ -697,34 +1289,21 @@ impl<'a> CollectionsManager<'a> {
             }
         }
 
-        let meta_path = self
-            .storage
-            .config
-            .db_collection_path(&self.space, &self.db, collection)
-            .join("_meta.json");
+        self.ensure_collection_for(collection, doc).await?;
 
-        if !meta_path.exists() {
-            let schema_hint = doc.get("$schema").and_then(|s| s.as_str());
-            if let Some(uri) = schema_hint {
-                self.create_collection(collection, uri).await?;
-            } else {
-                raise_error!(
-                    "ERR_DB_STRICT_SCHEMA_REQUIRED",
-                    error = "Impossible de créer la collection à la volée : aucun '$schema' défini dans le document.",
-                    context = json_value!({ "collection": collection })
-                );
-            }
-        }
+        // Contrôle d'unicité, écriture et indexation sous verrou : pas de doublon concurrent
+        let lock = self
+            .storage
+            .get_collection_lock(&self.space, &self.db, collection)?;
+        let guard = lock.lock().await;
+        let mut idx_mgr = IndexManager::new(self.storage, &self.space, &self.db);
+        idx_mgr.check_unique(collection, &_id, doc).await?;
 
         // Passage par référence &_id
         self.storage
             .write_document(&self.space, &self.db, collection, &_id, doc)
             .await?;
 
-        // Passage par référence &_id
-        self.add_item_to_index(collection, &_id).await?;
-
-        let mut idx_mgr = IndexManager::new(self.storage, &self.space, &self.db);
         if let Err(_e) = idx_mgr.index_document(collection, doc).await {
             #[cfg(debug_assertions)]
             user_warn!(
@@ -738,6 +1317,42 @@ impl<'a> CollectionsManager<'a> {
                 })
             );
         }
+        drop(guard);
+
+        // Passage par référence &_id
+        self.add_item_to_index(collection, &_id).await?;
+
+        changes::publish_change(ChangeEvent::new(
+            &self.space,
+            &self.db,
+            collection,
+            ChangeKind::Insert,
+            &_id,
+            Some(doc.clone()),
+        ));
+        Ok(())
+    }
+
+    /// Crée à la volée une collection absente à partir du `$schema` du document.
+    async fn ensure_collection_for(&self, collection: &str, doc: &JsonValue) -> RaiseResult<()> {
+        let meta_path = self
+            .storage
+            .config
+            .db_collection_path(&self.space, &self.db, collection)
+            .join("_meta.json");
+
+        if !meta_path.exists() {
+            let schema_hint = doc.get("$schema").and_then(|s| s.as_str());
+            if let Some(uri) = schema_hint {
+                self.create_collection(collection, uri).await?;
+            } else {
+                raise_error!(
+                    "ERR_DB_STRICT_SCHEMA_REQUIRED",
+                    error = "Impossible de créer la collection à la volée : aucun '$schema' défini dans le document.",
+                    context = json_value!({ "collection": collection })
+                );
+            }
+        }
         Ok(())
     }
 
@@ -749,19 +1364,125 @@ impl<'a> CollectionsManager<'a> {
     ) -> RaiseResult<JsonValue> {
         doc = self.resolve_document_references(collection, doc).await?;
         self.prepare_document(collection, &mut doc).await?;
+        revision::set_revision(&mut doc, 1);
         self.insert_raw(collection, &doc).await?;
         Ok(doc)
     }
 
+    /// Insère `docs` en une seule transaction (WAL + rollback) : le schéma est compilé une
+    /// fois, les documents sont validés par lots de `INSERT_MANY_BATCH_SIZE`, et un document
+    /// invalide annule tout l'import. Renvoie les `_id` dans l'ordre d'entrée.
+    pub async fn insert_many(
+        &self,
+        collection: &str,
+        docs: Vec<JsonValue>,
+    ) -> RaiseResult<Vec<String>> {
+        self.insert_many_with(collection, docs, INSERT_MANY_BATCH_SIZE, |_, _| {})
+            .await
+    }
+
+    /// `insert_many` avec une taille de lot explicite ; `on_progress(validés, total)` est
+    /// appelé après chaque lot.
+    #[instrument(name = "json_db.insert_many", skip_all, fields(space = %self.space, db = %self.db, collection = collection))]
+    pub async fn insert_many_with<F>(
+        &self,
+        collection: &str,
+        docs: Vec<JsonValue>,
+        batch_size: usize,
+        mut on_progress: F,
+    ) -> RaiseResult<Vec<String>>
+    where
+        F: FnMut(usize, usize) + Send,
+    {
+        self.authorize(collection, AccessMode::Write).await?;
+        let total = docs.len();
+        let mut ctx = self.preparation_context(collection).await;
+        let mut ops = Vec::with_capacity(total);
+        let mut ids = UniqueSet::new();
+
+        let mut pending = docs.into_iter();
+        loop {
+            let batch: Vec<JsonValue> = pending.by_ref().take(batch_size.max(1)).collect();
+            if batch.is_empty() {
+                break;
+            }
+            for doc in batch {
+                let mut doc = self.resolve_document_references(collection, doc).await?;
+                self.prepare_document_in(collection, &mut ctx, &mut doc)
+                    .await?;
+                revision::set_revision(&mut doc, 1);
+
+                let Some(id) = doc.get("_id").and_then(|v| v.as_str()).map(str::to_string) else {
+                    raise_error!(
+                        "ERR_TX_MISSING_ID",
+                        error = "Le document préparé ne contient pas d'identifiant '_id'.",
+                        context =
+                            json_value!({ "collection": collection, "action": "insert_many" })
+                    );
+                };
+                if !ids.insert(id.clone()) {
+                    raise_error!(
+                        "ERR_DB_DUPLICATE_ID",
+                        error = format!("Identifiant '{}' présent plusieurs fois dans le lot.", id),
+                        context = json_value!({ "collection": collection, "id": id })
+                    );
+                }
+                ops.push(Operation::Insert {
+                    collection: collection.to_string(),
+                    id,
+                    document: doc,
+                });
+            }
+            on_progress(ops.len(), total);
+        }
+
+        if ops.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if let Operation::Insert { document, .. } = &ops[0] {
+            self.ensure_collection_for(collection, document).await?;
+        }
+
+        let inserted: Vec<String> = ops.iter().map(|op| op.target().1.to_string()).collect();
+        TransactionManager::new(self.storage, &self.space, &self.db)
+            .execute(|tx| {
+                tx.operations.extend(ops);
+                Ok(())
+            })
+            .await?;
+        Ok(inserted)
+    }
+
+    #[instrument(name = "json_db.update_document", skip_all, fields(space = %self.space, db = %self.db, collection = collection, id = id))]
     pub async fn update_document(
         &self,
         collection: &str,
         id: &str,
         patch_data: JsonValue,
     ) -> RaiseResult<JsonValue> {
-        let resolved_patch = self
+        self.update_document_with(collection, id, patch_data, None)
+            .await
+    }
+
+    /// `update_document` avec une stratégie de fusion explicite
+    /// (`None` = défaut de la collection, cf. `collection_merge_strategy`).
+    pub async fn update_document_with(
+        &self,
+        collection: &str,
+        id: &str,
+        patch_data: JsonValue,
+        strategy: Option<MergeStrategy>,
+    ) -> RaiseResult<JsonValue> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let strategy = match strategy {
+            Some(s) => s,
+            None => self.collection_merge_strategy(collection).await,
+        };
+        let mut resolved_patch = self
             .resolve_document_references(collection, patch_data)
             .await?;
+        let expected_rev = revision::take_expected(&mut resolved_patch)?;
         let old_doc_opt = self.get_document(collection, id).await?;
         let Some(mut doc) = old_doc_opt else {
             raise_error!(
@@ -770,7 +1491,13 @@ impl<'a> CollectionsManager<'a> {
                 context = json_value!({ "action": "update_document" })
             );
         };
-        json_merge(&mut doc, resolved_patch);
+        let base_rev = revision::current_revision(Some(&doc));
+        revision::check(collection, id, expected_rev, base_rev)?;
+        let previous = doc.clone();
+        strategy.apply(&mut doc, resolved_patch);
+        self.collection_audit_policy(collection)
+            .await
+            .keep_creation(&previous, &mut doc);
 
         if let Some(obj) = doc.as_object_mut() {
             obj.insert("_id".to_string(), JsonValue::String(id.to_string()));
@@ -783,22 +1510,81 @@ impl<'a> CollectionsManager<'a> {
 
         self.prepare_document(collection, &mut doc).await?;
 
+        // Relecture sous verrou : une écriture concurrente a pu passer pendant la préparation
+        let lock = self
+            .storage
+            .get_collection_lock(&self.space, &self.db, collection)?;
+        let guard = lock.lock().await;
+        let stored = self
+            .storage
+            .read_document(&self.space, &self.db, collection, id)
+            .await?;
+        let current_rev = revision::current_revision(stored.as_ref());
+        revision::check(collection, id, expected_rev.map(|_| base_rev), current_rev)?;
+        revision::set_revision(&mut doc, current_rev + 1);
+        let mut idx_mgr = IndexManager::new(self.storage, &self.space, &self.db);
+        idx_mgr.check_unique(collection, id, &doc).await?;
         self.storage
             .write_document(&self.space, &self.db, collection, id, &doc)
             .await?;
 
-        let mut idx_mgr = IndexManager::new(self.storage, &self.space, &self.db);
+        // Les clés de l'ancienne version sont retirées avant d'indexer la nouvelle
+        if let Some(old) = &stored {
+            let _ = idx_mgr.remove_document(collection, old).await;
+        }
         let _ = idx_mgr.index_document(collection, &doc).await;
+        drop(guard);
 
+        changes::publish_change(ChangeEvent::new(
+            &self.space,
+            &self.db,
+            collection,
+            ChangeKind::Update,
+            id,
+            Some(doc.clone()),
+        ));
         Ok(doc)
     }
 
+    /// Mise à jour partielle : `patch` est un JSON Patch (RFC 6902, tableau d'opérations) ou
+    /// un JSON Merge Patch (RFC 7386, objet). Le document patché suit ensuite le pipeline de
+    /// `update_document` ; sans `_rev` explicite, l'écriture échoue si le document a changé
+    /// depuis sa lecture.
+    #[instrument(name = "json_db.patch_document", skip_all, fields(space = %self.space, db = %self.db, collection = collection, id = id))]
+    pub async fn patch_document(
+        &self,
+        collection: &str,
+        id: &str,
+        patch: JsonValue,
+    ) -> RaiseResult<JsonValue> {
+        let patch = DocumentPatch::from_value(patch)?;
+        let Some(mut doc) = self.get_document(collection, id).await? else {
+            raise_error!(
+                "ERR_DB_UPDATE_TARGET_NOT_FOUND",
+                error = "Échec du patch : le document original est introuvable.",
+                context =
+                    json_value!({ "action": "patch_document", "collection": collection, "id": id })
+            );
+        };
+        patch.apply(&mut doc)?;
+        self.update_document_with(collection, id, doc, Some(MergeStrategy::Replace))
+            .await
+    }
+
+    pub async fn upsert_document(&self, collection: &str, data: JsonValue) -> RaiseResult<String> {
+        self.upsert_document_with(collection, data, None).await
+    }
+
+    /// `upsert_document` avec une stratégie de fusion explicite pour le cas "document existant"
+    /// (`None` = défaut de la collection).
     #[async_recursive]
-    pub async fn upsert_document(
+    pub async fn upsert_document_with(
         &self,
         collection: &str,
         mut data: JsonValue,
+        strategy: Option<MergeStrategy>,
     ) -> RaiseResult<String> {
+        self.authorize(collection, AccessMode::Write).await?;
         data = self.resolve_document_references(collection, data).await?;
 
         let id_opt = data
@@ -865,9 +1651,17 @@ impl<'a> CollectionsManager<'a> {
             }
         }
 
+        // `_rev` attendu sur un document absent : seul 0 (création seule) est accepté
+        if target_id.is_none() {
+            let expected = revision::take_expected(&mut data)?;
+            let id = id_opt.as_deref().unwrap_or_default();
+            revision::check(collection, id, expected, 0)?;
+        }
+
         match target_id {
             Some(id) => {
-                self.update_document(collection, &id, data).await?;
+                self.update_document_with(collection, &id, data, strategy)
+                    .await?;
                 Ok(format!("Updated: {}", id))
             }
             None => {
@@ -878,7 +1672,9 @@ impl<'a> CollectionsManager<'a> {
         }
     }
 
+    #[instrument(name = "json_db.delete_document", skip_all, fields(space = %self.space, db = %self.db, collection = collection, id = id))]
     pub async fn delete_document(&self, collection: &str, id: &str) -> RaiseResult<bool> {
+        self.authorize(collection, AccessMode::Write).await?;
         let old_doc = self.get_document(collection, id).await?;
         self.storage
             .delete_document(&self.space, &self.db, collection, id)
@@ -888,38 +1684,233 @@ impl<'a> CollectionsManager<'a> {
             let _ = idx_mgr.remove_document(collection, &doc).await;
         }
         self.remove_item_from_index(collection, id).await?;
+        let dir = attachment::attachments_dir(
+            &self.storage.config,
+            &self.space,
+            &self.db,
+            collection,
+            id,
+        );
+        if fs::exists_async(&dir).await {
+            let _ = fs::remove_dir_all_async(&dir).await;
+        }
+        changes::publish_change(ChangeEvent::new(
+            &self.space,
+            &self.db,
+            collection,
+            ChangeKind::Delete,
+            id,
+            None,
+        ));
         Ok(true)
     }
 
-    #[async_recursive]
-    pub async fn prepare_document(&self, collection: &str, doc: &mut JsonValue) -> RaiseResult<()> {
-        let mut resolved_uri: Option<String> = None;
+    /// Attache (ou remplace) la pièce jointe `name` du document : le contenu est rangé par
+    /// empreinte sous la collection, puis `attachments.<name>` est écrit dans le document.
+    #[instrument(name = "json_db.put_attachment", skip_all, fields(space = %self.space, db = %self.db, collection = collection, id = id))]
+    pub async fn put_attachment(
+        &self,
+        collection: &str,
+        id: &str,
+        name: &str,
+        bytes: &[u8],
+    ) -> RaiseResult<AttachmentMeta> {
+        self.authorize(collection, AccessMode::Write).await?;
+        attachment::validate_name(name)?;
+        let (doc_id, mut table) = self.attachment_target(collection, id).await?;
+        let dir = attachment::attachments_dir(
+            &self.storage.config,
+            &self.space,
+            &self.db,
+            collection,
+            &doc_id,
+        );
 
-        let meta_path = self
-            .storage
-            .config
-            .db_collection_path(&self.space, &self.db, collection)
-            .join("_meta.json");
+        let meta = AttachmentMeta::describe(name, bytes);
+        attachment::store_blob(&self.storage.config, &dir, &meta, bytes).await?;
+        let previous = table.insert(name.to_string(), meta.clone());
 
-        if meta_path.exists() {
-            if let Ok(content) = fs::read_to_string_async(&meta_path).await {
-                if let Ok(meta) = json::deserialize_from_str::<JsonValue>(&content) {
-                    if let Some(s) = meta.get("schema").and_then(|v| v.as_str()) {
-                        if !s.is_empty() {
-                            resolved_uri = Some(self.build_schema_uri(s).await);
-                        }
-                    }
-                }
+        let patch = json_value!({ ATTACHMENTS_FIELD: { name: meta.clone() } });
+        if let Err(e) = self.patch_document(collection, &doc_id, patch).await {
+            table.remove(name);
+            if let Some(old) = &previous {
+                table.insert(name.to_string(), old.clone());
             }
+            let _ = attachment::release_blob(&dir, &meta, &table).await;
+            return Err(e);
+        }
+        if let Some(old) = &previous {
+            attachment::release_blob(&dir, old, &table).await?;
         }
+        Ok(meta)
+    }
 
-        if resolved_uri.is_none() {
-            if let Ok(sys_uri) = self.resolve_schema_from_index(collection).await {
-                if !sys_uri.is_empty() {
-                    resolved_uri = Some(sys_uri);
-                }
-            }
+    /// Lecture par blocs de la pièce jointe `name`, vérifiée contre l'empreinte du document.
+    pub async fn open_attachment(
+        &self,
+        collection: &str,
+        id: &str,
+        name: &str,
+    ) -> RaiseResult<AttachmentReader> {
+        let (doc_id, table) = self.attachment_target(collection, id).await?;
+        let Some(meta) = table.get(name).cloned() else {
+            raise_error!(
+                "ERR_DB_ATTACHMENT_NOT_FOUND",
+                error = format!("Pièce jointe '{}' absente du document '{}'.", name, doc_id),
+                context = json_value!({ "collection": collection, "id": doc_id, "available": table.keys().collect::<Vec<_>>() })
+            );
+        };
+        let dir = attachment::attachments_dir(
+            &self.storage.config,
+            &self.space,
+            &self.db,
+            collection,
+            &doc_id,
+        );
+        AttachmentReader::open(&self.storage.config, &dir, meta).await
+    }
+
+    pub async fn list_attachments(
+        &self,
+        collection: &str,
+        id: &str,
+    ) -> RaiseResult<OrderedMap<String, AttachmentMeta>> {
+        Ok(self.attachment_target(collection, id).await?.1)
+    }
+
+    /// Retire la pièce jointe `name` ; `false` si le document n'en avait pas.
+    pub async fn delete_attachment(
+        &self,
+        collection: &str,
+        id: &str,
+        name: &str,
+    ) -> RaiseResult<bool> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let (doc_id, mut table) = self.attachment_target(collection, id).await?;
+        let Some(meta) = table.remove(name) else {
+            return Ok(false);
+        };
+        self.patch_document(
+            collection,
+            &doc_id,
+            json_value!({ ATTACHMENTS_FIELD: { name: JsonValue::Null } }),
+        )
+        .await?;
+        let dir = attachment::attachments_dir(
+            &self.storage.config,
+            &self.space,
+            &self.db,
+            collection,
+            &doc_id,
+        );
+        attachment::release_blob(&dir, &meta, &table).await?;
+        Ok(true)
+    }
+
+    /// `_id` réel (le document peut être désigné par son handle) et table `attachments`.
+    async fn attachment_target(
+        &self,
+        collection: &str,
+        id: &str,
+    ) -> RaiseResult<(String, OrderedMap<String, AttachmentMeta>)> {
+        let Some(doc) = self.get_document(collection, id).await? else {
+            raise_error!(
+                "ERR_DB_DOCUMENT_NOT_FOUND",
+                error = format!(
+                    "Document '{}' introuvable dans la collection '{}'",
+                    id, collection
+                ),
+                context = json_value!({ "collection": collection, "_id": id, "action": "resolve_attachment_target" })
+            );
+        };
+        let doc_id = doc
+            .get("_id")
+            .and_then(|v| v.as_str())
+            .unwrap_or(id)
+            .to_string();
+        Ok((doc_id, attachment::attachments_of(&doc)))
+    }
+
+    /// Flux des insertions, mises à jour et suppressions de `collection` validées après
+    /// l'appel, qu'elles passent par une transaction WAL ou par ce gestionnaire.
+    pub fn watch(&self, collection: &str) -> ChangeStream {
+        changes::watch(&self.space, &self.db, collection)
+    }
+
+    /// Fusionne `patch` dans tous les documents correspondant à `filter`.
+    /// Exécuté en une transaction (WAL + rollback) ; renvoie le nombre de documents modifiés.
+    #[instrument(name = "json_db.update_many", skip_all, fields(space = %self.space, db = %self.db, collection = collection))]
+    pub async fn update_many(
+        &self,
+        collection: &str,
+        filter: QueryFilter,
+        patch: JsonValue,
+    ) -> RaiseResult<usize> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let query = Self::bulk_query(collection, filter);
+        TransactionManager::new(self.storage, &self.space, &self.db)
+            .execute_smart_counted(vec![TransactionRequest::UpdateMany { query, patch }])
+            .await
+    }
+
+    /// Supprime tous les documents correspondant à `filter` en une transaction.
+    /// Renvoie le nombre de documents supprimés.
+    #[instrument(name = "json_db.delete_many", skip_all, fields(space = %self.space, db = %self.db, collection = collection))]
+    pub async fn delete_many(&self, collection: &str, filter: QueryFilter) -> RaiseResult<usize> {
+        self.authorize(collection, AccessMode::Write).await?;
+        let query = Self::bulk_query(collection, filter);
+        TransactionManager::new(self.storage, &self.space, &self.db)
+            .execute_smart_counted(vec![TransactionRequest::DeleteMany { query }])
+            .await
+    }
+
+    fn bulk_query(collection: &str, filter: QueryFilter) -> Query {
+        Query {
+            collection: collection.to_string(),
+            filter: Some(filter),
+            rls_policy: None,
+            sort: None,
+            limit: None,
+            offset: None,
+            projection: None,
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
         }
+    }
+
+    #[async_recursive]
+    pub async fn prepare_document(&self, collection: &str, doc: &mut JsonValue) -> RaiseResult<()> {
+        let mut ctx = self.preparation_context(collection).await;
+        self.prepare_document_in(collection, &mut ctx, doc).await
+    }
+
+    /// Réglages de la collection lus une fois pour une série de documents.
+    async fn preparation_context(&self, collection: &str) -> PreparationContext {
+        PreparationContext {
+            collection_uri: self.collection_schema_uri(collection).await,
+            validation_mode: self.collection_validation_mode(collection).await,
+            audit: self.collection_audit_policy(collection).await,
+            compiled: None,
+        }
+    }
+
+    /// `prepare_document` avec un contexte partagé : le registre et le validateur du schéma
+    /// ne sont chargés qu'au premier document qui les requiert.
+    #[async_recursive]
+    async fn prepare_document_in(
+        &self,
+        collection: &str,
+        ctx: &mut PreparationContext,
+        doc: &mut JsonValue,
+    ) -> RaiseResult<()> {
+        let mut resolved_uri = ctx.collection_uri.clone();
+        let validation_mode = ctx.validation_mode;
+        let audit = ctx.audit.clone();
+        self.assign_document_id(collection, doc).await?;
 
         // ====================================================================
         // Auto-découverte du schéma pour les nouvelles collections
@@ -937,11 +1928,24 @@ impl<'a> CollectionsManager<'a> {
                 obj.insert("$schema".to_string(), JsonValue::String(uri.clone()));
             }
 
-            let reg =
-                SchemaRegistry::from_uri(&self.storage.config, uri, &self.space, &self.db).await?;
+            if ctx.compiled.as_ref().map(|c| c.uri.as_str()) != Some(uri.as_str()) {
+                let registry =
+                    SchemaRegistry::from_uri(&self.storage.config, uri, &self.space, &self.db)
+                        .await?;
+                let validator = SchemaValidator::compile_with_registry(uri, &registry)?;
+                ctx.compiled = Some(CompiledSchema {
+                    uri: uri.clone(),
+                    registry,
+                    validator,
+                });
+            }
+            let Some(compiled) = ctx.compiled.as_ref() else {
+                return Ok(());
+            };
+            let (reg, validator) = (&compiled.registry, &compiled.validator);
 
             if let Err(e) =
-                crate::rules_engine::apply_business_rules(self, collection, doc, None, &reg, uri)
+                crate::rules_engine::apply_business_rules(self, collection, doc, None, reg, uri)
                     .await
             {
                 user_warn!(
@@ -955,7 +1959,6 @@ impl<'a> CollectionsManager<'a> {
                 );
             }
 
-            let validator = SchemaValidator::compile_with_registry(uri, &reg)?;
             let compute_ctx = crate::rules_engine::compute::ComputeContext {
                 document: doc.clone(),
                 collection_name: collection.to_string(),
@@ -963,7 +1966,31 @@ impl<'a> CollectionsManager<'a> {
                 space_name: self.space.clone(),
             };
 
-            validator.compute_then_validate(doc, &compute_ctx).await?;
+            validator.compute(doc, &compute_ctx).await?;
+            // Après `x_compute` : les champs d'audit priment sur les calculs des schémas
+            audit.stamp(doc);
+            match validation_mode {
+                ValidationMode::Strict => validator.validate(doc)?,
+                ValidationMode::Warn => {
+                    if let Err(e) = validator.validate(doc) {
+                        user_warn!(
+                            "WRN_DB_SCHEMA_VALIDATION_WARN_MODE",
+                            json_value!({
+                                "collection": collection,
+                                "document_id": doc.get("_id"),
+                                "technical_error": e.to_string(),
+                                "hint": "Collection en mode 'warn' : le document est écrit malgré l'écart au schéma."
+                            })
+                        );
+                    }
+                }
+                ValidationMode::Off => {}
+            }
+
+            if validation_mode != ValidationMode::Off {
+                self.check_document_refs(collection, doc, reg, uri, validation_mode)
+                    .await?;
+            }
 
             if let Some(obj) = doc.as_object_mut() {
                 let ws_id = AppConfig::get()
@@ -985,12 +2012,14 @@ impl<'a> CollectionsManager<'a> {
                     );
                 }
             }
-        } else {
+        } else if validation_mode != ValidationMode::Off {
             raise_error!(
                 "ERR_DB_STRICT_SCHEMA_REQUIRED",
                 error = "Insertion refusée : Aucun schéma de validation n'est défini pour cette collection.",
                 context = json_value!({ "collection": collection, "action": "prepare_document" })
             );
+        } else {
+            audit.stamp(doc);
         }
 
         if let Err(e) = self.apply_semantic_logic(doc).await {
@@ -1356,17 +2385,6 @@ impl<'a> CollectionsManager<'a> {
     }
 }
 
-fn json_merge(a: &mut JsonValue, b: JsonValue) {
-    match (a, b) {
-        (JsonValue::Object(a), JsonValue::Object(b)) => {
-            for (k, v) in b {
-                json_merge(a.entry(k).or_insert(JsonValue::Null), v);
-            }
-        }
-        (a, b) => *a = b,
-    }
-}
-
 pub enum SmartLink<'a> {
     Local {
         col: &'a str,
@@ -1511,6 +2529,7 @@ fn resolve_refs_recursive<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::json_db::collections::id_strategy::content_hash;
     use crate::utils::testing::mock::insert_mock_db;
     use crate::utils::testing::DbSandbox;
 
@@ -1705,6 +2724,409 @@ mod tests {
         Ok(())
     }
 
+    #[async_test]
+    async fn test_update_many_and_delete_many_report_affected_count() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "bulk");
+        DbSandbox::mock_db(&mgr).await?;
+
+        mgr.create_collection(
+            "items",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+        for (name, layer) in [("A", "la"), ("B", "la"), ("C", "pa")] {
+            mgr.insert_with_schema("items", json_value!({ "name": name, "layer": layer }))
+                .await?;
+        }
+
+        let la = || QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::eq("layer", json_value!("la"))],
+        };
+
+        let updated = mgr
+            .update_many("items", la(), json_value!({ "status": "validated" }))
+            .await?;
+        assert_eq!(updated, 2);
+        let docs = mgr.list_all("items").await?;
+        let validated = docs.iter().filter(|d| d["status"] == "validated").count();
+        assert_eq!(validated, 2);
+        assert!(docs.iter().all(|d| d.get("name").is_some()));
+
+        let deleted = mgr.delete_many("items", la()).await?;
+        assert_eq!(deleted, 2);
+        assert_eq!(mgr.list_all("items").await?.len(), 1);
+
+        // Filtre sans correspondance : aucune écriture
+        assert_eq!(mgr.delete_many("items", la()).await?, 0);
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_insert_many_batches_in_one_transaction() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "bulk_insert");
+        DbSandbox::mock_db(&mgr).await?;
+        mgr.create_collection(
+            "items",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+
+        let docs: Vec<JsonValue> = (0..7)
+            .map(|i| json_value!({ "_id": format!("it{}", i), "name": format!("Item {}", i) }))
+            .collect();
+        let mut progress = Vec::new();
+        let ids = mgr
+            .insert_many_with("items", docs, 3, |done, total| progress.push((done, total)))
+            .await?;
+        assert_eq!(ids.len(), 7);
+        assert_eq!(ids[0], "it0");
+        assert_eq!(progress, vec![(3, 7), (6, 7), (7, 7)]);
+        let stored = mgr.get_document("items", "it6").await?.unwrap();
+        assert_eq!(stored["_rev"], 1);
+        assert_eq!(mgr.list_all("items").await?.len(), 7);
+
+        // Un identifiant répété annule tout le lot
+        let dup = vec![
+            json_value!({ "_id": "x1", "name": "X" }),
+            json_value!({ "_id": "x1", "name": "Y" }),
+        ];
+        assert!(mgr.insert_many("items", dup).await.is_err());
+        assert!(mgr.get_document("items", "x1").await?.is_none());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_upsert_merge_strategy_per_call_and_collection_default() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "merge");
+        DbSandbox::mock_db(&mgr).await?;
+        mgr.create_collection(
+            "items",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+        mgr.insert_with_schema(
+            "items",
+            json_value!({ "_id": "i1", "name": "Pump", "tags": ["a"], "note": "old" }),
+        )
+        .await?;
+
+        // Par appel : union des tableaux
+        mgr.upsert_document_with(
+            "items",
+            json_value!({ "_id": "i1", "tags": ["b"] }),
+            Some(MergeStrategy::ArrayUnion),
+        )
+        .await?;
+        let doc = mgr.get_document("items", "i1").await?.unwrap();
+        assert_eq!(doc["tags"], json_value!(["a", "b"]));
+        assert_eq!(doc["name"], "Pump");
+
+        // Défaut de collection : RFC 7386, `null` supprime le champ
+        assert_eq!(
+            mgr.collection_merge_strategy("items").await,
+            MergeStrategy::DeepMerge
+        );
+        mgr.set_collection_merge_strategy("items", MergeStrategy::MergePatch)
+            .await?;
+        assert!(mgr.collection_schema_uri("items").await.is_some());
+        mgr.upsert_document("items", json_value!({ "_id": "i1", "note": null }))
+            .await?;
+        let doc = mgr.get_document("items", "i1").await?.unwrap();
+        assert!(doc.get("note").is_none());
+        assert_eq!(doc["tags"], json_value!(["a", "b"]));
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_collection_id_strategies_on_insert() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "ids");
+        DbSandbox::mock_db(&mgr).await?;
+        for col in ["functions", "hashed"] {
+            mgr.create_collection(
+                col,
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        }
+        assert_eq!(
+            mgr.collection_id_strategy("functions").await,
+            IdStrategy::UuidV4
+        );
+
+        mgr.set_collection_id_strategy("functions", IdStrategy::parse("prefixed:SA-FUNC")?)
+            .await?;
+        // Un `_id` explicite est conservé et son numéro est sauté par la séquence
+        mgr.insert_with_schema(
+            "functions",
+            json_value!({ "_id": "SA-FUNC-0002", "name": "B" }),
+        )
+        .await?;
+        let first = mgr
+            .insert_with_schema("functions", json_value!({ "name": "A" }))
+            .await?;
+        let third = mgr
+            .insert_with_schema("functions", json_value!({ "name": "C" }))
+            .await?;
+        assert_eq!(first["_id"], "SA-FUNC-0001");
+        assert_eq!(third["_id"], "SA-FUNC-0003");
+
+        mgr.set_collection_id_strategy("hashed", IdStrategy::ContentHash)
+            .await?;
+        let doc = mgr
+            .insert_with_schema("hashed", json_value!({ "name": "Pump", "kw": 3 }))
+            .await?;
+        assert_eq!(
+            doc["_id"].as_str(),
+            Some(content_hash(&json_value!({ "kw": 3, "name": "Pump" })).as_str())
+        );
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_audit_fields_follow_actor_and_policy() -> RaiseResult<()> {
+        use crate::json_db::collections::audit_fields::{with_actor, AuditPolicy};
+
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "audit");
+        DbSandbox::mock_db(&mgr).await?;
+        mgr.create_collection(
+            "parts",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+
+        let created = with_actor(
+            "alice",
+            mgr.insert_with_schema("parts", json_value!({ "_id": "p1", "name": "Pump" })),
+        )
+        .await?;
+        assert_eq!(created["_created_by"], "alice");
+
+        // Remplacement complet : la création reste celle d'origine
+        let updated = with_actor(
+            "bob",
+            mgr.update_document_with(
+                "parts",
+                "p1",
+                json_value!({ "name": "Valve" }),
+                Some(MergeStrategy::Replace),
+            ),
+        )
+        .await?;
+        assert_eq!(updated["_created_by"], "alice");
+        assert_eq!(updated["_created_at"], created["_created_at"]);
+        assert_eq!(updated["_updated_by"], "bob");
+
+        mgr.set_collection_audit_policy(
+            "parts",
+            AuditPolicy {
+                timezone: "+05:30".into(),
+                created_by: None,
+                updated_by: None,
+                ..AuditPolicy::default()
+            },
+        )
+        .await?;
+        let local = with_actor(
+            "carol",
+            mgr.insert_with_schema("parts", json_value!({ "_id": "p2" })),
+        )
+        .await?;
+        assert!(local["_updated_at"].as_str().unwrap().ends_with("+05:30"));
+        assert!(local.get("_created_by").is_none());
+
+        let bad = AuditPolicy {
+            timezone: "nowhere".into(),
+            ..AuditPolicy::default()
+        };
+        assert!(mgr.set_collection_audit_policy("parts", bad).await.is_err());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_patch_document_json_patch_and_merge_patch() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "patches");
+        DbSandbox::mock_db(&mgr).await?;
+        mgr.create_collection(
+            "elements",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+        mgr.insert_with_schema(
+            "elements",
+            json_value!({ "_id": "e1", "name": "Pump", "specs": { "mass": 1, "power": 2 }, "tags": ["a"] }),
+        )
+        .await?;
+
+        let patched = mgr
+            .patch_document(
+                "elements",
+                "e1",
+                json_value!([
+                    { "op": "replace", "path": "/specs/mass", "value": 3 },
+                    { "op": "add", "path": "/tags/-", "value": "b" }
+                ]),
+            )
+            .await?;
+        assert_eq!(patched["specs"], json_value!({ "mass": 3, "power": 2 }));
+        assert_eq!(patched["tags"], json_value!(["a", "b"]));
+        assert_eq!(patched["_rev"], 2);
+
+        let patched = mgr
+            .patch_document(
+                "elements",
+                "e1",
+                json_value!({ "specs": { "power": null } }),
+            )
+            .await?;
+        assert_eq!(patched["specs"], json_value!({ "mass": 3 }));
+        assert_eq!(patched["name"], "Pump");
+
+        // `test` sur `_rev` : concurrence optimiste côté client, document inchangé en cas d'échec
+        assert!(mgr
+            .patch_document(
+                "elements",
+                "e1",
+                json_value!([
+                    { "op": "test", "path": "/_rev", "value": 1 },
+                    { "op": "replace", "path": "/name", "value": "Valve" }
+                ]),
+            )
+            .await
+            .is_err());
+        let stored = mgr.get_document("elements", "e1").await?.unwrap();
+        assert_eq!(stored["name"], "Pump");
+        assert!(mgr
+            .patch_document("elements", "missing", json_value!({ "name": "X" }))
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_revision_rejects_stale_writes() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "revisions");
+        DbSandbox::mock_db(&mgr).await?;
+        mgr.create_collection(
+            "elements",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+
+        let created = mgr
+            .insert_with_schema("elements", json_value!({ "_id": "e1", "name": "Pump" }))
+            .await?;
+        assert_eq!(created["_rev"], 1);
+
+        // Deux éditeurs lisent la révision 1 ; le second écrit sur une copie périmée
+        let updated = mgr
+            .update_document("elements", "e1", json_value!({ "mass": 3, "_rev": 1 }))
+            .await?;
+        assert_eq!(updated["_rev"], 2);
+        let Err(AppError::Structured(err)) = mgr
+            .update_document("elements", "e1", json_value!({ "mass": 5, "_rev": 1 }))
+            .await
+        else {
+            panic!("Une révision périmée doit être refusée");
+        };
+        assert_eq!(err.code, "ERR_DB_REVISION_CONFLICT");
+        let stored = mgr.get_document("elements", "e1").await?.unwrap();
+        assert_eq!(stored["mass"], 3);
+
+        // Sans `_rev` : dernier écrivain gagnant, la révision avance quand même
+        mgr.upsert_document("elements", json_value!({ "_id": "e1", "mass": 7 }))
+            .await?;
+        let stored = mgr.get_document("elements", "e1").await?.unwrap();
+        assert_eq!(stored["_rev"], 3);
+        assert!(mgr
+            .upsert_document("elements", json_value!({ "_id": "e1", "_rev": 2 }))
+            .await
+            .is_err());
+
+        // `_rev: 0` : création seule
+        mgr.upsert_document("elements", json_value!({ "_id": "e2", "_rev": 0 }))
+            .await?;
+        assert!(mgr
+            .upsert_document("elements", json_value!({ "_id": "e3", "_rev": 4 }))
+            .await
+            .is_err());
+        assert!(mgr
+            .upsert_document("elements", json_value!({ "_id": "e2", "_rev": 0 }))
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_unique_and_compound_indexes() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "unique");
+        DbSandbox::mock_db(&mgr).await?;
+        mgr.create_collection(
+            "parts",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+        mgr.insert_raw(
+            "parts",
+            &json_value!({ "_id": "p1", "serial": "S-1", "project": "a", "code": "C1" }),
+        )
+        .await?;
+        mgr.create_index_with("parts", &["serial"], "hash", true)
+            .await?;
+        mgr.create_index_with("parts", &["project", "code"], "btree", true)
+            .await?;
+
+        let Err(AppError::Structured(err)) = mgr
+            .insert_raw("parts", &json_value!({ "_id": "p2", "serial": "S-1" }))
+            .await
+        else {
+            panic!("Un doublon sur un index unique doit être refusé");
+        };
+        assert_eq!(err.code, "ERR_DB_UNIQUE_VIOLATION");
+        assert_eq!(err.context["conflicting_id"], "p1");
+        assert!(mgr.get_document("parts", "p2").await?.is_none());
+
+        // Composé : seule la combinaison complète est contrainte
+        mgr.insert_raw(
+            "parts",
+            &json_value!({ "_id": "p2", "serial": "S-2", "project": "b", "code": "C1" }),
+        )
+        .await?;
+        let Err(AppError::Structured(err)) = mgr
+            .update_document("parts", "p2", json_value!({ "project": "a" }))
+            .await
+        else {
+            panic!("La combinaison (project, code) est déjà prise");
+        };
+        assert_eq!(err.context["index_name"], "project+code");
+        assert_eq!(err.context["value"], json_value!(["a", "C1"]));
+
+        // La clé libérée par une mise à jour redevient disponible
+        mgr.update_document("parts", "p1", json_value!({ "serial": "S-9" }))
+            .await?;
+        mgr.update_document("parts", "p2", json_value!({ "serial": "S-1" }))
+            .await?;
+
+        // Données existantes en doublon : création d'index refusée
+        assert!(mgr
+            .create_index_with("parts", &["code"], "hash", true)
+            .await
+            .is_err());
+        assert!(mgr
+            .create_index_with("parts", &["serial", "code"], "fulltext", false)
+            .await
+            .is_err());
+        Ok(())
+    }
+
     #[async_test]
     async fn test_upsert_idempotence() -> RaiseResult<()> {
         let sandbox = DbSandbox::new().await?;
@@ -1936,6 +3358,50 @@ mod tests {
         Ok(())
     }
 
+    #[async_test]
+    async fn test_manager_watch_streams_direct_and_wal_writes() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "watch_test", "db_test");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "items",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        let mut stream = manager.watch("items");
+
+        manager
+            .insert_raw("items", &json_value!({ "_id": "i1", "val": "A" }))
+            .await?;
+        TransactionManager::new(&sandbox.storage, "watch_test", "db_test")
+            .execute_smart(vec![TransactionRequest::Update {
+                collection: "items".into(),
+                id: Some("i1".into()),
+                handle: None,
+                document: json_value!({ "val": "B" }),
+            }])
+            .await?;
+        manager.delete_document("items", "i1").await?;
+
+        let inserted = stream.next().await.expect("Flux fermé");
+        assert_eq!(inserted.kind, ChangeKind::Insert);
+        assert!(inserted.tx_id.is_none());
+
+        let updated = stream.next().await.expect("Flux fermé");
+        assert_eq!(updated.kind, ChangeKind::Update);
+        assert!(updated.tx_id.is_some(), "Mise à jour issue du WAL");
+        assert_eq!(updated.document.unwrap()["val"], "B");
+
+        let deleted = stream.next().await.expect("Flux fermé");
+        assert_eq!(
+            (deleted.kind, deleted.id.as_str()),
+            (ChangeKind::Delete, "i1")
+        );
+        assert!(deleted.document.is_none());
+        Ok(())
+    }
+
     #[async_test]
     #[serial_test::serial]
     async fn test_manager_resolve_single_reference() -> RaiseResult<()> {
@@ -2135,4 +3601,211 @@ mod tests {
 
         Ok(())
     }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_manager_enforces_collection_acl() -> RaiseResult<()> {
+        use crate::json_db::collections::acl::with_access;
+        use crate::utils::context::{AccessIdentity, IdentitySource};
+
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "space_test", "db_test");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "notes",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        manager
+            .insert_raw("notes", &json_value!({ "_id": "n1", "text": "a" }))
+            .await?;
+
+        let identity = |role: AccessRole| AccessIdentity {
+            user: format!("{:?}", role).to_lowercase(),
+            roles: vec![role],
+            source: IdentitySource::ApiToken,
+            expires_at: None,
+        };
+
+        // Seul un administrateur pose les règles
+        let grant = CollectionGrant {
+            read: vec![AccessRole::Viewer, AccessRole::Editor],
+            write: vec![AccessRole::Editor],
+        };
+        let denied = with_access(
+            identity(AccessRole::Editor),
+            manager.set_collection_acl("*", Some(grant.clone())),
+        )
+        .await;
+        assert!(denied.is_err());
+        with_access(
+            identity(AccessRole::Admin),
+            manager.set_collection_acl("*", Some(grant)),
+        )
+        .await?;
+
+        // Lecteur : lecture seule
+        let viewer = identity(AccessRole::Viewer);
+        let doc = with_access(viewer.clone(), manager.get_document("notes", "n1")).await?;
+        assert!(doc.is_some());
+        let write = with_access(
+            viewer,
+            manager.insert_raw("notes", &json_value!({ "_id": "n2" })),
+        )
+        .await;
+        assert!(write.is_err());
+
+        // Éditeur : écriture autorisée
+        with_access(
+            identity(AccessRole::Editor),
+            manager.insert_raw("notes", &json_value!({ "_id": "n2" })),
+        )
+        .await?;
+
+        // Appel interne sans identité : pas de contrôle
+        assert_eq!(manager.list_all("notes").await?.len(), 2);
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_manager_clone_db_and_copy_collection() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "space_test", "db_test");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_schema_def("v1/notes.schema.json", json_value!({ "type": "object" }))
+            .await?;
+        manager
+            .create_collection("notes", "v1/notes.schema.json")
+            .await?;
+        manager
+            .insert_raw("notes", &json_value!({ "_id": "n1", "text": "a" }))
+            .await?;
+
+        // 1. Fork complet : documents présents, schéma et index pointant vers la copie
+        manager.clone_db("space_fork", "db_fork").await?;
+        let fork = CollectionsManager::new(&sandbox.storage, "space_fork", "db_fork");
+        assert!(fork.get_document("notes", "n1").await?.is_some());
+        assert_eq!(
+            fork.collection_schema_uri("notes").await.as_deref(),
+            Some("db://space_fork/db_fork/schemas/v1/notes.schema.json")
+        );
+        assert!(fork.get_schema_def("v1/notes.schema.json").await.is_ok());
+        assert!(manager.clone_db("space_fork", "db_fork").await.is_err());
+
+        // 2. Les écritures du fork n'atteignent pas la source
+        fork.insert_raw("notes", &json_value!({ "_id": "n2" }))
+            .await?;
+        assert_eq!(manager.list_all("notes").await?.len(), 1);
+
+        // 3. Copie d'une collection sous un autre nom, schéma recopié dans la cible
+        let other = CollectionsManager::new(&sandbox.storage, "space_test", "db_other");
+        DbSandbox::mock_db(&other).await?;
+        let copied = fork
+            .copy_collection("notes", "space_test", "db_other", Some("notes_copy"))
+            .await?;
+        assert!(copied > 0);
+        assert_eq!(other.list_all("notes_copy").await?.len(), 2);
+        assert_eq!(
+            other.collection_schema_uri("notes_copy").await.as_deref(),
+            Some("db://space_test/db_other/schemas/v1/notes.schema.json")
+        );
+        assert!(other.get_schema_def("v1/notes.schema.json").await.is_ok());
+        assert!(fork
+            .copy_collection("notes", "space_test", "db_other", Some("notes_copy"))
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_manager_attachments_lifecycle() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let mgr = CollectionsManager::new(&sandbox.storage, "test", "evidence");
+        DbSandbox::mock_db(&mgr).await?;
+        mgr.create_collection(
+            "proofs",
+            "db://_system/_system/schemas/v1/db/generic.schema.json",
+        )
+        .await?;
+        mgr.insert_with_schema("proofs", json_value!({ "_id": "p1", "name": "Essai" }))
+            .await?;
+
+        // Contenu de plusieurs blocs : la lecture en flux doit le restituer à l'identique
+        let pdf: Vec<u8> = (0..attachment::ATTACHMENT_CHUNK_SIZE * 2 + 7)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let meta = mgr
+            .put_attachment("proofs", "p1", "rapport.pdf", &pdf)
+            .await?;
+        assert_eq!(meta.content_type, "application/pdf");
+        assert_eq!(meta.size, pdf.len() as u64);
+
+        let doc = mgr.get_document("proofs", "p1").await?.unwrap();
+        assert_eq!(doc[ATTACHMENTS_FIELD]["rapport.pdf"]["hash"], meta.hash);
+
+        let mut reader = mgr.open_attachment("proofs", "p1", "rapport.pdf").await?;
+        let mut chunks = 0;
+        let mut read = Vec::new();
+        while let Some(chunk) = reader.next_chunk().await? {
+            chunks += 1;
+            read.extend_from_slice(&chunk);
+        }
+        assert_eq!(chunks, 3);
+        assert_eq!(read, pdf);
+
+        // Remplacement : l'ancien contenu, plus référencé, est libéré
+        let dir = attachment::attachments_dir(
+            &sandbox.storage.config,
+            "test",
+            "evidence",
+            "proofs",
+            "p1",
+        );
+        let replaced = mgr
+            .put_attachment("proofs", "p1", "rapport.pdf", b"v2")
+            .await?;
+        assert!(!dir.join(meta.digest()).exists());
+        assert!(dir.join(replaced.digest()).exists());
+        let bytes = mgr
+            .open_attachment("proofs", "p1", "rapport.pdf")
+            .await?
+            .read_to_end()
+            .await?;
+        assert_eq!(bytes, b"v2");
+
+        // Contenu altéré sur disque : détecté à la lecture
+        fs::write_async(dir.join(replaced.digest()), b"v3").await?;
+        let tampered = mgr
+            .open_attachment("proofs", "p1", "rapport.pdf")
+            .await?
+            .read_to_end()
+            .await;
+        let Err(AppError::Structured(data)) = tampered else {
+            panic!("Le contenu altéré aurait dû être refusé");
+        };
+        assert_eq!(data.code, "ERR_DB_ATTACHMENT_CORRUPTED");
+
+        assert!(mgr
+            .put_attachment("proofs", "p1", "../evil.pdf", b"x")
+            .await
+            .is_err());
+        assert!(mgr
+            .put_attachment("proofs", "missing", "a.png", b"x")
+            .await
+            .is_err());
+
+        assert!(mgr.delete_attachment("proofs", "p1", "rapport.pdf").await?);
+        assert!(!mgr.delete_attachment("proofs", "p1", "rapport.pdf").await?);
+        assert!(mgr.list_attachments("proofs", "p1").await?.is_empty());
+        assert!(!dir.join(replaced.digest()).exists());
+
+        mgr.put_attachment("proofs", "p1", "photo.png", b"img")
+            .await?;
+        mgr.delete_document("proofs", "p1").await?;
+        assert!(!dir.exists());
+        Ok(())
+    }
 }
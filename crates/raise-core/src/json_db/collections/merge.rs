@@ -0,0 +1,131 @@
+// FICHIER : src-tauri/src/json_db/collections/merge.rs
+
+//! Stratégies de fusion appliquées par `update_document` / `upsert_document`
+//! lorsqu'un document existe déjà (défaut par collection via `_meta.json`).
+
+use crate::utils::prelude::*;
+
+/// Clé du `_meta.json` portant la stratégie par défaut d'une collection.
+pub const META_MERGE_STRATEGY_KEY: &str = "merge_strategy";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Le nouveau document remplace l'existant (seul `_id` est conservé).
+    Replace,
+    /// Fusion récursive des objets ; tableaux et scalaires sont remplacés (comportement historique).
+    #[default]
+    DeepMerge,
+    /// Comme `DeepMerge`, mais les tableaux sont unis (valeurs existantes d'abord, sans doublon).
+    ArrayUnion,
+    /// JSON Merge Patch (RFC 7386) : `null` supprime le champ.
+    MergePatch,
+}
+
+impl MergeStrategy {
+    /// Fusionne `patch` dans `target` selon la stratégie.
+    pub fn apply(self, target: &mut JsonValue, patch: JsonValue) {
+        match self {
+            Self::Replace => {
+                let id = target.get("_id").cloned();
+                *target = patch;
+                if let (Some(id), Some(obj)) = (id, target.as_object_mut()) {
+                    obj.entry("_id").or_insert(id);
+                }
+            }
+            Self::DeepMerge => json::deep_merge_values(target, patch),
+            Self::ArrayUnion => union_merge(target, patch),
+            Self::MergePatch => merge_patch(target, patch),
+        }
+    }
+}
+
+fn union_merge(target: &mut JsonValue, patch: JsonValue) {
+    match (target, patch) {
+        (JsonValue::Object(t), JsonValue::Object(p)) => {
+            for (k, v) in p {
+                union_merge(t.entry(k).or_insert(JsonValue::Null), v);
+            }
+        }
+        (JsonValue::Array(t), JsonValue::Array(p)) => {
+            for item in p {
+                if !t.contains(&item) {
+                    t.push(item);
+                }
+            }
+        }
+        (t, p) => *t = p,
+    }
+}
+
+/// Algorithme de la RFC 7386 §2.
+fn merge_patch(target: &mut JsonValue, patch: JsonValue) {
+    let JsonValue::Object(p) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = JsonValue::Object(JsonObject::new());
+    }
+    if let Some(t) = target.as_object_mut() {
+        for (k, v) in p {
+            if v.is_null() {
+                t.remove(&k);
+            } else {
+                merge_patch(t.entry(k).or_insert(JsonValue::Null), v);
+            }
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing() -> JsonValue {
+        json_value!({ "_id": "a", "name": "Pump", "tags": ["x"], "specs": { "mass": 1, "power": 2 } })
+    }
+
+    #[test]
+    fn test_deep_merge_keeps_untouched_fields() {
+        let mut doc = existing();
+        MergeStrategy::DeepMerge.apply(
+            &mut doc,
+            json_value!({ "tags": ["y"], "specs": { "mass": 5 } }),
+        );
+        assert_eq!(doc["specs"], json_value!({ "mass": 5, "power": 2 }));
+        assert_eq!(doc["tags"], json_value!(["y"]));
+        assert_eq!(doc["name"], "Pump");
+    }
+
+    #[test]
+    fn test_array_union_and_replace() {
+        let mut doc = existing();
+        MergeStrategy::ArrayUnion.apply(&mut doc, json_value!({ "tags": ["x", "y"] }));
+        assert_eq!(doc["tags"], json_value!(["x", "y"]));
+
+        let mut doc = existing();
+        MergeStrategy::Replace.apply(&mut doc, json_value!({ "name": "Valve" }));
+        assert_eq!(doc, json_value!({ "name": "Valve", "_id": "a" }));
+    }
+
+    #[test]
+    fn test_merge_patch_rfc7386_null_removes() {
+        let mut doc = existing();
+        MergeStrategy::MergePatch.apply(
+            &mut doc,
+            json_value!({ "name": null, "specs": { "power": null, "volt": 24 } }),
+        );
+        assert!(doc.get("name").is_none());
+        assert_eq!(doc["specs"], json_value!({ "mass": 1, "volt": 24 }));
+
+        // Exemple de la RFC : un patch non-objet remplace la cible
+        let mut scalar = json_value!({ "a": "b" });
+        MergeStrategy::MergePatch.apply(&mut scalar, json_value!(["c"]));
+        assert_eq!(scalar, json_value!(["c"]));
+    }
+}
@@ -3,9 +3,18 @@
 //! Façade Collections : API haut niveau pour manipuler les documents
 //! 🚀 V2 : Utilisation persistante du StorageEngine pour conserver le cache LRU.
 
+pub mod acl;
+pub mod attachment;
+pub mod audit_fields;
 pub mod collection;
 pub mod data_provider;
+pub mod id_strategy;
 pub mod manager;
+pub mod merge;
+pub mod patch;
+pub mod revision;
+pub mod ttl;
+pub mod vacuum;
 
 // FAÇADE UNIQUE
 
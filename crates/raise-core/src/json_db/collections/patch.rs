@@ -0,0 +1,269 @@
+// FICHIER : src-tauri/src/json_db/collections/patch.rs
+
+//! Mises à jour partielles par `patch_document` : JSON Patch (RFC 6902, tableau
+//! d'opérations) ou JSON Merge Patch (RFC 7386, objet). Le frontend n'envoie que les champs
+//! modifiés au lieu du document entier.
+
+use super::merge::MergeStrategy;
+use crate::utils::prelude::*;
+
+/// Opération JSON Patch (RFC 6902 §4).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: JsonValue },
+    Remove { path: String },
+    Replace { path: String, value: JsonValue },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: JsonValue },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocumentPatch {
+    /// Appliqué atomiquement : une opération en échec laisse le document intact.
+    JsonPatch(Vec<PatchOperation>),
+    MergePatch(JsonValue),
+}
+
+impl DocumentPatch {
+    /// Un tableau est lu comme un JSON Patch, un objet comme un Merge Patch.
+    pub fn from_value(patch: JsonValue) -> RaiseResult<Self> {
+        match patch {
+            JsonValue::Array(_) => match json::deserialize_from_value(patch) {
+                Ok(ops) => Ok(Self::JsonPatch(ops)),
+                Err(e) => raise_error!(
+                    "ERR_DB_PATCH_INVALID",
+                    error = format!("Opération JSON Patch invalide : {}", e)
+                ),
+            },
+            JsonValue::Object(_) => Ok(Self::MergePatch(patch)),
+            other => raise_error!(
+                "ERR_DB_PATCH_INVALID",
+                error = "Le patch doit être un tableau (JSON Patch) ou un objet (Merge Patch).",
+                context = json_value!({ "received": other })
+            ),
+        }
+    }
+
+    pub fn apply(self, target: &mut JsonValue) -> RaiseResult<()> {
+        match self {
+            Self::MergePatch(patch) => {
+                MergeStrategy::MergePatch.apply(target, patch);
+                Ok(())
+            }
+            Self::JsonPatch(ops) => {
+                let mut working = target.clone();
+                for (index, op) in ops.into_iter().enumerate() {
+                    if let Err(e) = apply_operation(&mut working, op) {
+                        raise_error!(
+                            "ERR_DB_PATCH_FAILED",
+                            error = e.to_string(),
+                            context = json_value!({ "operation_index": index })
+                        );
+                    }
+                }
+                *target = working;
+                Ok(())
+            }
+        }
+    }
+}
+
+fn apply_operation(doc: &mut JsonValue, op: PatchOperation) -> RaiseResult<()> {
+    match op {
+        PatchOperation::Add { path, value } => add(doc, &path, value),
+        PatchOperation::Remove { path } => remove(doc, &path).map(|_| ()),
+        PatchOperation::Replace { path, value } => {
+            check_pointer(&path)?;
+            match doc.pointer_mut(&path) {
+                Some(slot) => {
+                    *slot = value;
+                    Ok(())
+                }
+                None => path_not_found(&path),
+            }
+        }
+        PatchOperation::Move { from, path } => {
+            if path.starts_with(&format!("{}/", from)) {
+                raise_error!(
+                    "ERR_DB_PATCH_INVALID",
+                    error = format!("Impossible de déplacer '{}' dans son propre enfant.", from)
+                );
+            }
+            let value = remove(doc, &from)?;
+            add(doc, &path, value)
+        }
+        PatchOperation::Copy { from, path } => {
+            check_pointer(&from)?;
+            let Some(value) = doc.pointer(&from).cloned() else {
+                return path_not_found(&from);
+            };
+            add(doc, &path, value)
+        }
+        PatchOperation::Test { path, value } => {
+            check_pointer(&path)?;
+            if doc.pointer(&path) != Some(&value) {
+                raise_error!(
+                    "ERR_DB_PATCH_TEST_FAILED",
+                    error = format!("Test échoué sur '{}'.", path),
+                    context = json_value!({ "path": path, "expected": value, "actual": doc.pointer(&path) })
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+fn check_pointer(path: &str) -> RaiseResult<()> {
+    if !path.is_empty() && !path.starts_with('/') {
+        raise_error!(
+            "ERR_DB_PATCH_INVALID",
+            error = format!("Pointeur JSON invalide : '{}'.", path)
+        );
+    }
+    Ok(())
+}
+
+fn path_not_found<T>(path: &str) -> RaiseResult<T> {
+    raise_error!(
+        "ERR_DB_PATCH_PATH_NOT_FOUND",
+        error = format!("Chemin introuvable : '{}'.", path)
+    )
+}
+
+/// Découpe `/a/b~1c` en parent (`/a`) et dernier segment décodé (`b/c`).
+fn split_parent(path: &str) -> RaiseResult<(&str, String)> {
+    check_pointer(path)?;
+    let Some(pos) = path.rfind('/') else {
+        raise_error!(
+            "ERR_DB_PATCH_INVALID",
+            error = "La racine du document ne peut pas être la cible de cette opération."
+        );
+    };
+    let token = path[pos + 1..].replace("~1", "/").replace("~0", "~");
+    Ok((&path[..pos], token))
+}
+
+/// Index de tableau RFC 6901 : chiffres uniquement, sans zéro non significatif.
+fn array_index(token: &str, max: usize, path: &str) -> RaiseResult<usize> {
+    let valid = !token.is_empty()
+        && token.bytes().all(|b| b.is_ascii_digit())
+        && (token == "0" || !token.starts_with('0'));
+    match token.parse::<usize>() {
+        Ok(index) if valid && index <= max => Ok(index),
+        _ => raise_error!(
+            "ERR_DB_PATCH_INVALID",
+            error = format!("Index de tableau invalide dans '{}'.", path)
+        ),
+    }
+}
+
+fn add(doc: &mut JsonValue, path: &str, value: JsonValue) -> RaiseResult<()> {
+    if path.is_empty() {
+        *doc = value;
+        return Ok(());
+    }
+    let (parent, token) = split_parent(path)?;
+    match doc.pointer_mut(parent) {
+        Some(JsonValue::Object(map)) => {
+            map.insert(token, value);
+            Ok(())
+        }
+        Some(JsonValue::Array(items)) => {
+            let index = if token == "-" {
+                items.len()
+            } else {
+                array_index(&token, items.len(), path)?
+            };
+            items.insert(index, value);
+            Ok(())
+        }
+        _ => path_not_found(parent),
+    }
+}
+
+fn remove(doc: &mut JsonValue, path: &str) -> RaiseResult<JsonValue> {
+    let (parent, token) = split_parent(path)?;
+    let removed = match doc.pointer_mut(parent) {
+        Some(JsonValue::Object(map)) => map.remove(&token),
+        Some(JsonValue::Array(items)) if !items.is_empty() => {
+            let index = array_index(&token, items.len() - 1, path)?;
+            Some(items.remove(index))
+        }
+        _ => None,
+    };
+    match removed {
+        Some(value) => Ok(value),
+        None => path_not_found(path),
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc() -> JsonValue {
+        json_value!({ "name": "Pump", "tags": ["a", "b"], "specs": { "mass": 1, "a/b": 2 } })
+    }
+
+    #[test]
+    fn test_json_patch_rfc6902_operations() -> RaiseResult<()> {
+        let mut target = doc();
+        DocumentPatch::from_value(json_value!([
+            { "op": "test", "path": "/name", "value": "Pump" },
+            { "op": "replace", "path": "/specs/mass", "value": 5 },
+            { "op": "add", "path": "/tags/1", "value": "x" },
+            { "op": "add", "path": "/tags/-", "value": "z" },
+            { "op": "remove", "path": "/specs/a~1b" },
+            { "op": "copy", "from": "/name", "path": "/label" },
+            { "op": "move", "from": "/label", "path": "/specs/label" }
+        ]))?
+        .apply(&mut target)?;
+        assert_eq!(
+            target,
+            json_value!({
+                "name": "Pump",
+                "tags": ["a", "x", "b", "z"],
+                "specs": { "mass": 5, "label": "Pump" }
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_patch_is_atomic() -> RaiseResult<()> {
+        let mut target = doc();
+        let patch = DocumentPatch::from_value(json_value!([
+            { "op": "replace", "path": "/name", "value": "Valve" },
+            { "op": "test", "path": "/specs/mass", "value": 99 }
+        ]))?;
+        assert!(patch.apply(&mut target).is_err());
+        assert_eq!(target, doc());
+
+        for bad in [
+            json_value!([{ "op": "remove", "path": "/missing" }]),
+            json_value!([{ "op": "add", "path": "/tags/07", "value": 1 }]),
+            json_value!([{ "op": "move", "from": "/specs", "path": "/specs/inner" }]),
+        ] {
+            assert!(DocumentPatch::from_value(bad)?.apply(&mut target).is_err());
+        }
+        assert!(DocumentPatch::from_value(json_value!([{ "op": "explode" }])).is_err());
+        assert!(DocumentPatch::from_value(json_value!("oops")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_patch_object() -> RaiseResult<()> {
+        let mut target = doc();
+        DocumentPatch::from_value(json_value!({ "specs": { "mass": null }, "name": "Valve" }))?
+            .apply(&mut target)?;
+        assert_eq!(target["name"], "Valve");
+        assert_eq!(target["specs"], json_value!({ "a/b": 2 }));
+        Ok(())
+    }
+}
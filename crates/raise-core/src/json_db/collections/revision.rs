@@ -0,0 +1,95 @@
+// FICHIER : src-tauri/src/json_db/collections/revision.rs
+
+//! Contrôle de concurrence optimiste : chaque écriture de `update_document` /
+//! `upsert_document` incrémente `_rev`. Un appelant qui renvoie le `_rev` qu'il a lu voit son
+//! écriture refusée si le document a changé entre-temps (mise à jour perdue évitée).
+//! Sans `_rev` dans le patch, l'écriture passe (dernier écrivain gagnant) et incrémente le compteur.
+
+use crate::utils::prelude::*;
+
+/// Champ portant la révision d'un document (1 à la création).
+pub const REVISION_FIELD: &str = "_rev";
+
+/// Révision d'un document ; 0 pour un document absent ou antérieur au versionnage.
+pub fn current_revision(doc: Option<&JsonValue>) -> u64 {
+    doc.and_then(|d| d.get(REVISION_FIELD))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0)
+}
+
+/// Retire du patch la révision attendue par l'appelant (`_rev`), si elle est fournie.
+pub fn take_expected(patch: &mut JsonValue) -> RaiseResult<Option<u64>> {
+    let Some(value) = patch.as_object_mut().and_then(|o| o.remove(REVISION_FIELD)) else {
+        return Ok(None);
+    };
+    match value {
+        JsonValue::Null => Ok(None),
+        v => match v.as_u64() {
+            Some(rev) => Ok(Some(rev)),
+            None => raise_error!(
+                "ERR_DB_REVISION_INVALID",
+                error = "La révision '_rev' doit être un entier positif.",
+                context = json_value!({ "received": v })
+            ),
+        },
+    }
+}
+
+/// Refuse l'écriture si la révision attendue n'est plus celle du document stocké.
+pub fn check(collection: &str, id: &str, expected: Option<u64>, current: u64) -> RaiseResult<()> {
+    match expected {
+        Some(expected) if expected != current => raise_error!(
+            "ERR_DB_REVISION_CONFLICT",
+            error = format!(
+                "Conflit d'écriture sur '{}/{}' : révision {} attendue, {} trouvée.",
+                collection, id, expected, current
+            ),
+            context = json_value!({
+                "collection": collection,
+                "id": id,
+                "expected_rev": expected,
+                "current_rev": current,
+                "hint": "Relisez le document, réappliquez la modification puis renvoyez le nouveau '_rev'."
+            })
+        ),
+        _ => Ok(()),
+    }
+}
+
+pub fn set_revision(doc: &mut JsonValue, rev: u64) {
+    if let Some(obj) = doc.as_object_mut() {
+        obj.insert(REVISION_FIELD.to_string(), json_value!(rev));
+    }
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_revision_and_conflict() -> RaiseResult<()> {
+        let mut patch = json_value!({ "name": "Pump", "_rev": 3 });
+        assert_eq!(take_expected(&mut patch)?, Some(3));
+        assert_eq!(patch, json_value!({ "name": "Pump" }));
+        assert_eq!(take_expected(&mut patch)?, None);
+        assert!(take_expected(&mut json_value!({ "_rev": "3" })).is_err());
+
+        assert_eq!(current_revision(None), 0);
+        let mut doc = json_value!({ "_id": "p1" });
+        assert_eq!(current_revision(Some(&doc)), 0);
+        set_revision(&mut doc, 4);
+        assert_eq!(current_revision(Some(&doc)), 4);
+
+        check("parts", "p1", None, 4)?;
+        check("parts", "p1", Some(4), 4)?;
+        let Err(AppError::Structured(err)) = check("parts", "p1", Some(3), 4) else {
+            panic!("Une révision périmée doit être refusée");
+        };
+        assert_eq!(err.code, "ERR_DB_REVISION_CONFLICT");
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+// FICHIER : src-tauri/src/json_db/collections/ttl.rs
+
+//! Documents à durée de vie : une collection déclare dans son `_meta.json` un champ date et
+//! un délai (`"ttl": { "field": "_updated_at", "expireAfterSeconds": 86400 }`). Une tâche de
+//! fond lancée par le `StorageEngine` supprime périodiquement les documents expirés.
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+/// Clé du `_meta.json` portant la politique d'expiration d'une collection.
+pub const META_TTL_KEY: &str = "ttl";
+
+/// Au-delà, une date numérique est lue en millisecondes plutôt qu'en secondes.
+const MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serializable, Deserializable)]
+pub struct TtlPolicy {
+    /// Champ date du document : RFC 3339, ou epoch en secondes / millisecondes.
+    pub field: String,
+    #[serde(rename = "expireAfterSeconds", alias = "expire_after_secs")]
+    pub expire_after_secs: u64,
+}
+
+impl TtlPolicy {
+    pub fn new(field: &str, expire_after_secs: u64) -> Self {
+        Self {
+            field: field.to_string(),
+            expire_after_secs,
+        }
+    }
+
+    /// Échéance du document (epoch secondes) ; `None` si le champ est absent ou illisible,
+    /// auquel cas le document n'expire jamais.
+    pub fn expires_at(&self, doc: &JsonValue) -> Option<i64> {
+        let date = parse_timestamp(doc.pointer(&format!("/{}", self.field.replace('.', "/")))?)?;
+        Some(date.saturating_add(self.expire_after_secs as i64))
+    }
+
+    pub fn is_expired(&self, doc: &JsonValue, now: i64) -> bool {
+        self.expires_at(doc).is_some_and(|at| at <= now)
+    }
+}
+
+fn parse_timestamp(value: &JsonValue) -> Option<i64> {
+    match value {
+        JsonValue::Number(n) => {
+            let ts = n.as_i64().or_else(|| n.as_f64().map(|f| f as i64))?;
+            Some(if ts.abs() >= MILLIS_THRESHOLD {
+                ts / 1000
+            } else {
+                ts
+            })
+        }
+        JsonValue::String(s) => parse_system_time(s).ok().map(|d| d.timestamp()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct TtlSweepReport {
+    pub space: String,
+    pub db: String,
+    /// Documents supprimés par collection.
+    pub removed: OrderedMap<String, usize>,
+    pub total_removed: usize,
+}
+
+/// Supprime les documents expirés de chaque collection de la base. La politique du
+/// `_meta.json` prévaut ; `defaults` couvre les collections qui n'en déclarent pas.
+pub async fn sweep_expired(
+    manager: &CollectionsManager<'_>,
+    defaults: &OrderedMap<String, TtlPolicy>,
+) -> RaiseResult<TtlSweepReport> {
+    let mut report = TtlSweepReport {
+        space: manager.space.clone(),
+        db: manager.db.clone(),
+        ..Default::default()
+    };
+    let now = UtcClock::now().timestamp();
+
+    for collection in manager.list_collections().await? {
+        let policy = match manager.collection_ttl(&collection).await {
+            Some(policy) => policy,
+            None => match defaults.get(&collection) {
+                Some(policy) => policy.clone(),
+                None => continue,
+            },
+        };
+
+        let mut removed = 0;
+        for doc in manager.list_all(&collection).await? {
+            if !policy.is_expired(&doc, now) {
+                continue;
+            }
+            let Some(id) = doc.get("_id").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            if manager.delete_document(&collection, id).await? {
+                removed += 1;
+            }
+        }
+        if removed > 0 {
+            report.total_removed += removed;
+            report.removed.insert(collection, removed);
+        }
+    }
+
+    if report.total_removed > 0 {
+        user_info!(
+            "INF_DB_TTL_SWEEP_DONE",
+            json_value!({
+                "space": report.space,
+                "db": report.db,
+                "removed": report.removed
+            })
+        );
+    }
+    Ok(report)
+}
+
+/// Balayage périodique des documents expirés (`AppConfig.ttl`).
+pub fn spawn_ttl_sweeper(
+    storage: StorageEngine,
+    space: String,
+    db: String,
+    interval: TimeDuration,
+    defaults: OrderedMap<String, TtlPolicy>,
+) -> tokio::task::JoinHandle<()> {
+    spawn_async_task(async move {
+        loop {
+            sleep_async(interval).await;
+            let manager = CollectionsManager::new(&storage, &space, &db);
+            if let Err(e) = sweep_expired(&manager, &defaults).await {
+                user_warn!(
+                    "WRN_DB_TTL_SWEEP_FAILED",
+                    json_value!({ "space": space, "db": db, "error": e.to_string() })
+                );
+            }
+        }
+    })
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_ttl_policy_parses_dates() {
+        let policy = TtlPolicy::new("meta.seen", 60);
+        let now = 1_700_000_000;
+        assert!(policy.is_expired(&json_value!({ "meta": { "seen": now - 61 } }), now));
+        assert!(!policy.is_expired(&json_value!({ "meta": { "seen": now - 10 } }), now));
+        // Millisecondes et RFC 3339
+        assert!(policy.is_expired(
+            &json_value!({ "meta": { "seen": (now - 120) * 1000 } }),
+            now
+        ));
+        assert_eq!(
+            policy.expires_at(&json_value!({ "meta": { "seen": "2023-11-14T22:13:20Z" } })),
+            Some(1_700_000_060)
+        );
+        // Sans date lisible, le document n'expire pas
+        assert!(!policy.is_expired(&json_value!({ "meta": {} }), now));
+
+        let parsed: TtlPolicy = json::deserialize_from_value(
+            json_value!({ "field": "_updated_at", "expireAfterSeconds": 30 }),
+        )
+        .unwrap();
+        assert_eq!(parsed, TtlPolicy::new("_updated_at", 30));
+    }
+
+    #[async_test]
+    async fn test_sweep_removes_expired_documents() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "ttl");
+        DbSandbox::mock_db(&manager).await?;
+        let schema = "db://test/ttl/schemas/v1/db/generic.schema.json";
+        manager.create_collection("agent_sessions", schema).await?;
+        manager.create_collection("notes", schema).await?;
+
+        let now = UtcClock::now().timestamp();
+        manager
+            .insert_raw(
+                "agent_sessions",
+                &json_value!({ "_id": "old", "last_seen": now - 3_600 }),
+            )
+            .await?;
+        manager
+            .insert_raw(
+                "agent_sessions",
+                &json_value!({ "_id": "fresh", "last_seen": now }),
+            )
+            .await?;
+        manager
+            .insert_raw("notes", &json_value!({ "_id": "n1", "last_seen": 0 }))
+            .await?;
+
+        // Politique par défaut, puis politique déclarée dans `_meta.json`
+        let mut defaults = OrderedMap::new();
+        defaults.insert(
+            "agent_sessions".to_string(),
+            TtlPolicy::new("last_seen", 60),
+        );
+        let report = sweep_expired(&manager, &defaults).await?;
+        assert_eq!(report.total_removed, 1);
+        assert!(manager
+            .get_document("agent_sessions", "old")
+            .await?
+            .is_none());
+        assert!(manager
+            .get_document("agent_sessions", "fresh")
+            .await?
+            .is_some());
+        assert!(manager.get_document("notes", "n1").await?.is_some());
+
+        manager
+            .set_collection_ttl("notes", Some(TtlPolicy::new("last_seen", 60)))
+            .await?;
+        assert_eq!(
+            manager.collection_ttl("notes").await,
+            Some(TtlPolicy::new("last_seen", 60))
+        );
+        let report = sweep_expired(&manager, &OrderedMap::new()).await?;
+        assert_eq!(report.removed.get("notes"), Some(&1));
+        Ok(())
+    }
+}
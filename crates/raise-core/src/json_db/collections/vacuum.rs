@@ -0,0 +1,402 @@
+// FICHIER : src-tauri/src/json_db/collections/vacuum.rs
+
+//! Maintenance d'une base : purge des entrées mortes de `_system.json`, des fichiers
+//! temporaires abandonnés par les écritures atomiques et des segments d'index périmés.
+
+use crate::json_db::collections::collection;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::indexes::{driver, paths, IndexManager};
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct VacuumOptions {
+    /// Rapport seul : rien n'est supprimé ni réécrit.
+    pub dry_run: bool,
+    /// Âge minimal d'un fichier `*.tmp.*` avant suppression.
+    pub min_temp_age_secs: u64,
+}
+
+impl Default for VacuumOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            min_temp_age_secs: AppConfig::get().vacuum.min_temp_age_secs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct VacuumReport {
+    pub space: String,
+    pub db: String,
+    pub dry_run: bool,
+    pub collections_scanned: usize,
+    /// Entrées `items` de `_system.json` dont le fichier document n'existe plus.
+    pub dead_entries_removed: usize,
+    /// Fichiers temporaires d'écriture atomique abandonnés (crash, arrêt brutal).
+    pub temp_files_removed: usize,
+    /// Fichiers `_indexes/*.idx` sans définition dans `_meta.json`.
+    pub stale_index_files_removed: usize,
+    /// Références d'index vers des documents supprimés.
+    pub index_entries_pruned: usize,
+    pub reclaimed_bytes: u64,
+}
+
+/// Passe de maintenance complète sur la base du manager.
+pub async fn vacuum(
+    manager: &CollectionsManager<'_>,
+    options: &VacuumOptions,
+) -> RaiseResult<VacuumReport> {
+    let mut report = VacuumReport {
+        space: manager.space.clone(),
+        db: manager.db.clone(),
+        dry_run: options.dry_run,
+        ..Default::default()
+    };
+    let config = &manager.storage.config;
+    let db_root = config.db_root(&manager.space, &manager.db);
+    let min_age = TimeDuration::from_secs(options.min_temp_age_secs);
+
+    // 1. Fichiers temporaires à la racine de la base et dans le WAL
+    sweep_temp_files(&db_root, min_age, options.dry_run, &mut report).await?;
+    sweep_temp_files(&db_root.join("wal"), min_age, options.dry_run, &mut report).await?;
+
+    // 2. Collections physiques
+    let mut live_by_collection: OrderedMap<String, UniqueSet<String>> = OrderedMap::new();
+    let collections_root = db_root.join("collections");
+    if fs::exists_async(&collections_root).await {
+        let mut entries = fs::read_dir_async(&collections_root).await?;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let Some(name) = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(str::to_string)
+            else {
+                continue;
+            };
+            if !path.is_dir() {
+                continue;
+            }
+            report.collections_scanned += 1;
+
+            sweep_temp_files(&path, min_age, options.dry_run, &mut report).await?;
+            sweep_temp_files(
+                &path.join("_indexes"),
+                min_age,
+                options.dry_run,
+                &mut report,
+            )
+            .await?;
+
+            let live: UniqueSet<String> = collection::list_document_ids(
+                config,
+                &manager.space,
+                &manager.db,
+                &name,
+                None,
+                None,
+            )
+            .await?
+            .into_iter()
+            .collect();
+            vacuum_indexes(manager, &name, &live, options, &mut report).await?;
+            live_by_collection.insert(name, live);
+        }
+    }
+
+    // 3. Entrées mortes de l'index système
+    remove_dead_entries(manager, &live_by_collection, options.dry_run, &mut report).await?;
+
+    user_info!(
+        "INF_DB_VACUUM_DONE",
+        json_value!({
+            "space": report.space,
+            "db": report.db,
+            "dry_run": report.dry_run,
+            "reclaimed_bytes": report.reclaimed_bytes
+        })
+    );
+    Ok(report)
+}
+
+/// Planifie un `vacuum` périodique de la base (`AppConfig.vacuum`).
+pub fn spawn_vacuum_scheduler(
+    storage: StorageEngine,
+    space: String,
+    db: String,
+    interval: TimeDuration,
+) -> tokio::task::JoinHandle<()> {
+    spawn_async_task(async move {
+        loop {
+            sleep_async(interval).await;
+            let manager = CollectionsManager::new(&storage, &space, &db);
+            if let Err(e) = vacuum(&manager, &VacuumOptions::default()).await {
+                user_warn!(
+                    "WRN_DB_VACUUM_FAILED",
+                    json_value!({ "space": space, "db": db, "error": e.to_string() })
+                );
+            }
+        }
+    })
+}
+
+/// `<nom>.tmp.<uuid>` : motif des fichiers intermédiaires de `write_*_atomic_async`.
+fn is_temp_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.contains(".tmp."))
+}
+
+async fn sweep_temp_files(
+    dir: &Path,
+    min_age: TimeDuration,
+    dry_run: bool,
+    report: &mut VacuumReport,
+) -> RaiseResult<()> {
+    if !fs::exists_async(dir).await {
+        return Ok(());
+    }
+    let mut entries = fs::read_dir_async(dir).await?;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if !path.is_file() || !is_temp_file(&path) {
+            continue;
+        }
+        let Ok(meta) = fs::metadata_async(&path).await else {
+            continue;
+        };
+        let old_enough = meta
+            .modified()
+            .ok()
+            .and_then(|m| m.elapsed().ok())
+            .is_some_and(|age| age >= min_age);
+        if !old_enough {
+            continue;
+        }
+        if !dry_run {
+            fs::remove_file_async(&path).await?;
+        }
+        report.temp_files_removed += 1;
+        report.reclaimed_bytes += meta.len();
+    }
+    Ok(())
+}
+
+async fn vacuum_indexes(
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+    live: &UniqueSet<String>,
+    options: &VacuumOptions,
+    report: &mut VacuumReport,
+) -> RaiseResult<()> {
+    let config = &manager.storage.config;
+    let (space, db) = (manager.space.as_str(), manager.db.as_str());
+    let definitions = IndexManager::new(manager.storage, space, db)
+        .list_indexes(collection, None)
+        .await?;
+    let expected: Vec<PathBuf> = definitions
+        .iter()
+        .map(|def| paths::index_path(config, space, db, collection, &def.name, def.index_type))
+        .collect();
+
+    // Segments sans définition (index supprimé, type changé...)
+    let indexes_dir = paths::indexes_root(config, space, db, collection);
+    if fs::exists_async(&indexes_dir).await {
+        let mut entries = fs::read_dir_async(&indexes_dir).await?;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let is_segment = path.extension().is_some_and(|e| e == "idx");
+            if !is_segment || expected.contains(&path) {
+                continue;
+            }
+            let size = fs::metadata_async(&path)
+                .await
+                .map(|m| m.len())
+                .unwrap_or(0);
+            if !options.dry_run {
+                fs::remove_file_async(&path).await?;
+            }
+            report.stale_index_files_removed += 1;
+            report.reclaimed_bytes += size;
+        }
+    }
+
+    // Références vers des documents supprimés (format d'enregistrement commun aux trois types)
+    for path in &expected {
+        if !fs::exists_async(path).await {
+            continue;
+        }
+        let mut index: OrderedMap<String, Vec<String>> = driver::load(config, path).await?;
+        let mut pruned = 0;
+        index.retain(|_, ids| {
+            let before = ids.len();
+            ids.retain(|id| live.contains(id));
+            pruned += before - ids.len();
+            !ids.is_empty()
+        });
+        if pruned == 0 {
+            continue;
+        }
+        report.index_entries_pruned += pruned;
+        if options.dry_run {
+            continue;
+        }
+        let before = fs::metadata_async(path).await.map(|m| m.len()).unwrap_or(0);
+        driver::save(config, path, &index).await?;
+        let after = fs::metadata_async(path).await.map(|m| m.len()).unwrap_or(0);
+        report.reclaimed_bytes += before.saturating_sub(after);
+    }
+    Ok(())
+}
+
+async fn remove_dead_entries(
+    manager: &CollectionsManager<'_>,
+    live_by_collection: &OrderedMap<String, UniqueSet<String>>,
+    dry_run: bool,
+    report: &mut VacuumReport,
+) -> RaiseResult<()> {
+    let sys_path = manager
+        .storage
+        .config
+        .db_root(&manager.space, &manager.db)
+        .join("_system.json");
+    if !fs::exists_async(&sys_path).await {
+        return Ok(());
+    }
+
+    let lock = manager
+        .storage
+        .get_index_lock(&manager.space, &manager.db)?;
+    let guard = lock.lock().await;
+    let mut tx = manager.begin_system_tx(&guard).await?;
+
+    let mut removed = 0;
+    if let Some(cols) = tx
+        .document
+        .get_mut("collections")
+        .and_then(|c| c.as_object_mut())
+    {
+        for (name, entry) in cols.iter_mut() {
+            let empty = UniqueSet::new();
+            let live = live_by_collection.get(name).unwrap_or(&empty);
+            if let Some(items) = entry.get_mut("items").and_then(|i| i.as_array_mut()) {
+                let before = items.len();
+                items.retain(|item| {
+                    item.get("file")
+                        .and_then(|f| f.as_str())
+                        .map(|f| f.trim_end_matches(".json"))
+                        .is_none_or(|id| live.contains(id))
+                });
+                removed += before - items.len();
+            }
+        }
+    }
+
+    report.dead_entries_removed += removed;
+    if removed == 0 || dry_run {
+        return Ok(());
+    }
+    let before = fs::metadata_async(&sys_path)
+        .await
+        .map(|m| m.len())
+        .unwrap_or(0);
+    tx.commit().await?;
+    let after = fs::metadata_async(&sys_path)
+        .await
+        .map(|m| m.len())
+        .unwrap_or(0);
+    report.reclaimed_bytes += before.saturating_sub(after);
+    Ok(())
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[async_test]
+    async fn test_vacuum_reclaims_orphans_and_stale_index_data() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "vacuum");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "items",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        IndexManager::new(&sandbox.storage, "test", "vacuum")
+            .create_index("items", "name", "hash")
+            .await?;
+        for id in ["a", "b"] {
+            manager
+                .insert_with_schema("items", json_value!({ "_id": id, "name": id }))
+                .await?;
+        }
+
+        // Suppression "sauvage" du fichier : l'index système et l'index hash gardent la trace de "b"
+        let col_path = sandbox
+            .storage
+            .config
+            .db_collection_path("test", "vacuum", "items");
+        fs::remove_file_async(&col_path.join("b.json")).await?;
+        fs::write_async(&col_path.join("a.tmp.dead"), b"partial").await?;
+        fs::write_async(&col_path.join("_indexes/old.btree.idx"), b"stale").await?;
+
+        let dry = vacuum(
+            &manager,
+            &VacuumOptions {
+                dry_run: true,
+                min_temp_age_secs: 0,
+            },
+        )
+        .await?;
+        assert_eq!(dry.temp_files_removed, 1);
+        assert_eq!(dry.stale_index_files_removed, 1);
+        assert_eq!(dry.index_entries_pruned, 1);
+        assert_eq!(dry.dead_entries_removed, 1);
+        assert!(col_path.join("a.tmp.dead").exists());
+
+        let report = vacuum(
+            &manager,
+            &VacuumOptions {
+                dry_run: false,
+                min_temp_age_secs: 0,
+            },
+        )
+        .await?;
+        assert!(report.reclaimed_bytes >= 12);
+        assert!(!col_path.join("a.tmp.dead").exists());
+        assert!(!col_path.join("_indexes/old.btree.idx").exists());
+        assert!(IndexManager::new(&sandbox.storage, "test", "vacuum")
+            .search("items", "name", &json_value!("b"))
+            .await?
+            .is_empty());
+
+        // Idempotence : une seconde passe ne trouve plus rien
+        let again = vacuum(
+            &manager,
+            &VacuumOptions {
+                dry_run: false,
+                min_temp_age_secs: 0,
+            },
+        )
+        .await?;
+        assert_eq!(again.reclaimed_bytes, 0);
+        assert_eq!(again.dead_entries_removed, 0);
+        assert!(manager.get_document("items", "a").await?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_temp_file_pattern() {
+        assert!(is_temp_file(Path::new("/db/_system.tmp.3f2a")));
+        assert!(!is_temp_file(Path::new("/db/items/doc.json")));
+    }
+}
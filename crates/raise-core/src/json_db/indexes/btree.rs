@@ -24,7 +24,15 @@ pub async fn update_btree_index(
         &def.name,
         def.index_type,
     );
-    driver::update::<OrderedMap<String, Vec<String>>>(&path, def, doc_id, old_doc, new_doc).await
+    driver::update::<OrderedMap<String, Vec<String>>>(
+        &storage.config,
+        &path,
+        def,
+        doc_id,
+        old_doc,
+        new_doc,
+    )
+    .await
 }
 
 pub async fn search_btree_index(
@@ -44,7 +52,59 @@ pub async fn search_btree_index(
         def.index_type,
     );
     let key = value.to_string();
-    driver::search::<OrderedMap<String, Vec<String>>>(&path, &key).await
+    driver::search::<OrderedMap<String, Vec<String>>>(&storage.config, &path, &key).await
+}
+
+/// Parcours ordonné de l'index : groupes d'IDs par clé croissante.
+/// Les clés sont stockées sérialisées (`"30"`, `"\"abc\""`) : on les relit en JSON
+/// pour trier selon la sémantique du moteur de requête (nombres numériques, chaînes lexicales).
+pub async fn scan_btree_index(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    def: &IndexDefinition,
+) -> RaiseResult<Vec<(JsonValue, Vec<String>)>> {
+    let path = paths::index_path(
+        &storage.config,
+        space,
+        db,
+        collection,
+        &def.name,
+        def.index_type,
+    );
+    let index: OrderedMap<String, Vec<String>> = driver::load(&storage.config, &path).await?;
+    let mut entries: Vec<(JsonValue, Vec<String>)> = index
+        .into_iter()
+        .map(|(key, ids)| {
+            let value = json::deserialize_from_str(&key).unwrap_or(JsonValue::String(key));
+            (value, ids)
+        })
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| compare_keys(a, b));
+    Ok(entries)
+}
+
+/// Ordre total sur les clés : null < booléens < nombres < chaînes < autres.
+fn compare_keys(a: &JsonValue, b: &JsonValue) -> FmtOrdering {
+    fn rank(v: &JsonValue) -> u8 {
+        match v {
+            JsonValue::Null => 0,
+            JsonValue::Bool(_) => 1,
+            JsonValue::Number(_) => 2,
+            JsonValue::String(_) => 3,
+            _ => 4,
+        }
+    }
+    match (a, b) {
+        (JsonValue::Number(x), JsonValue::Number(y)) => x
+            .as_f64()
+            .partial_cmp(&y.as_f64())
+            .unwrap_or(FmtOrdering::Equal),
+        (JsonValue::String(x), JsonValue::String(y)) => x.cmp(y),
+        (JsonValue::Bool(x), JsonValue::Bool(y)) => x.cmp(y),
+        _ => rank(a).cmp(&rank(b)),
+    }
 }
 
 #[cfg(test)]
@@ -75,6 +135,8 @@ mod tests {
             field_path: "/age".into(),
             index_type: IndexType::BTree,
             unique: false,
+            stemming: false,
+            fields: Vec::new(),
         };
 
         let doc1 = json_value!({ "age": 30 });
@@ -105,4 +167,34 @@ mod tests {
         assert!(results_empty.is_empty());
         Ok(())
     }
+
+    #[async_test]
+    async fn test_btree_scan_orders_numbers_numerically() -> RaiseResult<()> {
+        let (dir, cfg) = setup_env();
+        let storage = StorageEngine::new(cfg)?;
+        fs::create_dir_all_async(&dir.path().join("s/d/collections/c/_indexes")).await?;
+
+        let def = IndexDefinition {
+            name: "age".into(),
+            field_path: "/age".into(),
+            index_type: IndexType::BTree,
+            unique: false,
+            stemming: false,
+            fields: Vec::new(),
+        };
+        for (id, age) in [("u1", 100), ("u2", 25), ("u3", 9), ("u4", 25)] {
+            let doc = json_value!({ "age": age });
+            update_btree_index(&storage, "s", "d", "c", &def, id, None, Some(&doc)).await?;
+        }
+
+        // Ordre lexical des clés stockées : "100" < "25" < "9" ; le parcours doit être numérique
+        let scan = scan_btree_index(&storage, "s", "d", "c", &def).await?;
+        let keys: Vec<JsonValue> = scan.iter().map(|(k, _)| k.clone()).collect();
+        assert_eq!(
+            keys,
+            vec![json_value!(9), json_value!(25), json_value!(100)]
+        );
+        assert_eq!(scan[1].1, vec!["u2".to_string(), "u4".to_string()]);
+        Ok(())
+    }
 }
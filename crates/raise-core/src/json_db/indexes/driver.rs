@@ -1,6 +1,7 @@
 // FICHIER : src-tauri/src/json_db/indexes/driver.rs
 
 use super::{IndexDefinition, IndexRecord};
+use crate::json_db::storage::{encryption, JsonDbConfig};
 use crate::utils::prelude::*;
 
 /// Trait définissant le comportement d'une structure d'index en mémoire
@@ -96,11 +97,11 @@ impl IndexMap for OrderedMap<String, Vec<String>> {
 
 // --- Logique I/O Générique (Async) ---
 
-pub async fn load<T: IndexMap>(path: &Path) -> RaiseResult<T> {
+pub async fn load<T: IndexMap>(config: &JsonDbConfig, path: &Path) -> RaiseResult<T> {
     if !fs::exists_async(path).await {
         return Ok(T::default());
     }
-    let records: Vec<IndexRecord> = match fs::read_json_compressed_async(path).await {
+    let records: Vec<IndexRecord> = match encryption::read_json_compressed(config, path).await {
         Ok(r) => r,
         Err(e) => raise_error!(
             "ERR_DB_INDEX_LOAD_FAILED",
@@ -111,9 +112,9 @@ pub async fn load<T: IndexMap>(path: &Path) -> RaiseResult<T> {
     Ok(T::from_records(records))
 }
 
-pub async fn save<T: IndexMap>(path: &Path, index: &T) -> RaiseResult<()> {
+pub async fn save<T: IndexMap>(config: &JsonDbConfig, path: &Path, index: &T) -> RaiseResult<()> {
     let records = index.to_records();
-    match fs::write_json_compressed_atomic_async(path, &records).await {
+    match encryption::write_json_compressed_atomic(config, path, &records).await {
         Ok(_) => Ok(()),
         Err(e) => raise_error!(
             "ERR_DB_INDEX_SAVE_FAILED",
@@ -123,8 +124,12 @@ pub async fn save<T: IndexMap>(path: &Path, index: &T) -> RaiseResult<()> {
     }
 }
 
-pub async fn search<T: IndexMap>(path: &Path, key: &str) -> RaiseResult<Vec<String>> {
-    let index: T = match load(path).await {
+pub async fn search<T: IndexMap>(
+    config: &JsonDbConfig,
+    path: &Path,
+    key: &str,
+) -> RaiseResult<Vec<String>> {
+    let index: T = match load(config, path).await {
         Ok(idx) => idx,
         Err(e) => return Err(e),
     };
@@ -132,28 +137,27 @@ pub async fn search<T: IndexMap>(path: &Path, key: &str) -> RaiseResult<Vec<Stri
 }
 
 pub async fn update<T: IndexMap>(
+    config: &JsonDbConfig,
     path: &Path,
     def: &IndexDefinition,
     doc_id: &str,
     old_doc: Option<&JsonValue>,
     new_doc: Option<&JsonValue>,
 ) -> RaiseResult<()> {
-    let mut index: T = load(path).await?;
+    let mut index: T = load(config, path).await?;
     let mut changed = false;
 
     // Suppression
     if let Some(doc) = old_doc {
-        if let Some(old_key) = doc.pointer(&def.field_path) {
-            index.remove_record(&old_key.to_string(), doc_id);
+        if let Some(old_key) = def.key_of(doc) {
+            index.remove_record(&old_key, doc_id);
             changed = true;
         }
     }
 
     // Ajout
     if let Some(doc) = new_doc {
-        if let Some(new_key) = doc.pointer(&def.field_path) {
-            let key_str = new_key.to_string();
-
+        if let Some(key_str) = def.key_of(doc) {
             // Unicité
             if def.unique {
                 if let Some(ids) = index.get_doc_ids(&key_str) {
@@ -182,7 +186,7 @@ pub async fn update<T: IndexMap>(
     }
 
     if changed {
-        save(path, &index).await?;
+        save(config, path, &index).await?;
     }
     Ok(())
 }
@@ -209,25 +213,26 @@ mod tests {
     #[async_test]
     async fn test_driver_io_roundtrip_and_search() {
         let dir = tempdir().unwrap();
+        let cfg = JsonDbConfig::new(dir.path().to_path_buf());
         let path = dir.path().join("index.bin");
 
         // 1. Save (Async)
         let mut index: UnorderedMap<String, Vec<String>> = UnorderedMap::new();
         index.insert_record("key1".into(), "doc1".into());
-        save(&path, &index).await.unwrap();
+        save(&cfg, &path, &index).await.unwrap();
 
         // 2. Load (Async)
-        let loaded: UnorderedMap<String, Vec<String>> = load(&path).await.unwrap();
+        let loaded: UnorderedMap<String, Vec<String>> = load(&cfg, &path).await.unwrap();
         assert_eq!(loaded.get_doc_ids("key1").unwrap()[0], "doc1");
 
         // 3. Search (Async)
-        let results = search::<UnorderedMap<String, Vec<String>>>(&path, "key1")
+        let results = search::<UnorderedMap<String, Vec<String>>>(&cfg, &path, "key1")
             .await
             .unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0], "doc1");
 
-        let empty = search::<UnorderedMap<String, Vec<String>>>(&path, "missing")
+        let empty = search::<UnorderedMap<String, Vec<String>>>(&cfg, &path, "missing")
             .await
             .unwrap();
         assert!(empty.is_empty());
@@ -236,22 +241,25 @@ mod tests {
     #[async_test]
     async fn test_driver_update_logic() {
         let dir = tempdir().unwrap();
+        let cfg = JsonDbConfig::new(dir.path().to_path_buf());
         let path = dir.path().join("update_test.bin");
         let def = IndexDefinition {
             name: "test".into(),
             field_path: "/val".into(),
             index_type: IndexType::Hash,
             unique: true,
+            stemming: false,
+            fields: Vec::new(),
         };
 
         let doc = json::json_value!({"val": "A"});
 
         // Initial update
-        update::<UnorderedMap<String, Vec<String>>>(&path, &def, "id1", None, Some(&doc))
+        update::<UnorderedMap<String, Vec<String>>>(&cfg, &path, &def, "id1", None, Some(&doc))
             .await
             .unwrap();
 
-        let results = search::<UnorderedMap<String, Vec<String>>>(&path, "\"A\"")
+        let results = search::<UnorderedMap<String, Vec<String>>>(&cfg, &path, "\"A\"")
             .await
             .unwrap();
         assert_eq!(results, vec!["id1"]);
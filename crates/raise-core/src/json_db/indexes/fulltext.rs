@@ -0,0 +1,303 @@
+// FICHIER : src-tauri/src/json_db/indexes/fulltext.rs
+
+//! Index plein texte : index inversé (terme -> fréquences par document) et classement BM25.
+//! Contrairement à l'index `text` (présence de mots-clés), il conserve les fréquences et la
+//! longueur des documents pour ordonner les résultats par pertinence.
+
+use super::{paths, IndexDefinition};
+use crate::json_db::storage::{encryption, JsonDbConfig, StorageEngine};
+
+use crate::utils::prelude::*;
+
+/// Paramètres BM25 usuels (saturation des fréquences, normalisation par la longueur).
+const BM25_K1: f64 = 1.2;
+const BM25_B: f64 = 0.75;
+
+/// Suffixes retirés par la racinisation légère (français puis anglais, les plus longs d'abord).
+const STEM_SUFFIXES: &[&str] = &[
+    "issements",
+    "issement",
+    "ations",
+    "ation",
+    "ements",
+    "ement",
+    "ments",
+    "ment",
+    "ités",
+    "ité",
+    "euses",
+    "euse",
+    "eux",
+    "ings",
+    "ing",
+    "ies",
+    "ed",
+    "es",
+    "s",
+    "x",
+];
+/// Une racine garde au moins ce nombre de caractères.
+const STEM_MIN_LEN: usize = 3;
+
+#[derive(Debug, Default, Serializable, Deserializable)]
+pub struct FullTextIndex {
+    /// Longueur (en termes) de chaque document indexé.
+    docs: UnorderedMap<String, usize>,
+    /// terme -> (id du document -> fréquence)
+    postings: UnorderedMap<String, UnorderedMap<String, usize>>,
+}
+
+impl FullTextIndex {
+    /// (Ré)indexe un document : ses anciens termes sont retirés au préalable.
+    pub fn insert(&mut self, doc_id: &str, terms: &[String]) {
+        self.remove(doc_id);
+        self.docs.insert(doc_id.to_string(), terms.len());
+        for term in terms {
+            *self
+                .postings
+                .entry(term.clone())
+                .or_default()
+                .entry(doc_id.to_string())
+                .or_default() += 1;
+        }
+    }
+
+    pub fn remove(&mut self, doc_id: &str) {
+        if self.docs.remove(doc_id).is_none() {
+            return;
+        }
+        self.postings.retain(|_, freqs| {
+            freqs.remove(doc_id);
+            !freqs.is_empty()
+        });
+    }
+
+    /// Documents contenant tous les termes de la requête, par score BM25 décroissant.
+    pub fn rank(&self, query_terms: &[String]) -> Vec<(String, f64)> {
+        let terms: UniqueSet<&String> = query_terms.iter().collect();
+        let mut postings = Vec::with_capacity(terms.len());
+        for term in terms {
+            match self.postings.get(term) {
+                Some(freqs) => postings.push(freqs),
+                None => return Vec::new(),
+            }
+        }
+        let Some((first, others)) = postings.split_first() else {
+            return Vec::new();
+        };
+
+        let total = self.docs.len() as f64;
+        let avg_len = self.docs.values().sum::<usize>() as f64 / total.max(1.0);
+        let mut ranked: Vec<(String, f64)> = first
+            .keys()
+            .filter(|id| others.iter().all(|freqs| freqs.contains_key(*id)))
+            .map(|id| {
+                let len = self.docs.get(id).copied().unwrap_or_default() as f64;
+                let score = postings
+                    .iter()
+                    .map(|freqs| {
+                        let df = freqs.len() as f64;
+                        let tf = freqs.get(id).copied().unwrap_or_default() as f64;
+                        let idf = (1.0 + (total - df + 0.5) / (df + 0.5)).ln();
+                        let norm = BM25_K1 * (1.0 - BM25_B + BM25_B * len / avg_len.max(1.0));
+                        idf * tf * (BM25_K1 + 1.0) / (tf + norm)
+                    })
+                    .sum();
+                (id.clone(), score)
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+}
+
+/// Découpe en termes minuscules (alphanumériques), racinisés si demandé.
+/// Les doublons sont conservés : ils portent la fréquence du terme.
+pub fn analyze(text: &str, stemming: bool) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| if stemming { stem(s) } else { s.to_string() })
+        .collect()
+}
+
+/// Racinisation légère : retire un suffixe connu puis un `e` final.
+pub fn stem(token: &str) -> String {
+    let long_enough = |s: &str| s.chars().count() >= STEM_MIN_LEN;
+    let mut root = STEM_SUFFIXES
+        .iter()
+        .find_map(|suffix| token.strip_suffix(suffix).filter(|r| long_enough(r)))
+        .unwrap_or(token);
+    if let Some(r) = root.strip_suffix('e').filter(|r| long_enough(r)) {
+        root = r;
+    }
+    root.to_string()
+}
+
+/// Texte indexable d'une valeur : chaîne, ou chaînes d'un tableau mises bout à bout.
+pub fn value_text(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Array(items) => {
+            let parts: Vec<&str> = items.iter().filter_map(|v| v.as_str()).collect();
+            (!parts.is_empty()).then(|| parts.join(" "))
+        }
+        _ => None,
+    }
+}
+
+/// Vrai si `text` contient tous les termes de `query` (racinisés des deux côtés).
+pub fn matches(text: &str, query: &str) -> bool {
+    let terms: UniqueSet<String> = analyze(text, true).into_iter().collect();
+    let query_terms = analyze(query, true);
+    !query_terms.is_empty() && query_terms.iter().all(|t| terms.contains(t))
+}
+
+/// Classement BM25 d'un lot de documents `(id, texte)` sans index persistant.
+pub fn rank_texts(texts: &[(String, String)], query: &str) -> Vec<(String, f64)> {
+    let mut index = FullTextIndex::default();
+    for (id, text) in texts {
+        index.insert(id, &analyze(text, true));
+    }
+    index.rank(&analyze(query, true))
+}
+
+fn doc_terms(def: &IndexDefinition, doc: Option<&JsonValue>) -> Option<Vec<String>> {
+    let text = doc?.pointer(&def.field_path).and_then(value_text)?;
+    Some(analyze(&text, def.stemming))
+}
+
+async fn load(config: &JsonDbConfig, path: &Path) -> RaiseResult<FullTextIndex> {
+    if !fs::exists_async(path).await {
+        return Ok(FullTextIndex::default());
+    }
+    match encryption::read_json_compressed(config, path).await {
+        Ok(index) => Ok(index),
+        Err(e) => raise_error!(
+            "ERR_DB_INDEX_LOAD_FAILED",
+            error = e,
+            context = json_value!({ "path": path.to_string_lossy() })
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn update_fulltext_index(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    def: &IndexDefinition,
+    doc_id: &str,
+    old_doc: Option<&JsonValue>,
+    new_doc: Option<&JsonValue>,
+) -> RaiseResult<()> {
+    let new_terms = doc_terms(def, new_doc);
+    if old_doc.is_none() && new_terms.is_none() {
+        return Ok(());
+    }
+
+    let path = paths::index_path(
+        &storage.config,
+        space,
+        db,
+        collection,
+        &def.name,
+        def.index_type,
+    );
+    let mut index = load(&storage.config, &path).await?;
+    match new_terms {
+        Some(terms) => index.insert(doc_id, &terms),
+        None => index.remove(doc_id),
+    }
+
+    match encryption::write_json_compressed_atomic(&storage.config, &path, &index).await {
+        Ok(_) => Ok(()),
+        Err(e) => raise_error!(
+            "ERR_DB_INDEX_SAVE_FAILED",
+            error = e,
+            context = json_value!({ "path": path.to_string_lossy() })
+        ),
+    }
+}
+
+/// IDs des documents contenant tous les termes de `query`, avec leur score BM25 décroissant.
+pub async fn rank_fulltext_index(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    def: &IndexDefinition,
+    query: &str,
+) -> RaiseResult<Vec<(String, f64)>> {
+    let path = paths::index_path(
+        &storage.config,
+        space,
+        db,
+        collection,
+        &def.name,
+        def.index_type,
+    );
+    let index = load(&storage.config, &path).await?;
+    Ok(index.rank(&analyze(query, def.stemming)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_db::indexes::IndexType;
+
+    #[test]
+    fn test_stemming_and_ranking() {
+        assert_eq!(stem("requirements"), stem("required"));
+        assert_eq!(stem("capteurs"), "capteur");
+        assert_eq!(stem("bus"), "bus");
+
+        let ranked = rank_texts(
+            &[
+                ("a".into(), "Sensor fusion for the braking sensors".into()),
+                (
+                    "b".into(),
+                    "A long note that mentions a sensor once among many other words".into(),
+                ),
+                ("c".into(), "Braking system".into()),
+            ],
+            "sensor",
+        );
+        let ids: Vec<&str> = ranked.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert!(matches("Braking sensors", "SENSOR braking"));
+        assert!(!matches("Braking system", "sensor braking"));
+    }
+
+    #[async_test]
+    async fn test_fulltext_lifecycle() -> RaiseResult<()> {
+        let dir = tempdir().unwrap();
+        let storage = StorageEngine::new(JsonDbConfig::new(dir.path().to_path_buf()))?;
+        fs::ensure_dir_async(&dir.path().join("s/d/collections/c/_indexes")).await?;
+
+        let def = IndexDefinition {
+            name: "bio".into(),
+            field_path: "/bio".into(),
+            index_type: IndexType::FullText,
+            unique: false,
+            stemming: true,
+            fields: Vec::new(),
+        };
+        let u1 = json_value!({ "bio": "Rust engineers love Rust" });
+        let u2 = json_value!({ "bio": ["Embedded engineer", "C and Rust"] });
+        update_fulltext_index(&storage, "s", "d", "c", &def, "u1", None, Some(&u1)).await?;
+        update_fulltext_index(&storage, "s", "d", "c", &def, "u2", None, Some(&u2)).await?;
+
+        let ranked = rank_fulltext_index(&storage, "s", "d", "c", &def, "rust engineer").await?;
+        let ids: Vec<&str> = ranked.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["u1", "u2"]);
+
+        update_fulltext_index(&storage, "s", "d", "c", &def, "u1", Some(&u1), None).await?;
+        let ranked = rank_fulltext_index(&storage, "s", "d", "c", &def, "rust").await?;
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].0, "u2");
+        Ok(())
+    }
+}
@@ -0,0 +1,394 @@
+// FICHIER : src-tauri/src/json_db/indexes/geo.rs
+
+//! Index géographique : grille régulière de cellules (`GEO_CELL_DEG` degrés de côté) et
+//! position exacte de chaque document. Une recherche ne visite que les cellules couvrant la
+//! zone demandée (cercle ou rectangle), puis filtre sur la distance réelle (haversine).
+
+use super::{paths, IndexDefinition};
+use crate::json_db::storage::{encryption, JsonDbConfig, StorageEngine};
+
+use crate::utils::prelude::*;
+
+/// Côté d'une cellule de la grille, en degrés (~11 km en latitude).
+pub const GEO_CELL_DEG: f64 = 0.1;
+/// Rayon terrestre moyen (km) utilisé par la formule de haversine.
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+/// Distance (km) couverte par un degré de latitude.
+const KM_PER_DEG_LAT: f64 = 111.195;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serializable, Deserializable)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl GeoPoint {
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self { lat, lon }
+    }
+
+    /// Lit une position : `{ "lat", "lon" }` (alias `lng`, `latitude` / `longitude`),
+    /// point GeoJSON `{ "type": "Point", "coordinates": [lon, lat] }` ou `[lon, lat]`.
+    /// `None` si les coordonnées manquent ou sortent des bornes.
+    pub fn from_value(value: &JsonValue) -> Option<Self> {
+        let (lat, lon) = match value {
+            JsonValue::Array(coords) => (coords.get(1)?.as_f64()?, coords.first()?.as_f64()?),
+            JsonValue::Object(obj) => {
+                if let Some(coords) = obj.get("coordinates") {
+                    return Self::from_value(coords);
+                }
+                let lat = obj.get("lat").or_else(|| obj.get("latitude"))?.as_f64()?;
+                let lon = obj
+                    .get("lon")
+                    .or_else(|| obj.get("lng"))
+                    .or_else(|| obj.get("longitude"))?
+                    .as_f64()?;
+                (lat, lon)
+            }
+            _ => return None,
+        };
+        let valid = (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon);
+        valid.then_some(Self { lat, lon })
+    }
+
+    /// Distance orthodromique en kilomètres.
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.lon - self.lon).to_radians();
+        let h = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+    }
+
+    fn cell(&self) -> (i32, i32) {
+        (
+            (self.lat / GEO_CELL_DEG).floor() as i32,
+            (self.lon / GEO_CELL_DEG).floor() as i32,
+        )
+    }
+}
+
+/// Zone recherchée : opérateur `Near` (point + rayon) ou `Within` (rectangle).
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoShape {
+    Circle { center: GeoPoint, radius_km: f64 },
+    BoundingBox { min: GeoPoint, max: GeoPoint },
+}
+
+impl GeoShape {
+    /// `{ "lat", "lon", "radius_km" }` ; le centre peut aussi être fourni sous `center`
+    /// (tout format accepté par `GeoPoint::from_value`) et le rayon en mètres (`radius_m`).
+    pub fn circle(value: &JsonValue) -> Option<Self> {
+        let obj = value.as_object()?;
+        let radius_km = match (obj.get("radius_km"), obj.get("radius_m")) {
+            (Some(km), _) => km.as_f64()?,
+            (None, Some(m)) => m.as_f64()? / 1000.0,
+            (None, None) => return None,
+        };
+        let center = GeoPoint::from_value(obj.get("center").unwrap_or(value))?;
+        (radius_km >= 0.0).then_some(Self::Circle { center, radius_km })
+    }
+
+    /// `{ "min_lat", "min_lon", "max_lat", "max_lon" }` (le rectangle ne traverse pas
+    /// l'antiméridien).
+    pub fn bounding_box(value: &JsonValue) -> Option<Self> {
+        let coord = |key: &str| value.get(key).and_then(|v| v.as_f64());
+        let min = GeoPoint::from_value(&json_value!({
+            "lat": coord("min_lat")?, "lon": coord("min_lon")?
+        }))?;
+        let max = GeoPoint::from_value(&json_value!({
+            "lat": coord("max_lat")?, "lon": coord("max_lon")?
+        }))?;
+        (min.lat <= max.lat && min.lon <= max.lon).then_some(Self::BoundingBox { min, max })
+    }
+
+    /// Cercle ou rectangle selon les clés présentes.
+    pub fn from_value(value: &JsonValue) -> Option<Self> {
+        Self::circle(value).or_else(|| Self::bounding_box(value))
+    }
+
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        match self {
+            Self::Circle { center, radius_km } => center.distance_km(point) <= *radius_km,
+            Self::BoundingBox { min, max } => {
+                (min.lat..=max.lat).contains(&point.lat) && (min.lon..=max.lon).contains(&point.lon)
+            }
+        }
+    }
+
+    /// Rectangle englobant (coins sud-ouest et nord-est), borné aux coordonnées valides.
+    fn envelope(&self) -> (GeoPoint, GeoPoint) {
+        match self {
+            Self::BoundingBox { min, max } => (*min, *max),
+            Self::Circle { center, radius_km } => {
+                let d_lat = radius_km / KM_PER_DEG_LAT;
+                let cos_lat = center.lat.to_radians().cos();
+                // Près des pôles, le cercle peut couvrir toutes les longitudes
+                let d_lon = if cos_lat > 1e-6 {
+                    (radius_km / (KM_PER_DEG_LAT * cos_lat)).min(360.0)
+                } else {
+                    360.0
+                };
+                (
+                    GeoPoint::new(
+                        (center.lat - d_lat).max(-90.0),
+                        (center.lon - d_lon).max(-180.0),
+                    ),
+                    GeoPoint::new(
+                        (center.lat + d_lat).min(90.0),
+                        (center.lon + d_lon).min(180.0),
+                    ),
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Serializable, Deserializable)]
+pub struct GeoIndex {
+    /// Position indexée de chaque document.
+    points: UnorderedMap<String, GeoPoint>,
+    /// Cellule `"lat:lon"` -> documents qu'elle contient.
+    cells: UnorderedMap<String, Vec<String>>,
+}
+
+impl GeoIndex {
+    /// (Ré)indexe un document : son ancienne position est retirée au préalable.
+    pub fn insert(&mut self, doc_id: &str, point: GeoPoint) {
+        self.remove(doc_id);
+        self.points.insert(doc_id.to_string(), point);
+        self.cells
+            .entry(cell_key(point.cell()))
+            .or_default()
+            .push(doc_id.to_string());
+    }
+
+    pub fn remove(&mut self, doc_id: &str) {
+        let Some(point) = self.points.remove(doc_id) else {
+            return;
+        };
+        let key = cell_key(point.cell());
+        if let Some(ids) = self.cells.get_mut(&key) {
+            ids.retain(|id| id != doc_id);
+            if ids.is_empty() {
+                self.cells.remove(&key);
+            }
+        }
+    }
+
+    /// Documents situés dans `shape` : par distance croissante au centre d'un cercle,
+    /// par identifiant pour un rectangle.
+    pub fn search(&self, shape: &GeoShape) -> Vec<String> {
+        let (min, max) = shape.envelope();
+        let (min_cell, max_cell) = (min.cell(), max.cell());
+        let cell_count =
+            (max_cell.0 - min_cell.0 + 1) as usize * (max_cell.1 - min_cell.1 + 1) as usize;
+
+        // Zone plus vaste que l'index : parcourir les positions coûte moins que les cellules
+        let candidates: Vec<&String> = if cell_count > self.points.len() {
+            self.points.keys().collect()
+        } else {
+            (min_cell.0..=max_cell.0)
+                .flat_map(|lat| (min_cell.1..=max_cell.1).map(move |lon| cell_key((lat, lon))))
+                .filter_map(|key| self.cells.get(&key))
+                .flatten()
+                .collect()
+        };
+
+        let mut hits: Vec<(String, f64)> = candidates
+            .into_iter()
+            .filter_map(|id| {
+                let point = self.points.get(id)?;
+                if !shape.contains(point) {
+                    return None;
+                }
+                let distance = match shape {
+                    GeoShape::Circle { center, .. } => center.distance_km(point),
+                    GeoShape::BoundingBox { .. } => 0.0,
+                };
+                Some((id.clone(), distance))
+            })
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        hits.into_iter().map(|(id, _)| id).collect()
+    }
+}
+
+fn cell_key((lat, lon): (i32, i32)) -> String {
+    format!("{}:{}", lat, lon)
+}
+
+async fn load(config: &JsonDbConfig, path: &Path) -> RaiseResult<GeoIndex> {
+    if !fs::exists_async(path).await {
+        return Ok(GeoIndex::default());
+    }
+    match encryption::read_json_compressed(config, path).await {
+        Ok(index) => Ok(index),
+        Err(e) => raise_error!(
+            "ERR_DB_INDEX_LOAD_FAILED",
+            error = e,
+            context = json_value!({ "path": path.to_string_lossy() })
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn update_geo_index(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    def: &IndexDefinition,
+    doc_id: &str,
+    old_doc: Option<&JsonValue>,
+    new_doc: Option<&JsonValue>,
+) -> RaiseResult<()> {
+    let new_point = new_doc
+        .and_then(|doc| doc.pointer(&def.field_path))
+        .and_then(GeoPoint::from_value);
+    if old_doc.is_none() && new_point.is_none() {
+        return Ok(());
+    }
+
+    let path = paths::index_path(
+        &storage.config,
+        space,
+        db,
+        collection,
+        &def.name,
+        def.index_type,
+    );
+    let mut index = load(&storage.config, &path).await?;
+    match new_point {
+        Some(point) => index.insert(doc_id, point),
+        None => index.remove(doc_id),
+    }
+
+    match encryption::write_json_compressed_atomic(&storage.config, &path, &index).await {
+        Ok(_) => Ok(()),
+        Err(e) => raise_error!(
+            "ERR_DB_INDEX_SAVE_FAILED",
+            error = e,
+            context = json_value!({ "path": path.to_string_lossy() })
+        ),
+    }
+}
+
+/// IDs des documents situés dans `shape` (voir `GeoIndex::search` pour l'ordre).
+pub async fn search_geo_index(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    def: &IndexDefinition,
+    shape: &GeoShape,
+) -> RaiseResult<Vec<String>> {
+    let path = paths::index_path(
+        &storage.config,
+        space,
+        db,
+        collection,
+        &def.name,
+        def.index_type,
+    );
+    Ok(load(&storage.config, &path).await?.search(shape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_db::indexes::IndexType;
+
+    #[test]
+    fn test_geo_parsing_and_distance() {
+        let paris = GeoPoint::from_value(&json_value!({ "lat": 48.8566, "lon": 2.3522 })).unwrap();
+        let lyon = GeoPoint::from_value(&json_value!({
+            "type": "Point", "coordinates": [4.8357, 45.7640]
+        }))
+        .unwrap();
+        assert!((paris.distance_km(&lyon) - 392.0).abs() < 2.0);
+        assert!(GeoPoint::from_value(&json_value!({ "lat": 95.0, "lon": 0.0 })).is_none());
+
+        let near =
+            GeoShape::circle(&json_value!({ "center": [2.3522, 48.8566], "radius_m": 500_000 }));
+        assert!(near.is_some_and(|shape| shape.contains(&lyon)));
+        let bbox = GeoShape::from_value(&json_value!({
+            "min_lat": 48.0, "min_lon": 2.0, "max_lat": 49.0, "max_lon": 3.0
+        }))
+        .unwrap();
+        assert!(bbox.contains(&paris) && !bbox.contains(&lyon));
+        assert!(GeoShape::bounding_box(&json_value!({
+            "min_lat": 49.0, "min_lon": 2.0, "max_lat": 48.0, "max_lon": 3.0
+        }))
+        .is_none());
+    }
+
+    #[async_test]
+    async fn test_geo_index_lifecycle() -> RaiseResult<()> {
+        let dir = tempdir().unwrap();
+        let storage = StorageEngine::new(JsonDbConfig::new(dir.path().to_path_buf()))?;
+        fs::ensure_dir_async(&dir.path().join("s/d/collections/c/_indexes")).await?;
+
+        let def = IndexDefinition {
+            name: "location".into(),
+            field_path: "/location".into(),
+            index_type: IndexType::Geo,
+            unique: false,
+            stemming: false,
+            fields: Vec::new(),
+        };
+        let docs = [
+            (
+                "antenna",
+                json_value!({ "location": { "lat": 48.8600, "lon": 2.3400 } }),
+            ),
+            (
+                "relay",
+                json_value!({ "location": { "lat": 48.9000, "lon": 2.4000 } }),
+            ),
+            (
+                "depot",
+                json_value!({ "location": { "lat": 45.7640, "lon": 4.8357 } }),
+            ),
+        ];
+        for (id, doc) in &docs {
+            update_geo_index(&storage, "s", "d", "c", &def, id, None, Some(doc)).await?;
+        }
+
+        let near = GeoShape::Circle {
+            center: GeoPoint::new(48.8566, 2.3522),
+            radius_km: 10.0,
+        };
+        let ids = search_geo_index(&storage, "s", "d", "c", &def, &near).await?;
+        assert_eq!(ids, vec!["antenna", "relay"]);
+
+        // Déplacement hors de la zone puis suppression
+        let moved = json_value!({ "location": { "lat": 43.6, "lon": 1.44 } });
+        update_geo_index(
+            &storage,
+            "s",
+            "d",
+            "c",
+            &def,
+            "relay",
+            Some(&docs[1].1),
+            Some(&moved),
+        )
+        .await?;
+        update_geo_index(
+            &storage,
+            "s",
+            "d",
+            "c",
+            &def,
+            "antenna",
+            Some(&docs[0].1),
+            None,
+        )
+        .await?;
+        assert!(search_geo_index(&storage, "s", "d", "c", &def, &near)
+            .await?
+            .is_empty());
+        Ok(())
+    }
+}
@@ -26,7 +26,15 @@ pub async fn update_hash_index(
         &def.name,
         def.index_type,
     );
-    driver::update::<UnorderedMap<String, Vec<String>>>(&path, def, doc_id, old_doc, new_doc).await
+    driver::update::<UnorderedMap<String, Vec<String>>>(
+        &storage.config,
+        &path,
+        def,
+        doc_id,
+        old_doc,
+        new_doc,
+    )
+    .await
 }
 
 /// Recherche des IDs de documents correspondant exactement à une valeur.
@@ -48,7 +56,7 @@ pub async fn search_hash_index(
     );
 
     let key = value.to_string();
-    driver::search::<UnorderedMap<String, Vec<String>>>(&path, &key).await
+    driver::search::<UnorderedMap<String, Vec<String>>>(&storage.config, &path, &key).await
 }
 
 #[cfg(test)]
@@ -80,6 +88,8 @@ mod tests {
             field_path: "/email".into(),
             index_type: IndexType::Hash,
             unique: true,
+            stemming: false,
+            fields: Vec::new(),
         };
 
         // 1. Insertion
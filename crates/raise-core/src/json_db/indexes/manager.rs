@@ -1,6 +1,7 @@
 // FICHIER : src-tauri/src/json_db/indexes/manager.rs
 
-use super::{btree, hash, text, IndexDefinition, IndexType};
+use super::geo::{self, GeoShape};
+use super::{btree, driver, fulltext, hash, paths, text, IndexDefinition, IndexType};
 use crate::json_db::collections::collection;
 use crate::json_db::storage::StorageEngine;
 
@@ -12,6 +13,9 @@ struct CollectionMeta {
     pub schema: Option<String>,
     #[serde(default)]
     pub indexes: Vec<IndexDefinition>,
+    /// Autres clés du `_meta.json` (ex: `merge_strategy`), préservées à la réécriture
+    #[serde(flatten)]
+    pub extra: JsonObject<String, JsonValue>,
 }
 
 pub struct IndexManager<'a> {
@@ -35,34 +39,85 @@ impl<'a> IndexManager<'a> {
         field: &str,
         kind_str: &str,
     ) -> RaiseResult<()> {
-        let kind = match kind_str.to_lowercase().as_str() {
-            "hash" => IndexType::Hash,
-            "btree" => IndexType::BTree,
-            "text" => IndexType::Text,
+        self.create_index_with(collection, &[field], kind_str, false)
+            .await
+    }
+
+    /// Index sur un ou plusieurs champs (composé, nommé `a+b`), éventuellement unique.
+    /// Un index unique est refusé si les documents existants le violent déjà.
+    pub async fn create_index_with(
+        &mut self,
+        collection: &str,
+        fields: &[&str],
+        kind_str: &str,
+        unique: bool,
+    ) -> RaiseResult<()> {
+        let (kind, stemming) = match kind_str.to_lowercase().as_str() {
+            "hash" => (IndexType::Hash, false),
+            "btree" => (IndexType::BTree, false),
+            "text" => (IndexType::Text, false),
+            "fulltext" => (IndexType::FullText, false),
+            "fulltext:stem" => (IndexType::FullText, true),
+            "geo" => (IndexType::Geo, false),
             _ => raise_error!(
                 "ERR_DB_INDEX_TYPE_UNKNOWN",
                 error = format!("Le type d'index '{}' n'est pas supporté.", kind_str),
                 context = json_value!({
                     "attempted_type": kind_str,
-                    "supported_types": ["hash", "btree", "text"],
+                    "supported_types": ["hash", "btree", "text", "fulltext", "fulltext:stem", "geo"],
                     "action": "parse_index_definition"
                 })
             ),
         };
 
-        let field_path = if field.starts_with('/') {
-            field.to_string()
-        } else {
-            format!("/{}", field)
+        let paths: Vec<String> = fields
+            .iter()
+            .map(|field| {
+                if field.starts_with('/') {
+                    field.to_string()
+                } else {
+                    format!("/{}", field)
+                }
+            })
+            .collect();
+        let Some(field_path) = paths.first().cloned() else {
+            raise_error!(
+                "ERR_DB_INDEX_FIELDS_EMPTY",
+                error = format!(
+                    "Impossible de créer un index sans champs sur '{}'.",
+                    collection
+                ),
+                context = json_value!({ "collection": collection })
+            );
         };
+        let keyed = matches!(kind, IndexType::Hash | IndexType::BTree);
+        if (paths.len() > 1 || unique) && !keyed {
+            raise_error!(
+                "ERR_DB_INDEX_CONSTRAINT_UNSUPPORTED",
+                error = format!(
+                    "Les index composés ou uniques doivent être de type hash ou btree (reçu '{}').",
+                    kind_str
+                ),
+                context = json_value!({
+                    "collection": collection,
+                    "fields": fields,
+                    "unique": unique
+                })
+            );
+        }
 
         let def = IndexDefinition {
-            name: field.to_string(),
+            name: fields.join("+"),
             field_path,
             index_type: kind,
-            unique: false,
+            unique,
+            stemming,
+            fields: if paths.len() > 1 { paths } else { Vec::new() },
         };
 
+        if unique {
+            self.ensure_unique_data(collection, &def).await?;
+        }
         add_index_definition(self.storage, &self.space, &self.db, collection, def.clone()).await?;
         self.rebuild_index(collection, &def).await?;
         Ok(())
@@ -157,7 +212,182 @@ impl<'a> IndexManager<'a> {
                 let query_str = value.as_str().unwrap_or("").to_string();
                 text::search_text_index(storage, s, d, collection, def, &query_str).await
             }
+            IndexType::FullText => {
+                let query_str = value.as_str().unwrap_or("");
+                let ranked =
+                    fulltext::rank_fulltext_index(storage, s, d, collection, def, query_str)
+                        .await?;
+                Ok(ranked.into_iter().map(|(id, _)| id).collect())
+            }
+            IndexType::Geo => {
+                let Some(shape) = GeoShape::from_value(value) else {
+                    raise_error!(
+                        "ERR_DB_QUERY_GEO_INVALID",
+                        error = format!("Zone géographique invalide pour l'index '{}'.", field),
+                        context = json_value!({
+                            "field": field,
+                            "received": value,
+                            "hint": "{ \"lat\", \"lon\", \"radius_km\" } ou { \"min_lat\", \"min_lon\", \"max_lat\", \"max_lon\" }"
+                        })
+                    );
+                };
+                geo::search_geo_index(storage, s, d, collection, def, &shape).await
+            }
+        }
+    }
+
+    /// IDs classés par pertinence (BM25) si `field` porte un index plein texte (`None` sinon).
+    /// Sert à l'opérateur `TextSearch` du `QueryEngine`.
+    pub async fn text_search(
+        &self,
+        collection: &str,
+        field: &str,
+        query: &str,
+    ) -> RaiseResult<Option<Vec<(String, f64)>>> {
+        let indexes = self.load_indexes(collection).await?;
+        let Some(def) = indexes
+            .iter()
+            .find(|i| i.name == field && i.index_type == IndexType::FullText)
+        else {
+            return Ok(None);
+        };
+        let ranked = fulltext::rank_fulltext_index(
+            self.storage,
+            &self.space,
+            &self.db,
+            collection,
+            def,
+            query,
+        )
+        .await?;
+        Ok(Some(ranked))
+    }
+
+    /// IDs des documents situés dans `shape` si `field` porte un index géographique (`None`
+    /// sinon). Sert aux opérateurs `Near` / `Within` du `QueryEngine`.
+    pub async fn geo_search(
+        &self,
+        collection: &str,
+        field: &str,
+        shape: &GeoShape,
+    ) -> RaiseResult<Option<Vec<String>>> {
+        let indexes = self.load_indexes(collection).await?;
+        let Some(def) = indexes
+            .iter()
+            .find(|i| i.name == field && i.index_type == IndexType::Geo)
+        else {
+            return Ok(None);
+        };
+        let ids =
+            geo::search_geo_index(self.storage, &self.space, &self.db, collection, def, shape)
+                .await?;
+        Ok(Some(ids))
+    }
+
+    /// IDs groupés par clé croissante si `field` porte un index BTree (`None` sinon).
+    /// Sert au tri `ORDER BY` piloté par index dans le `QueryEngine`.
+    pub async fn ordered_ids(
+        &self,
+        collection: &str,
+        field: &str,
+    ) -> RaiseResult<Option<Vec<Vec<String>>>> {
+        let indexes = self.load_indexes(collection).await?;
+        let Some(def) = indexes
+            .iter()
+            .find(|i| i.name == field && i.index_type == IndexType::BTree)
+        else {
+            return Ok(None);
+        };
+        let entries =
+            btree::scan_btree_index(self.storage, &self.space, &self.db, collection, def).await?;
+        Ok(Some(entries.into_iter().map(|(_, ids)| ids).collect()))
+    }
+
+    /// Refuse l'écriture de `doc` (identifiant `doc_id`) si un autre document porte déjà la
+    /// même clé sur un index unique de `collection`. Un champ absent n'est pas contraint.
+    /// À appeler sous le verrou de collection, avant l'écriture.
+    pub async fn check_unique(
+        &self,
+        collection: &str,
+        doc_id: &str,
+        doc: &JsonValue,
+    ) -> RaiseResult<()> {
+        for def in self.load_indexes(collection).await? {
+            if !def.unique {
+                continue;
+            }
+            let Some(key) = def.key_of(doc) else {
+                continue;
+            };
+            let path = paths::index_path(
+                &self.storage.config,
+                &self.space,
+                &self.db,
+                collection,
+                &def.name,
+                def.index_type,
+            );
+            let candidates = match def.index_type {
+                IndexType::BTree => {
+                    driver::search::<OrderedMap<String, Vec<String>>>(
+                        &self.storage.config,
+                        &path,
+                        &key,
+                    )
+                    .await?
+                }
+                _ => {
+                    driver::search::<UnorderedMap<String, Vec<String>>>(
+                        &self.storage.config,
+                        &path,
+                        &key,
+                    )
+                    .await?
+                }
+            };
+            for other_id in candidates.iter().filter(|id| id.as_str() != doc_id) {
+                // Entrée périmée (document supprimé ou modifié hors index) : ignorée
+                let other = self
+                    .storage
+                    .read_document(&self.space, &self.db, collection, other_id)
+                    .await?;
+                if other.and_then(|o| def.key_of(&o)).as_deref() == Some(key.as_str()) {
+                    raise_unique_violation(collection, &def, &key, doc_id, other_id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Vérifie qu'aucun document existant ne partage une clé de l'index unique `def`.
+    async fn ensure_unique_data(&self, collection: &str, def: &IndexDefinition) -> RaiseResult<()> {
+        let ids = collection::list_document_ids(
+            &self.storage.config,
+            &self.space,
+            &self.db,
+            collection,
+            None,
+            None,
+        )
+        .await?;
+        let mut seen: UnorderedMap<String, String> = UnorderedMap::new();
+        for id in ids {
+            let Ok(Some(doc)) = self
+                .storage
+                .read_document(&self.space, &self.db, collection, &id)
+                .await
+            else {
+                continue;
+            };
+            let Some(key) = def.key_of(&doc) else {
+                continue;
+            };
+            if let Some(first) = seen.get(&key) {
+                raise_unique_violation(collection, def, &key, &id, first)?;
+            }
+            seen.insert(key, id);
         }
+        Ok(())
     }
 
     async fn rebuild_index(&self, collection: &str, def: &IndexDefinition) -> RaiseResult<()> {
@@ -292,6 +522,10 @@ impl<'a> IndexManager<'a> {
                 btree::update_btree_index(storage, s, d, col, def, id, old, new).await
             }
             IndexType::Text => text::update_text_index(storage, s, d, col, def, id, old, new).await,
+            IndexType::FullText => {
+                fulltext::update_fulltext_index(storage, s, d, col, def, id, old, new).await
+            }
+            IndexType::Geo => geo::update_geo_index(storage, s, d, col, def, id, old, new).await,
         };
 
         if let Err(e) = result {
@@ -335,6 +569,32 @@ impl<'a> IndexManager<'a> {
     }
 }
 
+fn raise_unique_violation(
+    collection: &str,
+    def: &IndexDefinition,
+    key: &str,
+    doc_id: &str,
+    conflicting_id: &str,
+) -> RaiseResult<()> {
+    let value: JsonValue =
+        json::deserialize_from_str(key).unwrap_or_else(|_| JsonValue::String(key.to_string()));
+    raise_error!(
+        "ERR_DB_UNIQUE_VIOLATION",
+        error = format!(
+            "Violation de contrainte unique sur '{}' (index '{}') : valeur déjà portée par le document '{}'.",
+            collection, def.name, conflicting_id
+        ),
+        context = json_value!({
+            "collection": collection,
+            "index_name": def.name,
+            "value": value,
+            "document_id": doc_id,
+            "conflicting_id": conflicting_id,
+            "hint": "Modifiez la valeur du champ indexé ou mettez à jour le document existant."
+        })
+    )
+}
+
 pub async fn add_index_definition(
     storage: &StorageEngine,
     space: &str,
@@ -353,6 +613,7 @@ pub async fn add_index_definition(
         CollectionMeta {
             schema: None,
             indexes: vec![],
+            extra: JsonObject::new(),
         }
     };
 
@@ -469,4 +730,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[async_test]
+    async fn test_fulltext_index_ranks_matches() -> RaiseResult<()> {
+        let dir = match tempdir() {
+            Ok(d) => d,
+            Err(e) => panic!("Fail TempDir: {:?}", e),
+        };
+        let storage = StorageEngine::new(JsonDbConfig::new(dir.path().to_path_buf()))?;
+        let mut mgr = IndexManager::new(&storage, "s", "d");
+        fs::create_dir_all_async(&dir.path().join("s/d/collections/notes")).await?;
+
+        mgr.create_index("notes", "body", "fulltext:stem").await?;
+        let defs = mgr.list_indexes("notes", Some("body")).await?;
+        assert_eq!(defs[0].kind(), "fulltext:stem");
+
+        mgr.index_document(
+            "notes",
+            &json_value!({ "_id": "n1", "body": "Braking sensor" }),
+        )
+        .await?;
+        mgr.index_document(
+            "notes",
+            &json_value!({ "_id": "n2", "body": "Sensors, sensors everywhere: sensor fusion" }),
+        )
+        .await?;
+
+        let ranked = mgr.text_search("notes", "body", "sensors").await?;
+        let ids: Vec<String> = ranked
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec!["n2", "n1"]);
+        assert!(mgr.text_search("notes", "title", "sensor").await?.is_none());
+        Ok(())
+    }
 }
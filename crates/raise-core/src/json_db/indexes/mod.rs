@@ -2,6 +2,8 @@
 
 pub mod btree;
 pub mod driver;
+pub mod fulltext;
+pub mod geo;
 pub mod hash;
 pub mod manager;
 pub mod paths;
@@ -17,6 +19,9 @@ pub enum IndexType {
     Hash,
     BTree,
     Text,
+    FullText,
+    /// Positions géographiques (grille de cellules), pour `Near` / `Within`.
+    Geo,
 }
 
 #[derive(Debug, Clone, Serializable, Deserializable)]
@@ -25,6 +30,40 @@ pub struct IndexDefinition {
     pub field_path: String,
     pub index_type: IndexType,
     pub unique: bool,
+    /// Racinisation des termes (index `fulltext` uniquement).
+    #[serde(default)]
+    pub stemming: bool,
+    /// Chemins d'un index composé (`field_path` est alors le premier) ; vide sinon.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+impl IndexDefinition {
+    /// Type tel qu'accepté par `IndexManager::create_index` (`"fulltext:stem"` avec racinisation).
+    pub fn kind(&self) -> String {
+        match self.index_type {
+            IndexType::Hash => "hash".to_string(),
+            IndexType::BTree => "btree".to_string(),
+            IndexType::Text => "text".to_string(),
+            IndexType::FullText if self.stemming => "fulltext:stem".to_string(),
+            IndexType::FullText => "fulltext".to_string(),
+            IndexType::Geo => "geo".to_string(),
+        }
+    }
+
+    /// Clé d'indexation de `doc` : valeur sérialisée du champ, ou tableau des valeurs d'un
+    /// index composé (`["p1","A-01"]`). `None` si un des champs est absent.
+    pub fn key_of(&self, doc: &JsonValue) -> Option<String> {
+        if self.fields.len() < 2 {
+            return doc.pointer(&self.field_path).map(|v| v.to_string());
+        }
+        let values = self
+            .fields
+            .iter()
+            .map(|path| doc.pointer(path).cloned())
+            .collect::<Option<Vec<JsonValue>>>()?;
+        Some(JsonValue::Array(values).to_string())
+    }
 }
 
 #[derive(Debug, Clone, Serializable, Deserializable)]
@@ -59,6 +98,8 @@ mod tests {
             field_path: "/contact/email".to_string(),
             index_type: IndexType::Hash,
             unique: true,
+            stemming: false,
+            fields: Vec::new(),
         };
 
         let json = json::serialize_to_string(&def).unwrap();
@@ -68,4 +109,19 @@ mod tests {
         let loaded: IndexDefinition = json::deserialize_from_str(&json).unwrap();
         assert_eq!(loaded.index_type, IndexType::Hash);
     }
+
+    #[test]
+    fn test_compound_key() {
+        let def = IndexDefinition {
+            name: "project+code".to_string(),
+            field_path: "/project".to_string(),
+            index_type: IndexType::Hash,
+            unique: true,
+            stemming: false,
+            fields: vec!["/project".to_string(), "/code".to_string()],
+        };
+        let doc = json_value!({ "project": "p1", "code": "A-01" });
+        assert_eq!(def.key_of(&doc).as_deref(), Some(r#"["p1","A-01"]"#));
+        assert_eq!(def.key_of(&json_value!({ "project": "p1" })), None);
+    }
 }
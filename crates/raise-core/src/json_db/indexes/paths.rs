@@ -23,6 +23,8 @@ pub fn index_path(
         IndexType::Hash => "hash.idx",
         IndexType::BTree => "btree.idx",
         IndexType::Text => "text.idx",
+        IndexType::FullText => "fulltext.idx",
+        IndexType::Geo => "geo.idx",
     };
     indexes_root(cfg, space, db, collection).join(format!("{index_name}.{extension}"))
 }
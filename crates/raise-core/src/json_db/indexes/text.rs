@@ -34,7 +34,7 @@ pub async fn update_text_index(
         def.index_type,
     );
 
-    let mut index: UnorderedMap<String, Vec<String>> = driver::load(&path).await?;
+    let mut index: UnorderedMap<String, Vec<String>> = driver::load(&storage.config, &path).await?;
     let mut changed = false;
 
     if let Some(doc) = old_doc {
@@ -66,7 +66,7 @@ pub async fn update_text_index(
     }
 
     if changed {
-        driver::save(&path, &index).await?;
+        driver::save(&storage.config, &path, &index).await?;
     }
 
     Ok(())
@@ -90,7 +90,7 @@ pub async fn search_text_index(
     );
 
     let token = query.to_lowercase();
-    driver::search::<UnorderedMap<String, Vec<String>>>(&path, &token).await
+    driver::search::<UnorderedMap<String, Vec<String>>>(&storage.config, &path, &token).await
 }
 
 #[cfg(test)]
@@ -121,6 +121,8 @@ mod tests {
             field_path: "/bio".into(),
             index_type: IndexType::Text,
             unique: false,
+            stemming: false,
+            fields: Vec::new(),
         };
 
         let doc = json_value!({ "bio": "Rust is great" });
@@ -131,8 +131,9 @@ impl VocabularyRegistry {
             .join("_system.json");
 
         if fs::exists_async(&sys_path).await {
-            let content = fs::read_to_string_async(&sys_path).await?;
-            let sys_doc: JsonValue = json::deserialize_from_str(&content)?;
+            let sys_doc: JsonValue =
+                crate::json_db::storage::encryption::read_json(&db_mgr.storage.config, &sys_path)
+                    .await?;
 
             // 3. Hydratation de l'état RCU via le registre global
             if let Some(ontologies) = sys_doc.get("ontologies").and_then(|o| o.as_object()) {
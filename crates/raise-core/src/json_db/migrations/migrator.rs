@@ -7,6 +7,36 @@ use crate::json_db::storage::StorageEngine;
 
 use crate::utils::prelude::*;
 
+const MIGRATIONS_COLLECTION: &str = "_migrations";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationDirection {
+    Up,
+    Down,
+}
+
+/// Migration retenue par un plan (`migrate` ou `rollback_to`), avec les étapes à exécuter.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct PlannedMigration {
+    pub id: String,
+    pub version: String,
+    pub description: String,
+    pub direction: MigrationDirection,
+    pub steps: Vec<MigrationStep>,
+}
+
+/// Entrée de l'historique `_migrations`.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct AppliedMigration {
+    pub id: String,
+    pub version: String,
+    pub description: String,
+    pub applied_at: Option<String>,
+    /// Étapes `down` connues (enregistrées avec la migration).
+    pub reversible: bool,
+}
+
 pub struct Migrator<'a> {
     manager: CollectionsManager<'a>,
 }
@@ -24,7 +54,7 @@ impl<'a> Migrator<'a> {
             .manager
             .list_collections()
             .await? // Migration async
-            .contains(&"_migrations".to_string());
+            .contains(&MIGRATIONS_COLLECTION.to_string());
 
         if !exists {
             #[cfg(debug_assertions)]
@@ -32,7 +62,7 @@ impl<'a> Migrator<'a> {
 
             self.manager
                 .create_collection(
-                    "_migrations",
+                    MIGRATIONS_COLLECTION,
                     "db://_system/_system/schemas/v1/db/generic.schema.json",
                 )
                 .await?;
@@ -41,9 +71,21 @@ impl<'a> Migrator<'a> {
     }
 
     /// Exécute les migrations en attente - ASYNC
-    pub async fn run_migrations(&self, mut declared_migrations: Vec<Migration>) -> RaiseResult<()> {
+    pub async fn run_migrations(&self, declared_migrations: Vec<Migration>) -> RaiseResult<()> {
+        self.migrate(declared_migrations, false).await.map(|_| ())
+    }
+
+    /// Plan des migrations en attente, appliquées dans l'ordre SemVer sauf en `dry_run`
+    /// (aucune écriture, pas même la création de `_migrations`).
+    pub async fn migrate(
+        &self,
+        mut declared_migrations: Vec<Migration>,
+        dry_run: bool,
+    ) -> RaiseResult<Vec<PlannedMigration>> {
         // 1. Initialisation de la table de suivi
-        self.init().await?;
+        if !dry_run {
+            self.init().await?;
+        }
 
         // 2. Validation préalable de TOUTES les versions déclarées
         // On évite ainsi de découvrir une erreur de frappe à la moitié du processus.
@@ -59,8 +101,9 @@ impl<'a> Migrator<'a> {
         });
 
         // 4. Identification des migrations déjà appliquées
-        let applied_docs = self.manager.list_all("_migrations").await?;
-        let applied_ids: UniqueSet<String> = applied_docs
+        let applied_ids: UniqueSet<String> = self
+            .applied_records()
+            .await?
             .iter()
             .filter_map(|doc| {
                 doc.get("_id")
@@ -70,8 +113,12 @@ impl<'a> Migrator<'a> {
             .collect();
 
         // 5. Application séquentielle
+        let mut plan = Vec::new();
         for migration in declared_migrations {
-            if !applied_ids.contains(&migration.id) {
+            if applied_ids.contains(&migration.id) {
+                continue;
+            }
+            if !dry_run {
                 #[cfg(debug_assertions)]
                 println!(
                     "🚀 Migration : {} - {}",
@@ -80,9 +127,134 @@ impl<'a> Migrator<'a> {
 
                 self.apply_migration(&migration).await?;
             }
+            plan.push(PlannedMigration {
+                id: migration.id,
+                version: migration.version,
+                description: migration.description,
+                direction: MigrationDirection::Up,
+                steps: migration.up,
+            });
         }
 
-        Ok(())
+        Ok(plan)
+    }
+
+    /// Annule, de la plus récente à la plus ancienne, les migrations appliquées de version
+    /// strictement supérieure à `target_version` en exécutant leurs étapes `down`.
+    /// Les étapes déclarées dans `declared` priment sur celles enregistrées dans l'historique.
+    /// Rien n'est exécuté si l'une d'elles est irréversible ; rien n'est écrit en `dry_run`.
+    pub async fn rollback_to(
+        &self,
+        declared: &[Migration],
+        target_version: &str,
+        dry_run: bool,
+    ) -> RaiseResult<Vec<PlannedMigration>> {
+        let target = MigrationVersion::parse(target_version)?;
+
+        // Les entrées sans version SemVer (amorçage du système) ne sont jamais annulées.
+        let mut to_revert: Vec<(MigrationVersion, JsonValue)> = self
+            .applied_records()
+            .await?
+            .into_iter()
+            .filter_map(|doc| {
+                let version = MigrationVersion::parse(doc.get("version")?.as_str()?).ok()?;
+                (version > target).then_some((version, doc))
+            })
+            .collect();
+        to_revert.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let mut plan = Vec::with_capacity(to_revert.len());
+        for (version, doc) in to_revert {
+            let id = doc["_id"].as_str().unwrap_or_default().to_string();
+            let steps = match declared.iter().find(|m| m.id == id) {
+                Some(m) if !m.down.is_empty() => m.down.clone(),
+                _ => match doc.get("down").cloned() {
+                    Some(down) => json::deserialize_from_value(down).unwrap_or_default(),
+                    None => Vec::new(),
+                },
+            };
+            if steps.is_empty() {
+                raise_error!(
+                    "ERR_MIGRATION_IRREVERSIBLE",
+                    error = format!("La migration '{}' n'a pas d'étapes 'down'.", id),
+                    context = json_value!({
+                        "migration": id,
+                        "version": version.to_string(),
+                        "target": target_version
+                    })
+                );
+            }
+            plan.push(PlannedMigration {
+                id,
+                version: version.to_string(),
+                description: doc["description"].as_str().unwrap_or_default().to_string(),
+                direction: MigrationDirection::Down,
+                steps,
+            });
+        }
+
+        if !dry_run {
+            for migration in &plan {
+                #[cfg(debug_assertions)]
+                println!(
+                    "⏪ Rollback : {} - {}",
+                    migration.version, migration.description
+                );
+
+                for step in &migration.steps {
+                    self.execute_step(step).await?;
+                }
+                self.manager
+                    .delete_document(MIGRATIONS_COLLECTION, &migration.id)
+                    .await?;
+            }
+        }
+
+        Ok(plan)
+    }
+
+    /// Historique des migrations appliquées, par date d'application.
+    pub async fn history(&self) -> RaiseResult<Vec<AppliedMigration>> {
+        let text = |doc: &JsonValue, key: &str| {
+            doc.get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let mut history: Vec<AppliedMigration> = self
+            .applied_records()
+            .await?
+            .iter()
+            .map(|doc| AppliedMigration {
+                id: text(doc, "_id"),
+                version: text(doc, "version"),
+                description: text(doc, "description"),
+                applied_at: doc
+                    .get("applied_at")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string()),
+                reversible: doc
+                    .get("down")
+                    .and_then(|v| v.as_array())
+                    .is_some_and(|steps| !steps.is_empty()),
+            })
+            .collect();
+        history.sort_by(|a, b| a.applied_at.cmp(&b.applied_at));
+        Ok(history)
+    }
+
+    /// Documents de `_migrations` (aucun si la table n'existe pas encore).
+    async fn applied_records(&self) -> RaiseResult<Vec<JsonValue>> {
+        let exists = self
+            .manager
+            .list_collections()
+            .await?
+            .iter()
+            .any(|c| c == MIGRATIONS_COLLECTION);
+        if !exists {
+            return Ok(Vec::new());
+        }
+        self.manager.list_all(MIGRATIONS_COLLECTION).await
     }
 
     async fn apply_migration(&self, migration: &Migration) -> RaiseResult<()> {
@@ -99,10 +271,14 @@ impl<'a> Migrator<'a> {
             "name": { "fr": migration.description.clone(), "en": migration.description.clone() },
             "version": migration.version.clone(),
             "description": migration.description.clone(),
-            "applied_at": UtcClock::now().to_rfc3339()
+            "applied_at": UtcClock::now().to_rfc3339(),
+            // Conservées pour un rollback sans la déclaration d'origine
+            "down": migration.down.clone()
         });
 
-        self.manager.insert_raw("_migrations", &record).await?;
+        self.manager
+            .insert_raw(MIGRATIONS_COLLECTION, &record)
+            .await?;
 
         Ok(())
     }
@@ -197,14 +373,12 @@ impl<'a> Migrator<'a> {
             }
 
             // 6. Création d'un index
-            MigrationStep::CreateIndex { collection, fields } => {
-                if let Some(field) = fields.first() {
-                    self.manager
-                        .create_index(collection, field, "btree")
-                        .await?;
-                    #[cfg(debug_assertions)]
-                    println!("   -> Index btree créé sur {}::{}", collection, field);
-                } else {
+            MigrationStep::CreateIndex {
+                collection,
+                fields,
+                unique,
+            } => {
+                if fields.is_empty() {
                     raise_error!(
                         "ERR_MIGRATION_INDEX_EMPTY",
                         error = format!(
@@ -213,6 +387,16 @@ impl<'a> Migrator<'a> {
                         )
                     );
                 }
+                let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+                self.manager
+                    .create_index_with(collection, &fields, "btree", *unique)
+                    .await?;
+                #[cfg(debug_assertions)]
+                println!(
+                    "   -> Index btree créé sur {}::{}",
+                    collection,
+                    fields.join("+")
+                );
             }
 
             // 7. Suppression d'un index
@@ -404,4 +588,108 @@ mod tests {
 
         Ok(())
     }
+
+    #[async_test]
+    async fn test_dry_run_rollback_and_history() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+
+        let migrator = Migrator::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+
+        DbSandbox::mock_db(&migrator.manager).await?;
+
+        let m1 = Migration {
+            id: "m1".to_string(),
+            version: "1.0.0".to_string(),
+            description: "Init Users".to_string(),
+            up: vec![MigrationStep::CreateCollection {
+                name: "users".to_string(),
+                schema: json_value!("db://_system/_system/schemas/v1/db/generic.schema.json"),
+            }],
+            down: vec![MigrationStep::DropCollection {
+                name: "users".to_string(),
+            }],
+            applied_at: None,
+        };
+        let m2 = Migration {
+            id: "m2".to_string(),
+            version: "1.1.0".to_string(),
+            description: "Add Active Field".to_string(),
+            up: vec![MigrationStep::AddField {
+                collection: "users".to_string(),
+                field: "active".to_string(),
+                default: Some(json_value!(true)),
+            }],
+            down: vec![MigrationStep::RemoveField {
+                collection: "users".to_string(),
+                field: "active".to_string(),
+            }],
+            applied_at: None,
+        };
+
+        // 1. Dry-run : plan complet, aucune écriture
+        let plan = migrator.migrate(vec![m2.clone(), m1.clone()], true).await?;
+        let ids: Vec<&str> = plan.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["m1", "m2"]);
+        assert!(!migrator
+            .manager
+            .list_collections()
+            .await?
+            .contains(&"users".to_string()));
+
+        migrator
+            .run_migrations(vec![m1.clone(), m2.clone()])
+            .await?;
+        insert_mock_db(
+            &migrator.manager,
+            "users",
+            &json_value!({ "_id": "user_1", "name": "Alice", "active": true }),
+        )
+        .await?;
+
+        let history = migrator.history().await?;
+        let recorded: Vec<&str> = history.iter().map(|h| h.id.as_str()).collect();
+        assert!(recorded.ends_with(&["m1", "m2"]));
+        assert!(history
+            .iter()
+            .filter(|h| h.id.starts_with('m'))
+            .all(|h| h.reversible));
+
+        // 2. Rollback simulé puis réel vers 1.0.0 (étapes 'down' lues dans l'historique)
+        let plan = migrator.rollback_to(&[], "1.0.0", true).await?;
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].direction, MigrationDirection::Down);
+        let doc = migrator.manager.get("users", "user_1").await?.unwrap();
+        assert_eq!(doc["active"], true);
+
+        migrator.rollback_to(&[], "1.0.0", false).await?;
+        let doc = migrator.manager.get("users", "user_1").await?.unwrap();
+        assert!(doc.get("active").is_none());
+        assert!(!migrator.history().await?.iter().any(|h| h.id == "m2"));
+
+        // 3. Migration irréversible : refus avant toute exécution
+        let m3 = Migration {
+            id: "m3".to_string(),
+            version: "1.2.0".to_string(),
+            description: "Irreversible".to_string(),
+            up: vec![],
+            down: vec![],
+            applied_at: None,
+        };
+        migrator.run_migrations(vec![m3]).await?;
+        let Err(AppError::Structured(err)) = migrator.rollback_to(&[], "0.0.0", false).await else {
+            panic!("Le rollback d'une migration irréversible doit échouer");
+        };
+        assert_eq!(err.code, "ERR_MIGRATION_IRREVERSIBLE");
+        assert!(migrator
+            .manager
+            .list_collections()
+            .await?
+            .contains(&"users".to_string()));
+
+        Ok(())
+    }
 }
@@ -6,7 +6,7 @@ use crate::utils::prelude::*;
 pub mod migrator;
 pub mod version;
 
-pub use migrator::Migrator;
+pub use migrator::{AppliedMigration, MigrationDirection, Migrator, PlannedMigration};
 
 #[derive(Debug, Clone, Serializable, Deserializable)]
 pub struct Migration {
@@ -42,9 +42,12 @@ pub enum MigrationStep {
         old_name: String,
         new_name: String,
     },
+    /// Index btree ; composé si plusieurs champs, contrainte d'unicité avec `unique`.
     CreateIndex {
         collection: String,
         fields: Vec<String>,
+        #[serde(default)]
+        unique: bool,
     },
     DropIndex {
         collection: String,
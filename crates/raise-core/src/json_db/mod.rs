@@ -1,3 +1,5 @@
+pub mod backup;
+pub mod bundle;
 pub mod collections;
 pub mod graph;
 pub mod indexes;
@@ -7,6 +9,7 @@ pub mod query;
 pub mod schema;
 pub mod storage;
 pub mod transactions;
+pub mod views;
 
 // ============================================================================
 // UTILITAIRES DE TEST (Intégrés & Zéro Dette)
@@ -0,0 +1,137 @@
+// FICHIER : src-tauri/src/json_db/query/cursor.rs
+
+//! Curseurs de pagination opaques : valeurs des clés de tri et `_id` du document marqué,
+//! sérialisés en JSON puis en base64. Un curseur n'est valable que pour le tri qui l'a émis.
+
+use super::{SortField, SortOrder};
+use crate::utils::data::encoding::{decode_base64, encode_base64};
+use crate::utils::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct QueryCursor {
+    /// Signature du tri d'émission (`title:asc,_id`).
+    #[serde(rename = "s")]
+    pub sort: String,
+    /// Valeurs des champs de tri (`None` : champ absent ou nul).
+    #[serde(rename = "k")]
+    pub keys: Vec<Option<JsonValue>>,
+    /// `_id` du document, qui départage les clés égales.
+    #[serde(rename = "i")]
+    pub id: String,
+}
+
+/// Borne de pagination demandée par la requête.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CursorBound {
+    After(QueryCursor),
+    Before(QueryCursor),
+}
+
+pub fn sort_signature(sort: &[SortField]) -> String {
+    sort.iter()
+        .map(|s| {
+            let order = match s.order {
+                SortOrder::Asc => "asc",
+                SortOrder::Desc => "desc",
+            };
+            format!("{}:{},", s.field, order)
+        })
+        .chain(std::iter::once("_id".to_string()))
+        .collect()
+}
+
+impl QueryCursor {
+    pub fn new(sort: &[SortField], keys: Vec<Option<JsonValue>>, id: impl Into<String>) -> Self {
+        Self {
+            sort: sort_signature(sort),
+            keys,
+            id: id.into(),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let bytes = json::serialize_to_bytes(self).unwrap_or_default();
+        encode_base64(&bytes)
+    }
+
+    /// Décode `token` et vérifie qu'il a été émis pour `sort`.
+    pub fn decode(token: &str, sort: &[SortField]) -> RaiseResult<Self> {
+        let cursor: Option<Self> = decode_base64(token.trim())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .and_then(|content| json::deserialize_from_str(&content).ok());
+        let Some(cursor) = cursor else {
+            raise_error!(
+                "ERR_DB_QUERY_CURSOR_INVALID",
+                error = "Curseur de pagination illisible.",
+                context = json_value!({ "cursor": token })
+            );
+        };
+        if cursor.sort != sort_signature(sort) || cursor.keys.len() != sort.len() {
+            raise_error!(
+                "ERR_DB_QUERY_CURSOR_MISMATCH",
+                error = "Ce curseur a été émis pour un autre tri.",
+                context = json_value!({
+                    "cursor_sort": cursor.sort,
+                    "query_sort": sort_signature(sort),
+                    "hint": "Reprenez la pagination depuis la première page après un changement de tri."
+                })
+            );
+        }
+        Ok(cursor)
+    }
+}
+
+impl CursorBound {
+    /// Borne de la requête : `after` ou `before`, jamais les deux.
+    pub fn from_query(
+        after: Option<&str>,
+        before: Option<&str>,
+        sort: &[SortField],
+    ) -> RaiseResult<Option<Self>> {
+        match (after, before) {
+            (None, None) => Ok(None),
+            (Some(token), None) => Ok(Some(Self::After(QueryCursor::decode(token, sort)?))),
+            (None, Some(token)) => Ok(Some(Self::Before(QueryCursor::decode(token, sort)?))),
+            (Some(_), Some(_)) => raise_error!(
+                "ERR_DB_QUERY_CURSOR_CONFLICT",
+                error = "Une requête accepte 'after' ou 'before', pas les deux."
+            ),
+        }
+    }
+
+    pub fn cursor(&self) -> &QueryCursor {
+        match self {
+            Self::After(c) | Self::Before(c) => c,
+        }
+    }
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip_and_sort_check() -> RaiseResult<()> {
+        let sort = vec![SortField::new("title", SortOrder::Desc)];
+        let cursor = QueryCursor::new(&sort, vec![Some(json_value!("Intro"))], "p1");
+        let token = cursor.encode();
+        assert_eq!(QueryCursor::decode(&token, &sort)?, cursor);
+
+        let Err(AppError::Structured(err)) = QueryCursor::decode(&token, &[]) else {
+            panic!("Un curseur ne vaut que pour son tri");
+        };
+        assert_eq!(err.code, "ERR_DB_QUERY_CURSOR_MISMATCH");
+        assert!(QueryCursor::decode("pas-un-curseur", &sort).is_err());
+        assert!(CursorBound::from_query(Some(&token), Some(&token), &sort).is_err());
+        assert_eq!(
+            CursorBound::from_query(None, Some(&token), &sort)?,
+            Some(CursorBound::Before(cursor))
+        );
+        Ok(())
+    }
+}
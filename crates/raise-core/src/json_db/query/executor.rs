@@ -2,12 +2,24 @@
 
 use crate::utils::prelude::*;
 
+use crate::json_db::collections::id_strategy::content_hash;
 use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::indexes::fulltext;
+use crate::json_db::indexes::geo::{GeoPoint, GeoShape};
 use crate::json_db::indexes::manager::IndexManager;
+use crate::json_db::query::cursor::{CursorBound, QueryCursor};
+use crate::json_db::query::optimizer::{JoinPlan, JoinStrategy, QueryOptimizer};
+use crate::json_db::query::plan::{
+    AccessPath, CollectionPlan, FilterStep, JoinStep, QueryPlan, FULL_SCAN_WARNING_THRESHOLD,
+};
+use crate::json_db::query::sql::PreparedSql;
+use crate::json_db::query::vector::{VectorSearch, VectorSearchProvider, SCORE_FIELD};
 use crate::json_db::query::{
-    optimizer::QueryOptimizer, ComparisonOperator, Condition, FilterOperator, Projection, Query,
-    QueryFilter, QueryResult, SortField, SortOrder,
+    Accumulator, AggregateOp, Aggregation, ComparisonOperator, Condition, DistinctClause,
+    FilterOperator, JoinKind, Projection, Query, QueryFilter, QueryResult, SortField, SortOrder,
+    WindowFunction, WindowOp,
 };
+use crate::json_db::storage::cache::Cache;
 use crate::rules_engine::ast::Expr;
 use crate::rules_engine::evaluator::{Evaluator, NoOpDataProvider};
 
@@ -23,6 +35,35 @@ pub trait IndexProvider: Send + Sync {
         field: &'a str,
         value: &'a JsonValue,
     ) -> BoxFuture<'a, RaiseResult<Vec<String>>>;
+
+    /// IDs groupés par clé croissante d'un index ordonné (`None` = pas d'index BTree sur le champ).
+    fn ordered_ids<'a>(
+        &'a self,
+        _collection: &'a str,
+        _field: &'a str,
+    ) -> BoxFuture<'a, RaiseResult<Option<Vec<Vec<String>>>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// IDs classés par score BM25 d'un index plein texte (`None` = pas d'index `fulltext`).
+    fn text_search<'a>(
+        &'a self,
+        _collection: &'a str,
+        _field: &'a str,
+        _query: &'a str,
+    ) -> BoxFuture<'a, RaiseResult<Option<Vec<(String, f64)>>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    /// IDs des documents situés dans `shape` (`None` = pas d'index `geo` sur le champ).
+    fn geo_search<'a>(
+        &'a self,
+        _collection: &'a str,
+        _field: &'a str,
+        _shape: &'a GeoShape,
+    ) -> BoxFuture<'a, RaiseResult<Option<Vec<String>>>> {
+        Box::pin(async { Ok(None) })
+    }
 }
 
 // --- IMPLÉMENTATION NO-OP (BOUCHON) ---
@@ -65,13 +106,52 @@ impl<'a> IndexProvider for RealIndexProvider<'a> {
     ) -> BoxFuture<'b, RaiseResult<Vec<String>>> {
         Box::pin(async move { self.manager.search(collection, field, value).await })
     }
+
+    fn ordered_ids<'b>(
+        &'b self,
+        collection: &'b str,
+        field: &'b str,
+    ) -> BoxFuture<'b, RaiseResult<Option<Vec<Vec<String>>>>> {
+        Box::pin(async move { self.manager.ordered_ids(collection, field).await })
+    }
+
+    fn text_search<'b>(
+        &'b self,
+        collection: &'b str,
+        field: &'b str,
+        query: &'b str,
+    ) -> BoxFuture<'b, RaiseResult<Option<Vec<(String, f64)>>>> {
+        Box::pin(async move { self.manager.text_search(collection, field, query).await })
+    }
+
+    fn geo_search<'b>(
+        &'b self,
+        collection: &'b str,
+        field: &'b str,
+        shape: &'b GeoShape,
+    ) -> BoxFuture<'b, RaiseResult<Option<Vec<String>>>> {
+        Box::pin(async move { self.manager.geo_search(collection, field, shape).await })
+    }
 }
 
 // --- MOTEUR DE REQUÊTE ---
 
+/// Portée des chemins sur les lignes jointes : aucun préfixe de collection n'est retiré,
+/// `actors.name` désigne le champ `name` de la source `actors`.
+const JOIN_SCOPE: &str = "";
+
+/// Clés de tri calculées d'une ligne, le temps du tri et de la pagination.
+const SORT_KEYS_FIELD: &str = "_sort_keys";
+
+/// Requêtes SQL préparées, partagées par tous les `QueryEngine` du processus (clé : texte SQL).
+const PREPARED_SQL_CAPACITY: usize = 256;
+static PREPARED_SQL: StaticCell<Cache<String, SharedRef<PreparedSql>>> = StaticCell::new();
+
 pub struct QueryEngine<'a> {
     manager: &'a CollectionsManager<'a>,
     index_provider: Box<dyn IndexProvider + 'a>,
+    /// Store vectoriel des conditions `NearText` / `NearVector` (aucun par défaut).
+    vector_provider: Option<Box<dyn VectorSearchProvider + 'a>>,
 }
 
 impl<'a> QueryEngine<'a> {
@@ -80,6 +160,7 @@ impl<'a> QueryEngine<'a> {
         Self {
             manager,
             index_provider: Box::new(RealIndexProvider::new(idx_mgr)),
+            vector_provider: None,
         }
     }
 
@@ -90,25 +171,57 @@ impl<'a> QueryEngine<'a> {
         self
     }
 
+    /// Branche la recherche sémantique (ex: `VectorStore` configuré, côté `ai::memory`).
+    pub fn with_vector_provider(mut self, provider: Box<dyn VectorSearchProvider + 'a>) -> Self {
+        self.vector_provider = Some(provider);
+        self
+    }
+
+    /// Requête préparée pour `sql`, reprise du cache si ce texte a déjà été préparé : le
+    /// découpage et la validation syntaxique ne sont faits qu'une fois.
+    pub fn prepare(sql: &str) -> RaiseResult<SharedRef<PreparedSql>> {
+        let cache = match PREPARED_SQL.get() {
+            Some(cache) => cache,
+            None => {
+                let cache = Cache::new(PREPARED_SQL_CAPACITY, None)?;
+                PREPARED_SQL.get_or_init(|| cache)
+            }
+        };
+        let key = sql.to_string();
+        if let Some(prepared) = cache.get(&key)? {
+            return Ok(prepared);
+        }
+        let prepared = SharedRef::new(PreparedSql::prepare(sql)?);
+        cache.put(key, prepared.clone())?;
+        Ok(prepared)
+    }
+
+    #[instrument(name = "json_db.execute_query", skip_all, fields(collection = %query.collection))]
     pub async fn execute_query(&self, mut query: Query) -> RaiseResult<QueryResult> {
         let optimizer = QueryOptimizer::new();
         query = optimizer.optimize(query)?;
+        let vector_scores = self.find_vector_search(&query).await?;
+
+        if !query.joins.is_empty() {
+            return self.execute_join_query(&query, &optimizer).await;
+        }
 
         let collection_paths = self.resolve_collection_path(&query.collection).await?;
         let mut documents = Vec::new();
+        let mut text_scores = UnorderedMap::new();
 
         // 🎯 INTERCEPTION DE LA CLÉ PRIMAIRE (O(1))
         // Si la requête cherche un "_id" ou un "@id", on ne sollicite pas le moteur d'index secondaire.
-        let mut primary_key_val = None;
-        if let Some(filter) = &query.filter {
-            for cond in &filter.conditions {
-                if cond.operator == ComparisonOperator::Eq
-                    && (cond.field == "_id" || cond.field == "@id")
+        let primary_key_val = self.primary_key_lookup(&query);
+
+        // ⚡ CAS 0 : ORDER BY servi par un index BTree (ordre + pagination sans full scan)
+        if primary_key_val.is_none() && collection_paths.len() == 1 {
+            if let Some(sort) = optimizer.index_sort_field(&query) {
+                if let Some(result) = self
+                    .execute_index_sorted(&query, &collection_paths[0], sort)
+                    .await?
                 {
-                    if let Some(s) = cond.value.as_str() {
-                        primary_key_val = Some(s.to_string());
-                        break;
-                    }
+                    return Ok(result);
                 }
             }
         }
@@ -133,6 +246,39 @@ impl<'a> QueryEngine<'a> {
             let mut sub_query = query.clone();
             sub_query.collection = actual_collection_path.clone();
 
+            // 🧭 CAS 2a : voisins sémantiques fournis par le store vectoriel
+            if let Some(scores) = &vector_scores {
+                let ids: Vec<String> = scores.keys().cloned().collect();
+                let mut batch_docs = self
+                    .manager
+                    .read_many(&actual_collection_path, &ids)
+                    .await?;
+                documents.append(&mut batch_docs);
+                continue;
+            }
+
+            // 🔎 CAS 2bis : recherche plein texte servie par un index `fulltext` (déjà classée)
+            if let Some(ranked) = self.find_text_search(&sub_query).await {
+                let ids: Vec<String> = ranked.iter().map(|(id, _)| id.clone()).collect();
+                let mut batch_docs = self
+                    .manager
+                    .read_many(&actual_collection_path, &ids)
+                    .await?;
+                documents.append(&mut batch_docs);
+                text_scores.extend(ranked);
+                continue;
+            }
+
+            // 🌍 CAS 2ter : zone géographique servie par un index `geo`
+            if let Some((_field, ids)) = self.find_geo_search(&sub_query).await? {
+                let mut batch_docs = self
+                    .manager
+                    .read_many(&actual_collection_path, &ids)
+                    .await?;
+                documents.append(&mut batch_docs);
+                continue;
+            }
+
             let index_candidate = self.find_index_candidate(&sub_query).await?;
 
             let mut batch_docs = match index_candidate {
@@ -172,17 +318,26 @@ impl<'a> QueryEngine<'a> {
 
         // 2. FILTRAGE HYBRIDE (Sécurité + Métier) ASYNC
         let mut filtered_docs = Vec::new();
-        for doc in documents {
+        for mut doc in documents {
+            // 🧭 Conditions vectorielles : seuls les voisins retenus passent, avec leur score
+            if let Some(scores) = &vector_scores {
+                let score = doc
+                    .get("_id")
+                    .and_then(|v| v.as_str())
+                    .and_then(|id| scores.get(id))
+                    .copied();
+                match (score, doc.as_object_mut()) {
+                    (Some(score), Some(obj)) => {
+                        obj.insert(SCORE_FIELD.to_string(), json_value!(score));
+                    }
+                    _ => continue,
+                }
+            }
+
             // 🛡️ A. ROW-LEVEL SECURITY (RLS)
             // L'utilisateur n'a pas le droit de voir ce document si l'AST le refuse
             if let Some(rls_expr) = &query.rls_policy {
-                let provider = NoOpDataProvider; // Peut être remplacé par RealDataProvider si Lookup nécessaire
-                let is_allowed = match Evaluator::evaluate(rls_expr, &doc, &provider).await {
-                    Ok(res) => res.as_bool().unwrap_or(false),
-                    Err(_) => false, // Default Deny en cas d'erreur d'évaluation
-                };
-
-                if !is_allowed {
+                if !self.rls_allows(rls_expr, &doc).await {
                     continue; // 🚫 Document filtré silencieusement par la sécurité
                 }
             }
@@ -196,34 +351,897 @@ impl<'a> QueryEngine<'a> {
 
             filtered_docs.push(doc);
         }
-        documents = filtered_docs;
 
-        // 3. TRI, PAGINATION, PROJECTION
-        if let Some(sort_fields) = &query.sort {
-            documents.sort_by(|a, b| self.compare_docs(a, b, sort_fields, &query.collection));
+        // 🔎 PERTINENCE : sans ORDER BY explicite, une recherche plein texte ou sémantique
+        // classe les résultats (le score vectoriel prime)
+        if query.sort.is_none() {
+            if vector_scores.is_some() {
+                let score = |doc: &JsonValue| doc[SCORE_FIELD].as_f64().unwrap_or(0.0);
+                filtered_docs.sort_by(|a, b| score(b).total_cmp(&score(a)));
+            } else {
+                self.rank_by_relevance(&mut filtered_docs, &query, text_scores);
+                self.rank_by_distance(&mut filtered_docs, &query);
+            }
         }
+        self.shape_results(filtered_docs, &query, &query.collection)
+            .await
+    }
 
-        let total_count = documents.len() as u64;
-        let offset = query.offset.unwrap_or(0);
-        let limit = query.limit.unwrap_or(documents.len());
+    /// Plan d'exécution de `query`, sans la lancer : chemin d'accès par collection (mêmes
+    /// choix que `execute_query`), volume chargé estimé, ordre d'évaluation des conditions et
+    /// étapes en mémoire. Signale les parcours complets volumineux et les jointures non indexées.
+    #[instrument(name = "json_db.explain_query", skip_all, fields(collection = %query.collection))]
+    pub async fn explain_query(&self, query: Query) -> RaiseResult<QueryPlan> {
+        let optimizer = QueryOptimizer::new();
+        let query = optimizer.optimize(query)?;
+        let collection_paths = self.resolve_collection_path(&query.collection).await?;
+        let primary_key = self.primary_key_lookup(&query);
+
+        let mut plan = QueryPlan {
+            collection: query.collection.clone(),
+            sources: Vec::new(),
+            joins: Vec::new(),
+            filter_operator: query.filter.as_ref().map(|f| f.operator.clone()),
+            filters: Vec::new(),
+            stages: Vec::new(),
+            estimated_docs: 0,
+            warnings: Vec::new(),
+        };
 
-        let mut paged_docs: Vec<JsonValue> =
-            documents.into_iter().skip(offset).take(limit).collect();
+        for path in &collection_paths {
+            let total_docs = crate::json_db::collections::collection::list_document_ids(
+                &self.manager.storage.config,
+                &self.manager.space,
+                &self.manager.db,
+                path,
+                None,
+                None,
+            )
+            .await
+            .map(|ids| ids.len())
+            .unwrap_or(0);
+            let single = collection_paths.len() == 1;
+            let (access, estimated_docs) = self
+                .plan_access(&query, path, &optimizer, primary_key.as_deref(), single)
+                .await?;
+            let estimated_docs = estimated_docs.unwrap_or(total_docs).min(total_docs);
+
+            if access == AccessPath::FullScan && total_docs >= FULL_SCAN_WARNING_THRESHOLD {
+                let hint = query
+                    .filter
+                    .iter()
+                    .flat_map(|f| &f.conditions)
+                    .find(|c| c.operator == ComparisonOperator::Eq)
+                    .map(|c| format!(" : un index sur '{}' l'éviterait", c.field))
+                    .unwrap_or_default();
+                plan.warnings.push(format!(
+                    "Parcours complet de {} documents sur '{}'{}.",
+                    total_docs, path, hint
+                ));
+            }
+            plan.estimated_docs += estimated_docs;
+            plan.sources.push(CollectionPlan {
+                collection: path.clone(),
+                access,
+                total_docs,
+                estimated_docs,
+            });
+        }
+
+        for join in optimizer
+            .plan_joins(&query, self.index_provider.as_ref())
+            .await
+        {
+            if join.strategy == JoinStrategy::HashJoin {
+                plan.warnings.push(format!(
+                    "Jointure par hachage sur '{}' (collection chargée entièrement) : indexez '{}'.",
+                    join.join.collection, join.join.foreign_field
+                ));
+            }
+            plan.joins.push(JoinStep {
+                collection: join.join.collection,
+                strategy: join.strategy,
+            });
+        }
+
+        if let Some(filter) = &query.filter {
+            plan.filters = filter
+                .conditions
+                .iter()
+                .map(|cond| FilterStep {
+                    field: cond.field.clone(),
+                    operator: cond.operator.clone(),
+                    cost: optimizer.estimate_selectivity(cond),
+                })
+                .collect();
+        }
+
+        let index_sorted = plan
+            .sources
+            .iter()
+            .any(|s| matches!(s.access, AccessPath::IndexSort { .. }));
+        let stages = [
+            ("rls", query.rls_policy.is_some()),
+            ("group_by", query.aggregation.is_some()),
+            ("window", query.window.is_some()),
+            ("sort", query.sort.is_some() && !index_sorted),
+            ("distinct", query.distinct.is_some()),
+            (
+                "limit",
+                query.limit.is_some()
+                    || query.offset.is_some()
+                    || query.after.is_some()
+                    || query.before.is_some(),
+            ),
+            ("projection", query.projection.is_some()),
+        ];
+        plan.stages = stages
+            .into_iter()
+            .filter(|(_, active)| *active)
+            .map(|(stage, _)| stage.to_string())
+            .collect();
+        Ok(plan)
+    }
+
+    /// Chemin d'accès d'une collection et documents chargés (`None` : toute la collection).
+    async fn plan_access(
+        &self,
+        query: &Query,
+        collection_path: &str,
+        optimizer: &QueryOptimizer,
+        primary_key: Option<&str>,
+        single_collection: bool,
+    ) -> RaiseResult<(AccessPath, Option<usize>)> {
+        if !query.joins.is_empty() {
+            return Ok((AccessPath::FullScan, None));
+        }
+        if let Some(id) = primary_key {
+            return Ok((AccessPath::PrimaryKey { id: id.to_string() }, Some(1)));
+        }
+        if single_collection {
+            if let Some(sort) = optimizer.index_sort_field(query) {
+                let field = self.normalize_field_path(&sort.field, &query.collection);
+                if let Ok(Some(_)) = self
+                    .index_provider
+                    .ordered_ids(collection_path, &field)
+                    .await
+                {
+                    let page = query.limit.map(|limit| limit + query.offset.unwrap_or(0));
+                    return Ok((AccessPath::IndexSort { index: field }, page));
+                }
+            }
+        }
+
+        let vector_conditions: Vec<&Condition> = query
+            .filter
+            .iter()
+            .flat_map(|f| &f.conditions)
+            .filter(|c| VectorSearch::is_vector_condition(c))
+            .collect();
+        if !vector_conditions.is_empty() {
+            let mut k = usize::MAX;
+            for cond in vector_conditions {
+                k = k.min(VectorSearch::from_condition(cond, &query.collection)?.k);
+            }
+            return Ok((AccessPath::VectorSearch, Some(k)));
+        }
+
+        let mut sub_query = query.clone();
+        sub_query.collection = collection_path.to_string();
+        if let Some(ranked) = self.find_text_search(&sub_query).await {
+            let field = query
+                .filter
+                .iter()
+                .flat_map(|f| &f.conditions)
+                .find(|c| c.operator == ComparisonOperator::TextSearch)
+                .map(|c| c.field.clone())
+                .unwrap_or_default();
+            return Ok((AccessPath::FullTextIndex { field }, Some(ranked.len())));
+        }
+        if let Some((field, ids)) = self.find_geo_search(&sub_query).await? {
+            return Ok((AccessPath::GeoIndex { field }, Some(ids.len())));
+        }
+
+        if let Some((_field, value, index)) = self.find_index_candidate(&sub_query).await? {
+            let value = self.strip_quotes(&value);
+            if let Ok(ids) = self
+                .index_provider
+                .search(collection_path, &index, &value)
+                .await
+            {
+                return Ok((AccessPath::IndexScan { index, value }, Some(ids.len())));
+            }
+        }
+        Ok((AccessPath::FullScan, None))
+    }
+
+    /// Valeur d'une égalité sur `_id` / `@id` : lecture directe, sans index secondaire.
+    fn primary_key_lookup(&self, query: &Query) -> Option<String> {
+        query
+            .filter
+            .iter()
+            .flat_map(|f| &f.conditions)
+            .filter(|c| {
+                c.operator == ComparisonOperator::Eq && (c.field == "_id" || c.field == "@id")
+            })
+            .find_map(|c| c.value.as_str().map(|s| s.to_string()))
+    }
+
+    /// Trie par score BM25 décroissant (tri stable : l'ordre d'origine départage les ex aequo).
+    /// Sans index plein texte, les scores sont calculés sur les seuls documents retenus.
+    fn rank_by_relevance(
+        &self,
+        documents: &mut [JsonValue],
+        query: &Query,
+        mut scores: UnorderedMap<String, f64>,
+    ) {
+        let Some(cond) = query.filter.as_ref().and_then(|f| {
+            f.conditions
+                .iter()
+                .find(|c| c.operator == ComparisonOperator::TextSearch)
+        }) else {
+            return;
+        };
+
+        if scores.is_empty() {
+            let search = self.strip_quotes(&cond.value);
+            let texts: Vec<(String, String)> = documents
+                .iter()
+                .filter_map(|doc| {
+                    let id = doc.get("_id")?.as_str()?.to_string();
+                    let value = self.get_field_value_smart(doc, &cond.field, &query.collection)?;
+                    Some((id, fulltext::value_text(value)?))
+                })
+                .collect();
+            scores = fulltext::rank_texts(&texts, search.as_str().unwrap_or(""))
+                .into_iter()
+                .collect();
+        }
+
+        let score = |doc: &JsonValue| {
+            doc.get("_id")
+                .and_then(|v| v.as_str())
+                .and_then(|id| scores.get(id))
+                .copied()
+                .unwrap_or(0.0)
+        };
+        documents.sort_by(|a, b| score(b).total_cmp(&score(a)));
+    }
+
+    /// Trie par distance croissante au centre de la première condition `Near` (tri stable,
+    /// positions illisibles en dernier). Une recherche plein texte garde son classement.
+    fn rank_by_distance(&self, documents: &mut [JsonValue], query: &Query) {
+        let Some(filter) = &query.filter else {
+            return;
+        };
+        if filter
+            .conditions
+            .iter()
+            .any(|c| c.operator == ComparisonOperator::TextSearch)
+        {
+            return;
+        }
+        let Some((cond, center)) = filter.conditions.iter().find_map(|c| match geo_shape(c) {
+            Ok(Some(GeoShape::Circle { center, .. })) => Some((c, center)),
+            _ => None,
+        }) else {
+            return;
+        };
+        let distance = |doc: &JsonValue| {
+            self.get_field_value_smart(doc, &cond.field, &query.collection)
+                .and_then(GeoPoint::from_value)
+                .map(|point| center.distance_km(&point))
+                .unwrap_or(f64::INFINITY)
+        };
+        documents.sort_by(|a, b| distance(a).total_cmp(&distance(b)));
+    }
+
+    /// Étapes finales communes : agrégation, tri, pagination puis projection.
+    async fn shape_results(
+        &self,
+        mut documents: Vec<JsonValue>,
+        query: &Query,
+        scope: &str,
+    ) -> RaiseResult<QueryResult> {
+        // 3. AGRÉGATION : les groupes remplacent les documents pour la suite du pipeline
+        if let Some(aggregation) = &query.aggregation {
+            documents = self.aggregate(&documents, aggregation, scope);
+        }
+
+        // 3 bis. FENÊTRES puis QUALIFY, qui filtre sur leurs colonnes
+        if let Some(window) = &query.window {
+            self.apply_windows(&mut documents, &window.functions, scope);
+            if let Some(qualify) = &window.qualify {
+                let mut kept = Vec::with_capacity(documents.len());
+                for row in documents {
+                    if self.evaluate_filter(&row, qualify, scope).await {
+                        kept.push(row);
+                    }
+                }
+                documents = kept;
+            }
+        }
+
+        // 4. TRI (départagé par `_id` : ordre stable pour les curseurs), PAGINATION, PROJECTION
+        let sort_fields = query.sort.as_deref().unwrap_or_default();
+        let computed_sort = sort_fields.iter().any(|s| s.expr.is_some());
+        if computed_sort {
+            for row in &mut documents {
+                self.attach_sort_keys(row, sort_fields).await;
+            }
+        }
+        let bound =
+            CursorBound::from_query(query.after.as_deref(), query.before.as_deref(), sort_fields)?;
+        if query.sort.is_some() || bound.is_some() {
+            documents.sort_by(|a, b| {
+                self.compare_docs(a, b, sort_fields, scope)
+                    .then_with(|| self.row_id(a, scope).cmp(self.row_id(b, scope)))
+            });
+        }
+
+        // DISTINCT : la première ligne de chaque clé, dans l'ordre du tri, est conservée
+        if let Some(distinct) = &query.distinct {
+            let mut seen = UniqueSet::new();
+            documents.retain(|row| seen.insert(self.distinct_key(row, distinct, scope)));
+        }
+
+        let total = documents.len();
+        let limit = query.limit.unwrap_or(total);
+        let (start, end) = match &bound {
+            Some(CursorBound::After(cursor)) => {
+                let start = documents.partition_point(|d| {
+                    self.compare_to_cursor(d, cursor, sort_fields, scope) != FmtOrdering::Greater
+                });
+                (start, start.saturating_add(limit).min(total))
+            }
+            Some(CursorBound::Before(cursor)) => {
+                let end = documents.partition_point(|d| {
+                    self.compare_to_cursor(d, cursor, sort_fields, scope) == FmtOrdering::Less
+                });
+                (end.saturating_sub(limit), end)
+            }
+            None => {
+                let start = query.offset.unwrap_or(0).min(total);
+                (start, start.saturating_add(limit).min(total))
+            }
+        };
+
+        let mut paged_docs: Vec<JsonValue> = documents.drain(start..end).collect();
+        let (prev_cursor, next_cursor) =
+            self.page_cursors(&paged_docs, start > 0, end < total, sort_fields, scope);
+        if computed_sort {
+            for doc in &mut paged_docs {
+                if let Some(obj) = doc.as_object_mut() {
+                    obj.remove(SORT_KEYS_FIELD);
+                }
+            }
+        }
 
         if let Some(projection) = &query.projection {
             for doc in &mut paged_docs {
-                *doc = self.project_fields(doc, projection, &query.collection);
+                *doc = self.project(doc, projection, scope).await;
             }
         }
 
         Ok(QueryResult {
             documents: paged_docs,
-            total_count,
-            offset: Some(offset),
+            total_count: total as u64,
+            offset: Some(start),
             limit: Some(limit),
+            next_cursor,
+            prev_cursor,
         })
     }
 
+    /// Numérote les lignes de chaque partition dans l'ordre de la fenêtre (`_id` en dernier
+    /// recours) et pose la colonne `alias` sur chaque ligne.
+    fn apply_windows(&self, rows: &mut [JsonValue], functions: &[WindowFunction], scope: &str) {
+        for function in functions {
+            let partition = |row: &JsonValue| {
+                let values: Vec<JsonValue> = function
+                    .partition_by
+                    .iter()
+                    .map(|f| {
+                        self.get_field_value_smart(row, f, scope)
+                            .cloned()
+                            .unwrap_or(JsonValue::Null)
+                    })
+                    .collect();
+                JsonValue::Array(values).to_string()
+            };
+            let keys: Vec<String> = rows.iter().map(partition).collect();
+            let mut order: Vec<usize> = (0..rows.len()).collect();
+            order.sort_by(|&a, &b| {
+                keys[a]
+                    .cmp(&keys[b])
+                    .then_with(|| self.compare_docs(&rows[a], &rows[b], &function.order_by, scope))
+                    .then_with(|| {
+                        self.row_id(&rows[a], scope)
+                            .cmp(self.row_id(&rows[b], scope))
+                    })
+            });
+
+            let mut values = vec![0u64; rows.len()];
+            let (mut number, mut rank, mut dense) = (0u64, 0u64, 0u64);
+            for (pos, &idx) in order.iter().enumerate() {
+                let previous = pos.checked_sub(1).map(|p| order[p]);
+                let same_partition = previous.is_some_and(|p| keys[p] == keys[idx]);
+                if !same_partition {
+                    (number, rank, dense) = (0, 0, 0);
+                }
+                number += 1;
+                let tied = same_partition
+                    && previous.is_some_and(|p| {
+                        self.compare_docs(&rows[p], &rows[idx], &function.order_by, scope)
+                            == FmtOrdering::Equal
+                    });
+                if !tied {
+                    rank = number;
+                    dense += 1;
+                }
+                values[idx] = match function.op {
+                    WindowOp::RowNumber => number,
+                    WindowOp::Rank => rank,
+                    WindowOp::DenseRank => dense,
+                };
+            }
+
+            for (row, value) in rows.iter_mut().zip(values) {
+                if let Some(obj) = row.as_object_mut() {
+                    obj.insert(function.alias.clone(), json_value!(value));
+                }
+            }
+        }
+    }
+
+    /// Clé de dédoublonnage : valeurs des champs `on`, ou contenu métier de la ligne entière.
+    fn distinct_key(&self, row: &JsonValue, distinct: &DistinctClause, scope: &str) -> String {
+        if distinct.on.is_empty() {
+            return content_hash(row);
+        }
+        let values: Vec<JsonValue> = distinct
+            .on
+            .iter()
+            .map(|f| {
+                self.get_field_value_smart(row, f, scope)
+                    .cloned()
+                    .unwrap_or(JsonValue::Null)
+            })
+            .collect();
+        JsonValue::Array(values).to_string()
+    }
+
+    /// `_id` d'une ligne (document, ou document source d'une ligne jointe) ; vide pour un groupe.
+    fn row_id<'b>(&self, doc: &'b JsonValue, scope: &str) -> &'b str {
+        self.get_field_value_smart(doc, "_id", scope)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// Position d'un document par rapport à celui que marque `cursor`, dans l'ordre du tri.
+    fn compare_to_cursor(
+        &self,
+        doc: &JsonValue,
+        cursor: &QueryCursor,
+        sort_fields: &[SortField],
+        scope: &str,
+    ) -> FmtOrdering {
+        for (i, (s, key)) in sort_fields.iter().zip(&cursor.keys).enumerate() {
+            let value = self.sort_value(doc, i, s, scope);
+            let cmp = self.compare_json_values(value, key.as_ref());
+            if cmp != FmtOrdering::Equal {
+                return match s.order {
+                    SortOrder::Asc => cmp,
+                    SortOrder::Desc => cmp.reverse(),
+                };
+            }
+        }
+        self.row_id(doc, scope).cmp(cursor.id.as_str())
+    }
+
+    /// Curseurs des pages voisines, tirés des documents de bord de la page (avant projection).
+    fn page_cursors(
+        &self,
+        page: &[JsonValue],
+        has_prev: bool,
+        has_next: bool,
+        sort_fields: &[SortField],
+        scope: &str,
+    ) -> (Option<String>, Option<String>) {
+        let cursor = |doc: &JsonValue| {
+            let keys = sort_fields
+                .iter()
+                .enumerate()
+                .map(|(i, s)| self.sort_value(doc, i, s, scope).cloned())
+                .collect();
+            QueryCursor::new(sort_fields, keys, self.row_id(doc, scope)).encode()
+        };
+        (
+            page.first().filter(|_| has_prev).map(&cursor),
+            page.last().filter(|_| has_next).map(&cursor),
+        )
+    }
+
+    /// 🛡️ Row-Level Security : refus par défaut si l'évaluation échoue.
+    async fn rls_allows(&self, rls_expr: &Expr, doc: &JsonValue) -> bool {
+        let provider = NoOpDataProvider; // Peut être remplacé par RealDataProvider si Lookup nécessaire
+        match Evaluator::evaluate(rls_expr, doc, &provider).await {
+            Ok(res) => res.as_bool().unwrap_or(false),
+            Err(_) => false,
+        }
+    }
+
+    /// Exécution avec jointures : les documents de la collection de base (soumis à la RLS)
+    /// deviennent des lignes `{ <collection>: doc }`, enrichies jointure par jointure selon le
+    /// plan de l'optimiseur. Le WHERE et les étapes finales portent ensuite sur les lignes.
+    async fn execute_join_query(
+        &self,
+        query: &Query,
+        optimizer: &QueryOptimizer,
+    ) -> RaiseResult<QueryResult> {
+        let mut rows = Vec::new();
+        for path in self.resolve_collection_path(&query.collection).await? {
+            for doc in self.manager.list_all(&path).await? {
+                if let Some(rls_expr) = &query.rls_policy {
+                    if !self.rls_allows(rls_expr, &doc).await {
+                        continue;
+                    }
+                }
+                let mut row = JsonObject::new();
+                row.insert(query.collection.clone(), doc);
+                rows.push(JsonValue::Object(row));
+            }
+        }
+
+        for plan in optimizer
+            .plan_joins(query, self.index_provider.as_ref())
+            .await
+        {
+            #[cfg(debug_assertions)]
+            println!(
+                "⚡ QueryEngine: Join {} via {:?}",
+                plan.join.collection, plan.strategy
+            );
+            rows = self.apply_join(rows, &plan).await?;
+        }
+
+        let mut filtered_rows = Vec::new();
+        for row in rows {
+            if let Some(filter) = &query.filter {
+                if !self.evaluate_filter(&row, filter, JOIN_SCOPE).await {
+                    continue;
+                }
+            }
+            filtered_rows.push(row);
+        }
+
+        self.shape_results(filtered_rows, query, JOIN_SCOPE).await
+    }
+
+    /// Ajoute la source jointe à chaque ligne. Une clé locale en tableau (liste de
+    /// références) joint chacun de ses éléments ; une clé absente ou nulle ne joint rien.
+    async fn apply_join(
+        &self,
+        rows: Vec<JsonValue>,
+        plan: &JoinPlan,
+    ) -> RaiseResult<Vec<JsonValue>> {
+        let join = &plan.join;
+        let paths = self.resolve_collection_path(&join.collection).await?;
+
+        // Hachage : la collection jointe est lue une seule fois
+        let mut hashed: UnorderedMap<String, Vec<JsonValue>> = UnorderedMap::new();
+        if plan.strategy == JoinStrategy::HashJoin {
+            for path in &paths {
+                for doc in self.manager.list_all(path).await? {
+                    let Some(value) =
+                        self.get_field_value_smart(&doc, &join.foreign_field, &join.collection)
+                    else {
+                        continue;
+                    };
+                    for key in join_keys(value) {
+                        hashed.entry(key.to_string()).or_default().push(doc.clone());
+                    }
+                }
+            }
+        }
+        // Clé primaire / index : une lecture par valeur distincte de la clé locale
+        let mut lookups: UnorderedMap<String, Vec<JsonValue>> = UnorderedMap::new();
+
+        let mut joined = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut matches = Vec::new();
+            if let Some(local) = self
+                .get_field_value_smart(&row, &join.local_field, JOIN_SCOPE)
+                .cloned()
+            {
+                for key in join_keys(&local) {
+                    let signature = key.to_string();
+                    if plan.strategy != JoinStrategy::HashJoin && !lookups.contains_key(&signature)
+                    {
+                        let docs = self.lookup_join(&paths, &plan.strategy, key).await?;
+                        lookups.insert(signature.clone(), docs);
+                    }
+                    let source = match plan.strategy {
+                        JoinStrategy::HashJoin => &hashed,
+                        _ => &lookups,
+                    };
+                    matches.extend(source.get(&signature).cloned().unwrap_or_default());
+                }
+            }
+
+            if matches.is_empty() {
+                if join.kind == JoinKind::Left {
+                    joined.push(with_source(row, join.key(), JsonValue::Null));
+                }
+                continue;
+            }
+            for doc in matches {
+                joined.push(with_source(row.clone(), join.key(), doc));
+            }
+        }
+        Ok(joined)
+    }
+
+    async fn lookup_join(
+        &self,
+        paths: &[String],
+        strategy: &JoinStrategy,
+        key: &JsonValue,
+    ) -> RaiseResult<Vec<JsonValue>> {
+        let mut docs = Vec::new();
+        for path in paths {
+            match strategy {
+                JoinStrategy::PrimaryKey => {
+                    let id = key.as_str().map_or_else(|| key.to_string(), str::to_string);
+                    if let Ok(Some(doc)) = self.manager.get_document(path, &id).await {
+                        docs.push(doc);
+                    }
+                }
+                JoinStrategy::IndexLookup { index_field } => {
+                    let ids = self.index_provider.search(path, index_field, key).await?;
+                    docs.append(&mut self.manager.read_many(path, &ids).await?);
+                }
+                JoinStrategy::HashJoin => {}
+            }
+        }
+        Ok(docs)
+    }
+
+    /// Tri piloté par index : les IDs sont ordonnés par l'index BTree du champ de tri,
+    /// puis seule la page demandée est lue sur disque. Les documents sans valeur pour le
+    /// champ (absents de l'index) se placent comme dans le tri mémoire : en tête en ASC,
+    /// en queue en DESC. Renvoie `None` si aucun index ordonné n'est disponible.
+    async fn execute_index_sorted(
+        &self,
+        query: &Query,
+        collection_path: &str,
+        sort: &SortField,
+    ) -> RaiseResult<Option<QueryResult>> {
+        let sort_fields = query.sort.as_deref().unwrap_or_default();
+        let bound =
+            CursorBound::from_query(query.after.as_deref(), query.before.as_deref(), sort_fields)?;
+        let field = self.normalize_field_path(&sort.field, &query.collection);
+        let groups = match self
+            .index_provider
+            .ordered_ids(collection_path, &field)
+            .await
+        {
+            Ok(Some(groups)) => groups,
+            _ => return Ok(None),
+        };
+
+        #[cfg(debug_assertions)]
+        println!(
+            "⚡ QueryEngine: Index Sort sur {}.{}",
+            collection_path, field
+        );
+
+        // L'index peut référencer des documents supprimés : la liste physique fait foi
+        let all_ids = crate::json_db::collections::collection::list_document_ids(
+            &self.manager.storage.config,
+            &self.manager.space,
+            &self.manager.db,
+            collection_path,
+            None,
+            None,
+        )
+        .await?;
+        let mut unindexed: UniqueSet<&str> = all_ids.iter().map(String::as_str).collect();
+        let mut indexed = Vec::with_capacity(all_ids.len());
+        // Clés égales départagées par `_id`, comme le tri en mémoire
+        let mut push_group = |group: &Vec<String>, indexed: &mut Vec<String>| {
+            let mut group: Vec<&String> = group.iter().collect();
+            group.sort();
+            for id in group {
+                if unindexed.remove(id.as_str()) {
+                    indexed.push(id.clone());
+                }
+            }
+        };
+        match sort.order {
+            SortOrder::Asc => groups.iter().for_each(|g| push_group(g, &mut indexed)),
+            SortOrder::Desc => groups
+                .iter()
+                .rev()
+                .for_each(|g| push_group(g, &mut indexed)),
+        }
+        let mut missing: Vec<String> = all_ids
+            .iter()
+            .filter(|id| unindexed.contains(id.as_str()))
+            .cloned()
+            .collect();
+        missing.sort();
+
+        let ordered: Vec<String> = match sort.order {
+            SortOrder::Asc => missing.into_iter().chain(indexed).collect(),
+            SortOrder::Desc => indexed.into_iter().chain(missing).collect(),
+        };
+
+        let total = ordered.len();
+        let limit = query.limit.unwrap_or(total);
+        let (start, end) = match &bound {
+            Some(bound) => {
+                // Document marqué supprimé : repli sur le tri en mémoire (comparaison des clés)
+                let Some(pos) = ordered.iter().position(|id| *id == bound.cursor().id) else {
+                    return Ok(None);
+                };
+                match bound {
+                    CursorBound::After(_) => (pos + 1, (pos + 1).saturating_add(limit).min(total)),
+                    CursorBound::Before(_) => (pos.saturating_sub(limit), pos),
+                }
+            }
+            None => {
+                let start = query.offset.unwrap_or(0).min(total);
+                (start, start.saturating_add(limit).min(total))
+            }
+        };
+
+        let mut documents = self
+            .manager
+            .read_many(collection_path, &ordered[start..end])
+            .await?;
+        let (prev_cursor, next_cursor) = self.page_cursors(
+            &documents,
+            start > 0,
+            end < total,
+            sort_fields,
+            &query.collection,
+        );
+        if let Some(projection) = &query.projection {
+            for doc in &mut documents {
+                *doc = self.project(doc, projection, &query.collection).await;
+            }
+        }
+
+        Ok(Some(QueryResult {
+            documents,
+            total_count: total as u64,
+            offset: Some(start),
+            limit: Some(limit),
+            next_cursor,
+            prev_cursor,
+        }))
+    }
+
+    /// 🧭 Conditions `NearText` / `NearVector` : `_id` des voisins et score. Elles doivent
+    /// figurer dans un filtre `AND` sans jointure ; plusieurs conditions se cumulent
+    /// (intersection des voisins, scores additionnés).
+    async fn find_vector_search(
+        &self,
+        query: &Query,
+    ) -> RaiseResult<Option<UnorderedMap<String, f64>>> {
+        let Some(filter) = &query.filter else {
+            return Ok(None);
+        };
+        let conditions: Vec<&Condition> = filter
+            .conditions
+            .iter()
+            .filter(|c| VectorSearch::is_vector_condition(c))
+            .collect();
+        if conditions.is_empty() {
+            return Ok(None);
+        }
+        if filter.operator != FilterOperator::And || !query.joins.is_empty() {
+            raise_error!(
+                "ERR_DB_QUERY_VECTOR_PLACEMENT",
+                error = "Les conditions NearText / NearVector se combinent en AND, sans jointure.",
+                context =
+                    json_value!({ "collection": query.collection, "operator": filter.operator })
+            );
+        }
+        let Some(provider) = &self.vector_provider else {
+            raise_error!(
+                "ERR_DB_QUERY_VECTOR_UNAVAILABLE",
+                error = "Aucun store vectoriel n'est branché sur ce moteur de requêtes.",
+                context = json_value!({
+                    "collection": query.collection,
+                    "hint": "Construisez le QueryEngine avec `with_vector_provider` (ex: `StoreVectorSearch`)."
+                })
+            );
+        };
+
+        let mut merged: Option<UnorderedMap<String, f64>> = None;
+        for cond in conditions {
+            let request = VectorSearch::from_condition(cond, &query.collection)?;
+            let hits: UnorderedMap<String, f64> = provider
+                .search(self.manager, &request)
+                .await?
+                .into_iter()
+                .collect();
+            merged = Some(match merged {
+                None => hits,
+                Some(previous) => previous
+                    .into_iter()
+                    .filter_map(|(id, score)| hits.get(&id).map(|s| (id, score + s)))
+                    .collect(),
+            });
+        }
+        Ok(merged)
+    }
+
+    /// 🔎 Condition `TextSearch` d'un filtre `AND` portée par un index plein texte :
+    /// IDs candidats et scores BM25. Repli silencieux (scan) si l'index est illisible.
+    async fn find_text_search(&self, query: &Query) -> Option<Vec<(String, f64)>> {
+        let filter = query
+            .filter
+            .as_ref()
+            .filter(|f| f.operator == FilterOperator::And)?;
+
+        for cond in &filter.conditions {
+            if cond.operator != ComparisonOperator::TextSearch {
+                continue;
+            }
+            let search = self.strip_quotes(&cond.value);
+            let Some(text) = search.as_str() else {
+                continue;
+            };
+            let clean_field = self.normalize_field_path(&cond.field, &query.collection);
+            let leaf = cond.field.split('.').next_back().unwrap_or_default();
+
+            for field in [clean_field.as_str(), leaf] {
+                if let Ok(Some(ranked)) = self
+                    .index_provider
+                    .text_search(&query.collection, field, text)
+                    .await
+                {
+                    return Some(ranked);
+                }
+            }
+        }
+        None
+    }
+
+    /// 🌍 Conditions `Near` / `Within` : chaque zone est validée (erreur explicite si elle est
+    /// mal formée) ; la première portée par un index `geo` dans un filtre `AND` fournit les
+    /// candidats `(index, ids)`. Sans index, les conditions sont évaluées par scan.
+    async fn find_geo_search(&self, query: &Query) -> RaiseResult<Option<(String, Vec<String>)>> {
+        let Some(filter) = &query.filter else {
+            return Ok(None);
+        };
+        let mut candidates = None;
+        for cond in &filter.conditions {
+            let Some(shape) = geo_shape(cond)? else {
+                continue;
+            };
+            if candidates.is_some() || filter.operator != FilterOperator::And {
+                continue;
+            }
+            let clean_field = self.normalize_field_path(&cond.field, &query.collection);
+            let leaf = cond.field.split('.').next_back().unwrap_or_default();
+            for field in [clean_field.as_str(), leaf] {
+                if let Ok(Some(ids)) = self
+                    .index_provider
+                    .geo_search(&query.collection, field, &shape)
+                    .await
+                {
+                    candidates = Some((field.to_string(), ids));
+                    break;
+                }
+            }
+        }
+        Ok(candidates)
+    }
+
     /// 🎯 RECHERCHE D'INDEX ROBUSTE
     /// Retourne : (Nom du champ dans le document, Valeur cherchée, Nom de l'index à utiliser)
     async fn find_index_candidate(
@@ -273,6 +1291,123 @@ impl<'a> QueryEngine<'a> {
         Ok(None)
     }
 
+    // --- AGRÉGATION ---
+
+    /// Regroupe les documents par valeurs des champs `group_by` (ordre de première apparition)
+    /// et produit une ligne par groupe : clés de regroupement puis accumulateurs.
+    fn aggregate(
+        &self,
+        documents: &[JsonValue],
+        aggregation: &Aggregation,
+        collection_name: &str,
+    ) -> Vec<JsonValue> {
+        let mut groups: Vec<(Vec<JsonValue>, Vec<&JsonValue>)> = Vec::new();
+        let mut slots: UnorderedMap<String, usize> = UnorderedMap::new();
+
+        for doc in documents {
+            let key: Vec<JsonValue> = aggregation
+                .group_by
+                .iter()
+                .map(|f| {
+                    self.get_field_value_smart(doc, f, collection_name)
+                        .cloned()
+                        .unwrap_or(JsonValue::Null)
+                })
+                .collect();
+            let signature = JsonValue::Array(key.clone()).to_string();
+            let slot = *slots.entry(signature).or_insert_with(|| {
+                groups.push((key, Vec::new()));
+                groups.len() - 1
+            });
+            groups[slot].1.push(doc);
+        }
+
+        // Agrégat global sur un ensemble vide : une ligne reste due (COUNT(*) = 0)
+        if groups.is_empty() && aggregation.group_by.is_empty() {
+            groups.push((Vec::new(), Vec::new()));
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, members)| {
+                let mut row = JsonObject::new();
+                for (field, value) in aggregation.group_by.iter().zip(key) {
+                    row.insert(self.output_key(field, collection_name), value);
+                }
+                for acc in &aggregation.accumulators {
+                    row.insert(
+                        acc.alias.clone(),
+                        self.accumulate(acc, &members, collection_name),
+                    );
+                }
+                JsonValue::Object(row)
+            })
+            .collect()
+    }
+
+    fn accumulate(
+        &self,
+        acc: &Accumulator,
+        members: &[&JsonValue],
+        collection_name: &str,
+    ) -> JsonValue {
+        // Comme en SQL, les valeurs absentes ou nulles sont ignorées (sauf COUNT(*))
+        let values: Vec<&JsonValue> = match &acc.field {
+            Some(field) => members
+                .iter()
+                .filter_map(|doc| self.get_field_value_smart(doc, field, collection_name))
+                .filter(|v| !v.is_null())
+                .collect(),
+            None => members.to_vec(),
+        };
+
+        match acc.op {
+            AggregateOp::Count => json_value!(values.len()),
+            AggregateOp::CountDistinct => json_value!(self.distinct_values(&values).len()),
+            AggregateOp::Sum => {
+                if values.iter().all(|v| v.is_i64()) {
+                    json_value!(values.iter().filter_map(|v| v.as_i64()).sum::<i64>())
+                } else {
+                    json_value!(values.iter().filter_map(|v| v.as_f64()).sum::<f64>())
+                }
+            }
+            AggregateOp::Avg => {
+                let numbers: Vec<f64> = values.iter().filter_map(|v| v.as_f64()).collect();
+                if numbers.is_empty() {
+                    JsonValue::Null
+                } else {
+                    json_value!(numbers.iter().sum::<f64>() / numbers.len() as f64)
+                }
+            }
+            AggregateOp::Min => values
+                .iter()
+                .min_by(|a, b| self.compare_json_values(Some(*a), Some(*b)))
+                .map(|v| (*v).clone())
+                .unwrap_or(JsonValue::Null),
+            AggregateOp::Max => values
+                .iter()
+                .max_by(|a, b| self.compare_json_values(Some(*a), Some(*b)))
+                .map(|v| (*v).clone())
+                .unwrap_or(JsonValue::Null),
+            AggregateOp::Distinct => JsonValue::Array(self.distinct_values(&values)),
+        }
+    }
+
+    fn distinct_values(&self, values: &[&JsonValue]) -> Vec<JsonValue> {
+        let mut seen = UniqueSet::new();
+        values
+            .iter()
+            .filter(|v| seen.insert(v.to_string()))
+            .map(|v| (*v).clone())
+            .collect()
+    }
+
+    /// Nom de colonne d'une clé de regroupement : la feuille du chemin, comme en projection.
+    fn output_key(&self, field: &str, collection_name: &str) -> String {
+        let clean = self.normalize_field_path(field, collection_name);
+        clean.split('.').next_back().unwrap_or(&clean).to_string()
+    }
+
     // --- LOGIQUE MÉTIER ET NORMALISATION ---
     async fn evaluate_filter(
         &self,
@@ -321,6 +1456,12 @@ impl<'a> QueryEngine<'a> {
             ComparisonOperator::Eq => self.values_equal(val, Some(&clean_cond_val)),
             ComparisonOperator::Ne => !self.values_equal(val, Some(&clean_cond_val)),
             ComparisonOperator::Matches => self.values_equal(val, Some(&clean_cond_val)),
+            ComparisonOperator::TextSearch => {
+                match (val.and_then(fulltext::value_text), clean_cond_val.as_str()) {
+                    (Some(text), Some(search)) => fulltext::matches(&text, search),
+                    _ => false,
+                }
+            }
 
             ComparisonOperator::Gt => {
                 self.compare_values(val, &clean_cond_val) == Some(FmtOrdering::Greater)
@@ -393,6 +1534,16 @@ impl<'a> QueryEngine<'a> {
                 false
             }
 
+            // Appliquées en amont par `find_vector_search` (seuls les voisins arrivent ici)
+            ComparisonOperator::NearText | ComparisonOperator::NearVector => true,
+
+            ComparisonOperator::Near | ComparisonOperator::Within => {
+                match (geo_shape(condition), val.and_then(GeoPoint::from_value)) {
+                    (Ok(Some(shape)), Some(point)) => shape.contains(&point),
+                    _ => false,
+                }
+            }
+
             ComparisonOperator::IsA => {
                 let class_name = clean_cond_val.as_str().unwrap_or("");
                 let mut is_match = false;
@@ -608,9 +1759,9 @@ impl<'a> QueryEngine<'a> {
         sort_fields: &[SortField],
         collection_name: &str,
     ) -> FmtOrdering {
-        for s in sort_fields {
-            let va = self.get_field_value_smart(a, &s.field, collection_name);
-            let vb = self.get_field_value_smart(b, &s.field, collection_name);
+        for (i, s) in sort_fields.iter().enumerate() {
+            let va = self.sort_value(a, i, s, collection_name);
+            let vb = self.sort_value(b, i, s, collection_name);
             let cmp = self.compare_json_values(va, vb);
             if cmp != FmtOrdering::Equal {
                 return match s.order {
@@ -622,6 +1773,43 @@ impl<'a> QueryEngine<'a> {
         FmtOrdering::Equal
     }
 
+    /// Valeur de la `index`-ième clé de tri d'une ligne : le champ, ou l'expression
+    /// précalculée par `attach_sort_keys`. Une valeur nulle se range comme une valeur absente
+    /// (curseurs compris).
+    fn sort_value<'b>(
+        &self,
+        doc: &'b JsonValue,
+        index: usize,
+        sort: &SortField,
+        scope: &str,
+    ) -> Option<&'b JsonValue> {
+        let value = match sort.expr {
+            Some(_) => doc.get(SORT_KEYS_FIELD).and_then(|keys| keys.get(index)),
+            None => self.get_field_value_smart(doc, &sort.field, scope),
+        };
+        value.filter(|v| !v.is_null())
+    }
+
+    /// Évalue une fois par ligne les clés de tri calculées (`null` si l'évaluation échoue,
+    /// comme pour les colonnes calculées) et les range sous `SORT_KEYS_FIELD`.
+    async fn attach_sort_keys(&self, row: &mut JsonValue, sort_fields: &[SortField]) {
+        let provider = NoOpDataProvider;
+        let mut keys = Vec::with_capacity(sort_fields.len());
+        for sort in sort_fields {
+            let value = match &sort.expr {
+                Some(expr) => match Evaluator::evaluate(expr, &*row, &provider).await {
+                    Ok(res) => res.into_owned(),
+                    Err(_) => JsonValue::Null,
+                },
+                None => JsonValue::Null,
+            };
+            keys.push(value);
+        }
+        if let Some(obj) = row.as_object_mut() {
+            obj.insert(SORT_KEYS_FIELD.to_string(), JsonValue::Array(keys));
+        }
+    }
+
     fn compare_values(&self, a: Option<&JsonValue>, b: &JsonValue) -> Option<FmtOrdering> {
         self.compare_json_values(a, Some(b)).into()
     }
@@ -646,6 +1834,24 @@ impl<'a> QueryEngine<'a> {
         }
     }
 
+    /// Projection d'un document ; les colonnes calculées sont évaluées sur le document complet.
+    async fn project(&self, doc: &JsonValue, projection: &Projection, scope: &str) -> JsonValue {
+        let mut projected = self.project_fields(doc, projection, scope);
+        if let (Projection::Computed { columns, .. }, Some(obj)) =
+            (projection, projected.as_object_mut())
+        {
+            let provider = NoOpDataProvider;
+            for column in columns {
+                let value = match Evaluator::evaluate(&column.expr, doc, &provider).await {
+                    Ok(res) => res.into_owned(),
+                    Err(_) => JsonValue::Null,
+                };
+                obj.insert(column.alias.clone(), value);
+            }
+        }
+        projected
+    }
+
     fn project_fields(
         &self,
         doc: &JsonValue,
@@ -654,16 +1860,28 @@ impl<'a> QueryEngine<'a> {
     ) -> JsonValue {
         if let JsonValue::Object(map) = doc {
             let mut new_map = JsonObject::new();
+            let include = |fields: &[String], new_map: &mut JsonObject| {
+                for field in fields {
+                    if let Some(val) = self.get_field_value_smart(doc, field, collection_name) {
+                        let output_key = field.split('.').next_back().unwrap_or(field);
+                        new_map.insert(output_key.to_string(), val.clone());
+                    }
+                }
+            };
             match projection {
                 Projection::Include(fields) => {
                     if fields.is_empty() {
                         return doc.clone();
                     }
-                    for field in fields {
-                        if let Some(val) = self.get_field_value_smart(doc, field, collection_name) {
-                            let output_key = field.split('.').next_back().unwrap_or(field);
-                            new_map.insert(output_key.to_string(), val.clone());
-                        }
+                    include(fields, &mut new_map);
+                }
+                Projection::Computed {
+                    fields, wildcard, ..
+                } => {
+                    if *wildcard {
+                        new_map = map.clone();
+                    } else {
+                        include(fields, &mut new_map);
                     }
                 }
                 Projection::Exclude(fields) => {
@@ -706,20 +1924,21 @@ impl<'a> QueryEngine<'a> {
             .db_root(&self.manager.space, &self.manager.db)
             .join("_system.json");
 
-        if let Ok(content) = crate::utils::io::fs::read_to_string_async(&index_path).await {
-            if let Ok(index_json) =
-                crate::utils::data::json::deserialize_from_str::<JsonValue>(&content)
+        if let Ok(index_json) = crate::json_db::storage::encryption::read_json::<JsonValue>(
+            &self.manager.storage.config,
+            &index_path,
+        )
+        .await
+        {
+            if let Some(collections) = index_json
+                .pointer("/collections")
+                .and_then(|v| v.as_object())
             {
-                if let Some(collections) = index_json
-                    .pointer("/collections")
-                    .and_then(|v| v.as_object())
-                {
-                    for (path, _) in collections {
-                        if path == target_collection
-                            || path.ends_with(&format!("/{}", target_collection))
-                        {
-                            resolved_paths.push(path.clone());
-                        }
+                for (path, _) in collections {
+                    if path == target_collection
+                        || path.ends_with(&format!("/{}", target_collection))
+                    {
+                        resolved_paths.push(path.clone());
                     }
                 }
             }
@@ -734,6 +1953,50 @@ impl<'a> QueryEngine<'a> {
     }
 }
 
+/// Valeurs de jointure d'un champ : chaque élément non nul d'un tableau, sinon la valeur.
+/// Zone d'une condition `Near` (cercle) ou `Within` (rectangle) ; `None` pour les autres
+/// opérateurs.
+fn geo_shape(condition: &Condition) -> RaiseResult<Option<GeoShape>> {
+    let shape = match condition.operator {
+        ComparisonOperator::Near => GeoShape::circle(&condition.value),
+        ComparisonOperator::Within => GeoShape::bounding_box(&condition.value),
+        _ => return Ok(None),
+    };
+    match shape {
+        Some(shape) => Ok(Some(shape)),
+        None => raise_error!(
+            "ERR_DB_QUERY_GEO_INVALID",
+            error = format!(
+                "Zone géographique invalide pour {:?} sur '{}'.",
+                condition.operator, condition.field
+            ),
+            context = json_value!({
+                "field": condition.field,
+                "received": condition.value,
+                "expected": match condition.operator {
+                    ComparisonOperator::Near => "{ \"lat\", \"lon\", \"radius_km\" }",
+                    _ => "{ \"min_lat\", \"min_lon\", \"max_lat\", \"max_lon\" }",
+                }
+            })
+        ),
+    }
+}
+
+fn join_keys(value: &JsonValue) -> Vec<&JsonValue> {
+    match value {
+        JsonValue::Null => Vec::new(),
+        JsonValue::Array(items) => items.iter().filter(|v| !v.is_null()).collect(),
+        other => vec![other],
+    }
+}
+
+fn with_source(mut row: JsonValue, key: &str, doc: JsonValue) -> JsonValue {
+    if let Some(obj) = row.as_object_mut() {
+        obj.insert(key.to_string(), doc);
+    }
+    row
+}
+
 // ============================================================================
 // TESTS UNITAIRES
 // ============================================================================
@@ -742,6 +2005,8 @@ impl<'a> QueryEngine<'a> {
 mod tests {
     use super::*;
     use crate::json_db::collections::manager::CollectionsManager;
+    use crate::json_db::query::sql::{parse_sql, SqlRequest};
+    use crate::json_db::query::JoinClause;
     use crate::utils::testing::mock::insert_mock_db;
     use crate::utils::testing::DbSandbox;
 
@@ -810,6 +2075,12 @@ mod tests {
             limit: None,
             offset: None,
             projection: None,
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
         };
 
         let result = engine.execute_query(query).await?;
@@ -819,6 +2090,75 @@ mod tests {
         Ok(())
     }
 
+    #[async_test]
+    async fn test_group_by_aggregation_pipeline() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "parts",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+
+        for doc in [
+            json_value!({"_id": "1", "layer": "la", "mass": 2, "owner": "ana"}),
+            json_value!({"_id": "2", "layer": "la", "mass": 4, "owner": "bob"}),
+            json_value!({"_id": "3", "layer": "pa", "mass": 1.5, "owner": "ana"}),
+            json_value!({"_id": "4", "layer": "la", "owner": "ana"}),
+            json_value!({"_id": "5", "layer": "sa", "mass": 9, "owner": "eve"}),
+        ] {
+            insert_mock_db(&manager, "parts", &doc).await?;
+        }
+
+        let engine = QueryEngine::new(&manager);
+        let SqlRequest::Read(query) = parse_sql(
+            "SELECT layer, COUNT(*) AS n, SUM(mass), AVG(mass), MAX(mass), \
+             COUNT(DISTINCT owner) FROM parts WHERE layer != 'sa' \
+             GROUP BY layer ORDER BY n DESC",
+        )?
+        else {
+            panic!("SELECT attendu");
+        };
+        let result = engine.execute_query(query).await?;
+
+        assert_eq!(result.total_count, 2);
+        let la = &result.documents[0];
+        assert_eq!(la["layer"], "la");
+        assert_eq!(la["n"], 3);
+        assert_eq!(la["sum_mass"], 6);
+        assert_eq!(la["avg_mass"], 3.0);
+        assert_eq!(la["max_mass"], 4);
+        assert_eq!(la["count_owner"], 2);
+        assert_eq!(result.documents[1]["sum_mass"], 1.5);
+
+        // Agrégat global (sans GROUP BY) et valeurs distinctes
+        let mut query = Query::new("parts");
+        query.aggregation = Some(Aggregation {
+            group_by: vec![],
+            accumulators: vec![
+                Accumulator::new("total", AggregateOp::Count, None),
+                Accumulator::new("owners", AggregateOp::Distinct, Some("owner")),
+                Accumulator::new("lightest", AggregateOp::Min, Some("mass")),
+            ],
+        });
+        let result = engine.execute_query(query).await?;
+        assert_eq!(result.documents.len(), 1);
+        assert_eq!(result.documents[0]["total"], 5);
+        assert_eq!(
+            result.documents[0]["owners"],
+            json_value!(["ana", "bob", "eve"])
+        );
+        assert_eq!(result.documents[0]["lightest"], 1.5);
+
+        Ok(())
+    }
+
     #[async_test]
     async fn test_smart_like_and_array() -> RaiseResult<()> {
         let sandbox = DbSandbox::new().await?;
@@ -856,6 +2196,12 @@ mod tests {
             limit: None,
             offset: None,
             projection: None,
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
         };
 
         let result = engine.execute_query(query).await?;
@@ -905,6 +2251,12 @@ mod tests {
             limit: None,
             offset: None,
             projection: None,
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
         };
 
         let result = engine.execute_query(query).await?;
@@ -986,6 +2338,12 @@ mod tests {
             limit: None,
             offset: None,
             projection: None,
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
         };
 
         let result = engine.execute_query(query).await?;
@@ -1059,4 +2417,681 @@ mod tests {
 
         Ok(())
     }
+
+    async fn run_sql(engine: &QueryEngine<'_>, sql: &str) -> RaiseResult<QueryResult> {
+        let SqlRequest::Read(query) = parse_sql(sql)? else {
+            panic!("SELECT attendu");
+        };
+        engine.execute_query(query).await
+    }
+
+    #[async_test]
+    async fn test_sql_computed_projection() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "report");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "orders",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        for doc in [
+            json_value!({"_id": "o1", "name": "bolt", "qty": 4, "price": 2.5}),
+            json_value!({"_id": "o2", "name": "nut", "qty": 10, "price": 0.25}),
+            json_value!({"_id": "o3", "name": "gear", "price": 12}),
+        ] {
+            manager.insert_raw("orders", &doc).await?;
+        }
+        let engine = QueryEngine::new(&manager);
+
+        let report = run_sql(
+            &engine,
+            "SELECT name, qty * price AS total, UPPER(name) AS label FROM orders ORDER BY name",
+        )
+        .await?;
+        assert_eq!(
+            report.documents,
+            vec![
+                json_value!({"name": "bolt", "total": 10, "label": "BOLT"}),
+                // Quantité absente : colonne nulle, la requête aboutit
+                json_value!({"name": "gear", "total": null, "label": "GEAR"}),
+                json_value!({"name": "nut", "total": 2.5, "label": "NUT"}),
+            ]
+        );
+
+        // Colonne calculée sur les groupes
+        let grouped = run_sql(&engine, "SELECT COUNT(*) AS n, n * 2 AS twice FROM orders").await?;
+        assert_eq!(grouped.documents, vec![json_value!({"n": 3, "twice": 6})]);
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_sql_window_functions_and_distinct() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "report");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "parts",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        for doc in [
+            json_value!({"_id": "p1", "name": "pump", "layer": "PA", "mass": 10}),
+            json_value!({"_id": "p2", "name": "valve", "layer": "PA", "mass": 10}),
+            json_value!({"_id": "p3", "name": "tank", "layer": "PA", "mass": 4}),
+            json_value!({"_id": "p4", "name": "ecu", "layer": "LA", "mass": 1}),
+            json_value!({"_id": "p5", "name": "ecu", "layer": "LA", "mass": 1}),
+        ] {
+            manager.insert_raw("parts", &doc).await?;
+        }
+        let engine = QueryEngine::new(&manager);
+
+        let ranked = run_sql(
+            &engine,
+            "SELECT name, RANK() OVER (PARTITION BY layer ORDER BY mass DESC) AS r, \
+             DENSE_RANK() OVER (PARTITION BY layer ORDER BY mass DESC) AS d \
+             FROM parts WHERE layer = 'PA' ORDER BY name",
+        )
+        .await?;
+        assert_eq!(
+            ranked.documents,
+            vec![
+                json_value!({"name": "pump", "r": 1, "d": 1}),
+                json_value!({"name": "tank", "r": 3, "d": 2}),
+                json_value!({"name": "valve", "r": 1, "d": 1}),
+            ]
+        );
+
+        // Le plus lourd de chaque couche ; égalité départagée par `_id`
+        let top = run_sql(
+            &engine,
+            "SELECT name, ROW_NUMBER() OVER (PARTITION BY layer ORDER BY mass DESC) AS rn \
+             FROM parts QUALIFY rn <= 1 ORDER BY name",
+        )
+        .await?;
+        assert_eq!(
+            top.documents,
+            vec![
+                json_value!({"name": "ecu", "rn": 1}),
+                json_value!({"name": "pump", "rn": 1}),
+            ]
+        );
+
+        // DISTINCT * ignore les champs techniques (`_id`) ; DISTINCT ON garde la première ligne
+        let distinct = run_sql(&engine, "SELECT DISTINCT * FROM parts").await?;
+        assert_eq!(distinct.total_count, 4);
+        let lightest = run_sql(
+            &engine,
+            "SELECT DISTINCT ON (layer) name FROM parts ORDER BY layer, mass",
+        )
+        .await?;
+        assert_eq!(
+            lightest.documents,
+            vec![json_value!({"name": "ecu"}), json_value!({"name": "tank"})]
+        );
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_sql_joins_inner_and_left() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "joins");
+        DbSandbox::mock_db(&manager).await?;
+        for collection in ["articles", "actors"] {
+            manager
+                .create_collection(
+                    collection,
+                    "db://_system/_system/schemas/v1/db/generic.schema.json",
+                )
+                .await?;
+        }
+        // Index sur `handle` : la jointure par handle passe par l'index
+        IndexManager::new(&sandbox.storage, "test", "joins")
+            .create_index("actors", "handle", "hash")
+            .await?;
+
+        for doc in [
+            json_value!({"_id": "u1", "handle": "ana", "role": "admin"}),
+            json_value!({"_id": "u2", "handle": "bob", "role": "user"}),
+            json_value!({"_id": "p1", "title": "Intro", "author": "u1", "by": "ana"}),
+            json_value!({"_id": "p2", "title": "Suite", "author": "u2", "by": "bob"}),
+            json_value!({"_id": "p3", "title": "Brouillon", "author": "u9", "by": "eve"}),
+        ] {
+            let collection = if doc.get("title").is_some() {
+                "articles"
+            } else {
+                "actors"
+            };
+            manager.insert_raw(collection, &doc).await?;
+        }
+        let engine = QueryEngine::new(&manager);
+
+        // INNER (clé primaire) avec alias : l'article orphelin disparaît
+        let inner = run_sql(
+            &engine,
+            "SELECT a.title, u.role FROM articles a JOIN actors u ON a.author = u._id \
+             ORDER BY a.title",
+        )
+        .await?;
+        assert_eq!(inner.total_count, 2);
+        assert_eq!(
+            inner.documents,
+            vec![
+                json_value!({"title": "Intro", "role": "admin"}),
+                json_value!({"title": "Suite", "role": "user"}),
+            ]
+        );
+
+        // LEFT (index) : l'article orphelin reste, avec une source nulle
+        let left = run_sql(
+            &engine,
+            "SELECT * FROM articles LEFT JOIN actors ON articles.by = actors.handle \
+             WHERE articles.title != 'Suite' ORDER BY articles.title",
+        )
+        .await?;
+        assert_eq!(left.total_count, 2);
+        assert_eq!(left.documents[0]["articles"]["_id"], "p3");
+        assert!(left.documents[0]["actors"].is_null());
+        assert_eq!(left.documents[1]["actors"]["_id"], "u1");
+
+        // Filtre et agrégation sur la source jointe
+        let per_role = run_sql(
+            &engine,
+            "SELECT actors.role, COUNT(*) AS n FROM articles JOIN actors \
+             ON articles.author = actors._id WHERE actors.role = 'admin' GROUP BY actors.role",
+        )
+        .await?;
+        assert_eq!(
+            per_role.documents,
+            vec![json_value!({"role": "admin", "n": 1})]
+        );
+        Ok(())
+    }
+
+    fn sorted_query(field: &str, order: SortOrder) -> Query {
+        let mut query = Query::new("users");
+        query.sort = Some(vec![SortField::new(field, order)]);
+        query
+    }
+
+    #[async_test]
+    async fn test_explain_query_reports_access_path() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "explain");
+        DbSandbox::mock_db(&manager).await?;
+        for collection in ["components", "owners"] {
+            manager
+                .create_collection(
+                    collection,
+                    "db://_system/_system/schemas/v1/db/generic.schema.json",
+                )
+                .await?;
+        }
+        IndexManager::new(&sandbox.storage, "test", "explain")
+            .create_index("components", "status", "hash")
+            .await?;
+        for (id, status) in [("c1", "draft"), ("c2", "draft"), ("c3", "approved")] {
+            manager
+                .insert_raw(
+                    "components",
+                    &json_value!({ "_id": id, "status": status, "name": id, "owner": "o1" }),
+                )
+                .await?;
+        }
+        let engine = QueryEngine::new(&manager);
+
+        let SqlRequest::Explain(query) = parse_sql(
+            "EXPLAIN SELECT * FROM components WHERE name LIKE 'c%' AND status = 'draft' LIMIT 1",
+        )?
+        else {
+            panic!("EXPLAIN SELECT attendu");
+        };
+        let plan = engine.explain_query(query).await?;
+        assert_eq!(
+            plan.sources[0].access,
+            AccessPath::IndexScan {
+                index: "status".to_string(),
+                value: json_value!("draft")
+            }
+        );
+        assert_eq!((plan.sources[0].total_docs, plan.estimated_docs), (3, 2));
+        let order: Vec<&str> = plan.filters.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(order, vec!["status", "name"]);
+        assert_eq!(plan.stages, vec!["limit"]);
+
+        let mut by_id = Query::new("components");
+        by_id.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::eq("_id", json_value!("c3"))],
+        });
+        let plan = engine.explain_query(by_id).await?;
+        assert!(matches!(
+            plan.sources[0].access,
+            AccessPath::PrimaryKey { .. }
+        ));
+        assert_eq!(plan.estimated_docs, 1);
+
+        let mut joined = Query::new("components");
+        joined.joins = vec![JoinClause::new(
+            "owners",
+            JoinKind::Inner,
+            "components.owner",
+            "code",
+        )];
+        let plan = engine.explain_query(joined).await?;
+        assert_eq!(plan.sources[0].access, AccessPath::FullScan);
+        assert_eq!(plan.joins[0].strategy, JoinStrategy::HashJoin);
+        assert_eq!(plan.warnings.len(), 1);
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_order_by_served_from_btree_index() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "idx_sort");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "users",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        IndexManager::new(&sandbox.storage, "test", "idx_sort")
+            .create_index("users", "age", "btree")
+            .await?;
+
+        for (id, age) in [
+            ("a", json_value!(100)),
+            ("b", json_value!(9)),
+            ("c", json_value!(25)),
+        ] {
+            manager
+                .insert_raw("users", &json_value!({ "_id": id, "age": age }))
+                .await?;
+        }
+        // Sans valeur de tri : absent de l'index, placé en tête (ASC) comme le tri mémoire
+        manager
+            .insert_raw("users", &json_value!({ "_id": "z" }))
+            .await?;
+
+        let engine = QueryEngine::new(&manager);
+        let ids = |r: &QueryResult| -> Vec<String> {
+            r.documents
+                .iter()
+                .filter_map(|d| d["_id"].as_str().map(str::to_string))
+                .collect()
+        };
+
+        let asc = engine
+            .execute_query(sorted_query("age", SortOrder::Asc))
+            .await?;
+        assert_eq!(ids(&asc), vec!["z", "b", "c", "a"]);
+
+        let mut page = sorted_query("age", SortOrder::Desc);
+        page.offset = Some(1);
+        page.limit = Some(2);
+        let desc = engine.execute_query(page.clone()).await?;
+        assert_eq!(ids(&desc), vec!["c", "b"]);
+        assert_eq!(desc.total_count, 4);
+
+        // Même résultat que le chemin mémoire (sans index)
+        let in_memory = QueryEngine::new(&manager)
+            .with_index_provider(Box::new(NoOpIndexProvider))
+            .execute_query(page)
+            .await?;
+        assert_eq!(ids(&in_memory), ids(&desc));
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_cursor_pagination_is_stable() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "cursors");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "users",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        IndexManager::new(&sandbox.storage, "test", "cursors")
+            .create_index("users", "age", "btree")
+            .await?;
+        // Âges en double : l'ordre des ex aequo est fixé par `_id`
+        for (id, age) in [("a", 30), ("b", 20), ("c", 30), ("d", 10), ("e", 20)] {
+            manager
+                .insert_raw("users", &json_value!({ "_id": id, "age": age }))
+                .await?;
+        }
+
+        let ids = |r: &QueryResult| -> Vec<String> {
+            r.documents
+                .iter()
+                .filter_map(|d| d["_id"].as_str().map(str::to_string))
+                .collect()
+        };
+        let indexed = QueryEngine::new(&manager);
+        let in_memory = QueryEngine::new(&manager).with_index_provider(Box::new(NoOpIndexProvider));
+
+        for engine in [&indexed, &in_memory] {
+            let mut query = sorted_query("age", SortOrder::Asc);
+            query.limit = Some(2);
+            let first = engine.execute_query(query.clone()).await?;
+            assert_eq!(ids(&first), vec!["d", "b"]);
+            assert!(first.prev_cursor.is_none());
+
+            // Écriture concurrente avant le curseur : la page suivante ne se décale pas
+            manager
+                .insert_raw("users", &json_value!({ "_id": "a0", "age": 5 }))
+                .await?;
+            query.after = first.next_cursor.clone();
+            let second = engine.execute_query(query.clone()).await?;
+            assert_eq!(ids(&second), vec!["e", "a"]);
+
+            query.after = second.next_cursor.clone();
+            let last = engine.execute_query(query.clone()).await?;
+            assert_eq!(ids(&last), vec!["c"]);
+            assert!(last.next_cursor.is_none());
+
+            query.after = None;
+            query.before = last.prev_cursor.clone();
+            let back = engine.execute_query(query.clone()).await?;
+            assert_eq!(ids(&back), vec!["e", "a"]);
+
+            manager.delete_document("users", "a0").await?;
+        }
+
+        // Curseur émis pour un autre tri
+        let asc = sorted_query("age", SortOrder::Asc).sort.unwrap_or_default();
+        let mut other = sorted_query("age", SortOrder::Desc);
+        other.after = Some(QueryCursor::new(&asc, vec![Some(json_value!(20))], "b").encode());
+        let Err(AppError::Structured(err)) = in_memory.execute_query(other).await else {
+            panic!("Un curseur d'un autre tri doit être refusé");
+        };
+        assert_eq!(err.code, "ERR_DB_QUERY_CURSOR_MISMATCH");
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_text_search_ranks_by_relevance() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "fulltext");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "notes",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        IndexManager::new(&sandbox.storage, "test", "fulltext")
+            .create_index("notes", "body", "fulltext")
+            .await?;
+
+        for (id, body) in [
+            (
+                "n1",
+                "Braking sensor calibration procedure for the rear axle",
+            ),
+            ("n2", "Braking sensor and braking sensor wiring"),
+            ("n3", "Cabin lighting"),
+            ("n4", "Sensor list"),
+        ] {
+            manager
+                .insert_raw("notes", &json_value!({ "_id": id, "body": body }))
+                .await?;
+        }
+
+        let mut query = Query::new("notes");
+        query.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::text_search(
+                "body",
+                json_value!("braking sensor"),
+            )],
+        });
+        let ids = |r: &QueryResult| -> Vec<String> {
+            r.documents
+                .iter()
+                .filter_map(|d| d["_id"].as_str().map(str::to_string))
+                .collect()
+        };
+
+        let indexed = QueryEngine::new(&manager)
+            .execute_query(query.clone())
+            .await?;
+        assert_eq!(ids(&indexed), vec!["n2", "n1"]);
+
+        // Sans index : mêmes documents, classés sur le seul lot retenu
+        let scanned = QueryEngine::new(&manager)
+            .with_index_provider(Box::new(NoOpIndexProvider))
+            .execute_query(query)
+            .await?;
+        assert_eq!(ids(&scanned), vec!["n2", "n1"]);
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_geo_near_and_within() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "geo");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "components",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        IndexManager::new(&sandbox.storage, "test", "geo")
+            .create_index("components", "location", "geo")
+            .await?;
+
+        for (id, lat, lon) in [
+            ("radar", 48.8700, 2.3700),
+            ("antenna", 48.8600, 2.3500),
+            ("depot", 45.7640, 4.8357),
+        ] {
+            manager
+                .insert_raw(
+                    "components",
+                    &json_value!({ "_id": id, "location": { "lat": lat, "lon": lon } }),
+                )
+                .await?;
+        }
+        let ids = |r: &QueryResult| -> Vec<String> {
+            r.documents
+                .iter()
+                .filter_map(|d| d["_id"].as_str().map(str::to_string))
+                .collect()
+        };
+        let geo_query = |cond: Condition| {
+            let mut query = Query::new("components");
+            query.filter = Some(QueryFilter {
+                operator: FilterOperator::And,
+                conditions: vec![cond],
+            });
+            query
+        };
+
+        // "Composants à moins de 10 km de Paris", du plus proche au plus lointain
+        let near = geo_query(Condition::near(
+            "location",
+            json_value!({ "lat": 48.8566, "lon": 2.3522, "radius_km": 10 }),
+        ));
+        let engine = QueryEngine::new(&manager);
+        assert_eq!(
+            ids(&engine.execute_query(near.clone()).await?),
+            vec!["antenna", "radar"]
+        );
+        let plan = engine.explain_query(near.clone()).await?;
+        assert_eq!(
+            plan.sources[0].access,
+            AccessPath::GeoIndex {
+                field: "location".to_string()
+            }
+        );
+
+        // Sans index : même résultat par scan
+        let scanned = QueryEngine::new(&manager)
+            .with_index_provider(Box::new(NoOpIndexProvider))
+            .execute_query(near)
+            .await?;
+        assert_eq!(ids(&scanned), vec!["antenna", "radar"]);
+
+        let within = geo_query(Condition::within(
+            "location",
+            json_value!({ "min_lat": 45.0, "min_lon": 4.0, "max_lat": 46.0, "max_lon": 5.0 }),
+        ));
+        assert_eq!(ids(&engine.execute_query(within).await?), vec!["depot"]);
+
+        let invalid = geo_query(Condition::near("location", json_value!({ "lat": 48.8 })));
+        let Err(AppError::Structured(err)) = engine.execute_query(invalid).await else {
+            panic!("Une zone sans rayon doit être refusée");
+        };
+        assert_eq!(err.code, "ERR_DB_QUERY_GEO_INVALID");
+        Ok(())
+    }
+
+    /// Banc de mesure : `cargo test -p raise-core --release bench_index_sort -- --ignored --nocapture`
+    #[async_test]
+    #[ignore]
+    async fn bench_index_sort_100k_docs() -> RaiseResult<()> {
+        use crate::json_db::indexes::{driver, paths, IndexType};
+
+        const DOCS: usize = 100_000;
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "bench", "sort");
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "users",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+        IndexManager::new(&sandbox.storage, "bench", "sort")
+            .create_index("users", "age", "btree")
+            .await?;
+
+        // Écriture directe + index construit en une passe (l'insertion unitaire réécrit l'index à chaque document)
+        let mut index: OrderedMap<String, Vec<String>> = OrderedMap::new();
+        for i in 0..DOCS {
+            let id = format!("u{:06}", i);
+            let age = json_value!((i * 7919) % 100_000);
+            sandbox
+                .storage
+                .write_document(
+                    "bench",
+                    "sort",
+                    "users",
+                    &id,
+                    &json_value!({ "_id": id, "age": age }),
+                )
+                .await?;
+            index.entry(age.to_string()).or_default().push(id);
+        }
+        let idx_path = paths::index_path(
+            &sandbox.storage.config,
+            "bench",
+            "sort",
+            "users",
+            "age",
+            IndexType::BTree,
+        );
+        driver::save(&sandbox.storage.config, &idx_path, &index).await?;
+
+        let mut query = sorted_query("age", SortOrder::Desc);
+        query.limit = Some(50);
+
+        let start = TimeInstant::now();
+        let indexed = QueryEngine::new(&manager)
+            .execute_query(query.clone())
+            .await?;
+        let indexed_ms = start.elapsed().as_millis();
+
+        let start = TimeInstant::now();
+        let scanned = QueryEngine::new(&manager)
+            .with_index_provider(Box::new(NoOpIndexProvider))
+            .execute_query(query)
+            .await?;
+        let scanned_ms = start.elapsed().as_millis();
+
+        println!(
+            "📊 ORDER BY age DESC LIMIT 50 sur {} docs : index = {} ms, full scan = {} ms",
+            DOCS, indexed_ms, scanned_ms
+        );
+        assert_eq!(indexed.total_count, DOCS as u64);
+        assert_eq!(indexed.documents, scanned.documents);
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_sort_on_computed_keys_with_cursor() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "orders",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+
+        for doc in [
+            json_value!({"_id": "1", "qty": 2, "price": 5, "tags": ["a", "b"]}),
+            json_value!({"_id": "2", "qty": 1, "price": 3, "tags": ["a", "b"]}),
+            json_value!({"_id": "3", "qty": 4, "price": 1, "tags": ["a"]}),
+            json_value!({"_id": "4", "qty": 1, "price": 1, "tags": ["a", "b", "c"]}),
+        ] {
+            insert_mock_db(&manager, "orders", &doc).await?;
+        }
+
+        let engine = QueryEngine::new(&manager);
+        let SqlRequest::Read(mut query) = parse_sql(
+            "SELECT _id, qty * price AS total FROM orders \
+             ORDER BY LEN(tags) DESC, total ASC LIMIT 2",
+        )?
+        else {
+            panic!("SELECT attendu");
+        };
+
+        let first = engine.execute_query(query.clone()).await?;
+        let ids: Vec<&str> = first
+            .documents
+            .iter()
+            .filter_map(|d| d["_id"].as_str())
+            .collect();
+        assert_eq!(ids, vec!["4", "2"]);
+        assert_eq!(first.documents[1]["total"].as_f64(), Some(3.0));
+        // Les clés calculées ne fuient pas dans les résultats
+        assert!(first.documents[0].get(SORT_KEYS_FIELD).is_none());
+
+        // La page suivante reprend après la dernière clé calculée
+        query.after = first.next_cursor.clone();
+        let second = engine.execute_query(query).await?;
+        let ids: Vec<&str> = second
+            .documents
+            .iter()
+            .filter_map(|d| d["_id"].as_str())
+            .collect();
+        assert_eq!(ids, vec!["1", "3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepare_reuses_cached_statement() -> RaiseResult<()> {
+        let sql = "SELECT * FROM parts WHERE owner = $1";
+        let first = QueryEngine::prepare(sql)?;
+        let second = QueryEngine::prepare(sql)?;
+        assert!(SharedRef::ptr_eq(&first, &second));
+        assert_eq!(first.param_count(), 1);
+
+        // Une requête invalide n'entre pas dans le cache
+        assert!(QueryEngine::prepare("SELECT * FROM").is_err());
+        Ok(())
+    }
 }
@@ -1,14 +1,18 @@
 // FICHIER : src-tauri/src/json_db/query/mod.rs
 
+pub mod cursor;
 pub mod executor;
 pub mod optimizer;
 pub mod parser;
+pub mod plan;
 pub mod sql;
+pub mod vector;
 
 use crate::rules_engine::ast::Expr;
 use crate::utils::prelude::*;
 
 pub use executor::QueryEngine;
+pub use plan::QueryPlan;
 
 // --- Structures de Données ---
 
@@ -24,6 +28,33 @@ pub struct Query {
     pub limit: Option<usize>,
     pub offset: Option<usize>,
     pub projection: Option<Projection>,
+
+    /// Étape d'agrégation (GROUP BY), appliquée après le filtrage : le tri, la pagination
+    /// et la projection portent alors sur les groupes produits.
+    #[serde(default)]
+    pub aggregation: Option<Aggregation>,
+
+    /// Jointures appliquées dans l'ordre sur les documents de `collection`. Dès qu'une
+    /// jointure est présente, chaque ligne devient `{ <collection>: doc, <clé de jointure>: doc }`
+    /// et les chemins du filtre, du tri et de la projection se qualifient par leur source.
+    #[serde(default)]
+    pub joins: Vec<JoinClause>,
+
+    /// Curseurs opaques émis dans `QueryResult` (`next_cursor` / `prev_cursor`) : page qui
+    /// suit ou précède le document marqué, pour le même tri. Prioritaires sur `offset`.
+    #[serde(default)]
+    pub after: Option<String>,
+    #[serde(default)]
+    pub before: Option<String>,
+
+    /// Fonctions de fenêtre (`ROW_NUMBER`, `RANK` ... `OVER (PARTITION BY ...)`), calculées
+    /// après l'agrégation ; leurs colonnes peuvent être filtrées par `qualify`.
+    #[serde(default)]
+    pub window: Option<WindowStage>,
+
+    /// Dédoublonnage des lignes, appliqué après le tri et avant la pagination.
+    #[serde(default)]
+    pub distinct: Option<DistinctClause>,
 }
 
 impl Query {
@@ -36,14 +67,155 @@ impl Query {
             limit: None,
             offset: None,
             projection: None,
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
+        }
+    }
+}
+
+/// Jointure d'une collection sur un champ de la ligne courante (`local_field`, qualifié
+/// par sa source, ex: `articles.author`) et un champ du document joint (`foreign_field`).
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct JoinClause {
+    pub collection: String,
+    /// Clé de la source dans la ligne (défaut : le nom de la collection).
+    #[serde(default)]
+    pub alias: Option<String>,
+    #[serde(default)]
+    pub kind: JoinKind,
+    pub local_field: String,
+    pub foreign_field: String,
+}
+
+impl JoinClause {
+    pub fn new(collection: &str, kind: JoinKind, local_field: &str, foreign_field: &str) -> Self {
+        Self {
+            collection: collection.to_string(),
+            alias: None,
+            kind,
+            local_field: local_field.to_string(),
+            foreign_field: foreign_field.to_string(),
+        }
+    }
+
+    /// Clé sous laquelle le document joint est rangé dans la ligne.
+    pub fn key(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.collection)
+    }
+}
+
+/// `Inner` écarte les lignes sans correspondance ; `Left` les conserve avec une source `null`.
+#[derive(Debug, Clone, Copy, Default, Serializable, Deserializable, PartialEq)]
+pub enum JoinKind {
+    #[default]
+    Inner,
+    Left,
+}
+
+/// Regroupement des documents filtrés et calcul d'accumulateurs par groupe.
+/// Sans champ de regroupement, tous les documents forment un groupe unique.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct Aggregation {
+    #[serde(default)]
+    pub group_by: Vec<String>,
+    #[serde(default)]
+    pub accumulators: Vec<Accumulator>,
+}
+
+/// Calcul d'une colonne de sortie ; `field` est ignoré par `Count` (`COUNT(*)`).
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct Accumulator {
+    pub alias: String,
+    pub op: AggregateOp,
+    #[serde(default)]
+    pub field: Option<String>,
+}
+
+impl Accumulator {
+    pub fn new(alias: impl Into<String>, op: AggregateOp, field: Option<&str>) -> Self {
+        Self {
+            alias: alias.into(),
+            op,
+            field: field.map(str::to_string),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Serializable, Deserializable, PartialEq)]
+pub enum AggregateOp {
+    Count,
+    CountDistinct,
+    Sum,
+    Avg,
+    Min,
+    Max,
+    /// Liste des valeurs distinctes du champ dans le groupe.
+    Distinct,
+}
+
+/// Colonnes de fenêtre ajoutées à chaque ligne, puis filtre optionnel sur ces colonnes
+/// (`QUALIFY rank <= 3` : les trois premiers de chaque partition).
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct WindowStage {
+    pub functions: Vec<WindowFunction>,
+    #[serde(default)]
+    pub qualify: Option<QueryFilter>,
+}
+
+/// Numérotation des lignes de chaque partition dans l'ordre `order_by` (l'`_id` départage).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct WindowFunction {
+    pub alias: String,
+    pub op: WindowOp,
+    #[serde(default)]
+    pub partition_by: Vec<String>,
+    #[serde(default)]
+    pub order_by: Vec<SortField>,
+}
+
+#[derive(Debug, Clone, Copy, Serializable, Deserializable, PartialEq)]
+pub enum WindowOp {
+    /// 1, 2, 3, 4 : numéro de ligne.
+    RowNumber,
+    /// 1, 2, 2, 4 : les ex aequo partagent un rang, suivi d'un saut.
+    Rank,
+    /// 1, 2, 2, 3 : rangs sans saut.
+    DenseRank,
+}
+
+/// `on` vide : lignes identiques hors champs système (`_*`, `$*`) ; sinon première ligne
+/// (dans l'ordre du tri) de chaque combinaison de valeurs des champs `on`.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct DistinctClause {
+    #[serde(default)]
+    pub on: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
 pub enum Projection {
     Include(Vec<String>),
     Exclude(Vec<String>),
+    /// Champs repris tels quels (tout le document si `wildcard`), puis colonnes calculées
+    /// sur chaque document : `SELECT name, qty * price AS total`.
+    Computed {
+        #[serde(default)]
+        fields: Vec<String>,
+        #[serde(default)]
+        wildcard: bool,
+        columns: Vec<ComputedColumn>,
+    },
+}
+
+/// Colonne `alias` valant `expr` (moteur de règles) évaluée sur le document ou la ligne jointe.
+/// Comme en SQL, une valeur manquante ou de type incompatible donne `null`.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct ComputedColumn {
+    pub alias: String,
+    pub expr: Expr,
 }
 
 #[derive(Debug, Clone, Serializable, Deserializable)]
@@ -120,6 +292,26 @@ impl Condition {
         Self::new(field, ComparisonOperator::Matches, value)
     }
 
+    pub fn text_search(field: impl Into<String>, value: JsonValue) -> Self {
+        Self::new(field, ComparisonOperator::TextSearch, value)
+    }
+
+    pub fn near_text(field: impl Into<String>, value: JsonValue) -> Self {
+        Self::new(field, ComparisonOperator::NearText, value)
+    }
+
+    pub fn near_vector(field: impl Into<String>, value: JsonValue) -> Self {
+        Self::new(field, ComparisonOperator::NearVector, value)
+    }
+
+    pub fn near(field: impl Into<String>, value: JsonValue) -> Self {
+        Self::new(field, ComparisonOperator::Near, value)
+    }
+
+    pub fn within(field: impl Into<String>, value: JsonValue) -> Self {
+        Self::new(field, ComparisonOperator::Within, value)
+    }
+
     pub fn is_a(field: impl Into<String>, value: JsonValue) -> Self {
         Self::new(field, ComparisonOperator::IsA, value)
     }
@@ -143,14 +335,46 @@ pub enum ComparisonOperator {
     EndsWith,
     Matches, // Regex
     Like,    // SQL Like
+    /// Recherche plein texte : tous les termes présents, résultats classés par pertinence.
+    TextSearch,
+    /// Similarité sémantique (`VectorSearchProvider`) : `field` = collection vectorielle.
+    NearText,
+    NearVector,
+    /// Position à moins de `radius_km` d'un point (`{ "lat", "lon", "radius_km" }`).
+    Near,
+    /// Position dans un rectangle (`{ "min_lat", "min_lon", "max_lat", "max_lon" }`).
+    Within,
     IsA,     // Vérification de l'ancrage ontologique (@type)
     AstRule, // Injection d'un AST (Row-Level Security / RBAC)
 }
 
-#[derive(Debug, Clone, Serializable, Deserializable)]
+/// Clé de tri. Avec `expr`, la clé est la valeur de l'expression (moteur de règles) évaluée
+/// sur chaque ligne, et `field` n'en est que le libellé (signature des curseurs, plan) :
+/// `ORDER BY qty * price DESC`. Les clés s'appliquent dans l'ordre, chacune avec son sens.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
 pub struct SortField {
     pub field: String,
     pub order: SortOrder,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expr: Option<Expr>,
+}
+
+impl SortField {
+    pub fn new(field: impl Into<String>, order: SortOrder) -> Self {
+        Self {
+            field: field.into(),
+            order,
+            expr: None,
+        }
+    }
+
+    pub fn computed(label: impl Into<String>, expr: Expr, order: SortOrder) -> Self {
+        Self {
+            field: label.into(),
+            order,
+            expr: Some(expr),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
@@ -165,6 +389,12 @@ pub struct QueryResult {
     pub total_count: u64,
     pub offset: Option<usize>,
     pub limit: Option<usize>,
+    /// Curseur de la page suivante (`Query.after`), absent sur la dernière page.
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+    /// Curseur de la page précédente (`Query.before`), absent sur la première page.
+    #[serde(default)]
+    pub prev_cursor: Option<String>,
 }
 
 // ============================================================================
@@ -195,6 +425,12 @@ mod tests {
             limit: Some(10),
             offset: None,
             projection: Some(Projection::Include(vec!["name".into()])),
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
         };
 
         let json_str = json::serialize_to_string(&query).unwrap();
@@ -202,4 +438,27 @@ mod tests {
         assert!(json_str.contains("\"age\""));
         assert!(json_str.contains("\"Include\""));
     }
+
+    #[test]
+    fn test_aggregation_is_optional_in_payload() {
+        let query: Query =
+            json::deserialize_from_value(json_value!({ "collection": "users" })).unwrap();
+        assert!(query.aggregation.is_none());
+        assert!(query.joins.is_empty());
+
+        let query: Query = json::deserialize_from_value(json_value!({
+            "collection": "users",
+            "aggregation": {
+                "group_by": ["role"],
+                "accumulators": [{ "alias": "n", "op": "Count" }]
+            }
+        }))
+        .unwrap();
+        let agg = query.aggregation.unwrap();
+        assert_eq!(agg.group_by, vec!["role".to_string()]);
+        assert_eq!(
+            agg.accumulators[0],
+            Accumulator::new("n", AggregateOp::Count, None)
+        );
+    }
 }
@@ -2,7 +2,8 @@
 
 //! Optimiseur de requêtes pour améliorer les performances
 
-use super::{ComparisonOperator, Condition, Query, QueryFilter};
+use super::executor::IndexProvider;
+use super::{ComparisonOperator, Condition, JoinClause, Query, QueryFilter, SortField};
 
 use crate::utils::prelude::*;
 
@@ -12,6 +13,24 @@ pub struct QueryOptimizer {
     config: OptimizerConfig,
 }
 
+/// Stratégie d'exécution d'une jointure
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub enum JoinStrategy {
+    /// Le champ joint est la clé primaire (`_id` / `@id`) : lecture directe du document.
+    PrimaryKey,
+    /// Le champ joint porte un index : une recherche par valeur de la clé locale.
+    IndexLookup { index_field: String },
+    /// Repli : la collection jointe est chargée une fois et hachée sur le champ joint.
+    HashJoin,
+}
+
+/// Jointure accompagnée de la stratégie retenue par l'optimiseur
+#[derive(Debug, Clone)]
+pub struct JoinPlan {
+    pub join: JoinClause,
+    pub strategy: JoinStrategy,
+}
+
 /// Configuration de l'optimiseur
 #[derive(Debug, Clone)]
 pub struct OptimizerConfig {
@@ -71,6 +90,59 @@ impl QueryOptimizer {
         Ok(query)
     }
 
+    /// Plan "tri par index" : éligible si un unique champ de tri (non calculé) est demandé
+    /// sans filtre, RLS ni agrégation (l'ordre et la pagination peuvent alors être lus dans
+    /// l'index BTree sans charger les documents hors page).
+    pub fn index_sort_field<'q>(&self, query: &'q Query) -> Option<&'q SortField> {
+        let has_filter = query
+            .filter
+            .as_ref()
+            .is_some_and(|f| !f.conditions.is_empty());
+        if has_filter
+            || query.rls_policy.is_some()
+            || query.aggregation.is_some()
+            || query.window.is_some()
+            || query.distinct.is_some()
+            || !query.joins.is_empty()
+        {
+            return None;
+        }
+        match query.sort.as_deref() {
+            Some([field]) if field.expr.is_none() => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Planifie les jointures dans l'ordre de la requête : clé primaire, puis index existant
+    /// sur le champ joint (chemin complet ou feuille), sinon jointure par hachage.
+    pub async fn plan_joins(&self, query: &Query, indexes: &dyn IndexProvider) -> Vec<JoinPlan> {
+        let mut plans = Vec::with_capacity(query.joins.len());
+        for join in &query.joins {
+            let field = join.foreign_field.as_str();
+            let leaf = field.split('.').next_back().unwrap_or(field);
+
+            let strategy = if field == "_id" || field == "@id" {
+                JoinStrategy::PrimaryKey
+            } else if indexes.has_index(&join.collection, field).await {
+                JoinStrategy::IndexLookup {
+                    index_field: field.to_string(),
+                }
+            } else if leaf != field && indexes.has_index(&join.collection, leaf).await {
+                JoinStrategy::IndexLookup {
+                    index_field: leaf.to_string(),
+                }
+            } else {
+                JoinStrategy::HashJoin
+            };
+
+            plans.push(JoinPlan {
+                join: join.clone(),
+                strategy,
+            });
+        }
+        plans
+    }
+
     fn simplify_filter(&self, filter: QueryFilter) -> RaiseResult<QueryFilter> {
         let mut simplified = filter.clone();
 
@@ -95,7 +167,7 @@ impl QueryOptimizer {
 
     /// Estime la sélectivité (Coût) d'une condition.
     /// Plus le score est bas, plus la condition est restrictive et rapide à vérifier.
-    fn estimate_selectivity(&self, condition: &Condition) -> u32 {
+    pub fn estimate_selectivity(&self, condition: &Condition) -> u32 {
         match condition.operator {
             // Très sélectif (Egalité stricte)
             ComparisonOperator::Eq | ComparisonOperator::IsA => self.config.cost_eq,
@@ -105,10 +177,16 @@ impl QueryOptimizer {
             ComparisonOperator::Gt
             | ComparisonOperator::Gte
             | ComparisonOperator::Lt
-            | ComparisonOperator::Lte => self.config.cost_range,
+            | ComparisonOperator::Lte
+            | ComparisonOperator::Near
+            | ComparisonOperator::Within => self.config.cost_range,
 
             // Sélectivité faible (Texte début/fin)
-            ComparisonOperator::StartsWith | ComparisonOperator::EndsWith => self.config.cost_text,
+            ComparisonOperator::StartsWith
+            | ComparisonOperator::EndsWith
+            | ComparisonOperator::TextSearch => self.config.cost_text,
+            // Servies en amont par le store vectoriel : évaluées en premier
+            ComparisonOperator::NearText | ComparisonOperator::NearVector => self.config.cost_eq,
 
             // Coûteux (Scan complet ou Regex)
             ComparisonOperator::Contains
@@ -157,7 +235,29 @@ impl QueryOptimizer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::json_db::query::{Condition, FilterOperator, Query, QueryFilter};
+    use crate::json_db::query::executor::BoxFuture;
+    use crate::json_db::query::{
+        Condition, FilterOperator, JoinKind, Query, QueryFilter, SortOrder,
+    };
+    use crate::rules_engine::ast::Expr;
+
+    /// Index déclarés sous la forme `collection.champ`
+    struct StaticIndexes(Vec<&'static str>);
+
+    impl IndexProvider for StaticIndexes {
+        fn has_index<'a>(&'a self, collection: &'a str, field: &'a str) -> BoxFuture<'a, bool> {
+            let declared = format!("{}.{}", collection, field);
+            Box::pin(async move { self.0.iter().any(|i| *i == declared) })
+        }
+        fn search<'a>(
+            &'a self,
+            _c: &'a str,
+            _f: &'a str,
+            _v: &'a JsonValue,
+        ) -> BoxFuture<'a, RaiseResult<Vec<String>>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+    }
 
     #[test]
     fn test_optimize_reorder() -> RaiseResult<()> {
@@ -216,4 +316,60 @@ mod tests {
         assert_eq!(optimized.limit, Some(1000));
         Ok(())
     }
+
+    #[test]
+    fn test_index_sort_field_eligibility() {
+        let optimizer = QueryOptimizer::new();
+        let mut query = Query::new("users");
+        query.sort = Some(vec![SortField::new("age", SortOrder::Desc)]);
+        assert_eq!(
+            optimizer.index_sort_field(&query).map(|s| s.field.as_str()),
+            Some("age")
+        );
+
+        // Une clé calculée n'existe dans aucun index
+        let mut computed = query.clone();
+        computed.sort = Some(vec![SortField::computed(
+            "len(tags)",
+            Expr::Len(Box::new(Expr::Var("tags".into()))),
+            SortOrder::Desc,
+        )]);
+        assert!(optimizer.index_sort_field(&computed).is_none());
+
+        // Un filtre impose l'évaluation document par document : tri en mémoire
+        query.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::eq("role", json_value!("admin"))],
+        });
+        assert!(optimizer.index_sort_field(&query).is_none());
+    }
+
+    #[async_test]
+    async fn test_plan_joins_prefers_existing_indexes() {
+        let optimizer = QueryOptimizer::new();
+        let mut query = Query::new("articles");
+        query.joins = vec![
+            JoinClause::new("actors", JoinKind::Inner, "articles.author", "_id"),
+            JoinClause::new("teams", JoinKind::Left, "actors.team", "meta.code"),
+            JoinClause::new("tags", JoinKind::Left, "articles.tag", "label"),
+        ];
+        let indexes = StaticIndexes(vec!["teams.code"]);
+
+        let plans = optimizer.plan_joins(&query, &indexes).await;
+        let strategies: Vec<_> = plans.into_iter().map(|p| p.strategy).collect();
+        assert_eq!(
+            strategies,
+            vec![
+                JoinStrategy::PrimaryKey,
+                JoinStrategy::IndexLookup {
+                    index_field: "code".into()
+                },
+                JoinStrategy::HashJoin,
+            ]
+        );
+
+        // Une jointure impose l'exécution ligne par ligne : pas de tri par index
+        query.sort = Some(vec![SortField::new("articles.title", SortOrder::Asc)]);
+        assert!(optimizer.index_sort_field(&query).is_none());
+    }
 }
@@ -1,10 +1,11 @@
 // FICHIER : src-tauri/src/json_db/query/parser.rs
 
 use super::{
-    ComparisonOperator, Condition, FilterOperator, Projection, Query, QueryFilter, SortField,
-    SortOrder,
+    Accumulator, AggregateOp, Aggregation, ComparisonOperator, Condition, FilterOperator,
+    JoinClause, JoinKind, Projection, Query, QueryFilter, SortField, SortOrder,
 };
 
+use crate::rules_engine::ast::Expr;
 use crate::utils::prelude::*;
 
 pub fn parse_projection(fields: &[String]) -> RaiseResult<Projection> {
@@ -90,15 +91,58 @@ impl QueryBuilder {
     }
 
     pub fn sort(mut self, field: &str, order: SortOrder) -> Self {
-        let sort_field = SortField {
-            field: field.into(),
-            order,
-        };
-        if let Some(ref mut s) = self.query.sort {
-            s.push(sort_field);
-        } else {
-            self.query.sort = Some(vec![sort_field]);
-        }
+        self.query
+            .sort
+            .get_or_insert_with(Vec::new)
+            .push(SortField::new(field, order));
+        self
+    }
+
+    /// Ajoute une clé de tri calculée (`expr` évaluée sur chaque ligne), libellée `label`.
+    pub fn sort_by_expr(mut self, label: &str, expr: Expr, order: SortOrder) -> Self {
+        self.query
+            .sort
+            .get_or_insert_with(Vec::new)
+            .push(SortField::computed(label, expr, order));
+        self
+    }
+
+    /// Regroupe les résultats filtrés par les champs donnés (étape d'agrégation).
+    pub fn group_by(mut self, fields: &[&str]) -> Self {
+        let aggregation = self
+            .query
+            .aggregation
+            .get_or_insert_with(Aggregation::default);
+        aggregation
+            .group_by
+            .extend(fields.iter().map(|f| f.to_string()));
+        self
+    }
+
+    /// Ajoute une colonne calculée par groupe (`field` à `None` pour un `COUNT(*)`).
+    pub fn aggregate(mut self, alias: &str, op: AggregateOp, field: Option<&str>) -> Self {
+        self.query
+            .aggregation
+            .get_or_insert_with(Aggregation::default)
+            .accumulators
+            .push(Accumulator::new(alias, op, field));
+        self
+    }
+
+    /// Joint `collection` sur `local_field` (qualifié par sa source) = `foreign_field`.
+    pub fn join(
+        mut self,
+        collection: &str,
+        kind: JoinKind,
+        local_field: &str,
+        foreign_field: &str,
+    ) -> Self {
+        self.query.joins.push(JoinClause::new(
+            collection,
+            kind,
+            local_field,
+            foreign_field,
+        ));
         self
     }
 
@@ -118,16 +162,10 @@ pub fn parse_sort_specs(specs: &[String]) -> RaiseResult<Vec<SortField>> {
 fn parse_single_sort_spec(spec: &str) -> RaiseResult<SortField> {
     let spec = spec.trim();
     if let Some(f) = spec.strip_prefix('+') {
-        return Ok(SortField {
-            field: f.trim().to_string(),
-            order: SortOrder::Asc,
-        });
+        return Ok(SortField::new(f.trim(), SortOrder::Asc));
     }
     if let Some(f) = spec.strip_prefix('-') {
-        return Ok(SortField {
-            field: f.trim().to_string(),
-            order: SortOrder::Desc,
-        });
+        return Ok(SortField::new(f.trim(), SortOrder::Desc));
     }
 
     let (field, order) = match spec.split_once(':') {
@@ -140,10 +178,7 @@ fn parse_single_sort_spec(spec: &str) -> RaiseResult<SortField> {
         ),
         None => (spec, SortOrder::Asc),
     };
-    Ok(SortField {
-        field: field.to_string(),
-        order,
-    })
+    Ok(SortField::new(field, order))
 }
 
 pub fn parse_filter_from_json(value: &JsonValue) -> RaiseResult<QueryFilter> {
@@ -218,6 +253,11 @@ pub fn parse_filter_from_json(value: &JsonValue) -> RaiseResult<QueryFilter> {
                 "endswith" => ComparisonOperator::EndsWith,
                 "like" => ComparisonOperator::Like,
                 "matches" => ComparisonOperator::Matches,
+                "textsearch" | "text_search" => ComparisonOperator::TextSearch,
+                "neartext" | "near_text" => ComparisonOperator::NearText,
+                "nearvector" | "near_vector" => ComparisonOperator::NearVector,
+                "near" => ComparisonOperator::Near,
+                "within" => ComparisonOperator::Within,
                 "isa" | "is_a" => ComparisonOperator::IsA,
                 "astrule" | "ast_rule" => ComparisonOperator::AstRule,
                 _ => {
@@ -320,4 +360,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_query_builder_aggregation() {
+        let q = QueryBuilder::new("components")
+            .group_by(&["layer"])
+            .aggregate("total", AggregateOp::Count, None)
+            .aggregate("mass", AggregateOp::Sum, Some("specs.mass"))
+            .build();
+
+        let agg = q.aggregation.unwrap();
+        assert_eq!(agg.group_by, vec!["layer".to_string()]);
+        assert_eq!(agg.accumulators.len(), 2);
+        assert_eq!(agg.accumulators[1].field.as_deref(), Some("specs.mass"));
+    }
 }
@@ -0,0 +1,97 @@
+// FICHIER : src-tauri/src/json_db/query/plan.rs
+
+//! Plan d'exécution d'une requête (`QueryEngine::explain_query`, `EXPLAIN SELECT ...`) :
+//! chemin d'accès retenu par collection, volume chargé estimé et ordre d'évaluation des
+//! conditions. Le plan est calculé à partir des index, sans lire les documents.
+
+use super::optimizer::JoinStrategy;
+use super::{ComparisonOperator, FilterOperator};
+use crate::utils::prelude::*;
+
+/// Au-delà de ce volume, un parcours complet est signalé dans les avertissements du plan.
+pub const FULL_SCAN_WARNING_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccessPath {
+    /// Lecture directe par `_id` / `@id`.
+    PrimaryKey { id: String },
+    /// Égalité servie par un index secondaire.
+    IndexScan { index: String, value: JsonValue },
+    /// ORDER BY et pagination lus dans un index BTree.
+    IndexSort { index: String },
+    /// `TextSearch` servi par un index plein texte (candidats déjà classés).
+    FullTextIndex { field: String },
+    /// `Near` / `Within` servi par un index géographique.
+    GeoIndex { field: String },
+    /// Voisins fournis par le store vectoriel.
+    VectorSearch,
+    /// Lecture de tous les documents de la collection.
+    FullScan,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct CollectionPlan {
+    pub collection: String,
+    pub access: AccessPath,
+    /// Documents présents dans la collection.
+    pub total_docs: usize,
+    /// Documents chargés par le chemin d'accès, avant filtrage.
+    pub estimated_docs: usize,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct FilterStep {
+    pub field: String,
+    pub operator: ComparisonOperator,
+    /// Coût estimé par l'optimiseur : les conditions les moins chères sont évaluées d'abord.
+    pub cost: u32,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct JoinStep {
+    pub collection: String,
+    pub strategy: JoinStrategy,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct QueryPlan {
+    pub collection: String,
+    pub sources: Vec<CollectionPlan>,
+    pub joins: Vec<JoinStep>,
+    pub filter_operator: Option<FilterOperator>,
+    /// Conditions dans leur ordre d'évaluation.
+    pub filters: Vec<FilterStep>,
+    /// Étapes appliquées en mémoire après le filtrage (`rls`, `group_by`, `sort`, `limit`...).
+    pub stages: Vec<String>,
+    /// Total des documents chargés, toutes sources confondues.
+    pub estimated_docs: usize,
+    pub warnings: Vec<String>,
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_path_serialization() -> RaiseResult<()> {
+        let access = AccessPath::IndexScan {
+            index: "status".to_string(),
+            value: json_value!("draft"),
+        };
+        let json = json::serialize_to_value(&access)?;
+        assert_eq!(
+            json,
+            json_value!({ "type": "index_scan", "index": "status", "value": "draft" })
+        );
+        assert_eq!(
+            json::serialize_to_value(AccessPath::FullScan)?,
+            json_value!({ "type": "full_scan" })
+        );
+        Ok(())
+    }
+}
@@ -2,23 +2,35 @@
 use crate::utils::prelude::*;
 
 use crate::json_db::transactions::TransactionRequest;
+use crate::rules_engine::ast::Expr as RuleExpr;
 
 use sqlparser::ast::{
-    BinaryOperator, Delete, Expr, FromTable, Insert, OrderByExpr, OrderByKind, Query as SqlQuery,
-    SetExpr, Statement, TableFactor, Value as SqlJsonValue,
+    AssignmentTarget, BinaryOperator, Delete, Distinct, DuplicateTreatment, Expr, FromTable,
+    Function, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr, Insert, Join,
+    JoinConstraint, JoinOperator, OrderByExpr, OrderByKind, Query as SqlQuery, SelectItem, SetExpr,
+    Statement, TableFactor, UnaryOperator, Update, Value as SqlJsonValue, WindowType,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
+use sqlparser::tokenizer::{Token, Tokenizer};
 
 use super::{
-    ComparisonOperator, Condition, FilterOperator, Projection, Query, QueryFilter, SortField,
-    SortOrder,
+    Accumulator, AggregateOp, Aggregation, ComparisonOperator, ComputedColumn, Condition,
+    DistinctClause, FilterOperator, JoinClause, JoinKind, Projection, Query, QueryFilter,
+    SortField, SortOrder, WindowFunction, WindowOp, WindowStage,
 };
 
-/// Résultat du parsing SQL : soit une lecture, soit une transaction d'écriture
+/// Fonctions scalaires traduites en colonnes calculées (les autres sont des agrégats).
+const SCALAR_FUNCTIONS: &[&str] = &[
+    "UPPER", "LOWER", "TRIM", "ABS", "ROUND", "LENGTH", "LEN", "CONCAT",
+];
+
+/// Résultat du parsing SQL : une lecture, une transaction d'écriture, ou le plan d'une
+/// lecture (`EXPLAIN SELECT ...`, cf. `QueryEngine::explain_query`)
 pub enum SqlRequest {
     Read(Query),
     Write(Vec<TransactionRequest>),
+    Explain(Query),
 }
 
 pub fn parse_sql(sql: &str) -> RaiseResult<SqlRequest> {
@@ -40,6 +52,17 @@ pub fn parse_sql(sql: &str) -> RaiseResult<SqlRequest> {
         }
     };
 
+    translate_statements(&ast)
+}
+
+/// Variante paramétrée de `parse_sql` : les placeholders `$1`, `$2`… (ou `?`, positionnels)
+/// reçoivent les valeurs de `params`. Pour une requête exécutée souvent, préférer
+/// `QueryEngine::prepare`, qui met la préparation en cache.
+pub fn parse_sql_with_params(sql: &str, params: &[JsonValue]) -> RaiseResult<SqlRequest> {
+    PreparedSql::prepare(sql)?.bind(params)
+}
+
+fn translate_statements(ast: &[Statement]) -> RaiseResult<SqlRequest> {
     if ast.len() != 1 {
         raise_error!(
             "ERR_DB_SQL_SINGLE_STATEMENT_ONLY",
@@ -70,6 +93,26 @@ pub fn parse_sql(sql: &str) -> RaiseResult<SqlRequest> {
             }]))
         }
 
+        Statement::Update(update) => {
+            let (query, patch) = translate_update(update)?;
+            Ok(SqlRequest::Write(vec![TransactionRequest::UpdateMany {
+                query,
+                patch,
+            }]))
+        }
+
+        Statement::Explain { statement, .. } => match statement.as_ref() {
+            Statement::Query(q) => Ok(SqlRequest::Explain(translate_query(q)?)),
+            other => raise_error!(
+                "ERR_DB_SQL_EXPLAIN_UNSUPPORTED",
+                error = "EXPLAIN ne s'applique qu'aux requêtes SELECT.",
+                context = json_value!({
+                    "attempted_statement": other.to_string(),
+                    "action": "translate_sql_explain"
+                })
+            ),
+        },
+
         // Cas non supportés : Levée d'une erreur structurée
         unsupported => {
             raise_error!(
@@ -77,15 +120,176 @@ pub fn parse_sql(sql: &str) -> RaiseResult<SqlRequest> {
                 error = "Type de requête SQL non supporté par le moteur actuel.",
                 context = json_value!({
                     "attempted_statement": format!("{:?}", unsupported),
-                    "supported_statements": ["SELECT", "INSERT"],
+                    "supported_statements": ["SELECT", "INSERT", "UPDATE", "DELETE", "EXPLAIN SELECT"],
                     "action": "translate_sql_to_request",
-                    "hint": "Le moteur JSON-DB est actuellement limité aux opérations de lecture (SELECT) et d'insertion (INSERT)."
+                    "hint": "Le moteur JSON-DB est actuellement limité aux opérations SELECT, INSERT, UPDATE et DELETE."
+                })
+            );
+        }
+    }
+}
+
+// --- REQUÊTES PRÉPARÉES ---
+
+/// Élément d'une requête préparée : jeton SQL tel quel, ou emplacement du paramètre d'indice donné.
+#[derive(Debug, Clone)]
+enum SqlPiece {
+    Token(Token),
+    Param(usize),
+}
+
+/// Requête SQL découpée en jetons et validée une seule fois, puis liée à ses paramètres à
+/// chaque exécution. Les valeurs sont insérées comme jetons littéraux : une chaîne reste une
+/// chaîne, quel que soit son contenu (aucune injection possible).
+#[derive(Debug, Clone)]
+pub struct PreparedSql {
+    sql: String,
+    pieces: Vec<SqlPiece>,
+    param_count: usize,
+}
+
+impl PreparedSql {
+    pub fn prepare(sql: &str) -> RaiseResult<Self> {
+        let dialect = GenericDialect {};
+        let tokens = match Tokenizer::new(&dialect, sql).tokenize() {
+            Ok(tokens) => tokens,
+            Err(e) => raise_error!(
+                "ERR_DB_SQL_SYNTAX",
+                error = e,
+                context = json_value!({ "sql_query": sql, "action": "tokenize_sql" })
+            ),
+        };
+
+        let mut pieces = Vec::with_capacity(tokens.len());
+        let mut positional = 0;
+        let mut numbered = false;
+        for token in &tokens {
+            let Token::Placeholder(name) = token else {
+                pieces.push(SqlPiece::Token(token.clone()));
+                continue;
+            };
+            let index = if name == "?" {
+                positional += 1;
+                positional - 1
+            } else {
+                let number = name
+                    .strip_prefix('$')
+                    .or_else(|| name.strip_prefix('?'))
+                    .and_then(|n| n.parse::<usize>().ok());
+                match number {
+                    Some(n) if n >= 1 => {
+                        numbered = true;
+                        n - 1
+                    }
+                    _ => raise_error!(
+                        "ERR_DB_SQL_PARAM_INVALID",
+                        error = format!("Paramètre '{}' non supporté.", name),
+                        context = json_value!({
+                            "sql_query": sql,
+                            "placeholder": name,
+                            "hint": "Utilisez des paramètres numérotés ($1, $2…) ou positionnels (?)."
+                        })
+                    ),
+                }
+            };
+            pieces.push(SqlPiece::Param(index));
+        }
+
+        if numbered && positional > 0 {
+            raise_error!(
+                "ERR_DB_SQL_PARAM_MIXED",
+                error =
+                    "Paramètres numérotés ($n) et positionnels (?) mélangés dans une même requête.",
+                context = json_value!({ "sql_query": sql })
+            );
+        }
+
+        // Validation syntaxique unique, placeholders en place
+        parse_tokens(sql, tokens)?;
+
+        let param_count = pieces
+            .iter()
+            .filter_map(|piece| match piece {
+                SqlPiece::Param(index) => Some(index + 1),
+                SqlPiece::Token(_) => None,
+            })
+            .max()
+            .unwrap_or(0);
+
+        Ok(Self {
+            sql: sql.to_string(),
+            pieces,
+            param_count,
+        })
+    }
+
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// Nombre de valeurs attendues par `bind` (plus grand `$n`, ou nombre de `?`).
+    pub fn param_count(&self) -> usize {
+        self.param_count
+    }
+
+    /// Lie `params` (scalaires uniquement) aux placeholders puis traduit la requête.
+    pub fn bind(&self, params: &[JsonValue]) -> RaiseResult<SqlRequest> {
+        if params.len() != self.param_count {
+            raise_error!(
+                "ERR_DB_SQL_PARAM_COUNT_MISMATCH",
+                error = format!(
+                    "{} paramètre(s) attendu(s), {} fourni(s).",
+                    self.param_count,
+                    params.len()
+                ),
+                context = json_value!({
+                    "sql_query": self.sql,
+                    "expected": self.param_count,
+                    "received": params.len()
                 })
             );
         }
+
+        let mut tokens = Vec::with_capacity(self.pieces.len());
+        for piece in &self.pieces {
+            match piece {
+                SqlPiece::Token(token) => tokens.push(token.clone()),
+                SqlPiece::Param(index) => tokens.push(param_token(&params[*index], *index)?),
+            }
+        }
+        translate_statements(&parse_tokens(&self.sql, tokens)?)
+    }
+}
+
+fn parse_tokens(sql: &str, tokens: Vec<Token>) -> RaiseResult<Vec<Statement>> {
+    let dialect = GenericDialect {};
+    match Parser::new(&dialect).with_tokens(tokens).parse_statements() {
+        Ok(ast) => Ok(ast),
+        Err(e) => raise_error!(
+            "ERR_DB_SQL_SYNTAX",
+            error = e,
+            context = json_value!({ "sql_query": sql, "action": "parse_sql_tokens" })
+        ),
     }
 }
 
+fn param_token(value: &JsonValue, index: usize) -> RaiseResult<Token> {
+    let token = match value {
+        JsonValue::Null => Token::make_keyword("NULL"),
+        JsonValue::Bool(true) => Token::make_keyword("TRUE"),
+        JsonValue::Bool(false) => Token::make_keyword("FALSE"),
+        JsonValue::Number(n) => Token::Number(n.to_string(), false),
+        JsonValue::String(s) => Token::SingleQuotedString(s.clone()),
+        other => raise_error!(
+            "ERR_DB_SQL_PARAM_UNSUPPORTED",
+            error =
+                "Seules des valeurs scalaires (texte, nombre, booléen, null) peuvent être liées.",
+            context = json_value!({ "param": index + 1, "value": other })
+        ),
+    };
+    Ok(token)
+}
+
 // --- TRADUCTION INSERT ---
 fn translate_insert(insert: &Insert) -> RaiseResult<Vec<TransactionRequest>> {
     let collection = insert.table.to_string();
@@ -179,27 +383,14 @@ fn translate_query(sql_query: &SqlQuery) -> RaiseResult<Query> {
         offset = digits.parse::<usize>().ok();
     }
 
-    let sort = if let Some(order_by_struct) = &sql_query.order_by {
-        match &order_by_struct.kind {
-            OrderByKind::Expressions(exprs) => {
-                let mut fields = Vec::new();
-                for order_expr in exprs {
-                    fields.push(translate_order_by(order_expr)?);
-                }
-                if fields.is_empty() {
-                    None
-                } else {
-                    Some(fields)
-                }
-            }
-            _ => None,
-        }
-    } else {
-        None
+    // --- ORDER BY : traduit avec le SELECT (alias, colonnes calculées, jointures) ---
+    let order_by: &[OrderByExpr] = match sql_query.order_by.as_ref().map(|o| &o.kind) {
+        Some(OrderByKind::Expressions(exprs)) => exprs,
+        _ => &[],
     };
 
     match &*sql_query.body {
-        SetExpr::Select(select) => translate_select(select, limit, offset, sort),
+        SetExpr::Select(select) => translate_select(select, limit, offset, order_by),
         unsupported_expr => {
             raise_error!(
                 "ERR_DB_SQL_SELECT_EXPR_UNSUPPORTED",
@@ -252,33 +443,109 @@ fn translate_delete(delete: &Delete) -> RaiseResult<Query> {
         limit: None,
         offset: None,
         projection: None,
+        aggregation: None,
+        joins: Vec::new(),
+        after: None,
+        before: None,
+        window: None,
+        distinct: None,
     })
 }
 
+// --- TRADUCTION UPDATE ---
+/// `UPDATE col SET a = 1, specs.mass = 2 WHERE ...` -> (requête de ciblage, patch fusionné).
+fn translate_update(update: &Update) -> RaiseResult<(Query, JsonValue)> {
+    let TableFactor::Table { name, .. } = &update.table.relation else {
+        raise_error!(
+            "ERR_DB_SQL_UPDATE_RELATION_UNSUPPORTED",
+            error = "La cible de l'UPDATE est invalide ou utilise une structure non supportée."
+        );
+    };
+    if update.from.is_some() || !update.table.joins.is_empty() {
+        raise_error!(
+            "ERR_DB_SQL_UPDATE_JOIN_UNSUPPORTED",
+            error = "UPDATE ... FROM / JOIN n'est pas supporté par le moteur JSON-DB.",
+            context = json_value!({ "table_name": name.to_string() })
+        );
+    }
+
+    let mut patch = JsonValue::Object(JsonObject::new());
+    for assignment in &update.assignments {
+        let AssignmentTarget::ColumnName(column) = &assignment.target else {
+            raise_error!(
+                "ERR_DB_SQL_UPDATE_TUPLE_UNSUPPORTED",
+                error = "Les affectations multiples (a, b) = (...) ne sont pas supportées.",
+                context = json_value!({ "assignment": assignment.to_string() })
+            );
+        };
+        let value = expr_to_value(&assignment.value)?;
+        // Chemin pointé -> objet imbriqué (fusionné par json_merge à l'application)
+        let mut node = &mut patch;
+        let path = column.to_string();
+        let mut segments = path.split('.').peekable();
+        while let Some(segment) = segments.next() {
+            let Some(obj) = node.as_object_mut() else {
+                break;
+            };
+            if segments.peek().is_none() {
+                obj.insert(segment.to_string(), value.clone());
+                break;
+            }
+            node = obj
+                .entry(segment.to_string())
+                .or_insert_with(|| JsonValue::Object(JsonObject::new()));
+        }
+    }
+
+    let filter = match &update.selection {
+        Some(selection) => Some(translate_expr(selection)?),
+        None => None,
+    };
+
+    Ok((
+        Query {
+            collection: name.to_string(),
+            filter,
+            rls_policy: None,
+            sort: None,
+            limit: None,
+            offset: None,
+            projection: None,
+            aggregation: None,
+            joins: Vec::new(),
+            after: None,
+            before: None,
+            window: None,
+            distinct: None,
+        },
+        patch,
+    ))
+}
+
 // --- TRADUCTION SELECT ---
 fn translate_select(
     select: &sqlparser::ast::Select,
     limit: Option<usize>,
     offset: Option<usize>,
-    sort: Option<Vec<SortField>>,
+    order_by: &[OrderByExpr],
 ) -> RaiseResult<Query> {
     if select.from.len() != 1 {
         raise_error!(
             "ERR_DB_SQL_MULTIPLE_SOURCES_UNSUPPORTED",
-            error = "Le moteur JSON-DB ne supporte qu'une seule collection par requête (JOIN non supporté).",
+            error = "Le moteur JSON-DB n'accepte qu'une source dans la clause FROM : reliez les collections par JOIN.",
             context = json_value!({
                 "sources_found": select.from.iter().map(|f| f.relation.to_string()).collect::<Vec<_>>(),
                 "sources_count": select.from.len(),
                 "action": "validate_select_from_clause",
-                "hint": "Veuillez simplifier votre requête pour ne cibler qu'une seule collection '@collection'."
+                "hint": "Remplacez 'FROM a, b WHERE a.x = b.y' par 'FROM a JOIN b ON a.x = b.y'."
             })
         );
     }
 
-    let TableFactor::Table { name, .. } = &select.from[0].relation else {
+    let TableFactor::Table { name, alias, .. } = &select.from[0].relation else {
         raise_error!(
             "ERR_DB_SQL_FROM_RELATION_UNSUPPORTED",
-            error = "La clause FROM est invalide ou utilise une structure non supportée (sous-requêtes, jointures).",
+            error = "La clause FROM est invalide ou utilise une structure non supportée (sous-requêtes).",
             context = json_value!({
                 "attempted_relation": format!("{:?}", select.from[0].relation),
                 "action": "resolve_collection_name",
@@ -289,39 +556,85 @@ fn translate_select(
 
     let collection = name.to_string();
 
-    let projection = if select.projection.is_empty() {
-        None
-    } else {
-        let mut fields = Vec::new();
-        let mut is_wildcard = false;
+    let mut fields = Vec::new();
+    let mut accumulators = Vec::new();
+    let mut columns = Vec::new();
+    let mut windows = Vec::new();
+    let mut is_wildcard = false;
 
-        for item in &select.projection {
-            match item {
-                sqlparser::ast::SelectItem::UnnamedExpr(Expr::Identifier(ident)) => {
-                    fields.push(ident.value.clone());
-                }
-                sqlparser::ast::SelectItem::UnnamedExpr(Expr::CompoundIdentifier(idents)) => {
-                    fields.push(
-                        idents
-                            .iter()
-                            .map(|i| i.value.clone())
-                            .collect::<Vec<_>>()
-                            .join("."),
-                    );
-                }
-                sqlparser::ast::SelectItem::Wildcard(_) => {
-                    is_wildcard = true;
-                    break;
-                }
-                _ => {}
+    let base_alias = alias.as_ref().map(|a| a.name.value.clone());
+    let joined = !select.from[0].joins.is_empty();
+    let path = |field: String| computed_path(field, &collection, base_alias.as_deref(), joined);
+
+    for item in &select.projection {
+        match item {
+            SelectItem::UnnamedExpr(Expr::Function(func)) if func.over.is_some() => {
+                windows.push(translate_window(func, None)?);
+            }
+            SelectItem::ExprWithAlias {
+                expr: Expr::Function(func),
+                alias,
+            } if func.over.is_some() => {
+                windows.push(translate_window(func, Some(alias.value.clone()))?);
+            }
+            SelectItem::UnnamedExpr(Expr::Function(func)) if !is_scalar_function(func) => {
+                accumulators.push(translate_aggregate(func, None)?);
+            }
+            SelectItem::ExprWithAlias {
+                expr: Expr::Function(func),
+                alias,
+            } if !is_scalar_function(func) => {
+                accumulators.push(translate_aggregate(func, Some(alias.value.clone()))?);
+            }
+            SelectItem::UnnamedExpr(expr @ (Expr::Identifier(_) | Expr::CompoundIdentifier(_))) => {
+                fields.push(expr_to_field_name(expr)?);
             }
+            SelectItem::UnnamedExpr(expr) => columns.push(ComputedColumn {
+                alias: expr.to_string(),
+                expr: translate_computed(expr, &path)?,
+            }),
+            SelectItem::ExprWithAlias { expr, alias } => columns.push(ComputedColumn {
+                alias: alias.value.clone(),
+                expr: translate_computed(expr, &path)?,
+            }),
+            SelectItem::Wildcard(_) => {
+                is_wildcard = true;
+            }
+            _ => {}
         }
+    }
 
-        if is_wildcard || fields.is_empty() {
-            None
-        } else {
-            Some(Projection::Include(fields))
-        }
+    let aggregation = translate_grouping(select, &fields, accumulators, is_wildcard)?;
+    let window = translate_window_stage(select, windows)?;
+    let sort = translate_sort(order_by, &columns, &path)?;
+    let distinct = match &select.distinct {
+        Some(Distinct::Distinct) if is_wildcard => Some(DistinctClause::default()),
+        Some(Distinct::On(exprs)) => Some(DistinctClause {
+            on: exprs
+                .iter()
+                .map(expr_to_field_name)
+                .collect::<RaiseResult<Vec<_>>>()?,
+        }),
+        _ => None,
+    };
+
+    // Les colonnes de fenêtre s'ajoutent aux lignes : la projection les reprend
+    if let (Some(window), false, None) = (&window, is_wildcard, &aggregation) {
+        fields.extend(window.functions.iter().map(|f| f.alias.clone()));
+    }
+
+    // Les lignes agrégées ne contiennent déjà que les clés et les accumulateurs
+    let projection = if !columns.is_empty() {
+        let wildcard = aggregation.is_some() || is_wildcard;
+        Some(Projection::Computed {
+            fields: if wildcard { Vec::new() } else { fields },
+            wildcard,
+            columns,
+        })
+    } else if aggregation.is_some() || is_wildcard || fields.is_empty() {
+        None
+    } else {
+        Some(Projection::Include(fields))
     };
 
     let filter = if let Some(selection) = &select.selection {
@@ -330,7 +643,13 @@ fn translate_select(
         None
     };
 
-    Ok(Query {
+    let joins = select.from[0]
+        .joins
+        .iter()
+        .map(translate_join)
+        .collect::<RaiseResult<Vec<_>>>()?;
+
+    let mut query = Query {
         collection,
         filter,
         rls_policy: None,
@@ -338,16 +657,496 @@ fn translate_select(
         limit,
         offset,
         projection,
+        aggregation,
+        joins,
+        after: None,
+        before: None,
+        window,
+        distinct,
+    };
+    if let Some(alias) = alias {
+        let collection = query.collection.clone();
+        requalify(&mut query, &alias.name.value, &collection);
+    }
+    Ok(query)
+}
+
+/// `[INNER] JOIN` / `LEFT [OUTER] JOIN <collection> [alias] ON a.x = <collection>.y`.
+/// Le membre de l'égalité qualifié par la collection jointe (ou son alias) désigne le champ
+/// joint ; l'autre membre est lu sur la ligne courante.
+fn translate_join(join: &Join) -> RaiseResult<JoinClause> {
+    let TableFactor::Table { name, alias, .. } = &join.relation else {
+        raise_error!(
+            "ERR_DB_SQL_JOIN_RELATION_UNSUPPORTED",
+            error = "Seules des collections peuvent être jointes (pas de sous-requête).",
+            context = json_value!({ "join": join.to_string() })
+        );
+    };
+
+    let (kind, constraint) = match &join.join_operator {
+        JoinOperator::Join(c) | JoinOperator::Inner(c) => (JoinKind::Inner, c),
+        JoinOperator::Left(c) | JoinOperator::LeftOuter(c) => (JoinKind::Left, c),
+        _ => raise_error!(
+            "ERR_DB_SQL_JOIN_TYPE_UNSUPPORTED",
+            error = format!("Type de jointure non supporté : {}", join),
+            context = json_value!({ "supported": ["JOIN", "INNER JOIN", "LEFT [OUTER] JOIN"] })
+        ),
+    };
+
+    let mut on = match constraint {
+        JoinConstraint::On(expr) => expr,
+        _ => raise_error!(
+            "ERR_DB_SQL_JOIN_CONDITION_UNSUPPORTED",
+            error = "Une jointure exige une clause ON (USING et NATURAL ne sont pas supportés).",
+            context = json_value!({ "join": join.to_string() })
+        ),
+    };
+    while let Expr::Nested(inner) = on {
+        on = &**inner;
+    }
+    let Expr::BinaryOp {
+        left,
+        op: BinaryOperator::Eq,
+        right,
+    } = on
+    else {
+        raise_error!(
+            "ERR_DB_SQL_JOIN_CONDITION_UNSUPPORTED",
+            error = "La condition de jointure doit être une égalité entre deux champs.",
+            context = json_value!({ "condition": on.to_string() })
+        );
+    };
+
+    let collection = name.to_string();
+    let alias = alias.as_ref().map(|a| a.name.value.clone());
+    let prefix = format!("{}.", alias.as_deref().unwrap_or(&collection));
+    let lhs = expr_to_field_name(left)?;
+    let rhs = expr_to_field_name(right)?;
+    let (local_field, foreign_field) = match (lhs.strip_prefix(&prefix), rhs.strip_prefix(&prefix))
+    {
+        (_, Some(field)) => (lhs.clone(), field.to_string()),
+        (Some(field), None) => (rhs.clone(), field.to_string()),
+        (None, None) => raise_error!(
+            "ERR_DB_SQL_JOIN_CONDITION_UNSUPPORTED",
+            error = format!(
+                "Aucun membre de la condition ne porte sur la collection jointe '{}'.",
+                collection
+            ),
+            context = json_value!({
+                "condition": on.to_string(),
+                "hint": "Qualifiez le champ joint : ON articles.author = actors._id"
+            })
+        ),
+    };
+
+    Ok(JoinClause {
+        collection,
+        alias,
+        kind,
+        local_field,
+        foreign_field,
     })
 }
 
+/// Réécrit les chemins qualifiés par l'alias de la collection de base (`a.title` -> `articles.title`).
+fn requalify(query: &mut Query, alias: &str, collection: &str) {
+    let prefix = format!("{}.", alias);
+    let rename = |field: &mut String| {
+        if let Some(rest) = field.strip_prefix(&prefix) {
+            let renamed = format!("{}.{}", collection, rest);
+            *field = renamed;
+        }
+    };
+
+    if let Some(filter) = &mut query.filter {
+        filter
+            .conditions
+            .iter_mut()
+            .for_each(|c| rename(&mut c.field));
+    }
+    if let Some(window) = &mut query.window {
+        for function in &mut window.functions {
+            function.partition_by.iter_mut().for_each(&rename);
+            function
+                .order_by
+                .iter_mut()
+                .for_each(|s| rename(&mut s.field));
+        }
+        if let Some(qualify) = &mut window.qualify {
+            qualify
+                .conditions
+                .iter_mut()
+                .for_each(|c| rename(&mut c.field));
+        }
+    }
+    if let Some(distinct) = &mut query.distinct {
+        distinct.on.iter_mut().for_each(&rename);
+    }
+    if let Some(sort) = &mut query.sort {
+        // Les clés calculées portent déjà des chemins résolus (cf. `computed_path`)
+        sort.iter_mut()
+            .filter(|s| s.expr.is_none())
+            .for_each(|s| rename(&mut s.field));
+    }
+    if let Some(
+        Projection::Include(fields)
+        | Projection::Exclude(fields)
+        | Projection::Computed { fields, .. },
+    ) = &mut query.projection
+    {
+        fields.iter_mut().for_each(&rename);
+    }
+    if let Some(aggregation) = &mut query.aggregation {
+        aggregation.group_by.iter_mut().for_each(&rename);
+        aggregation
+            .accumulators
+            .iter_mut()
+            .filter_map(|a| a.field.as_mut())
+            .for_each(&rename);
+    }
+    query
+        .joins
+        .iter_mut()
+        .for_each(|j| rename(&mut j.local_field));
+}
+
+/// `GROUP BY`, `SELECT DISTINCT` et fonctions d'agrégat -> étape `Aggregation`.
+/// Les colonnes simples du SELECT doivent figurer dans le GROUP BY.
+fn translate_grouping(
+    select: &sqlparser::ast::Select,
+    fields: &[String],
+    accumulators: Vec<Accumulator>,
+    is_wildcard: bool,
+) -> RaiseResult<Option<Aggregation>> {
+    if let Some(having) = &select.having {
+        raise_error!(
+            "ERR_DB_SQL_HAVING_UNSUPPORTED",
+            error = "La clause HAVING n'est pas supportée par le moteur JSON-DB.",
+            context = json_value!({
+                "having": having.to_string(),
+                "hint": "Filtrez les documents avec WHERE avant le regroupement."
+            })
+        );
+    }
+
+    let mut group_by = match &select.group_by {
+        GroupByExpr::Expressions(exprs, _) => exprs
+            .iter()
+            .map(expr_to_field_name)
+            .collect::<RaiseResult<Vec<_>>>()?,
+        // GROUP BY ALL : regroupement sur toutes les colonnes non agrégées
+        GroupByExpr::All(_) => fields.to_vec(),
+    };
+
+    // SELECT DISTINCT a, b ≡ GROUP BY a, b (SELECT DISTINCT * : étape `distinct` du moteur)
+    let is_distinct = matches!(select.distinct, Some(Distinct::Distinct));
+    if is_distinct && !is_wildcard && group_by.is_empty() && accumulators.is_empty() {
+        group_by = fields.to_vec();
+    }
+
+    if group_by.is_empty() && accumulators.is_empty() {
+        return Ok(None);
+    }
+
+    if let Some(orphan) = fields.iter().find(|f| !group_by.contains(f)) {
+        raise_error!(
+            "ERR_DB_SQL_GROUP_BY_FIELD_MISSING",
+            error = format!(
+                "La colonne '{}' doit figurer dans le GROUP BY ou dans une fonction d'agrégat.",
+                orphan
+            ),
+            context = json_value!({ "field": orphan, "group_by": group_by })
+        );
+    }
+
+    Ok(Some(Aggregation {
+        group_by,
+        accumulators,
+    }))
+}
+
+/// `COUNT(*)`, `COUNT(DISTINCT x)`, `SUM(x)`, `AVG(x)`, `MIN(x)`, `MAX(x)`, `ARRAY_AGG(DISTINCT x)`.
+/// Sans alias, la colonne est nommée `count`, `sum_x`, `avg_x`…
+fn translate_aggregate(func: &Function, alias: Option<String>) -> RaiseResult<Accumulator> {
+    let name = func.name.to_string().to_uppercase();
+
+    let FunctionArguments::List(list) = &func.args else {
+        raise_error!(
+            "ERR_DB_SQL_AGGREGATE_ARGS_INVALID",
+            error = "Les fonctions d'agrégat attendent une liste d'arguments entre parenthèses.",
+            context = json_value!({ "function": func.to_string() })
+        );
+    };
+    let distinct = matches!(list.duplicate_treatment, Some(DuplicateTreatment::Distinct));
+
+    let field = match list.args.as_slice() {
+        [FunctionArg::Unnamed(FunctionArgExpr::Wildcard)] => None,
+        [FunctionArg::Unnamed(FunctionArgExpr::Expr(expr))] => Some(expr_to_field_name(expr)?),
+        _ => raise_error!(
+            "ERR_DB_SQL_AGGREGATE_ARGS_INVALID",
+            error = "Une fonction d'agrégat porte sur un unique champ (ou '*' pour COUNT).",
+            context = json_value!({ "function": func.to_string() })
+        ),
+    };
+
+    let op = match (name.as_str(), distinct) {
+        ("COUNT", false) => AggregateOp::Count,
+        ("COUNT", true) => AggregateOp::CountDistinct,
+        ("SUM", false) => AggregateOp::Sum,
+        ("AVG", false) => AggregateOp::Avg,
+        ("MIN", _) => AggregateOp::Min,
+        ("MAX", _) => AggregateOp::Max,
+        ("ARRAY_AGG", true) => AggregateOp::Distinct,
+        _ => raise_error!(
+            "ERR_DB_SQL_AGGREGATE_UNSUPPORTED",
+            error = format!("Fonction d'agrégat non supportée : {}", func),
+            context = json_value!({
+                "supported": ["COUNT", "COUNT(DISTINCT)", "SUM", "AVG", "MIN", "MAX", "ARRAY_AGG(DISTINCT)"]
+            })
+        ),
+    };
+
+    if field.is_none() && op != AggregateOp::Count {
+        raise_error!(
+            "ERR_DB_SQL_AGGREGATE_ARGS_INVALID",
+            error = format!("'*' n'est accepté que par COUNT : {}", func)
+        );
+    }
+
+    let alias = alias.unwrap_or_else(|| {
+        let prefix = name.to_lowercase();
+        match &field {
+            Some(f) => format!("{}_{}", prefix, f.split('.').next_back().unwrap_or(f)),
+            None => prefix,
+        }
+    });
+    Ok(Accumulator { alias, op, field })
+}
+
+/// `ROW_NUMBER() | RANK() | DENSE_RANK() OVER (PARTITION BY ... ORDER BY ...)`.
+fn translate_window(func: &Function, alias: Option<String>) -> RaiseResult<WindowFunction> {
+    let name = func.name.to_string().to_uppercase();
+    let op = match name.as_str() {
+        "ROW_NUMBER" => WindowOp::RowNumber,
+        "RANK" => WindowOp::Rank,
+        "DENSE_RANK" => WindowOp::DenseRank,
+        _ => raise_error!(
+            "ERR_DB_SQL_WINDOW_UNSUPPORTED",
+            error = format!("Fonction de fenêtre non supportée : {}", func),
+            context = json_value!({ "supported": ["ROW_NUMBER", "RANK", "DENSE_RANK"] })
+        ),
+    };
+    let spec = match &func.over {
+        Some(WindowType::WindowSpec(spec))
+            if spec.window_name.is_none() && spec.window_frame.is_none() =>
+        {
+            spec
+        }
+        _ => raise_error!(
+            "ERR_DB_SQL_WINDOW_UNSUPPORTED",
+            error = "Seule une fenêtre en ligne sans cadre est supportée : OVER (PARTITION BY ... ORDER BY ...).",
+            context = json_value!({ "function": func.to_string() })
+        ),
+    };
+
+    Ok(WindowFunction {
+        alias: alias.unwrap_or_else(|| name.to_lowercase()),
+        op,
+        partition_by: spec
+            .partition_by
+            .iter()
+            .map(expr_to_field_name)
+            .collect::<RaiseResult<Vec<_>>>()?,
+        order_by: spec
+            .order_by
+            .iter()
+            .map(translate_order_by)
+            .collect::<RaiseResult<Vec<_>>>()?,
+    })
+}
+
+/// Fonctions de fenêtre du SELECT et clause `QUALIFY`, qui filtre sur leurs colonnes.
+fn translate_window_stage(
+    select: &sqlparser::ast::Select,
+    functions: Vec<WindowFunction>,
+) -> RaiseResult<Option<WindowStage>> {
+    if functions.is_empty() {
+        if let Some(qualify) = &select.qualify {
+            raise_error!(
+                "ERR_DB_SQL_QUALIFY_WITHOUT_WINDOW",
+                error = "QUALIFY filtre les colonnes de fenêtre : aucune fonction OVER (...) dans le SELECT.",
+                context = json_value!({ "qualify": qualify.to_string() })
+            );
+        }
+        return Ok(None);
+    }
+    Ok(Some(WindowStage {
+        functions,
+        qualify: select.qualify.as_ref().map(translate_expr).transpose()?,
+    }))
+}
+
+fn is_scalar_function(func: &Function) -> bool {
+    let name = func.name.to_string().to_uppercase();
+    SCALAR_FUNCTIONS.contains(&name.as_str())
+}
+
+/// Chemin d'un champ de colonne calculée : qualifié par la collection s'il y a des jointures
+/// (les lignes sont alors `{ <source>: doc }`), relatif au document sinon.
+fn computed_path(field: String, collection: &str, alias: Option<&str>, joined: bool) -> String {
+    for qualifier in std::iter::once(collection).chain(alias) {
+        if let Some(rest) = field.strip_prefix(&format!("{}.", qualifier)) {
+            return match joined {
+                true => format!("{}.{}", collection, rest),
+                false => rest.to_string(),
+            };
+        }
+    }
+    field
+}
+
+/// Expression SQL d'une colonne du SELECT -> expression du moteur de règles.
+fn translate_computed(expr: &Expr, path: &dyn Fn(String) -> String) -> RaiseResult<RuleExpr> {
+    let unsupported = || -> RaiseResult<RuleExpr> {
+        raise_error!(
+            "ERR_DB_SQL_EXPRESSION_UNSUPPORTED",
+            error = format!("Expression non supportée dans le SELECT : {}", expr),
+            context = json_value!({
+                "supported": ["+", "-", "*", "/", "||", "=", "<>", "<", "<=", ">", ">=", "AND", "OR", "NOT"],
+                "functions": SCALAR_FUNCTIONS
+            })
+        )
+    };
+
+    let translated = match expr {
+        Expr::Nested(inner) => translate_computed(inner, path)?,
+        Expr::Identifier(_) | Expr::CompoundIdentifier(_) => {
+            RuleExpr::Var(path(expr_to_field_name(expr)?))
+        }
+        Expr::Value(_) => RuleExpr::Val(expr_to_value(expr)?),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr: inner,
+        } => match inner.as_ref() {
+            Expr::Value(_) => RuleExpr::Val(expr_to_value(expr)?),
+            _ => RuleExpr::Sub(vec![
+                RuleExpr::Val(json_value!(0)),
+                translate_computed(inner, path)?,
+            ]),
+        },
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: inner,
+        } => RuleExpr::Not(Box::new(translate_computed(inner, path)?)),
+        Expr::BinaryOp { left, op, right } => {
+            let l = translate_computed(left, path)?;
+            let r = translate_computed(right, path)?;
+            match op {
+                BinaryOperator::Plus => RuleExpr::Add(vec![l, r]),
+                BinaryOperator::Minus => RuleExpr::Sub(vec![l, r]),
+                BinaryOperator::Multiply => RuleExpr::Mul(vec![l, r]),
+                BinaryOperator::Divide => RuleExpr::Div(vec![l, r]),
+                BinaryOperator::StringConcat => RuleExpr::Concat(vec![l, r]),
+                BinaryOperator::Eq => RuleExpr::Eq(vec![l, r]),
+                BinaryOperator::NotEq => RuleExpr::Neq(vec![l, r]),
+                BinaryOperator::Gt => RuleExpr::Gt(Box::new(l), Box::new(r)),
+                BinaryOperator::Lt => RuleExpr::Lt(Box::new(l), Box::new(r)),
+                BinaryOperator::GtEq => RuleExpr::Gte(Box::new(l), Box::new(r)),
+                BinaryOperator::LtEq => RuleExpr::Lte(Box::new(l), Box::new(r)),
+                BinaryOperator::And => RuleExpr::And(vec![l, r]),
+                BinaryOperator::Or => RuleExpr::Or(vec![l, r]),
+                _ => return unsupported(),
+            }
+        }
+        Expr::Function(func) if is_scalar_function(func) => {
+            let mut args = match &func.args {
+                FunctionArguments::List(list) => list
+                    .args
+                    .iter()
+                    .map(|arg| match arg {
+                        FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => {
+                            translate_computed(e, path)
+                        }
+                        _ => unsupported(),
+                    })
+                    .collect::<RaiseResult<Vec<_>>>()?,
+                _ => Vec::new(),
+            };
+            let name = func.name.to_string().to_uppercase();
+            match (name.as_str(), args.len()) {
+                ("CONCAT", _) => RuleExpr::Concat(args),
+                ("ROUND", 2) => {
+                    let precision = Box::new(args.remove(1));
+                    RuleExpr::Round {
+                        value: Box::new(args.remove(0)),
+                        precision,
+                    }
+                }
+                (_, 1) => {
+                    let arg = Box::new(args.remove(0));
+                    match name.as_str() {
+                        "UPPER" => RuleExpr::Upper(arg),
+                        "LOWER" => RuleExpr::Lower(arg),
+                        "TRIM" => RuleExpr::Trim(arg),
+                        "ABS" => RuleExpr::Abs(arg),
+                        "ROUND" => RuleExpr::Round {
+                            value: arg,
+                            precision: Box::new(RuleExpr::Val(json_value!(0))),
+                        },
+                        _ => RuleExpr::Len(arg),
+                    }
+                }
+                _ => raise_error!(
+                    "ERR_DB_SQL_FUNCTION_ARGS_INVALID",
+                    error = format!("Nombre d'arguments invalide : {}", func),
+                    context = json_value!({ "function": name, "received": args.len() })
+                ),
+            }
+        }
+        _ => return unsupported(),
+    };
+    Ok(translated)
+}
+
 fn translate_order_by(expr: &OrderByExpr) -> RaiseResult<SortField> {
     let field = expr_to_field_name(&expr.expr)?;
-    let order = match expr.options.asc {
+    Ok(SortField::new(field, sort_order(expr)))
+}
+
+fn sort_order(expr: &OrderByExpr) -> SortOrder {
+    match expr.options.asc {
         Some(false) => SortOrder::Desc,
         _ => SortOrder::Asc,
-    };
-    Ok(SortField { field, order })
+    }
+}
+
+/// `ORDER BY` d'un SELECT, clé par clé avec son sens : un champ reste un chemin, l'alias
+/// d'une colonne calculée reprend son expression, et toute autre expression devient une
+/// clé calculée (`LEN(tags)`, `qty * price`).
+fn translate_sort(
+    order_by: &[OrderByExpr],
+    columns: &[ComputedColumn],
+    path: &dyn Fn(String) -> String,
+) -> RaiseResult<Option<Vec<SortField>>> {
+    if order_by.is_empty() {
+        return Ok(None);
+    }
+    let mut sort = Vec::with_capacity(order_by.len());
+    for item in order_by {
+        let order = sort_order(item);
+        let key = match &item.expr {
+            expr @ (Expr::Identifier(_) | Expr::CompoundIdentifier(_)) => {
+                let field = expr_to_field_name(expr)?;
+                match columns.iter().find(|c| c.alias == field) {
+                    Some(column) => SortField::computed(field, column.expr.clone(), order),
+                    None => SortField::new(field, order),
+                }
+            }
+            expr => SortField::computed(expr.to_string(), translate_computed(expr, path)?, order),
+        };
+        sort.push(key);
+    }
+    Ok(Some(sort))
 }
 
 fn translate_expr(expr: &Expr) -> RaiseResult<QueryFilter> {
@@ -567,6 +1366,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_explain_select() -> RaiseResult<()> {
+        let SqlRequest::Explain(query) =
+            parse_sql("EXPLAIN SELECT * FROM components WHERE status = 'draft'")?
+        else {
+            panic!("Expected Explain request");
+        };
+        assert_eq!(query.collection, "components");
+        assert!(query.filter.is_some());
+        assert!(parse_sql("EXPLAIN DELETE FROM components").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_delete_with_where_clause() -> RaiseResult<()> {
         let sql = "DELETE FROM sessions WHERE status = 'expired'";
@@ -601,6 +1413,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_update_with_nested_set() -> RaiseResult<()> {
+        let sql = "UPDATE components SET status = 'validated', specs.mass = 12 WHERE layer = 'la'";
+        let SqlRequest::Write(ops) = parse_sql(sql)? else {
+            panic!("Un UPDATE doit produire une requête Write.");
+        };
+        let [TransactionRequest::UpdateMany { query, patch }] = ops.as_slice() else {
+            panic!("L'opération générée devrait être un UpdateMany.");
+        };
+        assert_eq!(query.collection, "components");
+        assert_eq!(query.filter.as_ref().unwrap().conditions[0].field, "layer");
+        assert_eq!(
+            patch,
+            &json_value!({ "status": "validated", "specs": { "mass": 12 } })
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_parse_delete_all() -> RaiseResult<()> {
         let sql = "DELETE FROM cache_entries";
@@ -626,4 +1456,294 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_group_by_with_aggregates() -> RaiseResult<()> {
+        let sql = "SELECT layer, COUNT(*) AS total, AVG(specs.mass) FROM components \
+                   WHERE status = 'validated' GROUP BY layer ORDER BY total DESC";
+        let SqlRequest::Read(query) = parse_sql(sql)? else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        let aggregation = query.aggregation.expect("GROUP BY non traduit");
+        assert_eq!(aggregation.group_by, vec!["layer".to_string()]);
+        assert_eq!(
+            aggregation.accumulators,
+            vec![
+                Accumulator::new("total", AggregateOp::Count, None),
+                Accumulator::new("avg_mass", AggregateOp::Avg, Some("specs.mass")),
+            ]
+        );
+        assert!(query.projection.is_none());
+        assert_eq!(query.sort.unwrap()[0].field, "total");
+        assert_eq!(query.filter.unwrap().conditions[0].field, "status");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_computed_columns() -> RaiseResult<()> {
+        let sql = "SELECT o.name, o.qty * o.price AS total, UPPER(o.name) AS label \
+                   FROM orders o";
+        let SqlRequest::Read(query) = parse_sql(sql)? else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        assert_eq!(
+            query.projection,
+            Some(Projection::Computed {
+                fields: vec!["orders.name".to_string()],
+                wildcard: false,
+                columns: vec![
+                    ComputedColumn {
+                        alias: "total".to_string(),
+                        expr: RuleExpr::Mul(vec![
+                            RuleExpr::Var("qty".to_string()),
+                            RuleExpr::Var("price".to_string()),
+                        ]),
+                    },
+                    ComputedColumn {
+                        alias: "label".to_string(),
+                        expr: RuleExpr::Upper(Box::new(RuleExpr::Var("name".to_string()))),
+                    },
+                ],
+            })
+        );
+
+        let SqlRequest::Read(query) = parse_sql("SELECT *, -qty AS neg FROM orders")? else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        assert!(matches!(
+            query.projection,
+            Some(Projection::Computed { wildcard: true, .. })
+        ));
+        assert!(parse_sql("SELECT qty BETWEEN 1 AND 3 AS ok FROM orders").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_distinct_and_count_distinct() -> RaiseResult<()> {
+        let SqlRequest::Read(query) = parse_sql("SELECT DISTINCT layer FROM components")? else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        let aggregation = query.aggregation.unwrap();
+        assert_eq!(aggregation.group_by, vec!["layer".to_string()]);
+        assert!(aggregation.accumulators.is_empty());
+
+        let SqlRequest::Read(query) = parse_sql("SELECT COUNT(DISTINCT owner) FROM components")?
+        else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        let aggregation = query.aggregation.unwrap();
+        assert!(aggregation.group_by.is_empty());
+        assert_eq!(aggregation.accumulators[0].op, AggregateOp::CountDistinct);
+        assert_eq!(aggregation.accumulators[0].alias, "count_owner");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_window_qualify_and_distinct_on() -> RaiseResult<()> {
+        let sql = "SELECT name, ROW_NUMBER() OVER (PARTITION BY layer ORDER BY mass DESC) AS rn \
+                   FROM components QUALIFY rn <= 1";
+        let SqlRequest::Read(query) = parse_sql(sql)? else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        let window = query.window.unwrap();
+        assert_eq!(window.functions[0].alias, "rn");
+        assert_eq!(window.functions[0].op, WindowOp::RowNumber);
+        assert_eq!(window.functions[0].partition_by, vec!["layer".to_string()]);
+        assert_eq!(window.functions[0].order_by[0].order, SortOrder::Desc);
+        assert_eq!(window.qualify.unwrap().conditions[0].field, "rn");
+        assert_eq!(
+            query.projection,
+            Some(Projection::Include(vec!["name".into(), "rn".into()]))
+        );
+
+        let SqlRequest::Read(query) =
+            parse_sql("SELECT DISTINCT ON (layer) * FROM components ORDER BY layer, mass DESC")?
+        else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        assert_eq!(query.distinct.unwrap().on, vec!["layer".to_string()]);
+        assert!(query.aggregation.is_none());
+
+        let SqlRequest::Read(query) = parse_sql("SELECT DISTINCT * FROM components")? else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        assert_eq!(query.distinct, Some(DistinctClause::default()));
+
+        for sql in [
+            "SELECT name FROM components QUALIFY name = 'x'",
+            "SELECT LAG(mass) OVER (ORDER BY mass) FROM components",
+            "SELECT RANK() OVER (ORDER BY mass ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM components",
+        ] {
+            assert!(parse_sql(sql).is_err(), "{sql}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_group_by_rejects_ungrouped_column() {
+        let err = parse_sql("SELECT layer, name, COUNT(*) FROM components GROUP BY layer")
+            .err()
+            .expect("La colonne 'name' n'est ni groupée ni agrégée.");
+        assert!(err
+            .to_string()
+            .contains("ERR_DB_SQL_GROUP_BY_FIELD_MISSING"));
+    }
+
+    #[test]
+    fn test_parse_join_with_aliases() -> RaiseResult<()> {
+        let sql = "SELECT a.title, u.name FROM articles a LEFT JOIN actors u \
+                   ON a.author = u._id WHERE u.role = 'admin' ORDER BY a.title";
+        let SqlRequest::Read(query) = parse_sql(sql)? else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        assert_eq!(query.collection, "articles");
+        assert_eq!(
+            query.joins,
+            vec![JoinClause {
+                collection: "actors".into(),
+                alias: Some("u".into()),
+                kind: JoinKind::Left,
+                local_field: "articles.author".into(),
+                foreign_field: "_id".into(),
+            }]
+        );
+        // L'alias de la collection de base est ramené à son nom
+        assert_eq!(
+            query.projection,
+            Some(Projection::Include(vec![
+                "articles.title".into(),
+                "u.name".into()
+            ]))
+        );
+        assert_eq!(query.sort.unwrap()[0].field, "articles.title");
+        assert_eq!(query.filter.unwrap().conditions[0].field, "u.role");
+
+        // Membres inversés : le champ joint est identifié par sa qualification
+        let SqlRequest::Read(query) =
+            parse_sql("SELECT * FROM articles JOIN actors ON actors._id = articles.author")?
+        else {
+            panic!("Un SELECT doit produire une requête Read.");
+        };
+        assert_eq!(query.joins[0].kind, JoinKind::Inner);
+        assert_eq!(query.joins[0].local_field, "articles.author");
+        assert_eq!(query.joins[0].foreign_field, "_id");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_join_rejects_unsupported_forms() {
+        for (sql, code) in [
+            (
+                "SELECT * FROM a RIGHT JOIN b ON a.x = b.y",
+                "ERR_DB_SQL_JOIN_TYPE_UNSUPPORTED",
+            ),
+            (
+                "SELECT * FROM a JOIN b ON a.x = c.y",
+                "ERR_DB_SQL_JOIN_CONDITION_UNSUPPORTED",
+            ),
+            (
+                "SELECT * FROM a JOIN b USING (x)",
+                "ERR_DB_SQL_JOIN_CONDITION_UNSUPPORTED",
+            ),
+        ] {
+            let err = parse_sql(sql).err().expect("Jointure non supportée");
+            assert!(err.to_string().contains(code), "{} -> {}", sql, err);
+        }
+    }
+
+    #[test]
+    fn test_parse_sql_with_params_binds_literals() -> RaiseResult<()> {
+        let sql = "SELECT * FROM users WHERE name = $1 AND age > $2 LIMIT $3";
+        // Une tentative d'injection reste une simple chaîne
+        let params = [
+            json_value!("x' OR '1'='1"),
+            json_value!(-3),
+            json_value!(10),
+        ];
+        let SqlRequest::Read(query) = parse_sql_with_params(sql, &params)? else {
+            panic!("Expected Read request");
+        };
+
+        let filter = query.filter.expect("Filtre WHERE attendu");
+        assert_eq!(filter.conditions.len(), 2);
+        assert_eq!(filter.conditions[0].value, json_value!("x' OR '1'='1"));
+        assert_eq!(filter.conditions[1].value, json_value!(-3));
+        assert_eq!(query.limit, Some(10));
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepared_sql_positional_params_and_errors() -> RaiseResult<()> {
+        let prepared =
+            PreparedSql::prepare("INSERT INTO users (name, admin, team) VALUES (?, ?, ?)")?;
+        assert_eq!(prepared.param_count(), 3);
+
+        // Une même préparation sert plusieurs exécutions
+        for name in ["Alice", "Bob"] {
+            let SqlRequest::Write(ops) =
+                prepared.bind(&[json_value!(name), json_value!(true), JsonValue::Null])?
+            else {
+                panic!("Expected Write request");
+            };
+            let TransactionRequest::Insert { document, .. } = &ops[0] else {
+                panic!("Expected Insert op");
+            };
+            assert_eq!(document["name"], name);
+            assert_eq!(document["admin"], true);
+            assert!(document["team"].is_null());
+        }
+
+        let err = prepared.bind(&[json_value!("Alice")]).unwrap_err();
+        assert!(err.to_string().contains("ERR_DB_SQL_PARAM_COUNT_MISMATCH"));
+        let err = prepared
+            .bind(&[json_value!({ "a": 1 }), json_value!(true), JsonValue::Null])
+            .unwrap_err();
+        assert!(err.to_string().contains("ERR_DB_SQL_PARAM_UNSUPPORTED"));
+
+        let err = PreparedSql::prepare("SELECT * FROM users WHERE a = $1 AND b = ?").unwrap_err();
+        assert!(err.to_string().contains("ERR_DB_SQL_PARAM_MIXED"));
+        let err = PreparedSql::prepare("SELECT * FROM users WHERE a = $name").unwrap_err();
+        assert!(err.to_string().contains("ERR_DB_SQL_PARAM_INVALID"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_order_by_computed_keys() -> RaiseResult<()> {
+        let sql = "SELECT name, qty * price AS total FROM orders \
+                   ORDER BY total DESC, LEN(tags), name";
+        let SqlRequest::Read(query) = parse_sql(sql)? else {
+            panic!("Expected Read request");
+        };
+        let sort = query.sort.expect("ORDER BY non traduit");
+        assert_eq!(sort.len(), 3);
+
+        // L'alias d'une colonne calculée reprend son expression
+        assert_eq!(sort[0].field, "total");
+        assert_eq!(sort[0].order, SortOrder::Desc);
+        assert!(matches!(sort[0].expr, Some(RuleExpr::Mul(_))));
+        assert_eq!(
+            sort[1],
+            SortField::computed(
+                "LEN(tags)",
+                RuleExpr::Len(Box::new(RuleExpr::Var("tags".into()))),
+                SortOrder::Asc
+            )
+        );
+        assert_eq!(sort[2], SortField::new("name", SortOrder::Asc));
+
+        // Avec jointure, les chemins de l'expression sont qualifiés par la collection
+        let SqlRequest::Read(query) = parse_sql(
+            "SELECT * FROM articles a JOIN actors u ON a.author = u._id ORDER BY LEN(a.tags) DESC",
+        )?
+        else {
+            panic!("Expected Read request");
+        };
+        assert_eq!(
+            query.sort.unwrap()[0].expr,
+            Some(RuleExpr::Len(Box::new(RuleExpr::Var(
+                "articles.tags".into()
+            ))))
+        );
+        Ok(())
+    }
 }
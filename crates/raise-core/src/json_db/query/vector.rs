@@ -0,0 +1,145 @@
+// FICHIER : src-tauri/src/json_db/query/vector.rs
+
+//! Recherche sémantique dans les requêtes : conditions `NearText` / `NearVector` déléguées à
+//! un `VectorSearchProvider` (branché sur le `VectorStore` configuré côté IA). Les records
+//! vectoriels portent l'`_id` des documents ; leur score est fusionné dans `_score`.
+
+use super::executor::BoxFuture;
+use super::{ComparisonOperator, Condition};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::utils::prelude::*;
+
+/// Champ posé sur chaque document retenu par une condition vectorielle.
+pub const SCORE_FIELD: &str = "_score";
+/// Nombre de voisins demandés au store quand la condition ne précise pas `k`.
+pub const DEFAULT_NEAR_K: usize = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VectorTarget {
+    /// Texte à vectoriser par le moteur d'embeddings du provider.
+    Text(String),
+    Vector(Vec<f32>),
+}
+
+/// Recherche des `k` plus proches voisins dans la collection vectorielle `collection`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorSearch {
+    pub collection: String,
+    pub target: VectorTarget,
+    pub k: usize,
+    pub min_score: f32,
+}
+
+impl VectorSearch {
+    pub fn is_vector_condition(condition: &Condition) -> bool {
+        matches!(
+            condition.operator,
+            ComparisonOperator::NearText | ComparisonOperator::NearVector
+        )
+    }
+
+    /// Lit une condition `NearText` (`"freinage"` ou `{ "text", "k", "min_score" }`) ou
+    /// `NearVector` (`[0.1, ...]` ou `{ "vector", "k", "min_score" }`). Le champ désigne la
+    /// collection vectorielle ; vide, celle de la requête.
+    pub fn from_condition(condition: &Condition, query_collection: &str) -> RaiseResult<Self> {
+        let (payload, k, min_score) = match &condition.value {
+            JsonValue::Object(obj) => (
+                obj.get("text").or_else(|| obj.get("vector")),
+                obj.get("k").and_then(|v| v.as_u64()),
+                obj.get("min_score").and_then(|v| v.as_f64()),
+            ),
+            value => (Some(value), None, None),
+        };
+
+        let target = match (&condition.operator, payload) {
+            (ComparisonOperator::NearText, Some(JsonValue::String(text)))
+                if !text.trim().is_empty() =>
+            {
+                Some(VectorTarget::Text(text.clone()))
+            }
+            (ComparisonOperator::NearVector, Some(JsonValue::Array(items)))
+                if !items.is_empty() =>
+            {
+                items
+                    .iter()
+                    .map(|v| v.as_f64().map(|f| f as f32))
+                    .collect::<Option<Vec<f32>>>()
+                    .map(VectorTarget::Vector)
+            }
+            _ => None,
+        };
+        let Some(target) = target else {
+            raise_error!(
+                "ERR_DB_QUERY_VECTOR_INVALID",
+                error = format!(
+                    "Condition vectorielle invalide sur '{}' : texte ou vecteur attendu.",
+                    condition.field
+                ),
+                context = json_value!({
+                    "operator": condition.operator,
+                    "value": condition.value,
+                    "hint": "NearText : \"texte\" ou { \"text\", \"k\", \"min_score\" } ; NearVector : [..] ou { \"vector\", \"k\", \"min_score\" }"
+                })
+            );
+        };
+
+        let collection = match condition.field.trim() {
+            "" => query_collection.to_string(),
+            field => field.to_string(),
+        };
+        Ok(Self {
+            collection,
+            target,
+            k: k.map(|k| k as usize).unwrap_or(DEFAULT_NEAR_K),
+            min_score: min_score.unwrap_or(0.0) as f32,
+        })
+    }
+}
+
+pub trait VectorSearchProvider: Send + Sync {
+    /// `_id` des documents les plus proches, avec leur score (décroissant).
+    fn search<'a>(
+        &'a self,
+        manager: &'a CollectionsManager<'a>,
+        request: &'a VectorSearch,
+    ) -> BoxFuture<'a, RaiseResult<Vec<(String, f64)>>>;
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_condition_parsing() -> RaiseResult<()> {
+        let near = Condition::near_text("", json_value!("freinage d'urgence"));
+        let search = VectorSearch::from_condition(&near, "requirements")?;
+        assert_eq!(search.collection, "requirements");
+        assert_eq!(
+            search.target,
+            VectorTarget::Text("freinage d'urgence".into())
+        );
+        assert_eq!(search.k, DEFAULT_NEAR_K);
+
+        let near = Condition::near_vector(
+            "req_embeddings",
+            json_value!({ "vector": [1.0, 0.5], "k": 3, "min_score": 0.4 }),
+        );
+        let search = VectorSearch::from_condition(&near, "requirements")?;
+        assert_eq!(search.collection, "req_embeddings");
+        assert_eq!(search.target, VectorTarget::Vector(vec![1.0, 0.5]));
+        assert_eq!((search.k, search.min_score), (3, 0.4));
+
+        for bad in [
+            Condition::near_text("", json_value!(["pas", "un texte"])),
+            Condition::near_text("", json_value!("  ")),
+            Condition::near_vector("", json_value!([1.0, "x"])),
+        ] {
+            assert!(VectorSearch::from_condition(&bad, "requirements").is_err());
+        }
+        Ok(())
+    }
+}
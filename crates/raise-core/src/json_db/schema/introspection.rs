@@ -0,0 +1,340 @@
+// FICHIER : src-tauri/src/json_db/schema/introspection.rs
+
+//! Métadonnées d'autocomplétion dérivées du JSON Schema d'une collection
+//! (query builder du frontend, REPL du CLI).
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::schema::validator::{resolve_path_uri, split_uri_fragment};
+use crate::json_db::schema::SchemaRegistry;
+use crate::utils::prelude::*;
+
+/// Profondeur maximale de descente dans les objets imbriqués / `$ref` (anti-cycle).
+const MAX_DEPTH: usize = 8;
+
+/// Description d'un champ interrogeable (chemin en notation pointée : `specs.mass`).
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct FieldDescriptor {
+    pub path: String,
+    pub types: Vec<String>,
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enum_values: Option<Vec<JsonValue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Valeur calculée par le moteur (`x_compute` ou cible d'une règle `x_rules`)
+    pub computed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computed_by: Option<String>,
+    /// Cible déclarée par `x_ref` (`collection`, `db/collection` ou `space/db/collection`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ref_target: Option<String>,
+}
+
+/// Réponse de l'API d'autocomplétion pour une collection.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct CollectionFields {
+    pub collection: String,
+    pub schema_uri: Option<String>,
+    pub fields: Vec<FieldDescriptor>,
+}
+
+/// Décrit les champs d'une collection à partir de son schéma (`_meta.json` ou index système).
+/// Une collection sans schéma renvoie une liste vide plutôt qu'une erreur.
+pub async fn describe_collection(
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+) -> RaiseResult<CollectionFields> {
+    let Some(uri) = manager.collection_schema_uri(collection).await else {
+        return Ok(CollectionFields {
+            collection: collection.to_string(),
+            schema_uri: None,
+            fields: vec![],
+        });
+    };
+
+    let registry =
+        SchemaRegistry::from_uri(&manager.storage.config, &uri, &manager.space, &manager.db)
+            .await?;
+    let Some(schema) = registry.get_by_uri(&uri) else {
+        raise_error!(
+            "ERR_SCHEMA_NOT_FOUND",
+            error = format!("Schéma introuvable pour la collection '{}'", collection),
+            context = json_value!({ "collection": collection, "schema_uri": uri })
+        );
+    };
+
+    Ok(CollectionFields {
+        collection: collection.to_string(),
+        fields: describe_schema(schema, &registry, &uri),
+        schema_uri: Some(uri),
+    })
+}
+
+/// Aplatit un schéma en descripteurs de champs triés par chemin.
+pub fn describe_schema(
+    schema: &JsonValue,
+    registry: &SchemaRegistry,
+    uri: &str,
+) -> Vec<FieldDescriptor> {
+    let mut fields: OrderedMap<String, FieldDescriptor> = OrderedMap::new();
+    let mut rules = Vec::new();
+    walk(schema, registry, uri, "", 0, &mut fields, &mut rules);
+
+    // Les cibles des règles `x_rules` sont des champs calculés
+    for (target, handle) in rules {
+        let field = fields
+            .entry(target.clone())
+            .or_insert_with(|| FieldDescriptor {
+                path: target,
+                ..Default::default()
+            });
+        field.computed = true;
+        field.computed_by = Some(format!("x_rules:{}", handle));
+    }
+    fields.into_values().collect()
+}
+
+fn walk(
+    schema: &JsonValue,
+    registry: &SchemaRegistry,
+    uri: &str,
+    prefix: &str,
+    depth: usize,
+    out: &mut OrderedMap<String, FieldDescriptor>,
+    rules: &mut Vec<(String, String)>,
+) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+    let (node, node_uri) = resolve(schema, registry, uri);
+
+    if let Some(all_of) = node.get("allOf").and_then(|v| v.as_array()) {
+        for sub in all_of {
+            walk(sub, registry, &node_uri, prefix, depth + 1, out, rules);
+        }
+    }
+
+    for rule in node
+        .get("x_rules")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let target = rule
+            .get("target_path")
+            .or_else(|| rule.get("target"))
+            .and_then(|v| v.as_str());
+        let handle = rule
+            .get("handle")
+            .or_else(|| rule.get("id"))
+            .and_then(|v| v.as_str());
+        if let (Some(target), Some(handle)) = (target, handle) {
+            rules.push((join(prefix, target), handle.to_string()));
+        }
+    }
+
+    let required: Vec<&str> = node
+        .get("required")
+        .and_then(|v| v.as_array())
+        .map(|r| r.iter().filter_map(|f| f.as_str()).collect())
+        .unwrap_or_default();
+
+    let Some(props) = node.get("properties").and_then(|v| v.as_object()) else {
+        return;
+    };
+    for (key, raw) in props {
+        let path = join(prefix, key);
+        let (resolved, resolved_uri) = resolve(raw, registry, &node_uri);
+
+        let field = out.entry(path.clone()).or_insert_with(|| FieldDescriptor {
+            path: path.clone(),
+            ..Default::default()
+        });
+        field.required |= required.contains(&key.as_str());
+        merge_types(&mut field.types, &types_of(&resolved));
+        if let Some(values) = resolved
+            .get("enum")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .or_else(|| resolved.get("const").map(|c| vec![c.clone()]))
+        {
+            field.enum_values = Some(values);
+        }
+        if let Some(items) = resolved.get("items") {
+            let (items, _) = resolve(items, registry, &resolved_uri);
+            field.item_type = types_of(&items).into_iter().next();
+            if let Some(target) = items.get("x_ref").and_then(|v| v.as_str()) {
+                field.ref_target = Some(target.to_string());
+            }
+        }
+        if let Some(target) = raw
+            .get("x_ref")
+            .or_else(|| resolved.get("x_ref"))
+            .and_then(|v| v.as_str())
+        {
+            field.ref_target = Some(target.to_string());
+        }
+        if let Some(desc) = resolved.get("description").and_then(|v| v.as_str()) {
+            field.description = Some(desc.to_string());
+        }
+        if let Some(op) = raw
+            .get("x_compute")
+            .or_else(|| resolved.get("x_compute"))
+            .map(|c| {
+                c.pointer("/plan/op")
+                    .and_then(|o| o.as_str())
+                    .unwrap_or("plan")
+            })
+        {
+            field.computed = true;
+            field.computed_by = Some(format!("x_compute:{}", op));
+        }
+
+        if resolved.get("properties").is_some() || resolved.get("allOf").is_some() {
+            walk(
+                &resolved,
+                registry,
+                &resolved_uri,
+                &path,
+                depth + 1,
+                out,
+                rules,
+            );
+        }
+    }
+}
+
+/// Suit un `$ref` (local `#/...` ou relatif au schéma courant) ; renvoie le nœud et son URI.
+fn resolve(schema: &JsonValue, registry: &SchemaRegistry, uri: &str) -> (JsonValue, String) {
+    let Some(ref_str) = schema.get("$ref").and_then(|v| v.as_str()) else {
+        return (schema.clone(), uri.to_string());
+    };
+    let resolved = if ref_str.starts_with('#') {
+        format!("{}{}", uri, ref_str)
+    } else {
+        resolve_path_uri(uri, ref_str)
+    };
+    let (file_uri, fragment) = split_uri_fragment(&resolved);
+    let Some(root) = registry.get_by_uri(file_uri) else {
+        return (schema.clone(), uri.to_string());
+    };
+    let node = match fragment {
+        Some(frag) => root.pointer(&frag.replace('#', "")).unwrap_or(root),
+        None => root,
+    };
+    (node.clone(), file_uri.to_string())
+}
+
+fn types_of(schema: &JsonValue) -> Vec<String> {
+    match schema.get("type") {
+        Some(JsonValue::String(t)) => vec![t.clone()],
+        Some(JsonValue::Array(ts)) => ts
+            .iter()
+            .filter_map(|t| t.as_str().map(str::to_string))
+            .collect(),
+        _ if schema.get("properties").is_some() => vec!["object".into()],
+        _ if schema.get("items").is_some() => vec!["array".into()],
+        _ => vec![],
+    }
+}
+
+fn merge_types(target: &mut Vec<String>, extra: &[String]) {
+    for t in extra {
+        if !target.contains(t) {
+            target.push(t.clone());
+        }
+    }
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_describe_schema_flattens_refs_enums_and_computed() {
+        let base_uri = "db://s/d/schemas/v1/base.schema.json";
+        let uri = "db://s/d/schemas/v1/components.schema.json";
+        let mut registry = SchemaRegistry::new();
+        registry.register(
+            base_uri.into(),
+            json_value!({
+                "type": "object",
+                "required": ["_id"],
+                "properties": {
+                    "_id": { "type": "string", "x_compute": { "update": "if_missing", "plan": { "op": "uuid_v4" } } }
+                }
+            }),
+        );
+        registry.register(
+            uri.into(),
+            json_value!({
+                "allOf": [{ "$ref": "base.schema.json" }],
+                "type": "object",
+                "required": ["name"],
+                "x_rules": [{ "handle": "total_mass", "target_path": "specs.total", "expr": { "val": 0 } }],
+                "properties": {
+                    "name": { "type": "string" },
+                    "status": { "type": "string", "enum": ["draft", "validated"] },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "owner": { "type": "string", "x_ref": "users" },
+                    "parts": { "type": "array", "items": { "type": "string", "x_ref": "catalog/parts" } },
+                    "specs": { "$ref": "#/definitions/specs" }
+                },
+                "definitions": {
+                    "specs": { "type": "object", "properties": { "mass": { "type": ["number", "null"] } } }
+                }
+            }),
+        );
+
+        let fields = describe_schema(registry.get_by_uri(uri).unwrap(), &registry, uri);
+        let get = |p: &str| fields.iter().find(|f| f.path == p).unwrap();
+
+        assert!(get("_id").required);
+        assert_eq!(get("_id").computed_by.as_deref(), Some("x_compute:uuid_v4"));
+        assert!(get("name").required && !get("status").required);
+        assert_eq!(
+            get("status").enum_values,
+            Some(vec![json_value!("draft"), json_value!("validated")])
+        );
+        assert_eq!(get("tags").item_type.as_deref(), Some("string"));
+        assert_eq!(get("owner").ref_target.as_deref(), Some("users"));
+        assert_eq!(get("parts").ref_target.as_deref(), Some("catalog/parts"));
+        assert!(get("tags").ref_target.is_none());
+        assert_eq!(get("specs").types, vec!["object".to_string()]);
+        assert_eq!(get("specs.mass").types, vec!["number", "null"]);
+        assert_eq!(
+            get("specs.total").computed_by.as_deref(),
+            Some("x_rules:total_mass")
+        );
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_describe_collection_without_schema_is_empty() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        let info = describe_collection(&manager, "unknown_collection").await?;
+        assert!(info.schema_uri.is_none());
+        assert!(info.fields.is_empty());
+        Ok(())
+    }
+}
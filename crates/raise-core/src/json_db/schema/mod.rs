@@ -10,3 +10,6 @@ pub use validator::SchemaValidator;
 
 pub mod bootstrapper;
 pub mod ddl;
+pub mod introspection;
+pub mod references;
+pub mod strictness;
@@ -0,0 +1,320 @@
+// FICHIER : src-tauri/src/json_db/schema/references.rs
+
+//! Intégrité référentielle : champs déclarés `x_ref` dans les schémas, vérifiés à
+//! l'écriture (`prepare_document`) et contrôlés a posteriori par `check_refs`.
+//! Formes acceptées : `"x_ref": "users"` (même base), `"x_ref": "catalog/parts"`
+//! (autre base du domaine) ou `"x_ref": "space/db/collection"`.
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::collections::merge::MergeStrategy;
+use crate::json_db::schema::introspection::{self, FieldDescriptor};
+use crate::utils::prelude::*;
+
+/// Collection cible d'une référence, résolue par rapport à la base du document.
+#[derive(Debug, Clone, PartialEq, Eq, Serializable, Deserializable)]
+pub struct RefTarget {
+    pub space: String,
+    pub db: String,
+    pub collection: String,
+}
+
+impl RefTarget {
+    pub fn parse(declaration: &str, space: &str, db: &str) -> Self {
+        let parts: Vec<&str> = declaration.trim_matches('/').split('/').collect();
+        let (space, db, collection) = match parts.as_slice() {
+            [s, d, c] => (*s, *d, *c),
+            [d, c] => (space, *d, *c),
+            _ => (space, db, declaration),
+        };
+        Self {
+            space: space.to_string(),
+            db: db.to_string(),
+            collection: collection.to_string(),
+        }
+    }
+}
+
+/// Champ référence d'une collection (chemin pointé, comme l'introspection).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct RefField {
+    pub path: String,
+    pub target: RefTarget,
+}
+
+/// Référence dont le document cible n'existe pas.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct DanglingRef {
+    pub collection: String,
+    pub document_id: String,
+    pub field: String,
+    pub value: String,
+    pub target: RefTarget,
+    /// Vrai si `check_refs` a retiré la référence du document.
+    pub fixed: bool,
+}
+
+/// Rapport d'un contrôle d'intégrité sur une base entière.
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct RefCheckReport {
+    pub space: String,
+    pub db: String,
+    pub collections_scanned: usize,
+    pub documents_scanned: usize,
+    pub references_checked: usize,
+    pub dangling: Vec<DanglingRef>,
+}
+
+/// Extrait les champs `x_ref` de descripteurs d'introspection.
+pub fn ref_fields(fields: &[FieldDescriptor], space: &str, db: &str) -> Vec<RefField> {
+    fields
+        .iter()
+        .filter_map(|f| {
+            f.ref_target.as_deref().map(|decl| RefField {
+                path: f.path.clone(),
+                target: RefTarget::parse(decl, space, db),
+            })
+        })
+        .collect()
+}
+
+/// Références non résolues d'un document. Une valeur est soit un id (ou handle),
+/// soit un tableau d'ids ; `null` et champ absent sont ignorés.
+pub async fn find_dangling(
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+    doc: &JsonValue,
+    refs: &[RefField],
+) -> RaiseResult<Vec<DanglingRef>> {
+    let doc_id = doc.get("_id").and_then(|v| v.as_str()).unwrap_or_default();
+    let mut dangling = Vec::new();
+
+    for field in refs {
+        for value in ref_values(doc, &field.path) {
+            let is_self = field.target.collection == collection
+                && field.target.space == manager.space
+                && field.target.db == manager.db
+                && value == doc_id;
+            if is_self || target_exists(manager, &field.target, &value).await? {
+                continue;
+            }
+            dangling.push(DanglingRef {
+                collection: collection.to_string(),
+                document_id: doc_id.to_string(),
+                field: field.path.clone(),
+                value,
+                target: field.target.clone(),
+                fixed: false,
+            });
+        }
+    }
+    Ok(dangling)
+}
+
+/// Parcourt toutes les collections de la base et liste les références orphelines.
+/// Avec `fix`, les références sont retirées (champ supprimé, ou id retiré du tableau)
+/// et le document réécrit ; un document que le schéma refuse alors reste `fixed: false`.
+pub async fn check_refs(
+    manager: &CollectionsManager<'_>,
+    fix: bool,
+) -> RaiseResult<RefCheckReport> {
+    let mut report = RefCheckReport {
+        space: manager.space.clone(),
+        db: manager.db.clone(),
+        ..Default::default()
+    };
+
+    for collection in manager.list_collections().await? {
+        let described = introspection::describe_collection(manager, &collection).await?;
+        let refs = ref_fields(&described.fields, &manager.space, &manager.db);
+        report.collections_scanned += 1;
+        if refs.is_empty() {
+            continue;
+        }
+
+        for doc in manager.list_all(&collection).await? {
+            report.documents_scanned += 1;
+            report.references_checked += refs
+                .iter()
+                .map(|f| ref_values(&doc, &f.path).len())
+                .sum::<usize>();
+
+            let mut dangling = find_dangling(manager, &collection, &doc, &refs).await?;
+            if dangling.is_empty() {
+                continue;
+            }
+            if fix {
+                let mut cleaned = doc.clone();
+                for d in &dangling {
+                    remove_ref(&mut cleaned, &d.field, &d.value);
+                }
+                let id = dangling[0].document_id.clone();
+                match manager
+                    .update_document_with(&collection, &id, cleaned, Some(MergeStrategy::Replace))
+                    .await
+                {
+                    Ok(_) => dangling.iter_mut().for_each(|d| d.fixed = true),
+                    Err(e) => user_warn!(
+                        "WRN_DB_REF_FIX_REJECTED",
+                        json_value!({
+                            "collection": collection,
+                            "document_id": id,
+                            "technical_error": e.to_string()
+                        })
+                    ),
+                }
+            }
+            report.dangling.extend(dangling);
+        }
+    }
+    Ok(report)
+}
+
+async fn target_exists(
+    manager: &CollectionsManager<'_>,
+    target: &RefTarget,
+    value: &str,
+) -> RaiseResult<bool> {
+    if target.space == manager.space && target.db == manager.db {
+        return Ok(manager
+            .get_document(&target.collection, value)
+            .await?
+            .is_some());
+    }
+    let remote = CollectionsManager::new(manager.storage, &target.space, &target.db);
+    Ok(remote
+        .get_document(&target.collection, value)
+        .await?
+        .is_some())
+}
+
+fn ref_values(doc: &JsonValue, path: &str) -> Vec<String> {
+    let pointer = format!("/{}", path.replace('.', "/"));
+    match doc.pointer(&pointer) {
+        Some(JsonValue::String(id)) => vec![id.clone()],
+        Some(JsonValue::Array(items)) => items
+            .iter()
+            .filter_map(|i| i.as_str().map(str::to_string))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn remove_ref(doc: &mut JsonValue, path: &str, value: &str) {
+    let (parent, key) = match path.rsplit_once('.') {
+        Some((parent, key)) => (format!("/{}", parent.replace('.', "/")), key),
+        None => (String::new(), path),
+    };
+    let Some(obj) = doc.pointer_mut(&parent).and_then(|p| p.as_object_mut()) else {
+        return;
+    };
+    match obj.get_mut(key) {
+        Some(JsonValue::Array(items)) => items.retain(|i| i.as_str() != Some(value)),
+        Some(JsonValue::String(_)) => {
+            obj.remove(key);
+        }
+        _ => {}
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_db::schema::strictness::ValidationMode;
+    use crate::utils::testing::DbSandbox;
+
+    const GENERIC: &str = "db://_system/_system/schemas/v1/db/generic.schema.json";
+    const ORDER_SCHEMA: &str = "v1/db/orders.schema.json";
+
+    async fn setup(manager: &CollectionsManager<'_>) -> RaiseResult<()> {
+        DbSandbox::mock_db(manager).await?;
+        manager.create_collection("users", GENERIC).await?;
+        let schema = json_value!({
+            "type": "object",
+            "properties": {
+                "_id": { "type": "string" },
+                "customer": { "type": "string", "x_ref": "users" },
+                "parts": { "type": "array", "items": { "type": "string", "x_ref": "catalog/parts" } }
+            }
+        });
+        manager.create_schema_def(ORDER_SCHEMA, schema).await?;
+        let uri = manager.build_schema_uri(ORDER_SCHEMA).await;
+        manager.create_collection("orders", &uri).await
+    }
+
+    #[test]
+    fn test_parse_ref_target_forms() {
+        assert_eq!(
+            RefTarget::parse("users", "s", "d"),
+            RefTarget {
+                space: "s".into(),
+                db: "d".into(),
+                collection: "users".into()
+            }
+        );
+        assert_eq!(RefTarget::parse("catalog/parts", "s", "d").db, "catalog");
+        assert_eq!(RefTarget::parse("x/y/z", "s", "d").space, "x");
+    }
+
+    #[async_test]
+    async fn test_write_time_enforcement_and_check_refs_fix() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "refs");
+        setup(&manager).await?;
+        let catalog = CollectionsManager::new(&sandbox.storage, "test", "catalog");
+        DbSandbox::mock_db(&catalog).await?;
+        catalog.create_collection("parts", GENERIC).await?;
+        catalog
+            .insert_with_schema("parts", json_value!({ "_id": "p1" }))
+            .await?;
+        manager
+            .insert_with_schema("users", json_value!({ "_id": "u1" }))
+            .await?;
+
+        // Cible existante (même base et base voisine) : accepté
+        manager
+            .insert_with_schema(
+                "orders",
+                json_value!({ "_id": "o1", "customer": "u1", "parts": ["p1"] }),
+            )
+            .await?;
+        // Cible absente : refusé en mode strict
+        let err = manager
+            .insert_with_schema("orders", json_value!({ "_id": "o2", "customer": "ghost" }))
+            .await;
+        assert!(err.is_err());
+
+        // Les suppressions ultérieures laissent des références orphelines
+        manager
+            .set_collection_validation_mode("orders", ValidationMode::Warn)
+            .await?;
+        manager
+            .insert_with_schema(
+                "orders",
+                json_value!({ "_id": "o2", "customer": "ghost", "parts": ["p1", "p9"] }),
+            )
+            .await?;
+
+        let report = check_refs(&manager, false).await?;
+        assert_eq!(report.references_checked, 5);
+        let mut found: Vec<(&str, &str)> = report
+            .dangling
+            .iter()
+            .map(|d| (d.field.as_str(), d.value.as_str()))
+            .collect();
+        found.sort();
+        assert_eq!(found, vec![("customer", "ghost"), ("parts", "p9")]);
+        assert!(report.dangling.iter().all(|d| !d.fixed));
+
+        let fixed = check_refs(&manager, true).await?;
+        assert!(fixed.dangling.iter().all(|d| d.fixed));
+        let o2 = manager.get_document("orders", "o2").await?.unwrap();
+        assert!(o2.get("customer").is_none());
+        assert_eq!(o2["parts"], json_value!(["p1"]));
+        assert!(check_refs(&manager, false).await?.dangling.is_empty());
+        Ok(())
+    }
+}
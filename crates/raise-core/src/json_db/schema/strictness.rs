@@ -0,0 +1,202 @@
+// FICHIER : src-tauri/src/json_db/schema/strictness.rs
+
+//! Modes de validation par collection (`strict`, `warn`, `off`), stockés dans
+//! `_system.json` (`collections.<nom>.validation_mode`) et appliqués par `prepare_document`.
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::schema::{SchemaRegistry, SchemaValidator};
+use crate::utils::prelude::*;
+
+/// Clé de l'entrée collection de `_system.json` portant le mode.
+pub const SYSTEM_VALIDATION_MODE_KEY: &str = "validation_mode";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationMode {
+    /// Écriture refusée si le document viole le schéma (comportement historique).
+    #[default]
+    Strict,
+    /// Écart journalisé (`WRN_DB_SCHEMA_VALIDATION_WARN_MODE`), écriture acceptée.
+    Warn,
+    /// Pas de validation ; les défauts et `x_compute` restent appliqués.
+    Off,
+}
+
+impl ValidationMode {
+    pub fn parse(value: &str) -> RaiseResult<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "warn" => Ok(Self::Warn),
+            "off" => Ok(Self::Off),
+            _ => raise_error!(
+                "ERR_DB_VALIDATION_MODE_UNKNOWN",
+                error = format!("Mode de validation inconnu : '{}'", value),
+                context = json_value!({ "supported": ["strict", "warn", "off"] })
+            ),
+        }
+    }
+}
+
+/// Document existant qui ne passerait pas la validation.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct DocumentViolation {
+    pub id: String,
+    pub error: String,
+}
+
+/// Rapport de durcissement d'une collection.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct StrictnessReport {
+    pub collection: String,
+    pub previous_mode: ValidationMode,
+    pub mode: ValidationMode,
+    /// `false` en simulation (`dry_run`) : le mode n'a pas été modifié.
+    pub applied: bool,
+    pub checked: usize,
+    pub violations: Vec<DocumentViolation>,
+}
+
+/// Passe la collection en `mode` et liste les documents existants qui échoueraient
+/// à la validation. Les documents ne sont pas modifiés ; en `dry_run`, le mode non plus.
+pub async fn tighten_collection(
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+    mode: ValidationMode,
+    dry_run: bool,
+) -> RaiseResult<StrictnessReport> {
+    let previous_mode = manager.collection_validation_mode(collection).await;
+    let mut report = StrictnessReport {
+        collection: collection.to_string(),
+        previous_mode,
+        mode,
+        applied: false,
+        checked: 0,
+        violations: vec![],
+    };
+
+    if mode != ValidationMode::Off {
+        let Some(uri) = manager.collection_schema_uri(collection).await else {
+            raise_error!(
+                "ERR_DB_STRICT_SCHEMA_REQUIRED",
+                error = format!(
+                    "La collection '{}' n'a pas de schéma : seul le mode 'off' est possible.",
+                    collection
+                ),
+                context = json_value!({ "collection": collection, "requested_mode": mode })
+            );
+        };
+        let registry =
+            SchemaRegistry::from_uri(&manager.storage.config, &uri, &manager.space, &manager.db)
+                .await?;
+        let validator = SchemaValidator::compile_with_registry(&uri, &registry)?;
+
+        for doc in manager.list_all(collection).await? {
+            report.checked += 1;
+            if let Err(e) = validator.validate(&doc) {
+                report.violations.push(DocumentViolation {
+                    id: doc
+                        .get("_id")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("?")
+                        .to_string(),
+                    error: e.to_string(),
+                });
+            }
+        }
+    }
+
+    if !dry_run {
+        manager
+            .set_collection_validation_mode(collection, mode)
+            .await?;
+        report.applied = true;
+    }
+    Ok(report)
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    const STRICT_SCHEMA: &str = "v1/db/strict_items.schema.json";
+
+    async fn setup(manager: &CollectionsManager<'_>) -> RaiseResult<()> {
+        DbSandbox::mock_db(manager).await?;
+        let schema = json_value!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "_id": { "type": "string" },
+                "name": { "type": "string" }
+            }
+        });
+        manager.create_schema_def(STRICT_SCHEMA, schema).await?;
+        let uri = manager.build_schema_uri(STRICT_SCHEMA).await;
+        manager.create_collection("items", &uri).await
+    }
+
+    #[async_test]
+    async fn test_warn_and_off_modes_accept_invalid_documents() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "strictness");
+        setup(&manager).await?;
+
+        assert_eq!(
+            manager.collection_validation_mode("items").await,
+            ValidationMode::Strict
+        );
+        assert!(manager
+            .insert_with_schema("items", json_value!({ "_id": "bad1" }))
+            .await
+            .is_err());
+
+        manager
+            .set_collection_validation_mode("items", ValidationMode::Warn)
+            .await?;
+        manager
+            .insert_with_schema("items", json_value!({ "_id": "bad1" }))
+            .await?;
+
+        manager
+            .set_collection_validation_mode("items", ValidationMode::Off)
+            .await?;
+        manager
+            .insert_with_schema("items", json_value!({ "_id": "bad2", "name": 42 }))
+            .await?;
+        manager
+            .insert_with_schema("items", json_value!({ "_id": "ok", "name": "Pump" }))
+            .await?;
+
+        // Durcissement simulé : rapport sans changement de mode
+        let report = tighten_collection(&manager, "items", ValidationMode::Strict, true).await?;
+        assert!(!report.applied);
+        assert_eq!(report.previous_mode, ValidationMode::Off);
+        assert_eq!(report.checked, 3);
+        let mut failing: Vec<&str> = report.violations.iter().map(|v| v.id.as_str()).collect();
+        failing.sort();
+        assert_eq!(failing, vec!["bad1", "bad2"]);
+        assert_eq!(
+            manager.collection_validation_mode("items").await,
+            ValidationMode::Off
+        );
+
+        let report = tighten_collection(&manager, "items", ValidationMode::Strict, false).await?;
+        assert!(report.applied);
+        assert_eq!(
+            manager.collection_validation_mode("items").await,
+            ValidationMode::Strict
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_validation_mode() {
+        assert_eq!(ValidationMode::parse("WARN").unwrap(), ValidationMode::Warn);
+        assert!(ValidationMode::parse("lenient").is_err());
+    }
+}
@@ -31,6 +31,16 @@ impl SchemaValidator {
         &self,
         instance: &mut JsonValue,
         compute_ctx: &ComputeContext,
+    ) -> RaiseResult<()> {
+        self.compute(instance, compute_ctx).await?;
+        self.validate(instance)
+    }
+
+    /// Applique défauts et `x_compute` sans valider (modes `warn` / `off`).
+    pub async fn compute(
+        &self,
+        instance: &mut JsonValue,
+        compute_ctx: &ComputeContext,
     ) -> RaiseResult<()> {
         apply_defaults(
             instance,
@@ -39,9 +49,7 @@ impl SchemaValidator {
             &self.root_uri,
             compute_ctx,
         )
-        .await?;
-
-        self.validate(instance)
+        .await
     }
 
     pub fn validate(&self, instance: &JsonValue) -> RaiseResult<()> {
@@ -508,7 +516,7 @@ fn validate_array(
     Ok(())
 }
 
-fn split_uri_fragment(uri: &str) -> (&str, Option<&str>) {
+pub(crate) fn split_uri_fragment(uri: &str) -> (&str, Option<&str>) {
     if let Some(idx) = uri.find('#') {
         (&uri[0..idx], Some(&uri[idx..]))
     } else {
@@ -516,7 +524,7 @@ fn split_uri_fragment(uri: &str) -> (&str, Option<&str>) {
     }
 }
 
-fn resolve_path_uri(base: &str, target_path: &str) -> String {
+pub(crate) fn resolve_path_uri(base: &str, target_path: &str) -> String {
     if target_path.starts_with("db://") {
         return target_path.to_string();
     }
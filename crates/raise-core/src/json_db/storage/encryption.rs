@@ -0,0 +1,248 @@
+// FICHIER : src-tauri/src/json_db/storage/encryption.rs
+
+//! Chiffrement au repos (AES-256-GCM) des fichiers JSON-DB : documents, `_system.json`,
+//! journal WAL et index compressés. Un fichier scellé commence par `SEALED_MAGIC`, suivi du
+//! nonce puis du texte chiffré. Les fichiers en clair restent lisibles (migration progressive).
+
+use crate::json_db::storage::JsonDbConfig;
+use crate::utils::core::CryptoAead::{Aead, Aes256Gcm, KeyInit, Nonce};
+use crate::utils::data::encoding::decode_base64;
+use crate::utils::data::secrets::{SecretsProvider, SECRET_REF_PREFIX};
+use crate::utils::prelude::*;
+
+/// En-tête des fichiers scellés (version du format incluse).
+const SEALED_MAGIC: &[u8] = b"RAISE-ENC1\n";
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+/// Sel de dérivation d'une passphrase en clé AES-256.
+const KEY_DERIVATION_SALT: &[u8] = b"raise.json_db.storage";
+
+/// Clé de chiffrement du stockage (jamais sérialisée ni journalisée).
+#[derive(Clone)]
+pub struct StorageCipher {
+    key: SharedRef<[u8; KEY_LEN]>,
+}
+
+impl std::fmt::Debug for StorageCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StorageCipher(***)")
+    }
+}
+
+impl StorageCipher {
+    /// Clé brute (32 octets en base64) ou passphrase, dérivée par SHA-256.
+    pub fn from_key_material(material: &str) -> RaiseResult<Self> {
+        let material = material.trim();
+        if material.is_empty() {
+            raise_error!(
+                "ERR_DB_ENCRYPTION_KEY_EMPTY",
+                error = "La clé de chiffrement du stockage est vide."
+            );
+        }
+
+        let mut key = [0u8; KEY_LEN];
+        match decode_base64(material).ok().filter(|k| k.len() == KEY_LEN) {
+            Some(raw) => key.copy_from_slice(&raw),
+            None => {
+                let mut hasher = CryptoSha256::new();
+                hasher.update(KEY_DERIVATION_SALT);
+                hasher.update(material.as_bytes());
+                key.copy_from_slice(&hasher.finalize());
+            }
+        }
+        Ok(Self {
+            key: SharedRef::new(key),
+        })
+    }
+
+    /// Référence de trousseau (`secret:<nom>`, résolue par le `SecretsProvider`) ou clé directe.
+    pub async fn from_reference(reference: &str) -> RaiseResult<Self> {
+        match reference.trim().strip_prefix(SECRET_REF_PREFIX) {
+            Some(name) => {
+                let material = SecretsProvider::global().require(name).await?;
+                Self::from_key_material(&material)
+            }
+            None => Self::from_key_material(reference),
+        }
+    }
+
+    fn cipher(&self) -> RaiseResult<Aes256Gcm> {
+        match Aes256Gcm::new_from_slice(self.key.as_ref()) {
+            Ok(c) => Ok(c),
+            Err(e) => raise_error!("ERR_DB_ENCRYPTION_CRYPTO", error = e.to_string()),
+        }
+    }
+
+    pub fn seal(&self, plain: &[u8]) -> RaiseResult<Vec<u8>> {
+        let nonce_bytes = &UniqueId::new_v4().into_bytes()[..NONCE_LEN];
+        let ciphertext = match self
+            .cipher()?
+            .encrypt(Nonce::from_slice(nonce_bytes), plain)
+        {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_DB_ENCRYPTION_CRYPTO", error = e.to_string()),
+        };
+        let mut sealed = Vec::with_capacity(SEALED_MAGIC.len() + NONCE_LEN + ciphertext.len());
+        sealed.extend_from_slice(SEALED_MAGIC);
+        sealed.extend_from_slice(nonce_bytes);
+        sealed.extend_from_slice(&ciphertext);
+        Ok(sealed)
+    }
+
+    /// Déchiffre un contenu scellé ; un contenu en clair est rendu tel quel.
+    pub fn open(&self, data: &[u8]) -> RaiseResult<Vec<u8>> {
+        let Some(body) = data.strip_prefix(SEALED_MAGIC) else {
+            return Ok(data.to_vec());
+        };
+        if body.len() < NONCE_LEN {
+            raise_error!(
+                "ERR_DB_ENCRYPTION_CORRUPTED",
+                error = "Fichier chiffré tronqué."
+            );
+        }
+        let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+        match self.cipher()?.decrypt(Nonce::from_slice(nonce), ciphertext) {
+            Ok(plain) => Ok(plain),
+            Err(_) => raise_error!(
+                "ERR_DB_ENCRYPTION_KEY_MISMATCH",
+                error = "Déchiffrement impossible : clé incorrecte ou fichier altéré."
+            ),
+        }
+    }
+}
+
+pub fn is_sealed(data: &[u8]) -> bool {
+    data.starts_with(SEALED_MAGIC)
+}
+
+fn open_with(config: &JsonDbConfig, path: &Path, data: Vec<u8>) -> RaiseResult<Vec<u8>> {
+    match (&config.encryption, is_sealed(&data)) {
+        (Some(cipher), true) => match cipher.open(&data) {
+            Ok(plain) => Ok(plain),
+            Err(e) => raise_error!(
+                "ERR_DB_ENCRYPTION_READ_FAILED",
+                error = e,
+                context = json_value!({ "path": path.to_string_lossy() })
+            ),
+        },
+        (None, true) => raise_error!(
+            "ERR_DB_ENCRYPTION_KEY_MISSING",
+            error = "Fichier chiffré mais aucune clé n'est configurée pour ce stockage.",
+            context = json_value!({ "path": path.to_string_lossy() })
+        ),
+        (_, false) => Ok(data),
+    }
+}
+
+// --- E/S TRANSPARENTES ---
+
+/// Contenu en clair d'un fichier du stockage (déchiffré s'il est scellé).
+pub async fn read_plain(config: &JsonDbConfig, path: &Path) -> RaiseResult<Vec<u8>> {
+    open_with(config, path, fs::read_async(path).await?)
+}
+
+/// `fs::write_json_atomic_async`, scellé si le stockage est chiffré.
+pub async fn write_json_atomic<T: Serializable>(
+    config: &JsonDbConfig,
+    path: &Path,
+    data: &T,
+) -> RaiseResult<()> {
+    match &config.encryption {
+        Some(cipher) => {
+            let content = json::serialize_to_bytes(data)?;
+            fs::write_atomic_async(path, &cipher.seal(&content)?).await
+        }
+        None => fs::write_json_atomic_async(path, data).await,
+    }
+}
+
+/// `fs::read_json_async`, qui accepte fichiers scellés et fichiers en clair.
+pub async fn read_json<T: DeserializableOwned>(
+    config: &JsonDbConfig,
+    path: &Path,
+) -> RaiseResult<T> {
+    match String::from_utf8(read_plain(config, path).await?) {
+        Ok(content) => json::deserialize_from_str(&content),
+        Err(e) => raise_error!(
+            "ERR_DATA_CORRUPTION_UTF8",
+            error = e,
+            context = json_value!({ "path": path.to_string_lossy() })
+        ),
+    }
+}
+
+/// `fs::write_json_compressed_atomic_async` : compression puis scellement.
+pub async fn write_json_compressed_atomic<T: Serializable>(
+    config: &JsonDbConfig,
+    path: &Path,
+    data: &T,
+) -> RaiseResult<()> {
+    match &config.encryption {
+        Some(cipher) => {
+            let compressed = compress(&json::serialize_to_bytes(data)?)?;
+            fs::write_atomic_async(path, &cipher.seal(&compressed)?).await
+        }
+        None => fs::write_json_compressed_atomic_async(path, data).await,
+    }
+}
+
+pub async fn read_json_compressed<T: DeserializableOwned>(
+    config: &JsonDbConfig,
+    path: &Path,
+) -> RaiseResult<T> {
+    let decompressed = decompress(&read_plain(config, path).await?)?;
+    match String::from_utf8(decompressed) {
+        Ok(content) => json::deserialize_from_str(&content),
+        Err(e) => raise_error!("ERR_DATA_CORRUPTION_UTF8", error = e),
+    }
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_open_roundtrip() -> RaiseResult<()> {
+        let cipher = StorageCipher::from_key_material("correct horse battery staple")?;
+        let sealed = cipher.seal(b"{\"salary\":42}")?;
+
+        assert!(is_sealed(&sealed));
+        assert!(!sealed.windows(6).any(|w| w == b"salary"));
+        assert_eq!(cipher.open(&sealed)?, b"{\"salary\":42}");
+        assert_eq!(cipher.open(b"{\"plain\":true}")?, b"{\"plain\":true}");
+
+        let other = StorageCipher::from_key_material("another passphrase")?;
+        assert!(other.open(&sealed).is_err());
+        assert!(StorageCipher::from_key_material("  ").is_err());
+        assert_eq!(format!("{:?}", cipher), "StorageCipher(***)");
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_transparent_io_requires_key_for_sealed_files() -> RaiseResult<()> {
+        let dir = tempdir()?;
+        let plain_cfg = JsonDbConfig::new(dir.path().to_path_buf());
+        let cfg = plain_cfg.clone().with_encryption_key("hr-data-key")?;
+        let doc = json_value!({ "name": "Alice", "salary": 42 });
+
+        let path = dir.path().join("doc.json");
+        write_json_atomic(&cfg, &path, &doc).await?;
+        assert!(is_sealed(&fs::read_async(&path).await?));
+        assert_eq!(read_json::<JsonValue>(&cfg, &path).await?, doc);
+        assert!(read_json::<JsonValue>(&plain_cfg, &path).await.is_err());
+
+        let idx = dir.path().join("doc.idx");
+        write_json_compressed_atomic(&cfg, &idx, &doc).await?;
+        assert_eq!(read_json_compressed::<JsonValue>(&cfg, &idx).await?, doc);
+
+        // Fichiers antérieurs au chiffrement : toujours lisibles
+        let legacy = dir.path().join("legacy.json");
+        fs::write_json_atomic_async(&legacy, &doc).await?;
+        assert_eq!(read_json::<JsonValue>(&cfg, &legacy).await?, doc);
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 // FICHIER : src-tauri/src/json_db/storage/file_storage.rs
 
-use crate::json_db::storage::JsonDbConfig;
+use crate::json_db::storage::{encryption, JsonDbConfig};
+use crate::utils::data::json::replace_uri_in_json;
 use crate::utils::prelude::*;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -125,6 +126,172 @@ pub async fn drop_db(
     Ok(())
 }
 
+/// Préfixe des URIs internes d'une base (`db://space/db/`), remappé lors d'une copie.
+pub fn db_uri_prefix(space: &str, db: &str) -> String {
+    format!("db://{}/{}/", space, db)
+}
+
+/// Clone physique d'une base sous de nouvelles coordonnées : documents, index, schémas et
+/// `_system.json`, sans le journal WAL ni les fichiers temporaires. Les URIs internes des
+/// fichiers JSON (`db://src_space/src_db/…`) sont réécrites vers la cible.
+/// Retourne le nombre de fichiers copiés.
+pub async fn clone_db(
+    config: &JsonDbConfig,
+    src_space: &str,
+    src_db: &str,
+    dst_space: &str,
+    dst_db: &str,
+) -> RaiseResult<usize> {
+    let src_root = config.db_root(src_space, src_db);
+    let dst_root = config.db_root(dst_space, dst_db);
+    if !fs::exists_async(&src_root.join("_system.json")).await {
+        raise_error!(
+            "ERR_DB_NOT_FOUND",
+            error = format!("La base source '{}/{}' n'existe pas.", src_space, src_db),
+            context = json_value!({ "action": "clone_db" })
+        );
+    }
+    if fs::exists_async(&dst_root).await {
+        raise_error!(
+            "ERR_DB_ALREADY_EXISTS",
+            error = format!("La base cible '{}/{}' existe déjà.", dst_space, dst_db),
+            context = json_value!({
+                "action": "clone_db",
+                "hint": "Choisissez un autre nom ou supprimez d'abord la base cible."
+            })
+        );
+    }
+
+    let copied = copy_tree(
+        config,
+        &src_root,
+        &dst_root,
+        &db_uri_prefix(src_space, src_db),
+        &db_uri_prefix(dst_space, dst_db),
+        &["wal"],
+    )
+    .await;
+    let copied = match copied {
+        Ok(count) => count,
+        Err(e) => {
+            // Pas de base cible à moitié copiée
+            let _ = fs::remove_dir_all_async(&dst_root).await;
+            return Err(e);
+        }
+    };
+
+    // Acte de naissance de la copie
+    if let Some(mut index) = read_system_index(config, dst_space, dst_db).await? {
+        let identity = format!("{}_{}", dst_space, dst_db);
+        for (key, value) in [
+            ("handle", identity.as_str()),
+            ("name", identity.as_str()),
+            ("space", dst_space),
+            ("domain", dst_space),
+            ("database", dst_db),
+        ] {
+            if index.get(key).is_some() {
+                index[key] = json_value!(value);
+            }
+        }
+        write_system_index(config, dst_space, dst_db, &index).await?;
+    }
+    Ok(copied)
+}
+
+/// Copie les fichiers d'une collection (documents, `_meta.json`, index) vers une autre base
+/// ou sous un autre nom. L'inscription dans `_system.json` de la cible reste à la charge de
+/// l'appelant (`CollectionsManager::copy_collection`).
+pub async fn copy_collection(
+    config: &JsonDbConfig,
+    (src_space, src_db, collection): (&str, &str, &str),
+    (dst_space, dst_db, dst_collection): (&str, &str, &str),
+) -> RaiseResult<usize> {
+    let src_path = config.db_collection_path(src_space, src_db, collection);
+    let dst_path = config.db_collection_path(dst_space, dst_db, dst_collection);
+    if !fs::exists_async(&src_path).await {
+        raise_error!(
+            "ERR_DB_COLLECTION_NOT_FOUND",
+            error = format!("La collection '{}' n'existe pas.", collection),
+            context = json_value!({ "space": src_space, "db": src_db })
+        );
+    }
+    if fs::exists_async(&dst_path).await {
+        raise_error!(
+            "ERR_DB_COLLECTION_ALREADY_EXISTS",
+            error = format!(
+                "La collection '{}' existe déjà dans '{}/{}'.",
+                dst_collection, dst_space, dst_db
+            ),
+            context = json_value!({ "action": "copy_collection" })
+        );
+    }
+    copy_tree(
+        config,
+        &src_path,
+        &dst_path,
+        &db_uri_prefix(src_space, src_db),
+        &db_uri_prefix(dst_space, dst_db),
+        &[],
+    )
+    .await
+}
+
+/// Copie récursive : les JSON en clair sont relus pour remapper leurs URIs (puis rescellés
+/// si le stockage est chiffré), les autres fichiers sont copiés tels quels.
+async fn copy_tree(
+    config: &JsonDbConfig,
+    src_root: &Path,
+    dst_root: &Path,
+    from_prefix: &str,
+    to_prefix: &str,
+    skip_dirs: &[&str],
+) -> RaiseResult<usize> {
+    fs::create_dir_all_async(dst_root).await?;
+    let files: Vec<PathBuf> = fs::WalkDir::new(src_root)
+        .into_iter()
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect();
+
+    let mut copied = 0;
+    for path in files {
+        let Ok(rel) = path.strip_prefix(src_root) else {
+            continue;
+        };
+        let skipped = rel
+            .components()
+            .next()
+            .is_some_and(|c| skip_dirs.contains(&c.as_os_str().to_string_lossy().as_ref()));
+        let temporary = path
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().contains(".tmp."));
+        if skipped || temporary {
+            continue;
+        }
+
+        let target = dst_root.join(rel);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all_async(parent).await?;
+        }
+        if path.extension().and_then(|e| e.to_str()) == Some("json") {
+            let plain = encryption::read_plain(config, &path).await?;
+            if let Ok(mut value) = json::deserialize_from_bytes::<JsonValue>(&plain) {
+                if from_prefix != to_prefix {
+                    replace_uri_in_json(&mut value, from_prefix, to_prefix);
+                }
+                encryption::write_json_atomic(config, &target, &value).await?;
+                copied += 1;
+                continue;
+            }
+        }
+        fs::copy_async(&path, &target).await?;
+        copied += 1;
+    }
+    Ok(copied)
+}
+
 pub async fn write_document(
     config: &JsonDbConfig,
     space: &str,
@@ -144,8 +311,8 @@ pub async fn write_document(
         ),
     }
     let file_path = col_path.join(format!("{}.json", id));
-    // 2. Écriture Atomique (Zéro Corruption)
-    match fs::write_json_atomic_async(&file_path, document).await {
+    // 2. Écriture Atomique (Zéro Corruption), scellée si le stockage est chiffré
+    match encryption::write_json_atomic(config, &file_path, document).await {
         Ok(_) => Ok(()),
         Err(e) => raise_error!(
             "ERR_FS_WRITE_DOC_FAILED",
@@ -170,7 +337,7 @@ pub async fn read_document(
         return Ok(None);
     }
 
-    match fs::read_json_async(&file_path).await {
+    match encryption::read_json(config, &file_path).await {
         Ok(doc) => Ok(Some(doc)),
         Err(e) => raise_error!(
             "ERR_FS_READ_DOC_FAILED",
@@ -226,7 +393,7 @@ pub async fn read_system_index(
 ) -> RaiseResult<Option<JsonValue>> {
     let sys_path = config.db_root(space, db).join("_system.json");
     match fs::exists_async(&sys_path).await {
-        true => match fs::read_json_async(&sys_path).await {
+        true => match encryption::read_json(config, &sys_path).await {
             Ok(index_doc) => Ok(Some(index_doc)),
             Err(e) => raise_error!(
                 "ERR_FS_READ_INDEX_FAILED",
@@ -245,7 +412,7 @@ pub async fn write_system_index(
     index_doc: &JsonValue,
 ) -> RaiseResult<()> {
     let sys_path = config.db_root(space, db).join("_system.json");
-    match fs::write_json_atomic_async(&sys_path, index_doc).await {
+    match encryption::write_json_atomic(config, &sys_path, index_doc).await {
         Ok(_) => Ok(()),
         Err(e) => raise_error!(
             "ERR_FS_WRITE_INDEX_FAILED",
@@ -1,25 +1,85 @@
 // FICHIER : src-tauri/src/json_db/storage/mod.rs
+use crate::json_db::collections::ttl::TtlPolicy;
 use crate::utils::prelude::*;
 
 pub mod cache;
+pub mod encryption;
 pub mod file_storage;
 
+use encryption::StorageCipher;
+
+/// Variable d'environnement portant la clé (ou la référence `secret:<nom>`) du chiffrement au repos.
+pub const STORAGE_ENCRYPTION_ENV: &str = "RAISE_STORAGE_ENCRYPTION_KEY";
+
 // --- CONFIGURATION ---
 
 #[derive(Debug, Clone, Serializable, Deserializable)]
 pub struct JsonDbConfig {
     pub data_root: PathBuf,
+    /// Chiffrement au repos (AES-256-GCM) ; `None` = fichiers en clair.
+    #[serde(skip)]
+    pub encryption: Option<StorageCipher>,
+    /// Seuils du checkpoint automatique du journal (WAL).
+    #[serde(default)]
+    pub wal_checkpoint: WalCheckpointPolicy,
+}
+
+/// Un checkpoint replie les transactions validées du WAL dès que leurs entrées dépassent
+/// `max_bytes` ou que la plus ancienne a plus de `max_age_secs` (vérifié après chaque commit).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(default)]
+pub struct WalCheckpointPolicy {
+    pub enabled: bool,
+    pub max_bytes: u64,
+    pub max_age_secs: u64,
+}
+
+impl Default for WalCheckpointPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_bytes: 4 * 1024 * 1024,
+            max_age_secs: 300,
+        }
+    }
 }
 
 impl JsonDbConfig {
     pub fn new(data_root: PathBuf) -> Self {
-        Self { data_root }
+        Self {
+            data_root,
+            encryption: None,
+            wal_checkpoint: WalCheckpointPolicy::default(),
+        }
+    }
+
+    pub fn with_wal_checkpoint(mut self, policy: WalCheckpointPolicy) -> Self {
+        self.wal_checkpoint = policy;
+        self
     }
 
     pub fn from(path_str: String) -> RaiseResult<Self> {
-        Ok(Self {
-            data_root: PathBuf::from(path_str),
-        })
+        Ok(Self::new(PathBuf::from(path_str)))
+    }
+
+    /// Chiffre le stockage avec une clé brute (base64, 32 octets) ou une passphrase.
+    pub fn with_encryption_key(mut self, material: &str) -> RaiseResult<Self> {
+        self.encryption = Some(StorageCipher::from_key_material(material)?);
+        Ok(self)
+    }
+
+    /// Chiffre le stockage si une référence est fournie (`secret:<nom>` du trousseau, ou clé directe).
+    pub async fn with_encryption_ref(mut self, reference: Option<&str>) -> RaiseResult<Self> {
+        if let Some(reference) = reference.filter(|r| !r.trim().is_empty()) {
+            self.encryption = Some(StorageCipher::from_reference(reference).await?);
+        }
+        Ok(self)
+    }
+
+    /// `with_encryption_ref` alimenté par `RAISE_STORAGE_ENCRYPTION_KEY` (stockage en clair si absente).
+    pub async fn with_encryption_from_env(self) -> RaiseResult<Self> {
+        let reference = crate::utils::core::RuntimeEnv::var(STORAGE_ENCRYPTION_ENV).ok();
+        self.with_encryption_ref(reference.as_deref()).await
     }
 
     pub fn db_root(&self, space: &str, db: &str) -> PathBuf {
@@ -83,6 +143,35 @@ impl StorageEngine {
         Ok(lock)
     }
 
+    /// Verrou d'écriture d'une collection : sérialise la vérification de révision (`_rev`)
+    /// et l'écriture du document. Distinct du verrou d'index de la base.
+    pub fn get_collection_lock(
+        &self,
+        space: &str,
+        db: &str,
+        collection: &str,
+    ) -> RaiseResult<SharedRef<AsyncMutex<()>>> {
+        self.get_index_lock(space, &format!("{}/{}", db, collection))
+    }
+
+    /// Lance le balayage périodique des documents expirés d'une base (politiques `ttl` des
+    /// collections, `defaults` pour celles qui n'en déclarent pas).
+    pub fn spawn_ttl_sweeper(
+        &self,
+        space: &str,
+        db: &str,
+        interval: TimeDuration,
+        defaults: OrderedMap<String, TtlPolicy>,
+    ) -> tokio::task::JoinHandle<()> {
+        crate::json_db::collections::ttl::spawn_ttl_sweeper(
+            self.clone(),
+            space.to_string(),
+            db.to_string(),
+            interval,
+            defaults,
+        )
+    }
+
     /// Lit un document en cherchant d'abord dans le cache LRU
     pub async fn read_document(
         &self,
@@ -206,6 +295,9 @@ impl StorageEngine {
                             .await?;
 
                         total_recovered += count;
+                        // Transactions validées avant l'arrêt : index système réconcilié
+                        crate::json_db::transactions::wal::checkpoint(self, &space_name, &db_name)
+                            .await?;
                     }
                 }
             }
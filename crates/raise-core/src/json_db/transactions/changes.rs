@@ -0,0 +1,198 @@
+// FICHIER : src-tauri/src/json_db/transactions/changes.rs
+
+//! Flux de changements des collections : chaque écriture validée (transaction WAL ou
+//! écriture directe du `CollectionsManager`) est diffusée aux abonnés de la collection.
+
+use crate::utils::prelude::*;
+
+/// Capacité du canal de diffusion (un abonné trop lent reçoit un événement `Resync`).
+const CHANGE_FEED_CAPACITY: usize = 1024;
+
+static CHANGE_FEED: StaticCell<AsyncBroadcast::Sender<ChangeEvent>> = StaticCell::new();
+
+/// Flux d'événements d'une collection (`stream.next().await`).
+pub type ChangeStream = Pinned<Box<dyn AsyncStream<Item = ChangeEvent> + Send>>;
+
+#[derive(Debug, Clone, Copy, Serializable, Deserializable, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Insert,
+    Update,
+    Delete,
+    /// Des événements ont été perdus (abonné trop lent) : la vue doit recharger la collection.
+    Resync,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct ChangeEvent {
+    pub space: String,
+    pub db: String,
+    pub collection: String,
+    pub kind: ChangeKind,
+    /// Identifiant du document (vide pour `Resync`).
+    pub id: String,
+    /// Document après écriture (`None` pour une suppression).
+    pub document: Option<JsonValue>,
+    /// Transaction WAL d'origine (`None` pour une écriture directe).
+    pub tx_id: Option<String>,
+    pub timestamp: i64,
+}
+
+impl ChangeEvent {
+    pub fn new(
+        space: &str,
+        db: &str,
+        collection: &str,
+        kind: ChangeKind,
+        id: &str,
+        document: Option<JsonValue>,
+    ) -> Self {
+        Self {
+            space: space.to_string(),
+            db: db.to_string(),
+            collection: collection.to_string(),
+            kind,
+            id: id.to_string(),
+            document,
+            tx_id: None,
+            timestamp: UtcClock::now().timestamp_millis(),
+        }
+    }
+
+    /// Rattache l'événement à la transaction WAL qui l'a produit.
+    pub fn in_transaction(mut self, tx_id: &str) -> Self {
+        self.tx_id = Some(tx_id.to_string());
+        self
+    }
+
+    /// `collection` à `None` : toute collection de la base.
+    fn targets(&self, space: &str, db: &str, collection: Option<&str>) -> bool {
+        self.space == space && self.db == db && collection.is_none_or(|c| self.collection == c)
+    }
+}
+
+fn feed() -> &'static AsyncBroadcast::Sender<ChangeEvent> {
+    CHANGE_FEED.get_or_init(|| AsyncBroadcast::channel(CHANGE_FEED_CAPACITY).0)
+}
+
+/// Diffuse un changement. Sans abonné, l'événement est simplement ignoré.
+pub fn publish_change(event: ChangeEvent) {
+    let _ = feed().send(event);
+}
+
+/// Ouvre un flux sur les changements de `collection` publiés après l'appel.
+pub fn watch(space: &str, db: &str, collection: &str) -> ChangeStream {
+    subscribe(space, db, Some(collection.to_string()))
+}
+
+/// Ouvre un flux sur les changements de toutes les collections de la base, y compris
+/// celles créées après l'appel. Un abonné trop lent reçoit un `Resync` sans collection.
+pub fn watch_db(space: &str, db: &str) -> ChangeStream {
+    subscribe(space, db, None)
+}
+
+fn subscribe(space: &str, db: &str, collection: Option<String>) -> ChangeStream {
+    let rx = feed().subscribe();
+    let scope = (space.to_string(), db.to_string(), collection);
+
+    Box::pin(futures::stream::unfold(
+        (rx, scope),
+        |(mut rx, scope)| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) if event.targets(&scope.0, &scope.1, scope.2.as_deref()) => {
+                        return Some((event, (rx, scope)));
+                    }
+                    Ok(_) => continue,
+                    Err(AsyncBroadcast::RecvError::Lagged(skipped)) => {
+                        let collection = scope.2.clone().unwrap_or_default();
+                        user_warn!(
+                            "WRN_DB_CHANGE_STREAM_LAGGED",
+                            json_value!({ "collection": collection, "skipped": skipped })
+                        );
+                        let event = ChangeEvent::new(
+                            &scope.0,
+                            &scope.1,
+                            &collection,
+                            ChangeKind::Resync,
+                            "",
+                            None,
+                        );
+                        return Some((event, (rx, scope)));
+                    }
+                    Err(AsyncBroadcast::RecvError::Closed) => return None,
+                }
+            }
+        },
+    ))
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_test]
+    async fn test_watch_filters_on_collection() {
+        let mut stream = watch("changes_test", "db", "users");
+
+        publish_change(ChangeEvent::new(
+            "changes_test",
+            "db",
+            "other",
+            ChangeKind::Insert,
+            "x",
+            None,
+        ));
+        publish_change(
+            ChangeEvent::new(
+                "changes_test",
+                "db",
+                "users",
+                ChangeKind::Delete,
+                "u1",
+                None,
+            )
+            .in_transaction("tx-1"),
+        );
+
+        let event = stream.next().await.expect("Flux fermé");
+        assert_eq!(event.kind, ChangeKind::Delete);
+        assert_eq!(event.id, "u1");
+        assert_eq!(event.tx_id.as_deref(), Some("tx-1"));
+    }
+
+    #[async_test]
+    async fn test_watch_db_spans_collections() {
+        let mut stream = watch_db("changes_db_test", "db");
+
+        publish_change(ChangeEvent::new(
+            "changes_db_test",
+            "other_db",
+            "users",
+            ChangeKind::Insert,
+            "x",
+            None,
+        ));
+        for collection in ["users", "orders"] {
+            publish_change(ChangeEvent::new(
+                "changes_db_test",
+                "db",
+                collection,
+                ChangeKind::Insert,
+                "1",
+                None,
+            ));
+        }
+
+        let first = stream.next().await.expect("Flux fermé");
+        let second = stream.next().await.expect("Flux fermé");
+        assert_eq!(
+            (first.collection.as_str(), second.collection.as_str()),
+            ("users", "orders")
+        );
+    }
+}
@@ -1,5 +1,6 @@
 // FICHIER : src-tauri/src/json_db/transactions/manager.rs
 
+use crate::json_db::collections::audit_fields::AuditPolicy;
 use crate::json_db::collections::manager::{CollectionsManager, SystemIndexTx};
 use crate::json_db::indexes::IndexManager;
 use crate::json_db::query::{
@@ -7,8 +8,9 @@ use crate::json_db::query::{
 };
 use crate::json_db::schema::{SchemaRegistry, SchemaValidator};
 use crate::json_db::storage::StorageEngine;
+use crate::json_db::transactions::changes::{self, ChangeEvent, ChangeKind};
 use crate::json_db::transactions::lock_manager::LockManager;
-use crate::json_db::transactions::{Operation, Transaction, TransactionRequest};
+use crate::json_db::transactions::{wal, Operation, Transaction, TransactionRequest};
 
 use crate::utils::prelude::*;
 
@@ -32,6 +34,19 @@ enum UndoAction {
     },
 }
 
+/// Logique de schéma d'une collection, mise en cache le temps d'une transaction.
+struct SchemaLogic {
+    audit: AuditPolicy,
+    schema: Option<CompiledCollectionSchema>,
+}
+
+struct CompiledCollectionSchema {
+    uri: String,
+    space: String,
+    db: String,
+    validator: SchemaValidator,
+}
+
 pub struct TransactionManager<'a> {
     storage: &'a StorageEngine,
     space: String,
@@ -51,6 +66,15 @@ impl<'a> TransactionManager<'a> {
 
     /// API PUBLIQUE INTELLIGENTE (ASYNCHRONE)
     pub async fn execute_smart(&self, requests: Vec<TransactionRequest>) -> RaiseResult<()> {
+        self.execute_smart_counted(requests).await.map(|_| ())
+    }
+
+    /// Variante de `execute_smart` renvoyant le nombre de documents affectés
+    /// (opérations atomiques après dépliage des requêtes `*Many`).
+    pub async fn execute_smart_counted(
+        &self,
+        requests: Vec<TransactionRequest>,
+    ) -> RaiseResult<usize> {
         let mut prepared_ops = Vec::new();
 
         let col_mgr = CollectionsManager::new(self.storage, &self.space, &self.db);
@@ -264,6 +288,45 @@ impl<'a> TransactionManager<'a> {
                     }
                 }
 
+                TransactionRequest::UpdateMany { query, mut patch } => {
+                    let collection = query.collection.clone();
+                    self.resolve_all_refs(&query_engine, &mut patch, &prepared_ops)
+                        .await?;
+
+                    let result = query_engine.execute_query(query).await?;
+                    let found_count = result.documents.len();
+
+                    if found_count == 0 {
+                        crate::user_warn!(
+                            "TX_UPDATE_MANY_EMPTY",
+                            crate::utils::data::json::json_value!({
+                                "collection": collection,
+                                "hint": "Le filtre n'a ciblé aucun document. L'opération a été ignorée."
+                            })
+                        );
+                    } else {
+                        crate::user_info!(
+                            "TX_UPDATE_MANY_PREPARED",
+                            crate::utils::data::json::json_value!({
+                                "collection": collection,
+                                "documents_to_update": found_count
+                            })
+                        );
+                    }
+
+                    // Un Update (fusion json_merge) par document ciblé
+                    for doc in result.documents {
+                        if let Some(id_str) = doc.get("_id").and_then(|v| v.as_str()) {
+                            prepared_ops.push(Operation::Update {
+                                collection: collection.clone(),
+                                id: id_str.to_string(),
+                                previous_document: None,
+                                document: patch.clone(),
+                            });
+                        }
+                    }
+                }
+
                 TransactionRequest::InsertFrom { collection, path } => {
                     let mut doc = self.load_dataset_file(&path).await?;
 
@@ -401,13 +464,15 @@ impl<'a> TransactionManager<'a> {
             }
         }
 
+        let affected = prepared_ops.len();
         self.execute_internal(|tx: &mut Transaction| {
             for op in prepared_ops {
                 tx.operations.push(op);
             }
             Ok(())
         })
-        .await
+        .await?;
+        Ok(affected)
     }
 
     async fn load_dataset_file(&self, path: &str) -> RaiseResult<JsonValue> {
@@ -551,6 +616,12 @@ impl<'a> TransactionManager<'a> {
                 limit: Some(1),
                 offset: None,
                 projection: None,
+                aggregation: None,
+                joins: Vec::new(),
+                after: None,
+                before: None,
+                window: None,
+                distinct: None,
             };
 
             if let Ok(res) = qe.execute_query(query).await {
@@ -591,6 +662,12 @@ impl<'a> TransactionManager<'a> {
                 limit: Some(1),
                 offset: None,
                 projection: None,
+                aggregation: None,
+                joins: Vec::new(),
+                after: None,
+                before: None,
+                window: None,
+                distinct: None,
             };
             if let Ok(res) = qe.execute_query(query).await {
                 if let Some(found_doc) = res.documents.first() {
@@ -695,10 +772,22 @@ impl<'a> TransactionManager<'a> {
 
         // 3. Application de la transaction
         match self.apply_transaction(&tx, &mut sys_tx).await {
-            Ok(_) => {
+            Ok(applied) => {
                 self.commit_wal(&tx).await?;
                 // 🎯 4. VALIDATION PHYSIQUE : Le propriétaire (nous) valide le jeton.
                 sys_tx.commit().await?;
+                // 5. Diffusion des changements validés aux flux `watch`
+                for action in applied {
+                    changes::publish_change(self.change_event(action).in_transaction(&tx.id));
+                }
+                // 6. Checkpoint automatique du journal, hors du verrou d'index qu'il reprend
+                drop(guard);
+                if let Err(e) = wal::checkpoint_if_due(self.storage, &self.space, &self.db).await {
+                    user_warn!(
+                        "WRN_WAL_CHECKPOINT_FAILED",
+                        json_value!({ "db": self.db, "technical_error": e.to_string() })
+                    );
+                }
                 Ok(())
             }
             Err(e) => {
@@ -709,26 +798,21 @@ impl<'a> TransactionManager<'a> {
         }
     }
 
+    /// Entrée `Pending` du journal, annulée au démarrage si le processus s'arrête avant le commit.
     async fn write_wal(&self, tx: &Transaction) -> RaiseResult<()> {
-        let wal_path = self
-            .storage
-            .config
-            .db_root(&self.space, &self.db)
-            .join("wal");
-        fs::ensure_dir_async(&wal_path).await?;
-        let tx_file = wal_path.join(format!("{}.json", tx.id));
-        fs::write_json_atomic_async(&tx_file, tx).await?;
-        Ok(())
+        wal::write_entry(&self.storage.config, &self.space, &self.db, tx).await
     }
 
+    /// Applique les opérations ; renvoie les actions réalisées (ordre d'application).
     async fn apply_transaction(
         &self,
         tx: &Transaction,
         sys_tx: &mut SystemIndexTx<'_>,
-    ) -> RaiseResult<()> {
+    ) -> RaiseResult<Vec<UndoAction>> {
         let mut idx = IndexManager::new(self.storage, &self.space, &self.db);
 
         let mut undo_stack: Vec<UndoAction> = Vec::new();
+        let mut schema_cache = UnorderedMap::new();
 
         for op in &tx.operations {
             match op {
@@ -745,7 +829,15 @@ impl<'a> TransactionManager<'a> {
                         }
                     }
 
-                    if let Err(e) = self.apply_schema_logic(collection, &mut final_doc).await {
+                    if let Err(e) = self
+                        .apply_schema_logic(collection, &mut final_doc, &mut schema_cache)
+                        .await
+                    {
+                        self.rollback_runtime(&mut idx, undo_stack).await?;
+                        return Err(e);
+                    }
+
+                    if let Err(e) = idx.check_unique(collection, id, &final_doc).await {
                         self.rollback_runtime(&mut idx, undo_stack).await?;
                         return Err(e);
                     }
@@ -813,12 +905,24 @@ impl<'a> TransactionManager<'a> {
 
                     let old_doc_clone = final_doc.clone();
                     json_merge(&mut final_doc, document.clone());
+                    CollectionsManager::new(self.storage, &self.space, &self.db)
+                        .collection_audit_policy(collection)
+                        .await
+                        .keep_creation(&old_doc_clone, &mut final_doc);
 
                     if let Some(obj) = final_doc.as_object_mut() {
                         obj.insert("_id".to_string(), JsonValue::String(id.clone()));
                     }
 
-                    if let Err(e) = self.apply_schema_logic(collection, &mut final_doc).await {
+                    if let Err(e) = self
+                        .apply_schema_logic(collection, &mut final_doc, &mut schema_cache)
+                        .await
+                    {
+                        self.rollback_runtime(&mut idx, undo_stack).await?;
+                        return Err(e);
+                    }
+
+                    if let Err(e) = idx.check_unique(collection, id, &final_doc).await {
                         self.rollback_runtime(&mut idx, undo_stack).await?;
                         return Err(e);
                     }
@@ -897,7 +1001,26 @@ impl<'a> TransactionManager<'a> {
             }
         }
 
-        Ok(())
+        Ok(undo_stack)
+    }
+
+    /// Changement validé correspondant à une action appliquée (état final du document).
+    fn change_event(&self, action: UndoAction) -> ChangeEvent {
+        let (collection, id, kind, document) = match action {
+            UndoAction::Insert {
+                collection,
+                id,
+                inserted_doc,
+            } => (collection, id, ChangeKind::Insert, Some(inserted_doc)),
+            UndoAction::Update {
+                collection,
+                id,
+                bad_doc,
+                ..
+            } => (collection, id, ChangeKind::Update, Some(bad_doc)),
+            UndoAction::Delete { collection, id, .. } => (collection, id, ChangeKind::Delete, None),
+        };
+        ChangeEvent::new(&self.space, &self.db, &collection, kind, &id, document)
     }
 
     async fn rollback_runtime(
@@ -953,7 +1076,24 @@ impl<'a> TransactionManager<'a> {
         Ok(())
     }
 
-    async fn apply_schema_logic(&self, collection: &str, doc: &mut JsonValue) -> RaiseResult<()> {
+    /// Politique d'audit et validateur d'une collection, compilés une seule fois par
+    /// transaction : un lot de milliers d'insertions ne recharge pas le registre à chaque document.
+    async fn schema_logic<'c>(
+        &self,
+        collection: &str,
+        cache: &'c mut UnorderedMap<String, SchemaLogic>,
+    ) -> RaiseResult<&'c SchemaLogic> {
+        if !cache.contains_key(collection) {
+            let logic = self.load_schema_logic(collection).await?;
+            cache.insert(collection.to_string(), logic);
+        }
+        Ok(&cache[collection])
+    }
+
+    async fn load_schema_logic(&self, collection: &str) -> RaiseResult<SchemaLogic> {
+        let audit = CollectionsManager::new(self.storage, &self.space, &self.db)
+            .collection_audit_policy(collection)
+            .await;
         let meta_path = self
             .storage
             .config
@@ -973,48 +1113,77 @@ impl<'a> TransactionManager<'a> {
             }
         }
 
-        if let Some(uri) = resolved_uri {
-            if let Some(obj) = doc.as_object_mut() {
-                obj.insert("$schema".to_string(), JsonValue::String(uri.clone()));
+        let Some(uri) = resolved_uri else {
+            return Ok(SchemaLogic {
+                audit,
+                schema: None,
+            });
+        };
+        let mut target_space = self.space.clone();
+        let mut target_db = self.db.clone();
+
+        if let Some(without_scheme) = uri.strip_prefix("db://") {
+            let parts: Vec<&str> = without_scheme.splitn(3, '/').collect();
+            if parts.len() >= 2 {
+                target_space = parts[0].to_string();
+                target_db = parts[1].to_string();
             }
-            let mut target_space = self.space.clone();
-            let mut target_db = self.db.clone();
-
-            if let Some(without_scheme) = uri.strip_prefix("db://") {
-                let parts: Vec<&str> = without_scheme.splitn(3, '/').collect();
-                if parts.len() >= 2 {
-                    target_space = parts[0].to_string();
-                    target_db = parts[1].to_string();
-                }
+        }
+        let reg = SchemaRegistry::from_db(&self.storage.config, &target_space, &target_db).await?;
+        let validator = match SchemaValidator::compile_with_registry(&uri, &reg) {
+            Ok(v) => v,
+            Err(e) => {
+                raise_error!(
+                    "ERR_SCHEMA_VALIDATOR_COMPILATION_FAIL",
+                    error = format!(
+                        "Impossible de préparer le validateur pour le schéma : {}",
+                        uri
+                    ),
+                    context = json_value!({
+                        "schema_uri": uri,
+                        "nested_error": e,
+                        "action": "initialize_validator",
+                    })
+                );
+            }
+        };
+
+        Ok(SchemaLogic {
+            audit,
+            schema: Some(CompiledCollectionSchema {
+                uri,
+                space: target_space,
+                db: target_db,
+                validator,
+            }),
+        })
+    }
+
+    async fn apply_schema_logic(
+        &self,
+        collection: &str,
+        doc: &mut JsonValue,
+        cache: &mut UnorderedMap<String, SchemaLogic>,
+    ) -> RaiseResult<()> {
+        let logic = self.schema_logic(collection, cache).await?;
+
+        if let Some(schema) = &logic.schema {
+            if let Some(obj) = doc.as_object_mut() {
+                obj.insert("$schema".to_string(), JsonValue::String(schema.uri.clone()));
             }
-            let reg =
-                SchemaRegistry::from_db(&self.storage.config, &target_space, &target_db).await?;
-            let validator = match SchemaValidator::compile_with_registry(&uri, &reg) {
-                Ok(v) => v,
-                Err(e) => {
-                    raise_error!(
-                        "ERR_SCHEMA_VALIDATOR_COMPILATION_FAIL",
-                        error = format!(
-                            "Impossible de préparer le validateur pour le schéma : {}",
-                            uri
-                        ),
-                        context = json_value!({
-                            "schema_uri": uri,
-                            "nested_error": e,
-                            "action": "initialize_validator",
-                        })
-                    );
-                }
-            };
 
             let compute_ctx = crate::rules_engine::compute::ComputeContext {
                 document: doc.clone(),
                 collection_name: collection.to_string(),
-                db_name: target_db,
-                space_name: target_space,
+                db_name: schema.db.clone(),
+                space_name: schema.space.clone(),
             };
 
-            validator.compute_then_validate(doc, &compute_ctx).await?;
+            schema.validator.compute(doc, &compute_ctx).await?;
+            logic.audit.stamp(doc);
+            schema.validator.validate(doc)?;
+        } else {
+            logic.audit.stamp(doc);
         }
         Ok(())
     }
@@ -1061,20 +1230,11 @@ impl<'a> TransactionManager<'a> {
     }
 
     async fn commit_wal(&self, tx: &Transaction) -> RaiseResult<()> {
-        let path = self
-            .storage
-            .config
-            .db_root(&self.space, &self.db)
-            .join("wal")
-            .join(format!("{}.json", tx.id));
-        if fs::exists_async(&path).await {
-            fs::remove_file_async(&path).await?;
-        }
-        Ok(())
+        wal::mark_committed(&self.storage.config, &self.space, &self.db, tx).await
     }
 
     async fn rollback_wal(&self, tx: &Transaction) -> RaiseResult<()> {
-        self.commit_wal(tx).await
+        wal::remove_entry(&self.storage.config, &self.space, &self.db, &tx.id).await
     }
 
     #[async_recursive]
@@ -1175,6 +1335,12 @@ impl<'a> TransactionManager<'a> {
                             limit: Some(1),
                             offset: None,
                             projection: None,
+                            aggregation: None,
+                            joins: Vec::new(),
+                            after: None,
+                            before: None,
+                            window: None,
+                            distinct: None,
                         };
 
                         if t_domain == self.space && t_db == self.db {
@@ -2,6 +2,7 @@
 
 use crate::utils::prelude::*;
 
+pub mod changes;
 pub mod lock_manager;
 pub mod manager;
 pub mod wal;
@@ -34,6 +35,11 @@ pub enum TransactionRequest {
     DeleteMany {
         query: crate::json_db::query::Query,
     },
+    /// Fusionne `patch` dans chaque document ciblé par `query` (un `Update` atomique par document).
+    UpdateMany {
+        query: crate::json_db::query::Query,
+        patch: JsonValue,
+    },
     InsertFrom {
         collection: String,
         path: String,
@@ -98,6 +104,17 @@ pub enum Operation {
     },
 }
 
+impl Operation {
+    /// Collection et identifiant du document visé.
+    pub fn target(&self) -> (&str, &str) {
+        match self {
+            Self::Insert { collection, id, .. }
+            | Self::Update { collection, id, .. }
+            | Self::Delete { collection, id, .. } => (collection, id),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serializable, Deserializable)]
 pub enum TransactionStatus {
     Pending,
@@ -1,15 +1,35 @@
 // FICHIER : src-tauri/src/json_db/transactions/wal.rs
 
-use crate::json_db::storage::{JsonDbConfig, StorageEngine};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::{encryption, JsonDbConfig, StorageEngine};
 use crate::json_db::transactions::{Operation, Transaction, TransactionLog, TransactionStatus};
 
 use crate::utils::prelude::*;
 
+/// Suffixe des entrées validées, conservées jusqu'au prochain checkpoint.
+const COMMITTED_SUFFIX: &str = ".committed.json";
+
 /// Helper pour obtenir le chemin du dossier WAL
 fn get_wal_dir(config: &JsonDbConfig, space: &str, db: &str) -> PathBuf {
     config.db_root(space, db).join("wal")
 }
 
+/// Bilan d'un checkpoint du journal.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct CheckpointReport {
+    /// Transactions validées repliées puis retirées du journal.
+    pub folded: usize,
+    pub reclaimed_bytes: u64,
+    /// Transactions encore en attente (en cours, ou à annuler au démarrage).
+    pub pending: usize,
+}
+
+struct CommittedEntry {
+    path: PathBuf,
+    bytes: u64,
+    age_secs: u64,
+}
+
 /// Écrit une transaction dans le journal (Write Ahead Log)
 pub async fn write_entry(
     config: &JsonDbConfig,
@@ -30,7 +50,7 @@ pub async fn write_entry(
         timestamp: UtcClock::now().timestamp(),
     };
 
-    fs::write_json_atomic_async(&file_path, &log).await?;
+    encryption::write_json_atomic(config, &file_path, &log).await?;
 
     Ok(())
 }
@@ -51,6 +71,29 @@ pub async fn remove_entry(
     Ok(())
 }
 
+/// Marque la transaction comme validée : son entrée reste au journal jusqu'au checkpoint,
+/// qui garantit que l'index système reflète ses écritures même après un arrêt brutal.
+pub async fn mark_committed(
+    config: &JsonDbConfig,
+    space: &str,
+    db: &str,
+    tx: &Transaction,
+) -> RaiseResult<()> {
+    let dir = get_wal_dir(config, space, db);
+    fs::ensure_dir_async(&dir).await?;
+
+    let log = TransactionLog {
+        id: tx.id.clone(),
+        status: TransactionStatus::Committed,
+        operations: tx.operations.clone(),
+        timestamp: UtcClock::now().timestamp(),
+    };
+    let file_path = dir.join(format!("{}{}", tx.id, COMMITTED_SUFFIX));
+    encryption::write_json_atomic(config, &file_path, &log).await?;
+
+    remove_entry(config, space, db, &tx.id).await
+}
+
 /// (Optionnel) Charge les transactions en attente
 pub async fn list_pending(
     config: &JsonDbConfig,
@@ -75,8 +118,12 @@ pub async fn list_pending(
         } {
             let path = entry.path();
 
-            // Filtrage des fichiers JSON
-            if path.extension().is_some_and(|ext| ext == "json") {
+            // Filtrage des fichiers JSON (hors transactions déjà validées)
+            let committed = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.ends_with(COMMITTED_SUFFIX));
+            if !committed && path.extension().is_some_and(|ext| ext == "json") {
                 if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
                     pending_ids.push(stem.to_string());
                 }
@@ -86,6 +133,143 @@ pub async fn list_pending(
     Ok(pending_ids)
 }
 
+async fn committed_entries(
+    config: &JsonDbConfig,
+    space: &str,
+    db: &str,
+) -> RaiseResult<Vec<CommittedEntry>> {
+    let dir = get_wal_dir(config, space, db);
+    let mut committed = Vec::new();
+    if !fs::exists_async(&dir).await {
+        return Ok(committed);
+    }
+    let mut entries = fs::read_dir_async(&dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.ends_with(COMMITTED_SUFFIX) {
+            continue;
+        }
+        let meta = fs::metadata_async(entry.path()).await?;
+        let age_secs = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.elapsed().ok())
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        committed.push(CommittedEntry {
+            path: entry.path(),
+            bytes: meta.len(),
+            age_secs,
+        });
+    }
+    Ok(committed)
+}
+
+/// Vrai si les entrées validées dépassent un seuil de `config.wal_checkpoint`.
+pub async fn checkpoint_due(config: &JsonDbConfig, space: &str, db: &str) -> RaiseResult<bool> {
+    let policy = &config.wal_checkpoint;
+    if !policy.enabled {
+        return Ok(false);
+    }
+    let entries = committed_entries(config, space, db).await?;
+    let bytes: u64 = entries.iter().map(|e| e.bytes).sum();
+    let oldest = entries.iter().map(|e| e.age_secs).max();
+    Ok(!entries.is_empty()
+        && (bytes >= policy.max_bytes || oldest.is_some_and(|age| age >= policy.max_age_secs)))
+}
+
+/// Replie les transactions validées puis tronque le journal. Les documents sont déjà écrits
+/// au commit : le repli aligne l'index système (`items`) sur les fichiers présents, ce qui
+/// répare un arrêt survenu entre le commit du journal et celui de l'index. Les entrées en
+/// attente ne sont pas touchées (elles relèvent de la récupération au démarrage).
+pub async fn checkpoint(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+) -> RaiseResult<CheckpointReport> {
+    let config = &storage.config;
+    let manager = CollectionsManager::new(storage, space, db);
+    let lock = storage.get_index_lock(space, db)?;
+    let guard = lock.lock().await;
+
+    let entries = committed_entries(config, space, db).await?;
+    let mut report = CheckpointReport {
+        pending: list_pending(config, space, db).await?.len(),
+        ..CheckpointReport::default()
+    };
+    if entries.is_empty() {
+        return Ok(report);
+    }
+
+    // Sans index système (base brute), il n'y a rien à réconcilier
+    if manager.load_index().await.is_ok() {
+        let mut sys_tx = manager.begin_system_tx(&guard).await?;
+        for entry in &entries {
+            let Ok(log) = encryption::read_json::<TransactionLog>(config, &entry.path).await else {
+                continue;
+            };
+            for op in &log.operations {
+                let (collection, id) = op.target();
+                let present = storage
+                    .read_document(space, db, collection, id)
+                    .await?
+                    .is_some();
+                reconcile_item(&mut sys_tx.document, collection, id, present);
+            }
+        }
+        sys_tx.commit().await?;
+    }
+
+    for entry in entries {
+        fs::remove_file_async(&entry.path).await?;
+        report.folded += 1;
+        report.reclaimed_bytes += entry.bytes;
+    }
+    Ok(report)
+}
+
+/// Checkpoint automatique, déclenché par les seuils de la configuration.
+pub async fn checkpoint_if_due(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+) -> RaiseResult<Option<CheckpointReport>> {
+    if !checkpoint_due(&storage.config, space, db).await? {
+        return Ok(None);
+    }
+    checkpoint(storage, space, db).await.map(Some)
+}
+
+/// Aligne l'entrée `items` de la collection sur la présence du fichier du document.
+fn reconcile_item(index: &mut JsonValue, collection: &str, id: &str, present: bool) {
+    // Collection supprimée depuis : rien à réconcilier
+    let Some(col) = index
+        .get_mut("collections")
+        .and_then(|cols| cols.get_mut(collection))
+        .and_then(|col| col.as_object_mut())
+    else {
+        return;
+    };
+    let Some(items) = col
+        .entry("items")
+        .or_insert_with(|| json_value!([]))
+        .as_array_mut()
+    else {
+        return;
+    };
+    let file = format!("{}.json", id);
+    let listed = items
+        .iter()
+        .position(|i| i.get("file").and_then(|f| f.as_str()) == Some(file.as_str()));
+    match (present, listed) {
+        (true, None) => items.push(json_value!({ "file": file })),
+        (false, Some(pos)) => {
+            items.remove(pos);
+        }
+        _ => {}
+    }
+}
+
 /// 🎯 MOTEUR DE RECOVERY : Exécute la récupération après un crash
 /// À appeler obligatoirement au démarrage du backend, avant d'accepter la moindre requête.
 pub async fn recover_pending_transactions(
@@ -101,58 +285,54 @@ pub async fn recover_pending_transactions(
     for tx_id in pending_ids {
         let file_path = get_wal_dir(config, space, db).join(format!("{}.json", tx_id));
 
-        if let Ok(content) = fs::read_to_string_async(&file_path).await {
-            if let Ok(log) = json::deserialize_from_str::<TransactionLog>(&content) {
-                #[cfg(debug_assertions)]
-                println!(
-                    "⚠️ [WAL] Crash détecté ! Restauration (Rollback) de la transaction {}...",
-                    tx_id
-                );
-
-                // 2. On annule les opérations À L'ENVERS (LIFO - Last In, First Out)
-                for op in log.operations.into_iter().rev() {
-                    match op {
-                        Operation::Insert { collection, id, .. } => {
-                            // On supprime le fichier qui a potentiellement été écrit
-                            if let Err(e) =
-                                storage.delete_document(space, db, &collection, &id).await
-                            {
-                                raise_error!(
-                                    "ERR_WAL_RECOVERY_IO",
-                                    error = format!("Impossible d'annuler l'insertion : {}", e),
-                                    context = json_value!({"collection": collection, "id": id, "tx_id": tx_id})
-                                );
-                            }
+        if let Ok(log) = encryption::read_json::<TransactionLog>(config, &file_path).await {
+            #[cfg(debug_assertions)]
+            println!(
+                "⚠️ [WAL] Crash détecté ! Restauration (Rollback) de la transaction {}...",
+                tx_id
+            );
+
+            // 2. On annule les opérations À L'ENVERS (LIFO - Last In, First Out)
+            for op in log.operations.into_iter().rev() {
+                match op {
+                    Operation::Insert { collection, id, .. } => {
+                        // On supprime le fichier qui a potentiellement été écrit
+                        if let Err(e) = storage.delete_document(space, db, &collection, &id).await {
+                            raise_error!(
+                                "ERR_WAL_RECOVERY_IO",
+                                error = format!("Impossible d'annuler l'insertion : {}", e),
+                                context = json_value!({"collection": collection, "id": id, "tx_id": tx_id})
+                            );
                         }
-                        Operation::Update {
-                            collection,
-                            id,
-                            previous_document,
-                            ..
-                        } => {
-                            // On restaure l'ancien document (Undo)
-                            if let Some(old_doc) = previous_document {
-                                let _ = storage
-                                    .write_document(space, db, &collection, &id, &old_doc)
-                                    .await;
-                            }
+                    }
+                    Operation::Update {
+                        collection,
+                        id,
+                        previous_document,
+                        ..
+                    } => {
+                        // On restaure l'ancien document (Undo)
+                        if let Some(old_doc) = previous_document {
+                            let _ = storage
+                                .write_document(space, db, &collection, &id, &old_doc)
+                                .await;
                         }
-                        Operation::Delete {
-                            collection,
-                            id,
-                            previous_document,
-                        } => {
-                            // On ressuscite le document qui a été effacé à tort
-                            if let Some(old_doc) = previous_document {
-                                let _ = storage
-                                    .write_document(space, db, &collection, &id, &old_doc)
-                                    .await;
-                            }
+                    }
+                    Operation::Delete {
+                        collection,
+                        id,
+                        previous_document,
+                    } => {
+                        // On ressuscite le document qui a été effacé à tort
+                        if let Some(old_doc) = previous_document {
+                            let _ = storage
+                                .write_document(space, db, &collection, &id, &old_doc)
+                                .await;
                         }
                     }
                 }
-                recovered_count += 1;
             }
+            recovered_count += 1;
         }
         // 3. Le nettoyage est terminé, la base est de nouveau cohérente.
         // On supprime l'entrée du WAL pour ne pas la rejouer au prochain démarrage.
@@ -169,6 +349,7 @@ pub async fn recover_pending_transactions(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::json_db::storage::WalCheckpointPolicy;
     use crate::utils::testing::DbSandbox;
 
     #[async_test]
@@ -317,4 +498,56 @@ mod tests {
             panic!("❌ Échec du test 'test_wal_recovery_engine' : {}", e);
         }
     }
+
+    #[async_test]
+    async fn test_checkpoint_folds_committed_entries() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let storage = &sandbox.storage;
+        let (space, db) = ("wal_space", "wal_db");
+        let manager = CollectionsManager::new(storage, space, db);
+        DbSandbox::mock_db(&manager).await?;
+        manager
+            .create_collection(
+                "parts",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+
+        // Arrêt simulé entre le commit du journal et celui de l'index système
+        let mut tx = Transaction::new();
+        tx.add_insert("parts", "p1", json_value!({ "_id": "p1" }));
+        write_entry(&storage.config, space, db, &tx).await?;
+        storage
+            .write_document(space, db, "parts", "p1", &json_value!({ "_id": "p1" }))
+            .await?;
+        mark_committed(&storage.config, space, db, &tx).await?;
+        let pending = Transaction::new();
+        write_entry(&storage.config, space, db, &pending).await?;
+
+        assert_eq!(
+            list_pending(&storage.config, space, db).await?,
+            vec![pending.id.clone()]
+        );
+        assert!(!checkpoint_due(&storage.config, space, db).await?);
+        let eager = storage
+            .config
+            .clone()
+            .with_wal_checkpoint(WalCheckpointPolicy {
+                max_bytes: 1,
+                ..WalCheckpointPolicy::default()
+            });
+        assert!(checkpoint_due(&eager, space, db).await?);
+
+        let report = checkpoint(storage, space, db).await?;
+        assert_eq!((report.folded, report.pending), (1, 1));
+        assert!(report.reclaimed_bytes > 0);
+        let index = manager.load_index().await?;
+        assert_eq!(
+            index["collections"]["parts"]["items"],
+            json_value!([{ "file": "p1.json" }])
+        );
+        assert!(!checkpoint_due(&eager, space, db).await?);
+        assert_eq!(checkpoint(storage, space, db).await?.folded, 0);
+        Ok(())
+    }
 }
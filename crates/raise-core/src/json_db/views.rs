@@ -0,0 +1,555 @@
+// FICHIER : crates/raise-core/src/json_db/views.rs
+
+//! Vues matérialisées : le résultat d'une requête (`Query` ou SQL) est stocké dans une
+//! collection du même nom, interrogeable comme les autres, et tenu à jour depuis le flux de
+//! changements de ses sources. Les définitions sont rangées dans `_system.json` (`views.<nom>`).
+//!
+//! Une vue sans agrégation, jointure, fenêtre, DISTINCT ni pagination est rafraîchie
+//! document par document (ses lignes portent l'`_id` de la source) ; les autres sont
+//! recalculées entièrement à chaque changement d'une source.
+
+use crate::json_db::collections::id_strategy::content_hash;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::sql::{parse_sql, SqlRequest};
+use crate::json_db::query::vector::VectorSearch;
+use crate::json_db::query::{
+    Condition, FilterOperator, Projection, Query, QueryEngine, QueryFilter,
+};
+use crate::json_db::transactions::changes::{ChangeEvent, ChangeKind};
+use crate::utils::prelude::*;
+
+/// Clé de `_system.json` regroupant les définitions de vues.
+pub const SYSTEM_VIEWS_KEY: &str = "views";
+/// Schéma des collections de vues (lignes libres, comme les collections techniques).
+pub const VIEW_SCHEMA_URI: &str = "db://_system/_system/schemas/v1/db/generic.schema.json";
+
+#[derive(Debug, Clone, Copy, Serializable, Deserializable, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshMode {
+    /// Seules les lignes des documents modifiés sont recalculées.
+    Incremental,
+    /// Toute la vue est recalculée (agrégats, jointures, fenêtres, pagination...).
+    Full,
+}
+
+impl RefreshMode {
+    pub fn for_query(query: &Query) -> Self {
+        let per_document_filter = query.filter.as_ref().is_none_or(|f| {
+            f.operator == FilterOperator::And
+                && !f.conditions.iter().any(VectorSearch::is_vector_condition)
+        });
+        let incremental = per_document_filter
+            && query.aggregation.is_none()
+            && query.joins.is_empty()
+            && query.window.is_none()
+            && query.distinct.is_none()
+            && query.limit.is_none()
+            && query.offset.is_none()
+            && query.after.is_none()
+            && query.before.is_none();
+        if incremental {
+            Self::Incremental
+        } else {
+            Self::Full
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ViewDefinition {
+    pub name: String,
+    pub query: Query,
+    /// Texte SQL d'origine, conservé pour l'affichage.
+    #[serde(default)]
+    pub sql: Option<String>,
+    /// Collections lues par la requête (principale puis jointes).
+    pub sources: Vec<String>,
+    pub mode: RefreshMode,
+    /// Dernier recalcul complet (RFC3339).
+    #[serde(default)]
+    pub refreshed_at: Option<String>,
+}
+
+impl ViewDefinition {
+    pub fn from_query(name: &str, query: Query) -> RaiseResult<Self> {
+        let name = name.trim();
+        let mut sources = vec![query.collection.clone()];
+        for join in &query.joins {
+            if !sources.contains(&join.collection) {
+                sources.push(join.collection.clone());
+            }
+        }
+        if name.is_empty() || name.starts_with('_') || sources.iter().any(|s| s == name) {
+            raise_error!(
+                "ERR_DB_VIEW_INVALID",
+                error = format!("Nom de vue invalide : '{}'", name),
+                context = json_value!({
+                    "sources": sources,
+                    "hint": "Une vue porte un nom non vide, sans '_' initial, distinct de ses sources."
+                })
+            );
+        }
+        Ok(Self {
+            name: name.to_string(),
+            mode: RefreshMode::for_query(&query),
+            query,
+            sql: None,
+            sources,
+            refreshed_at: None,
+        })
+    }
+
+    /// Vue définie par une requête `SELECT`.
+    pub fn from_sql(name: &str, sql: &str) -> RaiseResult<Self> {
+        let SqlRequest::Read(query) = parse_sql(sql)? else {
+            raise_error!(
+                "ERR_DB_VIEW_INVALID",
+                error = "Une vue se définit par une requête SELECT.",
+                context = json_value!({ "view": name, "sql": sql })
+            );
+        };
+        let mut def = Self::from_query(name, query)?;
+        def.sql = Some(sql.to_string());
+        Ok(def)
+    }
+
+    pub fn depends_on(&self, collection: &str) -> bool {
+        self.sources.iter().any(|s| s == collection)
+    }
+
+    /// Requête exécutée pour remplir la vue : en mode incrémental, la projection garde
+    /// l'`_id` source qui identifie la ligne.
+    fn execution_query(&self) -> Query {
+        let mut query = self.query.clone();
+        if self.mode == RefreshMode::Incremental {
+            match &mut query.projection {
+                Some(Projection::Include(fields))
+                | Some(Projection::Computed {
+                    fields,
+                    wildcard: false,
+                    ..
+                }) if !fields.iter().any(|f| f == "_id") => fields.push("_id".to_string()),
+                Some(Projection::Exclude(fields)) => fields.retain(|f| f != "_id"),
+                _ => {}
+            }
+        }
+        query
+    }
+}
+
+/// Bilan d'un rafraîchissement.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct ViewRefresh {
+    pub view: String,
+    /// Lignes écrites (nouvelles ou modifiées).
+    pub upserted: usize,
+    pub deleted: usize,
+    /// Recalcul complet (et non ligne par ligne).
+    pub full: bool,
+}
+
+pub struct MaterializedViews<'a> {
+    manager: &'a CollectionsManager<'a>,
+}
+
+impl<'a> MaterializedViews<'a> {
+    pub fn new(manager: &'a CollectionsManager<'a>) -> Self {
+        Self { manager }
+    }
+
+    /// Crée la collection de la vue, enregistre sa définition et la remplit.
+    pub async fn create(&self, mut def: ViewDefinition) -> RaiseResult<ViewRefresh> {
+        if self.get(&def.name).await?.is_some() {
+            raise_error!(
+                "ERR_DB_VIEW_EXISTS",
+                error = format!("La vue '{}' existe déjà.", def.name),
+                context = json_value!({ "hint": "Supprimez-la avant de la redéfinir." })
+            );
+        }
+        if self.manager.list_collections().await?.contains(&def.name) {
+            raise_error!(
+                "ERR_DB_VIEW_NAME_TAKEN",
+                error = format!("Une collection s'appelle déjà '{}'.", def.name),
+                context = json_value!({ "space": self.manager.space, "db": self.manager.db })
+            );
+        }
+        // La requête est exécutée avant toute écriture : une définition invalide ne laisse rien
+        let rows = self.compute(&def, None).await?;
+        self.manager
+            .create_collection(&def.name, VIEW_SCHEMA_URI)
+            .await?;
+        let report = self.replace_rows(&def, rows).await?;
+        def.refreshed_at = Some(UtcClock::now().to_rfc3339());
+        self.save_definition(&def).await?;
+        Ok(report)
+    }
+
+    pub async fn drop(&self, name: &str) -> RaiseResult<()> {
+        self.require(name).await?;
+        let lock = self
+            .manager
+            .storage
+            .get_index_lock(&self.manager.space, &self.manager.db)?;
+        let guard = lock.lock().await;
+        let mut tx = self.manager.begin_system_tx(&guard).await?;
+        if let Some(views) = tx
+            .document
+            .get_mut(SYSTEM_VIEWS_KEY)
+            .and_then(|v| v.as_object_mut())
+        {
+            views.remove(name);
+        }
+        tx.commit().await?;
+        drop(guard);
+        self.manager.drop_collection(name).await
+    }
+
+    pub async fn list(&self) -> RaiseResult<Vec<ViewDefinition>> {
+        let index = self.manager.load_index().await?;
+        let Some(views) = index.get(SYSTEM_VIEWS_KEY).and_then(|v| v.as_object()) else {
+            return Ok(Vec::new());
+        };
+        views
+            .values()
+            .map(|v| json::deserialize_from_value(v.clone()))
+            .collect()
+    }
+
+    pub async fn get(&self, name: &str) -> RaiseResult<Option<ViewDefinition>> {
+        Ok(self.list().await?.into_iter().find(|v| v.name == name))
+    }
+
+    /// Vues à tenir à jour quand `collection` change.
+    pub async fn views_on(&self, collection: &str) -> RaiseResult<Vec<ViewDefinition>> {
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .filter(|v| v.depends_on(collection))
+            .collect())
+    }
+
+    /// Recalcule entièrement la vue.
+    pub async fn refresh(&self, name: &str) -> RaiseResult<ViewRefresh> {
+        let mut def = self.require(name).await?;
+        let rows = self.compute(&def, None).await?;
+        let report = self.replace_rows(&def, rows).await?;
+        def.refreshed_at = Some(UtcClock::now().to_rfc3339());
+        self.save_definition(&def).await?;
+        Ok(report)
+    }
+
+    /// Répercute un changement d'une source : ligne du document seule en mode incrémental,
+    /// recalcul complet sinon (ou après un `Resync`).
+    pub async fn apply_change(
+        &self,
+        def: &ViewDefinition,
+        event: &ChangeEvent,
+    ) -> RaiseResult<ViewRefresh> {
+        if def.mode == RefreshMode::Full || event.kind == ChangeKind::Resync {
+            return self.refresh(&def.name).await;
+        }
+        let mut report = ViewRefresh {
+            view: def.name.clone(),
+            ..ViewRefresh::default()
+        };
+        let rows = match event.kind {
+            ChangeKind::Delete => Vec::new(),
+            _ => self.compute(def, Some(&event.id)).await?,
+        };
+        let stored = self.manager.get_document(&def.name, &event.id).await?;
+        match rows.into_iter().next() {
+            Some(row) if stored.as_ref() != Some(&row) => {
+                self.write_row(&def.name, &row, stored.is_some()).await?;
+                report.upserted = 1;
+            }
+            Some(_) => {}
+            None if stored.is_some() => {
+                self.manager.delete_document(&def.name, &event.id).await?;
+                report.deleted = 1;
+            }
+            None => {}
+        }
+        Ok(report)
+    }
+
+    async fn require(&self, name: &str) -> RaiseResult<ViewDefinition> {
+        match self.get(name).await? {
+            Some(def) => Ok(def),
+            None => raise_error!(
+                "ERR_DB_VIEW_NOT_FOUND",
+                error = format!("Vue matérialisée '{}' introuvable.", name),
+                context = json_value!({ "space": self.manager.space, "db": self.manager.db })
+            ),
+        }
+    }
+
+    async fn save_definition(&self, def: &ViewDefinition) -> RaiseResult<()> {
+        let lock = self
+            .manager
+            .storage
+            .get_index_lock(&self.manager.space, &self.manager.db)?;
+        let guard = lock.lock().await;
+        let mut tx = self.manager.begin_system_tx(&guard).await?;
+        if !tx.document[SYSTEM_VIEWS_KEY].is_object() {
+            tx.document[SYSTEM_VIEWS_KEY] = json_value!({});
+        }
+        tx.document[SYSTEM_VIEWS_KEY][&def.name] = json::serialize_to_value(def)?;
+        tx.commit().await
+    }
+
+    /// Lignes de la vue (limitées au document `id` s'il est donné), identifiées par leur
+    /// `_id` source ou, à défaut, par l'empreinte de leur contenu.
+    async fn compute(&self, def: &ViewDefinition, id: Option<&str>) -> RaiseResult<Vec<JsonValue>> {
+        let mut query = def.execution_query();
+        if let Some(id) = id {
+            let only = Condition::eq("_id", json_value!(id));
+            match &mut query.filter {
+                Some(filter) => filter.conditions.push(only),
+                None => {
+                    query.filter = Some(QueryFilter {
+                        operator: FilterOperator::And,
+                        conditions: vec![only],
+                    })
+                }
+            }
+        }
+        let result = QueryEngine::new(self.manager).execute_query(query).await?;
+
+        let mut used = UniqueSet::new();
+        let mut rows = Vec::with_capacity(result.documents.len());
+        for mut row in result.documents {
+            let Some(obj) = row.as_object_mut() else {
+                continue;
+            };
+            let base = match obj.get("_id").and_then(|v| v.as_str()) {
+                Some(id) => id.to_string(),
+                None => content_hash(&JsonValue::Object(obj.clone())),
+            };
+            let mut row_id = base.clone();
+            let mut n = 1;
+            while !used.insert(row_id.clone()) {
+                n += 1;
+                row_id = format!("{}-{}", base, n);
+            }
+            obj.insert("_id".to_string(), JsonValue::String(row_id));
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    /// Aligne la collection de la vue sur `rows` : seules les lignes changées sont écrites.
+    async fn replace_rows(
+        &self,
+        def: &ViewDefinition,
+        rows: Vec<JsonValue>,
+    ) -> RaiseResult<ViewRefresh> {
+        let mut stored: UnorderedMap<String, JsonValue> = UnorderedMap::new();
+        for doc in self.manager.list_all(&def.name).await? {
+            if let Some(id) = doc.get("_id").and_then(|v| v.as_str()).map(str::to_string) {
+                stored.insert(id, doc);
+            }
+        }
+
+        let mut report = ViewRefresh {
+            view: def.name.clone(),
+            full: true,
+            ..ViewRefresh::default()
+        };
+        for row in rows {
+            let id = row["_id"].as_str().unwrap_or_default().to_string();
+            let previous = stored.remove(&id);
+            if previous.as_ref() != Some(&row) {
+                self.write_row(&def.name, &row, previous.is_some()).await?;
+                report.upserted += 1;
+            }
+        }
+        for id in stored.keys() {
+            self.manager.delete_document(&def.name, id).await?;
+            report.deleted += 1;
+        }
+        Ok(report)
+    }
+
+    /// Écrit la ligne telle quelle (ni schéma, ni audit, ni `_rev`). Une ligne remplacée est
+    /// d'abord supprimée pour que les index secondaires de la vue restent exacts.
+    async fn write_row(&self, view: &str, row: &JsonValue, replace: bool) -> RaiseResult<()> {
+        if replace {
+            let id = row["_id"].as_str().unwrap_or_default();
+            self.manager.delete_document(view, id).await?;
+        }
+        self.manager.insert_raw(view, row).await
+    }
+}
+
+// ============================================================================
+// TESTS UNITAIRES
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    async fn seed(manager: &CollectionsManager<'_>) -> RaiseResult<()> {
+        DbSandbox::mock_db(manager).await?;
+        manager
+            .create_collection("requirements", VIEW_SCHEMA_URI)
+            .await?;
+        for doc in [
+            json_value!({ "_id": "r1", "title": "Freinage", "status": "draft", "kind": "safety" }),
+            json_value!({ "_id": "r2", "title": "ABS", "status": "approved", "kind": "safety" }),
+            json_value!({ "_id": "r3", "title": "Éclairage", "status": "draft", "kind": "comfort" }),
+        ] {
+            manager.insert_raw("requirements", &doc).await?;
+        }
+        Ok(())
+    }
+
+    fn ids(docs: &[JsonValue]) -> Vec<String> {
+        let mut ids: Vec<String> = docs
+            .iter()
+            .filter_map(|d| d["_id"].as_str().map(str::to_string))
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_refresh_mode_and_definition_checks() -> RaiseResult<()> {
+        let def = ViewDefinition::from_sql(
+            "drafts",
+            "SELECT title FROM requirements WHERE status = 'draft'",
+        )?;
+        assert_eq!(def.mode, RefreshMode::Incremental);
+        assert_eq!(def.sources, vec!["requirements".to_string()]);
+        let Some(Projection::Include(fields)) = def.execution_query().projection else {
+            panic!("Projection attendue");
+        };
+        assert!(fields.contains(&"_id".to_string()));
+
+        let def = ViewDefinition::from_sql(
+            "by_kind",
+            "SELECT kind, COUNT(*) AS n FROM requirements GROUP BY kind",
+        )?;
+        assert_eq!(def.mode, RefreshMode::Full);
+
+        assert!(ViewDefinition::from_sql("requirements", "SELECT * FROM requirements").is_err());
+        assert!(ViewDefinition::from_sql("_hidden", "SELECT * FROM requirements").is_err());
+        assert!(ViewDefinition::from_sql("w", "DELETE FROM requirements WHERE a = 1").is_err());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_incremental_view_follows_source_changes() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "views");
+        seed(&manager).await?;
+        let views = MaterializedViews::new(&manager);
+
+        let def = ViewDefinition::from_sql(
+            "drafts",
+            "SELECT title FROM requirements WHERE status = 'draft'",
+        )?;
+        let report = views.create(def.clone()).await?;
+        assert_eq!((report.upserted, report.deleted), (2, 0));
+        assert_eq!(ids(&manager.list_all("drafts").await?), vec!["r1", "r3"]);
+        assert!(views.create(def.clone()).await.is_err());
+
+        // r2 repasse en brouillon : une ligne apparaît, sans recalcul du reste
+        let mut r2 = json_value!({ "_id": "r2", "title": "ABS", "status": "draft" });
+        manager.insert_raw("requirements", &r2).await?;
+        let event = ChangeEvent::new(
+            "test",
+            "views",
+            "requirements",
+            ChangeKind::Update,
+            "r2",
+            None,
+        );
+        let report = views.apply_change(&def, &event).await?;
+        assert_eq!((report.upserted, report.full), (1, false));
+        let row = manager
+            .get_document("drafts", "r2")
+            .await?
+            .expect("ligne r2");
+        assert_eq!(row, json_value!({ "_id": "r2", "title": "ABS" }));
+
+        // Sortie du filtre puis suppression de la source
+        r2["status"] = json_value!("approved");
+        manager.insert_raw("requirements", &r2).await?;
+        assert_eq!(views.apply_change(&def, &event).await?.deleted, 1);
+        manager.delete_document("requirements", "r1").await?;
+        let event = ChangeEvent::new(
+            "test",
+            "views",
+            "requirements",
+            ChangeKind::Delete,
+            "r1",
+            None,
+        );
+        assert_eq!(views.apply_change(&def, &event).await?.deleted, 1);
+        assert_eq!(ids(&manager.list_all("drafts").await?), vec!["r3"]);
+
+        // Un recalcul complet sur une vue à jour n'écrit rien
+        assert_eq!(
+            views.refresh("drafts").await?,
+            ViewRefresh {
+                view: "drafts".into(),
+                full: true,
+                ..ViewRefresh::default()
+            }
+        );
+        views.drop("drafts").await?;
+        assert!(views.list().await?.is_empty());
+        assert!(!manager
+            .list_collections()
+            .await?
+            .contains(&"drafts".to_string()));
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_aggregate_view_is_recomputed_and_queryable() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "views");
+        seed(&manager).await?;
+        let views = MaterializedViews::new(&manager);
+
+        let def = ViewDefinition::from_sql(
+            "by_kind",
+            "SELECT kind, COUNT(*) AS n FROM requirements GROUP BY kind",
+        )?;
+        views.create(def.clone()).await?;
+
+        let event = ChangeEvent::new(
+            "test",
+            "views",
+            "requirements",
+            ChangeKind::Insert,
+            "r4",
+            None,
+        );
+        manager
+            .insert_raw(
+                "requirements",
+                &json_value!({ "_id": "r4", "title": "Siège", "kind": "comfort" }),
+            )
+            .await?;
+        let report = views.apply_change(&def, &event).await?;
+        // Le groupe `comfort` change d'empreinte : ancienne ligne retirée, nouvelle écrite
+        assert!(report.full);
+        assert_eq!((report.upserted, report.deleted), (1, 1));
+
+        let mut query = Query::new("by_kind");
+        query.filter = Some(QueryFilter {
+            operator: FilterOperator::And,
+            conditions: vec![Condition::eq("kind", json_value!("comfort"))],
+        });
+        let result = QueryEngine::new(&manager).execute_query(query).await?;
+        assert_eq!(result.documents.len(), 1);
+        assert_eq!(result.documents[0]["n"], 2);
+        assert_eq!(views.views_on("requirements").await?.len(), 1);
+        Ok(())
+    }
+}
@@ -107,7 +107,13 @@ impl NodeEnvironment {
             })
         );
 
-        let storage_config = JsonDbConfig::new(domain_root.clone());
+        let storage_config = match JsonDbConfig::new(domain_root.clone())
+            .with_encryption_from_env()
+            .await
+        {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_KERNEL_STORAGE_INIT", error = e),
+        };
         let storage = match StorageEngine::new(storage_config) {
             Ok(s) => SharedRef::new(s),
             Err(e) => raise_error!("ERR_KERNEL_STORAGE_INIT", error = e),
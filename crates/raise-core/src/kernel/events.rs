@@ -0,0 +1,232 @@
+// FICHIER : crates/raise-core/src/kernel/events.rs
+//! Bus d'événements applicatif : les sous-systèmes publient des événements typés,
+//! les adaptateurs (Tauri, CLI) s'y abonnent pour les relayer à l'utilisateur.
+
+use crate::model_engine::validators::ValidationIssue;
+use crate::utils::prelude::*;
+
+/// Capacité du canal de diffusion (les abonnés trop lents perdent les plus anciens).
+const EVENT_BUS_CAPACITY: usize = 256;
+
+static EVENT_BUS: StaticCell<AsyncBroadcast::Sender<RaiseEvent>> = StaticCell::new();
+
+// =========================================================================
+// ÉVÉNEMENTS TYPÉS
+// =========================================================================
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(tag = "kind", content = "payload", rename_all = "snake_case")]
+pub enum RaiseEvent {
+    /// Fin d'une passe de validation du modèle (moteur de règles).
+    ValidationCompleted {
+        space: String,
+        db: String,
+        issue_count: usize,
+    },
+    /// Revalidation incrémentale : les problèmes des éléments listés remplacent les précédents.
+    ValidationIssuesUpdated {
+        space: String,
+        db: String,
+        element_ids: Vec<String>,
+        issues: Vec<ValidationIssue>,
+    },
+    /// Un commit Mentis a atteint le quorum et a été finalisé.
+    CommitFinalized { commit_id: String },
+    /// Une instance de workflow attend une intervention humaine.
+    WorkflowPaused { instance: String, workflow: String },
+    /// Un plugin cognitif WASM a été chargé en mémoire.
+    PluginLoaded {
+        plugin_id: String,
+        file_path: String,
+    },
+    /// Une clé de configuration a changé à chaud (API, fichier ou base).
+    ConfigChanged { key: String, source: String },
+    /// Écriture d'agent suspendue (élément verrouillé ou modifié en parallèle).
+    AgentWriteConflict {
+        conflict_id: String,
+        element_id: String,
+        agent: String,
+    },
+    /// Un fichier Capella surveillé a été resynchronisé (compteurs d'éléments touchés).
+    CapellaSynced {
+        path: String,
+        added: usize,
+        modified: usize,
+        removed: usize,
+    },
+    /// Avancement d'un téléchargement d'artefact de pair à pair (un événement par morceau).
+    ArtifactTransfer {
+        artifact_id: String,
+        chunks_done: usize,
+        chunks_total: usize,
+        bytes_done: u64,
+    },
+}
+
+impl RaiseEvent {
+    /// Canal d'émission côté frontend (`listen(channel, ...)`).
+    pub fn channel(&self) -> &'static str {
+        match self {
+            Self::ValidationCompleted { .. } => "raise:validation_completed",
+            Self::ValidationIssuesUpdated { .. } => "raise:validation_issues_updated",
+            Self::CommitFinalized { .. } => "raise:commit_finalized",
+            Self::WorkflowPaused { .. } => "raise:workflow_paused",
+            Self::PluginLoaded { .. } => "raise:plugin_loaded",
+            Self::ConfigChanged { .. } => "raise:config_changed",
+            Self::AgentWriteConflict { .. } => "raise:agent_write_conflict",
+            Self::CapellaSynced { .. } => "raise:capella_synced",
+            Self::ArtifactTransfer { .. } => "raise:artifact_transfer",
+        }
+    }
+}
+
+/// Entrée du catalogue d'abonnement exposé au frontend.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct EventDescriptor {
+    pub channel: String,
+    pub kind: String,
+    pub description: String,
+    pub payload_fields: Vec<String>,
+}
+
+/// 📖 Catalogue des événements disponibles (source de vérité pour les abonnés).
+pub fn event_catalogue() -> Vec<EventDescriptor> {
+    let entry = |channel: &str, kind: &str, description: &str, fields: &[&str]| EventDescriptor {
+        channel: channel.to_string(),
+        kind: kind.to_string(),
+        description: description.to_string(),
+        payload_fields: fields.iter().map(|f| f.to_string()).collect(),
+    };
+
+    vec![
+        entry(
+            "raise:validation_completed",
+            "validation_completed",
+            "Fin d'une passe de validation du modèle.",
+            &["space", "db", "issue_count"],
+        ),
+        entry(
+            "raise:validation_issues_updated",
+            "validation_issues_updated",
+            "Problèmes de validation recalculés pour les éléments modifiés.",
+            &["space", "db", "element_ids", "issues"],
+        ),
+        entry(
+            "raise:commit_finalized",
+            "commit_finalized",
+            "Commit Mentis finalisé après consensus.",
+            &["commit_id"],
+        ),
+        entry(
+            "raise:workflow_paused",
+            "workflow_paused",
+            "Workflow en pause (validation humaine requise).",
+            &["instance", "workflow"],
+        ),
+        entry(
+            "raise:plugin_loaded",
+            "plugin_loaded",
+            "Plugin cognitif chargé.",
+            &["plugin_id", "file_path"],
+        ),
+        entry(
+            "raise:config_changed",
+            "config_changed",
+            "Paramètre de configuration modifié à chaud.",
+            &["key", "source"],
+        ),
+        entry(
+            "raise:agent_write_conflict",
+            "agent_write_conflict",
+            "Écriture d'agent en conflit (arbitrage humain requis).",
+            &["conflict_id", "element_id", "agent"],
+        ),
+        entry(
+            "raise:capella_synced",
+            "capella_synced",
+            "Modifications d'un projet Capella lié répercutées dans le modèle.",
+            &["path", "added", "modified", "removed"],
+        ),
+        entry(
+            "raise:artifact_transfer",
+            "artifact_transfer",
+            "Progression d'un transfert d'artefact entre pairs.",
+            &["artifact_id", "chunks_done", "chunks_total", "bytes_done"],
+        ),
+    ]
+}
+
+// =========================================================================
+// PUBLICATION & ABONNEMENT
+// =========================================================================
+
+fn bus() -> &'static AsyncBroadcast::Sender<RaiseEvent> {
+    EVENT_BUS.get_or_init(|| AsyncBroadcast::channel(EVENT_BUS_CAPACITY).0)
+}
+
+/// Publie un événement. Sans abonné, l'événement est simplement tracé.
+pub fn publish_event(event: RaiseEvent) {
+    user_trace!(
+        "TRC_EVENT_PUBLISHED",
+        json_value!({ "channel": event.channel() })
+    );
+    let _ = bus().send(event);
+}
+
+/// Ouvre un abonnement au flux d'événements (reçoit ceux publiés après l'appel).
+pub fn subscribe_events() -> AsyncBroadcast::Receiver<RaiseEvent> {
+    bus().subscribe()
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_test]
+    async fn test_publish_reaches_subscriber() {
+        let mut rx = subscribe_events();
+
+        publish_event(RaiseEvent::PluginLoaded {
+            plugin_id: "bus_test_plugin".into(),
+            file_path: "/tmp/bus_test.wasm".into(),
+        });
+
+        // D'autres tests peuvent publier en parallèle : on filtre sur notre événement
+        loop {
+            match rx.recv().await {
+                Ok(RaiseEvent::PluginLoaded { plugin_id, .. })
+                    if plugin_id == "bus_test_plugin" =>
+                {
+                    break
+                }
+                Ok(_) | Err(AsyncBroadcast::RecvError::Lagged(_)) => continue,
+                Err(e) => panic!("Bus fermé : {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_event_serialization_is_tagged() {
+        let event = RaiseEvent::CommitFinalized {
+            commit_id: "c-42".into(),
+        };
+        let value = json::serialize_to_value(&event).unwrap();
+        assert_eq!(value["kind"], "commit_finalized");
+        assert_eq!(value["payload"]["commit_id"], "c-42");
+    }
+
+    #[test]
+    fn test_catalogue_matches_channels() {
+        let catalogue = event_catalogue();
+        let event = RaiseEvent::WorkflowPaused {
+            instance: "i".into(),
+            workflow: "w".into(),
+        };
+        assert!(catalogue.iter().any(|d| d.channel == event.channel()));
+        assert_eq!(catalogue.len(), 9);
+    }
+}
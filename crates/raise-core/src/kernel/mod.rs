@@ -2,4 +2,5 @@
 
 pub mod assets;
 pub mod environment;
+pub mod events;
 pub mod state;
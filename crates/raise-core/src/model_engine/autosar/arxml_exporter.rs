@@ -0,0 +1,519 @@
+// FICHIER : src-tauri/src/model_engine/autosar/arxml_exporter.rs
+
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+use super::profile::{short_name, ArxmlMappingProfile};
+
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::writer::Writer;
+
+/// Bilan d'un export ARXML.
+#[derive(Debug, Clone, Default, Serializable)]
+pub struct ArxmlExportReport {
+    pub components: usize,
+    pub ports: usize,
+    pub interfaces: usize,
+    /// Ports sans composant propriétaire et signaux introuvables (identifiants)
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PortKind {
+    Provided,
+    Required,
+    ProvidedRequired,
+}
+
+impl PortKind {
+    fn from_orientation(orientation: Option<&str>) -> Self {
+        match orientation.map(|o| o.to_ascii_uppercase()).as_deref() {
+            Some("OUT") => Self::Provided,
+            Some("IN") => Self::Required,
+            _ => Self::ProvidedRequired,
+        }
+    }
+
+    fn tags(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Provided => ("P-PORT-PROTOTYPE", "PROVIDED-INTERFACE-TREF"),
+            Self::Required => ("R-PORT-PROTOTYPE", "REQUIRED-INTERFACE-TREF"),
+            Self::ProvidedRequired => ("PR-PORT-PROTOTYPE", "PROVIDED-REQUIRED-INTERFACE-TREF"),
+        }
+    }
+}
+
+struct SwcPort {
+    name: String,
+    kind: PortKind,
+    interface: String,
+}
+
+struct Swc {
+    name: String,
+    tag: String,
+    ports: Vec<SwcPort>,
+}
+
+struct DataElement {
+    name: String,
+    data_type: String,
+}
+
+struct SrInterface {
+    name: String,
+    elements: Vec<DataElement>,
+}
+
+/// Export des composants, ports et signaux de l'architecture physique vers AUTOSAR (ARXML).
+///
+/// Chaque composant PA devient un type de SWC, chaque port un `P`/`R`/`PR-PORT-PROTOTYPE`
+/// selon son orientation, et chaque ensemble distinct de signaux une interface
+/// sender-receiver partagée par les ports qui l'échangent.
+pub struct ArxmlExporter {
+    profile: ArxmlMappingProfile,
+}
+
+impl Default for ArxmlExporter {
+    fn default() -> Self {
+        Self::new(ArxmlMappingProfile::default())
+    }
+}
+
+impl ArxmlExporter {
+    pub fn new(profile: ArxmlMappingProfile) -> Self {
+        Self { profile }
+    }
+
+    pub async fn export_to_file(
+        &self,
+        model: &ProjectModel,
+        path: &Path,
+    ) -> RaiseResult<ArxmlExportReport> {
+        let (xml, report) = self.export(model)?;
+        fs::write_atomic_async(path, xml.as_bytes()).await?;
+        user_success!(
+            "SUC_ARXML_EXPORTED",
+            json_value!({ "path": path.to_string_lossy(), "report": report })
+        );
+        Ok(report)
+    }
+
+    pub fn export(&self, model: &ProjectModel) -> RaiseResult<(String, ArxmlExportReport)> {
+        self.profile.validate()?;
+        let (components, interfaces, report) = self.map_model(model);
+
+        let mut w = ArxmlWriter::new();
+        w.emit(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        let location = format!(
+            "http://autosar.org/schema/r4.0 {}.xsd",
+            self.profile.schema_version
+        );
+        w.open_with(
+            "AUTOSAR",
+            &[
+                ("xmlns", "http://autosar.org/schema/r4.0"),
+                ("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance"),
+                ("xsi:schemaLocation", &location),
+            ],
+        )?;
+        w.open("AR-PACKAGES")?;
+        w.open("AR-PACKAGE")?;
+        w.leaf("SHORT-NAME", &self.profile.root_package)?;
+        w.open("AR-PACKAGES")?;
+
+        // --- Interfaces sender-receiver ---
+        w.open("AR-PACKAGE")?;
+        w.leaf("SHORT-NAME", "PortInterfaces")?;
+        w.open("ELEMENTS")?;
+        for interface in &interfaces {
+            w.open("SENDER-RECEIVER-INTERFACE")?;
+            w.leaf("SHORT-NAME", &interface.name)?;
+            w.open("DATA-ELEMENTS")?;
+            for element in &interface.elements {
+                w.open("VARIABLE-DATA-PROTOTYPE")?;
+                w.leaf("SHORT-NAME", &element.name)?;
+                w.reference("TYPE-TREF", "IMPLEMENTATION-DATA-TYPE", &element.data_type)?;
+                w.close("VARIABLE-DATA-PROTOTYPE")?;
+            }
+            w.close("DATA-ELEMENTS")?;
+            w.close("SENDER-RECEIVER-INTERFACE")?;
+        }
+        w.close("ELEMENTS")?;
+        w.close("AR-PACKAGE")?;
+
+        // --- Types de composants logiciels ---
+        let interface_root = format!("/{}/PortInterfaces", self.profile.root_package);
+        w.open("AR-PACKAGE")?;
+        w.leaf("SHORT-NAME", "SwComponentTypes")?;
+        w.open("ELEMENTS")?;
+        for swc in &components {
+            w.open(&swc.tag)?;
+            w.leaf("SHORT-NAME", &swc.name)?;
+            w.open("PORTS")?;
+            for port in &swc.ports {
+                let (port_tag, ref_tag) = port.kind.tags();
+                w.open(port_tag)?;
+                w.leaf("SHORT-NAME", &port.name)?;
+                w.reference(
+                    ref_tag,
+                    "SENDER-RECEIVER-INTERFACE",
+                    &format!("{}/{}", interface_root, port.interface),
+                )?;
+                w.close(port_tag)?;
+            }
+            w.close("PORTS")?;
+            w.close(&swc.tag)?;
+        }
+        w.close("ELEMENTS")?;
+        w.close("AR-PACKAGE")?;
+
+        w.close("AR-PACKAGES")?;
+        w.close("AR-PACKAGE")?;
+        w.close("AR-PACKAGES")?;
+        w.close("AUTOSAR")?;
+
+        Ok((w.finish()?, report))
+    }
+
+    /// Projection PA -> SWC / ports / interfaces, dans l'ordre des collections du modèle.
+    fn map_model(&self, model: &ProjectModel) -> (Vec<Swc>, Vec<SrInterface>, ArxmlExportReport) {
+        let sources = &self.profile.sources;
+        let mut report = ArxmlExportReport::default();
+
+        let mut swc_names = ShortNames::default();
+        let mut components: Vec<Swc> = Vec::new();
+        let mut index_by_id: UnorderedMap<&str, usize> = UnorderedMap::new();
+        for component in model.get_collection(&sources.layer, &sources.components) {
+            let category = text_property(component, &sources.component_category_key);
+            index_by_id.insert(&component.id, components.len());
+            components.push(Swc {
+                name: swc_names.claim(component.name.as_str()),
+                tag: self.profile.component_type(category).to_string(),
+                ports: Vec::new(),
+            });
+        }
+
+        let signals: UnorderedMap<&str, &ArcadiaElement> = model
+            .get_collection(&sources.layer, &sources.signals)
+            .iter()
+            .map(|s| (s.id.as_str(), s))
+            .collect();
+
+        let mut interface_names = ShortNames::default();
+        let mut interfaces: Vec<SrInterface> = Vec::new();
+        let mut interface_by_signals: UnorderedMap<Vec<String>, String> = UnorderedMap::new();
+        let mut port_names: UnorderedMap<usize, ShortNames> = UnorderedMap::new();
+
+        for port in model.get_collection(&sources.layer, &sources.ports) {
+            let owner = text_property(port, &sources.port_owner_key)
+                .and_then(|id| index_by_id.get(id).copied());
+            let Some(owner) = owner else {
+                report.skipped.push(port.id.clone());
+                continue;
+            };
+
+            let mut signal_ids: Vec<String> = Vec::new();
+            for id in port
+                .properties
+                .get(&sources.port_signals_key)
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str())
+            {
+                if signals.contains_key(id) {
+                    signal_ids.push(id.to_string());
+                } else {
+                    report.skipped.push(id.to_string());
+                }
+            }
+            signal_ids.sort();
+            signal_ids.dedup();
+
+            let port_name = port_names
+                .entry(owner)
+                .or_default()
+                .claim(port.name.as_str());
+            let interface = match interface_by_signals.get(&signal_ids) {
+                Some(name) => name.clone(),
+                None => {
+                    let name = interface_names
+                        .claim(&format!("{}{}", port_name, self.profile.interface_suffix));
+                    let mut element_names = ShortNames::default();
+                    let elements = signal_ids
+                        .iter()
+                        .map(|id| {
+                            let signal = signals[id.as_str()];
+                            DataElement {
+                                name: element_names.claim(signal.name.as_str()),
+                                data_type: self
+                                    .profile
+                                    .data_type(text_property(signal, &sources.signal_type_key))
+                                    .to_string(),
+                            }
+                        })
+                        .collect();
+                    interfaces.push(SrInterface {
+                        name: name.clone(),
+                        elements,
+                    });
+                    interface_by_signals.insert(signal_ids, name.clone());
+                    name
+                }
+            };
+
+            components[owner].ports.push(SwcPort {
+                name: port_name,
+                kind: PortKind::from_orientation(text_property(port, &sources.port_direction_key)),
+                interface,
+            });
+            report.ports += 1;
+        }
+
+        report.components = components.len();
+        report.interfaces = interfaces.len();
+        if !report.skipped.is_empty() {
+            user_warn!(
+                "WRN_ARXML_ELEMENTS_SKIPPED",
+                json_value!({ "skipped": report.skipped })
+            );
+        }
+        (components, interfaces, report)
+    }
+}
+
+fn text_property<'e>(element: &'e ArcadiaElement, key: &str) -> Option<&'e str> {
+    element.properties.get(key).and_then(|v| v.as_str())
+}
+
+/// Attribue des `SHORT-NAME` uniques dans un même espace de noms (suffixe `_2`, `_3`...).
+#[derive(Default)]
+struct ShortNames {
+    taken: UniqueSet<String>,
+}
+
+impl ShortNames {
+    fn claim(&mut self, raw: &str) -> String {
+        let base = short_name(raw);
+        let mut candidate = base.clone();
+        let mut n = 1;
+        while self.taken.contains(&candidate) {
+            n += 1;
+            candidate = format!("{}_{}", base, n);
+        }
+        self.taken.insert(candidate.clone());
+        candidate
+    }
+}
+
+/// Écriture indentée ; le texte et les attributs sont échappés par `quick-xml`.
+struct ArxmlWriter {
+    inner: Writer<Vec<u8>>,
+}
+
+impl ArxmlWriter {
+    fn new() -> Self {
+        Self {
+            inner: Writer::new_with_indent(Vec::new(), b' ', 2),
+        }
+    }
+
+    fn emit(&mut self, event: Event<'_>) -> RaiseResult<()> {
+        match self.inner.write_event(event) {
+            Ok(()) => Ok(()),
+            Err(e) => raise_error!("ERR_ARXML_WRITE", error = e.to_string()),
+        }
+    }
+
+    fn open(&mut self, tag: &str) -> RaiseResult<()> {
+        self.emit(Event::Start(BytesStart::new(tag)))
+    }
+
+    fn open_with(&mut self, tag: &str, attributes: &[(&str, &str)]) -> RaiseResult<()> {
+        let start = BytesStart::new(tag).with_attributes(attributes.iter().copied());
+        self.emit(Event::Start(start))
+    }
+
+    fn close(&mut self, tag: &str) -> RaiseResult<()> {
+        self.emit(Event::End(BytesEnd::new(tag)))
+    }
+
+    fn leaf(&mut self, tag: &str, text: &str) -> RaiseResult<()> {
+        self.open(tag)?;
+        self.emit(Event::Text(BytesText::new(text)))?;
+        self.close(tag)
+    }
+
+    fn reference(&mut self, tag: &str, dest: &str, path: &str) -> RaiseResult<()> {
+        self.open_with(tag, &[("DEST", dest)])?;
+        self.emit(Event::Text(BytesText::new(path)))?;
+        self.close(tag)
+    }
+
+    fn finish(self) -> RaiseResult<String> {
+        match String::from_utf8(self.inner.into_inner()) {
+            Ok(xml) => Ok(xml),
+            Err(e) => raise_error!("ERR_ARXML_WRITE", error = e.to_string()),
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_engine::types::NameType;
+
+    fn element(id: &str, name: &str, kind: &str, props: JsonValue) -> ArcadiaElement {
+        ArcadiaElement {
+            id: id.into(),
+            name: NameType::String(name.into()),
+            kind: kind.into(),
+            properties: json::deserialize_from_value(props).unwrap(),
+        }
+    }
+
+    fn brake_model() -> ProjectModel {
+        let mut model = ProjectModel::default();
+        model.add_element(
+            "pa",
+            "components",
+            element(
+                "c1",
+                "Wheel Sensor",
+                "PhysicalComponent",
+                json_value!({ "nature": "NODE" }),
+            ),
+        );
+        model.add_element(
+            "pa",
+            "components",
+            element(
+                "c2",
+                "Brake Controller",
+                "PhysicalComponent",
+                json_value!({}),
+            ),
+        );
+        model.add_element(
+            "pa",
+            "exchange_items",
+            element(
+                "s1",
+                "wheel speed",
+                "ExchangeItem",
+                json_value!({ "dataType": "float" }),
+            ),
+        );
+        model.add_element(
+            "pa",
+            "ports",
+            element(
+                "p1",
+                "SpeedOut",
+                "PhysicalPort",
+                json_value!({ "component": "c1", "orientation": "OUT", "signals": ["s1"] }),
+            ),
+        );
+        model.add_element(
+            "pa",
+            "ports",
+            element(
+                "p2",
+                "SpeedIn",
+                "PhysicalPort",
+                json_value!({ "component": "c2", "orientation": "in", "signals": ["s1", "ghost"] }),
+            ),
+        );
+        model.add_element(
+            "pa",
+            "ports",
+            element(
+                "p3",
+                "Orphan",
+                "PhysicalPort",
+                json_value!({ "component": "nope" }),
+            ),
+        );
+        model
+    }
+
+    #[test]
+    fn test_export_maps_components_ports_and_signals() -> RaiseResult<()> {
+        let profile: ArxmlMappingProfile = json::deserialize_from_value(json_value!({
+            "root_package": "Brakes",
+            "component_types": { "NODE": "SENSOR-ACTUATOR-SW-COMPONENT-TYPE" },
+            "data_types": { "float": "/AUTOSAR_Platform/ImplementationDataTypes/float32" }
+        }))
+        .unwrap();
+        let (xml, report) = ArxmlExporter::new(profile).export(&brake_model())?;
+
+        assert_eq!(report.components, 2);
+        assert_eq!(report.ports, 2);
+        // Les deux ports échangent le même signal : une seule interface
+        assert_eq!(report.interfaces, 1);
+        assert_eq!(report.skipped, vec!["ghost".to_string(), "p3".to_string()]);
+
+        assert!(xml.contains("<SENSOR-ACTUATOR-SW-COMPONENT-TYPE>"));
+        assert!(xml.contains("<SHORT-NAME>Wheel_Sensor</SHORT-NAME>"));
+        assert!(xml.contains("<APPLICATION-SW-COMPONENT-TYPE>"));
+        assert!(xml.contains("<P-PORT-PROTOTYPE>"));
+        assert!(xml.contains("<R-PORT-PROTOTYPE>"));
+        assert!(xml.contains("<SHORT-NAME>wheel_speed</SHORT-NAME>"));
+        assert!(xml.contains(
+            "<REQUIRED-INTERFACE-TREF DEST=\"SENDER-RECEIVER-INTERFACE\">/Brakes/PortInterfaces/SpeedOut_If</REQUIRED-INTERFACE-TREF>"
+        ));
+        assert!(xml.contains("/AUTOSAR_Platform/ImplementationDataTypes/float32"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_is_well_formed_and_names_are_unique() -> RaiseResult<()> {
+        let mut model = brake_model();
+        model.add_element(
+            "pa",
+            "components",
+            element(
+                "c3",
+                "Brake<Controller>",
+                "PhysicalComponent",
+                json_value!({}),
+            ),
+        );
+        let (xml, _) = ArxmlExporter::default().export(&model)?;
+        assert!(xml.contains("<SHORT-NAME>Brake_Controller_2</SHORT-NAME>"));
+
+        // Relecture complète : le document doit être bien formé
+        let mut reader = quick_xml::Reader::from_str(&xml);
+        let mut swc = 0;
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(e)) if e.name().as_ref().ends_with(b"SW-COMPONENT-TYPE") => {
+                    swc += 1
+                }
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(e) => panic!("ARXML mal formé : {}", e),
+            }
+        }
+        assert_eq!(swc, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_profile_is_rejected() {
+        let profile = ArxmlMappingProfile {
+            default_component_type: "COMPOSITION".into(),
+            ..Default::default()
+        };
+        let result = ArxmlExporter::new(profile).export(&brake_model());
+        match result {
+            Err(AppError::Structured(err)) => assert_eq!(err.code, "ERR_ARXML_PROFILE_INVALID"),
+            _ => panic!("Attendu ERR_ARXML_PROFILE_INVALID"),
+        }
+    }
+}
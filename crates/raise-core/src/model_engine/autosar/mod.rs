@@ -0,0 +1,8 @@
+// FICHIER : src-tauri/src/model_engine/autosar/mod.rs
+
+pub mod arxml_exporter;
+pub mod profile;
+
+// Re-exports
+pub use arxml_exporter::{ArxmlExportReport, ArxmlExporter};
+pub use profile::{ArxmlMappingProfile, ArxmlSources};
@@ -0,0 +1,212 @@
+// FICHIER : src-tauri/src/model_engine/autosar/profile.rs
+
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Types de composants logiciels AUTOSAR acceptés par l'export.
+pub const SWC_COMPONENT_TYPES: &[&str] = &[
+    "APPLICATION-SW-COMPONENT-TYPE",
+    "SENSOR-ACTUATOR-SW-COMPONENT-TYPE",
+    "ECU-ABSTRACTION-SW-COMPONENT-TYPE",
+    "COMPLEX-DEVICE-DRIVER-SW-COMPONENT-TYPE",
+    "SERVICE-SW-COMPONENT-TYPE",
+];
+
+/// Emplacement des éléments PA dans le graphe et propriétés lues par l'export.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(default)]
+pub struct ArxmlSources {
+    pub layer: String,
+    pub components: String,
+    pub ports: String,
+    pub signals: String,
+    /// Propriété d'un port désignant son composant propriétaire
+    pub port_owner_key: String,
+    /// Propriété d'un port portant son orientation (`IN`, `OUT`, `INOUT`)
+    pub port_direction_key: String,
+    /// Propriété d'un port listant les identifiants des signaux échangés
+    pub port_signals_key: String,
+    /// Propriété d'un composant servant de clé dans `component_types`
+    pub component_category_key: String,
+    /// Propriété d'un signal servant de clé dans `data_types`
+    pub signal_type_key: String,
+}
+
+impl Default for ArxmlSources {
+    fn default() -> Self {
+        Self {
+            layer: "pa".into(),
+            components: "components".into(),
+            ports: "ports".into(),
+            signals: "exchange_items".into(),
+            port_owner_key: "component".into(),
+            port_direction_key: "orientation".into(),
+            port_signals_key: "signals".into(),
+            component_category_key: "nature".into(),
+            signal_type_key: "dataType".into(),
+        }
+    }
+}
+
+/// Profil de correspondance Arcadia PA -> AUTOSAR (chargé depuis un fichier JSON).
+///
+/// ```json
+/// {
+///   "root_package": "Powertrain",
+///   "component_types": { "NODE": "ECU-ABSTRACTION-SW-COMPONENT-TYPE" },
+///   "data_types": { "float": "/AUTOSAR_Platform/ImplementationDataTypes/float32" }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(default)]
+pub struct ArxmlMappingProfile {
+    pub root_package: String,
+    /// Version du schéma (`AUTOSAR_00049` = R20-11)
+    pub schema_version: String,
+    pub sources: ArxmlSources,
+    /// Catégorie de composant -> type de SWC
+    pub component_types: OrderedMap<String, String>,
+    pub default_component_type: String,
+    /// Type de signal -> chemin du type de données d'implémentation
+    pub data_types: OrderedMap<String, String>,
+    pub default_data_type: String,
+    pub interface_suffix: String,
+}
+
+impl Default for ArxmlMappingProfile {
+    fn default() -> Self {
+        Self {
+            root_package: "Raise".into(),
+            schema_version: "AUTOSAR_00049".into(),
+            sources: ArxmlSources::default(),
+            component_types: OrderedMap::new(),
+            default_component_type: "APPLICATION-SW-COMPONENT-TYPE".into(),
+            data_types: OrderedMap::new(),
+            default_data_type: "/AUTOSAR_Platform/ImplementationDataTypes/uint32".into(),
+            interface_suffix: "_If".into(),
+        }
+    }
+}
+
+impl ArxmlMappingProfile {
+    pub async fn load(path: &Path) -> RaiseResult<Self> {
+        let profile: Self = fs::read_json_async(path).await?;
+        profile.validate()?;
+        Ok(profile)
+    }
+
+    /// Rejette un profil qui produirait un ARXML invalide (type de SWC inconnu, chemin relatif).
+    pub fn validate(&self) -> RaiseResult<()> {
+        let unknown: Vec<&String> = self
+            .component_types
+            .values()
+            .chain(std::iter::once(&self.default_component_type))
+            .filter(|t| !SWC_COMPONENT_TYPES.contains(&t.as_str()))
+            .collect();
+        if !unknown.is_empty() {
+            raise_error!(
+                "ERR_ARXML_PROFILE_INVALID",
+                error = "Type de composant AUTOSAR non supporté.",
+                context = json_value!({ "unknown": unknown, "allowed": SWC_COMPONENT_TYPES })
+            );
+        }
+
+        let relative: Vec<&String> = self
+            .data_types
+            .values()
+            .chain(std::iter::once(&self.default_data_type))
+            .filter(|p| !p.starts_with('/'))
+            .collect();
+        if !relative.is_empty() {
+            raise_error!(
+                "ERR_ARXML_PROFILE_INVALID",
+                error = "Les types de données doivent être des chemins AUTOSAR absolus.",
+                context = json_value!({ "data_types": relative })
+            );
+        }
+
+        if short_name(&self.root_package) != self.root_package {
+            raise_error!(
+                "ERR_ARXML_PROFILE_INVALID",
+                error = "Le paquetage racine doit être un SHORT-NAME valide.",
+                context = json_value!({ "root_package": self.root_package })
+            );
+        }
+        Ok(())
+    }
+
+    pub fn component_type(&self, category: Option<&str>) -> &str {
+        category
+            .and_then(|c| self.component_types.get(c))
+            .unwrap_or(&self.default_component_type)
+    }
+
+    pub fn data_type(&self, signal_type: Option<&str>) -> &str {
+        signal_type
+            .and_then(|t| self.data_types.get(t))
+            .unwrap_or(&self.default_data_type)
+    }
+}
+
+/// Identifiant AUTOSAR (`SHORT-NAME`) : lettre initiale, `[A-Za-z0-9_]` sans `_` doublé
+/// ni final, 128 caractères au plus.
+pub fn short_name(raw: &str) -> String {
+    let mut name = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c);
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert_str(0, "X_");
+    }
+    name.truncate(128);
+    name.trim_end_matches('_').to_string()
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_defaults_and_validation() {
+        let profile: ArxmlMappingProfile = json::deserialize_from_value(json_value!({
+            "root_package": "Powertrain",
+            "component_types": { "NODE": "ECU-ABSTRACTION-SW-COMPONENT-TYPE" }
+        }))
+        .unwrap();
+        assert!(profile.validate().is_ok());
+        assert_eq!(profile.sources.layer, "pa");
+        assert_eq!(
+            profile.component_type(Some("NODE")),
+            "ECU-ABSTRACTION-SW-COMPONENT-TYPE"
+        );
+        assert_eq!(
+            profile.component_type(Some("BEHAVIOR")),
+            "APPLICATION-SW-COMPONENT-TYPE"
+        );
+
+        let mut bad = profile.clone();
+        bad.component_types
+            .insert("NODE".into(), "ECU-SW-COMPONENT".into());
+        assert!(bad.validate().is_err());
+
+        let mut bad = profile;
+        bad.default_data_type = "uint8".into();
+        assert!(bad.validate().is_err());
+    }
+
+    #[test]
+    fn test_short_name_sanitizing() {
+        assert_eq!(short_name("Brake Controller"), "Brake_Controller");
+        assert_eq!(short_name("2nd-ECU"), "X_2nd_ECU");
+        assert_eq!(short_name("Brake <Controller>"), "Brake_Controller");
+        assert_eq!(short_name("--"), "X");
+        assert_eq!(short_name(&"a".repeat(200)).len(), 128);
+    }
+}
@@ -0,0 +1,330 @@
+// FICHIER : src-tauri/src/model_engine/capella/live_link.rs
+//! Lien "live" avec un projet Capella : le fichier `.capella` est surveillé et seuls
+//! les éléments ajoutés, modifiés ou supprimés depuis la dernière lecture sont
+//! répercutés dans le modèle RAISE (pas de ré-import complet).
+
+use super::model_reader::CapellaReader;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::transactions::TransactionRequest;
+use crate::kernel::events::{publish_event, RaiseEvent};
+use crate::model_engine::ingestion::ModelIngestionService;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
+use crate::traceability::change_tracker::{ChangeLog, ChangeTracker};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Écart entre deux lectures successives du fichier Capella.
+#[derive(Debug, Default)]
+pub struct CapellaDelta {
+    pub added: Vec<ArcadiaElement>,
+    pub modified: Vec<(ArcadiaElement, ChangeLog)>,
+    pub removed: Vec<ArcadiaElement>,
+}
+
+impl CapellaDelta {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compte rendu d'une synchronisation (identifiants touchés et champs modifiés).
+#[derive(Debug, Serializable, Deserializable)]
+pub struct CapellaSyncReport {
+    pub path: String,
+    pub added: Vec<String>,
+    pub modified: Vec<ChangeLog>,
+    pub removed: Vec<String>,
+    pub synced_at: String,
+}
+
+/// Synchronisation incrémentale d'un fichier Capella vers la base du projet.
+pub struct CapellaLiveLink {
+    path: PathBuf,
+    snapshot: Option<ProjectModel>,
+}
+
+impl CapellaLiveLink {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            snapshot: None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Compare deux lectures du modèle, élément par élément (identifiant Capella).
+    pub fn diff(previous: &ProjectModel, current: &ProjectModel) -> CapellaDelta {
+        let tracker = ChangeTracker::new();
+        let before: UnorderedMap<&str, &ArcadiaElement> = previous
+            .all_elements()
+            .into_iter()
+            .map(|el| (el.id.as_str(), el))
+            .collect();
+        let after: UniqueSet<&str> = current
+            .all_elements()
+            .into_iter()
+            .map(|el| el.id.as_str())
+            .collect();
+
+        let mut delta = CapellaDelta::default();
+        for el in current.all_elements() {
+            match before.get(el.id.as_str()) {
+                None => delta.added.push(el.clone()),
+                Some(old) => {
+                    let (Ok(old_doc), Ok(new_doc)) =
+                        (json::serialize_to_value(old), json::serialize_to_value(el))
+                    else {
+                        continue;
+                    };
+                    let log = tracker.diff(&el.id, &old_doc, &new_doc);
+                    if !log.changes.is_empty() {
+                        delta.modified.push((el.clone(), log));
+                    }
+                }
+            }
+        }
+        for el in previous.all_elements() {
+            if !after.contains(el.id.as_str()) {
+                delta.removed.push(el.clone());
+            }
+        }
+        delta
+    }
+
+    /// Relit le fichier et applique l'écart avec la lecture précédente, en une transaction.
+    /// La première synchronisation compare à un modèle vide : tout le fichier est importé.
+    pub async fn sync(
+        &mut self,
+        manager: &CollectionsManager<'_>,
+    ) -> RaiseResult<CapellaSyncReport> {
+        let path = self.path.clone();
+        let current = match spawn_cpu_task(move || CapellaReader::read_model(&path)).await {
+            Ok(res) => res?,
+            Err(e) => raise_error!(
+                "ERR_INGESTION_CPU_PANIC",
+                error = e.to_string(),
+                context = json_value!({"action": "capella_live_link"})
+            ),
+        };
+
+        let empty = ProjectModel::default();
+        let delta = Self::diff(self.snapshot.as_ref().unwrap_or(&empty), &current);
+        if !delta.is_empty() {
+            Self::apply_delta(manager, &delta).await?;
+        }
+        self.snapshot = Some(current);
+
+        let report = CapellaSyncReport {
+            path: self.path.to_string_lossy().to_string(),
+            added: delta.added.iter().map(|el| el.id.clone()).collect(),
+            modified: delta.modified.into_iter().map(|(_, log)| log).collect(),
+            removed: delta.removed.iter().map(|el| el.id.clone()).collect(),
+            synced_at: UtcClock::now().to_rfc3339(),
+        };
+        if !report.added.is_empty() || !report.modified.is_empty() || !report.removed.is_empty() {
+            user_info!(
+                "INF_CAPELLA_LIVE_SYNCED",
+                json_value!({
+                    "path": report.path,
+                    "added": report.added.len(),
+                    "modified": report.modified.len(),
+                    "removed": report.removed.len()
+                })
+            );
+            publish_event(RaiseEvent::CapellaSynced {
+                path: report.path.clone(),
+                added: report.added.len(),
+                modified: report.modified.len(),
+                removed: report.removed.len(),
+            });
+        }
+        Ok(report)
+    }
+
+    async fn apply_delta(
+        manager: &CollectionsManager<'_>,
+        delta: &CapellaDelta,
+    ) -> RaiseResult<()> {
+        let existing = manager.list_collections().await?;
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+
+        let mut requests = Vec::new();
+        let mut created: Vec<String> = Vec::new();
+        let upserts = delta
+            .added
+            .iter()
+            .chain(delta.modified.iter().map(|(el, _)| el));
+        for el in upserts {
+            let collection = ModelIngestionService::collection_for(el);
+            if !existing.contains(&collection) && !created.contains(&collection) {
+                manager.create_collection(&collection, &schema_uri).await?;
+                created.push(collection.clone());
+            }
+            let mut doc = json::serialize_to_value(el)?;
+            if let Some(obj) = doc.as_object_mut() {
+                obj.insert("_id".to_string(), json_value!(el.id.clone()));
+            }
+            requests.push(TransactionRequest::Upsert {
+                collection,
+                id: Some(el.id.clone()),
+                handle: None,
+                document: doc,
+            });
+        }
+        for el in &delta.removed {
+            let collection = ModelIngestionService::collection_for(el);
+            if existing.contains(&collection) {
+                requests.push(TransactionRequest::Delete {
+                    collection,
+                    id: el.id.clone(),
+                });
+            }
+        }
+
+        TransactionManager::new(manager.storage, &manager.space, &manager.db)
+            .execute_smart(requests)
+            .await
+    }
+}
+
+/// Lance la surveillance (par scrutation de la date de modification) d'un fichier Capella.
+/// Une première synchronisation complète est faite au démarrage.
+pub fn spawn_capella_watcher(
+    path: PathBuf,
+    storage: StorageEngine,
+    space: String,
+    db: String,
+    poll_interval: TimeDuration,
+) -> tokio::task::JoinHandle<()> {
+    spawn_async_task(async move {
+        let mut link = CapellaLiveLink::new(path.clone());
+        let mut last_modified = None;
+
+        loop {
+            let modified = fs::metadata_async(&path)
+                .await
+                .ok()
+                .and_then(|m| m.modified().ok());
+            if modified.is_some() && modified != last_modified {
+                let manager = CollectionsManager::new(&storage, &space, &db);
+                match link.sync(&manager).await {
+                    Ok(_) => last_modified = modified,
+                    // Fichier en cours d'écriture par Capella : nouvel essai au prochain tour
+                    Err(e) => user_warn!(
+                        "WRN_CAPELLA_LIVE_SYNC_FAILED",
+                        json_value!({ "path": path.to_string_lossy(), "error": e.to_string() })
+                    ),
+                }
+            }
+            sleep_async(poll_interval).await;
+        }
+    })
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_engine::types::NameType;
+    use crate::utils::io::fs::tempdir;
+    use crate::utils::testing::DbSandbox;
+
+    fn el(id: &str, name: &str) -> ArcadiaElement {
+        ArcadiaElement {
+            id: id.into(),
+            name: NameType::String(name.into()),
+            kind: "org.polarsys.capella.core.data.ctx:SystemFunction".into(),
+            properties: UnorderedMap::new(),
+        }
+    }
+
+    fn capella_xml(functions: &[(&str, &str)]) -> String {
+        let body: String = functions
+            .iter()
+            .map(|(id, name)| {
+                format!(
+                    r#"<ownedFunctions xsi:type="org.polarsys.capella.core.data.ctx:SystemFunction" id="{}" name="{}"/>"#,
+                    id, name
+                )
+            })
+            .collect();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><capella:Project xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">{}</capella:Project>"#,
+            body
+        )
+    }
+
+    #[test]
+    fn test_diff_detects_added_modified_removed() {
+        let mut previous = ProjectModel::default();
+        previous.add_element("sa", "functions", el("F1", "Acquire"));
+        previous.add_element("sa", "functions", el("F2", "Compute"));
+
+        let mut current = ProjectModel::default();
+        current.add_element("sa", "functions", el("F1", "Acquire Speed"));
+        current.add_element("sa", "functions", el("F3", "Display"));
+
+        let delta = CapellaLiveLink::diff(&previous, &current);
+        assert_eq!(delta.added.len(), 1);
+        assert_eq!(delta.added[0].id, "F3");
+        assert_eq!(delta.modified.len(), 1);
+        assert_eq!(delta.modified[0].1.changes[0].field, "name");
+        assert_eq!(delta.removed.len(), 1);
+        assert_eq!(delta.removed[0].id, "F2");
+
+        assert!(CapellaLiveLink::diff(&current, &current).is_empty());
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_sync_applies_only_deltas() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("live.capella");
+        fs::write_async(&path, capella_xml(&[("F1", "Acquire"), ("F2", "Compute")])).await?;
+
+        let mut link = CapellaLiveLink::new(path.clone());
+        let first = link.sync(&manager).await?;
+        assert_eq!(first.added.len(), 2);
+
+        fs::write_async(
+            &path,
+            capella_xml(&[("F1", "Acquire Speed"), ("F3", "Display")]),
+        )
+        .await?;
+        let second = link.sync(&manager).await?;
+        assert_eq!(second.added, vec!["F3".to_string()]);
+        assert_eq!(second.modified.len(), 1);
+        assert_eq!(second.removed, vec!["F2".to_string()]);
+
+        let parsed = CapellaReader::read_model(&path)?;
+        let collection = ModelIngestionService::collection_for(parsed.find_element("F1").unwrap());
+        let f1 = manager.get_document(&collection, "F1").await?.unwrap();
+        assert_eq!(f1["name"], "Acquire Speed");
+        assert!(manager.get_document(&collection, "F2").await?.is_none());
+        assert!(manager.get_document(&collection, "F3").await?.is_some());
+
+        // Fichier inchangé : aucune écriture
+        let third = link.sync(&manager).await?;
+        assert!(third.added.is_empty() && third.modified.is_empty() && third.removed.is_empty());
+        Ok(())
+    }
+}
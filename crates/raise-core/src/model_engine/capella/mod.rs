@@ -1,8 +1,10 @@
 pub mod diagram_generator;
+pub mod live_link;
 pub mod model_reader;
 pub mod model_writer;
 pub mod xmi_parser;
 
 // Re-exports
+pub use live_link::{spawn_capella_watcher, CapellaLiveLink, CapellaSyncReport};
 pub use model_reader::CapellaReader;
 pub use xmi_parser::CapellaXmiParser;
@@ -3,7 +3,7 @@
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::model_engine::arcadia::element_kind::ArcadiaSemantics;
 use crate::model_engine::capella::model_reader::CapellaReader;
-use crate::model_engine::types::ProjectModel;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 pub struct ModelIngestionService;
@@ -43,6 +43,16 @@ impl ModelIngestionService {
         Self::persist_model(&model, manager).await
     }
 
+    /// Collection cible d'un élément importé, déduite de sa catégorie sémantique.
+    pub fn collection_for(el: &ArcadiaElement) -> String {
+        let category_str = format!("{:?}", el.get_category()).to_lowercase() + "s";
+        if category_str == "others" {
+            "elements".to_string()
+        } else {
+            category_str
+        }
+    }
+
     /// Hydratation du Knowledge Graph (JSON-DB) à partir d'un modèle en mémoire.
     /// Aligné sur les bonnes pratiques RAISE : Match...raise_error.
     pub async fn persist_model(
@@ -55,12 +65,7 @@ impl ModelIngestionService {
 
         for el in elements {
             // 1. Routage intelligent via la sémantique (ArcadiaSemantics)
-            let category_str = format!("{:?}", el.get_category()).to_lowercase() + "s";
-            let collection_name = if category_str == "others" {
-                "elements".to_string()
-            } else {
-                category_str
-            };
+            let collection_name = Self::collection_for(el);
 
             // 2. Vérification/Création résiliente de la collection via schéma générique
             // Utilise le point de montage système pour la définition du schéma
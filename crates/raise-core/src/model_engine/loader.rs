@@ -162,6 +162,19 @@ impl<'a> ModelLoader<'a> {
         }
     }
 
+    /// Charge un élément directement depuis sa collection, sans passer par l'index
+    /// (revalidation incrémentale après écriture : l'index peut ne pas être à jour).
+    pub async fn load_element(
+        &self,
+        collection: &str,
+        id: &str,
+    ) -> RaiseResult<Option<ArcadiaElement>> {
+        match self.manager.get_document(collection, id).await? {
+            Some(doc) => Ok(Some(self.json_to_element(doc, Some(&self.manager.db))?)),
+            None => Ok(None),
+        }
+    }
+
     /// Transforme un document JSON en ArcadiaElement Pure Graph
     fn json_to_element(
         &self,
@@ -0,0 +1,476 @@
+// FICHIER : src-tauri/src/model_engine/metrics.rs
+
+use crate::blockchain::crypto::hashing::{hash_canonical, hash_like, HashAlgorithm};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::model_engine::arcadia::PROP_ALLOCATED_FUNCTIONS;
+use crate::model_engine::types::ProjectModel;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Collection JsonDb de l'historique des métriques (courbes de tendance).
+pub const METRICS_COLLECTION: &str = "model_metrics";
+
+/// Taille d'une couche Arcadia.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct LayerSize {
+    pub total: usize,
+    pub collections: OrderedMap<String, usize>,
+}
+
+/// Part des fonctions d'une couche allouées à un composant (`allocatedFunctions`).
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct AllocationRatio {
+    pub functions: usize,
+    pub allocated: usize,
+    pub ratio: f64,
+}
+
+/// Échanges traversant la frontière des composants (fonctions allouées comprises).
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct InterfaceComplexity {
+    pub exchanges: usize,
+    pub avg_per_component: f64,
+    pub max_component: Option<String>,
+    pub max_exchanges: usize,
+}
+
+/// Couplage du graphe des échanges (`source` -> `target`).
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct FanMetrics {
+    /// Moyenne sur les éléments ayant au moins un échange entrant
+    pub avg_fan_in: f64,
+    /// Moyenne sur les éléments ayant au moins un échange sortant
+    pub avg_fan_out: f64,
+    pub max_fan_in: usize,
+    pub max_fan_out: usize,
+}
+
+/// Évolution par rapport à la mesure de référence.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct ChurnMetrics {
+    /// Horodatage (ou libellé) de la mesure de référence
+    pub baseline: String,
+    pub added: usize,
+    pub removed: usize,
+    pub modified: usize,
+    /// (ajoutés + supprimés + modifiés) / taille de la référence
+    pub ratio: f64,
+}
+
+/// Rapport de métriques MBSE, sérialisé tel quel pour le tableau de bord.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct ModelMetricsReport {
+    pub computed_at: String,
+    pub label: Option<String>,
+    pub total_elements: usize,
+    pub layers: OrderedMap<String, LayerSize>,
+    pub allocation: OrderedMap<String, AllocationRatio>,
+    pub interfaces: InterfaceComplexity,
+    pub coupling: FanMetrics,
+    pub churn: Option<ChurnMetrics>,
+    /// Empreinte de chaque élément, base du calcul de churn de la mesure suivante
+    #[serde(default)]
+    pub fingerprints: OrderedMap<String, String>,
+}
+
+impl ModelMetricsReport {
+    /// Calcule les métriques du modèle, le churn étant mesuré contre `baseline` si fournie.
+    pub fn compute(model: &ProjectModel, baseline: Option<&ModelMetricsReport>) -> Self {
+        let mut report = Self {
+            computed_at: UtcClock::now().to_rfc3339(),
+            ..Default::default()
+        };
+
+        for (layer, collections) in &model.layers {
+            let size = report.layers.entry(layer.clone()).or_default();
+            for (name, elements) in collections {
+                size.collections.insert(name.clone(), elements.len());
+                size.total += elements.len();
+            }
+            report.total_elements += size.total;
+        }
+
+        // Les empreintes de la référence peuvent dater d'un autre algorithme (ou du format
+        // historique sans préfixe) : le churn compare dans le format de la référence.
+        let reference = baseline.and_then(|b| b.fingerprints.values().next().cloned());
+        let mut comparable = OrderedMap::new();
+        for element in model.all_elements() {
+            let Ok(value) = json::serialize_to_value(element) else {
+                continue;
+            };
+            let fingerprint = hash_canonical(HashAlgorithm::default(), &value);
+            if let Some(reference) = &reference {
+                comparable.insert(element.id.clone(), hash_like(reference, &value));
+            }
+            report.fingerprints.insert(element.id.clone(), fingerprint);
+        }
+        if reference.is_none() {
+            comparable = report.fingerprints.clone();
+        }
+
+        report.allocation = allocation_ratios(model);
+        report.interfaces = interface_complexity(model);
+        report.coupling = fan_metrics(model);
+        report.churn = baseline.map(|b| churn(b, &comparable));
+        report
+    }
+
+    pub fn with_label(mut self, label: Option<String>) -> Self {
+        self.label = label;
+        self
+    }
+}
+
+fn ratio(part: usize, whole: usize) -> f64 {
+    match whole {
+        0 => 0.0,
+        n => part as f64 / n as f64,
+    }
+}
+
+fn id_list(value: Option<&JsonValue>) -> Vec<&str> {
+    match value {
+        Some(JsonValue::String(id)) => vec![id.as_str()],
+        Some(JsonValue::Array(ids)) => ids.iter().filter_map(|v| v.as_str()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn allocation_ratios(model: &ProjectModel) -> OrderedMap<String, AllocationRatio> {
+    let allocated: UniqueSet<&str> = model
+        .all_elements()
+        .into_iter()
+        .flat_map(|e| id_list(e.properties.get(PROP_ALLOCATED_FUNCTIONS)))
+        .collect();
+
+    let mut ratios = OrderedMap::new();
+    for layer in model.layers.keys() {
+        let functions = model.get_collection(layer, "functions");
+        if functions.is_empty() {
+            continue;
+        }
+        let count = functions
+            .iter()
+            .filter(|f| allocated.contains(f.id.as_str()))
+            .count();
+        ratios.insert(
+            layer.clone(),
+            AllocationRatio {
+                functions: functions.len(),
+                allocated: count,
+                ratio: ratio(count, functions.len()),
+            },
+        );
+    }
+    ratios
+}
+
+/// Toutes les collections `exchanges` du modèle, sous forme de paires (source, cible).
+fn exchange_pairs(model: &ProjectModel) -> Vec<(&str, &str)> {
+    model
+        .layers
+        .values()
+        .filter_map(|collections| collections.get("exchanges"))
+        .flatten()
+        .filter_map(|e| {
+            let source = e.properties.get("source")?.as_str()?;
+            let target = e.properties.get("target")?.as_str()?;
+            Some((source, target))
+        })
+        .collect()
+}
+
+fn interface_complexity(model: &ProjectModel) -> InterfaceComplexity {
+    let exchanges = exchange_pairs(model);
+    let components: Vec<_> = model
+        .layers
+        .values()
+        .filter_map(|collections| collections.get("components"))
+        .flatten()
+        .collect();
+
+    let mut metrics = InterfaceComplexity {
+        exchanges: exchanges.len(),
+        ..Default::default()
+    };
+    let mut total = 0;
+    for component in &components {
+        let mut inside: UniqueSet<&str> =
+            id_list(component.properties.get(PROP_ALLOCATED_FUNCTIONS))
+                .into_iter()
+                .collect();
+        inside.insert(component.id.as_str());
+
+        let crossing = exchanges
+            .iter()
+            .filter(|(s, t)| inside.contains(s) != inside.contains(t))
+            .count();
+        total += crossing;
+        if crossing > metrics.max_exchanges {
+            metrics.max_exchanges = crossing;
+            metrics.max_component = Some(component.id.clone());
+        }
+    }
+    metrics.avg_per_component = ratio(total, components.len());
+    metrics
+}
+
+fn fan_metrics(model: &ProjectModel) -> FanMetrics {
+    let mut fan_in: UnorderedMap<&str, usize> = UnorderedMap::new();
+    let mut fan_out: UnorderedMap<&str, usize> = UnorderedMap::new();
+    for (source, target) in exchange_pairs(model) {
+        *fan_out.entry(source).or_insert(0) += 1;
+        *fan_in.entry(target).or_insert(0) += 1;
+    }
+
+    FanMetrics {
+        avg_fan_in: ratio(fan_in.values().sum(), fan_in.len()),
+        avg_fan_out: ratio(fan_out.values().sum(), fan_out.len()),
+        max_fan_in: fan_in.values().copied().max().unwrap_or(0),
+        max_fan_out: fan_out.values().copied().max().unwrap_or(0),
+    }
+}
+
+fn churn(baseline: &ModelMetricsReport, current: &OrderedMap<String, String>) -> ChurnMetrics {
+    let before = &baseline.fingerprints;
+    let added = current
+        .keys()
+        .filter(|id| !before.contains_key(*id))
+        .count();
+    let removed = before
+        .keys()
+        .filter(|id| !current.contains_key(*id))
+        .count();
+    let modified = current
+        .iter()
+        .filter(|(id, hash)| before.get(*id).is_some_and(|h| h != *hash))
+        .count();
+
+    ChurnMetrics {
+        baseline: baseline
+            .label
+            .clone()
+            .unwrap_or_else(|| baseline.computed_at.clone()),
+        added,
+        removed,
+        modified,
+        ratio: ratio(added + removed + modified, before.len()),
+    }
+}
+
+// =========================================================================
+// HISTORIQUE (JsonDb)
+// =========================================================================
+
+/// Historique des mesures d'un projet, pour les courbes de tendance.
+pub struct ModelMetricsHistory<'a> {
+    manager: &'a CollectionsManager<'a>,
+}
+
+impl<'a> ModelMetricsHistory<'a> {
+    pub fn new(manager: &'a CollectionsManager<'a>) -> Self {
+        Self { manager }
+    }
+
+    /// Mesure le modèle contre la dernière mesure enregistrée, puis l'historise.
+    pub async fn snapshot(
+        &self,
+        model: &ProjectModel,
+        label: Option<String>,
+    ) -> RaiseResult<ModelMetricsReport> {
+        let previous = self.latest().await?;
+        let report = ModelMetricsReport::compute(model, previous.as_ref()).with_label(label);
+        self.record(&report).await?;
+        Ok(report)
+    }
+
+    pub async fn record(&self, report: &ModelMetricsReport) -> RaiseResult<()> {
+        self.ensure_collection().await?;
+        let mut doc = json::serialize_to_value(report)?;
+        doc["_id"] = json_value!(UniqueId::new_v4().to_string());
+        self.manager
+            .upsert_document(METRICS_COLLECTION, doc)
+            .await?;
+        Ok(())
+    }
+
+    /// Mesures de la plus ancienne à la plus récente (les `limit` dernières si précisé).
+    /// Les empreintes sont omises : seules les séries chiffrées servent aux graphiques.
+    pub async fn trend(&self, limit: Option<usize>) -> RaiseResult<Vec<ModelMetricsReport>> {
+        let mut reports = self.load_all().await?;
+        if let Some(limit) = limit {
+            let skip = reports.len().saturating_sub(limit);
+            reports.drain(..skip);
+        }
+        for report in &mut reports {
+            report.fingerprints.clear();
+        }
+        Ok(reports)
+    }
+
+    pub async fn latest(&self) -> RaiseResult<Option<ModelMetricsReport>> {
+        Ok(self.load_all().await?.pop())
+    }
+
+    async fn load_all(&self) -> RaiseResult<Vec<ModelMetricsReport>> {
+        let docs = match self.manager.list_all(METRICS_COLLECTION).await {
+            Ok(docs) => docs,
+            // Aucune mesure encore enregistrée
+            Err(_) => return Ok(Vec::new()),
+        };
+        let mut reports: Vec<ModelMetricsReport> = docs
+            .into_iter()
+            .filter_map(|doc| json::deserialize_from_value(doc).ok())
+            .collect();
+        reports.sort_by(|a, b| a.computed_at.cmp(&b.computed_at));
+        Ok(reports)
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, METRICS_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(METRICS_COLLECTION, &schema_uri).await
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_engine::types::{ArcadiaElement, NameType};
+    use crate::utils::testing::DbSandbox;
+
+    fn element(id: &str, props: JsonValue) -> ArcadiaElement {
+        ArcadiaElement {
+            id: id.into(),
+            name: NameType::String(id.into()),
+            kind: "Element".into(),
+            properties: json::deserialize_from_value(props).unwrap(),
+        }
+    }
+
+    fn avionics_model() -> ProjectModel {
+        let mut model = ProjectModel::default();
+        for f in ["F1", "F2", "F3", "F4"] {
+            model.add_element("la", "functions", element(f, json_value!({})));
+        }
+        model.add_element(
+            "la",
+            "components",
+            element("C1", json_value!({ "allocatedFunctions": ["F1", "F2"] })),
+        );
+        model.add_element(
+            "la",
+            "components",
+            element("C2", json_value!({ "allocatedFunctions": "F3" })),
+        );
+        for (id, s, t) in [
+            ("E1", "F1", "F2"),
+            ("E2", "F1", "F3"),
+            ("E3", "F2", "F3"),
+            ("E4", "F4", "F3"),
+        ] {
+            model.add_element(
+                "la",
+                "exchanges",
+                element(id, json_value!({ "source": s, "target": t })),
+            );
+        }
+        model.add_element("sa", "actors", element("A1", json_value!({})));
+        model
+    }
+
+    #[test]
+    fn test_structure_metrics() {
+        let report = ModelMetricsReport::compute(&avionics_model(), None);
+
+        assert_eq!(report.total_elements, 11);
+        assert_eq!(report.layers["la"].total, 10);
+        assert_eq!(report.layers["la"].collections["exchanges"], 4);
+        assert_eq!(report.layers["sa"].total, 1);
+
+        let la = &report.allocation["la"];
+        assert_eq!((la.functions, la.allocated), (4, 3));
+        assert_eq!(la.ratio, 0.75);
+
+        // C1 (F1, F2) : E2 et E3 sortent ; C2 (F3) : E2, E3, E4 entrent
+        assert_eq!(report.interfaces.exchanges, 4);
+        assert_eq!(report.interfaces.max_component.as_deref(), Some("C2"));
+        assert_eq!(report.interfaces.max_exchanges, 3);
+        assert_eq!(report.interfaces.avg_per_component, 2.5);
+
+        // Entrants : F2 (1), F3 (3) ; sortants : F1 (2), F2 (1), F4 (1)
+        assert_eq!(report.coupling.avg_fan_in, 2.0);
+        assert_eq!(report.coupling.max_fan_in, 3);
+        assert!((report.coupling.avg_fan_out - 4.0 / 3.0).abs() < 1e-9);
+        assert!(report.churn.is_none());
+    }
+
+    #[test]
+    fn test_churn_against_baseline() {
+        let baseline =
+            ModelMetricsReport::compute(&avionics_model(), None).with_label(Some("PDR".into()));
+
+        let mut model = avionics_model();
+        model.layers.get_mut("sa").unwrap().clear();
+        model.add_element("la", "functions", element("F5", json_value!({})));
+        let c1 = &mut model
+            .layers
+            .get_mut("la")
+            .unwrap()
+            .get_mut("components")
+            .unwrap()[0];
+        c1.properties
+            .insert("allocatedFunctions".into(), json_value!(["F1", "F2", "F5"]));
+
+        let churn = ModelMetricsReport::compute(&model, Some(&baseline))
+            .churn
+            .unwrap();
+        assert_eq!(churn.baseline, "PDR");
+        assert_eq!((churn.added, churn.removed, churn.modified), (1, 1, 1));
+        assert!((churn.ratio - 3.0 / 11.0).abs() < 1e-9);
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_history_snapshots_and_trend() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        let history = ModelMetricsHistory::new(&manager);
+        assert!(history.latest().await?.is_none());
+
+        let mut model = avionics_model();
+        let first = history.snapshot(&model, Some("SRR".into())).await?;
+        assert!(first.churn.is_none());
+
+        model.add_element("la", "functions", element("F9", json_value!({})));
+        let second = history.snapshot(&model, None).await?;
+        assert_eq!(second.churn.as_ref().map(|c| c.added), Some(1));
+
+        let trend = history.trend(None).await?;
+        assert_eq!(trend.len(), 2);
+        assert_eq!(trend[0].label.as_deref(), Some("SRR"));
+        assert!(trend[1].fingerprints.is_empty());
+        assert_eq!(history.trend(Some(1)).await?[0].total_elements, 12);
+        Ok(())
+    }
+}
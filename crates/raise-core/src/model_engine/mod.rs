@@ -7,7 +7,11 @@ pub mod types;
 
 // 2. Modules de Logique Métier (Les fonctionnalités)
 pub mod arcadia; // Définitions sémantiques (OA, SA, LA, PA)
+pub mod autosar; // Export ARXML (chaîne outillée AUTOSAR)
 pub mod capella; // Support des fichiers .capella / .aird
+pub mod metrics; // Tableau de bord MBSE (taille, allocation, couplage, churn)
+pub mod patterns; // Bibliothèque de patrons d'éléments paramétrés
+pub mod refactor; // Renommage / déplacement / changement de type avec mise à jour des références
 pub mod sysml2;
 pub mod transformers; // Génération de code et conversion
 pub mod validators; // Vérification de cohérence
@@ -40,6 +44,18 @@ pub use capella::{CapellaReader, CapellaXmiParser};
 
 pub use sysml2::{Sysml2Parser, Sysml2ToArcadiaMapper};
 
+// Métriques MBSE
+pub use metrics::{ModelMetricsHistory, ModelMetricsReport};
+
+// Patrons d'éléments
+pub use patterns::{ElementPattern, PatternInstance, PatternLibrary};
+
+// Refactoring transactionnel
+pub use refactor::{ModelRefactor, RefactorOp, RefactorPlan};
+
+// AUTOSAR (Export)
+pub use autosar::{ArxmlExportReport, ArxmlExporter, ArxmlMappingProfile};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,236 @@
+// FICHIER : src-tauri/src/model_engine/patterns/library.rs
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::model_engine::ingestion::ModelIngestionService;
+use crate::model_engine::loader::ModelLoader;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+use super::pattern::{ElementPattern, PatternInstance};
+
+/// Collection JsonDb des patrons définis par les utilisateurs.
+pub const PATTERNS_COLLECTION: &str = "element_patterns";
+
+/// Bibliothèque de patrons : patrons natifs complétés (ou surchargés, même `handle`)
+/// par ceux enregistrés en base.
+pub struct PatternLibrary<'a> {
+    manager: &'a CollectionsManager<'a>,
+}
+
+impl<'a> PatternLibrary<'a> {
+    pub fn new(manager: &'a CollectionsManager<'a>) -> Self {
+        Self { manager }
+    }
+
+    pub async fn list(&self) -> RaiseResult<Vec<ElementPattern>> {
+        let mut patterns: OrderedMap<String, ElementPattern> = builtin_patterns()
+            .into_iter()
+            .map(|p| (p.handle.clone(), p))
+            .collect();
+        for pattern in self.stored().await? {
+            patterns.insert(pattern.handle.clone(), pattern);
+        }
+        Ok(patterns.into_values().collect())
+    }
+
+    pub async fn get(&self, handle: &str) -> RaiseResult<ElementPattern> {
+        match self.list().await?.into_iter().find(|p| p.handle == handle) {
+            Some(pattern) => Ok(pattern),
+            None => raise_error!(
+                "ERR_PATTERN_NOT_FOUND",
+                context = json_value!({ "handle": handle })
+            ),
+        }
+    }
+
+    /// Enregistre (ou remplace) un patron après validation.
+    pub async fn save(&self, pattern: &ElementPattern) -> RaiseResult<()> {
+        pattern.validate()?;
+        self.ensure_collection().await?;
+        let mut doc = json::serialize_to_value(pattern)?;
+        doc["_id"] = json_value!(pattern.handle);
+        self.manager
+            .upsert_document(PATTERNS_COLLECTION, doc)
+            .await?;
+        user_success!(
+            "SUC_PATTERN_SAVED",
+            json_value!({ "handle": pattern.handle })
+        );
+        Ok(())
+    }
+
+    /// Instancie le patron dans le projet `target` puis persiste les éléments créés.
+    pub async fn instantiate_into(
+        &self,
+        handle: &str,
+        args: &JsonObject<String, JsonValue>,
+        target: &CollectionsManager<'_>,
+    ) -> RaiseResult<PatternInstance> {
+        let pattern = self.get(handle).await?;
+
+        // Modèle courant du projet : nécessaire pour dédoublonner les noms
+        let loader = ModelLoader::new_with_manager(CollectionsManager::new(
+            target.storage,
+            &target.space,
+            &target.db,
+        ))?;
+        let model = loader.load_full_model().await?;
+
+        let instance = pattern.instantiate(args, &model)?;
+        ModelIngestionService::persist_model(&instance.to_model(), target).await?;
+        Ok(instance)
+    }
+
+    async fn stored(&self) -> RaiseResult<Vec<ElementPattern>> {
+        let docs = match self.manager.list_all(PATTERNS_COLLECTION).await {
+            Ok(docs) => docs,
+            // Aucun patron utilisateur enregistré
+            Err(_) => return Ok(Vec::new()),
+        };
+        let mut patterns = Vec::new();
+        for doc in docs {
+            match json::deserialize_from_value::<ElementPattern>(doc) {
+                Ok(pattern) => patterns.push(pattern),
+                Err(e) => user_warn!(
+                    "WRN_PATTERN_CORRUPTED",
+                    json_value!({ "error": e.to_string() })
+                ),
+            }
+        }
+        Ok(patterns)
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, PATTERNS_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(PATTERNS_COLLECTION, &schema_uri)
+            .await
+    }
+}
+
+// =========================================================================
+// PATRONS NATIFS
+// =========================================================================
+
+/// Patrons livrés avec RAISE.
+pub fn builtin_patterns() -> Vec<ElementPattern> {
+    let definitions = [
+        json_value!({
+            "handle": "redundant_sensor_pair",
+            "name": "Paire de capteurs redondants",
+            "description": "Deux capteurs identiques et un voteur qui consolide leurs mesures (architecture 2oo2).",
+            "parameters": [
+                { "name": "sensor", "description": "Nom du capteur (ex : Wheel Speed)" },
+                { "name": "measure", "description": "Grandeur mesurée", "default": "Measure" }
+            ],
+            "naming": { "case": "pascal" },
+            "elements": [
+                { "key": "primary", "layer": "pa", "collection": "components", "kind": "PhysicalComponent",
+                  "name": "{{sensor}} Sensor A", "properties": { "nature": "NODE", "redundancyGroup": "{{sensor}}", "redundantWith": "@secondary" } },
+                { "key": "secondary", "layer": "pa", "collection": "components", "kind": "PhysicalComponent",
+                  "name": "{{sensor}} Sensor B", "properties": { "nature": "NODE", "redundancyGroup": "{{sensor}}", "redundantWith": "@primary" } },
+                { "key": "voter", "layer": "pa", "collection": "components", "kind": "PhysicalComponent",
+                  "name": "{{sensor}} Voter", "properties": { "nature": "BEHAVIOR" } },
+                { "key": "link_a", "layer": "pa", "collection": "exchanges", "kind": "ComponentExchange",
+                  "name": "{{measure}} A", "properties": { "source": "@primary", "target": "@voter" } },
+                { "key": "link_b", "layer": "pa", "collection": "exchanges", "kind": "ComponentExchange",
+                  "name": "{{measure}} B", "properties": { "source": "@secondary", "target": "@voter" } }
+            ]
+        }),
+        json_value!({
+            "handle": "client_server_pair",
+            "name": "Paire client-serveur",
+            "description": "Un composant client et un composant serveur reliés par un échange requête / réponse.",
+            "parameters": [
+                { "name": "service", "description": "Nom du service rendu (ex : Navigation)" },
+                { "name": "protocol", "description": "Protocole des échanges", "default": "RPC" }
+            ],
+            "naming": { "case": "pascal" },
+            "elements": [
+                { "key": "client", "layer": "la", "collection": "components", "kind": "LogicalComponent",
+                  "name": "{{service}} Client", "properties": { "role": "client" } },
+                { "key": "server", "layer": "la", "collection": "components", "kind": "LogicalComponent",
+                  "name": "{{service}} Server", "properties": { "role": "server" } },
+                { "key": "request", "layer": "la", "collection": "exchanges", "kind": "ComponentExchange",
+                  "name": "{{service}} Request", "properties": { "source": "@client", "target": "@server", "protocol": "{{protocol}}" } },
+                { "key": "response", "layer": "la", "collection": "exchanges", "kind": "ComponentExchange",
+                  "name": "{{service}} Response", "properties": { "source": "@server", "target": "@client", "protocol": "{{protocol}}" } }
+            ]
+        }),
+    ];
+
+    definitions
+        .into_iter()
+        .filter_map(|def| json::deserialize_from_value(def).ok())
+        .collect()
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_builtin_patterns_are_valid() {
+        let builtins = builtin_patterns();
+        assert_eq!(builtins.len(), 2);
+        for pattern in &builtins {
+            assert!(pattern.validate().is_ok(), "{} invalide", pattern.handle);
+        }
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_library_save_override_and_instantiate() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        let library = PatternLibrary::new(&manager);
+
+        // Surcharge d'un patron natif par un patron utilisateur
+        let mut custom = library.get("client_server_pair").await?;
+        custom.naming.prefix = "Svc ".into();
+        library.save(&custom).await?;
+        assert_eq!(library.list().await?.len(), 2);
+        assert_eq!(
+            library.get("client_server_pair").await?.naming.prefix,
+            "Svc "
+        );
+
+        let mut invalid = custom.clone();
+        invalid.handle = "broken".into();
+        invalid.elements.clear();
+        assert!(library.save(&invalid).await.is_err());
+        assert!(library.get("broken").await.is_err());
+
+        let args = json::deserialize_from_value(json_value!({ "service": "Navigation" }))?;
+        let instance = library
+            .instantiate_into("client_server_pair", &args, &manager)
+            .await?;
+        assert_eq!(instance.elements.len(), 4);
+        assert_eq!(
+            instance.elements[0].element.name.as_str(),
+            "SvcNavigationClient"
+        );
+        Ok(())
+    }
+}
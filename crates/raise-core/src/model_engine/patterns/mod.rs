@@ -0,0 +1,11 @@
+// FICHIER : src-tauri/src/model_engine/patterns/mod.rs
+
+pub mod library;
+pub mod pattern;
+
+// Re-exports
+pub use library::{builtin_patterns, PatternLibrary};
+pub use pattern::{
+    ElementPattern, ElementTemplate, NameCase, NamingConvention, PatternInstance, PatternParameter,
+    PlacedElement,
+};
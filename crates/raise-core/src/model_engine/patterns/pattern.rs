@@ -0,0 +1,485 @@
+// FICHIER : src-tauri/src/model_engine/patterns/pattern.rs
+
+use crate::model_engine::arcadia::ArcadiaOntology;
+use crate::model_engine::types::{ArcadiaElement, NameType, ProjectModel};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Propriété posée sur chaque élément créé : identifiant de l'instanciation d'origine.
+pub const PROP_PATTERN_INSTANCE: &str = "patternInstance";
+
+/// Paramètre d'un patron (`{{nom}}` dans les noms et propriétés).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct PatternParameter {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Valeur par défaut ; sans elle, le paramètre est obligatoire
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+/// Convention de casse appliquée aux noms générés.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum NameCase {
+    #[default]
+    AsIs,
+    Pascal,
+    Snake,
+    UpperSnake,
+}
+
+/// Règles de nommage : préfixe, casse, puis suffixe numérique si le nom est déjà pris.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+#[serde(default)]
+pub struct NamingConvention {
+    pub prefix: String,
+    pub case: NameCase,
+}
+
+impl NamingConvention {
+    pub fn apply(&self, raw: &str) -> String {
+        let name = format!("{}{}", self.prefix, raw);
+        let words: Vec<&str> = name
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect();
+        match self.case {
+            NameCase::AsIs => name,
+            NameCase::Pascal => words
+                .iter()
+                .map(|w| {
+                    let mut chars = w.chars();
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                        None => String::new(),
+                    }
+                })
+                .collect(),
+            NameCase::Snake => words.join("_").to_lowercase(),
+            NameCase::UpperSnake => words.join("_").to_uppercase(),
+        }
+    }
+
+    /// Variante libre de `name` parmi `taken` (`Capteur 2`, `Capteur_2`, `Capteur2`...).
+    fn unique(&self, name: String, taken: &UniqueSet<String>) -> String {
+        if !taken.contains(&name) {
+            return name;
+        }
+        let separator = match self.case {
+            NameCase::AsIs => " ",
+            NameCase::Pascal => "",
+            NameCase::Snake | NameCase::UpperSnake => "_",
+        };
+        (2..)
+            .map(|n| format!("{}{}{}", name, separator, n))
+            .find(|candidate| !taken.contains(candidate))
+            .unwrap_or(name)
+    }
+}
+
+/// Gabarit d'élément. Les chaînes peuvent contenir `{{param}}` ; une valeur exactement
+/// égale à `@clé` est remplacée par l'identifiant de l'élément `clé` du même patron.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ElementTemplate {
+    pub key: String,
+    pub layer: String,
+    pub collection: String,
+    pub kind: String,
+    pub name: String,
+    #[serde(default)]
+    pub properties: JsonObject<String, JsonValue>,
+}
+
+/// Patron réutilisable : lot d'éléments paramétré, instancié en une seule opération.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ElementPattern {
+    pub handle: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub parameters: Vec<PatternParameter>,
+    #[serde(default)]
+    pub naming: NamingConvention,
+    pub elements: Vec<ElementTemplate>,
+}
+
+/// Élément produit par une instanciation, avec sa destination dans le graphe.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct PlacedElement {
+    pub key: String,
+    pub layer: String,
+    pub collection: String,
+    pub element: ArcadiaElement,
+}
+
+/// Résultat d'une instanciation (non encore appliqué au modèle).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct PatternInstance {
+    pub pattern: String,
+    pub instance_id: String,
+    pub elements: Vec<PlacedElement>,
+}
+
+impl PatternInstance {
+    pub fn apply_to(&self, model: &mut ProjectModel) {
+        for placed in &self.elements {
+            model.add_element(&placed.layer, &placed.collection, placed.element.clone());
+        }
+    }
+
+    /// Modèle ne contenant que les éléments créés (pour la persistance).
+    pub fn to_model(&self) -> ProjectModel {
+        let mut model = ProjectModel::default();
+        self.apply_to(&mut model);
+        model.meta.element_count = self.elements.len();
+        model
+    }
+}
+
+impl ElementPattern {
+    /// Cohérence du patron : clés uniques, références `@clé` et `{{param}}` déclarées.
+    pub fn validate(&self) -> RaiseResult<()> {
+        let mut problems = Vec::new();
+        if self.handle.trim().is_empty() {
+            problems.push("handle vide".to_string());
+        }
+        if self.elements.is_empty() {
+            problems.push("aucun élément".to_string());
+        }
+
+        let mut keys = UniqueSet::new();
+        for template in &self.elements {
+            if !keys.insert(template.key.as_str()) {
+                problems.push(format!("clé dupliquée '{}'", template.key));
+            }
+        }
+        let params: UniqueSet<&str> = self.parameters.iter().map(|p| p.name.as_str()).collect();
+
+        for template in &self.elements {
+            let mut strings = vec![template.name.as_str()];
+            collect_strings(&template.properties, &mut strings);
+            for s in strings {
+                if let Some(target) = s.strip_prefix('@') {
+                    if !keys.contains(target) {
+                        problems.push(format!("{} : référence inconnue '{}'", template.key, s));
+                    }
+                }
+                for param in placeholders(s) {
+                    if !params.contains(param) {
+                        problems.push(format!(
+                            "{} : paramètre non déclaré '{}'",
+                            template.key, param
+                        ));
+                    }
+                }
+            }
+        }
+
+        if !problems.is_empty() {
+            raise_error!(
+                "ERR_PATTERN_INVALID",
+                error = problems.join(" ; "),
+                context = json_value!({ "pattern": self.handle })
+            );
+        }
+        Ok(())
+    }
+
+    /// Produit les éléments du patron pour `model` : paramètres résolus, identifiants neufs,
+    /// noms conformes à la convention et uniques dans leur collection cible.
+    pub fn instantiate(
+        &self,
+        args: &JsonObject<String, JsonValue>,
+        model: &ProjectModel,
+    ) -> RaiseResult<PatternInstance> {
+        self.validate()?;
+        let values = self.resolve_parameters(args)?;
+
+        let ids: UnorderedMap<&str, String> = self
+            .elements
+            .iter()
+            .map(|t| (t.key.as_str(), UniqueId::new_v4().to_string()))
+            .collect();
+        let instance_id = UniqueId::new_v4().to_string();
+
+        let mut taken: UnorderedMap<(String, String), UniqueSet<String>> = UnorderedMap::new();
+        let mut elements = Vec::with_capacity(self.elements.len());
+        for template in &self.elements {
+            let names = taken
+                .entry((template.layer.clone(), template.collection.clone()))
+                .or_insert_with(|| {
+                    model
+                        .get_collection(&template.layer, &template.collection)
+                        .iter()
+                        .map(|e| e.name.as_str().to_string())
+                        .collect()
+                });
+            let name = self
+                .naming
+                .unique(self.naming.apply(&render(&template.name, &values)), names);
+            names.insert(name.clone());
+
+            let mut properties: UnorderedMap<String, JsonValue> = template
+                .properties
+                .iter()
+                .map(|(k, v)| (k.clone(), substitute(v, &values, &ids)))
+                .collect();
+            properties.insert(PROP_PATTERN_INSTANCE.into(), json_value!(instance_id));
+
+            elements.push(PlacedElement {
+                key: template.key.clone(),
+                layer: template.layer.clone(),
+                collection: template.collection.clone(),
+                element: ArcadiaElement {
+                    id: ids[template.key.as_str()].clone(),
+                    name: NameType::String(name),
+                    kind: ArcadiaOntology::get_uri(&template.layer, &template.kind)
+                        .unwrap_or_else(|| template.kind.clone()),
+                    properties,
+                },
+            });
+        }
+
+        user_info!(
+            "INF_PATTERN_INSTANTIATED",
+            json_value!({ "pattern": self.handle, "elements": elements.len() })
+        );
+        Ok(PatternInstance {
+            pattern: self.handle.clone(),
+            instance_id,
+            elements,
+        })
+    }
+
+    fn resolve_parameters(
+        &self,
+        args: &JsonObject<String, JsonValue>,
+    ) -> RaiseResult<UnorderedMap<String, String>> {
+        let unknown: Vec<&String> = args
+            .keys()
+            .filter(|k| !self.parameters.iter().any(|p| &p.name == *k))
+            .collect();
+        if !unknown.is_empty() {
+            raise_error!(
+                "ERR_PATTERN_PARAM_UNKNOWN",
+                context = json_value!({ "pattern": self.handle, "unknown": unknown })
+            );
+        }
+
+        let mut values = UnorderedMap::new();
+        let mut missing = Vec::new();
+        for param in &self.parameters {
+            let value = match args.get(&param.name) {
+                Some(JsonValue::String(s)) => Some(s.clone()),
+                Some(JsonValue::Null) | None => param.default.clone(),
+                Some(other) => Some(other.to_string()),
+            };
+            match value {
+                Some(v) => {
+                    values.insert(param.name.clone(), v);
+                }
+                None => missing.push(param.name.as_str()),
+            }
+        }
+        if !missing.is_empty() {
+            raise_error!(
+                "ERR_PATTERN_PARAM_MISSING",
+                context = json_value!({ "pattern": self.handle, "missing": missing })
+            );
+        }
+        Ok(values)
+    }
+}
+
+/// Noms des paramètres `{{...}}` présents dans `s`.
+fn placeholders(s: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                found.push(after[..end].trim());
+                rest = &after[end + 2..];
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+fn render(s: &str, values: &UnorderedMap<String, String>) -> String {
+    let mut out = s.to_string();
+    for param in placeholders(s) {
+        if let Some(value) = values.get(param) {
+            out = out.replace(&format!("{{{{{}}}}}", param), value);
+        }
+    }
+    out
+}
+
+fn substitute(
+    value: &JsonValue,
+    values: &UnorderedMap<String, String>,
+    ids: &UnorderedMap<&str, String>,
+) -> JsonValue {
+    match value {
+        JsonValue::String(s) => match s.strip_prefix('@').and_then(|k| ids.get(k)) {
+            Some(id) => json_value!(id),
+            None => json_value!(render(s, values)),
+        },
+        JsonValue::Array(items) => {
+            JsonValue::Array(items.iter().map(|v| substitute(v, values, ids)).collect())
+        }
+        JsonValue::Object(map) => JsonValue::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), substitute(v, values, ids)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn collect_strings<'v>(map: &'v JsonObject<String, JsonValue>, out: &mut Vec<&'v str>) {
+    fn walk<'v>(value: &'v JsonValue, out: &mut Vec<&'v str>) {
+        match value {
+            JsonValue::String(s) => out.push(s),
+            JsonValue::Array(items) => items.iter().for_each(|v| walk(v, out)),
+            JsonValue::Object(map) => map.values().for_each(|v| walk(v, out)),
+            _ => {}
+        }
+    }
+    map.values().for_each(|v| walk(v, out));
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sensor_pair() -> ElementPattern {
+        json::deserialize_from_value(json_value!({
+            "handle": "sensor_pair",
+            "name": "Paire de capteurs",
+            "parameters": [
+                { "name": "sensor" },
+                { "name": "measure", "default": "mesure" }
+            ],
+            "naming": { "case": "pascal" },
+            "elements": [
+                { "key": "a", "layer": "pa", "collection": "components", "kind": "PhysicalComponent",
+                  "name": "{{sensor}} A", "properties": { "redundancyGroup": "{{sensor}}", "peer": "@b" } },
+                { "key": "b", "layer": "pa", "collection": "components", "kind": "PhysicalComponent",
+                  "name": "{{sensor}} B", "properties": { "peer": "@a" } },
+                { "key": "link", "layer": "pa", "collection": "exchanges", "kind": "ComponentExchange",
+                  "name": "{{measure}} {{sensor}}", "properties": { "source": "@a", "target": ["@b"] } }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_naming_conventions() {
+        let naming = |case| NamingConvention {
+            prefix: "pa ".into(),
+            case,
+        };
+        assert_eq!(
+            naming(NameCase::AsIs).apply("wheel speed"),
+            "pa wheel speed"
+        );
+        assert_eq!(
+            naming(NameCase::Pascal).apply("wheel speed"),
+            "PaWheelSpeed"
+        );
+        assert_eq!(
+            naming(NameCase::Snake).apply("Wheel-Speed"),
+            "pa_wheel_speed"
+        );
+        assert_eq!(
+            naming(NameCase::UpperSnake).apply("wheel speed"),
+            "PA_WHEEL_SPEED"
+        );
+    }
+
+    #[test]
+    fn test_instantiate_resolves_params_refs_and_names() -> RaiseResult<()> {
+        let pattern = sensor_pair();
+        let mut model = ProjectModel::default();
+        model.add_element(
+            "pa",
+            "components",
+            ArcadiaElement {
+                id: "existing".into(),
+                name: NameType::String("WheelSpeedA".into()),
+                ..Default::default()
+            },
+        );
+
+        let args = json::deserialize_from_value(json_value!({ "sensor": "wheel speed" }))?;
+        let instance = pattern.instantiate(&args, &model)?;
+        assert_eq!(instance.elements.len(), 3);
+
+        let a = &instance.elements[0].element;
+        let b = &instance.elements[1].element;
+        let link = &instance.elements[2].element;
+        // Nom déjà pris dans la collection : suffixe numérique
+        assert_eq!(a.name.as_str(), "WheelSpeedA2");
+        assert_eq!(b.name.as_str(), "WheelSpeedB");
+        assert_eq!(link.name.as_str(), "MesureWheelSpeed");
+
+        assert_eq!(a.properties["redundancyGroup"], json_value!("wheel speed"));
+        assert_eq!(a.properties["peer"], json_value!(b.id));
+        assert_eq!(link.properties["source"], json_value!(a.id));
+        assert_eq!(link.properties["target"], json_value!([b.id]));
+        assert_eq!(
+            link.properties[PROP_PATTERN_INSTANCE],
+            json_value!(instance.instance_id)
+        );
+
+        instance.apply_to(&mut model);
+        assert_eq!(model.get_collection("pa", "components").len(), 3);
+
+        // Seconde instanciation : identifiants neufs, noms à nouveau dédoublonnés
+        let again = pattern.instantiate(&args, &model)?;
+        assert_ne!(again.elements[0].element.id, a.id);
+        assert_eq!(again.elements[0].element.name.as_str(), "WheelSpeedA3");
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_patterns_and_arguments() {
+        let pattern = sensor_pair();
+        let model = ProjectModel::default();
+
+        let code = |result: RaiseResult<PatternInstance>| match result {
+            Err(AppError::Structured(err)) => err.code.clone(),
+            _ => "OK".into(),
+        };
+        assert_eq!(
+            code(pattern.instantiate(&JsonObject::new(), &model)),
+            "ERR_PATTERN_PARAM_MISSING"
+        );
+        let typo =
+            json::deserialize_from_value(json_value!({ "sensor": "x", "sensr": "y" })).unwrap();
+        assert_eq!(
+            code(pattern.instantiate(&typo, &model)),
+            "ERR_PATTERN_PARAM_UNKNOWN"
+        );
+
+        let mut broken = pattern.clone();
+        broken.elements[2]
+            .properties
+            .insert("target".into(), json_value!("@ghost"));
+        broken.elements[1].name = "{{undeclared}}".into();
+        match broken.validate() {
+            Err(AppError::Structured(err)) => assert_eq!(err.code, "ERR_PATTERN_INVALID"),
+            _ => panic!("Attendu ERR_PATTERN_INVALID"),
+        }
+    }
+}
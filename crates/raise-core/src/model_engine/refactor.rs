@@ -0,0 +1,652 @@
+// FICHIER : src-tauri/src/model_engine/refactor.rs
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::transactions::TransactionRequest;
+use crate::model_engine::arcadia::element_kind::ArcadiaSemantics;
+use crate::model_engine::types::ArcadiaElement;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Clé de rattachement utilisée lorsque l'ancien parent n'en fournit aucune.
+const DEFAULT_OWNERSHIP_KEY: &str = "ownedElements";
+
+/// Propriétés désignant le parent direct d'un élément.
+const PARENT_KEYS: [&str; 2] = ["parent", "owner"];
+
+/// Opération de refactoring sur un élément du modèle.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum RefactorOp {
+    Rename { id: String, new_name: String },
+    Move { id: String, new_parent: String },
+    ChangeType { id: String, new_kind: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeAction {
+    Update,
+    Create,
+    Delete,
+}
+
+/// Document touché par le refactoring (aperçu).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct RefactorChange {
+    pub collection: String,
+    pub id: String,
+    pub action: ChangeAction,
+    /// Champs de premier niveau modifiés (vide pour une création / suppression)
+    pub fields: Vec<String>,
+}
+
+/// Aperçu d'un lot d'opérations : rien n'est écrit tant que `apply` n'est pas appelé.
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct RefactorPlan {
+    pub operations: Vec<RefactorOp>,
+    pub changes: Vec<RefactorChange>,
+    pub warnings: Vec<String>,
+    #[serde(skip)]
+    documents: OrderedMap<(String, String), JsonValue>,
+}
+
+impl RefactorPlan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Refactoring transactionnel du modèle : renommage, déplacement et changement de type
+/// avec mise à jour de toutes les références (allocations, échanges, traçabilité, manifestes de code).
+pub struct ModelRefactor<'a> {
+    manager: &'a CollectionsManager<'a>,
+}
+
+impl<'a> ModelRefactor<'a> {
+    pub fn new(manager: &'a CollectionsManager<'a>) -> Self {
+        Self { manager }
+    }
+
+    /// Calcule l'aperçu des documents affectés sans rien écrire.
+    pub async fn preview(&self, ops: &[RefactorOp]) -> RaiseResult<RefactorPlan> {
+        let original = self.snapshot().await?;
+        let mut session = RefactorSession {
+            docs: original.clone(),
+            warnings: Vec::new(),
+        };
+        for op in ops {
+            session.apply(op)?;
+        }
+
+        let mut changes = Vec::new();
+        for ((collection, id), doc) in &session.docs {
+            match original.get(&(collection.clone(), id.clone())) {
+                Some(before) if before == doc => {}
+                Some(before) => changes.push(RefactorChange {
+                    collection: collection.clone(),
+                    id: id.clone(),
+                    action: ChangeAction::Update,
+                    fields: changed_fields(before, doc),
+                }),
+                None => changes.push(RefactorChange {
+                    collection: collection.clone(),
+                    id: id.clone(),
+                    action: ChangeAction::Create,
+                    fields: Vec::new(),
+                }),
+            }
+        }
+        for (collection, id) in original.keys() {
+            if !session.docs.contains_key(&(collection.clone(), id.clone())) {
+                changes.push(RefactorChange {
+                    collection: collection.clone(),
+                    id: id.clone(),
+                    action: ChangeAction::Delete,
+                    fields: Vec::new(),
+                });
+            }
+        }
+
+        Ok(RefactorPlan {
+            operations: ops.to_vec(),
+            changes,
+            warnings: session.warnings,
+            documents: session.docs,
+        })
+    }
+
+    /// Applique le lot en une seule transaction : tout ou rien.
+    pub async fn apply(&self, ops: &[RefactorOp]) -> RaiseResult<RefactorPlan> {
+        let plan = self.preview(ops).await?;
+        if plan.is_empty() {
+            return Ok(plan);
+        }
+
+        let existing = self.manager.list_collections().await?;
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            self.manager.space, self.manager.db
+        );
+        let mut requests = Vec::new();
+        for change in &plan.changes {
+            let key = (change.collection.clone(), change.id.clone());
+            match change.action {
+                ChangeAction::Update => requests.push(TransactionRequest::Update {
+                    collection: change.collection.clone(),
+                    id: Some(change.id.clone()),
+                    handle: None,
+                    document: plan.documents[&key].clone(),
+                }),
+                ChangeAction::Create => {
+                    if !existing.contains(&change.collection) {
+                        self.manager
+                            .create_collection(&change.collection, &schema_uri)
+                            .await?;
+                    }
+                    requests.push(TransactionRequest::Insert {
+                        collection: change.collection.clone(),
+                        id: Some(change.id.clone()),
+                        document: plan.documents[&key].clone(),
+                    });
+                }
+                ChangeAction::Delete => requests.push(TransactionRequest::Delete {
+                    collection: change.collection.clone(),
+                    id: change.id.clone(),
+                }),
+            }
+        }
+
+        TransactionManager::new(self.manager.storage, &self.manager.space, &self.manager.db)
+            .execute_smart(requests)
+            .await?;
+
+        user_success!(
+            "SUC_MODEL_REFACTOR_APPLIED",
+            json_value!({ "operations": plan.operations.len(), "documents": plan.changes.len() })
+        );
+        Ok(plan)
+    }
+
+    /// Charge tous les documents du projet (collections techniques `_*` exclues).
+    async fn snapshot(&self) -> RaiseResult<OrderedMap<(String, String), JsonValue>> {
+        let mut docs = OrderedMap::new();
+        for collection in self.manager.list_collections().await? {
+            if collection.starts_with('_') {
+                continue;
+            }
+            for doc in self.manager.list_all(&collection).await? {
+                if let Some(id) = doc.get("_id").and_then(|v| v.as_str()) {
+                    docs.insert((collection.clone(), id.to_string()), doc.clone());
+                }
+            }
+        }
+        Ok(docs)
+    }
+}
+
+// =========================================================================
+// SESSION EN MÉMOIRE
+// =========================================================================
+
+struct RefactorSession {
+    docs: OrderedMap<(String, String), JsonValue>,
+    warnings: Vec<String>,
+}
+
+impl RefactorSession {
+    fn apply(&mut self, op: &RefactorOp) -> RaiseResult<()> {
+        match op {
+            RefactorOp::Rename { id, new_name } => self.rename(id, new_name),
+            RefactorOp::Move { id, new_parent } => self.move_to(id, new_parent),
+            RefactorOp::ChangeType { id, new_kind } => self.change_type(id, new_kind),
+        }
+    }
+
+    /// Localise le document d'un élément du modèle (porteur d'un `type`).
+    fn locate(&self, id: &str) -> RaiseResult<(String, String)> {
+        let found = self
+            .docs
+            .iter()
+            .find(|((_, doc_id), doc)| doc_id == id && doc.get("type").is_some());
+        match found {
+            Some((key, _)) => Ok(key.clone()),
+            None => raise_error!(
+                "ERR_REFACTOR_ELEMENT_NOT_FOUND",
+                context = json_value!({ "element_id": id })
+            ),
+        }
+    }
+
+    fn rename(&mut self, id: &str, new_name: &str) -> RaiseResult<()> {
+        if new_name.trim().is_empty() {
+            raise_error!(
+                "ERR_REFACTOR_INVALID_NAME",
+                context = json_value!({ "element_id": id })
+            );
+        }
+        let key = self.locate(id)?;
+        let old_name = self.docs[&key].get("name").cloned();
+        if let Some(JsonValue::Object(_)) = old_name {
+            self.warnings
+                .push(format!("{id} : nom multilingue remplacé par '{new_name}'"));
+        }
+        self.docs.get_mut(&key).expect("élément localisé")["name"] = json_value!(new_name);
+
+        // Copies dénormalisées du nom à côté de l'identifiant (liens, manifestes…)
+        if let Some(JsonValue::String(old)) = old_name {
+            self.sync_denormalized(
+                &key,
+                id,
+                &["name", "targetName", "sourceName"],
+                &old,
+                new_name,
+            );
+        }
+        Ok(())
+    }
+
+    fn move_to(&mut self, id: &str, new_parent: &str) -> RaiseResult<()> {
+        let key = self.locate(id)?;
+        let parent_key = self.locate(new_parent)?;
+        if id == new_parent || self.descendants(id).contains(new_parent) {
+            raise_error!(
+                "ERR_REFACTOR_MOVE_CYCLE",
+                context = json_value!({ "element_id": id, "new_parent": new_parent })
+            );
+        }
+
+        // 1. Détachement des anciens parents (tableaux `owned*`)
+        let mut ownership_key = None;
+        for (doc_key, doc) in self.docs.iter_mut() {
+            if *doc_key == parent_key {
+                continue;
+            }
+            let Some(obj) = doc.as_object_mut() else {
+                continue;
+            };
+            for (field, value) in obj.iter_mut() {
+                if !field.starts_with("owned") {
+                    continue;
+                }
+                if let Some(arr) = value.as_array_mut() {
+                    let before = arr.len();
+                    arr.retain(|v| v.as_str() != Some(id));
+                    if arr.len() != before {
+                        ownership_key.get_or_insert_with(|| field.clone());
+                    }
+                }
+            }
+        }
+
+        // 2. Rattachement au nouveau parent, sous la même clé que l'ancien
+        let field = ownership_key.unwrap_or_else(|| DEFAULT_OWNERSHIP_KEY.to_string());
+        let parent = self.docs.get_mut(&parent_key).expect("parent localisé");
+        let owned = &mut parent[field.as_str()];
+        if !owned.is_array() {
+            *owned = json_value!([]);
+        }
+        if let Some(arr) = owned.as_array_mut() {
+            if !arr.iter().any(|v| v.as_str() == Some(id)) {
+                arr.push(json_value!(id));
+            }
+        }
+
+        // 3. Pointeur retour de l'élément
+        let element = self.docs.get_mut(&key).expect("élément localisé");
+        for parent_field in PARENT_KEYS {
+            if element.get(parent_field).is_some() {
+                element[parent_field] = json_value!(new_parent);
+            }
+        }
+        Ok(())
+    }
+
+    fn change_type(&mut self, id: &str, new_kind: &str) -> RaiseResult<()> {
+        if new_kind.trim().is_empty() {
+            raise_error!(
+                "ERR_REFACTOR_INVALID_TYPE",
+                context = json_value!({ "element_id": id })
+            );
+        }
+        let key = self.locate(id)?;
+        let mut doc = self.docs[&key].clone();
+        let old_kind = doc["type"].as_str().unwrap_or_default().to_string();
+        doc["type"] = json_value!(new_kind);
+        if doc.get("@type").is_some() {
+            doc["@type"] = json_value!(new_kind);
+        }
+
+        // Re-routage si la catégorie sémantique change (ex : components -> functions)
+        let (collection, _) = &key;
+        let target = if *collection == routed_collection(&old_kind) {
+            routed_collection(new_kind)
+        } else {
+            collection.clone()
+        };
+        if target != *collection {
+            self.docs.remove(&key);
+            self.rewrite_links(collection, &target, id);
+        }
+        self.docs.insert((target, id.to_string()), doc);
+
+        self.sync_denormalized(&key, id, &["type", "kind"], &old_kind, new_kind);
+        Ok(())
+    }
+
+    /// Identifiants possédés (transitivement) par `id` via ses tableaux `owned*`.
+    fn descendants(&self, id: &str) -> UniqueSet<String> {
+        let mut seen = UniqueSet::new();
+        let mut stack = vec![id.to_string()];
+        while let Some(current) = stack.pop() {
+            let Some((_, doc)) = self
+                .docs
+                .iter()
+                .find(|((_, doc_id), d)| *doc_id == current && d.get("type").is_some())
+            else {
+                continue;
+            };
+            let Some(obj) = doc.as_object() else {
+                continue;
+            };
+            for (field, value) in obj {
+                if !field.starts_with("owned") {
+                    continue;
+                }
+                for child in value.as_array().into_iter().flatten() {
+                    if let Some(child) = child.as_str() {
+                        if seen.insert(child.to_string()) {
+                            stack.push(child.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        seen
+    }
+
+    /// Dans tout objet (hors document de l'élément) référençant `id`, remplace les champs
+    /// `fields` égaux à `old` par `new`.
+    fn sync_denormalized(
+        &mut self,
+        element_key: &(String, String),
+        id: &str,
+        fields: &[&str],
+        old: &str,
+        new: &str,
+    ) {
+        for (doc_key, doc) in self.docs.iter_mut() {
+            if doc_key.1 == element_key.1 {
+                continue;
+            }
+            sync_object(doc, id, fields, old, new);
+        }
+    }
+
+    /// Met à jour les liens `ref:{collection}:…:{id}` après un changement de collection.
+    fn rewrite_links(&mut self, from: &str, to: &str, id: &str) {
+        let prefix = format!("ref:{from}:");
+        for doc in self.docs.values_mut() {
+            rewrite_strings(doc, &mut |s| {
+                if s.starts_with(&prefix) && s.ends_with(&format!(":{id}")) {
+                    Some(format!("ref:{to}:{}", &s[prefix.len()..]))
+                } else {
+                    None
+                }
+            });
+        }
+    }
+}
+
+/// Collection de routage d'un type (même règle que `ModelIngestionService`).
+fn routed_collection(kind: &str) -> String {
+    let element = ArcadiaElement {
+        kind: kind.to_string(),
+        ..Default::default()
+    };
+    let category = format!("{:?}", element.get_category()).to_lowercase() + "s";
+    if category == "others" {
+        "elements".to_string()
+    } else {
+        category
+    }
+}
+
+fn sync_object(value: &mut JsonValue, id: &str, fields: &[&str], old: &str, new: &str) {
+    match value {
+        JsonValue::Object(obj) => {
+            let references = obj
+                .iter()
+                .any(|(k, v)| !k.starts_with('_') && v.as_str() == Some(id));
+            if references {
+                for field in fields {
+                    if let Some(v) = obj.get_mut(*field) {
+                        if v.as_str() == Some(old) {
+                            *v = json_value!(new);
+                        }
+                    }
+                }
+            }
+            for v in obj.values_mut() {
+                sync_object(v, id, fields, old, new);
+            }
+        }
+        JsonValue::Array(arr) => {
+            for v in arr {
+                sync_object(v, id, fields, old, new);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn rewrite_strings(value: &mut JsonValue, f: &mut dyn FnMut(&str) -> Option<String>) {
+    match value {
+        JsonValue::String(s) => {
+            if let Some(replaced) = f(s) {
+                *s = replaced;
+            }
+        }
+        JsonValue::Object(obj) => obj.values_mut().for_each(|v| rewrite_strings(v, f)),
+        JsonValue::Array(arr) => arr.iter_mut().for_each(|v| rewrite_strings(v, f)),
+        _ => {}
+    }
+}
+
+fn changed_fields(before: &JsonValue, after: &JsonValue) -> Vec<String> {
+    let empty = JsonObject::new();
+    let b = before.as_object().unwrap_or(&empty);
+    let a = after.as_object().unwrap_or(&empty);
+    let mut fields: Vec<String> = a
+        .iter()
+        .filter(|(k, v)| b.get(*k) != Some(*v))
+        .map(|(k, _)| k.clone())
+        .collect();
+    fields.extend(b.keys().filter(|k| !a.contains_key(*k)).cloned());
+    fields
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    fn session(docs: Vec<(&str, JsonValue)>) -> RefactorSession {
+        RefactorSession {
+            docs: docs
+                .into_iter()
+                .map(|(col, doc)| {
+                    let id = doc["_id"].as_str().unwrap().to_string();
+                    ((col.to_string(), id), doc)
+                })
+                .collect(),
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_rename_updates_denormalized_names() -> RaiseResult<()> {
+        let mut s = session(vec![
+            (
+                "components",
+                json_value!({ "_id": "c1", "type": "LogicalComponent", "name": "Nav" }),
+            ),
+            (
+                "traceability",
+                json_value!({ "_id": "t1", "links": [{ "target": "c1", "targetName": "Nav" }] }),
+            ),
+            (
+                "code_elements",
+                json_value!({ "_id": "m1", "elements": [{ "id": "c1", "name": "Nav" }, { "id": "x", "name": "Nav" }] }),
+            ),
+        ]);
+        s.apply(&RefactorOp::Rename {
+            id: "c1".into(),
+            new_name: "Navigation".into(),
+        })?;
+
+        let key = |c: &str, i: &str| (c.to_string(), i.to_string());
+        assert_eq!(s.docs[&key("components", "c1")]["name"], "Navigation");
+        assert_eq!(
+            s.docs[&key("traceability", "t1")]["links"][0]["targetName"],
+            "Navigation"
+        );
+        assert_eq!(
+            s.docs[&key("code_elements", "m1")]["elements"][0]["name"],
+            "Navigation"
+        );
+        // Un homonyme qui ne référence pas l'élément reste intact
+        assert_eq!(
+            s.docs[&key("code_elements", "m1")]["elements"][1]["name"],
+            "Nav"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_and_change_type_reroute() -> RaiseResult<()> {
+        let mut s = session(vec![
+            (
+                "components",
+                json_value!({ "_id": "root", "type": "LogicalComponent", "ownedLogicalComponents": ["a", "b"] }),
+            ),
+            (
+                "components",
+                json_value!({ "_id": "a", "type": "LogicalComponent", "parent": "root" }),
+            ),
+            (
+                "components",
+                json_value!({ "_id": "b", "type": "LogicalComponent", "parent": "root", "ownedLogicalComponents": ["c"] }),
+            ),
+            (
+                "components",
+                json_value!({ "_id": "c", "type": "LogicalComponent", "parent": "b" }),
+            ),
+            (
+                "exchanges",
+                json_value!({ "_id": "e1", "type": "ComponentExchange", "source": "a", "sourceRef": "ref:components:_id:a" }),
+            ),
+        ]);
+        let key = |c: &str, i: &str| (c.to_string(), i.to_string());
+
+        // Cycle interdit : b ne peut pas descendre sous son propre enfant
+        assert!(s
+            .apply(&RefactorOp::Move {
+                id: "b".into(),
+                new_parent: "c".into()
+            })
+            .is_err());
+
+        s.apply(&RefactorOp::Move {
+            id: "a".into(),
+            new_parent: "b".into(),
+        })?;
+        assert_eq!(
+            s.docs[&key("components", "root")]["ownedLogicalComponents"],
+            json_value!(["b"])
+        );
+        assert_eq!(
+            s.docs[&key("components", "b")]["ownedLogicalComponents"],
+            json_value!(["c", "a"])
+        );
+        assert_eq!(s.docs[&key("components", "a")]["parent"], "b");
+
+        s.apply(&RefactorOp::ChangeType {
+            id: "a".into(),
+            new_kind: "LogicalFunction".into(),
+        })?;
+        assert!(!s.docs.contains_key(&key("components", "a")));
+        assert_eq!(s.docs[&key("functions", "a")]["type"], "LogicalFunction");
+        assert_eq!(
+            s.docs[&key("exchanges", "e1")]["sourceRef"],
+            "ref:functions:_id:a"
+        );
+        assert_eq!(s.docs[&key("exchanges", "e1")]["source"], "a");
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_preview_then_apply_transactionally() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager.create_collection("components", &schema_uri).await?;
+        manager.create_collection("exchanges", &schema_uri).await?;
+        manager
+            .insert_raw(
+                "components",
+                &json_value!({ "_id": "c1", "type": "LogicalComponent", "name": "Nav" }),
+            )
+            .await?;
+        manager
+            .insert_raw("exchanges", &json_value!({ "_id": "e1", "type": "ComponentExchange", "name": "Req", "source": "c1", "sourceName": "Nav" }))
+            .await?;
+
+        let refactor = ModelRefactor::new(&manager);
+        let ops = vec![RefactorOp::Rename {
+            id: "c1".into(),
+            new_name: "Navigation".into(),
+        }];
+
+        let plan = refactor.preview(&ops).await?;
+        assert_eq!(plan.changes.len(), 2);
+        // L'aperçu n'écrit rien
+        let untouched = manager.get_document("exchanges", "e1").await?.unwrap();
+        assert_eq!(untouched["sourceName"], "Nav");
+
+        refactor.apply(&ops).await?;
+        let exchange = manager.get_document("exchanges", "e1").await?.unwrap();
+        assert_eq!(exchange["sourceName"], "Navigation");
+        let component = manager.get_document("components", "c1").await?.unwrap();
+        assert_eq!(component["name"], "Navigation");
+
+        // Élément inconnu : tout le lot est rejeté
+        let bad = vec![
+            RefactorOp::Rename {
+                id: "c1".into(),
+                new_name: "Other".into(),
+            },
+            RefactorOp::Rename {
+                id: "ghost".into(),
+                new_name: "X".into(),
+            },
+        ];
+        assert!(refactor.apply(&bad).await.is_err());
+        let component = manager.get_document("components", "c1").await?.unwrap();
+        assert_eq!(component["name"], "Navigation");
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 use crate::model_engine::types::{ArcadiaElement, ProjectModel};
 use crate::utils::prelude::*;
 pub mod dialogue_to_model;
+pub mod test_cases;
 
 /// Configuration pour piloter la transformation sémantique
 #[derive(Clone)]
@@ -0,0 +1,444 @@
+// FICHIER : src-tauri/src/model_engine/transformers/test_cases.rs
+
+use super::ModelTransformer;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::transactions::TransactionRequest;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Collection JsonDb des cas de test générés.
+pub const TEST_CASES_COLLECTION: &str = "test_cases";
+
+/// Séquence d'un scénario : échanges (id) ou messages `{ source, target, exchange?, name? }`.
+pub const PROP_MESSAGES: &str = "messages";
+/// Échanges ordonnés d'une chaîne fonctionnelle.
+pub const PROP_INVOLVED_EXCHANGES: &str = "involvedExchanges";
+/// Lien de traçabilité source -> cas de test.
+pub const PROP_VERIFIED_BY: &str = "verifiedBy";
+/// Lien de traçabilité cas de test -> source.
+pub const PROP_VERIFIES: &str = "verifies";
+
+/// Échange attendu à une étape.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ExpectedExchange {
+    pub exchange_id: Option<String>,
+    pub name: String,
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct TestStep {
+    pub order: usize,
+    pub actor: String,
+    pub action: String,
+    pub expected: ExpectedExchange,
+    pub expected_result: String,
+}
+
+/// Squelette de cas de test : à compléter (données, environnement) par l'équipe V&V.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct TestCaseSkeleton {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub name: String,
+    pub status: String,
+    /// Scénario ou chaîne fonctionnelle vérifié
+    pub verifies: Vec<String>,
+    pub source_kind: String,
+    pub preconditions: Vec<String>,
+    pub steps: Vec<TestStep>,
+    pub pass_criteria: Vec<String>,
+    pub warnings: Vec<String>,
+    pub generated_at: String,
+}
+
+/// Transforme scénarios et chaînes fonctionnelles en squelettes de cas de test.
+#[derive(Default)]
+pub struct TestCaseGenerator;
+
+impl TestCaseGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Éléments sources reconnus : `…Scenario` et `…FunctionalChain`.
+    pub fn is_source(element: &ArcadiaElement) -> bool {
+        element.kind.ends_with("Scenario") || element.kind.ends_with("FunctionalChain")
+    }
+
+    /// Génère un cas de test par scénario / chaîne du modèle.
+    pub fn generate(&self, model: &ProjectModel) -> Vec<TestCaseSkeleton> {
+        model
+            .all_elements()
+            .into_iter()
+            .filter(|el| Self::is_source(el))
+            .map(|el| self.generate_for(el, model))
+            .collect()
+    }
+
+    pub fn generate_for(&self, source: &ArcadiaElement, model: &ProjectModel) -> TestCaseSkeleton {
+        let key = if source.properties.contains_key(PROP_MESSAGES) {
+            PROP_MESSAGES
+        } else {
+            PROP_INVOLVED_EXCHANGES
+        };
+        let entries = source
+            .properties
+            .get(key)
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        let mut warnings = Vec::new();
+        let mut steps = Vec::new();
+        for entry in &entries {
+            match resolve_exchange(entry, model) {
+                Some(expected) => {
+                    let order = steps.len() + 1;
+                    steps.push(TestStep {
+                        order,
+                        actor: expected.source.clone(),
+                        action: format!("Émettre « {} » vers {}", expected.name, expected.target),
+                        expected_result: format!(
+                            "« {} » est reçu par {}",
+                            expected.name, expected.target
+                        ),
+                        expected,
+                    });
+                }
+                None => warnings.push(format!("Échange non résolu : {}", entry)),
+            }
+        }
+        if steps.is_empty() {
+            warnings.push(format!("Aucune étape : '{}' est vide.", key));
+        }
+
+        let mut pass_criteria = vec![
+            format!(
+                "Les {} échanges attendus sont observés dans l'ordre.",
+                steps.len()
+            ),
+            "Aucun échange inattendu entre les participants.".to_string(),
+        ];
+        pass_criteria.extend(conditions(source, "postCondition"));
+
+        TestCaseSkeleton {
+            id: format!("tc_{}", source.id),
+            name: format!("TC - {}", source.name.as_str()),
+            status: "draft".to_string(),
+            verifies: vec![source.id.clone()],
+            source_kind: source.kind.clone(),
+            preconditions: conditions(source, "preCondition"),
+            steps,
+            pass_criteria,
+            warnings,
+            generated_at: UtcClock::now().to_rfc3339(),
+        }
+    }
+}
+
+impl ModelTransformer for TestCaseGenerator {
+    fn transform(&self, element: &JsonValue) -> RaiseResult<JsonValue> {
+        let source: ArcadiaElement = json::deserialize_from_value(element.clone())?;
+        self.transform_with_context(&source, &ProjectModel::default())
+    }
+
+    fn transform_with_context(
+        &self,
+        element: &ArcadiaElement,
+        model: &ProjectModel,
+    ) -> RaiseResult<JsonValue> {
+        json::serialize_to_value(self.generate_for(element, model))
+    }
+}
+
+fn name_of(model: &ProjectModel, id: &str) -> String {
+    model
+        .find_element(id)
+        .map(|el| el.name.as_str().to_string())
+        .unwrap_or_else(|| id.to_string())
+}
+
+fn conditions(source: &ArcadiaElement, key: &str) -> Vec<String> {
+    match source.properties.get(key) {
+        Some(JsonValue::String(s)) if !s.trim().is_empty() => vec![s.clone()],
+        Some(JsonValue::Array(arr)) => arr
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Résout un échange référencé (id) ou décrit en ligne (message de séquence).
+fn resolve_exchange(entry: &JsonValue, model: &ProjectModel) -> Option<ExpectedExchange> {
+    let (exchange_id, inline) = match entry {
+        JsonValue::String(id) => (Some(id.clone()), None),
+        JsonValue::Object(obj) => (
+            obj.get("exchange")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            Some(obj),
+        ),
+        _ => return None,
+    };
+    let exchange = exchange_id.as_deref().and_then(|id| model.find_element(id));
+
+    let field = |key: &str| -> Option<String> {
+        inline
+            .and_then(|o| o.get(key))
+            .or_else(|| exchange.and_then(|e| e.properties.get(key)))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+    let source = field("source")?;
+    let target = field("target")?;
+    let name = inline
+        .and_then(|o| o.get("name"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .or_else(|| exchange.map(|e| e.name.as_str().to_string()))
+        .or_else(|| exchange_id.clone())?;
+
+    Some(ExpectedExchange {
+        exchange_id,
+        name,
+        source: name_of(model, &source),
+        target: name_of(model, &target),
+    })
+}
+
+// =========================================================================
+// PERSISTANCE & TRAÇABILITÉ
+// =========================================================================
+
+/// Enregistre les cas de test et pose le lien `verifiedBy` sur leurs sources, en une transaction.
+pub async fn persist_test_cases(
+    manager: &CollectionsManager<'_>,
+    cases: &[TestCaseSkeleton],
+) -> RaiseResult<usize> {
+    if cases.is_empty() {
+        return Ok(0);
+    }
+    let collections = manager.list_collections().await?;
+    if !collections.iter().any(|c| c == TEST_CASES_COLLECTION) {
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager
+            .create_collection(TEST_CASES_COLLECTION, &schema_uri)
+            .await?;
+    }
+
+    let mut requests = Vec::new();
+    for case in cases {
+        requests.push(TransactionRequest::Upsert {
+            collection: TEST_CASES_COLLECTION.to_string(),
+            id: Some(case.id.clone()),
+            handle: None,
+            document: json::serialize_to_value(case)?,
+        });
+
+        for source_id in &case.verifies {
+            let Some((collection, doc)) = locate(manager, &collections, source_id).await else {
+                user_warn!(
+                    "WRN_TEST_CASE_SOURCE_NOT_FOUND",
+                    json_value!({ "test_case": case.id, "source": source_id })
+                );
+                continue;
+            };
+            let mut verified_by: Vec<JsonValue> = doc
+                .get(PROP_VERIFIED_BY)
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default();
+            if verified_by
+                .iter()
+                .any(|v| v.as_str() == Some(case.id.as_str()))
+            {
+                continue;
+            }
+            verified_by.push(json_value!(case.id));
+            let mut patch = JsonObject::new();
+            patch.insert(PROP_VERIFIED_BY.to_string(), JsonValue::Array(verified_by));
+            requests.push(TransactionRequest::Update {
+                collection,
+                id: Some(source_id.clone()),
+                handle: None,
+                document: JsonValue::Object(patch),
+            });
+        }
+    }
+
+    TransactionManager::new(manager.storage, &manager.space, &manager.db)
+        .execute_smart(requests)
+        .await?;
+    user_success!(
+        "SUC_TEST_CASES_GENERATED",
+        json_value!({ "count": cases.len() })
+    );
+    Ok(cases.len())
+}
+
+/// Collection et document d'un élément du modèle.
+async fn locate(
+    manager: &CollectionsManager<'_>,
+    collections: &[String],
+    id: &str,
+) -> Option<(String, JsonValue)> {
+    for collection in collections {
+        if collection == TEST_CASES_COLLECTION || collection.starts_with('_') {
+            continue;
+        }
+        if let Ok(Some(doc)) = manager.get_document(collection, id).await {
+            return Some((collection.clone(), doc));
+        }
+    }
+    None
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_engine::types::NameType;
+    use crate::traceability::Tracer;
+    use crate::utils::testing::DbSandbox;
+
+    fn el(id: &str, name: &str, kind: &str, props: JsonValue) -> ArcadiaElement {
+        ArcadiaElement {
+            id: id.to_string(),
+            name: NameType::String(name.to_string()),
+            kind: kind.to_string(),
+            properties: props
+                .as_object()
+                .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn model() -> ProjectModel {
+        let mut m = ProjectModel::default();
+        m.add_element(
+            "sa",
+            "functions",
+            el("F1", "Acquire Speed", "SystemFunction", json_value!({})),
+        );
+        m.add_element(
+            "sa",
+            "functions",
+            el("F2", "Compute Brake", "SystemFunction", json_value!({})),
+        );
+        m.add_element(
+            "sa",
+            "exchanges",
+            el(
+                "FE1",
+                "Speed",
+                "FunctionalExchange",
+                json_value!({ "source": "F1", "target": "F2" }),
+            ),
+        );
+        m.add_element(
+            "sa",
+            "capabilities",
+            el(
+                "SC1",
+                "Emergency Braking",
+                "SystemScenario",
+                json_value!({
+                    "preCondition": "Véhicule en mouvement",
+                    "postCondition": "Véhicule arrêté",
+                    "messages": ["FE1", { "source": "F2", "target": "F1", "name": "Ack" }, "GHOST"]
+                }),
+            ),
+        );
+        m.add_element(
+            "sa",
+            "chains",
+            el(
+                "FC1",
+                "Braking Chain",
+                "FunctionalChain",
+                json_value!({
+                    "involvedExchanges": ["FE1"]
+                }),
+            ),
+        );
+        m
+    }
+
+    #[test]
+    fn test_scenario_to_skeleton() {
+        let cases = TestCaseGenerator::new().generate(&model());
+        assert_eq!(cases.len(), 2);
+
+        let sc = cases.iter().find(|c| c.id == "tc_SC1").unwrap();
+        assert_eq!(sc.verifies, vec!["SC1".to_string()]);
+        assert_eq!(sc.preconditions, vec!["Véhicule en mouvement".to_string()]);
+        assert_eq!(sc.steps.len(), 2);
+        assert_eq!(sc.steps[0].actor, "Acquire Speed");
+        assert_eq!(sc.steps[0].expected.exchange_id.as_deref(), Some("FE1"));
+        assert_eq!(sc.steps[1].expected.name, "Ack");
+        assert_eq!(sc.steps[1].order, 2);
+        assert!(sc.pass_criteria.contains(&"Véhicule arrêté".to_string()));
+        assert_eq!(sc.warnings.len(), 1);
+
+        let fc = cases.iter().find(|c| c.id == "tc_FC1").unwrap();
+        assert_eq!(fc.steps.len(), 1);
+        assert!(fc.warnings.is_empty());
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_persist_links_sources_in_traceability() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager.create_collection("chains", &schema_uri).await?;
+        manager
+            .insert_raw(
+                "chains",
+                &json_value!({ "_id": "FC1", "type": "FunctionalChain", "name": "Braking Chain" }),
+            )
+            .await?;
+
+        let m = model();
+        let case = TestCaseGenerator::new().generate_for(m.find_element("FC1").unwrap(), &m);
+        assert_eq!(persist_test_cases(&manager, &[case.clone()]).await?, 1);
+        // Régénération idempotente : pas de doublon dans verifiedBy
+        persist_test_cases(&manager, &[case]).await?;
+
+        let chain = manager.get_document("chains", "FC1").await?.unwrap();
+        assert_eq!(chain[PROP_VERIFIED_BY], json_value!(["tc_FC1"]));
+        let stored = manager
+            .get_document(TEST_CASES_COLLECTION, "tc_FC1")
+            .await?
+            .unwrap();
+        assert_eq!(stored["steps"].as_array().map(|s| s.len()), Some(1));
+
+        let tracer = Tracer::from_db(&manager).await?;
+        assert!(tracer
+            .get_downstream_ids("FC1")
+            .contains(&"tc_FC1".to_string()));
+        assert!(tracer
+            .get_downstream_ids("tc_FC1")
+            .contains(&"FC1".to_string()));
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 // FICHIER : src-tauri/src/model_engine/validators/compliance_validator.rs
 
 use crate::model_engine::loader::ModelLoader;
-use crate::model_engine::types::ArcadiaElement;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
 use crate::model_engine::validators::{ModelValidator, Severity, ValidationIssue};
 use crate::utils::prelude::*;
 
@@ -27,6 +27,7 @@ impl ComplianceValidator {
                 element_id: element.id.clone(),
                 message: format!("L'élément possède un nom générique ou vide : '{}'.", name),
                 rule_id: "RULE_NAMING".to_string(),
+                suggestion: None,
             });
         }
 
@@ -45,11 +46,21 @@ impl ComplianceValidator {
                 element_id: element.id.clone(),
                 message: format!("Documentation manquante pour l'élément '{}'.", name),
                 rule_id: "RULE_DOC".to_string(),
+                suggestion: None,
             });
         }
 
         issues
     }
+
+    /// Contrôle de tous les éléments d'un modèle déjà chargé (ex: fichier de dépôt en CI).
+    pub fn check_model(&self, model: &ProjectModel) -> Vec<ValidationIssue> {
+        model
+            .all_elements()
+            .into_iter()
+            .flat_map(|element| self.check_quality(element))
+            .collect()
+    }
 }
 
 #[async_interface]
@@ -70,9 +81,7 @@ impl ModelValidator for ComplianceValidator {
         // On charge le snapshot complet du modèle
         if let Ok(model) = loader.load_full_model().await {
             // 🎯 PURE GRAPH : On utilise l'itérateur universel sur toutes les couches
-            for element in model.all_elements() {
-                all_issues.extend(self.check_quality(element));
-            }
+            all_issues.extend(self.check_model(&model));
         }
 
         Ok(all_issues)
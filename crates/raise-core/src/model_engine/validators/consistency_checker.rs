@@ -1,14 +1,45 @@
 // FICHIER : src-tauri/src/model_engine/validators/consistency_checker.rs
 
 use super::{ModelValidator, Severity, ValidationIssue};
+use crate::json_db::collections::id_strategy::content_hash;
 use crate::json_db::jsonld::vocabulary::VocabularyRegistry;
 use crate::model_engine::loader::ModelLoader;
-use crate::model_engine::types::ArcadiaElement;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 #[derive(Default)]
 pub struct ConsistencyChecker;
 
+/// État d'un passage, fourni au suivant pour le mode incrémental. Sérialisable : il peut
+/// être conservé entre deux sessions.
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct ConsistencySnapshot {
+    /// Empreinte de chaque élément (identifiant unique et non vide).
+    fingerprints: UnorderedMap<String, String>,
+    issues: UnorderedMap<String, Vec<ValidationIssue>>,
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct ConsistencyStats {
+    pub elements: usize,
+    /// Éléments dont les règles ont été évaluées.
+    pub revisited: usize,
+    /// Éléments dont les problèmes ont été repris du passage précédent.
+    pub reused: usize,
+    /// Empreintes et détection des changements.
+    pub index_ms: u64,
+    /// Évaluation des règles (parallèle).
+    pub rules_ms: u64,
+    pub total_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+pub struct ConsistencyReport {
+    pub issues: Vec<ValidationIssue>,
+    pub stats: ConsistencyStats,
+    pub snapshot: ConsistencySnapshot,
+}
+
 impl ConsistencyChecker {
     pub fn new() -> Self {
         Self
@@ -27,6 +58,7 @@ impl ConsistencyChecker {
                 rule_id: "SYS_001".to_string(),
                 element_id: "unknown".to_string(),
                 message: format!("L'élément '{}' n'a pas d'identifiant unique (UUID).", name),
+                suggestion: None,
             });
         }
 
@@ -37,6 +69,7 @@ impl ConsistencyChecker {
                 rule_id: "SYS_002".to_string(),
                 element_id: element.id.clone(),
                 message: "L'élément n'a pas de nom descriptif.".to_string(),
+                suggestion: None,
             });
         }
 
@@ -54,6 +87,7 @@ impl ConsistencyChecker {
                                 "Violation de domaine : '{}' ne peut pas s'appliquer à un '{}' (Attendu: {}).",
                                 prop_def.label, element.kind, domain_iri
                             ),
+                            suggestion: None,
                         });
                     }
                 }
@@ -85,35 +119,18 @@ impl ConsistencyChecker {
         for (prop_key, prop_val) in &element.properties {
             if let Some(prop_def) = registry.get_property(prop_key) {
                 if let Some(range_iri) = &prop_def.range {
-                    let target_ids = match prop_val {
-                        JsonValue::String(s) => vec![s.clone()],
-                        JsonValue::Array(arr) => arr
-                            .iter()
-                            .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                            .collect(),
-                        _ => vec![],
-                    };
-
-                    for target_id in target_ids {
+                    for target_id in reference_targets(prop_val) {
                         // On ne vérifie que les références qui ressemblent à des IDs ou URIs
                         if target_id.starts_with("http") || target_id.len() > 20 {
-                            match loader.get_element(&target_id).await {
-                                Ok(target_el) => {
-                                    if !registry.is_subtype_of(&target_el.kind, range_iri) {
-                                        issues.push(ValidationIssue {
-                                            severity: Severity::Warning,
-                                            rule_id: "SEM_002".to_string(),
-                                            element_id: element.id.clone(),
-                                            message: format!(
-                                                "Relation invalide : La cible '{}' est de type '{}', attendu '{}' pour la propriété '{}'.",
-                                                target_el.name.as_str(), target_el.kind, range_iri, prop_def.label
-                                            ),
-                                        });
-                                    }
-                                }
-                                Err(_) => {
-                                    // Résilience : La cible est peut-être hors-scope ou non indexée
-                                }
+                            // Résilience : une cible introuvable est peut-être hors-scope ou non indexée
+                            if let Ok(target_el) = loader.get_element(target_id).await {
+                                issues.extend(range_issue(
+                                    registry,
+                                    element,
+                                    &prop_def.label,
+                                    range_iri,
+                                    &target_el,
+                                ));
                             }
                         }
                     }
@@ -123,6 +140,163 @@ impl ConsistencyChecker {
 
         Ok(issues)
     }
+
+    /// Variante synchrone de `check_relationships` : les cibles sont cherchées dans le modèle.
+    fn check_relationships_in(
+        &self,
+        element: &ArcadiaElement,
+        index: &UnorderedMap<&str, &ArcadiaElement>,
+    ) -> RaiseResult<Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        let registry = VocabularyRegistry::global()?;
+
+        for (prop_key, prop_val) in &element.properties {
+            let Some(prop_def) = registry.get_property(prop_key) else {
+                continue;
+            };
+            let Some(range_iri) = &prop_def.range else {
+                continue;
+            };
+            for target_id in reference_targets(prop_val) {
+                if let Some(target_el) = index.get(target_id) {
+                    issues.extend(range_issue(
+                        registry,
+                        element,
+                        &prop_def.label,
+                        range_iri,
+                        target_el,
+                    ));
+                }
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Vérifie tout le modèle, règles évaluées en parallèle. Avec le `previous` d'un passage
+    /// antérieur, seuls les éléments modifiés, ou référençant un élément modifié, ajouté ou
+    /// supprimé, sont revisités ; les problèmes des autres sont repris tels quels.
+    pub fn check_model(
+        &self,
+        model: &ProjectModel,
+        previous: Option<&ConsistencySnapshot>,
+    ) -> RaiseResult<ConsistencyReport> {
+        let started = TimeInstant::now();
+        let elements = model.all_elements();
+
+        let mut id_counts: UnorderedMap<&str, usize> = UnorderedMap::new();
+        for el in &elements {
+            *id_counts.entry(el.id.as_str()).or_default() += 1;
+        }
+        // Identifiant vide ou dupliqué : l'élément est revisité à chaque passage
+        let cacheable = |id: &str| !id.trim().is_empty() && id_counts.get(id) == Some(&1);
+        let index: UnorderedMap<&str, &ArcadiaElement> =
+            elements.iter().map(|el| (el.id.as_str(), *el)).collect();
+
+        let fingerprints: Vec<String> = execute_parallel_map(elements.clone(), fingerprint);
+        let mut changed: UniqueSet<&str> = UniqueSet::new();
+        if let Some(previous) = previous {
+            for (el, print) in elements.iter().zip(&fingerprints) {
+                if previous.fingerprints.get(&el.id) != Some(print) {
+                    changed.insert(el.id.as_str());
+                }
+            }
+            for id in previous.fingerprints.keys() {
+                if !index.contains_key(id.as_str()) {
+                    changed.insert(id.as_str());
+                }
+            }
+        }
+        let revisit = |el: &ArcadiaElement| -> bool {
+            let Some(previous) = previous else {
+                return true;
+            };
+            !cacheable(&el.id)
+                || !previous.issues.contains_key(&el.id)
+                || changed.contains(el.id.as_str())
+                || el
+                    .properties
+                    .values()
+                    .flat_map(reference_targets)
+                    .any(|target| changed.contains(target))
+        };
+        let to_check: Vec<&ArcadiaElement> =
+            elements.iter().copied().filter(|el| revisit(el)).collect();
+        let index_ms = started.elapsed().as_millis() as u64;
+
+        let rules_started = TimeInstant::now();
+        let revisited = to_check.len();
+        let checked: Vec<RaiseResult<Vec<ValidationIssue>>> =
+            execute_parallel_map(to_check, |el| {
+                let mut issues = self.check_local_logic(el)?;
+                issues.extend(self.check_relationships_in(el, &index)?);
+                Ok(issues)
+            });
+        let rules_ms = rules_started.elapsed().as_millis() as u64;
+
+        let mut checked = checked.into_iter();
+        let mut report = ConsistencyReport::default();
+        for (el, print) in elements.iter().zip(fingerprints) {
+            let issues = match previous {
+                Some(previous) if !revisit(el) => previous.issues[&el.id].clone(),
+                _ => checked.next().unwrap_or_else(|| Ok(Vec::new()))?,
+            };
+            if cacheable(&el.id) {
+                report.snapshot.fingerprints.insert(el.id.clone(), print);
+                report.snapshot.issues.insert(el.id.clone(), issues.clone());
+            }
+            report.issues.extend(issues);
+        }
+
+        report.stats = ConsistencyStats {
+            elements: elements.len(),
+            revisited,
+            reused: elements.len() - revisited,
+            index_ms,
+            rules_ms,
+            total_ms: started.elapsed().as_millis() as u64,
+        };
+        Ok(report)
+    }
+}
+
+/// Identifiants cités par une propriété (valeur simple ou liste).
+fn reference_targets(value: &JsonValue) -> Vec<&str> {
+    match value {
+        JsonValue::String(s) => vec![s.as_str()],
+        JsonValue::Array(arr) => arr.iter().filter_map(|v| v.as_str()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// SEM_002 : la cible d'une relation doit relever du `range` de la propriété.
+fn range_issue(
+    registry: &VocabularyRegistry,
+    element: &ArcadiaElement,
+    prop_label: &str,
+    range_iri: &str,
+    target: &ArcadiaElement,
+) -> Option<ValidationIssue> {
+    if registry.is_subtype_of(&target.kind, range_iri) {
+        return None;
+    }
+    Some(ValidationIssue {
+        severity: Severity::Warning,
+        rule_id: "SEM_002".to_string(),
+        element_id: element.id.clone(),
+        message: format!(
+            "Relation invalide : La cible '{}' est de type '{}', attendu '{}' pour la propriété '{}'.",
+            target.name.as_str(),
+            target.kind,
+            range_iri,
+            prop_label
+        ),
+        suggestion: None,
+    })
+}
+
+/// Empreinte canonique de l'élément (clés triées ; l'enveloppe garde les champs `_*`).
+fn fingerprint(element: &ArcadiaElement) -> String {
+    content_hash(&json_value!({ "element": element }))
 }
 
 #[async_interface]
@@ -145,17 +319,8 @@ impl ModelValidator for ConsistencyChecker {
     /// 🎯 SCAN UNIVERSEL : Parcourt dynamiquement tout le modèle chargé.
     /// Utilise les points de montage pour la résilience de chargement.
     async fn validate_full(&self, loader: &ModelLoader<'_>) -> RaiseResult<Vec<ValidationIssue>> {
-        let mut all_issues = Vec::new();
-
         let model = loader.load_full_model().await?;
-
-        for el in model.all_elements() {
-            let element_issues = self.validate_element(el, loader).await?;
-            // ✅ extend() sur un Vec aplatit proprement les issues dans all_issues.
-            all_issues.extend(element_issues);
-        }
-
-        Ok(all_issues)
+        Ok(self.check_model(&model, None)?.issues)
     }
 }
 
@@ -256,6 +421,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_check_model_incremental() -> RaiseResult<()> {
+        let element = |id: &str, name: &str| ArcadiaElement {
+            id: id.to_string(),
+            name: NameType::String(name.to_string()),
+            kind: "OperationalActor".to_string(),
+            ..Default::default()
+        };
+        let mut user = element("ACT-USER", "Opérateur");
+        user.properties
+            .insert("involvedIn".to_string(), json_value!(["ACT-PUMP"]));
+        let mut model = ProjectModel::default();
+        model.add_element("oa", "actors", element("ACT-PUMP", "Pompe"));
+        model.add_element("oa", "actors", element("ACT-EMPTY", ""));
+        model.add_element("oa", "actors", user);
+
+        let checker = ConsistencyChecker::new();
+        let first = checker.check_model(&model, None)?;
+        assert_eq!((first.stats.elements, first.stats.revisited), (3, 3));
+        assert!(first
+            .issues
+            .iter()
+            .any(|i| i.element_id == "ACT-EMPTY" && i.rule_id == "SYS_002"));
+
+        // Modèle inchangé : tout est repris du passage précédent
+        let second = checker.check_model(&model, Some(&first.snapshot))?;
+        assert_eq!((second.stats.revisited, second.stats.reused), (0, 3));
+        assert_eq!(second.issues, first.issues);
+
+        // La pompe change : elle et l'acteur qui la référence sont revisités
+        model
+            .layers
+            .get_mut("oa")
+            .unwrap()
+            .get_mut("actors")
+            .unwrap()[0]
+            .name = NameType::String("Pompe principale".to_string());
+        let third = checker.check_model(&model, Some(&second.snapshot))?;
+        assert_eq!((third.stats.revisited, third.stats.reused), (2, 1));
+        assert_eq!(third.issues, first.issues);
+
+        // Correction du nom vide : le problème disparaît
+        model
+            .layers
+            .get_mut("oa")
+            .unwrap()
+            .get_mut("actors")
+            .unwrap()[1]
+            .name = NameType::String("Capteur".to_string());
+        let fourth = checker.check_model(&model, Some(&third.snapshot))?;
+        assert_eq!(fourth.stats.revisited, 1);
+        assert!(fourth.issues.is_empty());
+        Ok(())
+    }
+
     ///  Résilience face à un loader défaillant (Mount Point corrompu)
     #[async_test]
     async fn test_resilience_loader_failure() -> RaiseResult<()> {
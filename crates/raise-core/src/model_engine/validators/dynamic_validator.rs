@@ -64,6 +64,7 @@ impl ModelValidator for DynamicValidator {
                                     format!("Violation de la règle dynamique : {}", rule.handle)
                                     // ✅ On utilise 'handle'
                                 }),
+                                suggestion: None,
                             });
                         }
                     }
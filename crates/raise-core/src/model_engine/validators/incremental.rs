@@ -0,0 +1,274 @@
+// FICHIER : src-tauri/src/model_engine/validators/incremental.rs
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::kernel::events::{publish_event, RaiseEvent};
+use crate::model_engine::loader::ModelLoader;
+use crate::model_engine::validators::{DynamicValidator, ModelValidator, ValidationIssue};
+use crate::rules_engine::ast::Rule;
+use crate::rules_engine::store::RuleStore;
+use crate::utils::prelude::*;
+
+/// Élément modifié par un commit (bridge blockchain, écriture d'agent...).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ElementChange {
+    pub collection: String,
+    pub id: String,
+    /// Chemins modifiés (`specs.mass`). `None` = élément entier (création ou diff inconnu).
+    pub changed_fields: Option<UniqueSet<String>>,
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+impl ElementChange {
+    pub fn created(collection: &str, id: &str) -> Self {
+        Self {
+            collection: collection.to_string(),
+            id: id.to_string(),
+            changed_fields: None,
+            deleted: false,
+        }
+    }
+
+    /// Mise à jour : les champs modifiés sont déduits du payload (chemins et préfixes).
+    pub fn updated(collection: &str, id: &str, payload: &JsonValue) -> Self {
+        let mut fields = UniqueSet::new();
+        collect_paths("", payload, &mut fields);
+        Self {
+            changed_fields: Some(fields),
+            ..Self::created(collection, id)
+        }
+    }
+
+    pub fn deleted(collection: &str, id: &str) -> Self {
+        Self {
+            deleted: true,
+            ..Self::created(collection, id)
+        }
+    }
+}
+
+/// Résultat d'une passe incrémentale : les problèmes de `element_ids` remplacent les anciens.
+#[derive(Debug, Clone, Default, PartialEq, Serializable, Deserializable)]
+pub struct ValidationDelta {
+    pub element_ids: Vec<String>,
+    pub issues: Vec<ValidationIssue>,
+    /// Nombre d'évaluations de règles effectuées (mesure du gain vs audit complet)
+    pub rules_evaluated: usize,
+}
+
+/// Service de revalidation incrémentale : ne rejoue que les règles dont les
+/// dépendances (extraites par l'`Analyzer` et indexées par le `RuleStore`)
+/// recoupent les champs modifiés.
+pub struct IncrementalValidator<'a> {
+    store: RuleStore<'a>,
+}
+
+impl<'a> IncrementalValidator<'a> {
+    /// Charge les règles `_system_rules` de la base cible.
+    pub async fn load(manager: &'a CollectionsManager<'a>) -> RaiseResult<Self> {
+        let mut store = RuleStore::new(manager);
+        store.sync_from_db().await?;
+        Ok(Self { store })
+    }
+
+    pub fn from_store(store: RuleStore<'a>) -> Self {
+        Self { store }
+    }
+
+    /// Règles à rejouer pour un changement (règles de la collection et règles globales `*`).
+    pub fn impacted_rules(&self, change: &ElementChange) -> Vec<Rule> {
+        let Some(fields) = &change.changed_fields else {
+            return self.store.get_all_rules();
+        };
+        let mut rules = self.store.get_impacted_rules(&change.collection, fields);
+        for rule in self.store.get_impacted_rules("*", fields) {
+            if !rules.iter().any(|r| r.handle == rule.handle) {
+                rules.push(rule);
+            }
+        }
+        rules
+    }
+
+    pub async fn revalidate(
+        &self,
+        loader: &ModelLoader<'_>,
+        changes: &[ElementChange],
+    ) -> RaiseResult<ValidationDelta> {
+        let mut delta = ValidationDelta::default();
+
+        for change in changes {
+            if !delta.element_ids.contains(&change.id) {
+                delta.element_ids.push(change.id.clone());
+            }
+            // Élément supprimé : ses problèmes disparaissent simplement côté UI
+            if change.deleted {
+                continue;
+            }
+            let rules = self.impacted_rules(change);
+            if rules.is_empty() {
+                continue;
+            }
+            let Some(element) = loader.load_element(&change.collection, &change.id).await? else {
+                continue;
+            };
+
+            delta.rules_evaluated += rules.len();
+            let issues = DynamicValidator::new(rules)
+                .validate_element(&element, loader)
+                .await?;
+            delta.issues.extend(issues);
+        }
+        Ok(delta)
+    }
+}
+
+/// 🪝 Hook post-commit : revalide les éléments touchés et pousse les problèmes
+/// mis à jour vers l'UI (`raise:validation_issues_updated`).
+pub async fn revalidate_changes(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    changes: Vec<ElementChange>,
+) -> RaiseResult<ValidationDelta> {
+    if changes.is_empty() {
+        return Ok(ValidationDelta::default());
+    }
+    let manager = CollectionsManager::new(storage, space, db);
+    let validator = IncrementalValidator::load(&manager).await?;
+    let loader = ModelLoader::new(storage, space, db)?;
+    let delta = validator.revalidate(&loader, &changes).await?;
+
+    publish_event(RaiseEvent::ValidationIssuesUpdated {
+        space: space.to_string(),
+        db: db.to_string(),
+        element_ids: delta.element_ids.clone(),
+        issues: delta.issues.clone(),
+    });
+    Ok(delta)
+}
+
+/// Variante non bloquante pour les chemins d'écriture : un échec de revalidation
+/// ne doit jamais annuler un commit déjà appliqué.
+pub async fn revalidate_after_commit(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    changes: Vec<ElementChange>,
+) {
+    if let Err(e) = revalidate_changes(storage, space, db, changes).await {
+        user_warn!(
+            "WRN_INCREMENTAL_VALIDATION_FAILED",
+            json_value!({ "space": space, "db": db, "error": e.to_string() })
+        );
+    }
+}
+
+fn collect_paths(prefix: &str, value: &JsonValue, out: &mut UniqueSet<String>) {
+    if let Some(obj) = value.as_object() {
+        for (key, child) in obj {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            collect_paths(&path, child, out);
+            out.insert(path);
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules_engine::ast::Expr;
+    use crate::utils::testing::DbSandbox;
+
+    fn rule(handle: &str, var: &str) -> Rule {
+        Rule {
+            _id: Some(format!("uuid-{}", handle)),
+            handle: handle.into(),
+            target: "all".into(),
+            expr: Expr::Lt(
+                Box::new(Expr::Var(var.into())),
+                Box::new(Expr::Val(json_value!(500))),
+            ),
+            description: Some(format!("{} trop élevé", var)),
+            severity: None,
+        }
+    }
+
+    #[test]
+    fn test_updated_change_collects_nested_paths() {
+        let change =
+            ElementChange::updated("elements", "e1", &json_value!({ "specs": { "mass": 1 } }));
+        let fields = change.changed_fields.unwrap();
+        assert!(fields.contains("specs"));
+        assert!(fields.contains("specs.mass"));
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_only_rules_depending_on_changed_fields_are_rerun() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        let schema = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        manager.create_collection("elements", &schema).await?;
+        manager
+            .insert_raw(
+                "elements",
+                &json_value!({ "_id": "e1", "name": "Moteur", "mass": 900, "power": 900 }),
+            )
+            .await?;
+
+        let mut store = RuleStore::new(&manager);
+        store.cache_inline_rule("elements", rule("max_mass", "mass"))?;
+        store.cache_inline_rule("*", rule("max_power", "power"))?;
+        let validator = IncrementalValidator::from_store(store);
+        let loader = ModelLoader::new_with_manager(CollectionsManager::new(
+            &sandbox.storage,
+            &manager.space,
+            &manager.db,
+        ))?;
+
+        // Seule la masse change : la règle sur la puissance n'est pas rejouée
+        let delta = validator
+            .revalidate(
+                &loader,
+                &[ElementChange::updated(
+                    "elements",
+                    "e1",
+                    &json_value!({ "mass": 900 }),
+                )],
+            )
+            .await?;
+        assert_eq!(delta.rules_evaluated, 1);
+        assert_eq!(delta.issues.len(), 1);
+        assert_eq!(delta.issues[0].rule_id, "uuid-max_mass");
+
+        // Création : toutes les règles s'appliquent
+        let delta = validator
+            .revalidate(&loader, &[ElementChange::created("elements", "e1")])
+            .await?;
+        assert_eq!(delta.rules_evaluated, 2);
+
+        // Suppression : l'élément est listé, sans problème associé
+        let delta = validator
+            .revalidate(&loader, &[ElementChange::deleted("elements", "e1")])
+            .await?;
+        assert_eq!(delta.element_ids, vec!["e1".to_string()]);
+        assert!(delta.issues.is_empty());
+        Ok(())
+    }
+}
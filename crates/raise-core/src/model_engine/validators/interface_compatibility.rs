@@ -0,0 +1,525 @@
+// FICHIER : src-tauri/src/model_engine/validators/interface_compatibility.rs
+
+use super::{ModelValidator, Severity, ValidationIssue};
+use crate::model_engine::loader::ModelLoader;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Éléments échangés portés par une interface ou un lien.
+pub const PROP_EXCHANGE_ITEMS: &str = "exchangeItems";
+/// Traçabilité de raffinement (PA -> LA), portée par le lien ou l'item physique.
+pub const PROP_REALIZES: &str = "realizes";
+/// Traçabilité inverse (LA -> PA), portée par l'interface logique.
+pub const PROP_REALIZED_BY: &str = "realizedBy";
+
+const PROP_DATA_TYPE: &str = "dataType";
+const PROP_DIRECTION: &str = "direction";
+const PROP_MULTIPLICITY: &str = "multiplicity";
+
+/// Vérifie que les contrats des interfaces LA sont tenus par les liens PA qui les réalisent :
+/// présence de chaque item échangé, type de donnée, direction et multiplicité.
+#[derive(Default)]
+pub struct InterfaceCompatibilityValidator;
+
+impl InterfaceCompatibilityValidator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Contrôle de tout le modèle (sans accès base).
+    pub fn check_model(&self, model: &ProjectModel) -> Vec<ValidationIssue> {
+        let index: UnorderedMap<&str, &ArcadiaElement> = model
+            .all_elements()
+            .into_iter()
+            .map(|el| (el.id.as_str(), el))
+            .collect();
+        let pa_links: Vec<&ArcadiaElement> = layer_elements(model, "pa")
+            .filter(|el| {
+                !el.kind.ends_with("Interface") && el.properties.contains_key(PROP_EXCHANGE_ITEMS)
+            })
+            .collect();
+
+        let mut issues = Vec::new();
+        for interface in layer_elements(model, "la").filter(|el| el.kind.ends_with("Interface")) {
+            let realizing: Vec<&ArcadiaElement> = pa_links
+                .iter()
+                .copied()
+                .filter(|link| {
+                    references(link.properties.get(PROP_REALIZES)).contains(&interface.id)
+                })
+                .chain(
+                    references(interface.properties.get(PROP_REALIZED_BY))
+                        .iter()
+                        .filter_map(|id| index.get(id.as_str()).copied()),
+                )
+                .collect();
+
+            if realizing.is_empty() {
+                issues.push(issue(
+                    Severity::Warning,
+                    "INTF_NOT_REALIZED",
+                    &interface.id,
+                    format!(
+                        "L'interface logique '{}' n'est réalisée par aucun lien physique.",
+                        interface.name.as_str()
+                    ),
+                ));
+                continue;
+            }
+
+            let physical: Vec<ItemView> = realizing
+                .iter()
+                .flat_map(|link| items(link, &index))
+                .collect();
+            for logical in items(interface, &index) {
+                match physical.iter().find(|p| p.realizes(&logical)) {
+                    Some(phys) => issues.extend(compare(interface, &logical, phys)),
+                    None => issues.push(issue(
+                        Severity::Error,
+                        "INTF_ITEM_MISSING",
+                        &interface.id,
+                        format!(
+                            "L'item '{}' de l'interface '{}' n'est transporté par aucun lien PA qui la réalise.",
+                            logical.name,
+                            interface.name.as_str()
+                        ),
+                    )),
+                }
+            }
+        }
+        issues
+    }
+}
+
+#[async_interface]
+impl ModelValidator for InterfaceCompatibilityValidator {
+    async fn validate_element(
+        &self,
+        element: &ArcadiaElement,
+        loader: &ModelLoader<'_>,
+    ) -> RaiseResult<Vec<ValidationIssue>> {
+        if !element.kind.ends_with("Interface") {
+            return Ok(Vec::new());
+        }
+        // Le contrat dépend des liens PA : on contrôle le modèle et on garde l'interface visée
+        let model = loader.load_full_model().await?;
+        Ok(self
+            .check_model(&model)
+            .into_iter()
+            .filter(|i| i.element_id == element.id)
+            .collect())
+    }
+
+    async fn validate_full(&self, loader: &ModelLoader<'_>) -> RaiseResult<Vec<ValidationIssue>> {
+        let model = loader.load_full_model().await?;
+        Ok(self.check_model(&model))
+    }
+}
+
+// =========================================================================
+// LECTURE DES ITEMS
+// =========================================================================
+
+/// Vue normalisée d'un item échangé (référencé par id ou décrit en ligne).
+#[derive(Debug, Clone)]
+struct ItemView {
+    id: Option<String>,
+    name: String,
+    realizes: Vec<String>,
+    data_type: Option<String>,
+    direction: Option<String>,
+    multiplicity: Option<Multiplicity>,
+}
+
+impl ItemView {
+    fn from_props(id: Option<String>, name: String, props: &JsonObject<String, JsonValue>) -> Self {
+        Self {
+            id,
+            name,
+            realizes: references(props.get(PROP_REALIZES)),
+            data_type: props
+                .get(PROP_DATA_TYPE)
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            direction: props
+                .get(PROP_DIRECTION)
+                .and_then(|v| v.as_str())
+                .map(|d| d.to_uppercase()),
+            multiplicity: props.get(PROP_MULTIPLICITY).and_then(Multiplicity::parse),
+        }
+    }
+
+    /// Un item physique réalise un item logique s'il le trace, le partage ou en porte le nom.
+    fn realizes(&self, logical: &ItemView) -> bool {
+        match &logical.id {
+            Some(id) if self.id.as_ref() == Some(id) || self.realizes.contains(id) => true,
+            _ => self.name == logical.name,
+        }
+    }
+}
+
+fn items(owner: &ArcadiaElement, index: &UnorderedMap<&str, &ArcadiaElement>) -> Vec<ItemView> {
+    let Some(JsonValue::Array(entries)) = owner.properties.get(PROP_EXCHANGE_ITEMS) else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| match entry {
+            JsonValue::String(id) => {
+                let el = index.get(id.as_str())?;
+                let props: JsonObject<String, JsonValue> = el
+                    .properties
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect();
+                Some(ItemView::from_props(
+                    Some(el.id.clone()),
+                    el.name.as_str().to_string(),
+                    &props,
+                ))
+            }
+            JsonValue::Object(obj) => {
+                let id = obj.get("id").and_then(|v| v.as_str()).map(str::to_string);
+                let name = obj
+                    .get("name")
+                    .and_then(|v| v.as_str())
+                    .or(id.as_deref())
+                    .unwrap_or_default()
+                    .to_string();
+                Some(ItemView::from_props(id, name, obj))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn layer_elements<'m>(
+    model: &'m ProjectModel,
+    layer: &str,
+) -> impl Iterator<Item = &'m ArcadiaElement> {
+    model
+        .layers
+        .get(layer)
+        .into_iter()
+        .flat_map(|cols| cols.values())
+        .flatten()
+}
+
+/// Identifiants d'une référence simple, d'une liste ou d'objets `{ "@id" | "id" }`.
+fn references(value: Option<&JsonValue>) -> Vec<String> {
+    let as_id = |v: &JsonValue| match v {
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Object(o) => o
+            .get("@id")
+            .or_else(|| o.get("id"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        _ => None,
+    };
+    match value {
+        Some(JsonValue::Array(arr)) => arr.iter().filter_map(as_id).collect(),
+        Some(v) => as_id(v).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+// =========================================================================
+// COMPARAISON DES CONTRATS
+// =========================================================================
+
+/// Multiplicité `lower..upper` (`None` = non borné).
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Multiplicity {
+    lower: u64,
+    upper: Option<u64>,
+}
+
+impl Multiplicity {
+    /// Accepte `1`, `"0..1"`, `"1..*"`, `"*"` ou `{ "lower": 0, "upper": 4 }`.
+    fn parse(value: &JsonValue) -> Option<Self> {
+        let bound = |s: &str| -> Option<Option<u64>> {
+            match s.trim() {
+                "*" | "n" => Some(None),
+                n => n.parse().ok().map(Some),
+            }
+        };
+        match value {
+            JsonValue::Number(n) => n.as_u64().map(|v| Self {
+                lower: v,
+                upper: Some(v),
+            }),
+            JsonValue::String(s) => match s.split_once("..") {
+                Some((lo, up)) => Some(Self {
+                    lower: bound(lo)??,
+                    upper: bound(up)?,
+                }),
+                None => bound(s).map(|upper| Self {
+                    lower: upper.unwrap_or(0),
+                    upper,
+                }),
+            },
+            JsonValue::Object(o) => Some(Self {
+                lower: o.get("lower").and_then(|v| v.as_u64()).unwrap_or(0),
+                upper: o.get("upper").and_then(|v| v.as_u64()),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Vrai si `self` ne peut pas porter toutes les occurrences admises par `logical`.
+    fn narrows(&self, logical: &Multiplicity) -> bool {
+        let upper_narrowed = match (self.upper, logical.upper) {
+            (Some(_), None) => true,
+            (Some(p), Some(l)) => p < l,
+            _ => false,
+        };
+        upper_narrowed || self.lower > logical.lower
+    }
+}
+
+impl std::fmt::Display for Multiplicity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.upper {
+            Some(u) if u == self.lower => write!(f, "{}", u),
+            Some(u) => write!(f, "{}..{}", self.lower, u),
+            None => write!(f, "{}..*", self.lower),
+        }
+    }
+}
+
+fn compare(
+    interface: &ArcadiaElement,
+    logical: &ItemView,
+    phys: &ItemView,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let label = format!(
+        "'{}' (interface '{}')",
+        logical.name,
+        interface.name.as_str()
+    );
+
+    if let (Some(l), Some(p)) = (&logical.data_type, &phys.data_type) {
+        if l != p {
+            issues.push(issue(
+                Severity::Error,
+                "INTF_TYPE_MISMATCH",
+                &interface.id,
+                format!("Type de l'item {} : '{}' en LA, '{}' en PA.", label, l, p),
+            ));
+        }
+    }
+    if let (Some(l), Some(p)) = (&logical.direction, &phys.direction) {
+        if l != p {
+            issues.push(issue(
+                Severity::Error,
+                "INTF_DIRECTION_MISMATCH",
+                &interface.id,
+                format!("Direction de l'item {} : {} en LA, {} en PA.", label, l, p),
+            ));
+        }
+    }
+    if let (Some(l), Some(p)) = (&logical.multiplicity, &phys.multiplicity) {
+        if l != p {
+            let severity = if p.narrows(l) {
+                Severity::Error
+            } else {
+                Severity::Warning
+            };
+            issues.push(issue(
+                severity,
+                "INTF_MULTIPLICITY_MISMATCH",
+                &interface.id,
+                format!(
+                    "Multiplicité de l'item {} : {} en LA, {} en PA.",
+                    label, l, p
+                ),
+            ));
+        }
+    }
+    issues
+}
+
+fn issue(severity: Severity, rule_id: &str, element_id: &str, message: String) -> ValidationIssue {
+    ValidationIssue {
+        severity,
+        rule_id: rule_id.to_string(),
+        element_id: element_id.to_string(),
+        message,
+        suggestion: None,
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_engine::types::NameType;
+
+    fn el(id: &str, kind: &str, props: JsonValue) -> ArcadiaElement {
+        ArcadiaElement {
+            id: id.to_string(),
+            name: NameType::String(id.to_string()),
+            kind: kind.to_string(),
+            properties: props
+                .as_object()
+                .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn model() -> ProjectModel {
+        let mut m = ProjectModel::default();
+        m.add_element(
+            "la",
+            "interfaces",
+            el(
+                "IF_NAV",
+                "LogicalInterface",
+                json_value!({
+                    "exchangeItems": ["EI_POS", "EI_SPEED", "EI_MODE"]
+                }),
+            ),
+        );
+        m.add_element(
+            "la",
+            "interfaces",
+            el(
+                "IF_ORPHAN",
+                "LogicalInterface",
+                json_value!({
+                    "exchangeItems": ["EI_POS"]
+                }),
+            ),
+        );
+        m.add_element(
+            "la",
+            "exchange_items",
+            el(
+                "EI_POS",
+                "ExchangeItem",
+                json_value!({
+                    "dataType": "Position", "direction": "out", "multiplicity": "1"
+                }),
+            ),
+        );
+        m.add_element(
+            "la",
+            "exchange_items",
+            el(
+                "EI_SPEED",
+                "ExchangeItem",
+                json_value!({
+                    "dataType": "Float32", "direction": "out", "multiplicity": "0..4"
+                }),
+            ),
+        );
+        m.add_element(
+            "la",
+            "exchange_items",
+            el(
+                "EI_MODE",
+                "ExchangeItem",
+                json_value!({
+                    "dataType": "Mode", "direction": "in"
+                }),
+            ),
+        );
+        m
+    }
+
+    fn rules(issues: &[ValidationIssue]) -> Vec<(&str, &str)> {
+        issues
+            .iter()
+            .map(|i| (i.rule_id.as_str(), i.element_id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_consistent_realization_passes() {
+        let mut m = model();
+        m.add_element("pa", "exchanges", el("PL_BUS", "PhysicalLink", json_value!({
+            "realizes": [{ "@id": "IF_NAV" }, "IF_ORPHAN"],
+            "exchangeItems": [
+                "EI_POS",
+                { "name": "speed", "realizes": "EI_SPEED", "dataType": "Float32", "direction": "OUT", "multiplicity": { "lower": 0, "upper": 4 } },
+                { "name": "EI_MODE", "dataType": "Mode" }
+            ]
+        })));
+        let issues = InterfaceCompatibilityValidator::new().check_model(&m);
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    #[test]
+    fn test_refinement_mismatches_are_flagged() {
+        let mut m = model();
+        m.add_element(
+            "pa",
+            "exchanges",
+            el(
+                "PL_CAN",
+                "PhysicalLink",
+                json_value!({
+                    "realizes": "IF_NAV",
+                    "exchangeItems": [
+                        { "name": "EI_POS", "dataType": "Position", "direction": "in" },
+                        { "realizes": "EI_SPEED", "dataType": "Int16", "multiplicity": "0..2" }
+                    ]
+                }),
+            ),
+        );
+        let issues = InterfaceCompatibilityValidator::new().check_model(&m);
+        let found = rules(&issues);
+
+        assert!(found.contains(&("INTF_NOT_REALIZED", "IF_ORPHAN")));
+        assert!(found.contains(&("INTF_DIRECTION_MISMATCH", "IF_NAV")));
+        assert!(found.contains(&("INTF_TYPE_MISMATCH", "IF_NAV")));
+        assert!(found.contains(&("INTF_ITEM_MISSING", "IF_NAV")));
+        let multiplicity = issues
+            .iter()
+            .find(|i| i.rule_id == "INTF_MULTIPLICITY_MISMATCH")
+            .expect("multiplicité signalée");
+        // 0..2 en PA ne peut pas porter les 4 occurrences admises en LA
+        assert_eq!(multiplicity.severity, Severity::Error);
+        assert_eq!(issues.len(), 5);
+    }
+
+    #[test]
+    fn test_multiplicity_parsing() {
+        let m = |v: JsonValue| Multiplicity::parse(&v).unwrap();
+        assert_eq!(
+            m(json_value!("1")),
+            Multiplicity {
+                lower: 1,
+                upper: Some(1)
+            }
+        );
+        assert_eq!(
+            m(json_value!("1..*")),
+            Multiplicity {
+                lower: 1,
+                upper: None
+            }
+        );
+        assert_eq!(
+            m(json_value!("*")),
+            Multiplicity {
+                lower: 0,
+                upper: None
+            }
+        );
+        assert_eq!(
+            m(json_value!(2)),
+            Multiplicity {
+                lower: 2,
+                upper: Some(2)
+            }
+        );
+        assert!(m(json_value!("0..1")).narrows(&m(json_value!("0..*"))));
+        assert!(!m(json_value!("0..*")).narrows(&m(json_value!("1"))));
+        assert!(Multiplicity::parse(&json_value!("abc")).is_none());
+    }
+}
@@ -3,7 +3,11 @@
 pub mod compliance_validator;
 pub mod consistency_checker;
 pub mod dynamic_validator;
+pub mod incremental;
+pub mod interface_compatibility;
 pub mod ontological_validator;
+pub mod requirement_quality;
+pub mod sarif;
 
 use crate::utils::prelude::*;
 
@@ -14,7 +18,11 @@ use crate::model_engine::types::ArcadiaElement;
 pub use compliance_validator::ComplianceValidator;
 pub use consistency_checker::ConsistencyChecker;
 pub use dynamic_validator::DynamicValidator;
+pub use incremental::{ElementChange, IncrementalValidator, ValidationDelta};
+pub use interface_compatibility::InterfaceCompatibilityValidator;
 pub use ontological_validator::OntologicalValidator;
+pub use requirement_quality::{RequirementQuality, RequirementQualityAnalyzer};
+pub use sarif::{SarifExporter, SarifSource};
 
 /// Niveau de sévérité d'un problème de validation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
@@ -31,6 +39,9 @@ pub struct ValidationIssue {
     pub rule_id: String,
     pub element_id: String,
     pub message: String,
+    /// Correction proposée (reformulation, découpage...), lorsque le validateur en fournit une
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
 }
 
 /// Trait commun que tous les validateurs doivent implémenter.
@@ -75,6 +86,7 @@ mod tests {
                     rule_id: "MOCK_RULE".to_string(),
                     element_id: element.id.clone(),
                     message: "Invalid name".to_string(),
+                    suggestion: None,
                 }])
             } else {
                 Ok(vec![])
@@ -29,6 +29,7 @@ impl OntologicalValidator {
                     "Sémantique inconnue ou non-mappée dans l'ontologie : '{}'",
                     kind
                 ),
+                suggestion: None,
             });
         }
 
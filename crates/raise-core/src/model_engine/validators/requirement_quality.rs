@@ -0,0 +1,437 @@
+// FICHIER : src-tauri/src/model_engine/validators/requirement_quality.rs
+
+use super::{ModelValidator, Severity, ValidationIssue};
+use crate::ai::agents::prompt_registry::PromptRegistry;
+use crate::ai::agents::tools::extract_json_from_llm;
+use crate::ai::llm::client::{LlmBackend, LlmClient};
+use crate::model_engine::loader::ModelLoader;
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
+use crate::utils::data::json::Clearance;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Gabarit de prompt (surchargeable dans le `PromptRegistry`) de la revue LLM.
+pub const REQUIREMENT_REVIEW_TEMPLATE: &str = "requirement_quality_review";
+
+const DEFAULT_REVIEW_PROMPT: &str =
+    "Tu es un relecteur d'exigences d'ingénierie système (INCOSE). \
+Évalue l'exigence fournie et réponds UNIQUEMENT par un objet JSON strict : \
+{\"clarity\": 0.0-1.0, \"atomicity\": 0.0-1.0, \"testability\": 0.0-1.0, \
+\"findings\": [{\"message\": \"problème constaté\", \"suggestion\": \"reformulation proposée\"}]}";
+
+/// Termes vagues (FR / EN) et reformulation conseillée.
+const WEAK_WORDS: [(&str, &str); 22] = [
+    ("approprié", "préciser le critère d'acceptation"),
+    ("adéquat", "préciser le critère d'acceptation"),
+    ("suffisant", "donner la valeur minimale attendue"),
+    ("rapide", "donner une durée maximale (ex : ≤ 200 ms)"),
+    ("rapidement", "donner une durée maximale (ex : ≤ 200 ms)"),
+    ("facile", "définir une mesure d'utilisabilité"),
+    ("convivial", "définir une mesure d'utilisabilité"),
+    ("flexible", "énumérer les variations à supporter"),
+    (
+        "si possible",
+        "supprimer la condition ou en faire une exigence distincte",
+    ),
+    ("environ", "donner une valeur et une tolérance"),
+    ("etc", "énumérer tous les cas"),
+    ("et/ou", "choisir « et » ou « ou » explicitement"),
+    ("optimal", "donner la valeur cible mesurable"),
+    ("minimiser", "donner un seuil maximal"),
+    ("maximiser", "donner un seuil minimal"),
+    ("appropriate", "state the acceptance criterion"),
+    ("adequate", "state the acceptance criterion"),
+    ("user-friendly", "define a usability measure"),
+    ("fast", "give a maximum duration (e.g. ≤ 200 ms)"),
+    ("as soon as possible", "give a maximum duration"),
+    ("approximately", "give a value and a tolerance"),
+    ("and/or", "choose « and » or « or » explicitly"),
+];
+
+/// Verbes d'obligation : une exigence atomique en porte exactement un.
+const MODAL_VERBS: [&str; 8] = [
+    "shall", "must", "doit", "doivent", "devra", "devront", "should", "will",
+];
+
+/// Termes invérifiables (universels absolus).
+const UNVERIFIABLE: [&str; 5] = [
+    "toujours",
+    "jamais",
+    "always",
+    "never",
+    "en toutes circonstances",
+];
+
+/// Propriétés portant le texte de l'exigence, par ordre de priorité.
+const TEXT_KEYS: [&str; 3] = ["statement", "text", "description"];
+
+/// Score d'une exigence (0 = mauvais, 1 = excellent) et constats associés.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct RequirementQuality {
+    pub requirement_id: String,
+    /// Absence d'ambiguïté (termes vagues)
+    pub clarity: f64,
+    pub atomicity: f64,
+    pub testability: f64,
+    pub score: f64,
+    /// Vrai si la revue LLM a été intégrée au score
+    pub llm_reviewed: bool,
+    pub issues: Vec<ValidationIssue>,
+}
+
+/// Analyse de qualité des exigences (`transverse.requirements`) : règles lexicales,
+/// complétées par une revue du LLM local lorsqu'un client est fourni.
+#[derive(Default)]
+pub struct RequirementQualityAnalyzer {
+    llm: Option<LlmClient>,
+}
+
+impl RequirementQualityAnalyzer {
+    pub fn new() -> Self {
+        Self { llm: None }
+    }
+
+    pub fn with_llm(llm: LlmClient) -> Self {
+        Self { llm: Some(llm) }
+    }
+
+    /// Analyse toutes les exigences du modèle.
+    pub async fn analyze_model(&self, model: &ProjectModel) -> Vec<RequirementQuality> {
+        let mut results = Vec::new();
+        for requirement in model.get_collection("transverse", "requirements") {
+            results.push(self.analyze(requirement).await);
+        }
+        results
+    }
+
+    /// Analyse une exigence. Un échec du LLM dégrade en analyse par règles seules.
+    pub async fn analyze(&self, requirement: &ArcadiaElement) -> RequirementQuality {
+        let text = requirement_text(requirement);
+        let mut quality = score_by_rules(&requirement.id, &text, requirement);
+
+        if let Some(llm) = &self.llm {
+            match self.review_with_llm(llm, &text).await {
+                Ok(review) => quality.merge_review(review),
+                Err(e) => user_warn!(
+                    "WRN_REQ_QUALITY_LLM_UNAVAILABLE",
+                    json_value!({ "requirement_id": requirement.id, "error": e.to_string() })
+                ),
+            }
+        }
+        quality
+    }
+
+    async fn review_with_llm(&self, llm: &LlmClient, text: &str) -> RaiseResult<LlmReview> {
+        let system_prompt = PromptRegistry::system(llm.storage())
+            .render_or(REQUIREMENT_REVIEW_TEMPLATE, DEFAULT_REVIEW_PROMPT, None)
+            .await;
+        let response = llm
+            .ask(
+                LlmBackend::LocalLlama,
+                &system_prompt,
+                text,
+                Clearance::Internal,
+            )
+            .await?;
+        parse_review(&response)
+    }
+}
+
+#[async_interface]
+impl ModelValidator for RequirementQualityAnalyzer {
+    async fn validate_element(
+        &self,
+        element: &ArcadiaElement,
+        _loader: &ModelLoader<'_>,
+    ) -> RaiseResult<Vec<ValidationIssue>> {
+        if !element.kind.ends_with("Requirement") {
+            return Ok(Vec::new());
+        }
+        Ok(self.analyze(element).await.issues)
+    }
+
+    async fn validate_full(&self, loader: &ModelLoader<'_>) -> RaiseResult<Vec<ValidationIssue>> {
+        let model = loader.load_full_model().await?;
+        Ok(self
+            .analyze_model(&model)
+            .await
+            .into_iter()
+            .flat_map(|q| q.issues)
+            .collect())
+    }
+}
+
+// =========================================================================
+// RÈGLES
+// =========================================================================
+
+fn requirement_text(requirement: &ArcadiaElement) -> String {
+    TEXT_KEYS
+        .iter()
+        .find_map(|key| requirement.properties.get(*key).and_then(|v| v.as_str()))
+        .unwrap_or_else(|| requirement.name.as_str())
+        .to_string()
+}
+
+fn issue(
+    severity: Severity,
+    rule_id: &str,
+    requirement_id: &str,
+    message: String,
+    suggestion: Option<String>,
+) -> ValidationIssue {
+    ValidationIssue {
+        severity,
+        rule_id: rule_id.to_string(),
+        element_id: requirement_id.to_string(),
+        message,
+        suggestion,
+    }
+}
+
+/// Occurrences d'un terme en mot entier (`lower` déjà en minuscules).
+fn count_term(lower: &str, term: &str) -> usize {
+    lower
+        .match_indices(term)
+        .filter(|(start, _)| {
+            let before = lower[..*start].chars().next_back();
+            let after = lower[start + term.len()..].chars().next();
+            !before.is_some_and(|c| c.is_alphanumeric())
+                && !after.is_some_and(|c| c.is_alphanumeric())
+        })
+        .count()
+}
+
+fn contains_term(lower: &str, term: &str) -> bool {
+    count_term(lower, term) > 0
+}
+
+fn score_by_rules(id: &str, text: &str, requirement: &ArcadiaElement) -> RequirementQuality {
+    let lower = text.to_lowercase();
+    let mut issues = Vec::new();
+
+    // 1. Ambiguïté : chaque terme vague coûte un quart de point
+    let weak: Vec<&(&str, &str)> = WEAK_WORDS
+        .iter()
+        .filter(|(word, _)| contains_term(&lower, word))
+        .collect();
+    for (word, advice) in &weak {
+        issues.push(issue(
+            Severity::Warning,
+            "REQ_AMBIGUOUS_TERM",
+            id,
+            format!("Terme vague « {} » dans l'exigence.", word),
+            Some(format!("Remplacer « {} » : {}.", word, advice)),
+        ));
+    }
+    let clarity = (1.0 - 0.25 * weak.len() as f64).max(0.0);
+
+    // 2. Atomicité : un seul verbe d'obligation
+    let modals: usize = MODAL_VERBS
+        .iter()
+        .map(|verb| count_term(&lower, verb))
+        .sum();
+    let atomicity = match modals {
+        0 => {
+            issues.push(issue(
+                Severity::Warning,
+                "REQ_NO_MODAL",
+                id,
+                "Aucun verbe d'obligation : l'énoncé ressemble à une description.".to_string(),
+                Some("Formuler « Le système doit … » (ou « The system shall … »).".to_string()),
+            ));
+            0.5
+        }
+        1 => 1.0,
+        n => {
+            issues.push(issue(
+                Severity::Warning,
+                "REQ_NOT_ATOMIC",
+                id,
+                format!("L'exigence porte {} obligations distinctes.", n),
+                Some("Scinder en une exigence par obligation.".to_string()),
+            ));
+            1.0 / n as f64
+        }
+    };
+
+    // 3. Testabilité : critère mesurable ou méthode de vérification, pas d'universel absolu
+    let measurable = lower.chars().any(|c| c.is_ascii_digit());
+    let has_method = requirement.properties.contains_key("verificationMethod");
+    let mut testability: f64 = match (measurable, has_method) {
+        (true, true) => 1.0,
+        (true, false) | (false, true) => 0.75,
+        (false, false) => 0.4,
+    };
+    if let Some(term) = UNVERIFIABLE.iter().find(|t| contains_term(&lower, t)) {
+        testability -= 0.25;
+        issues.push(issue(
+            Severity::Warning,
+            "REQ_UNVERIFIABLE",
+            id,
+            format!("« {} » ne peut pas être démontré par un essai fini.", term),
+            Some("Borner la condition (durée, nombre de cycles, domaine d'emploi).".to_string()),
+        ));
+    }
+    if !measurable && !has_method {
+        issues.push(issue(
+            Severity::Info,
+            "REQ_NOT_TESTABLE",
+            id,
+            "Ni valeur mesurable ni méthode de vérification (`verificationMethod`).".to_string(),
+            Some("Ajouter un seuil chiffré ou renseigner la méthode (test, analyse, inspection, démonstration).".to_string()),
+        ));
+    }
+    let testability = testability.max(0.0);
+
+    RequirementQuality {
+        requirement_id: id.to_string(),
+        clarity,
+        atomicity,
+        testability,
+        score: (clarity + atomicity + testability) / 3.0,
+        llm_reviewed: false,
+        issues,
+    }
+}
+
+// =========================================================================
+// REVUE LLM
+// =========================================================================
+
+#[derive(Debug, Clone, Default, Deserializable)]
+struct LlmFinding {
+    message: String,
+    #[serde(default)]
+    suggestion: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserializable)]
+struct LlmReview {
+    clarity: Option<f64>,
+    atomicity: Option<f64>,
+    testability: Option<f64>,
+    #[serde(default)]
+    findings: Vec<LlmFinding>,
+}
+
+fn parse_review(response: &str) -> RaiseResult<LlmReview> {
+    match json::deserialize_from_str::<LlmReview>(&extract_json_from_llm(response)) {
+        Ok(review) => Ok(review),
+        Err(e) => raise_error!(
+            "ERR_REQ_QUALITY_LLM_PARSE",
+            error = e.to_string(),
+            context = json_value!({ "response": response })
+        ),
+    }
+}
+
+impl RequirementQuality {
+    /// Moyenne des scores règles / LLM, constats LLM ajoutés en suggestions.
+    fn merge_review(&mut self, review: LlmReview) {
+        let blend = |rule: f64, llm: Option<f64>| match llm {
+            Some(v) => (rule + v.clamp(0.0, 1.0)) / 2.0,
+            None => rule,
+        };
+        self.clarity = blend(self.clarity, review.clarity);
+        self.atomicity = blend(self.atomicity, review.atomicity);
+        self.testability = blend(self.testability, review.testability);
+        self.score = (self.clarity + self.atomicity + self.testability) / 3.0;
+        self.llm_reviewed = true;
+        for finding in review.findings {
+            self.issues.push(issue(
+                Severity::Info,
+                "REQ_LLM_REVIEW",
+                &self.requirement_id,
+                finding.message,
+                finding.suggestion,
+            ));
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_engine::types::NameType;
+
+    fn requirement(id: &str, statement: &str) -> ArcadiaElement {
+        let mut properties = UnorderedMap::new();
+        properties.insert("statement".to_string(), json_value!(statement));
+        ArcadiaElement {
+            id: id.to_string(),
+            name: NameType::String(id.to_string()),
+            kind: "Requirement".to_string(),
+            properties,
+        }
+    }
+
+    fn rules(q: &RequirementQuality) -> Vec<&str> {
+        q.issues.iter().map(|i| i.rule_id.as_str()).collect()
+    }
+
+    #[async_test]
+    async fn test_rules_score_good_and_bad_requirements() {
+        let analyzer = RequirementQualityAnalyzer::new();
+
+        let mut r1 = requirement("R1", "Le système doit freiner en moins de 150 ms.");
+        r1.properties
+            .insert("verificationMethod".to_string(), json_value!("test"));
+        let good = analyzer.analyze(&r1).await;
+        assert!(good.issues.is_empty(), "{:?}", good.issues);
+        assert_eq!(good.score, 1.0);
+
+        let bad = analyzer
+            .analyze(&requirement(
+                "R2",
+                "Le système doit être rapide et convivial et doit toujours répondre, etc.",
+            ))
+            .await;
+        let ids = rules(&bad);
+        assert_eq!(
+            ids.iter().filter(|r| **r == "REQ_AMBIGUOUS_TERM").count(),
+            3
+        );
+        assert!(ids.contains(&"REQ_NOT_ATOMIC"));
+        assert!(ids.contains(&"REQ_UNVERIFIABLE"));
+        assert!(ids.contains(&"REQ_NOT_TESTABLE"));
+        assert!(bad.score < 0.4);
+        assert!(bad.issues.iter().all(|i| i.suggestion.is_some()));
+        assert!(!bad.llm_reviewed);
+    }
+
+    #[test]
+    fn test_whole_word_matching() {
+        // « fast » ne doit pas être détecté dans « breakfast », ni « will » dans « willing »
+        let q = score_by_rules(
+            "R3",
+            "The breakfast server shall be willing to serve 200 guests.",
+            &requirement("R3", ""),
+        );
+        assert!(rules(&q).is_empty(), "{:?}", q.issues);
+        assert_eq!(q.atomicity, 1.0);
+    }
+
+    #[test]
+    fn test_llm_review_is_blended() -> RaiseResult<()> {
+        let mut q = score_by_rules(
+            "R4",
+            "The pump shall deliver 10 L/min.",
+            &requirement("R4", ""),
+        );
+        let review = parse_review(
+            "Voici mon avis : {\"clarity\": 0.5, \"testability\": 1.0, \"findings\": [{\"message\": \"Pression non précisée\", \"suggestion\": \"Ajouter la pression de refoulement\"}]}",
+        )?;
+        q.merge_review(review);
+        assert!(q.llm_reviewed);
+        assert_eq!(q.clarity, 0.75);
+        assert_eq!(q.atomicity, 1.0);
+        assert_eq!(q.issues.len(), 1);
+        assert_eq!(q.issues[0].rule_id, "REQ_LLM_REVIEW");
+        assert!(parse_review("pas de JSON").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+// FICHIER : src-tauri/src/model_engine/validators/sarif.rs
+
+//! Export SARIF 2.1.0 des résultats de validation et de conformité : les interfaces de revue
+//! de code (GitHub code scanning, GitLab) annotent les fichiers de modèle du dépôt à la ligne
+//! où l'élément concerné est déclaré.
+
+use super::{Severity, ValidationIssue};
+use crate::utils::prelude::*;
+
+pub const SARIF_VERSION: &str = "2.1.0";
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Fichier de modèle versionné : ligne de déclaration de chaque élément (`"id"` / `"_id"`).
+pub struct SarifSource {
+    /// Chemin relatif à la racine du dépôt (ex: `models/drone.json`).
+    uri: String,
+    lines: UnorderedMap<String, usize>,
+}
+
+impl SarifSource {
+    pub fn new(uri: &str, content: &str) -> Self {
+        let mut lines = UnorderedMap::new();
+        for (number, line) in content.lines().enumerate() {
+            for id in declared_ids(line) {
+                lines.entry(id.to_string()).or_insert(number + 1);
+            }
+        }
+        Self {
+            uri: uri.replace('\\', "/"),
+            lines,
+        }
+    }
+
+    pub fn line_of(&self, element_id: &str) -> Option<usize> {
+        self.lines.get(element_id).copied()
+    }
+}
+
+/// Valeurs des clés `"id"` / `"_id"` déclarées sur une ligne JSON.
+fn declared_ids(line: &str) -> Vec<&str> {
+    let mut ids = Vec::new();
+    for key in ["\"id\"", "\"_id\""] {
+        let mut rest = line;
+        while let Some(pos) = rest.find(key) {
+            rest = &rest[pos + key.len()..];
+            let value = rest
+                .trim_start()
+                .strip_prefix(':')
+                .map(str::trim_start)
+                .and_then(|v| v.strip_prefix('"'))
+                .and_then(|v| v.find('"').map(|end| &v[..end]));
+            if let Some(id) = value.filter(|id| !id.is_empty()) {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+pub struct SarifExporter {
+    sources: Vec<SarifSource>,
+}
+
+impl Default for SarifExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SarifExporter {
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+        }
+    }
+
+    pub fn with_source(mut self, source: SarifSource) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Journal SARIF (un `run` RAISE). Un problème est rattaché au premier fichier déclarant
+    /// son élément, sinon à la première ligne du premier fichier fourni.
+    pub fn export(&self, issues: &[ValidationIssue]) -> JsonValue {
+        let mut rule_ids: Vec<&str> = Vec::new();
+        let mut rules = Vec::new();
+        let mut results = Vec::new();
+
+        for issue in issues {
+            let rule_index = match rule_ids.iter().position(|id| *id == issue.rule_id) {
+                Some(index) => index,
+                None => {
+                    rule_ids.push(&issue.rule_id);
+                    rules.push(json_value!({
+                        "id": issue.rule_id,
+                        "name": issue.rule_id,
+                        "defaultConfiguration": { "level": sarif_level(issue.severity) }
+                    }));
+                    rule_ids.len() - 1
+                }
+            };
+
+            let mut text = issue.message.clone();
+            if let Some(suggestion) = &issue.suggestion {
+                text.push_str(&format!("\nSuggestion : {}", suggestion));
+            }
+            let mut result = json_value!({
+                "ruleId": issue.rule_id,
+                "ruleIndex": rule_index,
+                "level": sarif_level(issue.severity),
+                "message": { "text": text },
+                "partialFingerprints": {
+                    "raiseElement/v1": format!("{}:{}", issue.rule_id, issue.element_id)
+                },
+                "properties": { "elementId": issue.element_id }
+            });
+            if let Some(location) = self.locate(&issue.element_id) {
+                result["locations"] = json_value!([location]);
+            }
+            results.push(result);
+        }
+
+        json_value!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "RAISE",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules
+                    }
+                },
+                "results": results
+            }]
+        })
+    }
+
+    pub async fn export_to_file(&self, issues: &[ValidationIssue], path: &Path) -> RaiseResult<()> {
+        fs::write_json_atomic_async(path, &self.export(issues)).await
+    }
+
+    fn locate(&self, element_id: &str) -> Option<JsonValue> {
+        let (source, line) = self
+            .sources
+            .iter()
+            .find_map(|s| s.line_of(element_id).map(|line| (s, line)))
+            .or_else(|| self.sources.first().map(|s| (s, 1)))?;
+        Some(json_value!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": source.uri, "uriBaseId": "%SRCROOT%" },
+                "region": { "startLine": line }
+            }
+        }))
+    }
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(severity: Severity, rule_id: &str, element_id: &str) -> ValidationIssue {
+        ValidationIssue {
+            severity,
+            rule_id: rule_id.to_string(),
+            element_id: element_id.to_string(),
+            message: format!("{} sur {}", rule_id, element_id),
+            suggestion: None,
+        }
+    }
+
+    #[test]
+    fn test_sarif_locates_elements_in_model_file() {
+        let content = r#"{
+  "layers": { "oa": { "actors": [
+    { "id": "ACT-1", "name": "" },
+    { "id": "ACT-2", "name": "Pilote" }
+  ] } }
+}"#;
+        let exporter =
+            SarifExporter::new().with_source(SarifSource::new("models\\drone.json", content));
+        let mut warning = issue(Severity::Warning, "RULE_NAMING", "ACT-1");
+        warning.suggestion = Some("Nommer l'acteur".to_string());
+        let log = exporter.export(&[
+            warning,
+            issue(Severity::Info, "RULE_DOC", "ACT-2"),
+            issue(Severity::Info, "RULE_DOC", "GHOST"),
+        ]);
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().map(Vec::len),
+            Some(2)
+        );
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results[0]["level"], "warning");
+        assert!(results[0]["message"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Nommer l'acteur"));
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "models/drone.json");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(results[1]["ruleIndex"], 1);
+        assert_eq!(results[1]["level"], "note");
+        assert_eq!(
+            results[1]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            4
+        );
+        // Élément introuvable : rattaché au début du fichier
+        assert_eq!(
+            results[2]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            1
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::utils::prelude::*;
 use super::runtime::CognitivePlugin;
 use crate::ai::orchestrator::AiOrchestrator;
 use crate::json_db::storage::StorageEngine;
+use crate::kernel::events::{publish_event, RaiseEvent};
 
 pub struct PluginManager {
     storage: StorageEngine,
@@ -30,7 +31,10 @@ impl PluginManager {
         space: &str,
         db: &str,
     ) -> RaiseResult<()> {
-        println!("🔌 Chargement du plugin : {} ({})", plugin_id, file_path);
+        user_info!(
+            "INF_PLUGIN_LOADING",
+            json_value!({ "plugin_id": plugin_id, "file_path": file_path })
+        );
 
         let binary = match fs::read_async(file_path).await {
             Ok(b) => b,
@@ -60,6 +64,11 @@ impl PluginManager {
             .lock()
             .await
             .insert(plugin_id.to_string(), plugin);
+
+        publish_event(RaiseEvent::PluginLoaded {
+            plugin_id: plugin_id.to_string(),
+            file_path: file_path.to_string(),
+        });
         Ok(())
     }
 
@@ -0,0 +1,79 @@
+// FICHIER : crates/raise-core/src/services/access_service.rs
+//! Façade métier du contrôle d'accès par rôle pour l'interface graphique.
+
+use crate::utils::context::audit::{AuditChannel, AuditLog};
+use crate::utils::context::{
+    AccessGuard, AccessIdentity, AccessRole, ApiTokenClaims, ApiTokenIssuer, Session,
+    SessionManager,
+};
+use crate::utils::data::secrets::SecretsProvider;
+use crate::utils::prelude::*;
+
+/// Rattache l'identité d'accès à l'utilisateur de la session locale ouverte.
+pub fn bind_session(guard: &AccessGuard, session: &Session) -> AccessIdentity {
+    guard.set_local_user(&AppConfig::get().access_control, &session.user_handle)
+}
+
+/// Ouvre une identité à partir d'un jeton OIDC.
+pub fn login_oidc(guard: &AccessGuard, token: &str) -> RaiseResult<AccessIdentity> {
+    guard.login_oidc(&AppConfig::get().access_control, token)
+}
+
+/// Ouvre une identité à partir d'un jeton d'API émis par ce poste.
+pub async fn login_api_token(guard: &AccessGuard, token: &str) -> RaiseResult<AccessIdentity> {
+    let issuer = ApiTokenIssuer::from_secrets(SecretsProvider::global()).await?;
+    guard.login_api_token(&issuer, token)
+}
+
+/// Émet un jeton d'API (durée en heures, sans expiration si absente).
+pub async fn issue_api_token(
+    user: &str,
+    roles: Vec<AccessRole>,
+    ttl_hours: Option<i64>,
+) -> RaiseResult<(String, ApiTokenClaims)> {
+    let issuer = ApiTokenIssuer::from_secrets(SecretsProvider::global()).await?;
+    issuer.issue(user, roles, ttl_hours.map(CalendarDuration::hours))
+}
+
+/// Identité soumise aux règles d'accès des collections (`None` si le contrôle est désactivé).
+pub fn collection_identity(guard: &AccessGuard) -> Option<AccessIdentity> {
+    if !AppConfig::get().access_control.enabled {
+        return None;
+    }
+    guard.identity()
+}
+
+pub fn whoami(guard: &AccessGuard) -> Option<AccessIdentity> {
+    guard.identity()
+}
+
+pub fn logout(guard: &AccessGuard) {
+    guard.logout();
+}
+
+/// Contrôle une commande invoquée depuis le frontend.
+pub fn authorize(guard: &AccessGuard, command: &str) -> RaiseResult<()> {
+    guard.authorize(&AppConfig::get().access_control, command)
+}
+
+/// Trace un refus d'accès dans le journal d'audit (issue `failure`).
+pub async fn record_denial(
+    audit: &AuditLog,
+    guard: &AccessGuard,
+    session_mgr: &SessionManager,
+    command: &str,
+    params: &JsonValue,
+    error_code: &str,
+) -> RaiseResult<()> {
+    let actor = match guard.identity() {
+        Some(identity) => identity.user,
+        None => match session_mgr.get_current_session().await {
+            Some(session) => session.user_handle,
+            None => "anonymous".to_string(),
+        },
+    };
+    audit
+        .record(&actor, AuditChannel::Gui, command, params, Some(error_code))
+        .await?;
+    Ok(())
+}
@@ -1,7 +1,8 @@
 // FICHIER : crates/raise-core/src/services/ai_service.rs
 
+use crate::ai::agents::coordination::{ElementLockRegistry, WriteConflict};
 use crate::ai::agents::AgentResult;
-use crate::ai::orchestrator::AiOrchestrator;
+use crate::ai::orchestrator::{AiOrchestrator, AiStreamEvent, StreamCancellation};
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 // Import Moteur Natif
@@ -10,9 +11,11 @@ use crate::ai::llm::NativeLlmState;
 // Imports World Model
 use crate::ai::nlp::parser::CommandType;
 use crate::model_engine::types::{ArcadiaElement, NameType};
+use crate::model_engine::validators::{RequirementQuality, RequirementQualityAnalyzer};
 
 // Imports GNN Arcadia
 use crate::ai::deep_learning::models::gnn_model::ArcadiaGnnModel;
+use crate::ai::graph_store::duplicates::{find_duplicates, DuplicateOptions, DuplicateReport};
 use crate::ai::graph_store::{GraphAdjacency, GraphFeatures};
 use crate::ai::nlp::embeddings::EmbeddingEngine;
 use crate::json_db::collections::manager::CollectionsManager;
@@ -23,7 +26,12 @@ use crate::ai::training::dataset::{extract_domain_data, TrainingExample};
 
 use crate::ai::agents::prompt_engine::PromptEngine;
 use crate::ai::agents::tools::extract_json_from_llm;
+use crate::ai::context::rag::RagRetriever;
 use crate::ai::llm::client::{LlmBackend, LlmClient, LlmEngine};
+use crate::ai::memory::migration::{EmbeddingMigrator, EmbeddingStatus, MigrationJob};
+use crate::ai::voice::ingest::{AudioPayload, Transcription};
+use crate::services::project_service::{self, ProjectWorkspace};
+use crate::services::voice_service::{self, VoiceState};
 use crate::utils::data::json::Clearance;
 
 /// 🎯 LOGIQUE CORE : Exécute un blueprint de prompt (Data-Driven).
@@ -97,6 +105,51 @@ pub async fn ai_export_dataset(
     extract_domain_data(&manager, domain).await // 🎯 FIX : Retrait du '&' superflu
 }
 
+/// État de l'index RAG d'une base au regard du modèle d'embedding actif.
+pub async fn ai_embedding_status(
+    storage: &StorageEngine,
+    space: &str,
+    db_name: &str,
+) -> RaiseResult<EmbeddingStatus> {
+    let manager = CollectionsManager::new(storage, space, db_name);
+    let mut rag = RagRetriever::new(&manager).await?;
+    rag.embedding_status(&manager).await
+}
+
+/// Lance la ré-vectorisation en tâche de fond et retourne immédiatement le job
+/// (None si l'index est déjà à jour). La progression se lit via `ai_embedding_migrations`.
+pub async fn ai_embedding_migrate(
+    storage: SharedRef<StorageEngine>,
+    space: String,
+    db_name: String,
+) -> RaiseResult<Option<MigrationJob>> {
+    let manager = CollectionsManager::new(storage.as_ref(), &space, &db_name);
+    let mut rag = RagRetriever::new(&manager).await?;
+    let status = rag.embedding_status(&manager).await?;
+    if !status.migration_required {
+        return Ok(None);
+    }
+    let job = EmbeddingMigrator::system(storage.as_ref())
+        .prepare(&manager, &status)
+        .await?;
+
+    let background_job = job.clone();
+    spawn_async_task(async move {
+        let manager = CollectionsManager::new(storage.as_ref(), &space, &db_name);
+        let migrator = EmbeddingMigrator::system(storage.as_ref());
+        if let Err(e) = rag
+            .run_embedding_migration(&manager, &migrator, background_job)
+            .await
+        {
+            user_error!(
+                "ERR_EMBEDDING_MIGRATION_FAILED",
+                json_value!({ "space": space, "db": db_name, "error": e.to_string() })
+            );
+        }
+    });
+    Ok(Some(job))
+}
+
 // --- STATES ---
 pub struct AiState(pub AsyncMutex<Option<SharedRef<AsyncMutex<AiOrchestrator>>>>);
 
@@ -106,6 +159,12 @@ impl AiState {
     }
 }
 
+/// Réponses en flux en cours, indexées par identifiant de flux (annulation).
+#[derive(Default)]
+pub struct AiStreamState {
+    pub streams: SyncMutex<UnorderedMap<String, StreamCancellation>>,
+}
+
 // --- COMMANDES ORCHESTRATION UNIFIÉE (V2) ---
 
 pub async fn ai_reset(ai_state: &AiState) -> RaiseResult<()> {
@@ -223,6 +282,150 @@ pub async fn ai_chat(ai_state: &AiState, user_input: &str) -> RaiseResult<AgentR
     }
 }
 
+/// Variante en flux de `ai_chat` : les fragments sont émis sur `events` pendant la génération.
+/// Le flux est enregistré sous `stream_id` jusqu'à sa fin pour pouvoir être annulé.
+pub async fn ai_chat_stream(
+    ai_state: &AiState,
+    streams: &AiStreamState,
+    stream_id: &str,
+    user_input: &str,
+    events: AsyncChannel::UnboundedSender<AiStreamEvent>,
+) -> RaiseResult<AgentResult> {
+    let shared_orch = match &*ai_state.0.lock().await {
+        Some(orch) => orch.clone(),
+        None => raise_error!("ERR_AI_SYSTEM_NOT_READY"),
+    };
+
+    let cancel = StreamCancellation::new();
+    match streams.streams.lock() {
+        Ok(mut map) => map.insert(stream_id.to_string(), cancel.clone()),
+        Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+    };
+
+    let outcome = {
+        let mut orchestrator = shared_orch.lock().await;
+        orchestrator
+            .execute_workflow_stream(user_input, events, cancel.clone())
+            .await
+    };
+    if let Ok(mut map) = streams.streams.lock() {
+        map.remove(stream_id);
+    }
+
+    match outcome {
+        Ok(res) => Ok(res),
+        Err(e) if cancel.is_cancelled() => raise_error!(
+            "ERR_AI_STREAM_CANCELLED",
+            error = e.to_string(),
+            context = json_value!({ "stream_id": stream_id })
+        ),
+        Err(e) => raise_error!("ERR_AI_WORKFLOW_EXECUTION", error = e.to_string()),
+    }
+}
+
+/// Demande l'arrêt d'une réponse en flux ; `false` si le flux est inconnu ou déjà terminé.
+pub fn ai_chat_cancel(streams: &AiStreamState, stream_id: &str) -> RaiseResult<bool> {
+    let map = match streams.streams.lock() {
+        Ok(g) => g,
+        Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+    };
+    match map.get(stream_id) {
+        Some(cancel) => {
+            cancel.cancel();
+            user_info!(
+                "INF_AI_STREAM_CANCEL_REQUESTED",
+                json_value!({ "stream_id": stream_id })
+            );
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Écritures d'agents en conflit, en attente d'arbitrage (registre partagé avec l'orchestrateur).
+pub fn ai_write_conflicts() -> Vec<WriteConflict> {
+    ElementLockRegistry::global().pending_conflicts(None)
+}
+
+/// Arbitre un conflit d'écriture : `approved` applique la proposition de l'agent.
+pub async fn ai_resolve_write_conflict(
+    storage: &StorageEngine,
+    conflict_id: &str,
+    approved: bool,
+) -> RaiseResult<WriteConflict> {
+    ElementLockRegistry::global()
+        .resolve(storage, conflict_id, approved)
+        .await
+}
+
+/// Doublons sémantiques du projet actif (vecteurs de son GraphStore).
+pub async fn ai_find_duplicates(
+    workspace: &ProjectWorkspace,
+    storage: &StorageEngine,
+    options: &DuplicateOptions,
+) -> RaiseResult<DuplicateReport> {
+    let (space, db, store) = project_service::get_active_graph_store(workspace).await?;
+    let model = project_service::get_active_model(workspace).await?;
+    let model = model.lock().await;
+    let manager = CollectionsManager::new(storage, &space, &db);
+    find_duplicates(&store, &manager, &model, options).await
+}
+
+/// 📝 Qualité des exigences du projet actif : règles lexicales, plus revue du LLM local si `use_llm`.
+pub async fn ai_requirement_quality(
+    workspace: &ProjectWorkspace,
+    ai_state: &AiState,
+    storage: SharedRef<StorageEngine>,
+    use_llm: bool,
+) -> RaiseResult<Vec<RequirementQuality>> {
+    let (space, db, _) = project_service::get_active_graph_store(workspace).await?;
+    let analyzer = if use_llm {
+        let native_llm = {
+            let guard = ai_state.0.lock().await;
+            match &*guard {
+                Some(orch_ref) => orch_ref.lock().await.llm_native.clone(),
+                None => None,
+            }
+        };
+        let manager = CollectionsManager::new(storage.as_ref(), &space, &db);
+        RequirementQualityAnalyzer::with_llm(
+            LlmClient::new(&manager, storage.clone(), native_llm).await?,
+        )
+    } else {
+        RequirementQualityAnalyzer::new()
+    };
+
+    let model = project_service::get_active_model(workspace).await?;
+    let model = model.lock().await.clone();
+    Ok(analyzer.analyze_model(&model).await)
+}
+
+/// Transcription suivie d'un tour de chat (édition du modèle à la voix).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct VoiceChatResult {
+    pub transcription: Transcription,
+    /// Absent si rien d'intelligible n'a été entendu.
+    pub result: Option<AgentResult>,
+}
+
+pub async fn ai_voice_chat(
+    ai_state: &AiState,
+    voice_state: &VoiceState,
+    storage: &StorageEngine,
+    payload: &AudioPayload,
+) -> RaiseResult<VoiceChatResult> {
+    let transcription = voice_service::transcribe_recording(voice_state, storage, payload).await?;
+    let result = if transcription.text.trim().is_empty() {
+        None
+    } else {
+        Some(ai_chat(ai_state, &transcription.text).await?)
+    };
+    Ok(VoiceChatResult {
+        transcription,
+        result,
+    })
+}
+
 pub async fn ask_native_llm(
     state: &NativeLlmState,
     sys: &str, // 🎯 OPTIMISATION : &str
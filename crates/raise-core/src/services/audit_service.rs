@@ -0,0 +1,64 @@
+// FICHIER : crates/raise-core/src/services/audit_service.rs
+//! Façade métier du journal d'audit pour l'interface graphique.
+
+use crate::blockchain::storage::chain::Ledger;
+use crate::utils::context::audit::{
+    is_state_changing, parse_day, AuditAnchor, AuditChannel, AuditEntry, AuditLog, AuditQuery,
+    AuditVerification,
+};
+use crate::utils::context::SessionManager;
+use crate::utils::prelude::*;
+
+/// Trace une commande invoquée depuis le frontend (ignorée si elle est en lecture seule).
+pub async fn record_gui_invocation(
+    audit: &AuditLog,
+    session_mgr: &SessionManager,
+    command: &str,
+    params: &JsonValue,
+) -> RaiseResult<Option<AuditEntry>> {
+    if !is_state_changing(command) {
+        return Ok(None);
+    }
+    let actor = match session_mgr.get_current_session().await {
+        Some(session) => session.user_handle,
+        None => "anonymous".to_string(),
+    };
+    let entry = audit
+        .record(&actor, AuditChannel::Gui, command, params, None)
+        .await?;
+    Ok(Some(entry))
+}
+
+/// Consultation du journal (dates au format `YYYY-MM-DD`).
+pub async fn list_entries(
+    audit: &AuditLog,
+    actor: Option<String>,
+    action: Option<String>,
+    since: Option<&str>,
+    until: Option<&str>,
+    limit: Option<usize>,
+) -> RaiseResult<Vec<AuditEntry>> {
+    let filter = AuditQuery {
+        actor,
+        action,
+        since: since.map(parse_day).transpose()?,
+        until: until.map(parse_day).transpose()?,
+        outcome: None,
+        limit,
+    };
+    audit.query(&filter).await
+}
+
+/// Scelle le digest d'une journée (ancré dans le Ledger s'il est monté).
+pub async fn anchor_day(
+    audit: &AuditLog,
+    day: &str,
+    ledger: Option<&SyncMutex<Ledger>>,
+) -> RaiseResult<AuditAnchor> {
+    audit.anchor_day(parse_day(day)?, ledger).await
+}
+
+/// Contrôle d'intégrité d'une journée.
+pub async fn verify_day(audit: &AuditLog, day: &str) -> RaiseResult<AuditVerification> {
+    audit.verify_day(parse_day(day)?).await
+}
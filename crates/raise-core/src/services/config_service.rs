@@ -0,0 +1,59 @@
+// FICHIER : crates/raise-core/src/services/config_service.rs
+
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+
+/// Lit la valeur effective d'une clé de configuration (surcharge runtime prioritaire).
+pub fn config_get(key: &str) -> RaiseResult<JsonValue> {
+    match AppConfig::live_value(key) {
+        Some(value) => Ok(value),
+        None => raise_error!(
+            "ERR_CONFIG_KEY_UNKNOWN",
+            error = format!("Clé de configuration inconnue : '{}'", key),
+            context = json_value!({ "key": key })
+        ),
+    }
+}
+
+/// Modifie une clé à chaud et persiste la surcharge dans la partition système.
+pub async fn config_set(storage: &StorageEngine, key: &str, value: JsonValue) -> RaiseResult<()> {
+    let config = AppConfig::get();
+    let sys_mgr = CollectionsManager::new(
+        storage,
+        &config.mount_points.system.domain,
+        &config.mount_points.system.db,
+    );
+    AppConfig::set_live_value(&sys_mgr, key, value).await
+}
+
+/// Liste les surcharges runtime actives.
+pub fn config_list_overrides() -> OrderedMap<String, JsonValue> {
+    AppConfig::live_overrides()
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_config_set_then_get() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+
+        config_set(&sandbox.storage, "test.service.port", json_value!(4242)).await?;
+        assert_eq!(config_get("test.service.port")?, json_value!(4242));
+        assert!(config_list_overrides().contains_key("test.service.port"));
+
+        let err = config_get("test.service.unknown").unwrap_err();
+        let AppError::Structured(data) = err;
+        assert_eq!(data.code, "ERR_CONFIG_KEY_UNKNOWN");
+        Ok(())
+    }
+}
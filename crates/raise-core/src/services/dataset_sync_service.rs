@@ -0,0 +1,514 @@
+// FICHIER : src-tauri/src/services/dataset_sync_service.rs
+
+//! Synchronisation bidirectionnelle entre l'arborescence `dataset/` (éditée via git) et les
+//! collections JSON-DB. La base reste la référence à l'exécution : en cas de conflit, sa
+//! version est réécrite dans le fichier et la version du fichier est conservée à côté.
+
+use crate::ai::orchestrator::StreamCancellation;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::collections::merge::MergeStrategy;
+use crate::json_db::storage::StorageEngine;
+use crate::json_db::transactions::changes;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// État du dernier passage, rangé à la racine de la base (hors du dépôt git).
+const SYNC_STATE_FILE: &str = "_dataset_sync.json";
+/// Suffixe des copies de fichiers perdants d'un conflit (ignorées par le scan).
+const CONFLICT_SUFFIX: &str = ".conflict.json";
+
+/// Association d'un dossier du dataset (relatif à sa racine) à une collection.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct DatasetMapping {
+    pub folder: String,
+    pub collection: String,
+}
+
+impl DatasetMapping {
+    pub fn new(folder: &str, collection: &str) -> Self {
+        Self {
+            folder: folder.to_string(),
+            collection: collection.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct SyncConflict {
+    pub collection: String,
+    pub id: String,
+    /// Copie de la version du fichier écartée (`None` si le fichier avait été supprimé).
+    pub conflict_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct SyncReport {
+    pub imported: usize,
+    pub exported: usize,
+    pub deleted_documents: usize,
+    pub deleted_files: usize,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+impl SyncReport {
+    pub fn is_noop(&self) -> bool {
+        self.imported + self.exported + self.deleted_documents + self.deleted_files == 0
+            && self.conflicts.is_empty()
+    }
+}
+
+/// Empreintes des deux côtés au dernier passage : un côté a changé si son empreinte diffère.
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+struct SyncEntry {
+    file: String,
+    file_hash: String,
+    db_hash: String,
+}
+
+/// collection -> id -> dernier état synchronisé
+type SyncState = OrderedMap<String, OrderedMap<String, SyncEntry>>;
+
+struct DatasetFile {
+    file: String,
+    doc: JsonValue,
+    hash: String,
+}
+
+#[derive(Clone)]
+pub struct DatasetSync {
+    storage: SharedRef<StorageEngine>,
+    space: String,
+    db: String,
+    dataset_root: PathBuf,
+    mappings: Vec<DatasetMapping>,
+    /// Sérialise les passages (boucle de surveillance et appels manuels).
+    running: SharedRef<AsyncMutex<()>>,
+}
+
+impl DatasetSync {
+    /// Racine par défaut : `<data_root>/dataset`, comme `$PATH_RAISE_DATASET` des transactions.
+    pub fn new(
+        storage: SharedRef<StorageEngine>,
+        space: &str,
+        db: &str,
+        mappings: Vec<DatasetMapping>,
+    ) -> Self {
+        let dataset_root = storage.config.data_root.join("dataset");
+        Self {
+            storage,
+            space: space.to_string(),
+            db: db.to_string(),
+            dataset_root,
+            mappings,
+            running: SharedRef::new(AsyncMutex::new(())),
+        }
+    }
+
+    pub fn with_dataset_root(mut self, dataset_root: PathBuf) -> Self {
+        self.dataset_root = dataset_root;
+        self
+    }
+
+    /// Réconcilie une fois chaque dossier avec sa collection.
+    pub async fn sync_once(&self) -> RaiseResult<SyncReport> {
+        let _guard = self.running.lock().await;
+        let manager = CollectionsManager::new(&self.storage, &self.space, &self.db);
+        let state_path = self
+            .storage
+            .config
+            .db_root(&self.space, &self.db)
+            .join(SYNC_STATE_FILE);
+        let mut state: SyncState = if fs::exists_async(&state_path).await {
+            fs::read_json_async(&state_path).await?
+        } else {
+            SyncState::new()
+        };
+
+        let mut report = SyncReport::default();
+        for mapping in &self.mappings {
+            let entries = state.entry(mapping.collection.clone()).or_default();
+            self.sync_mapping(&manager, mapping, entries, &mut report)
+                .await?;
+        }
+
+        fs::write_json_atomic_async(&state_path, &state).await?;
+        if !report.is_noop() {
+            user_info!(
+                "INF_DATASET_SYNC_DONE",
+                json_value!({
+                    "imported": report.imported,
+                    "exported": report.exported,
+                    "deleted_documents": report.deleted_documents,
+                    "deleted_files": report.deleted_files,
+                    "conflicts": report.conflicts.len()
+                })
+            );
+        }
+        Ok(report)
+    }
+
+    /// Lance la surveillance : un passage à chaque changement des collections suivies,
+    /// et au plus tard toutes les `interval` pour capter les éditions de fichiers.
+    pub fn spawn(self, interval: TimeDuration) -> StreamCancellation {
+        let cancel = StreamCancellation::new();
+        let token = cancel.clone();
+        spawn_async_task(async move {
+            let mut db_changes = futures::stream::select_all(
+                self.mappings
+                    .iter()
+                    .map(|m| changes::watch(&self.space, &self.db, &m.collection)),
+            );
+            loop {
+                if let Err(e) = self.sync_once().await {
+                    user_warn!(
+                        "WRN_DATASET_SYNC_FAILED",
+                        json_value!({ "technical_error": e.to_string() })
+                    );
+                }
+                AgentAttention! {
+                    _ = token.cancelled() => break,
+                    _ = sleep_async(interval) => {},
+                    Some(_) = db_changes.next() => {},
+                }
+            }
+        });
+        cancel
+    }
+
+    async fn sync_mapping(
+        &self,
+        manager: &CollectionsManager<'_>,
+        mapping: &DatasetMapping,
+        entries: &mut OrderedMap<String, SyncEntry>,
+        report: &mut SyncReport,
+    ) -> RaiseResult<()> {
+        let folder = self.dataset_root.join(&mapping.folder);
+        fs::ensure_dir_async(&folder).await?;
+        let (files, unreadable) = scan_folder(&folder).await?;
+
+        let mut docs = OrderedMap::new();
+        for doc in manager.list_all(&mapping.collection).await? {
+            if let Some(id) = doc.get("_id").and_then(|v| v.as_str()).map(str::to_string) {
+                docs.insert(id, doc);
+            }
+        }
+
+        let mut ids: Vec<String> = files
+            .keys()
+            .chain(docs.keys())
+            .chain(entries.keys())
+            .cloned()
+            .collect();
+        ids.sort();
+        ids.dedup();
+
+        for id in ids {
+            let base = entries.get(&id).cloned();
+            // Un fichier en cours d'édition (JSON invalide) n'est ni importé ni supprimé
+            if base.as_ref().is_some_and(|b| unreadable.contains(&b.file)) {
+                continue;
+            }
+            let file = files.get(&id);
+            let doc = docs.get(&id);
+            let db_hash = doc.map(content_hash);
+            let file_changed = file.map(|f| &f.hash) != base.as_ref().map(|b| &b.file_hash);
+            let db_changed = db_hash.as_ref() != base.as_ref().map(|b| &b.db_hash);
+
+            match (file_changed, db_changed) {
+                (false, false) => {}
+                (true, false) => match file {
+                    Some(file) => {
+                        let stored =
+                            import_file(manager, &mapping.collection, &id, file, doc).await?;
+                        entries.insert(id, entry_for(file, &stored));
+                        report.imported += 1;
+                    }
+                    None => {
+                        manager.delete_document(&mapping.collection, &id).await?;
+                        entries.remove(&id);
+                        report.deleted_documents += 1;
+                    }
+                },
+                (false, true) => {
+                    let file_name = tracked_file(&id, file, base.as_ref());
+                    match doc {
+                        Some(doc) => {
+                            let entry = export_doc(&folder, &file_name, doc).await?;
+                            entries.insert(id, entry);
+                            report.exported += 1;
+                        }
+                        None => {
+                            remove_file(&folder.join(&file_name)).await?;
+                            entries.remove(&id);
+                            report.deleted_files += 1;
+                        }
+                    }
+                }
+                (true, true) => match (file, doc) {
+                    (None, None) => {
+                        entries.remove(&id);
+                    }
+                    // Édition déjà présente en base (premier passage, écho d'un export...)
+                    (Some(file), Some(doc)) if contains_fields(doc, &file.doc) => {
+                        entries.insert(id, entry_for(file, doc));
+                    }
+                    _ => {
+                        let file_name = tracked_file(&id, file, base.as_ref());
+                        let conflict_file = match file {
+                            Some(file) => Some(preserve_conflict(&folder, file).await?),
+                            None => None,
+                        };
+                        match doc {
+                            Some(doc) => {
+                                let entry = export_doc(&folder, &file_name, doc).await?;
+                                entries.insert(id.clone(), entry);
+                            }
+                            None => {
+                                remove_file(&folder.join(&file_name)).await?;
+                                entries.remove(&id);
+                            }
+                        }
+                        user_warn!(
+                            "WRN_DATASET_SYNC_CONFLICT",
+                            json_value!({
+                                "collection": mapping.collection,
+                                "id": id,
+                                "conflict_file": conflict_file,
+                                "hint": "La version en base a été conservée ; fusionnez la copie .conflict.json puis supprimez-la."
+                            })
+                        );
+                        report.conflicts.push(SyncConflict {
+                            collection: mapping.collection.clone(),
+                            id,
+                            conflict_file,
+                        });
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Lit les `*.json` du dossier, indexés par `_id` (ou par nom de fichier à défaut).
+/// Retourne aussi les fichiers illisibles, pour ne pas les prendre pour des suppressions.
+async fn scan_folder(
+    folder: &Path,
+) -> RaiseResult<(OrderedMap<String, DatasetFile>, UniqueSet<String>)> {
+    let mut files = OrderedMap::new();
+    let mut unreadable = UniqueSet::new();
+    let mut dir = fs::read_dir_async(folder).await?;
+    while let Some(entry) = dir.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.ends_with(".json") || name.ends_with(CONFLICT_SUFFIX) {
+            continue;
+        }
+        let content = fs::read_to_string_async(&entry.path()).await?;
+        let Ok(doc) = json::deserialize_from_str::<JsonValue>(&content) else {
+            user_warn!(
+                "WRN_DATASET_SYNC_UNREADABLE",
+                json_value!({ "file": entry.path() })
+            );
+            unreadable.insert(name);
+            continue;
+        };
+        let id = match doc.get("_id").and_then(|v| v.as_str()) {
+            Some(id) => id.to_string(),
+            None => name.trim_end_matches(".json").to_string(),
+        };
+        let hash = content_hash(&doc);
+        files.insert(
+            id,
+            DatasetFile {
+                file: name,
+                doc,
+                hash,
+            },
+        );
+    }
+    Ok((files, unreadable))
+}
+
+async fn import_file(
+    manager: &CollectionsManager<'_>,
+    collection: &str,
+    id: &str,
+    file: &DatasetFile,
+    existing: Option<&JsonValue>,
+) -> RaiseResult<JsonValue> {
+    let mut doc = file.doc.clone();
+    if let Some(obj) = doc.as_object_mut() {
+        obj.insert("_id".to_string(), JsonValue::String(id.to_string()));
+    }
+    match existing {
+        Some(_) => {
+            manager
+                .update_document_with(collection, id, doc, Some(MergeStrategy::Replace))
+                .await
+        }
+        None => manager.insert_with_schema(collection, doc).await,
+    }
+}
+
+async fn export_doc(folder: &Path, file_name: &str, doc: &JsonValue) -> RaiseResult<SyncEntry> {
+    let content = json::serialize_to_string_pretty(doc)?;
+    fs::write_atomic_async(&folder.join(file_name), content.as_bytes()).await?;
+    Ok(SyncEntry {
+        file: file_name.to_string(),
+        file_hash: content_hash(doc),
+        db_hash: content_hash(doc),
+    })
+}
+
+/// Copie la version du fichier à côté de l'original avant qu'il soit réécrit.
+async fn preserve_conflict(folder: &Path, file: &DatasetFile) -> RaiseResult<String> {
+    let conflict_name = format!("{}{}", file.file.trim_end_matches(".json"), CONFLICT_SUFFIX);
+    let content = json::serialize_to_string_pretty(&file.doc)?;
+    fs::write_atomic_async(&folder.join(&conflict_name), content.as_bytes()).await?;
+    Ok(conflict_name)
+}
+
+async fn remove_file(path: &Path) -> RaiseResult<()> {
+    if fs::exists_async(path).await {
+        fs::remove_file_async(path).await?;
+    }
+    Ok(())
+}
+
+fn entry_for(file: &DatasetFile, doc: &JsonValue) -> SyncEntry {
+    SyncEntry {
+        file: file.file.clone(),
+        file_hash: file.hash.clone(),
+        db_hash: content_hash(doc),
+    }
+}
+
+/// Fichier suivi pour ce document : l'existant, celui du dernier passage, sinon `<id>.json`.
+fn tracked_file(id: &str, file: Option<&DatasetFile>, base: Option<&SyncEntry>) -> String {
+    match (file, base) {
+        (Some(file), _) => file.file.clone(),
+        (None, Some(base)) => base.file.clone(),
+        (None, None) => format!("{}.json", id),
+    }
+}
+
+/// Vrai si chaque champ du fichier a la même valeur en base (la base ajoute `$schema`, etc.).
+fn contains_fields(doc: &JsonValue, file_doc: &JsonValue) -> bool {
+    match file_doc.as_object() {
+        Some(fields) => fields.iter().all(|(k, v)| doc.get(k) == Some(v)),
+        None => false,
+    }
+}
+
+fn content_hash(doc: &JsonValue) -> String {
+    let mut hasher = CryptoSha256::new();
+    hasher.update(doc.to_string().as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    async fn setup() -> RaiseResult<(DbSandbox, DatasetSync, PathBuf)> {
+        let sandbox = DbSandbox::new().await?;
+        let space = sandbox.config.mount_points.system.domain.clone();
+        let db = sandbox.config.mount_points.system.db.clone();
+        let manager = CollectionsManager::new(&sandbox.storage, &space, &db);
+        manager
+            .create_collection(
+                "actors",
+                "db://_system/_system/schemas/v1/db/generic.schema.json",
+            )
+            .await?;
+
+        let sync = DatasetSync::new(
+            SharedRef::new(sandbox.storage.clone()),
+            &space,
+            &db,
+            vec![DatasetMapping::new("actors", "actors")],
+        );
+        let folder = sandbox
+            .storage
+            .config
+            .data_root
+            .join("dataset")
+            .join("actors");
+        fs::ensure_dir_async(&folder).await?;
+        Ok((sandbox, sync, folder))
+    }
+
+    async fn write_file(path: &Path, doc: JsonValue) -> RaiseResult<()> {
+        fs::write_async(path, json::serialize_to_string_pretty(&doc)?).await
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_sync_mirrors_both_directions() -> RaiseResult<()> {
+        let (sandbox, sync, folder) = setup().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, &sync.space, &sync.db);
+
+        write_file(&folder.join("bob.json"), json_value!({ "role": "worker" })).await?;
+        let report = sync.sync_once().await?;
+        assert_eq!(report.imported, 1);
+        let bob = manager.get_document("actors", "bob").await?.expect("bob");
+        assert_eq!(bob["role"], "worker");
+
+        // Second passage sans modification : rien à faire
+        assert!(sync.sync_once().await?.is_noop());
+
+        manager
+            .update_document("actors", "bob", json_value!({ "role": "boss" }))
+            .await?;
+        let report = sync.sync_once().await?;
+        assert_eq!(report.exported, 1);
+        let on_disk: JsonValue = fs::read_json_async(&folder.join("bob.json")).await?;
+        assert_eq!(on_disk["role"], "boss");
+
+        fs::remove_file_async(&folder.join("bob.json")).await?;
+        let report = sync.sync_once().await?;
+        assert_eq!(report.deleted_documents, 1);
+        assert!(manager.get_document("actors", "bob").await?.is_none());
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_sync_conflict_keeps_db_version() -> RaiseResult<()> {
+        let (sandbox, sync, folder) = setup().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, &sync.space, &sync.db);
+
+        write_file(&folder.join("bob.json"), json_value!({ "role": "worker" })).await?;
+        sync.sync_once().await?;
+
+        manager
+            .update_document("actors", "bob", json_value!({ "role": "boss" }))
+            .await?;
+        write_file(&folder.join("bob.json"), json_value!({ "role": "intern" })).await?;
+
+        let report = sync.sync_once().await?;
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(
+            report.conflicts[0].conflict_file.as_deref(),
+            Some("bob.conflict.json")
+        );
+
+        let on_disk: JsonValue = fs::read_json_async(&folder.join("bob.json")).await?;
+        assert_eq!(on_disk["role"], "boss");
+        let kept: JsonValue = fs::read_json_async(&folder.join("bob.conflict.json")).await?;
+        assert_eq!(kept["role"], "intern");
+        let bob = manager.get_document("actors", "bob").await?.expect("bob");
+        assert_eq!(bob["role"], "boss");
+
+        assert!(sync.sync_once().await?.is_noop());
+        Ok(())
+    }
+}
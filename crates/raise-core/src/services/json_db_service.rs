@@ -2,11 +2,20 @@
 
 use crate::utils::prelude::*;
 
+use crate::ai::orchestrator::StreamCancellation;
+use crate::json_db::backup::{self, RestoreReport, SnapshotManifest};
+use crate::json_db::collections::acl::{CollectionAcl, CollectionGrant};
 use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::collections::vacuum::{self, VacuumOptions, VacuumReport};
 use crate::json_db::query::{sql::SqlRequest, Query, QueryEngine, QueryResult};
+use crate::json_db::schema::introspection::{self, CollectionFields};
+use crate::json_db::schema::references::{self, RefCheckReport};
+use crate::json_db::schema::strictness::{self, StrictnessReport, ValidationMode};
 use crate::json_db::schema::SchemaRegistry;
 use crate::json_db::storage::StorageEngine;
+use crate::json_db::transactions::changes::{self, ChangeEvent};
 use crate::json_db::transactions::manager::TransactionManager;
+use crate::json_db::views::{MaterializedViews, ViewDefinition, ViewRefresh};
 
 // Helper pour instancier le manager rapidement
 fn mgr<'a>(
@@ -333,6 +342,32 @@ pub async fn jsondb_update_document(
     }
 }
 
+/// Applique un JSON Patch (tableau) ou un Merge Patch (objet) et renvoie le document mis à
+/// jour (nouvelle `_rev` comprise).
+pub async fn jsondb_patch_document(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    id: &str,
+    patch: JsonValue,
+) -> RaiseResult<JsonValue> {
+    let manager = mgr(storage, space, db)?;
+
+    match manager.patch_document(collection, id, patch).await {
+        Ok(doc) => Ok(doc),
+        Err(e) => raise_error!(
+            "ERR_DB_PATCH_FAILED",
+            error = e,
+            context = json_value!({
+                "action": "patch_document",
+                "collection": collection,
+                "document_id": id
+            })
+        ),
+    }
+}
+
 pub async fn jsondb_get_document(
     storage: &StorageEngine,
     space: &str,
@@ -383,6 +418,78 @@ pub async fn jsondb_delete_document(
     }
 }
 
+// --- FLUX DE CHANGEMENTS ---
+
+/// Abonnements actifs aux flux de changements, indexés par identifiant (désabonnement).
+#[derive(Clone, Default)]
+pub struct ChangeSubscriptions {
+    subscriptions: SharedRef<SyncMutex<UnorderedMap<String, StreamCancellation>>>,
+}
+
+/// Relaie les changements de `collection` vers `events` jusqu'au désabonnement ou à la
+/// fermeture du récepteur. Un identifiant déjà utilisé remplace l'abonnement précédent.
+pub fn jsondb_subscribe_collection(
+    subscriptions: &ChangeSubscriptions,
+    subscription_id: &str,
+    space: &str,
+    db: &str,
+    collection: &str,
+    events: AsyncChannel::UnboundedSender<ChangeEvent>,
+) -> RaiseResult<()> {
+    // Flux ouvert avant le retour : aucun changement postérieur à l'appel n'est perdu
+    let mut stream = changes::watch(space, db, collection);
+    let cancel = StreamCancellation::new();
+    match subscriptions.subscriptions.lock() {
+        Ok(mut map) => {
+            if let Some(previous) = map.insert(subscription_id.to_string(), cancel.clone()) {
+                previous.cancel();
+            }
+        }
+        Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+    }
+
+    let registry = subscriptions.clone();
+    let id = subscription_id.to_string();
+    spawn_async_task(async move {
+        loop {
+            AgentAttention! {
+                _ = cancel.cancelled() => break,
+                next = stream.next() => {
+                    let Some(event) = next else { break };
+                    if events.send(event).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+        // Un abonnement annulé a déjà été retiré (ou remplacé) par son annulateur
+        if let Ok(mut map) = registry.subscriptions.lock() {
+            if !cancel.is_cancelled() {
+                map.remove(&id);
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Met fin à un abonnement ; `false` s'il est inconnu ou déjà terminé.
+pub fn jsondb_unsubscribe_collection(
+    subscriptions: &ChangeSubscriptions,
+    subscription_id: &str,
+) -> RaiseResult<bool> {
+    let mut map = match subscriptions.subscriptions.lock() {
+        Ok(g) => g,
+        Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+    };
+    match map.remove(subscription_id) {
+        Some(cancel) => {
+            cancel.cancel();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
 pub async fn jsondb_list_all(
     storage: &StorageEngine,
     space: &str,
@@ -407,6 +514,241 @@ pub async fn jsondb_list_all(
     Ok(documents) // <--- C'est cette ligne qui manquait pour satisfaire le compilateur !
 }
 
+/// Métadonnées d'autocomplétion (champs, types, enums, obligatoires, calculés) d'une collection.
+pub async fn jsondb_describe_collection(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+) -> RaiseResult<CollectionFields> {
+    let manager = mgr(storage, space, db)?;
+
+    match introspection::describe_collection(&manager, collection).await {
+        Ok(info) => Ok(info),
+        Err(e) => raise_error!(
+            "ERR_DB_DESCRIBE_COLLECTION_FAILED",
+            error = e,
+            context = json_value!({
+                "collection": collection,
+                "action": "describe_collection",
+                "hint": "Le schéma de la collection n'a pas pu être chargé. Vérifiez son URI dans _meta.json ou l'index système."
+            })
+        ),
+    }
+}
+
+pub async fn jsondb_tighten_collection(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    mode: ValidationMode,
+    dry_run: bool,
+) -> RaiseResult<StrictnessReport> {
+    let manager = mgr(storage, space, db)?;
+
+    match strictness::tighten_collection(&manager, collection, mode, dry_run).await {
+        Ok(report) => Ok(report),
+        Err(e) => raise_error!(
+            "ERR_DB_TIGHTEN_COLLECTION_FAILED",
+            error = e,
+            context = json_value!({
+                "collection": collection,
+                "mode": mode,
+                "action": "tighten_collection"
+            })
+        ),
+    }
+}
+
+pub async fn jsondb_vacuum(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    dry_run: bool,
+) -> RaiseResult<VacuumReport> {
+    let manager = mgr(storage, space, db)?;
+    let options = VacuumOptions {
+        dry_run,
+        ..Default::default()
+    };
+
+    match vacuum::vacuum(&manager, &options).await {
+        Ok(report) => Ok(report),
+        Err(e) => raise_error!(
+            "ERR_DB_VACUUM_FAILED",
+            error = e,
+            context = json_value!({ "space": space, "db": db, "dry_run": dry_run })
+        ),
+    }
+}
+
+pub async fn jsondb_check_refs(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    fix: bool,
+) -> RaiseResult<RefCheckReport> {
+    let manager = mgr(storage, space, db)?;
+
+    match references::check_refs(&manager, fix).await {
+        Ok(report) => Ok(report),
+        Err(e) => raise_error!(
+            "ERR_DB_CHECK_REFS_FAILED",
+            error = e,
+            context = json_value!({ "space": space, "db": db, "fix": fix })
+        ),
+    }
+}
+
+pub async fn jsondb_create_snapshot(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    path: &str,
+) -> RaiseResult<SnapshotManifest> {
+    match backup::create_snapshot(storage, space, db, Path::new(path)).await {
+        Ok(manifest) => Ok(manifest),
+        Err(e) => raise_error!(
+            "ERR_DB_SNAPSHOT_FAILED",
+            error = e,
+            context = json_value!({ "space": space, "db": db, "path": path })
+        ),
+    }
+}
+
+pub async fn jsondb_restore_snapshot(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    path: &str,
+) -> RaiseResult<RestoreReport> {
+    match backup::restore_snapshot(storage, space, db, Path::new(path)).await {
+        Ok(report) => Ok(report),
+        Err(e) => raise_error!(
+            "ERR_DB_RESTORE_FAILED",
+            error = e,
+            context = json_value!({ "space": space, "db": db, "path": path })
+        ),
+    }
+}
+
+// --- FORKS ---
+
+/// Copie complète de la base vers `dst_space/dst_db` (étude « what-if »).
+pub async fn jsondb_clone_db(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    dst_space: &str,
+    dst_db: &str,
+) -> RaiseResult<usize> {
+    let manager = mgr(storage, space, db)?;
+    match manager.clone_db(dst_space, dst_db).await {
+        Ok(files) => Ok(files),
+        Err(e) => raise_error!(
+            "ERR_DB_CLONE_FAILED",
+            error = e,
+            context = json_value!({
+                "source": format!("{}/{}", space, db),
+                "target": format!("{}/{}", dst_space, dst_db)
+            })
+        ),
+    }
+}
+
+/// Copie une collection vers une base existante (`dst_collection` absent : même nom).
+pub async fn jsondb_copy_collection(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    collection: &str,
+    dst_space: &str,
+    dst_db: &str,
+    dst_collection: Option<&str>,
+) -> RaiseResult<usize> {
+    let manager = mgr(storage, space, db)?;
+    manager
+        .copy_collection(collection, dst_space, dst_db, dst_collection)
+        .await
+}
+
+// --- VUES MATÉRIALISÉES ---
+
+pub async fn jsondb_create_view(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    name: &str,
+    sql: &str,
+) -> RaiseResult<ViewRefresh> {
+    let manager = mgr(storage, space, db)?;
+    let def = ViewDefinition::from_sql(name, sql)?;
+
+    match MaterializedViews::new(&manager).create(def).await {
+        Ok(report) => Ok(report),
+        Err(e) => raise_error!(
+            "ERR_DB_VIEW_CREATE_FAILED",
+            error = e,
+            context = json_value!({ "space": space, "db": db, "view": name })
+        ),
+    }
+}
+
+pub async fn jsondb_refresh_view(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    name: &str,
+) -> RaiseResult<ViewRefresh> {
+    let manager = mgr(storage, space, db)?;
+    MaterializedViews::new(&manager).refresh(name).await
+}
+
+pub async fn jsondb_drop_view(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    name: &str,
+) -> RaiseResult<bool> {
+    let manager = mgr(storage, space, db)?;
+    MaterializedViews::new(&manager).drop(name).await?;
+    Ok(true)
+}
+
+pub async fn jsondb_list_views(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+) -> RaiseResult<Vec<ViewDefinition>> {
+    let manager = mgr(storage, space, db)?;
+    MaterializedViews::new(&manager).list().await
+}
+
+// --- CONTRÔLE D'ACCÈS ---
+
+pub async fn jsondb_get_acl(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+) -> RaiseResult<CollectionAcl> {
+    let manager = mgr(storage, space, db)?;
+    Ok(manager.collection_acl().await)
+}
+
+/// Déclare la règle d'un motif de collections (`grant` absent : suppression).
+pub async fn jsondb_set_acl(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    pattern: &str,
+    grant: Option<CollectionGrant>,
+) -> RaiseResult<CollectionAcl> {
+    let manager = mgr(storage, space, db)?;
+    manager.set_collection_acl(pattern, grant).await?;
+    Ok(manager.collection_acl().await)
+}
+
 // --- REQUÊTES (MODIFIÉ POUR INSERT SQL) ---
 
 pub async fn jsondb_execute_query(
@@ -439,8 +781,6 @@ pub async fn jsondb_execute_sql(
     db: &str,
     sql: &str,
 ) -> RaiseResult<QueryResult> {
-    let manager = mgr(storage, space, db)?;
-
     // 1. Parsing SQL avec capture d'erreur de syntaxe
     let request = match crate::json_db::query::sql::parse_sql(sql) {
         Ok(req) => req,
@@ -455,6 +795,42 @@ pub async fn jsondb_execute_sql(
         ),
     };
 
+    execute_sql_request(storage, space, db, request).await
+}
+
+/// Variante paramétrée : `sql` est préparée une seule fois (cache de `QueryEngine::prepare`)
+/// puis liée à `params` (`$1`, `$2`… ou `?`), sans concaténation de chaînes.
+pub async fn jsondb_execute_sql_with_params(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    sql: &str,
+    params: &[JsonValue],
+) -> RaiseResult<QueryResult> {
+    let request = match QueryEngine::prepare(sql).and_then(|prepared| prepared.bind(params)) {
+        Ok(req) => req,
+        Err(e) => raise_error!(
+            "ERR_SQL_PARSE_FAILED",
+            error = e,
+            context = json_value!({
+                "action": "bind_sql_params",
+                "query_preview": sql,
+                "params_count": params.len()
+            })
+        ),
+    };
+
+    execute_sql_request(storage, space, db, request).await
+}
+
+async fn execute_sql_request(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    request: SqlRequest,
+) -> RaiseResult<QueryResult> {
+    let manager = mgr(storage, space, db)?;
+
     match request {
         // CAS LECTURE (SELECT)
         SqlRequest::Read(query) => {
@@ -472,26 +848,41 @@ pub async fn jsondb_execute_sql(
                 ),
             }
         }
-        // CAS ÉCRITURE (INSERT / UPDATE)
+        // CAS PLAN (EXPLAIN SELECT) : une ligne, le plan d'exécution
+        SqlRequest::Explain(query) => {
+            let plan = QueryEngine::new(&manager).explain_query(query).await?;
+            Ok(QueryResult {
+                documents: vec![json::serialize_to_value(&plan)?],
+                total_count: 1,
+                limit: None,
+                offset: None,
+                next_cursor: None,
+                prev_cursor: None,
+            })
+        }
+        // CAS ÉCRITURE (INSERT / UPDATE / DELETE) : total_count = documents affectés
         SqlRequest::Write(requests) => {
             let tx_mgr = TransactionManager::new(storage, space, db);
 
-            if let Err(e) = tx_mgr.execute_smart(requests).await {
-                raise_error!(
+            let affected = match tx_mgr.execute_smart_counted(requests).await {
+                Ok(count) => count,
+                Err(e) => raise_error!(
                     "ERR_SQL_WRITE_TRANSACTION",
                     error = e,
                     context = json_value!({
                         "action": "execute_sql_write",
                         "hint": "L'écriture SQL a échoué. Vérifiez les contraintes de schéma."
                     })
-                );
-            }
+                ),
+            };
 
             Ok(QueryResult {
                 documents: vec![],
-                total_count: 0,
+                total_count: affected as u64,
                 limit: None,
                 offset: None,
+                next_cursor: None,
+                prev_cursor: None,
             })
         }
     }
@@ -0,0 +1,180 @@
+// FICHIER : src-tauri/src/services/materialized_view_service.rs
+
+//! Maintenance des vues matérialisées d'une base : chaque changement publié sur une
+//! collection source est répercuté sur les vues qui la lisent. Les vues créées après le
+//! lancement sont prises en compte (définitions relues à chaque événement).
+
+use crate::ai::orchestrator::StreamCancellation;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::json_db::transactions::changes::{self, ChangeEvent, ChangeKind};
+use crate::json_db::views::{MaterializedViews, ViewRefresh};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+#[derive(Clone)]
+pub struct MaterializedViewSync {
+    storage: SharedRef<StorageEngine>,
+    space: String,
+    db: String,
+    /// Sérialise les rafraîchissements (boucle de surveillance et appels manuels).
+    running: SharedRef<AsyncMutex<()>>,
+}
+
+impl MaterializedViewSync {
+    pub fn new(storage: SharedRef<StorageEngine>, space: &str, db: &str) -> Self {
+        Self {
+            storage,
+            space: space.to_string(),
+            db: db.to_string(),
+            running: SharedRef::new(AsyncMutex::new(())),
+        }
+    }
+
+    /// Recalcule toutes les vues (rattrapage des changements survenus hors surveillance).
+    pub async fn refresh_all(&self) -> RaiseResult<Vec<ViewRefresh>> {
+        let _guard = self.running.lock().await;
+        let manager = CollectionsManager::new(&self.storage, &self.space, &self.db);
+        let views = MaterializedViews::new(&manager);
+        let mut reports = Vec::new();
+        for def in views.list().await? {
+            reports.push(views.refresh(&def.name).await?);
+        }
+        Ok(reports)
+    }
+
+    /// Répercute `event` sur les vues qui lisent sa collection. Un `Resync` sans collection
+    /// (flux de la base en retard) recalcule toutes les vues.
+    pub async fn apply_change(&self, event: &ChangeEvent) -> RaiseResult<Vec<ViewRefresh>> {
+        if event.kind == ChangeKind::Resync && event.collection.is_empty() {
+            return self.refresh_all().await;
+        }
+        let _guard = self.running.lock().await;
+        let manager = CollectionsManager::new(&self.storage, &self.space, &self.db);
+        let views = MaterializedViews::new(&manager);
+        let mut reports = Vec::new();
+        for def in views.views_on(&event.collection).await? {
+            reports.push(views.apply_change(&def, event).await?);
+        }
+        Ok(reports)
+    }
+
+    /// Lance la surveillance : rattrapage initial, puis un rafraîchissement par changement.
+    /// Les écritures d'une vue sont elles-mêmes publiées : une vue peut en lire une autre.
+    pub fn spawn(self) -> StreamCancellation {
+        let cancel = StreamCancellation::new();
+        let token = cancel.clone();
+        // Flux ouvert avant le rattrapage : aucun changement intermédiaire n'est perdu
+        let mut db_changes = changes::watch_db(&self.space, &self.db);
+        spawn_async_task(async move {
+            if let Err(e) = self.refresh_all().await {
+                user_warn!(
+                    "WRN_DB_VIEW_REFRESH_FAILED",
+                    json_value!({ "technical_error": e.to_string() })
+                );
+            }
+            loop {
+                AgentAttention! {
+                    _ = token.cancelled() => break,
+                    next = db_changes.next() => {
+                        let Some(event) = next else { break };
+                        if let Err(e) = self.apply_change(&event).await {
+                            user_warn!(
+                                "WRN_DB_VIEW_REFRESH_FAILED",
+                                json_value!({
+                                    "collection": event.collection,
+                                    "id": event.id,
+                                    "technical_error": e.to_string()
+                                })
+                            );
+                        }
+                    }
+                }
+            }
+        });
+        cancel
+    }
+}
+
+/// Surveillances actives, une par base (`space/db`) : lancées à la première utilisation
+/// d'une vue de la base, puis conservées pour la durée de l'application.
+#[derive(Clone, Default)]
+pub struct ViewWatchers {
+    running: SharedRef<SyncMutex<UnorderedMap<String, StreamCancellation>>>,
+}
+
+impl ViewWatchers {
+    /// Lance `MaterializedViewSync` sur la base si elle n'est pas déjà suivie.
+    /// Retourne `true` si une nouvelle surveillance a été démarrée.
+    pub fn ensure(&self, storage: &StorageEngine, space: &str, db: &str) -> RaiseResult<bool> {
+        let mut running = match self.running.lock() {
+            Ok(g) => g,
+            Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+        };
+        let key = format!("{}/{}", space, db);
+        if running.get(&key).is_some_and(|c| !c.is_cancelled()) {
+            return Ok(false);
+        }
+        let sync = MaterializedViewSync::new(SharedRef::new(storage.clone()), space, db);
+        running.insert(key, sync.spawn());
+        Ok(true)
+    }
+
+    /// Arrête la surveillance d'une base (suppression de la base).
+    pub fn stop(&self, space: &str, db: &str) -> RaiseResult<bool> {
+        let mut running = match self.running.lock() {
+            Ok(g) => g,
+            Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+        };
+        match running.remove(&format!("{}/{}", space, db)) {
+            Some(cancel) => {
+                cancel.cancel();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_db::views::{ViewDefinition, VIEW_SCHEMA_URI};
+    use crate::utils::testing::DbSandbox;
+
+    #[async_test]
+    async fn test_watcher_keeps_view_in_sync() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "view_sync");
+        DbSandbox::mock_db(&manager).await?;
+        manager.create_collection("links", VIEW_SCHEMA_URI).await?;
+        manager
+            .insert_raw("links", &json_value!({ "_id": "l1", "covered": true }))
+            .await?;
+        let def =
+            ViewDefinition::from_sql("covered_links", "SELECT * FROM links WHERE covered = true")?;
+        MaterializedViews::new(&manager).create(def).await?;
+
+        let watchers = ViewWatchers::default();
+        assert!(watchers.ensure(&sandbox.storage, "test", "view_sync")?);
+        assert!(!watchers.ensure(&sandbox.storage, "test", "view_sync")?);
+        manager
+            .insert_raw("links", &json_value!({ "_id": "l2", "covered": true }))
+            .await?;
+
+        let mut synced = false;
+        for _ in 0..50 {
+            if manager.get_document("covered_links", "l2").await?.is_some() {
+                synced = true;
+                break;
+            }
+            sleep_async(TimeDuration::from_millis(50)).await;
+        }
+        assert!(watchers.stop("test", "view_sync")?);
+        assert!(synced, "La vue doit suivre l'insertion dans sa source");
+        Ok(())
+    }
+}
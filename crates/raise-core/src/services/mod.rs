@@ -1,15 +1,23 @@
+pub mod access_service;
 pub mod ai_service;
+pub mod audit_service;
 pub mod blockchain_service;
 pub mod codegen_service;
 pub mod cognitive_service;
+pub mod config_service;
+pub mod dataset_sync_service;
 pub mod dl_service;
 pub mod genetics_service;
 pub mod gnn_service;
 pub mod json_db_service;
+pub mod materialized_view_service;
 pub mod model_service;
+pub mod project_service;
 pub mod rules_service;
+pub mod server_service;
 pub mod traceability_service;
 pub mod training_service;
 pub mod utils_service;
 pub mod voice_service;
+pub mod webhook_service;
 pub mod workflow_service;
@@ -4,9 +4,25 @@ use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::json_db::storage::StorageEngine;
+use crate::model_engine::capella::spawn_capella_watcher;
 use crate::model_engine::loader::ModelLoader;
+use crate::model_engine::metrics::{ModelMetricsHistory, ModelMetricsReport};
+use crate::model_engine::patterns::{ElementPattern, PatternInstance, PatternLibrary};
+use crate::model_engine::refactor::{ModelRefactor, RefactorOp, RefactorPlan};
+use crate::model_engine::transformers::test_cases::{
+    persist_test_cases, TestCaseGenerator, TestCaseSkeleton,
+};
 use crate::model_engine::types::ProjectModel;
 
+/// Période de scrutation par défaut d'un fichier Capella lié.
+const CAPELLA_LINK_POLL_SECS: u64 = 2;
+
+/// Liens "live" Capella actifs, indexés par chemin du fichier surveillé.
+#[derive(Default)]
+pub struct CapellaLinkState {
+    pub links: AsyncMutex<UnorderedMap<String, tokio::task::JoinHandle<()>>>,
+}
+
 /// Charge l'intégralité du modèle en mémoire pour analyse.
 /// Respecte les points de montage pour la résolution sémantique.
 pub async fn load_project_model(
@@ -30,6 +46,168 @@ pub async fn load_project_model(
     }
 }
 
+/// 📊 Mesure le modèle du projet (churn contre la mesure précédente) et l'historise.
+pub async fn compute_model_metrics(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    label: Option<String>,
+) -> RaiseResult<ModelMetricsReport> {
+    let model = load_project_model(storage, space, db).await?;
+    let manager = CollectionsManager::new(storage, space, db);
+    let mut report = ModelMetricsHistory::new(&manager)
+        .snapshot(&model, label)
+        .await?;
+    // Les empreintes ne servent qu'au calcul interne du churn
+    report.fingerprints.clear();
+    Ok(report)
+}
+
+/// 📈 Séries historiques des métriques pour les courbes de tendance.
+pub async fn get_model_metrics_trend(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    limit: Option<usize>,
+) -> RaiseResult<Vec<ModelMetricsReport>> {
+    let manager = CollectionsManager::new(storage, space, db);
+    ModelMetricsHistory::new(&manager).trend(limit).await
+}
+
+/// 🧩 Patrons disponibles (natifs + bibliothèque système).
+pub async fn list_element_patterns(storage: &StorageEngine) -> RaiseResult<Vec<ElementPattern>> {
+    let config = AppConfig::get();
+    let sys_mgr = CollectionsManager::new(
+        storage,
+        &config.mount_points.system.domain,
+        &config.mount_points.system.db,
+    );
+    PatternLibrary::new(&sys_mgr).list().await
+}
+
+pub async fn save_element_pattern(
+    storage: &StorageEngine,
+    pattern: &ElementPattern,
+) -> RaiseResult<()> {
+    let config = AppConfig::get();
+    let sys_mgr = CollectionsManager::new(
+        storage,
+        &config.mount_points.system.domain,
+        &config.mount_points.system.db,
+    );
+    PatternLibrary::new(&sys_mgr).save(pattern).await
+}
+
+/// 🧩 Instancie un patron dans le projet `space/db` en une seule opération.
+pub async fn instantiate_element_pattern(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    handle: &str,
+    params: &JsonObject<String, JsonValue>,
+) -> RaiseResult<PatternInstance> {
+    let config = AppConfig::get();
+    let sys_mgr = CollectionsManager::new(
+        storage,
+        &config.mount_points.system.domain,
+        &config.mount_points.system.db,
+    );
+    let target = CollectionsManager::new(storage, space, db);
+    PatternLibrary::new(&sys_mgr)
+        .instantiate_into(handle, params, &target)
+        .await
+}
+
+/// ✏️ Aperçu d'un lot de refactoring (renommage, déplacement, changement de type) sans écriture.
+pub async fn preview_model_refactor(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    ops: &[RefactorOp],
+) -> RaiseResult<RefactorPlan> {
+    let manager = CollectionsManager::new(storage, space, db);
+    ModelRefactor::new(&manager).preview(ops).await
+}
+
+/// ✏️ Applique un lot de refactoring et ses mises à jour de références en une transaction.
+pub async fn apply_model_refactor(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    ops: &[RefactorOp],
+) -> RaiseResult<RefactorPlan> {
+    let manager = CollectionsManager::new(storage, space, db);
+    ModelRefactor::new(&manager).apply(ops).await
+}
+
+/// 🧪 Génère les squelettes de cas de test des scénarios et chaînes fonctionnelles du projet,
+/// les enregistre dans `test_cases` et les relie à leurs sources (`verifiedBy`).
+pub async fn generate_test_cases(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+) -> RaiseResult<Vec<TestCaseSkeleton>> {
+    let model = load_project_model(storage, space, db).await?;
+    let cases = TestCaseGenerator::new().generate(&model);
+    let manager = CollectionsManager::new(storage, space, db);
+    persist_test_cases(&manager, &cases).await?;
+    Ok(cases)
+}
+
+/// 🔗 Lie un fichier Capella au projet : synchronisation initiale puis suivi des modifications.
+/// Un lien déjà actif sur le même fichier est remplacé.
+pub async fn start_capella_link(
+    state: &CapellaLinkState,
+    storage: &StorageEngine,
+    path: &str,
+    space: &str,
+    db: &str,
+    poll_secs: Option<u64>,
+) -> RaiseResult<()> {
+    if !fs::exists_async(Path::new(path)).await {
+        raise_error!(
+            "ERR_FS_NOT_FOUND",
+            error = "Le fichier Capella à lier est introuvable.",
+            context = json_value!({ "path": path })
+        );
+    }
+    let handle = spawn_capella_watcher(
+        PathBuf::from(path),
+        storage.clone(),
+        space.to_string(),
+        db.to_string(),
+        TimeDuration::from_secs(poll_secs.unwrap_or(CAPELLA_LINK_POLL_SECS).max(1)),
+    );
+    if let Some(previous) = state.links.lock().await.insert(path.to_string(), handle) {
+        previous.abort();
+    }
+    user_info!(
+        "INF_CAPELLA_LINK_STARTED",
+        json_value!({ "path": path, "space": space, "db": db })
+    );
+    Ok(())
+}
+
+/// Coupe le lien d'un fichier Capella. Retourne `false` si aucun lien n'était actif.
+pub async fn stop_capella_link(state: &CapellaLinkState, path: &str) -> bool {
+    match state.links.lock().await.remove(path) {
+        Some(handle) => {
+            handle.abort();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Fichiers Capella actuellement liés.
+pub async fn list_capella_links(state: &CapellaLinkState) -> Vec<String> {
+    let mut links = state.links.lock().await;
+    links.retain(|_, handle| !handle.is_finished());
+    let mut paths: Vec<String> = links.keys().cloned().collect();
+    paths.sort();
+    paths
+}
+
 /// 📥 Ingeste un tableau JSON généré par l'IA dans le Graphe Arcadia.
 /// Utilise le mapping ontologique pour router, en s'appuyant sur le Schéma JSON strict.
 pub async fn ingest_arcadia_elements(
@@ -1,2 +1,498 @@
-//Fichier crates/raise-core/src/services/project_service.rs
+// FICHIER : crates/raise-core/src/services/project_service.rs
 
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+use crate::ai::graph_store::GraphStore;
+use crate::json_db::bundle::{BundleManifest, ImportOptions, ImportReport, ProjectBundle};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::model_engine::types::ProjectModel;
+use crate::services::gnn_service::GnnState;
+use crate::services::model_service;
+
+/// Collection système hébergeant la liste des projets récents.
+const RECENT_PROJECTS_COLLECTION: &str = "configs";
+/// Handle du document de configuration des projets récents.
+const RECENT_PROJECTS_HANDLE: &str = "recent_projects";
+/// Nombre maximal de projets conservés dans l'historique.
+const MAX_RECENT_PROJECTS: usize = 10;
+
+// =========================================================================
+// STRUCTURES DE DONNÉES
+// =========================================================================
+
+/// 📂 Un projet ouvert : son propre couple (space, db) et ses états IA isolés.
+pub struct ProjectSlot {
+    pub space: String,
+    pub db: String,
+    pub model: SharedRef<AsyncMutex<ProjectModel>>,
+    /// Graphe sémantique du projet (optionnel : dépend de la configuration IA)
+    pub graph_store: Option<SharedRef<GraphStore>>,
+    /// Moteur GNN dédié au projet
+    pub gnn: SharedRef<GnnState>,
+    pub opened_at: String,
+}
+
+/// Vue sérialisable d'un projet ouvert (exposée au frontend).
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct ProjectView {
+    pub handle: String,
+    pub space: String,
+    pub db: String,
+    pub element_count: usize,
+    pub has_graph_store: bool,
+    pub is_active: bool,
+    pub opened_at: String,
+}
+
+/// Entrée de l'historique des projets récents (persistée en base système).
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct RecentProject {
+    pub space: String,
+    pub db: String,
+    pub last_opened_at: String,
+}
+
+/// 🗂️ Espace de travail multi-projets (TAURI STATE).
+pub struct ProjectWorkspace {
+    pub projects: AsyncRwLock<UnorderedMap<String, ProjectSlot>>,
+    pub active: AsyncRwLock<Option<String>>,
+}
+
+impl ProjectWorkspace {
+    pub fn new() -> Self {
+        Self {
+            projects: AsyncRwLock::new(UnorderedMap::new()),
+            active: AsyncRwLock::new(None),
+        }
+    }
+}
+
+impl Default for ProjectWorkspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Identifiant unique d'un projet dans l'espace de travail.
+pub fn project_handle(space: &str, db: &str) -> String {
+    format!("{}/{}", space, db)
+}
+
+// =========================================================================
+// CYCLE DE VIE DES PROJETS
+// =========================================================================
+
+/// Ouvre un projet (ou le recharge s'il est déjà ouvert) et le rend actif.
+pub async fn open_project(
+    workspace: &ProjectWorkspace,
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+) -> RaiseResult<ProjectView> {
+    let handle = project_handle(space, db);
+    let model = model_service::load_project_model(storage, space, db).await?;
+
+    // Graphe sémantique par projet : best-effort (la config IA peut être absente)
+    let graph_path = storage.config.db_root(space, db).join("graph_store");
+    let manager = CollectionsManager::new(storage, space, db);
+    let graph_store = match GraphStore::new(graph_path, &manager).await {
+        Ok(store) => Some(SharedRef::new(store)),
+        Err(e) => {
+            user_warn!(
+                "WRN_PROJECT_GRAPH_STORE_UNAVAILABLE",
+                json_value!({ "project": handle, "error": e.to_string() })
+            );
+            None
+        }
+    };
+
+    let slot = ProjectSlot {
+        space: space.to_string(),
+        db: db.to_string(),
+        model: SharedRef::new(AsyncMutex::new(model)),
+        graph_store,
+        gnn: SharedRef::new(GnnState::new()),
+        opened_at: UtcClock::now().to_rfc3339(),
+    };
+
+    workspace
+        .projects
+        .write()
+        .await
+        .insert(handle.clone(), slot);
+    *workspace.active.write().await = Some(handle.clone());
+
+    remember_recent_project(storage, space, db).await?;
+
+    user_success!("MSG_PROJECT_OPENED", json_value!({ "project": handle }));
+    describe_project(workspace, &handle).await
+}
+
+/// Ferme un projet et libère ses états. Si c'était le projet actif,
+/// un autre projet ouvert (s'il en reste) devient actif.
+pub async fn close_project(workspace: &ProjectWorkspace, space: &str, db: &str) -> RaiseResult<()> {
+    let handle = project_handle(space, db);
+
+    let mut projects = workspace.projects.write().await;
+    if projects.remove(&handle).is_none() {
+        raise_error!(
+            "ERR_PROJECT_NOT_OPEN",
+            error = format!("Le projet '{}' n'est pas ouvert.", handle),
+            context = json_value!({ "action": "close_project", "space": space, "db": db })
+        );
+    }
+
+    let mut active = workspace.active.write().await;
+    if active.as_deref() == Some(handle.as_str()) {
+        *active = projects.keys().min().cloned();
+    }
+
+    user_info!("MSG_PROJECT_CLOSED", json_value!({ "project": handle }));
+    Ok(())
+}
+
+/// Bascule le projet actif vers un projet déjà ouvert.
+pub async fn switch_project(
+    workspace: &ProjectWorkspace,
+    space: &str,
+    db: &str,
+) -> RaiseResult<ProjectView> {
+    let handle = project_handle(space, db);
+
+    if !workspace.projects.read().await.contains_key(&handle) {
+        raise_error!(
+            "ERR_PROJECT_NOT_OPEN",
+            error = format!(
+                "Impossible de basculer : le projet '{}' n'est pas ouvert.",
+                handle
+            ),
+            context = json_value!({ "action": "switch_project", "space": space, "db": db })
+        );
+    }
+
+    *workspace.active.write().await = Some(handle.clone());
+    describe_project(workspace, &handle).await
+}
+
+/// Liste les projets ouverts (triés par handle).
+pub async fn list_open_projects(workspace: &ProjectWorkspace) -> RaiseResult<Vec<ProjectView>> {
+    let mut handles: Vec<String> = workspace.projects.read().await.keys().cloned().collect();
+    handles.sort();
+
+    let mut views = Vec::with_capacity(handles.len());
+    for handle in handles {
+        views.push(describe_project(workspace, &handle).await?);
+    }
+    Ok(views)
+}
+
+/// Retourne le modèle du projet actif.
+pub async fn get_active_model(
+    workspace: &ProjectWorkspace,
+) -> RaiseResult<SharedRef<AsyncMutex<ProjectModel>>> {
+    let Some(handle) = workspace.active.read().await.clone() else {
+        raise_error!(
+            "ERR_PROJECT_NONE_ACTIVE",
+            error = "Aucun projet actif dans l'espace de travail."
+        );
+    };
+
+    match workspace.projects.read().await.get(&handle) {
+        Some(slot) => Ok(slot.model.clone()),
+        None => raise_error!(
+            "ERR_PROJECT_NOT_OPEN",
+            error = format!("Le projet actif '{}' n'est plus ouvert.", handle)
+        ),
+    }
+}
+
+/// Retourne l'état GNN dédié au projet actif.
+pub async fn get_active_gnn(workspace: &ProjectWorkspace) -> RaiseResult<SharedRef<GnnState>> {
+    let Some(handle) = workspace.active.read().await.clone() else {
+        raise_error!(
+            "ERR_PROJECT_NONE_ACTIVE",
+            error = "Aucun projet actif dans l'espace de travail."
+        );
+    };
+
+    match workspace.projects.read().await.get(&handle) {
+        Some(slot) => Ok(slot.gnn.clone()),
+        None => raise_error!(
+            "ERR_PROJECT_NOT_OPEN",
+            error = format!("Le projet actif '{}' n'est plus ouvert.", handle)
+        ),
+    }
+}
+
+/// Retourne le graphe sémantique du projet actif, avec son couple (space, db).
+pub async fn get_active_graph_store(
+    workspace: &ProjectWorkspace,
+) -> RaiseResult<(String, String, SharedRef<GraphStore>)> {
+    let Some(handle) = workspace.active.read().await.clone() else {
+        raise_error!(
+            "ERR_PROJECT_NONE_ACTIVE",
+            error = "Aucun projet actif dans l'espace de travail."
+        );
+    };
+
+    let projects = workspace.projects.read().await;
+    let Some(slot) = projects.get(&handle) else {
+        raise_error!(
+            "ERR_PROJECT_NOT_OPEN",
+            error = format!("Le projet actif '{}' n'est plus ouvert.", handle)
+        );
+    };
+    match &slot.graph_store {
+        Some(store) => Ok((slot.space.clone(), slot.db.clone(), store.clone())),
+        None => raise_error!(
+            "ERR_PROJECT_GRAPH_STORE_UNAVAILABLE",
+            error = format!("Le projet '{}' n'a pas de graphe sémantique.", handle),
+            context =
+                json_value!({ "hint": "Vérifiez la configuration du composant ai_graph_store." })
+        ),
+    }
+}
+
+async fn describe_project(workspace: &ProjectWorkspace, handle: &str) -> RaiseResult<ProjectView> {
+    let is_active = workspace.active.read().await.as_deref() == Some(handle);
+    let projects = workspace.projects.read().await;
+
+    let Some(slot) = projects.get(handle) else {
+        raise_error!(
+            "ERR_PROJECT_NOT_OPEN",
+            error = format!("Le projet '{}' n'est pas ouvert.", handle)
+        );
+    };
+
+    let element_count = slot.model.lock().await.meta.element_count;
+
+    Ok(ProjectView {
+        handle: handle.to_string(),
+        space: slot.space.clone(),
+        db: slot.db.clone(),
+        element_count,
+        has_graph_store: slot.graph_store.is_some(),
+        is_active,
+        opened_at: slot.opened_at.clone(),
+    })
+}
+
+// =========================================================================
+// HISTORIQUE DES PROJETS RÉCENTS (PERSISTÉ EN BASE SYSTÈME)
+// =========================================================================
+
+/// Lit la liste des projets récents (du plus récent au plus ancien).
+pub async fn list_recent_projects(storage: &StorageEngine) -> RaiseResult<Vec<RecentProject>> {
+    let config = AppConfig::get();
+    let sys_mgr = CollectionsManager::new(
+        storage,
+        &config.mount_points.system.domain,
+        &config.mount_points.system.db,
+    );
+
+    if !sys_mgr
+        .list_collections()
+        .await?
+        .iter()
+        .any(|c| c == RECENT_PROJECTS_COLLECTION)
+    {
+        return Ok(Vec::new());
+    }
+
+    let Some(doc) = sys_mgr
+        .get_document(RECENT_PROJECTS_COLLECTION, RECENT_PROJECTS_HANDLE)
+        .await?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let entries = doc.get("projects").cloned().unwrap_or(json_value!([]));
+    match json::deserialize_from_value::<Vec<RecentProject>>(entries) {
+        Ok(list) => Ok(list),
+        Err(e) => raise_error!(
+            "ERR_PROJECT_RECENT_CORRUPTED",
+            error = e.to_string(),
+            context = json_value!({ "document": RECENT_PROJECTS_HANDLE })
+        ),
+    }
+}
+
+async fn remember_recent_project(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+) -> RaiseResult<()> {
+    let config = AppConfig::get();
+    let sys_domain = &config.mount_points.system.domain;
+    let sys_db = &config.mount_points.system.db;
+    let sys_mgr = CollectionsManager::new(storage, sys_domain, sys_db);
+
+    // ⚠️ create_collection réinitialise les métadonnées : on vérifie d'abord l'existence
+    if !sys_mgr
+        .list_collections()
+        .await?
+        .iter()
+        .any(|c| c == RECENT_PROJECTS_COLLECTION)
+    {
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            sys_domain, sys_db
+        );
+        sys_mgr
+            .create_collection(RECENT_PROJECTS_COLLECTION, &schema_uri)
+            .await?;
+    }
+
+    let mut recents = list_recent_projects(storage).await?;
+    recents.retain(|p| !(p.space == space && p.db == db));
+    recents.insert(
+        0,
+        RecentProject {
+            space: space.to_string(),
+            db: db.to_string(),
+            last_opened_at: UtcClock::now().to_rfc3339(),
+        },
+    );
+    recents.truncate(MAX_RECENT_PROJECTS);
+
+    sys_mgr
+        .upsert_document(
+            RECENT_PROJECTS_COLLECTION,
+            json_value!({
+                "handle": RECENT_PROJECTS_HANDLE,
+                "projects": recents
+            }),
+        )
+        .await?;
+    Ok(())
+}
+
+// =========================================================================
+// PAQUETS DE PROJET (.raisepkg)
+// =========================================================================
+
+/// 📦 Exporte la base d'un projet dans un paquet `.raisepkg`.
+pub async fn export_project_bundle(
+    storage: &StorageEngine,
+    space: &str,
+    db: &str,
+    path: &Path,
+) -> RaiseResult<BundleManifest> {
+    let manager = CollectionsManager::new(storage, space, db);
+    let bundle = ProjectBundle::collect(&manager).await?;
+    bundle.save(path).await?;
+    user_success!(
+        "INF_BUNDLE_EXPORTED",
+        json_value!({ "project": project_handle(space, db), "path": path.to_string_lossy() })
+    );
+    Ok(bundle.manifest)
+}
+
+/// 📥 Importe un paquet `.raisepkg` après vérification de son intégrité.
+pub async fn import_project_bundle(
+    storage: &StorageEngine,
+    path: &Path,
+    options: &ImportOptions,
+) -> RaiseResult<ImportReport> {
+    ProjectBundle::load(path)
+        .await?
+        .import(storage, options)
+        .await
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    async fn setup_project(sandbox: &DbSandbox, space: &str, db: &str) -> RaiseResult<()> {
+        let manager = CollectionsManager::new(&sandbox.storage, space, db);
+        DbSandbox::mock_db(&manager).await?;
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_open_switch_close_projects() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        setup_project(&sandbox, "space_a", "db_a").await?;
+        setup_project(&sandbox, "space_b", "db_b").await?;
+
+        let workspace = ProjectWorkspace::new();
+
+        let view_a = open_project(&workspace, &sandbox.storage, "space_a", "db_a").await?;
+        assert!(view_a.is_active);
+        assert_eq!(view_a.handle, "space_a/db_a");
+
+        let view_b = open_project(&workspace, &sandbox.storage, "space_b", "db_b").await?;
+        assert!(view_b.is_active);
+
+        let open = list_open_projects(&workspace).await?;
+        assert_eq!(open.len(), 2);
+        assert!(!open[0].is_active, "space_a ne doit plus être actif");
+
+        let switched = switch_project(&workspace, "space_a", "db_a").await?;
+        assert!(switched.is_active);
+
+        // Isolation des états par projet
+        {
+            let projects = workspace.projects.read().await;
+            let a = projects.get("space_a/db_a").unwrap();
+            let b = projects.get("space_b/db_b").unwrap();
+            assert!(!SharedRef::ptr_eq(&a.model, &b.model));
+            assert!(!SharedRef::ptr_eq(&a.gnn, &b.gnn));
+        }
+
+        close_project(&workspace, "space_a", "db_a").await?;
+        assert_eq!(
+            workspace.active.read().await.as_deref(),
+            Some("space_b/db_b"),
+            "Le projet restant doit devenir actif"
+        );
+
+        close_project(&workspace, "space_b", "db_b").await?;
+        assert!(workspace.active.read().await.is_none());
+        assert!(get_active_model(&workspace).await.is_err());
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_switch_or_close_unknown_project_fails() -> RaiseResult<()> {
+        let workspace = ProjectWorkspace::new();
+
+        let err = switch_project(&workspace, "ghost", "db").await.unwrap_err();
+        let AppError::Structured(data) = err;
+        assert_eq!(data.code, "ERR_PROJECT_NOT_OPEN");
+
+        let err = close_project(&workspace, "ghost", "db").await.unwrap_err();
+        let AppError::Structured(data) = err;
+        assert_eq!(data.code, "ERR_PROJECT_NOT_OPEN");
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_recent_projects_are_persisted_and_deduplicated() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        setup_project(&sandbox, "space_a", "db_a").await?;
+        setup_project(&sandbox, "space_b", "db_b").await?;
+
+        let workspace = ProjectWorkspace::new();
+        open_project(&workspace, &sandbox.storage, "space_a", "db_a").await?;
+        open_project(&workspace, &sandbox.storage, "space_b", "db_b").await?;
+        open_project(&workspace, &sandbox.storage, "space_a", "db_a").await?;
+
+        // Relecture depuis la base (nouvel espace de travail = redémarrage)
+        let recents = list_recent_projects(&sandbox.storage).await?;
+        assert_eq!(recents.len(), 2);
+        assert_eq!(recents[0].space, "space_a");
+        assert_eq!(recents[1].space, "space_b");
+        Ok(())
+    }
+}
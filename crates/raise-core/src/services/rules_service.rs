@@ -3,9 +3,12 @@
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 use crate::json_db::storage::StorageEngine;
+use crate::kernel::events::{publish_event, RaiseEvent};
 use crate::model_engine::loader::ModelLoader;
 use crate::model_engine::types::ProjectModel;
-use crate::model_engine::validators::{DynamicValidator, ModelValidator, ValidationIssue};
+use crate::model_engine::validators::{
+    DynamicValidator, InterfaceCompatibilityValidator, ModelValidator, ValidationIssue,
+};
 use crate::rules_engine::ast::Rule;
 use crate::rules_engine::evaluator::{Evaluator, NoOpDataProvider};
 
@@ -81,8 +84,20 @@ pub async fn validate_model(
 
     // 4. Instanciation et exécution du validateur
     let validator = DynamicValidator::new(rules);
-    let issues = validator.validate_full(&loader).await?;
-
+    let mut issues = validator.validate_full(&loader).await?;
+
+    // 5. Contrats inter-couches : interfaces LA tenues par les liens PA qui les réalisent
+    issues.extend(
+        InterfaceCompatibilityValidator::new()
+            .validate_full(&loader)
+            .await?,
+    );
+
+    publish_event(RaiseEvent::ValidationCompleted {
+        space,
+        db,
+        issue_count: issues.len(),
+    });
     Ok(issues)
 }
 
@@ -0,0 +1,446 @@
+// FICHIER : crates/raise-core/src/services/server_service.rs
+//! Mode serveur : expose les commandes du backend (json_db, IA, workflows, blockchain) sur
+//! une API HTTP authentifiée, pour la CI et les interfaces distantes, sans webview.
+
+use crate::blockchain::consensus::reputation::ReputationStore;
+use crate::blockchain::explorer::{ChainExplorer, ExplorerQuery};
+use crate::json_db::collections::acl::with_optional_access;
+use crate::json_db::query::Query;
+use crate::json_db::storage::StorageEngine;
+use crate::services::access_service;
+use crate::services::ai_service::{self, AiState};
+use crate::services::json_db_service;
+use crate::services::webhook_service::bearer_matches;
+use crate::services::workflow_service::{self, WorkflowStore};
+use crate::utils::context::audit::{is_state_changing, AuditChannel, AuditLog};
+use crate::utils::context::tokens::API_TOKEN_PREFIX;
+use crate::utils::context::{AccessGuard, ApiTokenIssuer};
+use crate::utils::data::secrets::SecretsProvider;
+use crate::utils::network::http_types::{HttpHeaderMap, HttpPathParam, HttpSharedState};
+use crate::utils::network::server::{get, post, start_network_api_async};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+/// Commandes exposées, sous le même nom que leurs équivalents Tauri (mêmes politiques d'accès).
+pub const SERVER_COMMANDS: &[&str] = &[
+    "jsondb_list_collections",
+    "jsondb_create_collection",
+    "jsondb_insert_document",
+    "jsondb_get_document",
+    "jsondb_update_document",
+    "jsondb_delete_document",
+    "jsondb_list_all",
+    "jsondb_execute_query",
+    "jsondb_execute_sql",
+    "ai_chat",
+    "ai_write_conflicts",
+    "start_workflow",
+    "resume_workflow",
+    "get_workflow_state",
+    "get_workflow_scheduling",
+    "mentis_explore",
+    "mentis_reputation_report",
+];
+
+/// Ressources du backend partagées par les requêtes.
+#[derive(Clone)]
+pub struct ServerState {
+    pub storage: SharedRef<StorageEngine>,
+    pub ai: SharedRef<AiState>,
+    pub workflows: SharedRef<AsyncMutex<WorkflowStore>>,
+    pub audit: AuditLog,
+}
+
+/// Routeur de l'API : `GET /api/v1/health`, `GET /api/v1/commands`,
+/// `POST /api/v1/commands/{command}` (arguments nommés dans le corps JSON).
+pub fn server_router(state: ServerState) -> HttpRouter {
+    HttpRouter::new()
+        .route("/api/v1/health", get(health))
+        .route("/api/v1/commands", get(list_commands))
+        .route("/api/v1/commands/{command}", post(invoke_command))
+        .with_state(state)
+}
+
+/// Lance l'API sur `server.host:server.port` (bloquant jusqu'à l'arrêt du serveur).
+pub async fn serve(state: ServerState) -> RaiseResult<()> {
+    let config = AppConfig::get().server.clone();
+    user_info!(
+        "INF_SERVER_LISTENING",
+        json_value!({ "host": config.host, "port": config.port })
+    );
+    start_network_api_async(&config.host, config.port, server_router(state)).await
+}
+
+async fn health() -> HttpJsonPayload<JsonValue> {
+    HttpJsonPayload(json_value!({ "status": "ok", "version": env!("CARGO_PKG_VERSION") }))
+}
+
+async fn list_commands() -> HttpJsonPayload<JsonValue> {
+    HttpJsonPayload(json_value!({ "commands": SERVER_COMMANDS }))
+}
+
+async fn invoke_command(
+    HttpSharedState(state): HttpSharedState<ServerState>,
+    HttpPathParam(command): HttpPathParam<String>,
+    headers: HttpHeaderMap,
+    HttpJsonPayload(args): HttpJsonPayload<JsonValue>,
+) -> (HttpStatusCode, HttpJsonPayload<JsonValue>) {
+    let authorization = headers.get("authorization").and_then(|v| v.to_str().ok());
+
+    match handle_command(&state, &command, authorization, args).await {
+        Ok(result) => (
+            HttpStatusCode::OK,
+            HttpJsonPayload(json_value!({ "result": result })),
+        ),
+        Err(AppError::Structured(data)) => {
+            let status = match data.code.as_str() {
+                "ERR_SERVER_UNAUTHORIZED" | "ERR_ACCESS_UNAUTHENTICATED" => {
+                    HttpStatusCode::UNAUTHORIZED
+                }
+                "ERR_ACCESS_DENIED" => HttpStatusCode::FORBIDDEN,
+                "ERR_SERVER_UNKNOWN_COMMAND" => HttpStatusCode::NOT_FOUND,
+                "ERR_SERVER_INVALID_ARGS" => HttpStatusCode::UNPROCESSABLE_ENTITY,
+                "ERR_WF_SCHEDULER_NOT_READY" | "ERR_AI_SYSTEM_NOT_READY" => {
+                    HttpStatusCode::SERVICE_UNAVAILABLE
+                }
+                _ => HttpStatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, HttpJsonPayload(json_value!({ "error": data.code })))
+        }
+    }
+}
+
+/// Authentifie l'appel, applique le contrôle d'accès, exécute la commande et la trace.
+pub async fn handle_command(
+    state: &ServerState,
+    command: &str,
+    authorization: Option<&str>,
+    args: JsonValue,
+) -> RaiseResult<JsonValue> {
+    let guard = authenticate(authorization).await?;
+    let actor = guard
+        .identity()
+        .map(|i| i.user)
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    if let Err(e) = guard.authorize(&AppConfig::get().access_control, command) {
+        let AppError::Structured(data) = &e;
+        state
+            .audit
+            .record(&actor, AuditChannel::Api, command, &args, Some(&data.code))
+            .await?;
+        return Err(e);
+    }
+
+    let audited = is_state_changing(command).then(|| args.clone());
+    let identity = access_service::collection_identity(&guard);
+    let result = with_optional_access(identity, dispatch(state, command, args)).await;
+    if let Some(params) = audited {
+        let error_code = match &result {
+            Err(AppError::Structured(data)) => Some(data.code.clone()),
+            Ok(_) => None,
+        };
+        state
+            .audit
+            .record(
+                &actor,
+                AuditChannel::Api,
+                command,
+                &params,
+                error_code.as_deref(),
+            )
+            .await?;
+    }
+    result
+}
+
+/// Jeton d'API signé par le poste (`raise_…`), jeton de service (secret
+/// `server.token_secret`, identité `server.service_user`), sinon jeton OIDC.
+pub async fn authenticate(authorization: Option<&str>) -> RaiseResult<AccessGuard> {
+    let config = AppConfig::get();
+    let Some(token) = authorization.and_then(|h| h.strip_prefix("Bearer ")) else {
+        raise_error!("ERR_SERVER_UNAUTHORIZED");
+    };
+
+    let guard = AccessGuard::new();
+    if token.starts_with(API_TOKEN_PREFIX) {
+        let issuer = ApiTokenIssuer::from_secrets(SecretsProvider::global()).await?;
+        if guard.login_api_token(&issuer, token).is_ok() {
+            return Ok(guard);
+        }
+    }
+    if let Some(expected) = SecretsProvider::global()
+        .get(&config.server.token_secret)
+        .await?
+    {
+        if bearer_matches(authorization, &expected) {
+            guard.set_local_user(&config.access_control, &config.server.service_user);
+            return Ok(guard);
+        }
+    }
+    if config.access_control.oidc.is_some()
+        && guard.login_oidc(&config.access_control, token).is_ok()
+    {
+        return Ok(guard);
+    }
+
+    user_warn!("WRN_SERVER_REJECTED");
+    raise_error!("ERR_SERVER_UNAUTHORIZED");
+}
+
+/// Table de routage des commandes vers les services (arguments en `snake_case`).
+pub async fn dispatch(
+    state: &ServerState,
+    command: &str,
+    args: JsonValue,
+) -> RaiseResult<JsonValue> {
+    let storage = state.storage.as_ref();
+    match command {
+        "jsondb_list_collections" => {
+            let res = json_db_service::jsondb_list_collections(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "jsondb_create_collection" => {
+            let res = json_db_service::jsondb_create_collection(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+                &arg::<String>(&args, "collection")?,
+                &arg::<String>(&args, "schema_uri")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "jsondb_insert_document" => {
+            json_db_service::jsondb_insert_document(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+                &arg::<String>(&args, "collection")?,
+                arg(&args, "document")?,
+            )
+            .await
+        }
+        "jsondb_get_document" => {
+            let res = json_db_service::jsondb_get_document(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+                &arg::<String>(&args, "collection")?,
+                &arg::<String>(&args, "id")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "jsondb_update_document" => {
+            json_db_service::jsondb_update_document(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+                &arg::<String>(&args, "collection")?,
+                &arg::<String>(&args, "id")?,
+                arg(&args, "document")?,
+            )
+            .await
+        }
+        "jsondb_delete_document" => {
+            let res = json_db_service::jsondb_delete_document(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+                &arg::<String>(&args, "collection")?,
+                &arg::<String>(&args, "id")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "jsondb_list_all" => {
+            let res = json_db_service::jsondb_list_all(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+                &arg::<String>(&args, "collection")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "jsondb_execute_query" => {
+            let res = json_db_service::jsondb_execute_query(
+                storage,
+                &arg::<String>(&args, "space")?,
+                &arg::<String>(&args, "db")?,
+                arg::<Query>(&args, "query")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "jsondb_execute_sql" => {
+            let space = arg::<String>(&args, "space")?;
+            let db = arg::<String>(&args, "db")?;
+            let sql = arg::<String>(&args, "sql")?;
+            let res = match opt_arg::<Vec<JsonValue>>(&args, "params")? {
+                Some(params) => {
+                    json_db_service::jsondb_execute_sql_with_params(
+                        storage, &space, &db, &sql, &params,
+                    )
+                    .await?
+                }
+                None => json_db_service::jsondb_execute_sql(storage, &space, &db, &sql).await?,
+            };
+            json::serialize_to_value(&res)
+        }
+        "ai_chat" => {
+            let res = ai_service::ai_chat(&state.ai, &arg::<String>(&args, "user_input")?).await?;
+            json::serialize_to_value(&res)
+        }
+        "ai_write_conflicts" => json::serialize_to_value(&ai_service::ai_write_conflicts()),
+        "start_workflow" => {
+            let res = workflow_service::start_workflow(
+                storage,
+                &state.workflows,
+                arg(&args, "mission_id")?,
+                arg(&args, "workflow_handle")?,
+                opt_arg(&args, "inputs")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "resume_workflow" => {
+            let res = workflow_service::resume_workflow(
+                storage,
+                &state.workflows,
+                &arg::<String>(&args, "instance_handle")?,
+                &arg::<String>(&args, "node_id")?,
+                arg(&args, "approved")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "get_workflow_state" => {
+            let res = workflow_service::get_workflow_state(
+                &state.workflows,
+                &arg::<String>(&args, "instance_handle")?,
+            )
+            .await?;
+            json::serialize_to_value(&res)
+        }
+        "get_workflow_scheduling" => {
+            let res = workflow_service::get_scheduling_snapshot(&state.workflows).await?;
+            json::serialize_to_value(&res)
+        }
+        "mentis_explore" => {
+            let query: ExplorerQuery = opt_arg(&args, "query")?.unwrap_or_default();
+            let res = ChainExplorer::system(storage).explore(&query).await?;
+            json::serialize_to_value(&res)
+        }
+        "mentis_reputation_report" => {
+            let ledger = ReputationStore::system(storage)
+                .load(AppConfig::get().reputation.clone())
+                .await?;
+            json::serialize_to_value(&ledger.report())
+        }
+        _ => raise_error!(
+            "ERR_SERVER_UNKNOWN_COMMAND",
+            context = json_value!({ "command": command })
+        ),
+    }
+}
+
+/// Argument obligatoire du corps de la requête.
+fn arg<T: DeserializableOwned>(args: &JsonValue, name: &str) -> RaiseResult<T> {
+    match opt_arg(args, name)? {
+        Some(value) => Ok(value),
+        None => raise_error!(
+            "ERR_SERVER_INVALID_ARGS",
+            error = format!("Argument manquant : {}", name),
+            context = json_value!({ "argument": name })
+        ),
+    }
+}
+
+/// Argument facultatif (absent ou `null`).
+fn opt_arg<T: DeserializableOwned>(args: &JsonValue, name: &str) -> RaiseResult<Option<T>> {
+    match args.get(name) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(value) => match json::deserialize_from_value(value.clone()) {
+            Ok(v) => Ok(Some(v)),
+            Err(e) => raise_error!(
+                "ERR_SERVER_INVALID_ARGS",
+                error = e,
+                context = json_value!({ "argument": name })
+            ),
+        },
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_arguments_are_typed() {
+        let args = json_value!({ "space": "un2", "approved": "yes", "inputs": null });
+        assert_eq!(arg::<String>(&args, "space").unwrap(), "un2");
+        assert!(arg::<bool>(&args, "approved").is_err());
+        assert!(arg::<String>(&args, "db").is_err());
+        assert!(opt_arg::<JsonValue>(&args, "inputs").unwrap().is_none());
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_router_requires_authentication() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let storage = SharedRef::new(sandbox.storage.clone());
+        let state = ServerState {
+            storage: storage.clone(),
+            ai: SharedRef::new(AiState::new(None)),
+            workflows: SharedRef::new(AsyncMutex::new(WorkflowStore::default())),
+            audit: AuditLog::new(storage),
+        };
+
+        let listener = match HttpTcpListener::bind("127.0.0.1:0").await {
+            Ok(l) => l,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        let addr = match listener.local_addr() {
+            Ok(a) => a,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        let router = server_router(state);
+        spawn_async_task(async move {
+            let _ = run_http_server(listener, router).await;
+        });
+
+        let health = match get_client()
+            .get(format!("http://{}/api/v1/health", addr))
+            .send()
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => raise_error!("ERR_TEST_HTTP", error = e),
+        };
+        assert_eq!(health.status(), HttpStatusCode::OK);
+
+        let response = match get_client()
+            .post(format!("http://{}/api/v1/commands/jsondb_list_all", addr))
+            .header("authorization", "Bearer not-the-token")
+            .json(&json_value!({ "space": "un2", "db": "_system", "collection": "actors" }))
+            .send()
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => raise_error!("ERR_TEST_HTTP", error = e),
+        };
+        assert_eq!(response.status(), HttpStatusCode::UNAUTHORIZED);
+        Ok(())
+    }
+}
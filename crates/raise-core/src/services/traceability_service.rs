@@ -1,10 +1,12 @@
 // FICHIER : src-tauri/src/services/traceability_service.rs
 
 use crate::model_engine::types::ProjectModel;
+use crate::spatial_engine::GraphNeighborhood;
 use crate::utils::prelude::*;
 
 use crate::traceability::{
     impact_analyzer::{ImpactAnalyzer, ImpactReport},
+    neighborhood::{self, NeighborhoodQuery},
     reporting::{
         audit_report::{AuditGenerator, AuditReport},
         trace_matrix::{MatrixGenerator, TraceabilityMatrix},
@@ -61,37 +63,12 @@ pub async fn get_traceability_matrix(model: &ProjectModel) -> RaiseResult<Tracea
     Ok(matrix)
 }
 
+/// Voisinage typé d'un élément (profondeur, filtres de relation et sens de parcours).
 pub async fn get_element_neighbors(
     model: &ProjectModel,
     element_id: &str,
-) -> RaiseResult<JsonValue> {
-    let docs = get_model_docs(model);
-
-    // Utilisation du nouveau Tracer
+    query: &NeighborhoodQuery,
+) -> RaiseResult<GraphNeighborhood> {
     let tracer = Tracer::from_legacy_model(model)?;
-
-    // Récupération des IDs
-    let upstream_ids = tracer.get_upstream_ids(element_id);
-    let downstream_ids = tracer.get_downstream_ids(element_id);
-
-    // Résolution des objets complets via l'index
-    let mut upstream = Vec::new();
-    for id in upstream_ids {
-        if let Some(val) = docs.get(&id) {
-            upstream.push(val.clone());
-        }
-    }
-
-    let mut downstream = Vec::new();
-    for id in downstream_ids {
-        if let Some(val) = docs.get(&id) {
-            downstream.push(val.clone());
-        }
-    }
-
-    Ok(json_value!({
-        "center_id": element_id,
-        "upstream": upstream,
-        "downstream": downstream
-    }))
+    neighborhood::explore(&tracer, model, element_id, query)
 }
@@ -1,5 +1,6 @@
 // FICHIER : src-tauri/src/services/utils_service.rs
 
+use crate::utils::network::EndpointHealth;
 use crate::utils::{context, prelude::*};
 
 /// Structure de réponse renvoyée au Frontend
@@ -34,6 +35,19 @@ pub async fn get_app_info() -> RaiseResult<SystemInfoResponse> {
     Ok(response)
 }
 
+/// Santé des endpoints HTTP sortants (LLM, Qdrant, Fabric...) vue par le disjoncteur
+pub async fn get_network_health() -> RaiseResult<Vec<EndpointHealth>> {
+    tracing::debug!("📥 Commande reçue : get_network_health");
+    Ok(CircuitBreaker::global().health())
+}
+
+/// Referme manuellement le circuit d'un endpoint (ou de tous)
+pub async fn reset_network_circuit(url: Option<&str>) -> RaiseResult<()> {
+    tracing::info!("📥 Commande reçue : reset_network_circuit ({:?})", url);
+    CircuitBreaker::global().reset(url);
+    Ok(())
+}
+
 // ============================================================================
 // COMMANDES DE GESTION DE SESSION (FRONTEND)
 // ============================================================================
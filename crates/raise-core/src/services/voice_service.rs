@@ -1,5 +1,6 @@
 // FICHIER : crates/raise-core/src/services/voice_service.rs
 
+use crate::ai::voice::ingest::{decode_payload, transcribe_clip, AudioPayload, Transcription};
 use crate::ai::voice::stt::WhisperEngine;
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::json_db::storage::{JsonDbConfig, StorageEngine};
@@ -113,6 +114,41 @@ pub async fn transcribe_audio(
     }
 }
 
+/// Transcrit un enregistrement complet (WAV ou PCM envoyé par l'interface).
+/// Le moteur Whisper est partagé avec l'assistant micro et chargé au premier appel.
+pub async fn transcribe_recording(
+    voice_state: &VoiceState,
+    storage: &StorageEngine,
+    payload: &AudioPayload,
+) -> RaiseResult<Transcription> {
+    // Validation avant le chargement (coûteux) du modèle
+    let clip = decode_payload(payload)?;
+
+    let mut engine_guard = voice_state.engine.lock().await;
+    if engine_guard.is_none() {
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        match WhisperEngine::new(&manager).await {
+            Ok(engine) => *engine_guard = Some(engine),
+            Err(e) => raise_error!("ERR_VOICE_ENGINE_INIT", error = e.to_string()),
+        }
+    }
+    let Some(engine) = engine_guard.as_mut() else {
+        raise_error!("ERR_VOICE_ENGINE_NOT_READY", error = "Moteur STT inactif");
+    };
+
+    let transcription = transcribe_clip(engine, &clip)?;
+    user_info!(
+        "INF_VOICE_TRANSCRIBED",
+        json_value!({ "duration_seconds": transcription.duration_seconds, "chars": transcription.text.len() })
+    );
+    Ok(transcription)
+}
+
 // =========================================================================
 // TESTS UNITAIRES (Respect des tests existants & Résilience Mount Points)
 // =========================================================================
@@ -188,4 +224,19 @@ mod tests {
         assert!(guard_res.is_ok());
         Ok(())
     }
+
+    #[async_test]
+    async fn test_transcribe_recording_rejects_invalid_audio() -> RaiseResult<()> {
+        let sandbox = crate::utils::testing::DbSandbox::new().await?;
+        let state = VoiceState::new();
+        let payload = AudioPayload::Wav {
+            data_base64: encode_base64(b"pas un wav"),
+        };
+        assert!(transcribe_recording(&state, &sandbox.storage, &payload)
+            .await
+            .is_err());
+        // Le modèle n'est pas chargé pour une entrée invalide
+        assert!(state.engine.lock().await.is_none());
+        Ok(())
+    }
 }
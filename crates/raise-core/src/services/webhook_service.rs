@@ -0,0 +1,213 @@
+// FICHIER : src-tauri/src/services/webhook_service.rs
+
+use crate::json_db::storage::StorageEngine;
+use crate::services::workflow_service::{self, WorkflowStore, WorkflowView};
+use crate::utils::data::secrets::SecretsProvider;
+use crate::utils::network::http_types::{HttpHeaderMap, HttpPathParam, HttpSharedState};
+use crate::utils::network::server::{post, start_network_api_async};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+use crate::workflow_engine::WebhookTrigger;
+
+const DEFAULT_WEBHOOK_MISSION: &str = "webhook";
+
+/// Ressources partagées par les requêtes entrantes.
+#[derive(Clone)]
+pub struct WebhookState {
+    pub storage: SharedRef<StorageEngine>,
+    pub workflows: SharedRef<AsyncMutex<WorkflowStore>>,
+}
+
+/// Routeur `POST /webhooks/{workflow_handle}` : démarre le workflow avec le corps reçu.
+pub fn webhook_router(state: WebhookState) -> HttpRouter {
+    HttpRouter::new()
+        .route("/webhooks/{workflow_handle}", post(receive_webhook))
+        .with_state(state)
+}
+
+/// Lance le serveur de webhooks si `webhooks.enabled` ; retourne immédiatement sinon.
+pub async fn serve_webhooks(state: WebhookState) -> RaiseResult<()> {
+    let config = AppConfig::get().webhooks.clone();
+    if !config.enabled {
+        return Ok(());
+    }
+    start_network_api_async(&config.host, config.port, webhook_router(state)).await
+}
+
+async fn receive_webhook(
+    HttpSharedState(state): HttpSharedState<WebhookState>,
+    HttpPathParam(workflow_handle): HttpPathParam<String>,
+    headers: HttpHeaderMap,
+    HttpJsonPayload(payload): HttpJsonPayload<JsonValue>,
+) -> (HttpStatusCode, HttpJsonPayload<JsonValue>) {
+    let authorization = headers.get("authorization").and_then(|v| v.to_str().ok());
+
+    match trigger_workflow(&state, &workflow_handle, authorization, payload).await {
+        Ok(view) => (
+            HttpStatusCode::OK,
+            HttpJsonPayload(json::serialize_to_value(&view).unwrap_or_default()),
+        ),
+        Err(AppError::Structured(data)) => {
+            let status = match data.code.as_str() {
+                "ERR_WEBHOOK_UNKNOWN_WORKFLOW" => HttpStatusCode::NOT_FOUND,
+                "ERR_WEBHOOK_UNAUTHORIZED" => HttpStatusCode::UNAUTHORIZED,
+                "ERR_WF_SCHEDULER_NOT_READY" => HttpStatusCode::SERVICE_UNAVAILABLE,
+                code if code.starts_with("ERR_WF_CONTEXT") => HttpStatusCode::UNPROCESSABLE_ENTITY,
+                _ => HttpStatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, HttpJsonPayload(json_value!({ "error": data.code })))
+        }
+    }
+}
+
+/// Authentifie l'appel puis démarre une instance du workflow ciblé.
+pub async fn trigger_workflow(
+    state: &WebhookState,
+    workflow_handle: &str,
+    authorization: Option<&str>,
+    payload: JsonValue,
+) -> RaiseResult<WorkflowView> {
+    let trigger = {
+        let store = state.workflows.lock().await;
+        let Some(scheduler) = store.scheduler.as_ref() else {
+            raise_error!("ERR_WF_SCHEDULER_NOT_READY");
+        };
+        // Un workflow sans déclencheur est indiscernable d'un workflow inconnu
+        match scheduler
+            .definitions
+            .get(workflow_handle)
+            .and_then(|d| d.webhook.clone())
+        {
+            Some(trigger) => trigger,
+            None => raise_error!(
+                "ERR_WEBHOOK_UNKNOWN_WORKFLOW",
+                context = json_value!({ "workflow": workflow_handle })
+            ),
+        }
+    };
+
+    let expected = SecretsProvider::global()
+        .require(&trigger.token_secret)
+        .await?;
+    if !bearer_matches(authorization, &expected) {
+        user_warn!(
+            "WRN_WEBHOOK_REJECTED",
+            json_value!({ "workflow": workflow_handle })
+        );
+        raise_error!(
+            "ERR_WEBHOOK_UNAUTHORIZED",
+            context = json_value!({ "workflow": workflow_handle })
+        );
+    }
+
+    user_info!(
+        "INF_WEBHOOK_TRIGGERED",
+        json_value!({ "workflow": workflow_handle })
+    );
+    let mission_id = trigger
+        .mission_id
+        .clone()
+        .unwrap_or_else(|| DEFAULT_WEBHOOK_MISSION.to_string());
+    workflow_service::start_workflow(
+        &state.storage,
+        &state.workflows,
+        mission_id,
+        workflow_handle.to_string(),
+        Some(webhook_inputs(&trigger, payload)),
+    )
+    .await
+}
+
+/// Contexte d'entrée : corps fusionné à la racine, ou rangé sous `payload_key`.
+/// Un corps qui n'est pas un objet est toujours rangé (sous `payload` par défaut).
+fn webhook_inputs(trigger: &WebhookTrigger, payload: JsonValue) -> JsonValue {
+    match (&trigger.payload_key, payload) {
+        (None, JsonValue::Object(map)) => JsonValue::Object(map),
+        (Some(key), payload) => json_value!({ key.clone(): payload }),
+        (None, payload) => json_value!({ "payload": payload }),
+    }
+}
+
+/// Comparaison en temps constant du jeton `Bearer`.
+pub(crate) fn bearer_matches(authorization: Option<&str>, expected: &str) -> bool {
+    let Some(token) = authorization.and_then(|h| h.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    token.len() == expected.len()
+        && token
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_webhook_inputs_and_bearer() {
+        let mut trigger = WebhookTrigger {
+            token_secret: "erp_token".into(),
+            mission_id: None,
+            payload_key: None,
+        };
+        assert_eq!(
+            webhook_inputs(&trigger, json_value!({ "order": 7 })),
+            json_value!({ "order": 7 })
+        );
+        assert_eq!(
+            webhook_inputs(&trigger, json_value!([1, 2])),
+            json_value!({ "payload": [1, 2] })
+        );
+        trigger.payload_key = Some("erp_event".into());
+        assert_eq!(
+            webhook_inputs(&trigger, json_value!({ "order": 7 })),
+            json_value!({ "erp_event": { "order": 7 } })
+        );
+
+        assert!(bearer_matches(Some("Bearer s3cr3t-token"), "s3cr3t-token"));
+        assert!(!bearer_matches(Some("Bearer s3cr3t-tokeN"), "s3cr3t-token"));
+        assert!(!bearer_matches(Some("s3cr3t-token"), "s3cr3t-token"));
+        assert!(!bearer_matches(None, "s3cr3t-token"));
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_router_reports_unready_scheduler() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let state = WebhookState {
+            storage: SharedRef::new(sandbox.storage.clone()),
+            workflows: SharedRef::new(AsyncMutex::new(WorkflowStore::default())),
+        };
+
+        let listener = match HttpTcpListener::bind("127.0.0.1:0").await {
+            Ok(l) => l,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        let addr = match listener.local_addr() {
+            Ok(a) => a,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        let router = webhook_router(state);
+        spawn_async_task(async move {
+            let _ = run_http_server(listener, router).await;
+        });
+
+        let response = match get_client()
+            .post(format!("http://{}/webhooks/wf_erp_sync", addr))
+            .json(&json_value!({ "order": 7 }))
+            .send()
+            .await
+        {
+            Ok(r) => r,
+            Err(e) => raise_error!("ERR_TEST_HTTP", error = e),
+        };
+        assert_eq!(response.status(), HttpStatusCode::SERVICE_UNAVAILABLE);
+        Ok(())
+    }
+}
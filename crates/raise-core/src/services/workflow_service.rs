@@ -2,8 +2,10 @@
 
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
+use crate::workflow_engine::critic::PlanCritique;
 use crate::workflow_engine::{
-    ExecutionStatus, WorkflowCompiler, WorkflowDefinition, WorkflowInstance, WorkflowScheduler,
+    AdmissionSnapshot, ExecutionStatus, WorkflowCompiler, WorkflowDefinition, WorkflowInstance,
+    WorkflowScheduler,
 };
 
 use crate::json_db::collections::manager::CollectionsManager;
@@ -23,6 +25,8 @@ pub struct WorkflowView {
     pub status: ExecutionStatus,
     pub current_nodes: Vec<String>,
     pub logs: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan_review: Option<PlanCritique>,
 }
 
 impl From<&WorkflowInstance> for WorkflowView {
@@ -32,6 +36,7 @@ impl From<&WorkflowInstance> for WorkflowView {
             status: instance.status,
             current_nodes: instance.node_states.keys().cloned().collect(),
             logs: instance.logs.clone(),
+            plan_review: instance.plan_review.clone(),
         }
     }
 }
@@ -116,6 +121,7 @@ pub async fn start_workflow(
     state: &AsyncMutex<WorkflowStore>,
     mission_id: String,
     workflow_handle: String,
+    inputs: Option<JsonValue>,
 ) -> RaiseResult<WorkflowView> {
     let config = AppConfig::get();
     let manager = CollectionsManager::new(
@@ -124,6 +130,16 @@ pub async fn start_workflow(
         &config.mount_points.system.db,
     );
 
+    let inputs = match inputs {
+        None | Some(JsonValue::Null) => JsonObject::new(),
+        Some(JsonValue::Object(map)) => map,
+        Some(other) => raise_error!(
+            "ERR_WF_CONTEXT_NOT_OBJECT",
+            error = "Le contexte d'entrée doit être un objet JSON",
+            context = json_value!({ "received": other })
+        ),
+    };
+
     let instance_handle = {
         let mut store = state.lock().await;
         let scheduler = match store.scheduler.as_mut() {
@@ -132,7 +148,7 @@ pub async fn start_workflow(
         };
 
         let instance = scheduler
-            .create_instance(&mission_id, &workflow_handle, &manager)
+            .create_instance_with_context(&mission_id, &workflow_handle, inputs, &manager)
             .await?;
         let handle = instance.handle.clone();
         store.instances.insert(handle.clone(), instance);
@@ -171,6 +187,17 @@ pub async fn resume_workflow(
     run_workflow_loop(state, instance_handle, &manager).await
 }
 
+/// État de la file d'admission (exécutions en cours et en attente par classe).
+pub async fn get_scheduling_snapshot(
+    state: &AsyncMutex<WorkflowStore>,
+) -> RaiseResult<AdmissionSnapshot> {
+    let store = state.lock().await;
+    match store.scheduler.as_ref() {
+        Some(scheduler) => Ok(scheduler.admission.snapshot()),
+        None => raise_error!("ERR_WF_SCHEDULER_NOT_READY"),
+    }
+}
+
 pub async fn get_workflow_state(
     state: &AsyncMutex<WorkflowStore>,
     instance_handle: &str,
@@ -192,6 +219,25 @@ async fn run_workflow_loop(
     instance_handle: &str,
     manager: &CollectionsManager<'_>,
 ) -> RaiseResult<WorkflowView> {
+    // 🚦 Admission hors verrou : l'attente d'un créneau ne bloque pas le store
+    let (admission, hints) = {
+        let guard = state.lock().await;
+        let scheduler = match guard.scheduler.as_ref() {
+            Some(s) => s,
+            None => raise_error!("ERR_WF_SCHEDULER_NOT_READY"),
+        };
+        let workflow_id = guard
+            .instances
+            .get(instance_handle)
+            .map(|i| i.workflow_id.as_str())
+            .unwrap_or_default();
+        (
+            scheduler.admission.clone(),
+            scheduler.scheduling_hints(workflow_id),
+        )
+    };
+    let _permit = admission.acquire(&hints).await;
+
     let _final_status = {
         let guard = state.lock().await;
         match guard.scheduler.as_ref() {
@@ -11,6 +11,24 @@ pub enum LayerType {
     Chaos = 4, // Zone IA / Non-structurée
 }
 
+impl LayerType {
+    /// Associe une clé de couche du `ProjectModel` ("oa", "sa"...) à son plan spatial.
+    pub fn from_layer(layer: &str) -> Self {
+        match layer.to_lowercase().as_str() {
+            "oa" => LayerType::OA,
+            "sa" => LayerType::SA,
+            "la" => LayerType::LA,
+            "pa" => LayerType::PA,
+            _ => LayerType::Chaos,
+        }
+    }
+
+    /// Altitude du plan dans la scène 3D (OA en haut, Chaos sous PA).
+    fn altitude(&self) -> f32 {
+        (3 - self.clone() as i32) as f32 * 10.0
+    }
+}
+
 #[derive(Debug, Serializable, Deserializable, Clone)]
 pub struct SpatialNode {
     pub id: String,
@@ -26,6 +44,9 @@ pub struct SpatialLink {
     pub source: String,
     pub target: String,
     pub strength: f32,
+    /// Type de relation sémantique (ex: "allocatedTo"), absent pour les liens structurels
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation: Option<String>,
 }
 
 #[derive(Debug, Serializable, Deserializable, Clone)]
@@ -41,6 +62,80 @@ pub struct GraphMeta {
     pub layer_distribution: [usize; 5],
 }
 
+/// Nœud d'un voisinage extrait autour d'un élément central.
+#[derive(Debug, Serializable, Deserializable, Clone)]
+pub struct NeighborNode {
+    pub id: String,
+    pub label: String,
+    pub kind: Option<String>,
+    pub layer: LayerType,
+    /// Nombre de sauts depuis l'élément central
+    pub distance: usize,
+    /// Vrai si le nœud possède des liens non encore explorés (expansion progressive)
+    pub has_more: bool,
+}
+
+/// Sous-graphe typé partagé entre la traçabilité et la vue spatiale.
+#[derive(Debug, Serializable, Deserializable, Clone)]
+pub struct GraphNeighborhood {
+    pub center_id: String,
+    pub depth: usize,
+    pub nodes: Vec<NeighborNode>,
+    pub links: Vec<SpatialLink>,
+    /// Vrai si la limite de nœuds a interrompu l'exploration
+    pub truncated: bool,
+}
+
+impl GraphNeighborhood {
+    /// Projette le voisinage dans la scène 3D : un plan par couche, un anneau par distance.
+    pub fn to_spatial_graph(&self) -> SpatialGraph {
+        let mut layer_counts = [0; 5];
+        let mut rings: UnorderedMap<(usize, usize), usize> = UnorderedMap::new();
+        for node in &self.nodes {
+            *rings
+                .entry((node.layer.clone() as usize, node.distance))
+                .or_default() += 1;
+        }
+
+        let mut placed: UnorderedMap<(usize, usize), usize> = UnorderedMap::new();
+        let nodes: Vec<SpatialNode> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let key = (node.layer.clone() as usize, node.distance);
+                let total = rings.get(&key).copied().unwrap_or(1).max(1);
+                let index = placed.entry(key).or_default();
+                let angle = (*index as f32 / total as f32) * 2.0 * MATH_PI;
+                *index += 1;
+                layer_counts[key.0] += 1;
+
+                let radius = node.distance as f32 * 6.0;
+                SpatialNode {
+                    id: node.id.clone(),
+                    label: node.label.clone(),
+                    position: [
+                        radius * angle.cos(),
+                        node.layer.altitude(),
+                        radius * angle.sin(),
+                    ],
+                    layer: node.layer.clone(),
+                    weight: if node.distance == 0 { 2.0 } else { 1.0 },
+                    stability: 1.0,
+                }
+            })
+            .collect();
+
+        SpatialGraph {
+            meta: GraphMeta {
+                node_count: nodes.len(),
+                layer_distribution: layer_counts,
+            },
+            nodes,
+            links: self.links.clone(),
+        }
+    }
+}
+
 // --- LOGIQUE MÉTIER ---
 
 pub fn get_spatial_topology() -> SpatialGraph {
@@ -91,6 +186,7 @@ pub fn get_spatial_topology() -> SpatialGraph {
                 source: root_id.clone(),
                 target: sub_id,
                 strength: 0.7,
+                relation: None,
             });
         }
     }
@@ -122,4 +218,35 @@ mod tests {
         assert_eq!(graph.meta.layer_distribution.len(), 5);
         assert_eq!(graph.meta.node_count, graph.nodes.len());
     }
+
+    #[test]
+    fn test_neighborhood_projection() {
+        let node = |id: &str, layer: &str, distance: usize| NeighborNode {
+            id: id.into(),
+            label: id.into(),
+            kind: None,
+            layer: LayerType::from_layer(layer),
+            distance,
+            has_more: false,
+        };
+        let neighborhood = GraphNeighborhood {
+            center_id: "f1".into(),
+            depth: 1,
+            nodes: vec![
+                node("f1", "sa", 0),
+                node("c1", "la", 1),
+                node("c2", "la", 1),
+            ],
+            links: vec![],
+            truncated: false,
+        };
+
+        let graph = neighborhood.to_spatial_graph();
+        assert_eq!(graph.meta.node_count, 3);
+        assert_eq!(graph.meta.layer_distribution, [0, 1, 2, 0, 0]);
+        assert_eq!(graph.nodes[0].position, [0.0, 20.0, 0.0]);
+        // Les deux voisins LA sont répartis sur le même anneau, sans collision
+        assert_ne!(graph.nodes[1].position, graph.nodes[2].position);
+        assert_eq!(graph.nodes[1].position[1], 10.0);
+    }
 }
@@ -1,12 +1,14 @@
 pub mod change_tracker;
 pub mod compliance;
 pub mod impact_analyzer;
+pub mod neighborhood;
 pub mod reporting;
 pub mod tracer;
 
 pub use change_tracker::ChangeTracker;
 pub use impact_analyzer::ImpactAnalyzer;
-pub use tracer::Tracer;
+pub use neighborhood::NeighborhoodQuery;
+pub use tracer::{Tracer, TraversalDirection};
 
 #[cfg(test)]
 mod tests {
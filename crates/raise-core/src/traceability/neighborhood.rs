@@ -0,0 +1,272 @@
+// FICHIER : src-tauri/src/traceability/neighborhood.rs
+
+use super::tracer::{TraceLink, Tracer, TraversalDirection};
+use crate::model_engine::types::{ArcadiaElement, ProjectModel};
+use crate::spatial_engine::{GraphNeighborhood, LayerType, NeighborNode, SpatialLink};
+use crate::utils::prelude::*;
+
+/// Profondeur maximale acceptée pour une requête de voisinage.
+pub const MAX_NEIGHBORHOOD_DEPTH: usize = 6;
+
+/// Paramètres d'exploration du voisinage d'un élément.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+#[serde(default)]
+pub struct NeighborhoodQuery {
+    /// Nombre de sauts depuis l'élément central (1 = liens directs)
+    pub depth: usize,
+    /// Types de relation retenus (vide = toutes)
+    pub relations: Vec<String>,
+    pub direction: TraversalDirection,
+    /// Garde-fou contre l'explosion combinatoire des graphes denses
+    pub max_nodes: usize,
+}
+
+impl Default for NeighborhoodQuery {
+    fn default() -> Self {
+        Self {
+            depth: 1,
+            relations: Vec::new(),
+            direction: TraversalDirection::Both,
+            max_nodes: 500,
+        }
+    }
+}
+
+impl NeighborhoodQuery {
+    fn accepts(&self, link: &TraceLink) -> bool {
+        self.relations.is_empty() || self.relations.iter().any(|r| r == &link.relation)
+    }
+}
+
+/// Parcours en largeur du graphe de traçabilité autour de `center_id`.
+pub fn explore(
+    tracer: &Tracer,
+    model: &ProjectModel,
+    center_id: &str,
+    query: &NeighborhoodQuery,
+) -> RaiseResult<GraphNeighborhood> {
+    if query.depth > MAX_NEIGHBORHOOD_DEPTH {
+        raise_error!(
+            "ERR_TRACE_DEPTH_EXCEEDED",
+            context = json_value!({ "depth": query.depth, "max": MAX_NEIGHBORHOOD_DEPTH })
+        );
+    }
+
+    // Index id -> (couche, élément) pour la résolution des nœuds
+    let mut index: UnorderedMap<&str, (&str, &ArcadiaElement)> = UnorderedMap::new();
+    for (layer, collections) in &model.layers {
+        for element in collections.values().flatten() {
+            index.insert(element.id.as_str(), (layer.as_str(), element));
+        }
+    }
+
+    if !index.contains_key(center_id) {
+        raise_error!(
+            "ERR_TRACE_ELEMENT_NOT_FOUND",
+            context = json_value!({ "id": center_id })
+        );
+    }
+
+    let mut distances: UnorderedMap<String, usize> = UnorderedMap::new();
+    let mut order = vec![center_id.to_string()];
+    let mut links: Vec<TraceLink> = Vec::new();
+    let mut truncated = false;
+    distances.insert(center_id.to_string(), 0);
+
+    let mut cursor = 0;
+    while cursor < order.len() {
+        let id = order[cursor].clone();
+        cursor += 1;
+        let distance = distances[&id];
+        if distance >= query.depth {
+            continue;
+        }
+
+        for link in tracer.get_links(&id, query.direction) {
+            if !query.accepts(&link) {
+                continue;
+            }
+            let other = if link.source == id {
+                &link.target
+            } else {
+                &link.source
+            };
+            if !distances.contains_key(other) {
+                if distances.len() >= query.max_nodes {
+                    truncated = true;
+                    continue;
+                }
+                distances.insert(other.clone(), distance + 1);
+                order.push(other.clone());
+            }
+            if !links.contains(&link) {
+                links.push(link);
+            }
+        }
+    }
+
+    let nodes = order
+        .iter()
+        .map(|id| {
+            let has_more = tracer
+                .get_links(id, query.direction)
+                .iter()
+                .filter(|l| query.accepts(l))
+                .any(|l| !distances.contains_key(&l.source) || !distances.contains_key(&l.target));
+
+            // Les cibles hors modèle (ex: model_id externe) restent visibles dans la zone Chaos
+            let (label, kind, layer) = match index.get(id.as_str()) {
+                Some((layer, element)) => (
+                    element.name.as_str().to_string(),
+                    Some(element.kind.clone()),
+                    LayerType::from_layer(layer),
+                ),
+                None => (id.clone(), None, LayerType::Chaos),
+            };
+
+            NeighborNode {
+                id: id.clone(),
+                label,
+                kind,
+                layer,
+                distance: distances[id],
+                has_more,
+            }
+        })
+        .collect();
+
+    let links = links
+        .into_iter()
+        .map(|l| SpatialLink {
+            source: l.source,
+            target: l.target,
+            strength: 1.0,
+            relation: Some(l.relation),
+        })
+        .collect();
+
+    Ok(GraphNeighborhood {
+        center_id: center_id.to_string(),
+        depth: query.depth,
+        nodes,
+        links,
+        truncated,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model_engine::types::NameType;
+
+    fn element(id: &str, kind: &str, links: &[(&str, &str)]) -> ArcadiaElement {
+        let mut properties = UnorderedMap::new();
+        for (relation, target) in links {
+            properties.insert(relation.to_string(), json_value!(target));
+        }
+        ArcadiaElement {
+            id: id.into(),
+            name: NameType::String(id.to_uppercase()),
+            kind: kind.into(),
+            properties,
+        }
+    }
+
+    /// req_1 <-satisfiedBy- f1 -allocatedTo-> c1 -allocatedTo-> n1
+    fn sample_model() -> ProjectModel {
+        let mut model = ProjectModel::default();
+        model.add_element("sa", "requirements", element("req_1", "Requirement", &[]));
+        model.add_element(
+            "sa",
+            "functions",
+            element(
+                "f1",
+                "SystemFunction",
+                &[("allocatedTo", "c1"), ("satisfiedBy", "req_1")],
+            ),
+        );
+        model.add_element(
+            "la",
+            "components",
+            element("c1", "LogicalComponent", &[("allocatedTo", "n1")]),
+        );
+        model.add_element("pa", "components", element("n1", "PhysicalNode", &[]));
+        model
+    }
+
+    #[test]
+    fn test_depth_and_frontier() -> RaiseResult<()> {
+        let model = sample_model();
+        let tracer = Tracer::from_legacy_model(&model)?;
+
+        let direct = explore(&tracer, &model, "f1", &NeighborhoodQuery::default())?;
+        assert_eq!(direct.nodes.len(), 3);
+        assert_eq!(direct.links.len(), 2);
+        let c1 = direct.nodes.iter().find(|n| n.id == "c1").unwrap();
+        assert_eq!(c1.distance, 1);
+        assert_eq!(c1.layer, LayerType::LA);
+        assert!(c1.has_more, "c1 doit signaler son lien vers n1");
+
+        let deep = explore(
+            &tracer,
+            &model,
+            "f1",
+            &NeighborhoodQuery {
+                depth: 2,
+                ..Default::default()
+            },
+        )?;
+        let n1 = deep.nodes.iter().find(|n| n.id == "n1").unwrap();
+        assert_eq!(n1.distance, 2);
+        assert!(!n1.has_more);
+        Ok(())
+    }
+
+    #[test]
+    fn test_relation_and_direction_filters() -> RaiseResult<()> {
+        let model = sample_model();
+        let tracer = Tracer::from_legacy_model(&model)?;
+
+        let allocations = explore(
+            &tracer,
+            &model,
+            "f1",
+            &NeighborhoodQuery {
+                depth: 3,
+                relations: vec!["allocatedTo".into()],
+                ..Default::default()
+            },
+        )?;
+        let ids: Vec<&str> = allocations.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["f1", "c1", "n1"]);
+        assert!(allocations
+            .links
+            .iter()
+            .all(|l| l.relation.as_deref() == Some("allocatedTo")));
+
+        let upstream = explore(
+            &tracer,
+            &model,
+            "c1",
+            &NeighborhoodQuery {
+                depth: 3,
+                direction: TraversalDirection::Upstream,
+                ..Default::default()
+            },
+        )?;
+        let ids: Vec<&str> = upstream.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["c1", "f1"]);
+
+        assert!(explore(
+            &tracer,
+            &model,
+            "f1",
+            &NeighborhoodQuery {
+                depth: MAX_NEIGHBORHOOD_DEPTH + 1,
+                ..Default::default()
+            },
+        )
+        .is_err());
+        Ok(())
+    }
+}
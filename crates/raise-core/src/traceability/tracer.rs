@@ -6,10 +6,32 @@ use crate::json_db::jsonld::{ContextManager, VocabularyRegistry};
 use crate::model_engine::types::ProjectModel;
 use crate::utils::prelude::*;
 
+/// Lien typé entre deux éléments (la relation est le nom de la propriété porteuse).
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct TraceLink {
+    pub source: String,
+    pub target: String,
+    pub relation: String,
+}
+
+/// Sens de parcours du graphe de traçabilité.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum TraversalDirection {
+    /// Liens sortants (allocatedTo, realizedBy...)
+    Downstream,
+    /// Liens entrants
+    Upstream,
+    #[default]
+    Both,
+}
+
 /// Service de traçabilité basé sur un Graphe d'identifiants.
 pub struct Tracer {
     downstream_links: UnorderedMap<String, Vec<String>>,
     upstream_links: UnorderedMap<String, Vec<String>>,
+    outgoing: UnorderedMap<String, Vec<TraceLink>>,
+    incoming: UnorderedMap<String, Vec<TraceLink>>,
 }
 
 impl Tracer {
@@ -47,6 +69,8 @@ impl Tracer {
     fn build_graph(documents: Vec<JsonValue>) -> RaiseResult<Self> {
         let mut downstream: UnorderedMap<String, Vec<String>> = UnorderedMap::new();
         let mut upstream: UnorderedMap<String, Vec<String>> = UnorderedMap::new();
+        let mut outgoing: UnorderedMap<String, Vec<TraceLink>> = UnorderedMap::new();
+        let mut incoming: UnorderedMap<String, Vec<TraceLink>> = UnorderedMap::new();
         let ctx = ContextManager::new()?;
         let registry = VocabularyRegistry::global()?;
 
@@ -80,6 +104,17 @@ impl Tracer {
                                 .entry(target_id.clone())
                                 .or_default()
                                 .push(id.clone());
+
+                            let link = TraceLink {
+                                source: id.clone(),
+                                target: target_id.clone(),
+                                relation: key.clone(),
+                            };
+                            incoming
+                                .entry(target_id.clone())
+                                .or_default()
+                                .push(link.clone());
+                            outgoing.entry(id.clone()).or_default().push(link);
                         }
                         downstream.entry(id.clone()).or_default().extend(targets);
                     }
@@ -90,6 +125,8 @@ impl Tracer {
         Ok(Self {
             downstream_links: downstream,
             upstream_links: upstream,
+            outgoing,
+            incoming,
         })
     }
 
@@ -106,12 +143,28 @@ impl Tracer {
             .cloned()
             .unwrap_or_default()
     }
+
+    /// Liens typés touchant `element_id` dans le sens demandé.
+    pub fn get_links(&self, element_id: &str, direction: TraversalDirection) -> Vec<TraceLink> {
+        let mut links = Vec::new();
+        if direction != TraversalDirection::Upstream {
+            if let Some(out) = self.outgoing.get(element_id) {
+                links.extend(out.iter().cloned());
+            }
+        }
+        if direction != TraversalDirection::Downstream {
+            if let Some(inc) = self.incoming.get(element_id) {
+                links.extend(inc.iter().cloned());
+            }
+        }
+        links
+    }
 }
 
 fn is_link_property(key: &str, ctx: &ContextManager, registry: &VocabularyRegistry) -> bool {
     if matches!(
         key,
-        "allocatedTo" | "realizedBy" | "satisfiedBy" | "verifiedBy" | "model_id"
+        "allocatedTo" | "realizedBy" | "satisfiedBy" | "verifiedBy" | "verifies" | "model_id"
     ) {
         return true;
     }
@@ -155,6 +208,13 @@ mod tests {
         );
         assert_eq!(upstream[0], "rep_1");
 
+        let links = tracer.get_links("ai_1", TraversalDirection::Upstream);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].relation, "model_id");
+        assert!(tracer
+            .get_links("ai_1", TraversalDirection::Downstream)
+            .is_empty());
+
         Ok(())
     }
 }
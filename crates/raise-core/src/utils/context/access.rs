@@ -0,0 +1,524 @@
+// FICHIER : crates/raise-core/src/utils/context/access.rs
+//! Contrôle d'accès par rôle aux commandes de l'interface : chaque commande invoquée
+//! est confrontée aux rôles de l'identité active (session locale ou jeton OIDC).
+//! La vérification est synchrone pour pouvoir être branchée sur l'invoke_handler.
+
+use crate::utils::context::audit::is_state_changing;
+use crate::utils::context::tokens::ApiTokenIssuer;
+use crate::utils::data::config::{AccessControlConfig, OidcConfig, RolePolicy};
+use crate::utils::prelude::*;
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+/// Commandes toujours ouvertes : sans elles, impossible de s'authentifier.
+const ALWAYS_ALLOWED: &[&str] = &[
+    "session_login",
+    "session_logout",
+    "session_get",
+    "access_login_oidc",
+    "access_login_token",
+    "access_whoami",
+    "get_app_info",
+    "get_event_catalogue",
+];
+
+/// Commandes destructives ou d'administration, refusées aux éditeurs par défaut.
+const EDITOR_DENIED: &[&str] = &[
+    "jsondb_create_db",
+    "jsondb_clone_db",
+    "jsondb_drop_*",
+    "jsondb_delete_document",
+    "jsondb_tighten_collection",
+    "jsondb_vacuum",
+    "jsondb_init_demo_rules",
+    "jsondb_set_acl",
+    "access_issue_token",
+    "config_set",
+    "audit_anchor_day",
+    "ai_reset",
+    "ai_model_download",
+    "ai_model_switch",
+    "ai_prompt_publish",
+    "ai_maintenance_schedule",
+    "cognitive_load_plugin",
+    "mentis_init_node",
+    "mentis_broadcast_mutation",
+    "reset_network_circuit",
+];
+
+/// Commandes de revue ouvertes aux validateurs en plus de la consultation.
+const VALIDATOR_ALLOWED: &[&str] = &[
+    "validate_model",
+    "dry_run_rule",
+    "run_compliance_audit",
+    "resume_workflow",
+    "ai_resolve_write_conflict",
+    "audit_anchor_day",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessRole {
+    Viewer,
+    Editor,
+    Validator,
+    Admin,
+}
+
+impl AccessRole {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "viewer" => Some(Self::Viewer),
+            "editor" => Some(Self::Editor),
+            "validator" => Some(Self::Validator),
+            "admin" => Some(Self::Admin),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Viewer => "viewer",
+            Self::Editor => "editor",
+            Self::Validator => "validator",
+            Self::Admin => "admin",
+        }
+    }
+
+    /// Politique intégrée (surchargée par `access_control.policies`).
+    pub fn default_policy(&self) -> RolePolicy {
+        let patterns = |list: &[&str]| list.iter().map(|p| p.to_string()).collect();
+        match self {
+            Self::Viewer => RolePolicy {
+                read: true,
+                ..Default::default()
+            },
+            Self::Validator => RolePolicy {
+                read: true,
+                allow: patterns(VALIDATOR_ALLOWED),
+                deny: Vec::new(),
+            },
+            Self::Editor => RolePolicy {
+                read: true,
+                allow: vec!["*".to_string()],
+                deny: patterns(EDITOR_DENIED),
+            },
+            Self::Admin => RolePolicy {
+                read: true,
+                allow: vec!["*".to_string()],
+                deny: Vec::new(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentitySource {
+    /// Utilisateur de la session locale du poste.
+    Local,
+    /// Jeton signé par le fournisseur d'identité configuré.
+    Oidc,
+    /// Jeton d'API émis par le poste (`tokens::ApiTokenIssuer`).
+    ApiToken,
+}
+
+/// Identité à laquelle s'appliquent les contrôles.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct AccessIdentity {
+    pub user: String,
+    pub roles: Vec<AccessRole>,
+    pub source: IdentitySource,
+    /// Expiration du jeton (timestamp Unix), absente pour une identité locale.
+    pub expires_at: Option<i64>,
+}
+
+/// Garde d'accès partagée entre l'invoke_handler et les commandes d'authentification.
+#[derive(Clone, Default)]
+pub struct AccessGuard {
+    identity: SharedRef<SyncRwLock<Option<AccessIdentity>>>,
+}
+
+impl AccessGuard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn identity(&self) -> Option<AccessIdentity> {
+        self.identity.read().ok().and_then(|i| i.clone())
+    }
+
+    fn set_identity(&self, identity: Option<AccessIdentity>) {
+        let mut slot = match self.identity.write() {
+            Ok(s) => s,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        *slot = identity;
+    }
+
+    /// Identité issue de la session locale : rôles lus dans `access_control.user_roles`.
+    pub fn set_local_user(&self, config: &AccessControlConfig, user: &str) -> AccessIdentity {
+        let roles = config
+            .user_roles
+            .get(user)
+            .map(|names| names.iter().filter_map(|r| AccessRole::parse(r)).collect())
+            .unwrap_or_default();
+        let identity = AccessIdentity {
+            user: user.to_string(),
+            roles: with_default_role(config, roles),
+            source: IdentitySource::Local,
+            expires_at: None,
+        };
+        self.set_identity(Some(identity.clone()));
+        identity
+    }
+
+    /// Identité issue d'un jeton OIDC (signature, émetteur, audience et expiration vérifiés).
+    pub fn login_oidc(
+        &self,
+        config: &AccessControlConfig,
+        token: &str,
+    ) -> RaiseResult<AccessIdentity> {
+        let Some(oidc) = &config.oidc else {
+            raise_error!(
+                "ERR_ACCESS_OIDC_NOT_CONFIGURED",
+                error = "Aucun fournisseur OIDC n'est configuré (access_control.oidc)."
+            );
+        };
+        let claims = verify_oidc_token(oidc, token, UtcClock::now().timestamp())?;
+
+        let user = claims
+            .get("preferred_username")
+            .or_else(|| claims.get("sub"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("anonymous")
+            .to_string();
+        let mut roles: Vec<AccessRole> = oidc
+            .roles_claim
+            .split('.')
+            .try_fold(&claims, |node, part| node.get(part))
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|r| r.as_str().and_then(AccessRole::parse))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if roles.is_empty() {
+            if let Some(names) = config.user_roles.get(&user) {
+                roles = names.iter().filter_map(|r| AccessRole::parse(r)).collect();
+            }
+        }
+
+        let identity = AccessIdentity {
+            user,
+            roles: with_default_role(config, roles),
+            source: IdentitySource::Oidc,
+            expires_at: claims.get("exp").and_then(|v| v.as_i64()),
+        };
+        user_info!(
+            "INF_ACCESS_OIDC_LOGIN",
+            json_value!({ "user": identity.user, "roles": identity.roles })
+        );
+        self.set_identity(Some(identity.clone()));
+        Ok(identity)
+    }
+
+    /// Identité issue d'un jeton d'API émis localement.
+    pub fn login_api_token(
+        &self,
+        issuer: &ApiTokenIssuer,
+        token: &str,
+    ) -> RaiseResult<AccessIdentity> {
+        let identity = issuer.verify(token, UtcClock::now().timestamp())?;
+        user_info!(
+            "INF_ACCESS_TOKEN_LOGIN",
+            json_value!({ "user": identity.user, "roles": identity.roles })
+        );
+        self.set_identity(Some(identity.clone()));
+        Ok(identity)
+    }
+
+    pub fn logout(&self) {
+        self.set_identity(None);
+    }
+
+    /// Autorise ou refuse une commande pour l'identité active.
+    pub fn authorize(&self, config: &AccessControlConfig, command: &str) -> RaiseResult<()> {
+        if !config.enabled || ALWAYS_ALLOWED.contains(&command) {
+            return Ok(());
+        }
+        let identity = self.identity().filter(|i| {
+            i.expires_at
+                .is_none_or(|exp| exp > UtcClock::now().timestamp())
+        });
+        let Some(identity) = identity else {
+            raise_error!(
+                "ERR_ACCESS_UNAUTHENTICATED",
+                error = "Aucune identité active : connexion requise.",
+                context = json_value!({ "command": command })
+            );
+        };
+        if identity
+            .roles
+            .iter()
+            .any(|role| policy_for(config, *role).permits(command))
+        {
+            return Ok(());
+        }
+        raise_error!(
+            "ERR_ACCESS_DENIED",
+            error = "Commande non autorisée pour les rôles de l'utilisateur.",
+            context = json_value!({
+                "command": command,
+                "user": identity.user,
+                "roles": identity.roles
+            })
+        );
+    }
+}
+
+fn with_default_role(config: &AccessControlConfig, roles: Vec<AccessRole>) -> Vec<AccessRole> {
+    if !roles.is_empty() {
+        return roles;
+    }
+    AccessRole::parse(&config.default_role)
+        .map(|r| vec![r])
+        .unwrap_or_default()
+}
+
+/// Politique effective d'un rôle : surcharge de configuration, sinon politique intégrée.
+pub fn policy_for(config: &AccessControlConfig, role: AccessRole) -> RolePolicy {
+    config
+        .policies
+        .get(role.as_str())
+        .cloned()
+        .unwrap_or_else(|| role.default_policy())
+}
+
+impl RolePolicy {
+    pub fn permits(&self, command: &str) -> bool {
+        if self.deny.iter().any(|p| pattern_matches(p, command)) {
+            return false;
+        }
+        (self.read && !is_state_changing(command))
+            || self.allow.iter().any(|p| pattern_matches(p, command))
+    }
+}
+
+fn pattern_matches(pattern: &str, command: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => command.starts_with(prefix),
+        None => pattern == command,
+    }
+}
+
+// =========================================================================
+// JETONS OIDC (JWT compact signé EdDSA)
+// =========================================================================
+
+/// Vérifie un jeton `header.payload.signature` et retourne ses claims.
+pub fn verify_oidc_token(oidc: &OidcConfig, token: &str, now: i64) -> RaiseResult<JsonValue> {
+    let parts: Vec<&str> = token.trim().split('.').collect();
+    let [header_b64, payload_b64, signature_b64] = parts.as_slice() else {
+        raise_error!(
+            "ERR_ACCESS_TOKEN_MALFORMED",
+            error = "Jeton OIDC attendu au format header.payload.signature."
+        );
+    };
+
+    let header: JsonValue = json::deserialize_from_bytes(&decode_base64url(header_b64)?)?;
+    if header.get("alg").and_then(|v| v.as_str()) != Some("EdDSA") {
+        raise_error!(
+            "ERR_ACCESS_TOKEN_ALG",
+            error = "Algorithme de signature non supporté (EdDSA attendu).",
+            context = json_value!({ "alg": header.get("alg") })
+        );
+    }
+
+    let key_bytes: [u8; 32] = match hex::decode(&oidc.public_key)
+        .ok()
+        .and_then(|b| b.try_into().ok())
+    {
+        Some(b) => b,
+        None => raise_error!(
+            "ERR_ACCESS_OIDC_KEY_INVALID",
+            error = "Clé publique OIDC invalide (32 octets hex attendus)."
+        ),
+    };
+    let signature_ok = VerifyingKey::from_bytes(&key_bytes)
+        .ok()
+        .zip(Signature::from_slice(&decode_base64url(signature_b64)?).ok())
+        .is_some_and(|(key, sig)| {
+            let signed = format!("{}.{}", header_b64, payload_b64);
+            key.verify(signed.as_bytes(), &sig).is_ok()
+        });
+    if !signature_ok {
+        raise_error!(
+            "ERR_ACCESS_TOKEN_SIGNATURE",
+            error = "Signature du jeton OIDC invalide."
+        );
+    }
+
+    let claims: JsonValue = json::deserialize_from_bytes(&decode_base64url(payload_b64)?)?;
+    if claims.get("iss").and_then(|v| v.as_str()) != Some(oidc.issuer.as_str()) {
+        raise_error!(
+            "ERR_ACCESS_TOKEN_ISSUER",
+            error = "Émetteur du jeton non reconnu.",
+            context = json_value!({ "expected": oidc.issuer, "found": claims.get("iss") })
+        );
+    }
+    if let Some(audience) = &oidc.audience {
+        let matches = match claims.get("aud") {
+            Some(JsonValue::String(aud)) => aud == audience,
+            Some(JsonValue::Array(auds)) => auds.iter().any(|a| a.as_str() == Some(audience)),
+            _ => false,
+        };
+        if !matches {
+            raise_error!(
+                "ERR_ACCESS_TOKEN_AUDIENCE",
+                error = "Audience du jeton non reconnue.",
+                context = json_value!({ "expected": audience })
+            );
+        }
+    }
+    match claims.get("exp").and_then(|v| v.as_i64()) {
+        Some(exp) if exp > now => Ok(claims),
+        _ => raise_error!(
+            "ERR_ACCESS_TOKEN_EXPIRED",
+            error = "Jeton OIDC expiré ou sans date d'expiration."
+        ),
+    }
+}
+
+/// Base64url sans remplissage (RFC 7515) vers octets.
+pub(crate) fn decode_base64url(input: &str) -> RaiseResult<Vec<u8>> {
+    decode_base64(&input.replace('-', "+").replace('_', "/"))
+}
+
+pub(crate) fn encode_base64url(bytes: &[u8]) -> String {
+    encode_base64(bytes)
+        .trim_end_matches('=')
+        .replace('+', "-")
+        .replace('/', "_")
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn config() -> AccessControlConfig {
+        let mut config = AccessControlConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        config
+            .user_roles
+            .insert("alice".into(), vec!["editor".into()]);
+        config
+    }
+
+    fn sign_token(key: &SigningKey, claims: &JsonValue) -> String {
+        let header = encode_base64url(br#"{"alg":"EdDSA","typ":"JWT"}"#);
+        let payload = encode_base64url(claims.to_string().as_bytes());
+        let signed = format!("{}.{}", header, payload);
+        let signature = key.sign(signed.as_bytes());
+        format!("{}.{}", signed, encode_base64url(&signature.to_bytes()))
+    }
+
+    #[test]
+    fn test_role_policies() {
+        let config = config();
+        let guard = AccessGuard::new();
+
+        // Sans identité : seules les commandes d'authentification passent
+        assert!(guard.authorize(&config, "session_login").is_ok());
+        assert!(guard.authorize(&config, "jsondb_list_all").is_err());
+
+        guard.set_local_user(&config, "bob");
+        assert!(guard.authorize(&config, "jsondb_list_all").is_ok());
+        assert!(guard.authorize(&config, "jsondb_insert_document").is_err());
+
+        guard.set_local_user(&config, "alice");
+        assert!(guard.authorize(&config, "jsondb_insert_document").is_ok());
+        match guard.authorize(&config, "jsondb_drop_db") {
+            Err(AppError::Structured(err)) => assert_eq!(err.code, "ERR_ACCESS_DENIED"),
+            _ => panic!("Un éditeur ne doit pas pouvoir supprimer une base"),
+        }
+
+        // Désactivé : tout passe
+        let open = AccessControlConfig::default();
+        assert!(guard.authorize(&open, "jsondb_drop_db").is_ok());
+    }
+
+    #[test]
+    fn test_validator_and_policy_override() {
+        let mut config = config();
+        config
+            .user_roles
+            .insert("val".into(), vec!["validator".into()]);
+        let guard = AccessGuard::new();
+        guard.set_local_user(&config, "val");
+        assert!(guard.authorize(&config, "resume_workflow").is_ok());
+        assert!(guard.authorize(&config, "start_workflow").is_err());
+
+        config.policies.insert(
+            "validator".into(),
+            RolePolicy {
+                read: true,
+                allow: vec!["*_workflow".into(), "start_*".into()],
+                deny: vec![],
+            },
+        );
+        assert!(guard.authorize(&config, "start_workflow").is_ok());
+    }
+
+    #[test]
+    fn test_oidc_login() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut config = config();
+        config.oidc = Some(OidcConfig {
+            issuer: "https://idp.example".into(),
+            audience: Some("raise".into()),
+            public_key: hex::encode(key.verifying_key().to_bytes()),
+            roles_claim: "realm_access.roles".into(),
+        });
+        let exp = UtcClock::now().timestamp() + 3600;
+        let token = sign_token(
+            &key,
+            &json_value!({
+                "iss": "https://idp.example",
+                "aud": ["raise"],
+                "exp": exp,
+                "preferred_username": "carol",
+                "realm_access": { "roles": ["admin", "unknown"] }
+            }),
+        );
+
+        let guard = AccessGuard::new();
+        let identity = guard.login_oidc(&config, &token).unwrap();
+        assert_eq!(identity.user, "carol");
+        assert_eq!(identity.roles, vec![AccessRole::Admin]);
+        assert_eq!(identity.source, IdentitySource::Oidc);
+        assert!(guard.authorize(&config, "jsondb_drop_db").is_ok());
+
+        // Jeton altéré
+        let forged = token.replacen('.', ".e", 1);
+        assert!(guard.login_oidc(&config, &forged).is_err());
+
+        // Jeton expiré
+        let expired = sign_token(
+            &key,
+            &json_value!({ "iss": "https://idp.example", "aud": "raise", "exp": 1 }),
+        );
+        match guard.login_oidc(&config, &expired) {
+            Err(AppError::Structured(err)) => assert_eq!(err.code, "ERR_ACCESS_TOKEN_EXPIRED"),
+            _ => panic!("Le jeton expiré doit être refusé"),
+        }
+    }
+}
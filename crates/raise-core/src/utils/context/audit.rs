@@ -0,0 +1,549 @@
+// FICHIER : crates/raise-core/src/utils/context/audit.rs
+//! Journal d'audit unifié CLI/GUI : chaque commande modifiant l'état est tracée
+//! (qui, quoi, quand, empreinte des paramètres) dans une collection en ajout seul
+//! de la partition système. Les journaux quotidiens peuvent être scellés par une
+//! racine de Merkle ancrée dans le Ledger Mentis.
+
+use crate::blockchain::crypto::hashing::{calculate_hash, calculate_merkle_root};
+use crate::blockchain::crypto::signing::KeyPair;
+use crate::blockchain::storage::chain::Ledger;
+use crate::blockchain::storage::commit::{MentisCommit, Mutation, MutationOp};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
+use crate::json_db::storage::StorageEngine;
+use crate::utils::core::CalendarDate;
+use crate::utils::prelude::*;
+
+pub const AUDIT_COLLECTION: &str = "audit_log";
+pub const AUDIT_ANCHOR_COLLECTION: &str = "audit_anchors";
+
+/// Verbes qui modifient l'état : prioritaires sur les verbes de lecture.
+const MUTATING_VERBS: &[&str] = &[
+    "add", "alter", "anchor", "apply", "commit", "create", "delete", "drop", "import", "insert",
+    "load", "login", "logout", "register", "remove", "reset", "resume", "set", "start", "submit",
+    "train", "update", "upsert", "use", "write",
+];
+
+/// Verbes de consultation : jamais audités.
+const READ_ONLY_VERBS: &[&str] = &[
+    "analyze", "audit", "backends", "check", "evaluate", "explain", "get", "health", "history",
+    "impact", "info", "inspect", "lint", "list", "ping", "predict", "query", "search", "show",
+    "stats", "status", "topology", "usage", "verify", "whoami",
+];
+
+/// Arguments jamais inclus dans l'empreinte (évite un oracle hors-ligne sur les secrets).
+const SENSITIVE_PARAMS: &[&str] = &[
+    "api_key",
+    "passphrase",
+    "password",
+    "secret",
+    "token",
+    "value",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditChannel {
+    Cli,
+    Gui,
+    /// API HTTP du mode serveur.
+    Api,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+/// Une action utilisateur tracée.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct AuditEntry {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub timestamp: String,
+    /// Jour UTC (`YYYY-MM-DD`) : unité de scellement des digests.
+    pub day: String,
+    pub actor: String,
+    pub channel: AuditChannel,
+    /// Chemin de la commande (`jsondb.insert`, `jsondb_create_db`...).
+    pub action: String,
+    pub params_hash: String,
+    pub outcome: AuditOutcome,
+    #[serde(default)]
+    pub error_code: Option<String>,
+    /// Empreinte de l'entrée elle-même (détection d'altération).
+    pub entry_hash: String,
+}
+
+impl AuditEntry {
+    fn compute_hash(&self) -> String {
+        calculate_hash(&json_value!({
+            "id": self.id,
+            "timestamp": self.timestamp,
+            "actor": self.actor,
+            "channel": self.channel,
+            "action": self.action,
+            "params_hash": self.params_hash,
+            "outcome": self.outcome,
+            "error_code": self.error_code
+        }))
+    }
+}
+
+/// Filtres de consultation du journal.
+#[derive(Debug, Clone, Default)]
+pub struct AuditQuery {
+    pub actor: Option<String>,
+    /// Préfixe d'action (`jsondb` couvre `jsondb.insert`, `jsondb.drop-db`...).
+    pub action: Option<String>,
+    pub since: Option<CalendarDate>,
+    pub until: Option<CalendarDate>,
+    pub outcome: Option<AuditOutcome>,
+    pub limit: Option<usize>,
+}
+
+/// Ancrage d'un digest quotidien dans le Ledger.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct AuditAnchor {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub day: String,
+    pub entry_count: usize,
+    pub merkle_root: String,
+    pub anchored_at: String,
+    pub commit: MentisCommit,
+}
+
+/// Résultat du contrôle d'intégrité d'une journée.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct AuditVerification {
+    pub day: String,
+    pub entry_count: usize,
+    pub merkle_root: String,
+    pub anchored_root: Option<String>,
+    /// Entrées dont l'empreinte ne correspond plus au contenu.
+    pub tampered_entries: Vec<String>,
+    pub is_valid: bool,
+}
+
+/// Une commande modifie-t-elle l'état ? (`jsondb.list-collections` → non, `jsondb_create_db` → oui)
+pub fn is_state_changing(action: &str) -> bool {
+    let tokens: Vec<String> = action
+        .split(['.', '-', '_', ' '])
+        .map(|t| t.to_lowercase())
+        .collect();
+    if tokens.iter().any(|t| MUTATING_VERBS.contains(&t.as_str())) {
+        return true;
+    }
+    !tokens.iter().any(|t| READ_ONLY_VERBS.contains(&t.as_str()))
+}
+
+/// Analyse une journée `YYYY-MM-DD` (`today` accepté).
+pub fn parse_day(value: &str) -> RaiseResult<CalendarDate> {
+    if value == "today" {
+        return Ok(UtcClock::now().date_naive());
+    }
+    match CalendarDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(d) => Ok(d),
+        Err(e) => raise_error!(
+            "ERR_AUDIT_INVALID_DATE",
+            error = e,
+            context = json_value!({ "value": value, "expected": "YYYY-MM-DD" })
+        ),
+    }
+}
+
+/// Empreinte canonique des paramètres, arguments sensibles exclus.
+pub fn hash_params(params: &JsonValue) -> String {
+    let mut cleaned = params.clone();
+    strip_sensitive(&mut cleaned);
+    calculate_hash(&cleaned)
+}
+
+fn strip_sensitive(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(map) => {
+            map.retain(|k, _| !SENSITIVE_PARAMS.contains(&k.to_lowercase().as_str()));
+            map.values_mut().for_each(strip_sensitive);
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(strip_sensitive),
+        _ => {}
+    }
+}
+
+/// Accès au journal d'audit (partition système).
+#[derive(Clone)]
+pub struct AuditLog {
+    storage: SharedRef<StorageEngine>,
+}
+
+impl AuditLog {
+    pub fn new(storage: SharedRef<StorageEngine>) -> Self {
+        Self { storage }
+    }
+
+    fn manager(&self) -> CollectionsManager<'_> {
+        let config = AppConfig::get();
+        CollectionsManager::new(
+            &self.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        )
+    }
+
+    async fn ensure_collection(mgr: &CollectionsManager<'_>, name: &str) -> RaiseResult<()> {
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, name)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(name, &schema_uri).await
+    }
+
+    /// Ajoute une entrée au journal (jamais modifiée ni supprimée ensuite).
+    pub async fn record(
+        &self,
+        actor: &str,
+        channel: AuditChannel,
+        action: &str,
+        params: &JsonValue,
+        error_code: Option<&str>,
+    ) -> RaiseResult<AuditEntry> {
+        let now = UtcClock::now();
+        let mut entry = AuditEntry {
+            id: UniqueId::new_v4().to_string(),
+            timestamp: now.to_rfc3339(),
+            day: now.format("%Y-%m-%d").to_string(),
+            actor: actor.to_string(),
+            channel,
+            action: action.to_string(),
+            params_hash: hash_params(params),
+            outcome: match error_code {
+                Some(_) => AuditOutcome::Failure,
+                None => AuditOutcome::Success,
+            },
+            error_code: error_code.map(str::to_string),
+            entry_hash: String::new(),
+        };
+        entry.entry_hash = entry.compute_hash();
+
+        let mgr = self.manager();
+        Self::ensure_collection(&mgr, AUDIT_COLLECTION).await?;
+        let doc = json::serialize_to_value(&entry)?;
+        if let Err(e) = mgr.insert_raw(AUDIT_COLLECTION, &doc).await {
+            raise_error!(
+                "ERR_AUDIT_WRITE",
+                error = e,
+                context = json_value!({ "action": action, "actor": actor })
+            );
+        }
+        Ok(entry)
+    }
+
+    /// Consultation filtrée, entrées les plus récentes d'abord.
+    pub async fn query(&self, filter: &AuditQuery) -> RaiseResult<Vec<AuditEntry>> {
+        let mut conditions = Vec::new();
+        if let Some(actor) = &filter.actor {
+            conditions.push(Condition::eq("actor", json_value!(actor)));
+        }
+        if let Some(since) = filter.since {
+            conditions.push(Condition::gte("day", json_value!(since.to_string())));
+        }
+        if let Some(until) = filter.until {
+            conditions.push(Condition::lte("day", json_value!(until.to_string())));
+        }
+
+        let mut entries = self.load(conditions).await?;
+        entries.retain(|e| {
+            filter
+                .action
+                .as_deref()
+                .is_none_or(|prefix| e.action.starts_with(prefix))
+                && filter.outcome.is_none_or(|o| e.outcome == o)
+        });
+        entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        if let Some(limit) = filter.limit {
+            entries.truncate(limit);
+        }
+        Ok(entries)
+    }
+
+    async fn load(&self, conditions: Vec<Condition>) -> RaiseResult<Vec<AuditEntry>> {
+        let mgr = self.manager();
+        Self::ensure_collection(&mgr, AUDIT_COLLECTION).await?;
+
+        let mut query = Query::new(AUDIT_COLLECTION);
+        if !conditions.is_empty() {
+            query.filter = Some(QueryFilter {
+                operator: FilterOperator::And,
+                conditions,
+            });
+        }
+        let result = QueryEngine::new(&mgr).execute_query(query).await?;
+
+        let mut entries = Vec::with_capacity(result.documents.len());
+        for doc in result.documents {
+            match json::deserialize_from_value::<AuditEntry>(doc) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => raise_error!("ERR_AUDIT_CORRUPTED_ENTRY", error = e),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Entrées d'une journée dans un ordre stable (base du digest).
+    async fn entries_of_day(&self, day: CalendarDate) -> RaiseResult<Vec<AuditEntry>> {
+        let mut entries = self
+            .load(vec![Condition::eq("day", json_value!(day.to_string()))])
+            .await?;
+        entries.sort_by(|a, b| (&a.timestamp, &a.id).cmp(&(&b.timestamp, &b.id)));
+        Ok(entries)
+    }
+
+    fn merkle_root(entries: &[AuditEntry]) -> String {
+        let hashes: Vec<String> = entries.iter().map(|e| e.entry_hash.clone()).collect();
+        calculate_merkle_root(&hashes)
+    }
+
+    /// Scelle la journée : digest signé dans un commit Mentis, ajouté au Ledger s'il est fourni.
+    pub async fn anchor_day(
+        &self,
+        day: CalendarDate,
+        ledger: Option<&SyncMutex<Ledger>>,
+    ) -> RaiseResult<AuditAnchor> {
+        let anchor_id = format!("audit_digest_{}", day);
+        let mgr = self.manager();
+        Self::ensure_collection(&mgr, AUDIT_ANCHOR_COLLECTION).await?;
+
+        if mgr
+            .get_document(AUDIT_ANCHOR_COLLECTION, &anchor_id)
+            .await?
+            .is_some()
+        {
+            raise_error!(
+                "ERR_AUDIT_ALREADY_ANCHORED",
+                error = "Le digest de cette journée est déjà ancré",
+                context = json_value!({ "day": day.to_string() })
+            );
+        }
+
+        let entries = self.entries_of_day(day).await?;
+        if entries.is_empty() {
+            raise_error!(
+                "ERR_AUDIT_EMPTY_DAY",
+                error = "Aucune entrée d'audit pour cette journée",
+                context = json_value!({ "day": day.to_string() })
+            );
+        }
+        let merkle_root = Self::merkle_root(&entries);
+
+        let mutation = Mutation {
+            element_id: format!("urn:raise:audit:{}", day),
+            operation: MutationOp::Create,
+            payload: json_value!({
+                "day": day.to_string(),
+                "entry_count": entries.len(),
+                "merkle_root": merkle_root
+            }),
+        };
+        let keys = KeyPair::generate();
+
+        let commit = match ledger {
+            Some(state) => {
+                let mut ledger = match state.lock() {
+                    Ok(guard) => guard,
+                    Err(_) => raise_error!("ERR_LEDGER_LOCK", error = "Ledger lock poisoned"),
+                };
+                let commit =
+                    MentisCommit::new(vec![mutation], ledger.last_commit_hash.clone(), &keys);
+                ledger.append_commit(commit.clone())?;
+                commit
+            }
+            None => MentisCommit::new(vec![mutation], None, &keys),
+        };
+
+        let anchor = AuditAnchor {
+            id: anchor_id,
+            day: day.to_string(),
+            entry_count: entries.len(),
+            merkle_root,
+            anchored_at: UtcClock::now().to_rfc3339(),
+            commit,
+        };
+        mgr.insert_raw(AUDIT_ANCHOR_COLLECTION, &json::serialize_to_value(&anchor)?)
+            .await?;
+
+        user_success!(
+            "AUDIT_DAY_ANCHORED",
+            json_value!({
+                "day": anchor.day,
+                "entries": anchor.entry_count,
+                "merkle_root": anchor.merkle_root,
+                "commit_id": anchor.commit.id
+            })
+        );
+        Ok(anchor)
+    }
+
+    /// Recalcule les empreintes d'une journée et les confronte à l'ancrage éventuel.
+    pub async fn verify_day(&self, day: CalendarDate) -> RaiseResult<AuditVerification> {
+        let entries = self.entries_of_day(day).await?;
+        let tampered_entries: Vec<String> = entries
+            .iter()
+            .filter(|e| e.compute_hash() != e.entry_hash)
+            .map(|e| e.id.clone())
+            .collect();
+        let merkle_root = Self::merkle_root(&entries);
+
+        let mgr = self.manager();
+        let anchor = match mgr
+            .get_document(AUDIT_ANCHOR_COLLECTION, &format!("audit_digest_{}", day))
+            .await?
+        {
+            Some(doc) => Some(json::deserialize_from_value::<AuditAnchor>(doc)?),
+            None => None,
+        };
+
+        let anchor_ok = anchor.as_ref().is_none_or(|a| {
+            a.commit.verify()
+                && a.merkle_root == merkle_root
+                && a.commit
+                    .mutations
+                    .first()
+                    .and_then(|m| m.payload["merkle_root"].as_str())
+                    == Some(a.merkle_root.as_str())
+        });
+
+        Ok(AuditVerification {
+            day: day.to_string(),
+            entry_count: entries.len(),
+            is_valid: tampered_entries.is_empty() && anchor_ok,
+            anchored_root: anchor.map(|a| a.merkle_root),
+            merkle_root,
+            tampered_entries,
+        })
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    #[test]
+    fn test_state_changing_classification() {
+        assert!(is_state_changing("jsondb.insert"));
+        assert!(is_state_changing("jsondb_create_db"));
+        assert!(is_state_changing("utils.config.set"));
+        assert!(is_state_changing("workflow.update-status"));
+        assert!(!is_state_changing("jsondb.list-collections"));
+        assert!(!is_state_changing("session_get"));
+        assert!(!is_state_changing("audit.list"));
+        assert!(is_state_changing("audit.anchor"));
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert!(parse_day("2026-03-08").is_ok());
+        let Err(AppError::Structured(data)) = parse_day("08/03/2026") else {
+            panic!("Format de date invalide accepté");
+        };
+        assert_eq!(data.code, "ERR_AUDIT_INVALID_DATE");
+    }
+
+    #[test]
+    fn test_params_hash_ignores_secrets_and_key_order() {
+        let a = hash_params(&json_value!({ "key": "llm", "value": "sk-123" }));
+        let b = hash_params(&json_value!({ "value": "other", "key": "llm" }));
+        assert_eq!(a, b);
+        assert_ne!(a, hash_params(&json_value!({ "key": "qdrant" })));
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_record_query_anchor_and_verify() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let audit = AuditLog::new(SharedRef::new(sandbox.storage.clone()));
+
+        audit
+            .record(
+                "alice",
+                AuditChannel::Cli,
+                "jsondb.insert",
+                &json_value!({ "collection": "articles" }),
+                None,
+            )
+            .await?;
+        audit
+            .record(
+                "bob",
+                AuditChannel::Gui,
+                "jsondb_drop_db",
+                &json_value!({ "db": "sandbox" }),
+                Some("ERR_DB_NOT_FOUND"),
+            )
+            .await?;
+
+        let alice = audit
+            .query(&AuditQuery {
+                actor: Some("alice".into()),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(alice.len(), 1);
+        assert_eq!(alice[0].action, "jsondb.insert");
+
+        let failures = audit
+            .query(&AuditQuery {
+                outcome: Some(AuditOutcome::Failure),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].error_code.as_deref(), Some("ERR_DB_NOT_FOUND"));
+
+        let today = UtcClock::now().date_naive();
+        let ledger = SyncMutex::new(Ledger::new());
+        let anchor = audit.anchor_day(today, Some(&ledger)).await?;
+        assert_eq!(anchor.entry_count, 2);
+        assert_eq!(ledger.lock().unwrap().len(), 1);
+
+        let Err(AppError::Structured(data)) = audit.anchor_day(today, None).await else {
+            panic!("Un second ancrage de la même journée doit être refusé");
+        };
+        assert_eq!(data.code, "ERR_AUDIT_ALREADY_ANCHORED");
+
+        let report = audit.verify_day(today).await?;
+        assert!(report.is_valid);
+        assert_eq!(
+            report.anchored_root.as_deref(),
+            Some(report.merkle_root.as_str())
+        );
+
+        // Une entrée ajoutée après le scellement invalide le digest ancré
+        audit
+            .record(
+                "alice",
+                AuditChannel::Cli,
+                "jsondb.delete",
+                &json_value!({}),
+                None,
+            )
+            .await?;
+        assert!(!audit.verify_day(today).await?.is_valid);
+        Ok(())
+    }
+}
@@ -10,8 +10,9 @@ use crate::utils::core::{SharedRef, StaticCell, SyncRwLock};
 
 // 3. Données : Collections Sémantiques et Configuration
 use crate::utils::data::config::AppConfig;
-use crate::utils::data::json::{self, json_value};
-use crate::utils::data::{Deserializable, OrderedMap, Serializable, UnorderedMap};
+use crate::utils::data::json::{self, json_value, JsonValue};
+use crate::utils::data::{Deserializable, OrderedMap, Serializable, UniqueSet, UnorderedMap};
+use crate::utils::io::fs::{self, Path};
 
 // 4. Macros RAISE Globales
 use crate::{raise_error, user_info};
@@ -85,7 +86,8 @@ impl Translator {
         }
     }
 
-    /// Charge une langue spécifique depuis la collection 'locales' via le Catalogue Système Global.
+    /// Charge une langue et sa chaîne de repli (`fr-CA` → `fr` → `en`) depuis la collection
+    /// 'locales' via le Catalogue Système Global. La locale la plus spécifique l'emporte.
     pub async fn load_from_db(&mut self, storage: &StorageEngine, lang: &str) -> RaiseResult<()> {
         let app_config = AppConfig::get();
         let sys_domain = &app_config.mount_points.system.domain;
@@ -94,52 +96,69 @@ impl Translator {
         // Instanciation du manager sur la partition système pour lancer la recherche
         let manager = CollectionsManager::new(storage, sys_domain, sys_db);
 
-        // Recherche globale pilotée par le catalogue
-        let result = match manager.find_global_document("locales", lang).await {
-            Ok(res) => res,
-            Err(e) => raise_error!(
-                "ERR_I18N_DB_READ",
-                error = e.to_string(),
-                context = json_value!({ "requested_lang": lang, "action": "find_global_document" })
-            ),
-        };
+        let chain = fallback_chain(lang);
+        let mut merged = UnorderedMap::new();
+        let mut loaded = Vec::new();
+        let mut sources = Vec::new();
+
+        // Du plus générique au plus spécifique : chaque niveau écrase le précédent
+        for candidate in chain.iter().rev() {
+            // Recherche globale pilotée par le catalogue
+            let result = match manager.find_global_document("locales", candidate).await {
+                Ok(res) => res,
+                Err(e) => raise_error!(
+                    "ERR_I18N_DB_READ",
+                    error = e.to_string(),
+                    context = json_value!({ "requested_lang": candidate, "action": "find_global_document" })
+                ),
+            };
+
+            let Some((found_domain, found_db, doc_val)) = result else {
+                continue;
+            };
 
-        if let Some((found_domain, found_db, doc_val)) = result {
             // 🎯 Rigueur : Désérialisation stricte
             let document: LocaleDocument = match json::deserialize_from_value(doc_val) {
                 Ok(doc) => doc,
                 Err(e) => raise_error!(
                     "ERR_I18N_PARSE",
                     error = e.to_string(),
-                    context = json_value!({ "lang": lang })
+                    context = json_value!({ "lang": candidate })
                 ),
             };
 
-            self.translations = document
-                .translations
-                .into_iter()
-                .map(|item| (item.key, item.value))
-                .collect();
-
-            self.current_lang = document.handle.clone(); // Utilisation du handle
-
-            user_info!(
-                "I18N_LOCALE_LOADED",
-                json_value!({
-                    "language": lang,
-                    "key_count": self.translations.len(),
-                    "source": format!("{}/{}", found_domain, found_db)
-                })
+            merged.extend(
+                document
+                    .translations
+                    .into_iter()
+                    .map(|item| (item.key, item.value)),
             );
-
-            return Ok(());
+            loaded.push(document.handle); // Utilisation du handle
+            sources.push(format!("{}/{}", found_domain, found_db));
         }
 
-        raise_error!(
-            "ERR_I18N_NOT_FOUND",
-            error = format!("Langue '{}' introuvable globalement.", lang),
-            context = json_value!({ "lang": lang })
+        let Some(most_specific) = loaded.last().cloned() else {
+            raise_error!(
+                "ERR_I18N_NOT_FOUND",
+                error = format!("Langue '{}' introuvable globalement.", lang),
+                context = json_value!({ "lang": lang, "fallback_chain": chain })
+            );
+        };
+
+        self.translations = merged;
+        self.current_lang = most_specific;
+
+        user_info!(
+            "I18N_LOCALE_LOADED",
+            json_value!({
+                "language": lang,
+                "resolved": loaded,
+                "key_count": self.translations.len(),
+                "source": sources
+            })
         );
+
+        Ok(())
     }
 
     pub fn t(&self, key: &str) -> String {
@@ -148,6 +167,437 @@ impl Translator {
             None => key.to_string(), // Fallback sur la clé technique si absente
         }
     }
+
+    /// Traduction avec arguments nommés (`{name}`) et règles ICU (`plural` / `select`).
+    pub fn t_with(&self, key: &str, args: &JsonValue) -> String {
+        format_message(&self.t(key), args, &self.current_lang)
+    }
+}
+
+// =========================================================================
+// CHAÎNE DE REPLI DES LOCALES
+// =========================================================================
+
+/// Locale terminale de toute chaîne de repli.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// `fr_CA` / `fr-CA` → `["fr-CA", "fr", "en"]`
+pub fn fallback_chain(lang: &str) -> Vec<String> {
+    let normalized = lang.trim().replace('_', "-");
+    let parts: Vec<&str> = normalized.split('-').filter(|p| !p.is_empty()).collect();
+
+    let mut chain: Vec<String> = (1..=parts.len())
+        .rev()
+        .map(|n| parts[..n].join("-"))
+        .collect();
+    if !chain.iter().any(|l| l == DEFAULT_LOCALE) {
+        chain.push(DEFAULT_LOCALE.to_string());
+    }
+    chain
+}
+
+// =========================================================================
+// RÈGLES DE PLURIEL (CLDR, catégories cardinales)
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Catégorie de pluriel d'un nombre pour une langue (sous-ensemble CLDR des langues courantes).
+pub fn plural_category(lang: &str, n: f64) -> PluralCategory {
+    let base = lang.split(['-', '_']).next().unwrap_or(lang).to_lowercase();
+    let is_int = n.fract() == 0.0;
+    let i = n.abs().trunc() as u64;
+    let (m10, m100) = (i % 10, i % 100);
+
+    match base.as_str() {
+        // Pas de pluriel grammatical
+        "ja" | "zh" | "ko" | "th" | "vi" | "id" => PluralCategory::Other,
+        // Le singulier couvre 0 et 1 (et 1,5)
+        "fr" | "pt" => {
+            if i <= 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "ru" | "uk" | "be" if is_int => {
+            if m10 == 1 && m100 != 11 {
+                PluralCategory::One
+            } else if (2..=4).contains(&m10) && !(12..=14).contains(&m100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "pl" if is_int => {
+            if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&m10) && !(12..=14).contains(&m100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "cs" | "sk" if is_int => match i {
+            1 => PluralCategory::One,
+            2..=4 => PluralCategory::Few,
+            _ => PluralCategory::Other,
+        },
+        "ar" if is_int => match (i, m100) {
+            (0, _) => PluralCategory::Zero,
+            (1, _) => PluralCategory::One,
+            (2, _) => PluralCategory::Two,
+            (_, 3..=10) => PluralCategory::Few,
+            (_, 11..=99) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        },
+        // en, de, it, es, nl, sv... : "one" uniquement pour l'entier 1
+        _ => {
+            if is_int && i == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+    }
+}
+
+// =========================================================================
+// FORMATAGE DES MESSAGES (interpolation nommée + ICU plural/select)
+// =========================================================================
+
+/// Rend un gabarit : `{name}`, `{count, plural, =0 {aucun} one {# élément} other {# éléments}}`,
+/// `{role, select, admin {...} other {...}}`. Un argument absent laisse le gabarit intact.
+pub fn format_message(template: &str, args: &JsonValue, lang: &str) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some(len) = matching_brace(&rest[start..]) else {
+            break; // Accolade orpheline : rendu littéral du reste
+        };
+        let block = &rest[start..start + len];
+        match render_placeholder(&block[1..len - 1], args, lang) {
+            Some(rendered) => out.push_str(&rendered),
+            None => out.push_str(block),
+        }
+        rest = &rest[start + len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Longueur (en octets) du bloc `{...}` équilibré en tête de `s`.
+fn matching_brace(s: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `one {# item} other {# items}` → [("one", "# item"), ("other", "# items")]
+fn parse_options(options: &str) -> Option<Vec<(String, String)>> {
+    let mut parsed = Vec::new();
+    let mut rest = options.trim_start();
+    while !rest.is_empty() {
+        let selector_end = rest.find(|c: char| c.is_whitespace() || c == '{')?;
+        let selector = &rest[..selector_end];
+        rest = rest[selector_end..].trim_start();
+        if !rest.starts_with('{') || selector.is_empty() {
+            return None;
+        }
+        let len = matching_brace(rest)?;
+        parsed.push((selector.to_string(), rest[1..len - 1].to_string()));
+        rest = rest[len..].trim_start();
+    }
+    Some(parsed)
+}
+
+fn display_arg(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => s.clone(),
+        JsonValue::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn display_number(n: f64) -> String {
+    if n.fract() == 0.0 {
+        format!("{}", n as i64)
+    } else {
+        n.to_string()
+    }
+}
+
+fn render_placeholder(inner: &str, args: &JsonValue, lang: &str) -> Option<String> {
+    let mut parts = inner.splitn(3, ',');
+    let name = parts.next()?.trim();
+    let value = args.get(name);
+
+    match (parts.next().map(str::trim), parts.next()) {
+        (None, _) => value.map(display_arg),
+        (Some("plural"), Some(options)) => {
+            let n = value.and_then(|v| v.as_f64())?;
+            let options = parse_options(options)?;
+            let exact = format!("={}", display_number(n));
+            let category = plural_category(lang, n).as_str();
+            let branch = [exact.as_str(), category, "other"]
+                .iter()
+                .find_map(|sel| options.iter().find(|(s, _)| s == sel))?;
+            Some(format_message(
+                &branch.1.replace('#', &display_number(n)),
+                args,
+                lang,
+            ))
+        }
+        (Some("select"), Some(options)) => {
+            let selected = value.map(display_arg).unwrap_or_default();
+            let options = parse_options(options)?;
+            let branch = [selected.as_str(), "other"]
+                .iter()
+                .find_map(|sel| options.iter().find(|(s, _)| s == sel))?;
+            Some(format_message(&branch.1, args, lang))
+        }
+        _ => None,
+    }
+}
+
+/// Noms des arguments attendus par un gabarit (erreur si la syntaxe ICU est invalide).
+pub fn message_arguments(template: &str) -> Result<UniqueSet<String>, String> {
+    let mut names = UniqueSet::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        if rest[..start].contains('}') {
+            return Err("accolade fermante orpheline".to_string());
+        }
+        let Some(len) = matching_brace(&rest[start..]) else {
+            return Err("accolade non fermée".to_string());
+        };
+        let inner = &rest[start + 1..start + len - 1];
+        let mut parts = inner.splitn(3, ',');
+        let name = parts.next().unwrap_or_default().trim();
+        if name.is_empty() {
+            return Err("argument sans nom".to_string());
+        }
+        names.insert(name.to_string());
+
+        match (parts.next().map(str::trim), parts.next()) {
+            (None, _) => {}
+            (Some(kind @ ("plural" | "select")), Some(options)) => {
+                let options = parse_options(options)
+                    .ok_or_else(|| format!("options '{}' illisibles pour '{}'", kind, name))?;
+                if !options.iter().any(|(s, _)| s == "other") {
+                    return Err(format!("branche 'other' manquante pour '{}'", name));
+                }
+                for (_, branch) in options {
+                    names.extend(message_arguments(&branch)?);
+                }
+            }
+            (Some(kind), _) => return Err(format!("type d'argument inconnu : {}", kind)),
+        }
+        rest = &rest[start + len..];
+    }
+
+    if rest.contains('}') {
+        return Err("accolade fermante orpheline".to_string());
+    }
+    Ok(names)
+}
+
+// =========================================================================
+// LINT DES FICHIERS DE LOCALES
+// =========================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum I18nLintKind {
+    /// Clé présente dans d'autres locales mais absente de celle-ci.
+    MissingKey,
+    /// Arguments différents de ceux de la locale de référence.
+    ArgumentMismatch,
+    /// Gabarit ICU invalide.
+    InvalidSyntax,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct I18nLintIssue {
+    pub locale: String,
+    pub key: String,
+    pub kind: I18nLintKind,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct I18nLintReport {
+    pub locales: Vec<String>,
+    pub key_count: usize,
+    pub issues: Vec<I18nLintIssue>,
+}
+
+impl I18nLintReport {
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Compare des catalogues de traductions (locale → clé → valeur).
+/// Les locales régionales (`fr-CA`) ne sont pas tenues d'être complètes : elles se replient.
+pub fn lint_locales(
+    bundles: &OrderedMap<String, UnorderedMap<String, String>>,
+    reference: &str,
+) -> I18nLintReport {
+    let all_keys: OrderedMap<&str, ()> = bundles
+        .values()
+        .flat_map(|b| b.keys().map(|k| (k.as_str(), ())))
+        .collect();
+    let reference_bundle = bundles.get(reference);
+    let mut issues = Vec::new();
+
+    for (locale, bundle) in bundles {
+        let is_regional = locale.contains('-');
+
+        for key in all_keys.keys() {
+            let Some(value) = bundle.get(*key) else {
+                if !is_regional {
+                    issues.push(I18nLintIssue {
+                        locale: locale.clone(),
+                        key: key.to_string(),
+                        kind: I18nLintKind::MissingKey,
+                        detail: "clé absente".to_string(),
+                    });
+                }
+                continue;
+            };
+
+            let arguments = match message_arguments(value) {
+                Ok(a) => a,
+                Err(detail) => {
+                    issues.push(I18nLintIssue {
+                        locale: locale.clone(),
+                        key: key.to_string(),
+                        kind: I18nLintKind::InvalidSyntax,
+                        detail,
+                    });
+                    continue;
+                }
+            };
+
+            if locale == reference {
+                continue;
+            }
+            let expected = reference_bundle
+                .and_then(|b| b.get(*key))
+                .and_then(|v| message_arguments(v).ok());
+            if let Some(expected) = expected {
+                if expected != arguments {
+                    let mut expected: Vec<_> = expected.into_iter().collect();
+                    let mut found: Vec<_> = arguments.into_iter().collect();
+                    expected.sort();
+                    found.sort();
+                    issues.push(I18nLintIssue {
+                        locale: locale.clone(),
+                        key: key.to_string(),
+                        kind: I18nLintKind::ArgumentMismatch,
+                        detail: format!("attendu {:?}, trouvé {:?}", expected, found),
+                    });
+                }
+            }
+        }
+    }
+
+    I18nLintReport {
+        locales: bundles.keys().cloned().collect(),
+        key_count: all_keys.len(),
+        issues,
+    }
+}
+
+/// Lit les fichiers `<locale>.json` d'un dossier (format d'import des locales) puis les compare.
+pub async fn lint_locale_dir(dir: &Path, reference: &str) -> RaiseResult<I18nLintReport> {
+    let mut bundles = OrderedMap::new();
+
+    for entry in fs::read_dir_sync(dir)?.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let raw: JsonValue = fs::read_json_async(&path).await?;
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default()
+            .to_string();
+
+        // Fichier brut, ou liste d'opérations `{"type": "upsert", "document": {...}}`
+        let documents = match raw.as_array() {
+            Some(ops) => ops
+                .iter()
+                .filter_map(|op| op.get("document").cloned())
+                .collect(),
+            None => vec![raw],
+        };
+
+        for document in documents {
+            let locale = document
+                .get("handle")
+                .and_then(|h| h.as_str())
+                .unwrap_or(&stem)
+                .to_string();
+            let bundle: &mut UnorderedMap<String, String> = bundles.entry(locale).or_default();
+            if let Some(items) = document.get("translations").and_then(|t| t.as_array()) {
+                for item in items {
+                    if let (Some(k), Some(v)) = (
+                        item.get("key").and_then(|k| k.as_str()),
+                        item.get("value").and_then(|v| v.as_str()),
+                    ) {
+                        bundle.insert(k.to_string(), v.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    if bundles.is_empty() {
+        raise_error!(
+            "ERR_I18N_LINT_NO_LOCALE",
+            error = "Aucun fichier de locale trouvé.",
+            context = json_value!({ "dir": dir.to_string_lossy() })
+        );
+    }
+    Ok(lint_locales(&bundles, reference))
 }
 
 // --- INTERFACE PUBLIQUE ---
@@ -198,6 +648,17 @@ pub fn t(key: &str) -> String {
     }
 }
 
+/// Variante de `t` avec arguments nommés : `t_with("FILES_COUNT", &json_value!({"count": 3}))`.
+pub fn t_with(key: &str, args: &JsonValue) -> String {
+    match TRANSLATOR.get() {
+        Some(arc) => match arc.read() {
+            Ok(read_guard) => read_guard.t_with(key, args),
+            Err(_) => key.to_string(),
+        },
+        None => format_message(key, args, DEFAULT_LOCALE),
+    }
+}
+
 // --- TESTS UNITAIRES ---
 #[cfg(test)]
 mod tests {
@@ -274,4 +735,75 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_fallback_chain_regional_to_default() {
+        assert_eq!(fallback_chain("fr_CA"), vec!["fr-CA", "fr", "en"]);
+        assert_eq!(fallback_chain("en-GB"), vec!["en-GB", "en"]);
+        assert_eq!(fallback_chain("de"), vec!["de", "en"]);
+    }
+
+    #[test]
+    fn test_format_message_interpolation_and_plurals() {
+        let template =
+            "{user} a {count, plural, =0 {aucun fichier} one {# fichier} other {# fichiers}}";
+
+        let render = |count: i64| {
+            format_message(
+                template,
+                &json_value!({ "user": "Ada", "count": count }),
+                "fr",
+            )
+        };
+        assert_eq!(render(0), "Ada a aucun fichier");
+        assert_eq!(render(1), "Ada a 1 fichier");
+        assert_eq!(render(5), "Ada a 5 fichiers");
+
+        // Règles propres à la langue : 0 est "other" en anglais, "many" en russe
+        assert_eq!(plural_category("en", 0.0), PluralCategory::Other);
+        assert_eq!(plural_category("fr-CA", 0.0), PluralCategory::One);
+        assert_eq!(plural_category("ru", 5.0), PluralCategory::Many);
+        assert_eq!(plural_category("ru", 22.0), PluralCategory::Few);
+
+        // Select + argument manquant laissé intact
+        assert_eq!(
+            format_message(
+                "{role, select, admin {Accès total} other {Lecture}} ({missing})",
+                &json_value!({ "role": "admin" }),
+                "fr"
+            ),
+            "Accès total ({missing})"
+        );
+    }
+
+    #[test]
+    fn test_lint_detects_missing_keys_and_argument_mismatch() {
+        let bundle = |pairs: &[(&str, &str)]| -> UnorderedMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        let mut bundles = OrderedMap::new();
+        bundles.insert(
+            "en".to_string(),
+            bundle(&[("HELLO", "Hello {name}"), ("BYE", "Bye")]),
+        );
+        bundles.insert("fr".to_string(), bundle(&[("HELLO", "Bonjour {nom}")]));
+        bundles.insert("fr-CA".to_string(), bundle(&[("BYE", "Salut {")]));
+
+        let report = lint_locales(&bundles, "en");
+        assert_eq!(report.key_count, 2);
+
+        let kinds: Vec<(&str, &str, I18nLintKind)> = report
+            .issues
+            .iter()
+            .map(|i| (i.locale.as_str(), i.key.as_str(), i.kind))
+            .collect();
+        assert!(kinds.contains(&("fr", "BYE", I18nLintKind::MissingKey)));
+        assert!(kinds.contains(&("fr", "HELLO", I18nLintKind::ArgumentMismatch)));
+        assert!(kinds.contains(&("fr-CA", "BYE", I18nLintKind::InvalidSyntax)));
+        // Une locale régionale n'a pas à redéfinir HELLO
+        assert!(!kinds.contains(&("fr-CA", "HELLO", I18nLintKind::MissingKey)));
+    }
 }
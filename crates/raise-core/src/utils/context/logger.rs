@@ -5,6 +5,7 @@ use crate::utils::data::config::AppConfig;
 use crate::utils::io::fs;
 
 // 2. Core : Concurrence et Initialisation
+use crate::utils::context::telemetry::build_otlp_layer;
 use crate::utils::core::InitGuard;
 use tracing_subscriber::Layer;
 // 3. Core : Moteur de Logs (La salle des machines)
@@ -14,6 +15,7 @@ use crate::utils::core::logs::{
     LogFilter, LogFormatter, LogInitExt, LogLayerExt, LogRegistry, RollingStrategy,
 };
 use crate::utils::data::json::json_value;
+use crate::utils::data::secrets::RedactingWriter;
 
 static INIT: InitGuard = InitGuard::new();
 
@@ -33,7 +35,7 @@ pub fn init_logging() {
         let file_appender = RollingStrategy::daily(&log_dir, "raise.log");
         let file_layer = LogFormatter::layer()
             .json()
-            .with_writer(file_appender)
+            .with_writer(RedactingWriter(file_appender))
             .with_target(true)
             .with_thread_ids(true)
             .with_file(true)
@@ -44,10 +46,20 @@ pub fn init_logging() {
 
         let console_layer = LogFormatter::layer()
             .compact()
+            .with_writer(RedactingWriter(std::io::stdout))
             .with_target(false)
             .with_filter(env_filter);
 
-        let registry = LogRegistry().with(file_layer).with(console_layer);
+        // Export OTLP optionnel (désactivé tant que `telemetry.enabled` est faux)
+        let otlp_layer = build_otlp_layer(&config.telemetry);
+        let otlp_endpoint = otlp_layer
+            .as_ref()
+            .map(|_| config.telemetry.otlp_endpoint.clone());
+
+        let registry = LogRegistry()
+            .with(file_layer)
+            .with(console_layer)
+            .with(otlp_layer);
 
         if let Err(_e) = registry.try_init() {
             return;
@@ -56,6 +68,12 @@ pub fn init_logging() {
             "MSG_LOGGER_INITIALIZED",
             json_value!({ "log_dir": log_dir.to_string_lossy() })
         );
+        if let Some(endpoint) = otlp_endpoint {
+            user_info!(
+                "MSG_TELEMETRY_EXPORT_ENABLED",
+                json_value!({ "endpoint": endpoint })
+            );
+        }
     });
 }
 
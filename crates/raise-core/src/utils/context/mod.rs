@@ -1,19 +1,28 @@
 // FICHIER : src-tauri/src/utils/context/mod.rs
 
+pub mod access;
+pub mod audit;
 pub mod i18n;
 pub mod logger;
 pub mod session;
+pub mod telemetry;
+pub mod tokens;
 
 // =========================================================================
 // FAÇADE `context` : État Global et Observabilité (AI-Ready)
 // =========================================================================
 // 🤖 IA NOTE : Ce module gère le "Contexte d'Exécution" de l'application :
 // - Qui utilise l'application ? (Session)
+// - Qu'a-t-il le droit de faire ? (Contrôle d'accès par rôle, jetons d'API)
 // - Dans quelle langue ? (i18n)
-// - Que se passe-t-il ? (Logger)
+// - Que se passe-t-il ? (Logger, Télémétrie OTLP)
+// - Qui a modifié quoi ? (Journal d'audit)
 // L'état est souvent protégé par des verrous asynchrones (AsyncRwLock).
 
 pub use crate::utils::data::config::AppConfig;
-pub use i18n::{init_i18n, t};
+pub use access::{AccessGuard, AccessIdentity, AccessRole, IdentitySource};
+pub use audit::{AuditChannel, AuditEntry, AuditLog, AuditQuery};
+pub use i18n::{init_i18n, t, t_with};
 pub use logger::init_logging;
 pub use session::{Session, SessionManager, SessionStatus};
+pub use tokens::{ApiTokenClaims, ApiTokenIssuer};
@@ -0,0 +1,556 @@
+// FICHIER : crates/raise-core/src/utils/context/telemetry.rs
+//! Export OpenTelemetry (OTLP/HTTP JSON) branché sur le moteur `tracing` du logger.
+//! Chaque span reçoit un contexte de trace hérité de son parent (propagation entre
+//! json_db, ai, workflow_engine et blockchain), est échantillonné par module, puis
+//! expédié périodiquement vers un collecteur (OTel Collector, Grafana Alloy/Tempo...).
+
+use crate::utils::data::config::TelemetryConfig;
+use crate::utils::network::http_types::BlockingHttpClient;
+use crate::utils::prelude::*;
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context as LayerContext;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Au-delà, les spans les plus récents sont abandonnés (collecteur injoignable).
+const MAX_BUFFERED_SPANS: usize = 4096;
+const INSTRUMENTATION_SCOPE: &str = "raise-core";
+const CRATE_PREFIX: &str = "raise_core::";
+
+// =========================================================================
+// ÉCHANTILLONNAGE PAR MODULE
+// =========================================================================
+
+#[derive(Debug, Clone)]
+pub struct ModuleSampler {
+    default_ratio: f64,
+    per_module: OrderedMap<String, f64>,
+}
+
+impl ModuleSampler {
+    pub fn from_config(config: &TelemetryConfig) -> Self {
+        Self {
+            default_ratio: config.default_sample_ratio,
+            per_module: config.module_sampling.clone(),
+        }
+    }
+
+    /// Ratio applicable : la règle la plus spécifique (préfixe de chemin le plus long) gagne.
+    pub fn ratio_for(&self, module: &str) -> f64 {
+        self.per_module
+            .iter()
+            .filter(|(prefix, _)| {
+                module == prefix.as_str() || module.starts_with(&format!("{}::", prefix))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, ratio)| *ratio)
+            .unwrap_or(self.default_ratio)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Décision déterministe dérivée du trace_id : une trace est gardée ou jetée en entier.
+    pub fn should_sample(&self, trace_id: u128, module: &str) -> bool {
+        let ratio = self.ratio_for(module);
+        if ratio >= 1.0 {
+            return true;
+        }
+        if ratio <= 0.0 {
+            return false;
+        }
+        ((trace_id as u64) as f64 / u64::MAX as f64) < ratio
+    }
+}
+
+/// `raise_core::json_db::query::executor` → `json_db::query::executor`
+fn module_of(target: &str) -> String {
+    target
+        .strip_prefix(CRATE_PREFIX)
+        .unwrap_or(target)
+        .to_string()
+}
+
+// =========================================================================
+// COLLECTE DES SPANS
+// =========================================================================
+
+/// Contexte de trace attaché aux extensions du span (lu par les spans enfants).
+#[derive(Debug, Clone)]
+struct SpanContext {
+    trace_id: u128,
+    span_id: u64,
+    parent_span_id: Option<u64>,
+    sampled: bool,
+    name: String,
+    module: String,
+    start_unix_nano: i64,
+    started: TimeInstant,
+    attributes: OrderedMap<String, String>,
+}
+
+/// Span terminé, prêt à être exporté.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinishedSpan {
+    pub trace_id: u128,
+    pub span_id: u64,
+    pub parent_span_id: Option<u64>,
+    pub name: String,
+    pub module: String,
+    pub start_unix_nano: i64,
+    pub end_unix_nano: i64,
+    pub attributes: OrderedMap<String, String>,
+}
+
+/// Agrégats cumulés par module racine (exportés en métriques OTLP `sum`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModuleMetrics {
+    pub span_count: u64,
+    pub total_duration_ms: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct TelemetryBuffer {
+    pub spans: Vec<FinishedSpan>,
+    pub metrics: OrderedMap<String, ModuleMetrics>,
+    pub dropped_spans: u64,
+}
+
+struct AttributeVisitor<'a>(&'a mut OrderedMap<String, String>);
+
+impl Visit for AttributeVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn FmtDebug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+fn now_unix_nano() -> i64 {
+    UtcClock::now().timestamp_nanos_opt().unwrap_or_default()
+}
+
+fn new_span_id() -> u64 {
+    // Un span_id OTLP ne doit jamais être nul
+    (UniqueId::new_v4().as_u128() as u64).max(1)
+}
+
+/// Couche `tracing` alimentant le tampon d'export OTLP.
+pub struct OtlpLayer {
+    sampler: ModuleSampler,
+    buffer: SharedRef<SyncMutex<TelemetryBuffer>>,
+}
+
+impl OtlpLayer {
+    pub fn new(sampler: ModuleSampler) -> Self {
+        Self {
+            sampler,
+            buffer: SharedRef::new(SyncMutex::new(TelemetryBuffer::default())),
+        }
+    }
+
+    /// Tampon partagé avec l'exporteur.
+    pub fn buffer(&self) -> SharedRef<SyncMutex<TelemetryBuffer>> {
+        self.buffer.clone()
+    }
+}
+
+impl<S> Layer<S> for OtlpLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: LayerContext<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let module = module_of(attrs.metadata().target());
+
+        // Propagation : un span enfant hérite de la trace et de la décision du parent
+        let inherited = span.parent().and_then(|parent| {
+            parent
+                .extensions()
+                .get::<SpanContext>()
+                .map(|p| (p.trace_id, p.span_id, p.sampled))
+        });
+        let (trace_id, parent_span_id, sampled) = match inherited {
+            Some((trace_id, parent_id, sampled)) => (trace_id, Some(parent_id), sampled),
+            None => {
+                let trace_id = UniqueId::new_v4().as_u128();
+                (
+                    trace_id,
+                    None,
+                    self.sampler.should_sample(trace_id, &module),
+                )
+            }
+        };
+
+        let mut attributes = OrderedMap::new();
+        attrs.record(&mut AttributeVisitor(&mut attributes));
+
+        span.extensions_mut().insert(SpanContext {
+            trace_id,
+            span_id: new_span_id(),
+            parent_span_id,
+            sampled,
+            name: attrs.metadata().name().to_string(),
+            module,
+            start_unix_nano: now_unix_nano(),
+            started: TimeInstant::now(),
+            attributes,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: LayerContext<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(context) = span.extensions_mut().get_mut::<SpanContext>() {
+                values.record(&mut AttributeVisitor(&mut context.attributes));
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: LayerContext<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(context) = span.extensions_mut().remove::<SpanContext>() else {
+            return;
+        };
+
+        let elapsed = context.started.elapsed();
+        let mut buffer = match self.buffer.lock() {
+            Ok(b) => b,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        // Les métriques couvrent tous les spans, échantillonnés ou non
+        let root_module = context
+            .module
+            .split("::")
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let metrics = buffer.metrics.entry(root_module).or_default();
+        metrics.span_count += 1;
+        metrics.total_duration_ms += elapsed.as_secs_f64() * 1000.0;
+
+        if !context.sampled {
+            return;
+        }
+        if buffer.spans.len() >= MAX_BUFFERED_SPANS {
+            buffer.dropped_spans += 1;
+            return;
+        }
+        buffer.spans.push(FinishedSpan {
+            trace_id: context.trace_id,
+            span_id: context.span_id,
+            parent_span_id: context.parent_span_id,
+            name: context.name,
+            module: context.module,
+            start_unix_nano: context.start_unix_nano,
+            end_unix_nano: context.start_unix_nano + elapsed.as_nanos() as i64,
+            attributes: context.attributes,
+        });
+    }
+}
+
+// =========================================================================
+// ENCODAGE OTLP/JSON
+// =========================================================================
+
+fn otlp_attributes<'a>(pairs: impl Iterator<Item = (&'a str, &'a str)>) -> JsonValue {
+    JsonValue::Array(
+        pairs
+            .map(|(k, v)| json_value!({ "key": k, "value": { "stringValue": v } }))
+            .collect(),
+    )
+}
+
+fn otlp_resource(service_name: &str) -> JsonValue {
+    json_value!({
+        "attributes": otlp_attributes(
+            [
+                ("service.name", service_name),
+                ("service.version", env!("CARGO_PKG_VERSION")),
+            ]
+            .into_iter()
+        )
+    })
+}
+
+/// Corps d'une requête `POST /v1/traces`.
+pub fn encode_traces(service_name: &str, spans: &[FinishedSpan]) -> JsonValue {
+    let encoded: Vec<JsonValue> = spans
+        .iter()
+        .map(|s| {
+            let mut attributes: Vec<(&str, &str)> = vec![("code.namespace", s.module.as_str())];
+            attributes.extend(s.attributes.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+            json_value!({
+                "traceId": format!("{:032x}", s.trace_id),
+                "spanId": format!("{:016x}", s.span_id),
+                "parentSpanId": s.parent_span_id.map(|p| format!("{:016x}", p)).unwrap_or_default(),
+                "name": s.name,
+                "kind": 1,
+                "startTimeUnixNano": s.start_unix_nano.to_string(),
+                "endTimeUnixNano": s.end_unix_nano.to_string(),
+                "attributes": otlp_attributes(attributes.into_iter())
+            })
+        })
+        .collect();
+
+    json_value!({
+        "resourceSpans": [{
+            "resource": otlp_resource(service_name),
+            "scopeSpans": [{ "scope": { "name": INSTRUMENTATION_SCOPE }, "spans": encoded }]
+        }]
+    })
+}
+
+/// Corps d'une requête `POST /v1/metrics` (sommes cumulatives par module).
+pub fn encode_metrics(
+    service_name: &str,
+    metrics: &OrderedMap<String, ModuleMetrics>,
+) -> JsonValue {
+    let now = now_unix_nano().to_string();
+    let points = |value: &dyn Fn(&ModuleMetrics) -> JsonValue| -> Vec<JsonValue> {
+        metrics
+            .iter()
+            .map(|(module, m)| {
+                let mut point = json_value!({
+                    "attributes": otlp_attributes([("module", module.as_str())].into_iter()),
+                    "timeUnixNano": now
+                });
+                if let (Some(obj), Some(extra)) = (point.as_object_mut(), value(m).as_object()) {
+                    obj.extend(extra.clone());
+                }
+                point
+            })
+            .collect()
+    };
+
+    json_value!({
+        "resourceMetrics": [{
+            "resource": otlp_resource(service_name),
+            "scopeMetrics": [{
+                "scope": { "name": INSTRUMENTATION_SCOPE },
+                "metrics": [
+                    {
+                        "name": "raise.spans.count",
+                        "unit": "1",
+                        "sum": {
+                            "aggregationTemporality": 2,
+                            "isMonotonic": true,
+                            "dataPoints": points(&|m| json_value!({ "asInt": m.span_count.to_string() }))
+                        }
+                    },
+                    {
+                        "name": "raise.spans.duration",
+                        "unit": "ms",
+                        "sum": {
+                            "aggregationTemporality": 2,
+                            "isMonotonic": true,
+                            "dataPoints": points(&|m| json_value!({ "asDouble": m.total_duration_ms }))
+                        }
+                    }
+                ]
+            }]
+        }]
+    })
+}
+
+// =========================================================================
+// EXPORTEUR
+// =========================================================================
+
+/// Construit la couche OTLP et démarre l'exporteur si la télémétrie est activée.
+pub fn build_otlp_layer(config: &TelemetryConfig) -> Option<OtlpLayer> {
+    if !config.enabled {
+        return None;
+    }
+
+    let layer = OtlpLayer::new(ModuleSampler::from_config(config));
+    spawn_exporter(config.clone(), layer.buffer());
+    Some(layer)
+}
+
+/// Thread dédié (hors runtime Tokio : le logger est initialisé avant celui-ci).
+fn spawn_exporter(config: TelemetryConfig, buffer: SharedRef<SyncMutex<TelemetryBuffer>>) {
+    let interval = TimeDuration::from_secs(config.export_interval_secs.max(1));
+    let endpoint = config.otlp_endpoint.trim_end_matches('/').to_string();
+
+    let spawned = std::thread::Builder::new()
+        .name("raise-otlp-exporter".into())
+        .spawn(move || {
+            let client = match BlockingHttpClient::builder()
+                .timeout(TimeDuration::from_secs(10))
+                .build()
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    user_warn!(
+                        "WRN_TELEMETRY_CLIENT_INIT",
+                        json_value!({ "error": e.to_string() })
+                    );
+                    return;
+                }
+            };
+
+            let mut failing = false;
+            loop {
+                std::thread::sleep(interval);
+
+                let (spans, metrics, dropped) = {
+                    let mut guard = match buffer.lock() {
+                        Ok(b) => b,
+                        Err(poisoned) => poisoned.into_inner(),
+                    };
+                    let dropped = std::mem::take(&mut guard.dropped_spans);
+                    (
+                        std::mem::take(&mut guard.spans),
+                        guard.metrics.clone(),
+                        dropped,
+                    )
+                };
+
+                let mut requests = vec![(
+                    format!("{}/v1/metrics", endpoint),
+                    encode_metrics(&config.service_name, &metrics),
+                )];
+                if !spans.is_empty() {
+                    requests.push((
+                        format!("{}/v1/traces", endpoint),
+                        encode_traces(&config.service_name, &spans),
+                    ));
+                }
+
+                let mut error = None;
+                for (url, body) in requests {
+                    match client.post(&url).json(&body).send() {
+                        Ok(resp) if resp.status().is_success() => {}
+                        Ok(resp) => error = Some(format!("{} → HTTP {}", url, resp.status())),
+                        Err(e) => error = Some(e.to_string()),
+                    }
+                }
+
+                // Un seul avertissement par période d'indisponibilité du collecteur
+                match error {
+                    Some(e) if !failing => {
+                        failing = true;
+                        user_warn!(
+                            "WRN_TELEMETRY_EXPORT_FAILED",
+                            json_value!({ "endpoint": endpoint, "error": e, "dropped_spans": dropped })
+                        );
+                    }
+                    None if failing => {
+                        failing = false;
+                        user_info!(
+                            "INF_TELEMETRY_EXPORT_RESTORED",
+                            json_value!({ "endpoint": endpoint })
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+    if let Err(e) = spawned {
+        user_warn!(
+            "WRN_TELEMETRY_CLIENT_INIT",
+            json_value!({ "error": e.to_string() })
+        );
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::core::logs::{LogEngine, LogLayerExt, LogRegistry};
+
+    fn sampler(default_ratio: f64, rules: &[(&str, f64)]) -> ModuleSampler {
+        let mut config = TelemetryConfig {
+            default_sample_ratio: default_ratio,
+            ..TelemetryConfig::default()
+        };
+        for (module, ratio) in rules {
+            config.module_sampling.insert(module.to_string(), *ratio);
+        }
+        ModuleSampler::from_config(&config)
+    }
+
+    #[test]
+    fn test_sampler_uses_most_specific_module_rule() {
+        let s = sampler(0.5, &[("json_db", 0.0), ("json_db::query", 1.0)]);
+
+        assert_eq!(s.ratio_for("json_db::collections::manager"), 0.0);
+        assert_eq!(s.ratio_for("json_db::query::executor"), 1.0);
+        assert_eq!(s.ratio_for("json_dbx"), 0.5);
+        assert!(!s.should_sample(u128::MAX, "json_db::storage"));
+        assert!(s.should_sample(u128::MAX, "json_db::query"));
+    }
+
+    #[test]
+    fn test_child_spans_inherit_trace_context() {
+        let layer = OtlpLayer::new(sampler(1.0, &[]));
+        let buffer = layer.buffer();
+        let subscriber = LogRegistry().with(layer);
+
+        LogEngine::with_default(subscriber, || {
+            let root = tracing::info_span!("workflow.run", instance = "wf-1");
+            let _enter = root.enter();
+            let child = tracing::info_span!("json_db.query", collection = "actors");
+            drop(child.enter());
+        });
+
+        let guard = buffer.lock().unwrap();
+        assert_eq!(guard.spans.len(), 2);
+        let child = guard
+            .spans
+            .iter()
+            .find(|s| s.name == "json_db.query")
+            .unwrap();
+        let root = guard
+            .spans
+            .iter()
+            .find(|s| s.name == "workflow.run")
+            .unwrap();
+
+        assert_eq!(child.trace_id, root.trace_id);
+        assert_eq!(child.parent_span_id, Some(root.span_id));
+        assert!(root.parent_span_id.is_none());
+        assert_eq!(root.attributes.get("instance"), Some(&"wf-1".to_string()));
+
+        let payload = encode_traces("raise-test", &guard.spans);
+        let spans = &payload["resourceSpans"][0]["scopeSpans"][0]["spans"];
+        assert_eq!(spans.as_array().unwrap().len(), 2);
+        assert_eq!(spans[0]["traceId"].as_str().unwrap().len(), 32);
+    }
+
+    #[test]
+    fn test_unsampled_spans_still_feed_metrics() {
+        let layer = OtlpLayer::new(sampler(0.0, &[]));
+        let buffer = layer.buffer();
+        let subscriber = LogRegistry().with(layer);
+
+        LogEngine::with_default(subscriber, || {
+            drop(tracing::info_span!("ignored").enter());
+        });
+
+        let guard = buffer.lock().unwrap();
+        assert!(guard.spans.is_empty());
+        let total: u64 = guard.metrics.values().map(|m| m.span_count).sum();
+        assert_eq!(total, 1);
+
+        let payload = encode_metrics("raise-test", &guard.metrics);
+        assert_eq!(
+            payload["resourceMetrics"][0]["scopeMetrics"][0]["metrics"][0]["name"],
+            "raise.spans.count"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+// FICHIER : crates/raise-core/src/utils/context/tokens.rs
+//! Jetons d'API émis localement : identité et rôles signés (Ed25519) par la clé du poste,
+//! pour les accès sans session interactive (CLI, scripts de revue, serveur).
+//! Format : `raise_<claims base64url>.<signature base64url>`.
+
+use crate::utils::context::access::{
+    decode_base64url, encode_base64url, AccessIdentity, AccessRole, IdentitySource,
+};
+use crate::utils::data::secrets::SecretsProvider;
+use crate::utils::prelude::*;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier};
+
+pub const API_TOKEN_PREFIX: &str = "raise_";
+/// Nom du secret portant la graine de signature (hex, 32 octets).
+pub const API_TOKEN_SIGNING_SECRET: &str = "raise_api_token_signing_key";
+
+/// Contenu signé d'un jeton.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ApiTokenClaims {
+    pub sub: String,
+    pub roles: Vec<AccessRole>,
+    /// Émission et expiration (timestamps Unix) ; sans `exp`, le jeton n'expire pas.
+    pub iat: i64,
+    pub exp: Option<i64>,
+    pub jti: String,
+}
+
+/// Émetteur et vérificateur des jetons d'API du poste.
+pub struct ApiTokenIssuer {
+    key: SigningKey,
+}
+
+impl ApiTokenIssuer {
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self {
+            key: SigningKey::from_bytes(&seed),
+        }
+    }
+
+    /// Clé lue dans le gestionnaire de secrets, générée et enregistrée au premier usage.
+    pub async fn from_secrets(secrets: &SecretsProvider) -> RaiseResult<Self> {
+        if let Some(hex_seed) = secrets.get(API_TOKEN_SIGNING_SECRET).await? {
+            let seed: [u8; 32] = match hex::decode(hex_seed.trim())
+                .ok()
+                .and_then(|b| b.try_into().ok())
+            {
+                Some(seed) => seed,
+                None => raise_error!(
+                    "ERR_ACCESS_TOKEN_KEY_INVALID",
+                    error = "Graine de signature des jetons invalide (32 octets hex attendus).",
+                    context = json_value!({ "secret": API_TOKEN_SIGNING_SECRET })
+                ),
+            };
+            return Ok(Self::from_seed(seed));
+        }
+        let seed: [u8; 32] = rand::random();
+        secrets
+            .set(API_TOKEN_SIGNING_SECRET, &hex::encode(seed), None)
+            .await?;
+        Ok(Self::from_seed(seed))
+    }
+
+    /// Émet un jeton pour `user` ; `ttl` absent : jeton sans expiration.
+    pub fn issue(
+        &self,
+        user: &str,
+        roles: Vec<AccessRole>,
+        ttl: Option<CalendarDuration>,
+    ) -> RaiseResult<(String, ApiTokenClaims)> {
+        if roles.is_empty() {
+            raise_error!(
+                "ERR_ACCESS_TOKEN_NO_ROLE",
+                error = "Un jeton d'API doit porter au moins un rôle.",
+                context = json_value!({ "user": user })
+            );
+        }
+        let now = UtcClock::now();
+        let claims = ApiTokenClaims {
+            sub: user.to_string(),
+            roles,
+            iat: now.timestamp(),
+            exp: ttl.map(|ttl| (now + ttl).timestamp()),
+            jti: UniqueId::new_v4().to_string(),
+        };
+        let payload = encode_base64url(&json::serialize_to_bytes(&claims)?);
+        let signature = self.key.sign(payload.as_bytes());
+        let token = format!(
+            "{}{}.{}",
+            API_TOKEN_PREFIX,
+            payload,
+            encode_base64url(&signature.to_bytes())
+        );
+        Ok((token, claims))
+    }
+
+    /// Vérifie signature et expiration, et retourne l'identité portée par le jeton.
+    pub fn verify(&self, token: &str, now: i64) -> RaiseResult<AccessIdentity> {
+        let Some((payload, signature)) = token
+            .trim()
+            .strip_prefix(API_TOKEN_PREFIX)
+            .and_then(|body| body.split_once('.'))
+        else {
+            raise_error!(
+                "ERR_ACCESS_TOKEN_MALFORMED",
+                error = "Jeton d'API attendu au format raise_<claims>.<signature>."
+            );
+        };
+        let signature_ok = Signature::from_slice(&decode_base64url(signature)?).is_ok_and(|sig| {
+            self.key
+                .verifying_key()
+                .verify(payload.as_bytes(), &sig)
+                .is_ok()
+        });
+        if !signature_ok {
+            raise_error!(
+                "ERR_ACCESS_TOKEN_SIGNATURE",
+                error = "Signature du jeton d'API invalide."
+            );
+        }
+
+        let claims: ApiTokenClaims = json::deserialize_from_bytes(&decode_base64url(payload)?)?;
+        if claims.exp.is_some_and(|exp| exp <= now) {
+            raise_error!(
+                "ERR_ACCESS_TOKEN_EXPIRED",
+                error = "Jeton d'API expiré.",
+                context = json_value!({ "user": claims.sub, "jti": claims.jti })
+            );
+        }
+        Ok(AccessIdentity {
+            user: claims.sub,
+            roles: claims.roles,
+            source: IdentitySource::ApiToken,
+            expires_at: claims.exp,
+        })
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_verify_api_token() -> RaiseResult<()> {
+        let issuer = ApiTokenIssuer::from_seed([3u8; 32]);
+        let (token, claims) = issuer.issue(
+            "reviewer",
+            vec![AccessRole::Viewer],
+            Some(CalendarDuration::hours(1)),
+        )?;
+        assert!(token.starts_with(API_TOKEN_PREFIX));
+
+        let identity = issuer.verify(&token, claims.iat)?;
+        assert_eq!(identity.user, "reviewer");
+        assert_eq!(identity.roles, vec![AccessRole::Viewer]);
+        assert_eq!(identity.source, IdentitySource::ApiToken);
+
+        // Expiré, signé par une autre clé, ou altéré
+        assert!(issuer.verify(&token, claims.exp.unwrap()).is_err());
+        let other = ApiTokenIssuer::from_seed([4u8; 32]);
+        assert!(other.verify(&token, claims.iat).is_err());
+        let forged = token.replacen(API_TOKEN_PREFIX, &format!("{}e", API_TOKEN_PREFIX), 1);
+        assert!(issuer.verify(&forged, claims.iat).is_err());
+        assert!(issuer.verify("not-a-token", claims.iat).is_err());
+
+        assert!(issuer.issue("nobody", vec![], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+// FICHIER : src-tauri/src/utils/core/error_catalog.rs
+
+//! Catalogue des codes d'erreur, collecté à la compilation par `build.rs` à partir des
+//! invocations `raise_error!` / `build_error!` de la crate.
+//! Les messages sont localisés via i18n (clé = code, conseil = `<code>_HINT`),
+//! avec repli sur la description et le conseil relevés dans le code source.
+
+use crate::utils::context::i18n;
+use crate::utils::data::Serializable;
+
+/// Suffixe des clés i18n portant le conseil de remédiation d'un code.
+pub const HINT_KEY_SUFFIX: &str = "_HINT";
+
+/// Entrée brute du catalogue, telle que relevée dans le code source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable)]
+pub struct ErrorSpec {
+    pub code: &'static str,
+    /// Module de la première levée du code (ex: `json_db::query::sql`).
+    pub module: &'static str,
+    /// Premier `error = "..."` littéral associé au code.
+    pub description: Option<&'static str>,
+    /// Premier `"hint": "..."` littéral du contexte associé au code.
+    pub hint: Option<&'static str>,
+    /// Nombre de sites de levée dans la crate.
+    pub occurrences: usize,
+}
+
+// Généré par build.rs, trié par code.
+include!(concat!(env!("OUT_DIR"), "/error_catalog.rs"));
+
+/// Entrée localisée, prête à l'affichage ou à l'export JSON.
+#[derive(Debug, Clone, PartialEq, Serializable)]
+pub struct ErrorEntry {
+    pub code: String,
+    pub module: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    pub occurrences: usize,
+}
+
+/// Tous les codes connus, triés par code.
+pub fn catalog() -> &'static [ErrorSpec] {
+    ERROR_CATALOG
+}
+
+pub fn lookup(code: &str) -> Option<&'static ErrorSpec> {
+    ERROR_CATALOG
+        .binary_search_by(|spec| spec.code.cmp(code))
+        .ok()
+        .map(|index| &ERROR_CATALOG[index])
+}
+
+/// Message localisé d'un code : traduction i18n, sinon description du catalogue, sinon le code.
+pub fn message(code: &str) -> String {
+    let translated = i18n::t(code);
+    if translated != code {
+        return translated;
+    }
+    lookup(code)
+        .and_then(|spec| spec.description)
+        .map(str::to_string)
+        .unwrap_or(translated)
+}
+
+/// Conseil de remédiation localisé (`<code>_HINT`), sinon celui du catalogue.
+pub fn hint(code: &str) -> Option<String> {
+    let key = format!("{}{}", code, HINT_KEY_SUFFIX);
+    let translated = i18n::t(&key);
+    if translated != key {
+        return Some(translated);
+    }
+    lookup(code).and_then(|spec| spec.hint).map(str::to_string)
+}
+
+/// Catalogue localisé, optionnellement filtré (sous-chaîne du code ou du module, sans casse).
+pub fn localized_catalog(filter: Option<&str>) -> Vec<ErrorEntry> {
+    let filter = filter.map(str::to_lowercase);
+    ERROR_CATALOG
+        .iter()
+        .filter(|spec| match &filter {
+            Some(f) => {
+                spec.code.to_lowercase().contains(f.as_str())
+                    || spec.module.to_lowercase().contains(f.as_str())
+            }
+            None => true,
+        })
+        .map(|spec| ErrorEntry {
+            code: spec.code.to_string(),
+            module: spec.module.to_string(),
+            message: message(spec.code),
+            hint: hint(spec.code),
+            occurrences: spec.occurrences,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_is_sorted_and_unique() {
+        assert!(!catalog().is_empty());
+        assert!(catalog().windows(2).all(|w| w[0].code < w[1].code));
+        assert!(catalog().iter().all(|spec| spec.occurrences > 0));
+    }
+
+    #[test]
+    fn test_catalog_collects_description_module_and_hint() {
+        let spec = lookup("ERR_DB_SQL_PARAM_MIXED").expect("code absent du catalogue");
+        assert_eq!(spec.module, "json_db::query::sql");
+        assert!(spec.description.is_some());
+
+        let spec = lookup("ERR_DB_SQL_SINGLE_STATEMENT_ONLY").expect("code absent du catalogue");
+        assert!(spec.hint.is_some());
+
+        assert!(lookup("ERR_DOES_NOT_EXIST").is_none());
+    }
+
+    #[test]
+    fn test_message_falls_back_to_catalog_then_code() {
+        let spec = lookup("ERR_DB_SQL_PARAM_MIXED").unwrap();
+        // Traduction i18n si disponible, description du catalogue sinon : jamais le code brut
+        assert_ne!(message(spec.code), spec.code);
+
+        assert_eq!(message("ERR_DOES_NOT_EXIST"), "ERR_DOES_NOT_EXIST");
+        assert_eq!(hint("ERR_DOES_NOT_EXIST"), None);
+    }
+
+    #[test]
+    fn test_localized_catalog_filter() {
+        let entries = localized_catalog(Some("err_db_sql_param"));
+        assert!(entries.len() >= 2);
+        assert!(entries
+            .iter()
+            .all(|e| e.code.starts_with("ERR_DB_SQL_PARAM")));
+    }
+}
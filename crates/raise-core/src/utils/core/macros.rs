@@ -234,7 +234,7 @@ macro_rules! build_error {
         }
 
         let final_context = $crate::utils::data::json::JsonValue::Object(ctx_map);
-        let reason_msg = $crate::utils::context::i18n::t($key);
+        let reason_msg = $crate::utils::core::error_catalog::message($key);
 
         $crate::utils::tracing::error!(
             event = "user_error",
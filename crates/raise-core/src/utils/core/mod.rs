@@ -2,6 +2,7 @@
 
 pub mod env;
 pub mod error;
+pub mod error_catalog;
 pub mod macros;
 
 // =========================================================================
@@ -25,11 +26,19 @@ pub use chrono::Utc as UtcClock;
 /// (Année, Mois, Jour) sans heure ni fuseau horaire.
 pub use chrono::NaiveDate as CalendarDate;
 
+/// 🤖 IA NOTE : Alias pour chrono::FixedOffset. Décalage fixe par rapport à UTC (ex: "+02:00"),
+/// pour produire des horodatages RFC3339 dans un fuseau explicite.
+pub use chrono::FixedOffset as UtcOffset;
+
 /// 🤖 IA NOTE : Alias pour chrono::Duration.
 /// À utiliser EXCLUSIVEMENT pour les calculs de dates calendaires (ajouter des jours, mois, etc.).
 /// Ne pas confondre avec `TimeDuration` (std::time::Duration) utilisé pour les timeouts réseau/CPU.
 pub use chrono::Duration as CalendarDuration; // 🎯 L'alias sémantique strict !
 
+/// 🤖 IA NOTE : Traits d'accès aux composantes d'un `UtcTimestamp`
+/// (jour, mois, jour de semaine / heure, minute). Utilisés par les planificateurs cron.
+pub use chrono::{Datelike as CalendarFields, Timelike as ClockFields};
+
 /// 🤖 IA NOTE : Parse une chaîne de caractères au format RFC3339 (ex: "2026-06-06T11:44:39Z")
 /// et la convertit de manière sécurisée en un `UtcTimestamp`.
 /// Centralise la gestion d'erreur temporelle pour éviter les fuites d'abstraction de la crate `chrono`.
@@ -173,6 +182,17 @@ pub use sha2::Digest as CryptoDigest;
 /// 🤖 IA NOTE : Moteur de hashage cryptographique SHA-256 (256-bit).
 pub use sha2::Sha256 as CryptoSha256;
 
+/// 🤖 IA NOTE : Moteur de hashage BLAKE3 (256-bit), plus rapide que SHA-256 sur gros volumes.
+pub use blake3::Hasher as CryptoBlake3;
+
+/// 🤖 IA NOTE : Chiffrement authentifié AES-256-GCM (coffre de secrets local).
+/// `Aes256Gcm::new_from_slice(&key)` puis `.encrypt(Nonce::from_slice(&nonce), data)`.
+#[allow(non_snake_case)]
+pub mod CryptoAead {
+    pub use aes_gcm::aead::{Aead, KeyInit};
+    pub use aes_gcm::{Aes256Gcm, Nonce};
+}
+
 // =========================================================================
 // SYNC, RUNTIME & OWNERSHIP (Façade AI-Ready)
 // =========================================================================
@@ -197,6 +217,8 @@ pub use std::sync::RwLock as SyncRwLock;
 
 // --- Primitives Asynchrones (Non-bloquantes) ---
 pub use tokio::sync::Mutex as AsyncMutex;
+/// Réveil de tâches en attente (ex: libération d'un créneau d'exécution).
+pub use tokio::sync::Notify as AsyncSignal;
 pub use tokio::sync::OnceCell as AsyncStaticCell;
 pub use tokio::sync::RwLock as AsyncRwLock;
 
@@ -221,8 +243,20 @@ pub use tokio::select as AgentAttention;
 #[allow(non_snake_case)]
 pub mod AsyncChannel {
     pub use tokio::sync::mpsc::{channel, Receiver, Sender};
+    /// Variante sans borne : envoi synchrone possible (callbacks de génération).
+    pub use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 }
 
+/// Diffusion 1 → N : chaque abonné reçoit une copie de chaque message.
+#[allow(non_snake_case)]
+pub mod AsyncBroadcast {
+    pub use tokio::sync::broadcast::error::RecvError;
+    pub use tokio::sync::broadcast::{channel, Receiver, Sender};
+}
+
+/// Flux asynchrone : séquence de valeurs consommée au fil de l'eau (`stream.next().await`).
+pub use futures::stream::{Stream as AsyncStream, StreamExt as AsyncStreamExt};
+
 /// 🤖 IA NOTE : `RawIoResult` est l'alias de `std::io::Result`.
 /// Il ne doit être utilisé que dans les implémentations de traits bas niveau (Read/Write).
 /// Pour tout le code métier, utilisez impérativement `RaiseResult`.
@@ -2,6 +2,7 @@
 
 // 1. Base de données (AI-Ready Queries)
 use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::collections::ttl::TtlPolicy;
 use crate::json_db::query::{Condition, FilterOperator, Query, QueryEngine, QueryFilter};
 // 2. Core : Environnement, Concurrence et Erreurs
 use crate::utils::core::error::AppError;
@@ -13,14 +14,19 @@ use crate::{kernel_fatal, raise_error};
 use crate::utils::io::fs::{self, PathBuf};
 
 // 4. Data : Traits, Collections sémantiques et JSON
+use crate::utils::data::config_validation;
 use crate::utils::data::json::{self, json_value, JsonValue};
+use crate::utils::data::secrets::SecretsProvider;
 use crate::utils::data::{
-    CustomDeserializerEngine, Deserializable, DeserializationErrorTrait, Serializable, UnorderedMap,
+    CustomDeserializerEngine, Deserializable, DeserializationErrorTrait, OrderedMap, Serializable,
+    UnorderedMap,
 };
 
 /// Singleton global pour la configuration
 pub static CONFIG: StaticCell<AppConfig> = StaticCell::new();
 pub static DEVICE: StaticCell<candle_core::Device> = StaticCell::new();
+/// Fichier source de la configuration active (surveillé pour le rechargement à chaud)
+pub static CONFIG_SOURCE: StaticCell<PathBuf> = StaticCell::new();
 
 /// Constantes Système pour amorcer la première lecture
 pub const BOOTSTRAP_DOMAIN: &str = "_system";
@@ -73,6 +79,39 @@ pub struct AppConfig {
 
     #[serde(default)]
     pub system_assets: SystemAssets,
+
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
+
+    #[serde(default)]
+    pub network: NetworkConfig,
+
+    #[serde(default)]
+    pub vacuum: VacuumConfig,
+
+    #[serde(default)]
+    pub ttl: TtlConfig,
+
+    #[serde(default)]
+    pub reputation: ReputationConfig,
+
+    #[serde(default)]
+    pub ai_budget: AiBudgetConfig,
+
+    #[serde(default)]
+    pub webhooks: WebhookConfig,
+
+    #[serde(default)]
+    pub server: ServerConfig,
+
+    #[serde(default)]
+    pub workflow_scheduling: WorkflowSchedulingConfig,
+
+    #[serde(default)]
+    pub plan_review: PlanReviewConfig,
+
+    #[serde(default)]
+    pub access_control: AccessControlConfig,
 }
 
 #[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
@@ -192,6 +231,411 @@ pub struct CoreConfig {
     pub use_gpu: bool,
 }
 
+/// Export OpenTelemetry (OTLP/HTTP) des traces et métriques `tracing`.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct TelemetryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_otlp_endpoint")]
+    pub otlp_endpoint: String,
+    #[serde(default = "default_service_name")]
+    pub service_name: String,
+    /// Ratio d'échantillonnage appliqué aux modules non listés (0.0 → 1.0).
+    #[serde(default = "default_sample_ratio")]
+    pub default_sample_ratio: f64,
+    /// Ratio par module (`json_db`, `ai::llm`, `workflow_engine`, `blockchain`...).
+    #[serde(default)]
+    pub module_sampling: OrderedMap<String, f64>,
+    #[serde(default = "default_export_interval_secs")]
+    pub export_interval_secs: u64,
+}
+
+fn default_otlp_endpoint() -> String {
+    "http://localhost:4318".to_string()
+}
+
+fn default_service_name() -> String {
+    "raise".to_string()
+}
+
+fn default_sample_ratio() -> f64 {
+    1.0
+}
+
+fn default_export_interval_secs() -> u64 {
+    5
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            otlp_endpoint: default_otlp_endpoint(),
+            service_name: default_service_name(),
+            default_sample_ratio: default_sample_ratio(),
+            module_sampling: OrderedMap::new(),
+            export_interval_secs: default_export_interval_secs(),
+        }
+    }
+}
+
+/// Expiration des documents JSON-DB : balayage périodique des collections à durée de vie.
+/// `collections` s'applique aux collections dont le `_meta.json` ne déclare pas de `ttl`.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct TtlConfig {
+    #[serde(default = "default_ttl_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_ttl_interval_secs")]
+    pub interval_secs: u64,
+    #[serde(default = "default_ttl_collections")]
+    pub collections: OrderedMap<String, TtlPolicy>,
+}
+
+fn default_ttl_enabled() -> bool {
+    true
+}
+
+fn default_ttl_interval_secs() -> u64 {
+    300
+}
+
+/// Sessions d'agents inactives depuis 7 jours, instances de workflow inchangées depuis 30 jours.
+fn default_ttl_collections() -> OrderedMap<String, TtlPolicy> {
+    let mut collections = OrderedMap::new();
+    collections.insert(
+        "agent_sessions".to_string(),
+        TtlPolicy::new("_updated_at", 7 * 86_400),
+    );
+    collections.insert(
+        "workflow_instances".to_string(),
+        TtlPolicy::new("updated_at", 30 * 86_400),
+    );
+    collections
+}
+
+impl Default for TtlConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_ttl_enabled(),
+            interval_secs: default_ttl_interval_secs(),
+            collections: default_ttl_collections(),
+        }
+    }
+}
+
+/// Maintenance JSON-DB : `vacuum` périodique (fichiers orphelins, index périmés).
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct VacuumConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_vacuum_interval_secs")]
+    pub interval_secs: u64,
+    /// Âge minimal d'un fichier temporaire avant suppression (écriture atomique en cours sinon).
+    #[serde(default = "default_vacuum_min_temp_age_secs")]
+    pub min_temp_age_secs: u64,
+}
+
+fn default_vacuum_interval_secs() -> u64 {
+    86_400
+}
+
+fn default_vacuum_min_temp_age_secs() -> u64 {
+    300
+}
+
+impl Default for VacuumConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_vacuum_interval_secs(),
+            min_temp_age_secs: default_vacuum_min_temp_age_secs(),
+        }
+    }
+}
+
+/// Réputation des validateurs Mentis : points de pénalité par infraction et
+/// exclusion temporaire du quorum au-delà du seuil.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct ReputationConfig {
+    pub missed_vote_penalty: u32,
+    pub invalid_proposal_penalty: u32,
+    pub double_vote_penalty: u32,
+    pub exclusion_threshold: u32,
+    pub exclusion_minutes: i64,
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        Self {
+            missed_vote_penalty: 1,
+            invalid_proposal_penalty: 5,
+            double_vote_penalty: 10,
+            exclusion_threshold: 10,
+            exclusion_minutes: 60,
+        }
+    }
+}
+
+/// Tarif d'un backend LLM, en unité monétaire par million de tokens.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct LlmPrice {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+}
+
+/// Suivi des coûts LLM (`ai_usage`) et budgets mensuels.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct AiBudgetConfig {
+    /// Tarifs par backend (`claude`, `gemini`, `mistral`, `local`...). Absent : coût nul.
+    pub prices: OrderedMap<String, LlmPrice>,
+    /// Budget mensuel global, tous projets confondus.
+    pub monthly_budget: Option<f64>,
+    /// Budgets mensuels par projet (`domaine/db`), prioritaires sur le budget global.
+    pub project_budgets: OrderedMap<String, f64>,
+    /// Part du budget déclenchant l'avertissement (0.8 = 80 %).
+    pub soft_warning_ratio: f64,
+}
+
+impl Default for AiBudgetConfig {
+    fn default() -> Self {
+        Self {
+            prices: OrderedMap::new(),
+            monthly_budget: None,
+            project_budgets: OrderedMap::new(),
+            soft_warning_ratio: 0.8,
+        }
+    }
+}
+
+/// Point d'entrée HTTP des déclencheurs webhook de workflows (désactivé par défaut).
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct WebhookConfig {
+    pub enabled: bool,
+    /// Interface d'écoute ("0.0.0.0" pour exposer le poste au réseau).
+    pub host: String,
+    pub port: u16,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "127.0.0.1".to_string(),
+            port: 8787,
+        }
+    }
+}
+
+/// API HTTP du mode serveur (`raise-server`), sans interface graphique.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    /// Nom du secret contenant le jeton `Bearer` des clients d'automatisation (CI).
+    pub token_secret: String,
+    /// Utilisateur (au sens du contrôle d'accès) endossé par les appels authentifiés par jeton.
+    pub service_user: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8790,
+            token_secret: "raise_server_token".to_string(),
+            service_user: "ci".to_string(),
+        }
+    }
+}
+
+/// Admission des exécutions de workflows : plafonds de concurrence et vieillissement des priorités.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct WorkflowSchedulingConfig {
+    /// Nombre maximal d'instances en cours d'exécution, toutes classes confondues.
+    pub max_concurrent: usize,
+    /// Plafonds par classe de workflow (`codegen`, `etl`...). Classe absente : seul le plafond global s'applique.
+    pub class_limits: OrderedMap<String, usize>,
+    /// Une instance en attente gagne un niveau de priorité toutes les `aging_secs` secondes (0 = désactivé).
+    pub aging_secs: u64,
+}
+
+impl Default for WorkflowSchedulingConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 4,
+            class_limits: OrderedMap::new(),
+            aging_secs: 60,
+        }
+    }
+}
+
+/// Revue automatique des plans de workflow par le critique avant exécution.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct PlanReviewConfig {
+    pub enabled: bool,
+    /// Bloque l'instance (`BLOCKED`) si la critique est inacceptable ; sinon simple avertissement.
+    pub enforce: bool,
+    pub max_nodes: usize,
+    /// Estimation forfaitaire de la consommation d'un nœud `task` (prompt + réponse).
+    pub tokens_per_llm_call: u64,
+    pub max_estimated_tokens: u64,
+}
+
+impl Default for PlanReviewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            enforce: false,
+            max_nodes: 200,
+            tokens_per_llm_call: 2_000,
+            max_estimated_tokens: 200_000,
+        }
+    }
+}
+
+/// Contrôle d'accès par rôle aux commandes de l'interface (postes d'ingénierie partagés).
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct AccessControlConfig {
+    /// Désactivé : toute commande est autorisée (poste mono-utilisateur).
+    pub enabled: bool,
+    /// Rôle attribué à une identité sans rôle connu (`viewer`, `editor`, `validator`, `admin`).
+    pub default_role: String,
+    /// Rôles des utilisateurs locaux, par handle.
+    pub user_roles: OrderedMap<String, Vec<String>>,
+    /// Surcharges de la politique par défaut, par rôle.
+    pub policies: OrderedMap<String, RolePolicy>,
+    #[serde(default)]
+    pub oidc: Option<OidcConfig>,
+}
+
+impl Default for AccessControlConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            default_role: "viewer".to_string(),
+            user_roles: OrderedMap::new(),
+            policies: OrderedMap::new(),
+            oidc: None,
+        }
+    }
+}
+
+/// Commandes ouvertes à un rôle. Motifs : nom exact, préfixe (`jsondb_drop_*`) ou `*`.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq, Default)]
+#[serde(default)]
+pub struct RolePolicy {
+    /// Autorise toutes les commandes de consultation.
+    pub read: bool,
+    pub allow: Vec<String>,
+    /// Prioritaire sur `allow` et `read`.
+    pub deny: Vec<String>,
+}
+
+/// Fournisseur d'identité OIDC dont les jetons sont acceptés.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct OidcConfig {
+    pub issuer: String,
+    #[serde(default)]
+    pub audience: Option<String>,
+    /// Clé publique Ed25519 (hex) de l'émetteur : seuls les jetons `alg: EdDSA` sont acceptés.
+    pub public_key: String,
+    /// Chemin pointé du claim portant les rôles (`roles`, `realm_access.roles`...).
+    #[serde(default = "default_roles_claim")]
+    pub roles_claim: String,
+}
+
+fn default_roles_claim() -> String {
+    "roles".to_string()
+}
+
+/// Client HTTP sortant : proxy d'entreprise, certificats clients (mTLS), disjoncteur.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct NetworkConfig {
+    /// Proxy explicite, prioritaire sur les variables `HTTP(S)_PROXY`.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    /// Sans proxy explicite : respecter `HTTP_PROXY` / `HTTPS_PROXY` / `NO_PROXY`.
+    #[serde(default = "default_true")]
+    pub use_system_proxy: bool,
+    #[serde(default)]
+    pub tls: Option<ClientTlsConfig>,
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct ProxyConfig {
+    pub url: String,
+    /// Hôtes contournant le proxy (`localhost`, `.corp.local`, `10.0.0.0/8`...).
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Variable d'environnement portant le mot de passe (jamais en clair dans la config).
+    #[serde(default)]
+    pub password_env: Option<String>,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq, Default)]
+pub struct ClientTlsConfig {
+    /// Fichier PEM contenant le certificat client et sa clé privée (mTLS).
+    #[serde(default)]
+    pub identity_pem: Option<String>,
+    /// Autorités de certification supplémentaires (passerelle d'entreprise).
+    #[serde(default)]
+    pub ca_bundle_pem: Option<String>,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Échecs consécutifs avant ouverture du circuit.
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+    /// Durée d'ouverture avant une requête de sonde.
+    #[serde(default = "default_open_duration_secs")]
+    pub open_duration_secs: u64,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_failure_threshold() -> u32 {
+    5
+}
+
+fn default_open_duration_secs() -> u64 {
+    30
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            proxy: None,
+            use_system_proxy: true,
+            tls: None,
+            circuit_breaker: CircuitBreakerConfig::default(),
+        }
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_failure_threshold(),
+            open_duration_secs: default_open_duration_secs(),
+        }
+    }
+}
+
 // =========================================================================
 // IMPLÉMENTATION PRINCIPALE
 // =========================================================================
@@ -246,6 +690,11 @@ impl AppConfig {
             .expect("❌ AppConfig non initialisé ! Appelez AppConfig::init() au démarrage.")
     }
 
+    /// Chemin du document de configuration chargé au démarrage (absent en mode bootstrap).
+    pub fn source_path() -> Option<&'static PathBuf> {
+        CONFIG_SOURCE.get()
+    }
+
     pub fn is_test_env(&self) -> bool {
         self.core.env_mode == "test"
     }
@@ -326,7 +775,12 @@ impl AppConfig {
         // 4. RETOUR OU ERREUR FATALE (Pas de fallback silencieux !)
         if let Some(doc) = result.documents.into_iter().next() {
             if let Some(settings) = doc.get("service_settings") {
-                return Ok(settings.clone());
+                // Les références `secret:<clé>` sont résolues via le fournisseur de secrets
+                let mut settings = settings.clone();
+                SecretsProvider::global()
+                    .resolve_refs(&mut settings)
+                    .await?;
+                return Ok(settings);
             } else {
                 raise_error!(
                     "ERR_CONFIG_INVALID_SETTINGS",
@@ -467,7 +921,7 @@ impl AppConfig {
             }
         });
 
-        let Some((config_path, raw_json, _json_val)) = system_match else {
+        let Some((config_path, raw_json, json_val)) = system_match else {
             crate::user_warn!(
                 "WRN_BOOTSTRAP_MODE",
                 json_value!({
@@ -478,6 +932,11 @@ impl AppConfig {
             return Ok(Self::generate_bootstrap_config());
         };
 
+        let _ = CONFIG_SOURCE.set(config_path.clone());
+
+        // Tous les problèmes du document d'un coup, avant que la désérialisation ne bute sur le premier
+        config_validation::ensure_valid_config(&json_val, &config_path.to_string_lossy())?;
+
         let mut config: AppConfig = match json::deserialize_from_str(&raw_json) {
             Ok(c) => c,
             Err(e) => {
@@ -658,6 +1117,17 @@ impl AppConfig {
             dapp: None,
             mandator: None,
             system_assets: SystemAssets::default(),
+            telemetry: TelemetryConfig::default(),
+            network: NetworkConfig::default(),
+            vacuum: VacuumConfig::default(),
+            ttl: TtlConfig::default(),
+            reputation: ReputationConfig::default(),
+            ai_budget: AiBudgetConfig::default(),
+            webhooks: WebhookConfig::default(),
+            server: ServerConfig::default(),
+            workflow_scheduling: WorkflowSchedulingConfig::default(),
+            plan_review: PlanReviewConfig::default(),
+            access_control: AccessControlConfig::default(),
         }
     }
 
@@ -859,6 +1329,17 @@ mod tests {
         assert!(config.workstation.is_none());
     }
 
+    #[test]
+    fn test_bootstrap_config_matches_schema() {
+        // Garde-fou : le schéma de validation doit suivre l'évolution des structures
+        let mut doc = json::serialize_to_value(&AppConfig::generate_bootstrap_config())
+            .expect("Sérialisation échouée");
+        doc["paths"] = json_value!({ "PATH_RAISE_DOMAIN": "/tmp/raise" });
+
+        let problems = config_validation::validate_config_document(&doc);
+        assert!(problems.is_empty(), "Schéma désynchronisé : {:?}", problems);
+    }
+
     #[async_test]
     #[serial_test::serial]
     async fn test_get_runtime_settings_resolves_correctly() -> RaiseResult<()> {
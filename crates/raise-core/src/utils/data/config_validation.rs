@@ -0,0 +1,702 @@
+// FICHIER : crates/raise-core/src/utils/data/config_validation.rs
+//! Validation du document de configuration avant sa désérialisation par `AppConfig::init` :
+//! schéma JSON (`config_schema`) puis contrôles de cohérence (ports des services, chemins).
+//! Tous les problèmes sont rapportés ensemble, au démarrage, plutôt qu'au fil des
+//! sous-systèmes qui les découvriraient un à un.
+//!
+//! | Code                               | Problème                                                  |
+//! | ---------------------------------- | --------------------------------------------------------- |
+//! | `ERR_CONFIG_UNKNOWN_KEY`           | Clé inconnue (faute de frappe, option retirée)            |
+//! | `ERR_CONFIG_MISSING_KEY`           | Clé obligatoire absente                                   |
+//! | `ERR_CONFIG_TYPE_MISMATCH`         | Type JSON inattendu                                       |
+//! | `ERR_CONFIG_VALUE_OUT_OF_RANGE`    | Valeur hors bornes ou hors des valeurs admises            |
+//! | `ERR_CONFIG_SERVICE_PORT_MISSING`  | Service déclaré (`server`, `webhooks` actif) sans `port`  |
+//! | `ERR_CONFIG_SERVICE_PORT_CONFLICT` | `server` et `webhooks` écoutent sur le même port          |
+//! | `ERR_CONFIG_PATH_INVALID`          | Chemin vide, `~` sans dossier personnel, ou sous un fichier |
+//! | `ERR_CONFIG_PATH_MISSING`          | `PATH_RAISE_DOMAIN` absent de `paths` et de l'environnement |
+
+use crate::utils::core::RuntimeEnv;
+use crate::utils::data::config::{ServerConfig, WebhookConfig};
+use crate::utils::prelude::*;
+
+/// Identifiant du schéma de la configuration système.
+pub const CONFIG_SCHEMA_ID: &str = "raise://schemas/config/app_config.schema.json";
+/// Chemins sans lesquels aucune base ne peut être ouverte.
+const REQUIRED_PATHS: &[&str] = &["PATH_RAISE_DOMAIN"];
+
+/// Problème relevé dans le document de configuration.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ConfigProblem {
+    pub code: String,
+    /// Clé pointée (`webhooks.port`, `paths.PATH_RAISE_DOMAIN`, `active_services[2]`).
+    pub path: String,
+    pub message: String,
+}
+
+impl ConfigProblem {
+    fn new(code: &str, path: &str, message: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            path: if path.is_empty() {
+                "(racine)".to_string()
+            } else {
+                path.to_string()
+            },
+            message: message.into(),
+        }
+    }
+}
+
+/// Schéma JSON du document de configuration (`AppConfig`). Les clés techniques (`_…`, `$…`,
+/// `@…`) et les extensions (`x_…`) échappent au contrôle des clés inconnues.
+pub fn config_schema() -> JsonValue {
+    let string = json_value!({ "type": "string" });
+    let opt_string = json_value!({ "type": ["string", "null"] });
+    let boolean = json_value!({ "type": "boolean" });
+    let count = json_value!({ "type": "integer", "minimum": 0 });
+    let positive = json_value!({ "type": "integer", "minimum": 1 });
+    let ratio = json_value!({ "type": "number", "minimum": 0.0, "maximum": 1.0 });
+    let port = json_value!({ "type": "integer", "minimum": 1, "maximum": 65535 });
+    let strings = json_value!({ "type": "array", "items": string });
+    let pointer = json_value!({
+        "type": "object",
+        "required": ["domain", "db"],
+        "additionalProperties": false,
+        "properties": { "domain": string, "db": string }
+    });
+    let roles = json_value!({ "enum": ["viewer", "editor", "validator", "admin"] });
+
+    json_value!({
+        "$id": CONFIG_SCHEMA_ID,
+        "type": "object",
+        "required": [
+            "_id", "handle", "_created_at", "_updated_at", "@type",
+            "mount_points", "core", "paths", "active_dapp_id", "workstation_id"
+        ],
+        "additionalProperties": false,
+        "properties": {
+            "_id": string,
+            "_created_at": string,
+            "_updated_at": string,
+            "@type": { "type": ["string", "array"] },
+            "handle": string,
+            "name": { "type": ["object", "null"], "additionalProperties": string },
+            "status": string,
+            "description": { "type": ["string", "object"] },
+            "mount_points": {
+                "type": "object",
+                "required": [
+                    "system", "raise", "exploration", "modeling",
+                    "simulation", "integration", "production", "operation"
+                ],
+                "additionalProperties": false,
+                "properties": {
+                    "system": pointer, "raise": pointer, "exploration": pointer,
+                    "modeling": pointer, "simulation": pointer, "integration": pointer,
+                    "production": pointer, "operation": pointer
+                }
+            },
+            "core": {
+                "type": "object",
+                "required": [
+                    "env_mode", "graph_mode", "log_level",
+                    "vector_store_provider", "language", "use_gpu"
+                ],
+                "additionalProperties": false,
+                "properties": {
+                    "env_mode": string, "graph_mode": string, "log_level": string,
+                    "vector_store_provider": string, "language": string, "use_gpu": boolean
+                }
+            },
+            "paths": { "type": ["object", "array"] },
+            "active_dapp_id": string,
+            "workstation_id": string,
+            "active_services": strings,
+            "active_components": strings,
+            "system_assets": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "schemas_uri": opt_string,
+                    "locales_uri": opt_string,
+                    "ontologies_uri": opt_string,
+                    "ai_assets_paths": {
+                        "type": ["object", "null"],
+                        "additionalProperties": false,
+                        "properties": {
+                            "models": opt_string, "embeddings": opt_string, "lora": opt_string,
+                            "voice": opt_string, "ontologies": opt_string
+                        }
+                    }
+                }
+            },
+            "telemetry": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "enabled": boolean,
+                    "otlp_endpoint": string,
+                    "service_name": string,
+                    "default_sample_ratio": ratio,
+                    "module_sampling": { "type": "object", "additionalProperties": ratio },
+                    "export_interval_secs": positive
+                }
+            },
+            "network": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "proxy": {
+                        "type": ["object", "null"],
+                        "required": ["url"],
+                        "additionalProperties": false,
+                        "properties": {
+                            "url": string, "no_proxy": strings,
+                            "username": opt_string, "password_env": opt_string
+                        }
+                    },
+                    "use_system_proxy": boolean,
+                    "tls": {
+                        "type": ["object", "null"],
+                        "additionalProperties": false,
+                        "properties": { "identity_pem": opt_string, "ca_bundle_pem": opt_string }
+                    },
+                    "circuit_breaker": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": { "failure_threshold": positive, "open_duration_secs": count }
+                    }
+                }
+            },
+            "vacuum": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "enabled": boolean, "interval_secs": positive, "min_temp_age_secs": count
+                }
+            },
+            "ttl": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "enabled": boolean,
+                    "interval_secs": positive,
+                    "collections": { "type": "object", "additionalProperties": { "type": "object" } }
+                }
+            },
+            "reputation": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "missed_vote_penalty": count, "invalid_proposal_penalty": count,
+                    "double_vote_penalty": count, "exclusion_threshold": positive,
+                    "exclusion_minutes": count
+                }
+            },
+            "ai_budget": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "prices": {
+                        "type": "object",
+                        "additionalProperties": {
+                            "type": "object",
+                            "additionalProperties": false,
+                            "properties": {
+                                "input_per_mtok": { "type": "number", "minimum": 0.0 },
+                                "output_per_mtok": { "type": "number", "minimum": 0.0 }
+                            }
+                        }
+                    },
+                    "monthly_budget": { "type": ["number", "null"], "minimum": 0.0 },
+                    "project_budgets": {
+                        "type": "object",
+                        "additionalProperties": { "type": "number", "minimum": 0.0 }
+                    },
+                    "soft_warning_ratio": ratio
+                }
+            },
+            "webhooks": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": { "enabled": boolean, "host": string, "port": port }
+            },
+            "server": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "host": string, "port": port, "token_secret": string, "service_user": string
+                }
+            },
+            "workflow_scheduling": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "max_concurrent": positive,
+                    "class_limits": { "type": "object", "additionalProperties": count },
+                    "aging_secs": count
+                }
+            },
+            "plan_review": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "enabled": boolean, "enforce": boolean, "max_nodes": positive,
+                    "tokens_per_llm_call": count, "max_estimated_tokens": count
+                }
+            },
+            "access_control": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "enabled": boolean,
+                    "default_role": roles,
+                    "user_roles": {
+                        "type": "object",
+                        "additionalProperties": { "type": "array", "items": roles }
+                    },
+                    "policies": {
+                        "type": "object",
+                        "additionalProperties": {
+                            "type": "object",
+                            "additionalProperties": false,
+                            "properties": { "read": boolean, "allow": strings, "deny": strings }
+                        }
+                    },
+                    "oidc": {
+                        "type": ["object", "null"],
+                        "required": ["issuer", "public_key"],
+                        "additionalProperties": false,
+                        "properties": {
+                            "issuer": string, "audience": opt_string,
+                            "public_key": string, "roles_claim": string
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Tous les problèmes du document : schéma, ports des services et chemins.
+pub fn validate_config_document(doc: &JsonValue) -> Vec<ConfigProblem> {
+    let mut problems = Vec::new();
+    check_node(doc, &config_schema(), "", &mut problems);
+    check_service_ports(doc, &mut problems);
+    check_paths(doc, &mut problems);
+    problems
+}
+
+/// Valide le document chargé depuis `source` ; en cas de problème, les affiche tous et
+/// échoue avec `ERR_CONFIG_VALIDATION_FAILED` (liste complète dans le contexte).
+pub fn ensure_valid_config(doc: &JsonValue, source: &str) -> RaiseResult<()> {
+    let problems = validate_config_document(doc);
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    let report = problems
+        .iter()
+        .map(|p| format!("\n   - [{}] {} : {}", p.code, p.path, p.message))
+        .collect::<String>();
+    crate::kernel_fatal!("Validation de la configuration", source, report);
+
+    raise_error!(
+        "ERR_CONFIG_VALIDATION_FAILED",
+        error = format!(
+            "{} problème(s) dans la configuration '{}'.",
+            problems.len(),
+            source
+        ),
+        context = json_value!({ "file": source, "problems": problems })
+    );
+}
+
+fn check_node(value: &JsonValue, schema: &JsonValue, path: &str, out: &mut Vec<ConfigProblem>) {
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            JsonValue::String(t) => vec![t.as_str()],
+            JsonValue::Array(ts) => ts.iter().filter_map(|t| t.as_str()).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|t| matches_type(value, t)) {
+            out.push(ConfigProblem::new(
+                "ERR_CONFIG_TYPE_MISMATCH",
+                path,
+                format!(
+                    "type '{}' attendu, '{}' trouvé",
+                    types.join("|"),
+                    type_name(value)
+                ),
+            ));
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|v| v.as_array()) {
+        if !allowed.contains(value) {
+            out.push(ConfigProblem::new(
+                "ERR_CONFIG_VALUE_OUT_OF_RANGE",
+                path,
+                format!("{} n'est pas une valeur admise ({:?})", value, allowed),
+            ));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        let min = schema.get("minimum").and_then(|v| v.as_f64());
+        let max = schema.get("maximum").and_then(|v| v.as_f64());
+        if min.is_some_and(|min| number < min) || max.is_some_and(|max| number > max) {
+            out.push(ConfigProblem::new(
+                "ERR_CONFIG_VALUE_OUT_OF_RANGE",
+                path,
+                format!(
+                    "{} hors bornes [{}, {}]",
+                    value,
+                    min.map_or("-∞".to_string(), |v| v.to_string()),
+                    max.map_or("+∞".to_string(), |v| v.to_string())
+                ),
+            ));
+        }
+    }
+
+    if let Some(obj) = value.as_object() {
+        if let Some(required) = schema.get("required").and_then(|v| v.as_array()) {
+            for key in required.iter().filter_map(|k| k.as_str()) {
+                if !obj.contains_key(key) {
+                    out.push(ConfigProblem::new(
+                        "ERR_CONFIG_MISSING_KEY",
+                        &join_path(path, key),
+                        "clé obligatoire absente",
+                    ));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(|v| v.as_object());
+        for (key, child) in obj {
+            let child_path = join_path(path, key);
+            if let Some(sub) = properties.and_then(|p| p.get(key)) {
+                check_node(child, sub, &child_path, out);
+                continue;
+            }
+            match schema.get("additionalProperties") {
+                Some(JsonValue::Bool(false)) if !is_exempt_key(key) => {
+                    let hint = properties
+                        .and_then(|p| closest_key(key, p.keys()))
+                        .map(|k| format!(" (vouliez-vous dire '{}' ?)", k))
+                        .unwrap_or_default();
+                    out.push(ConfigProblem::new(
+                        "ERR_CONFIG_UNKNOWN_KEY",
+                        &child_path,
+                        format!("clé inconnue{}", hint),
+                    ));
+                }
+                Some(sub) if sub.is_object() => check_node(child, sub, &child_path, out),
+                _ => {}
+            }
+        }
+    }
+
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (index, item) in array.iter().enumerate() {
+            check_node(item, items, &format!("{}[{}]", path, index), out);
+        }
+    }
+}
+
+/// Ports explicites des services déclarés, et absence de collision entre eux.
+fn check_service_ports(doc: &JsonValue, out: &mut Vec<ConfigProblem>) {
+    let webhooks = doc.get("webhooks").filter(|w| w.is_object());
+    let webhooks_enabled = webhooks
+        .and_then(|w| w.get("enabled"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let server = doc.get("server").filter(|s| s.is_object());
+
+    if webhooks_enabled && webhooks.and_then(|w| w.get("port")).is_none() {
+        out.push(ConfigProblem::new(
+            "ERR_CONFIG_SERVICE_PORT_MISSING",
+            "webhooks.port",
+            "webhooks activés sans port d'écoute explicite",
+        ));
+    }
+    if server.is_some_and(|s| s.get("port").is_none()) {
+        out.push(ConfigProblem::new(
+            "ERR_CONFIG_SERVICE_PORT_MISSING",
+            "server.port",
+            "section 'server' déclarée sans port d'écoute",
+        ));
+    }
+
+    let (Some(webhooks), Some(server)) = (webhooks, server) else {
+        return;
+    };
+    if !webhooks_enabled {
+        return;
+    }
+    let defaults = (WebhookConfig::default(), ServerConfig::default());
+    let endpoint = |section: &JsonValue, host: &str, port: u16| {
+        (
+            section
+                .get("host")
+                .and_then(|v| v.as_str())
+                .unwrap_or(host)
+                .to_string(),
+            section.get("port").and_then(|v| v.as_u64()),
+            port,
+        )
+    };
+    let (w_host, w_port, w_default) = endpoint(webhooks, &defaults.0.host, defaults.0.port);
+    let (s_host, s_port, s_default) = endpoint(server, &defaults.1.host, defaults.1.port);
+    let w_port = w_port.unwrap_or(w_default as u64);
+    let s_port = s_port.unwrap_or(s_default as u64);
+    let hosts_overlap = w_host == s_host || w_host == "0.0.0.0" || s_host == "0.0.0.0";
+    if w_port == s_port && hosts_overlap {
+        out.push(ConfigProblem::new(
+            "ERR_CONFIG_SERVICE_PORT_CONFLICT",
+            "server.port",
+            format!("port {} déjà utilisé par webhooks.port", s_port),
+        ));
+    }
+}
+
+/// Chemins déclarés : non vides, résolubles, et pas sous un fichier existant.
+fn check_paths(doc: &JsonValue, out: &mut Vec<ConfigProblem>) {
+    let entries: Vec<(String, Option<&JsonValue>)> = match doc.get("paths") {
+        Some(JsonValue::Object(map)) => map.iter().map(|(k, v)| (k.clone(), Some(v))).collect(),
+        Some(JsonValue::Array(items)) => items
+            .iter()
+            .filter_map(|item| {
+                let id = item.get("id")?.as_str()?;
+                Some((id.to_string(), item.get("value")))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    for (key, value) in &entries {
+        let path = join_path("paths", key);
+        let Some(raw) = value.and_then(|v| v.as_str()) else {
+            out.push(ConfigProblem::new(
+                "ERR_CONFIG_PATH_INVALID",
+                &path,
+                "chemin attendu sous forme de chaîne",
+            ));
+            continue;
+        };
+        if let Some(reason) = path_problem(raw) {
+            out.push(ConfigProblem::new("ERR_CONFIG_PATH_INVALID", &path, reason));
+        }
+    }
+
+    for required in REQUIRED_PATHS {
+        let declared = entries.iter().any(|(k, _)| k == required);
+        if !declared && RuntimeEnv::var(required).is_err() {
+            out.push(ConfigProblem::new(
+                "ERR_CONFIG_PATH_MISSING",
+                &join_path("paths", required),
+                "chemin indispensable absent (ni dans 'paths', ni dans l'environnement)",
+            ));
+        }
+    }
+}
+
+fn path_problem(raw: &str) -> Option<String> {
+    if raw.trim().is_empty() {
+        return Some("chemin vide".to_string());
+    }
+    if raw.contains('\0') {
+        return Some("caractère nul dans le chemin".to_string());
+    }
+    if raw.starts_with("~/") && dirs::home_dir().is_none() {
+        return Some("'~' utilisé sans dossier personnel résolu".to_string());
+    }
+
+    let path = PathBuf::from(raw);
+    if !path.is_absolute() {
+        return None;
+    }
+    // Le premier ancêtre existant doit être un dossier : sinon le chemin est inconstructible.
+    let existing = path.ancestors().find(|p| p.exists())?;
+    if existing.is_dir() {
+        return None;
+    }
+    Some(format!(
+        "'{}' est un fichier, pas un dossier",
+        existing.display()
+    ))
+}
+
+fn matches_type(value: &JsonValue, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(n) if n.is_f64() => "number",
+        JsonValue::Number(_) => "integer",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+fn is_exempt_key(key: &str) -> bool {
+    key.starts_with('_') || key.starts_with('$') || key.starts_with('@') || key.starts_with("x_")
+}
+
+fn join_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+/// Clé connue la plus proche (distance d'édition ≤ 2), pour suggérer une correction.
+fn closest_key<'a>(key: &str, candidates: impl Iterator<Item = &'a String>) -> Option<&'a str> {
+    candidates
+        .map(|c| (edit_distance(key, c), c))
+        .filter(|(d, _)| *d <= 2)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c.as_str())
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimal_doc() -> JsonValue {
+        let pointer = json_value!({ "domain": "sandbox", "db": "main" });
+        json_value!({
+            "_id": "cfg-1",
+            "handle": "default",
+            "_created_at": "2026-01-01T00:00:00Z",
+            "_updated_at": "2026-01-01T00:00:00Z",
+            "@type": "Configuration",
+            "mount_points": {
+                "system": pointer, "raise": pointer, "exploration": pointer, "modeling": pointer,
+                "simulation": pointer, "integration": pointer, "production": pointer,
+                "operation": pointer
+            },
+            "core": {
+                "env_mode": "production", "graph_mode": "none", "log_level": "info",
+                "vector_store_provider": "memory", "language": "fr", "use_gpu": false
+            },
+            "paths": { "PATH_RAISE_DOMAIN": "~/raise_domain" },
+            "active_dapp_id": "dapp",
+            "workstation_id": "ws"
+        })
+    }
+
+    #[test]
+    fn test_valid_document_has_no_problem() {
+        let mut doc = minimal_doc();
+        doc["webhooks"] = json_value!({ "enabled": true, "port": 8787 });
+        doc["server"] = json_value!({ "port": 8790 });
+        doc["x_notes"] = json_value!("extension libre");
+        assert_eq!(validate_config_document(&doc), Vec::<ConfigProblem>::new());
+    }
+
+    #[test]
+    fn test_all_problems_reported_at_once() {
+        let mut doc = minimal_doc();
+        doc.as_object_mut().unwrap().remove("workstation_id");
+        doc["core"]["use_gpu"] = json_value!("yes");
+        doc["telemetry"] = json_value!({ "enabeld": true, "default_sample_ratio": 2.0 });
+        doc["webhooks"] = json_value!({ "enabled": true });
+        doc["server"] = json_value!({ "host": "0.0.0.0", "port": 8787 });
+        doc["access_control"] = json_value!({ "default_role": "owner" });
+        doc["paths"]["PATH_RAISE_DATASET"] = json_value!("");
+
+        let problems = validate_config_document(&doc);
+        let found: Vec<(&str, &str)> = problems
+            .iter()
+            .map(|p| (p.code.as_str(), p.path.as_str()))
+            .collect();
+        for expected in [
+            ("ERR_CONFIG_MISSING_KEY", "workstation_id"),
+            ("ERR_CONFIG_TYPE_MISMATCH", "core.use_gpu"),
+            ("ERR_CONFIG_UNKNOWN_KEY", "telemetry.enabeld"),
+            (
+                "ERR_CONFIG_VALUE_OUT_OF_RANGE",
+                "telemetry.default_sample_ratio",
+            ),
+            (
+                "ERR_CONFIG_VALUE_OUT_OF_RANGE",
+                "access_control.default_role",
+            ),
+            ("ERR_CONFIG_SERVICE_PORT_MISSING", "webhooks.port"),
+            ("ERR_CONFIG_SERVICE_PORT_CONFLICT", "server.port"),
+            ("ERR_CONFIG_PATH_INVALID", "paths.PATH_RAISE_DATASET"),
+        ] {
+            assert!(
+                found.contains(&expected),
+                "{:?} absent de {:?}",
+                expected,
+                found
+            );
+        }
+        let typo = problems
+            .iter()
+            .find(|p| p.path == "telemetry.enabeld")
+            .unwrap();
+        assert!(typo.message.contains("'enabled'"));
+
+        let err = ensure_valid_config(&doc, "configs/default.json").unwrap_err();
+        let AppError::Structured(data) = &err;
+        assert_eq!(data.code, "ERR_CONFIG_VALIDATION_FAILED");
+        assert_eq!(
+            data.context["problems"].as_array().map(|p| p.len()),
+            Some(problems.len())
+        );
+    }
+
+    #[test]
+    fn test_path_under_a_file_is_rejected() -> RaiseResult<()> {
+        let dir = tempdir()?;
+        let file = dir.path().join("not_a_dir");
+        fs::write_sync(&file, b"x")?;
+
+        assert!(path_problem(&dir.path().join("new/sub").to_string_lossy()).is_none());
+        assert!(path_problem(&file.join("sub").to_string_lossy()).is_some());
+        assert!(path_problem("relative/dir").is_none());
+        assert!(path_problem("  ").is_some());
+        Ok(())
+    }
+}
@@ -149,6 +149,98 @@ pub fn deep_merge_values(a: &mut JsonValue, b: JsonValue) {
     }
 }
 
+/// Sérialisation canonique inspirée de RFC 8785 (JCS), base de tout hachage de contenu :
+/// clés triées par unités UTF-16, aucun espace, nombres au format ECMAScript
+/// (`1.0` → `1`, `1e-7`, `1e+21`). Deux valeurs égales produisent les mêmes octets.
+pub fn to_canonical_string(value: &JsonValue) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &JsonValue, out: &mut String) {
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        JsonValue::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(i), _, _) => out.push_str(&i.to_string()),
+            (_, Some(u), _) => out.push_str(&u.to_string()),
+            (_, _, Some(f)) => out.push_str(&canonical_number(f)),
+            _ => out.push_str(&n.to_string()),
+        },
+        JsonValue::String(s) => write_canonical_str(s, out),
+        JsonValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        JsonValue::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort_by(|a, b| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_str(key, out);
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Échappement JSON minimal (`"`, `\\`, caractères de contrôle), identique à JCS.
+fn write_canonical_str(s: &str, out: &mut String) {
+    match serde_json::to_string(s) {
+        Ok(escaped) => out.push_str(&escaped),
+        Err(_) => out.push_str("\"\""),
+    }
+}
+
+/// `Number.prototype.toString` d'ECMAScript à partir de la représentation la plus courte.
+fn canonical_number(f: f64) -> String {
+    if f == 0.0 || !f.is_finite() {
+        return "0".to_string();
+    }
+    let sign = if f < 0.0 { "-" } else { "" };
+    // `{:e}` donne les chiffres significatifs minimaux : `d.ddde±x`
+    let sci = format!("{:e}", f.abs());
+    let (mantissa, exp) = sci.split_once('e').unwrap_or((&sci, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exp.parse::<i32>().unwrap_or(0) + 1;
+
+    let body = if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat((-n) as usize), digits)
+    } else {
+        let rest = if k > 1 {
+            format!(".{}", &digits[1..])
+        } else {
+            String::new()
+        };
+        let e = n - 1;
+        format!(
+            "{}{}e{}{}",
+            &digits[..1],
+            rest,
+            if e < 0 { "-" } else { "+" },
+            e.abs()
+        )
+    };
+    format!("{}{}", sign, body)
+}
+
 /// 🤖 IA NOTE : Désérialise une chaîne YAML en un type `T`.
 /// Utilise le moteur `serde_yaml` avec la gestion d'erreur structurée de RAISE.
 pub fn deserialize_from_yaml<T: DeserializableOwned>(content: &str) -> RaiseResult<T> {
@@ -275,4 +367,27 @@ mod tests {
         assert_eq!(base["api"]["host"], "localhost");
         assert_eq!(base["db"], "staging");
     }
+
+    #[test]
+    fn test_canonical_json() {
+        let value: JsonValue = deserialize_from_str(
+            r#"{ "b": [1.0, 2.5, 1e-7, 1e21, -0.0], "a": { "z": null, "é": "\u0001\"" }, "\ufb33": 2, "\u20ac": true, "\ud83d\ude00": 1 }"#,
+        )
+        .unwrap();
+        // Tri UTF-16 (et non UTF-8) : U+FB33 après U+1F600, comme dans RFC 8785
+        assert_eq!(
+            to_canonical_string(&value),
+            r#"{"a":{"z":null,"é":"\u0001\""},"b":[1,2.5,1e-7,1e+21,0],"€":true,"😀":1,"דּ":2}"#
+        );
+        // Indépendante de l'ordre d'insertion
+        let reordered = json_value!({ "a": 1, "b": 2 });
+        let original = json_value!({ "b": 2, "a": 1 });
+        assert_eq!(
+            to_canonical_string(&reordered),
+            to_canonical_string(&original)
+        );
+        assert_eq!(canonical_number(123456.789), "123456.789");
+        assert_eq!(canonical_number(0.000001), "0.000001");
+        assert_eq!(canonical_number(-1.5e300), "-1.5e+300");
+    }
 }
@@ -0,0 +1,343 @@
+// FICHIER : crates/raise-core/src/utils/data/live_config.rs
+//! Couche de configuration "à chaud" posée au-dessus du singleton `AppConfig`.
+//! Les valeurs sont adressées par clé pointée (`core.log_level`, `llm.url`, ...) :
+//! une surcharge runtime (DB ou fichier surveillé) masque la valeur de démarrage.
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::kernel::events::{publish_event, RaiseEvent};
+use crate::utils::data::config_validation::validate_config_document;
+use crate::utils::prelude::*;
+
+/// Collection système portant le document des surcharges.
+const OVERRIDES_COLLECTION: &str = "configs";
+/// Handle du document persistant les surcharges runtime.
+const OVERRIDES_HANDLE: &str = "runtime_overrides";
+
+static LIVE_OVERRIDES: StaticCell<SyncRwLock<OrderedMap<String, JsonValue>>> = StaticCell::new();
+
+fn overrides() -> &'static SyncRwLock<OrderedMap<String, JsonValue>> {
+    LIVE_OVERRIDES.get_or_init(|| SyncRwLock::new(OrderedMap::new()))
+}
+
+/// Origine d'une modification de configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigChangeSource {
+    Api,
+    File,
+    Database,
+}
+
+impl ConfigChangeSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Api => "api",
+            Self::File => "file",
+            Self::Database => "database",
+        }
+    }
+}
+
+// =========================================================================
+// LECTURE / ÉCRITURE
+// =========================================================================
+
+impl AppConfig {
+    /// Valeur effective d'une clé pointée : surcharge runtime, sinon config de démarrage.
+    pub fn live_value(key: &str) -> Option<JsonValue> {
+        if let Ok(map) = overrides().read() {
+            if let Some(v) = map.get(key) {
+                return Some(v.clone());
+            }
+        }
+
+        let base = json::serialize_to_value(AppConfig::get()).ok()?;
+        lookup_dotted(&base, key).cloned()
+    }
+
+    /// Liste les surcharges runtime actives.
+    pub fn live_overrides() -> OrderedMap<String, JsonValue> {
+        overrides().read().map(|m| m.clone()).unwrap_or_default()
+    }
+
+    /// Applique une surcharge en mémoire et notifie les abonnés.
+    /// Retourne `false` si la valeur était déjà identique (aucune notification).
+    pub fn apply_live_value(key: &str, value: JsonValue, source: ConfigChangeSource) -> bool {
+        let mut map = match overrides().write() {
+            Ok(m) => m,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if map.get(key) == Some(&value) {
+            return false;
+        }
+        map.insert(key.to_string(), value);
+        drop(map);
+
+        user_info!(
+            "INF_CONFIG_LIVE_UPDATED",
+            json_value!({ "key": key, "source": source.as_str() })
+        );
+        publish_event(RaiseEvent::ConfigChanged {
+            key: key.to_string(),
+            source: source.as_str().to_string(),
+        });
+        true
+    }
+
+    /// Surcharge une clé et la persiste dans la base système.
+    pub async fn set_live_value(
+        manager: &CollectionsManager<'_>,
+        key: &str,
+        value: JsonValue,
+    ) -> RaiseResult<()> {
+        if key.trim().is_empty() {
+            raise_error!(
+                "ERR_CONFIG_KEY_EMPTY",
+                error = "La clé de configuration ne peut pas être vide."
+            );
+        }
+
+        if !manager
+            .list_collections()
+            .await?
+            .iter()
+            .any(|c| c == OVERRIDES_COLLECTION)
+        {
+            let config = AppConfig::get();
+            let schema_uri = format!(
+                "db://{}/{}/schemas/v1/db/generic.schema.json",
+                config.mount_points.system.domain, config.mount_points.system.db
+            );
+            manager
+                .create_collection(OVERRIDES_COLLECTION, &schema_uri)
+                .await?;
+        }
+
+        let mut persisted = Self::load_persisted_overrides(manager).await?;
+        persisted.insert(key.to_string(), value.clone());
+
+        manager
+            .upsert_document(
+                OVERRIDES_COLLECTION,
+                json_value!({ "handle": OVERRIDES_HANDLE, "overrides": persisted }),
+            )
+            .await?;
+
+        Self::apply_live_value(key, value, ConfigChangeSource::Api);
+        Ok(())
+    }
+
+    /// Recharge les surcharges persistées (à appeler au boot, après `init`).
+    pub async fn load_live_overrides(manager: &CollectionsManager<'_>) -> RaiseResult<usize> {
+        let persisted = Self::load_persisted_overrides(manager).await?;
+        let count = persisted.len();
+        for (key, value) in persisted {
+            Self::apply_live_value(&key, value, ConfigChangeSource::Database);
+        }
+        Ok(count)
+    }
+
+    async fn load_persisted_overrides(
+        manager: &CollectionsManager<'_>,
+    ) -> RaiseResult<OrderedMap<String, JsonValue>> {
+        let Some(doc) = manager
+            .get_document(OVERRIDES_COLLECTION, OVERRIDES_HANDLE)
+            .await?
+        else {
+            return Ok(OrderedMap::new());
+        };
+
+        let mut out = OrderedMap::new();
+        if let Some(obj) = doc.get("overrides").and_then(|v| v.as_object()) {
+            for (k, v) in obj {
+                out.insert(k.clone(), v.clone());
+            }
+        }
+        Ok(out)
+    }
+}
+
+// =========================================================================
+// SURVEILLANCE DU FICHIER DE CONFIGURATION (HOT RELOAD)
+// =========================================================================
+
+/// Compare deux versions du document de configuration et applique chaque feuille modifiée.
+/// Retourne la liste des clés notifiées.
+pub fn apply_config_diff(previous: &JsonValue, current: &JsonValue) -> Vec<String> {
+    let mut before = OrderedMap::new();
+    let mut after = OrderedMap::new();
+    flatten_leaves("", previous, &mut before);
+    flatten_leaves("", current, &mut after);
+
+    let mut changed = Vec::new();
+    for (key, value) in after {
+        if key.starts_with('_') {
+            continue; // Métadonnées techniques (_id, _updated_at...)
+        }
+        if before.get(&key) != Some(&value)
+            && AppConfig::apply_live_value(&key, value, ConfigChangeSource::File)
+        {
+            changed.push(key);
+        }
+    }
+    changed
+}
+
+/// Lance une surveillance (par scrutation de la date de modification) du fichier source.
+pub fn spawn_config_watcher(
+    path: PathBuf,
+    poll_interval: TimeDuration,
+) -> tokio::task::JoinHandle<()> {
+    spawn_async_task(async move {
+        let mut last_modified = fs::metadata_async(&path)
+            .await
+            .ok()
+            .and_then(|m| m.modified().ok());
+        let mut snapshot: Option<JsonValue> = fs::read_json_async(&path).await.ok();
+
+        loop {
+            sleep_async(poll_interval).await;
+
+            let modified = fs::metadata_async(&path)
+                .await
+                .ok()
+                .and_then(|m| m.modified().ok());
+            if modified.is_none() || modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            match fs::read_json_async::<JsonValue>(&path).await {
+                Ok(current) => {
+                    // Un document invalide n'est pas appliqué : la configuration courante reste en place
+                    let problems = validate_config_document(&current);
+                    if !problems.is_empty() {
+                        user_warn!(
+                            "WRN_CONFIG_HOT_RELOAD_INVALID",
+                            json_value!({ "file": path.to_string_lossy(), "problems": problems })
+                        );
+                        continue;
+                    }
+                    if let Some(previous) = &snapshot {
+                        let changed = apply_config_diff(previous, &current);
+                        if !changed.is_empty() {
+                            user_info!(
+                                "INF_CONFIG_HOT_RELOADED",
+                                json_value!({ "file": path.to_string_lossy(), "keys": changed })
+                            );
+                        }
+                    }
+                    snapshot = Some(current);
+                }
+                Err(e) => user_warn!(
+                    "WRN_CONFIG_HOT_RELOAD_PARSE",
+                    json_value!({ "file": path.to_string_lossy(), "error": e.to_string() })
+                ),
+            }
+        }
+    })
+}
+
+fn lookup_dotted<'a>(root: &'a JsonValue, key: &str) -> Option<&'a JsonValue> {
+    key.split('.').try_fold(root, |node, part| node.get(part))
+}
+
+fn flatten_leaves(prefix: &str, value: &JsonValue, out: &mut OrderedMap<String, JsonValue>) {
+    match value.as_object() {
+        Some(obj) if !obj.is_empty() => {
+            for (k, v) in obj {
+                let key = if prefix.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{}.{}", prefix, k)
+                };
+                flatten_leaves(&key, v, out);
+            }
+        }
+        _ => {
+            out.insert(prefix.to_string(), value.clone());
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernel::events::subscribe_events;
+    use crate::utils::testing::DbSandbox;
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_live_value_falls_back_to_boot_config() -> RaiseResult<()> {
+        let _sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+
+        assert_eq!(
+            AppConfig::live_value("core.language"),
+            Some(json_value!(config.core.language))
+        );
+        assert!(AppConfig::live_value("core.does_not_exist").is_none());
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_set_live_value_persists_and_notifies() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        let mut rx = subscribe_events();
+
+        AppConfig::set_live_value(&manager, "test.live.llm_url", json_value!("http://x:1")).await?;
+
+        assert_eq!(
+            AppConfig::live_value("test.live.llm_url"),
+            Some(json_value!("http://x:1"))
+        );
+
+        // Notification reçue sur le bus
+        loop {
+            match rx.recv().await {
+                Ok(RaiseEvent::ConfigChanged { key, .. }) if key == "test.live.llm_url" => break,
+                Ok(_) | Err(AsyncBroadcast::RecvError::Lagged(_)) => continue,
+                Err(e) => panic!("Bus fermé : {}", e),
+            }
+        }
+
+        // Persistance : relecture depuis la base
+        let persisted = AppConfig::load_persisted_overrides(&manager).await?;
+        assert_eq!(
+            persisted.get("test.live.llm_url"),
+            Some(&json_value!("http://x:1"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_config_diff_detects_changed_leaves() {
+        let previous = json_value!({
+            "_updated_at": "t0",
+            "core": { "log_level": "info", "use_gpu": false },
+            "test_diff": { "port": 8080 }
+        });
+        let current = json_value!({
+            "_updated_at": "t1",
+            "core": { "log_level": "info", "use_gpu": false },
+            "test_diff": { "port": 9090 }
+        });
+
+        let changed = apply_config_diff(&previous, &current);
+        assert_eq!(changed, vec!["test_diff.port".to_string()]);
+        assert_eq!(
+            AppConfig::live_overrides().get("test_diff.port"),
+            Some(&json_value!(9090))
+        );
+    }
+}
@@ -1,8 +1,11 @@
 // FICHIER : src-tauri/src/utils/data/mod.rs
 
 pub mod config;
+pub mod config_validation;
 pub mod encoding;
 pub mod json;
+pub mod live_config;
+pub mod secrets;
 // =========================================================================
 // FAÇADE `data` : Re-exports Sémantiques (AI-Ready)
 // =========================================================================
@@ -17,6 +20,9 @@ pub use json::{
 };
 
 pub use config::{AppConfig, CoreConfig, CONFIG};
+pub use config_validation::{validate_config_document, ConfigProblem};
+pub use live_config::{spawn_config_watcher, ConfigChangeSource};
+pub use secrets::{redact_secrets, SecretBackendKind, SecretsProvider};
 // Types standards et structures de données fréquemment utilisés dans le domaine métier
 // --- Contrats de Sérialisation ---
 pub use serde::de::DeserializeOwned as DeserializableOwned;
@@ -0,0 +1,744 @@
+// FICHIER : crates/raise-core/src/utils/data/secrets.rs
+//! Fournisseur de secrets (clés API, jetons) découplé de la configuration.
+//! Chaîne de résolution : trousseau de l'OS → coffre chiffré local → variables d'environnement.
+//! Les réglages de service peuvent référencer un secret via `"api_key": "secret:gemini_api_key"`.
+//! Toute valeur résolue est enregistrée pour être masquée dans les journaux.
+
+use crate::utils::core::logs::LogWriterTrait;
+use crate::utils::core::CryptoAead::{Aead, Aes256Gcm, KeyInit, Nonce};
+use crate::utils::core::{RawIoResult, RuntimeEnv};
+use crate::utils::data::encoding::{decode_base64, encode_base64};
+use crate::utils::io::io_traits::SyncWrite;
+use crate::utils::io::os_types::ProcessIoConfig;
+use crate::utils::prelude::*;
+
+/// Préfixe d'une référence de secret dans un document de configuration.
+pub const SECRET_REF_PREFIX: &str = "secret:";
+/// Passphrase du coffre chiffré (jamais persistée).
+pub const VAULT_PASSPHRASE_ENV: &str = "RAISE_VAULT_PASSPHRASE";
+/// Préfixe prioritaire des secrets fournis par l'environnement.
+const ENV_SECRET_PREFIX: &str = "RAISE_SECRET_";
+const KEYRING_SERVICE: &str = "raise";
+const VAULT_KDF_ROUNDS: u32 = 100_000;
+const REDACTION_MASK: &str = "***REDACTED***";
+/// En dessous, une valeur est trop courte pour être masquée sans bruit.
+const MIN_REDACTABLE_LEN: usize = 6;
+
+static GLOBAL_PROVIDER: StaticCell<SecretsProvider> = StaticCell::new();
+static REDACTED_VALUES: StaticCell<SyncRwLock<UniqueSet<String>>> = StaticCell::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretBackendKind {
+    Keyring,
+    Vault,
+    Env,
+}
+
+impl SecretBackendKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Keyring => "keyring",
+            Self::Vault => "vault",
+            Self::Env => "env",
+        }
+    }
+
+    pub fn parse(value: &str) -> RaiseResult<Self> {
+        match value.to_lowercase().as_str() {
+            "keyring" => Ok(Self::Keyring),
+            "vault" => Ok(Self::Vault),
+            "env" => Ok(Self::Env),
+            other => raise_error!(
+                "ERR_SECRET_BACKEND_UNKNOWN",
+                error = format!("Backend de secrets inconnu : {}", other),
+                context = json_value!({ "expected": ["keyring", "vault", "env"] })
+            ),
+        }
+    }
+}
+
+#[async_interface]
+pub trait SecretBackend: Send + Sync {
+    fn kind(&self) -> SecretBackendKind;
+    /// Le backend est-il utilisable sur ce poste (outil présent, passphrase fournie...) ?
+    fn is_available(&self) -> bool;
+    async fn get(&self, key: &str) -> RaiseResult<Option<String>>;
+    async fn set(&self, key: &str, value: &str) -> RaiseResult<()>;
+    async fn delete(&self, key: &str) -> RaiseResult<bool>;
+}
+
+// =========================================================================
+// BACKEND 1 : TROUSSEAU DE L'OS (secret-tool / security)
+// =========================================================================
+
+pub struct KeyringBackend {
+    service: String,
+}
+
+impl Default for KeyringBackend {
+    fn default() -> Self {
+        Self {
+            service: KEYRING_SERVICE.to_string(),
+        }
+    }
+}
+
+impl KeyringBackend {
+    fn tool() -> Option<&'static str> {
+        let candidate = if cfg!(target_os = "macos") {
+            "security"
+        } else if cfg!(target_os = "linux") {
+            "secret-tool"
+        } else {
+            return None;
+        };
+        command_in_path(candidate).then_some(candidate)
+    }
+
+    async fn run(&self, args: &[&str], stdin: Option<&str>) -> RaiseResult<(bool, String)> {
+        use tokio::io::AsyncWriteExt;
+
+        let Some(tool) = Self::tool() else {
+            raise_error!(
+                "ERR_SECRET_KEYRING_UNAVAILABLE",
+                error = "Aucun trousseau système accessible (secret-tool / security)."
+            );
+        };
+
+        let mut child = match AsyncCommand::new(tool)
+            .args(args)
+            .stdin(ProcessIoConfig::piped())
+            .stdout(ProcessIoConfig::piped())
+            .stderr(ProcessIoConfig::piped())
+            .spawn()
+        {
+            Ok(c) => c,
+            Err(e) => raise_error!(
+                "ERR_SECRET_KEYRING_EXEC",
+                error = e,
+                context = json_value!({ "tool": tool })
+            ),
+        };
+
+        if let Some(mut pipe) = child.stdin.take() {
+            if let Some(input) = stdin {
+                if let Err(e) = pipe.write_all(input.as_bytes()).await {
+                    raise_error!("ERR_SECRET_KEYRING_EXEC", error = e);
+                }
+            }
+        }
+
+        match child.wait_with_output().await {
+            Ok(output) => Ok((
+                output.status.success(),
+                String::from_utf8_lossy(&output.stdout)
+                    .trim_end_matches('\n')
+                    .to_string(),
+            )),
+            Err(e) => raise_error!(
+                "ERR_SECRET_KEYRING_EXEC",
+                error = e,
+                context = json_value!({ "tool": tool })
+            ),
+        }
+    }
+}
+
+#[async_interface]
+impl SecretBackend for KeyringBackend {
+    fn kind(&self) -> SecretBackendKind {
+        SecretBackendKind::Keyring
+    }
+
+    fn is_available(&self) -> bool {
+        Self::tool().is_some()
+    }
+
+    async fn get(&self, key: &str) -> RaiseResult<Option<String>> {
+        let (ok, out) = if cfg!(target_os = "macos") {
+            self.run(
+                &[
+                    "find-generic-password",
+                    "-s",
+                    &self.service,
+                    "-a",
+                    key,
+                    "-w",
+                ],
+                None,
+            )
+            .await?
+        } else {
+            self.run(&["lookup", "service", &self.service, "account", key], None)
+                .await?
+        };
+        Ok((ok && !out.is_empty()).then_some(out))
+    }
+
+    async fn set(&self, key: &str, value: &str) -> RaiseResult<()> {
+        let (ok, _) = if cfg!(target_os = "macos") {
+            self.run(
+                &[
+                    "add-generic-password",
+                    "-U",
+                    "-s",
+                    &self.service,
+                    "-a",
+                    key,
+                    "-w",
+                    value,
+                ],
+                None,
+            )
+            .await?
+        } else {
+            let label = format!("RAISE secret {}", key);
+            self.run(
+                &[
+                    "store",
+                    "--label",
+                    &label,
+                    "service",
+                    &self.service,
+                    "account",
+                    key,
+                ],
+                Some(value),
+            )
+            .await?
+        };
+
+        if !ok {
+            raise_error!(
+                "ERR_SECRET_KEYRING_WRITE",
+                error = "Le trousseau système a refusé l'écriture.",
+                context = json_value!({ "key": key })
+            );
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> RaiseResult<bool> {
+        let (ok, _) = if cfg!(target_os = "macos") {
+            self.run(
+                &["delete-generic-password", "-s", &self.service, "-a", key],
+                None,
+            )
+            .await?
+        } else {
+            self.run(&["clear", "service", &self.service, "account", key], None)
+                .await?
+        };
+        Ok(ok)
+    }
+}
+
+fn command_in_path(cmd: &str) -> bool {
+    RuntimeEnv::var_os("PATH")
+        .map(|paths| RuntimeEnv::split_paths(&paths).any(|dir| dir.join(cmd).is_file()))
+        .unwrap_or(false)
+}
+
+// =========================================================================
+// BACKEND 2 : COFFRE CHIFFRÉ LOCAL (AES-256-GCM)
+// =========================================================================
+
+#[derive(Debug, Clone, Default, Serializable, Deserializable)]
+struct VaultFile {
+    salt: String,
+    kdf_rounds: u32,
+    entries: OrderedMap<String, VaultEntry>,
+}
+
+#[derive(Debug, Clone, Serializable, Deserializable)]
+struct VaultEntry {
+    nonce: String,
+    ciphertext: String,
+    updated_at: String,
+}
+
+pub struct VaultBackend {
+    path: PathBuf,
+    passphrase: Option<String>,
+    lock: AsyncMutex<()>,
+}
+
+impl VaultBackend {
+    pub fn new(path: PathBuf, passphrase: Option<String>) -> Self {
+        Self {
+            path,
+            passphrase: passphrase.filter(|p| !p.is_empty()),
+            lock: AsyncMutex::new(()),
+        }
+    }
+
+    /// Coffre du poste : `{PATH_RAISE_DOMAIN}/_system/secrets/vault.json`.
+    pub fn from_config(config: &AppConfig) -> Option<Self> {
+        let path = config
+            .get_path("PATH_RAISE_DOMAIN")?
+            .join("_system")
+            .join("secrets")
+            .join("vault.json");
+        Some(Self::new(path, RuntimeEnv::var(VAULT_PASSPHRASE_ENV).ok()))
+    }
+
+    fn passphrase(&self) -> RaiseResult<&str> {
+        match self.passphrase.as_deref() {
+            Some(p) => Ok(p),
+            None => raise_error!(
+                "ERR_SECRET_VAULT_LOCKED",
+                error = "Coffre verrouillé : passphrase absente.",
+                context = json_value!({ "hint": format!("Définissez {}.", VAULT_PASSPHRASE_ENV) })
+            ),
+        }
+    }
+
+    /// Dérivation de clé par hachage itéré (sel + passphrase).
+    fn derive_key(passphrase: &str, salt: &[u8], rounds: u32) -> Vec<u8> {
+        let mut hasher = CryptoSha256::new();
+        hasher.update(salt);
+        hasher.update(passphrase.as_bytes());
+        let mut key = hasher.finalize().to_vec();
+        for _ in 1..rounds.max(1) {
+            let mut hasher = CryptoSha256::new();
+            hasher.update(&key);
+            hasher.update(salt);
+            key = hasher.finalize().to_vec();
+        }
+        key
+    }
+
+    fn cipher(&self, vault: &VaultFile) -> RaiseResult<Aes256Gcm> {
+        let salt = decode_base64(&vault.salt)?;
+        let key = Self::derive_key(self.passphrase()?, &salt, vault.kdf_rounds);
+        match Aes256Gcm::new_from_slice(&key) {
+            Ok(c) => Ok(c),
+            Err(e) => raise_error!("ERR_SECRET_VAULT_CRYPTO", error = e.to_string()),
+        }
+    }
+
+    async fn load(&self) -> RaiseResult<VaultFile> {
+        if !fs::exists_async(&self.path).await {
+            return Ok(VaultFile {
+                salt: encode_base64(UniqueId::new_v4().as_bytes()),
+                kdf_rounds: VAULT_KDF_ROUNDS,
+                entries: OrderedMap::new(),
+            });
+        }
+        fs::read_json_async(&self.path).await
+    }
+}
+
+#[async_interface]
+impl SecretBackend for VaultBackend {
+    fn kind(&self) -> SecretBackendKind {
+        SecretBackendKind::Vault
+    }
+
+    fn is_available(&self) -> bool {
+        self.passphrase.is_some()
+    }
+
+    async fn get(&self, key: &str) -> RaiseResult<Option<String>> {
+        if !self.is_available() || !fs::exists_async(&self.path).await {
+            return Ok(None);
+        }
+        let vault = self.load().await?;
+        let Some(entry) = vault.entries.get(key) else {
+            return Ok(None);
+        };
+
+        let nonce = decode_base64(&entry.nonce)?;
+        let ciphertext = decode_base64(&entry.ciphertext)?;
+        let plain = match self
+            .cipher(&vault)?
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+        {
+            Ok(p) => p,
+            Err(_) => raise_error!(
+                "ERR_SECRET_VAULT_DECRYPT",
+                error = "Déchiffrement impossible (passphrase erronée ou coffre altéré).",
+                context = json_value!({ "key": key })
+            ),
+        };
+        Ok(Some(String::from_utf8_lossy(&plain).to_string()))
+    }
+
+    async fn set(&self, key: &str, value: &str) -> RaiseResult<()> {
+        let _guard = self.lock.lock().await;
+        let mut vault = self.load().await?;
+        let cipher = self.cipher(&vault)?;
+
+        let nonce_bytes = &UniqueId::new_v4().into_bytes()[..12];
+        let ciphertext = match cipher.encrypt(Nonce::from_slice(nonce_bytes), value.as_bytes()) {
+            Ok(c) => c,
+            Err(e) => raise_error!("ERR_SECRET_VAULT_CRYPTO", error = e.to_string()),
+        };
+
+        vault.entries.insert(
+            key.to_string(),
+            VaultEntry {
+                nonce: encode_base64(nonce_bytes),
+                ciphertext: encode_base64(&ciphertext),
+                updated_at: UtcClock::now().to_rfc3339(),
+            },
+        );
+        fs::write_json_atomic_async(&self.path, &vault).await
+    }
+
+    async fn delete(&self, key: &str) -> RaiseResult<bool> {
+        let _guard = self.lock.lock().await;
+        if !fs::exists_async(&self.path).await {
+            return Ok(false);
+        }
+        let mut vault = self.load().await?;
+        let removed = vault.entries.remove(key).is_some();
+        if removed {
+            fs::write_json_atomic_async(&self.path, &vault).await?;
+        }
+        Ok(removed)
+    }
+}
+
+// =========================================================================
+// BACKEND 3 : VARIABLES D'ENVIRONNEMENT (lecture seule)
+// =========================================================================
+
+#[derive(Default)]
+pub struct EnvBackend;
+
+impl EnvBackend {
+    /// `gemini_api_key` → `RAISE_SECRET_GEMINI_API_KEY`, puis `GEMINI_API_KEY`.
+    fn candidates(key: &str) -> [String; 2] {
+        let upper = key.to_uppercase().replace(['.', '-', ':'], "_");
+        [format!("{}{}", ENV_SECRET_PREFIX, upper), upper]
+    }
+}
+
+#[async_interface]
+impl SecretBackend for EnvBackend {
+    fn kind(&self) -> SecretBackendKind {
+        SecretBackendKind::Env
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    async fn get(&self, key: &str) -> RaiseResult<Option<String>> {
+        Ok(Self::candidates(key)
+            .iter()
+            .find_map(|name| RuntimeEnv::var(name).ok().filter(|v| !v.is_empty())))
+    }
+
+    async fn set(&self, key: &str, _value: &str) -> RaiseResult<()> {
+        raise_error!(
+            "ERR_SECRET_BACKEND_READONLY",
+            error = "Les variables d'environnement sont en lecture seule.",
+            context = json_value!({ "key": key, "hint": Self::candidates(key)[0] })
+        )
+    }
+
+    async fn delete(&self, _key: &str) -> RaiseResult<bool> {
+        Ok(false)
+    }
+}
+
+// =========================================================================
+// FOURNISSEUR (CHAÎNE DE RÉSOLUTION)
+// =========================================================================
+
+pub struct SecretsProvider {
+    backends: Vec<Box<dyn SecretBackend>>,
+}
+
+impl SecretsProvider {
+    pub fn new(backends: Vec<Box<dyn SecretBackend>>) -> Self {
+        Self { backends }
+    }
+
+    /// Chaîne standard : trousseau → coffre → environnement.
+    pub fn from_config(config: &AppConfig) -> Self {
+        let mut backends: Vec<Box<dyn SecretBackend>> = vec![Box::new(KeyringBackend::default())];
+        if let Some(vault) = VaultBackend::from_config(config) {
+            backends.push(Box::new(vault));
+        }
+        backends.push(Box::new(EnvBackend));
+        Self::new(backends)
+    }
+
+    pub fn global() -> &'static SecretsProvider {
+        GLOBAL_PROVIDER.get_or_init(|| Self::from_config(AppConfig::get()))
+    }
+
+    /// Backends disponibles, dans l'ordre de résolution.
+    pub fn backends(&self) -> Vec<(SecretBackendKind, bool)> {
+        self.backends
+            .iter()
+            .map(|b| (b.kind(), b.is_available()))
+            .collect()
+    }
+
+    /// Premier backend disponible qui connaît la clé. La valeur est inscrite au masquage.
+    pub async fn get(&self, key: &str) -> RaiseResult<Option<String>> {
+        for backend in self.backends.iter().filter(|b| b.is_available()) {
+            match backend.get(key).await {
+                Ok(Some(value)) => {
+                    register_secret_for_redaction(&value);
+                    return Ok(Some(value));
+                }
+                Ok(None) => continue,
+                Err(e) => user_warn!(
+                    "WRN_SECRET_BACKEND_FAILED",
+                    json_value!({ "backend": backend.kind().as_str(), "key": key, "error": e.to_string() })
+                ),
+            }
+        }
+        Ok(None)
+    }
+
+    pub async fn require(&self, key: &str) -> RaiseResult<String> {
+        match self.get(key).await? {
+            Some(v) => Ok(v),
+            None => raise_error!(
+                "ERR_SECRET_NOT_FOUND",
+                error = format!("Secret introuvable : {}", key),
+                context = json_value!({
+                    "key": key,
+                    "backends": self.backends().iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>()
+                })
+            ),
+        }
+    }
+
+    /// Écrit dans le backend demandé, sinon dans le premier backend inscriptible disponible.
+    pub async fn set(
+        &self,
+        key: &str,
+        value: &str,
+        target: Option<SecretBackendKind>,
+    ) -> RaiseResult<SecretBackendKind> {
+        if key.trim().is_empty() {
+            raise_error!(
+                "ERR_SECRET_KEY_EMPTY",
+                error = "Le nom du secret ne peut pas être vide."
+            );
+        }
+        register_secret_for_redaction(value);
+
+        let candidates = self.backends.iter().filter(|b| {
+            b.is_available()
+                && b.kind() != SecretBackendKind::Env
+                && target.is_none_or(|t| t == b.kind())
+        });
+        for backend in candidates {
+            match backend.set(key, value).await {
+                Ok(()) => {
+                    user_info!(
+                        "INF_SECRET_STORED",
+                        json_value!({ "key": key, "backend": backend.kind().as_str() })
+                    );
+                    return Ok(backend.kind());
+                }
+                Err(e) if target.is_none() => user_warn!(
+                    "WRN_SECRET_BACKEND_FAILED",
+                    json_value!({ "backend": backend.kind().as_str(), "key": key, "error": e.to_string() })
+                ),
+                Err(e) => return Err(e),
+            }
+        }
+
+        raise_error!(
+            "ERR_SECRET_NO_WRITABLE_BACKEND",
+            error = "Aucun backend de secrets inscriptible n'est disponible.",
+            context = json_value!({
+                "key": key,
+                "target": target.map(|t| t.as_str()),
+                "hint": format!("Installez un trousseau système ou définissez {}.", VAULT_PASSPHRASE_ENV)
+            })
+        )
+    }
+
+    /// Supprime la clé de tous les backends inscriptibles. Retourne `true` si au moins un l'a retirée.
+    pub async fn delete(&self, key: &str) -> RaiseResult<bool> {
+        let mut removed = false;
+        for backend in self.backends.iter().filter(|b| b.is_available()) {
+            removed |= backend.delete(key).await?;
+        }
+        Ok(removed)
+    }
+
+    /// Remplace récursivement chaque chaîne `secret:<clé>` par sa valeur.
+    #[async_recursive]
+    pub async fn resolve_refs(&self, value: &mut JsonValue) -> RaiseResult<usize> {
+        let mut resolved = 0;
+        match value {
+            JsonValue::String(s) => {
+                if let Some(key) = s.strip_prefix(SECRET_REF_PREFIX) {
+                    *s = self.require(key).await?;
+                    resolved += 1;
+                }
+            }
+            JsonValue::Array(items) => {
+                for item in items.iter_mut() {
+                    resolved += self.resolve_refs(item).await?;
+                }
+            }
+            JsonValue::Object(map) => {
+                for (_, item) in map.iter_mut() {
+                    resolved += self.resolve_refs(item).await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(resolved)
+    }
+}
+
+// =========================================================================
+// MASQUAGE AUTOMATIQUE DANS LES JOURNAUX
+// =========================================================================
+
+fn redacted_values() -> &'static SyncRwLock<UniqueSet<String>> {
+    REDACTED_VALUES.get_or_init(|| SyncRwLock::new(UniqueSet::new()))
+}
+
+/// Inscrit une valeur sensible : elle sera masquée dans toute sortie de log.
+pub fn register_secret_for_redaction(value: &str) {
+    if value.len() < MIN_REDACTABLE_LEN {
+        return;
+    }
+    if let Ok(mut set) = redacted_values().write() {
+        set.insert(value.to_string());
+    }
+}
+
+/// Masque toutes les valeurs sensibles connues présentes dans le texte.
+pub fn redact_secrets(text: &str) -> String {
+    let Ok(set) = redacted_values().read() else {
+        return text.to_string();
+    };
+    set.iter().fold(text.to_string(), |acc, secret| {
+        if acc.contains(secret.as_str()) {
+            acc.replace(secret.as_str(), REDACTION_MASK)
+        } else {
+            acc
+        }
+    })
+}
+
+/// Enveloppe d'un `MakeWriter` `tracing` appliquant `redact_secrets` à chaque ligne écrite.
+pub struct RedactingWriter<M>(pub M);
+
+pub struct RedactedOutput<W>(W);
+
+impl<'a, M: LogWriterTrait<'a>> LogWriterTrait<'a> for RedactingWriter<M> {
+    type Writer = RedactedOutput<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactedOutput(self.0.make_writer())
+    }
+}
+
+impl<W: SyncWrite> SyncWrite for RedactedOutput<W> {
+    fn write(&mut self, buf: &[u8]) -> RawIoResult<usize> {
+        let text = String::from_utf8_lossy(buf);
+        let redacted = redact_secrets(&text);
+        if redacted == text {
+            self.0.write_all(buf)?;
+        } else {
+            self.0.write_all(redacted.as_bytes())?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> RawIoResult<()> {
+        self.0.flush()
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::io::fs::tempdir;
+
+    fn vault_only(dir: &Path, passphrase: Option<&str>) -> SecretsProvider {
+        SecretsProvider::new(vec![Box::new(VaultBackend::new(
+            dir.join("vault.json"),
+            passphrase.map(String::from),
+        ))])
+    }
+
+    #[async_test]
+    async fn test_vault_roundtrip_is_encrypted_at_rest() -> RaiseResult<()> {
+        let dir = tempdir().unwrap();
+        let provider = vault_only(dir.path(), Some("correct horse"));
+
+        let backend = provider
+            .set("gemini_api_key", "AIza-test-123456", None)
+            .await?;
+        assert_eq!(backend, SecretBackendKind::Vault);
+        assert_eq!(
+            provider.get("gemini_api_key").await?.as_deref(),
+            Some("AIza-test-123456")
+        );
+
+        let raw = fs::read_to_string_async(&dir.path().join("vault.json")).await?;
+        assert!(!raw.contains("AIza-test-123456"));
+
+        // Mauvaise passphrase : le secret est illisible (et signalé, pas renvoyé)
+        let intruder = vault_only(dir.path(), Some("wrong"));
+        assert!(intruder.get("gemini_api_key").await?.is_none());
+
+        assert!(provider.delete("gemini_api_key").await?);
+        assert!(provider.get("gemini_api_key").await?.is_none());
+        Ok(())
+    }
+
+    #[async_test]
+    async fn test_locked_vault_rejects_writes_and_resolves_refs_from_env() -> RaiseResult<()> {
+        let dir = tempdir().unwrap();
+        let mut backends: Vec<Box<dyn SecretBackend>> = vec![Box::new(VaultBackend::new(
+            dir.path().join("vault.json"),
+            None,
+        ))];
+        backends.push(Box::new(EnvBackend));
+        let provider = SecretsProvider::new(backends);
+
+        let err = provider.set("k", "value-123456", None).await.unwrap_err();
+        let AppError::Structured(data) = err;
+        assert_eq!(data.code, "ERR_SECRET_NO_WRITABLE_BACKEND");
+
+        RuntimeEnv::set_var("RAISE_SECRET_TEST_SECRETS_TOKEN", "env-token-987654");
+        let mut settings = json_value!({
+            "url": "https://api.example",
+            "auth": { "token": "secret:test_secrets_token" }
+        });
+        assert_eq!(provider.resolve_refs(&mut settings).await?, 1);
+        assert_eq!(settings["auth"]["token"], "env-token-987654");
+
+        settings["other"] = json_value!("secret:test_secrets_missing");
+        assert!(provider.resolve_refs(&mut settings).await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_redacting_writer_masks_registered_secrets() {
+        register_secret_for_redaction("sk-live-abcdef123");
+        register_secret_for_redaction("abc"); // Trop court : ignoré
+
+        let mut out = RedactedOutput(Vec::new());
+        out.write_all(b"calling api with key=sk-live-abcdef123 (abc)")
+            .unwrap();
+        let line = String::from_utf8(out.0).unwrap();
+
+        assert!(!line.contains("sk-live-abcdef123"));
+        assert!(line.contains(REDACTION_MASK));
+        assert!(line.contains("(abc)"));
+    }
+}
@@ -46,6 +46,20 @@ pub fn open_sync(path: impl AsRef<Path>) -> RaiseResult<std::fs::File> {
     }
 }
 
+/// 🤖 IA NOTE : Crée (ou tronque) un fichier en écriture de manière asynchrone.
+/// Pour écrire un flux par morceaux (téléchargements) sans le bufferiser en RAM.
+pub async fn create_async(path: impl AsRef<Path>) -> RaiseResult<tokio::fs::File> {
+    let p = path.as_ref();
+    match tokio::fs::File::create(p).await {
+        Ok(file) => Ok(file),
+        Err(e) => raise_error!(
+            "ERR_FS_CREATE_ASYNC",
+            error = e,
+            context = json_value!({ "path": p.to_string_lossy() })
+        ),
+    }
+}
+
 // =========================================================================
 // 1. LECTURE & ÉCRITURE BASIQUES
 // =========================================================================
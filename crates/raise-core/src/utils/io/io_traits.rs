@@ -12,3 +12,9 @@ pub use std::io::Seek as SyncSeek;
 
 /// 🤖 IA NOTE : Trait pour la lecture ligne par ligne avec mise en mémoire tampon.
 pub use std::io::BufRead as SyncBufRead;
+
+/// 🤖 IA NOTE : Trait pour l'écriture asynchrone (`write_all`, `flush` sur un fichier tokio).
+pub use tokio::io::AsyncWriteExt as AsyncWrite;
+
+/// 🤖 IA NOTE : Trait pour la lecture asynchrone par blocs (`read` sur un fichier tokio).
+pub use tokio::io::AsyncReadExt as AsyncRead;
@@ -0,0 +1,320 @@
+// FICHIER : src-tauri/src/utils/network/circuit_breaker.rs
+
+// 1. Core : Concurrence, Temps et Erreurs
+use crate::utils::core::error::RaiseResult;
+use crate::utils::core::{StaticCell, SyncMutex, TimeDuration, TimeInstant, UtcClock};
+
+// 2. Data : Sérialisation, JSON et Configuration
+use crate::utils::data::config::{CircuitBreakerConfig, CONFIG};
+use crate::utils::data::json::json_value;
+use crate::utils::data::{Deserializable, OrderedMap, Serializable};
+
+// 3. Network : Types HTTP
+use crate::utils::network::http_types::HttpUrl;
+
+/// État d'un circuit (patron "Circuit Breaker").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serializable, Deserializable)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Trafic normal.
+    Closed,
+    /// Endpoint jugé indisponible : les appels échouent immédiatement.
+    Open,
+    /// Délai d'ouverture écoulé : une seule requête de sonde est autorisée.
+    HalfOpen,
+}
+
+/// Santé observée d'un endpoint (exposée au frontend et au CLI).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct EndpointHealth {
+    pub endpoint: String,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub total_requests: u64,
+    pub total_failures: u64,
+    pub last_error: Option<String>,
+    pub last_success_at: Option<String>,
+    pub last_failure_at: Option<String>,
+    pub avg_latency_ms: f64,
+}
+
+#[derive(Debug)]
+struct EndpointEntry {
+    health: EndpointHealth,
+    opened_at: Option<TimeInstant>,
+    probe_in_flight: bool,
+}
+
+/// Disjoncteur par endpoint (`scheme://host:port`) : évite de marteler un LLM,
+/// Qdrant ou une passerelle Fabric tombés, et trace leur santé.
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    endpoints: SyncMutex<OrderedMap<String, EndpointEntry>>,
+}
+
+static GLOBAL_BREAKER: StaticCell<CircuitBreaker> = StaticCell::new();
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            endpoints: SyncMutex::new(OrderedMap::new()),
+        }
+    }
+
+    /// Instance partagée par le client HTTP global (paramètres issus de `AppConfig.network`).
+    pub fn global() -> &'static CircuitBreaker {
+        GLOBAL_BREAKER.get_or_init(|| {
+            let config = CONFIG
+                .get()
+                .map(|c| c.network.circuit_breaker.clone())
+                .unwrap_or_default();
+            CircuitBreaker::new(config)
+        })
+    }
+
+    /// Clé de regroupement : `scheme://host:port` (le chemin est ignoré).
+    pub fn endpoint_key(url: &str) -> String {
+        match HttpUrl::parse(url) {
+            Ok(u) => format!(
+                "{}://{}:{}",
+                u.scheme(),
+                u.host_str().unwrap_or_default(),
+                u.port_or_known_default().unwrap_or_default()
+            ),
+            Err(_) => url.to_string(),
+        }
+    }
+
+    /// Autorise (ou non) un appel vers `url`. Circuit ouvert => `ERR_NET_CIRCUIT_OPEN`.
+    pub fn acquire(&self, url: &str) -> RaiseResult<()> {
+        let key = Self::endpoint_key(url);
+        let open_duration = TimeDuration::from_secs(self.config.open_duration_secs);
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|p| p.into_inner());
+        let entry = endpoints
+            .entry(key.clone())
+            .or_insert_with(|| EndpointEntry::new(&key));
+
+        match entry.health.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open => {
+                let elapsed = entry.opened_at.map(|t| t.elapsed()).unwrap_or_default();
+                if elapsed >= open_duration {
+                    entry.health.state = CircuitState::HalfOpen;
+                    entry.probe_in_flight = true;
+                    crate::user_info!("NET_CIRCUIT_HALF_OPEN", json_value!({ "endpoint": key }));
+                    return Ok(());
+                }
+                let retry_in = open_duration.saturating_sub(elapsed).as_secs();
+                crate::raise_error!(
+                    "ERR_NET_CIRCUIT_OPEN",
+                    error = "Endpoint temporairement désactivé après des échecs répétés",
+                    context = json_value!({
+                        "endpoint": key,
+                        "consecutive_failures": entry.health.consecutive_failures,
+                        "retry_in_secs": retry_in,
+                        "last_error": entry.health.last_error
+                    })
+                );
+            }
+            CircuitState::HalfOpen => {
+                if entry.probe_in_flight {
+                    crate::raise_error!(
+                        "ERR_NET_CIRCUIT_OPEN",
+                        error = "Sonde déjà en cours sur cet endpoint",
+                        context = json_value!({ "endpoint": key, "state": "half_open" })
+                    );
+                }
+                entry.probe_in_flight = true;
+                Ok(())
+            }
+        }
+    }
+
+    /// Appel abouti (y compris 4xx : l'endpoint est joignable).
+    pub fn record_success(&self, url: &str, latency: TimeDuration) {
+        let key = Self::endpoint_key(url);
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|p| p.into_inner());
+        let entry = endpoints
+            .entry(key.clone())
+            .or_insert_with(|| EndpointEntry::new(&key));
+
+        if entry.health.state != CircuitState::Closed {
+            crate::user_info!("NET_CIRCUIT_CLOSED", json_value!({ "endpoint": key }));
+        }
+        entry.record(latency);
+        entry.health.state = CircuitState::Closed;
+        entry.health.consecutive_failures = 0;
+        entry.health.last_success_at = Some(UtcClock::now().to_rfc3339());
+        entry.opened_at = None;
+        entry.probe_in_flight = false;
+    }
+
+    /// Échec réseau, 5xx ou 429 : rapproche le circuit de l'ouverture.
+    pub fn record_failure(&self, url: &str, error: &str) {
+        let key = Self::endpoint_key(url);
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|p| p.into_inner());
+        let entry = endpoints
+            .entry(key.clone())
+            .or_insert_with(|| EndpointEntry::new(&key));
+
+        entry.health.total_requests += 1;
+        entry.health.total_failures += 1;
+        entry.health.consecutive_failures += 1;
+        entry.health.last_error = Some(error.to_string());
+        entry.health.last_failure_at = Some(UtcClock::now().to_rfc3339());
+        entry.probe_in_flight = false;
+
+        let must_open = entry.health.state == CircuitState::HalfOpen
+            || entry.health.consecutive_failures >= self.config.failure_threshold.max(1);
+        if must_open {
+            if entry.health.state != CircuitState::Open {
+                crate::user_warn!(
+                    "NET_CIRCUIT_OPENED",
+                    json_value!({
+                        "endpoint": key,
+                        "consecutive_failures": entry.health.consecutive_failures,
+                        "open_duration_secs": self.config.open_duration_secs
+                    })
+                );
+            }
+            entry.health.state = CircuitState::Open;
+            entry.opened_at = Some(TimeInstant::now());
+        }
+    }
+
+    /// Instantané de la santé de tous les endpoints contactés.
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let endpoints = self.endpoints.lock().unwrap_or_else(|p| p.into_inner());
+        let open_duration = TimeDuration::from_secs(self.config.open_duration_secs);
+        endpoints
+            .values()
+            .map(|e| {
+                // Affichage : un circuit dont le délai a expiré est prêt pour une sonde
+                let expired = e.opened_at.is_some_and(|t| t.elapsed() >= open_duration);
+                let mut h = e.health.clone();
+                if h.state == CircuitState::Open && expired {
+                    h.state = CircuitState::HalfOpen;
+                }
+                h
+            })
+            .collect()
+    }
+
+    /// Referme le circuit d'un endpoint (ou de tous si `None`).
+    pub fn reset(&self, url: Option<&str>) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|p| p.into_inner());
+        match url {
+            Some(u) => {
+                endpoints.remove(&Self::endpoint_key(u));
+            }
+            None => endpoints.clear(),
+        }
+    }
+}
+
+impl EndpointEntry {
+    fn new(endpoint: &str) -> Self {
+        Self {
+            health: EndpointHealth {
+                endpoint: endpoint.to_string(),
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                total_requests: 0,
+                total_failures: 0,
+                last_error: None,
+                last_success_at: None,
+                last_failure_at: None,
+                avg_latency_ms: 0.0,
+            },
+            opened_at: None,
+            probe_in_flight: false,
+        }
+    }
+
+    /// Moyenne glissante des latences des appels aboutis.
+    fn record(&mut self, latency: TimeDuration) {
+        let successes = self.health.total_requests - self.health.total_failures;
+        let ms = latency.as_secs_f64() * 1000.0;
+        self.health.avg_latency_ms =
+            (self.health.avg_latency_ms * successes as f64 + ms) / (successes + 1) as f64;
+        self.health.total_requests += 1;
+    }
+}
+
+// --- TESTS UNITAIRES ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::core::error::AppError;
+
+    fn breaker(threshold: u32, open_secs: u64) -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: threshold,
+            open_duration_secs: open_secs,
+        })
+    }
+
+    #[test]
+    fn test_endpoint_key_ignores_path() {
+        assert_eq!(
+            CircuitBreaker::endpoint_key("https://api.example.com/v1/messages"),
+            "https://api.example.com:443"
+        );
+        assert_eq!(
+            CircuitBreaker::endpoint_key("http://localhost:6333/collections"),
+            "http://localhost:6333"
+        );
+    }
+
+    #[test]
+    fn test_circuit_opens_after_threshold() {
+        let cb = breaker(2, 60);
+        let url = "http://qdrant.local:6333/points";
+
+        cb.record_failure(url, "connection refused");
+        assert!(cb.acquire(url).is_ok());
+        cb.record_failure(url, "connection refused");
+
+        let Err(AppError::Structured(data)) = cb.acquire(url) else {
+            panic!("Le circuit devrait être ouvert");
+        };
+        assert_eq!(data.code, "ERR_NET_CIRCUIT_OPEN");
+
+        // Un autre endpoint n'est pas affecté
+        assert!(cb.acquire("http://llm.local:8080/v1").is_ok());
+
+        let health = cb.health();
+        let qdrant = health
+            .iter()
+            .find(|h| h.endpoint == "http://qdrant.local:6333")
+            .unwrap();
+        assert_eq!(qdrant.total_failures, 2);
+        assert_eq!(qdrant.last_error.as_deref(), Some("connection refused"));
+    }
+
+    #[test]
+    fn test_half_open_probe_closes_or_reopens() {
+        let cb = breaker(1, 0);
+        let url = "https://fabric.gateway:7051";
+
+        cb.record_failure(url, "503");
+        // Délai nul : première requête = sonde, la suivante est refusée
+        assert!(cb.acquire(url).is_ok());
+        assert!(cb.acquire(url).is_err());
+
+        // Sonde en échec : réouverture immédiate
+        cb.record_failure(url, "503");
+        assert_eq!(cb.health()[0].consecutive_failures, 2);
+
+        // Nouvelle sonde réussie : circuit refermé
+        assert!(cb.acquire(url).is_ok());
+        cb.record_success(url, TimeDuration::from_millis(20));
+        let h = &cb.health()[0];
+        assert_eq!(h.state, CircuitState::Closed);
+        assert_eq!(h.consecutive_failures, 0);
+        assert!(h.avg_latency_ms > 0.0);
+        assert!(cb.acquire(url).is_ok());
+    }
+}
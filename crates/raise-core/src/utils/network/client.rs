@@ -2,30 +2,159 @@
 
 // 1. Core : Concurrence, Temps et Erreurs
 use crate::utils::core::error::RaiseResult;
-use crate::utils::core::{sleep_async, StaticCell, TimeDuration};
+use crate::utils::core::{sleep_async, RuntimeEnv, StaticCell, TimeDuration, TimeInstant};
 
-// 2. Data : Sérialisation et JSON
+// 2. Data : Sérialisation, JSON et Configuration
+use crate::utils::data::config::{NetworkConfig, CONFIG};
 use crate::utils::data::json::json_value;
 use crate::utils::data::{DeserializableOwned, Serializable};
 
-// 3. Network : Types HTTP (via la façade network/mod.rs)
-use crate::utils::network::http_types::{HttpClient, HttpClientBuilder, HttpStatusCode};
+// 3. IO : Lecture des certificats
+use crate::utils::io::fs;
+
+// 4. Network : Types HTTP (via la façade network/mod.rs)
+use crate::utils::network::circuit_breaker::CircuitBreaker;
+use crate::utils::network::http_types::{
+    HttpCertificate, HttpClient, HttpClientBuilder, HttpIdentity, HttpNoProxy, HttpProxy,
+    HttpStatusCode,
+};
 
 /// Singleton : Le client HTTP est réutilisé pour bénéficier du pool de connexions (Performance).
 static GLOBAL_CLIENT: StaticCell<HttpClient> = StaticCell::new();
 
-/// Récupère l'instance unique du client HTTP global.
+/// Client des transferts volumineux (modèles, datasets) : sans délai global de requête.
+static DOWNLOAD_CLIENT: StaticCell<HttpClient> = StaticCell::new();
+
+/// Récupère l'instance unique du client HTTP global (proxy et mTLS issus de `AppConfig.network`).
 pub fn get_client() -> &'static HttpClient {
     GLOBAL_CLIENT.get_or_init(|| {
-        HttpClientBuilder::new()
-            .timeout(TimeDuration::from_secs(60))
-            .pool_idle_timeout(TimeDuration::from_secs(90))
-            .user_agent(concat!("Raise-Core/", env!("CARGO_PKG_VERSION")))
-            .build()
-            .expect("❌ CRITICAL: Impossible d'initialiser le client HTTP global")
+        let network = CONFIG.get().map(|c| c.network.clone()).unwrap_or_default();
+        match build_client(&network) {
+            Ok(client) => client,
+            Err(e) => {
+                // Configuration réseau invalide : on dégrade vers le client par défaut
+                crate::user_error!(
+                    "ERR_NET_CLIENT_CONFIG",
+                    json_value!({ "error": e.to_string() })
+                );
+                base_builder()
+                    .build()
+                    .expect("❌ CRITICAL: Impossible d'initialiser le client HTTP global")
+            }
+        }
+    })
+}
+
+/// Client de téléchargement : même proxy et mTLS que `get_client`, mais seul un silence
+/// prolongé du serveur interrompt le transfert (un modèle de plusieurs Go dépasse 60 s).
+pub fn get_download_client() -> &'static HttpClient {
+    DOWNLOAD_CLIENT.get_or_init(|| {
+        let network = CONFIG.get().map(|c| c.network.clone()).unwrap_or_default();
+        match configure_builder(download_builder(), &network) {
+            Ok(client) => client,
+            Err(e) => {
+                crate::user_error!(
+                    "ERR_NET_CLIENT_CONFIG",
+                    json_value!({ "error": e.to_string() })
+                );
+                download_builder()
+                    .build()
+                    .expect("❌ CRITICAL: Impossible d'initialiser le client de téléchargement")
+            }
+        }
     })
 }
 
+fn download_builder() -> HttpClientBuilder {
+    HttpClientBuilder::new()
+        .connect_timeout(TimeDuration::from_secs(30))
+        .read_timeout(TimeDuration::from_secs(120))
+        .user_agent(concat!("Raise-Core/", env!("CARGO_PKG_VERSION")))
+}
+
+fn base_builder() -> HttpClientBuilder {
+    HttpClientBuilder::new()
+        .timeout(TimeDuration::from_secs(60))
+        .pool_idle_timeout(TimeDuration::from_secs(90))
+        .user_agent(concat!("Raise-Core/", env!("CARGO_PKG_VERSION")))
+}
+
+/// Construit un client HTTP selon la configuration réseau (proxy, certificats).
+pub fn build_client(network: &NetworkConfig) -> RaiseResult<HttpClient> {
+    configure_builder(base_builder(), network)
+}
+
+fn configure_builder(
+    mut builder: HttpClientBuilder,
+    network: &NetworkConfig,
+) -> RaiseResult<HttpClient> {
+    match &network.proxy {
+        Some(cfg) => {
+            let mut proxy = match HttpProxy::all(&cfg.url) {
+                Ok(p) => p,
+                Err(e) => crate::raise_error!(
+                    "ERR_NET_PROXY_INVALID",
+                    error = e,
+                    context = json_value!({ "proxy": cfg.url })
+                ),
+            };
+            if let Some(user) = &cfg.username {
+                let password = cfg
+                    .password_env
+                    .as_deref()
+                    .and_then(|var| RuntimeEnv::var(var).ok())
+                    .unwrap_or_default();
+                proxy = proxy.basic_auth(user, &password);
+            }
+            if !cfg.no_proxy.is_empty() {
+                proxy = proxy.no_proxy(HttpNoProxy::from_string(&cfg.no_proxy.join(",")));
+            }
+            builder = builder.proxy(proxy);
+        }
+        // Par défaut reqwest lit HTTP_PROXY / HTTPS_PROXY / NO_PROXY
+        None if !network.use_system_proxy => builder = builder.no_proxy(),
+        None => {}
+    }
+
+    if let Some(tls) = &network.tls {
+        if let Some(path) = &tls.ca_bundle_pem {
+            let pem = fs::read_sync(path)?;
+            let certs = match HttpCertificate::from_pem_bundle(&pem) {
+                Ok(c) => c,
+                Err(e) => crate::raise_error!(
+                    "ERR_NET_TLS_CA_INVALID",
+                    error = e,
+                    context = json_value!({ "path": path })
+                ),
+            };
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+        if let Some(path) = &tls.identity_pem {
+            let pem = fs::read_sync(path)?;
+            let identity = match HttpIdentity::from_pem(&pem) {
+                Ok(id) => id,
+                Err(e) => crate::raise_error!(
+                    "ERR_NET_TLS_IDENTITY_INVALID",
+                    error = e,
+                    context = json_value!({ "path": path })
+                ),
+            };
+            builder = builder.identity(identity);
+        }
+    }
+
+    match builder.build() {
+        Ok(client) => Ok(client),
+        Err(e) => crate::raise_error!(
+            "ERR_NET_CLIENT_BUILD",
+            error = e,
+            context = json_value!({ "proxy": network.proxy.as_ref().map(|p| &p.url) })
+        ),
+    }
+}
+
 /// Envoie une requête POST JSON avec Authentification Bearer optionnelle et stratégie de Retry.
 pub async fn post_authenticated_async<T: Serializable, R: DeserializableOwned>(
     url: &str,
@@ -34,11 +163,13 @@ pub async fn post_authenticated_async<T: Serializable, R: DeserializableOwned>(
     max_retries: u32,
 ) -> RaiseResult<R> {
     let client = get_client();
+    let breaker = CircuitBreaker::global();
     let mut attempt = 0;
     let mut delay = TimeDuration::from_secs(1);
 
     loop {
         attempt += 1;
+        breaker.acquire(url)?;
 
         let mut request_builder = client.post(url).json(body);
 
@@ -51,10 +182,18 @@ pub async fn post_authenticated_async<T: Serializable, R: DeserializableOwned>(
             json_value!({ "url": url, "attempt": attempt, "max_retries": max_retries })
         );
 
+        let started = TimeInstant::now();
         match request_builder.send().await {
             Ok(response) => {
                 let status = response.status();
 
+                // 5xx / 429 : l'endpoint est en difficulté ; sinon il est joignable
+                if status.is_server_error() || status == HttpStatusCode::TOO_MANY_REQUESTS {
+                    breaker.record_failure(url, &format!("HTTP {}", status.as_u16()));
+                } else {
+                    breaker.record_success(url, started.elapsed());
+                }
+
                 if status.is_success() {
                     return match response.json::<R>().await {
                         Ok(data) => Ok(data),
@@ -88,6 +227,7 @@ pub async fn post_authenticated_async<T: Serializable, R: DeserializableOwned>(
                 }
             }
             Err(e) => {
+                breaker.record_failure(url, &e.to_string());
                 // 🎯 Utilisation STRICTE de la macro métier Raise
                 crate::user_warn!(
                     "NET_CONN_FAILED",
@@ -124,14 +264,27 @@ pub async fn post_json_with_retry_async<T: Serializable, R: DeserializableOwned>
 /// Effectue une requête GET simple et retourne le corps en String.
 pub async fn get_string_async(url: &str) -> RaiseResult<String> {
     let client = get_client();
+    let breaker = CircuitBreaker::global();
+    breaker.acquire(url)?;
 
+    let started = TimeInstant::now();
     let resp = match client.get(url).send().await {
-        Ok(r) => r,
-        Err(e) => crate::raise_error!(
-            "ERR_NET_GET_SEND",
-            error = e,
-            context = json_value!({ "url": url })
-        ),
+        Ok(r) => {
+            if r.status().is_server_error() || r.status() == HttpStatusCode::TOO_MANY_REQUESTS {
+                breaker.record_failure(url, &format!("HTTP {}", r.status().as_u16()));
+            } else {
+                breaker.record_success(url, started.elapsed());
+            }
+            r
+        }
+        Err(e) => {
+            breaker.record_failure(url, &e.to_string());
+            crate::raise_error!(
+                "ERR_NET_GET_SEND",
+                error = e,
+                context = json_value!({ "url": url })
+            )
+        }
     };
 
     let resp = match resp.error_for_status() {
@@ -170,6 +323,28 @@ mod tests {
         assert!(is_same_reference(c1, c2));
     }
 
+    #[test]
+    fn test_build_client_with_proxy_config() {
+        use crate::utils::data::config::ProxyConfig;
+
+        let mut network = NetworkConfig {
+            proxy: Some(ProxyConfig {
+                url: "http://proxy.corp.local:3128".into(),
+                no_proxy: vec!["localhost".into(), ".corp.local".into()],
+                username: Some("svc-raise".into()),
+                password_env: Some("RAISE_TEST_PROXY_PASSWORD".into()),
+            }),
+            ..Default::default()
+        };
+        assert!(build_client(&network).is_ok());
+
+        network.proxy.as_mut().unwrap().url = "::not a proxy::".into();
+        let Err(AppError::Structured(data)) = build_client(&network) else {
+            panic!("Une URL de proxy invalide doit être rejetée");
+        };
+        assert_eq!(data.code, "ERR_NET_PROXY_INVALID");
+    }
+
     #[async_test]
     async fn test_network_error_observability() {
         let res = get_string_async("http://0.0.0.0:1").await;
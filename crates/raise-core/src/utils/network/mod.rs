@@ -1,5 +1,6 @@
 // FICHIER : src-tauri/src/utils/network/mod.rs
 
+pub mod circuit_breaker;
 pub mod client;
 pub mod p2p;
 pub mod server;
@@ -10,16 +11,32 @@ pub mod server;
 
 pub mod http_types {
     // --- Client HTTP (Reqwest) ---
+    /// 🤖 IA NOTE : Client bloquant, réservé aux threads hors runtime Tokio (ex: exporteur de télémétrie).
+    pub use reqwest::blocking::Client as BlockingHttpClient;
     /// 🤖 IA NOTE : Le moteur HTTP interne pour les requêtes sortantes.
     pub use reqwest::Client as HttpClient;
     /// 🤖 IA NOTE : Constructeur pour configurer le client HTTP global.
     pub use reqwest::ClientBuilder as HttpClientBuilder;
+    /// 🤖 IA NOTE : Verbe HTTP (GET, PUT, DELETE...) pour les API REST génériques.
+    pub use reqwest::Method as HttpMethod;
     /// 🤖 IA NOTE : Code de statut HTTP (200, 404, etc.).
     pub use reqwest::StatusCode as HttpStatusCode;
+    /// 🤖 IA NOTE : URL analysée (schéma, hôte, port) pour regrouper les appels par endpoint.
+    pub use reqwest::Url as HttpUrl;
+    /// 🤖 IA NOTE : Certificat client (mTLS) et autorités de certification additionnelles.
+    pub use reqwest::{Certificate as HttpCertificate, Identity as HttpIdentity};
+    /// 🤖 IA NOTE : Proxy sortant (passerelle d'entreprise) et sa liste d'exclusions.
+    pub use reqwest::{NoProxy as HttpNoProxy, Proxy as HttpProxy};
 
     // --- Serveur HTTP (Axum / Tokio) ---
     /// 🤖 IA NOTE : Extracteur de payload JSON pour les requêtes entrantes.
     pub use axum::extract::Json as HttpJsonPayload;
+    /// 🤖 IA NOTE : Extracteur des segments dynamiques de l'URL (ex: "/webhooks/{handle}").
+    pub use axum::extract::Path as HttpPathParam;
+    /// 🤖 IA NOTE : Extracteur de l'état partagé injecté via `HttpRouter::with_state`.
+    pub use axum::extract::State as HttpSharedState;
+    /// 🤖 IA NOTE : En-têtes d'une requête entrante (authentification, signatures).
+    pub use axum::http::HeaderMap as HttpHeaderMap;
     /// 🤖 IA NOTE : Lanceur du serveur HTTP asynchrone.
     pub use axum::serve as run_http_server;
     /// 🤖 IA NOTE : Le routeur principal pour définir les endpoints de l'API REST locale.
@@ -77,8 +94,10 @@ pub mod p2p_types {
 
 // --- Exports Métier Haut Niveau ---
 // Les fonctions prêtes à l'emploi que le reste de l'application (et l'IA) doit utiliser.
+pub use circuit_breaker::{CircuitBreaker, CircuitState, EndpointHealth};
 pub use client::{
-    get_client, get_string_async, post_authenticated_async, post_json_with_retry_async,
+    build_client, get_client, get_download_client, get_string_async, post_authenticated_async,
+    post_json_with_retry_async,
 };
 pub use p2p::build_p2p_node_async;
 pub use server::start_local_api_async;
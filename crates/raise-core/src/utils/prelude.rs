@@ -12,6 +12,7 @@ pub use crate::utils::core::{
     async_main,
     async_recursive,
     async_test,
+    instrument, // 🎯 Span `tracing` (propagé vers l'export OTLP)
     is_same_reference,
     memory_copy_fast,
     parse_system_time,
@@ -21,16 +22,23 @@ pub use crate::utils::core::{
     spawn_cpu_task,
     terminate_process,
     AgentAttention,
+    AsyncBroadcast,
     AsyncChannel,
     AsyncCommand,
     AsyncFuture,
     AsyncMutex,
     AsyncRwLock,
+    AsyncSignal,
     AsyncStaticCell,
+    AsyncStream,
+    AsyncStreamExt,
     BufferedRead,
     CalendarDate,
     CalendarDuration,
+    CalendarFields,
+    ClockFields,
     CowData,
+    CryptoBlake3,
     CryptoDigest,
     CryptoSha256,
     DataStreamPeekable, // 🎯 Pour l'anticipation (lookahead)
@@ -72,13 +80,16 @@ pub use crate::utils::core::{
     // Identifiants & Temps (Alias RAISE)
     UniqueId,     // 🎯 Alias de uuid::Uuid
     UtcClock,     // 🎯 Alias de chrono::Utc
+    UtcOffset,    // 🎯 Alias de chrono::FixedOffset
     UtcTimestamp, // 🎯 Alias de chrono::DateTime<Utc>
     VariantMarker,
     MATH_PI, // 🎯 La constante fondamentale
 };
 
 // --- 2. I/O, FS & SYSTÈME ---
-pub use crate::utils::io::io_traits::{SyncBufRead, SyncRead, SyncSeek, SyncWrite};
+pub use crate::utils::io::io_traits::{
+    AsyncRead, AsyncWrite, SyncBufRead, SyncRead, SyncSeek, SyncWrite,
+};
 pub use crate::utils::io::os_types::{
     os_temp_dir, ProcessChild, ProcessCommand, ProcessExitStatus, ProcessIoConfig, ProcessOutput,
     UnixFilePermissions,
@@ -168,8 +179,8 @@ pub use crate::utils::inference::{
 
 // --- 5. RÉSEAU & CONNECTIVITÉ ---
 pub use crate::utils::network::http_types::{
-    run_http_server, HttpClient, HttpClientBuilder, HttpJsonPayload, HttpRouter, HttpStatusCode,
-    HttpTcpListener,
+    run_http_server, HttpClient, HttpClientBuilder, HttpJsonPayload, HttpMethod, HttpRouter,
+    HttpStatusCode, HttpTcpListener,
 };
 
 pub use crate::utils::network::p2p_types::{
@@ -198,8 +209,8 @@ pub use crate::utils::network::p2p_types::{
 };
 
 pub use crate::utils::network::{
-    build_p2p_node_async, get_client, get_string_async, post_authenticated_async,
-    post_json_with_retry_async, start_local_api_async,
+    build_p2p_node_async, get_client, get_download_client, get_string_async,
+    post_authenticated_async, post_json_with_retry_async, start_local_api_async, CircuitBreaker,
 };
 
 // --- 6. MACROS & OBSERVABILITÉ (Exports Racine) ---
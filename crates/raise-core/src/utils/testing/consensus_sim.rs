@@ -0,0 +1,575 @@
+// FICHIER : src-tauri/src/utils/testing/consensus_sim.rs
+
+//! 🧪 Banc de simulation du consensus Mentis : plusieurs `ConsensusEngine` en mémoire reliés
+//! par un réseau simulé (pertes, délais, partitions), piloté par un générateur à graine.
+//! Une même graine rejoue exactement le même scénario : un échec se reproduit à l'identique.
+//!
+//! ```ignore
+//! let mut sim = ConsensusSimulation::new(4, 3, seed, NetworkConditions::lossy(0.3, 5));
+//! let block = sim.propose(0, None);
+//! sim.run_until(200, |s| s.is_finalized_everywhere(&block));
+//! sim.check_safety()?;
+//! sim.check_liveness(&block)?;
+//! ```
+
+use crate::blockchain::consensus::reputation::ReputationLedger;
+use crate::blockchain::consensus::vote::Vote;
+use crate::blockchain::consensus::ConsensusEngine;
+use crate::blockchain::crypto::KeyPair;
+use crate::blockchain::MentisCommit;
+use crate::utils::data::config::ReputationConfig;
+use crate::utils::prelude::*;
+use crate::utils::testing::kit::MockClock;
+
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Conditions du réseau simulé ; les délais sont exprimés en ticks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkConditions {
+    /// Probabilité de perte de chaque message (0.0 à 1.0).
+    pub drop_rate: f64,
+    pub min_delay: u64,
+    pub max_delay: u64,
+    /// Période de réémission des propositions et votes de chaque nœud (`None` : jamais).
+    pub retransmit_every: Option<u64>,
+}
+
+impl Default for NetworkConditions {
+    /// Réseau parfait : aucune perte, un tick de latence.
+    fn default() -> Self {
+        Self {
+            drop_rate: 0.0,
+            min_delay: 1,
+            max_delay: 1,
+            retransmit_every: None,
+        }
+    }
+}
+
+impl NetworkConditions {
+    /// Réseau avec pertes et gigue, réémission toutes les `2 × max_delay` ticks.
+    pub fn lossy(drop_rate: f64, max_delay: u64) -> Self {
+        Self {
+            drop_rate,
+            min_delay: 1,
+            max_delay: max_delay.max(1),
+            retransmit_every: Some(max_delay.max(1) * 2),
+        }
+    }
+}
+
+/// Comportement d'un nœud simulé.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeBehavior {
+    /// Vote pour la première proposition reçue à chaque hauteur (même parent).
+    Honest,
+    /// Nœud arrêté : ne reçoit ni n'émet plus rien.
+    Crashed,
+    /// Vote pour toutes les propositions, y compris concurrentes.
+    DoubleVoter,
+}
+
+#[derive(Debug, Clone)]
+enum SimMessage {
+    Propose(MentisCommit),
+    Vote(Vote),
+}
+
+impl SimMessage {
+    fn describe(&self) -> String {
+        match self {
+            Self::Propose(commit) => format!("propose:{}", commit.id),
+            Self::Vote(vote) => format!("vote:{}:{}", vote.voter, vote.commit_id),
+        }
+    }
+}
+
+struct InFlight {
+    deliver_at: u64,
+    seq: u64,
+    to: usize,
+    message: SimMessage,
+}
+
+/// Entrée du journal de simulation : deux exécutions de même graine produisent le même journal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimEvent {
+    Sent {
+        tick: u64,
+        from: usize,
+        to: usize,
+        message: String,
+        deliver_at: u64,
+    },
+    Dropped {
+        tick: u64,
+        from: usize,
+        to: usize,
+        message: String,
+    },
+    Delivered {
+        tick: u64,
+        to: usize,
+        message: String,
+    },
+    Finalized {
+        tick: u64,
+        node: usize,
+        commit_id: String,
+    },
+}
+
+/// Nœud simulé : un moteur de consensus, son identité et ce qu'il a vu.
+pub struct SimNode {
+    pub keys: KeyPair,
+    pub engine: ConsensusEngine,
+    pub behavior: NodeBehavior,
+    /// Commits finalisés dans l'ordre, avec leur parent.
+    pub finalized: Vec<(Option<String>, String)>,
+    /// Propositions valides reçues, par identifiant.
+    known: OrderedMap<String, MentisCommit>,
+    /// Vote émis par parent : un nœud honnête ne vote qu'une fois par hauteur.
+    voted: OrderedMap<Option<String>, String>,
+    /// Votes arrivés avant leur proposition, rejoués à sa réception.
+    early_votes: Vec<Vote>,
+    /// Propositions et votes émis par ce nœud (réémis périodiquement).
+    outbox: Vec<SimMessage>,
+}
+
+impl SimNode {
+    pub fn has_finalized(&self, commit_id: &str) -> bool {
+        self.finalized.iter().any(|(_, id)| id == commit_id)
+    }
+}
+
+/// Réseau simulé de moteurs de consensus, avancé tick par tick.
+pub struct ConsensusSimulation {
+    pub nodes: Vec<SimNode>,
+    pub conditions: NetworkConditions,
+    pub seed: u64,
+    pub tick: u64,
+    pub trace: Vec<SimEvent>,
+    rng: StdRng,
+    /// Horodate les propositions : les identifiants de commit sont reproductibles.
+    clock: MockClock,
+    in_flight: Vec<InFlight>,
+    seq: u64,
+    /// Groupe de partition de chaque nœud : seuls les membres d'un même groupe communiquent.
+    groups: Vec<usize>,
+}
+
+impl ConsensusSimulation {
+    /// `nodes` validateurs honnêtes (autorités les uns des autres), quorum `quorum`.
+    pub fn new(nodes: usize, quorum: usize, seed: u64, conditions: NetworkConditions) -> Self {
+        let keys: Vec<KeyPair> = (0..nodes)
+            .map(|i| KeyPair {
+                public_key: format!("raise_pk_sim_{:02}", i),
+            })
+            .collect();
+        let authorities: Vec<String> = keys.iter().map(KeyPair::public_key_hex).collect();
+
+        let nodes = keys
+            .into_iter()
+            .map(|keys| {
+                let mut engine = ConsensusEngine {
+                    pending_validations: UnorderedMap::new(),
+                    default_quorum: quorum,
+                    authorities: UniqueSet::new(),
+                    reputation: ReputationLedger::new(ReputationConfig::default()),
+                };
+                engine.set_authorities(authorities.clone());
+                SimNode {
+                    keys,
+                    engine,
+                    behavior: NodeBehavior::Honest,
+                    finalized: Vec::new(),
+                    known: OrderedMap::new(),
+                    voted: OrderedMap::new(),
+                    early_votes: Vec::new(),
+                    outbox: Vec::new(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            groups: vec![0; nodes.len()],
+            nodes,
+            conditions,
+            seed,
+            tick: 0,
+            trace: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+            clock: MockClock::default(),
+            in_flight: Vec::new(),
+            seq: 0,
+        }
+    }
+
+    pub fn set_behavior(&mut self, node: usize, behavior: NodeBehavior) {
+        self.nodes[node].behavior = behavior;
+    }
+
+    /// Coupe le réseau : chaque liste forme un groupe isolé des autres ; les nœuds non cités
+    /// restent ensemble. Les messages en vol ne sont pas rappelés.
+    pub fn partition(&mut self, groups: &[&[usize]]) {
+        self.groups = vec![0; self.nodes.len()];
+        for (index, members) in groups.iter().enumerate() {
+            for &node in members.iter() {
+                self.groups[node] = index + 1;
+            }
+        }
+    }
+
+    /// Rétablit la connectivité complète.
+    pub fn heal(&mut self) {
+        self.groups = vec![0; self.nodes.len()];
+    }
+
+    /// `node` propose un commit de parent `parent`, le diffuse et vote pour lui.
+    /// Retourne l'identifiant du commit.
+    pub fn propose(&mut self, node: usize, parent: Option<String>) -> String {
+        self.clock.advance(CalendarDuration::milliseconds(1));
+        let commit = MentisCommit::new_at(vec![], parent, &self.nodes[node].keys, self.clock.now());
+        let id = commit.id.clone();
+
+        self.nodes[node]
+            .outbox
+            .push(SimMessage::Propose(commit.clone()));
+        self.broadcast(node, SimMessage::Propose(commit.clone()));
+        self.on_proposal(node, commit);
+        id
+    }
+
+    /// Avance d'un tick : réémissions éventuelles, puis livraison des messages échus.
+    pub fn step(&mut self) {
+        self.tick += 1;
+
+        if let Some(every) = self.conditions.retransmit_every {
+            if every > 0 && self.tick % every == 0 {
+                for node in 0..self.nodes.len() {
+                    for message in self.nodes[node].outbox.clone() {
+                        self.broadcast(node, message);
+                    }
+                }
+            }
+        }
+
+        let tick = self.tick;
+        let (mut due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.in_flight)
+            .into_iter()
+            .partition(|m| m.deliver_at <= tick);
+        self.in_flight = pending;
+        due.sort_by_key(|m| (m.deliver_at, m.seq));
+
+        for InFlight { to, message, .. } in due {
+            if self.nodes[to].behavior == NodeBehavior::Crashed {
+                continue;
+            }
+            self.trace.push(SimEvent::Delivered {
+                tick,
+                to,
+                message: message.describe(),
+            });
+            match message {
+                SimMessage::Propose(commit) => self.on_proposal(to, commit),
+                SimMessage::Vote(vote) => self.on_vote(to, vote),
+            }
+        }
+    }
+
+    pub fn run(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            self.step();
+        }
+    }
+
+    /// Avance jusqu'à ce que `done` soit vrai ou que `max_ticks` soient écoulés.
+    /// Retourne `true` si la condition a été atteinte.
+    pub fn run_until(&mut self, max_ticks: u64, mut done: impl FnMut(&Self) -> bool) -> bool {
+        for _ in 0..max_ticks {
+            if done(self) {
+                return true;
+            }
+            self.step();
+        }
+        done(self)
+    }
+
+    /// Nœuds honnêtes en fonctionnement : ceux dont on vérifie les propriétés.
+    fn correct_nodes(&self) -> impl Iterator<Item = (usize, &SimNode)> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.behavior == NodeBehavior::Honest)
+    }
+
+    pub fn is_finalized_everywhere(&self, commit_id: &str) -> bool {
+        self.correct_nodes()
+            .all(|(_, n)| n.has_finalized(commit_id))
+    }
+
+    /// Sûreté : aucun nœud honnête ne finalise deux commits concurrents (même parent),
+    /// ni un commit différent de celui d'un autre nœud honnête à la même hauteur.
+    pub fn check_safety(&self) -> RaiseResult<()> {
+        let mut decided: OrderedMap<Option<String>, (usize, String)> = OrderedMap::new();
+        for (index, node) in self.correct_nodes() {
+            for (parent, commit_id) in &node.finalized {
+                match decided.get(parent) {
+                    Some((other, decided_id)) if decided_id != commit_id => raise_error!(
+                        "ERR_CONSENSUS_SAFETY_VIOLATION",
+                        error = "Deux commits concurrents ont été finalisés.",
+                        context = json_value!({
+                            "seed": self.seed,
+                            "tick": self.tick,
+                            "parent": parent,
+                            "commits": [decided_id, commit_id],
+                            "nodes": [other, index]
+                        })
+                    ),
+                    Some(_) => {}
+                    None => {
+                        decided.insert(parent.clone(), (index, commit_id.clone()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Vivacité : tous les nœuds honnêtes ont finalisé `commit_id`.
+    pub fn check_liveness(&self, commit_id: &str) -> RaiseResult<()> {
+        let missing: Vec<usize> = self
+            .correct_nodes()
+            .filter(|(_, n)| !n.has_finalized(commit_id))
+            .map(|(index, _)| index)
+            .collect();
+        if !missing.is_empty() {
+            raise_error!(
+                "ERR_CONSENSUS_LIVENESS_VIOLATION",
+                error = format!("Commit '{}' non finalisé partout.", commit_id),
+                context = json_value!({
+                    "seed": self.seed,
+                    "tick": self.tick,
+                    "missing_nodes": missing
+                })
+            );
+        }
+        Ok(())
+    }
+
+    fn broadcast(&mut self, from: usize, message: SimMessage) {
+        for to in 0..self.nodes.len() {
+            if to != from {
+                self.send(from, to, message.clone());
+            }
+        }
+    }
+
+    fn send(&mut self, from: usize, to: usize, message: SimMessage) {
+        let tick = self.tick;
+        let unreachable = self.nodes[from].behavior == NodeBehavior::Crashed
+            || self.nodes[to].behavior == NodeBehavior::Crashed
+            || self.groups[from] != self.groups[to];
+        let lost =
+            self.conditions.drop_rate > 0.0 && self.rng.random::<f64>() < self.conditions.drop_rate;
+        if unreachable || lost {
+            self.trace.push(SimEvent::Dropped {
+                tick,
+                from,
+                to,
+                message: message.describe(),
+            });
+            return;
+        }
+
+        let (min, max) = (self.conditions.min_delay, self.conditions.max_delay);
+        let delay = self.rng.random_range(min..=max.max(min)).max(1);
+        self.seq += 1;
+        self.trace.push(SimEvent::Sent {
+            tick,
+            from,
+            to,
+            message: message.describe(),
+            deliver_at: tick + delay,
+        });
+        self.in_flight.push(InFlight {
+            deliver_at: tick + delay,
+            seq: self.seq,
+            to,
+            message,
+        });
+    }
+
+    fn on_proposal(&mut self, node: usize, commit: MentisCommit) {
+        let sim_node = &mut self.nodes[node];
+        if sim_node.known.contains_key(&commit.id) || sim_node.has_finalized(&commit.id) {
+            return;
+        }
+        if !commit.verify() {
+            sim_node.engine.report_invalid_proposal(&commit);
+            return;
+        }
+        sim_node.engine.register_commit(&commit);
+        sim_node.known.insert(commit.id.clone(), commit.clone());
+
+        let should_vote = match sim_node.behavior {
+            NodeBehavior::Honest => !sim_node.voted.contains_key(&commit.parent_hash),
+            NodeBehavior::DoubleVoter => true,
+            NodeBehavior::Crashed => false,
+        };
+        let early: Vec<Vote> = std::mem::take(&mut sim_node.early_votes);
+        let (ready, waiting): (Vec<_>, Vec<_>) =
+            early.into_iter().partition(|v| v.commit_id == commit.id);
+        sim_node.early_votes = waiting;
+
+        if should_vote {
+            sim_node
+                .voted
+                .entry(commit.parent_hash.clone())
+                .or_insert_with(|| commit.id.clone());
+            let vote = Vote::new(commit.id.clone(), &sim_node.keys);
+            sim_node.outbox.push(SimMessage::Vote(vote.clone()));
+            self.broadcast(node, SimMessage::Vote(vote.clone()));
+            self.on_vote(node, vote);
+        }
+        for vote in ready {
+            self.on_vote(node, vote);
+        }
+    }
+
+    fn on_vote(&mut self, node: usize, vote: Vote) {
+        let tick = self.tick;
+        let sim_node = &mut self.nodes[node];
+        if sim_node.has_finalized(&vote.commit_id) {
+            return;
+        }
+        let Some(parent) = sim_node
+            .known
+            .get(&vote.commit_id)
+            .map(|c| c.parent_hash.clone())
+        else {
+            if !sim_node.early_votes.contains(&vote) {
+                sim_node.early_votes.push(vote);
+            }
+            return;
+        };
+
+        let commit_id = vote.commit_id.clone();
+        if sim_node.engine.process_incoming_vote(vote) {
+            sim_node.engine.finalize_validation(&commit_id);
+            sim_node.finalized.push((parent, commit_id.clone()));
+            self.trace.push(SimEvent::Finalized {
+                tick,
+                node,
+                commit_id,
+            });
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_replays_same_trace() {
+        let scenario = |seed: u64| {
+            let mut sim = ConsensusSimulation::new(4, 3, seed, NetworkConditions::lossy(0.3, 4));
+            let block = sim.propose(0, None);
+            sim.run(60);
+            (block, sim.trace)
+        };
+
+        let (block_a, trace_a) = scenario(7);
+        let (block_b, trace_b) = scenario(7);
+        assert_eq!(block_a, block_b);
+        assert_eq!(trace_a, trace_b);
+        assert_ne!(trace_a, scenario(8).1);
+    }
+
+    #[test]
+    fn test_lossy_network_is_safe_and_live() -> RaiseResult<()> {
+        for seed in 0..20 {
+            let mut sim = ConsensusSimulation::new(4, 3, seed, NetworkConditions::lossy(0.3, 5));
+
+            // Propositions concurrentes : au plus une est finalisée
+            let a = sim.propose(0, None);
+            let b = sim.propose(1, None);
+            sim.run(100);
+            sim.check_safety()?;
+
+            // Un seul proposant à la hauteur suivante : finalisé partout
+            let decided = sim.nodes[2].finalized.first().map(|(_, id)| id.clone());
+            if let Some(id) = &decided {
+                assert!(*id == a || *id == b);
+            }
+            let next = sim.propose(2, Some(decided.unwrap_or(a)));
+            sim.run_until(300, |s| s.is_finalized_everywhere(&next));
+            sim.check_safety()?;
+            sim.check_liveness(&next)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_blocks_minority_until_healed() -> RaiseResult<()> {
+        let conditions = NetworkConditions {
+            retransmit_every: Some(3),
+            ..NetworkConditions::default()
+        };
+        let mut sim = ConsensusSimulation::new(5, 3, 42, conditions);
+        sim.partition(&[&[0, 1], &[2, 3, 4]]);
+
+        let minority = sim.propose(0, None);
+        let majority = sim.propose(2, None);
+        sim.run(30);
+
+        // Seule la majorité atteint le quorum
+        assert!((2..5).all(|n| sim.nodes[n].has_finalized(&majority)));
+        assert!(sim.nodes.iter().all(|n| !n.has_finalized(&minority)));
+        assert!(sim.check_liveness(&majority).is_err());
+
+        // Réseau rétabli : la minorité rattrape la décision de la majorité
+        sim.heal();
+        assert!(sim.run_until(50, |s| s.is_finalized_everywhere(&majority)));
+        sim.check_safety()?;
+        assert!(sim.nodes.iter().all(|n| !n.has_finalized(&minority)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_crashed_node_does_not_block_quorum() -> RaiseResult<()> {
+        let mut sim = ConsensusSimulation::new(4, 3, 1, NetworkConditions::lossy(0.1, 3));
+        sim.set_behavior(3, NodeBehavior::Crashed);
+        let block = sim.propose(0, None);
+        assert!(sim.run_until(100, |s| s.is_finalized_everywhere(&block)));
+        sim.check_safety()?;
+        sim.check_liveness(&block)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_voter_is_excluded_without_breaking_safety() -> RaiseResult<()> {
+        // Quorum unanime : aucun des deux commits concurrents ne peut aboutir
+        let mut sim = ConsensusSimulation::new(4, 4, 3, NetworkConditions::default());
+        sim.set_behavior(3, NodeBehavior::DoubleVoter);
+        let a = sim.propose(0, None);
+        let b = sim.propose(1, None);
+        sim.run(20);
+
+        sim.check_safety()?;
+        let cheater = sim.nodes[3].keys.public_key_hex();
+        for node in &sim.nodes[..3] {
+            assert!(!node.has_finalized(&a) && !node.has_finalized(&b));
+            assert_eq!(node.engine.reputation.records[&cheater].double_votes, 1);
+            assert!(node.engine.reputation.is_excluded(&cheater));
+        }
+        Ok(())
+    }
+}
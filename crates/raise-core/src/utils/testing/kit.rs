@@ -0,0 +1,522 @@
+// FICHIER : src-tauri/src/utils/testing/kit.rs
+
+//! 🧪 Kit de tests d'intégration pour les plugins et crates aval : un `TestEnvBuilder` fluide
+//! (base JSON-DB, LLM simulé, chaîne Mentis en mémoire) et des assertions par instantané.
+//!
+//! ```ignore
+//! let env = TestEnv::builder()
+//!     .with_collection("actors")
+//!     .with_document("actors", json_value!({ "_id": "a1", "name": "Alice" }))
+//!     .with_mock_llm("OK")
+//!     .build()
+//!     .await?;
+//! assert_json_snapshot("actors_seed", &env.snapshot("actors").await?)?;
+//! ```
+
+use crate::ai::llm::client::LlmEngine;
+use crate::blockchain::crypto::KeyPair;
+use crate::blockchain::p2p::{MentisNetMessage, MentisResponse};
+use crate::blockchain::sync::engine::SyncPeer;
+use crate::blockchain::sync::SyncEngine;
+use crate::blockchain::{Ledger, MentisCommit, Mutation, MutationOp, ValueGateway};
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::data::config::{BOOTSTRAP_DB, BOOTSTRAP_DOMAIN};
+use crate::utils::prelude::*;
+use crate::utils::testing::mock::{inject_mock_component, insert_mock_db, MockLlmEngine};
+use crate::utils::testing::DbSandbox;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Variable d'environnement forçant la réécriture des instantanés (`1`).
+pub const UPDATE_SNAPSHOTS_ENV: &str = "RAISE_UPDATE_SNAPSHOTS";
+/// Champs horodatés retirés des instantanés de collection.
+const VOLATILE_FIELDS: &[&str] = &["_created_at", "_updated_at"];
+
+enum CollectionSeed {
+    Generic,
+    Schema(JsonValue),
+}
+
+/// Construit un environnement de test isolé (dossier temporaire détruit avec le `TestEnv`).
+#[derive(Default)]
+pub struct TestEnvBuilder {
+    target: Option<(String, String)>,
+    collections: Vec<(String, CollectionSeed)>,
+    documents: Vec<(String, JsonValue)>,
+    components: Vec<(String, JsonValue)>,
+    llm_response: Option<String>,
+    ledger_commits: Option<usize>,
+    clock: Option<SharedRef<MockClock>>,
+}
+
+impl TestEnvBuilder {
+    /// Base cible (par défaut : la base système de la sandbox).
+    pub fn database(mut self, space: &str, db: &str) -> Self {
+        self.target = Some((space.to_string(), db.to_string()));
+        self
+    }
+
+    /// Collection au schéma générique (documents libres).
+    pub fn with_collection(mut self, name: &str) -> Self {
+        self.collections
+            .push((name.to_string(), CollectionSeed::Generic));
+        self
+    }
+
+    /// Collection validée par `schema`, enregistré sous `v1/mock/<name>.schema.json`.
+    pub fn with_schema_collection(mut self, name: &str, schema: JsonValue) -> Self {
+        self.collections
+            .push((name.to_string(), CollectionSeed::Schema(schema)));
+        self
+    }
+
+    pub fn with_document(mut self, collection: &str, doc: JsonValue) -> Self {
+        self.documents.push((collection.to_string(), doc));
+        self
+    }
+
+    /// Configuration de composant (`service_configs`), comme `inject_mock_component`.
+    pub fn with_component(mut self, component: &str, settings: JsonValue) -> Self {
+        self.components.push((component.to_string(), settings));
+        self
+    }
+
+    /// Moteur LLM simulé renvoyant toujours `response` (aucun modèle chargé).
+    pub fn with_mock_llm(mut self, response: &str) -> Self {
+        self.llm_response = Some(response.to_string());
+        self
+    }
+
+    /// Registre Mentis en mémoire pré-rempli de `commits` commits signés.
+    pub fn with_mock_ledger(mut self, commits: usize) -> Self {
+        self.ledger_commits = Some(commits);
+        self
+    }
+
+    /// Horloge déterministe horodatant les blocs du registre simulé.
+    pub fn with_mock_clock(mut self, clock: SharedRef<MockClock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub async fn build(self) -> RaiseResult<TestEnv> {
+        let sandbox = DbSandbox::new().await?;
+        let (space, db) = match self.target {
+            Some(target) => target,
+            None => (
+                sandbox.config.mount_points.system.domain.clone(),
+                sandbox.config.mount_points.system.db.clone(),
+            ),
+        };
+
+        let manager = CollectionsManager::new(&sandbox.storage, &space, &db);
+        if space != sandbox.config.mount_points.system.domain
+            || db != sandbox.config.mount_points.system.db
+        {
+            DbSandbox::mock_db(&manager).await?;
+        }
+
+        let generic_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            BOOTSTRAP_DOMAIN, BOOTSTRAP_DB
+        );
+        for (name, seed) in self.collections {
+            let uri = match seed {
+                CollectionSeed::Generic => generic_uri.clone(),
+                CollectionSeed::Schema(schema) => {
+                    let schema_name = format!("v1/mock/{}.schema.json", name);
+                    manager.create_schema_def(&schema_name, schema).await?;
+                    manager.build_schema_uri(&schema_name).await
+                }
+            };
+            manager.create_collection(&name, &uri).await?;
+        }
+        for (collection, doc) in &self.documents {
+            insert_mock_db(&manager, collection, doc).await?;
+        }
+        for (component, settings) in self.components {
+            inject_mock_component(&manager, &component, settings).await?;
+        }
+
+        let llm = self.llm_response.map(|response| {
+            let engine: SharedRef<AsyncMutex<dyn LlmEngine>> =
+                SharedRef::new(AsyncMutex::new(MockLlmEngine { response }));
+            engine
+        });
+        let chain = match self.ledger_commits {
+            Some(commits) => Some(match self.clock {
+                Some(clock) => MockChain::with_clock(commits, clock)?,
+                None => MockChain::with_commits(commits)?,
+            }),
+            None => None,
+        };
+
+        Ok(TestEnv {
+            sandbox,
+            space,
+            db,
+            llm,
+            chain,
+        })
+    }
+}
+
+pub struct TestEnv {
+    pub sandbox: DbSandbox,
+    pub space: String,
+    pub db: String,
+    pub llm: Option<SharedRef<AsyncMutex<dyn LlmEngine>>>,
+    pub chain: Option<MockChain>,
+}
+
+impl TestEnv {
+    pub fn builder() -> TestEnvBuilder {
+        TestEnvBuilder::default()
+    }
+
+    pub fn storage(&self) -> &StorageEngine {
+        &self.sandbox.storage
+    }
+
+    pub fn manager(&self) -> CollectionsManager<'_> {
+        CollectionsManager::new(&self.sandbox.storage, &self.space, &self.db)
+    }
+
+    /// Contenu stable d'une collection : documents triés par `_id`, horodatages retirés.
+    pub async fn snapshot(&self, collection: &str) -> RaiseResult<JsonValue> {
+        let mut docs = self.manager().list_all(collection).await?;
+        for doc in &mut docs {
+            if let Some(obj) = doc.as_object_mut() {
+                for field in VOLATILE_FIELDS {
+                    obj.remove(*field);
+                }
+            }
+        }
+        docs.sort_by(|a, b| {
+            let id = |d: &JsonValue| d.get("_id").and_then(|v| v.as_str()).map(str::to_string);
+            id(a).cmp(&id(b))
+        });
+        Ok(JsonValue::Array(docs))
+    }
+}
+
+// --- CHAÎNE MENTIS SIMULÉE ---
+
+/// Origine par défaut de l'horloge simulée (2024-01-01T00:00:00Z).
+pub const MOCK_CLOCK_EPOCH: i64 = 1_704_067_200;
+/// Écart entre deux blocs ajoutés par `MockChain::extend`.
+pub const MOCK_BLOCK_INTERVAL_SECS: i64 = 60;
+
+/// Horloge déterministe : n'avance que sur demande du test.
+pub struct MockClock {
+    now: SyncMutex<UtcTimestamp>,
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::starting_at(UtcTimestamp::from_timestamp(MOCK_CLOCK_EPOCH, 0).unwrap_or_default())
+    }
+}
+
+impl MockClock {
+    pub fn starting_at(at: UtcTimestamp) -> Self {
+        Self {
+            now: SyncMutex::new(at),
+        }
+    }
+
+    pub fn now(&self) -> UtcTimestamp {
+        match self.now.lock() {
+            Ok(guard) => *guard,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    pub fn advance(&self, by: CalendarDuration) {
+        self.set(self.now() + by);
+    }
+
+    pub fn set(&self, at: UtcTimestamp) {
+        if let Ok(mut guard) = self.now.lock() {
+            *guard = at;
+        }
+    }
+}
+
+/// Registre en mémoire, clés de signature, horloge et passerelle de valeur simulées.
+pub struct MockChain {
+    pub ledger: SharedRef<SyncMutex<Ledger>>,
+    pub keys: KeyPair,
+    pub gateway: SharedRef<MockValueGateway>,
+    /// Horodate les commits ajoutés : les états historiques sont reproductibles.
+    pub clock: SharedRef<MockClock>,
+}
+
+impl MockChain {
+    pub fn with_commits(commits: usize) -> RaiseResult<Self> {
+        Self::with_clock(commits, SharedRef::new(MockClock::default()))
+    }
+
+    /// Chaîne pré-remplie dont les blocs sont horodatés par `clock`.
+    pub fn with_clock(commits: usize, clock: SharedRef<MockClock>) -> RaiseResult<Self> {
+        let chain = Self {
+            ledger: SharedRef::new(SyncMutex::new(Ledger::new())),
+            keys: KeyPair::generate(),
+            gateway: SharedRef::new(MockValueGateway::default()),
+            clock,
+        };
+        chain.extend(commits)?;
+        Ok(chain)
+    }
+
+    fn ledger(&self) -> RaiseResult<SyncMutexGuard<'_, Ledger>> {
+        match self.ledger.lock() {
+            Ok(guard) => Ok(guard),
+            Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+        }
+    }
+
+    /// Ajoute un commit horodaté à l'instant courant de l'horloge ; renvoie sa hauteur.
+    pub fn commit(&self, mutations: Vec<Mutation>) -> RaiseResult<usize> {
+        let mut ledger = self.ledger()?;
+        let commit = MentisCommit::new_at(
+            mutations,
+            ledger.last_commit_hash.clone(),
+            &self.keys,
+            self.clock.now(),
+        );
+        ledger.append_commit(commit)?;
+        Ok(ledger.height())
+    }
+
+    /// Ajoute `count` commits vides, espacés de `MOCK_BLOCK_INTERVAL_SECS`.
+    pub fn extend(&self, count: usize) -> RaiseResult<()> {
+        for _ in 0..count {
+            self.commit(vec![])?;
+            self.clock
+                .advance(CalendarDuration::seconds(MOCK_BLOCK_INTERVAL_SECS));
+        }
+        Ok(())
+    }
+
+    pub fn height(&self) -> RaiseResult<usize> {
+        Ok(self.ledger()?.height())
+    }
+
+    /// Valeur d'un élément après le bloc de hauteur `height` (cf. `Ledger::get_at_block`).
+    pub fn get_at_block(&self, element_id: &str, height: usize) -> RaiseResult<Option<JsonValue>> {
+        Ok(self
+            .ledger()?
+            .get_at_block(element_id, height)
+            .map(|entry| entry.value))
+    }
+
+    /// Pair de synchronisation servi par ce registre (pour `SyncEngine::synchronize_with`).
+    pub fn peer(&self) -> LocalSyncPeer {
+        LocalSyncPeer(SyncEngine::new(self.ledger.clone()))
+    }
+}
+
+/// Pair en mémoire : répond via le `SyncEngine` d'un registre local.
+pub struct LocalSyncPeer(SyncEngine);
+
+#[async_interface]
+impl SyncPeer for LocalSyncPeer {
+    async fn request(&self, msg: MentisNetMessage) -> RaiseResult<MentisResponse> {
+        match self.0.process_sync_request(&msg)? {
+            Some(response) => Ok(response),
+            None => raise_error!(
+                "ERR_TEST_PEER_NO_RESPONSE",
+                context = json_value!({ "message": format!("{:?}", msg) })
+            ),
+        }
+    }
+}
+
+/// Passerelle de paiement simulée : paiements acceptés par défaut, versements journalisés.
+pub struct MockValueGateway {
+    pub accept_payments: AtomicBool,
+    payouts: SyncMutex<Vec<(String, String)>>,
+}
+
+impl Default for MockValueGateway {
+    fn default() -> Self {
+        Self {
+            accept_payments: AtomicBool::new(true),
+            payouts: SyncMutex::new(Vec::new()),
+        }
+    }
+}
+
+impl MockValueGateway {
+    /// Versements déclenchés : `(commit_id, seller)`.
+    pub fn payouts(&self) -> Vec<(String, String)> {
+        match self.payouts.lock() {
+            Ok(guard) => guard.clone(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+#[async_interface]
+impl ValueGateway for MockValueGateway {
+    async fn verify_payment(&self, _commit_id: &str, _buyer: &str) -> RaiseResult<bool> {
+        Ok(self.accept_payments.load(Ordering::SeqCst))
+    }
+
+    async fn trigger_payout(&self, commit_id: &str, seller: &str) -> RaiseResult<()> {
+        match self.payouts.lock() {
+            Ok(mut guard) => guard.push((commit_id.to_string(), seller.to_string())),
+            Err(_) => raise_error!("ERR_SYS_MUTEX_POISONED"),
+        }
+        Ok(())
+    }
+}
+
+// --- INSTANTANÉS ---
+
+/// Dossier des instantanés : `<crate testée>/tests/snapshots` (`CARGO_MANIFEST_DIR` est
+/// fourni par `cargo test` à l'exécution, donc propre à la crate appelante).
+pub fn snapshot_dir() -> PathBuf {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(root).join("tests").join("snapshots")
+}
+
+/// Compare `value` à l'instantané `<name>.json`. Un instantané absent est créé ;
+/// `RAISE_UPDATE_SNAPSHOTS=1` réécrit les instantanés existants.
+pub fn assert_json_snapshot(name: &str, value: &JsonValue) -> RaiseResult<()> {
+    assert_json_snapshot_in(&snapshot_dir(), name, value)
+}
+
+pub fn assert_json_snapshot_in(dir: &Path, name: &str, value: &JsonValue) -> RaiseResult<()> {
+    let path = dir.join(format!("{}.json", name));
+    let update = std::env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|v| v == "1");
+
+    if update || !fs::exists_sync(&path) {
+        fs::ensure_dir_sync(dir)?;
+        fs::write_sync(&path, json::serialize_to_string_pretty(value)?)?;
+        user_info!(
+            "INF_TEST_SNAPSHOT_WRITTEN",
+            json_value!({ "snapshot": path.to_string_lossy() })
+        );
+        return Ok(());
+    }
+
+    let expected: JsonValue = fs::read_json_sync(&path)?;
+    if &expected != value {
+        raise_error!(
+            "ERR_TEST_SNAPSHOT_MISMATCH",
+            error = format!("L'instantané '{}' ne correspond plus.", name),
+            context = json_value!({
+                "snapshot": path.to_string_lossy(),
+                "expected": expected,
+                "actual": value,
+                "hint": format!("Relancez avec {}=1 si le changement est voulu.", UPDATE_SNAPSHOTS_ENV)
+            })
+        );
+    }
+    Ok(())
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_builder_seeds_db_llm_and_chain() -> RaiseResult<()> {
+        let env = TestEnv::builder()
+            .database("kit_space", "kit_db")
+            .with_collection("actors")
+            .with_schema_collection(
+                "ratings",
+                json_value!({ "type": "object", "properties": { "score": { "type": "number" } } }),
+            )
+            .with_document("actors", json_value!({ "_id": "b2", "name": "Bob" }))
+            .with_document("actors", json_value!({ "_id": "a1", "name": "Alice" }))
+            .with_mock_llm("pong")
+            .with_mock_ledger(3)
+            .build()
+            .await?;
+
+        let snapshot = env.snapshot("actors").await?;
+        assert_eq!(snapshot[0]["name"], "Alice");
+        assert_eq!(snapshot[1]["name"], "Bob");
+        assert!(env
+            .manager()
+            .list_collections()
+            .await?
+            .contains(&"ratings".to_string()));
+
+        let llm = env.llm.as_ref().expect("LLM simulé");
+        assert_eq!(llm.lock().await.generate("", "ping", 8).await?, "pong");
+
+        let chain = env.chain.as_ref().expect("Chaîne simulée");
+        let local = SharedRef::new(SyncMutex::new(Ledger::new()));
+        SyncEngine::new(local.clone())
+            .synchronize_with(&chain.peer(), |_| {})
+            .await?;
+        assert_eq!(local.lock().map(|l| l.len()).unwrap_or_default(), 3);
+
+        chain.gateway.trigger_payout("c1", "alice").await?;
+        assert_eq!(chain.gateway.payouts(), vec![("c1".into(), "alice".into())]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mock_chain_time_travel() -> RaiseResult<()> {
+        let clock = SharedRef::new(MockClock::default());
+        let chain = MockChain::with_clock(2, clock.clone())?;
+        let start = UtcTimestamp::from_timestamp(MOCK_CLOCK_EPOCH, 0).unwrap();
+        assert_eq!(
+            clock.now(),
+            start + CalendarDuration::seconds(2 * MOCK_BLOCK_INTERVAL_SECS)
+        );
+
+        let lease = |expires: i64| Mutation {
+            element_id: "urn:lease:1".into(),
+            operation: MutationOp::Update,
+            payload: json_value!({ "expires_at": expires }),
+        };
+        let h1 = chain.commit(vec![lease(100)])?;
+        clock.advance(CalendarDuration::hours(1));
+        let h2 = chain.commit(vec![lease(200)])?;
+        assert_eq!((h1, h2, chain.height()?), (3, 4, 4));
+
+        assert!(chain.get_at_block("urn:lease:1", 2)?.is_none());
+        assert_eq!(
+            chain.get_at_block("urn:lease:1", h1)?.unwrap()["expires_at"],
+            100
+        );
+        assert_eq!(
+            chain.get_at_block("urn:lease:1", h2)?.unwrap()["expires_at"],
+            200
+        );
+
+        let ledger = chain.ledger()?;
+        assert_eq!(ledger.block_timestamp(1), Some(start));
+        assert_eq!(
+            ledger.height_at(clock.now() - CalendarDuration::minutes(1)),
+            3
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_snapshot_roundtrip() -> RaiseResult<()> {
+        let dir = tempdir()?;
+        let value = json_value!({ "answer": 42 });
+
+        assert_json_snapshot_in(dir.path(), "answer", &value)?;
+        assert!(dir.path().join("answer.json").exists());
+        assert_json_snapshot_in(dir.path(), "answer", &value)?;
+        assert!(
+            assert_json_snapshot_in(dir.path(), "answer", &json_value!({ "answer": 7 })).is_err()
+        );
+        Ok(())
+    }
+}
@@ -11,8 +11,10 @@ use crate::utils::io::fs::{self, tempdir, Path, PathBuf, TempDir};
 
 // 2. Data : Configuration, JSON et Traits
 use crate::utils::data::config::{
-    AiAssetsPaths, AppConfig, CoreConfig, DbPointer, MountPointsConfig, SystemAssets, BOOTSTRAP_DB,
-    BOOTSTRAP_DOMAIN, CONFIG,
+    AccessControlConfig, AiAssetsPaths, AiBudgetConfig, AppConfig, CoreConfig, DbPointer,
+    MountPointsConfig, NetworkConfig, PlanReviewConfig, ReputationConfig, ServerConfig,
+    SystemAssets, TelemetryConfig, TtlConfig, VacuumConfig, WebhookConfig,
+    WorkflowSchedulingConfig, BOOTSTRAP_DB, BOOTSTRAP_DOMAIN, CONFIG,
 };
 use crate::utils::data::json::{self, json_value, JsonValue};
 use crate::utils::data::UnorderedMap;
@@ -346,6 +348,17 @@ pub fn create_default_test_config() -> AppConfig {
                 ),
             }),
         },
+        telemetry: TelemetryConfig::default(),
+        network: NetworkConfig::default(),
+        vacuum: VacuumConfig::default(),
+        ttl: TtlConfig::default(),
+        reputation: ReputationConfig::default(),
+        ai_budget: AiBudgetConfig::default(),
+        webhooks: WebhookConfig::default(),
+        server: ServerConfig::default(),
+        workflow_scheduling: WorkflowSchedulingConfig::default(),
+        plan_review: PlanReviewConfig::default(),
+        access_control: AccessControlConfig::default(),
 
         paths,
         active_dapp_id: "ref:dapps:handle:raise_core".to_string(),
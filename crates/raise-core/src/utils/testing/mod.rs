@@ -1,5 +1,7 @@
 // FICHIER : src-tauri/src/utils/testing/mod.rs
 
+pub mod consensus_sim;
+pub mod kit;
 pub mod mock;
 
 // On expose les sandboxes pour qu'elles soient facilement utilisables
@@ -8,3 +10,9 @@ pub use mock::{
     inject_collection_schema, inject_mock_component, inject_mock_config, AgentDbSandbox, DbSandbox,
     GlobalDbSandbox, SESSION_SCHEMA_MOCK,
 };
+
+// Kit fluide pour les tests d'intégration des plugins et crates aval.
+pub use kit::{assert_json_snapshot, MockChain, MockClock, TestEnv, TestEnvBuilder};
+
+// Banc de simulation déterministe du consensus (réseau avec pertes, délais et partitions).
+pub use consensus_sim::{ConsensusSimulation, NetworkConditions, NodeBehavior, SimEvent};
@@ -0,0 +1,333 @@
+// FICHIER : src-tauri/src/workflow_engine/admission.rs
+
+use crate::utils::data::config::WorkflowSchedulingConfig;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+use crate::workflow_engine::{SchedulingHints, WorkflowPriority};
+
+/// File d'admission des exécutions de workflows.
+///
+/// Chaque exécution obtient un `RunPermit` avant de tourner. Un créneau libéré revient à la
+/// demande de plus haute priorité effective (priorité déclarée + vieillissement), à égalité à
+/// la classe la moins occupée, puis à la plus ancienne. Une classe pleine ne bloque pas les
+/// autres : un lot de `codegen` en `background` laisse passer les flux HITL `interactive`.
+pub struct AdmissionController {
+    config: WorkflowSchedulingConfig,
+    state: SyncMutex<AdmissionState>,
+    signal: AsyncSignal,
+}
+
+#[derive(Default)]
+struct AdmissionState {
+    next_ticket: u64,
+    waiting: Vec<Ticket>,
+    running: UnorderedMap<String, usize>,
+}
+
+struct Ticket {
+    id: u64,
+    class: String,
+    priority: WorkflowPriority,
+    enqueued_at: TimeInstant,
+}
+
+/// Photographie de la file pour la supervision (UI, CLI).
+#[derive(Debug, Clone, Default, Serializable)]
+pub struct AdmissionSnapshot {
+    pub max_concurrent: usize,
+    pub running: usize,
+    pub running_by_class: OrderedMap<String, usize>,
+    pub waiting: usize,
+    pub waiting_by_class: OrderedMap<String, usize>,
+}
+
+/// Créneau d'exécution : libéré (et la file réveillée) à sa destruction.
+pub struct RunPermit {
+    controller: SharedRef<AdmissionController>,
+    class: String,
+}
+
+impl Drop for RunPermit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.controller.lock_state();
+            if let Some(count) = state.running.get_mut(&self.class) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    state.running.remove(&self.class);
+                }
+            }
+        }
+        self.controller.signal.notify_waiters();
+    }
+}
+
+/// Retire le ticket de la file si l'attente est abandonnée (future annulée).
+struct PendingTicket<'a> {
+    controller: &'a AdmissionController,
+    id: u64,
+    admitted: bool,
+}
+
+impl Drop for PendingTicket<'_> {
+    fn drop(&mut self) {
+        if self.admitted {
+            return;
+        }
+        self.controller
+            .lock_state()
+            .waiting
+            .retain(|t| t.id != self.id);
+        self.controller.signal.notify_waiters();
+    }
+}
+
+impl Default for AdmissionController {
+    fn default() -> Self {
+        Self::new(WorkflowSchedulingConfig::default())
+    }
+}
+
+impl AdmissionController {
+    pub fn new(config: WorkflowSchedulingConfig) -> Self {
+        Self {
+            config,
+            state: SyncMutex::new(AdmissionState::default()),
+            signal: AsyncSignal::new(),
+        }
+    }
+
+    /// Attend qu'un créneau soit attribué à ce workflow selon sa priorité et sa classe.
+    pub async fn acquire(self: &SharedRef<Self>, hints: &SchedulingHints) -> RunPermit {
+        let class = hints.class_name().to_string();
+        let id = {
+            let mut state = self.lock_state();
+            let id = state.next_ticket;
+            state.next_ticket += 1;
+            state.waiting.push(Ticket {
+                id,
+                class: class.clone(),
+                priority: hints.priority,
+                enqueued_at: TimeInstant::now(),
+            });
+            id
+        };
+        let mut pending = PendingTicket {
+            controller: self,
+            id,
+            admitted: false,
+        };
+
+        loop {
+            // Le futur est créé avant la vérification pour ne manquer aucune libération
+            let notified = self.signal.notified();
+            if self.try_admit(id) {
+                pending.admitted = true;
+                // Un créneau peut encore rester libre pour une autre classe
+                self.signal.notify_waiters();
+                return RunPermit {
+                    controller: self.clone(),
+                    class,
+                };
+            }
+            notified.await;
+        }
+    }
+
+    pub fn snapshot(&self) -> AdmissionSnapshot {
+        let state = self.lock_state();
+        let mut waiting_by_class = OrderedMap::new();
+        for ticket in &state.waiting {
+            *waiting_by_class.entry(ticket.class.clone()).or_insert(0) += 1;
+        }
+        AdmissionSnapshot {
+            max_concurrent: self.max_concurrent(),
+            running: state.running.values().sum(),
+            running_by_class: state.running.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            waiting: state.waiting.len(),
+            waiting_by_class,
+        }
+    }
+
+    fn try_admit(&self, id: u64) -> bool {
+        let mut state = self.lock_state();
+        if self.select(&state, TimeInstant::now()) != Some(id) {
+            return false;
+        }
+        let Some(index) = state.waiting.iter().position(|t| t.id == id) else {
+            return false;
+        };
+        let ticket = state.waiting.remove(index);
+        *state.running.entry(ticket.class).or_insert(0) += 1;
+        true
+    }
+
+    /// Ticket à admettre au prochain créneau, `None` si aucun n'est admissible.
+    fn select(&self, state: &AdmissionState, now: TimeInstant) -> Option<u64> {
+        let running_total: usize = state.running.values().sum();
+        if running_total >= self.max_concurrent() {
+            return None;
+        }
+        let running_in = |class: &str| state.running.get(class).copied().unwrap_or(0);
+
+        state
+            .waiting
+            .iter()
+            .filter(|t| match self.config.class_limits.get(&t.class) {
+                Some(limit) => running_in(&t.class) < *limit,
+                None => true,
+            })
+            .max_by_key(|t| {
+                (
+                    self.effective_priority(t, now),
+                    ReverseOrder(running_in(&t.class)),
+                    ReverseOrder(t.id),
+                )
+            })
+            .map(|t| t.id)
+    }
+
+    /// Priorité déclarée, augmentée d'un niveau par tranche de `aging_secs` d'attente.
+    fn effective_priority(&self, ticket: &Ticket, now: TimeInstant) -> u64 {
+        let aging = match self.config.aging_secs {
+            0 => 0,
+            secs => now.saturating_duration_since(ticket.enqueued_at).as_secs() / secs,
+        };
+        ticket.priority.rank() + aging
+    }
+
+    fn max_concurrent(&self) -> usize {
+        self.config.max_concurrent.max(1)
+    }
+
+    fn lock_state(&self) -> SyncMutexGuard<'_, AdmissionState> {
+        match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hints(priority: WorkflowPriority, class: Option<&str>) -> SchedulingHints {
+        SchedulingHints {
+            priority,
+            class: class.map(String::from),
+        }
+    }
+
+    #[async_test]
+    async fn test_interactive_overtakes_background_batch() {
+        let controller = SharedRef::new(AdmissionController::new(WorkflowSchedulingConfig {
+            max_concurrent: 1,
+            ..Default::default()
+        }));
+        let busy = controller
+            .acquire(&hints(WorkflowPriority::Normal, None))
+            .await;
+
+        let order = SharedRef::new(SyncMutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for (label, priority, class) in [
+            ("codegen_1", WorkflowPriority::Background, Some("codegen")),
+            ("codegen_2", WorkflowPriority::Background, Some("codegen")),
+            ("hitl", WorkflowPriority::Interactive, None),
+        ] {
+            let (controller, order) = (controller.clone(), order.clone());
+            tasks.push(spawn_async_task(async move {
+                let _permit = controller.acquire(&hints(priority, class)).await;
+                order.lock().unwrap().push(label);
+            }));
+            sleep_async(TimeDuration::from_millis(10)).await;
+        }
+
+        let snapshot = controller.snapshot();
+        assert_eq!(snapshot.running, 1);
+        assert_eq!(snapshot.waiting, 3);
+        assert_eq!(snapshot.waiting_by_class.get("codegen"), Some(&2));
+
+        drop(busy);
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec!["hitl", "codegen_1", "codegen_2"]
+        );
+        assert_eq!(controller.snapshot().running, 0);
+    }
+
+    #[async_test]
+    async fn test_class_limit_leaves_room_for_other_classes() {
+        let mut class_limits = OrderedMap::new();
+        class_limits.insert("codegen".to_string(), 1);
+        let controller = SharedRef::new(AdmissionController::new(WorkflowSchedulingConfig {
+            max_concurrent: 4,
+            class_limits,
+            aging_secs: 0,
+        }));
+
+        let _codegen = controller
+            .acquire(&hints(WorkflowPriority::Critical, Some("codegen")))
+            .await;
+
+        let second = hints(WorkflowPriority::Critical, Some("codegen"));
+        let blocked = AgentAttention! {
+            _ = controller.acquire(&second) => false,
+            _ = sleep_async(TimeDuration::from_millis(50)) => true,
+        };
+        assert!(blocked, "la classe codegen est plafonnée à 1");
+        // L'attente abandonnée ne doit pas rester dans la file
+        assert_eq!(controller.snapshot().waiting, 0);
+
+        let _etl = controller
+            .acquire(&hints(WorkflowPriority::Background, Some("etl")))
+            .await;
+        assert_eq!(controller.snapshot().running, 2);
+    }
+
+    #[test]
+    fn test_aging_prevents_starvation() {
+        let controller = AdmissionController::new(WorkflowSchedulingConfig {
+            max_concurrent: 1,
+            aging_secs: 30,
+            ..Default::default()
+        });
+        let now = TimeInstant::now();
+        let later = now + TimeDuration::from_secs(60);
+        let mut state = AdmissionState {
+            next_ticket: 2,
+            waiting: vec![
+                Ticket {
+                    id: 0,
+                    class: "codegen".into(),
+                    priority: WorkflowPriority::Background,
+                    enqueued_at: now,
+                },
+                Ticket {
+                    id: 1,
+                    class: "default".into(),
+                    priority: WorkflowPriority::Normal,
+                    enqueued_at: now,
+                },
+            ],
+            running: UnorderedMap::new(),
+        };
+
+        assert_eq!(controller.select(&state, now), Some(1));
+
+        // Après 60 s d'attente, le lot background (0 + 2) passe devant un flux normal tout juste arrivé (1 + 0)
+        state.waiting[1].enqueued_at = later;
+        assert_eq!(controller.select(&state, later), Some(0));
+
+        // Plafond global atteint : plus personne n'est admis
+        state.running.insert("default".into(), 1);
+        assert_eq!(controller.select(&state, later), None);
+    }
+}
@@ -0,0 +1,226 @@
+// FICHIER : src-tauri/src/workflow_engine/context_schema.rs
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::schema::{SchemaRegistry, SchemaValidator};
+use crate::utils::prelude::*;
+
+use super::WorkflowDefinition;
+
+/// Erreur de conformité sur un champ du contexte d'entrée.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct ContextFieldError {
+    /// Nom du champ (`$` pour une règle portant sur le document entier)
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// Valide le contexte d'entrée d'une instance contre le `context_schema` du workflow.
+/// Toutes les erreurs de champ sont rapportées d'un coup (`ERR_WF_CONTEXT_INVALID`)
+/// afin d'échouer avant le premier nœud plutôt qu'en cours d'exécution.
+pub async fn validate_context(
+    definition: &WorkflowDefinition,
+    context: &JsonObject<String, JsonValue>,
+    manager: &CollectionsManager<'_>,
+) -> RaiseResult<()> {
+    let Some(declared) = &definition.context_schema else {
+        return Ok(());
+    };
+
+    let mut registry =
+        SchemaRegistry::from_db(&manager.storage.config, &manager.space, &manager.db).await?;
+    let (uri, schema) = match declared {
+        JsonValue::String(uri) => match registry.get_by_uri(uri) {
+            Some(s) => (uri.clone(), s.clone()),
+            None => raise_error!(
+                "ERR_WF_CONTEXT_SCHEMA_NOT_FOUND",
+                error = format!("Schéma de contexte introuvable : {}", uri),
+                context = json_value!({ "workflow": definition.handle })
+            ),
+        },
+        JsonValue::Object(_) => {
+            // Schéma embarqué : enregistré à côté des schémas de la base pour résoudre les `$ref` relatifs
+            let uri = format!(
+                "db://{}/{}/schemas/v1/workflows/context/{}.schema.json",
+                manager.space, manager.db, definition.handle
+            );
+            registry.register(uri.clone(), declared.clone());
+            (uri, declared.clone())
+        }
+        _ => raise_error!(
+            "ERR_WF_CONTEXT_SCHEMA_INVALID",
+            error = "`context_schema` doit être un objet JSON Schema ou une URI db://",
+            context = json_value!({ "workflow": definition.handle })
+        ),
+    };
+
+    let instance = JsonValue::Object(context.clone());
+    let validator = SchemaValidator::compile_with_registry(&uri, &registry)?;
+    let Err(root_error) = validator.validate(&instance) else {
+        return Ok(());
+    };
+
+    let mut errors = collect_field_errors(&uri, &schema, context, &mut registry);
+    if errors.is_empty() {
+        errors.push(to_field_error("$", &root_error));
+    }
+
+    raise_error!(
+        "ERR_WF_CONTEXT_INVALID",
+        error = format!(
+            "Contexte d'entrée non conforme pour le workflow '{}' ({} erreur(s))",
+            definition.handle,
+            errors.len()
+        ),
+        context = json_value!({
+            "workflow": definition.handle,
+            "schema": uri,
+            "errors": errors
+        })
+    );
+}
+
+/// Passe champ par champ : le validateur s'arrête à la première erreur,
+/// on isole donc chaque propriété dans une copie restreinte du schéma racine
+/// (les `definitions` restent disponibles pour les `$ref` internes).
+fn collect_field_errors(
+    uri: &str,
+    schema: &JsonValue,
+    context: &JsonObject<String, JsonValue>,
+    registry: &mut SchemaRegistry,
+) -> Vec<ContextFieldError> {
+    let mut errors = Vec::new();
+
+    if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+        for field in required.iter().filter_map(|f| f.as_str()) {
+            if !context.contains_key(field) {
+                errors.push(ContextFieldError {
+                    field: field.to_string(),
+                    code: "ERR_VALIDATION_REQUIRED_FIELD_MISSING".into(),
+                    message: format!("Propriété obligatoire manquante : '{}'", field),
+                });
+            }
+        }
+    }
+
+    let properties = schema.get("properties").and_then(|p| p.as_object());
+    if let Some(props) = properties {
+        for (field, sub_schema) in props {
+            let Some(value) = context.get(field) else {
+                continue;
+            };
+            let mut restricted = schema.clone();
+            if let Some(obj) = restricted.as_object_mut() {
+                obj.remove("required");
+                obj.remove("additionalProperties");
+                obj.remove("patternProperties");
+                obj.insert(
+                    "properties".into(),
+                    json_value!({ field.as_str(): sub_schema }),
+                );
+            }
+            let field_uri = uri.replace(".json", &format!(".{}.json", field));
+            registry.register(field_uri.clone(), restricted);
+
+            let single = json_value!({ field.as_str(): value });
+            let outcome = SchemaValidator::compile_with_registry(&field_uri, registry)
+                .and_then(|v| v.validate(&single));
+            if let Err(e) = outcome {
+                errors.push(to_field_error(field, &e));
+            }
+        }
+    }
+
+    if schema.get("additionalProperties") == Some(&JsonValue::Bool(false)) {
+        for key in context.keys() {
+            let declared = properties.is_some_and(|p| p.contains_key(key));
+            if !declared && !key.starts_with('_') {
+                errors.push(ContextFieldError {
+                    field: key.clone(),
+                    code: "ERR_VALIDATION_ADDITIONAL_PROPERTY_FORBIDDEN".into(),
+                    message: format!("Propriété non autorisée : '{}'", key),
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+fn to_field_error(field: &str, error: &AppError) -> ContextFieldError {
+    let AppError::Structured(data) = error;
+    ContextFieldError {
+        field: field.to_string(),
+        code: data.code.clone(),
+        message: data.message.clone(),
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+    use crate::workflow_engine::SchedulingHints;
+
+    fn definition(schema: Option<JsonValue>) -> WorkflowDefinition {
+        WorkflowDefinition {
+            _id: None,
+            handle: "wf_ctx".into(),
+            nodes: vec![],
+            edges: vec![],
+            entry: "start".into(),
+            context_schema: schema,
+            webhook: None,
+            scheduling: SchedulingHints::default(),
+            anchor_receipt: false,
+        }
+    }
+
+    fn context(value: JsonValue) -> JsonObject<String, JsonValue> {
+        value.as_object().cloned().unwrap_or_default()
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_context_schema_reports_every_invalid_field() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &sandbox.config.mount_points.system.domain,
+            &sandbox.config.mount_points.system.db,
+        );
+        let def = definition(Some(json_value!({
+            "type": "object",
+            "required": ["system_name", "budget"],
+            "additionalProperties": false,
+            "properties": {
+                "system_name": { "type": "string", "minLength": 3 },
+                "budget": { "type": "number" },
+                "priority": { "type": "integer" }
+            }
+        })));
+
+        let ok = context(json_value!({ "system_name": "Brake", "budget": 12.5 }));
+        validate_context(&def, &ok, &manager).await?;
+
+        let bad = context(json_value!({ "system_name": "B", "priority": "high", "extra": 1 }));
+        let Err(AppError::Structured(data)) = validate_context(&def, &bad, &manager).await else {
+            panic!("Le contexte aurait dû être rejeté");
+        };
+        assert_eq!(data.code, "ERR_WF_CONTEXT_INVALID");
+
+        let errors: Vec<ContextFieldError> =
+            json::deserialize_from_value(data.context["errors"].clone())?;
+        let mut fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        fields.sort();
+        assert_eq!(fields, vec!["budget", "extra", "priority", "system_name"]);
+
+        // Sans schéma déclaré, tout contexte est accepté
+        validate_context(&definition(None), &bad, &manager).await?;
+        Ok(())
+    }
+}
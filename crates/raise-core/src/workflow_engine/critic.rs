@@ -5,6 +5,9 @@ use crate::ai::assurance::xai::XaiFrame;
 use crate::json_db::collections::manager::CollectionsManager;
 use crate::rules_engine::ast::Expr;
 use crate::rules_engine::evaluator::{DataProvider, Evaluator};
+use crate::utils::data::config::PlanReviewConfig;
+use crate::workflow_engine::mandate::{Mandate, Strategy};
+use crate::workflow_engine::{NodeType, WorkflowDefinition, WorkflowNode};
 
 /// Résultat de l'évaluation d'une action par le critique
 #[derive(Debug, Clone, Serializable, Deserializable)]
@@ -14,6 +17,60 @@ pub struct CritiqueResult {
     pub reasoning: String,   // Explication pour l'audit
 }
 
+/// Gravité d'un constat de revue de plan.
+#[derive(Debug, Clone, Copy, Serializable, Deserializable, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingSeverity {
+    Info,
+    Warning,
+    Blocking,
+}
+
+/// Constat unitaire de la revue d'un plan.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct PlanFinding {
+    /// Famille du contrôle : `mandate`, `resources` ou `anti_pattern`
+    pub check: String,
+    pub code: String,
+    pub severity: FindingSeverity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    pub message: String,
+}
+
+/// Estimation statique des ressources consommées par un plan.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+pub struct PlanEstimates {
+    pub node_count: usize,
+    pub llm_calls: usize,
+    /// Appels sortants ou écritures (MCP, HTTP, WASM, JsonDb)
+    pub external_calls: usize,
+    pub estimated_tokens: u64,
+    /// Nombre de nœuds du plus long chemin depuis l'entrée
+    pub longest_path: usize,
+}
+
+/// Critique structurée d'un plan, attachée à l'instance avant exécution.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+pub struct PlanCritique {
+    pub workflow: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<String>,
+    pub score: f32,
+    pub is_acceptable: bool,
+    pub estimates: PlanEstimates,
+    pub findings: Vec<PlanFinding>,
+    pub reviewed_at: i64,
+}
+
+impl PlanCritique {
+    pub fn has_blocking(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|f| f.severity == FindingSeverity::Blocking)
+    }
+}
+
 /// 🎯 NOUVEAU : Le Provider qui permet au moteur de règles d'interroger la base de données
 pub struct CriticDataProvider<'a> {
     manager: &'a CollectionsManager<'a>,
@@ -118,4 +175,552 @@ impl WorkflowCritic {
             reasoning: notes.join(" | "),
         })
     }
+
+    /// Revue statique d'un plan avant exécution : conformité au mandat, estimation des
+    /// ressources et anti-patterns connus des plans générés (cycles, nœuds orphelins,
+    /// écritures externes sans garde). Un constat `Blocking` rend le plan inacceptable.
+    pub fn review_plan(
+        &self,
+        def: &WorkflowDefinition,
+        mandate: Option<&Mandate>,
+        config: &PlanReviewConfig,
+    ) -> PlanCritique {
+        let graph = PlanGraph::new(def);
+        let mut findings = graph.anti_patterns();
+
+        // 1. Conformité au mandat
+        match mandate {
+            Some(mandate) => findings.extend(graph.mandate_findings(mandate)),
+            None => findings.push(PlanFinding {
+                check: "mandate".into(),
+                code: "PLAN_NO_MANDATE".into(),
+                severity: FindingSeverity::Info,
+                node_id: None,
+                message: "Aucun mandat rattaché à la mission : contrôles de gouvernance ignorés."
+                    .into(),
+            }),
+        }
+
+        // 2. Estimation des ressources
+        let estimates = graph.estimates(config);
+        if estimates.node_count > config.max_nodes {
+            findings.push(PlanFinding {
+                check: "resources".into(),
+                code: "PLAN_TOO_MANY_NODES".into(),
+                severity: FindingSeverity::Blocking,
+                node_id: None,
+                message: format!(
+                    "{} nœuds pour un maximum de {}.",
+                    estimates.node_count, config.max_nodes
+                ),
+            });
+        }
+        if estimates.estimated_tokens > config.max_estimated_tokens {
+            findings.push(PlanFinding {
+                check: "resources".into(),
+                code: "PLAN_TOKEN_BUDGET_EXCEEDED".into(),
+                severity: FindingSeverity::Blocking,
+                node_id: None,
+                message: format!(
+                    "~{} tokens estimés ({} appels LLM) pour un maximum de {}.",
+                    estimates.estimated_tokens, estimates.llm_calls, config.max_estimated_tokens
+                ),
+            });
+        }
+
+        let mut score: f32 = 1.0;
+        for finding in &findings {
+            score -= match finding.severity {
+                FindingSeverity::Blocking => 0.4,
+                FindingSeverity::Warning => 0.1,
+                FindingSeverity::Info => 0.0,
+            };
+        }
+        let score = score.clamp(0.0, 1.0);
+
+        let mut critique = PlanCritique {
+            workflow: def.handle.clone(),
+            mandate: mandate.map(|m| m.handle.clone()),
+            score,
+            is_acceptable: false,
+            estimates,
+            findings,
+            reviewed_at: UtcClock::now().timestamp(),
+        };
+        critique.is_acceptable = score >= self.threshold && !critique.has_blocking();
+        critique
+    }
+}
+
+/// Vue indexée d'un `WorkflowDefinition` pour les contrôles structurels.
+struct PlanGraph<'a> {
+    def: &'a WorkflowDefinition,
+    children: UnorderedMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> PlanGraph<'a> {
+    fn new(def: &'a WorkflowDefinition) -> Self {
+        let mut children: UnorderedMap<&str, Vec<&str>> = UnorderedMap::new();
+        for edge in &def.edges {
+            children
+                .entry(edge.from.as_str())
+                .or_default()
+                .push(edge.to.as_str());
+        }
+        Self { def, children }
+    }
+
+    fn has_node(&self, id: &str) -> bool {
+        self.def.nodes.iter().any(|n| n.id == id)
+    }
+
+    /// Nœuds atteignables depuis l'entrée, sans traverser les nœuds pour lesquels `stop` est vrai.
+    fn reachable(&self, stop: impl Fn(&NodeType) -> bool) -> Vec<&'a str> {
+        let mut seen = vec![self.def.entry.as_str()];
+        let mut cursor = 0;
+        while cursor < seen.len() {
+            let id = seen[cursor];
+            cursor += 1;
+            let blocked = self
+                .def
+                .nodes
+                .iter()
+                .find(|n| n.id == id)
+                .is_some_and(|n| stop(&n.r#type));
+            if blocked {
+                continue;
+            }
+            for child in self.children.get(id).into_iter().flatten() {
+                if !seen.contains(child) {
+                    seen.push(child);
+                }
+            }
+        }
+        seen
+    }
+
+    fn anti_patterns(&self) -> Vec<PlanFinding> {
+        let mut findings = Vec::new();
+        let mut push = |code: &str, severity, node_id: Option<&str>, message: String| {
+            findings.push(PlanFinding {
+                check: "anti_pattern".into(),
+                code: code.into(),
+                severity,
+                node_id: node_id.map(String::from),
+                message,
+            });
+        };
+
+        let mut ids: Vec<&str> = Vec::new();
+        for node in &self.def.nodes {
+            if ids.contains(&node.id.as_str()) {
+                push(
+                    "PLAN_DUPLICATE_NODE",
+                    FindingSeverity::Blocking,
+                    Some(&node.id),
+                    "Identifiant de nœud dupliqué.".into(),
+                );
+            }
+            ids.push(&node.id);
+        }
+
+        if !self.has_node(&self.def.entry) {
+            push(
+                "PLAN_ENTRY_MISSING",
+                FindingSeverity::Blocking,
+                Some(&self.def.entry),
+                "Le nœud d'entrée n'existe pas dans le plan.".into(),
+            );
+            return findings;
+        }
+
+        for edge in &self.def.edges {
+            for end in [&edge.from, &edge.to] {
+                if !self.has_node(end) {
+                    push(
+                        "PLAN_DANGLING_EDGE",
+                        FindingSeverity::Blocking,
+                        Some(end),
+                        format!("Arête {} -> {} vers un nœud inconnu.", edge.from, edge.to),
+                    );
+                }
+            }
+        }
+
+        if let Some(node_id) = self.find_cycle() {
+            push(
+                "PLAN_CYCLE",
+                FindingSeverity::Blocking,
+                Some(node_id),
+                "Boucle détectée : le moteur attend un graphe acyclique.".into(),
+            );
+        }
+
+        let reachable = self.reachable(|_| false);
+        for node in &self.def.nodes {
+            if !reachable.contains(&node.id.as_str()) {
+                push(
+                    "PLAN_UNREACHABLE_NODE",
+                    FindingSeverity::Warning,
+                    Some(&node.id),
+                    format!("'{}' n'est jamais atteint depuis l'entrée.", node.name),
+                );
+            }
+        }
+
+        if !self.def.nodes.iter().any(|n| n.r#type == NodeType::End) {
+            push(
+                "PLAN_NO_END",
+                FindingSeverity::Warning,
+                None,
+                "Aucun nœud de fin explicite.".into(),
+            );
+        }
+
+        // Écriture externe atteignable sans franchir de garde (QualityGate ou validation humaine)
+        let unguarded = self.reachable(|t| matches!(t, NodeType::QualityGate | NodeType::GateHitl));
+        for node in &self.def.nodes {
+            if unguarded.contains(&node.id.as_str()) && is_external_write(node) {
+                push(
+                    "PLAN_UNGUARDED_WRITE",
+                    FindingSeverity::Warning,
+                    Some(&node.id),
+                    format!(
+                        "'{}' modifie un système sans garde préalable (quality_gate / gate_hitl).",
+                        node.name
+                    ),
+                );
+            }
+        }
+
+        findings
+    }
+
+    fn mandate_findings(&self, mandate: &Mandate) -> Vec<PlanFinding> {
+        let reachable = self.reachable(|_| false);
+        let mut findings = Vec::new();
+
+        for veto in mandate.hard_logic.vetos.iter().filter(|v| v.active) {
+            let guarded = self.def.nodes.iter().any(|n| {
+                n.r#type == NodeType::QualityGate
+                    && n.params.get("rule").and_then(|r| r.as_str()) == Some(veto.rule.as_str())
+                    && reachable.contains(&n.id.as_str())
+            });
+            if !guarded {
+                findings.push(PlanFinding {
+                    check: "mandate".into(),
+                    code: "PLAN_VETO_NOT_ENFORCED".into(),
+                    severity: FindingSeverity::Blocking,
+                    node_id: None,
+                    message: format!(
+                        "Le veto '{}' du mandat {} n'est contrôlé par aucun quality_gate atteignable.",
+                        veto.rule, mandate.handle
+                    ),
+                });
+            }
+        }
+
+        if mandate.governance.strategy == Strategy::SafetyFirst
+            && !self
+                .def
+                .nodes
+                .iter()
+                .any(|n| n.r#type == NodeType::GateHitl)
+        {
+            findings.push(PlanFinding {
+                check: "mandate".into(),
+                code: "PLAN_NO_HUMAN_GATE".into(),
+                severity: FindingSeverity::Warning,
+                node_id: None,
+                message: "Stratégie SAFETY_FIRST sans aucune validation humaine (gate_hitl)."
+                    .into(),
+            });
+        }
+
+        findings
+    }
+
+    fn estimates(&self, config: &PlanReviewConfig) -> PlanEstimates {
+        let llm_calls = self
+            .def
+            .nodes
+            .iter()
+            .filter(|n| n.r#type == NodeType::Task)
+            .count();
+        let external_calls = self
+            .def
+            .nodes
+            .iter()
+            .filter(|n| {
+                matches!(
+                    n.r#type,
+                    NodeType::CallMcp | NodeType::HttpCall | NodeType::Wasm | NodeType::DbOp
+                )
+            })
+            .count();
+
+        PlanEstimates {
+            node_count: self.def.nodes.len(),
+            llm_calls,
+            external_calls,
+            estimated_tokens: llm_calls as u64 * config.tokens_per_llm_call,
+            longest_path: self.longest_path(),
+        }
+    }
+
+    /// Profondeur du DAG depuis l'entrée (tri topologique de Kahn sur la partie atteignable).
+    fn longest_path(&self) -> usize {
+        let reachable = self.reachable(|_| false);
+        let mut in_degree: UnorderedMap<&str, usize> =
+            reachable.iter().map(|id| (*id, 0)).collect();
+        for id in &reachable {
+            for child in self.children.get(id).into_iter().flatten() {
+                if let Some(d) = in_degree.get_mut(child) {
+                    *d += 1;
+                }
+            }
+        }
+
+        let mut depth: UnorderedMap<&str, usize> = UnorderedMap::new();
+        let mut queue: Vec<&str> = in_degree
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in &queue {
+            depth.insert(id, 1);
+        }
+        while let Some(id) = queue.pop() {
+            let current = depth[id];
+            for child in self.children.get(id).into_iter().flatten() {
+                let Some(d) = in_degree.get_mut(child) else {
+                    continue;
+                };
+                let entry = depth.entry(child).or_insert(0);
+                *entry = (*entry).max(current + 1);
+                *d -= 1;
+                if *d == 0 {
+                    queue.push(child);
+                }
+            }
+        }
+        depth.values().copied().max().unwrap_or(0)
+    }
+
+    /// Premier nœud appartenant à un cycle (parcours en profondeur tricolore).
+    fn find_cycle(&self) -> Option<&'a str> {
+        let mut state: UnorderedMap<&str, u8> = UnorderedMap::new(); // 1 = en cours, 2 = terminé
+        for node in &self.def.nodes {
+            if state.contains_key(node.id.as_str()) {
+                continue;
+            }
+            let mut stack: Vec<(&str, usize)> = vec![(node.id.as_str(), 0)];
+            state.insert(node.id.as_str(), 1);
+            while let Some((id, index)) = stack.pop() {
+                let children = self.children.get(id).map(|c| c.as_slice()).unwrap_or(&[]);
+                match children.get(index) {
+                    Some(child) => {
+                        stack.push((id, index + 1));
+                        match state.get(child) {
+                            Some(1) => return Some(child),
+                            Some(_) => {}
+                            None => {
+                                state.insert(child, 1);
+                                stack.push((child, 0));
+                            }
+                        }
+                    }
+                    None => {
+                        state.insert(id, 2);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Nœud modifiant un système externe ou le Jumeau Numérique.
+fn is_external_write(node: &WorkflowNode) -> bool {
+    let param = |key: &str| node.params.get(key).and_then(|v| v.as_str());
+    match node.r#type {
+        NodeType::HttpCall => !matches!(
+            param("method").map(str::to_uppercase).as_deref(),
+            None | Some("GET") | Some("HEAD")
+        ),
+        NodeType::DbOp => param("op").is_some_and(|op| op != "query"),
+        NodeType::Wasm => true,
+        _ => false,
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow_engine::{SchedulingHints, WorkflowEdge};
+
+    fn node(id: &str, r#type: NodeType, params: JsonValue) -> WorkflowNode {
+        WorkflowNode {
+            id: id.into(),
+            r#type,
+            name: id.into(),
+            params,
+        }
+    }
+
+    fn plan(nodes: Vec<WorkflowNode>, edges: &[(&str, &str)]) -> WorkflowDefinition {
+        WorkflowDefinition {
+            _id: None,
+            handle: "wf_generated".into(),
+            entry: "start".into(),
+            context_schema: None,
+            webhook: None,
+            scheduling: SchedulingHints::default(),
+            anchor_receipt: false,
+            nodes,
+            edges: edges
+                .iter()
+                .map(|(from, to)| WorkflowEdge {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    condition: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn mandate() -> Mandate {
+        json::deserialize_from_value(json_value!({
+            "handle": "mandate-safety",
+            "name": "Mandat Sécurité",
+            "meta": { "mandator_id": "00000000-0000-0000-0000-000000000000", "version": "1.0", "status": "ACTIVE" },
+            "governance": { "strategy": "SAFETY_FIRST", "condorcetWeights": {} },
+            "hardLogic": { "vetos": [{ "rule": "MAX_TEMP", "active": true, "action": "STOP" }] },
+            "observability": { "heartbeatMs": 100 }
+        }))
+        .unwrap()
+    }
+
+    fn codes(critique: &PlanCritique) -> Vec<&str> {
+        critique.findings.iter().map(|f| f.code.as_str()).collect()
+    }
+
+    #[test]
+    fn test_compliant_plan_is_acceptable() {
+        let def = plan(
+            vec![
+                node("start", NodeType::Task, json_value!({})),
+                node(
+                    "veto",
+                    NodeType::QualityGate,
+                    json_value!({ "rule": "MAX_TEMP" }),
+                ),
+                node("review", NodeType::GateHitl, json_value!({})),
+                node(
+                    "push",
+                    NodeType::HttpCall,
+                    json_value!({ "method": "POST", "url": "https://erp.local" }),
+                ),
+                node("end", NodeType::End, json_value!({})),
+            ],
+            &[
+                ("start", "veto"),
+                ("veto", "review"),
+                ("review", "push"),
+                ("push", "end"),
+            ],
+        );
+
+        let critique = WorkflowCritic::default().review_plan(
+            &def,
+            Some(&mandate()),
+            &PlanReviewConfig::default(),
+        );
+        assert!(critique.is_acceptable, "{:?}", critique.findings);
+        assert!(critique.findings.is_empty());
+        assert_eq!(critique.mandate.as_deref(), Some("mandate-safety"));
+        assert_eq!(critique.estimates.llm_calls, 1);
+        assert_eq!(critique.estimates.external_calls, 1);
+        assert_eq!(critique.estimates.longest_path, 5);
+    }
+
+    #[test]
+    fn test_generated_plan_anti_patterns_and_mandate() {
+        let def = plan(
+            vec![
+                node("start", NodeType::Task, json_value!({})),
+                node("write", NodeType::DbOp, json_value!({ "op": "update" })),
+                node("loop", NodeType::Task, json_value!({})),
+                node("orphan", NodeType::Task, json_value!({})),
+            ],
+            &[
+                ("start", "write"),
+                ("write", "loop"),
+                ("loop", "start"),
+                ("loop", "ghost"),
+            ],
+        );
+
+        let critique = WorkflowCritic::default().review_plan(
+            &def,
+            Some(&mandate()),
+            &PlanReviewConfig::default(),
+        );
+        let codes = codes(&critique);
+        for expected in [
+            "PLAN_DANGLING_EDGE",
+            "PLAN_CYCLE",
+            "PLAN_UNREACHABLE_NODE",
+            "PLAN_NO_END",
+            "PLAN_UNGUARDED_WRITE",
+            "PLAN_VETO_NOT_ENFORCED",
+            "PLAN_NO_HUMAN_GATE",
+        ] {
+            assert!(
+                codes.contains(&expected),
+                "{} absent de {:?}",
+                expected,
+                codes
+            );
+        }
+        assert!(critique.has_blocking());
+        assert!(!critique.is_acceptable);
+        assert_eq!(critique.score, 0.0);
+    }
+
+    #[test]
+    fn test_resource_budget() {
+        let nodes = (0..4)
+            .map(|i| node(&format!("t{}", i), NodeType::Task, json_value!({})))
+            .chain([node("start", NodeType::Task, json_value!({}))])
+            .chain([node("end", NodeType::End, json_value!({}))])
+            .collect();
+        let def = plan(
+            nodes,
+            &[
+                ("start", "t0"),
+                ("t0", "t1"),
+                ("t1", "t2"),
+                ("t2", "t3"),
+                ("t3", "end"),
+            ],
+        );
+        let config = PlanReviewConfig {
+            tokens_per_llm_call: 1_000,
+            max_estimated_tokens: 4_000,
+            ..Default::default()
+        };
+
+        let critique = WorkflowCritic::default().review_plan(&def, None, &config);
+        assert_eq!(critique.estimates.estimated_tokens, 5_000);
+        assert_eq!(
+            codes(&critique),
+            vec!["PLAN_NO_MANDATE", "PLAN_TOKEN_BUDGET_EXCEEDED"]
+        );
+        assert!(!critique.is_acceptable);
+    }
 }
@@ -3,12 +3,10 @@
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 use super::compiler::WorkflowCompiler;
-use super::handlers::{
-    decision::DecisionHandler, end::EndHandler, hitl::GateHitlHandler, mcp::McpHandler,
-    policy::GatePolicyHandler, task::TaskHandler, wasm::WasmHandler, HandlerContext, NodeHandler,
-};
+use super::handlers::{CustomNodeExecutor, HandlerContext, HandlerRegistry, NodeHandler};
+use super::node_cache::{context_delta, NodeCachePolicy, NodeResultCache};
 use super::tools::AgentTool;
-use super::{critic::WorkflowCritic, ExecutionStatus, NodeType, WorkflowDefinition, WorkflowNode};
+use super::{critic::WorkflowCritic, ExecutionStatus, WorkflowDefinition, WorkflowNode};
 use crate::plugins::manager::PluginManager;
 
 use crate::ai::orchestrator::AiOrchestrator;
@@ -21,7 +19,7 @@ pub struct WorkflowExecutor {
     pub plugin_manager: SharedRef<PluginManager>,
     critic: WorkflowCritic,
     tools: UnorderedMap<String, Box<dyn AgentTool>>,
-    handlers: UnorderedMap<NodeType, Box<dyn NodeHandler>>,
+    handlers: HandlerRegistry,
 }
 
 impl WorkflowExecutor {
@@ -29,23 +27,12 @@ impl WorkflowExecutor {
         orchestrator: SharedRef<AsyncMutex<AiOrchestrator>>,
         plugin_manager: SharedRef<PluginManager>,
     ) -> Self {
-        let mut handlers: UnorderedMap<NodeType, Box<dyn NodeHandler>> = UnorderedMap::new();
-
-        // 🎯 ALIGNEMENT MBSE : Utilisation de QualityGate pour la gouvernance
-        handlers.insert(NodeType::QualityGate, Box::new(GatePolicyHandler));
-        handlers.insert(NodeType::Task, Box::new(TaskHandler));
-        handlers.insert(NodeType::Decision, Box::new(DecisionHandler));
-        handlers.insert(NodeType::CallMcp, Box::new(McpHandler));
-        handlers.insert(NodeType::Wasm, Box::new(WasmHandler));
-        handlers.insert(NodeType::GateHitl, Box::new(GateHitlHandler));
-        handlers.insert(NodeType::End, Box::new(EndHandler));
-
         Self {
             orchestrator,
             plugin_manager,
             critic: WorkflowCritic::default(),
             tools: UnorderedMap::new(),
-            handlers,
+            handlers: HandlerRegistry::with_builtins(),
         }
     }
 
@@ -53,6 +40,19 @@ impl WorkflowExecutor {
         self.tools.insert(tool.name().to_string(), tool);
     }
 
+    /// Ajoute un handler propre à cet exécuteur (refusé si le type est déjà servi).
+    pub fn register_handler(&mut self, handler: SharedRef<dyn NodeHandler>) -> RaiseResult<()> {
+        self.handlers.register(handler)
+    }
+
+    /// Ajoute un exécuteur typé pour un type de nœud personnalisé.
+    pub fn register_node_executor<E: CustomNodeExecutor>(
+        &mut self,
+        executor: E,
+    ) -> RaiseResult<()> {
+        self.handlers.register_executor(executor)
+    }
+
     // ========================================================================
     // LE PONT : Chargement et Compilation Sécurisés
     // ========================================================================
@@ -91,6 +91,7 @@ impl WorkflowExecutor {
 
     /// Exécute un nœud spécifique en routant vers le handler approprié.
     /// Pattern Match strict pour éviter les échecs silencieux.
+    #[instrument(name = "workflow.execute_node", skip_all, fields(node = %node.id, kind = ?node.r#type))]
     pub async fn execute_node<'a>(
         &'a self,
         node: &WorkflowNode,
@@ -110,10 +111,28 @@ impl WorkflowExecutor {
             manager,
         };
 
+        // ♻️ Nœud déterministe : réutilisation d'un résultat déjà calculé
+        let cache = NodeResultCache::new(manager);
+        let cached = match NodeCachePolicy::from_node(node) {
+            Some(policy) => {
+                let key = policy.cache_key(node, context);
+                if let Some(outputs) = cache.lookup(&key, &policy).await {
+                    user_info!(
+                        "INF_WF_NODE_CACHE_HIT",
+                        json_value!({ "node_id": node.id, "outputs": outputs.len() })
+                    );
+                    context.extend(outputs);
+                    return Ok(ExecutionStatus::Completed);
+                }
+                Some((key, context.clone()))
+            }
+            None => None,
+        };
+
         // 🎯 RÉSILIENCE : Match exhaustif sur les exécuteurs
-        match self.handlers.get(&node.r#type) {
+        let status = match self.handlers.get(&node.r#type) {
             Some(handler) => match handler.execute(node, context, &shared_ctx).await {
-                Ok(status) => Ok(status),
+                Ok(status) => status,
                 Err(e) => raise_error!(
                     "ERR_WF_NODE_FAILURE",
                     error = e.to_string(),
@@ -128,7 +147,21 @@ impl WorkflowExecutor {
                     "node_type": format!("{:?}", node.r#type)
                 })
             ),
+        };
+
+        // Seul un succès est mémorisé : un échec doit pouvoir être rejoué
+        if let (Some((key, before)), ExecutionStatus::Completed) = (cached, status) {
+            if let Err(e) = cache
+                .store(&key, node, context_delta(&before, context))
+                .await
+            {
+                user_warn!(
+                    "WRN_WF_NODE_CACHE_STORE",
+                    json_value!({ "node_id": node.id, "error": e.to_string() })
+                );
+            }
         }
+        Ok(status)
     }
 }
 
@@ -142,6 +175,7 @@ mod tests {
     use crate::model_engine::types::ProjectModel;
     use crate::utils::testing::AgentDbSandbox;
     use crate::workflow_engine::tools::SystemMonitorTool;
+    use crate::workflow_engine::NodeType;
 
     async fn create_test_executor_with_tools(
         storage: SharedRef<crate::json_db::storage::StorageEngine>,
@@ -267,7 +301,7 @@ mod tests {
         };
 
         // On retire manuellement le handler pour le test
-        exec_mut.handlers.remove(&NodeType::Wasm);
+        exec_mut.handlers.unregister(&NodeType::Wasm);
 
         let mut ctx = UnorderedMap::new();
         let result = exec_mut.execute_node(&node, &mut ctx, &manager).await;
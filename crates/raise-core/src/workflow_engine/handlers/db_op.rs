@@ -0,0 +1,277 @@
+// FICHIER : src-tauri/src/workflow_engine/handlers/db_op.rs
+
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::query::sql::{parse_sql, SqlRequest};
+use crate::json_db::query::{Query, QueryEngine, QueryFilter};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+use crate::workflow_engine::handlers::{resolve_placeholders, HandlerContext, NodeHandler};
+use crate::workflow_engine::{ExecutionStatus, NodeType, WorkflowNode};
+
+/// Opération JsonDb native : déplace des données sans bloc WASM ni agent.
+///
+/// Paramètres du nœud :
+/// - `op` : `query` | `insert` | `upsert` | `update`
+/// - `collection` : collection cible (optionnelle si `sql` est fourni)
+/// - `query` (objet `Query`) ou `sql` (SELECT) pour `query`, avec `sql_params` (tableau lié
+///   aux placeholders `$1`, `$2`… ou `?`) plutôt que des valeurs concaténées dans `sql`
+/// - `document` : document ou tableau de documents pour `insert` / `upsert`
+/// - `id` ou `filter` + `patch` pour `update`
+/// - `output_key` : clé de contexte recevant le résultat (défaut `db_result`)
+///
+/// Toute chaîne de la forme `{{chemin.dans.le.contexte}}` est remplacée par la valeur du contexte.
+pub struct DbOpHandler;
+
+#[async_interface]
+impl NodeHandler for DbOpHandler {
+    fn node_type(&self) -> NodeType {
+        NodeType::DbOp
+    }
+
+    async fn execute(
+        &self,
+        node: &WorkflowNode,
+        context: &mut UnorderedMap<String, JsonValue>,
+        shared_ctx: &HandlerContext<'_>,
+    ) -> RaiseResult<ExecutionStatus> {
+        let output_key = match node.params.get("output_key").and_then(|v| v.as_str()) {
+            Some(s) => s.to_string(),
+            None => "db_result".to_string(),
+        };
+
+        match run_db_op(&node.params, context, shared_ctx.manager).await {
+            Ok(result) => {
+                context.insert(output_key.clone(), result);
+                user_success!(
+                    "SUC_DB_OP_COMPLETED",
+                    json_value!({ "node": node.id, "output_key": output_key })
+                );
+                Ok(ExecutionStatus::Completed)
+            }
+            Err(e) => {
+                user_error!(
+                    "ERR_DB_OP_EXECUTION",
+                    json_value!({ "node": node.id, "error": e.to_string() })
+                );
+                Ok(ExecutionStatus::Failed)
+            }
+        }
+    }
+}
+
+/// Exécute l'opération décrite par `params` et renvoie la valeur à injecter dans le contexte.
+pub async fn run_db_op(
+    params: &JsonValue,
+    context: &UnorderedMap<String, JsonValue>,
+    manager: &CollectionsManager<'_>,
+) -> RaiseResult<JsonValue> {
+    let params = resolve_placeholders(params, context);
+    let op = params.get("op").and_then(|v| v.as_str()).unwrap_or("query");
+    let collection = params.get("collection").and_then(|v| v.as_str());
+
+    user_info!(
+        "INF_DB_OP_START",
+        json_value!({ "op": op, "collection": collection })
+    );
+
+    match op {
+        "query" => {
+            let query = build_query(&params, collection)?;
+            let result = QueryEngine::new(manager).execute_query(query).await?;
+            Ok(JsonValue::Array(result.documents))
+        }
+        "insert" | "upsert" => {
+            let collection = require_collection(collection, op)?;
+            let Some(payload) = params.get("document") else {
+                raise_error!(
+                    "ERR_DB_OP_MISSING_PARAM",
+                    context = json_value!({ "op": op, "param": "document" })
+                );
+            };
+            let documents = match payload {
+                JsonValue::Array(docs) => docs.clone(),
+                doc => vec![doc.clone()],
+            };
+
+            let mut written = Vec::with_capacity(documents.len());
+            for doc in documents {
+                if op == "insert" {
+                    written.push(manager.insert_with_schema(collection, doc).await?);
+                } else {
+                    let id = manager.upsert_document(collection, doc).await?;
+                    written.push(json_value!(id));
+                }
+            }
+            Ok(match payload {
+                JsonValue::Array(_) => JsonValue::Array(written),
+                _ => written.pop().unwrap_or(JsonValue::Null),
+            })
+        }
+        "update" => {
+            let collection = require_collection(collection, op)?;
+            let patch = params.get("patch").cloned().unwrap_or(json_value!({}));
+
+            if let Some(id) = params.get("id").and_then(|v| v.as_str()) {
+                return manager.update_document(collection, id, patch).await;
+            }
+            let Some(filter) = params.get("filter") else {
+                raise_error!(
+                    "ERR_DB_OP_MISSING_PARAM",
+                    context = json_value!({ "op": op, "param": "id|filter" })
+                );
+            };
+            let filter: QueryFilter = json::deserialize_from_value(filter.clone())?;
+            let updated = manager.update_many(collection, filter, patch).await?;
+            Ok(json_value!({ "updated": updated }))
+        }
+        other => raise_error!(
+            "ERR_DB_OP_UNKNOWN",
+            context =
+                json_value!({ "op": other, "supported": ["query", "insert", "upsert", "update"] })
+        ),
+    }
+}
+
+fn require_collection<'p>(collection: Option<&'p str>, op: &str) -> RaiseResult<&'p str> {
+    match collection {
+        Some(c) => Ok(c),
+        None => raise_error!(
+            "ERR_DB_OP_MISSING_PARAM",
+            context = json_value!({ "op": op, "param": "collection" })
+        ),
+    }
+}
+
+fn build_query(params: &JsonValue, collection: Option<&str>) -> RaiseResult<Query> {
+    if let Some(sql) = params.get("sql").and_then(|v| v.as_str()) {
+        let request = match params.get("sql_params").and_then(|v| v.as_array()) {
+            Some(values) => QueryEngine::prepare(sql)?.bind(values)?,
+            None => parse_sql(sql)?,
+        };
+        return match request {
+            SqlRequest::Read(query) => Ok(query),
+            SqlRequest::Write(_) | SqlRequest::Explain(_) => raise_error!(
+                "ERR_DB_OP_SQL_NOT_READ",
+                error = "Seules les requêtes SELECT sont acceptées par l'opération 'query'.",
+                context = json_value!({ "sql": sql })
+            ),
+        };
+    }
+
+    let collection = require_collection(collection, "query")?;
+    let mut raw = params.get("query").cloned().unwrap_or(json_value!({}));
+    match raw.as_object_mut() {
+        Some(obj) => {
+            obj.entry("collection")
+                .or_insert_with(|| json_value!(collection));
+        }
+        None => raise_error!(
+            "ERR_DB_OP_INVALID_QUERY",
+            context = json_value!({ "query": raw })
+        ),
+    }
+    json::deserialize_from_value(raw)
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+
+    async fn setup_manager(sandbox: &DbSandbox) -> RaiseResult<CollectionsManager<'_>> {
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        let generic_schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            manager.space, manager.db
+        );
+        let _ = manager
+            .create_collection("sensors", &generic_schema_uri)
+            .await;
+        Ok(manager)
+    }
+
+    #[test]
+    fn test_placeholders_resolution() {
+        let mut context = UnorderedMap::new();
+        context.insert(
+            "batch".to_string(),
+            json_value!({ "rows": [{ "name": "s1" }], "site": "lyon" }),
+        );
+
+        let params = json_value!({
+            "document": "{{batch.rows}}",
+            "patch": { "site": "{{ batch.site }}", "first": "{{batch.rows.0.name}}" },
+            "literal": "pas un {{placeholder}}"
+        });
+        let resolved = resolve_placeholders(&params, &context);
+
+        assert_eq!(resolved["document"], json_value!([{ "name": "s1" }]));
+        assert_eq!(resolved["patch"]["site"], "lyon");
+        assert_eq!(resolved["patch"]["first"], "s1");
+        assert_eq!(resolved["literal"], "pas un {{placeholder}}");
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_insert_query_update_roundtrip() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = setup_manager(&sandbox).await?;
+
+        let mut context = UnorderedMap::new();
+        context.insert(
+            "extracted".to_string(),
+            json_value!([
+                { "_id": "s1", "name": "temp", "status": "raw" },
+                { "_id": "s2", "name": "vib", "status": "raw" }
+            ]),
+        );
+
+        let inserted = run_db_op(
+            &json_value!({ "op": "insert", "collection": "sensors", "document": "{{extracted}}" }),
+            &context,
+            &manager,
+        )
+        .await?;
+        assert_eq!(inserted.as_array().map(|a| a.len()), Some(2));
+
+        let updated = run_db_op(
+            &json_value!({ "op": "update", "collection": "sensors", "id": "s1", "patch": { "status": "clean" } }),
+            &context,
+            &manager,
+        )
+        .await?;
+        assert_eq!(updated["status"], "clean");
+
+        let rows = run_db_op(
+            &json_value!({
+                "op": "query",
+                "collection": "sensors",
+                "query": {
+                    "filter": {
+                        "operator": "And",
+                        "conditions": [{ "field": "status", "operator": "Eq", "value": "raw" }]
+                    }
+                }
+            }),
+            &context,
+            &manager,
+        )
+        .await?;
+        let rows = rows.as_array().cloned().unwrap_or_default();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0]["_id"], "s2");
+
+        let unknown = run_db_op(&json_value!({ "op": "drop" }), &context, &manager).await;
+        assert!(unknown.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+// FICHIER : src-tauri/src/workflow_engine/handlers/http_call.rs
+
+use crate::utils::core::{sleep_async, TimeDuration, TimeInstant};
+use crate::utils::data::secrets::{SecretsProvider, SECRET_REF_PREFIX};
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+use crate::workflow_engine::handlers::{
+    render_template, resolve_placeholders, HandlerContext, NodeHandler,
+};
+use crate::workflow_engine::{ExecutionStatus, NodeType, WorkflowNode};
+
+/// Statuts rejoués par défaut (délai, saturation, indisponibilité passagère).
+const DEFAULT_RETRY_ON: [u16; 6] = [408, 429, 500, 502, 503, 504];
+const MAX_BACKOFF_MS: u64 = 10_000;
+
+/// Politique de rejeu d'un appel HTTP (backoff exponentiel plafonné à 10 s).
+#[derive(Debug, Clone, Serializable, Deserializable)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Nombre total de tentatives (1 = pas de rejeu)
+    pub max_attempts: u32,
+    pub backoff_ms: u64,
+    /// Statuts HTTP déclenchant un rejeu ; les erreurs réseau sont toujours rejouées
+    pub retry_on: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff_ms: 500,
+            retry_on: DEFAULT_RETRY_ON.to_vec(),
+        }
+    }
+}
+
+/// Appel HTTP sortant vers un système externe.
+///
+/// Paramètres du nœud :
+/// - `method` (défaut `GET`), `url` : gabarit `{{chemin}}` interpolé depuis le contexte
+/// - `headers` : valeurs interpolées ; `secret:<clé>` ou `{{secret:<clé>}}` lus dans le `SecretsProvider`
+/// - `body` : JSON dont les chaînes `{{chemin}}` sont remplacées par les valeurs du contexte
+/// - `timeout_secs`, `retry` (`RetryPolicy`)
+/// - `output_key` (défaut `http_response`) : reçoit `{ status, body }`
+/// - `mapping` : `{ "clé_contexte": "/pointeur/json" }` appliqué à `{ status, body }`
+pub struct HttpCallHandler;
+
+#[async_interface]
+impl NodeHandler for HttpCallHandler {
+    fn node_type(&self) -> NodeType {
+        NodeType::HttpCall
+    }
+
+    async fn execute(
+        &self,
+        node: &WorkflowNode,
+        context: &mut UnorderedMap<String, JsonValue>,
+        _shared_ctx: &HandlerContext<'_>,
+    ) -> RaiseResult<ExecutionStatus> {
+        match run_http_call(&node.params, context).await {
+            Ok(true) => {
+                user_success!("SUC_HTTP_CALL_COMPLETED", json_value!({ "node": node.id }));
+                Ok(ExecutionStatus::Completed)
+            }
+            Ok(false) => Ok(ExecutionStatus::Failed),
+            Err(e) => {
+                user_error!(
+                    "ERR_HTTP_CALL_EXECUTION",
+                    json_value!({ "node": node.id, "error": e.to_string() })
+                );
+                Ok(ExecutionStatus::Failed)
+            }
+        }
+    }
+}
+
+/// Exécute l'appel et projette la réponse dans le contexte.
+/// Renvoie `true` si le statut final est 2xx.
+pub async fn run_http_call(
+    params: &JsonValue,
+    context: &mut UnorderedMap<String, JsonValue>,
+) -> RaiseResult<bool> {
+    let Some(url_template) = params.get("url").and_then(|v| v.as_str()) else {
+        raise_error!(
+            "ERR_HTTP_CALL_MISSING_URL",
+            context = json_value!({ "param": "url" })
+        );
+    };
+    let url = render_template(url_template, context);
+
+    let method_name = params
+        .get("method")
+        .and_then(|v| v.as_str())
+        .unwrap_or("GET")
+        .to_uppercase();
+    let method = match HttpMethod::from_bytes(method_name.as_bytes()) {
+        Ok(m) => m,
+        Err(e) => raise_error!(
+            "ERR_HTTP_CALL_INVALID_METHOD",
+            error = e,
+            context = json_value!({ "method": method_name })
+        ),
+    };
+
+    let mut headers = Vec::new();
+    if let Some(raw) = params.get("headers").and_then(|v| v.as_object()) {
+        for (name, value) in raw {
+            if let Some(template) = value.as_str() {
+                headers.push((name.clone(), render_header(template, context).await?));
+            }
+        }
+    }
+
+    let body = params.get("body").map(|b| resolve_placeholders(b, context));
+    let retry: RetryPolicy = match params.get("retry") {
+        Some(r) => json::deserialize_from_value(r.clone())?,
+        None => RetryPolicy::default(),
+    };
+    let timeout = params
+        .get("timeout_secs")
+        .and_then(|v| v.as_u64())
+        .map(TimeDuration::from_secs);
+
+    user_info!(
+        "INF_HTTP_CALL_START",
+        json_value!({ "method": method.as_str(), "url": url })
+    );
+
+    let client = get_client();
+    let breaker = CircuitBreaker::global();
+    let mut delay = TimeDuration::from_millis(retry.backoff_ms);
+    let mut attempt = 0;
+
+    let (status, body) = loop {
+        attempt += 1;
+        breaker.acquire(&url)?;
+
+        let mut request = client.request(method.clone(), &url);
+        for (name, value) in &headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        if let Some(payload) = &body {
+            request = request.json(payload);
+        }
+        if let Some(t) = timeout {
+            request = request.timeout(t);
+        }
+
+        let started = TimeInstant::now();
+        let retryable = match request.send().await {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_server_error() || status == HttpStatusCode::TOO_MANY_REQUESTS {
+                    breaker.record_failure(&url, &format!("HTTP {}", status.as_u16()));
+                } else {
+                    breaker.record_success(&url, started.elapsed());
+                }
+
+                if !retry.retry_on.contains(&status.as_u16()) || attempt >= retry.max_attempts {
+                    let text = response.text().await.unwrap_or_default();
+                    let body = json::deserialize_from_str::<JsonValue>(&text)
+                        .unwrap_or(JsonValue::String(text));
+                    break (status, body);
+                }
+                format!("HTTP {}", status.as_u16())
+            }
+            Err(e) => {
+                breaker.record_failure(&url, &e.to_string());
+                if attempt >= retry.max_attempts {
+                    raise_error!(
+                        "ERR_HTTP_CALL_UNREACHABLE",
+                        error = e,
+                        context = json_value!({ "url": url, "attempts": attempt })
+                    );
+                }
+                e.to_string()
+            }
+        };
+
+        user_warn!(
+            "WRN_HTTP_CALL_RETRY",
+            json_value!({ "url": url, "attempt": attempt, "reason": retryable })
+        );
+        sleep_async(delay).await;
+        delay = (delay * 2).min(TimeDuration::from_millis(MAX_BACKOFF_MS));
+    };
+
+    let envelope = json_value!({ "status": status.as_u16(), "body": body });
+    if let Some(mapping) = params.get("mapping").and_then(|v| v.as_object()) {
+        for (key, pointer) in mapping {
+            let value = pointer
+                .as_str()
+                .and_then(|p| envelope.pointer(p))
+                .cloned()
+                .unwrap_or(JsonValue::Null);
+            context.insert(key.clone(), value);
+        }
+    }
+
+    let output_key = params
+        .get("output_key")
+        .and_then(|v| v.as_str())
+        .unwrap_or("http_response");
+    context.insert(output_key.to_string(), envelope);
+
+    if !status.is_success() {
+        user_warn!(
+            "WRN_HTTP_CALL_STATUS",
+            json_value!({ "url": url, "status": status.as_u16(), "attempts": attempt })
+        );
+    }
+    Ok(status.is_success())
+}
+
+/// Interpole un en-tête : références de secrets d'abord, puis chemins du contexte.
+async fn render_header(
+    template: &str,
+    context: &UnorderedMap<String, JsonValue>,
+) -> RaiseResult<String> {
+    let secrets = SecretsProvider::global();
+    if let Some(key) = template.strip_prefix(SECRET_REF_PREFIX) {
+        return secrets.require(key).await;
+    }
+
+    let marker = format!("{{{{{}", SECRET_REF_PREFIX);
+    let mut resolved = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(&marker) {
+        let Some(len) = rest[start + marker.len()..].find("}}") else {
+            break;
+        };
+        let key = &rest[start + marker.len()..start + marker.len() + len];
+        resolved.push_str(&rest[..start]);
+        resolved.push_str(&secrets.require(key.trim()).await?);
+        rest = &rest[start + marker.len() + len + 2..];
+    }
+    resolved.push_str(rest);
+    Ok(render_template(&resolved, context))
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::core::RuntimeEnv;
+    use crate::utils::network::http_types::HttpHeaderMap;
+    use crate::utils::network::server::{get, post};
+    use crate::utils::testing::mock;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static FLAKY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Service externe simulé : `/flaky` échoue une fois, `/echo` renvoie en-têtes et corps.
+    async fn serve_partner() -> RaiseResult<String> {
+        let router = HttpRouter::new()
+            .route(
+                "/flaky",
+                get(|| async {
+                    if FLAKY_CALLS.fetch_add(1, Ordering::SeqCst) == 0 {
+                        (HttpStatusCode::SERVICE_UNAVAILABLE, "busy".to_string())
+                    } else {
+                        (HttpStatusCode::OK, r#"{"ticket":{"id":"T-42"}}"#.to_string())
+                    }
+                }),
+            )
+            .route(
+                "/orders/{id}",
+                post(
+                    |headers: HttpHeaderMap, HttpJsonPayload(body): HttpJsonPayload<JsonValue>| async move {
+                        let auth = headers
+                            .get("authorization")
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or_default()
+                            .to_string();
+                        HttpJsonPayload(json_value!({ "auth": auth, "received": body }))
+                    },
+                ),
+            );
+        let listener = match HttpTcpListener::bind("127.0.0.1:0").await {
+            Ok(l) => l,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        let addr = match listener.local_addr() {
+            Ok(a) => a,
+            Err(e) => raise_error!("ERR_TEST_BIND", error = e),
+        };
+        spawn_async_task(async move {
+            let _ = run_http_server(listener, router).await;
+        });
+        Ok(format!("http://{}", addr))
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_retry_then_mapping() -> RaiseResult<()> {
+        let base = serve_partner().await?;
+        FLAKY_CALLS.store(0, Ordering::SeqCst);
+
+        let mut context = UnorderedMap::new();
+        context.insert("partner".to_string(), json_value!({ "url": base }));
+
+        let ok = run_http_call(
+            &json_value!({
+                "url": "{{partner.url}}/flaky",
+                "retry": { "max_attempts": 2, "backoff_ms": 10 },
+                "mapping": { "ticket_id": "/body/ticket/id", "ticket_status": "/status" }
+            }),
+            &mut context,
+        )
+        .await?;
+
+        assert!(ok);
+        assert_eq!(FLAKY_CALLS.load(Ordering::SeqCst), 2);
+        assert_eq!(context["ticket_id"], "T-42");
+        assert_eq!(context["ticket_status"], 200);
+        assert_eq!(context["http_response"]["status"], 200);
+        Ok(())
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_post_with_secret_header_and_templated_body() -> RaiseResult<()> {
+        mock::inject_mock_config().await;
+        let base = serve_partner().await?;
+        RuntimeEnv::set_var("RAISE_SECRET_TEST_HTTP_CALL_TOKEN", "partner-token-123");
+
+        let mut context = UnorderedMap::new();
+        context.insert("order".to_string(), json_value!({ "id": 7, "qty": 3 }));
+
+        let ok = run_http_call(
+            &json_value!({
+                "method": "post",
+                "url": format!("{}/orders/{{{{order.id}}}}", base),
+                "headers": { "Authorization": "Bearer {{secret:test_http_call_token}}" },
+                "body": { "quantity": "{{order.qty}}" },
+                "output_key": "partner_reply"
+            }),
+            &mut context,
+        )
+        .await?;
+
+        assert!(ok);
+        let reply = &context["partner_reply"]["body"];
+        assert_eq!(reply["auth"], "Bearer partner-token-123");
+        assert_eq!(reply["received"]["quantity"], 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_template_inline() {
+        let mut context = UnorderedMap::new();
+        context.insert(
+            "site".to_string(),
+            json_value!({ "code": "LYS", "zone": 4 }),
+        );
+        assert_eq!(
+            render_template(
+                "/sites/{{site.code}}/zones/{{ site.zone }}{{missing}}",
+                &context
+            ),
+            "/sites/LYS/zones/4"
+        );
+    }
+}
@@ -1,10 +1,13 @@
 // FICHIER : src-tauri/src/workflow_engine/handlers/mod.rs
 
+pub mod db_op;
 pub mod decision;
 pub mod end;
 pub mod hitl;
+pub mod http_call;
 pub mod mcp;
 pub mod policy;
+pub mod registry;
 pub mod task;
 pub mod wasm;
 pub mod world_model;
@@ -18,6 +21,10 @@ use super::critic::WorkflowCritic;
 use super::tools::AgentTool;
 use super::{ExecutionStatus, NodeType, WorkflowNode};
 
+pub use registry::{
+    register_node_executor, register_node_handler, CustomNodeExecutor, HandlerRegistry,
+};
+
 /// Le Contexte Partagé : La "boîte à outils" que l'Exécuteur prête aux Handlers
 pub struct HandlerContext<'a> {
     pub orchestrator: &'a SharedRef<AsyncMutex<AiOrchestrator>>,
@@ -41,3 +48,77 @@ pub trait NodeHandler: Send + Sync {
         shared_ctx: &HandlerContext<'_>,
     ) -> RaiseResult<ExecutionStatus>;
 }
+
+// =========================================================================
+// GABARITS `{{chemin}}` PARTAGÉS (db_op, http_call)
+// =========================================================================
+
+/// Valeur du contexte désignée par un chemin pointé (`batch.rows.0.name`).
+fn lookup_context_path<'c>(
+    path: &str,
+    context: &'c UnorderedMap<String, JsonValue>,
+) -> Option<&'c JsonValue> {
+    let mut segments = path.trim().split('.');
+    let root = context.get(segments.next()?)?;
+    segments.try_fold(root, |current, segment| match current {
+        JsonValue::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => current.get(segment),
+    })
+}
+
+/// Remplace récursivement les chaînes réduites à `{{chemin}}` par la valeur JSON du contexte
+/// (tableaux et objets compris). Un chemin absent donne `null`.
+pub(crate) fn resolve_placeholders(
+    value: &JsonValue,
+    context: &UnorderedMap<String, JsonValue>,
+) -> JsonValue {
+    match value {
+        JsonValue::String(s) => {
+            let Some(path) = s
+                .trim()
+                .strip_prefix("{{")
+                .and_then(|rest| rest.strip_suffix("}}"))
+                .filter(|path| !path.contains("{{") && !path.contains("}}"))
+            else {
+                return value.clone();
+            };
+            lookup_context_path(path, context)
+                .cloned()
+                .unwrap_or(JsonValue::Null)
+        }
+        JsonValue::Array(items) => JsonValue::Array(
+            items
+                .iter()
+                .map(|v| resolve_placeholders(v, context))
+                .collect(),
+        ),
+        JsonValue::Object(map) => JsonValue::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), resolve_placeholders(v, context)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Interpole chaque `{{chemin}}` d'une chaîne (URL, en-tête). Les chaînes sont insérées
+/// telles quelles, les autres valeurs sous leur forme JSON ; un chemin absent donne "".
+pub(crate) fn render_template(template: &str, context: &UnorderedMap<String, JsonValue>) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        rendered.push_str(&rest[..start]);
+        let path = &rest[start + 2..start + 2 + len];
+        match lookup_context_path(path, context) {
+            Some(JsonValue::String(s)) => rendered.push_str(s),
+            Some(JsonValue::Null) | None => {}
+            Some(other) => rendered.push_str(&other.to_string()),
+        }
+        rest = &rest[start + 2 + len + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
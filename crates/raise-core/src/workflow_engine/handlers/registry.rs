@@ -0,0 +1,339 @@
+// FICHIER : src-tauri/src/workflow_engine/handlers/registry.rs
+
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+
+use super::{
+    db_op::DbOpHandler, decision::DecisionHandler, end::EndHandler, hitl::GateHitlHandler,
+    http_call::HttpCallHandler, mcp::McpHandler, policy::GatePolicyHandler, task::TaskHandler,
+    wasm::WasmHandler, HandlerContext, NodeHandler,
+};
+use crate::workflow_engine::{ExecutionStatus, NodeType, WorkflowNode};
+
+/// Exécuteur typé d'un nœud personnalisé, fourni par une crate aval ou un plugin.
+///
+/// Les `params` du nœud sont désérialisés en `Self::Params` avant chaque exécution :
+/// un paramètre manquant ou mal typé échoue avant tout effet de bord.
+#[async_interface]
+#[allow(clippy::double_must_use)] // Méthodes par défaut générées par `async_interface`
+pub trait CustomNodeExecutor: Send + Sync + 'static {
+    type Params: DeserializableOwned + Send;
+
+    /// Nom du type dans les définitions (`"type": "erp_sync"`)
+    fn type_name(&self) -> &str;
+
+    /// Appelé une fois à l'enregistrement (vérification de configuration, préchauffage...)
+    fn on_register(&self) -> RaiseResult<()> {
+        Ok(())
+    }
+
+    async fn before_execute(
+        &self,
+        _node: &WorkflowNode,
+        _context: &UnorderedMap<String, JsonValue>,
+    ) -> RaiseResult<()> {
+        Ok(())
+    }
+
+    async fn execute(
+        &self,
+        params: Self::Params,
+        node: &WorkflowNode,
+        context: &mut UnorderedMap<String, JsonValue>,
+        shared_ctx: &HandlerContext<'_>,
+    ) -> RaiseResult<ExecutionStatus>;
+
+    /// Toujours appelé après `execute`, avec `Failed` si celui-ci a retourné une erreur
+    async fn after_execute(
+        &self,
+        _node: &WorkflowNode,
+        _context: &mut UnorderedMap<String, JsonValue>,
+        _status: ExecutionStatus,
+    ) -> RaiseResult<()> {
+        Ok(())
+    }
+}
+
+/// Adaptateur `CustomNodeExecutor` -> `NodeHandler` (désérialisation + hooks).
+struct TypedNodeHandler<E> {
+    node_type: NodeType,
+    executor: E,
+}
+
+#[async_interface]
+impl<E: CustomNodeExecutor> NodeHandler for TypedNodeHandler<E> {
+    fn node_type(&self) -> NodeType {
+        self.node_type.clone()
+    }
+
+    async fn execute(
+        &self,
+        node: &WorkflowNode,
+        context: &mut UnorderedMap<String, JsonValue>,
+        shared_ctx: &HandlerContext<'_>,
+    ) -> RaiseResult<ExecutionStatus> {
+        let params: E::Params = decode_params(node)?;
+
+        self.executor.before_execute(node, context).await?;
+        let result = self
+            .executor
+            .execute(params, node, context, shared_ctx)
+            .await;
+        let status = match &result {
+            Ok(status) => *status,
+            Err(_) => ExecutionStatus::Failed,
+        };
+        self.executor.after_execute(node, context, status).await?;
+        result
+    }
+}
+
+/// `params` du nœud vers le type attendu (`null` est traité comme un objet vide).
+fn decode_params<P: DeserializableOwned>(node: &WorkflowNode) -> RaiseResult<P> {
+    let raw = match &node.params {
+        JsonValue::Null => json_value!({}),
+        params => params.clone(),
+    };
+    match json::deserialize_from_value(raw) {
+        Ok(params) => Ok(params),
+        Err(e) => raise_error!(
+            "ERR_WF_NODE_PARAMS_INVALID",
+            error = e.to_string(),
+            context = json_value!({ "node_id": node.id, "node_type": node.r#type })
+        ),
+    }
+}
+
+fn typed_handler<E: CustomNodeExecutor>(executor: E) -> RaiseResult<SharedRef<dyn NodeHandler>> {
+    let node_type = NodeType::custom(executor.type_name())?;
+    executor.on_register()?;
+    Ok(SharedRef::new(TypedNodeHandler {
+        node_type,
+        executor,
+    }))
+}
+
+// =========================================================================
+// REGISTRE
+// =========================================================================
+
+/// Table des exécuteurs par type de nœud, consultée par le `WorkflowExecutor`.
+#[derive(Clone, Default)]
+pub struct HandlerRegistry {
+    handlers: UnorderedMap<NodeType, SharedRef<dyn NodeHandler>>,
+}
+
+impl HandlerRegistry {
+    /// Handlers natifs, complétés par ceux enregistrés au démarrage (`register_node_*`).
+    pub fn with_builtins() -> Self {
+        let builtins: [SharedRef<dyn NodeHandler>; 9] = [
+            // 🎯 ALIGNEMENT MBSE : Utilisation de QualityGate pour la gouvernance
+            SharedRef::new(GatePolicyHandler),
+            SharedRef::new(TaskHandler),
+            SharedRef::new(DecisionHandler),
+            SharedRef::new(McpHandler),
+            SharedRef::new(WasmHandler),
+            SharedRef::new(DbOpHandler),
+            SharedRef::new(HttpCallHandler),
+            SharedRef::new(GateHitlHandler),
+            SharedRef::new(EndHandler),
+        ];
+
+        let mut registry = Self::default();
+        for handler in builtins {
+            registry.handlers.insert(handler.node_type(), handler);
+        }
+        for handler in read_global().iter() {
+            registry
+                .handlers
+                .insert(handler.node_type(), handler.clone());
+        }
+        registry
+    }
+
+    /// Ajoute un handler ; un type déjà servi est refusé plutôt que remplacé silencieusement.
+    pub fn register(&mut self, handler: SharedRef<dyn NodeHandler>) -> RaiseResult<()> {
+        let node_type = handler.node_type();
+        if self.handlers.contains_key(&node_type) {
+            raise_error!(
+                "ERR_WF_HANDLER_DUPLICATE",
+                context = json_value!({ "node_type": node_type })
+            );
+        }
+        self.handlers.insert(node_type, handler);
+        Ok(())
+    }
+
+    pub fn register_executor<E: CustomNodeExecutor>(&mut self, executor: E) -> RaiseResult<()> {
+        self.register(typed_handler(executor)?)
+    }
+
+    pub fn unregister(&mut self, node_type: &NodeType) -> Option<SharedRef<dyn NodeHandler>> {
+        self.handlers.remove(node_type)
+    }
+
+    pub fn get(&self, node_type: &NodeType) -> Option<&SharedRef<dyn NodeHandler>> {
+        self.handlers.get(node_type)
+    }
+
+    pub fn node_types(&self) -> Vec<NodeType> {
+        self.handlers.keys().cloned().collect()
+    }
+}
+
+// =========================================================================
+// ENREGISTREMENT GLOBAL (Crates aval & Plugins, au démarrage)
+// =========================================================================
+
+static GLOBAL_HANDLERS: StaticCell<SyncRwLock<Vec<SharedRef<dyn NodeHandler>>>> = StaticCell::new();
+
+fn read_global() -> Vec<SharedRef<dyn NodeHandler>> {
+    let lock = GLOBAL_HANDLERS.get_or_init(Default::default);
+    match lock.read() {
+        Ok(handlers) => handlers.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Enregistre un handler pour tous les `WorkflowExecutor` créés ensuite.
+pub fn register_node_handler(handler: SharedRef<dyn NodeHandler>) -> RaiseResult<()> {
+    let node_type = handler.node_type();
+    if !matches!(node_type, NodeType::Custom(_)) {
+        raise_error!(
+            "ERR_WF_NODE_TYPE_RESERVED",
+            context = json_value!({ "node_type": node_type })
+        );
+    }
+
+    let lock = GLOBAL_HANDLERS.get_or_init(Default::default);
+    let mut handlers = match lock.write() {
+        Ok(h) => h,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    if handlers.iter().any(|h| h.node_type() == node_type) {
+        raise_error!(
+            "ERR_WF_HANDLER_DUPLICATE",
+            context = json_value!({ "node_type": node_type })
+        );
+    }
+
+    user_info!(
+        "INF_WF_HANDLER_REGISTERED",
+        json_value!({ "node_type": node_type })
+    );
+    handlers.push(handler);
+    Ok(())
+}
+
+/// Enregistre un exécuteur typé pour tous les `WorkflowExecutor` créés ensuite.
+pub fn register_node_executor<E: CustomNodeExecutor>(executor: E) -> RaiseResult<()> {
+    register_node_handler(typed_handler(executor)?)
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserializable)]
+    struct ErpParams {
+        endpoint: String,
+        #[serde(default)]
+        batch_size: Option<u32>,
+    }
+
+    struct ErpSync {
+        name: &'static str,
+        registrations: SharedRef<SyncMutex<u32>>,
+    }
+
+    #[async_interface]
+    impl CustomNodeExecutor for ErpSync {
+        type Params = ErpParams;
+
+        fn type_name(&self) -> &str {
+            self.name
+        }
+
+        fn on_register(&self) -> RaiseResult<()> {
+            *self.registrations.lock().unwrap() += 1;
+            Ok(())
+        }
+
+        async fn execute(
+            &self,
+            params: ErpParams,
+            _node: &WorkflowNode,
+            context: &mut UnorderedMap<String, JsonValue>,
+            _shared_ctx: &HandlerContext<'_>,
+        ) -> RaiseResult<ExecutionStatus> {
+            context.insert("erp".into(), json_value!(params.endpoint));
+            Ok(ExecutionStatus::Completed)
+        }
+    }
+
+    fn erp(name: &'static str) -> (ErpSync, SharedRef<SyncMutex<u32>>) {
+        let registrations = SharedRef::new(SyncMutex::new(0));
+        (
+            ErpSync {
+                name,
+                registrations: registrations.clone(),
+            },
+            registrations,
+        )
+    }
+
+    #[test]
+    fn test_registry_accepts_custom_and_rejects_conflicts() -> RaiseResult<()> {
+        let mut registry = HandlerRegistry::with_builtins();
+        assert!(registry.get(&NodeType::DbOp).is_some());
+
+        let (executor, registrations) = erp("erp_sync_local");
+        registry.register_executor(executor)?;
+        assert_eq!(*registrations.lock().unwrap(), 1);
+        assert!(registry
+            .get(&NodeType::Custom("erp_sync_local".into()))
+            .is_some());
+
+        assert!(registry.register_executor(erp("erp_sync_local").0).is_err());
+        assert!(registry.register_executor(erp("task").0).is_err());
+        assert!(registry.register(SharedRef::new(EndHandler)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_registration_reaches_new_registries() -> RaiseResult<()> {
+        let (executor, _) = erp("erp_sync_global");
+        register_node_executor(executor)?;
+        assert!(register_node_executor(erp("erp_sync_global").0).is_err());
+        assert!(register_node_handler(SharedRef::new(EndHandler)).is_err());
+
+        let registry = HandlerRegistry::with_builtins();
+        assert!(registry
+            .node_types()
+            .contains(&NodeType::Custom("erp_sync_global".into())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_typed_params_decoding() {
+        let mut node = WorkflowNode {
+            id: "sync".into(),
+            r#type: NodeType::Custom("erp_sync".into()),
+            name: "Sync ERP".into(),
+            params: json_value!({ "endpoint": "https://erp.local", "batch_size": 50 }),
+        };
+        let params: ErpParams = decode_params(&node).unwrap();
+        assert_eq!(params.endpoint, "https://erp.local");
+        assert_eq!(params.batch_size, Some(50));
+
+        node.params = JsonValue::Null;
+        let missing = decode_params::<ErpParams>(&node);
+        match missing {
+            Err(AppError::Structured(err)) => assert_eq!(err.code, "ERR_WF_NODE_PARAMS_INVALID"),
+            _ => panic!("Attendu ERR_WF_NODE_PARAMS_INVALID"),
+        }
+    }
+}
@@ -1,11 +1,15 @@
 // FICHIER : src-tauri/src/workflow_engine/mod.rs
 
+pub mod admission;
 pub mod compiler;
+pub mod context_schema;
 pub mod critic;
 pub mod executor;
 pub mod handlers;
 pub mod mandate;
+pub mod node_cache;
 pub mod rbac;
+pub mod receipt;
 pub mod scheduler;
 pub mod squad;
 pub mod state_machine;
@@ -14,9 +18,11 @@ pub mod tools;
 use crate::utils::prelude::*;
 
 // --- RE-EXPORTS (L'API Publique du Moteur) ---
+pub use admission::{AdmissionController, AdmissionSnapshot, RunPermit};
 pub use compiler::WorkflowCompiler;
 pub use executor::WorkflowExecutor;
 pub use mandate::Mandate;
+pub use receipt::{ExecutionReceipt, ReceiptAnchor};
 pub use scheduler::WorkflowScheduler;
 pub use state_machine::WorkflowStateMachine;
 
@@ -35,9 +41,28 @@ pub enum NodeType {
 
     CallMcp,    // Appel outil externe direct (Model Context Protocol)
     Wasm,       // Exécution d'un module WebAssembly
+    DbOp,       // Opération JsonDb native (query/insert/update) pour les flux ETL
+    HttpCall,   // Appel HTTP sortant (API partenaire, webhook externe)
     Milestone,  // Jalon bloquant marquant la fin d'une phase majeure
     SubProject, // Appel à un autre workflow (Sous-graphe)
     End,        // Fin du flux
+    /// Type enregistré par une crate aval ou un plugin (`handlers::register_node_executor`)
+    #[serde(untagged)]
+    Custom(String),
+}
+
+impl NodeType {
+    /// Type personnalisé ; refuse les noms réservés aux types natifs (`task`, `db_op`...).
+    pub fn custom(name: &str) -> RaiseResult<Self> {
+        match json::deserialize_from_value::<NodeType>(json_value!(name)) {
+            Ok(NodeType::Custom(_)) if !name.trim().is_empty() => Ok(NodeType::Custom(name.into())),
+            _ => raise_error!(
+                "ERR_WF_NODE_TYPE_RESERVED",
+                error = "Ce nom de type est vide ou réservé à un nœud natif.",
+                context = json_value!({ "node_type": name })
+            ),
+        }
+    }
 }
 
 /// Statut d'exécution d'une instance ou d'un nœud
@@ -80,6 +105,80 @@ pub struct WorkflowDefinition {
     pub nodes: Vec<WorkflowNode>,
     pub edges: Vec<WorkflowEdge>,
     pub entry: String, // ID du nœud de départ
+    /// JSON Schema du contexte d'entrée (objet embarqué ou URI `db://`), vérifié au démarrage
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_schema: Option<JsonValue>,
+    /// Déclencheur HTTP entrant (`POST /webhooks/{handle}`), absent = non exposé
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookTrigger>,
+    /// Priorité et classe de concurrence utilisées par l'admission du scheduler
+    #[serde(default)]
+    pub scheduling: SchedulingHints,
+    /// Ancre un reçu d'exécution signé dans la chaîne Mentis à la fin de chaque instance
+    /// (processus réglementés : preuve d'exécution infalsifiable)
+    #[serde(default)]
+    pub anchor_receipt: bool,
+}
+
+/// Niveau de priorité d'un workflow face à la file d'admission.
+#[derive(
+    Debug, Clone, Copy, Default, Serializable, Deserializable, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowPriority {
+    Background, // Traitements de masse (génération de code, ETL nocturne)
+    #[default]
+    Normal,
+    Interactive, // Flux attendus par un humain (HITL, commandes UI)
+    Critical,    // Réponse à incident, passe devant tout le reste
+}
+
+impl WorkflowPriority {
+    pub fn rank(self) -> u64 {
+        self as u64
+    }
+}
+
+/// Paramètres d'ordonnancement déclarés par un workflow.
+#[derive(Debug, Clone, Default, Serializable, Deserializable, PartialEq)]
+#[serde(default)]
+pub struct SchedulingHints {
+    pub priority: WorkflowPriority,
+    /// Classe de concurrence (`codegen`, `etl`...) plafonnée par `workflow_scheduling.class_limits`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+}
+
+impl SchedulingHints {
+    /// Classe effective : les workflows non classés partagent la classe `default`.
+    pub fn class_name(&self) -> &str {
+        self.class.as_deref().unwrap_or("default")
+    }
+}
+
+/// Exposition d'un workflow à un système externe via webhook.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct WebhookTrigger {
+    /// Clé du `SecretsProvider` contenant le jeton attendu en `Authorization: Bearer`
+    pub token_secret: String,
+    /// Mission rattachée aux instances déclenchées (défaut : `webhook`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<String>,
+    /// Range le corps reçu sous cette clé du contexte au lieu de le fusionner à la racine
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_key: Option<String>,
+}
+
+/// Décision humaine rendue sur un nœud `gate_hitl`.
+#[derive(Debug, Clone, Serializable, Deserializable, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HitlApproval {
+    pub node_id: String,
+    pub approved: bool,
+    /// Utilisateur de la session active au moment de la décision
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approver: Option<String>,
+    pub decided_at: i64,
 }
 
 /// Instance dynamique (L'Exécution en cours - Jumeau Numérique)
@@ -106,6 +205,22 @@ pub struct WorkflowInstance {
     /// Journal d'audit détaillé
     pub logs: Vec<String>,
 
+    /// Critique du plan produite par le `WorkflowCritic` avant exécution
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_review: Option<critic::PlanCritique>,
+
+    /// Empreinte du contexte d'entrée, figée à la création (reçu d'exécution)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_hash: Option<String>,
+
+    /// Décisions HITL dans l'ordre où elles ont été rendues
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approvals: Vec<HitlApproval>,
+
+    /// Identifiant du reçu ancré à la fin de l'exécution (`anchor_receipt`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipt_id: Option<String>,
+
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -117,6 +232,7 @@ impl WorkflowInstance {
         mission_id: &str,
         initial_context: UnorderedMap<String, JsonValue>,
     ) -> Self {
+        let input_hash = receipt::input_hash(&initial_context);
         Self {
             _id: None,
             handle: handle.to_string(),
@@ -130,6 +246,10 @@ impl WorkflowInstance {
                 "Création de l'instance pour la mission {}",
                 mission_id
             )],
+            plan_review: None,
+            input_hash: Some(input_hash),
+            approvals: Vec::new(),
+            receipt_id: None,
             created_at: UtcClock::now().timestamp(),
             updated_at: UtcClock::now().timestamp(),
         }
@@ -154,6 +274,18 @@ mod tests {
         let t2 = NodeType::QualityGate;
         let json_t2 = json::serialize_to_string(&t2).unwrap();
         assert_eq!(json_t2, "\"quality_gate\"");
+
+        let t3 = NodeType::DbOp;
+        assert_eq!(json::serialize_to_string(&t3).unwrap(), "\"db_op\"");
+
+        let custom: NodeType = json::deserialize_from_str("\"erp_sync\"").unwrap();
+        assert_eq!(custom, NodeType::Custom("erp_sync".into()));
+        assert_eq!(json::serialize_to_string(&custom).unwrap(), "\"erp_sync\"");
+        assert!(NodeType::custom("db_op").is_err());
+
+        let p = WorkflowPriority::Interactive;
+        assert_eq!(json::serialize_to_string(&p).unwrap(), "\"interactive\"");
+        assert!(WorkflowPriority::Interactive > WorkflowPriority::Background);
     }
 
     #[test]
@@ -0,0 +1,301 @@
+// FICHIER : src-tauri/src/workflow_engine/node_cache.rs
+
+use crate::blockchain::crypto::hashing::calculate_hash;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+use crate::workflow_engine::WorkflowNode;
+
+/// Collection JsonDb des résultats de nœuds déterministes.
+pub const NODE_CACHE_COLLECTION: &str = "workflow_node_cache";
+
+/// Clés du contexte exclues de l'empreinte par défaut (identifiants générés à chaque exécution).
+const VOLATILE_CONTEXT_KEYS: &[&str] = &["xai_traces"];
+
+/// Politique de mise en cache déclarée dans les `params` d'un nœud :
+///
+/// ```json
+/// { "deterministic": true, "cache_inputs": ["spec", "model"], "cache_ttl_secs": 86400 }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeCachePolicy {
+    /// Clés du contexte entrant dans l'empreinte (`None` = tout le contexte non volatil)
+    pub inputs: Option<Vec<String>>,
+    pub ttl_secs: Option<i64>,
+}
+
+impl NodeCachePolicy {
+    /// Politique du nœud, `None` s'il n'est pas marqué `deterministic`.
+    pub fn from_node(node: &WorkflowNode) -> Option<Self> {
+        let deterministic = node
+            .params
+            .get("deterministic")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !deterministic {
+            return None;
+        }
+
+        let inputs = node
+            .params
+            .get("cache_inputs")
+            .and_then(|v| v.as_array())
+            .map(|keys| {
+                keys.iter()
+                    .filter_map(|k| k.as_str().map(String::from))
+                    .collect()
+            });
+        let ttl_secs = node.params.get("cache_ttl_secs").and_then(|v| v.as_i64());
+        Some(Self { inputs, ttl_secs })
+    }
+
+    /// Empreinte du nœud : identifiant, type, paramètres et entrées du contexte.
+    pub fn cache_key(
+        &self,
+        node: &WorkflowNode,
+        context: &UnorderedMap<String, JsonValue>,
+    ) -> String {
+        let inputs: JsonObject<String, JsonValue> = match &self.inputs {
+            Some(keys) => keys
+                .iter()
+                .map(|k| {
+                    (
+                        k.clone(),
+                        context.get(k).cloned().unwrap_or(JsonValue::Null),
+                    )
+                })
+                .collect(),
+            None => context
+                .iter()
+                .filter(|(k, _)| !VOLATILE_CONTEXT_KEYS.contains(&k.as_str()))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        };
+
+        calculate_hash(&json_value!({
+            "node_id": node.id,
+            "node_type": node.r#type,
+            "params": node.params,
+            "inputs": inputs
+        }))
+    }
+}
+
+/// Entrées ajoutées ou modifiées par l'exécution d'un nœud.
+pub fn context_delta(
+    before: &UnorderedMap<String, JsonValue>,
+    after: &UnorderedMap<String, JsonValue>,
+) -> JsonObject<String, JsonValue> {
+    after
+        .iter()
+        .filter(|(k, v)| before.get(*k) != Some(*v))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+/// Cache persistant des résultats de nœuds déterministes (JsonDb).
+///
+/// Relancer un workflow après un échec tardif réutilise les étapes coûteuses déjà
+/// calculées (embeddings, génération de code) tant que leurs entrées sont identiques.
+pub struct NodeResultCache<'a> {
+    manager: &'a CollectionsManager<'a>,
+}
+
+impl<'a> NodeResultCache<'a> {
+    pub fn new(manager: &'a CollectionsManager<'a>) -> Self {
+        Self { manager }
+    }
+
+    /// Sorties mémorisées pour cette empreinte, `None` si absentes ou expirées.
+    pub async fn lookup(
+        &self,
+        key: &str,
+        policy: &NodeCachePolicy,
+    ) -> Option<JsonObject<String, JsonValue>> {
+        let doc = match self.manager.get_document(NODE_CACHE_COLLECTION, key).await {
+            Ok(Some(doc)) => doc,
+            _ => return None,
+        };
+
+        if let Some(ttl) = policy.ttl_secs {
+            let stored_at = doc.get("storedAt").and_then(|v| v.as_i64()).unwrap_or(0);
+            if UtcClock::now().timestamp() - stored_at > ttl {
+                return None;
+            }
+        }
+        doc.get("outputs").and_then(|v| v.as_object()).cloned()
+    }
+
+    pub async fn store(
+        &self,
+        key: &str,
+        node: &WorkflowNode,
+        outputs: JsonObject<String, JsonValue>,
+    ) -> RaiseResult<()> {
+        self.ensure_collection().await?;
+        self.manager
+            .upsert_document(
+                NODE_CACHE_COLLECTION,
+                json_value!({
+                    "_id": key,
+                    "nodeId": node.id,
+                    "outputs": outputs,
+                    "storedAt": UtcClock::now().timestamp()
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Purge les entrées d'un nœud (ou toutes) ; retourne le nombre d'entrées supprimées.
+    pub async fn invalidate(&self, node_id: Option<&str>) -> RaiseResult<usize> {
+        let mut removed = 0;
+        for doc in self.manager.list_all(NODE_CACHE_COLLECTION).await? {
+            let matches = match node_id {
+                Some(id) => doc.get("nodeId").and_then(|v| v.as_str()) == Some(id),
+                None => true,
+            };
+            if let (true, Some(key)) = (matches, doc.get("_id").and_then(|v| v.as_str())) {
+                self.manager
+                    .delete_document(NODE_CACHE_COLLECTION, key)
+                    .await?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, NODE_CACHE_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(NODE_CACHE_COLLECTION, &schema_uri)
+            .await
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+    use crate::workflow_engine::NodeType;
+
+    fn embed_node(params: JsonValue) -> WorkflowNode {
+        WorkflowNode {
+            id: "embed".into(),
+            r#type: NodeType::Task,
+            name: "Embeddings".into(),
+            params,
+        }
+    }
+
+    fn context(pairs: &[(&str, JsonValue)]) -> UnorderedMap<String, JsonValue> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_policy_and_key_stability() {
+        assert!(NodeCachePolicy::from_node(&embed_node(json_value!({}))).is_none());
+
+        let node = embed_node(json_value!({ "deterministic": true, "cache_inputs": ["spec"] }));
+        let policy = NodeCachePolicy::from_node(&node).unwrap();
+        assert_eq!(policy.inputs, Some(vec!["spec".to_string()]));
+
+        let base = policy.cache_key(
+            &node,
+            &context(&[
+                ("spec", json_value!({ "a": 1, "b": 2 })),
+                ("other", json_value!(1)),
+            ]),
+        );
+        // Ordre des clés et entrées hors `cache_inputs` sans effet
+        let same = policy.cache_key(
+            &node,
+            &context(&[
+                ("other", json_value!(2)),
+                ("spec", json_value!({ "b": 2, "a": 1 })),
+            ]),
+        );
+        assert_eq!(base, same);
+
+        let changed = policy.cache_key(&node, &context(&[("spec", json_value!({ "a": 9 }))]));
+        assert_ne!(base, changed);
+
+        // Sans `cache_inputs`, les traces XAI (volatiles) n'invalident pas l'empreinte
+        let whole = NodeCachePolicy::from_node(&embed_node(json_value!({ "deterministic": true })))
+            .unwrap();
+        let n = embed_node(json_value!({ "deterministic": true }));
+        assert_eq!(
+            whole.cache_key(
+                &n,
+                &context(&[
+                    ("spec", json_value!(1)),
+                    ("xai_traces", json_value!(["x1"]))
+                ])
+            ),
+            whole.cache_key(
+                &n,
+                &context(&[
+                    ("spec", json_value!(1)),
+                    ("xai_traces", json_value!(["x2"]))
+                ])
+            )
+        );
+    }
+
+    #[async_test]
+    #[serial_test::serial]
+    async fn test_store_lookup_and_invalidate() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let config = AppConfig::get();
+        let manager = CollectionsManager::new(
+            &sandbox.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        DbSandbox::mock_db(&manager).await?;
+        let cache = NodeResultCache::new(&manager);
+
+        let node = embed_node(json_value!({ "deterministic": true }));
+        let policy = NodeCachePolicy::from_node(&node).unwrap();
+        let before = context(&[("spec", json_value!("moteur"))]);
+        let mut after = before.clone();
+        after.insert("vectors".into(), json_value!([0.1, 0.2]));
+
+        let key = policy.cache_key(&node, &before);
+        assert!(cache.lookup(&key, &policy).await.is_none());
+
+        cache
+            .store(&key, &node, context_delta(&before, &after))
+            .await?;
+        let outputs = cache.lookup(&key, &policy).await.unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs["vectors"], json_value!([0.1, 0.2]));
+
+        let expired = NodeCachePolicy {
+            ttl_secs: Some(-1),
+            ..policy.clone()
+        };
+        assert!(cache.lookup(&key, &expired).await.is_none());
+
+        assert_eq!(cache.invalidate(Some("embed")).await?, 1);
+        assert!(cache.lookup(&key, &policy).await.is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+// FICHIER : src-tauri/src/workflow_engine/receipt.rs
+
+//! Reçus d'exécution ancrés : à la fin d'une instance dont le workflow active
+//! `anchor_receipt`, l'empreinte de la définition, celle du contexte d'entrée, le statut final
+//! et les décisions HITL sont scellés dans un reçu dont le hash est ancré dans la chaîne
+//! Mentis. Toute modification ultérieure du reçu ou de l'instance devient détectable.
+
+use crate::blockchain::crypto::signing::KeyPair;
+use crate::blockchain::explorer::{ChainExplorer, ExploreKind, ExplorerQuery};
+use crate::blockchain::storage::commit::{MentisCommit, Mutation, MutationOp};
+use crate::json_db::collections::id_strategy::content_hash;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::json_db::storage::StorageEngine;
+use crate::utils::prelude::*;
+
+use super::{ExecutionStatus, HitlApproval, WorkflowDefinition, WorkflowInstance};
+
+/// Collection des reçus d'exécution (un document par instance).
+pub const RECEIPT_COLLECTION: &str = "workflow_receipts";
+/// Type sémantique porté par la mutation ancrée.
+pub const RECEIPT_TYPE: &str = "WorkflowExecutionReceipt";
+
+/// Contenu scellé d'un reçu.
+#[derive(Debug, Clone, PartialEq, Serializable, Deserializable)]
+pub struct ReceiptContent {
+    pub instance: String,
+    pub workflow: String,
+    pub mission_id: String,
+    pub definition_hash: String,
+    /// Absente pour les instances créées avant l'introduction des reçus.
+    pub input_hash: Option<String>,
+    pub status: ExecutionStatus,
+    pub approvals: Vec<HitlApproval>,
+    pub completed_at: i64,
+}
+
+impl ReceiptContent {
+    fn from_instance(def: &WorkflowDefinition, instance: &WorkflowInstance) -> RaiseResult<Self> {
+        Ok(Self {
+            instance: instance.handle.clone(),
+            workflow: def.handle.clone(),
+            mission_id: instance.mission_id.clone(),
+            definition_hash: definition_hash(def)?,
+            input_hash: instance.input_hash.clone(),
+            status: instance.status,
+            approvals: instance.approvals.clone(),
+            completed_at: UtcClock::now().timestamp(),
+        })
+    }
+
+    pub fn hash(&self) -> RaiseResult<String> {
+        Ok(content_hash(&json::serialize_to_value(self)?))
+    }
+}
+
+/// Reçu d'exécution et commit qui l'ancre.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ExecutionReceipt {
+    /// `urn:raise:wf-receipt:<instance>`
+    #[serde(rename = "_id")]
+    pub id: String,
+    #[serde(flatten)]
+    pub content: ReceiptContent,
+    /// Empreinte du contenu, portée par la mutation ancrée.
+    pub receipt_hash: String,
+    /// Commit d'ancrage, conservé pour revérification hors réseau.
+    pub commit: MentisCommit,
+}
+
+/// Résultat de la vérification d'un reçu contre l'instance et la chaîne.
+#[derive(Debug, Clone, Serializable, Deserializable)]
+pub struct ReceiptVerification {
+    pub instance: String,
+    /// Vrai si le reçu existe, est intact, ancré, indexé et conforme à l'instance.
+    pub verified: bool,
+    pub anchored_at: Option<UtcTimestamp>,
+    pub commit_id: Option<String>,
+    pub content_valid: bool,
+    pub commit_valid: bool,
+    pub commit_indexed: bool,
+    /// Statut, entrées et décisions HITL de l'instance identiques à ceux du reçu.
+    pub instance_matches: bool,
+}
+
+pub fn receipt_id(instance_handle: &str) -> String {
+    format!("urn:raise:wf-receipt:{}", instance_handle)
+}
+
+/// Empreinte du contexte d'entrée d'une instance (indépendante de l'ordre des clés).
+pub fn input_hash(inputs: &UnorderedMap<String, JsonValue>) -> String {
+    content_hash(&json_value!({ "inputs": inputs }))
+}
+
+/// Empreinte de la définition compilée du workflow.
+pub fn definition_hash(def: &WorkflowDefinition) -> RaiseResult<String> {
+    Ok(content_hash(
+        &json_value!({ "definition": json::serialize_to_value(def)? }),
+    ))
+}
+
+/// Émission et vérification des reçus, adossées à une base JSON-DB.
+pub struct ReceiptAnchor<'a> {
+    storage: &'a StorageEngine,
+    manager: CollectionsManager<'a>,
+}
+
+impl<'a> ReceiptAnchor<'a> {
+    pub fn new(storage: &'a StorageEngine, space: &str, db: &str) -> Self {
+        Self {
+            storage,
+            manager: CollectionsManager::new(storage, space, db),
+        }
+    }
+
+    fn explorer(&self) -> ChainExplorer<'a> {
+        ChainExplorer::new(self.storage, &self.manager.space, &self.manager.db)
+    }
+
+    async fn ensure_collection(&self) -> RaiseResult<()> {
+        let mgr = &self.manager;
+        let meta = mgr
+            .storage
+            .config
+            .db_collection_path(&mgr.space, &mgr.db, RECEIPT_COLLECTION)
+            .join("_meta.json");
+        if meta.exists() {
+            return Ok(());
+        }
+        let schema_uri = format!(
+            "db://{}/{}/schemas/v1/db/generic.schema.json",
+            mgr.space, mgr.db
+        );
+        mgr.create_collection(RECEIPT_COLLECTION, &schema_uri).await
+    }
+
+    /// Scelle et ancre le reçu d'une instance terminée. Un reçu déjà émis pour l'instance
+    /// est renvoyé tel quel (le premier ancrage fait foi).
+    pub async fn anchor(
+        &self,
+        def: &WorkflowDefinition,
+        instance: &WorkflowInstance,
+        keys: &KeyPair,
+    ) -> RaiseResult<ExecutionReceipt> {
+        let id = receipt_id(&instance.handle);
+        self.ensure_collection().await?;
+        if let Some(existing) = self.manager.get_document(RECEIPT_COLLECTION, &id).await? {
+            return json::deserialize_from_value(existing);
+        }
+
+        let content = ReceiptContent::from_instance(def, instance)?;
+        let receipt_hash = content.hash()?;
+        let mutation = Mutation {
+            element_id: id.clone(),
+            operation: MutationOp::Create,
+            payload: json_value!({
+                "@type": RECEIPT_TYPE,
+                "instance": content.instance,
+                "workflow": content.workflow,
+                "status": content.status,
+                "receipt_hash": receipt_hash
+            }),
+        };
+        let commit = MentisCommit::new(vec![mutation], self.explorer().head().await?, keys);
+        self.explorer().index_commit(&commit).await?;
+
+        let receipt = ExecutionReceipt {
+            id,
+            content,
+            receipt_hash,
+            commit,
+        };
+        self.manager
+            .insert_raw(RECEIPT_COLLECTION, &json::serialize_to_value(&receipt)?)
+            .await?;
+
+        user_success!(
+            "INF_WF_RECEIPT_ANCHORED",
+            json_value!({
+                "instance": receipt.content.instance,
+                "status": receipt.content.status,
+                "commit_id": receipt.commit.id
+            })
+        );
+        Ok(receipt)
+    }
+
+    /// Revérifie le reçu d'une instance : intégrité du contenu, du commit d'ancrage, présence
+    /// du bloc dans l'index de la chaîne et concordance avec l'instance persistée.
+    pub async fn verify(&self, instance: &WorkflowInstance) -> RaiseResult<ReceiptVerification> {
+        let mut verification = ReceiptVerification {
+            instance: instance.handle.clone(),
+            verified: false,
+            anchored_at: None,
+            commit_id: None,
+            content_valid: false,
+            commit_valid: false,
+            commit_indexed: false,
+            instance_matches: false,
+        };
+
+        self.ensure_collection().await?;
+        let id = receipt_id(&instance.handle);
+        let Some(doc) = self.manager.get_document(RECEIPT_COLLECTION, &id).await? else {
+            return Ok(verification);
+        };
+        let receipt: ExecutionReceipt = json::deserialize_from_value(doc)?;
+
+        verification.anchored_at = Some(receipt.commit.timestamp);
+        verification.commit_id = Some(receipt.commit.id.clone());
+        verification.content_valid = receipt.content.hash()? == receipt.receipt_hash;
+        verification.commit_valid = receipt.commit.verify()
+            && receipt.commit.mutations.iter().any(|m| {
+                m.element_id == receipt.id
+                    && m.payload.get("receipt_hash").and_then(|h| h.as_str())
+                        == Some(receipt.receipt_hash.as_str())
+            });
+        verification.commit_indexed = self
+            .explorer()
+            .explore(&ExplorerQuery {
+                kind: ExploreKind::Blocks,
+                commit_id: Some(receipt.commit.id.clone()),
+                ..Default::default()
+            })
+            .await?
+            .total
+            > 0;
+        verification.instance_matches = receipt.content.status == instance.status
+            && receipt.content.input_hash == instance.input_hash
+            && receipt.content.approvals == instance.approvals;
+        verification.verified = verification.content_valid
+            && verification.commit_valid
+            && verification.commit_indexed
+            && verification.instance_matches;
+        Ok(verification)
+    }
+}
+
+// =========================================================================
+// TESTS UNITAIRES
+// =========================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::DbSandbox;
+    use crate::workflow_engine::SchedulingHints;
+
+    fn definition() -> WorkflowDefinition {
+        WorkflowDefinition {
+            _id: None,
+            handle: "wf_release".to_string(),
+            nodes: vec![],
+            edges: vec![],
+            entry: "start".to_string(),
+            context_schema: None,
+            webhook: None,
+            scheduling: SchedulingHints::default(),
+            anchor_receipt: true,
+        }
+    }
+
+    #[async_test]
+    async fn test_anchor_then_verify_receipt() -> RaiseResult<()> {
+        let sandbox = DbSandbox::new().await?;
+        let manager = CollectionsManager::new(&sandbox.storage, "test", "receipts");
+        DbSandbox::mock_db(&manager).await?;
+        let anchor = ReceiptAnchor::new(&sandbox.storage, "test", "receipts");
+        let keys = KeyPair::generate();
+
+        let mut inputs = UnorderedMap::new();
+        inputs.insert("release".to_string(), json_value!("1.0"));
+        let mut instance = WorkflowInstance::new("inst_release_1", "wf_release", "m1", inputs);
+        instance.approvals.push(HitlApproval {
+            node_id: "qa_gate".to_string(),
+            approved: true,
+            approver: Some("alice".to_string()),
+            decided_at: 1,
+        });
+        instance.status = ExecutionStatus::Completed;
+
+        let receipt = anchor.anchor(&definition(), &instance, &keys).await?;
+        assert_eq!(receipt.content.status, ExecutionStatus::Completed);
+        assert_eq!(receipt.content.input_hash, instance.input_hash);
+        let again = anchor.anchor(&definition(), &instance, &keys).await?;
+        assert_eq!(again.commit.id, receipt.commit.id);
+
+        let ok = anchor.verify(&instance).await?;
+        assert!(ok.verified);
+        assert_eq!(ok.commit_id.as_deref(), Some(receipt.commit.id.as_str()));
+
+        // Une décision HITL réécrite après coup est détectée
+        instance.approvals[0].approved = false;
+        let ko = anchor.verify(&instance).await?;
+        assert!(!ko.verified);
+        assert!(ko.content_valid && ko.commit_valid && !ko.instance_matches);
+
+        // Le hash de la définition change avec son contenu
+        let mut changed = definition();
+        changed.entry = "other".to_string();
+        assert_ne!(definition_hash(&changed)?, definition_hash(&definition())?);
+        Ok(())
+    }
+}
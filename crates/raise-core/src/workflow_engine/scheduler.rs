@@ -1,15 +1,26 @@
 // FICHIER : src-tauri/src/workflow_engine/scheduler.rs
+use crate::blockchain::crypto::signing::KeyPair;
 use crate::json_db::collections::manager::CollectionsManager;
+use crate::kernel::events::{publish_event, RaiseEvent};
+use crate::utils::data::config::{PlanReviewConfig, WorkflowSchedulingConfig};
 use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
 
 use crate::workflow_engine::{
-    executor::WorkflowExecutor, state_machine::WorkflowStateMachine, ExecutionStatus,
+    admission::AdmissionController, context_schema::validate_context, critic::WorkflowCritic,
+    executor::WorkflowExecutor, mandate::Mandate, receipt, receipt::ReceiptAnchor,
+    state_machine::WorkflowStateMachine, ExecutionStatus, HitlApproval, SchedulingHints,
     WorkflowDefinition, WorkflowInstance,
 };
 
 pub struct WorkflowScheduler {
     pub executor: WorkflowExecutor,
     pub definitions: UnorderedMap<String, WorkflowDefinition>,
+    /// File d'admission partagée : priorités et plafonds de concurrence des exécutions
+    pub admission: SharedRef<AdmissionController>,
+    /// Revue du plan par le critique à la création de chaque instance
+    pub plan_review: PlanReviewConfig,
+    /// Clé de signature des commits d'ancrage des reçus d'exécution
+    pub receipt_keys: SharedRef<KeyPair>,
 }
 
 impl WorkflowScheduler {
@@ -17,6 +28,35 @@ impl WorkflowScheduler {
         Self {
             executor,
             definitions: UnorderedMap::new(),
+            admission: SharedRef::new(AdmissionController::default()),
+            plan_review: PlanReviewConfig::default(),
+            receipt_keys: SharedRef::new(KeyPair::generate()),
+        }
+    }
+
+    /// Signe les reçus d'exécution avec la clé du nœud (clé éphémère par défaut).
+    pub fn with_receipt_keys(mut self, keys: KeyPair) -> Self {
+        self.receipt_keys = SharedRef::new(keys);
+        self
+    }
+
+    /// Applique les plafonds de concurrence de la configuration (`workflow_scheduling`).
+    pub fn with_scheduling(mut self, config: WorkflowSchedulingConfig) -> Self {
+        self.admission = SharedRef::new(AdmissionController::new(config));
+        self
+    }
+
+    /// Applique la politique de revue des plans de la configuration (`plan_review`).
+    pub fn with_plan_review(mut self, config: PlanReviewConfig) -> Self {
+        self.plan_review = config;
+        self
+    }
+
+    /// Paramètres d'ordonnancement d'un workflow (valeurs par défaut s'il est inconnu).
+    pub fn scheduling_hints(&self, workflow_handle: &str) -> SchedulingHints {
+        match self.definitions.get(workflow_handle) {
+            Some(def) => def.scheduling.clone(),
+            None => SchedulingHints::default(),
         }
     }
 
@@ -53,6 +93,19 @@ impl WorkflowScheduler {
         mission_id: &str,
         workflow_handle: &str,
         manager: &'a CollectionsManager<'a>,
+    ) -> RaiseResult<WorkflowInstance> {
+        self.create_instance_with_context(mission_id, workflow_handle, JsonObject::new(), manager)
+            .await
+    }
+
+    /// Crée une instance avec un contexte d'entrée, validé contre le `context_schema`
+    /// du workflow avant toute persistance (échec immédiat avec erreurs par champ).
+    pub async fn create_instance_with_context<'a>(
+        &self,
+        mission_id: &str,
+        workflow_handle: &str,
+        inputs: JsonObject<String, JsonValue>,
+        manager: &'a CollectionsManager<'a>,
     ) -> RaiseResult<WorkflowInstance> {
         let def = match self.definitions.get(workflow_handle) {
             Some(definition) => definition,
@@ -62,6 +115,21 @@ impl WorkflowScheduler {
             ),
         };
 
+        validate_context(def, &inputs, manager).await?;
+
+        let plan_review = match self.plan_review.enabled {
+            true => {
+                let mandate = Self::mission_mandate(mission_id, manager).await;
+                Some(WorkflowCritic::default().review_plan(
+                    def,
+                    mandate.as_ref(),
+                    &self.plan_review,
+                ))
+            }
+            false => None,
+        };
+
+        let context: UnorderedMap<String, JsonValue> = inputs.into_iter().collect();
         let mut instance = WorkflowInstance {
             _id: None,
             handle: format!(
@@ -73,20 +141,57 @@ impl WorkflowScheduler {
             workflow_id: def.handle.clone(),
             status: ExecutionStatus::Pending,
             node_states: UnorderedMap::new(),
-            context: UnorderedMap::new(),
+            input_hash: Some(receipt::input_hash(&context)),
+            approvals: Vec::new(),
+            receipt_id: None,
+            context,
             xai_traces: Vec::new(),
             logs: vec![format!(
                 "Création de l'instance pour le workflow {}",
                 def.handle
             )],
+            plan_review: None,
             created_at: UtcClock::now().timestamp(),
             updated_at: UtcClock::now().timestamp(),
         };
 
+        if let Some(critique) = plan_review {
+            let report = json_value!({
+                "workflow": def.handle,
+                "score": critique.score,
+                "findings": critique.findings.len()
+            });
+            if critique.is_acceptable {
+                user_info!("INF_WF_PLAN_REVIEW_PASSED", report);
+            } else if self.plan_review.enforce {
+                user_warn!("WRN_WF_PLAN_BLOCKED", report);
+                instance.status = ExecutionStatus::Blocked;
+                instance
+                    .logs
+                    .push("⛔ Plan rejeté par le critique : exécution bloquée.".into());
+            } else {
+                user_warn!("WRN_WF_PLAN_REVIEW_FAILED", report);
+            }
+            instance.plan_review = Some(critique);
+        }
+
         self.persist_instance(&mut instance, manager).await?;
         Ok(instance)
     }
 
+    /// Mandat de la mission (`missions.mandate_id`), absent pour les missions hors gouvernance.
+    async fn mission_mandate(
+        mission_id: &str,
+        manager: &CollectionsManager<'_>,
+    ) -> Option<Mandate> {
+        let mission = match manager.get_document("missions", mission_id).await {
+            Ok(Some(doc)) => doc,
+            _ => return None,
+        };
+        let handle = mission.get("mandate_id").and_then(|v| v.as_str())?;
+        Mandate::fetch_from_store(manager, handle).await.ok()
+    }
+
     /// Exécute une étape élémentaire du workflow.
     pub async fn run_step<'a>(
         &'a self,
@@ -108,6 +213,7 @@ impl WorkflowScheduler {
             if instance.status == ExecutionStatus::Running {
                 instance.status = ExecutionStatus::Completed;
                 instance.logs.push("🏁 Exécution terminée.".into());
+                self.anchor_receipt(def, instance, manager).await?;
                 self.persist_instance(instance, manager).await?;
             }
             return Ok(false);
@@ -134,6 +240,14 @@ impl WorkflowScheduler {
 
                 if status == ExecutionStatus::Paused || status == ExecutionStatus::Failed {
                     instance.status = status;
+                    if status == ExecutionStatus::Paused {
+                        publish_event(RaiseEvent::WorkflowPaused {
+                            instance: instance.handle.clone(),
+                            workflow: instance.workflow_id.clone(),
+                        });
+                    } else {
+                        self.anchor_receipt(def, instance, manager).await?;
+                    }
                     break;
                 }
             }
@@ -147,6 +261,7 @@ impl WorkflowScheduler {
     }
 
     /// Boucle d'exécution automatique jusqu'à complétion ou pause.
+    #[instrument(name = "workflow.execute_instance", skip_all, fields(instance = instance_handle))]
     pub async fn execute_instance_loop<'a>(
         &'a self,
         instance_handle: &str,
@@ -209,12 +324,40 @@ impl WorkflowScheduler {
             ExecutionStatus::Failed
         };
         instance.node_states.insert(node_id.to_string(), new_status);
+        instance.approvals.push(HitlApproval {
+            node_id: node_id.to_string(),
+            approved,
+            approver: AppConfig::get().user.as_ref().map(|u| u.id.clone()),
+            decided_at: UtcClock::now().timestamp(),
+        });
         instance.status = ExecutionStatus::Running;
 
         self.persist_instance(&mut instance, manager).await?;
         Ok(instance.status)
     }
 
+    /// Ancre le reçu d'une instance arrivée à son statut final, si le workflow l'exige
+    /// (`anchor_receipt`). Le reçu est rangé dans la base de l'instance.
+    async fn anchor_receipt(
+        &self,
+        def: &WorkflowDefinition,
+        instance: &mut WorkflowInstance,
+        manager: &CollectionsManager<'_>,
+    ) -> RaiseResult<()> {
+        if !def.anchor_receipt || instance.receipt_id.is_some() {
+            return Ok(());
+        }
+        let receipt = ReceiptAnchor::new(manager.storage, &manager.space, &manager.db)
+            .anchor(def, instance, &self.receipt_keys)
+            .await?;
+        instance.logs.push(format!(
+            "🔏 Reçu d'exécution ancré (commit {}).",
+            receipt.commit.id
+        ));
+        instance.receipt_id = Some(receipt.id);
+        Ok(())
+    }
+
     /// Persistance atomique de l'état de l'instance.
     async fn persist_instance(
         &self,
@@ -248,6 +391,7 @@ mod tests {
     use crate::model_engine::types::ProjectModel;
     use crate::plugins::manager::PluginManager;
     use crate::utils::testing::AgentDbSandbox; // 🎯 Ajout de DbSandbox
+    use crate::workflow_engine::SchedulingHints;
 
     async fn setup_test_environment(
         storage: SharedRef<crate::json_db::storage::StorageEngine>,
@@ -293,6 +437,10 @@ mod tests {
             _id: None,
             handle: "wf_test".to_string(),
             entry: "n1".to_string(),
+            context_schema: None,
+            webhook: None,
+            scheduling: SchedulingHints::default(),
+            anchor_receipt: false,
             nodes: vec![],
             edges: vec![],
         };
@@ -343,6 +491,10 @@ mod tests {
             context: UnorderedMap::new(),
             xai_traces: Vec::new(),
             logs: Vec::new(),
+            plan_review: None,
+            input_hash: None,
+            approvals: Vec::new(),
+            receipt_id: None,
             created_at: 0,
             updated_at: 0,
         };
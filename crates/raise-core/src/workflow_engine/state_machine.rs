@@ -27,6 +27,7 @@ impl<'a> WorkflowStateMachine<'a> {
         if instance.status == ExecutionStatus::Paused
             || instance.status == ExecutionStatus::Completed
             || instance.status == ExecutionStatus::Failed
+            || instance.status == ExecutionStatus::Blocked
         {
             return runnable;
         }
@@ -245,13 +246,17 @@ impl<'a> WorkflowStateMachine<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::workflow_engine::{NodeType, WorkflowEdge, WorkflowNode};
+    use crate::workflow_engine::{NodeType, SchedulingHints, WorkflowEdge, WorkflowNode};
 
     fn create_sequential_def() -> WorkflowDefinition {
         WorkflowDefinition {
             _id: None,
             handle: "wf_seq".into(),
             entry: "start".into(),
+            context_schema: None,
+            webhook: None,
+            scheduling: SchedulingHints::default(),
+            anchor_receipt: false,
             nodes: vec![
                 WorkflowNode {
                     id: "start".into(),
@@ -336,6 +341,10 @@ mod tests {
             _id: None,
             handle: "wf_branch".into(),
             entry: "start".into(),
+            context_schema: None,
+            webhook: None,
+            scheduling: SchedulingHints::default(),
+            anchor_receipt: false,
             nodes: vec![
                 WorkflowNode {
                     id: "start".into(),
@@ -391,6 +400,10 @@ mod tests {
             _id: None,
             handle: "wf_ast".into(),
             entry: "start".into(),
+            context_schema: None,
+            webhook: None,
+            scheduling: SchedulingHints::default(),
+            anchor_receipt: false,
             nodes: vec![
                 WorkflowNode {
                     id: "start".into(),
@@ -3,7 +3,9 @@
 use crate::utils::prelude::*;
 // 🎯 NOUVEAU : Import du contexte
 use super::handlers::HandlerContext;
+pub mod model_tools;
 pub mod system_tools;
+pub use model_tools::InstantiatePatternTool;
 pub use system_tools::SystemMonitorTool;
 
 /// Définition d'un Outil que l'Agent (ou le Workflow) peut appeler.
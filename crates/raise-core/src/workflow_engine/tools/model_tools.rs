@@ -0,0 +1,83 @@
+// FICHIER : src-tauri/src/workflow_engine/tools/model_tools.rs
+
+use super::AgentTool;
+use crate::json_db::collections::manager::CollectionsManager;
+use crate::model_engine::patterns::PatternLibrary;
+use crate::utils::prelude::*; // 🎯 Façade Unique RAISE
+use crate::workflow_engine::handlers::HandlerContext;
+
+/// Outil d'instanciation de patrons : l'agent crée un lot d'éléments cohérent
+/// (noms, liens internes) en un seul appel au lieu d'éléments isolés.
+#[derive(Debug, Default)]
+pub struct InstantiatePatternTool;
+
+#[async_interface]
+impl AgentTool for InstantiatePatternTool {
+    fn name(&self) -> &str {
+        "instantiate_pattern"
+    }
+
+    fn description(&self) -> &str {
+        "Instancie un patron de la bibliothèque (ex : redundant_sensor_pair, client_server_pair) dans le modèle courant. Retourne les identifiants et noms des éléments créés."
+    }
+
+    fn parameters_schema(&self) -> JsonValue {
+        json_value!({
+            "type": "object",
+            "properties": {
+                "pattern": { "type": "string", "description": "Handle du patron" },
+                "parameters": { "type": "object", "description": "Valeurs des paramètres du patron" }
+            },
+            "required": ["pattern"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        args: &JsonValue,
+        context: &HandlerContext<'_>,
+    ) -> RaiseResult<JsonValue> {
+        let Some(handle) = args.get("pattern").and_then(|v| v.as_str()) else {
+            raise_error!(
+                "ERR_TOOL_PATTERN_ARGS",
+                error = "Argument 'pattern' manquant.",
+                context = json_value!({ "args": args })
+            );
+        };
+        let params = args
+            .get("parameters")
+            .and_then(|v| v.as_object())
+            .cloned()
+            .unwrap_or_default();
+
+        // La bibliothèque est partagée (partition système), l'instance va dans le projet courant
+        let config = AppConfig::get();
+        let sys_mgr = CollectionsManager::new(
+            context.manager.storage,
+            &config.mount_points.system.domain,
+            &config.mount_points.system.db,
+        );
+        let instance = PatternLibrary::new(&sys_mgr)
+            .instantiate_into(handle, &params, context.manager)
+            .await?;
+
+        let created: Vec<JsonValue> = instance
+            .elements
+            .iter()
+            .map(|p| {
+                json_value!({
+                    "key": p.key,
+                    "id": p.element.id,
+                    "name": p.element.name.as_str(),
+                    "layer": p.layer,
+                    "collection": p.collection
+                })
+            })
+            .collect();
+        Ok(json_value!({
+            "pattern": instance.pattern,
+            "instance_id": instance.instance_id,
+            "elements": created
+        }))
+    }
+}
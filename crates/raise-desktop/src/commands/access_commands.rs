@@ -0,0 +1,81 @@
+// FICHIER : crates/raise-desktop/src/commands/access_commands.rs
+
+use raise_core::services::access_service;
+use raise_core::utils::context::{
+    AccessGuard, AccessIdentity, AccessRole, AuditLog, SessionManager,
+};
+use raise_core::utils::prelude::*;
+
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::{command, Manager, Runtime, State};
+
+#[command]
+pub async fn access_login_oidc(
+    access: State<'_, AccessGuard>,
+    token: String,
+) -> RaiseResult<AccessIdentity> {
+    access_service::login_oidc(access.inner(), &token)
+}
+
+#[command]
+pub async fn access_login_token(
+    access: State<'_, AccessGuard>,
+    token: String,
+) -> RaiseResult<AccessIdentity> {
+    access_service::login_api_token(access.inner(), &token).await
+}
+
+/// Émet un jeton d'API pour un relecteur (réservé aux administrateurs).
+#[command]
+pub async fn access_issue_token(
+    user: String,
+    roles: Vec<AccessRole>,
+    ttl_hours: Option<i64>,
+) -> RaiseResult<JsonValue> {
+    let (token, claims) = access_service::issue_api_token(&user, roles, ttl_hours).await?;
+    Ok(json_value!({ "token": token, "claims": claims }))
+}
+
+#[command]
+pub async fn access_whoami(access: State<'_, AccessGuard>) -> RaiseResult<Option<AccessIdentity>> {
+    Ok(access_service::whoami(access.inner()))
+}
+
+/// Contrôle une invocation du frontend avant son exécution (branché sur l'invoke_handler).
+/// Un refus est tracé dans le journal d'audit de manière asynchrone.
+pub fn authorize_invocation<R: Runtime>(invoke: &Invoke<R>) -> RaiseResult<()> {
+    let app = invoke.message.webview().app_handle().clone();
+    let Some(access) = app.try_state::<AccessGuard>().map(|s| s.inner().clone()) else {
+        return Ok(());
+    };
+    let command = invoke.message.command().to_string();
+    let Err(err) = access_service::authorize(&access, &command) else {
+        return Ok(());
+    };
+
+    let code = match &err {
+        AppError::Structured(e) => e.code.clone(),
+        _ => "ERR_ACCESS_DENIED".to_string(),
+    };
+    let params = match invoke.message.payload() {
+        InvokeBody::Json(value) => value.clone(),
+        InvokeBody::Raw(_) => JsonValue::Null,
+    };
+    if let (Some(audit), Some(sessions)) = (
+        app.try_state::<AuditLog>().map(|s| s.inner().clone()),
+        app.try_state::<SessionManager>().map(|s| s.inner().clone()),
+    ) {
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) =
+                access_service::record_denial(&audit, &access, &sessions, &command, &params, &code)
+                    .await
+            {
+                user_warn!(
+                    "AUDIT_RECORD_FAILED",
+                    json_value!({ "action": command, "error": e.to_string() })
+                );
+            }
+        });
+    }
+    Err(err)
+}
@@ -1,14 +1,32 @@
 // FICHIER : crates/raise-desktop/src/commands/ai_commands.rs
 
+use raise_core::ai::agents::coordination::WriteConflict;
+use raise_core::ai::agents::intent_classifier::EngineeringIntent;
+use raise_core::ai::agents::intent_examples::{IntentExample, IntentExampleStore};
+use raise_core::ai::agents::prompt_registry::{PromptRegistry, PromptTemplate};
 use raise_core::ai::agents::AgentResult;
+use raise_core::ai::graph_store::duplicates::{DuplicateOptions, DuplicateReport};
+use raise_core::ai::llm::models::{LocalModelManager, LocalModelStatus, ModelCheck};
+use raise_core::ai::llm::usage::{UsageDashboard, UsageTracker};
 use raise_core::ai::llm::NativeLlmState;
+use raise_core::ai::maintenance::{
+    MaintenanceRun, MaintenanceScheduler, MaintenanceTask, RunTrigger, ScheduleEntry,
+};
+use raise_core::ai::memory::migration::{EmbeddingMigrator, EmbeddingStatus, MigrationJob};
 use raise_core::ai::training::dataset::TrainingExample;
 use raise_core::json_db::storage::StorageEngine;
+use raise_core::kernel::state::RaiseKernelState;
+use raise_core::model_engine::validators::RequirementQuality;
 use raise_core::utils::prelude::*;
 
 // 🎯 On importe les services et états depuis le noyau
-use raise_core::services::ai_service::{self, AiState};
+use raise_core::ai::orchestrator::AiStreamEvent;
+use raise_core::ai::voice::ingest::{AudioPayload, Transcription};
+use raise_core::services::ai_service::{self, AiState, AiStreamState, VoiceChatResult};
+use raise_core::services::project_service::ProjectWorkspace;
+use raise_core::services::voice_service::{self, VoiceState};
 
+use tauri::ipc::Channel;
 use tauri::{command, State};
 
 /// 🖥️ COMMANDE TAURI : Expose la logique blueprint à l'interface graphique.
@@ -46,6 +64,171 @@ pub async fn ai_export_dataset(
     ai_service::ai_export_dataset(storage_ref.as_ref(), &space, &db_name, &domain).await
 }
 
+/// 🧬 COMMANDE TAURI : Compare l'index RAG au modèle d'embedding actif.
+#[command]
+pub async fn ai_embedding_status(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    space: String,
+    db_name: String,
+) -> RaiseResult<EmbeddingStatus> {
+    ai_service::ai_embedding_status(storage.inner().as_ref(), &space, &db_name).await
+}
+
+/// 🧬 COMMANDE TAURI : Lance la ré-vectorisation en arrière-plan (None si à jour).
+#[command]
+pub async fn ai_embedding_migrate(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    space: String,
+    db_name: String,
+) -> RaiseResult<Option<MigrationJob>> {
+    ai_service::ai_embedding_migrate(storage.inner().clone(), space, db_name).await
+}
+
+/// 🧬 COMMANDE TAURI : Migrations d'embeddings et leur progression.
+#[command]
+pub async fn ai_embedding_migrations(
+    storage: State<'_, SharedRef<StorageEngine>>,
+) -> RaiseResult<Vec<MigrationJob>> {
+    EmbeddingMigrator::system(storage.inner().as_ref())
+        .list()
+        .await
+}
+
+/// 📦 COMMANDE TAURI : Catalogue des modèles GGUF locaux (installés, actif).
+#[command]
+pub async fn ai_models_list(
+    storage: State<'_, SharedRef<StorageEngine>>,
+) -> RaiseResult<Vec<LocalModelStatus>> {
+    LocalModelManager::system(storage.inner().as_ref())
+        .list()
+        .await
+}
+
+/// 📦 COMMANDE TAURI : Télécharge un modèle du catalogue (empreinte SHA-256 contrôlée).
+#[command]
+pub async fn ai_model_download(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    model_id: String,
+) -> RaiseResult<ModelCheck> {
+    LocalModelManager::system(storage.inner().as_ref())
+        .download(&model_id)
+        .await
+}
+
+/// 📦 COMMANDE TAURI : Recalcule l'empreinte d'un modèle installé.
+#[command]
+pub async fn ai_model_verify(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    model_id: String,
+) -> RaiseResult<ModelCheck> {
+    LocalModelManager::system(storage.inner().as_ref())
+        .verify(&model_id)
+        .await
+}
+
+/// 📦 COMMANDE TAURI : Bascule le modèle d'inférence actif (rechargé à chaud).
+#[command]
+pub async fn ai_model_switch(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    kernel: State<'_, RaiseKernelState>,
+    model_id: String,
+) -> RaiseResult<LocalModelStatus> {
+    LocalModelManager::system(storage.inner().as_ref())
+        .switch(&model_id, kernel.native_llm.as_ref())
+        .await
+}
+
+/// 💶 COMMANDE TAURI : Tableau de bord des coûts LLM (mois courant par défaut).
+#[command]
+pub async fn ai_usage_dashboard(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    month: Option<String>,
+) -> RaiseResult<UsageDashboard> {
+    UsageTracker::system(storage.inner().as_ref())
+        .dashboard(month.as_deref())
+        .await
+}
+
+/// 📝 COMMANDE TAURI : Dernière version de chaque gabarit de prompt.
+#[command]
+pub async fn ai_prompt_list(
+    storage: State<'_, SharedRef<StorageEngine>>,
+) -> RaiseResult<Vec<PromptTemplate>> {
+    PromptRegistry::system(storage.inner().as_ref())
+        .list()
+        .await
+}
+
+/// 📝 COMMANDE TAURI : Historique des versions d'un gabarit.
+#[command]
+pub async fn ai_prompt_history(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    name: String,
+) -> RaiseResult<Vec<PromptTemplate>> {
+    PromptRegistry::system(storage.inner().as_ref())
+        .versions(&name)
+        .await
+}
+
+/// ✏️ COMMANDE TAURI : Publie une nouvelle version d'un gabarit.
+#[command]
+pub async fn ai_prompt_publish(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    name: String,
+    template: String,
+    description: Option<String>,
+    author: String,
+) -> RaiseResult<PromptTemplate> {
+    PromptRegistry::system(storage.inner().as_ref())
+        .publish(&name, &template, description.as_deref(), &author)
+        .await
+}
+
+/// 🧪 COMMANDE TAURI : Ajoute une note d'évaluation à une version.
+#[command]
+pub async fn ai_prompt_annotate(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    name: String,
+    version: u32,
+    author: String,
+    score: Option<f64>,
+    comment: String,
+) -> RaiseResult<PromptTemplate> {
+    PromptRegistry::system(storage.inner().as_ref())
+        .annotate(&name, version, &author, score, &comment)
+        .await
+}
+
+/// 📚 COMMANDE TAURI : Exemples annotés du classifieur d'intentions.
+#[command]
+pub async fn ai_intent_examples(
+    storage: State<'_, SharedRef<StorageEngine>>,
+) -> RaiseResult<Vec<IntentExample>> {
+    IntentExampleStore::system(storage.inner().as_ref())
+        .list()
+        .await
+}
+
+/// 🚩 COMMANDE TAURI : Ajoute un exemple, ou une correction si `predicted` est fourni.
+#[command]
+pub async fn ai_intent_example_flag(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    utterance: String,
+    expected: EngineeringIntent,
+    predicted: Option<EngineeringIntent>,
+    author: String,
+) -> RaiseResult<IntentExample> {
+    let store = IntentExampleStore::system(storage.inner().as_ref());
+    match predicted {
+        Some(predicted) => {
+            store
+                .flag_misclassification(&utterance, expected, Some(predicted), &author)
+                .await
+        }
+        None => store.add(&utterance, expected, &author).await,
+    }
+}
+
 // --- COMMANDES ORCHESTRATION UNIFIÉE (V2) ---
 
 #[command]
@@ -78,6 +261,148 @@ pub async fn ai_chat(ai_state: State<'_, AiState>, user_input: String) -> RaiseR
     ai_service::ai_chat(ai_state.inner(), &user_input).await
 }
 
+/// 💬 COMMANDE TAURI : `ai_chat` en flux. Les fragments (`delta`) arrivent sur `on_event`
+/// pendant la génération ; le résultat consolidé est aussi retourné à la fin.
+#[command]
+pub async fn ai_chat_stream(
+    ai_state: State<'_, AiState>,
+    streams: State<'_, AiStreamState>,
+    stream_id: String,
+    user_input: String,
+    on_event: Channel<AiStreamEvent>,
+) -> RaiseResult<AgentResult> {
+    let (tx, mut rx) = AsyncChannel::unbounded_channel();
+    let forwarder = tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            // Fenêtre fermée : on vide le canal sans bloquer la génération
+            let _ = on_event.send(event);
+        }
+    });
+
+    let result = ai_service::ai_chat_stream(
+        ai_state.inner(),
+        streams.inner(),
+        &stream_id,
+        &user_input,
+        tx,
+    )
+    .await;
+    // L'émetteur est libéré avec le flux : on attend que les derniers fragments partent
+    let _ = forwarder.await;
+    result
+}
+
+/// ⏹️ COMMANDE TAURI : Interrompt une réponse en flux (`false` si déjà terminée).
+#[command]
+pub async fn ai_chat_cancel(
+    streams: State<'_, AiStreamState>,
+    stream_id: String,
+) -> RaiseResult<bool> {
+    ai_service::ai_chat_cancel(streams.inner(), &stream_id)
+}
+
+/// 🔒 COMMANDE TAURI : Écritures d'agents en conflit, en attente d'arbitrage humain.
+#[command]
+pub async fn ai_write_conflicts() -> RaiseResult<Vec<WriteConflict>> {
+    Ok(ai_service::ai_write_conflicts())
+}
+
+/// 🔒 COMMANDE TAURI : Arbitre un conflit (`approved` applique la proposition de l'agent).
+#[command]
+pub async fn ai_resolve_write_conflict(
+    storage: State<'_, SharedRef<StorageEngine>>,
+    conflict_id: String,
+    approved: bool,
+) -> RaiseResult<WriteConflict> {
+    ai_service::ai_resolve_write_conflict(storage.inner().as_ref(), &conflict_id, approved).await
+}
+
+/// 🧹 COMMANDE TAURI : Planification des tâches de maintenance IA et prochaines échéances.
+#[command]
+pub async fn ai_maintenance_schedule(
+    scheduler: State<'_, SharedRef<MaintenanceScheduler>>,
+) -> RaiseResult<Vec<ScheduleEntry>> {
+    scheduler.schedule(&UtcClock::now()).await
+}
+
+/// 🧹 COMMANDE TAURI : Exécute immédiatement une tâche de maintenance.
+#[command]
+pub async fn ai_maintenance_run(
+    scheduler: State<'_, SharedRef<MaintenanceScheduler>>,
+    task: MaintenanceTask,
+) -> RaiseResult<MaintenanceRun> {
+    scheduler.run_task(task, RunTrigger::Manual).await
+}
+
+/// 🧹 COMMANDE TAURI : Historique des exécutions (les plus récentes d'abord).
+#[command]
+pub async fn ai_maintenance_history(
+    scheduler: State<'_, SharedRef<MaintenanceScheduler>>,
+    task: Option<MaintenanceTask>,
+    limit: Option<usize>,
+) -> RaiseResult<Vec<MaintenanceRun>> {
+    scheduler.history(task, limit.unwrap_or(50)).await
+}
+
+/// 👯 COMMANDE TAURI : Doublons sémantiques du projet actif et propositions de fusion.
+#[command]
+pub async fn ai_find_duplicates(
+    workspace: State<'_, ProjectWorkspace>,
+    storage: State<'_, SharedRef<StorageEngine>>,
+    options: Option<DuplicateOptions>,
+) -> RaiseResult<DuplicateReport> {
+    ai_service::ai_find_duplicates(
+        workspace.inner(),
+        storage.inner().as_ref(),
+        &options.unwrap_or_default(),
+    )
+    .await
+}
+
+/// 📝 COMMANDE TAURI : Score de qualité des exigences (ambiguïté, atomicité, testabilité).
+#[command]
+pub async fn ai_requirement_quality(
+    workspace: State<'_, ProjectWorkspace>,
+    ai_state: State<'_, AiState>,
+    storage: State<'_, SharedRef<StorageEngine>>,
+    use_llm: Option<bool>,
+) -> RaiseResult<Vec<RequirementQuality>> {
+    ai_service::ai_requirement_quality(
+        workspace.inner(),
+        ai_state.inner(),
+        storage.inner().clone(),
+        use_llm.unwrap_or(true),
+    )
+    .await
+}
+
+/// 🎤 COMMANDE TAURI : Transcrit un enregistrement vocal ; le texte est ensuite soumis à `ai_chat`.
+#[command]
+pub async fn ai_transcribe(
+    voice_state: State<'_, VoiceState>,
+    storage: State<'_, SharedRef<StorageEngine>>,
+    audio: AudioPayload,
+) -> RaiseResult<Transcription> {
+    voice_service::transcribe_recording(voice_state.inner(), storage.inner().as_ref(), &audio).await
+}
+
+/// 🎤 COMMANDE TAURI : Transcription + tour de chat en un seul appel (édition vocale).
+#[command]
+pub async fn ai_voice_chat(
+    ai_state: State<'_, AiState>,
+    voice_state: State<'_, VoiceState>,
+    storage: State<'_, SharedRef<StorageEngine>>,
+    audio: AudioPayload,
+) -> RaiseResult<VoiceChatResult> {
+    ai_service::ai_voice_chat(
+        ai_state.inner(),
+        voice_state.inner(),
+        storage.inner().as_ref(),
+        &audio,
+    )
+    .await
+}
+
 #[command]
 pub async fn ask_native_llm(
     state: State<'_, NativeLlmState>,
@@ -0,0 +1,82 @@
+// FICHIER : crates/raise-desktop/src/commands/audit_commands.rs
+
+use raise_core::blockchain::storage::chain::Ledger;
+use raise_core::services::audit_service;
+use raise_core::utils::context::audit::{
+    is_state_changing, AuditAnchor, AuditEntry, AuditLog, AuditVerification,
+};
+use raise_core::utils::context::SessionManager;
+use raise_core::utils::prelude::*;
+
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::{command, AppHandle, Manager, Runtime, State};
+
+#[command]
+pub async fn audit_list(
+    audit: State<'_, AuditLog>,
+    actor: Option<String>,
+    action: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    limit: Option<usize>,
+) -> RaiseResult<Vec<AuditEntry>> {
+    audit_service::list_entries(
+        audit.inner(),
+        actor,
+        action,
+        since.as_deref(),
+        until.as_deref(),
+        limit,
+    )
+    .await
+}
+
+#[command]
+pub async fn audit_anchor_day(
+    app: AppHandle,
+    audit: State<'_, AuditLog>,
+    day: String,
+) -> RaiseResult<AuditAnchor> {
+    // Ancrage dans le Ledger Mentis uniquement s'il est monté
+    let ledger = app.try_state::<SyncMutex<Ledger>>();
+    audit_service::anchor_day(audit.inner(), &day, ledger.as_ref().map(|l| l.inner())).await
+}
+
+#[command]
+pub async fn audit_verify_day(
+    audit: State<'_, AuditLog>,
+    day: String,
+) -> RaiseResult<AuditVerification> {
+    audit_service::verify_day(audit.inner(), &day).await
+}
+
+/// Trace une invocation du frontend avant son exécution (branché sur l'invoke_handler).
+/// L'écriture est asynchrone : elle ne retarde jamais la commande.
+pub fn trace_invocation<R: Runtime>(invoke: &Invoke<R>) {
+    let command = invoke.message.command().to_string();
+    if !is_state_changing(&command) {
+        return;
+    }
+    let params = match invoke.message.payload() {
+        InvokeBody::Json(value) => value.clone(),
+        InvokeBody::Raw(_) => JsonValue::Null,
+    };
+    let app = invoke.message.webview().app_handle().clone();
+    let (Some(audit), Some(sessions)) = (
+        app.try_state::<AuditLog>().map(|s| s.inner().clone()),
+        app.try_state::<SessionManager>().map(|s| s.inner().clone()),
+    ) else {
+        return;
+    };
+
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) =
+            audit_service::record_gui_invocation(&audit, &sessions, &command, &params).await
+        {
+            user_warn!(
+                "AUDIT_RECORD_FAILED",
+                json_value!({ "action": command, "error": e.to_string() })
+            );
+        }
+    });
+}
@@ -4,14 +4,20 @@ use raise_core::utils::prelude::*;
 
 // 🎯 Tout provient désormais de raise_core
 use raise_core::blockchain::{
+    consensus::reputation::{ReputationReport, ReputationStore},
     crypto::signing::KeyPair,
     ensure_blockchain_client,
+    explorer::{ChainExplorer, ExplorerPage, ExplorerQuery},
+    notary::{NotarizedFile, Notary, NotaryVerification},
     p2p::{MentisBehavior, MentisNetMessage},
     storage::chain::Ledger,
     storage::commit::{MentisCommit, Mutation},
+    vpn::{InnernetClient, PeerOverview, PeerSeries},
     BlockchainState, NetworkConfig,
 };
 
+use raise_core::json_db::storage::StorageEngine;
+
 use libp2p::{gossipsub, Swarm};
 use tauri::{command, State};
 
@@ -77,3 +83,56 @@ pub fn mentis_get_ledger_info(ledger_state: State<'_, SyncMutex<Ledger>>) -> Jso
         Err(_) => json_value!({ "error": "LOCK_POISONED", "status": "error" }),
     }
 }
+
+#[command]
+pub async fn mentis_explore(
+    storage: State<'_, StorageEngine>,
+    query: ExplorerQuery,
+) -> RaiseResult<ExplorerPage> {
+    ChainExplorer::system(storage.inner()).explore(&query).await
+}
+
+#[command]
+pub async fn mentis_reputation_report(
+    storage: State<'_, StorageEngine>,
+) -> RaiseResult<ReputationReport> {
+    let ledger = ReputationStore::system(storage.inner())
+        .load(AppConfig::get().reputation.clone())
+        .await?;
+    Ok(ledger.report())
+}
+
+#[command]
+pub async fn mentis_notarize_file(
+    storage: State<'_, StorageEngine>,
+    path: String,
+) -> RaiseResult<NotarizedFile> {
+    Notary::system(storage.inner())
+        .notarize(Path::new(&path), &KeyPair::generate())
+        .await
+}
+
+#[command]
+pub async fn mentis_verify_file(
+    storage: State<'_, StorageEngine>,
+    path: String,
+) -> RaiseResult<NotaryVerification> {
+    Notary::system(storage.inner())
+        .verify(Path::new(&path))
+        .await
+}
+
+/// Pairs du maillage VPN avec leur dernière mesure (RTT, débits) ; alimente l'historique.
+#[command]
+pub async fn vpn_list_peers(vpn: State<'_, InnernetClient>) -> RaiseResult<Vec<PeerOverview>> {
+    vpn.list_peers().await
+}
+
+/// Séries temporelles RTT / débits d'un pair (`public_key`) ou de tous, plus lent en tête.
+#[command]
+pub async fn vpn_peer_stats(
+    vpn: State<'_, InnernetClient>,
+    public_key: Option<String>,
+) -> RaiseResult<Vec<PeerSeries>> {
+    Ok(vpn.peer_stats(public_key.as_deref()).await)
+}
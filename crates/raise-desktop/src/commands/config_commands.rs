@@ -0,0 +1,26 @@
+// FICHIER : crates/raise-desktop/src/commands/config_commands.rs
+
+use raise_core::json_db::storage::StorageEngine;
+use raise_core::services::config_service;
+use raise_core::utils::prelude::*;
+
+use tauri::{command, State};
+
+#[command]
+pub fn config_get(key: String) -> RaiseResult<JsonValue> {
+    config_service::config_get(&key)
+}
+
+#[command]
+pub async fn config_set(
+    storage: State<'_, StorageEngine>,
+    key: String,
+    value: JsonValue,
+) -> RaiseResult<()> {
+    config_service::config_set(storage.inner(), &key, value).await
+}
+
+#[command]
+pub fn config_list_overrides() -> OrderedMap<String, JsonValue> {
+    config_service::config_list_overrides()
+}
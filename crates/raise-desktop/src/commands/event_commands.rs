@@ -0,0 +1,39 @@
+// FICHIER : crates/raise-desktop/src/commands/event_commands.rs
+
+use raise_core::kernel::events::{self, EventDescriptor};
+use raise_core::utils::prelude::*;
+
+use tauri::{command, AppHandle, Emitter};
+
+/// 📡 Pont Bus d'événements -> Frontend.
+/// Relaie chaque événement du noyau sur son canal Tauri (`app.emit`).
+pub fn spawn_event_bridge(app: AppHandle) {
+    let mut rx = events::subscribe_events();
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    if let Err(e) = app.emit(event.channel(), &event) {
+                        user_warn!(
+                            "WRN_EVENT_EMIT_FAILED",
+                            json_value!({ "channel": event.channel(), "error": e.to_string() })
+                        );
+                    }
+                }
+                Err(AsyncBroadcast::RecvError::Lagged(skipped)) => {
+                    user_warn!(
+                        "WRN_EVENT_BRIDGE_LAGGED",
+                        json_value!({ "skipped": skipped })
+                    );
+                }
+                Err(AsyncBroadcast::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[command]
+pub fn get_event_catalogue() -> Vec<EventDescriptor> {
+    events::event_catalogue()
+}
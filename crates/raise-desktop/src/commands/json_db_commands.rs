@@ -1,12 +1,24 @@
 // FICHIER : crates/raise-desktop/src/commands/json_db_commands.rs
 
+use raise_core::json_db::backup::{RestoreReport, SnapshotManifest};
+use raise_core::json_db::collections::acl::{with_optional_access, CollectionAcl, CollectionGrant};
+use raise_core::json_db::collections::vacuum::VacuumReport;
 use raise_core::json_db::query::QueryResult;
+use raise_core::json_db::schema::introspection::CollectionFields;
+use raise_core::json_db::schema::references::RefCheckReport;
+use raise_core::json_db::schema::strictness::{StrictnessReport, ValidationMode};
 use raise_core::json_db::storage::StorageEngine;
+use raise_core::json_db::transactions::changes::ChangeEvent;
+use raise_core::json_db::views::{ViewDefinition, ViewRefresh};
+use raise_core::utils::context::AccessGuard;
 use raise_core::utils::prelude::*;
 
 // 🎯 On importe le service pur depuis le noyau
-use raise_core::services::json_db_service;
+use raise_core::services::access_service;
+use raise_core::services::json_db_service::{self, ChangeSubscriptions};
+use raise_core::services::materialized_view_service::ViewWatchers;
 
+use tauri::ipc::Channel;
 use tauri::{command, State};
 
 #[command]
@@ -21,26 +33,33 @@ pub async fn jsondb_create_db(
 #[command]
 pub async fn jsondb_drop_db(
     storage: State<'_, StorageEngine>,
+    watchers: State<'_, ViewWatchers>,
     space: String,
     db: String,
 ) -> RaiseResult<bool> {
+    watchers.stop(&space, &db)?;
     json_db_service::jsondb_drop_db(storage.inner(), &space, &db).await
 }
 
 #[command]
 pub async fn jsondb_create_collection(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
     schema_uri: String,
 ) -> RaiseResult<bool> {
-    json_db_service::jsondb_create_collection(
-        storage.inner(),
-        &space,
-        &db,
-        &collection,
-        &schema_uri,
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_create_collection(
+            storage.inner(),
+            &space,
+            &db,
+            &collection,
+            &schema_uri,
+        ),
     )
     .await
 }
@@ -57,35 +76,59 @@ pub async fn jsondb_list_collections(
 #[command]
 pub async fn jsondb_drop_collection(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
 ) -> RaiseResult<bool> {
-    json_db_service::jsondb_drop_collection(storage.inner(), &space, &db, &collection).await
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_drop_collection(storage.inner(), &space, &db, &collection),
+    )
+    .await
 }
 
 #[command]
 pub async fn jsondb_create_index(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
     field: String,
     kind: String,
 ) -> RaiseResult<bool> {
-    json_db_service::jsondb_create_index(storage.inner(), &space, &db, &collection, &field, &kind)
-        .await
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_create_index(
+            storage.inner(),
+            &space,
+            &db,
+            &collection,
+            &field,
+            &kind,
+        ),
+    )
+    .await
 }
 
 #[command]
 pub async fn jsondb_drop_index(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
     field: String,
 ) -> RaiseResult<bool> {
-    json_db_service::jsondb_drop_index(storage.inner(), &space, &db, &collection, &field).await
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_drop_index(storage.inner(), &space, &db, &collection, &field),
+    )
+    .await
 }
 
 #[command]
@@ -102,31 +145,72 @@ pub async fn jsondb_evaluate_draft(
 #[command]
 pub async fn jsondb_insert_document(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
     document: JsonValue,
 ) -> RaiseResult<JsonValue> {
-    json_db_service::jsondb_insert_document(storage.inner(), &space, &db, &collection, document)
-        .await
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_insert_document(
+            storage.inner(),
+            &space,
+            &db,
+            &collection,
+            document,
+        ),
+    )
+    .await
 }
 
 #[command]
 pub async fn jsondb_update_document(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
     id: String,
     document: JsonValue,
 ) -> RaiseResult<JsonValue> {
-    json_db_service::jsondb_update_document(
-        storage.inner(),
-        &space,
-        &db,
-        &collection,
-        &id,
-        document,
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_update_document(
+            storage.inner(),
+            &space,
+            &db,
+            &collection,
+            &id,
+            document,
+        ),
+    )
+    .await
+}
+
+#[command]
+pub async fn jsondb_patch_document(
+    storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
+    space: String,
+    db: String,
+    collection: String,
+    id: String,
+    patch: JsonValue,
+) -> RaiseResult<JsonValue> {
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_patch_document(
+            storage.inner(),
+            &space,
+            &db,
+            &collection,
+            &id,
+            patch,
+        ),
     )
     .await
 }
@@ -134,33 +218,250 @@ pub async fn jsondb_update_document(
 #[command]
 pub async fn jsondb_get_document(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
     id: String,
 ) -> RaiseResult<Option<JsonValue>> {
-    json_db_service::jsondb_get_document(storage.inner(), &space, &db, &collection, &id).await
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_get_document(storage.inner(), &space, &db, &collection, &id),
+    )
+    .await
 }
 
 #[command]
 pub async fn jsondb_delete_document(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
     id: String,
 ) -> RaiseResult<bool> {
-    json_db_service::jsondb_delete_document(storage.inner(), &space, &db, &collection, &id).await
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_delete_document(storage.inner(), &space, &db, &collection, &id),
+    )
+    .await
+}
+
+/// 📡 COMMANDE TAURI : Abonne la vue aux changements d'une collection. Les événements
+/// arrivent sur `on_event` jusqu'à `jsondb_unsubscribe_collection` (ou la fermeture de la vue).
+#[command]
+pub async fn jsondb_subscribe_collection(
+    subscriptions: State<'_, ChangeSubscriptions>,
+    subscription_id: String,
+    space: String,
+    db: String,
+    collection: String,
+    on_event: Channel<ChangeEvent>,
+) -> RaiseResult<()> {
+    let (tx, mut rx) = AsyncChannel::unbounded_channel();
+    json_db_service::jsondb_subscribe_collection(
+        subscriptions.inner(),
+        &subscription_id,
+        &space,
+        &db,
+        &collection,
+        tx,
+    )?;
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            // Vue fermée : le récepteur est libéré, l'abonnement s'arrête au prochain événement
+            if on_event.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
+#[command]
+pub async fn jsondb_unsubscribe_collection(
+    subscriptions: State<'_, ChangeSubscriptions>,
+    subscription_id: String,
+) -> RaiseResult<bool> {
+    json_db_service::jsondb_unsubscribe_collection(subscriptions.inner(), &subscription_id)
 }
 
 #[command]
 pub async fn jsondb_list_all(
     storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
     space: String,
     db: String,
     collection: String,
 ) -> RaiseResult<Vec<JsonValue>> {
-    json_db_service::jsondb_list_all(storage.inner(), &space, &db, &collection).await
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_list_all(storage.inner(), &space, &db, &collection),
+    )
+    .await
+}
+
+#[command]
+pub async fn jsondb_describe_collection(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    collection: String,
+) -> RaiseResult<CollectionFields> {
+    json_db_service::jsondb_describe_collection(storage.inner(), &space, &db, &collection).await
+}
+
+#[command]
+pub async fn jsondb_tighten_collection(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    collection: String,
+    mode: ValidationMode,
+    dry_run: bool,
+) -> RaiseResult<StrictnessReport> {
+    json_db_service::jsondb_tighten_collection(
+        storage.inner(),
+        &space,
+        &db,
+        &collection,
+        mode,
+        dry_run,
+    )
+    .await
+}
+
+#[command]
+pub async fn jsondb_vacuum(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    dry_run: bool,
+) -> RaiseResult<VacuumReport> {
+    json_db_service::jsondb_vacuum(storage.inner(), &space, &db, dry_run).await
+}
+
+#[command]
+pub async fn jsondb_check_refs(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    fix: bool,
+) -> RaiseResult<RefCheckReport> {
+    json_db_service::jsondb_check_refs(storage.inner(), &space, &db, fix).await
+}
+
+#[command]
+pub async fn jsondb_create_snapshot(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    path: String,
+) -> RaiseResult<SnapshotManifest> {
+    json_db_service::jsondb_create_snapshot(storage.inner(), &space, &db, &path).await
+}
+
+#[command]
+pub async fn jsondb_restore_snapshot(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    path: String,
+) -> RaiseResult<RestoreReport> {
+    json_db_service::jsondb_restore_snapshot(storage.inner(), &space, &db, &path).await
+}
+
+#[command]
+pub async fn jsondb_clone_db(
+    storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
+    space: String,
+    db: String,
+    dst_space: String,
+    dst_db: String,
+) -> RaiseResult<usize> {
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_clone_db(storage.inner(), &space, &db, &dst_space, &dst_db),
+    )
+    .await
+}
+
+#[command]
+pub async fn jsondb_copy_collection(
+    storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
+    space: String,
+    db: String,
+    collection: String,
+    dst_space: String,
+    dst_db: String,
+    dst_collection: Option<String>,
+) -> RaiseResult<usize> {
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_copy_collection(
+            storage.inner(),
+            &space,
+            &db,
+            &collection,
+            &dst_space,
+            &dst_db,
+            dst_collection.as_deref(),
+        ),
+    )
+    .await
+}
+
+#[command]
+pub async fn jsondb_create_view(
+    storage: State<'_, StorageEngine>,
+    watchers: State<'_, ViewWatchers>,
+    space: String,
+    db: String,
+    name: String,
+    sql: String,
+) -> RaiseResult<ViewRefresh> {
+    watchers.ensure(storage.inner(), &space, &db)?;
+    json_db_service::jsondb_create_view(storage.inner(), &space, &db, &name, &sql).await
+}
+
+#[command]
+pub async fn jsondb_refresh_view(
+    storage: State<'_, StorageEngine>,
+    watchers: State<'_, ViewWatchers>,
+    space: String,
+    db: String,
+    name: String,
+) -> RaiseResult<ViewRefresh> {
+    watchers.ensure(storage.inner(), &space, &db)?;
+    json_db_service::jsondb_refresh_view(storage.inner(), &space, &db, &name).await
+}
+
+#[command]
+pub async fn jsondb_drop_view(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    name: String,
+) -> RaiseResult<bool> {
+    json_db_service::jsondb_drop_view(storage.inner(), &space, &db, &name).await
+}
+
+#[command]
+pub async fn jsondb_list_views(
+    storage: State<'_, StorageEngine>,
+    watchers: State<'_, ViewWatchers>,
+    space: String,
+    db: String,
+) -> RaiseResult<Vec<ViewDefinition>> {
+    watchers.ensure(storage.inner(), &space, &db)?;
+    json_db_service::jsondb_list_views(storage.inner(), &space, &db).await
 }
 
 #[command]
@@ -169,8 +470,21 @@ pub async fn jsondb_execute_sql(
     space: String,
     db: String,
     sql: String,
+    params: Option<Vec<JsonValue>>,
 ) -> RaiseResult<QueryResult> {
-    json_db_service::jsondb_execute_sql(storage.inner(), &space, &db, &sql).await
+    match params {
+        Some(params) => {
+            json_db_service::jsondb_execute_sql_with_params(
+                storage.inner(),
+                &space,
+                &db,
+                &sql,
+                &params,
+            )
+            .await
+        }
+        None => json_db_service::jsondb_execute_sql(storage.inner(), &space, &db, &sql).await,
+    }
 }
 
 #[command]
@@ -191,3 +505,29 @@ pub async fn jsondb_init_demo_rules(
 ) -> RaiseResult<()> {
     json_db_service::jsondb_init_demo_rules(storage.inner(), &space, &db).await
 }
+
+#[command]
+pub async fn jsondb_get_acl(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+) -> RaiseResult<CollectionAcl> {
+    json_db_service::jsondb_get_acl(storage.inner(), &space, &db).await
+}
+
+#[command]
+pub async fn jsondb_set_acl(
+    storage: State<'_, StorageEngine>,
+    access: State<'_, AccessGuard>,
+    space: String,
+    db: String,
+    pattern: String,
+    grant: Option<CollectionGrant>,
+) -> RaiseResult<CollectionAcl> {
+    let identity = access_service::collection_identity(access.inner());
+    with_optional_access(
+        identity,
+        json_db_service::jsondb_set_acl(storage.inner(), &space, &db, &pattern, grant),
+    )
+    .await
+}
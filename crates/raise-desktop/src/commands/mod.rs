@@ -1,8 +1,12 @@
+pub mod access_commands;
 pub mod ai_commands;
+pub mod audit_commands;
 pub mod blockchain_commands;
 pub mod codegen_commands;
 pub mod cognitive_commands;
+pub mod config_commands;
 pub mod dl_commands;
+pub mod event_commands;
 pub mod genetics_commands;
 pub mod gnn_commands;
 pub mod json_db_commands;
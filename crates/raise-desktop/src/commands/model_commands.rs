@@ -1,8 +1,14 @@
 // FICHIER : crates/raise-desktop/src/commands/model_commands.rs
 
+use raise_core::json_db::bundle::{BundleManifest, ImportOptions, ImportReport};
 use raise_core::json_db::storage::StorageEngine;
+use raise_core::model_engine::metrics::ModelMetricsReport;
+use raise_core::model_engine::patterns::{ElementPattern, PatternInstance};
+use raise_core::model_engine::refactor::{RefactorOp, RefactorPlan};
+use raise_core::model_engine::transformers::test_cases::TestCaseSkeleton;
 use raise_core::model_engine::types::ProjectModel;
-use raise_core::services::model_service;
+use raise_core::services::model_service::{self, CapellaLinkState};
+use raise_core::services::project_service::{self, ProjectView, ProjectWorkspace, RecentProject};
 use raise_core::utils::prelude::*;
 
 use tauri::{command, State};
@@ -15,3 +21,192 @@ pub async fn load_project_model(
 ) -> RaiseResult<ProjectModel> {
     model_service::load_project_model(storage.inner(), &space, &db).await
 }
+
+#[command]
+pub async fn model_metrics_compute(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    label: Option<String>,
+) -> RaiseResult<ModelMetricsReport> {
+    model_service::compute_model_metrics(storage.inner(), &space, &db, label).await
+}
+
+#[command]
+pub async fn model_metrics_trend(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    limit: Option<usize>,
+) -> RaiseResult<Vec<ModelMetricsReport>> {
+    model_service::get_model_metrics_trend(storage.inner(), &space, &db, limit).await
+}
+
+// =========================================================================
+// PATRONS D'ÉLÉMENTS
+// =========================================================================
+
+#[command]
+pub async fn pattern_list(storage: State<'_, StorageEngine>) -> RaiseResult<Vec<ElementPattern>> {
+    model_service::list_element_patterns(storage.inner()).await
+}
+
+#[command]
+pub async fn pattern_save(
+    storage: State<'_, StorageEngine>,
+    pattern: ElementPattern,
+) -> RaiseResult<()> {
+    model_service::save_element_pattern(storage.inner(), &pattern).await
+}
+
+#[command]
+pub async fn pattern_instantiate(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    handle: String,
+    params: JsonObject<String, JsonValue>,
+) -> RaiseResult<PatternInstance> {
+    model_service::instantiate_element_pattern(storage.inner(), &space, &db, &handle, &params).await
+}
+
+#[command]
+pub async fn model_refactor_preview(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    operations: Vec<RefactorOp>,
+) -> RaiseResult<RefactorPlan> {
+    model_service::preview_model_refactor(storage.inner(), &space, &db, &operations).await
+}
+
+#[command]
+pub async fn model_refactor_apply(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    operations: Vec<RefactorOp>,
+) -> RaiseResult<RefactorPlan> {
+    model_service::apply_model_refactor(storage.inner(), &space, &db, &operations).await
+}
+
+#[command]
+pub async fn model_generate_test_cases(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+) -> RaiseResult<Vec<TestCaseSkeleton>> {
+    model_service::generate_test_cases(storage.inner(), &space, &db).await
+}
+
+#[command]
+pub async fn capella_link_start(
+    state: State<'_, CapellaLinkState>,
+    storage: State<'_, StorageEngine>,
+    path: String,
+    space: String,
+    db: String,
+    poll_secs: Option<u64>,
+) -> RaiseResult<()> {
+    model_service::start_capella_link(
+        state.inner(),
+        storage.inner(),
+        &path,
+        &space,
+        &db,
+        poll_secs,
+    )
+    .await
+}
+
+#[command]
+pub async fn capella_link_stop(
+    state: State<'_, CapellaLinkState>,
+    path: String,
+) -> RaiseResult<bool> {
+    Ok(model_service::stop_capella_link(state.inner(), &path).await)
+}
+
+#[command]
+pub async fn capella_link_list(state: State<'_, CapellaLinkState>) -> RaiseResult<Vec<String>> {
+    Ok(model_service::list_capella_links(state.inner()).await)
+}
+
+// =========================================================================
+// ESPACE DE TRAVAIL MULTI-PROJETS
+// =========================================================================
+
+#[command]
+pub async fn project_open(
+    workspace: State<'_, ProjectWorkspace>,
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+) -> RaiseResult<ProjectView> {
+    project_service::open_project(workspace.inner(), storage.inner(), &space, &db).await
+}
+
+#[command]
+pub async fn project_close(
+    workspace: State<'_, ProjectWorkspace>,
+    space: String,
+    db: String,
+) -> RaiseResult<()> {
+    project_service::close_project(workspace.inner(), &space, &db).await
+}
+
+#[command]
+pub async fn project_switch(
+    workspace: State<'_, ProjectWorkspace>,
+    space: String,
+    db: String,
+) -> RaiseResult<ProjectView> {
+    project_service::switch_project(workspace.inner(), &space, &db).await
+}
+
+#[command]
+pub async fn project_list_open(
+    workspace: State<'_, ProjectWorkspace>,
+) -> RaiseResult<Vec<ProjectView>> {
+    project_service::list_open_projects(workspace.inner()).await
+}
+
+#[command]
+pub async fn project_list_recent(
+    storage: State<'_, StorageEngine>,
+) -> RaiseResult<Vec<RecentProject>> {
+    project_service::list_recent_projects(storage.inner()).await
+}
+
+#[command]
+pub async fn project_export_bundle(
+    storage: State<'_, StorageEngine>,
+    space: String,
+    db: String,
+    path: String,
+) -> RaiseResult<BundleManifest> {
+    project_service::export_project_bundle(storage.inner(), &space, &db, Path::new(&path)).await
+}
+
+#[command]
+pub async fn project_import_bundle(
+    storage: State<'_, StorageEngine>,
+    path: String,
+    options: Option<ImportOptions>,
+) -> RaiseResult<ImportReport> {
+    project_service::import_project_bundle(
+        storage.inner(),
+        Path::new(&path),
+        &options.unwrap_or_default(),
+    )
+    .await
+}
+
+#[command]
+pub async fn get_active_project_model(
+    workspace: State<'_, ProjectWorkspace>,
+) -> RaiseResult<ProjectModel> {
+    let model = project_service::get_active_model(workspace.inner()).await?;
+    let guard = model.lock().await;
+    Ok(guard.clone())
+}
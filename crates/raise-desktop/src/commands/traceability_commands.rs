@@ -1,9 +1,11 @@
 // FICHIER : crates/raise-desktop/src/commands/traceability_commands.rs
 
+use raise_core::spatial_engine::GraphNeighborhood;
 use raise_core::traceability::impact_analyzer::ImpactReport;
 use raise_core::traceability::reporting::{
     audit_report::AuditReport, trace_matrix::TraceabilityMatrix,
 };
+use raise_core::traceability::NeighborhoodQuery;
 use raise_core::utils::prelude::*;
 
 // 🎯 On importe le service depuis le noyau
@@ -44,7 +46,9 @@ pub async fn get_traceability_matrix(
 pub async fn get_element_neighbors(
     state: State<'_, SharedRef<AppState>>,
     element_id: String,
-) -> RaiseResult<JsonValue> {
+    query: Option<NeighborhoodQuery>,
+) -> RaiseResult<GraphNeighborhood> {
     let model = state.model.lock().await;
-    traceability_service::get_element_neighbors(&model, &element_id).await
+    traceability_service::get_element_neighbors(&model, &element_id, &query.unwrap_or_default())
+        .await
 }
@@ -1,6 +1,8 @@
 // FICHIER : crates/raise-desktop/src/commands/utils_commands.rs
 
-use raise_core::utils::context::{Session, SessionManager};
+use raise_core::services::access_service;
+use raise_core::utils::context::{AccessGuard, Session, SessionManager};
+use raise_core::utils::network::EndpointHealth;
 use raise_core::utils::prelude::*;
 
 // 🎯 On importe le service et les types de retour
@@ -13,16 +15,33 @@ pub async fn get_app_info() -> RaiseResult<SystemInfoResponse> {
     utils_service::get_app_info().await
 }
 
+#[command]
+pub async fn get_network_health() -> RaiseResult<Vec<EndpointHealth>> {
+    utils_service::get_network_health().await
+}
+
+#[command]
+pub async fn reset_network_circuit(url: Option<String>) -> RaiseResult<()> {
+    utils_service::reset_network_circuit(url.as_deref()).await
+}
+
 #[command]
 pub async fn session_login(
     user_id: String,
     state: State<'_, SessionManager>,
+    access: State<'_, AccessGuard>,
 ) -> RaiseResult<Session> {
-    utils_service::session_login(&user_id, state.inner()).await
+    let session = utils_service::session_login(&user_id, state.inner()).await?;
+    access_service::bind_session(access.inner(), &session);
+    Ok(session)
 }
 
 #[command]
-pub async fn session_logout(state: State<'_, SessionManager>) -> RaiseResult<()> {
+pub async fn session_logout(
+    state: State<'_, SessionManager>,
+    access: State<'_, AccessGuard>,
+) -> RaiseResult<()> {
+    access_service::logout(access.inner());
     utils_service::session_logout(state.inner()).await
 }
 
@@ -2,7 +2,7 @@
 
 use raise_core::json_db::storage::StorageEngine;
 use raise_core::utils::prelude::*;
-use raise_core::workflow_engine::WorkflowDefinition;
+use raise_core::workflow_engine::{AdmissionSnapshot, WorkflowDefinition};
 
 // 🎯 On importe le service et les DTOs depuis le noyau
 use raise_core::services::workflow_service::{self, WorkflowStore, WorkflowView};
@@ -20,7 +20,7 @@ pub async fn set_sensor_value(
 #[command]
 pub async fn compile_mission(
     storage: State<'_, SharedRef<StorageEngine>>,
-    state: State<'_, AsyncMutex<WorkflowStore>>,
+    state: State<'_, SharedRef<AsyncMutex<WorkflowStore>>>,
     mission_id: String,
 ) -> RaiseResult<String> {
     workflow_service::compile_mission(storage.inner(), state.inner(), &mission_id).await
@@ -28,7 +28,7 @@ pub async fn compile_mission(
 
 #[command]
 pub async fn register_workflow(
-    state: State<'_, AsyncMutex<WorkflowStore>>,
+    state: State<'_, SharedRef<AsyncMutex<WorkflowStore>>>,
     definition: WorkflowDefinition,
 ) -> RaiseResult<String> {
     workflow_service::register_workflow(state.inner(), definition).await
@@ -37,15 +37,17 @@ pub async fn register_workflow(
 #[command]
 pub async fn start_workflow(
     storage: State<'_, SharedRef<StorageEngine>>,
-    state: State<'_, AsyncMutex<WorkflowStore>>,
+    state: State<'_, SharedRef<AsyncMutex<WorkflowStore>>>,
     mission_id: String,
     workflow_handle: String,
+    inputs: Option<JsonValue>,
 ) -> RaiseResult<WorkflowView> {
     workflow_service::start_workflow(
         storage.inner(),
         state.inner(),
         mission_id.to_string(),
         workflow_handle.to_string(),
+        inputs,
     )
     .await
 }
@@ -53,7 +55,7 @@ pub async fn start_workflow(
 #[command]
 pub async fn resume_workflow(
     storage: State<'_, SharedRef<StorageEngine>>,
-    state: State<'_, AsyncMutex<WorkflowStore>>,
+    state: State<'_, SharedRef<AsyncMutex<WorkflowStore>>>,
     instance_handle: String,
     node_id: String,
     approved: bool,
@@ -70,8 +72,15 @@ pub async fn resume_workflow(
 
 #[command]
 pub async fn get_workflow_state(
-    state: State<'_, AsyncMutex<WorkflowStore>>,
+    state: State<'_, SharedRef<AsyncMutex<WorkflowStore>>>,
     instance_handle: String,
 ) -> RaiseResult<WorkflowView> {
     workflow_service::get_workflow_state(state.inner(), &instance_handle).await
 }
+
+#[command]
+pub async fn get_workflow_scheduling(
+    state: State<'_, SharedRef<AsyncMutex<WorkflowStore>>>,
+) -> RaiseResult<AdmissionSnapshot> {
+    workflow_service::get_scheduling_snapshot(state.inner()).await
+}
@@ -9,25 +9,35 @@ use raise_core::utils::{context, prelude::*};
 use tauri::Manager;
 
 // --- IMPORTS RAISE CORE ---
+use raise_core::ai::maintenance::MaintenanceScheduler;
+use raise_core::blockchain::vpn::{self, InnernetClient};
 use raise_core::blockchain::BlockchainState;
 use raise_core::json_db::collections::manager::CollectionsManager;
+use raise_core::json_db::collections::vacuum::spawn_vacuum_scheduler;
 use raise_core::json_db::jsonld::VocabularyRegistry;
 use raise_core::json_db::migrations::migrator::Migrator;
 use raise_core::json_db::migrations::{Migration, MigrationStep};
 use raise_core::json_db::storage::{JsonDbConfig, StorageEngine};
 use raise_core::plugins::manager::PluginManager;
+use raise_core::utils::data::spawn_config_watcher;
 
 use raise_core::ai::graph_store::GraphStore;
 use raise_core::kernel::state::RaiseKernelState;
 use raise_core::model_engine::types::ProjectModel;
 use raise_core::workflow_engine::executor::WorkflowExecutor;
 use raise_core::workflow_engine::scheduler::WorkflowScheduler;
+use raise_core::workflow_engine::tools::InstantiatePatternTool;
 
 // --- ÉTATS DES SERVICES DU NOYAU ---
-use raise_core::services::ai_service::AiState;
+use raise_core::services::ai_service::{AiState, AiStreamState};
 use raise_core::services::dl_service::DlState;
 use raise_core::services::gnn_service::GnnState;
+use raise_core::services::json_db_service::ChangeSubscriptions;
+use raise_core::services::materialized_view_service::ViewWatchers;
+use raise_core::services::model_service::CapellaLinkState;
+use raise_core::services::project_service::ProjectWorkspace;
 use raise_core::services::voice_service::VoiceState;
+use raise_core::services::webhook_service::{self, WebhookState};
 use raise_core::services::workflow_service::WorkflowStore;
 
 // --- ÉTAT LOCAL TAURI ---
@@ -38,9 +48,10 @@ pub struct AppState {
 // --- COMMANDES LOCALES (Bridges Tauri) ---
 pub mod commands;
 use commands::{
-    ai_commands, blockchain_commands, codegen_commands, cognitive_commands, dl_commands,
-    genetics_commands, gnn_commands, json_db_commands, model_commands, rules_commands,
-    traceability_commands, training_commands, utils_commands, voice_commands, workflow_commands,
+    access_commands, ai_commands, audit_commands, blockchain_commands, codegen_commands,
+    cognitive_commands, config_commands, dl_commands, event_commands, genetics_commands,
+    gnn_commands, json_db_commands, model_commands, rules_commands, traceability_commands,
+    training_commands, utils_commands, voice_commands, workflow_commands,
 };
 
 #[allow(clippy::await_holding_lock)]
@@ -85,7 +96,9 @@ fn main() {
                 }
             }
 
-            let config = JsonDbConfig::new(db_root.clone());
+            let config = tauri::async_runtime::block_on(
+                JsonDbConfig::new(db_root.clone()).with_encryption_from_env(),
+            )?;
             let storage = StorageEngine::new(config.clone())?;
 
             let system_domain = &app_config.mount_points.system.domain;
@@ -173,6 +186,46 @@ fn main() {
                 system_db,
             ));
 
+            // 5b. CONFIGURATION À CHAUD (Surcharges persistées + surveillance du fichier source)
+            tauri::async_runtime::block_on(async {
+                let manager = CollectionsManager::new(&storage, system_domain, system_db);
+                if let Err(e) = AppConfig::load_live_overrides(&manager).await {
+                    user_warn!(
+                        "WRN_CONFIG_OVERRIDES_LOAD_FAIL",
+                        json_value!({"error": e.to_string()})
+                    );
+                }
+                if let Some(path) = AppConfig::source_path() {
+                    spawn_config_watcher(path.clone(), TimeDuration::from_secs(2));
+                }
+            });
+
+            // 5c. MAINTENANCE PLANIFIÉE (Vacuum de la base système)
+            if app_config.vacuum.enabled {
+                let interval = TimeDuration::from_secs(app_config.vacuum.interval_secs);
+                tauri::async_runtime::block_on(async {
+                    spawn_vacuum_scheduler(
+                        storage.clone(),
+                        system_domain.clone(),
+                        system_db.clone(),
+                        interval,
+                    );
+                });
+            }
+
+            // 5d. EXPIRATION DES DOCUMENTS (Sessions d'agents, instances de workflow périmées)
+            if app_config.ttl.enabled {
+                let interval = TimeDuration::from_secs(app_config.ttl.interval_secs);
+                tauri::async_runtime::block_on(async {
+                    storage.spawn_ttl_sweeper(
+                        system_domain,
+                        system_db,
+                        interval,
+                        app_config.ttl.collections.clone(),
+                    );
+                });
+            }
+
             // 6. INJECTION DES ÉTATS DE BASE
             let plugin_mgr = SharedRef::new(PluginManager::new(&storage, None));
             app.manage(config);
@@ -181,6 +234,8 @@ fn main() {
             app.manage(context::SessionManager::new(SharedRef::new(
                 storage.clone(),
             )));
+            app.manage(context::AuditLog::new(SharedRef::new(storage.clone())));
+            app.manage(context::AccessGuard::new());
             /*
             app.manage(SharedRef::new(AppState {
                 model: SharedRef::new(AsyncMutex::new(ProjectModel::default())),
@@ -188,9 +243,12 @@ fn main() {
             */
 
             // États des Services Métier
-            app.manage(AsyncMutex::new(WorkflowStore::default()));
+            let workflow_store = SharedRef::new(AsyncMutex::new(WorkflowStore::default()));
+            app.manage(workflow_store.clone());
             app.manage(DlState::new());
             app.manage(GnnState::new());
+            app.manage(ProjectWorkspace::new());
+            app.manage(CapellaLinkState::default());
             app.manage(VoiceState::new());
             app.manage(raise_core::services::rules_service::RuleEngineState {
                 model: raise_core::utils::prelude::AsyncMutex::new(ProjectModel::default()),
@@ -198,6 +256,12 @@ fn main() {
 
             // BLOCKCHAIN
             app.manage(SharedRef::new(AsyncMutex::new(BlockchainState::default())));
+            let vpn_client = InnernetClient::new(vpn::NetworkConfig::default());
+            tauri::async_runtime::block_on(async {
+                vpn_client
+                    .spawn_peer_sampler(TimeDuration::from_secs(vpn::PEER_SAMPLE_INTERVAL_SECS));
+            });
+            app.manage(vpn_client);
 
             // ====================================================================
             // 7. 🧠 LE NOYAU (KERNEL) : SÉQUENCE DE BOOT STRICTE ET UNIFIÉE
@@ -210,6 +274,15 @@ fn main() {
             // Injection des états IA dérivés du Kernel
             let ai_state = AiState::new(kernel.orchestrator.clone());
             app.manage(ai_state);
+            app.manage(AiStreamState::default());
+            app.manage(ChangeSubscriptions::default());
+
+            // 🪟 Vues matérialisées de la base système tenues à jour dès le démarrage
+            let view_watchers = ViewWatchers::default();
+            tauri::async_runtime::block_on(async {
+                view_watchers.ensure(&storage, system_domain, system_db)
+            })?;
+            app.manage(view_watchers);
 
             app.manage(raise_core::ai::llm::NativeLlmState(std::sync::Mutex::new(
                 None,
@@ -217,82 +290,209 @@ fn main() {
 
             app.manage(kernel.clone());
 
+            // 🧹 Maintenance IA planifiée (résumés de sessions, index vectoriel, dérive)
+            let mut maintenance = MaintenanceScheduler::system(SharedRef::new(storage.clone()));
+            if let Some(orch_ref) = &kernel.orchestrator {
+                let orch = tauri::async_runtime::block_on(orch_ref.lock());
+                maintenance = maintenance
+                    .with_llm(orch.llm_remote.clone())
+                    .with_vector_store(orch.rag.vector_store(), orch.rag.collection_name());
+            }
+            let maintenance = SharedRef::new(maintenance);
+            tauri::async_runtime::block_on(async {
+                maintenance.clone().spawn();
+            });
+            app.manage(maintenance);
+
+            // 📡 Pont du bus d'événements vers le frontend
+            event_commands::spawn_event_bridge(app.handle().clone());
+
             // ====================================================================
             // 8. WORKFLOW ENGINE
             // ====================================================================
             if let Some(orch_ref) = kernel.orchestrator {
-                let executor = WorkflowExecutor::new(orch_ref, plugin_mgr);
+                let mut executor = WorkflowExecutor::new(orch_ref, plugin_mgr);
+                executor.register_tool(Box::new(InstantiatePatternTool));
                 tauri::async_runtime::block_on(async {
-                    let wf_state = app.handle().state::<AsyncMutex<WorkflowStore>>();
-                    let mut wf_store = wf_state.lock().await;
-                    wf_store.scheduler = Some(WorkflowScheduler::new(executor));
+                    let mut wf_store = workflow_store.lock().await;
+                    wf_store.scheduler = Some(
+                        WorkflowScheduler::new(executor)
+                            .with_scheduling(AppConfig::get().workflow_scheduling.clone())
+                            .with_plan_review(AppConfig::get().plan_review.clone()),
+                    );
+                });
+
+                // 🪝 Déclencheurs webhook entrants (si `webhooks.enabled`)
+                let webhook_state = WebhookState {
+                    storage: SharedRef::new(storage.clone()),
+                    workflows: workflow_store.clone(),
+                };
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = webhook_service::serve_webhooks(webhook_state).await {
+                        user_error!(
+                            "ERR_WEBHOOK_SERVER",
+                            json_value!({ "error": e.to_string() })
+                        );
+                    }
                 });
             }
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![
-            json_db_commands::jsondb_create_db,
-            json_db_commands::jsondb_drop_db,
-            json_db_commands::jsondb_create_collection,
-            json_db_commands::jsondb_list_collections,
-            json_db_commands::jsondb_drop_collection,
-            json_db_commands::jsondb_create_index,
-            json_db_commands::jsondb_drop_index,
-            json_db_commands::jsondb_insert_document,
-            json_db_commands::jsondb_get_document,
-            json_db_commands::jsondb_update_document,
-            json_db_commands::jsondb_delete_document,
-            json_db_commands::jsondb_list_all,
-            json_db_commands::jsondb_execute_query,
-            json_db_commands::jsondb_execute_sql,
-            json_db_commands::jsondb_evaluate_draft,
-            json_db_commands::jsondb_init_demo_rules,
-            model_commands::load_project_model,
-            rules_commands::dry_run_rule,
-            rules_commands::validate_model,
-            ai_commands::ai_chat,
-            ai_commands::ai_reset,
-            ai_commands::ask_native_llm,
-            ai_commands::ai_learn_text,
-            ai_commands::ai_export_dataset,
-            ai_commands::validate_arcadia_gnn,
-            dl_commands::init_dl_model,
-            dl_commands::run_dl_prediction,
-            dl_commands::train_dl_step,
-            dl_commands::save_dl_model,
-            dl_commands::load_dl_model,
-            gnn_commands::init_gnn_engine,
-            gnn_commands::train_gnn_step,
-            gnn_commands::audit_ontology,
-            training_commands::tauri_train_domain,
-            cognitive_commands::cognitive_load_plugin,
-            cognitive_commands::cognitive_run_plugin,
-            cognitive_commands::cognitive_list_plugins,
-            blockchain_commands::mentis_init_node,
-            blockchain_commands::mentis_broadcast_mutation,
-            blockchain_commands::mentis_get_ledger_info,
-            genetics_commands::run_architecture_optimization,
-            genetics_commands::debug_genetics_ping,
-            codegen_commands::generate_source_code,
-            codegen_commands::ingest_module,
-            codegen_commands::weave_module,
-            traceability_commands::analyze_impact,
-            traceability_commands::run_compliance_audit,
-            traceability_commands::get_traceability_matrix,
-            traceability_commands::get_element_neighbors,
-            utils_commands::get_app_info,
-            utils_commands::session_login,
-            utils_commands::session_logout,
-            utils_commands::session_get,
-            voice_commands::toggle_voice_assistant,
-            workflow_commands::compile_mission,
-            workflow_commands::register_workflow,
-            workflow_commands::start_workflow,
-            workflow_commands::resume_workflow,
-            workflow_commands::get_workflow_state,
-            workflow_commands::set_sensor_value,
-        ])
+        .invoke_handler({
+            let handler = tauri::generate_handler![
+                access_commands::access_login_oidc,
+                access_commands::access_login_token,
+                access_commands::access_issue_token,
+                access_commands::access_whoami,
+                audit_commands::audit_list,
+                audit_commands::audit_anchor_day,
+                audit_commands::audit_verify_day,
+                json_db_commands::jsondb_create_db,
+                json_db_commands::jsondb_drop_db,
+                json_db_commands::jsondb_create_collection,
+                json_db_commands::jsondb_list_collections,
+                json_db_commands::jsondb_drop_collection,
+                json_db_commands::jsondb_create_index,
+                json_db_commands::jsondb_drop_index,
+                json_db_commands::jsondb_insert_document,
+                json_db_commands::jsondb_get_document,
+                json_db_commands::jsondb_update_document,
+                json_db_commands::jsondb_patch_document,
+                json_db_commands::jsondb_delete_document,
+                json_db_commands::jsondb_list_all,
+                json_db_commands::jsondb_subscribe_collection,
+                json_db_commands::jsondb_unsubscribe_collection,
+                json_db_commands::jsondb_describe_collection,
+                json_db_commands::jsondb_tighten_collection,
+                json_db_commands::jsondb_vacuum,
+                json_db_commands::jsondb_check_refs,
+                json_db_commands::jsondb_create_snapshot,
+                json_db_commands::jsondb_restore_snapshot,
+                json_db_commands::jsondb_clone_db,
+                json_db_commands::jsondb_copy_collection,
+                json_db_commands::jsondb_create_view,
+                json_db_commands::jsondb_refresh_view,
+                json_db_commands::jsondb_drop_view,
+                json_db_commands::jsondb_list_views,
+                json_db_commands::jsondb_get_acl,
+                json_db_commands::jsondb_set_acl,
+                json_db_commands::jsondb_execute_query,
+                json_db_commands::jsondb_execute_sql,
+                json_db_commands::jsondb_evaluate_draft,
+                json_db_commands::jsondb_init_demo_rules,
+                config_commands::config_get,
+                config_commands::config_set,
+                config_commands::config_list_overrides,
+                event_commands::get_event_catalogue,
+                model_commands::load_project_model,
+                model_commands::model_metrics_compute,
+                model_commands::model_metrics_trend,
+                model_commands::pattern_list,
+                model_commands::pattern_save,
+                model_commands::pattern_instantiate,
+                model_commands::model_refactor_preview,
+                model_commands::model_refactor_apply,
+                model_commands::model_generate_test_cases,
+                model_commands::capella_link_start,
+                model_commands::capella_link_stop,
+                model_commands::capella_link_list,
+                model_commands::project_open,
+                model_commands::project_close,
+                model_commands::project_switch,
+                model_commands::project_list_open,
+                model_commands::project_list_recent,
+                model_commands::project_export_bundle,
+                model_commands::project_import_bundle,
+                model_commands::get_active_project_model,
+                rules_commands::dry_run_rule,
+                rules_commands::validate_model,
+                ai_commands::ai_chat,
+                ai_commands::ai_chat_stream,
+                ai_commands::ai_chat_cancel,
+                ai_commands::ai_write_conflicts,
+                ai_commands::ai_resolve_write_conflict,
+                ai_commands::ai_maintenance_schedule,
+                ai_commands::ai_maintenance_run,
+                ai_commands::ai_maintenance_history,
+                ai_commands::ai_models_list,
+                ai_commands::ai_model_download,
+                ai_commands::ai_model_verify,
+                ai_commands::ai_model_switch,
+                ai_commands::ai_find_duplicates,
+                ai_commands::ai_requirement_quality,
+                ai_commands::ai_transcribe,
+                ai_commands::ai_voice_chat,
+                ai_commands::ai_reset,
+                ai_commands::ask_native_llm,
+                ai_commands::ai_learn_text,
+                ai_commands::ai_export_dataset,
+                ai_commands::ai_usage_dashboard,
+                ai_commands::ai_embedding_status,
+                ai_commands::ai_embedding_migrate,
+                ai_commands::ai_embedding_migrations,
+                ai_commands::ai_prompt_list,
+                ai_commands::ai_prompt_history,
+                ai_commands::ai_prompt_publish,
+                ai_commands::ai_prompt_annotate,
+                ai_commands::ai_intent_examples,
+                ai_commands::ai_intent_example_flag,
+                ai_commands::validate_arcadia_gnn,
+                dl_commands::init_dl_model,
+                dl_commands::run_dl_prediction,
+                dl_commands::train_dl_step,
+                dl_commands::save_dl_model,
+                dl_commands::load_dl_model,
+                gnn_commands::init_gnn_engine,
+                gnn_commands::train_gnn_step,
+                gnn_commands::audit_ontology,
+                training_commands::tauri_train_domain,
+                cognitive_commands::cognitive_load_plugin,
+                cognitive_commands::cognitive_run_plugin,
+                cognitive_commands::cognitive_list_plugins,
+                blockchain_commands::mentis_init_node,
+                blockchain_commands::mentis_broadcast_mutation,
+                blockchain_commands::mentis_get_ledger_info,
+                blockchain_commands::mentis_explore,
+                blockchain_commands::mentis_reputation_report,
+                blockchain_commands::mentis_notarize_file,
+                blockchain_commands::mentis_verify_file,
+                blockchain_commands::vpn_list_peers,
+                blockchain_commands::vpn_peer_stats,
+                genetics_commands::run_architecture_optimization,
+                genetics_commands::debug_genetics_ping,
+                codegen_commands::generate_source_code,
+                codegen_commands::ingest_module,
+                codegen_commands::weave_module,
+                traceability_commands::analyze_impact,
+                traceability_commands::run_compliance_audit,
+                traceability_commands::get_traceability_matrix,
+                traceability_commands::get_element_neighbors,
+                utils_commands::get_app_info,
+                utils_commands::get_network_health,
+                utils_commands::reset_network_circuit,
+                utils_commands::session_login,
+                utils_commands::session_logout,
+                utils_commands::session_get,
+                voice_commands::toggle_voice_assistant,
+                workflow_commands::compile_mission,
+                workflow_commands::register_workflow,
+                workflow_commands::start_workflow,
+                workflow_commands::resume_workflow,
+                workflow_commands::get_workflow_state,
+                workflow_commands::get_workflow_scheduling,
+                workflow_commands::set_sensor_value,
+            ];
+            // 🔐 Contrôle d'accès par rôle, puis 🧾 journal d'audit des commandes modifiant l'état
+            move |invoke| {
+                if let Err(err) = access_commands::authorize_invocation(&invoke) {
+                    invoke.resolver.reject(err);
+                    return true;
+                }
+                audit_commands::trace_invocation(&invoke);
+                handler(invoke)
+            }
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -320,7 +520,17 @@ async fn run_app_migrations(storage: &StorageEngine, space: &str, db: &str) -> R
                     schema: JsonValue::String(schema_uri),
                 },
             ],
-            down: vec![],
+            down: vec![
+                MigrationStep::DropCollection {
+                    name: "exchange_items".to_string(),
+                },
+                MigrationStep::DropCollection {
+                    name: "systems".to_string(),
+                },
+                MigrationStep::DropCollection {
+                    name: "articles".to_string(),
+                },
+            ],
             applied_at: None,
         },
         Migration {
@@ -330,8 +540,12 @@ async fn run_app_migrations(storage: &StorageEngine, space: &str, db: &str) -> R
             up: vec![MigrationStep::CreateIndex {
                 collection: "articles".to_string(),
                 fields: vec!["title".to_string()],
+                unique: false,
+            }],
+            down: vec![MigrationStep::DropIndex {
+                collection: "articles".to_string(),
+                name: "title".to_string(),
             }],
-            down: vec![],
             applied_at: None,
         },
     ];
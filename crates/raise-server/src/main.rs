@@ -0,0 +1,131 @@
+// FICHIER : crates/raise-server/src/main.rs
+
+use raise_core::json_db::collections::manager::CollectionsManager;
+use raise_core::json_db::collections::vacuum::spawn_vacuum_scheduler;
+use raise_core::kernel::environment::NodeEnvironment;
+use raise_core::kernel::state::RaiseKernelState;
+use raise_core::plugins::manager::PluginManager;
+use raise_core::services::ai_service::AiState;
+use raise_core::services::server_service::{self, ServerState};
+use raise_core::services::webhook_service::{self, WebhookState};
+use raise_core::services::workflow_service::WorkflowStore;
+use raise_core::utils::data::spawn_config_watcher;
+use raise_core::utils::io::os::run_cli_app;
+use raise_core::utils::{context, prelude::*};
+use raise_core::workflow_engine::executor::WorkflowExecutor;
+use raise_core::workflow_engine::scheduler::WorkflowScheduler;
+use raise_core::workflow_engine::tools::InstantiatePatternTool;
+
+fn main() -> RaiseResult<()> {
+    run_cli_app(async {
+        // 1. INITIALISATION CONFIGURATION, LOGGER ET LANGUE
+        if let Err(e) = AppConfig::init() {
+            raise_error!(
+                "ERR_SERVER_CRITICAL_INIT_FAILED",
+                error = e,
+                context = json_value!({"step": "AppConfig::init"})
+            );
+        }
+        context::init_logging();
+        let config = AppConfig::get();
+        if context::init_i18n(&config.core.language).await.is_err() {
+            eprintln!(
+                "⚠️ [BOOTSTRAP MODE] Traductions inaccessibles. Démarrage en mode sans échec."
+            );
+        }
+        user_info!("INF_RAISE_BOOT_START");
+
+        // 2. AMORÇAGE DU NŒUD PHYSIQUE (stockage)
+        let (node_env, needs_restart) = match NodeEnvironment::boot_physical_node().await {
+            Ok(env) => env,
+            Err(e) => raise_error!(
+                "ERR_SERVER_PHYSICAL_BOOT",
+                error = e,
+                context = json_value!({"hint": "Impossible d'amorcer le nœud matériel. Vérifiez les droits d'écriture."})
+            ),
+        };
+        if needs_restart {
+            user_info!(
+                "NODE_BOOT_SIGNAL",
+                json_value!({"action": "Amorçage atomique complet. Terminaison du processus par le lanceur."})
+            );
+            terminate_process(0);
+        }
+        let storage = node_env.storage;
+        let system_domain = &config.mount_points.system.domain;
+        let system_db = &config.mount_points.system.db;
+
+        // 3. MOTEURS DU CŒUR (WAL, sémantique, règles) + CONFIGURATION À CHAUD
+        let system_mgr = CollectionsManager::new(&storage, system_domain, system_db);
+        raise_core::bootstrap_core(&system_mgr).await?;
+        if let Err(e) = AppConfig::load_live_overrides(&system_mgr).await {
+            user_warn!(
+                "WRN_CONFIG_OVERRIDES_LOAD_FAIL",
+                json_value!({"error": e.to_string()})
+            );
+        }
+        if let Some(path) = AppConfig::source_path() {
+            spawn_config_watcher(path, TimeDuration::from_secs(2));
+        }
+        if config.vacuum.enabled {
+            spawn_vacuum_scheduler(
+                (*storage).clone(),
+                system_domain.clone(),
+                system_db.clone(),
+                TimeDuration::from_secs(config.vacuum.interval_secs),
+            );
+        }
+        if config.ttl.enabled {
+            storage.spawn_ttl_sweeper(
+                system_domain,
+                system_db,
+                TimeDuration::from_secs(config.ttl.interval_secs),
+                config.ttl.collections.clone(),
+            );
+        }
+
+        // 4. NOYAU : ORCHESTRATEUR IA ET MOTEUR DE WORKFLOWS
+        let kernel = match RaiseKernelState::boot(storage.clone()).await {
+            Ok(state) => state,
+            Err(e) => raise_error!(
+                "ERR_SERVER_KERNEL_BOOT_FAILED",
+                error = e.to_string(),
+                context = json_value!({"hint": "Échec critique lors du montage de la partition système."})
+            ),
+        };
+        let workflows = SharedRef::new(AsyncMutex::new(WorkflowStore::default()));
+        if let Some(orch_ref) = kernel.orchestrator.clone() {
+            let plugin_mgr = SharedRef::new(PluginManager::new(&storage, None));
+            let mut executor = WorkflowExecutor::new(orch_ref, plugin_mgr);
+            executor.register_tool(Box::new(InstantiatePatternTool));
+            workflows.lock().await.scheduler = Some(
+                WorkflowScheduler::new(executor)
+                    .with_scheduling(config.workflow_scheduling.clone())
+                    .with_plan_review(config.plan_review.clone()),
+            );
+        }
+
+        // 🪝 Déclencheurs webhook entrants (si `webhooks.enabled`)
+        let webhook_state = WebhookState {
+            storage: storage.clone(),
+            workflows: workflows.clone(),
+        };
+        spawn_async_task(async move {
+            if let Err(e) = webhook_service::serve_webhooks(webhook_state).await {
+                user_error!(
+                    "ERR_WEBHOOK_SERVER",
+                    json_value!({ "error": e.to_string() })
+                );
+            }
+        });
+
+        // 5. API HTTP (bloquant jusqu'à l'arrêt)
+        server_service::serve(ServerState {
+            storage: storage.clone(),
+            ai: SharedRef::new(AiState::new(kernel.orchestrator.clone())),
+            workflows,
+            audit: context::AuditLog::new(storage),
+        })
+        .await
+    })
+}